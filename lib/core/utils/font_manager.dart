@@ -130,16 +130,12 @@ class FontManager {
           return null;
         }
 
-        ttfBytes = await rust_ffi.convertWoff2ToTtf(woff2Data: woff2Bytes);
-        developer.log('TTF size: ${ttfBytes.length} bytes', name: 'FONT');
-
-        if (ttfBytes.isNotEmpty) {
-          await ttfFile.writeAsBytes(ttfBytes);
-          developer.log('Saved TTF: $ttfPath', name: 'FONT');
-        } else {
-          developer.log('Conversion returned empty!', name: 'FONT');
-          return null;
-        }
+        // 直接写入缓存文件，避免 TTF 字节跨 FFI 拷贝
+        final ttfSize = await rust_ffi.convertWoff2ToTtfFile(
+          woff2Data: woff2Bytes,
+          ttfPath: ttfPath,
+        );
+        developer.log('Saved TTF: $ttfPath ($ttfSize bytes)', name: 'FONT');
       }
 
       // 6. 加载到 Flutter
@@ -0,0 +1,114 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `anchor`, `char_index`, `exact_matches`, `fuzzy_matches`, `nearest_char`, `new`, `resolve`, `similarity`, `slice`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Candidate`, `Text`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+/// Make an anchor for a range of `text`.
+///
+/// # Arguments
+/// * `text` - Chapter text
+/// * `start` - Start of the range
+/// * `end` - End of the range, after `start`
+Future<TextAnchor> createAnchor({
+  required String text,
+  required int start,
+  required int end,
+}) => RustLib.instance.api.crateApiAnchorsCreateAnchor(
+  text: text,
+  start: start,
+  end: end,
+);
+
+/// Find an anchor in a new version of the chapter, or `None` if the quoted
+/// text is gone.
+Future<AnchorResolution?> resolveAnchor({
+  required String text,
+  required TextAnchor anchor,
+}) => RustLib.instance.api.crateApiAnchorsResolveAnchor(
+  text: text,
+  anchor: anchor,
+);
+
+/// Resolve several anchors against the same text, in input order.
+Future<List<AnchorResolution?>> resolveAnchors({
+  required String text,
+  required List<TextAnchor> anchors,
+}) => RustLib.instance.api.crateApiAnchorsResolveAnchors(
+  text: text,
+  anchors: anchors,
+);
+
+class AnchorResolution {
+  /// The anchor with its quote, context and offsets taken from the new
+  /// text, to store in place of the old one.
+  final TextAnchor anchor;
+  /// Whether the quote was found unchanged.
+  final bool exact;
+  /// How much of the quote survived, from 0.75 to 1.
+  final double similarity;
+
+  const AnchorResolution({
+    required this.anchor,
+    required this.exact,
+    required this.similarity,
+  });
+
+  @override
+  int get hashCode => anchor.hashCode ^ exact.hashCode ^ similarity.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AnchorResolution &&
+          runtimeType == other.runtimeType &&
+          anchor == other.anchor &&
+          exact == other.exact &&
+          similarity == other.similarity;
+}
+
+class TextAnchor {
+  /// The quoted text.
+  final String exact;
+  /// Text just before the quote.
+  final String prefix;
+  /// Text just after the quote.
+  final String suffix;
+  /// Where the quote was last found.
+  final int start;
+  final int end;
+
+  const TextAnchor({
+    required this.exact,
+    required this.prefix,
+    required this.suffix,
+    required this.start,
+    required this.end,
+  });
+
+  @override
+  int get hashCode =>
+      exact.hashCode ^
+      prefix.hashCode ^
+      suffix.hashCode ^
+      start.hashCode ^
+      end.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TextAnchor &&
+          runtimeType == other.runtimeType &&
+          exact == other.exact &&
+          prefix == other.prefix &&
+          suffix == other.suffix &&
+          start == other.start &&
+          end == other.end;
+}
@@ -0,0 +1,182 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `detect_format`, `entry`, `extract`, `import_kind`, `list_entries`, `rar_name`, `safe_path`, `unique_path`, `write_entry`, `write_limited`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Extractor`, `RawEntry`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// List the files in a ZIP, RAR or 7z archive.
+///
+/// # Arguments
+/// * `path` - Path to the archive
+Future<ArchiveInfo> listArchive({required String path}) =>
+    RustLib.instance.api.crateApiArchiveListArchive(path: path);
+
+/// Extract entries of an archive into `dest_dir`, keeping their folders.
+/// An entry that cannot be extracted is reported with an error and the
+/// rest carry on; hitting the archive-wide size limit stops the call.
+///
+/// # Arguments
+/// * `path` - Path to the archive
+/// * `dest_dir` - Directory to extract into, created if missing
+/// * `indices` - Entries to extract; every unencrypted book if omitted
+/// * `sink` - Receives one event per entry
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+Stream<ArchiveProgress> extractArchive({
+  required String path,
+  required String destDir,
+  Uint32List? indices,
+  int? cancelToken,
+}) => RustLib.instance.api.crateApiArchiveExtractArchive(
+  path: path,
+  destDir: destDir,
+  indices: indices,
+  cancelToken: cancelToken,
+);
+
+class ArchiveEntry {
+  /// Position in the archive; pass this to `extract_archive`.
+  final int index;
+  /// Entry path inside the archive, with `/` separators.
+  final String name;
+  /// Uncompressed size in bytes, as the archive declares it.
+  final int size;
+  /// The importer for the entry, or `None` if it is not a book.
+  final ImportKind? kind;
+  /// Whether the entry is password-protected and cannot be extracted.
+  final bool encrypted;
+
+  const ArchiveEntry({
+    required this.index,
+    required this.name,
+    required this.size,
+    this.kind,
+    required this.encrypted,
+  });
+
+  @override
+  int get hashCode =>
+      index.hashCode ^
+      name.hashCode ^
+      size.hashCode ^
+      kind.hashCode ^
+      encrypted.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ArchiveEntry &&
+          runtimeType == other.runtimeType &&
+          index == other.index &&
+          name == other.name &&
+          size == other.size &&
+          kind == other.kind &&
+          encrypted == other.encrypted;
+}
+
+enum ArchiveFormat { zip, rar, sevenZip }
+
+class ArchiveInfo {
+  final ArchiveFormat format;
+  /// Files in archive order; directories are left out.
+  final List<ArchiveEntry> entries;
+
+  const ArchiveInfo({required this.format, required this.entries});
+
+  @override
+  int get hashCode => format.hashCode ^ entries.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ArchiveInfo &&
+          runtimeType == other.runtimeType &&
+          format == other.format &&
+          entries == other.entries;
+}
+
+/// Reported once per entry, whether or not it could be extracted.
+class ArchiveProgress {
+  final int done;
+  final int total;
+  final ExtractedFile file;
+
+  const ArchiveProgress({
+    required this.done,
+    required this.total,
+    required this.file,
+  });
+
+  @override
+  int get hashCode => done.hashCode ^ total.hashCode ^ file.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ArchiveProgress &&
+          runtimeType == other.runtimeType &&
+          done == other.done &&
+          total == other.total &&
+          file == other.file;
+}
+
+class ExtractedFile {
+  final int index;
+  final String name;
+  final ImportKind? kind;
+  /// Where the entry was written, if it was.
+  final String? path;
+  /// Why the entry was not extracted.
+  final String? error;
+
+  const ExtractedFile({
+    required this.index,
+    required this.name,
+    this.kind,
+    this.path,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      index.hashCode ^
+      name.hashCode ^
+      kind.hashCode ^
+      path.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ExtractedFile &&
+          runtimeType == other.runtimeType &&
+          index == other.index &&
+          name == other.name &&
+          kind == other.kind &&
+          path == other.path &&
+          error == other.error;
+}
+
+/// Which importer handles an extracted file.
+enum ImportKind {
+  /// `parse_epub`
+  epub,
+  /// `parse_mobi`, for MOBI, AZW and AZW3
+  mobi,
+  /// `parse_pdf`
+  pdf,
+  /// `parse_fb2`
+  fb2,
+  /// `parse_umd`
+  umd,
+  /// `process_books`
+  txt,
+  /// `list_comic_pages`, for CBZ and CBR
+  comic,
+}
@@ -0,0 +1,214 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'tts.dart';
+import 'tts_prep.dart';
+
+// These functions are ignored because they are not marked as `pub`: `assemble`, `clear`, `current`, `entries`, `entry_path`, `evict`, `extension`, `find`, `get`, `lock`, `mp3_frames`, `new`, `parse_wav`, `parse`, `put`, `stats`, `touch`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AudioCache`, `Entry`, `FrameHeader`, `Wav`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Enable the audio cache. Calling this again switches to the new
+/// directory or size cap.
+///
+/// # Arguments
+/// * `cache_dir` - Directory owned by the cache; created if missing
+/// * `max_bytes` - Total size of cached audio to keep
+Future<void> initAudioCache({
+  required String cacheDir,
+  required BigInt maxBytes,
+}) => RustLib.instance.api.crateApiAudioCacheInitAudioCache(
+  cacheDir: cacheDir,
+  maxBytes: maxBytes,
+);
+
+/// Store the audio of one utterance.
+///
+/// # Arguments
+/// * `voice` - Engine, voice and settings that produced the audio
+/// * `text` - Text that was spoken
+/// * `format` - Container of `data`
+/// * `data` - Audio bytes
+Future<void> putCachedAudio({
+  required String voice,
+  required String text,
+  required AudioFormat format,
+  required List<int> data,
+}) => RustLib.instance.api.crateApiAudioCachePutCachedAudio(
+  voice: voice,
+  text: text,
+  format: format,
+  data: data,
+);
+
+/// The cached audio of one utterance, if any.
+Future<CachedAudio?> getCachedAudio({
+  required String voice,
+  required String text,
+}) => RustLib.instance.api.crateApiAudioCacheGetCachedAudio(
+  voice: voice,
+  text: text,
+);
+
+/// IDs of the utterances that have no cached audio, for fetching from an
+/// online engine.
+Future<List<String>> missingCachedAudio({
+  required String voice,
+  required List<Utterance> utterances,
+}) => RustLib.instance.api.crateApiAudioCacheMissingCachedAudio(
+  voice: voice,
+  utterances: utterances,
+);
+
+/// Synthesise the uncached utterances with the loaded local voice and
+/// cache them as WAV. Returns how many were synthesised.
+///
+/// # Arguments
+/// * `voice` - Cache key for the local voice and `options`
+/// * `utterances` - Utterances from `prepare_tts`
+/// * `options` - Speed, pitch and speaker
+Future<int> presynthesizeAudio({
+  required String voice,
+  required List<Utterance> utterances,
+  required TtsOptions options,
+}) => RustLib.instance.api.crateApiAudioCachePresynthesizeAudio(
+  voice: voice,
+  utterances: utterances,
+  options: options,
+);
+
+/// Join the cached audio of `utterances` into one file at `output_path`.
+/// Uncached utterances are skipped and reported; all cached segments must
+/// share one format.
+///
+/// # Arguments
+/// * `voice` - Cache key the audio was stored under
+/// * `utterances` - Utterances in playback order
+/// * `output_path` - File to write; replaced if it exists
+Future<ChapterAudio> assembleChapterAudio({
+  required String voice,
+  required List<Utterance> utterances,
+  required String outputPath,
+}) => RustLib.instance.api.crateApiAudioCacheAssembleChapterAudio(
+  voice: voice,
+  utterances: utterances,
+  outputPath: outputPath,
+);
+
+/// Report the number and total size of cached segments.
+Future<AudioCacheStats> audioCacheStats() =>
+    RustLib.instance.api.crateApiAudioCacheAudioCacheStats();
+
+/// Delete every cached segment. The cache stays enabled.
+Future<void> clearAudioCache() =>
+    RustLib.instance.api.crateApiAudioCacheClearAudioCache();
+
+/// Current size of the audio cache.
+class AudioCacheStats {
+  final int entryCount;
+  final BigInt totalBytes;
+  final BigInt maxBytes;
+
+  const AudioCacheStats({
+    required this.entryCount,
+    required this.totalBytes,
+    required this.maxBytes,
+  });
+
+  @override
+  int get hashCode =>
+      entryCount.hashCode ^ totalBytes.hashCode ^ maxBytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AudioCacheStats &&
+          runtimeType == other.runtimeType &&
+          entryCount == other.entryCount &&
+          totalBytes == other.totalBytes &&
+          maxBytes == other.maxBytes;
+}
+
+enum AudioFormat { mp3, wav }
+
+/// Where an utterance starts in an assembled file.
+class AudioSegment {
+  final String utteranceId;
+  final BigInt startMs;
+  final BigInt durationMs;
+
+  const AudioSegment({
+    required this.utteranceId,
+    required this.startMs,
+    required this.durationMs,
+  });
+
+  @override
+  int get hashCode =>
+      utteranceId.hashCode ^ startMs.hashCode ^ durationMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AudioSegment &&
+          runtimeType == other.runtimeType &&
+          utteranceId == other.utteranceId &&
+          startMs == other.startMs &&
+          durationMs == other.durationMs;
+}
+
+class CachedAudio {
+  final AudioFormat format;
+  final Uint8List data;
+
+  const CachedAudio({required this.format, required this.data});
+
+  @override
+  int get hashCode => format.hashCode ^ data.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CachedAudio &&
+          runtimeType == other.runtimeType &&
+          format == other.format &&
+          data == other.data;
+}
+
+class ChapterAudio {
+  final AudioFormat format;
+  final BigInt durationMs;
+  /// Segments in playback order.
+  final List<AudioSegment> segments;
+  /// Utterances left out because they are not cached.
+  final List<String> missing;
+
+  const ChapterAudio({
+    required this.format,
+    required this.durationMs,
+    required this.segments,
+    required this.missing,
+  });
+
+  @override
+  int get hashCode =>
+      format.hashCode ^
+      durationMs.hashCode ^
+      segments.hashCode ^
+      missing.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChapterAudio &&
+          runtimeType == other.runtimeType &&
+          format == other.format &&
+          durationMs == other.durationMs &&
+          segments == other.segments &&
+          missing == other.missing;
+}
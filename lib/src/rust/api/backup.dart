@@ -0,0 +1,149 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'purify.dart';
+
+// These functions are ignored because they are not marked as `pub`: `book_from`, `book_json`, `bookmark_from`, `bookmark_json`, `highlight_from`, `highlight_json`, `info`, `merge_rules`, `merge_sources`, `number`, `object`, `pack`, `progress_from`, `progress_json`, `read`, `required`, `rule_from`, `rule_json`, `sha256`, `source_list`, `text`, `unpack`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Backup`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+/// Write the open library database, purify rules and book sources to a
+/// backup file.
+///
+/// # Arguments
+/// * `path` - Backup file to write, replaced if it exists
+/// * `purify_rules` - Current purify rules
+/// * `book_sources_json` - Imported book sources as a JSON array or object
+Future<BackupInfo> createBackup({
+  required String path,
+  required List<PurifyRule> purifyRules,
+  required String bookSourcesJson,
+}) => RustLib.instance.api.crateApiBackupCreateBackup(
+  path: path,
+  purifyRules: purifyRules,
+  bookSourcesJson: bookSourcesJson,
+);
+
+/// Check a backup file and describe what it holds, without restoring it.
+Future<BackupInfo> readBackupInfo({required String path}) =>
+    RustLib.instance.api.crateApiBackupReadBackupInfo(path: path);
+
+/// Check a backup file and merge it into the open library database.
+///
+/// # Arguments
+/// * `path` - Backup file
+/// * `purify_rules` - Current purify rules, to merge the backup's into
+/// * `book_sources_json` - Current book sources, to merge the backup's into
+Future<RestoreResult> restoreBackup({
+  required String path,
+  required List<PurifyRule> purifyRules,
+  required String bookSourcesJson,
+}) => RustLib.instance.api.crateApiBackupRestoreBackup(
+  path: path,
+  purifyRules: purifyRules,
+  bookSourcesJson: bookSourcesJson,
+);
+
+class BackupInfo {
+  final int version;
+  /// Seconds since the Unix epoch.
+  final PlatformInt64 createdAt;
+  final int bookCount;
+  final int bookmarkCount;
+  final int highlightCount;
+  final int purifyRuleCount;
+  final int bookSourceCount;
+
+  const BackupInfo({
+    required this.version,
+    required this.createdAt,
+    required this.bookCount,
+    required this.bookmarkCount,
+    required this.highlightCount,
+    required this.purifyRuleCount,
+    required this.bookSourceCount,
+  });
+
+  @override
+  int get hashCode =>
+      version.hashCode ^
+      createdAt.hashCode ^
+      bookCount.hashCode ^
+      bookmarkCount.hashCode ^
+      highlightCount.hashCode ^
+      purifyRuleCount.hashCode ^
+      bookSourceCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BackupInfo &&
+          runtimeType == other.runtimeType &&
+          version == other.version &&
+          createdAt == other.createdAt &&
+          bookCount == other.bookCount &&
+          bookmarkCount == other.bookmarkCount &&
+          highlightCount == other.highlightCount &&
+          purifyRuleCount == other.purifyRuleCount &&
+          bookSourceCount == other.bookSourceCount;
+}
+
+class RestoreResult {
+  final int booksAdded;
+  /// Stored books replaced by a copy updated later.
+  final int booksUpdated;
+  final int progressUpdated;
+  final int bookmarksAdded;
+  final int highlightsAdded;
+  final int purifyRulesAdded;
+  final int bookSourcesAdded;
+  /// The current purify rules followed by the backup's new ones.
+  final List<PurifyRule> purifyRules;
+  /// The current book sources followed by the backup's new ones, as a
+  /// JSON array.
+  final String bookSourcesJson;
+
+  const RestoreResult({
+    required this.booksAdded,
+    required this.booksUpdated,
+    required this.progressUpdated,
+    required this.bookmarksAdded,
+    required this.highlightsAdded,
+    required this.purifyRulesAdded,
+    required this.bookSourcesAdded,
+    required this.purifyRules,
+    required this.bookSourcesJson,
+  });
+
+  @override
+  int get hashCode =>
+      booksAdded.hashCode ^
+      booksUpdated.hashCode ^
+      progressUpdated.hashCode ^
+      bookmarksAdded.hashCode ^
+      highlightsAdded.hashCode ^
+      purifyRulesAdded.hashCode ^
+      bookSourcesAdded.hashCode ^
+      purifyRules.hashCode ^
+      bookSourcesJson.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RestoreResult &&
+          runtimeType == other.runtimeType &&
+          booksAdded == other.booksAdded &&
+          booksUpdated == other.booksUpdated &&
+          progressUpdated == other.progressUpdated &&
+          bookmarksAdded == other.bookmarksAdded &&
+          highlightsAdded == other.highlightsAdded &&
+          purifyRulesAdded == other.purifyRulesAdded &&
+          bookSourcesAdded == other.bookSourcesAdded &&
+          purifyRules == other.purifyRules &&
+          bookSourcesJson == other.bookSourcesJson;
+}
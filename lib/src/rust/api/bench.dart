@@ -0,0 +1,96 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `chapter`, `throughput`, `tier`, `widths`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+/// Benchmark the device. Meant to be run once, e.g. on first launch, with
+/// the result stored.
+Future<BenchReport> run() => RustLib.instance.api.crateApiBenchRun();
+
+/// The settings for a tier, e.g. one the user picked instead of the
+/// measured one.
+Future<TierSettings> tierSettings({required PerformanceTier tier}) =>
+    RustLib.instance.api.crateApiBenchTierSettings(tier: tier);
+
+class BenchReport {
+  final double inflateMbPerSec;
+  final double segmentCharsPerSec;
+  final double layoutCharsPerSec;
+  /// Geometric mean of the throughputs relative to a mid-range phone.
+  final double score;
+  final PerformanceTier tier;
+  final TierSettings settings;
+  /// Wall time of the whole run.
+  final int elapsedMs;
+
+  const BenchReport({
+    required this.inflateMbPerSec,
+    required this.segmentCharsPerSec,
+    required this.layoutCharsPerSec,
+    required this.score,
+    required this.tier,
+    required this.settings,
+    required this.elapsedMs,
+  });
+
+  @override
+  int get hashCode =>
+      inflateMbPerSec.hashCode ^
+      segmentCharsPerSec.hashCode ^
+      layoutCharsPerSec.hashCode ^
+      score.hashCode ^
+      tier.hashCode ^
+      settings.hashCode ^
+      elapsedMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BenchReport &&
+          runtimeType == other.runtimeType &&
+          inflateMbPerSec == other.inflateMbPerSec &&
+          segmentCharsPerSec == other.segmentCharsPerSec &&
+          layoutCharsPerSec == other.layoutCharsPerSec &&
+          score == other.score &&
+          tier == other.tier &&
+          settings == other.settings &&
+          elapsedMs == other.elapsedMs;
+}
+
+enum PerformanceTier { low, medium, high }
+
+/// Settings suited to a tier.
+class TierSettings {
+  /// Chapters to prefetch ahead of the reading position.
+  final int prefetchDepth;
+  /// JPEG quality for covers and comic pages, 1 to 100.
+  final int imageQuality;
+  /// Whether page-turn and other animations are on.
+  final bool animations;
+
+  const TierSettings({
+    required this.prefetchDepth,
+    required this.imageQuality,
+    required this.animations,
+  });
+
+  @override
+  int get hashCode =>
+      prefetchDepth.hashCode ^ imageQuality.hashCode ^ animations.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TierSettings &&
+          runtimeType == other.runtimeType &&
+          prefetchDepth == other.prefetchDepth &&
+          imageQuality == other.imageQuality &&
+          animations == other.animations;
+}
@@ -0,0 +1,64 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `is_han`, `is_latin_letter`, `runs`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Run`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+
+/// Latin words only, with 40% of each emphasised.
+Future<BionicOptions> defaultBionicOptions() =>
+    RustLib.instance.api.crateApiBionicDefaultBionicOptions();
+
+/// The ranges of `text` to emphasise, in order and not overlapping.
+Future<List<EmphasisSpan>> bionicSpans({
+  required String text,
+  required BionicOptions options,
+}) => RustLib.instance.api.crateApiBionicBionicSpans(
+  text: text,
+  options: options,
+);
+
+class BionicOptions {
+  /// Share of each Latin word's letters emphasised, rounded, between
+  /// 0.3 and 0.5. At least one letter is emphasised.
+  final double latinRatio;
+  /// Also emphasise the first character of Chinese words.
+  final bool cjk;
+
+  const BionicOptions({required this.latinRatio, required this.cjk});
+
+  @override
+  int get hashCode => latinRatio.hashCode ^ cjk.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BionicOptions &&
+          runtimeType == other.runtimeType &&
+          latinRatio == other.latinRatio &&
+          cjk == other.cjk;
+}
+
+/// A range to draw in bold.
+class EmphasisSpan {
+  final int start;
+  final int end;
+
+  const EmphasisSpan({required this.start, required this.end});
+
+  @override
+  int get hashCode => start.hashCode ^ end.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EmphasisSpan &&
+          runtimeType == other.runtimeType &&
+          start == other.start &&
+          end == other.end;
+}
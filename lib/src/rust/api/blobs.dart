@@ -0,0 +1,97 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `gc`, `get`, `lock`, `open`, `path`, `put`, `release`, `with_store`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `BlobStore`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+/// Enable the blob store. Calling this again switches to the new directory.
+///
+/// # Arguments
+/// * `store_dir` - Directory owned by the store; created if missing
+Future<void> initBlobStore({required String storeDir}) =>
+    RustLib.instance.api.crateApiBlobsInitBlobStore(storeDir: storeDir);
+
+/// Store a blob, point `owner` at it and return its hash. A blob with the
+/// same bytes is reused.
+///
+/// # Arguments
+/// * `owner` - Name of the reference, e.g. `cover:<book id>`
+/// * `data` - Blob bytes
+Future<String> put({required String owner, required List<int> data}) =>
+    RustLib.instance.api.crateApiBlobsPut(owner: owner, data: data);
+
+/// Read a blob by hash, or `None` if it is not stored.
+Future<Uint8List?> get_({required String hash}) =>
+    RustLib.instance.api.crateApiBlobsGet(hash: hash);
+
+/// The file holding a blob, for image widgets that read files directly,
+/// or `None` if it is not stored.
+Future<String?> blobPath({required String hash}) =>
+    RustLib.instance.api.crateApiBlobsBlobPath(hash: hash);
+
+/// The hash `owner` refers to, if any.
+Future<String?> blobForOwner({required String owner}) =>
+    RustLib.instance.api.crateApiBlobsBlobForOwner(owner: owner);
+
+/// Drop the references of every owner starting with `prefix`, such as all
+/// of a deleted book's images. Returns the number dropped; the blobs stay
+/// until `gc`.
+Future<int> release({required String prefix}) =>
+    RustLib.instance.api.crateApiBlobsRelease(prefix: prefix);
+
+/// Delete blobs without references and files missing from the index.
+Future<BlobGcReport> gc() => RustLib.instance.api.crateApiBlobsGc();
+
+/// Report the number and total size of stored blobs.
+Future<BlobStoreStats> blobStoreStats() =>
+    RustLib.instance.api.crateApiBlobsBlobStoreStats();
+
+class BlobGcReport {
+  final int removed;
+  final BigInt freedBytes;
+
+  const BlobGcReport({required this.removed, required this.freedBytes});
+
+  @override
+  int get hashCode => removed.hashCode ^ freedBytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BlobGcReport &&
+          runtimeType == other.runtimeType &&
+          removed == other.removed &&
+          freedBytes == other.freedBytes;
+}
+
+class BlobStoreStats {
+  final int blobCount;
+  final BigInt totalBytes;
+  final int referenceCount;
+
+  const BlobStoreStats({
+    required this.blobCount,
+    required this.totalBytes,
+    required this.referenceCount,
+  });
+
+  @override
+  int get hashCode =>
+      blobCount.hashCode ^ totalBytes.hashCode ^ referenceCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BlobStoreStats &&
+          runtimeType == other.runtimeType &&
+          blobCount == other.blobCount &&
+          totalBytes == other.totalBytes &&
+          referenceCount == other.referenceCount;
+}
@@ -0,0 +1,201 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `chapter_images`, `empty`, `image`, `new`, `part_href`, `title_chapters`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Flatten chapter HTML into text, keeping the place of each image.
+///
+/// # Arguments
+/// * `html` - A chapter's `html`
+Future<ChapterText> chapterText({required String html}) =>
+    RustLib.instance.api.crateApiBookChapterText(html: html);
+
+class BookChapter {
+  final int index;
+  /// Source document path; chapter links use this to refer to each other.
+  final String href;
+  final String? title;
+  /// Body content with scripts, styles and presentational attributes
+  /// removed. Links and image sources are resource hrefs.
+  final String html;
+  /// The `img` elements of `html`, in document order.
+  final List<ChapterImage> images;
+
+  const BookChapter({
+    required this.index,
+    required this.href,
+    this.title,
+    required this.html,
+    required this.images,
+  });
+
+  @override
+  int get hashCode =>
+      index.hashCode ^
+      href.hashCode ^
+      title.hashCode ^
+      html.hashCode ^
+      images.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BookChapter &&
+          runtimeType == other.runtimeType &&
+          index == other.index &&
+          href == other.href &&
+          title == other.title &&
+          html == other.html &&
+          images == other.images;
+}
+
+class BookMetadata {
+  final String? title;
+  final List<String> authors;
+  final String? language;
+  final String? identifier;
+  final String? publisher;
+  final String? description;
+  /// Resource href of the cover image, if the book declares one.
+  final String? coverHref;
+
+  const BookMetadata({
+    this.title,
+    required this.authors,
+    this.language,
+    this.identifier,
+    this.publisher,
+    this.description,
+    this.coverHref,
+  });
+
+  @override
+  int get hashCode =>
+      title.hashCode ^
+      authors.hashCode ^
+      language.hashCode ^
+      identifier.hashCode ^
+      publisher.hashCode ^
+      description.hashCode ^
+      coverHref.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BookMetadata &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          authors == other.authors &&
+          language == other.language &&
+          identifier == other.identifier &&
+          publisher == other.publisher &&
+          description == other.description &&
+          coverHref == other.coverHref;
+}
+
+class ChapterImage {
+  /// Resource href, as taken by `load_book_image` and the importer's
+  /// extraction function.
+  final String href;
+  final String? alt;
+
+  const ChapterImage({required this.href, this.alt});
+
+  @override
+  int get hashCode => href.hashCode ^ alt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChapterImage &&
+          runtimeType == other.runtimeType &&
+          href == other.href &&
+          alt == other.alt;
+}
+
+/// A chapter flattened for the text pipeline.
+class ChapterText {
+  /// One paragraph per line. Each image is a line of its own holding
+  /// U+FFFC, the object replacement character.
+  final String text;
+  /// UTF-16 offset of each image's placeholder, in the order of
+  /// `BookChapter::images`.
+  final Uint32List imageOffsets;
+
+  const ChapterText({required this.text, required this.imageOffsets});
+
+  @override
+  int get hashCode => text.hashCode ^ imageOffsets.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChapterText &&
+          runtimeType == other.runtimeType &&
+          text == other.text &&
+          imageOffsets == other.imageOffsets;
+}
+
+/// A parsed book, ready for the reader to paginate.
+class ParsedBook {
+  final BookMetadata metadata;
+  /// Chapters in reading order.
+  final List<BookChapter> chapters;
+  /// Table of contents in document order; nesting is given by `depth`.
+  final List<TocEntry> toc;
+
+  const ParsedBook({
+    required this.metadata,
+    required this.chapters,
+    required this.toc,
+  });
+
+  @override
+  int get hashCode => metadata.hashCode ^ chapters.hashCode ^ toc.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ParsedBook &&
+          runtimeType == other.runtimeType &&
+          metadata == other.metadata &&
+          chapters == other.chapters &&
+          toc == other.toc;
+}
+
+class TocEntry {
+  final String title;
+  /// Chapter href, optionally followed by a `#fragment`.
+  final String href;
+  /// Chapter the entry points into, if it is part of the spine.
+  final int? chapterIndex;
+  /// Nesting level, starting at 0 for top-level entries.
+  final int depth;
+
+  const TocEntry({
+    required this.title,
+    required this.href,
+    this.chapterIndex,
+    required this.depth,
+  });
+
+  @override
+  int get hashCode =>
+      title.hashCode ^ href.hashCode ^ chapterIndex.hashCode ^ depth.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TocEntry &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          href == other.href &&
+          chapterIndex == other.chapterIndex &&
+          depth == other.depth;
+}
@@ -0,0 +1,84 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `decode`, `extract`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// Extract and decode an image of a parsed book.
+///
+/// # Arguments
+/// * `path` - Path to the EPUB, MOBI/AZW, FB2 or UMD file
+/// * `href` - Image href from `BookChapter::images` or `cover_href`
+/// * `max_width` - Largest width to decode to, in pixels; 0 for no limit
+/// * `max_height` - Largest height to decode to, in pixels; 0 for no limit
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+Future<BookImage> loadBookImage({
+  required String path,
+  required String href,
+  required int maxWidth,
+  required int maxHeight,
+  int? cancelToken,
+}) => RustLib.instance.api.crateApiBookImageLoadBookImage(
+  path: path,
+  href: href,
+  maxWidth: maxWidth,
+  maxHeight: maxHeight,
+  cancelToken: cancelToken,
+);
+
+/// Extract an image of a parsed book without decoding it.
+///
+/// # Arguments
+/// * `path` - Path to the EPUB, MOBI/AZW, FB2 or UMD file
+/// * `href` - Image href from `BookChapter::images` or `cover_href`
+Future<Uint8List> extractBookImage({
+  required String path,
+  required String href,
+}) => RustLib.instance.api.crateApiBookImageExtractBookImage(
+  path: path,
+  href: href,
+);
+
+/// A decoded chapter image.
+class BookImage {
+  /// RGBA pixels, row by row.
+  final Uint8List rgba;
+  final int width;
+  final int height;
+  /// Size of the image as stored in the book.
+  final int sourceWidth;
+  final int sourceHeight;
+
+  const BookImage({
+    required this.rgba,
+    required this.width,
+    required this.height,
+    required this.sourceWidth,
+    required this.sourceHeight,
+  });
+
+  @override
+  int get hashCode =>
+      rgba.hashCode ^
+      width.hashCode ^
+      height.hashCode ^
+      sourceWidth.hashCode ^
+      sourceHeight.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BookImage &&
+          runtimeType == other.runtimeType &&
+          rgba == other.rgba &&
+          width == other.width &&
+          height == other.height &&
+          sourceWidth == other.sourceWidth &&
+          sourceHeight == other.sourceHeight;
+}
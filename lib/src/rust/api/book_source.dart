@@ -0,0 +1,373 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'downloader.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `absolute`, `analyzer`, `book_info`, `content_page`, `decode`, `encode_non_ascii`, `fetch`, `flatten`, `html_to_text`, `link`, `links`, `page_choice`, `page_request`, `paginate`, `parse_headers`, `parse_source`, `search_results`, `source_base`, `string_field`, `text`, `toc_page`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `PageRequest`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Parse one source or an array of sources exported from Legado.
+Future<List<BookSource>> parseBookSources({required String json}) =>
+    RustLib.instance.api.crateApiBookSourceParseBookSources(json: json);
+
+/// Search a source and return the books on one result page.
+///
+/// # Arguments
+/// * `source` - Source to search
+/// * `key` - Search keywords
+/// * `page` - Result page, starting at 1
+Future<List<SourceBook>> searchBookSource({
+  required BookSource source,
+  required String key,
+  required int page,
+}) => RustLib.instance.api.crateApiBookSourceSearchBookSource(
+  source: source,
+  key: key,
+  page: page,
+);
+
+/// Fetch a book's detail page.
+///
+/// # Arguments
+/// * `source` - Source the book came from
+/// * `book_url` - `book_url` of a search result
+Future<SourceBook> fetchSourceBookInfo({
+  required BookSource source,
+  required String bookUrl,
+}) => RustLib.instance.api.crateApiBookSourceFetchSourceBookInfo(
+  source: source,
+  bookUrl: bookUrl,
+);
+
+/// Fetch a book's table of contents, following `nextTocUrl` pages.
+///
+/// # Arguments
+/// * `source` - Source the book came from
+/// * `toc_url` - `toc_url` from `fetch_source_book_info`
+Future<List<SourceChapter>> fetchSourceToc({
+  required BookSource source,
+  required String tocUrl,
+}) => RustLib.instance.api.crateApiBookSourceFetchSourceToc(
+  source: source,
+  tocUrl: tocUrl,
+);
+
+/// Fetch a chapter's text, following `nextContentUrl` pages, as plain text
+/// with one paragraph per line.
+///
+/// # Arguments
+/// * `source` - Source the book came from
+/// * `chapter_url` - Chapter URL from `fetch_source_toc`
+/// * `next_chapter_url` - URL of the following chapter, where pagination
+///   stops if the next page link points there
+Future<String> fetchSourceContent({
+  required BookSource source,
+  required String chapterUrl,
+  String? nextChapterUrl,
+}) => RustLib.instance.api.crateApiBookSourceFetchSourceContent(
+  source: source,
+  chapterUrl: chapterUrl,
+  nextChapterUrl: nextChapterUrl,
+);
+
+class BookInfoRules {
+  /// Selects the part of the page the other rules apply to.
+  final String? init;
+  final String? name;
+  final String? author;
+  final String? intro;
+  final String? kind;
+  final String? lastChapter;
+  final String? coverUrl;
+  final String? tocUrl;
+  final String? wordCount;
+
+  const BookInfoRules({
+    this.init,
+    this.name,
+    this.author,
+    this.intro,
+    this.kind,
+    this.lastChapter,
+    this.coverUrl,
+    this.tocUrl,
+    this.wordCount,
+  });
+
+  @override
+  int get hashCode =>
+      init.hashCode ^
+      name.hashCode ^
+      author.hashCode ^
+      intro.hashCode ^
+      kind.hashCode ^
+      lastChapter.hashCode ^
+      coverUrl.hashCode ^
+      tocUrl.hashCode ^
+      wordCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BookInfoRules &&
+          runtimeType == other.runtimeType &&
+          init == other.init &&
+          name == other.name &&
+          author == other.author &&
+          intro == other.intro &&
+          kind == other.kind &&
+          lastChapter == other.lastChapter &&
+          coverUrl == other.coverUrl &&
+          tocUrl == other.tocUrl &&
+          wordCount == other.wordCount;
+}
+
+class BookSource {
+  /// `bookSourceUrl`; relative URLs resolve against it.
+  final String url;
+  final String name;
+  final String? group;
+  final bool enabled;
+  /// Request headers from the source's `header` field.
+  final List<DownloadHeader> headers;
+  /// Search URL template using `{{key}}` and `{{page}}`.
+  final String? searchUrl;
+  final SearchRules search;
+  final BookInfoRules bookInfo;
+  final TocRules toc;
+  final ContentRules content;
+
+  const BookSource({
+    required this.url,
+    required this.name,
+    this.group,
+    required this.enabled,
+    required this.headers,
+    this.searchUrl,
+    required this.search,
+    required this.bookInfo,
+    required this.toc,
+    required this.content,
+  });
+
+  @override
+  int get hashCode =>
+      url.hashCode ^
+      name.hashCode ^
+      group.hashCode ^
+      enabled.hashCode ^
+      headers.hashCode ^
+      searchUrl.hashCode ^
+      search.hashCode ^
+      bookInfo.hashCode ^
+      toc.hashCode ^
+      content.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BookSource &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          name == other.name &&
+          group == other.group &&
+          enabled == other.enabled &&
+          headers == other.headers &&
+          searchUrl == other.searchUrl &&
+          search == other.search &&
+          bookInfo == other.bookInfo &&
+          toc == other.toc &&
+          content == other.content;
+}
+
+class ContentRules {
+  final String? content;
+  final String? nextContentUrl;
+  /// `##regex##replacement` applied to the whole chapter text.
+  final String? replaceRegex;
+
+  const ContentRules({this.content, this.nextContentUrl, this.replaceRegex});
+
+  @override
+  int get hashCode =>
+      content.hashCode ^ nextContentUrl.hashCode ^ replaceRegex.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ContentRules &&
+          runtimeType == other.runtimeType &&
+          content == other.content &&
+          nextContentUrl == other.nextContentUrl &&
+          replaceRegex == other.replaceRegex;
+}
+
+class SearchRules {
+  final String? bookList;
+  final String? name;
+  final String? author;
+  final String? intro;
+  final String? kind;
+  final String? lastChapter;
+  final String? coverUrl;
+  final String? bookUrl;
+  final String? wordCount;
+
+  const SearchRules({
+    this.bookList,
+    this.name,
+    this.author,
+    this.intro,
+    this.kind,
+    this.lastChapter,
+    this.coverUrl,
+    this.bookUrl,
+    this.wordCount,
+  });
+
+  @override
+  int get hashCode =>
+      bookList.hashCode ^
+      name.hashCode ^
+      author.hashCode ^
+      intro.hashCode ^
+      kind.hashCode ^
+      lastChapter.hashCode ^
+      coverUrl.hashCode ^
+      bookUrl.hashCode ^
+      wordCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SearchRules &&
+          runtimeType == other.runtimeType &&
+          bookList == other.bookList &&
+          name == other.name &&
+          author == other.author &&
+          intro == other.intro &&
+          kind == other.kind &&
+          lastChapter == other.lastChapter &&
+          coverUrl == other.coverUrl &&
+          bookUrl == other.bookUrl &&
+          wordCount == other.wordCount;
+}
+
+/// A book found by a search or read from its detail page.
+class SourceBook {
+  final String name;
+  final String? author;
+  final String? intro;
+  final String? kind;
+  final String? lastChapter;
+  final String? coverUrl;
+  final String? wordCount;
+  final String bookUrl;
+  /// Table of contents URL; the book URL until the details are fetched.
+  final String tocUrl;
+
+  const SourceBook({
+    required this.name,
+    this.author,
+    this.intro,
+    this.kind,
+    this.lastChapter,
+    this.coverUrl,
+    this.wordCount,
+    required this.bookUrl,
+    required this.tocUrl,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      author.hashCode ^
+      intro.hashCode ^
+      kind.hashCode ^
+      lastChapter.hashCode ^
+      coverUrl.hashCode ^
+      wordCount.hashCode ^
+      bookUrl.hashCode ^
+      tocUrl.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SourceBook &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          author == other.author &&
+          intro == other.intro &&
+          kind == other.kind &&
+          lastChapter == other.lastChapter &&
+          coverUrl == other.coverUrl &&
+          wordCount == other.wordCount &&
+          bookUrl == other.bookUrl &&
+          tocUrl == other.tocUrl;
+}
+
+class SourceChapter {
+  final String title;
+  final String url;
+  /// A volume heading rather than a readable chapter.
+  final bool isVolume;
+
+  const SourceChapter({
+    required this.title,
+    required this.url,
+    required this.isVolume,
+  });
+
+  @override
+  int get hashCode => title.hashCode ^ url.hashCode ^ isVolume.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SourceChapter &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          url == other.url &&
+          isVolume == other.isVolume;
+}
+
+class TocRules {
+  final String? chapterList;
+  final String? chapterName;
+  final String? chapterUrl;
+  final String? isVolume;
+  final String? nextTocUrl;
+
+  const TocRules({
+    this.chapterList,
+    this.chapterName,
+    this.chapterUrl,
+    this.isVolume,
+    this.nextTocUrl,
+  });
+
+  @override
+  int get hashCode =>
+      chapterList.hashCode ^
+      chapterName.hashCode ^
+      chapterUrl.hashCode ^
+      isVolume.hashCode ^
+      nextTocUrl.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TocRules &&
+          runtimeType == other.runtimeType &&
+          chapterList == other.chapterList &&
+          chapterName == other.chapterName &&
+          chapterUrl == other.chapterUrl &&
+          isVolume == other.isVolume &&
+          nextTocUrl == other.nextTocUrl;
+}
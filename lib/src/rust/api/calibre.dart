@@ -0,0 +1,196 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'archive.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `file_name`, `identifier`, `interpret`, `new`, `parse_opf`, `read_folder`, `scan`, `write_opf`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Element`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Find every book folder of a Calibre library, by path.
+///
+/// # Arguments
+/// * `library_dir` - The library root, or any folder below it
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+Future<List<CalibreBook>> scanCalibreLibrary({
+  required String libraryDir,
+  int? cancelToken,
+}) => RustLib.instance.api.crateApiCalibreScanCalibreLibrary(
+  libraryDir: libraryDir,
+  cancelToken: cancelToken,
+);
+
+/// Read a `metadata.opf`, or any OPF package document.
+Future<CalibreMetadata> readCalibreOpf({required String path}) =>
+    RustLib.instance.api.crateApiCalibreReadCalibreOpf(path: path);
+
+/// Write a book as a Calibre folder, `Author/Title/`, holding a copy of the
+/// book file, `metadata.opf` and `cover.jpg`. Returns the folder.
+///
+/// # Arguments
+/// * `metadata` - Metadata for the OPF; a missing `uuid` identifier is
+///   derived from the title and authors
+/// * `book_path` - The book file to copy
+/// * `cover` - Cover image bytes (JPEG, PNG, GIF or WebP), saved as JPEG
+/// * `library_dir` - Calibre library, or any folder to collect books in
+Future<String> exportCalibreBook({
+  required CalibreMetadata metadata,
+  required String bookPath,
+  Uint8List? cover,
+  required String libraryDir,
+}) => RustLib.instance.api.crateApiCalibreExportCalibreBook(
+  metadata: metadata,
+  bookPath: bookPath,
+  cover: cover,
+  libraryDir: libraryDir,
+);
+
+class CalibreBook {
+  /// The book's folder.
+  final String dir;
+  final CalibreMetadata metadata;
+  /// `cover.jpg`, if the folder has one.
+  final String? coverPath;
+  /// Files the app can import, by name; may be empty.
+  final List<CalibreFormat> formats;
+
+  const CalibreBook({
+    required this.dir,
+    required this.metadata,
+    this.coverPath,
+    required this.formats,
+  });
+
+  @override
+  int get hashCode =>
+      dir.hashCode ^ metadata.hashCode ^ coverPath.hashCode ^ formats.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CalibreBook &&
+          runtimeType == other.runtimeType &&
+          dir == other.dir &&
+          metadata == other.metadata &&
+          coverPath == other.coverPath &&
+          formats == other.formats;
+}
+
+/// A book file in a Calibre folder.
+class CalibreFormat {
+  final String path;
+  final ImportKind kind;
+
+  const CalibreFormat({required this.path, required this.kind});
+
+  @override
+  int get hashCode => path.hashCode ^ kind.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CalibreFormat &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          kind == other.kind;
+}
+
+class CalibreIdentifier {
+  /// Lowercase scheme, e.g. `isbn`, `uuid` or `calibre`.
+  final String scheme;
+  final String value;
+
+  const CalibreIdentifier({required this.scheme, required this.value});
+
+  @override
+  int get hashCode => scheme.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CalibreIdentifier &&
+          runtimeType == other.runtimeType &&
+          scheme == other.scheme &&
+          value == other.value;
+}
+
+class CalibreMetadata {
+  final String? title;
+  /// Sort key of the title, e.g. `Three-Body Problem, The`.
+  final String? titleSort;
+  final List<String> authors;
+  /// Sort key of the authors, e.g. `Liu, Cixin`.
+  final String? authorSort;
+  final String? series;
+  /// Position in the series; Calibre allows fractions such as 1.5.
+  final double? seriesIndex;
+  final List<String> tags;
+  final String? publisher;
+  /// Calibre's comments, usually HTML.
+  final String? description;
+  final String? language;
+  /// Publication date as written, usually ISO 8601.
+  final String? published;
+  /// 0 to 10, two per star.
+  final int? rating;
+  final List<CalibreIdentifier> identifiers;
+
+  const CalibreMetadata({
+    this.title,
+    this.titleSort,
+    required this.authors,
+    this.authorSort,
+    this.series,
+    this.seriesIndex,
+    required this.tags,
+    this.publisher,
+    this.description,
+    this.language,
+    this.published,
+    this.rating,
+    required this.identifiers,
+  });
+
+  static Future<CalibreMetadata> default_() =>
+      RustLib.instance.api.crateApiCalibreCalibreMetadataDefault();
+
+  @override
+  int get hashCode =>
+      title.hashCode ^
+      titleSort.hashCode ^
+      authors.hashCode ^
+      authorSort.hashCode ^
+      series.hashCode ^
+      seriesIndex.hashCode ^
+      tags.hashCode ^
+      publisher.hashCode ^
+      description.hashCode ^
+      language.hashCode ^
+      published.hashCode ^
+      rating.hashCode ^
+      identifiers.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CalibreMetadata &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          titleSort == other.titleSort &&
+          authors == other.authors &&
+          authorSort == other.authorSort &&
+          series == other.series &&
+          seriesIndex == other.seriesIndex &&
+          tags == other.tags &&
+          publisher == other.publisher &&
+          description == other.description &&
+          language == other.language &&
+          published == other.published &&
+          rating == other.rating &&
+          identifiers == other.identifiers;
+}
@@ -0,0 +1,226 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'fingerprint.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `associated_data`, `book_dir`, `check_updates`, `cipher`, `current`, `entries`, `entry_path`, `fingerprint_path`, `fingerprint`, `lock`, `migrate`, `new`, `open`, `read_entry`, `read`, `seal`, `summary_path`, `summary`, `write_entry`, `write`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ChapterCache`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Enable the chapter cache. Calling this again switches to the new
+/// directory or key.
+///
+/// # Arguments
+/// * `cache_dir` - Directory owned by the cache; created if missing
+/// * `key` - 32-byte AES-256 key to encrypt entries with, or `None` to
+///   store plaintext
+Future<void> initChapterCache({required String cacheDir, Uint8List? key}) =>
+    RustLib.instance.api.crateApiChapterCacheInitChapterCache(
+      cacheDir: cacheDir,
+      key: key,
+    );
+
+/// Store the text of a chapter, replacing any cached copy. The text is
+/// sanitized first, as by `sanitize_text`, and its summary is cached with
+/// it using the options from `set_summary_options`.
+///
+/// # Arguments
+/// * `book_id` - Library ID of the book
+/// * `chapter_index` - Position of the chapter in the table of contents
+/// * `text` - Chapter content
+Future<void> writeCachedChapter({
+  required String bookId,
+  required int chapterIndex,
+  required String text,
+}) => RustLib.instance.api.crateApiChapterCacheWriteCachedChapter(
+  bookId: bookId,
+  chapterIndex: chapterIndex,
+  text: text,
+);
+
+/// Read a cached chapter, or `None` if it is not cached.
+///
+/// # Arguments
+/// * `book_id` - Library ID of the book
+/// * `chapter_index` - Position of the chapter in the table of contents
+Future<String?> readCachedChapter({
+  required String bookId,
+  required int chapterIndex,
+}) => RustLib.instance.api.crateApiChapterCacheReadCachedChapter(
+  bookId: bookId,
+  chapterIndex: chapterIndex,
+);
+
+/// The cached summary of a chapter, or `None` if the chapter is not
+/// cached. Chapters cached before summaries were are summarized now.
+///
+/// # Arguments
+/// * `book_id` - Library ID of the book
+/// * `chapter_index` - Position of the chapter in the table of contents
+Future<String?> readCachedSummary({
+  required String bookId,
+  required int chapterIndex,
+}) => RustLib.instance.api.crateApiChapterCacheReadCachedSummary(
+  bookId: bookId,
+  chapterIndex: chapterIndex,
+);
+
+/// The fingerprint of a cached chapter, or `None` if it is not cached.
+/// Chapters cached before fingerprints were are fingerprinted now.
+///
+/// # Arguments
+/// * `book_id` - Library ID of the book
+/// * `chapter_index` - Position of the chapter in the table of contents
+Future<ChapterFingerprint?> readCachedFingerprint({
+  required String bookId,
+  required int chapterIndex,
+}) => RustLib.instance.api.crateApiChapterCacheReadCachedFingerprint(
+  bookId: bookId,
+  chapterIndex: chapterIndex,
+);
+
+/// Compare a book's cache with a freshly fetched table of contents and
+/// the text of some of its chapters, such as the last few cached ones.
+/// The cache is left as it is.
+///
+/// # Arguments
+/// * `book_id` - Library ID of the book
+/// * `chapter_count` - Number of chapters in the fresh table of contents
+/// * `chapters` - Freshly fetched chapters to compare with the cache
+Future<ChapterUpdateReport> checkChapterUpdates({
+  required String bookId,
+  required int chapterCount,
+  required List<FetchedChapter> chapters,
+}) => RustLib.instance.api.crateApiChapterCacheCheckChapterUpdates(
+  bookId: bookId,
+  chapterCount: chapterCount,
+  chapters: chapters,
+);
+
+/// Delete every cached chapter of a book. Returns the number deleted.
+Future<int> removeCachedBook({required String bookId}) =>
+    RustLib.instance.api.crateApiChapterCacheRemoveCachedBook(bookId: bookId);
+
+/// Rewrite every entry with the current key: encrypt plaintext entries,
+/// re-encrypt ones sealed with `previous_key`, or decrypt them all when the
+/// cache was initialized without a key.
+///
+/// # Arguments
+/// * `previous_key` - Key the cache was encrypted with before, if any
+Future<ChapterCacheMigration> migrateChapterCache({Uint8List? previousKey}) =>
+    RustLib.instance.api.crateApiChapterCacheMigrateChapterCache(
+      previousKey: previousKey,
+    );
+
+/// Outcome of `migrate_chapter_cache`.
+class ChapterCacheMigration {
+  /// Entries rewritten in the current format.
+  final int converted;
+  /// Entries already in the current format.
+  final int unchanged;
+  /// Entries that could not be read with either key and were deleted.
+  final int removed;
+
+  const ChapterCacheMigration({
+    required this.converted,
+    required this.unchanged,
+    required this.removed,
+  });
+
+  @override
+  int get hashCode =>
+      converted.hashCode ^ unchanged.hashCode ^ removed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChapterCacheMigration &&
+          runtimeType == other.runtimeType &&
+          converted == other.converted &&
+          unchanged == other.unchanged &&
+          removed == other.removed;
+}
+
+enum ChapterChange {
+  unchanged,
+  /// The text differs a little, e.g. a silently fixed typo or paragraph.
+  edited,
+  /// The text has little in common with the cached copy, e.g. because
+  /// chapters were inserted before it.
+  replaced,
+  /// The chapter is not cached.
+  uncached,
+}
+
+class ChapterUpdate {
+  final int chapterIndex;
+  final ChapterChange change;
+  /// Estimated similarity to the cached copy, from 0 to 1; 0 if uncached.
+  final double similarity;
+
+  const ChapterUpdate({
+    required this.chapterIndex,
+    required this.change,
+    required this.similarity,
+  });
+
+  @override
+  int get hashCode =>
+      chapterIndex.hashCode ^ change.hashCode ^ similarity.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChapterUpdate &&
+          runtimeType == other.runtimeType &&
+          chapterIndex == other.chapterIndex &&
+          change == other.change &&
+          similarity == other.similarity;
+}
+
+class ChapterUpdateReport {
+  /// Chapters of the fresh table of contents after the last cached one.
+  final Uint32List newChapters;
+  /// One per fetched chapter, in the order given.
+  final List<ChapterUpdate> chapters;
+
+  const ChapterUpdateReport({
+    required this.newChapters,
+    required this.chapters,
+  });
+
+  @override
+  int get hashCode => newChapters.hashCode ^ chapters.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChapterUpdateReport &&
+          runtimeType == other.runtimeType &&
+          newChapters == other.newChapters &&
+          chapters == other.chapters;
+}
+
+/// A chapter fetched for an update check.
+class FetchedChapter {
+  final int chapterIndex;
+  final String text;
+
+  const FetchedChapter({required this.chapterIndex, required this.text});
+
+  @override
+  int get hashCode => chapterIndex.hashCode ^ text.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FetchedChapter &&
+          runtimeType == other.runtimeType &&
+          chapterIndex == other.chapterIndex &&
+          text == other.text;
+}
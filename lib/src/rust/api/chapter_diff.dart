@@ -0,0 +1,83 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `char_len`, `common_paragraphs`, `lcs`, `pair_changes`, `paragraphs`, `similarity`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+/// Compare two versions of a chapter's text.
+Future<ChapterDiff> diffChapters({required String old, required String new_}) =>
+    RustLib.instance.api.crateApiChapterDiffDiffChapters(old: old, new_: new_);
+
+class ChapterDiff {
+  /// Character-weighted similarity of the two versions, from 0 to 1.
+  final double similarity;
+  final int unchangedParagraphs;
+  /// Changes in text order.
+  final List<DiffHunk> hunks;
+
+  const ChapterDiff({
+    required this.similarity,
+    required this.unchangedParagraphs,
+    required this.hunks,
+  });
+
+  @override
+  int get hashCode =>
+      similarity.hashCode ^ unchangedParagraphs.hashCode ^ hunks.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChapterDiff &&
+          runtimeType == other.runtimeType &&
+          similarity == other.similarity &&
+          unchangedParagraphs == other.unchangedParagraphs &&
+          hunks == other.hunks;
+}
+
+/// One changed paragraph. Indexes count non-blank paragraphs; for an
+/// insert `old_index` is the old paragraph it was inserted before, and for
+/// a delete `new_index` is the new paragraph that now follows the gap.
+class DiffHunk {
+  final DiffHunkKind kind;
+  final int oldIndex;
+  final int newIndex;
+  /// Empty for inserts.
+  final String oldText;
+  /// Empty for deletes.
+  final String newText;
+
+  const DiffHunk({
+    required this.kind,
+    required this.oldIndex,
+    required this.newIndex,
+    required this.oldText,
+    required this.newText,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      oldIndex.hashCode ^
+      newIndex.hashCode ^
+      oldText.hashCode ^
+      newText.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DiffHunk &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          oldIndex == other.oldIndex &&
+          newIndex == other.newIndex &&
+          oldText == other.oldText &&
+          newText == other.newText;
+}
+
+enum DiffHunkKind { insert, delete, modify }
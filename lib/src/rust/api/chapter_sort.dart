@@ -0,0 +1,39 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'db.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `chinese_number`, `digit`, `is_numeral`, `natural_cmp`, `tens`, `tokens`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Token`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `eq`, `fmt`
+
+/// A key that sorts like the title in natural order: lowercase, with every
+/// number written as 20 zero-padded digits.
+Future<String> chapterSortKey({required String title}) =>
+    RustLib.instance.api.crateApiChapterSortChapterSortKey(title: title);
+
+/// Compare two titles in natural order: negative if `a` sorts first,
+/// positive if `b` does, 0 if they sort together.
+Future<int> compareChapterTitles({required String a, required String b}) =>
+    RustLib.instance.api.crateApiChapterSortCompareChapterTitles(a: a, b: b);
+
+/// The chapter number of a title: the number after `第` if there is one,
+/// else the first number in it.
+Future<BigInt?> chapterNumber({required String title}) =>
+    RustLib.instance.api.crateApiChapterSortChapterNumber(title: title);
+
+/// Sort chapters by title in natural order and renumber them from 0, e.g.
+/// before `set_library_chapters` for chapters imported as loose files.
+/// Chapters that sort together keep their order.
+///
+/// # Arguments
+/// * `chapters` - Chapters in any order
+Future<List<LibraryChapter>> sortLibraryChapters({
+  required List<LibraryChapter> chapters,
+}) => RustLib.instance.api.crateApiChapterSortSortLibraryChapters(
+  chapters: chapters,
+);
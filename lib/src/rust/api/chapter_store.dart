@@ -0,0 +1,158 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `append`, `book`, `checksum`, `compact`, `compress`, `current`, `decompress`, `delete`, `dictionary_path`, `dictionary`, `get`, `header_len`, `lock`, `needs_compaction`, `open`, `put`, `quarantine`, `record_len`, `scan`, `segment_path`, `stats`, `stored_bytes`, `stored_chapter_indices`, `verify`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Book`, `ChapterStore`, `Location`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`
+
+/// Enable the chapter store. Calling this again switches to the new
+/// directory.
+///
+/// # Arguments
+/// * `store_dir` - Directory owned by the store; created if missing
+Future<void> initChapterStore({required String storeDir}) => RustLib
+    .instance
+    .api
+    .crateApiChapterStoreInitChapterStore(storeDir: storeDir);
+
+/// Store the text of a chapter, replacing any stored copy. The text is
+/// sanitized first, as by `sanitize_text`.
+///
+/// # Arguments
+/// * `book_id` - Library ID of the book
+/// * `chapter_index` - Position of the chapter in the table of contents
+/// * `text` - Chapter content
+Future<void> putChapter({
+  required String bookId,
+  required int chapterIndex,
+  required String text,
+}) => RustLib.instance.api.crateApiChapterStorePutChapter(
+  bookId: bookId,
+  chapterIndex: chapterIndex,
+  text: text,
+);
+
+/// Read a stored chapter, or `None` if it is not stored.
+///
+/// # Arguments
+/// * `book_id` - Library ID of the book
+/// * `chapter_index` - Position of the chapter in the table of contents
+Future<String?> getChapter({
+  required String bookId,
+  required int chapterIndex,
+}) => RustLib.instance.api.crateApiChapterStoreGetChapter(
+  bookId: bookId,
+  chapterIndex: chapterIndex,
+);
+
+/// Delete a stored chapter. Returns false if it was not stored.
+Future<bool> deleteChapter({
+  required String bookId,
+  required int chapterIndex,
+}) => RustLib.instance.api.crateApiChapterStoreDeleteChapter(
+  bookId: bookId,
+  chapterIndex: chapterIndex,
+);
+
+/// Delete every stored chapter of a book.
+Future<void> deleteStoredBook({required String bookId}) =>
+    RustLib.instance.api.crateApiChapterStoreDeleteStoredBook(bookId: bookId);
+
+/// Compact a book's segments now, training a dictionary if it has enough
+/// chapters, and return the resulting sizes.
+Future<ChapterStoreStats> compactChapterStore({required String bookId}) =>
+    RustLib.instance.api.crateApiChapterStoreCompactChapterStore(
+      bookId: bookId,
+    );
+
+/// Check the stored chapters of books, such as on startup after a crash.
+/// Corrupt records are quarantined so they are never read again; only
+/// books that had any are returned.
+///
+/// # Arguments
+/// * `book_ids` - Library IDs of the books to check
+Future<List<StoredBookScan>> verifyChapterStore({
+  required List<String> bookIds,
+}) => RustLib.instance.api.crateApiChapterStoreVerifyChapterStore(
+  bookIds: bookIds,
+);
+
+/// Report how a book's chapters are stored.
+Future<ChapterStoreStats> chapterStoreStats({required String bookId}) =>
+    RustLib.instance.api.crateApiChapterStoreChapterStoreStats(bookId: bookId);
+
+class ChapterStoreStats {
+  final int chapterCount;
+  /// Size of the stored text before compression.
+  final BigInt textBytes;
+  /// Size of the segment files.
+  final BigInt storedBytes;
+  /// Part of `stored_bytes` taken by superseded records.
+  final BigInt garbageBytes;
+  final int segmentCount;
+  final bool hasDictionary;
+
+  const ChapterStoreStats({
+    required this.chapterCount,
+    required this.textBytes,
+    required this.storedBytes,
+    required this.garbageBytes,
+    required this.segmentCount,
+    required this.hasDictionary,
+  });
+
+  @override
+  int get hashCode =>
+      chapterCount.hashCode ^
+      textBytes.hashCode ^
+      storedBytes.hashCode ^
+      garbageBytes.hashCode ^
+      segmentCount.hashCode ^
+      hasDictionary.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChapterStoreStats &&
+          runtimeType == other.runtimeType &&
+          chapterCount == other.chapterCount &&
+          textBytes == other.textBytes &&
+          storedBytes == other.storedBytes &&
+          garbageBytes == other.garbageBytes &&
+          segmentCount == other.segmentCount &&
+          hasDictionary == other.hasDictionary;
+}
+
+/// A book `verify_chapter_store` found corrupt records in.
+class StoredBookScan {
+  final String bookId;
+  /// Records that failed their checksum or could not be decompressed.
+  final int corruptRecords;
+  /// Chapters left with no readable copy, to be downloaded again.
+  final Uint32List lostChapters;
+
+  const StoredBookScan({
+    required this.bookId,
+    required this.corruptRecords,
+    required this.lostChapters,
+  });
+
+  @override
+  int get hashCode =>
+      bookId.hashCode ^ corruptRecords.hashCode ^ lostChapters.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StoredBookScan &&
+          runtimeType == other.runtimeType &&
+          bookId == other.bookId &&
+          corruptRecords == other.corruptRecords &&
+          lostChapters == other.lostChapters;
+}
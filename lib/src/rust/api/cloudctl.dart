@@ -0,0 +1,99 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `load`, `lock`, `now`, `parse_config`, `parse_key`, `public_key`, `save`, `verify`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Watermark`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+/// Remember accepted configs under `state_dir`. Without this, staleness
+/// is only checked against configs accepted since launch.
+///
+/// # Arguments
+/// * `state_dir` - Directory for the small state file; created if missing
+Future<void> initCloudctl({required String stateDir}) =>
+    RustLib.instance.api.crateApiCloudctlInitCloudctl(stateDir: stateDir);
+
+/// Verify a signed config envelope with the built-in key and return the
+/// config. Fails if the signature is wrong, the config is older than one
+/// already accepted, or it has expired.
+///
+/// # Arguments
+/// * `envelope` - Envelope JSON as fetched
+Future<CloudConfig> verifyCloudConfig({required String envelope}) =>
+    RustLib.instance.api.crateApiCloudctlVerifyCloudConfig(envelope: envelope);
+
+class CloudConfig {
+  /// When the config was issued, in seconds since the Unix epoch.
+  final PlatformInt64 issuedAt;
+  /// When the config stops applying, if it expires.
+  final PlatformInt64? expiresAt;
+  /// Review-build mode: hide third-party sources and other features app
+  /// store review must not see.
+  final bool complianceMode;
+  /// Feature keys the app should turn off.
+  final List<String> disabledFeatures;
+  /// Oldest app version still supported, e.g. `1.4.0`.
+  final String? minAppVersion;
+  /// Notice to show once, if any.
+  final String? announcement;
+  /// Other string settings by key, in payload order.
+  final List<CloudConfigValue> values;
+
+  const CloudConfig({
+    required this.issuedAt,
+    this.expiresAt,
+    required this.complianceMode,
+    required this.disabledFeatures,
+    this.minAppVersion,
+    this.announcement,
+    required this.values,
+  });
+
+  @override
+  int get hashCode =>
+      issuedAt.hashCode ^
+      expiresAt.hashCode ^
+      complianceMode.hashCode ^
+      disabledFeatures.hashCode ^
+      minAppVersion.hashCode ^
+      announcement.hashCode ^
+      values.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CloudConfig &&
+          runtimeType == other.runtimeType &&
+          issuedAt == other.issuedAt &&
+          expiresAt == other.expiresAt &&
+          complianceMode == other.complianceMode &&
+          disabledFeatures == other.disabledFeatures &&
+          minAppVersion == other.minAppVersion &&
+          announcement == other.announcement &&
+          values == other.values;
+}
+
+class CloudConfigValue {
+  final String key;
+  final String value;
+
+  const CloudConfigValue({required this.key, required this.value});
+
+  @override
+  int get hashCode => key.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CloudConfigValue &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          value == other.value;
+}
@@ -0,0 +1,68 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `detect_format`, `downscale`, `is_page`, `list_rar`, `list_zip`, `open_zip`, `rar_name`, `read_rar_entry`, `read_zip_entry`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Format`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// List the page images of a CBZ or CBR archive in reading order.
+///
+/// # Arguments
+/// * `path` - Path to the comic archive
+Future<List<ComicPage>> listComicPages({required String path}) =>
+    RustLib.instance.api.crateApiComicListComicPages(path: path);
+
+/// Read one page image, optionally scaled down to fit a bounding box.
+///
+/// Pages that already fit are returned unchanged. Downscaled JPEGs are
+/// re-encoded as JPEG, other formats as PNG.
+///
+/// # Arguments
+/// * `path` - Path to the comic archive
+/// * `index` - Page index from `list_comic_pages`
+/// * `max_width` - Maximum width in pixels
+/// * `max_height` - Maximum height in pixels
+Future<Uint8List> readComicPage({
+  required String path,
+  required int index,
+  int? maxWidth,
+  int? maxHeight,
+}) => RustLib.instance.api.crateApiComicReadComicPage(
+  path: path,
+  index: index,
+  maxWidth: maxWidth,
+  maxHeight: maxHeight,
+);
+
+class ComicPage {
+  /// Position in reading order; pass this to `read_comic_page`.
+  final int index;
+  /// Entry path inside the archive.
+  final String name;
+  /// Uncompressed size in bytes.
+  final int size;
+
+  const ComicPage({
+    required this.index,
+    required this.name,
+    required this.size,
+  });
+
+  @override
+  int get hashCode => index.hashCode ^ name.hashCode ^ size.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ComicPage &&
+          runtimeType == other.runtimeType &&
+          index == other.index &&
+          name == other.name &&
+          size == other.size;
+}
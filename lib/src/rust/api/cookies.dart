@@ -0,0 +1,96 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `cookie_json`, `default_path`, `enabled`, `exchange`, `handle`, `header_for`, `initialized`, `is_expired`, `lock`, `matches_host`, `now`, `open`, `parse_cookies`, `parse_set_cookie`, `path_matches`, `save`, `store`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CookieJar`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+/// Load the cookie jar from `jar_dir` and start using it for requests. The
+/// token vault must be unlocked.
+///
+/// # Arguments
+/// * `jar_dir` - Directory owned by the jar; created if missing
+Future<void> initCookieJar({required String jarDir}) =>
+    RustLib.instance.api.crateApiCookiesInitCookieJar(jarDir: jarDir);
+
+/// Cookies in the jar, for a cookie management screen.
+///
+/// # Arguments
+/// * `host` - Only cookies sent to this host, or `None` for all of them
+Future<List<StoredCookie>> listCookies({String? host}) =>
+    RustLib.instance.api.crateApiCookiesListCookies(host: host);
+
+/// Delete cookies, such as to log out of a source, and return how many
+/// were deleted.
+///
+/// # Arguments
+/// * `host` - Only cookies sent to this host, or `None` for all of them
+Future<int> clearCookies({String? host}) =>
+    RustLib.instance.api.crateApiCookiesClearCookies(host: host);
+
+/// Store cookies as if a response from `url` had set them, such as the
+/// cookies of a WebView login.
+///
+/// # Arguments
+/// * `url` - Page the cookies belong to
+/// * `cookies` - `Set-Cookie` header values; the `name=value` pairs of a
+///   `Cookie` header work too, as session cookies
+Future<int> addCookies({required String url, required List<String> cookies}) =>
+    RustLib.instance.api.crateApiCookiesAddCookies(url: url, cookies: cookies);
+
+class StoredCookie {
+  final String name;
+  final String value;
+  /// Host or domain, lowercase and without a leading dot.
+  final String domain;
+  /// Only sent to `domain` itself, not to its subdomains.
+  final bool hostOnly;
+  final String path;
+  /// Seconds since the Unix epoch; `None` for a session cookie.
+  final PlatformInt64? expires;
+  /// Only sent over HTTPS.
+  final bool secure;
+  final bool httpOnly;
+
+  const StoredCookie({
+    required this.name,
+    required this.value,
+    required this.domain,
+    required this.hostOnly,
+    required this.path,
+    this.expires,
+    required this.secure,
+    required this.httpOnly,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      value.hashCode ^
+      domain.hashCode ^
+      hostOnly.hashCode ^
+      path.hashCode ^
+      expires.hashCode ^
+      secure.hashCode ^
+      httpOnly.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StoredCookie &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          value == other.value &&
+          domain == other.domain &&
+          hostOnly == other.hostOnly &&
+          path == other.path &&
+          expires == other.expires &&
+          secure == other.secure &&
+          httpOnly == other.httpOnly;
+}
@@ -0,0 +1,92 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `ascent`, `channels`, `descent`, `draw_line`, `map`, `render`, `scale`, `wrap_title`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Outline`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `close`, `curve_to`, `fmt`, `line_to`, `move_to`, `quad_to`
+
+/// A 600×900 style whose colours are picked from a fixed set by the title,
+/// so the same book always gets the same cover and neighbours differ.
+///
+/// # Arguments
+/// * `title` - Book title
+Future<CoverStyle> defaultCoverStyle({required String title}) =>
+    RustLib.instance.api.crateApiCoverDefaultCoverStyle(title: title);
+
+/// Draw a cover and return it as PNG.
+///
+/// # Arguments
+/// * `title` - Book title
+/// * `author` - Author name; empty to leave it out
+/// * `font_path` - TTF or OTF file to draw with; the first face of a collection
+/// * `style` - Size and colours
+Future<Uint8List> generateCover({
+  required String title,
+  required String author,
+  required String fontPath,
+  required CoverStyle style,
+}) => RustLib.instance.api.crateApiCoverGenerateCover(
+  title: title,
+  author: author,
+  fontPath: fontPath,
+  style: style,
+);
+
+/// Size and colours of a generated cover. Colours are `0xAARRGGBB`.
+class CoverStyle {
+  final int width;
+  final int height;
+  /// Top colour of the background.
+  final int background;
+  /// Bottom colour of the background; equal to `background` for a solid fill.
+  final int gradientEnd;
+  final int textColor;
+  /// Title font size in pixels.
+  final double titleSize;
+  /// Author font size in pixels.
+  final double authorSize;
+  /// Space kept clear around the text, in pixels.
+  final double margin;
+
+  const CoverStyle({
+    required this.width,
+    required this.height,
+    required this.background,
+    required this.gradientEnd,
+    required this.textColor,
+    required this.titleSize,
+    required this.authorSize,
+    required this.margin,
+  });
+
+  @override
+  int get hashCode =>
+      width.hashCode ^
+      height.hashCode ^
+      background.hashCode ^
+      gradientEnd.hashCode ^
+      textColor.hashCode ^
+      titleSize.hashCode ^
+      authorSize.hashCode ^
+      margin.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CoverStyle &&
+          runtimeType == other.runtimeType &&
+          width == other.width &&
+          height == other.height &&
+          background == other.background &&
+          gradientEnd == other.gradientEnd &&
+          textColor == other.textColor &&
+          titleSize == other.titleSize &&
+          authorSize == other.authorSize &&
+          margin == other.margin;
+}
@@ -0,0 +1,145 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'book_image.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `background_weights`, `blend`, `border_share`, `border`, `channels`, `chroma`, `colour_share`, `dim`, `get`, `invert`, `is_transparent`, `is_white`, `tone`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Pixels`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+/// Options for the default night theme.
+Future<DarkImageOptions> defaultDarkImageOptions() =>
+    RustLib.instance.api.crateApiDarkImageDefaultDarkImageOptions();
+
+/// Tone an image already loaded with `load_book_image` for the dark theme.
+///
+/// # Arguments
+/// * `image` - Decoded image
+/// * `options` - Page colours and how to tone the image
+Future<DarkImage> toneImageForDarkTheme({
+  required BookImage image,
+  required DarkImageOptions options,
+}) => RustLib.instance.api.crateApiDarkImageToneImageForDarkTheme(
+  image: image,
+  options: options,
+);
+
+/// Extract and decode an image of a parsed book and tone it for the dark
+/// theme.
+///
+/// # Arguments
+/// * `path` - Path to the EPUB, MOBI/AZW, FB2 or UMD file
+/// * `href` - Image href from `BookChapter::images`
+/// * `max_width` - Largest width to decode to, in pixels; 0 for no limit
+/// * `max_height` - Largest height to decode to, in pixels; 0 for no limit
+/// * `options` - Page colours and how to tone the image
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+Future<DarkImage> loadDarkBookImage({
+  required String path,
+  required String href,
+  required int maxWidth,
+  required int maxHeight,
+  required DarkImageOptions options,
+  int? cancelToken,
+}) => RustLib.instance.api.crateApiDarkImageLoadDarkBookImage(
+  path: path,
+  href: href,
+  maxWidth: maxWidth,
+  maxHeight: maxHeight,
+  options: options,
+  cancelToken: cancelToken,
+);
+
+class DarkImage {
+  final BookImage image;
+  /// What was done to the image; never `Auto`.
+  final DarkImageMode mode;
+  /// Share of the border that is near-white or transparent.
+  final double backgroundShare;
+  final bool lineArt;
+
+  const DarkImage({
+    required this.image,
+    required this.mode,
+    required this.backgroundShare,
+    required this.lineArt,
+  });
+
+  @override
+  int get hashCode =>
+      image.hashCode ^
+      mode.hashCode ^
+      backgroundShare.hashCode ^
+      lineArt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DarkImage &&
+          runtimeType == other.runtimeType &&
+          image == other.image &&
+          mode == other.mode &&
+          backgroundShare == other.backgroundShare &&
+          lineArt == other.lineArt;
+}
+
+enum DarkImageMode {
+  /// Pick one of the others from what the image looks like.
+  auto,
+  /// Invert lightness into the page colours, keeping hue.
+  invert,
+  /// Replace the light background with the page background and dim the rest.
+  recolor,
+  /// Dim the whole image.
+  dim,
+}
+
+class DarkImageOptions {
+  final DarkImageMode mode;
+  /// Page background colour.
+  final int background;
+  /// Page text colour, which black ink becomes when inverting.
+  final int foreground;
+  /// Smallest value of every channel for a pixel to count as near-white.
+  final int whiteLevel;
+  /// Share of the border, from 0 to 1, that must be near-white or
+  /// transparent for the background to count as light.
+  final double minBackgroundShare;
+  /// Factor applied to pixels that are dimmed, from 0 to 1.
+  final double brightness;
+
+  const DarkImageOptions({
+    required this.mode,
+    required this.background,
+    required this.foreground,
+    required this.whiteLevel,
+    required this.minBackgroundShare,
+    required this.brightness,
+  });
+
+  @override
+  int get hashCode =>
+      mode.hashCode ^
+      background.hashCode ^
+      foreground.hashCode ^
+      whiteLevel.hashCode ^
+      minBackgroundShare.hashCode ^
+      brightness.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DarkImageOptions &&
+          runtimeType == other.runtimeType &&
+          mode == other.mode &&
+          background == other.background &&
+          foreground == other.foreground &&
+          whiteLevel == other.whiteLevel &&
+          minBackgroundShare == other.minBackgroundShare &&
+          brightness == other.brightness;
+}
@@ -0,0 +1,455 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `apply_sync_records`, `apply_sync`, `book_row`, `bookmark_key`, `bookmark_row`, `bury`, `content_key`, `dump`, `export_library`, `export_tombstones`, `highlight_key`, `highlight_row`, `insert_bookmarks`, `insert_books`, `insert_highlights`, `insert_progress`, `lock`, `merge_library`, `merge`, `progress_row`, `read_bookmarks`, `read_books`, `read_chapters`, `read_highlights`, `read_tombstones`, `remove_bookmark`, `remove_highlight`, `replace_chapters`, `setup`, `with_db`, `write_bookmarks`, `write_books`, `write_chapters`, `write_progress`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `LibraryDump`, `MergeCounts`, `Tombstone`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `default`
+
+/// Open the library database, creating and migrating it as needed.
+/// Calling this again switches to the new file.
+///
+/// # Arguments
+/// * `path` - Database file; missing parent directories are created
+Future<void> openLibraryDb({required String path}) =>
+    RustLib.instance.api.crateApiDbOpenLibraryDb(path: path);
+
+/// Close the library database, checkpointing the WAL into the main file.
+Future<void> closeLibraryDb() =>
+    RustLib.instance.api.crateApiDbCloseLibraryDb();
+
+/// Insert books or update them by ID. Returns the number written.
+///
+/// # Arguments
+/// * `books` - Books to write, in one transaction
+Future<int> upsertBooks({required List<LibraryBook> books}) =>
+    RustLib.instance.api.crateApiDbUpsertBooks(books: books);
+
+/// Look up a book by ID.
+Future<LibraryBook?> getLibraryBook({required String id}) =>
+    RustLib.instance.api.crateApiDbGetLibraryBook(id: id);
+
+/// Delete books with their chapters, progress and bookmarks. Returns the
+/// number of books deleted.
+///
+/// # Arguments
+/// * `ids` - IDs of the books to delete
+Future<int> deleteBooks({required List<String> ids}) =>
+    RustLib.instance.api.crateApiDbDeleteBooks(ids: ids);
+
+/// List one page of books.
+///
+/// # Arguments
+/// * `query` - Search text, sort order and page
+Future<BookPage> queryBooks({required BookQuery query}) =>
+    RustLib.instance.api.crateApiDbQueryBooks(query: query);
+
+/// Replace a book's table of contents and update its chapter count.
+///
+/// # Arguments
+/// * `book_id` - ID of a stored book
+/// * `chapters` - The whole table of contents; indices should run from 0
+Future<void> setLibraryChapters({
+  required String bookId,
+  required List<LibraryChapter> chapters,
+}) => RustLib.instance.api.crateApiDbSetLibraryChapters(
+  bookId: bookId,
+  chapters: chapters,
+);
+
+/// List one page of a book's chapters, in order.
+///
+/// # Arguments
+/// * `book_id` - ID of the book
+/// * `offset` - Chapters to skip
+/// * `limit` - Chapters to return at most
+Future<List<LibraryChapter>> queryLibraryChapters({
+  required String bookId,
+  required int offset,
+  required int limit,
+}) => RustLib.instance.api.crateApiDbQueryLibraryChapters(
+  bookId: bookId,
+  offset: offset,
+  limit: limit,
+);
+
+/// Save reading progress for several books; entries older than the stored
+/// progress are skipped. Also updates each book's `last_read_at`.
+///
+/// # Arguments
+/// * `progress` - Progress to write, in one transaction
+Future<void> upsertProgress({required List<ReadingProgress> progress}) =>
+    RustLib.instance.api.crateApiDbUpsertProgress(progress: progress);
+
+/// Look up the reading progress of a book.
+Future<ReadingProgress?> getProgress({required String bookId}) =>
+    RustLib.instance.api.crateApiDbGetProgress(bookId: bookId);
+
+/// Add or replace bookmarks and return their IDs, in order.
+///
+/// # Arguments
+/// * `bookmarks` - Bookmarks to write, in one transaction
+Future<Int64List> upsertBookmarks({required List<Bookmark> bookmarks}) =>
+    RustLib.instance.api.crateApiDbUpsertBookmarks(bookmarks: bookmarks);
+
+/// Delete a bookmark. Returns false if there was none with the ID.
+Future<bool> deleteBookmark({required PlatformInt64 id}) =>
+    RustLib.instance.api.crateApiDbDeleteBookmark(id: id);
+
+/// List one page of bookmarks by position in the book, or for all books
+/// newest first.
+///
+/// # Arguments
+/// * `book_id` - Book to list bookmarks of, or `None` for all books
+/// * `offset` - Bookmarks to skip
+/// * `limit` - Bookmarks to return at most
+Future<List<Bookmark>> queryBookmarks({
+  String? bookId,
+  required int offset,
+  required int limit,
+}) => RustLib.instance.api.crateApiDbQueryBookmarks(
+  bookId: bookId,
+  offset: offset,
+  limit: limit,
+);
+
+/// Add or replace highlights and return their IDs, in order.
+///
+/// # Arguments
+/// * `highlights` - Highlights to write, in one transaction
+Future<Int64List> upsertHighlights({required List<Highlight> highlights}) =>
+    RustLib.instance.api.crateApiDbUpsertHighlights(highlights: highlights);
+
+/// Delete a highlight. Returns false if there was none with the ID.
+Future<bool> deleteHighlight({required PlatformInt64 id}) =>
+    RustLib.instance.api.crateApiDbDeleteHighlight(id: id);
+
+/// List one page of highlights by position in the book, or for all books
+/// newest first.
+///
+/// # Arguments
+/// * `book_id` - Book to list highlights of, or `None` for all books
+/// * `offset` - Highlights to skip
+/// * `limit` - Highlights to return at most
+Future<List<Highlight>> queryHighlights({
+  String? bookId,
+  required int offset,
+  required int limit,
+}) => RustLib.instance.api.crateApiDbQueryHighlights(
+  bookId: bookId,
+  offset: offset,
+  limit: limit,
+);
+
+class BookPage {
+  final List<LibraryBook> books;
+  /// Books matching the query across all pages.
+  final int total;
+
+  const BookPage({required this.books, required this.total});
+
+  @override
+  int get hashCode => books.hashCode ^ total.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BookPage &&
+          runtimeType == other.runtimeType &&
+          books == other.books &&
+          total == other.total;
+}
+
+class BookQuery {
+  /// Matched against title and author, case-insensitively.
+  final String? search;
+  final BookSort sort;
+  final int offset;
+  final int limit;
+
+  const BookQuery({
+    this.search,
+    required this.sort,
+    required this.offset,
+    required this.limit,
+  });
+
+  @override
+  int get hashCode =>
+      search.hashCode ^ sort.hashCode ^ offset.hashCode ^ limit.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BookQuery &&
+          runtimeType == other.runtimeType &&
+          search == other.search &&
+          sort == other.sort &&
+          offset == other.offset &&
+          limit == other.limit;
+}
+
+enum BookSort {
+  /// Most recently read first, then unread books by when they were added.
+  recentlyRead,
+  recentlyAdded,
+  recentlyUpdated,
+  title,
+  author,
+}
+
+class Bookmark {
+  /// `None` to add a bookmark, or the ID of one to replace.
+  final PlatformInt64? id;
+  final String bookId;
+  final int chapterIndex;
+  final int chapterOffset;
+  final String excerpt;
+  final String? note;
+  final PlatformInt64 createdAt;
+
+  const Bookmark({
+    this.id,
+    required this.bookId,
+    required this.chapterIndex,
+    required this.chapterOffset,
+    required this.excerpt,
+    this.note,
+    required this.createdAt,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      bookId.hashCode ^
+      chapterIndex.hashCode ^
+      chapterOffset.hashCode ^
+      excerpt.hashCode ^
+      note.hashCode ^
+      createdAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Bookmark &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          bookId == other.bookId &&
+          chapterIndex == other.chapterIndex &&
+          chapterOffset == other.chapterOffset &&
+          excerpt == other.excerpt &&
+          note == other.note &&
+          createdAt == other.createdAt;
+}
+
+class Highlight {
+  /// `None` to add a highlight, or the ID of one to replace.
+  final PlatformInt64? id;
+  final String bookId;
+  final int chapterIndex;
+  /// Offsets of the highlighted range within the chapter, in UTF-16 code
+  /// units.
+  final int startOffset;
+  final int endOffset;
+  final String text;
+  final String? note;
+  /// ARGB.
+  final int color;
+  final PlatformInt64 createdAt;
+
+  const Highlight({
+    this.id,
+    required this.bookId,
+    required this.chapterIndex,
+    required this.startOffset,
+    required this.endOffset,
+    required this.text,
+    this.note,
+    required this.color,
+    required this.createdAt,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      bookId.hashCode ^
+      chapterIndex.hashCode ^
+      startOffset.hashCode ^
+      endOffset.hashCode ^
+      text.hashCode ^
+      note.hashCode ^
+      color.hashCode ^
+      createdAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Highlight &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          bookId == other.bookId &&
+          chapterIndex == other.chapterIndex &&
+          startOffset == other.startOffset &&
+          endOffset == other.endOffset &&
+          text == other.text &&
+          note == other.note &&
+          color == other.color &&
+          createdAt == other.createdAt;
+}
+
+class LibraryBook {
+  final String id;
+  final String title;
+  final String? author;
+  final String? intro;
+  final String? kind;
+  final String? coverUrl;
+  /// Book source the book is read from; `None` for local files.
+  final String? sourceUrl;
+  final String? bookUrl;
+  final String? tocUrl;
+  final String? latestChapterTitle;
+  final int chapterCount;
+  final int? wordCount;
+  /// Seconds since the Unix epoch. Kept from the first insert.
+  final PlatformInt64 addedAt;
+  final PlatformInt64 updatedAt;
+  /// Set from saved progress; an upsert without it keeps the stored one.
+  final PlatformInt64? lastReadAt;
+
+  const LibraryBook({
+    required this.id,
+    required this.title,
+    this.author,
+    this.intro,
+    this.kind,
+    this.coverUrl,
+    this.sourceUrl,
+    this.bookUrl,
+    this.tocUrl,
+    this.latestChapterTitle,
+    required this.chapterCount,
+    this.wordCount,
+    required this.addedAt,
+    required this.updatedAt,
+    this.lastReadAt,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      title.hashCode ^
+      author.hashCode ^
+      intro.hashCode ^
+      kind.hashCode ^
+      coverUrl.hashCode ^
+      sourceUrl.hashCode ^
+      bookUrl.hashCode ^
+      tocUrl.hashCode ^
+      latestChapterTitle.hashCode ^
+      chapterCount.hashCode ^
+      wordCount.hashCode ^
+      addedAt.hashCode ^
+      updatedAt.hashCode ^
+      lastReadAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LibraryBook &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          title == other.title &&
+          author == other.author &&
+          intro == other.intro &&
+          kind == other.kind &&
+          coverUrl == other.coverUrl &&
+          sourceUrl == other.sourceUrl &&
+          bookUrl == other.bookUrl &&
+          tocUrl == other.tocUrl &&
+          latestChapterTitle == other.latestChapterTitle &&
+          chapterCount == other.chapterCount &&
+          wordCount == other.wordCount &&
+          addedAt == other.addedAt &&
+          updatedAt == other.updatedAt &&
+          lastReadAt == other.lastReadAt;
+}
+
+class LibraryChapter {
+  final int index;
+  final String title;
+  final String? url;
+  final bool isVolume;
+  final int? wordCount;
+
+  const LibraryChapter({
+    required this.index,
+    required this.title,
+    this.url,
+    required this.isVolume,
+    this.wordCount,
+  });
+
+  @override
+  int get hashCode =>
+      index.hashCode ^
+      title.hashCode ^
+      url.hashCode ^
+      isVolume.hashCode ^
+      wordCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LibraryChapter &&
+          runtimeType == other.runtimeType &&
+          index == other.index &&
+          title == other.title &&
+          url == other.url &&
+          isVolume == other.isVolume &&
+          wordCount == other.wordCount;
+}
+
+class ReadingProgress {
+  final String bookId;
+  final int chapterIndex;
+  /// Offset within the chapter, in UTF-16 code units.
+  final int chapterOffset;
+  /// Position in the whole book, from 0 to 1.
+  final double percent;
+  /// Seconds since the Unix epoch; older progress never replaces newer.
+  final PlatformInt64 updatedAt;
+
+  const ReadingProgress({
+    required this.bookId,
+    required this.chapterIndex,
+    required this.chapterOffset,
+    required this.percent,
+    required this.updatedAt,
+  });
+
+  @override
+  int get hashCode =>
+      bookId.hashCode ^
+      chapterIndex.hashCode ^
+      chapterOffset.hashCode ^
+      percent.hashCode ^
+      updatedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReadingProgress &&
+          runtimeType == other.runtimeType &&
+          bookId == other.bookId &&
+          chapterIndex == other.chapterIndex &&
+          chapterOffset == other.chapterOffset &&
+          percent == other.percent &&
+          updatedAt == other.updatedAt;
+}
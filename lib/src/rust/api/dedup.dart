@@ -0,0 +1,139 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'db.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `all_books`, `book_sketch`, `cluster`, `fnv1a`, `fold`, `new`, `new`, `new`, `normalize_author`, `normalize_title`, `resolve`, `root`, `score`, `similarity`, `similarity`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Aliases`, `Key`, `Sketch`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`
+
+Future<DedupOptions> defaultDedupOptions() =>
+    RustLib.instance.api.crateApiDedupDefaultDedupOptions();
+
+/// Group the library into clusters of probable duplicates. Books without a
+/// duplicate are left out.
+///
+/// # Arguments
+/// * `options` - Threshold, content comparison and aliases
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+Future<List<DuplicateCluster>> findDuplicateBooks({
+  required DedupOptions options,
+  int? cancelToken,
+}) => RustLib.instance.api.crateApiDedupFindDuplicateBooks(
+  options: options,
+  cancelToken: cancelToken,
+);
+
+/// Library books that are probably the same as a book about to be
+/// imported, best match first.
+///
+/// # Arguments
+/// * `title` - Title of the new book
+/// * `author` - Its author, if known
+/// * `options` - Threshold and aliases; content is not compared
+Future<List<DuplicateMatch>> findDuplicatesOf({
+  required String title,
+  String? author,
+  required DedupOptions options,
+}) => RustLib.instance.api.crateApiDedupFindDuplicatesOf(
+  title: title,
+  author: author,
+  options: options,
+);
+
+class BookAlias {
+  /// Another name of the book, such as a translation or abbreviation.
+  final String alias;
+  final String title;
+
+  const BookAlias({required this.alias, required this.title});
+
+  @override
+  int get hashCode => alias.hashCode ^ title.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BookAlias &&
+          runtimeType == other.runtimeType &&
+          alias == other.alias &&
+          title == other.title;
+}
+
+class DedupOptions {
+  /// Least score, from 0 to 1, for two books to be duplicates.
+  final double minScore;
+  /// Compare the text of cached chapters as well as titles and authors.
+  final bool compareContent;
+  final List<BookAlias> aliases;
+
+  const DedupOptions({
+    required this.minScore,
+    required this.compareContent,
+    required this.aliases,
+  });
+
+  @override
+  int get hashCode =>
+      minScore.hashCode ^ compareContent.hashCode ^ aliases.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DedupOptions &&
+          runtimeType == other.runtimeType &&
+          minScore == other.minScore &&
+          compareContent == other.compareContent &&
+          aliases == other.aliases;
+}
+
+class DuplicateCluster {
+  /// The copy to keep first: the one with the most chapters, then the
+  /// most recently read.
+  final List<LibraryBook> books;
+  /// Score of the weakest link in the cluster.
+  final double score;
+  /// Whether chapter text linked any of the books.
+  final bool contentMatch;
+
+  const DuplicateCluster({
+    required this.books,
+    required this.score,
+    required this.contentMatch,
+  });
+
+  @override
+  int get hashCode => books.hashCode ^ score.hashCode ^ contentMatch.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DuplicateCluster &&
+          runtimeType == other.runtimeType &&
+          books == other.books &&
+          score == other.score &&
+          contentMatch == other.contentMatch;
+}
+
+class DuplicateMatch {
+  final LibraryBook book;
+  final double score;
+
+  const DuplicateMatch({required this.book, required this.score});
+
+  @override
+  int get hashCode => book.hashCode ^ score.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DuplicateMatch &&
+          runtimeType == other.runtimeType &&
+          book == other.book &&
+          score == other.score;
+}
@@ -0,0 +1,151 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `chars`, `cmp`, `define`, `find`, `has_prefix`, `index`, `info`, `len`, `lock`, `lower_bound`, `new`, `prefix`, `resource_files`, `title`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Dictionary`, `Fold`, `KeyIndex`, `Source`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+/// Open a dictionary and add it to the lookup list. Opening a dictionary
+/// that is already open returns it again.
+///
+/// # Arguments
+/// * `path` - An `.mdx` file, or the `.ifo` file of a StarDict dictionary
+Future<DictInfo> openDictionary({required String path}) =>
+    RustLib.instance.api.crateApiDictOpenDictionary(path: path);
+
+/// Close a dictionary. Returns false if it was not open.
+Future<bool> closeDictionary({required int id}) =>
+    RustLib.instance.api.crateApiDictCloseDictionary(id: id);
+
+/// List open dictionaries in lookup order.
+Future<List<DictInfo>> listDictionaries() =>
+    RustLib.instance.api.crateApiDictListDictionaries();
+
+/// Look a word up in every open dictionary. Matching ignores case and,
+/// where the dictionary asks for it, punctuation and spaces.
+///
+/// # Arguments
+/// * `word` - Word to look up; surrounding punctuation is ignored
+/// * `max_results` - Most definitions to return across all dictionaries
+Future<List<DictDefinition>> lookupWord({
+  required String word,
+  required int maxResults,
+}) => RustLib.instance.api.crateApiDictLookupWord(
+  word: word,
+  maxResults: maxResults,
+);
+
+/// Headwords starting with `prefix` across all open dictionaries, sorted
+/// and without duplicates, for lookup suggestions.
+///
+/// # Arguments
+/// * `prefix` - Start of the word typed so far
+/// * `max_results` - Most headwords to return
+Future<List<String>> lookupPrefix({
+  required String prefix,
+  required int maxResults,
+}) => RustLib.instance.api.crateApiDictLookupPrefix(
+  prefix: prefix,
+  maxResults: maxResults,
+);
+
+/// Load a file a definition refers to, such as an image, sound or style
+/// sheet. Returns `None` if the dictionary does not have it.
+///
+/// # Arguments
+/// * `dict_id` - Dictionary the definition came from
+/// * `path` - Path as written in the definition HTML, e.g. `img/a.png`
+Future<Uint8List?> dictionaryResource({
+  required int dictId,
+  required String path,
+}) => RustLib.instance.api.crateApiDictDictionaryResource(
+  dictId: dictId,
+  path: path,
+);
+
+class DictDefinition {
+  final int dictId;
+  final String dictTitle;
+  /// Headword as written in the dictionary.
+  final String headword;
+  /// Definition HTML. Images and styles refer to resources by relative
+  /// path; load them with `dictionary_resource`.
+  final String html;
+
+  const DictDefinition({
+    required this.dictId,
+    required this.dictTitle,
+    required this.headword,
+    required this.html,
+  });
+
+  @override
+  int get hashCode =>
+      dictId.hashCode ^ dictTitle.hashCode ^ headword.hashCode ^ html.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DictDefinition &&
+          runtimeType == other.runtimeType &&
+          dictId == other.dictId &&
+          dictTitle == other.dictTitle &&
+          headword == other.headword &&
+          html == other.html;
+}
+
+enum DictFormat { mdict, starDict }
+
+class DictInfo {
+  final int id;
+  /// Path the dictionary was opened from.
+  final String path;
+  final DictFormat format;
+  final String title;
+  /// Description from the dictionary header; may contain HTML.
+  final String description;
+  /// Number of headwords.
+  final int entryCount;
+  /// Number of resource files (`.mdd` files or a StarDict `res`
+  /// directory) found next to the dictionary.
+  final int resourceFiles;
+
+  const DictInfo({
+    required this.id,
+    required this.path,
+    required this.format,
+    required this.title,
+    required this.description,
+    required this.entryCount,
+    required this.resourceFiles,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      path.hashCode ^
+      format.hashCode ^
+      title.hashCode ^
+      description.hashCode ^
+      entryCount.hashCode ^
+      resourceFiles.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DictInfo &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          path == other.path &&
+          format == other.format &&
+          title == other.title &&
+          description == other.description &&
+          entryCount == other.entryCount &&
+          resourceFiles == other.resourceFiles;
+}
@@ -0,0 +1,285 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'prefetch.dart';
+
+// These functions are ignored because they are not marked as `pub`: `background_paused`, `background_paused`, `backoff`, `cancel`, `cap`, `configure`, `content_range`, `digest_matches`, `digest_of`, `download`, `downloader`, `emit`, `enqueue_low_priority`, `enqueue_with`, `enqueue`, `fetch`, `listen`, `lock`, `new`, `pace`, `pace`, `part_path`, `reserve`, `run`, `schedule`, `server_digest`, `set_bandwidth`, `tag_path`, `task_state`, `truncate`, `validate`, `verified_prefix`, `verify_part`, `wait_until`, `write_cached`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Bandwidth`, `Downloader`, `Failure`, `Priority`, `Progress`, `State`, `Task`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+/// Settings used until `configure_downloader` is called.
+Future<DownloaderConfig> defaultDownloaderConfig() =>
+    RustLib.instance.api.crateApiDownloaderDefaultDownloaderConfig();
+
+/// No caps, and background downloads run on any connection.
+Future<BandwidthPolicy> defaultBandwidthPolicy() =>
+    RustLib.instance.api.crateApiDownloaderDefaultBandwidthPolicy();
+
+/// Change the bandwidth caps and background policy; they apply to running
+/// downloads at once.
+Future<void> setBandwidthPolicy({required BandwidthPolicy policy}) =>
+    RustLib.instance.api.crateApiDownloaderSetBandwidthPolicy(policy: policy);
+
+/// Report the current connection; until then it is taken to be unmetered.
+Future<void> setNetworkType({required NetworkType network}) =>
+    RustLib.instance.api.crateApiDownloaderSetNetworkType(network: network);
+
+/// Change the downloader settings. Running downloads keep the settings they
+/// started with.
+Future<void> configureDownloader({required DownloaderConfig config}) =>
+    RustLib.instance.api.crateApiDownloaderConfigureDownloader(config: config);
+
+/// Receive events for every download task, replacing any previous stream.
+/// Nothing is buffered while no stream is listening; the final file on
+/// disk is the source of truth after a restart.
+Stream<DownloadEvent> downloadEvents() =>
+    RustLib.instance.api.crateApiDownloaderDownloadEvents();
+
+/// Queue a download and return its task ID.
+///
+/// A partial download left at the same destination by an earlier run is
+/// resumed when the server supports range requests.
+///
+/// # Arguments
+/// * `request` - URL, destination file and extra headers
+Future<int> enqueueDownload({required DownloadRequest request}) =>
+    RustLib.instance.api.crateApiDownloaderEnqueueDownload(request: request);
+
+/// Queue a background download, such as a cover, and return its task ID.
+/// It starts only when no other download is waiting, and follows the
+/// background policy of `set_bandwidth_policy`.
+///
+/// # Arguments
+/// * `request` - URL, destination file and extra headers
+Future<int> enqueueBackgroundDownload({required DownloadRequest request}) =>
+    RustLib.instance.api.crateApiDownloaderEnqueueBackgroundDownload(
+      request: request,
+    );
+
+/// Cancel a queued or running download and delete its partial data.
+/// Returns false if the task has already finished or does not exist.
+Future<bool> cancelDownload({required int taskId}) =>
+    RustLib.instance.api.crateApiDownloaderCancelDownload(taskId: taskId);
+
+class BandwidthPolicy {
+  /// Cap on all downloads together, in KB/s; 0 for none.
+  final int maxKbps;
+  /// Cap on a metered connection, in KB/s; 0 for only `max_kbps`.
+  final int meteredMaxKbps;
+  /// Whether background downloads, such as prefetched chapters and
+  /// covers, wait while the connection is metered.
+  final bool pauseBackgroundWhenMetered;
+
+  const BandwidthPolicy({
+    required this.maxKbps,
+    required this.meteredMaxKbps,
+    required this.pauseBackgroundWhenMetered,
+  });
+
+  @override
+  int get hashCode =>
+      maxKbps.hashCode ^
+      meteredMaxKbps.hashCode ^
+      pauseBackgroundWhenMetered.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BandwidthPolicy &&
+          runtimeType == other.runtimeType &&
+          maxKbps == other.maxKbps &&
+          meteredMaxKbps == other.meteredMaxKbps &&
+          pauseBackgroundWhenMetered == other.pauseBackgroundWhenMetered;
+}
+
+enum ChecksumAlgorithm { blake3, sha256 }
+
+class DownloadChecksum {
+  final ChecksumAlgorithm algorithm;
+  /// Hex digest of the whole file.
+  final String digest;
+  /// Size of each part in `part_digests`.
+  final BigInt partSize;
+  /// Hex digests of the file's consecutive `part_size` byte parts, the
+  /// last one possibly shorter; may be empty.
+  final List<String> partDigests;
+
+  const DownloadChecksum({
+    required this.algorithm,
+    required this.digest,
+    required this.partSize,
+    required this.partDigests,
+  });
+
+  @override
+  int get hashCode =>
+      algorithm.hashCode ^
+      digest.hashCode ^
+      partSize.hashCode ^
+      partDigests.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DownloadChecksum &&
+          runtimeType == other.runtimeType &&
+          algorithm == other.algorithm &&
+          digest == other.digest &&
+          partSize == other.partSize &&
+          partDigests == other.partDigests;
+}
+
+class DownloadEvent {
+  final int taskId;
+  final DownloadState state;
+  /// Bytes written so far, including a resumed partial download.
+  final BigInt downloaded;
+  /// Size of the whole file, when the server reports it.
+  final BigInt? total;
+  /// Attempts started so far.
+  final int attempt;
+  /// Why the task failed or is being retried.
+  final String? error;
+
+  const DownloadEvent({
+    required this.taskId,
+    required this.state,
+    required this.downloaded,
+    this.total,
+    required this.attempt,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      taskId.hashCode ^
+      state.hashCode ^
+      downloaded.hashCode ^
+      total.hashCode ^
+      attempt.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DownloadEvent &&
+          runtimeType == other.runtimeType &&
+          taskId == other.taskId &&
+          state == other.state &&
+          downloaded == other.downloaded &&
+          total == other.total &&
+          attempt == other.attempt &&
+          error == other.error;
+}
+
+class DownloadHeader {
+  final String name;
+  final String value;
+
+  const DownloadHeader({required this.name, required this.value});
+
+  @override
+  int get hashCode => name.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DownloadHeader &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          value == other.value;
+}
+
+class DownloadRequest {
+  final String url;
+  /// File to write; missing parent directories are created.
+  final String destination;
+  /// Extra request headers, such as `Referer` or `Cookie`.
+  final List<DownloadHeader> headers;
+  /// Expected content, such as from a font or book manifest.
+  final DownloadChecksum? checksum;
+
+  const DownloadRequest({
+    required this.url,
+    required this.destination,
+    required this.headers,
+    this.checksum,
+  });
+
+  @override
+  int get hashCode =>
+      url.hashCode ^
+      destination.hashCode ^
+      headers.hashCode ^
+      checksum.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DownloadRequest &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          destination == other.destination &&
+          headers == other.headers &&
+          checksum == other.checksum;
+}
+
+enum DownloadState {
+  queued,
+  running,
+  /// Waiting to retry after a failed attempt.
+  retrying,
+  completed,
+  failed,
+  cancelled,
+}
+
+class DownloaderConfig {
+  /// Downloads running at once across all hosts.
+  final int maxConcurrent;
+  /// Downloads running at once against a single host.
+  final int maxPerHost;
+  /// Retries after the first attempt before a task fails.
+  final int maxRetries;
+  /// Delay before the first retry; each further retry doubles it.
+  final int initialBackoffMs;
+  /// Connect and read timeout.
+  final int timeoutSecs;
+  final String userAgent;
+
+  const DownloaderConfig({
+    required this.maxConcurrent,
+    required this.maxPerHost,
+    required this.maxRetries,
+    required this.initialBackoffMs,
+    required this.timeoutSecs,
+    required this.userAgent,
+  });
+
+  @override
+  int get hashCode =>
+      maxConcurrent.hashCode ^
+      maxPerHost.hashCode ^
+      maxRetries.hashCode ^
+      initialBackoffMs.hashCode ^
+      timeoutSecs.hashCode ^
+      userAgent.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DownloaderConfig &&
+          runtimeType == other.runtimeType &&
+          maxConcurrent == other.maxConcurrent &&
+          maxPerHost == other.maxPerHost &&
+          maxRetries == other.maxRetries &&
+          initialBackoffMs == other.initialBackoffMs &&
+          timeoutSecs == other.timeoutSecs &&
+          userAgent == other.userAgent;
+}
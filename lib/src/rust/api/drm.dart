@@ -0,0 +1,54 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `encrypts_content`, `epub_scheme`, `mobi_scheme`, `pdf_scheme`, `protected_error`, `read_up_to`, `record_offset`, `scheme_name`, `sniff`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+
+/// Check whether a book file is DRM-protected.
+///
+/// # Arguments
+/// * `path` - Path to the book
+Future<DrmStatus> detectDrm({required String path}) =>
+    RustLib.instance.api.crateApiDrmDetectDrm(path: path);
+
+enum BookFormat { epub, mobi, kfx, topaz, pdf, unknown }
+
+enum DrmScheme {
+  adobeAdept,
+  barnesAndNoble,
+  readiumLcp,
+  appleFairPlay,
+  /// Mobipocket/Kindle encryption in a MOBI, AZW or AZW3 file.
+  kindle,
+  kindleKfx,
+  kindleTopaz,
+  /// PDF standard security; such files open without a password when only
+  /// an owner password is set.
+  pdfPassword,
+  unknown,
+}
+
+class DrmStatus {
+  final BookFormat format;
+  /// `None` if the book is not protected.
+  final DrmScheme? scheme;
+
+  const DrmStatus({required this.format, this.scheme});
+
+  @override
+  int get hashCode => format.hashCode ^ scheme.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DrmStatus &&
+          runtimeType == other.runtimeType &&
+          format == other.format &&
+          scheme == other.scheme;
+}
@@ -0,0 +1,84 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `byte_candidates`, `clean_ratio`, `detect`, `utf16_candidates`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Detection`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+/// Detect the charset of `bytes` and decode them to UTF-8.
+///
+/// # Arguments
+/// * `bytes` - Raw file contents
+/// * `override_encoding` - Encoding label to decode with instead of the
+///   detected one, e.g. `big5`; the candidates are still reported
+Future<DecodedText> detectAndDecodeText({
+  required List<int> bytes,
+  String? overrideEncoding,
+}) => RustLib.instance.api.crateApiEncodingDetectAndDecodeText(
+  bytes: bytes,
+  overrideEncoding: overrideEncoding,
+);
+
+/// Text converted to UTF-8, with the charset it was decoded from.
+class DecodedText {
+  final String text;
+  /// Encoding label, e.g. `UTF-8`, `GBK` or `Shift_JIS`.
+  final String encoding;
+  /// Confidence of the chosen encoding, from 0 to 1; 1 for overrides.
+  final double confidence;
+  /// Whether some bytes were invalid and replaced with U+FFFD.
+  final bool hadErrors;
+  /// Detected encodings, most likely first.
+  final List<EncodingCandidate> candidates;
+
+  const DecodedText({
+    required this.text,
+    required this.encoding,
+    required this.confidence,
+    required this.hadErrors,
+    required this.candidates,
+  });
+
+  @override
+  int get hashCode =>
+      text.hashCode ^
+      encoding.hashCode ^
+      confidence.hashCode ^
+      hadErrors.hashCode ^
+      candidates.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DecodedText &&
+          runtimeType == other.runtimeType &&
+          text == other.text &&
+          encoding == other.encoding &&
+          confidence == other.confidence &&
+          hadErrors == other.hadErrors &&
+          candidates == other.candidates;
+}
+
+class EncodingCandidate {
+  final String encoding;
+  final double confidence;
+
+  const EncodingCandidate({required this.encoding, required this.confidence});
+
+  @override
+  int get hashCode => encoding.hashCode ^ confidence.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EncodingCandidate &&
+          runtimeType == other.runtimeType &&
+          encoding == other.encoding &&
+          confidence == other.confidence;
+}
@@ -0,0 +1,127 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'book.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `deobfuscate`, `entry_name`, `extract_resource`, `finish_entries`, `has_property`, `is_document`, `link_toc`, `list_resources`, `obfuscation_algorithm`, `open_archive`, `open_file`, `parse_nav`, `parse_ncx`, `parse_opf`, `parse`, `push_stylesheet`, `read_entry`, `read_package`, `read_resource`, `read_toc`, `resolve_href`, `resource_kind`, `rootfile_path`, `spine_items`, `styled_chapter`, `toc_item`, `uuid_bytes`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ManifestItem`, `Package`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Parse a local EPUB file into chapters and a table of contents.
+///
+/// # Arguments
+/// * `path` - Path to the `.epub` file
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+Future<ParsedBook> parseEpub({required String path, int? cancelToken}) =>
+    RustLib.instance.api.crateApiEpubParseEpub(
+      path: path,
+      cancelToken: cancelToken,
+    );
+
+/// List the images, stylesheets, fonts and other resources of an EPUB,
+/// sorted by archive path.
+Future<List<EpubResource>> listEpubResources({required String path}) =>
+    RustLib.instance.api.crateApiEpubListEpubResources(path: path);
+
+/// Extract an embedded resource by archive path.
+///
+/// Obfuscated fonts are restored and WOFF/WOFF2 fonts are returned as TTF;
+/// everything else is returned as stored.
+///
+/// # Arguments
+/// * `path` - Path to the `.epub` file
+/// * `href` - Archive path of the resource, e.g. an `img` `src` from chapter HTML
+Future<Uint8List> extractEpubResource({
+  required String path,
+  required String href,
+}) => RustLib.instance.api.crateApiEpubExtractEpubResource(
+  path: path,
+  href: href,
+);
+
+/// Load a chapter with its publisher styling, for books whose layout
+/// matters.
+///
+/// # Arguments
+/// * `path` - Path to the `.epub` file
+/// * `href` - The chapter's `BookChapter::href`
+/// * `layout` - Whether to keep the styling as published or cleaned
+Future<StyledChapter> loadEpubChapterStyles({
+  required String path,
+  required String href,
+  required EpubLayout layout,
+}) => RustLib.instance.api.crateApiEpubLoadEpubChapterStyles(
+  path: path,
+  href: href,
+  layout: layout,
+);
+
+/// How much of the publisher's styling `load_epub_chapter_styles` keeps.
+enum EpubLayout {
+  /// The stylesheets as published, with links resolved to archive paths.
+  original,
+  /// Colours, backgrounds, font families, fixed font sizes, line heights
+  /// and page margins removed so reading settings and night mode apply,
+  /// with indents, italics, alignment and separators kept.
+  cleaned,
+}
+
+/// A non-document resource declared in the EPUB manifest.
+class EpubResource {
+  /// Archive path, as used by chapter HTML and `extract_epub_resource`.
+  final String href;
+  final String mediaType;
+  final ResourceKind kind;
+  /// Uncompressed size in bytes.
+  final int size;
+
+  const EpubResource({
+    required this.href,
+    required this.mediaType,
+    required this.kind,
+    required this.size,
+  });
+
+  @override
+  int get hashCode =>
+      href.hashCode ^ mediaType.hashCode ^ kind.hashCode ^ size.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EpubResource &&
+          runtimeType == other.runtimeType &&
+          href == other.href &&
+          mediaType == other.mediaType &&
+          kind == other.kind &&
+          size == other.size;
+}
+
+/// Kind of an embedded EPUB resource, derived from its media type.
+enum ResourceKind { image, stylesheet, font, other }
+
+/// A chapter with the classes and styles its stylesheets need.
+class StyledChapter {
+  /// Body HTML as in `BookChapter`, plus `class` and `style` attributes.
+  final String html;
+  /// The chapter's linked, imported and embedded stylesheets, in order.
+  final String css;
+
+  const StyledChapter({required this.html, required this.css});
+
+  @override
+  int get hashCode => html.hashCode ^ css.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StyledChapter &&
+          runtimeType == other.runtimeType &&
+          html == other.html &&
+          css == other.css;
+}
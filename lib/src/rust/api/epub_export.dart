@@ -0,0 +1,80 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'book.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `book_text`, `build`, `chapter_body`, `cover_type`, `document`, `generated_identifier`, `item`, `nav_body`, `new`, `opf`, `stylesheet`, `timestamp`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+/// Write `chapters` as an EPUB 3 file.
+///
+/// # Arguments
+/// * `metadata` - Title, authors and other package metadata; `cover_href` is ignored
+/// * `chapters` - Chapters in reading order
+/// * `cover` - Cover image bytes (JPEG, PNG, GIF or WebP)
+/// * `font` - TrueType font to embed; it is subset to the book's text
+/// * `output_path` - Destination file; the parent directory must exist
+/// * `sink` - Receives progress after each chapter; closes when the file is written
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+Stream<EpubExportProgress> exportEpub({
+  required BookMetadata metadata,
+  required List<EpubExportChapter> chapters,
+  Uint8List? cover,
+  Uint8List? font,
+  required String outputPath,
+  int? cancelToken,
+}) => RustLib.instance.api.crateApiEpubExportExportEpub(
+  metadata: metadata,
+  chapters: chapters,
+  cover: cover,
+  font: font,
+  outputPath: outputPath,
+  cancelToken: cancelToken,
+);
+
+/// One chapter of the book to export.
+class EpubExportChapter {
+  final String title;
+  /// Chapter text with one paragraph per line.
+  final String text;
+
+  const EpubExportChapter({required this.title, required this.text});
+
+  @override
+  int get hashCode => title.hashCode ^ text.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EpubExportChapter &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          text == other.text;
+}
+
+/// Reported after each chapter is written.
+class EpubExportProgress {
+  final int writtenChapters;
+  final int totalChapters;
+
+  const EpubExportProgress({
+    required this.writtenChapters,
+    required this.totalChapters,
+  });
+
+  @override
+  int get hashCode => writtenChapters.hashCode ^ totalChapters.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EpubExportProgress &&
+          runtimeType == other.runtimeType &&
+          writtenChapters == other.writtenChapters &&
+          totalChapters == other.totalChapters;
+}
@@ -0,0 +1,127 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'book.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `add`, `copy`, `documents`, `finish`, `href`, `items`, `merge`, `nav_document`, `new`, `open`, `read`, `shows`, `size`, `split`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Source`, `Volume`, `Writer`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+/// Split an EPUB into volumes, written as `<name>-01.epub`, `<name>-02.epub`
+/// and so on. Content before the first TOC entry the book is split at, such
+/// as the cover and title page, goes into the first volume.
+///
+/// # Arguments
+/// * `path` - EPUB file to split
+/// * `options` - Where volumes start; at least one limit must be set
+/// * `output_dir` - Existing directory for the volumes; files of the same name are replaced
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+Future<List<EpubVolume>> splitEpub({
+  required String path,
+  required EpubSplitOptions options,
+  required String outputDir,
+  int? cancelToken,
+}) => RustLib.instance.api.crateApiEpubToolsSplitEpub(
+  path: path,
+  options: options,
+  outputDir: outputDir,
+  cancelToken: cancelToken,
+);
+
+/// Merge EPUBs into one, in the given order. The combined table of
+/// contents has an entry per book with the book's own entries beneath it.
+/// Returns the number of content documents in the merged spine.
+///
+/// # Arguments
+/// * `sources` - Books to merge, in reading order
+/// * `metadata` - Metadata of the merged book; a missing title, language or author list is taken from the first book
+/// * `output_path` - Destination file; the parent directory must exist
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+Future<int> mergeEpubs({
+  required List<EpubMergeSource> sources,
+  required BookMetadata metadata,
+  required String outputPath,
+  int? cancelToken,
+}) => RustLib.instance.api.crateApiEpubToolsMergeEpubs(
+  sources: sources,
+  metadata: metadata,
+  outputPath: outputPath,
+  cancelToken: cancelToken,
+);
+
+/// One book to merge.
+class EpubMergeSource {
+  final String path;
+  /// Title of the TOC entry holding the book's own contents; defaults to
+  /// the book's title.
+  final String? title;
+
+  const EpubMergeSource({required this.path, this.title});
+
+  @override
+  int get hashCode => path.hashCode ^ title.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EpubMergeSource &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          title == other.title;
+}
+
+/// Where `split_epub` starts a new volume. With both limits set, a volume
+/// ends at whichever comes first.
+class EpubSplitOptions {
+  /// Start a volume at each TOC entry this deep or shallower, 0 for
+  /// top-level entries only.
+  final int? tocDepth;
+  /// Start a volume before a chapter that would take the volume's content
+  /// past this many bytes, uncompressed. A volume always has at least one
+  /// chapter, however large.
+  final BigInt? maxVolumeBytes;
+
+  const EpubSplitOptions({this.tocDepth, this.maxVolumeBytes});
+
+  @override
+  int get hashCode => tocDepth.hashCode ^ maxVolumeBytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EpubSplitOptions &&
+          runtimeType == other.runtimeType &&
+          tocDepth == other.tocDepth &&
+          maxVolumeBytes == other.maxVolumeBytes;
+}
+
+/// One file written by `split_epub`.
+class EpubVolume {
+  final String path;
+  final String title;
+  /// Content documents in the volume's spine.
+  final int chapterCount;
+
+  const EpubVolume({
+    required this.path,
+    required this.title,
+    required this.chapterCount,
+  });
+
+  @override
+  int get hashCode => path.hashCode ^ title.hashCode ^ chapterCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EpubVolume &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          title == other.title &&
+          chapterCount == other.chapterCount;
+}
@@ -0,0 +1,71 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `code`, `panic_error`, `panic_message`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `from`
+
+/// The most recent panic since the last call, if any.
+Future<ApiError?> takeLastPanic() =>
+    RustLib.instance.api.crateApiErrorTakeLastPanic();
+
+class ApiError implements FrbException {
+  final ErrorCode code;
+  /// The full message, context first.
+  final String message;
+  /// The message of each error in the chain, outermost first.
+  final List<String> context;
+  /// The source file and line the error was converted at: the API
+  /// function for calls that are not `async`, the task runner for those
+  /// that are.
+  final String? location;
+  final String? backtrace;
+
+  const ApiError({
+    required this.code,
+    required this.message,
+    required this.context,
+    this.location,
+    this.backtrace,
+  });
+
+  @override
+  int get hashCode =>
+      code.hashCode ^
+      message.hashCode ^
+      context.hashCode ^
+      location.hashCode ^
+      backtrace.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ApiError &&
+          runtimeType == other.runtimeType &&
+          code == other.code &&
+          message == other.message &&
+          context == other.context &&
+          location == other.location &&
+          backtrace == other.backtrace;
+}
+
+enum ErrorCode {
+  /// The call's cancel token was cancelled.
+  cancelled,
+  /// A file or other item the call needed does not exist.
+  notFound,
+  permissionDenied,
+  /// The arguments or the data passed in are malformed.
+  invalidInput,
+  /// Any other file system error.
+  io,
+  /// A connection or HTTP error.
+  network,
+  /// The Rust code panicked.
+  panic,
+  other,
+}
@@ -0,0 +1,124 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `finish`, `post_process`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+/// Select from HTML with a CSS selector.
+///
+/// # Arguments
+/// * `html` - Page HTML
+/// * `selector` - CSS selector
+/// * `getter` - `text` (the default), `ownText`, `textNodes`, `html`, `all`
+///   for the outer HTML, or an attribute name such as `href`
+/// * `steps` - Post-processing, applied in order
+Future<Extraction> extractCss({
+  required String html,
+  required String selector,
+  String? getter,
+  required List<PostStep> steps,
+}) => RustLib.instance.api.crateApiExtractionExtractCss(
+  html: html,
+  selector: selector,
+  getter: getter,
+  steps: steps,
+);
+
+/// Select from HTML with an XPath expression. Elements give their outer
+/// HTML, other nodes and attributes their text.
+///
+/// # Arguments
+/// * `html` - Page HTML
+/// * `expression` - XPath 1.0 expression, such as `//a/@href`
+/// * `steps` - Post-processing, applied in order
+Future<Extraction> extractXpath({
+  required String html,
+  required String expression,
+  required List<PostStep> steps,
+}) => RustLib.instance.api.crateApiExtractionExtractXpath(
+  html: html,
+  expression: expression,
+  steps: steps,
+);
+
+/// Select from JSON with a JSONPath query. Strings give their value,
+/// arrays their items and objects their JSON text.
+///
+/// # Arguments
+/// * `json` - JSON document
+/// * `path` - JSONPath query, such as `$.data.list[*].name`
+/// * `steps` - Post-processing, applied in order
+Future<Extraction> extractJsonpath({
+  required String json,
+  required String path,
+  required List<PostStep> steps,
+}) => RustLib.instance.api.crateApiExtractionExtractJsonpath(
+  json: json,
+  path: path,
+  steps: steps,
+);
+
+class Extraction {
+  /// What the selector matched, before post-processing.
+  final List<String> matched;
+  /// The values after every step.
+  final List<String> values;
+
+  const Extraction({required this.matched, required this.values});
+
+  @override
+  int get hashCode => matched.hashCode ^ values.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Extraction &&
+          runtimeType == other.runtimeType &&
+          matched == other.matched &&
+          values == other.values;
+}
+
+class PostStep {
+  final PostStepKind kind;
+  /// Regex for `Regex` and `Replace`, separator for `Join`.
+  final String pattern;
+  /// Replacement for `Replace`.
+  final String replacement;
+
+  const PostStep({
+    required this.kind,
+    required this.pattern,
+    required this.replacement,
+  });
+
+  @override
+  int get hashCode => kind.hashCode ^ pattern.hashCode ^ replacement.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PostStep &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          pattern == other.pattern &&
+          replacement == other.replacement;
+}
+
+enum PostStepKind {
+  /// Replace each value by its regex matches, one value per match: the
+  /// first capture group if there is one, else the whole match. Values
+  /// without a match are dropped.
+  regex,
+  /// Regex replacement in each value; `$1` refers to groups.
+  replace,
+  /// Trim whitespace and drop empty values.
+  trim,
+  /// Join all values into one, with `pattern` as the separator.
+  join,
+}
@@ -0,0 +1,36 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'book.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `anchor`, `current`, `end`, `extract_binary`, `finish_title`, `finish`, `in_title_info`, `is_block`, `link_target`, `metadata_text`, `parent_is`, `parse`, `push`, `read_document`, `start`, `text`, `unzip_document`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Chapter`, `Converter`, `Metadata`, `Title`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `default`, `default`
+
+/// Parse a local `.fb2` or `.fb2.zip` file into chapters and a table of
+/// contents.
+///
+/// # Arguments
+/// * `path` - Path to the FictionBook file
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+Future<ParsedBook> parseFb2({required String path, int? cancelToken}) => RustLib
+    .instance
+    .api
+    .crateApiFb2ParseFb2(path: path, cancelToken: cancelToken);
+
+/// Extract an embedded image by the href used in chapter HTML or
+/// `cover_href`.
+///
+/// # Arguments
+/// * `path` - Path to the FictionBook file
+/// * `href` - Binary id, e.g. `cover.jpg`
+Future<Uint8List> extractFb2Resource({
+  required String path,
+  required String href,
+}) =>
+    RustLib.instance.api.crateApiFb2ExtractFb2Resource(path: path, href: href);
@@ -0,0 +1,93 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `atom_link`, `decode`, `finish`, `numeric_offset`, `parse_date`, `parse`, `resolve`, `timestamp`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `PendingEntry`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+/// Fetch and parse a feed.
+///
+/// # Arguments
+/// * `url` - Feed URL
+Future<Feed> fetchFeed({required String url}) =>
+    RustLib.instance.api.crateApiFeedFetchFeed(url: url);
+
+/// Parse a feed that was fetched some other way.
+///
+/// # Arguments
+/// * `data` - Feed document in any charset
+/// * `url` - URL the feed came from, for resolving relative links
+Future<Feed> parseFeed({required List<int> data, required String url}) =>
+    RustLib.instance.api.crateApiFeedParseFeed(data: data, url: url);
+
+class Feed {
+  final String title;
+  /// Website the feed belongs to.
+  final String? link;
+  /// Entries in feed order, usually newest first.
+  final List<FeedEntry> entries;
+
+  const Feed({required this.title, this.link, required this.entries});
+
+  @override
+  int get hashCode => title.hashCode ^ link.hashCode ^ entries.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Feed &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          link == other.link &&
+          entries == other.entries;
+}
+
+class FeedEntry {
+  /// `guid` or `id`, else the link; stable across fetches.
+  final String id;
+  final String title;
+  final String? link;
+  /// Publication time, or the last update when the feed has none, in
+  /// seconds since the Unix epoch.
+  final PlatformInt64? published;
+  /// Plain-text summary.
+  final String? summary;
+  final String? author;
+
+  const FeedEntry({
+    required this.id,
+    required this.title,
+    this.link,
+    this.published,
+    this.summary,
+    this.author,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      title.hashCode ^
+      link.hashCode ^
+      published.hashCode ^
+      summary.hashCode ^
+      author.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FeedEntry &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          title == other.title &&
+          link == other.link &&
+          published == other.published &&
+          summary == other.summary &&
+          author == other.author;
+}
@@ -0,0 +1,47 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `decode`, `encode`, `fingerprint`, `fnv1a`, `simhash`, `similarity`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+/// Fingerprint a chapter's text.
+Future<ChapterFingerprint> fingerprintChapter({required String text}) =>
+    RustLib.instance.api.crateApiFingerprintFingerprintChapter(text: text);
+
+/// Estimate how similar two fingerprinted texts are, from 1 for equal text
+/// to about 0 for unrelated text.
+Future<double> fingerprintSimilarity({
+  required ChapterFingerprint a,
+  required ChapterFingerprint b,
+}) => RustLib.instance.api.crateApiFingerprintFingerprintSimilarity(a: a, b: b);
+
+class ChapterFingerprint {
+  /// BLAKE3 of the normalized text, in hex.
+  final String hash;
+  final BigInt simhash;
+  /// Characters of the normalized text, whitespace excluded.
+  final int chars;
+
+  const ChapterFingerprint({
+    required this.hash,
+    required this.simhash,
+    required this.chars,
+  });
+
+  @override
+  int get hashCode => hash.hashCode ^ simhash.hashCode ^ chars.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChapterFingerprint &&
+          runtimeType == other.runtimeType &&
+          hash == other.hash &&
+          simhash == other.simhash &&
+          chars == other.chars;
+}
@@ -0,0 +1,87 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `bundle_path`, `extend`, `font_hash`, `initialized`, `lock`, `read_codepoints`, `remove`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// Enable font bundles. Calling this again switches to the new directory.
+///
+/// # Arguments
+/// * `bundle_dir` - Directory owned by the bundles; created if missing
+Future<void> initFontBundles({required String bundleDir}) => RustLib
+    .instance
+    .api
+    .crateApiFontBundleInitFontBundles(bundleDir: bundleDir);
+
+/// Add the characters of newly read text to a book's bundle, rebuilding
+/// its subset if any are new.
+///
+/// # Arguments
+/// * `book_id` - Stable identifier of the book
+/// * `font_path` - TTF, OTF, WOFF or WOFF2 file of the full font
+/// * `text` - Text of the chapter being read
+Future<FontBundle> extendFontBundle({
+  required String bookId,
+  required String fontPath,
+  required String text,
+}) => RustLib.instance.api.crateApiFontBundleExtendFontBundle(
+  bookId: bookId,
+  fontPath: fontPath,
+  text: text,
+);
+
+/// The cumulative subset of a book's bundle, ready for embedding, or
+/// `None` if nothing was added for this book and font yet.
+///
+/// # Arguments
+/// * `book_id` - Stable identifier of the book
+/// * `font_path` - The font file passed to `extend_font_bundle`
+Future<Uint8List?> readFontBundle({
+  required String bookId,
+  required String fontPath,
+}) => RustLib.instance.api.crateApiFontBundleReadFontBundle(
+  bookId: bookId,
+  fontPath: fontPath,
+);
+
+/// Delete every bundle of a book and return how many fonts it had.
+///
+/// # Arguments
+/// * `book_id` - Stable identifier of the book
+Future<int> removeFontBundles({required String bookId}) =>
+    RustLib.instance.api.crateApiFontBundleRemoveFontBundles(bookId: bookId);
+
+/// A bundle after `extend_font_bundle`.
+class FontBundle {
+  /// Code points recorded for the book, besides the basic Latin and
+  /// punctuation every subset keeps.
+  final int codepoints;
+  /// Code points the call added; 0 when the subset was reused.
+  final int added;
+  /// Size of the subset TTF.
+  final int bytes;
+
+  const FontBundle({
+    required this.codepoints,
+    required this.added,
+    required this.bytes,
+  });
+
+  @override
+  int get hashCode => codepoints.hashCode ^ added.hashCode ^ bytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FontBundle &&
+          runtimeType == other.runtimeType &&
+          codepoints == other.codepoints &&
+          added == other.added &&
+          bytes == other.bytes;
+}
@@ -0,0 +1,157 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `cached_path`, `clear`, `current`, `entries`, `entry_path`, `evict`, `get_or_convert`, `get`, `id_path`, `initialized`, `list`, `load`, `lock`, `new`, `put`, `remove`, `save`, `stats`, `touch`, `unlink`, `update`, `with_index`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CacheIndex`, `EntryInfo`, `FontCache`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `default`
+
+/// Enable the persistent font cache.
+///
+/// Once enabled, `convert_woff2_to_ttf` (and the functions built on it)
+/// return cached TTFs for WOFF2 data it has converted before. Calling this
+/// again switches to the new directory or size cap.
+///
+/// # Arguments
+/// * `cache_dir` - Directory owned by the cache; created if missing
+/// * `max_bytes` - Total size of cached TTFs to keep
+Future<void> initFontCache({required String cacheDir, required int maxBytes}) =>
+    RustLib.instance.api.crateApiFontCacheInitFontCache(
+      cacheDir: cacheDir,
+      maxBytes: maxBytes,
+    );
+
+/// Report the number and total size of cached fonts.
+Future<FontCacheStats> fontCacheStats() =>
+    RustLib.instance.api.crateApiFontCacheFontCacheStats();
+
+/// Delete every cached font. The cache stays enabled.
+Future<void> clearFontCache() =>
+    RustLib.instance.api.crateApiFontCacheClearFontCache();
+
+/// List cached fonts, most recently used first.
+Future<List<FontCacheEntry>> listFontCacheEntries() =>
+    RustLib.instance.api.crateApiFontCacheListFontCacheEntries();
+
+/// The cache ID of a font, from the bytes it was converted from.
+///
+/// # Arguments
+/// * `source_data` - Font bytes as passed to `convert_woff2_to_ttf`
+Future<String> fontCacheId({required List<int> sourceData}) =>
+    RustLib.instance.api.crateApiFontCacheFontCacheId(sourceData: sourceData);
+
+/// Record that a book uses a cached font, for `list_font_cache_entries`.
+/// Returns false if the font is not cached.
+///
+/// # Arguments
+/// * `id` - ID from `font_cache_id`
+/// * `book_id` - Library ID of the book
+Future<bool> linkFontCacheBook({required String id, required String bookId}) =>
+    RustLib.instance.api.crateApiFontCacheLinkFontCacheBook(
+      id: id,
+      bookId: bookId,
+    );
+
+/// Forget a book in every cached font, such as when it is deleted, and
+/// return how many fonts it was linked to.
+///
+/// # Arguments
+/// * `book_id` - Library ID of the book
+Future<int> unlinkFontCacheBook({required String bookId}) =>
+    RustLib.instance.api.crateApiFontCacheUnlinkFontCacheBook(bookId: bookId);
+
+/// Pin or unpin a cached font. Returns false if the font is not cached.
+///
+/// # Arguments
+/// * `id` - ID from `font_cache_id` or `list_font_cache_entries`
+/// * `pinned` - Whether eviction must keep the font
+Future<bool> pinFontCacheEntry({required String id, required bool pinned}) =>
+    RustLib.instance.api.crateApiFontCachePinFontCacheEntry(
+      id: id,
+      pinned: pinned,
+    );
+
+/// Delete one cached font, pinned or not. Returns false if it was not
+/// cached.
+///
+/// # Arguments
+/// * `id` - ID from `font_cache_id` or `list_font_cache_entries`
+Future<bool> evictFontCacheEntry({required String id}) =>
+    RustLib.instance.api.crateApiFontCacheEvictFontCacheEntry(id: id);
+
+/// One cached font, for storage management.
+class FontCacheEntry {
+  /// Stable ID; see `font_cache_id`.
+  final String id;
+  /// Family name read from the font when it was cached.
+  final String? familyName;
+  final int sizeBytes;
+  /// Seconds since the Unix epoch.
+  final PlatformInt64 lastUsed;
+  /// Books linked with `link_font_cache_book`.
+  final List<String> bookIds;
+  /// Pinned entries are never evicted.
+  final bool pinned;
+
+  const FontCacheEntry({
+    required this.id,
+    this.familyName,
+    required this.sizeBytes,
+    required this.lastUsed,
+    required this.bookIds,
+    required this.pinned,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      familyName.hashCode ^
+      sizeBytes.hashCode ^
+      lastUsed.hashCode ^
+      bookIds.hashCode ^
+      pinned.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FontCacheEntry &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          familyName == other.familyName &&
+          sizeBytes == other.sizeBytes &&
+          lastUsed == other.lastUsed &&
+          bookIds == other.bookIds &&
+          pinned == other.pinned;
+}
+
+/// Current size of the font cache.
+class FontCacheStats {
+  final int entryCount;
+  final int totalBytes;
+  final int maxBytes;
+
+  const FontCacheStats({
+    required this.entryCount,
+    required this.totalBytes,
+    required this.maxBytes,
+  });
+
+  @override
+  int get hashCode =>
+      entryCount.hashCode ^ totalBytes.hashCode ^ maxBytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FontCacheStats &&
+          runtimeType == other.runtimeType &&
+          entryCount == other.entryCount &&
+          totalBytes == other.totalBytes &&
+          maxBytes == other.maxBytes;
+}
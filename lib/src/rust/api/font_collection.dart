@@ -0,0 +1,72 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `extract_face`, `face_count`, `is_collection`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// List all faces in a TTC/OTC font collection.
+///
+/// Typographic family/subfamily names (IDs 16/17) are preferred over the
+/// legacy four-style names so that e.g. "Songti SC Light" groups under
+/// "Songti SC".
+Future<List<CollectionFace>> listCollectionFaces({
+  required List<int> ttcData,
+}) => RustLib.instance.api.crateApiFontCollectionListCollectionFaces(
+  ttcData: ttcData,
+);
+
+/// Extract one face of a TTC/OTC collection as a standalone TTF/OTF.
+///
+/// # Arguments
+/// * `ttc_data` - Raw collection bytes
+/// * `index` - Face index as reported by `list_collection_faces`
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` - Standalone font bytes ready for FontLoader
+/// * `Err(_)` - If the collection is malformed or `index` is out of range
+Future<Uint8List> extractCollectionFace({
+  required List<int> ttcData,
+  required int index,
+}) => RustLib.instance.api.crateApiFontCollectionExtractCollectionFace(
+  ttcData: ttcData,
+  index: index,
+);
+
+/// A face contained in a font collection.
+class CollectionFace {
+  /// Index to pass to `extract_collection_face`.
+  final int index;
+  final String familyName;
+  final String styleName;
+  final String? postscriptName;
+
+  const CollectionFace({
+    required this.index,
+    required this.familyName,
+    required this.styleName,
+    this.postscriptName,
+  });
+
+  @override
+  int get hashCode =>
+      index.hashCode ^
+      familyName.hashCode ^
+      styleName.hashCode ^
+      postscriptName.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CollectionFace &&
+          runtimeType == other.runtimeType &&
+          index == other.index &&
+          familyName == other.familyName &&
+          styleName == other.styleName &&
+          postscriptName == other.postscriptName;
+}
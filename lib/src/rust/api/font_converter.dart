@@ -4,18 +4,28 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
+// These functions are ignored because they are not marked as `pub`: `encode_simple_glyph`, `end_contour`, `finish`, `for_each_chunk`, `is_sfnt`, `named_instances`, `push`, `width_class`, `write_atomically`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ContourCollector`, `ContourPoint`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `close`, `curve_to`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `line_to`, `move_to`, `quad_to`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
 /// Convert WOFF2 bytes to TTF bytes.
 ///
-/// This is a pure in-memory operation - no file I/O is performed.
+/// This is a pure in-memory operation unless `init_font_cache` has been
+/// called, in which case previously converted fonts are read from the cache.
+///
+/// The decoded font is passed through `repair_ttf`, so fonts with stale
+/// checksums or truncated tables are fixed before they reach FontLoader.
 ///
 /// # Arguments
 /// * `woff2_data` - Raw WOFF2 font bytes (e.g., downloaded from server)
 ///
 /// # Returns
 /// * `Ok(Vec<u8>)` - TTF font bytes ready for FontLoader
-/// * `Err(_)` - If WOFF2 decoding fails
+/// * `Err(_)` - If WOFF2 decoding fails or the decoded font is beyond repair
 ///
 /// # Example (Dart side)
 /// ```dart
@@ -29,6 +39,164 @@ Future<Uint8List> convertWoff2ToTtf({required List<int> woff2Data}) => RustLib
     .api
     .crateApiFontConverterConvertWoff2ToTtf(woff2Data: woff2Data);
 
+/// Convert WOFF2 bytes to a TTF file on disk.
+///
+/// flutter_rust_bridge 2's SSE codec has no `ZeroCopyBuffer`, so returning
+/// the TTF always copies it into Dart. Writing it straight to the font cache
+/// keeps a single copy of a 20 MB CJK font alive instead of two. The file is
+/// written next to `ttf_path` and renamed into place, so an interrupted write
+/// never leaves a truncated cache entry behind.
+///
+/// # Arguments
+/// * `woff2_data` - Raw WOFF2 font bytes
+/// * `ttf_path` - Destination of the TTF file; the parent directory must exist
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+///
+/// # Returns
+/// * `Ok(u32)` - Size of the written TTF in bytes
+/// * `Err(_)` - If WOFF2 decoding fails or the file cannot be written
+Future<int> convertWoff2ToTtfFile({
+  required List<int> woff2Data,
+  required String ttfPath,
+  int? cancelToken,
+}) => RustLib.instance.api.crateApiFontConverterConvertWoff2ToTtfFile(
+  woff2Data: woff2Data,
+  ttfPath: ttfPath,
+  cancelToken: cancelToken,
+);
+
+/// Convert several WOFF2 fonts to TTF in parallel, such as the weights of a
+/// font pack. A font that fails to convert does not fail the others.
+///
+/// # Arguments
+/// * `fonts` - WOFF2 fonts with names to tell the results apart
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+///
+/// # Returns
+/// * `Ok(Vec<ConversionResult>)` - One result per font, in the order given
+/// * `Err(_)` - Only if the call was cancelled
+Future<List<ConversionResult>> convertWoff2Batch({
+  required List<NamedFont> fonts,
+  int? cancelToken,
+}) => RustLib.instance.api.crateApiFontConverterConvertWoff2Batch(
+  fonts: fonts,
+  cancelToken: cancelToken,
+);
+
+/// Convert WOFF2 bytes to TTF and stream the result in chunks.
+///
+/// Dart can append each chunk to a file and update a progress indicator as
+/// it arrives, instead of receiving the whole font as a single buffer.
+///
+/// # Arguments
+/// * `woff2_data` - Raw WOFF2 font bytes
+/// * `chunk_size` - Maximum chunk size in bytes, 1 MiB if `None`
+/// * `sink` - Receives the chunks in order; the stream closes after the last one
+///
+/// # Example (Dart side)
+/// ```dart
+/// final out = File(ttfPath).openWrite();
+/// await for (final chunk in convertWoff2ToTtfChunked(woff2Data: woff2Bytes)) {
+///   out.add(chunk.data);
+///   onProgress((chunk.offset + chunk.data.length) / chunk.totalLen);
+/// }
+/// await out.close();
+/// ```
+Stream<TtfChunk> convertWoff2ToTtfChunked({
+  required List<int> woff2Data,
+  int? chunkSize,
+}) => RustLib.instance.api.crateApiFontConverterConvertWoff2ToTtfChunked(
+  woff2Data: woff2Data,
+  chunkSize: chunkSize,
+);
+
+/// Compress TTF/OTF bytes to WOFF2.
+///
+/// This is the reverse of `convert_woff2_to_ttf`, intended for storing fonts
+/// compressed in the on-disk cache and only decompressing at load time.
+///
+/// # Arguments
+/// * `ttf_data` - Raw TTF/OTF font bytes
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` - WOFF2 font bytes
+/// * `Err(_)` - If the input is not an SFNT font or encoding fails
+Future<Uint8List> compressTtfToWoff2({required List<int> ttfData}) => RustLib
+    .instance
+    .api
+    .crateApiFontConverterCompressTtfToWoff2(ttfData: ttfData);
+
+/// Convert WOFF (version 1) bytes to TTF bytes.
+///
+/// WOFF 1.0 stores each SFNT table individually, optionally zlib-compressed.
+/// Tables are inflated, reassembled into a regular SFNT container and
+/// checked with `repair_ttf`.
+///
+/// # Arguments
+/// * `woff_data` - Raw WOFF font bytes
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` - TTF font bytes ready for FontLoader
+/// * `Err(_)` - If the header or any table is malformed
+Future<Uint8List> convertWoffToTtf({required List<int> woffData}) => RustLib
+    .instance
+    .api
+    .crateApiFontConverterConvertWoffToTtf(woffData: woffData);
+
+/// Convert any supported web font (WOFF or WOFF2) to TTF bytes.
+///
+/// The format is detected from the file signature. Plain TTF/OTF data is
+/// returned unchanged and collections yield their first face, so callers can
+/// feed every downloaded or imported font through a single FontLoader path.
+Future<Uint8List> convertFontToTtf({required List<int> fontData}) => RustLib
+    .instance
+    .api
+    .crateApiFontConverterConvertFontToTtf(fontData: fontData);
+
+/// Inspect a font's real family name, weight, style and variation axes.
+///
+/// Accepts the same inputs as `convert_font_to_ttf` (TTF/OTF, WOFF, WOFF2 and
+/// collections, which report their first face), so metadata can be read
+/// straight from downloaded bytes before registration.
+Future<FontInfo> inspectFont({required List<int> fontData}) =>
+    RustLib.instance.api.crateApiFontConverterInspectFont(fontData: fontData);
+
+/// Instantiate a static font from a TrueType variable font.
+///
+/// Every glyph is re-outlined at the requested axis location and written as
+/// a simple (non-composite) glyph, horizontal advances are taken from `HVAR`
+/// when present, and the variation tables are dropped so Flutter sees an
+/// ordinary static font. Axes not mentioned in `axes` keep their defaults;
+/// pass a named instance's coordinates from `inspect_font` to select it.
+///
+/// # Arguments
+/// * `ttf_data` - TrueType-flavored variable font bytes
+/// * `axes` - Axis values in user space, e.g. `wght = 700`
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` - Static TTF bytes ready for FontLoader
+/// * `Err(_)` - If the font is not variable, uses CFF2 outlines, or an axis tag is unknown
+Future<Uint8List> instantiateVariableFont({
+  required List<int> ttfData,
+  required List<AxisValue> axes,
+}) => RustLib.instance.api.crateApiFontConverterInstantiateVariableFont(
+  ttfData: ttfData,
+  axes: axes,
+);
+
+/// Check which code points in `text` the font cannot render.
+///
+/// Whitespace and control characters are ignored since they never need a
+/// glyph. The app can use the report to decide whether to fall back to the
+/// system font for a chapter.
+Future<CoverageReport> checkCoverage({
+  required List<int> fontData,
+  required String text,
+}) => RustLib.instance.api.crateApiFontConverterCheckCoverage(
+  fontData: fontData,
+  text: text,
+);
+
 /// Find code points that map to zero-advance, empty glyphs in a TTF font.
 ///
 /// These are effectively invisible placeholders in the obfuscation font.
@@ -37,3 +205,261 @@ Future<Uint32List> extractInvisibleCodepoints({required List<int> ttfData}) =>
     RustLib.instance.api.crateApiFontConverterExtractInvisibleCodepoints(
       ttfData: ttfData,
     );
+
+/// A user-space value for one variation axis.
+class AxisValue {
+  /// Four-character axis tag, e.g. `wght`.
+  final String tag;
+  final double value;
+
+  const AxisValue({required this.tag, required this.value});
+
+  @override
+  int get hashCode => tag.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AxisValue &&
+          runtimeType == other.runtimeType &&
+          tag == other.tag &&
+          value == other.value;
+}
+
+/// The outcome of converting one font of a batch.
+class ConversionResult {
+  final String name;
+  /// TTF bytes, if the conversion succeeded.
+  final Uint8List? ttf;
+  /// Why the conversion failed.
+  final String? error;
+
+  const ConversionResult({required this.name, this.ttf, this.error});
+
+  @override
+  int get hashCode => name.hashCode ^ ttf.hashCode ^ error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ConversionResult &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          ttf == other.ttf &&
+          error == other.error;
+}
+
+/// Result of checking a font's glyph coverage against some text.
+class CoverageReport {
+  /// Number of distinct code points in the text that need a glyph.
+  final int totalCodepoints;
+  /// Distinct code points the font has no cmap entry for, in order of first
+  /// appearance.
+  final Uint32List missingCodepoints;
+  /// Fraction of `total_codepoints` the font covers, in `0.0..=1.0`.
+  final double coverage;
+
+  const CoverageReport({
+    required this.totalCodepoints,
+    required this.missingCodepoints,
+    required this.coverage,
+  });
+
+  @override
+  int get hashCode =>
+      totalCodepoints.hashCode ^ missingCodepoints.hashCode ^ coverage.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CoverageReport &&
+          runtimeType == other.runtimeType &&
+          totalCodepoints == other.totalCodepoints &&
+          missingCodepoints == other.missingCodepoints &&
+          coverage == other.coverage;
+}
+
+/// A single variation axis of a variable font.
+class FontAxis {
+  /// Four-character axis tag, e.g. `wght`.
+  final String tag;
+  final String? name;
+  final double minValue;
+  final double defaultValue;
+  final double maxValue;
+  final bool hidden;
+
+  const FontAxis({
+    required this.tag,
+    this.name,
+    required this.minValue,
+    required this.defaultValue,
+    required this.maxValue,
+    required this.hidden,
+  });
+
+  @override
+  int get hashCode =>
+      tag.hashCode ^
+      name.hashCode ^
+      minValue.hashCode ^
+      defaultValue.hashCode ^
+      maxValue.hashCode ^
+      hidden.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FontAxis &&
+          runtimeType == other.runtimeType &&
+          tag == other.tag &&
+          name == other.name &&
+          minValue == other.minValue &&
+          defaultValue == other.defaultValue &&
+          maxValue == other.maxValue &&
+          hidden == other.hidden;
+}
+
+/// Metadata describing a font face, read from its `name`, `OS/2` and `fvar`
+/// tables.
+class FontInfo {
+  /// Typographic family name, falling back to the legacy family name.
+  final String familyName;
+  /// Typographic subfamily (style) name, falling back to the legacy one.
+  final String styleName;
+  final String? fullName;
+  final String? postscriptName;
+  /// `OS/2.usWeightClass` (100–900).
+  final int weight;
+  final bool isItalic;
+  final bool isMonospaced;
+  final bool isVariable;
+  /// Variation axes; empty for static fonts.
+  final List<FontAxis> axes;
+  /// Named instances declared in `fvar` (e.g. "Bold", "Condensed Light").
+  final List<NamedInstance> namedInstances;
+  final int unitsPerEm;
+  final int glyphCount;
+
+  const FontInfo({
+    required this.familyName,
+    required this.styleName,
+    this.fullName,
+    this.postscriptName,
+    required this.weight,
+    required this.isItalic,
+    required this.isMonospaced,
+    required this.isVariable,
+    required this.axes,
+    required this.namedInstances,
+    required this.unitsPerEm,
+    required this.glyphCount,
+  });
+
+  @override
+  int get hashCode =>
+      familyName.hashCode ^
+      styleName.hashCode ^
+      fullName.hashCode ^
+      postscriptName.hashCode ^
+      weight.hashCode ^
+      isItalic.hashCode ^
+      isMonospaced.hashCode ^
+      isVariable.hashCode ^
+      axes.hashCode ^
+      namedInstances.hashCode ^
+      unitsPerEm.hashCode ^
+      glyphCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FontInfo &&
+          runtimeType == other.runtimeType &&
+          familyName == other.familyName &&
+          styleName == other.styleName &&
+          fullName == other.fullName &&
+          postscriptName == other.postscriptName &&
+          weight == other.weight &&
+          isItalic == other.isItalic &&
+          isMonospaced == other.isMonospaced &&
+          isVariable == other.isVariable &&
+          axes == other.axes &&
+          namedInstances == other.namedInstances &&
+          unitsPerEm == other.unitsPerEm &&
+          glyphCount == other.glyphCount;
+}
+
+/// A font passed to `convert_woff2_batch`.
+class NamedFont {
+  /// Identifies the font in the results, e.g. its file name.
+  final String name;
+  final Uint8List data;
+
+  const NamedFont({required this.name, required this.data});
+
+  @override
+  int get hashCode => name.hashCode ^ data.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NamedFont &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          data == other.data;
+}
+
+/// A named instance of a variable font.
+class NamedInstance {
+  final String? name;
+  final String? postscriptName;
+  /// Axis values selecting this instance; pass to `instantiate_variable_font`.
+  final List<AxisValue> coordinates;
+
+  const NamedInstance({
+    this.name,
+    this.postscriptName,
+    required this.coordinates,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^ postscriptName.hashCode ^ coordinates.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NamedInstance &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          postscriptName == other.postscriptName &&
+          coordinates == other.coordinates;
+}
+
+/// A piece of the TTF produced by `convert_woff2_to_ttf_chunked`.
+class TtfChunk {
+  /// Position of `data` within the TTF.
+  final int offset;
+  /// Size of the whole TTF, for progress reporting.
+  final int totalLen;
+  final Uint8List data;
+
+  const TtfChunk({
+    required this.offset,
+    required this.totalLen,
+    required this.data,
+  });
+
+  @override
+  int get hashCode => offset.hashCode ^ totalLen.hashCode ^ data.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TtfChunk &&
+          runtimeType == other.runtimeType &&
+          offset == other.offset &&
+          totalLen == other.totalLen &&
+          data == other.data;
+}
@@ -0,0 +1,67 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `draw`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// Draw `sample_text` in a font.
+///
+/// # Arguments
+/// * `font_bytes` - TTF, OTF, WOFF or WOFF2 data; the first face of a collection
+/// * `sample_text` - Text to draw, with `\n` between lines; a default
+///   Chinese and Latin sample if empty
+/// * `px_size` - Font size in pixels, up to 256
+Future<FontPreview> render({
+  required List<int> fontBytes,
+  required String sampleText,
+  required double pxSize,
+}) => RustLib.instance.api.crateApiFontPreviewRender(
+  fontBytes: fontBytes,
+  sampleText: sampleText,
+  pxSize: pxSize,
+);
+
+class FontPreview {
+  /// RGBA pixels, row by row.
+  final Uint8List rgba;
+  /// The same image as PNG.
+  final Uint8List png;
+  final int width;
+  final int height;
+  /// Characters of the sample the font has no glyph for, whitespace
+  /// aside. They are drawn as the font's missing-glyph box.
+  final int missingChars;
+
+  const FontPreview({
+    required this.rgba,
+    required this.png,
+    required this.width,
+    required this.height,
+    required this.missingChars,
+  });
+
+  @override
+  int get hashCode =>
+      rgba.hashCode ^
+      png.hashCode ^
+      width.hashCode ^
+      height.hashCode ^
+      missingChars.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FontPreview &&
+          runtimeType == other.runtimeType &&
+          rgba == other.rgba &&
+          png == other.png &&
+          width == other.width &&
+          height == other.height &&
+          missingChars == other.missingChars;
+}
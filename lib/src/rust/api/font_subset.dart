@@ -0,0 +1,30 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `basic`, `build_cmap`, `build_format12`, `build_format4`, `glyph_closure`, `is_basic`, `subset_codepoints`
+
+/// Subset a TrueType font to the glyphs needed for `text`.
+///
+/// Basic Latin and common CJK punctuation are always retained so UI strings
+/// around the chapter (titles, page numbers) still render with the font.
+///
+/// # Arguments
+/// * `ttf_data` - TrueType-flavored font bytes (e.g. output of `convert_woff2_to_ttf`)
+/// * `text` - Chapter text whose characters must remain renderable
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` - Subset TTF bytes ready for FontLoader
+/// * `Err(_)` - If the font is malformed or uses CFF outlines
+Future<Uint8List> subsetFont({
+  required List<int> ttfData,
+  required String text,
+}) => RustLib.instance.api.crateApiFontSubsetSubsetFont(
+  ttfData: ttfData,
+  text: text,
+);
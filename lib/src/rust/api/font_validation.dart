@@ -0,0 +1,105 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `analyze`, `check_glyph_locations`, `check_metrics`, `finish`, `is_repairable`, `repair`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Analysis`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+/// Check a TTF/OTF font's structure without modifying it.
+///
+/// Table bounds, checksums, `head.checkSumAdjustment`, `loca` and `hmtx`
+/// consistency, and the presence of required tables are verified.
+Future<ValidationReport> validateTtf({required List<int> ttfData}) =>
+    RustLib.instance.api.crateApiFontValidationValidateTtf(ttfData: ttfData);
+
+/// Repair a TTF/OTF font so it can be safely registered.
+///
+/// Checksums are recomputed, truncated or duplicate tables are dropped,
+/// broken `loca` entries become empty glyphs and short `hmtx` tables are
+/// padded. Fonts without issues are returned unchanged.
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` - Repaired (or untouched) font bytes
+/// * `Err(_)` - If the font is missing required tables or has no readable table directory
+Future<Uint8List> repairTtf({required List<int> ttfData}) =>
+    RustLib.instance.api.crateApiFontValidationRepairTtf(ttfData: ttfData);
+
+/// A single problem found in a font.
+class ValidationIssue {
+  final ValidationIssueKind kind;
+  /// Tag of the affected table, if the issue is table-specific.
+  final String? table;
+  final String message;
+
+  const ValidationIssue({
+    required this.kind,
+    this.table,
+    required this.message,
+  });
+
+  @override
+  int get hashCode => kind.hashCode ^ table.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ValidationIssue &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          table == other.table &&
+          message == other.message;
+}
+
+/// Kind of problem found while validating a font.
+enum ValidationIssueKind {
+  /// A table's stored checksum does not match its contents.
+  checksumMismatch,
+  /// `head.checkSumAdjustment` is wrong for the font as a whole.
+  checksumAdjustmentMismatch,
+  /// A second record for an already-seen table tag.
+  duplicateTable,
+  /// A table extends past the end of the file and was dropped.
+  corruptTable,
+  /// `loca` points outside `glyf` or goes backwards.
+  invalidGlyphLocations,
+  /// `hmtx` is shorter than `hhea`/`maxp` say it should be.
+  truncatedMetrics,
+  /// A required table is missing or unusable. Not repairable.
+  missingTable,
+  /// The table directory itself is unreadable. Not repairable.
+  invalidHeader,
+}
+
+/// Outcome of `validate_ttf`.
+class ValidationReport {
+  /// No issues were found.
+  final bool isValid;
+  /// Every issue found can be fixed by `repair_ttf`.
+  final bool isRepairable;
+  final List<ValidationIssue> issues;
+
+  const ValidationReport({
+    required this.isValid,
+    required this.isRepairable,
+    required this.issues,
+  });
+
+  @override
+  int get hashCode =>
+      isValid.hashCode ^ isRepairable.hashCode ^ issues.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ValidationReport &&
+          runtimeType == other.runtimeType &&
+          isValid == other.isValid &&
+          isRepairable == other.isRepairable &&
+          issues == other.issues;
+}
@@ -0,0 +1,106 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `accepts`, `clear`, `conditional`, `current`, `decode`, `discard`, `encode`, `entries`, `entry_path`, `evict`, `get`, `is_fresh`, `key`, `lock`, `new`, `new`, `now`, `parse_http_date`, `policy`, `put`, `read`, `response`, `revalidated`, `stats`, `store`, `touch`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CachedResponse`, `FetchError`, `HttpCache`, `Lookup`, `Meta`, `Stored`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`
+
+/// Enable the persistent HTTP cache. Calling this again switches to the new
+/// directory or size cap.
+///
+/// # Arguments
+/// * `cache_dir` - Directory owned by the cache; created if missing
+/// * `max_bytes` - Total size of cached responses to keep
+Future<void> initHttpCache({required String cacheDir, required int maxBytes}) =>
+    RustLib.instance.api.crateApiHttpCacheInitHttpCache(
+      cacheDir: cacheDir,
+      maxBytes: maxBytes,
+    );
+
+/// Report the number and total size of cached responses.
+Future<HttpCacheStats> httpCacheStats() =>
+    RustLib.instance.api.crateApiHttpCacheHttpCacheStats();
+
+/// Look up the cached response for a URL fetched without credentials.
+Future<HttpCacheEntry?> httpCacheEntry({required String url}) =>
+    RustLib.instance.api.crateApiHttpCacheHttpCacheEntry(url: url);
+
+/// Remove the cached response for a URL. Returns false if there was none.
+Future<bool> removeHttpCacheEntry({required String url}) =>
+    RustLib.instance.api.crateApiHttpCacheRemoveHttpCacheEntry(url: url);
+
+/// Delete every cached response. The cache stays enabled.
+Future<void> clearHttpCache() =>
+    RustLib.instance.api.crateApiHttpCacheClearHttpCache();
+
+/// A cached response, as reported to Dart.
+class HttpCacheEntry {
+  /// URL the response was finally served from, after redirects.
+  final String url;
+  final String? contentType;
+  final int size;
+  /// Whether the response can be used without revalidating it.
+  final bool fresh;
+  /// When the response was stored or last revalidated, in seconds since
+  /// the Unix epoch.
+  final PlatformInt64 storedAt;
+
+  const HttpCacheEntry({
+    required this.url,
+    this.contentType,
+    required this.size,
+    required this.fresh,
+    required this.storedAt,
+  });
+
+  @override
+  int get hashCode =>
+      url.hashCode ^
+      contentType.hashCode ^
+      size.hashCode ^
+      fresh.hashCode ^
+      storedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HttpCacheEntry &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          contentType == other.contentType &&
+          size == other.size &&
+          fresh == other.fresh &&
+          storedAt == other.storedAt;
+}
+
+/// Current size of the HTTP cache.
+class HttpCacheStats {
+  final int entryCount;
+  final int totalBytes;
+  final int maxBytes;
+
+  const HttpCacheStats({
+    required this.entryCount,
+    required this.totalBytes,
+    required this.maxBytes,
+  });
+
+  @override
+  int get hashCode =>
+      entryCount.hashCode ^ totalBytes.hashCode ^ maxBytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HttpCacheStats &&
+          runtimeType == other.runtimeType &&
+          entryCount == other.entryCount &&
+          totalBytes == other.totalBytes &&
+          maxBytes == other.maxBytes;
+}
@@ -0,0 +1,70 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `add_exceptions`, `build`, `bundled`, `for_each_word`, `hyphenate`, `hyphenator`, `insert`, `language_key`, `lock`, `lowercase`, `parse`, `tex_group`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Hyphenator`, `TrieNode`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+/// Load hyphenation patterns for a language, replacing any loaded before.
+/// Returns the number of patterns read.
+///
+/// # Arguments
+/// * `language` - BCP 47 tag such as `en-US`, `de` or `fr`; lookups for
+///   `en-GB` fall back to `en`
+/// * `patterns` - Contents of a TeX pattern file
+/// * `exceptions` - Extra hyphenated words such as `ta-ble`, one per line
+///   or space separated, overriding the patterns
+/// * `left_min` - Fewest letters before a hyphen; 2 by default
+/// * `right_min` - Fewest letters after a hyphen; 3 for English, otherwise
+///   2 by default
+Future<int> loadHyphenationPatterns({
+  required String language,
+  required String patterns,
+  String? exceptions,
+  int? leftMin,
+  int? rightMin,
+}) => RustLib.instance.api.crateApiHyphenationLoadHyphenationPatterns(
+  language: language,
+  patterns: patterns,
+  exceptions: exceptions,
+  leftMin: leftMin,
+  rightMin: rightMin,
+);
+
+/// Languages with patterns loaded or built in, as normalised lowercase
+/// tags.
+Future<List<String>> hyphenationLanguages() =>
+    RustLib.instance.api.crateApiHyphenationHyphenationLanguages();
+
+/// Where soft hyphens may go in `text`: UTF-16 offsets of the characters
+/// a hyphen may be placed before, in increasing order.
+///
+/// # Arguments
+/// * `language` - Language whose patterns were loaded
+/// * `text` - Any text; only runs of letters are hyphenated
+Future<Uint32List> hyphenationPoints({
+  required String language,
+  required String text,
+}) => RustLib.instance.api.crateApiHyphenationHyphenationPoints(
+  language: language,
+  text: text,
+);
+
+/// Copy of `text` with a soft hyphen (U+00AD) at every hyphenation point.
+///
+/// # Arguments
+/// * `language` - Language whose patterns were loaded
+/// * `text` - Any text; only runs of letters are hyphenated
+Future<String> hyphenateText({
+  required String language,
+  required String text,
+}) => RustLib.instance.api.crateApiHyphenationHyphenateText(
+  language: language,
+  text: text,
+);
@@ -0,0 +1,100 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `crop`, `decode`, `resize`, `to_jpeg`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+/// Options matching the 2:3 library grid.
+Future<CoverImageOptions> defaultCoverImageOptions() =>
+    RustLib.instance.api.crateApiImageDefaultCoverImageOptions();
+
+/// Decode, crop, resize and re-encode a cover as WebP.
+///
+/// # Arguments
+/// * `image_bytes` - Encoded image (JPEG, PNG, GIF, WebP, or AVIF with the `avif` feature)
+/// * `options` - Output size, shape and quality
+Future<ProcessedImage> processCover({
+  required List<int> imageBytes,
+  required CoverImageOptions options,
+}) => RustLib.instance.api.crateApiImageProcessCover(
+  imageBytes: imageBytes,
+  options: options,
+);
+
+class CoverImageOptions {
+  /// Largest output width in pixels; 0 for no limit.
+  final int maxWidth;
+  /// Largest output height in pixels; 0 for no limit.
+  final int maxHeight;
+  /// Width divided by height to crop to; 0 keeps the original shape.
+  final double aspectRatio;
+  /// WebP quality from 0 to 100.
+  final double quality;
+
+  const CoverImageOptions({
+    required this.maxWidth,
+    required this.maxHeight,
+    required this.aspectRatio,
+    required this.quality,
+  });
+
+  @override
+  int get hashCode =>
+      maxWidth.hashCode ^
+      maxHeight.hashCode ^
+      aspectRatio.hashCode ^
+      quality.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CoverImageOptions &&
+          runtimeType == other.runtimeType &&
+          maxWidth == other.maxWidth &&
+          maxHeight == other.maxHeight &&
+          aspectRatio == other.aspectRatio &&
+          quality == other.quality;
+}
+
+class ProcessedImage {
+  /// WebP bytes.
+  final Uint8List data;
+  final int width;
+  final int height;
+  /// Size of the decoded original.
+  final int sourceWidth;
+  final int sourceHeight;
+
+  const ProcessedImage({
+    required this.data,
+    required this.width,
+    required this.height,
+    required this.sourceWidth,
+    required this.sourceHeight,
+  });
+
+  @override
+  int get hashCode =>
+      data.hashCode ^
+      width.hashCode ^
+      height.hashCode ^
+      sourceWidth.hashCode ^
+      sourceHeight.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProcessedImage &&
+          runtimeType == other.runtimeType &&
+          data == other.data &&
+          width == other.width &&
+          height == other.height &&
+          sourceWidth == other.sourceWidth &&
+          sourceHeight == other.sourceHeight;
+}
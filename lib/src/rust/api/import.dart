@@ -0,0 +1,233 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'book.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'purify.dart';
+import 'stats.dart';
+import 'txt.dart';
+
+// These functions are ignored because they are not marked as `pub`: `fallback_parts`, `import_file`, `import`, `line_blocks`, `line_end`, `lock`, `parse_book`, `pick_encoding`, `process_parsed`, `process`, `split`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Imported`, `Line`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Options with the built-in rules, a typical reading speed and no
+/// purification.
+Future<ImportOptions> defaultImportOptions() =>
+    RustLib.instance.api.crateApiImportDefaultImportOptions();
+
+/// Import books one after another. Text files are decoded, split into
+/// chapters, and purified and counted a chapter at a time, all in
+/// parallel; other formats are read by their importer and counted. A file
+/// that cannot be imported is reported and the rest carry on.
+///
+/// # Arguments
+/// * `paths` - Books, such as those extracted by `extract_archive`; text
+///   files may be at most 50 MB, and comics are skipped
+/// * `options` - Encoding, heading, purify and statistics settings; only
+///   the statistics settings apply to formats other than text
+/// * `sink` - Receives every file's events, then a `Finished` event
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+Stream<ImportEvent> processBooks({
+  required List<String> paths,
+  required ImportOptions options,
+  int? cancelToken,
+}) => RustLib.instance.api.crateApiImportProcessBooks(
+  paths: paths,
+  options: options,
+  cancelToken: cancelToken,
+);
+
+class ImportEvent {
+  final ImportEventKind kind;
+  /// Position of the file in the list passed in.
+  final int fileIndex;
+  final String path;
+  final int done;
+  final int total;
+  /// The warning, or why the file was skipped or failed.
+  final String? message;
+  /// The imported book, on `FileImported` only.
+  final ProcessedBook? book;
+  /// On `Finished` only.
+  final ImportReport? report;
+
+  const ImportEvent({
+    required this.kind,
+    required this.fileIndex,
+    required this.path,
+    required this.done,
+    required this.total,
+    this.message,
+    this.book,
+    this.report,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      fileIndex.hashCode ^
+      path.hashCode ^
+      done.hashCode ^
+      total.hashCode ^
+      message.hashCode ^
+      book.hashCode ^
+      report.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImportEvent &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          fileIndex == other.fileIndex &&
+          path == other.path &&
+          done == other.done &&
+          total == other.total &&
+          message == other.message &&
+          book == other.book &&
+          report == other.report;
+}
+
+/// What an `ImportEvent` reports.
+enum ImportEventKind {
+  /// A file was queued. Sent for every file before any is read.
+  fileDiscovered,
+  /// `done` of `total` blocks of the file are decoded.
+  decoding,
+  /// The file's text was split into chapters.
+  splitting,
+  /// `done` of `total` chapters are purified and counted.
+  processing,
+  /// Something in the file needs a look, such as an uncertain encoding;
+  /// the import carries on.
+  warning,
+  /// The file was imported; `book` holds it.
+  fileImported,
+  /// The file was left alone, such as a comic or an empty file.
+  fileSkipped,
+  /// The file could not be imported; the rest carry on.
+  fileFailed,
+  /// Every file is done; `report` sums them up.
+  finished,
+}
+
+class ImportIssue {
+  final String path;
+  final String message;
+
+  const ImportIssue({required this.path, required this.message});
+
+  @override
+  int get hashCode => path.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImportIssue &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          message == other.message;
+}
+
+class ImportOptions {
+  /// Encoding label to decode with instead of the detected one.
+  final String? overrideEncoding;
+  /// Heading rules; `None` uses `default_txt_rules()`.
+  final List<TxtChapterRule>? headingRules;
+  /// Clean-up rules applied to every chapter of a text file; may be empty.
+  final List<PurifyRule> purifyRules;
+  /// Reading speed for the statistics, in CJK characters per minute.
+  final int charsPerMinute;
+
+  const ImportOptions({
+    this.overrideEncoding,
+    this.headingRules,
+    required this.purifyRules,
+    required this.charsPerMinute,
+  });
+
+  @override
+  int get hashCode =>
+      overrideEncoding.hashCode ^
+      headingRules.hashCode ^
+      purifyRules.hashCode ^
+      charsPerMinute.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImportOptions &&
+          runtimeType == other.runtimeType &&
+          overrideEncoding == other.overrideEncoding &&
+          headingRules == other.headingRules &&
+          purifyRules == other.purifyRules &&
+          charsPerMinute == other.charsPerMinute;
+}
+
+/// What happened to the files of one call, for a post-import summary.
+class ImportReport {
+  final int imported;
+  final List<ImportIssue> skipped;
+  final List<ImportIssue> failed;
+  /// Warnings of every file, imported or not.
+  final List<ImportIssue> warnings;
+
+  const ImportReport({
+    required this.imported,
+    required this.skipped,
+    required this.failed,
+    required this.warnings,
+  });
+
+  static Future<ImportReport> default_() =>
+      RustLib.instance.api.crateApiImportImportReportDefault();
+
+  @override
+  int get hashCode =>
+      imported.hashCode ^
+      skipped.hashCode ^
+      failed.hashCode ^
+      warnings.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImportReport &&
+          runtimeType == other.runtimeType &&
+          imported == other.imported &&
+          skipped == other.skipped &&
+          failed == other.failed &&
+          warnings == other.warnings;
+}
+
+class ProcessedBook {
+  final ParsedBook book;
+  /// Encoding label a text file was decoded from, e.g. `GBK`; empty for
+  /// other formats.
+  final String encoding;
+  /// Statistics of the purified text, per chapter and in total.
+  final BookStats stats;
+
+  const ProcessedBook({
+    required this.book,
+    required this.encoding,
+    required this.stats,
+  });
+
+  @override
+  int get hashCode => book.hashCode ^ encoding.hashCode ^ stats.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProcessedBook &&
+          runtimeType == other.runtimeType &&
+          book == other.book &&
+          encoding == other.encoding &&
+          stats == other.stats;
+}
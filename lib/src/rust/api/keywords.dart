@@ -0,0 +1,115 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `add`, `keywords`, `kind`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Counts`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+/// Extract keywords and suggest tags from a library book's cached
+/// chapters. Long books are sampled.
+///
+/// # Arguments
+/// * `book_id` - Library ID of the book
+/// * `limit` - Keywords to return at most
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+Future<BookKeywords> extractBookKeywords({
+  required String bookId,
+  required int limit,
+  int? cancelToken,
+}) => RustLib.instance.api.crateApiKeywordsExtractBookKeywords(
+  bookId: bookId,
+  limit: limit,
+  cancelToken: cancelToken,
+);
+
+/// Extract keywords and suggest tags from text.
+///
+/// # Arguments
+/// * `texts` - Chapters or other plain text
+/// * `limit` - Keywords to return at most
+Future<BookKeywords> extractKeywords({
+  required List<String> texts,
+  required int limit,
+}) => RustLib.instance.api.crateApiKeywordsExtractKeywords(
+  texts: texts,
+  limit: limit,
+);
+
+class BookKeywords {
+  /// Highest score first.
+  final List<Keyword> keywords;
+  /// Densest first.
+  final List<TagSuggestion> tags;
+
+  const BookKeywords({required this.keywords, required this.tags});
+
+  @override
+  int get hashCode => keywords.hashCode ^ tags.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BookKeywords &&
+          runtimeType == other.runtimeType &&
+          keywords == other.keywords &&
+          tags == other.tags;
+}
+
+class Keyword {
+  final String term;
+  final KeywordKind kind;
+  /// Occurrences in the text read.
+  final int count;
+  /// Frequency in the text times rarity in general; only comparable
+  /// within one result.
+  final double score;
+
+  const Keyword({
+    required this.term,
+    required this.kind,
+    required this.count,
+    required this.score,
+  });
+
+  @override
+  int get hashCode =>
+      term.hashCode ^ kind.hashCode ^ count.hashCode ^ score.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Keyword &&
+          runtimeType == other.runtimeType &&
+          term == other.term &&
+          kind == other.kind &&
+          count == other.count &&
+          score == other.score;
+}
+
+enum KeywordKind { person, place, organization, term }
+
+class TagSuggestion {
+  final String tag;
+  /// Genre term occurrences per 10,000 words.
+  final double density;
+
+  const TagSuggestion({required this.tag, required this.density});
+
+  @override
+  int get hashCode => tag.hashCode ^ density.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TagSuggestion &&
+          runtimeType == other.runtimeType &&
+          tag == other.tag &&
+          density == other.density;
+}
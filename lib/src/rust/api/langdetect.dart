@@ -0,0 +1,51 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `add`, `book_language`, `end_word`, `guesses`, `letters`, `marker`, `marker`, `merge`, `script`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Sample`, `Script`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `hash`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+/// The languages of a text, most likely first.
+///
+/// # Arguments
+/// * `text` - Plain text, such as a chapter; only the start of long texts
+///   is read
+Future<List<LanguageGuess>> detect({required String text}) =>
+    RustLib.instance.api.crateApiLangdetectDetect(text: text);
+
+/// The languages of a book, most likely first, weighing each chapter by
+/// its length.
+///
+/// # Arguments
+/// * `chapters` - Plain text of each chapter
+Future<List<LanguageGuess>> detectBookLanguage({
+  required List<String> chapters,
+}) => RustLib.instance.api.crateApiLangdetectDetectBookLanguage(
+  chapters: chapters,
+);
+
+class LanguageGuess {
+  /// ISO 639-1 code such as `zh`, `ja` or `en`.
+  final String code;
+  /// Share of the text in this language, from 0 to 1.
+  final double confidence;
+
+  const LanguageGuess({required this.code, required this.confidence});
+
+  @override
+  int get hashCode => code.hashCode ^ confidence.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LanguageGuess &&
+          runtimeType == other.runtimeType &&
+          code == other.code &&
+          confidence == other.confidence;
+}
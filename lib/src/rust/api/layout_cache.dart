@@ -0,0 +1,60 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `clear`, `current`, `decode`, `encode`, `entries`, `entry_path`, `evict`, `get_or_paginate`, `get`, `initialized`, `layout_key`, `lock`, `new`, `put`, `stats`, `touch`, `u32`, `u8`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `EntryReader`, `LayoutCache`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`
+
+/// Enable the persistent layout cache. Calling this again switches to the
+/// new directory or size cap.
+///
+/// # Arguments
+/// * `cache_dir` - Directory owned by the cache; created if missing
+/// * `max_bytes` - Total size of cached layouts to keep
+Future<void> initLayoutCache({
+  required String cacheDir,
+  required int maxBytes,
+}) => RustLib.instance.api.crateApiLayoutCacheInitLayoutCache(
+  cacheDir: cacheDir,
+  maxBytes: maxBytes,
+);
+
+/// Report the number and total size of cached layouts.
+Future<LayoutCacheStats> layoutCacheStats() =>
+    RustLib.instance.api.crateApiLayoutCacheLayoutCacheStats();
+
+/// Delete every cached layout. The cache stays enabled.
+Future<void> clearLayoutCache() =>
+    RustLib.instance.api.crateApiLayoutCacheClearLayoutCache();
+
+/// Current size of the layout cache.
+class LayoutCacheStats {
+  final int entryCount;
+  final int totalBytes;
+  final int maxBytes;
+
+  const LayoutCacheStats({
+    required this.entryCount,
+    required this.totalBytes,
+    required this.maxBytes,
+  });
+
+  @override
+  int get hashCode =>
+      entryCount.hashCode ^ totalBytes.hashCode ^ maxBytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LayoutCacheStats &&
+          runtimeType == other.runtimeType &&
+          entryCount == other.entryCount &&
+          totalBytes == other.totalBytes &&
+          maxBytes == other.maxBytes;
+}
@@ -0,0 +1,77 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'db.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `consume`, `fuzzy_match`, `is_of`, `key`, `lock`, `new`, `new`, `pinyin_match`, `range`, `search`, `search`, `subsequence_match`, `text_match`, `toneless`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `FieldChar`, `Field`, `IndexedBook`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+/// Search the library by title and author, including by pinyin.
+///
+/// # Arguments
+/// * `query` - Text, pinyin or pinyin initials
+/// * `limit` - Most results returned
+Future<List<LibrarySearchResult>> searchLibraryBooks({
+  required String query,
+  required int limit,
+}) => RustLib.instance.api.crateApiLibrarySearchSearchLibraryBooks(
+  query: query,
+  limit: limit,
+);
+
+class LibrarySearchResult {
+  final LibraryBook book;
+  /// From 0 to 1; results are sorted by it.
+  final double score;
+  final List<MatchRange> titleMatches;
+  final List<MatchRange> authorMatches;
+
+  const LibrarySearchResult({
+    required this.book,
+    required this.score,
+    required this.titleMatches,
+    required this.authorMatches,
+  });
+
+  @override
+  int get hashCode =>
+      book.hashCode ^
+      score.hashCode ^
+      titleMatches.hashCode ^
+      authorMatches.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LibrarySearchResult &&
+          runtimeType == other.runtimeType &&
+          book == other.book &&
+          score == other.score &&
+          titleMatches == other.titleMatches &&
+          authorMatches == other.authorMatches;
+}
+
+/// A matched range of a field, in UTF-16 code units.
+class MatchRange {
+  final int start;
+  final int end;
+
+  const MatchRange({required this.start, required this.end});
+
+  @override
+  int get hashCode => start.hashCode ^ end.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MatchRange &&
+          runtimeType == other.runtimeType &&
+          start == other.start &&
+          end == other.end;
+}
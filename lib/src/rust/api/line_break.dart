@@ -0,0 +1,70 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `allows`, `breaks`, `new`, `standard`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Kinsoku`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+/// The usual Chinese and Japanese kinsoku sets. Pass empty strings instead
+/// for plain UAX #14.
+Future<KinsokuRules> defaultKinsokuRules() =>
+    RustLib.instance.api.crateApiLineBreakDefaultKinsokuRules();
+
+/// Every line-break opportunity in `text`, in order.
+///
+/// # Arguments
+/// * `text` - Any text
+/// * `rules` - Kinsoku rules; `default_kinsoku_rules` if omitted
+Future<List<LineBreak>> lineBreakOpportunities({
+  required String text,
+  KinsokuRules? rules,
+}) => RustLib.instance.api.crateApiLineBreakLineBreakOpportunities(
+  text: text,
+  rules: rules,
+);
+
+class KinsokuRules {
+  /// Characters that may not begin a line.
+  final String noLineStart;
+  /// Characters that may not end a line.
+  final String noLineEnd;
+
+  const KinsokuRules({required this.noLineStart, required this.noLineEnd});
+
+  @override
+  int get hashCode => noLineStart.hashCode ^ noLineEnd.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is KinsokuRules &&
+          runtimeType == other.runtimeType &&
+          noLineStart == other.noLineStart &&
+          noLineEnd == other.noLineEnd;
+}
+
+/// A place where a line may end.
+class LineBreak {
+  /// UTF-16 offset where the next line would start.
+  final int offset;
+  /// Whether the line must end here, as after a newline.
+  final bool mandatory;
+
+  const LineBreak({required this.offset, required this.mandatory});
+
+  @override
+  int get hashCode => offset.hashCode ^ mandatory.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LineBreak &&
+          runtimeType == other.runtimeType &&
+          offset == other.offset &&
+          mandatory == other.mandatory;
+}
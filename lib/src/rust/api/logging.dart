@@ -0,0 +1,165 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `append`, `error`, `is_secret_key`, `level_name`, `lock`, `log_files`, `new`, `open`, `pack`, `push`, `recent`, `record_json`, `record`, `rotate`, `scrub_record`, `scrub`, `trim`, `warn`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `LogFile`, `Logger`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `cmp`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `partial_cmp`
+
+/// 1000 records of level info and above, in memory only.
+Future<LoggingConfig> defaultLoggingConfig() =>
+    RustLib.instance.api.crateApiLoggingDefaultLoggingConfig();
+
+/// Change the logging settings. Records already in memory are kept, up to
+/// the new capacity.
+///
+/// # Arguments
+/// * `config` - Buffer size, minimum level and file output
+Future<void> configureLogging({required LoggingConfig config}) =>
+    RustLib.instance.api.crateApiLoggingConfigureLogging(config: config);
+
+/// Add a record from the Dart side.
+///
+/// # Arguments
+/// * `level` - Severity
+/// * `target` - The part of the app logging it
+/// * `message` - What happened
+/// * `fields` - Extra key-value context
+Future<void> writeLog({
+  required LogLevel level,
+  required String target,
+  required String message,
+  required List<LogField> fields,
+}) => RustLib.instance.api.crateApiLoggingWriteLog(
+  level: level,
+  target: target,
+  message: message,
+  fields: fields,
+);
+
+/// The most recent records at or above a level, oldest first.
+///
+/// # Arguments
+/// * `min_level` - Lowest level to return
+/// * `limit` - Records to return at most
+Future<List<LogRecord>> recentLogs({
+  required LogLevel minLevel,
+  required int limit,
+}) => RustLib.instance.api.crateApiLoggingRecentLogs(
+  minLevel: minLevel,
+  limit: limit,
+);
+
+/// Drop the records in memory. Log files are left alone.
+Future<void> clearLogs() => RustLib.instance.api.crateApiLoggingClearLogs();
+
+/// Write a zip for a bug report with the records in memory as
+/// `logs.jsonl` and the log files under `files/`, all scrubbed of
+/// credentials. Returns the number of records in `logs.jsonl`.
+///
+/// # Arguments
+/// * `path` - Path of the zip to write
+Future<int> exportLogs({required String path}) =>
+    RustLib.instance.api.crateApiLoggingExportLogs(path: path);
+
+class LogField {
+  final String key;
+  final String value;
+
+  const LogField({required this.key, required this.value});
+
+  @override
+  int get hashCode => key.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LogField &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          value == other.value;
+}
+
+enum LogLevel { debug, info, warn, error }
+
+class LogRecord {
+  /// Milliseconds since the Unix epoch.
+  final PlatformInt64 timeMs;
+  final LogLevel level;
+  /// The part of the app that logged it, such as `downloader`.
+  final String target;
+  final String message;
+  final List<LogField> fields;
+
+  const LogRecord({
+    required this.timeMs,
+    required this.level,
+    required this.target,
+    required this.message,
+    required this.fields,
+  });
+
+  @override
+  int get hashCode =>
+      timeMs.hashCode ^
+      level.hashCode ^
+      target.hashCode ^
+      message.hashCode ^
+      fields.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LogRecord &&
+          runtimeType == other.runtimeType &&
+          timeMs == other.timeMs &&
+          level == other.level &&
+          target == other.target &&
+          message == other.message &&
+          fields == other.fields;
+}
+
+class LoggingConfig {
+  /// Records kept in memory.
+  final int capacity;
+  /// Records below this level are dropped.
+  final LogLevel minLevel;
+  /// Directory for log files; `None` keeps logs in memory only.
+  final String? directory;
+  /// Size at which the current file is rotated.
+  final BigInt maxFileBytes;
+  /// Files kept, counting the current one.
+  final int maxFiles;
+
+  const LoggingConfig({
+    required this.capacity,
+    required this.minLevel,
+    this.directory,
+    required this.maxFileBytes,
+    required this.maxFiles,
+  });
+
+  @override
+  int get hashCode =>
+      capacity.hashCode ^
+      minLevel.hashCode ^
+      directory.hashCode ^
+      maxFileBytes.hashCode ^
+      maxFiles.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LoggingConfig &&
+          runtimeType == other.runtimeType &&
+          capacity == other.capacity &&
+          minLevel == other.minLevel &&
+          directory == other.directory &&
+          maxFileBytes == other.maxFileBytes &&
+          maxFiles == other.maxFiles;
+}
@@ -0,0 +1,113 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'txt.dart';
+
+// These functions are ignored because they are not marked as `pub`: `char_start`, `get`, `is_utf16`, `line_range`, `line_start`, `lock`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `MappedTxt`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+/// Map a text file and index its chapters. The file must not be changed
+/// while it is open.
+///
+/// # Arguments
+/// * `path` - Path to the text file, under 4 GB
+/// * `rules` - Heading rules; `None` uses `default_txt_rules()`
+Future<MappedTxtInfo> openMappedTxt({
+  required String path,
+  List<TxtChapterRule>? rules,
+}) => RustLib.instance.api.crateApiMappedTxtOpenMappedTxt(
+  path: path,
+  rules: rules,
+);
+
+/// Read one chapter of an open file as HTML.
+///
+/// # Arguments
+/// * `id` - ID from `open_mapped_txt`
+/// * `chapter_index` - Position of the chapter in the file's index
+Future<String> readMappedChapter({
+  required int id,
+  required int chapterIndex,
+}) => RustLib.instance.api.crateApiMappedTxtReadMappedChapter(
+  id: id,
+  chapterIndex: chapterIndex,
+);
+
+/// Read plain text from anywhere in an open file, e.g. to jump to a
+/// position in a book without chapters. The range is widened to start at
+/// the line containing `start` and to end at a line break, as long as the
+/// line is shorter than `max_bytes`.
+///
+/// # Arguments
+/// * `id` - ID from `open_mapped_txt`
+/// * `start` - Byte offset to read from
+/// * `max_bytes` - Bytes to read at most, before widening to line ends
+Future<MappedTextSlice> readMappedText({
+  required int id,
+  required int start,
+  required int maxBytes,
+}) => RustLib.instance.api.crateApiMappedTxtReadMappedText(
+  id: id,
+  start: start,
+  maxBytes: maxBytes,
+);
+
+/// Close an open file. Returns false if it was not open.
+Future<bool> closeMappedTxt({required int id}) =>
+    RustLib.instance.api.crateApiMappedTxtCloseMappedTxt(id: id);
+
+/// Text read from a mapped file, with the byte range it came from.
+class MappedTextSlice {
+  final String text;
+  final int start;
+  /// Where the next slice starts; the file size at the end.
+  final int end;
+
+  const MappedTextSlice({
+    required this.text,
+    required this.start,
+    required this.end,
+  });
+
+  @override
+  int get hashCode => text.hashCode ^ start.hashCode ^ end.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MappedTextSlice &&
+          runtimeType == other.runtimeType &&
+          text == other.text &&
+          start == other.start &&
+          end == other.end;
+}
+
+class MappedTxtInfo {
+  final int id;
+  final String path;
+  final TxtIndex index;
+
+  const MappedTxtInfo({
+    required this.id,
+    required this.path,
+    required this.index,
+  });
+
+  @override
+  int get hashCode => id.hashCode ^ path.hashCode ^ index.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MappedTxtInfo &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          path == other.path &&
+          index == other.index;
+}
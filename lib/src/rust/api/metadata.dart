@@ -0,0 +1,87 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `clean_author`, `clean_title`, `combine`, `comparable`, `from_content`, `from_file_name`, `patterns`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Patterns`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+/// Guess the title and author of a file to import from its name and, for
+/// text files, its first pages.
+///
+/// # Arguments
+/// * `path` - File to import; only `.txt` files are read
+Future<ScrapedMetadata> scrapeMetadata({required String path}) =>
+    RustLib.instance.api.crateApiMetadataScrapeMetadata(path: path);
+
+/// Guess the title and author from a file name and text already read.
+///
+/// # Arguments
+/// * `file_name` - Name of the file, with or without its extension
+/// * `text` - The start of the book's text, if it has any
+Future<ScrapedMetadata> scrapeMetadataFrom({
+  required String fileName,
+  String? text,
+}) => RustLib.instance.api.crateApiMetadataScrapeMetadataFrom(
+  fileName: fileName,
+  text: text,
+);
+
+class MetadataGuess {
+  final String value;
+  /// From 0 to 1.
+  final double confidence;
+  final MetadataOrigin origin;
+
+  const MetadataGuess({
+    required this.value,
+    required this.confidence,
+    required this.origin,
+  });
+
+  @override
+  int get hashCode => value.hashCode ^ confidence.hashCode ^ origin.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MetadataGuess &&
+          runtimeType == other.runtimeType &&
+          value == other.value &&
+          confidence == other.confidence &&
+          origin == other.origin;
+}
+
+enum MetadataOrigin {
+  fileName,
+  content,
+  /// The file name and the content agree.
+  both,
+}
+
+class ScrapedMetadata {
+  final MetadataGuess? title;
+  /// Several authors are kept together, as written.
+  final MetadataGuess? author;
+  /// Release notes taken out of the file name, such as `校对版` or `全本`.
+  final List<String> tags;
+
+  const ScrapedMetadata({this.title, this.author, required this.tags});
+
+  @override
+  int get hashCode => title.hashCode ^ author.hashCode ^ tags.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ScrapedMetadata &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          author == other.author &&
+          tags == other.tags;
+}
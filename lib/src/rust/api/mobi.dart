@@ -0,0 +1,37 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'book.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `ascii`, `base32`, `be_u16`, `be_u32`, `book_header`, `build_chapters`, `chapter_for_part`, `decode`, `decompress`, `exth_string`, `exth_u32`, `exth_values`, `extract_resource`, `insert_anchors`, `kf8_chapters`, `metadata`, `mobi7_chapters`, `new`, `palmdoc_decompress`, `parse`, `pdb_records`, `read_exth`, `read_file`, `read_header`, `read_index`, `read_ncx`, `read_tags`, `read_text`, `read_varint`, `strip_trailing_entries`, `tag`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Header`, `HuffCdic`, `IndexEntry`, `Index`, `NcxEntry`
+
+/// Parse a local MOBI, AZW or DRM-free AZW3 file into chapters and a table
+/// of contents.
+///
+/// # Arguments
+/// * `path` - Path to the Kindle book
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+Future<ParsedBook> parseMobi({required String path, int? cancelToken}) =>
+    RustLib.instance.api.crateApiMobiParseMobi(
+      path: path,
+      cancelToken: cancelToken,
+    );
+
+/// Extract an image referenced by parsed chapter HTML.
+///
+/// # Arguments
+/// * `path` - Path to the Kindle book
+/// * `href` - Image href from chapter HTML, e.g. `images/3`
+Future<Uint8List> extractMobiResource({
+  required String path,
+  required String href,
+}) => RustLib.instance.api.crateApiMobiExtractMobiResource(
+  path: path,
+  href: href,
+);
@@ -0,0 +1,84 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `agent`, `lock`, `proxy_for`, `ureq_proxy`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AgentKey`, `Network`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `hash`, `hash`, `hash`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+/// Route requests through `proxy`, or connect directly when it is `None`.
+/// Hosts with their own setting from `set_host_proxy` are not affected.
+Future<void> setProxy({ProxyConfig? proxy}) =>
+    RustLib.instance.api.crateApiNetworkSetProxy(proxy: proxy);
+
+/// Override the proxy for one book source.
+///
+/// # Arguments
+/// * `host` - Host name, e.g. `www.example.com`; subdomains are not included
+/// * `proxy` - Proxy for this host, or `None` to connect to it directly
+Future<void> setHostProxy({required String host, ProxyConfig? proxy}) =>
+    RustLib.instance.api.crateApiNetworkSetHostProxy(host: host, proxy: proxy);
+
+/// Remove a host's proxy override, so it uses the global proxy again.
+Future<void> clearHostProxy({required String host}) =>
+    RustLib.instance.api.crateApiNetworkClearHostProxy(host: host);
+
+/// Resolve host names with DNS over HTTPS, falling back to the system
+/// resolver when the endpoint fails.
+///
+/// # Arguments
+/// * `endpoint` - RFC 8484 endpoint such as `https://cloudflare-dns.com/dns-query`
+///   or `https://dns.alidns.com/dns-query`, or `None` to use the system resolver
+Future<void> setDnsOverHttps({String? endpoint}) =>
+    RustLib.instance.api.crateApiNetworkSetDnsOverHttps(endpoint: endpoint);
+
+/// Forget the addresses cached by the DNS-over-HTTPS resolver.
+Future<void> clearDnsCache() =>
+    RustLib.instance.api.crateApiNetworkClearDnsCache();
+
+class ProxyConfig {
+  final ProxyKind kind;
+  final String host;
+  final int port;
+  final String? username;
+  final String? password;
+
+  const ProxyConfig({
+    required this.kind,
+    required this.host,
+    required this.port,
+    this.username,
+    this.password,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      host.hashCode ^
+      port.hashCode ^
+      username.hashCode ^
+      password.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProxyConfig &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          host == other.host &&
+          port == other.port &&
+          username == other.username &&
+          password == other.password;
+}
+
+enum ProxyKind {
+  /// An HTTP proxy; HTTPS requests are tunnelled through it with CONNECT.
+  http,
+  socks5,
+}
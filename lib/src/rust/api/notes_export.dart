@@ -0,0 +1,71 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `by_chapter`, `chapter_title`, `escape`, `json`, `markdown`, `offset`, `render`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Note`, `Notes`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+/// Render the notes of a book from the library database.
+///
+/// # Arguments
+/// * `book_id` - Book to export
+/// * `options` - Format, bookmarks and time zone
+Future<String> renderBookNotes({
+  required String bookId,
+  required NotesExportOptions options,
+}) => RustLib.instance.api.crateApiNotesExportRenderBookNotes(
+  bookId: bookId,
+  options: options,
+);
+
+/// Write the notes of a book to a file, replacing it. Returns how many
+/// highlights and bookmarks were written.
+///
+/// # Arguments
+/// * `book_id` - Book to export
+/// * `path` - File to write, usually ending in `.md` or `.json`
+/// * `options` - Format, bookmarks and time zone
+Future<int> exportBookNotes({
+  required String bookId,
+  required String path,
+  required NotesExportOptions options,
+}) => RustLib.instance.api.crateApiNotesExportExportBookNotes(
+  bookId: bookId,
+  path: path,
+  options: options,
+);
+
+class NotesExportOptions {
+  final NotesFormat format;
+  /// Include bookmarks as well as highlights.
+  final bool includeBookmarks;
+  /// Minutes east of UTC to show Markdown times in; JSON is always UTC.
+  final int utcOffsetMinutes;
+
+  const NotesExportOptions({
+    required this.format,
+    required this.includeBookmarks,
+    required this.utcOffsetMinutes,
+  });
+
+  @override
+  int get hashCode =>
+      format.hashCode ^ includeBookmarks.hashCode ^ utcOffsetMinutes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NotesExportOptions &&
+          runtimeType == other.runtimeType &&
+          format == other.format &&
+          includeBookmarks == other.includeBookmarks &&
+          utcOffsetMinutes == other.utcOffsetMinutes;
+}
+
+enum NotesFormat { markdown, json }
@@ -0,0 +1,184 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `add_atom_link`, `atom_link`, `authorization`, `collect_json_entries`, `contributors`, `empty_entry`, `empty_feed`, `entry_link`, `feed_link`, `fetch`, `json_links`, `parse_atom_feed`, `parse_feed`, `parse_json_feed`, `parse_search_description`, `resolve`, `str_field`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Fetch and parse an OPDS 1.2 or 2.0 feed.
+///
+/// # Arguments
+/// * `url` - Feed URL, e.g. a Calibre-web `/opds` root or a search URL
+/// * `credentials` - HTTP basic auth credentials, if the catalog needs them
+Future<OpdsFeed> fetchOpdsFeed({
+  required String url,
+  OpdsCredentials? credentials,
+}) => RustLib.instance.api.crateApiOpdsFetchOpdsFeed(
+  url: url,
+  credentials: credentials,
+);
+
+/// Expand a search template with `query`. Both OpenSearch templates
+/// (`{searchTerms}`) and OPDS 2.0 URI templates (`{?query}`) are supported;
+/// other parameters are left empty.
+Future<String> opdsSearchUrl({
+  required String template,
+  required String query,
+}) => RustLib.instance.api.crateApiOpdsOpdsSearchUrl(
+  template: template,
+  query: query,
+);
+
+/// Queue an acquisition link on the downloader, with the catalog's
+/// credentials, and return the task ID.
+///
+/// # Arguments
+/// * `url` - Acquisition link `href`
+/// * `destination` - File to write
+/// * `credentials` - HTTP basic auth credentials, if the catalog needs them
+Future<int> enqueueOpdsDownload({
+  required String url,
+  required String destination,
+  OpdsCredentials? credentials,
+}) => RustLib.instance.api.crateApiOpdsEnqueueOpdsDownload(
+  url: url,
+  destination: destination,
+  credentials: credentials,
+);
+
+class OpdsCredentials {
+  final String username;
+  final String password;
+
+  const OpdsCredentials({required this.username, required this.password});
+
+  @override
+  int get hashCode => username.hashCode ^ password.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OpdsCredentials &&
+          runtimeType == other.runtimeType &&
+          username == other.username &&
+          password == other.password;
+}
+
+class OpdsEntry {
+  final String? id;
+  final String title;
+  final List<String> authors;
+  /// Plain-text description.
+  final String? summary;
+  final String? coverUrl;
+  final String? thumbnailUrl;
+  /// Feed to open when the entry is a navigation entry.
+  final String? navigationUrl;
+  /// Download links of a publication, in feed order.
+  final List<OpdsLink> acquisitions;
+
+  const OpdsEntry({
+    this.id,
+    required this.title,
+    required this.authors,
+    this.summary,
+    this.coverUrl,
+    this.thumbnailUrl,
+    this.navigationUrl,
+    required this.acquisitions,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      title.hashCode ^
+      authors.hashCode ^
+      summary.hashCode ^
+      coverUrl.hashCode ^
+      thumbnailUrl.hashCode ^
+      navigationUrl.hashCode ^
+      acquisitions.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OpdsEntry &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          title == other.title &&
+          authors == other.authors &&
+          summary == other.summary &&
+          coverUrl == other.coverUrl &&
+          thumbnailUrl == other.thumbnailUrl &&
+          navigationUrl == other.navigationUrl &&
+          acquisitions == other.acquisitions;
+}
+
+class OpdsFeed {
+  final String title;
+  final List<OpdsEntry> entries;
+  final String? startUrl;
+  final String? nextUrl;
+  final String? previousUrl;
+  /// Search URL template; expand it with `opds_search_url`.
+  final String? searchTemplate;
+
+  const OpdsFeed({
+    required this.title,
+    required this.entries,
+    this.startUrl,
+    this.nextUrl,
+    this.previousUrl,
+    this.searchTemplate,
+  });
+
+  @override
+  int get hashCode =>
+      title.hashCode ^
+      entries.hashCode ^
+      startUrl.hashCode ^
+      nextUrl.hashCode ^
+      previousUrl.hashCode ^
+      searchTemplate.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OpdsFeed &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          entries == other.entries &&
+          startUrl == other.startUrl &&
+          nextUrl == other.nextUrl &&
+          previousUrl == other.previousUrl &&
+          searchTemplate == other.searchTemplate;
+}
+
+class OpdsLink {
+  final String href;
+  final String? rel;
+  /// MIME type, e.g. `application/epub+zip`.
+  final String? mediaType;
+  final String? title;
+
+  const OpdsLink({required this.href, this.rel, this.mediaType, this.title});
+
+  @override
+  int get hashCode =>
+      href.hashCode ^ rel.hashCode ^ mediaType.hashCode ^ title.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OpdsLink &&
+          runtimeType == other.runtimeType &&
+          href == other.href &&
+          rel == other.rel &&
+          mediaType == other.mediaType &&
+          title == other.title;
+}
@@ -0,0 +1,54 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `converter`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+/// Convert text, e.g. a chapter before rendering.
+Future<String> convertChinese({
+  required String text,
+  required ChineseConversion conversion,
+}) => RustLib.instance.api.crateApiOpenccConvertChinese(
+  text: text,
+  conversion: conversion,
+);
+
+/// Convert several texts with the same converter, e.g. every chapter of a
+/// book on export. Results are in input order.
+Future<List<String>> convertChineseBatch({
+  required List<String> texts,
+  required ChineseConversion conversion,
+}) => RustLib.instance.api.crateApiOpenccConvertChineseBatch(
+  texts: texts,
+  conversion: conversion,
+);
+
+enum ChineseConversion {
+  /// Simplified to Traditional.
+  s2T,
+  /// Traditional to Simplified.
+  t2S,
+  /// Simplified to Taiwan Traditional characters.
+  s2Tw,
+  /// Taiwan Traditional characters to Simplified.
+  tw2S,
+  /// Simplified to Taiwan Traditional, with Taiwan phrasing
+  /// (e.g. 软件 → 軟體).
+  s2Twp,
+  /// Taiwan Traditional with Taiwan phrasing to Simplified.
+  tw2Sp,
+  /// Simplified to Hong Kong Traditional characters.
+  s2Hk,
+  /// Hong Kong Traditional characters to Simplified.
+  hk2S,
+  /// Traditional to Taiwan Traditional characters.
+  t2Tw,
+  /// Traditional to Hong Kong Traditional characters.
+  t2Hk,
+}
@@ -0,0 +1,157 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'line_break.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `at`, `break_lines`, `load_font`, `new`, `paginate`, `prefix_widths`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Utf16Offsets`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+/// Split `text` into pages. Paragraphs are separated by newlines; blank
+/// lines are skipped. With `init_layout_cache`, a chapter laid out before
+/// with the same font and settings is returned from the cache.
+///
+/// # Arguments
+/// * `text` - Chapter text
+/// * `font_path` - TTF or OTF file used for measuring; the first face of a
+///   collection
+/// * `layout` - Page size, margins and text style
+/// * `kinsoku` - Characters kept off line edges; `default_kinsoku_rules`
+///   if omitted
+Future<List<TextPage>> paginateText({
+  required String text,
+  required String fontPath,
+  required PageLayout layout,
+  KinsokuRules? kinsoku,
+}) => RustLib.instance.api.crateApiPaginationPaginateText(
+  text: text,
+  fontPath: fontPath,
+  layout: layout,
+  kinsoku: kinsoku,
+);
+
+/// Page geometry and text style, in logical pixels.
+class PageLayout {
+  final double width;
+  final double height;
+  final double marginTop;
+  final double marginRight;
+  final double marginBottom;
+  final double marginLeft;
+  final double fontSize;
+  /// Line height as a multiple of the font size.
+  final double lineHeight;
+  /// Extra space above a paragraph, except at the top of a page.
+  final double paragraphSpacing;
+  /// First-line indent in ems.
+  final double indent;
+
+  const PageLayout({
+    required this.width,
+    required this.height,
+    required this.marginTop,
+    required this.marginRight,
+    required this.marginBottom,
+    required this.marginLeft,
+    required this.fontSize,
+    required this.lineHeight,
+    required this.paragraphSpacing,
+    required this.indent,
+  });
+
+  @override
+  int get hashCode =>
+      width.hashCode ^
+      height.hashCode ^
+      marginTop.hashCode ^
+      marginRight.hashCode ^
+      marginBottom.hashCode ^
+      marginLeft.hashCode ^
+      fontSize.hashCode ^
+      lineHeight.hashCode ^
+      paragraphSpacing.hashCode ^
+      indent.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PageLayout &&
+          runtimeType == other.runtimeType &&
+          width == other.width &&
+          height == other.height &&
+          marginTop == other.marginTop &&
+          marginRight == other.marginRight &&
+          marginBottom == other.marginBottom &&
+          marginLeft == other.marginLeft &&
+          fontSize == other.fontSize &&
+          lineHeight == other.lineHeight &&
+          paragraphSpacing == other.paragraphSpacing &&
+          indent == other.indent;
+}
+
+/// A laid-out line; `end` excludes trailing whitespace.
+class TextLine {
+  final int start;
+  final int end;
+  /// Whether this is the first line of a paragraph, and so indented.
+  final bool paragraphStart;
+  /// Advance width of the line's text, without the indent.
+  final double width;
+  /// Whether the line ends at a soft hyphen, which should be drawn as a
+  /// hyphen; `width` includes it.
+  final bool hyphenated;
+
+  const TextLine({
+    required this.start,
+    required this.end,
+    required this.paragraphStart,
+    required this.width,
+    required this.hyphenated,
+  });
+
+  @override
+  int get hashCode =>
+      start.hashCode ^
+      end.hashCode ^
+      paragraphStart.hashCode ^
+      width.hashCode ^
+      hyphenated.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TextLine &&
+          runtimeType == other.runtimeType &&
+          start == other.start &&
+          end == other.end &&
+          paragraphStart == other.paragraphStart &&
+          width == other.width &&
+          hyphenated == other.hyphenated;
+}
+
+/// A page of text. Pages cover the text contiguously: each `end` is the
+/// next page's `start`, and the last one is the text length.
+class TextPage {
+  final int start;
+  final int end;
+  final List<TextLine> lines;
+
+  const TextPage({required this.start, required this.end, required this.lines});
+
+  @override
+  int get hashCode => start.hashCode ^ end.hashCode ^ lines.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TextPage &&
+          runtimeType == other.runtimeType &&
+          start == other.start &&
+          end == other.end &&
+          lines == other.lines;
+}
@@ -0,0 +1,102 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `accent`, `build_palette`, `channels`, `cluster`, `contrast`, `distance`, `luminance`, `mix`, `nearest`, `saturation`, `seed`, `to_argb`, `with_contrast`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+/// Extract the main colours of a cover image and suggest theme colours.
+///
+/// # Arguments
+/// * `image_bytes` - Encoded image (JPEG, PNG, GIF, WebP)
+Future<CoverPalette> extract({required List<int> imageBytes}) =>
+    RustLib.instance.api.crateApiPaletteExtract(imageBytes: imageBytes);
+
+class CoverPalette {
+  final int dominant;
+  final int dark;
+  final int light;
+  /// Clusters, largest first.
+  final List<PaletteSwatch> swatches;
+  final ThemeColors lightTheme;
+  final ThemeColors darkTheme;
+
+  const CoverPalette({
+    required this.dominant,
+    required this.dark,
+    required this.light,
+    required this.swatches,
+    required this.lightTheme,
+    required this.darkTheme,
+  });
+
+  @override
+  int get hashCode =>
+      dominant.hashCode ^
+      dark.hashCode ^
+      light.hashCode ^
+      swatches.hashCode ^
+      lightTheme.hashCode ^
+      darkTheme.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CoverPalette &&
+          runtimeType == other.runtimeType &&
+          dominant == other.dominant &&
+          dark == other.dark &&
+          light == other.light &&
+          swatches == other.swatches &&
+          lightTheme == other.lightTheme &&
+          darkTheme == other.darkTheme;
+}
+
+class PaletteSwatch {
+  final int color;
+  /// Share of the sampled pixels in this cluster, from 0 to 1.
+  final double population;
+
+  const PaletteSwatch({required this.color, required this.population});
+
+  @override
+  int get hashCode => color.hashCode ^ population.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PaletteSwatch &&
+          runtimeType == other.runtimeType &&
+          color == other.color &&
+          population == other.population;
+}
+
+class ThemeColors {
+  final int background;
+  final int foreground;
+  final int accent;
+
+  const ThemeColors({
+    required this.background,
+    required this.foreground,
+    required this.accent,
+  });
+
+  @override
+  int get hashCode =>
+      background.hashCode ^ foreground.hashCode ^ accent.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ThemeColors &&
+          runtimeType == other.runtimeType &&
+          background == other.background &&
+          foreground == other.foreground &&
+          accent == other.accent;
+}
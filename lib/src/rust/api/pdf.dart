@@ -0,0 +1,24 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'book.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `body_size`, `build_chapters`, `flush`, `is_cjk`, `join_line`, `lines`, `metadata`, `parse`, `reflow`, `size_key`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Block`, `Collector`, `Glyph`, `Line`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `begin_page`, `begin_word`, `end_line`, `end_page`, `end_word`, `output_character`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+/// Parse a text-based PDF into reflowable chapters.
+///
+/// # Arguments
+/// * `path` - Path to the PDF file
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+Future<ParsedBook> parsePdf({required String path, int? cancelToken}) => RustLib
+    .instance
+    .api
+    .crateApiPdfParsePdf(path: path, cancelToken: cancelToken);
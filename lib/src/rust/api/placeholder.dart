@@ -0,0 +1,79 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `blurhash`, `shrink`, `thumbhash`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// Generate the placeholders of a cover image.
+///
+/// # Arguments
+/// * `image_bytes` - Encoded cover (JPEG, PNG, GIF, WebP)
+/// * `thumbnail_size` - Longest side of the thumbnail in pixels; 0 for the default
+Future<CoverPlaceholder> coverPlaceholder({
+  required List<int> imageBytes,
+  required int thumbnailSize,
+}) => RustLib.instance.api.crateApiPlaceholderCoverPlaceholder(
+  imageBytes: imageBytes,
+  thumbnailSize: thumbnailSize,
+);
+
+/// Render a ThumbHash as a small PNG, for platforms without a Dart decoder.
+///
+/// # Arguments
+/// * `thumbhash` - Base64 ThumbHash from `cover_placeholder`
+Future<Uint8List> thumbhashToPng({required String thumbhash}) => RustLib
+    .instance
+    .api
+    .crateApiPlaceholderThumbhashToPng(thumbhash: thumbhash);
+
+class CoverPlaceholder {
+  final String blurhash;
+  /// Base64 ThumbHash.
+  final String thumbhash;
+  /// JPEG thumbnail.
+  final Uint8List thumbnail;
+  final int thumbnailWidth;
+  final int thumbnailHeight;
+  /// Size of the original cover.
+  final int width;
+  final int height;
+
+  const CoverPlaceholder({
+    required this.blurhash,
+    required this.thumbhash,
+    required this.thumbnail,
+    required this.thumbnailWidth,
+    required this.thumbnailHeight,
+    required this.width,
+    required this.height,
+  });
+
+  @override
+  int get hashCode =>
+      blurhash.hashCode ^
+      thumbhash.hashCode ^
+      thumbnail.hashCode ^
+      thumbnailWidth.hashCode ^
+      thumbnailHeight.hashCode ^
+      width.hashCode ^
+      height.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CoverPlaceholder &&
+          runtimeType == other.runtimeType &&
+          blurhash == other.blurhash &&
+          thumbhash == other.thumbhash &&
+          thumbnail == other.thumbnail &&
+          thumbnailWidth == other.thumbnailWidth &&
+          thumbnailHeight == other.thumbnailHeight &&
+          width == other.width &&
+          height == other.height;
+}
@@ -0,0 +1,200 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'downloader.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `cancel`, `chapter_count`, `lock`, `pause`, `status`, `wanted`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Prefetch`, `Queued`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Settings used until `configure_prefetch` is called.
+Future<PrefetchConfig> defaultPrefetchConfig() =>
+    RustLib.instance.api.crateApiPrefetchDefaultPrefetchConfig();
+
+/// Change the prefetch settings; they apply from the next `update_prefetch`.
+Future<void> configurePrefetch({required PrefetchConfig config}) =>
+    RustLib.instance.api.crateApiPrefetchConfigurePrefetch(config: config);
+
+/// Queue the chapters after the current one and cancel tasks no longer
+/// needed, then return the resulting status.
+///
+/// # Arguments
+/// * `plan` - Book, position, upcoming chapters, reading speed and network
+Future<PrefetchStatus> updatePrefetch({required PrefetchPlan plan}) =>
+    RustLib.instance.api.crateApiPrefetchUpdatePrefetch(plan: plan);
+
+/// Cancel every unfinished prefetch task, as when switching sources, and
+/// forget the current plan. Returns the number of tasks cancelled.
+Future<int> cancelPrefetch() =>
+    RustLib.instance.api.crateApiPrefetchCancelPrefetch();
+
+/// The current plan and the state of its tasks.
+Future<PrefetchStatus> prefetchStatus() =>
+    RustLib.instance.api.crateApiPrefetchPrefetchStatus();
+
+enum NetworkType {
+  /// Wi-Fi or ethernet without a data cap.
+  unmetered,
+  /// Cellular data or a hotspot.
+  metered,
+  offline,
+}
+
+class PrefetchChapter {
+  /// Position in the table of contents.
+  final int index;
+  final DownloadRequest request;
+  /// Length in characters, if known.
+  final int? length;
+
+  const PrefetchChapter({
+    required this.index,
+    required this.request,
+    this.length,
+  });
+
+  @override
+  int get hashCode => index.hashCode ^ request.hashCode ^ length.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrefetchChapter &&
+          runtimeType == other.runtimeType &&
+          index == other.index &&
+          request == other.request &&
+          length == other.length;
+}
+
+class PrefetchConfig {
+  /// Chapters queued ahead at most.
+  final int maxChapters;
+  /// Chapters queued ahead when the reading speed is unknown.
+  final int defaultChapters;
+  /// Reading time to keep downloaded ahead.
+  final int lookaheadMinutes;
+  /// Whether to prefetch on metered connections.
+  final bool allowMetered;
+
+  const PrefetchConfig({
+    required this.maxChapters,
+    required this.defaultChapters,
+    required this.lookaheadMinutes,
+    required this.allowMetered,
+  });
+
+  @override
+  int get hashCode =>
+      maxChapters.hashCode ^
+      defaultChapters.hashCode ^
+      lookaheadMinutes.hashCode ^
+      allowMetered.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrefetchConfig &&
+          runtimeType == other.runtimeType &&
+          maxChapters == other.maxChapters &&
+          defaultChapters == other.defaultChapters &&
+          lookaheadMinutes == other.lookaheadMinutes &&
+          allowMetered == other.allowMetered;
+}
+
+enum PrefetchPause {
+  offline,
+  /// On a metered connection, and `allow_metered` is off.
+  metered,
+}
+
+class PrefetchPlan {
+  /// Identifies the book and its source, e.g. `<book id>@<source url>`.
+  final String key;
+  /// Index of the chapter being read.
+  final int currentIndex;
+  /// Chapters that may be prefetched, in any order; ones at or before
+  /// `current_index` and ones already downloaded are skipped.
+  final List<PrefetchChapter> chapters;
+  /// Reading speed in characters per minute, if known.
+  final int? charsPerMinute;
+  final NetworkType network;
+
+  const PrefetchPlan({
+    required this.key,
+    required this.currentIndex,
+    required this.chapters,
+    this.charsPerMinute,
+    required this.network,
+  });
+
+  @override
+  int get hashCode =>
+      key.hashCode ^
+      currentIndex.hashCode ^
+      chapters.hashCode ^
+      charsPerMinute.hashCode ^
+      network.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrefetchPlan &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          currentIndex == other.currentIndex &&
+          chapters == other.chapters &&
+          charsPerMinute == other.charsPerMinute &&
+          network == other.network;
+}
+
+class PrefetchStatus {
+  /// Key of the current plan.
+  final String? key;
+  /// Why the current plan queues nothing.
+  final PrefetchPause? paused;
+  /// Tasks of the current plan, by chapter index.
+  final List<PrefetchTask> tasks;
+
+  const PrefetchStatus({this.key, this.paused, required this.tasks});
+
+  @override
+  int get hashCode => key.hashCode ^ paused.hashCode ^ tasks.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrefetchStatus &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          paused == other.paused &&
+          tasks == other.tasks;
+}
+
+class PrefetchTask {
+  final int chapterIndex;
+  final int taskId;
+  final DownloadState state;
+
+  const PrefetchTask({
+    required this.chapterIndex,
+    required this.taskId,
+    required this.state,
+  });
+
+  @override
+  int get hashCode => chapterIndex.hashCode ^ taskId.hashCode ^ state.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrefetchTask &&
+          runtimeType == other.runtimeType &&
+          chapterIndex == other.chapterIndex &&
+          taskId == other.taskId &&
+          state == other.state;
+}
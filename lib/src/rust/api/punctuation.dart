@@ -0,0 +1,74 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `is_cjk`, `is_han`, `is_quote`, `pair_quotes`, `remove_cjk_spaces`, `replace_dialogue_brackets`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+/// Repair the punctuation of chapter text. Line endings are kept.
+///
+/// # Arguments
+/// * `text` - Chapter text
+/// * `level` - How far to go where the intended punctuation is a guess
+Future<PunctuationRepair> repairPunctuation({
+  required String text,
+  required RepairLevel level,
+}) => RustLib.instance.api.crateApiPunctuationRepairPunctuation(
+  text: text,
+  level: level,
+);
+
+class PunctuationRepair {
+  /// The repaired text.
+  final String text;
+  /// Total of the counts below.
+  final int changes;
+  /// Runs of spaces removed.
+  final int spacesRemoved;
+  /// Quotes whose direction or form changed.
+  final int quotesFixed;
+  /// Pairs of parentheses turned into quotes.
+  final int bracketsReplaced;
+
+  const PunctuationRepair({
+    required this.text,
+    required this.changes,
+    required this.spacesRemoved,
+    required this.quotesFixed,
+    required this.bracketsReplaced,
+  });
+
+  @override
+  int get hashCode =>
+      text.hashCode ^
+      changes.hashCode ^
+      spacesRemoved.hashCode ^
+      quotesFixed.hashCode ^
+      bracketsReplaced.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PunctuationRepair &&
+          runtimeType == other.runtimeType &&
+          text == other.text &&
+          changes == other.changes &&
+          spacesRemoved == other.spacesRemoved &&
+          quotesFixed == other.quotesFixed &&
+          bracketsReplaced == other.bracketsReplaced;
+}
+
+enum RepairLevel {
+  /// Remove spaces next to Han characters, pair quotes on lines with an
+  /// even number of them, and replace parentheses holding a sentence
+  /// after a colon, as in `他说：（走吧。）`.
+  conservative,
+  /// Also remove ideographic spaces and spaces between CJK punctuation,
+  /// pair quotes on lines with an odd number, leaving the last one open,
+  /// and replace parentheses holding a whole paragraph that ends a sentence.
+  aggressive,
+}
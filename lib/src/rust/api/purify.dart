@@ -0,0 +1,129 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `apply_compiled`, `apply`, `compile`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Built-in rules for invisible characters and common site watermarks.
+Future<List<PurifyRule>> defaultPurifyRules() =>
+    RustLib.instance.api.crateApiPurifyDefaultPurifyRules();
+
+/// Apply enabled rules to `text` in order.
+Future<String> purifyText({
+  required String text,
+  required List<PurifyRule> rules,
+}) => RustLib.instance.api.crateApiPurifyPurifyText(text: text, rules: rules);
+
+/// Apply enabled rules like `purify_text`, also reporting what each matched.
+Future<PurifyReport> previewPurify({
+  required String text,
+  required List<PurifyRule> rules,
+}) =>
+    RustLib.instance.api.crateApiPurifyPreviewPurify(text: text, rules: rules);
+
+class PurifyReport {
+  /// The text after all rules.
+  final String text;
+  /// Rules that matched anything, in rule order.
+  final List<PurifyRuleMatch> matches;
+
+  const PurifyReport({required this.text, required this.matches});
+
+  @override
+  int get hashCode => text.hashCode ^ matches.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PurifyReport &&
+          runtimeType == other.runtimeType &&
+          text == other.text &&
+          matches == other.matches;
+}
+
+class PurifyRule {
+  final String name;
+  final PurifyRuleKind kind;
+  final String pattern;
+  /// Ignored for `Line` and `Watermark` rules.
+  final String replacement;
+  final bool enabled;
+
+  const PurifyRule({
+    required this.name,
+    required this.kind,
+    required this.pattern,
+    required this.replacement,
+    required this.enabled,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      kind.hashCode ^
+      pattern.hashCode ^
+      replacement.hashCode ^
+      enabled.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PurifyRule &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          kind == other.kind &&
+          pattern == other.pattern &&
+          replacement == other.replacement &&
+          enabled == other.enabled;
+}
+
+enum PurifyRuleKind {
+  /// Replace every occurrence of `pattern` as plain text.
+  literal,
+  /// Replace matches of a regular expression in Rust `regex` syntax.
+  /// `^` and `$` match at line boundaries, and `replacement` may refer to
+  /// groups as `$1` or `${name}`.
+  regex,
+  /// Remove every line matching a regular expression.
+  line,
+  /// Remove invisible watermark characters and replace look-alike letters,
+  /// as `strip_watermarks` does; `pattern` is ignored.
+  watermark,
+}
+
+/// What one rule matched during a preview.
+class PurifyRuleMatch {
+  final int ruleIndex;
+  final String name;
+  final int count;
+  /// The first few matched texts; whole lines for `Line` rules and
+  /// character descriptions for `Watermark` rules.
+  final List<String> samples;
+
+  const PurifyRuleMatch({
+    required this.ruleIndex,
+    required this.name,
+    required this.count,
+    required this.samples,
+  });
+
+  @override
+  int get hashCode =>
+      ruleIndex.hashCode ^ name.hashCode ^ count.hashCode ^ samples.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PurifyRuleMatch &&
+          runtimeType == other.runtimeType &&
+          ruleIndex == other.ruleIndex &&
+          name == other.name &&
+          count == other.count &&
+          samples == other.samples;
+}
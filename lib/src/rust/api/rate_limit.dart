@@ -0,0 +1,116 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `acquire`, `cool_down`, `emit`, `limit_for`, `limit`, `limiter`, `lock`, `new`, `parse_retry_after`, `refill`, `status`, `take`, `update`, `validate`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Bucket`, `Limiter`, `Limits`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+/// Polite settings for a typical book source.
+Future<RateLimit> defaultRateLimit() =>
+    RustLib.instance.api.crateApiRateLimitDefaultRateLimit();
+
+/// Limit requests to every host, or remove the limit with `None` (the
+/// default). Hosts with their own setting from `set_host_rate_limit` are
+/// not affected.
+Future<void> setRateLimit({RateLimit? limit}) =>
+    RustLib.instance.api.crateApiRateLimitSetRateLimit(limit: limit);
+
+/// Override the rate limit for one book source.
+///
+/// # Arguments
+/// * `host` - Host name, e.g. `www.example.com`; subdomains are not included
+/// * `limit` - Limit for this host, or `None` to send its requests unthrottled
+Future<void> setHostRateLimit({required String host, RateLimit? limit}) =>
+    RustLib.instance.api.crateApiRateLimitSetHostRateLimit(
+      host: host,
+      limit: limit,
+    );
+
+/// Remove a host's rate limit override, so it uses the global limit again.
+Future<void> clearHostRateLimit({required String host}) =>
+    RustLib.instance.api.crateApiRateLimitClearHostRateLimit(host: host);
+
+/// The current state of every rate-limited host that has sent requests.
+Future<List<RateLimitStatus>> rateLimitStatus() =>
+    RustLib.instance.api.crateApiRateLimitRateLimitStatus();
+
+/// Receive a host's status whenever one of its requests starts waiting,
+/// is sent or triggers a cool-down, replacing any previous stream.
+Stream<RateLimitStatus> rateLimitEvents() =>
+    RustLib.instance.api.crateApiRateLimitRateLimitEvents();
+
+class RateLimit {
+  /// Sustained request rate.
+  final double requestsPerSecond;
+  /// Requests that may be sent back to back after a quiet period.
+  final int burst;
+  /// Random delay of up to this many milliseconds added to each request.
+  final int jitterMs;
+  /// Pause after a 429 or 503 response; a longer `Retry-After` wins.
+  final int cooldownSecs;
+
+  const RateLimit({
+    required this.requestsPerSecond,
+    required this.burst,
+    required this.jitterMs,
+    required this.cooldownSecs,
+  });
+
+  @override
+  int get hashCode =>
+      requestsPerSecond.hashCode ^
+      burst.hashCode ^
+      jitterMs.hashCode ^
+      cooldownSecs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RateLimit &&
+          runtimeType == other.runtimeType &&
+          requestsPerSecond == other.requestsPerSecond &&
+          burst == other.burst &&
+          jitterMs == other.jitterMs &&
+          cooldownSecs == other.cooldownSecs;
+}
+
+class RateLimitStatus {
+  final String host;
+  /// Requests blocked waiting for their turn.
+  final int waiting;
+  /// Requests that could be sent immediately.
+  final int available;
+  /// Milliseconds until the host's cool-down ends, or 0.
+  final BigInt cooldownMs;
+
+  const RateLimitStatus({
+    required this.host,
+    required this.waiting,
+    required this.available,
+    required this.cooldownMs,
+  });
+
+  @override
+  int get hashCode =>
+      host.hashCode ^
+      waiting.hashCode ^
+      available.hashCode ^
+      cooldownMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RateLimitStatus &&
+          runtimeType == other.runtimeType &&
+          host == other.host &&
+          waiting == other.waiting &&
+          available == other.available &&
+          cooldownMs == other.cooldownMs;
+}
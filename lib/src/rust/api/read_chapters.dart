@@ -0,0 +1,115 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `contains`, `count_below`, `decode`, `encode`, `first_gap`, `insert`, `load`, `now`, `remap`, `remove`, `save`, `update`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Runs`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+/// Mark chapters as read and return how many were not read before.
+///
+/// # Arguments
+/// * `book_id` - Book the chapters belong to
+/// * `start` - First chapter index
+/// * `end` - Index after the last chapter, e.g. `start + 1` for one chapter
+Future<int> markChaptersRead({
+  required String bookId,
+  required int start,
+  required int end,
+}) => RustLib.instance.api.crateApiReadChaptersMarkChaptersRead(
+  bookId: bookId,
+  start: start,
+  end: end,
+);
+
+/// Mark chapters as unread and return how many were read before.
+///
+/// # Arguments
+/// * `book_id` - Book the chapters belong to
+/// * `start` - First chapter index
+/// * `end` - Index after the last chapter; `u32::MAX` for the rest of the book
+Future<int> markChaptersUnread({
+  required String bookId,
+  required int start,
+  required int end,
+}) => RustLib.instance.api.crateApiReadChaptersMarkChaptersUnread(
+  bookId: bookId,
+  start: start,
+  end: end,
+);
+
+/// Replace a book's read chapters, such as when moving over a list kept
+/// elsewhere.
+///
+/// # Arguments
+/// * `book_id` - Book the chapters belong to
+/// * `chapters` - Indexes of the read chapters, in any order
+Future<void> setReadChapters({
+  required String bookId,
+  required List<int> chapters,
+}) => RustLib.instance.api.crateApiReadChaptersSetReadChapters(
+  bookId: bookId,
+  chapters: chapters,
+);
+
+/// The runs of read chapters of a book, in order.
+///
+/// # Arguments
+/// * `book_id` - Book the chapters belong to
+Future<List<ChapterRange>> readChapterRanges({required String bookId}) =>
+    RustLib.instance.api.crateApiReadChaptersReadChapterRanges(bookId: bookId);
+
+/// How many of a book's chapters have not been read.
+///
+/// # Arguments
+/// * `book_id` - Book the chapters belong to
+/// * `chapter_count` - Chapters the book has; read chapters past it are ignored
+Future<int> unreadChapterCount({
+  required String bookId,
+  required int chapterCount,
+}) => RustLib.instance.api.crateApiReadChaptersUnreadChapterCount(
+  bookId: bookId,
+  chapterCount: chapterCount,
+);
+
+/// The first chapter at or after `from` that has not been read, or `None`
+/// if all the rest have been.
+///
+/// # Arguments
+/// * `book_id` - Book the chapters belong to
+/// * `from` - Index to look from, 0 for the start of the book
+/// * `chapter_count` - Chapters the book has
+Future<int?> firstUnreadChapter({
+  required String bookId,
+  required int from,
+  required int chapterCount,
+}) => RustLib.instance.api.crateApiReadChaptersFirstUnreadChapter(
+  bookId: bookId,
+  from: from,
+  chapterCount: chapterCount,
+);
+
+/// A run of read chapters, `start` included and `end` not.
+class ChapterRange {
+  final int start;
+  final int end;
+
+  const ChapterRange({required this.start, required this.end});
+
+  @override
+  int get hashCode => start.hashCode ^ end.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChapterRange &&
+          runtimeType == other.runtimeType &&
+          start == other.start &&
+          end == other.end;
+}
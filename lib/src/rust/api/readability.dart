@@ -0,0 +1,96 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `content_score`, `element`, `end_block`, `extract`, `find_content`, `image`, `is_dropped`, `is_unlikely`, `names`, `new`, `new`, `normalize`, `push_text`, `text`, `title`, `walk`, `weight`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Builder`, `Hints`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Extract the main text of a scraped chapter page.
+///
+/// # Arguments
+/// * `html` - Page HTML
+/// * `base_url` - URL the page was loaded from, for resolving image sources
+Future<ReadableContent> extractReadable({
+  required String html,
+  String? baseUrl,
+}) => RustLib.instance.api.crateApiReadabilityExtractReadable(
+  html: html,
+  baseUrl: baseUrl,
+);
+
+class ReadableBlock {
+  final ReadableBlockKind kind;
+  final List<ReadableSpan> spans;
+
+  const ReadableBlock({required this.kind, required this.spans});
+
+  @override
+  int get hashCode => kind.hashCode ^ spans.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReadableBlock &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          spans == other.spans;
+}
+
+enum ReadableBlockKind { paragraph, heading }
+
+/// Content extracted from a chapter page.
+class ReadableContent {
+  /// Page heading, or the document title if there is no `<h1>`.
+  final String? title;
+  final List<ReadableBlock> blocks;
+
+  const ReadableContent({this.title, required this.blocks});
+
+  @override
+  int get hashCode => title.hashCode ^ blocks.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReadableContent &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          blocks == other.blocks;
+}
+
+/// A run of text in one style, or an inline image.
+class ReadableSpan {
+  /// Text of the run; the alt text for images.
+  final String text;
+  /// Absolute image URL when this span is an image.
+  final String? imageSrc;
+  final bool bold;
+  final bool italic;
+
+  const ReadableSpan({
+    required this.text,
+    this.imageSrc,
+    required this.bold,
+    required this.italic,
+  });
+
+  @override
+  int get hashCode =>
+      text.hashCode ^ imageSrc.hashCode ^ bold.hashCode ^ italic.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReadableSpan &&
+          runtimeType == other.runtimeType &&
+          text == other.text &&
+          imageSrc == other.imageSrc &&
+          bold == other.bold &&
+          italic == other.italic;
+}
@@ -0,0 +1,73 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'txt.dart';
+
+// These functions are ignored because they are not marked as `pub`: `chapter_at`, `global`, `new`, `now`, `old_chapter`, `position`, `remap`, `replace_stored`, `utf16_len`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `NewChapter`, `Split`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// Split a book's stored chapters again and move its saved positions onto
+/// the new chapters. Every chapter of the book must be in the chapter store.
+///
+/// # Arguments
+/// * `book_id` - Library ID of the book
+/// * `rules` - Heading rules, or `None` for `default_txt_rules`
+/// * `dry_run` - Only work out the outcome, without changing anything
+/// * `cancel_token` - Token from `create_cancel_token`, or `None`
+Future<ResplitOutcome> resplitStoredBook({
+  required String bookId,
+  List<TxtChapterRule>? rules,
+  required bool dryRun,
+  int? cancelToken,
+}) => RustLib.instance.api.crateApiResplitResplitStoredBook(
+  bookId: bookId,
+  rules: rules,
+  dryRun: dryRun,
+  cancelToken: cancelToken,
+);
+
+class ResplitOutcome {
+  /// Titles of the new chapters, in order; empty for text before the
+  /// first heading.
+  final List<String> titles;
+  /// Bookmarks whose chapter or offset changed.
+  final int movedBookmarks;
+  /// Highlights whose chapter or offsets changed.
+  final int movedHighlights;
+  /// Reading position in the new chapters, if the book has progress.
+  final int? chapterIndex;
+  final int? chapterOffset;
+
+  const ResplitOutcome({
+    required this.titles,
+    required this.movedBookmarks,
+    required this.movedHighlights,
+    this.chapterIndex,
+    this.chapterOffset,
+  });
+
+  @override
+  int get hashCode =>
+      titles.hashCode ^
+      movedBookmarks.hashCode ^
+      movedHighlights.hashCode ^
+      chapterIndex.hashCode ^
+      chapterOffset.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ResplitOutcome &&
+          runtimeType == other.runtimeType &&
+          titles == other.titles &&
+          movedBookmarks == other.movedBookmarks &&
+          movedHighlights == other.movedHighlights &&
+          chapterIndex == other.chapterIndex &&
+          chapterOffset == other.chapterOffset;
+}
@@ -0,0 +1,46 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `char_reading`, `is_han`, `phrase_readings`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// Annotate `text` with tone-marked pinyin. The spans cover the whole text
+/// in order.
+Future<List<PinyinSpan>> annotatePinyin({required String text}) =>
+    RustLib.instance.api.crateApiRubyAnnotatePinyin(text: text);
+
+/// A run of text and its pinyin. Han characters get one span each; other
+/// text is grouped into spans with empty pinyin. Offsets are UTF-16 code
+/// units.
+class PinyinSpan {
+  final String text;
+  final String pinyin;
+  final int start;
+  final int end;
+
+  const PinyinSpan({
+    required this.text,
+    required this.pinyin,
+    required this.start,
+    required this.end,
+  });
+
+  @override
+  int get hashCode =>
+      text.hashCode ^ pinyin.hashCode ^ start.hashCode ^ end.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PinyinSpan &&
+          runtimeType == other.runtimeType &&
+          text == other.text &&
+          pinyin == other.pinyin &&
+          start == other.start &&
+          end == other.end;
+}
@@ -0,0 +1,110 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'purify.dart';
+import 'txt.dart';
+
+// These functions are ignored because they are not marked as `pub`: `is_slow`, `lines`, `range`, `time_per_mib`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+/// Run a purify rule on sample text, whether or not it is enabled.
+///
+/// # Arguments
+/// * `rule` - The rule being edited
+/// * `sample_text` - Text to run it on, such as a chapter of the book
+Future<RuleTest> testRule({
+  required PurifyRule rule,
+  required String sampleText,
+}) => RustLib.instance.api.crateApiRuleTestTestRule(
+  rule: rule,
+  sampleText: sampleText,
+);
+
+/// Run a chapter heading rule on the lines of sample text.
+///
+/// # Arguments
+/// * `rule` - The rule being edited
+/// * `sample_text` - Text to run it on, such as the start of a TXT file
+Future<RuleTest> testChapterRule({
+  required TxtChapterRule rule,
+  required String sampleText,
+}) => RustLib.instance.api.crateApiRuleTestTestChapterRule(
+  rule: rule,
+  sampleText: sampleText,
+);
+
+/// What a rule did to sample text.
+class RuleTest {
+  /// The first matches, in order.
+  final List<RuleTestMatch> matches;
+  final int matchCount;
+  /// The sample after a purify rule; empty for chapter rules.
+  final String output;
+  /// Time to compile the pattern, in microseconds.
+  final BigInt compileUs;
+  /// Time to apply the rule to a MiB of text like the sample.
+  final BigInt matchUsPerMib;
+  /// Whether either time is long enough to be felt in the reader.
+  final bool slow;
+
+  const RuleTest({
+    required this.matches,
+    required this.matchCount,
+    required this.output,
+    required this.compileUs,
+    required this.matchUsPerMib,
+    required this.slow,
+  });
+
+  @override
+  int get hashCode =>
+      matches.hashCode ^
+      matchCount.hashCode ^
+      output.hashCode ^
+      compileUs.hashCode ^
+      matchUsPerMib.hashCode ^
+      slow.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RuleTest &&
+          runtimeType == other.runtimeType &&
+          matches == other.matches &&
+          matchCount == other.matchCount &&
+          output == other.output &&
+          compileUs == other.compileUs &&
+          matchUsPerMib == other.matchUsPerMib &&
+          slow == other.slow;
+}
+
+/// A match in the sample. Offsets are UTF-16 code units.
+class RuleTestMatch {
+  final int start;
+  final int end;
+  /// The matched text; the whole line for `Line` rules.
+  final String text;
+
+  const RuleTestMatch({
+    required this.start,
+    required this.end,
+    required this.text,
+  });
+
+  @override
+  int get hashCode => start.hashCode ^ end.hashCode ^ text.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RuleTestMatch &&
+          runtimeType == other.runtimeType &&
+          start == other.start &&
+          end == other.end &&
+          text == other.text;
+}
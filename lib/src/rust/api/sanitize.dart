@@ -0,0 +1,14 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `is_removed`, `sanitize`
+
+/// Sanitize text: normalize to NFC and remove byte order marks, bidi
+/// controls and control characters other than tabs and line breaks.
+Future<String> sanitizeText({required String text}) =>
+    RustLib.instance.api.crateApiSanitizeSanitizeText(text: text);
@@ -0,0 +1,130 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `append`, `bigrams`, `book_ids`, `book_path`, `book`, `bytes`, `candidates`, `digest`, `fold`, `header`, `header`, `live_len`, `lock`, `new`, `new`, `open`, `parse_record`, `post`, `postings`, `put_bytes`, `read_header`, `read_text`, `record`, `remove`, `replay`, `rewrite`, `save_postings`, `search`, `snippet`, `stamp`, `string`, `take`, `u32`, `u64`, `unpost`, `upsert`, `with_index`, `write_upsert`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `BookIndex`, `Reader`, `SearchIndex`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+/// Enable the search index, stored under `index_dir`. Calling this again
+/// switches to the new directory.
+Future<void> initSearchIndex({required String indexDir}) =>
+    RustLib.instance.api.crateApiSearchInitSearchIndex(indexDir: indexDir);
+
+/// Add chapters to a book's index, replacing earlier versions of the same
+/// chapter indexes. Titles and text are sanitized first, as by
+/// `sanitize_text`.
+Future<void> indexChapters({
+  required String bookId,
+  required List<SearchChapter> chapters,
+}) => RustLib.instance.api.crateApiSearchIndexChapters(
+  bookId: bookId,
+  chapters: chapters,
+);
+
+/// Chapter indexes already in a book's index, in ascending order.
+Future<Uint32List> indexedChapters({required String bookId}) =>
+    RustLib.instance.api.crateApiSearchIndexedChapters(bookId: bookId);
+
+/// Drop a book from the index and delete its file.
+Future<void> removeBookFromIndex({required String bookId}) =>
+    RustLib.instance.api.crateApiSearchRemoveBookFromIndex(bookId: bookId);
+
+/// Find `query` in one book, in chapter order.
+Future<List<SearchHit>> searchBook({
+  required String bookId,
+  required String query,
+  required int maxResults,
+}) => RustLib.instance.api.crateApiSearchSearchBook(
+  bookId: bookId,
+  query: query,
+  maxResults: maxResults,
+);
+
+/// Find `query` in every indexed book, ordered by book ID then chapter.
+Future<List<SearchHit>> searchLibrary({
+  required String query,
+  required int maxResults,
+}) => RustLib.instance.api.crateApiSearchSearchLibrary(
+  query: query,
+  maxResults: maxResults,
+);
+
+class SearchChapter {
+  final int chapterIndex;
+  final String title;
+  final String text;
+
+  const SearchChapter({
+    required this.chapterIndex,
+    required this.title,
+    required this.text,
+  });
+
+  @override
+  int get hashCode => chapterIndex.hashCode ^ title.hashCode ^ text.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SearchChapter &&
+          runtimeType == other.runtimeType &&
+          chapterIndex == other.chapterIndex &&
+          title == other.title &&
+          text == other.text;
+}
+
+/// An occurrence of the query. Offsets are UTF-16 code units.
+class SearchHit {
+  final String bookId;
+  final int chapterIndex;
+  final String chapterTitle;
+  /// Start of the match in the chapter text.
+  final int offset;
+  final int length;
+  /// Text around the match on a single line.
+  final String snippet;
+  final int highlightStart;
+  final int highlightEnd;
+
+  const SearchHit({
+    required this.bookId,
+    required this.chapterIndex,
+    required this.chapterTitle,
+    required this.offset,
+    required this.length,
+    required this.snippet,
+    required this.highlightStart,
+    required this.highlightEnd,
+  });
+
+  @override
+  int get hashCode =>
+      bookId.hashCode ^
+      chapterIndex.hashCode ^
+      chapterTitle.hashCode ^
+      offset.hashCode ^
+      length.hashCode ^
+      snippet.hashCode ^
+      highlightStart.hashCode ^
+      highlightEnd.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SearchHit &&
+          runtimeType == other.runtimeType &&
+          bookId == other.bookId &&
+          chapterIndex == other.chapterIndex &&
+          chapterTitle == other.chapterTitle &&
+          offset == other.offset &&
+          length == other.length &&
+          snippet == other.snippet &&
+          highlightStart == other.highlightStart &&
+          highlightEnd == other.highlightEnd;
+}
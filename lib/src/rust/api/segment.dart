@@ -0,0 +1,51 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `byte_offset`, `jieba`, `segments`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// Split `text` into words. The segments cover the whole text in order.
+Future<List<TextSegment>> segment({required String text}) =>
+    RustLib.instance.api.crateApiSegmentSegment(text: text);
+
+/// The word containing the character at `offset`, or `None` if that
+/// character is whitespace or punctuation or `offset` is past the end.
+///
+/// Only the paragraph around `offset` is segmented, so this is cheap to
+/// call with a whole chapter.
+Future<TextSegment?> wordAt({required String text, required int offset}) =>
+    RustLib.instance.api.crateApiSegmentWordAt(text: text, offset: offset);
+
+class TextSegment {
+  final String text;
+  final int start;
+  final int end;
+  /// False for runs of whitespace and punctuation.
+  final bool isWord;
+
+  const TextSegment({
+    required this.text,
+    required this.start,
+    required this.end,
+    required this.isWord,
+  });
+
+  @override
+  int get hashCode =>
+      text.hashCode ^ start.hashCode ^ end.hashCode ^ isWord.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TextSegment &&
+          runtimeType == other.runtimeType &&
+          text == other.text &&
+          start == other.start &&
+          end == other.end &&
+          isWord == other.isWord;
+}
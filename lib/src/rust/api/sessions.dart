@@ -0,0 +1,154 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `beat`, `date`, `day_totals`, `merge`, `now`, `seconds`, `start`, `stats`, `streaks`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+
+/// Start a reading session now and return its ID.
+///
+/// # Arguments
+/// * `book_id` - Book being read
+Future<PlatformInt64> startReadingSession({required String bookId}) =>
+    RustLib.instance.api.crateApiSessionsStartReadingSession(bookId: bookId);
+
+/// Record that the reader is still reading. Returns the ID to use from
+/// now on, which is a new session's after a long pause.
+///
+/// # Arguments
+/// * `session_id` - ID from `start_reading_session` or the last heartbeat
+Future<PlatformInt64> heartbeatReadingSession({
+  required PlatformInt64 sessionId,
+}) => RustLib.instance.api.crateApiSessionsHeartbeatReadingSession(
+  sessionId: sessionId,
+);
+
+/// End a reading session now.
+///
+/// # Arguments
+/// * `session_id` - ID from `start_reading_session` or the last heartbeat
+Future<void> stopReadingSession({required PlatformInt64 sessionId}) => RustLib
+    .instance
+    .api
+    .crateApiSessionsStopReadingSession(sessionId: sessionId);
+
+/// Reading statistics for a time range.
+///
+/// # Arguments
+/// * `from` - Start of the range, in seconds since the Unix epoch
+/// * `to` - End of the range, exclusive
+/// * `utc_offset_minutes` - Offset of the reader's time zone, e.g. 480 for
+///   China Standard Time
+Future<ReadingStats> readingStats({
+  required PlatformInt64 from,
+  required PlatformInt64 to,
+  required int utcOffsetMinutes,
+}) => RustLib.instance.api.crateApiSessionsReadingStats(
+  from: from,
+  to: to,
+  utcOffsetMinutes: utcOffsetMinutes,
+);
+
+/// Delete recorded sessions and return how many were deleted.
+///
+/// # Arguments
+/// * `book_id` - Only this book's sessions, or `None` for all of them
+Future<int> clearReadingSessions({String? bookId}) =>
+    RustLib.instance.api.crateApiSessionsClearReadingSessions(bookId: bookId);
+
+class BookReadingTime {
+  final String bookId;
+  final BigInt seconds;
+  final int sessions;
+  /// End of the last session, in seconds since the Unix epoch.
+  final PlatformInt64 lastReadAt;
+
+  const BookReadingTime({
+    required this.bookId,
+    required this.seconds,
+    required this.sessions,
+    required this.lastReadAt,
+  });
+
+  @override
+  int get hashCode =>
+      bookId.hashCode ^
+      seconds.hashCode ^
+      sessions.hashCode ^
+      lastReadAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BookReadingTime &&
+          runtimeType == other.runtimeType &&
+          bookId == other.bookId &&
+          seconds == other.seconds &&
+          sessions == other.sessions &&
+          lastReadAt == other.lastReadAt;
+}
+
+class ReadingDay {
+  /// Local date as `YYYY-MM-DD`.
+  final String date;
+  final BigInt seconds;
+
+  const ReadingDay({required this.date, required this.seconds});
+
+  @override
+  int get hashCode => date.hashCode ^ seconds.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReadingDay &&
+          runtimeType == other.runtimeType &&
+          date == other.date &&
+          seconds == other.seconds;
+}
+
+class ReadingStats {
+  /// Reading time in the range, overlaps counted once.
+  final BigInt totalSeconds;
+  /// Days with reading in the range, in date order.
+  final List<ReadingDay> days;
+  /// Books read in the range, most read first.
+  final List<BookReadingTime> books;
+  /// Consecutive days with reading up to the end of the range; the last
+  /// day not having been read yet does not break it.
+  final int currentStreak;
+  /// Longest run of consecutive days with reading up to the end of the range.
+  final int longestStreak;
+
+  const ReadingStats({
+    required this.totalSeconds,
+    required this.days,
+    required this.books,
+    required this.currentStreak,
+    required this.longestStreak,
+  });
+
+  @override
+  int get hashCode =>
+      totalSeconds.hashCode ^
+      days.hashCode ^
+      books.hashCode ^
+      currentStreak.hashCode ^
+      longestStreak.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReadingStats &&
+          runtimeType == other.runtimeType &&
+          totalSeconds == other.totalSeconds &&
+          days == other.days &&
+          books == other.books &&
+          currentStreak == other.currentStreak &&
+          longestStreak == other.longestStreak;
+}
@@ -0,0 +1,116 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'book_source.dart';
+import 'downloader.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `best_book`, `best_chapter`, `chapter_key`, `chapter_score`, `normalize`, `parallel`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+/// Search `sources` for a book, best match first. Sources that fail or
+/// have no match are left out.
+///
+/// # Arguments
+/// * `sources` - Sources to try; leave out the one that failed
+/// * `title` - Book title
+/// * `author` - Book author, if known; a different author rules a result out
+Future<List<AlternativeSource>> findAlternativeSources({
+  required List<BookSource> sources,
+  required String title,
+  String? author,
+}) => RustLib.instance.api.crateApiSourceSwitchFindAlternativeSources(
+  sources: sources,
+  title: title,
+  author: author,
+);
+
+/// Fetch a chapter from the best source that has it.
+///
+/// # Arguments
+/// * `sources` - Sources to try; leave out the one that failed
+/// * `title` - Book title
+/// * `author` - Book author, if known
+/// * `chapter_title` - Title of the missing chapter
+/// * `chapter_index` - Position of the chapter in the current table of
+///   contents, used to choose between equally good matches
+Future<AlternativeChapter> fetchChapterFromAlternatives({
+  required List<BookSource> sources,
+  required String title,
+  String? author,
+  required String chapterTitle,
+  int? chapterIndex,
+}) => RustLib.instance.api.crateApiSourceSwitchFetchChapterFromAlternatives(
+  sources: sources,
+  title: title,
+  author: author,
+  chapterTitle: chapterTitle,
+  chapterIndex: chapterIndex,
+);
+
+class AlternativeChapter {
+  final BookSource source;
+  final SourceBook book;
+  final SourceChapter chapter;
+  /// Plain text, as from `fetch_source_content`.
+  final String content;
+  /// How well the chapter title matches, from 0 to 1.
+  final double score;
+
+  const AlternativeChapter({
+    required this.source,
+    required this.book,
+    required this.chapter,
+    required this.content,
+    required this.score,
+  });
+
+  @override
+  int get hashCode =>
+      source.hashCode ^
+      book.hashCode ^
+      chapter.hashCode ^
+      content.hashCode ^
+      score.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AlternativeChapter &&
+          runtimeType == other.runtimeType &&
+          source == other.source &&
+          book == other.book &&
+          chapter == other.chapter &&
+          content == other.content &&
+          score == other.score;
+}
+
+class AlternativeSource {
+  final BookSource source;
+  /// The book as found on `source`.
+  final SourceBook book;
+  /// How well the book matches the title and author, from 0 to 1.
+  final double score;
+
+  const AlternativeSource({
+    required this.source,
+    required this.book,
+    required this.score,
+  });
+
+  @override
+  int get hashCode => source.hashCode ^ book.hashCode ^ score.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AlternativeSource &&
+          runtimeType == other.runtimeType &&
+          source == other.source &&
+          book == other.book &&
+          score == other.score;
+}
@@ -0,0 +1,89 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `check_speed`, `count`, `is_cjk`, `is_punctuation`, `reading_minutes`, `sum`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+/// Count the characters and words of `text`.
+///
+/// # Arguments
+/// * `text` - Chapter text
+/// * `chars_per_minute` - Reading speed in CJK characters per minute
+Future<TextStats> countText({
+  required String text,
+  required int charsPerMinute,
+}) => RustLib.instance.api.crateApiStatsCountText(
+  text: text,
+  charsPerMinute: charsPerMinute,
+);
+
+/// Count every chapter of a book, plus the book's total.
+///
+/// # Arguments
+/// * `chapters` - Chapter texts
+/// * `chars_per_minute` - Reading speed in CJK characters per minute
+Future<BookStats> countBook({
+  required List<String> chapters,
+  required int charsPerMinute,
+}) => RustLib.instance.api.crateApiStatsCountBook(
+  chapters: chapters,
+  charsPerMinute: charsPerMinute,
+);
+
+class BookStats {
+  final TextStats total;
+  /// Per-chapter statistics, in input order.
+  final List<TextStats> chapters;
+
+  const BookStats({required this.total, required this.chapters});
+
+  @override
+  int get hashCode => total.hashCode ^ chapters.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BookStats &&
+          runtimeType == other.runtimeType &&
+          total == other.total &&
+          chapters == other.chapters;
+}
+
+class TextStats {
+  /// Han characters, kana and hangul.
+  final int cjkChars;
+  /// Runs of letters and digits outside CJK scripts.
+  final int latinWords;
+  final int punctuation;
+  final double readingMinutes;
+
+  const TextStats({
+    required this.cjkChars,
+    required this.latinWords,
+    required this.punctuation,
+    required this.readingMinutes,
+  });
+
+  @override
+  int get hashCode =>
+      cjkChars.hashCode ^
+      latinWords.hashCode ^
+      punctuation.hashCode ^
+      readingMinutes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TextStats &&
+          runtimeType == other.runtimeType &&
+          cjkChars == other.cjkChars &&
+          latinWords == other.latinWords &&
+          punctuation == other.punctuation &&
+          readingMinutes == other.readingMinutes;
+}
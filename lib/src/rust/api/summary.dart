@@ -0,0 +1,54 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `is_candidate`, `lock`, `options`, `summarize`, `words`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// Two sentences of at most 80 characters.
+Future<SummaryOptions> defaultSummaryOptions() =>
+    RustLib.instance.api.crateApiSummaryDefaultSummaryOptions();
+
+/// Set the options used for summaries written to the chapter cache.
+/// Chapters cached earlier keep their summaries.
+Future<void> setSummaryOptions({required SummaryOptions options}) =>
+    RustLib.instance.api.crateApiSummarySetSummaryOptions(options: options);
+
+/// Summarize a chapter in a sentence or two taken from its text. Returns
+/// an empty string if the chapter has no sentence to take.
+///
+/// # Arguments
+/// * `text` - Plain chapter text
+/// * `options` - Length limits
+Future<String> summarizeChapter({
+  required String text,
+  required SummaryOptions options,
+}) => RustLib.instance.api.crateApiSummarySummarizeChapter(
+  text: text,
+  options: options,
+);
+
+class SummaryOptions {
+  /// Longest summary, in characters; a longer single sentence is cut
+  /// and ends in `…`.
+  final int maxChars;
+  final int maxSentences;
+
+  const SummaryOptions({required this.maxChars, required this.maxSentences});
+
+  @override
+  int get hashCode => maxChars.hashCode ^ maxSentences.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SummaryOptions &&
+          runtimeType == other.runtimeType &&
+          maxChars == other.maxChars &&
+          maxSentences == other.maxSentences;
+}
@@ -0,0 +1,147 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'webdav.dart';
+
+// These functions are ignored because they are not marked as `pub`: `add_bookmark`, `add_counts`, `add_highlight`, `add_progress`, `apply_replica`, `blob_name`, `blob_version`, `blobs_after`, `bury`, `decode`, `delta_count`, `derive`, `encode`, `export_replica`, `fingerprint`, `fingerprints`, `hex`, `len`, `manifest_condition`, `merge`, `new`, `open`, `parse`, `progress_fingerprint`, `read_manifest`, `records`, `result`, `seal`, `sync_with`, `sync`, `to_json`, `tombstone_fingerprint`, `tombstone_list`, `without`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Manifest`, `Replica`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+/// Sync the open library's reading data through a WebDAV directory.
+///
+/// # Arguments
+/// * `config` - Server and credentials
+/// * `remote_dir` - Directory for sync data, relative to the base URL;
+///   created if missing
+/// * `passphrase` - Sync passphrase, the same on every device
+/// * `state` - State returned by the last sync, if any
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+Future<SyncResult> syncWebdav({
+  required WebDavConfig config,
+  required String remoteDir,
+  required String passphrase,
+  SyncState? state,
+  int? cancelToken,
+}) => RustLib.instance.api.crateApiSyncSyncWebdav(
+  config: config,
+  remoteDir: remoteDir,
+  passphrase: passphrase,
+  state: state,
+  cancelToken: cancelToken,
+);
+
+/// Sync the open library's reading data through an HTTP blob store that
+/// answers `GET`, `PUT` and `DELETE` on `<base_url>/<name>` and honours
+/// `If-Match` and `If-None-Match` with HTTP 412.
+///
+/// # Arguments
+/// * `base_url` - URL of the user's sync area
+/// * `bearer_token` - Sent as `Authorization: Bearer`, if any
+/// * `passphrase` - Sync passphrase, the same on every device
+/// * `state` - State returned by the last sync, if any
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+Future<SyncResult> syncHttp({
+  required String baseUrl,
+  String? bearerToken,
+  required String passphrase,
+  SyncState? state,
+  int? cancelToken,
+}) => RustLib.instance.api.crateApiSyncSyncHttp(
+  baseUrl: baseUrl,
+  bearerToken: bearerToken,
+  passphrase: passphrase,
+  state: state,
+  cancelToken: cancelToken,
+);
+
+class SyncResult {
+  /// Remote version after the sync.
+  final int version;
+  /// Records uploaded, 0 if nothing changed locally.
+  final int uploaded;
+  /// Records from other devices merged into the library.
+  final int downloaded;
+  final int progressUpdated;
+  final int bookmarksAdded;
+  final int highlightsAdded;
+  /// Deleted because they were deleted on another device.
+  final int bookmarksRemoved;
+  final int highlightsRemoved;
+  /// Store and pass to the next sync.
+  final SyncState state;
+
+  const SyncResult({
+    required this.version,
+    required this.uploaded,
+    required this.downloaded,
+    required this.progressUpdated,
+    required this.bookmarksAdded,
+    required this.highlightsAdded,
+    required this.bookmarksRemoved,
+    required this.highlightsRemoved,
+    required this.state,
+  });
+
+  @override
+  int get hashCode =>
+      version.hashCode ^
+      uploaded.hashCode ^
+      downloaded.hashCode ^
+      progressUpdated.hashCode ^
+      bookmarksAdded.hashCode ^
+      highlightsAdded.hashCode ^
+      bookmarksRemoved.hashCode ^
+      highlightsRemoved.hashCode ^
+      state.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SyncResult &&
+          runtimeType == other.runtimeType &&
+          version == other.version &&
+          uploaded == other.uploaded &&
+          downloaded == other.downloaded &&
+          progressUpdated == other.progressUpdated &&
+          bookmarksAdded == other.bookmarksAdded &&
+          highlightsAdded == other.highlightsAdded &&
+          bookmarksRemoved == other.bookmarksRemoved &&
+          highlightsRemoved == other.highlightsRemoved &&
+          state == other.state;
+}
+
+/// What a device knew after its last sync. Store it and pass it to the
+/// next sync; without it, everything is uploaded again.
+class SyncState {
+  /// ID of the remote data; it changes when the remote is reset.
+  final String remoteId;
+  /// Remote version merged last.
+  final int version;
+  /// Hashes of the records on the remote, to find what changed locally.
+  final List<String> fingerprints;
+
+  const SyncState({
+    required this.remoteId,
+    required this.version,
+    required this.fingerprints,
+  });
+
+  @override
+  int get hashCode =>
+      remoteId.hashCode ^ version.hashCode ^ fingerprints.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SyncState &&
+          runtimeType == other.runtimeType &&
+          remoteId == other.remoteId &&
+          version == other.version &&
+          fingerprints == other.fingerprints;
+}
@@ -0,0 +1,26 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `cancel_token`, `cancel`, `cancelled`, `check`, `io_pool`, `is_cancelled`, `key`, `lock`, `pool`, `run_blocking_io`, `run_blocking`, `run_on`, `wakers`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CancelToken`, `Inner`, `Pending`, `Slot`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `drop`, `poll`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `default`
+
+/// Create a cancel token to pass to a long-running call.
+Future<int> createCancelToken() =>
+    RustLib.instance.api.crateApiTaskCreateCancelToken();
+
+/// Cancel every call made with `token`, including ones made later.
+/// Returns false if the token does not exist.
+Future<bool> cancelTask({required int token}) =>
+    RustLib.instance.api.crateApiTaskCancelTask(token: token);
+
+/// Forget a token once the calls using it have finished. Cancelling a call
+/// still needs the token, so do not release it while one is running.
+Future<void> releaseCancelToken({required int token}) =>
+    RustLib.instance.api.crateApiTaskReleaseCancelToken(token: token);
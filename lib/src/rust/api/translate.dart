@@ -0,0 +1,129 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `batches`, `chat`, `endpoint_url`, `json_array`, `post`, `request`, `strings`, `translate`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+/// A config for a LibreTranslate server on this device.
+Future<TranslatorConfig> defaultTranslatorConfig() =>
+    RustLib.instance.api.crateApiTranslateDefaultTranslatorConfig();
+
+/// Translate paragraphs, returning one translation per paragraph in the
+/// same order. Blank paragraphs are not sent and translate to empty
+/// strings.
+///
+/// # Arguments
+/// * `paragraphs` - Paragraphs to translate
+/// * `config` - Service and languages
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+Future<List<String>> translateParagraphs({
+  required List<String> paragraphs,
+  required TranslatorConfig config,
+  int? cancelToken,
+}) => RustLib.instance.api.crateApiTranslateTranslateParagraphs(
+  paragraphs: paragraphs,
+  config: config,
+  cancelToken: cancelToken,
+);
+
+/// Translate chapter text paragraph by paragraph, one paragraph per line.
+///
+/// # Arguments
+/// * `text` - Chapter text
+/// * `config` - Service and languages
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+Future<List<TranslatedParagraph>> translateChapter({
+  required String text,
+  required TranslatorConfig config,
+  int? cancelToken,
+}) => RustLib.instance.api.crateApiTranslateTranslateChapter(
+  text: text,
+  config: config,
+  cancelToken: cancelToken,
+);
+
+class TranslatedParagraph {
+  final String original;
+  /// Empty for blank paragraphs.
+  final String translation;
+
+  const TranslatedParagraph({
+    required this.original,
+    required this.translation,
+  });
+
+  @override
+  int get hashCode => original.hashCode ^ translation.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TranslatedParagraph &&
+          runtimeType == other.runtimeType &&
+          original == other.original &&
+          translation == other.translation;
+}
+
+enum TranslationProvider {
+  /// A LibreTranslate server; `endpoint` is its base URL.
+  libreTranslate,
+  /// The DeepL API; `endpoint` is `https://api-free.deepl.com` or
+  /// `https://api.deepl.com`.
+  deepL,
+  /// An OpenAI-compatible chat completions API; `endpoint` is its base URL
+  /// such as `https://api.openai.com/v1` or `http://localhost:11434/v1`.
+  openAi,
+}
+
+class TranslatorConfig {
+  final TranslationProvider provider;
+  final String endpoint;
+  final String? apiKey;
+  /// Model name, required for `OpenAi`.
+  final String? model;
+  /// Language code of the text, or `None` to detect it.
+  final String? sourceLanguage;
+  /// Language code to translate into, such as `en` or `zh`.
+  final String targetLanguage;
+  /// Seconds to wait for each response.
+  final int timeoutSecs;
+
+  const TranslatorConfig({
+    required this.provider,
+    required this.endpoint,
+    this.apiKey,
+    this.model,
+    this.sourceLanguage,
+    required this.targetLanguage,
+    required this.timeoutSecs,
+  });
+
+  @override
+  int get hashCode =>
+      provider.hashCode ^
+      endpoint.hashCode ^
+      apiKey.hashCode ^
+      model.hashCode ^
+      sourceLanguage.hashCode ^
+      targetLanguage.hashCode ^
+      timeoutSecs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TranslatorConfig &&
+          runtimeType == other.runtimeType &&
+          provider == other.provider &&
+          endpoint == other.endpoint &&
+          apiKey == other.apiKey &&
+          model == other.model &&
+          sourceLanguage == other.sourceLanguage &&
+          targetLanguage == other.targetLanguage &&
+          timeoutSecs == other.timeoutSecs;
+}
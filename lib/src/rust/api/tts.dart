@@ -0,0 +1,167 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'tts_prep.dart';
+
+// These functions are ignored because they are not marked as `pub`: `get`, `info`, `lock`, `parse`, `parse`, `phoneme_ids`, `resample`, `symbols_of`, `synthesize_wav`, `synthesize`, `to_pcm`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Lexicon`, `VoiceConfig`, `Voice`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+/// Load the ONNX Runtime library. Only the first call in a process takes
+/// effect; later calls return without reloading.
+///
+/// # Arguments
+/// * `library_path` - Path of `libonnxruntime.so`, `onnxruntime.dll` or the
+///   framework binary on Apple platforms
+Future<void> initTtsRuntime({required String libraryPath}) =>
+    RustLib.instance.api.crateApiTtsInitTtsRuntime(libraryPath: libraryPath);
+
+/// Load a voice, replacing the current one.
+///
+/// # Arguments
+/// * `model_path` - Voice model (`.onnx`)
+/// * `config_path` - Voice config (`.onnx.json`)
+/// * `lexicon_path` - Optional lexicon mapping words to phoneme symbols
+Future<TtsVoiceInfo> loadTtsVoice({
+  required String modelPath,
+  required String configPath,
+  String? lexiconPath,
+}) => RustLib.instance.api.crateApiTtsLoadTtsVoice(
+  modelPath: modelPath,
+  configPath: configPath,
+  lexiconPath: lexiconPath,
+);
+
+/// Unload the current voice and free its model.
+Future<void> unloadTtsVoice() =>
+    RustLib.instance.api.crateApiTtsUnloadTtsVoice();
+
+Future<TtsOptions> defaultTtsOptions() =>
+    RustLib.instance.api.crateApiTtsDefaultTtsOptions();
+
+/// Synthesise one utterance with the current voice.
+///
+/// # Arguments
+/// * `text` - Sentence-sized text; see `prepare_tts`
+/// * `options` - Speed, pitch and speaker
+Future<Uint8List> synthesizeSpeech({
+  required String text,
+  required TtsOptions options,
+}) => RustLib.instance.api.crateApiTtsSynthesizeSpeech(
+  text: text,
+  options: options,
+);
+
+/// Synthesise utterances in order, sending each as soon as it is ready so
+/// playback can start after the first sentence. Each one's duration is
+/// recorded in the TTS timeline (see `start_tts_timeline`).
+///
+/// # Arguments
+/// * `utterances` - Utterances from `prepare_tts`
+/// * `options` - Speed, pitch and speaker
+Stream<TtsAudioChunk> synthesizeSpeechStream({
+  required List<Utterance> utterances,
+  required TtsOptions options,
+}) => RustLib.instance.api.crateApiTtsSynthesizeSpeechStream(
+  utterances: utterances,
+  options: options,
+);
+
+/// Wrap 16-bit mono PCM in a WAV header.
+///
+/// # Arguments
+/// * `pcm` - 16-bit little-endian mono samples
+/// * `sample_rate` - Samples per second
+Future<Uint8List> pcmToWav({required List<int> pcm, required int sampleRate}) =>
+    RustLib.instance.api.crateApiTtsPcmToWav(pcm: pcm, sampleRate: sampleRate);
+
+/// One synthesised utterance of a stream.
+class TtsAudioChunk {
+  /// ID of the utterance the audio speaks.
+  final String utteranceId;
+  final int sampleRate;
+  /// 16-bit little-endian mono PCM.
+  final Uint8List pcm;
+
+  const TtsAudioChunk({
+    required this.utteranceId,
+    required this.sampleRate,
+    required this.pcm,
+  });
+
+  @override
+  int get hashCode => utteranceId.hashCode ^ sampleRate.hashCode ^ pcm.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TtsAudioChunk &&
+          runtimeType == other.runtimeType &&
+          utteranceId == other.utteranceId &&
+          sampleRate == other.sampleRate &&
+          pcm == other.pcm;
+}
+
+class TtsOptions {
+  /// Playback rate; 1.0 is the voice's natural speed.
+  final double speed;
+  /// Pitch factor; 1.0 keeps the voice's pitch, 1.1 is about 1.6 semitones up.
+  final double pitch;
+  /// Speaker of a multi-speaker voice; ignored otherwise.
+  final int speaker;
+
+  const TtsOptions({
+    required this.speed,
+    required this.pitch,
+    required this.speaker,
+  });
+
+  @override
+  int get hashCode => speed.hashCode ^ pitch.hashCode ^ speaker.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TtsOptions &&
+          runtimeType == other.runtimeType &&
+          speed == other.speed &&
+          pitch == other.pitch &&
+          speaker == other.speaker;
+}
+
+class TtsVoiceInfo {
+  final int sampleRate;
+  final int speakers;
+  /// Language code from the config, e.g. `zh_CN`; empty if missing.
+  final String language;
+  final bool usesLexicon;
+
+  const TtsVoiceInfo({
+    required this.sampleRate,
+    required this.speakers,
+    required this.language,
+    required this.usesLexicon,
+  });
+
+  @override
+  int get hashCode =>
+      sampleRate.hashCode ^
+      speakers.hashCode ^
+      language.hashCode ^
+      usesLexicon.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TtsVoiceInfo &&
+          runtimeType == other.runtimeType &&
+          sampleRate == other.sampleRate &&
+          speakers == other.speakers &&
+          language == other.language &&
+          usesLexicon == other.usesLexicon;
+}
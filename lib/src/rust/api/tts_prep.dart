@@ -0,0 +1,107 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `after_word`, `count`, `digits`, `escape`, `expand`, `integer_zh`, `is_han`, `new`, `normalized`, `number`, `push_segment`, `read_as_amount`, `say_as`, `sentences`, `split_long`, `spoken`, `ssml`, `utterances`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+Future<SsmlOptions> defaultSsmlOptions() =>
+    RustLib.instance.api.crateApiTtsPrepDefaultSsmlOptions();
+
+/// Split chapter text into utterances in reading order. Sentences with
+/// nothing to speak, such as separator lines, are skipped.
+Future<List<Utterance>> prepareTts({required String text}) =>
+    RustLib.instance.api.crateApiTtsPrepPrepareTts(text: text);
+
+/// Split chapter text into utterances as `prepare_tts` does, each with an
+/// SSML document next to its plain text.
+///
+/// # Arguments
+/// * `text` - Chapter text
+/// * `options` - Language, paragraph pauses and dialogue prosody
+Future<List<Utterance>> prepareTtsSsml({
+  required String text,
+  required SsmlOptions options,
+}) => RustLib.instance.api.crateApiTtsPrepPrepareTtsSsml(
+  text: text,
+  options: options,
+);
+
+class SsmlOptions {
+  /// Language of the text for `xml:lang`, e.g. `zh-CN`.
+  final String language;
+  /// Pause after each paragraph, in milliseconds; 0 for none.
+  final int paragraphBreakMs;
+  /// Prosody pitch for quoted dialogue, e.g. `+5%`, or `None` to leave it
+  /// to the engine.
+  final String? dialoguePitch;
+  /// Prosody rate for quoted dialogue, e.g. `95%`.
+  final String? dialogueRate;
+
+  const SsmlOptions({
+    required this.language,
+    required this.paragraphBreakMs,
+    this.dialoguePitch,
+    this.dialogueRate,
+  });
+
+  @override
+  int get hashCode =>
+      language.hashCode ^
+      paragraphBreakMs.hashCode ^
+      dialoguePitch.hashCode ^
+      dialogueRate.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SsmlOptions &&
+          runtimeType == other.runtimeType &&
+          language == other.language &&
+          paragraphBreakMs == other.paragraphBreakMs &&
+          dialoguePitch == other.dialoguePitch &&
+          dialogueRate == other.dialogueRate;
+}
+
+class Utterance {
+  final String id;
+  /// Text to speak.
+  final String text;
+  /// UTF-16 range of the sentence in the chapter text.
+  final int start;
+  final int end;
+  /// SSML document to send instead of `text` to engines that accept it;
+  /// only set by `prepare_tts_ssml`.
+  final String? ssml;
+
+  const Utterance({
+    required this.id,
+    required this.text,
+    required this.start,
+    required this.end,
+    this.ssml,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      text.hashCode ^
+      start.hashCode ^
+      end.hashCode ^
+      ssml.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Utterance &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          text == other.text &&
+          start == other.start &&
+          end == other.end &&
+          ssml == other.ssml;
+}
@@ -0,0 +1,83 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'tts_prep.dart';
+
+// These functions are ignored because they are not marked as `pub`: `lock`, `new`, `position_for_time`, `record_pcm`, `record`, `starts`, `time_for_position`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Timeline`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+/// Start the timeline of a chapter, forgetting the previous one.
+///
+/// # Arguments
+/// * `utterances` - Utterances in playback order, from `prepare_tts`
+Future<void> startTtsTimeline({required List<Utterance> utterances}) => RustLib
+    .instance
+    .api
+    .crateApiTtsTimelineStartTtsTimeline(utterances: utterances);
+
+/// Record the duration of an utterance's audio. Utterances skipped in
+/// playback should be recorded with a duration of 0.
+///
+/// # Arguments
+/// * `utterance_id` - ID from `prepare_tts`; unknown IDs are ignored
+/// * `duration_ms` - Length of its audio in milliseconds
+Future<void> recordTtsDuration({
+  required String utteranceId,
+  required BigInt durationMs,
+}) => RustLib.instance.api.crateApiTtsTimelineRecordTtsDuration(
+  utteranceId: utteranceId,
+  durationMs: durationMs,
+);
+
+/// The text being spoken at a playback time, or `None` if it is past the
+/// utterances whose duration is known.
+///
+/// # Arguments
+/// * `time_ms` - Playback time from the start of the chapter's audio
+Future<TtsPosition?> positionForTime({required BigInt timeMs}) =>
+    RustLib.instance.api.crateApiTtsTimelinePositionForTime(timeMs: timeMs);
+
+/// The playback time at which the sentence containing `offset` starts, or
+/// the next sentence if `offset` is between sentences. `None` if that
+/// sentence comes after an utterance whose duration is not known yet.
+///
+/// # Arguments
+/// * `offset` - UTF-16 offset in the chapter text, e.g. the reading position
+Future<BigInt?> timeForPosition({required int offset}) =>
+    RustLib.instance.api.crateApiTtsTimelineTimeForPosition(offset: offset);
+
+/// Where playback is in the chapter text.
+class TtsPosition {
+  final String utteranceId;
+  /// UTF-16 range of the sentence being spoken.
+  final int start;
+  final int end;
+  /// Estimated UTF-16 offset being spoken within the sentence.
+  final int offset;
+
+  const TtsPosition({
+    required this.utteranceId,
+    required this.start,
+    required this.end,
+    required this.offset,
+  });
+
+  @override
+  int get hashCode =>
+      utteranceId.hashCode ^ start.hashCode ^ end.hashCode ^ offset.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TtsPosition &&
+          runtimeType == other.runtimeType &&
+          utteranceId == other.utteranceId &&
+          start == other.start &&
+          end == other.end &&
+          offset == other.offset;
+}
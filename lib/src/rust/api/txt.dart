@@ -0,0 +1,124 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'book.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `chapter_html`, `compile_rules`, `decode`, `detect_encoding`, `heading`, `parse_file`, `parse`, `read_line`, `read_sample`, `scan`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+/// The built-in heading rules used when no custom rules are given.
+Future<List<TxtChapterRule>> defaultTxtRules() =>
+    RustLib.instance.api.crateApiTxtDefaultTxtRules();
+
+/// Scan a text file for chapter headings without loading it into memory.
+///
+/// # Arguments
+/// * `path` - Path to the text file
+/// * `rules` - Heading rules; `None` uses `default_txt_rules()`
+Future<TxtIndex> indexTxt({
+  required String path,
+  List<TxtChapterRule>? rules,
+}) => RustLib.instance.api.crateApiTxtIndexTxt(path: path, rules: rules);
+
+/// Read one chapter of an indexed file as HTML.
+///
+/// # Arguments
+/// * `path` - Path to the text file
+/// * `chapter` - Chapter from `index_txt` for the same file
+Future<String> readTxtChapter({
+  required String path,
+  required TxtChapter chapter,
+}) => RustLib.instance.api.crateApiTxtReadTxtChapter(
+  path: path,
+  chapter: chapter,
+);
+
+/// Parse a whole text file into chapters, using the file name as the title.
+///
+/// # Arguments
+/// * `path` - Path to the text file, at most 50 MB
+/// * `rules` - Heading rules; `None` uses `default_txt_rules()`
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+Future<ParsedBook> parseTxt({
+  required String path,
+  List<TxtChapterRule>? rules,
+  int? cancelToken,
+}) => RustLib.instance.api.crateApiTxtParseTxt(
+  path: path,
+  rules: rules,
+  cancelToken: cancelToken,
+);
+
+/// Byte range of one chapter in the source file.
+class TxtChapter {
+  /// Heading line, or `None` for text before the first heading and for
+  /// fixed-size parts of files without headings.
+  final String? title;
+  final int start;
+  final int end;
+
+  const TxtChapter({this.title, required this.start, required this.end});
+
+  @override
+  int get hashCode => title.hashCode ^ start.hashCode ^ end.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TxtChapter &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          start == other.start &&
+          end == other.end;
+}
+
+/// A chapter heading pattern, matched against each trimmed line.
+class TxtChapterRule {
+  final String name;
+  /// Regular expression in Rust `regex` syntax.
+  final String pattern;
+
+  const TxtChapterRule({required this.name, required this.pattern});
+
+  @override
+  int get hashCode => name.hashCode ^ pattern.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TxtChapterRule &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          pattern == other.pattern;
+}
+
+/// Chapter offsets of a text file.
+class TxtIndex {
+  /// Detected encoding label, e.g. `UTF-8` or `GBK`.
+  final String encoding;
+  final int fileSize;
+  final List<TxtChapter> chapters;
+
+  const TxtIndex({
+    required this.encoding,
+    required this.fileSize,
+    required this.chapters,
+  });
+
+  @override
+  int get hashCode => encoding.hashCode ^ fileSize.hashCode ^ chapters.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TxtIndex &&
+          runtimeType == other.runtimeType &&
+          encoding == other.encoding &&
+          fileSize == other.fileSize &&
+          chapters == other.chapters;
+}
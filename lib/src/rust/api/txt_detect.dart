@@ -0,0 +1,102 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'txt.dart';
+
+// These functions are ignored because they are not marked as `pub`: `detect`, `fullwidth_number`, `roman_number`, `score`, `shape`, `split`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Shape`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+/// Score every line of a text file as a possible chapter heading.
+///
+/// # Arguments
+/// * `path` - Path to the text file
+Future<TxtChapterProposal> detectTxtChapters({required String path}) =>
+    RustLib.instance.api.crateApiTxtDetectDetectTxtChapters(path: path);
+
+/// Split a text file at confirmed headings, as `index_txt` would at rule
+/// matches.
+///
+/// # Arguments
+/// * `path` - Path to the text file
+/// * `headings` - Headings to split at, usually the selected candidates
+///   from `detect_txt_chapters` for the same file
+Future<TxtIndex> indexTxtAtHeadings({
+  required String path,
+  required List<TxtHeadingCandidate> headings,
+}) => RustLib.instance.api.crateApiTxtDetectIndexTxtAtHeadings(
+  path: path,
+  headings: headings,
+);
+
+/// Proposed chapter split of a text file.
+class TxtChapterProposal {
+  /// Detected encoding label, e.g. `UTF-8` or `GBK`.
+  final String encoding;
+  final int fileSize;
+  /// Candidates in file order.
+  final List<TxtHeadingCandidate> headings;
+
+  const TxtChapterProposal({
+    required this.encoding,
+    required this.fileSize,
+    required this.headings,
+  });
+
+  @override
+  int get hashCode => encoding.hashCode ^ fileSize.hashCode ^ headings.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TxtChapterProposal &&
+          runtimeType == other.runtimeType &&
+          encoding == other.encoding &&
+          fileSize == other.fileSize &&
+          headings == other.headings;
+}
+
+/// A line that may be a chapter heading.
+class TxtHeadingCandidate {
+  final String title;
+  /// Byte offset of the line in the file.
+  final int start;
+  /// Zero-based line number.
+  final int line;
+  /// How likely the line is a heading, from 0 to 1.
+  final double confidence;
+  /// Whether the line is part of the proposed split.
+  final bool selected;
+
+  const TxtHeadingCandidate({
+    required this.title,
+    required this.start,
+    required this.line,
+    required this.confidence,
+    required this.selected,
+  });
+
+  @override
+  int get hashCode =>
+      title.hashCode ^
+      start.hashCode ^
+      line.hashCode ^
+      confidence.hashCode ^
+      selected.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TxtHeadingCandidate &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          start == other.start &&
+          line == other.line &&
+          confidence == other.confidence &&
+          selected == other.selected;
+}
@@ -0,0 +1,64 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `curly_quotes`, `full_width`, `is_cjk`, `to_full_width`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// The passes applied by default; curly quotes are opt-in.
+Future<TypographyOptions> defaultTypographyOptions() =>
+    RustLib.instance.api.crateApiTypographyDefaultTypographyOptions();
+
+/// Normalize the typography of chapter text.
+Future<String> normalizeTypography({
+  required String text,
+  required TypographyOptions options,
+}) => RustLib.instance.api.crateApiTypographyNormalizeTypography(
+  text: text,
+  options: options,
+);
+
+class TypographyOptions {
+  /// Convert `,.?!:;()` next to CJK text to their full-width forms.
+  final bool fullWidthPunctuation;
+  /// Keep at most one blank line between paragraphs.
+  final bool collapseBlankLines;
+  /// Start every paragraph with a two-character ideographic indent,
+  /// replacing any other leading whitespace.
+  final bool indentParagraphs;
+  /// Write ellipses as `……` and dashes as `——`.
+  final bool unifyEllipsesAndDashes;
+  /// Turn straight quotes into curly ones.
+  final bool curlyQuotes;
+
+  const TypographyOptions({
+    required this.fullWidthPunctuation,
+    required this.collapseBlankLines,
+    required this.indentParagraphs,
+    required this.unifyEllipsesAndDashes,
+    required this.curlyQuotes,
+  });
+
+  @override
+  int get hashCode =>
+      fullWidthPunctuation.hashCode ^
+      collapseBlankLines.hashCode ^
+      indentParagraphs.hashCode ^
+      unifyEllipsesAndDashes.hashCode ^
+      curlyQuotes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TypographyOptions &&
+          runtimeType == other.runtimeType &&
+          fullWidthPunctuation == other.fullWidthPunctuation &&
+          collapseBlankLines == other.collapseBlankLines &&
+          indentParagraphs == other.indentParagraphs &&
+          unifyEllipsesAndDashes == other.unifyEllipsesAndDashes &&
+          curlyQuotes == other.curlyQuotes;
+}
@@ -0,0 +1,36 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'book.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `block`, `chapter_ranges`, `chunk`, `extract_resource`, `inflate`, `le_u16`, `le_u32`, `parse`, `read_file`, `read`, `truncated`, `utf16`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Umd`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+/// Parse a local `.umd` file, text or cartoon, into chapters and a table of
+/// contents.
+///
+/// # Arguments
+/// * `path` - Path to the UMD file
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+Future<ParsedBook> parseUmd({required String path, int? cancelToken}) => RustLib
+    .instance
+    .api
+    .crateApiUmdParseUmd(path: path, cancelToken: cancelToken);
+
+/// Extract the cover or a cartoon page by the href used in chapter HTML or
+/// `cover_href`.
+///
+/// # Arguments
+/// * `path` - Path to the UMD file
+/// * `href` - `cover`, or a page href from chapter HTML, e.g. `images/3`
+Future<Uint8List> extractUmdResource({
+  required String path,
+  required String href,
+}) =>
+    RustLib.instance.api.crateApiUmdExtractUmdResource(path: path, href: href);
@@ -0,0 +1,117 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'book_source.dart';
+import 'downloader.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `compare`, `diff`, `fetch_all`, `host`, `now`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+/// Check books for new chapters and return a summary per book, in the
+/// order given.
+///
+/// # Arguments
+/// * `books` - Books to check, such as those being read
+/// * `time_budget_ms` - Return after this long, whether or not every book has been checked
+/// * `save_chapters` - Store fetched tables of contents that changed, so the next check
+///   compares with them; otherwise only report
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+Future<List<BookUpdateSummary>> checkBookUpdates({
+  required List<UpdateCheckBook> books,
+  required int timeBudgetMs,
+  required bool saveChapters,
+  int? cancelToken,
+}) => RustLib.instance.api.crateApiUpdateCheckCheckBookUpdates(
+  books: books,
+  timeBudgetMs: timeBudgetMs,
+  saveChapters: saveChapters,
+  cancelToken: cancelToken,
+);
+
+class BookUpdateSummary {
+  final String bookId;
+  final UpdateCheckStatus status;
+  /// Chapters on the source that are not stored, volume headings excluded.
+  final int newChapters;
+  /// Stored chapters the source no longer lists.
+  final int removedChapters;
+  /// Entries in the fetched table of contents.
+  final int chapterCount;
+  final String? latestChapterTitle;
+  final String? error;
+
+  const BookUpdateSummary({
+    required this.bookId,
+    required this.status,
+    required this.newChapters,
+    required this.removedChapters,
+    required this.chapterCount,
+    this.latestChapterTitle,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      bookId.hashCode ^
+      status.hashCode ^
+      newChapters.hashCode ^
+      removedChapters.hashCode ^
+      chapterCount.hashCode ^
+      latestChapterTitle.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BookUpdateSummary &&
+          runtimeType == other.runtimeType &&
+          bookId == other.bookId &&
+          status == other.status &&
+          newChapters == other.newChapters &&
+          removedChapters == other.removedChapters &&
+          chapterCount == other.chapterCount &&
+          latestChapterTitle == other.latestChapterTitle &&
+          error == other.error;
+}
+
+/// A book to check, with the source it is read from.
+class UpdateCheckBook {
+  /// ID of the book in the library database.
+  final String bookId;
+  final BookSource source;
+  /// Table of contents URL, as stored with the book.
+  final String tocUrl;
+
+  const UpdateCheckBook({
+    required this.bookId,
+    required this.source,
+    required this.tocUrl,
+  });
+
+  @override
+  int get hashCode => bookId.hashCode ^ source.hashCode ^ tocUrl.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UpdateCheckBook &&
+          runtimeType == other.runtimeType &&
+          bookId == other.bookId &&
+          source == other.source &&
+          tocUrl == other.tocUrl;
+}
+
+enum UpdateCheckStatus {
+  /// The source lists chapters the library does not have.
+  updated,
+  unchanged,
+  /// Fetching or reading the table of contents failed.
+  failed,
+  /// The time budget ran out before the book was checked.
+  notChecked,
+}
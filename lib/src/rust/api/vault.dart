@@ -0,0 +1,52 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `current`, `lock`, `open_data`, `open_sealed`, `open`, `open`, `seal_data`, `seal`, `seal`, `unwrap_key`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Vault`
+
+/// Unlock the vault in `vault_dir`, creating it on first use. Fails if the
+/// vault was created with different device material; delete the directory
+/// to start over, which makes previously sealed secrets unreadable.
+///
+/// # Arguments
+/// * `vault_dir` - Directory owned by the vault; created if missing
+/// * `device_secret` - At least 16 bytes of device-bound material from the
+///   platform keystore; the same bytes must be passed on every launch
+Future<void> initTokenVault({
+  required String vaultDir,
+  required List<int> deviceSecret,
+}) => RustLib.instance.api.crateApiVaultInitTokenVault(
+  vaultDir: vaultDir,
+  deviceSecret: deviceSecret,
+);
+
+/// Forget the unlocked vault key until `init_token_vault` is called again.
+Future<void> lockTokenVault() =>
+    RustLib.instance.api.crateApiVaultLockTokenVault();
+
+/// Encrypt a secret, returning a string that is safe to store anywhere.
+///
+/// # Arguments
+/// * `name` - What the secret is, e.g. `refresh_token`; needed to open it
+/// * `secret` - Plaintext secret
+Future<String> sealSecret({required String name, required String secret}) =>
+    RustLib.instance.api.crateApiVaultSealSecret(name: name, secret: secret);
+
+/// Decrypt a string returned by `seal_secret` under the same name.
+///
+/// # Arguments
+/// * `name` - Name the secret was sealed under
+/// * `sealed` - Sealed secret string
+Future<String> openSecret({required String name, required String sealed}) =>
+    RustLib.instance.api.crateApiVaultOpenSecret(name: name, sealed: sealed);
+
+/// Compare two secrets in constant time, so the time taken does not reveal
+/// how much of a guess was right. Only the lengths can be told apart.
+Future<bool> secretsEqual({required String a, required String b}) =>
+    RustLib.instance.api.crateApiVaultSecretsEqual(a: a, b: b);
@@ -0,0 +1,159 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'line_break.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'pagination.dart';
+
+// These functions are ignored because they are not marked as `pub`: `get`, `has_vert_glyph`, `new`, `paginate_vertical`, `upright`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `FormCache`, `Form`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+/// Split `text` into pages of vertical columns. `layout` is read as for
+/// `paginate_text`, with the line height giving the column width and the
+/// indent and paragraph spacing applied down and across.
+///
+/// # Arguments
+/// * `text` - Chapter text
+/// * `font_path` - TTF or OTF file used for measuring; the first face of a
+///   collection
+/// * `layout` - Page size, margins and text style
+/// * `kinsoku` - Characters kept off column edges; `default_kinsoku_rules`
+///   if omitted
+Future<List<VerticalPage>> paginateVerticalText({
+  required String text,
+  required String fontPath,
+  required PageLayout layout,
+  KinsokuRules? kinsoku,
+}) => RustLib.instance.api.crateApiVerticalPaginateVerticalText(
+  text: text,
+  fontPath: fontPath,
+  layout: layout,
+  kinsoku: kinsoku,
+);
+
+/// A laid-out column; `end` excludes trailing whitespace.
+class TextColumn {
+  final int start;
+  final int end;
+  /// Whether this is the first column of a paragraph, and so indented.
+  final bool paragraphStart;
+  /// Left edge of the column, which is one line height wide.
+  final double x;
+  /// Advance height of the column's text, without the indent.
+  final double height;
+  final List<VerticalGlyph> glyphs;
+
+  const TextColumn({
+    required this.start,
+    required this.end,
+    required this.paragraphStart,
+    required this.x,
+    required this.height,
+    required this.glyphs,
+  });
+
+  @override
+  int get hashCode =>
+      start.hashCode ^
+      end.hashCode ^
+      paragraphStart.hashCode ^
+      x.hashCode ^
+      height.hashCode ^
+      glyphs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TextColumn &&
+          runtimeType == other.runtimeType &&
+          start == other.start &&
+          end == other.end &&
+          paragraphStart == other.paragraphStart &&
+          x == other.x &&
+          height == other.height &&
+          glyphs == other.glyphs;
+}
+
+/// A character placed in a column.
+class VerticalGlyph {
+  /// UTF-16 offset of the character in the text.
+  final int offset;
+  /// Length in UTF-16 code units.
+  final int len;
+  /// What to draw: the character itself or its vertical presentation
+  /// form.
+  final String text;
+  /// Top of the glyph's cell.
+  final double y;
+  /// Height of the glyph's cell.
+  final double advance;
+  /// Draw turned 90° clockwise.
+  final bool rotated;
+  /// Draw with the `vert` font feature enabled.
+  final bool vertFeature;
+
+  const VerticalGlyph({
+    required this.offset,
+    required this.len,
+    required this.text,
+    required this.y,
+    required this.advance,
+    required this.rotated,
+    required this.vertFeature,
+  });
+
+  @override
+  int get hashCode =>
+      offset.hashCode ^
+      len.hashCode ^
+      text.hashCode ^
+      y.hashCode ^
+      advance.hashCode ^
+      rotated.hashCode ^
+      vertFeature.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is VerticalGlyph &&
+          runtimeType == other.runtimeType &&
+          offset == other.offset &&
+          len == other.len &&
+          text == other.text &&
+          y == other.y &&
+          advance == other.advance &&
+          rotated == other.rotated &&
+          vertFeature == other.vertFeature;
+}
+
+/// A page of vertical text; pages cover the text contiguously like
+/// `TextPage`.
+class VerticalPage {
+  final int start;
+  final int end;
+  /// Columns from right to left.
+  final List<TextColumn> columns;
+
+  const VerticalPage({
+    required this.start,
+    required this.end,
+    required this.columns,
+  });
+
+  @override
+  int get hashCode => start.hashCode ^ end.hashCode ^ columns.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is VerticalPage &&
+          runtimeType == other.runtimeType &&
+          start == other.start &&
+          end == other.end &&
+          columns == other.columns;
+}
@@ -0,0 +1,87 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `describe`, `homoglyph`, `invisible`, `is_han`, `is_rtl`, `kind`, `lookalike`, `needs_joiner`, `strip`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Found`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+/// Find watermark characters without changing the text.
+Future<WatermarkReport> scanWatermarks({required String text}) =>
+    RustLib.instance.api.crateApiWatermarkScanWatermarks(text: text);
+
+/// Remove invisible watermark characters and replace look-alikes.
+Future<String> stripWatermarks({required String text}) =>
+    RustLib.instance.api.crateApiWatermarkStripWatermarks(text: text);
+
+/// One watermark character and where it occurs.
+class WatermarkChar {
+  final int codepoint;
+  final String name;
+  final WatermarkKind kind;
+  /// What a homoglyph is replaced by; empty for invisible characters.
+  final String replacement;
+  final int count;
+  /// UTF-16 offsets of the first 100 occurrences.
+  final Uint32List offsets;
+
+  const WatermarkChar({
+    required this.codepoint,
+    required this.name,
+    required this.kind,
+    required this.replacement,
+    required this.count,
+    required this.offsets,
+  });
+
+  @override
+  int get hashCode =>
+      codepoint.hashCode ^
+      name.hashCode ^
+      kind.hashCode ^
+      replacement.hashCode ^
+      count.hashCode ^
+      offsets.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WatermarkChar &&
+          runtimeType == other.runtimeType &&
+          codepoint == other.codepoint &&
+          name == other.name &&
+          kind == other.kind &&
+          replacement == other.replacement &&
+          count == other.count &&
+          offsets == other.offsets;
+}
+
+enum WatermarkKind {
+  /// Removed when stripping.
+  invisible,
+  /// Replaced by the character it imitates when stripping.
+  homoglyph,
+}
+
+class WatermarkReport {
+  /// Characters in order of first occurrence.
+  final List<WatermarkChar> chars;
+  final int total;
+
+  const WatermarkReport({required this.chars, required this.total});
+
+  @override
+  int get hashCode => chars.hashCode ^ total.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WatermarkReport &&
+          runtimeType == other.runtimeType &&
+          chars == other.chars &&
+          total == other.total;
+}
@@ -0,0 +1,142 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'purify.dart';
+
+// These functions are ignored because they are not marked as `pub`: `book_details`, `chapter_text`, `detect`, `fetch`, `import`, `is_descending`, `next_page`, `normalize`, `path_stem`, `title_pattern`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Detected`, `LinkGroup`, `TocLink`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+/// Fetch a web novel's index page and detect its chapter list.
+///
+/// # Arguments
+/// * `url` - Index page, the one listing the chapters
+Future<WebBook> detectWebToc({required String url}) =>
+    RustLib.instance.api.crateApiWebImportDetectWebToc(url: url);
+
+/// Fetch chapters, purify them and write them to the chapter cache, which
+/// must be set up with `init_chapter_cache`.
+///
+/// # Arguments
+/// * `book_id` - Library ID the chapters are cached under
+/// * `chapters` - Chapters to import, usually from `detect_web_toc`
+/// * `purify_rules` - Clean-up rules applied to every chapter; may be empty
+/// * `sink` - Receives one event per chapter
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+Stream<WebImportProgress> importWebBook({
+  required String bookId,
+  required List<WebChapter> chapters,
+  required List<PurifyRule> purifyRules,
+  int? cancelToken,
+}) => RustLib.instance.api.crateApiWebImportImportWebBook(
+  bookId: bookId,
+  chapters: chapters,
+  purifyRules: purifyRules,
+  cancelToken: cancelToken,
+);
+
+class WebBook {
+  final String? title;
+  final String? author;
+  /// The index page, after redirects.
+  final String url;
+  final List<WebChapter> chapters;
+  /// How sure the detector is that `chapters` is the chapter list, from 0
+  /// to 1. Below about 0.5 the user should check the list.
+  final double confidence;
+
+  const WebBook({
+    this.title,
+    this.author,
+    required this.url,
+    required this.chapters,
+    required this.confidence,
+  });
+
+  @override
+  int get hashCode =>
+      title.hashCode ^
+      author.hashCode ^
+      url.hashCode ^
+      chapters.hashCode ^
+      confidence.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WebBook &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          author == other.author &&
+          url == other.url &&
+          chapters == other.chapters &&
+          confidence == other.confidence;
+}
+
+class WebChapter {
+  final int index;
+  final String title;
+  final String url;
+
+  const WebChapter({
+    required this.index,
+    required this.title,
+    required this.url,
+  });
+
+  @override
+  int get hashCode => index.hashCode ^ title.hashCode ^ url.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WebChapter &&
+          runtimeType == other.runtimeType &&
+          index == other.index &&
+          title == other.title &&
+          url == other.url;
+}
+
+/// Reported after each chapter.
+class WebImportProgress {
+  final int done;
+  final int total;
+  final int chapterIndex;
+  /// Whether the chapter was already cached and not fetched again.
+  final bool skipped;
+  /// Why the chapter could not be imported; the others carry on.
+  final String? error;
+
+  const WebImportProgress({
+    required this.done,
+    required this.total,
+    required this.chapterIndex,
+    required this.skipped,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      done.hashCode ^
+      total.hashCode ^
+      chapterIndex.hashCode ^
+      skipped.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WebImportProgress &&
+          runtimeType == other.runtimeType &&
+          done == other.done &&
+          total == other.total &&
+          chapterIndex == other.chapterIndex &&
+          skipped == other.skipped &&
+          error == other.error;
+}
@@ -0,0 +1,190 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `delete`, `execute`, `hash_file`, `make_dirs`, `new`, `normalize`, `parse_multistatus`, `propfind`, `read_bytes`, `read_text`, `record`, `relative_path`, `request`, `stat`, `sync_status`, `url`, `write_bytes`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Body`, `Client`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Check that the server is reachable and the credentials are accepted.
+/// The base directory is created if it does not exist.
+Future<void> webdavTestConnection({required WebDavConfig config}) =>
+    RustLib.instance.api.crateApiWebdavWebdavTestConnection(config: config);
+
+/// List the entries of a remote directory. Hash sidecars are left out.
+///
+/// # Arguments
+/// * `config` - Server and credentials
+/// * `path` - Directory relative to the base URL; empty for the base itself
+Future<List<WebDavEntry>> webdavList({
+  required WebDavConfig config,
+  required String path,
+}) => RustLib.instance.api.crateApiWebdavWebdavList(config: config, path: path);
+
+/// Upload a local file, creating missing remote directories, and return
+/// the record to store for later sync checks.
+///
+/// # Arguments
+/// * `config` - Server and credentials
+/// * `local_path` - File to upload
+/// * `remote_path` - Destination relative to the base URL
+Future<WebDavSyncRecord> webdavUpload({
+  required WebDavConfig config,
+  required String localPath,
+  required String remotePath,
+}) => RustLib.instance.api.crateApiWebdavWebdavUpload(
+  config: config,
+  localPath: localPath,
+  remotePath: remotePath,
+);
+
+/// Download a remote file and return the record to store for later sync
+/// checks. The local file is replaced only once the download is complete.
+///
+/// # Arguments
+/// * `config` - Server and credentials
+/// * `remote_path` - File relative to the base URL
+/// * `local_path` - Destination; missing parent directories are created
+Future<WebDavSyncRecord> webdavDownload({
+  required WebDavConfig config,
+  required String remotePath,
+  required String localPath,
+}) => RustLib.instance.api.crateApiWebdavWebdavDownload(
+  config: config,
+  remotePath: remotePath,
+  localPath: localPath,
+);
+
+/// Delete a remote file or directory, along with a file's hash sidecar.
+Future<void> webdavDelete({
+  required WebDavConfig config,
+  required String remotePath,
+}) => RustLib.instance.api.crateApiWebdavWebdavDelete(
+  config: config,
+  remotePath: remotePath,
+);
+
+/// Compare a local file with its remote copy.
+///
+/// # Arguments
+/// * `config` - Server and credentials
+/// * `local_path` - Local copy of the file
+/// * `remote_path` - Remote copy, relative to the base URL
+/// * `last_sync` - Record returned by the last upload or download, if any
+Future<WebDavSyncStatus> webdavSyncStatus({
+  required WebDavConfig config,
+  required String localPath,
+  required String remotePath,
+  WebDavSyncRecord? lastSync,
+}) => RustLib.instance.api.crateApiWebdavWebdavSyncStatus(
+  config: config,
+  localPath: localPath,
+  remotePath: remotePath,
+  lastSync: lastSync,
+);
+
+class WebDavConfig {
+  /// Root of the backup area, e.g. `https://dav.jianguoyun.com/dav/Novella/`.
+  final String baseUrl;
+  final String username;
+  /// Password, or the app password Nutstore issues for third-party apps.
+  final String password;
+
+  const WebDavConfig({
+    required this.baseUrl,
+    required this.username,
+    required this.password,
+  });
+
+  @override
+  int get hashCode => baseUrl.hashCode ^ username.hashCode ^ password.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WebDavConfig &&
+          runtimeType == other.runtimeType &&
+          baseUrl == other.baseUrl &&
+          username == other.username &&
+          password == other.password;
+}
+
+class WebDavEntry {
+  /// Path relative to the base URL, without a trailing slash.
+  final String path;
+  final String name;
+  final bool isDir;
+  final BigInt size;
+  /// Last modification time in seconds since the Unix epoch.
+  final PlatformInt64? modified;
+  final String? etag;
+
+  const WebDavEntry({
+    required this.path,
+    required this.name,
+    required this.isDir,
+    required this.size,
+    this.modified,
+    this.etag,
+  });
+
+  @override
+  int get hashCode =>
+      path.hashCode ^
+      name.hashCode ^
+      isDir.hashCode ^
+      size.hashCode ^
+      modified.hashCode ^
+      etag.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WebDavEntry &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          name == other.name &&
+          isDir == other.isDir &&
+          size == other.size &&
+          modified == other.modified &&
+          etag == other.etag;
+}
+
+/// What a device knew about a file after it last uploaded or downloaded
+/// it. Store it and pass it to `webdav_sync_status` next time.
+class WebDavSyncRecord {
+  /// BLAKE3 hash of the file content, in hex.
+  final String hash;
+  final String? etag;
+  final PlatformInt64? modified;
+
+  const WebDavSyncRecord({required this.hash, this.etag, this.modified});
+
+  @override
+  int get hashCode => hash.hashCode ^ etag.hashCode ^ modified.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WebDavSyncRecord &&
+          runtimeType == other.runtimeType &&
+          hash == other.hash &&
+          etag == other.etag &&
+          modified == other.modified;
+}
+
+enum WebDavSyncStatus {
+  inSync,
+  /// Only the local file changed since the last sync; upload it.
+  localChanged,
+  /// Only the remote file changed since the last sync; download it.
+  remoteChanged,
+  /// Both sides changed, or they differ and there is no sync record.
+  conflict,
+  remoteMissing,
+}
@@ -0,0 +1,113 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'archive.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `book_link`, `book_page`, `chapter_page`, `chapter_text`, `error`, `html`, `lan_address`, `lock`, `page`, `paragraphs`, `read_head`, `route`, `serve`, `shelf_page`, `turn_away`, `upload`, `write_response`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Request`, `Response`, `Running`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+/// Uploads off, no token and a 200 MB upload limit, on port 8080.
+Future<WebServerConfig> defaultWebServerConfig() =>
+    RustLib.instance.api.crateApiWebserverDefaultWebServerConfig();
+
+/// Start serving on all interfaces, stopping a server already running.
+///
+/// # Arguments
+/// * `config` - Port, uploads and access token
+Future<WebServerInfo> startWebServer({required WebServerConfig config}) =>
+    RustLib.instance.api.crateApiWebserverStartWebServer(config: config);
+
+/// Stop the server. Returns false if none was running. The port is free
+/// again on return; requests already being served are finished.
+Future<bool> stopWebServer() =>
+    RustLib.instance.api.crateApiWebserverStopWebServer();
+
+/// Receive uploaded books, replacing any previous stream. Uploads made
+/// while nothing listens stay in the upload directory unannounced.
+Stream<WebUpload> webServerEvents() =>
+    RustLib.instance.api.crateApiWebserverWebServerEvents();
+
+class WebServerConfig {
+  /// Port to listen on; 0 picks a free one.
+  final int port;
+  /// Where uploaded books are saved; `None` turns uploading off.
+  final String? uploadDir;
+  /// Secret visitors must present once; `None` lets anyone on the
+  /// network in.
+  final String? accessToken;
+  /// Largest upload accepted, in megabytes.
+  final int maxUploadMb;
+
+  const WebServerConfig({
+    required this.port,
+    this.uploadDir,
+    this.accessToken,
+    required this.maxUploadMb,
+  });
+
+  @override
+  int get hashCode =>
+      port.hashCode ^
+      uploadDir.hashCode ^
+      accessToken.hashCode ^
+      maxUploadMb.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WebServerConfig &&
+          runtimeType == other.runtimeType &&
+          port == other.port &&
+          uploadDir == other.uploadDir &&
+          accessToken == other.accessToken &&
+          maxUploadMb == other.maxUploadMb;
+}
+
+class WebServerInfo {
+  final int port;
+  /// Addresses to open in a browser, best guess first, with the token.
+  final List<String> urls;
+
+  const WebServerInfo({required this.port, required this.urls});
+
+  @override
+  int get hashCode => port.hashCode ^ urls.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WebServerInfo &&
+          runtimeType == other.runtimeType &&
+          port == other.port &&
+          urls == other.urls;
+}
+
+/// A book uploaded through the web page, ready to import.
+class WebUpload {
+  /// File name the browser sent.
+  final String name;
+  /// Where the file was saved.
+  final String path;
+  /// `Txt` or `Epub`.
+  final ImportKind kind;
+
+  const WebUpload({required this.name, required this.path, required this.kind});
+
+  @override
+  int get hashCode => name.hashCode ^ path.hashCode ^ kind.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WebUpload &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          path == other.path &&
+          kind == other.kind;
+}
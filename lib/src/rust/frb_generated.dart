@@ -3,7 +3,101 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/anchors.dart';
+import 'api/archive.dart';
+import 'api/audio_cache.dart';
+import 'api/backup.dart';
+import 'api/bench.dart';
+import 'api/bionic.dart';
+import 'api/blobs.dart';
+import 'api/book.dart';
+import 'api/book_image.dart';
+import 'api/book_source.dart';
+import 'api/calibre.dart';
+import 'api/chapter_cache.dart';
+import 'api/chapter_diff.dart';
+import 'api/chapter_sort.dart';
+import 'api/chapter_store.dart';
+import 'api/cloudctl.dart';
+import 'api/comic.dart';
+import 'api/cookies.dart';
+import 'api/cover.dart';
+import 'api/dark_image.dart';
+import 'api/db.dart';
+import 'api/dedup.dart';
+import 'api/dict.dart';
+import 'api/downloader.dart';
+import 'api/drm.dart';
+import 'api/encoding.dart';
+import 'api/epub.dart';
+import 'api/epub_export.dart';
+import 'api/epub_tools.dart';
+import 'api/error.dart';
+import 'api/extraction.dart';
+import 'api/fb2.dart';
+import 'api/feed.dart';
+import 'api/fingerprint.dart';
+import 'api/font_bundle.dart';
+import 'api/font_cache.dart';
+import 'api/font_collection.dart';
 import 'api/font_converter.dart';
+import 'api/font_preview.dart';
+import 'api/font_subset.dart';
+import 'api/font_validation.dart';
+import 'api/http_cache.dart';
+import 'api/hyphenation.dart';
+import 'api/image.dart';
+import 'api/import.dart';
+import 'api/keywords.dart';
+import 'api/langdetect.dart';
+import 'api/layout_cache.dart';
+import 'api/library_search.dart';
+import 'api/line_break.dart';
+import 'api/logging.dart';
+import 'api/mapped_txt.dart';
+import 'api/metadata.dart';
+import 'api/mobi.dart';
+import 'api/network.dart';
+import 'api/notes_export.dart';
+import 'api/opds.dart';
+import 'api/opencc.dart';
+import 'api/pagination.dart';
+import 'api/palette.dart';
+import 'api/pdf.dart';
+import 'api/placeholder.dart';
+import 'api/prefetch.dart';
+import 'api/punctuation.dart';
+import 'api/purify.dart';
+import 'api/rate_limit.dart';
+import 'api/read_chapters.dart';
+import 'api/readability.dart';
+import 'api/resplit.dart';
+import 'api/ruby.dart';
+import 'api/rule_test.dart';
+import 'api/sanitize.dart';
+import 'api/search.dart';
+import 'api/segment.dart';
+import 'api/sessions.dart';
+import 'api/source_switch.dart';
+import 'api/stats.dart';
+import 'api/summary.dart';
+import 'api/sync.dart';
+import 'api/task.dart';
+import 'api/translate.dart';
+import 'api/tts.dart';
+import 'api/tts_prep.dart';
+import 'api/tts_timeline.dart';
+import 'api/txt.dart';
+import 'api/txt_detect.dart';
+import 'api/typography.dart';
+import 'api/umd.dart';
+import 'api/update_check.dart';
+import 'api/vault.dart';
+import 'api/vertical.dart';
+import 'api/watermark.dart';
+import 'api/web_import.dart';
+import 'api/webdav.dart';
+import 'api/webserver.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
@@ -54,7 +148,9 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
       RustLibWire.fromExternalLibrary;
 
   @override
-  Future<void> executeRustInitializers() async {}
+  Future<void> executeRustInitializers() async {
+    await api.crateApiErrorInitApp();
+  }
 
   @override
   ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
@@ -63,270 +159,25794 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   @override
   String get codegenVersion => '2.11.1';
 
-  @override
-  int get rustContentHash => 1505400433;
+  @override
+  int get rustContentHash => -1640238035;
+
+  static const kDefaultExternalLibraryLoaderConfig =
+      ExternalLibraryLoaderConfig(
+        stem: 'novella_native',
+        ioDirectory: 'rust/target/release/',
+        webPrefix: 'pkg/',
+      );
+}
+
+abstract class RustLibApi extends BaseApi {
+  Future<int> crateApiCookiesAddCookies({
+    required String url,
+    required List<String> cookies,
+  });
+
+  Future<List<PinyinSpan>> crateApiRubyAnnotatePinyin({required String text});
+
+  Future<ChapterAudio> crateApiAudioCacheAssembleChapterAudio({
+    required String voice,
+    required List<Utterance> utterances,
+    required String outputPath,
+  });
+
+  Future<AudioCacheStats> crateApiAudioCacheAudioCacheStats();
+
+  Future<List<EmphasisSpan>> crateApiBionicBionicSpans({
+    required String text,
+    required BionicOptions options,
+  });
+
+  Future<String?> crateApiBlobsBlobForOwner({required String owner});
+
+  Future<String?> crateApiBlobsBlobPath({required String hash});
+
+  Future<BlobStoreStats> crateApiBlobsBlobStoreStats();
+
+  Future<CalibreMetadata> crateApiCalibreCalibreMetadataDefault();
+
+  Future<bool> crateApiDownloaderCancelDownload({required int taskId});
+
+  Future<int> crateApiPrefetchCancelPrefetch();
+
+  Future<bool> crateApiTaskCancelTask({required int token});
+
+  Future<BigInt?> crateApiChapterSortChapterNumber({required String title});
+
+  Future<String> crateApiChapterSortChapterSortKey({required String title});
+
+  Future<ChapterStoreStats> crateApiChapterStoreChapterStoreStats({
+    required String bookId,
+  });
+
+  Future<ChapterText> crateApiBookChapterText({required String html});
+
+  Future<List<BookUpdateSummary>> crateApiUpdateCheckCheckBookUpdates({
+    required List<UpdateCheckBook> books,
+    required int timeBudgetMs,
+    required bool saveChapters,
+    int? cancelToken,
+  });
+
+  Future<ChapterUpdateReport> crateApiChapterCacheCheckChapterUpdates({
+    required String bookId,
+    required int chapterCount,
+    required List<FetchedChapter> chapters,
+  });
+
+  Future<CoverageReport> crateApiFontConverterCheckCoverage({
+    required List<int> fontData,
+    required String text,
+  });
+
+  Future<void> crateApiAudioCacheClearAudioCache();
+
+  Future<int> crateApiCookiesClearCookies({String? host});
+
+  Future<void> crateApiNetworkClearDnsCache();
+
+  Future<void> crateApiFontCacheClearFontCache();
+
+  Future<void> crateApiNetworkClearHostProxy({required String host});
+
+  Future<void> crateApiRateLimitClearHostRateLimit({required String host});
+
+  Future<void> crateApiHttpCacheClearHttpCache();
+
+  Future<void> crateApiLayoutCacheClearLayoutCache();
+
+  Future<void> crateApiLoggingClearLogs();
+
+  Future<int> crateApiSessionsClearReadingSessions({String? bookId});
+
+  Future<bool> crateApiDictCloseDictionary({required int id});
+
+  Future<void> crateApiDbCloseLibraryDb();
+
+  Future<bool> crateApiMappedTxtCloseMappedTxt({required int id});
+
+  Future<ChapterStoreStats> crateApiChapterStoreCompactChapterStore({
+    required String bookId,
+  });
+
+  Future<int> crateApiChapterSortCompareChapterTitles({
+    required String a,
+    required String b,
+  });
+
+  Future<Uint8List> crateApiFontConverterCompressTtfToWoff2({
+    required List<int> ttfData,
+  });
+
+  Future<void> crateApiDownloaderConfigureDownloader({
+    required DownloaderConfig config,
+  });
+
+  Future<void> crateApiLoggingConfigureLogging({required LoggingConfig config});
+
+  Future<void> crateApiPrefetchConfigurePrefetch({
+    required PrefetchConfig config,
+  });
+
+  Future<String> crateApiOpenccConvertChinese({
+    required String text,
+    required ChineseConversion conversion,
+  });
+
+  Future<List<String>> crateApiOpenccConvertChineseBatch({
+    required List<String> texts,
+    required ChineseConversion conversion,
+  });
+
+  Future<Uint8List> crateApiFontConverterConvertFontToTtf({
+    required List<int> fontData,
+  });
+
+  Future<List<ConversionResult>> crateApiFontConverterConvertWoff2Batch({
+    required List<NamedFont> fonts,
+    int? cancelToken,
+  });
+
+  Future<Uint8List> crateApiFontConverterConvertWoff2ToTtf({
+    required List<int> woff2Data,
+  });
+
+  Stream<TtfChunk> crateApiFontConverterConvertWoff2ToTtfChunked({
+    required List<int> woff2Data,
+    int? chunkSize,
+  });
+
+  Future<int> crateApiFontConverterConvertWoff2ToTtfFile({
+    required List<int> woff2Data,
+    required String ttfPath,
+    int? cancelToken,
+  });
+
+  Future<Uint8List> crateApiFontConverterConvertWoffToTtf({
+    required List<int> woffData,
+  });
+
+  Future<BookStats> crateApiStatsCountBook({
+    required List<String> chapters,
+    required int charsPerMinute,
+  });
+
+  Future<TextStats> crateApiStatsCountText({
+    required String text,
+    required int charsPerMinute,
+  });
+
+  Future<CoverPlaceholder> crateApiPlaceholderCoverPlaceholder({
+    required List<int> imageBytes,
+    required int thumbnailSize,
+  });
+
+  Future<TextAnchor> crateApiAnchorsCreateAnchor({
+    required String text,
+    required int start,
+    required int end,
+  });
+
+  Future<BackupInfo> crateApiBackupCreateBackup({
+    required String path,
+    required List<PurifyRule> purifyRules,
+    required String bookSourcesJson,
+  });
+
+  Future<int> crateApiTaskCreateCancelToken();
+
+  Future<BandwidthPolicy> crateApiDownloaderDefaultBandwidthPolicy();
+
+  Future<BionicOptions> crateApiBionicDefaultBionicOptions();
+
+  Future<CoverImageOptions> crateApiImageDefaultCoverImageOptions();
+
+  Future<CoverStyle> crateApiCoverDefaultCoverStyle({required String title});
+
+  Future<DarkImageOptions> crateApiDarkImageDefaultDarkImageOptions();
+
+  Future<DedupOptions> crateApiDedupDefaultDedupOptions();
+
+  Future<DownloaderConfig> crateApiDownloaderDefaultDownloaderConfig();
+
+  Future<ImportOptions> crateApiImportDefaultImportOptions();
+
+  Future<KinsokuRules> crateApiLineBreakDefaultKinsokuRules();
+
+  Future<LoggingConfig> crateApiLoggingDefaultLoggingConfig();
+
+  Future<PrefetchConfig> crateApiPrefetchDefaultPrefetchConfig();
+
+  Future<List<PurifyRule>> crateApiPurifyDefaultPurifyRules();
+
+  Future<RateLimit> crateApiRateLimitDefaultRateLimit();
+
+  Future<SsmlOptions> crateApiTtsPrepDefaultSsmlOptions();
+
+  Future<SummaryOptions> crateApiSummaryDefaultSummaryOptions();
+
+  Future<TranslatorConfig> crateApiTranslateDefaultTranslatorConfig();
+
+  Future<TtsOptions> crateApiTtsDefaultTtsOptions();
+
+  Future<List<TxtChapterRule>> crateApiTxtDefaultTxtRules();
+
+  Future<TypographyOptions> crateApiTypographyDefaultTypographyOptions();
+
+  Future<WebServerConfig> crateApiWebserverDefaultWebServerConfig();
+
+  Future<bool> crateApiDbDeleteBookmark({required PlatformInt64 id});
+
+  Future<int> crateApiDbDeleteBooks({required List<String> ids});
+
+  Future<bool> crateApiChapterStoreDeleteChapter({
+    required String bookId,
+    required int chapterIndex,
+  });
+
+  Future<bool> crateApiDbDeleteHighlight({required PlatformInt64 id});
+
+  Future<void> crateApiChapterStoreDeleteStoredBook({required String bookId});
+
+  Future<List<LanguageGuess>> crateApiLangdetectDetect({required String text});
+
+  Future<DecodedText> crateApiEncodingDetectAndDecodeText({
+    required List<int> bytes,
+    String? overrideEncoding,
+  });
+
+  Future<List<LanguageGuess>> crateApiLangdetectDetectBookLanguage({
+    required List<String> chapters,
+  });
+
+  Future<DrmStatus> crateApiDrmDetectDrm({required String path});
+
+  Future<TxtChapterProposal> crateApiTxtDetectDetectTxtChapters({
+    required String path,
+  });
+
+  Future<WebBook> crateApiWebImportDetectWebToc({required String url});
+
+  Future<Uint8List?> crateApiDictDictionaryResource({
+    required int dictId,
+    required String path,
+  });
+
+  Future<ChapterDiff> crateApiChapterDiffDiffChapters({
+    required String old,
+    required String new_,
+  });
+
+  Stream<DownloadEvent> crateApiDownloaderDownloadEvents();
+
+  Future<int> crateApiDownloaderEnqueueBackgroundDownload({
+    required DownloadRequest request,
+  });
+
+  Future<int> crateApiDownloaderEnqueueDownload({
+    required DownloadRequest request,
+  });
+
+  Future<int> crateApiOpdsEnqueueOpdsDownload({
+    required String url,
+    required String destination,
+    OpdsCredentials? credentials,
+  });
+
+  Future<bool> crateApiFontCacheEvictFontCacheEntry({required String id});
+
+  Future<int> crateApiNotesExportExportBookNotes({
+    required String bookId,
+    required String path,
+    required NotesExportOptions options,
+  });
+
+  Future<String> crateApiCalibreExportCalibreBook({
+    required CalibreMetadata metadata,
+    required String bookPath,
+    Uint8List? cover,
+    required String libraryDir,
+  });
+
+  Stream<EpubExportProgress> crateApiEpubExportExportEpub({
+    required BookMetadata metadata,
+    required List<EpubExportChapter> chapters,
+    Uint8List? cover,
+    Uint8List? font,
+    required String outputPath,
+    int? cancelToken,
+  });
+
+  Future<int> crateApiLoggingExportLogs({required String path});
+
+  Future<FontBundle> crateApiFontBundleExtendFontBundle({
+    required String bookId,
+    required String fontPath,
+    required String text,
+  });
+
+  Future<CoverPalette> crateApiPaletteExtract({required List<int> imageBytes});
+
+  Stream<ArchiveProgress> crateApiArchiveExtractArchive({
+    required String path,
+    required String destDir,
+    Uint32List? indices,
+    int? cancelToken,
+  });
+
+  Future<Uint8List> crateApiBookImageExtractBookImage({
+    required String path,
+    required String href,
+  });
+
+  Future<BookKeywords> crateApiKeywordsExtractBookKeywords({
+    required String bookId,
+    required int limit,
+    int? cancelToken,
+  });
+
+  Future<Uint8List> crateApiFontCollectionExtractCollectionFace({
+    required List<int> ttcData,
+    required int index,
+  });
+
+  Future<Extraction> crateApiExtractionExtractCss({
+    required String html,
+    required String selector,
+    String? getter,
+    required List<PostStep> steps,
+  });
+
+  Future<Uint8List> crateApiEpubExtractEpubResource({
+    required String path,
+    required String href,
+  });
+
+  Future<Uint8List> crateApiFb2ExtractFb2Resource({
+    required String path,
+    required String href,
+  });
+
+  Future<Uint32List> crateApiFontConverterExtractInvisibleCodepoints({
+    required List<int> ttfData,
+  });
+
+  Future<Extraction> crateApiExtractionExtractJsonpath({
+    required String json,
+    required String path,
+    required List<PostStep> steps,
+  });
+
+  Future<BookKeywords> crateApiKeywordsExtractKeywords({
+    required List<String> texts,
+    required int limit,
+  });
+
+  Future<Uint8List> crateApiMobiExtractMobiResource({
+    required String path,
+    required String href,
+  });
+
+  Future<ReadableContent> crateApiReadabilityExtractReadable({
+    required String html,
+    String? baseUrl,
+  });
+
+  Future<Uint8List> crateApiUmdExtractUmdResource({
+    required String path,
+    required String href,
+  });
+
+  Future<Extraction> crateApiExtractionExtractXpath({
+    required String html,
+    required String expression,
+    required List<PostStep> steps,
+  });
+
+  Future<AlternativeChapter> crateApiSourceSwitchFetchChapterFromAlternatives({
+    required List<BookSource> sources,
+    required String title,
+    String? author,
+    required String chapterTitle,
+    int? chapterIndex,
+  });
+
+  Future<Feed> crateApiFeedFetchFeed({required String url});
+
+  Future<OpdsFeed> crateApiOpdsFetchOpdsFeed({
+    required String url,
+    OpdsCredentials? credentials,
+  });
+
+  Future<SourceBook> crateApiBookSourceFetchSourceBookInfo({
+    required BookSource source,
+    required String bookUrl,
+  });
+
+  Future<String> crateApiBookSourceFetchSourceContent({
+    required BookSource source,
+    required String chapterUrl,
+    String? nextChapterUrl,
+  });
+
+  Future<List<SourceChapter>> crateApiBookSourceFetchSourceToc({
+    required BookSource source,
+    required String tocUrl,
+  });
+
+  Future<List<AlternativeSource>> crateApiSourceSwitchFindAlternativeSources({
+    required List<BookSource> sources,
+    required String title,
+    String? author,
+  });
+
+  Future<List<DuplicateCluster>> crateApiDedupFindDuplicateBooks({
+    required DedupOptions options,
+    int? cancelToken,
+  });
+
+  Future<List<DuplicateMatch>> crateApiDedupFindDuplicatesOf({
+    required String title,
+    String? author,
+    required DedupOptions options,
+  });
+
+  Future<ChapterFingerprint> crateApiFingerprintFingerprintChapter({
+    required String text,
+  });
+
+  Future<double> crateApiFingerprintFingerprintSimilarity({
+    required ChapterFingerprint a,
+    required ChapterFingerprint b,
+  });
+
+  Future<int?> crateApiReadChaptersFirstUnreadChapter({
+    required String bookId,
+    required int from,
+    required int chapterCount,
+  });
+
+  Future<String> crateApiFontCacheFontCacheId({required List<int> sourceData});
+
+  Future<FontCacheStats> crateApiFontCacheFontCacheStats();
+
+  Future<BlobGcReport> crateApiBlobsGc();
+
+  Future<Uint8List> crateApiCoverGenerateCover({
+    required String title,
+    required String author,
+    required String fontPath,
+    required CoverStyle style,
+  });
+
+  Future<Uint8List?> crateApiBlobsGet({required String hash});
+
+  Future<CachedAudio?> crateApiAudioCacheGetCachedAudio({
+    required String voice,
+    required String text,
+  });
+
+  Future<String?> crateApiChapterStoreGetChapter({
+    required String bookId,
+    required int chapterIndex,
+  });
+
+  Future<LibraryBook?> crateApiDbGetLibraryBook({required String id});
+
+  Future<ReadingProgress?> crateApiDbGetProgress({required String bookId});
+
+  Future<PlatformInt64> crateApiSessionsHeartbeatReadingSession({
+    required PlatformInt64 sessionId,
+  });
+
+  Future<HttpCacheEntry?> crateApiHttpCacheHttpCacheEntry({
+    required String url,
+  });
+
+  Future<HttpCacheStats> crateApiHttpCacheHttpCacheStats();
+
+  Future<String> crateApiHyphenationHyphenateText({
+    required String language,
+    required String text,
+  });
+
+  Future<List<String>> crateApiHyphenationHyphenationLanguages();
+
+  Future<Uint32List> crateApiHyphenationHyphenationPoints({
+    required String language,
+    required String text,
+  });
+
+  Future<ImportReport> crateApiImportImportReportDefault();
+
+  Stream<WebImportProgress> crateApiWebImportImportWebBook({
+    required String bookId,
+    required List<WebChapter> chapters,
+    required List<PurifyRule> purifyRules,
+    int? cancelToken,
+  });
+
+  Future<void> crateApiSearchIndexChapters({
+    required String bookId,
+    required List<SearchChapter> chapters,
+  });
+
+  Future<TxtIndex> crateApiTxtIndexTxt({
+    required String path,
+    List<TxtChapterRule>? rules,
+  });
+
+  Future<TxtIndex> crateApiTxtDetectIndexTxtAtHeadings({
+    required String path,
+    required List<TxtHeadingCandidate> headings,
+  });
+
+  Future<Uint32List> crateApiSearchIndexedChapters({required String bookId});
+
+  Future<void> crateApiErrorInitApp();
+
+  Future<void> crateApiAudioCacheInitAudioCache({
+    required String cacheDir,
+    required BigInt maxBytes,
+  });
+
+  Future<void> crateApiBlobsInitBlobStore({required String storeDir});
+
+  Future<void> crateApiChapterCacheInitChapterCache({
+    required String cacheDir,
+    Uint8List? key,
+  });
+
+  Future<void> crateApiChapterStoreInitChapterStore({required String storeDir});
+
+  Future<void> crateApiCloudctlInitCloudctl({required String stateDir});
+
+  Future<void> crateApiCookiesInitCookieJar({required String jarDir});
+
+  Future<void> crateApiFontBundleInitFontBundles({required String bundleDir});
+
+  Future<void> crateApiFontCacheInitFontCache({
+    required String cacheDir,
+    required int maxBytes,
+  });
+
+  Future<void> crateApiHttpCacheInitHttpCache({
+    required String cacheDir,
+    required int maxBytes,
+  });
+
+  Future<void> crateApiLayoutCacheInitLayoutCache({
+    required String cacheDir,
+    required int maxBytes,
+  });
+
+  Future<void> crateApiSearchInitSearchIndex({required String indexDir});
+
+  Future<void> crateApiVaultInitTokenVault({
+    required String vaultDir,
+    required List<int> deviceSecret,
+  });
+
+  Future<void> crateApiTtsInitTtsRuntime({required String libraryPath});
+
+  Future<FontInfo> crateApiFontConverterInspectFont({
+    required List<int> fontData,
+  });
+
+  Future<Uint8List> crateApiFontConverterInstantiateVariableFont({
+    required List<int> ttfData,
+    required List<AxisValue> axes,
+  });
+
+  Future<LayoutCacheStats> crateApiLayoutCacheLayoutCacheStats();
+
+  Future<List<LineBreak>> crateApiLineBreakLineBreakOpportunities({
+    required String text,
+    KinsokuRules? rules,
+  });
+
+  Future<bool> crateApiFontCacheLinkFontCacheBook({
+    required String id,
+    required String bookId,
+  });
+
+  Future<ArchiveInfo> crateApiArchiveListArchive({required String path});
+
+  Future<List<CollectionFace>> crateApiFontCollectionListCollectionFaces({
+    required List<int> ttcData,
+  });
+
+  Future<List<ComicPage>> crateApiComicListComicPages({required String path});
+
+  Future<List<StoredCookie>> crateApiCookiesListCookies({String? host});
+
+  Future<List<DictInfo>> crateApiDictListDictionaries();
+
+  Future<List<EpubResource>> crateApiEpubListEpubResources({
+    required String path,
+  });
+
+  Future<List<FontCacheEntry>> crateApiFontCacheListFontCacheEntries();
+
+  Future<BookImage> crateApiBookImageLoadBookImage({
+    required String path,
+    required String href,
+    required int maxWidth,
+    required int maxHeight,
+    int? cancelToken,
+  });
+
+  Future<DarkImage> crateApiDarkImageLoadDarkBookImage({
+    required String path,
+    required String href,
+    required int maxWidth,
+    required int maxHeight,
+    required DarkImageOptions options,
+    int? cancelToken,
+  });
+
+  Future<StyledChapter> crateApiEpubLoadEpubChapterStyles({
+    required String path,
+    required String href,
+    required EpubLayout layout,
+  });
+
+  Future<int> crateApiHyphenationLoadHyphenationPatterns({
+    required String language,
+    required String patterns,
+    String? exceptions,
+    int? leftMin,
+    int? rightMin,
+  });
+
+  Future<TtsVoiceInfo> crateApiTtsLoadTtsVoice({
+    required String modelPath,
+    required String configPath,
+    String? lexiconPath,
+  });
+
+  Future<void> crateApiVaultLockTokenVault();
+
+  Future<List<String>> crateApiDictLookupPrefix({
+    required String prefix,
+    required int maxResults,
+  });
+
+  Future<List<DictDefinition>> crateApiDictLookupWord({
+    required String word,
+    required int maxResults,
+  });
+
+  Future<int> crateApiReadChaptersMarkChaptersRead({
+    required String bookId,
+    required int start,
+    required int end,
+  });
+
+  Future<int> crateApiReadChaptersMarkChaptersUnread({
+    required String bookId,
+    required int start,
+    required int end,
+  });
+
+  Future<int> crateApiEpubToolsMergeEpubs({
+    required List<EpubMergeSource> sources,
+    required BookMetadata metadata,
+    required String outputPath,
+    int? cancelToken,
+  });
+
+  Future<ChapterCacheMigration> crateApiChapterCacheMigrateChapterCache({
+    Uint8List? previousKey,
+  });
+
+  Future<List<String>> crateApiAudioCacheMissingCachedAudio({
+    required String voice,
+    required List<Utterance> utterances,
+  });
+
+  Future<String> crateApiTypographyNormalizeTypography({
+    required String text,
+    required TypographyOptions options,
+  });
+
+  Future<String> crateApiOpdsOpdsSearchUrl({
+    required String template,
+    required String query,
+  });
+
+  Future<DictInfo> crateApiDictOpenDictionary({required String path});
+
+  Future<void> crateApiDbOpenLibraryDb({required String path});
+
+  Future<MappedTxtInfo> crateApiMappedTxtOpenMappedTxt({
+    required String path,
+    List<TxtChapterRule>? rules,
+  });
+
+  Future<String> crateApiVaultOpenSecret({
+    required String name,
+    required String sealed,
+  });
+
+  Future<List<TextPage>> crateApiPaginationPaginateText({
+    required String text,
+    required String fontPath,
+    required PageLayout layout,
+    KinsokuRules? kinsoku,
+  });
+
+  Future<List<VerticalPage>> crateApiVerticalPaginateVerticalText({
+    required String text,
+    required String fontPath,
+    required PageLayout layout,
+    KinsokuRules? kinsoku,
+  });
+
+  Future<List<BookSource>> crateApiBookSourceParseBookSources({
+    required String json,
+  });
+
+  Future<ParsedBook> crateApiEpubParseEpub({
+    required String path,
+    int? cancelToken,
+  });
+
+  Future<ParsedBook> crateApiFb2ParseFb2({
+    required String path,
+    int? cancelToken,
+  });
+
+  Future<Feed> crateApiFeedParseFeed({
+    required List<int> data,
+    required String url,
+  });
+
+  Future<ParsedBook> crateApiMobiParseMobi({
+    required String path,
+    int? cancelToken,
+  });
+
+  Future<ParsedBook> crateApiPdfParsePdf({
+    required String path,
+    int? cancelToken,
+  });
+
+  Future<ParsedBook> crateApiTxtParseTxt({
+    required String path,
+    List<TxtChapterRule>? rules,
+    int? cancelToken,
+  });
+
+  Future<ParsedBook> crateApiUmdParseUmd({
+    required String path,
+    int? cancelToken,
+  });
+
+  Future<Uint8List> crateApiTtsPcmToWav({
+    required List<int> pcm,
+    required int sampleRate,
+  });
+
+  Future<bool> crateApiFontCachePinFontCacheEntry({
+    required String id,
+    required bool pinned,
+  });
+
+  Future<TtsPosition?> crateApiTtsTimelinePositionForTime({
+    required BigInt timeMs,
+  });
+
+  Future<PrefetchStatus> crateApiPrefetchPrefetchStatus();
+
+  Future<List<Utterance>> crateApiTtsPrepPrepareTts({required String text});
+
+  Future<List<Utterance>> crateApiTtsPrepPrepareTtsSsml({
+    required String text,
+    required SsmlOptions options,
+  });
+
+  Future<int> crateApiAudioCachePresynthesizeAudio({
+    required String voice,
+    required List<Utterance> utterances,
+    required TtsOptions options,
+  });
+
+  Future<PurifyReport> crateApiPurifyPreviewPurify({
+    required String text,
+    required List<PurifyRule> rules,
+  });
+
+  Stream<ImportEvent> crateApiImportProcessBooks({
+    required List<String> paths,
+    required ImportOptions options,
+    int? cancelToken,
+  });
+
+  Future<ProcessedImage> crateApiImageProcessCover({
+    required List<int> imageBytes,
+    required CoverImageOptions options,
+  });
+
+  Future<String> crateApiPurifyPurifyText({
+    required String text,
+    required List<PurifyRule> rules,
+  });
+
+  Future<String> crateApiBlobsPut({
+    required String owner,
+    required List<int> data,
+  });
+
+  Future<void> crateApiAudioCachePutCachedAudio({
+    required String voice,
+    required String text,
+    required AudioFormat format,
+    required List<int> data,
+  });
+
+  Future<void> crateApiChapterStorePutChapter({
+    required String bookId,
+    required int chapterIndex,
+    required String text,
+  });
+
+  Future<List<Bookmark>> crateApiDbQueryBookmarks({
+    String? bookId,
+    required int offset,
+    required int limit,
+  });
+
+  Future<BookPage> crateApiDbQueryBooks({required BookQuery query});
+
+  Future<List<Highlight>> crateApiDbQueryHighlights({
+    String? bookId,
+    required int offset,
+    required int limit,
+  });
+
+  Future<List<LibraryChapter>> crateApiDbQueryLibraryChapters({
+    required String bookId,
+    required int offset,
+    required int limit,
+  });
+
+  Stream<RateLimitStatus> crateApiRateLimitRateLimitEvents();
+
+  Future<List<RateLimitStatus>> crateApiRateLimitRateLimitStatus();
+
+  Future<BackupInfo> crateApiBackupReadBackupInfo({required String path});
+
+  Future<String?> crateApiChapterCacheReadCachedChapter({
+    required String bookId,
+    required int chapterIndex,
+  });
+
+  Future<ChapterFingerprint?> crateApiChapterCacheReadCachedFingerprint({
+    required String bookId,
+    required int chapterIndex,
+  });
+
+  Future<String?> crateApiChapterCacheReadCachedSummary({
+    required String bookId,
+    required int chapterIndex,
+  });
+
+  Future<CalibreMetadata> crateApiCalibreReadCalibreOpf({required String path});
+
+  Future<List<ChapterRange>> crateApiReadChaptersReadChapterRanges({
+    required String bookId,
+  });
+
+  Future<Uint8List> crateApiComicReadComicPage({
+    required String path,
+    required int index,
+    int? maxWidth,
+    int? maxHeight,
+  });
+
+  Future<Uint8List?> crateApiFontBundleReadFontBundle({
+    required String bookId,
+    required String fontPath,
+  });
+
+  Future<String> crateApiMappedTxtReadMappedChapter({
+    required int id,
+    required int chapterIndex,
+  });
+
+  Future<MappedTextSlice> crateApiMappedTxtReadMappedText({
+    required int id,
+    required int start,
+    required int maxBytes,
+  });
+
+  Future<String> crateApiTxtReadTxtChapter({
+    required String path,
+    required TxtChapter chapter,
+  });
+
+  Future<ReadingStats> crateApiSessionsReadingStats({
+    required PlatformInt64 from,
+    required PlatformInt64 to,
+    required int utcOffsetMinutes,
+  });
+
+  Future<List<LogRecord>> crateApiLoggingRecentLogs({
+    required LogLevel minLevel,
+    required int limit,
+  });
+
+  Future<void> crateApiTtsTimelineRecordTtsDuration({
+    required String utteranceId,
+    required BigInt durationMs,
+  });
+
+  Future<int> crateApiBlobsRelease({required String prefix});
+
+  Future<void> crateApiTaskReleaseCancelToken({required int token});
+
+  Future<void> crateApiSearchRemoveBookFromIndex({required String bookId});
+
+  Future<int> crateApiChapterCacheRemoveCachedBook({required String bookId});
+
+  Future<int> crateApiFontBundleRemoveFontBundles({required String bookId});
+
+  Future<bool> crateApiHttpCacheRemoveHttpCacheEntry({required String url});
+
+  Future<FontPreview> crateApiFontPreviewRender({
+    required List<int> fontBytes,
+    required String sampleText,
+    required double pxSize,
+  });
+
+  Future<String> crateApiNotesExportRenderBookNotes({
+    required String bookId,
+    required NotesExportOptions options,
+  });
+
+  Future<PunctuationRepair> crateApiPunctuationRepairPunctuation({
+    required String text,
+    required RepairLevel level,
+  });
+
+  Future<Uint8List> crateApiFontValidationRepairTtf({
+    required List<int> ttfData,
+  });
+
+  Future<AnchorResolution?> crateApiAnchorsResolveAnchor({
+    required String text,
+    required TextAnchor anchor,
+  });
+
+  Future<List<AnchorResolution?>> crateApiAnchorsResolveAnchors({
+    required String text,
+    required List<TextAnchor> anchors,
+  });
+
+  Future<ResplitOutcome> crateApiResplitResplitStoredBook({
+    required String bookId,
+    List<TxtChapterRule>? rules,
+    required bool dryRun,
+    int? cancelToken,
+  });
+
+  Future<RestoreResult> crateApiBackupRestoreBackup({
+    required String path,
+    required List<PurifyRule> purifyRules,
+    required String bookSourcesJson,
+  });
+
+  Future<BenchReport> crateApiBenchRun();
+
+  Future<String> crateApiSanitizeSanitizeText({required String text});
+
+  Future<List<CalibreBook>> crateApiCalibreScanCalibreLibrary({
+    required String libraryDir,
+    int? cancelToken,
+  });
+
+  Future<WatermarkReport> crateApiWatermarkScanWatermarks({
+    required String text,
+  });
+
+  Future<ScrapedMetadata> crateApiMetadataScrapeMetadata({
+    required String path,
+  });
+
+  Future<ScrapedMetadata> crateApiMetadataScrapeMetadataFrom({
+    required String fileName,
+    String? text,
+  });
+
+  Future<String> crateApiVaultSealSecret({
+    required String name,
+    required String secret,
+  });
+
+  Future<List<SearchHit>> crateApiSearchSearchBook({
+    required String bookId,
+    required String query,
+    required int maxResults,
+  });
+
+  Future<List<SourceBook>> crateApiBookSourceSearchBookSource({
+    required BookSource source,
+    required String key,
+    required int page,
+  });
+
+  Future<List<SearchHit>> crateApiSearchSearchLibrary({
+    required String query,
+    required int maxResults,
+  });
+
+  Future<List<LibrarySearchResult>> crateApiLibrarySearchSearchLibraryBooks({
+    required String query,
+    required int limit,
+  });
+
+  Future<bool> crateApiVaultSecretsEqual({
+    required String a,
+    required String b,
+  });
+
+  Future<List<TextSegment>> crateApiSegmentSegment({required String text});
+
+  Future<void> crateApiDownloaderSetBandwidthPolicy({
+    required BandwidthPolicy policy,
+  });
+
+  Future<void> crateApiNetworkSetDnsOverHttps({String? endpoint});
+
+  Future<void> crateApiNetworkSetHostProxy({
+    required String host,
+    ProxyConfig? proxy,
+  });
+
+  Future<void> crateApiRateLimitSetHostRateLimit({
+    required String host,
+    RateLimit? limit,
+  });
+
+  Future<void> crateApiDbSetLibraryChapters({
+    required String bookId,
+    required List<LibraryChapter> chapters,
+  });
+
+  Future<void> crateApiDownloaderSetNetworkType({required NetworkType network});
+
+  Future<void> crateApiNetworkSetProxy({ProxyConfig? proxy});
+
+  Future<void> crateApiRateLimitSetRateLimit({RateLimit? limit});
+
+  Future<void> crateApiReadChaptersSetReadChapters({
+    required String bookId,
+    required List<int> chapters,
+  });
+
+  Future<void> crateApiSummarySetSummaryOptions({
+    required SummaryOptions options,
+  });
+
+  Future<List<LibraryChapter>> crateApiChapterSortSortLibraryChapters({
+    required List<LibraryChapter> chapters,
+  });
+
+  Future<List<EpubVolume>> crateApiEpubToolsSplitEpub({
+    required String path,
+    required EpubSplitOptions options,
+    required String outputDir,
+    int? cancelToken,
+  });
+
+  Future<PlatformInt64> crateApiSessionsStartReadingSession({
+    required String bookId,
+  });
+
+  Future<void> crateApiTtsTimelineStartTtsTimeline({
+    required List<Utterance> utterances,
+  });
+
+  Future<WebServerInfo> crateApiWebserverStartWebServer({
+    required WebServerConfig config,
+  });
+
+  Future<void> crateApiSessionsStopReadingSession({
+    required PlatformInt64 sessionId,
+  });
+
+  Future<bool> crateApiWebserverStopWebServer();
+
+  Future<String> crateApiWatermarkStripWatermarks({required String text});
+
+  Future<Uint8List> crateApiFontSubsetSubsetFont({
+    required List<int> ttfData,
+    required String text,
+  });
+
+  Future<String> crateApiSummarySummarizeChapter({
+    required String text,
+    required SummaryOptions options,
+  });
+
+  Future<SyncResult> crateApiSyncSyncHttp({
+    required String baseUrl,
+    String? bearerToken,
+    required String passphrase,
+    SyncState? state,
+    int? cancelToken,
+  });
+
+  Future<SyncResult> crateApiSyncSyncWebdav({
+    required WebDavConfig config,
+    required String remoteDir,
+    required String passphrase,
+    SyncState? state,
+    int? cancelToken,
+  });
+
+  Future<Uint8List> crateApiTtsSynthesizeSpeech({
+    required String text,
+    required TtsOptions options,
+  });
+
+  Stream<TtsAudioChunk> crateApiTtsSynthesizeSpeechStream({
+    required List<Utterance> utterances,
+    required TtsOptions options,
+  });
+
+  Future<ApiError?> crateApiErrorTakeLastPanic();
+
+  Future<RuleTest> crateApiRuleTestTestChapterRule({
+    required TxtChapterRule rule,
+    required String sampleText,
+  });
+
+  Future<RuleTest> crateApiRuleTestTestRule({
+    required PurifyRule rule,
+    required String sampleText,
+  });
+
+  Future<Uint8List> crateApiPlaceholderThumbhashToPng({
+    required String thumbhash,
+  });
+
+  Future<TierSettings> crateApiBenchTierSettings({
+    required PerformanceTier tier,
+  });
+
+  Future<BigInt?> crateApiTtsTimelineTimeForPosition({required int offset});
+
+  Future<DarkImage> crateApiDarkImageToneImageForDarkTheme({
+    required BookImage image,
+    required DarkImageOptions options,
+  });
+
+  Future<List<TranslatedParagraph>> crateApiTranslateTranslateChapter({
+    required String text,
+    required TranslatorConfig config,
+    int? cancelToken,
+  });
+
+  Future<List<String>> crateApiTranslateTranslateParagraphs({
+    required List<String> paragraphs,
+    required TranslatorConfig config,
+    int? cancelToken,
+  });
+
+  Future<int> crateApiFontCacheUnlinkFontCacheBook({required String bookId});
+
+  Future<void> crateApiTtsUnloadTtsVoice();
+
+  Future<int> crateApiReadChaptersUnreadChapterCount({
+    required String bookId,
+    required int chapterCount,
+  });
+
+  Future<PrefetchStatus> crateApiPrefetchUpdatePrefetch({
+    required PrefetchPlan plan,
+  });
+
+  Future<Int64List> crateApiDbUpsertBookmarks({
+    required List<Bookmark> bookmarks,
+  });
+
+  Future<int> crateApiDbUpsertBooks({required List<LibraryBook> books});
+
+  Future<Int64List> crateApiDbUpsertHighlights({
+    required List<Highlight> highlights,
+  });
+
+  Future<void> crateApiDbUpsertProgress({
+    required List<ReadingProgress> progress,
+  });
+
+  Future<ValidationReport> crateApiFontValidationValidateTtf({
+    required List<int> ttfData,
+  });
+
+  Future<List<StoredBookScan>> crateApiChapterStoreVerifyChapterStore({
+    required List<String> bookIds,
+  });
+
+  Future<CloudConfig> crateApiCloudctlVerifyCloudConfig({
+    required String envelope,
+  });
+
+  Stream<WebUpload> crateApiWebserverWebServerEvents();
+
+  Future<void> crateApiWebdavWebdavDelete({
+    required WebDavConfig config,
+    required String remotePath,
+  });
+
+  Future<WebDavSyncRecord> crateApiWebdavWebdavDownload({
+    required WebDavConfig config,
+    required String remotePath,
+    required String localPath,
+  });
+
+  Future<List<WebDavEntry>> crateApiWebdavWebdavList({
+    required WebDavConfig config,
+    required String path,
+  });
+
+  Future<WebDavSyncStatus> crateApiWebdavWebdavSyncStatus({
+    required WebDavConfig config,
+    required String localPath,
+    required String remotePath,
+    WebDavSyncRecord? lastSync,
+  });
+
+  Future<void> crateApiWebdavWebdavTestConnection({
+    required WebDavConfig config,
+  });
+
+  Future<WebDavSyncRecord> crateApiWebdavWebdavUpload({
+    required WebDavConfig config,
+    required String localPath,
+    required String remotePath,
+  });
+
+  Future<TextSegment?> crateApiSegmentWordAt({
+    required String text,
+    required int offset,
+  });
+
+  Future<void> crateApiChapterCacheWriteCachedChapter({
+    required String bookId,
+    required int chapterIndex,
+    required String text,
+  });
+
+  Future<void> crateApiLoggingWriteLog({
+    required LogLevel level,
+    required String target,
+    required String message,
+    required List<LogField> fields,
+  });
+}
+
+class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+  RustLibApiImpl({
+    required super.handler,
+    required super.wire,
+    required super.generalizedFrbRustBinding,
+    required super.portManager,
+  });
+
+  @override
+  Future<int> crateApiCookiesAddCookies({
+    required String url,
+    required List<String> cookies,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          sse_encode_list_String(cookies, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 1,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiCookiesAddCookiesConstMeta,
+        argValues: [url, cookies],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCookiesAddCookiesConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_cookies",
+        argNames: ["url", "cookies"],
+      );
+
+  @override
+  Future<List<PinyinSpan>> crateApiRubyAnnotatePinyin({required String text}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 2,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_pinyin_span,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiRubyAnnotatePinyinConstMeta,
+        argValues: [text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRubyAnnotatePinyinConstMeta =>
+      const TaskConstMeta(debugName: "annotate_pinyin", argNames: ["text"]);
+
+  @override
+  Future<ChapterAudio> crateApiAudioCacheAssembleChapterAudio({
+    required String voice,
+    required List<Utterance> utterances,
+    required String outputPath,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(voice, serializer);
+          sse_encode_list_utterance(utterances, serializer);
+          sse_encode_String(outputPath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 3,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_chapter_audio,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiAudioCacheAssembleChapterAudioConstMeta,
+        argValues: [voice, utterances, outputPath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAudioCacheAssembleChapterAudioConstMeta =>
+      const TaskConstMeta(
+        debugName: "assemble_chapter_audio",
+        argNames: ["voice", "utterances", "outputPath"],
+      );
+
+  @override
+  Future<AudioCacheStats> crateApiAudioCacheAudioCacheStats() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 4,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_audio_cache_stats,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiAudioCacheAudioCacheStatsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAudioCacheAudioCacheStatsConstMeta =>
+      const TaskConstMeta(debugName: "audio_cache_stats", argNames: []);
+
+  @override
+  Future<List<EmphasisSpan>> crateApiBionicBionicSpans({
+    required String text,
+    required BionicOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          sse_encode_box_autoadd_bionic_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 5,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_emphasis_span,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBionicBionicSpansConstMeta,
+        argValues: [text, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBionicBionicSpansConstMeta =>
+      const TaskConstMeta(
+        debugName: "bionic_spans",
+        argNames: ["text", "options"],
+      );
+
+  @override
+  Future<String?> crateApiBlobsBlobForOwner({required String owner}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(owner, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 6,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiBlobsBlobForOwnerConstMeta,
+        argValues: [owner],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBlobsBlobForOwnerConstMeta =>
+      const TaskConstMeta(debugName: "blob_for_owner", argNames: ["owner"]);
+
+  @override
+  Future<String?> crateApiBlobsBlobPath({required String hash}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(hash, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 7,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiBlobsBlobPathConstMeta,
+        argValues: [hash],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBlobsBlobPathConstMeta =>
+      const TaskConstMeta(debugName: "blob_path", argNames: ["hash"]);
+
+  @override
+  Future<BlobStoreStats> crateApiBlobsBlobStoreStats() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 8,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_blob_store_stats,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiBlobsBlobStoreStatsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBlobsBlobStoreStatsConstMeta =>
+      const TaskConstMeta(debugName: "blob_store_stats", argNames: []);
+
+  @override
+  Future<CalibreMetadata> crateApiCalibreCalibreMetadataDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 9,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_calibre_metadata,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCalibreCalibreMetadataDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCalibreCalibreMetadataDefaultConstMeta =>
+      const TaskConstMeta(debugName: "calibre_metadata_default", argNames: []);
+
+  @override
+  Future<bool> crateApiDownloaderCancelDownload({required int taskId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(taskId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 10,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDownloaderCancelDownloadConstMeta,
+        argValues: [taskId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloaderCancelDownloadConstMeta =>
+      const TaskConstMeta(debugName: "cancel_download", argNames: ["taskId"]);
+
+  @override
+  Future<int> crateApiPrefetchCancelPrefetch() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 11,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPrefetchCancelPrefetchConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPrefetchCancelPrefetchConstMeta =>
+      const TaskConstMeta(debugName: "cancel_prefetch", argNames: []);
+
+  @override
+  Future<bool> crateApiTaskCancelTask({required int token}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(token, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 12,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTaskCancelTaskConstMeta,
+        argValues: [token],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTaskCancelTaskConstMeta =>
+      const TaskConstMeta(debugName: "cancel_task", argNames: ["token"]);
+
+  @override
+  Future<BigInt?> crateApiChapterSortChapterNumber({required String title}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(title, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 13,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChapterSortChapterNumberConstMeta,
+        argValues: [title],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChapterSortChapterNumberConstMeta =>
+      const TaskConstMeta(debugName: "chapter_number", argNames: ["title"]);
+
+  @override
+  Future<String> crateApiChapterSortChapterSortKey({required String title}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(title, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 14,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChapterSortChapterSortKeyConstMeta,
+        argValues: [title],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChapterSortChapterSortKeyConstMeta =>
+      const TaskConstMeta(debugName: "chapter_sort_key", argNames: ["title"]);
+
+  @override
+  Future<ChapterStoreStats> crateApiChapterStoreChapterStoreStats({
+    required String bookId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 15,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_chapter_store_stats,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiChapterStoreChapterStoreStatsConstMeta,
+        argValues: [bookId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChapterStoreChapterStoreStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "chapter_store_stats",
+        argNames: ["bookId"],
+      );
+
+  @override
+  Future<ChapterText> crateApiBookChapterText({required String html}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(html, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 16,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_chapter_text,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBookChapterTextConstMeta,
+        argValues: [html],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBookChapterTextConstMeta =>
+      const TaskConstMeta(debugName: "chapter_text", argNames: ["html"]);
+
+  @override
+  Future<List<BookUpdateSummary>> crateApiUpdateCheckCheckBookUpdates({
+    required List<UpdateCheckBook> books,
+    required int timeBudgetMs,
+    required bool saveChapters,
+    int? cancelToken,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_update_check_book(books, serializer);
+          sse_encode_u_32(timeBudgetMs, serializer);
+          sse_encode_bool(saveChapters, serializer);
+          sse_encode_opt_box_autoadd_u_32(cancelToken, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 17,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_book_update_summary,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiUpdateCheckCheckBookUpdatesConstMeta,
+        argValues: [books, timeBudgetMs, saveChapters, cancelToken],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUpdateCheckCheckBookUpdatesConstMeta =>
+      const TaskConstMeta(
+        debugName: "check_book_updates",
+        argNames: ["books", "timeBudgetMs", "saveChapters", "cancelToken"],
+      );
+
+  @override
+  Future<ChapterUpdateReport> crateApiChapterCacheCheckChapterUpdates({
+    required String bookId,
+    required int chapterCount,
+    required List<FetchedChapter> chapters,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          sse_encode_u_32(chapterCount, serializer);
+          sse_encode_list_fetched_chapter(chapters, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 18,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_chapter_update_report,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiChapterCacheCheckChapterUpdatesConstMeta,
+        argValues: [bookId, chapterCount, chapters],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChapterCacheCheckChapterUpdatesConstMeta =>
+      const TaskConstMeta(
+        debugName: "check_chapter_updates",
+        argNames: ["bookId", "chapterCount", "chapters"],
+      );
+
+  @override
+  Future<CoverageReport> crateApiFontConverterCheckCoverage({
+    required List<int> fontData,
+    required String text,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(fontData, serializer);
+          sse_encode_String(text, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 19,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_coverage_report,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontConverterCheckCoverageConstMeta,
+        argValues: [fontData, text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontConverterCheckCoverageConstMeta =>
+      const TaskConstMeta(
+        debugName: "check_coverage",
+        argNames: ["fontData", "text"],
+      );
+
+  @override
+  Future<void> crateApiAudioCacheClearAudioCache() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 20,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiAudioCacheClearAudioCacheConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAudioCacheClearAudioCacheConstMeta =>
+      const TaskConstMeta(debugName: "clear_audio_cache", argNames: []);
+
+  @override
+  Future<int> crateApiCookiesClearCookies({String? host}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(host, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 21,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiCookiesClearCookiesConstMeta,
+        argValues: [host],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCookiesClearCookiesConstMeta =>
+      const TaskConstMeta(debugName: "clear_cookies", argNames: ["host"]);
+
+  @override
+  Future<void> crateApiNetworkClearDnsCache() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 22,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiNetworkClearDnsCacheConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNetworkClearDnsCacheConstMeta =>
+      const TaskConstMeta(debugName: "clear_dns_cache", argNames: []);
+
+  @override
+  Future<void> crateApiFontCacheClearFontCache() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 23,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontCacheClearFontCacheConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontCacheClearFontCacheConstMeta =>
+      const TaskConstMeta(debugName: "clear_font_cache", argNames: []);
+
+  @override
+  Future<void> crateApiNetworkClearHostProxy({required String host}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(host, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 24,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiNetworkClearHostProxyConstMeta,
+        argValues: [host],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNetworkClearHostProxyConstMeta =>
+      const TaskConstMeta(debugName: "clear_host_proxy", argNames: ["host"]);
+
+  @override
+  Future<void> crateApiRateLimitClearHostRateLimit({required String host}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(host, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 25,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiRateLimitClearHostRateLimitConstMeta,
+        argValues: [host],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRateLimitClearHostRateLimitConstMeta =>
+      const TaskConstMeta(
+        debugName: "clear_host_rate_limit",
+        argNames: ["host"],
+      );
+
+  @override
+  Future<void> crateApiHttpCacheClearHttpCache() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 26,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiHttpCacheClearHttpCacheConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHttpCacheClearHttpCacheConstMeta =>
+      const TaskConstMeta(debugName: "clear_http_cache", argNames: []);
+
+  @override
+  Future<void> crateApiLayoutCacheClearLayoutCache() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 27,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiLayoutCacheClearLayoutCacheConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLayoutCacheClearLayoutCacheConstMeta =>
+      const TaskConstMeta(debugName: "clear_layout_cache", argNames: []);
+
+  @override
+  Future<void> crateApiLoggingClearLogs() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 28,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLoggingClearLogsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoggingClearLogsConstMeta =>
+      const TaskConstMeta(debugName: "clear_logs", argNames: []);
+
+  @override
+  Future<int> crateApiSessionsClearReadingSessions({String? bookId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(bookId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSessionsClearReadingSessionsConstMeta,
+        argValues: [bookId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionsClearReadingSessionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "clear_reading_sessions",
+        argNames: ["bookId"],
+      );
+
+  @override
+  Future<bool> crateApiDictCloseDictionary({required int id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 30,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDictCloseDictionaryConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDictCloseDictionaryConstMeta =>
+      const TaskConstMeta(debugName: "close_dictionary", argNames: ["id"]);
+
+  @override
+  Future<void> crateApiDbCloseLibraryDb() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDbCloseLibraryDbConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDbCloseLibraryDbConstMeta =>
+      const TaskConstMeta(debugName: "close_library_db", argNames: []);
+
+  @override
+  Future<bool> crateApiMappedTxtCloseMappedTxt({required int id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 32,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMappedTxtCloseMappedTxtConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMappedTxtCloseMappedTxtConstMeta =>
+      const TaskConstMeta(debugName: "close_mapped_txt", argNames: ["id"]);
+
+  @override
+  Future<ChapterStoreStats> crateApiChapterStoreCompactChapterStore({
+    required String bookId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_chapter_store_stats,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiChapterStoreCompactChapterStoreConstMeta,
+        argValues: [bookId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChapterStoreCompactChapterStoreConstMeta =>
+      const TaskConstMeta(
+        debugName: "compact_chapter_store",
+        argNames: ["bookId"],
+      );
+
+  @override
+  Future<int> crateApiChapterSortCompareChapterTitles({
+    required String a,
+    required String b,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(a, serializer);
+          sse_encode_String(b, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChapterSortCompareChapterTitlesConstMeta,
+        argValues: [a, b],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChapterSortCompareChapterTitlesConstMeta =>
+      const TaskConstMeta(
+        debugName: "compare_chapter_titles",
+        argNames: ["a", "b"],
+      );
+
+  @override
+  Future<Uint8List> crateApiFontConverterCompressTtfToWoff2({
+    required List<int> ttfData,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(ttfData, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontConverterCompressTtfToWoff2ConstMeta,
+        argValues: [ttfData],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontConverterCompressTtfToWoff2ConstMeta =>
+      const TaskConstMeta(
+        debugName: "compress_ttf_to_woff2",
+        argNames: ["ttfData"],
+      );
+
+  @override
+  Future<void> crateApiDownloaderConfigureDownloader({
+    required DownloaderConfig config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_downloader_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 36,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDownloaderConfigureDownloaderConstMeta,
+        argValues: [config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloaderConfigureDownloaderConstMeta =>
+      const TaskConstMeta(
+        debugName: "configure_downloader",
+        argNames: ["config"],
+      );
+
+  @override
+  Future<void> crateApiLoggingConfigureLogging({
+    required LoggingConfig config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_logging_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 37,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiLoggingConfigureLoggingConstMeta,
+        argValues: [config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoggingConfigureLoggingConstMeta =>
+      const TaskConstMeta(debugName: "configure_logging", argNames: ["config"]);
+
+  @override
+  Future<void> crateApiPrefetchConfigurePrefetch({
+    required PrefetchConfig config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_prefetch_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 38,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiPrefetchConfigurePrefetchConstMeta,
+        argValues: [config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPrefetchConfigurePrefetchConstMeta =>
+      const TaskConstMeta(
+        debugName: "configure_prefetch",
+        argNames: ["config"],
+      );
+
+  @override
+  Future<String> crateApiOpenccConvertChinese({
+    required String text,
+    required ChineseConversion conversion,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          sse_encode_chinese_conversion(conversion, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiOpenccConvertChineseConstMeta,
+        argValues: [text, conversion],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiOpenccConvertChineseConstMeta =>
+      const TaskConstMeta(
+        debugName: "convert_chinese",
+        argNames: ["text", "conversion"],
+      );
+
+  @override
+  Future<List<String>> crateApiOpenccConvertChineseBatch({
+    required List<String> texts,
+    required ChineseConversion conversion,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(texts, serializer);
+          sse_encode_chinese_conversion(conversion, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiOpenccConvertChineseBatchConstMeta,
+        argValues: [texts, conversion],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiOpenccConvertChineseBatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "convert_chinese_batch",
+        argNames: ["texts", "conversion"],
+      );
+
+  @override
+  Future<Uint8List> crateApiFontConverterConvertFontToTtf({
+    required List<int> fontData,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(fontData, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 41,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontConverterConvertFontToTtfConstMeta,
+        argValues: [fontData],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontConverterConvertFontToTtfConstMeta =>
+      const TaskConstMeta(
+        debugName: "convert_font_to_ttf",
+        argNames: ["fontData"],
+      );
+
+  @override
+  Future<List<ConversionResult>> crateApiFontConverterConvertWoff2Batch({
+    required List<NamedFont> fonts,
+    int? cancelToken,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_named_font(fonts, serializer);
+          sse_encode_opt_box_autoadd_u_32(cancelToken, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 42,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_conversion_result,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontConverterConvertWoff2BatchConstMeta,
+        argValues: [fonts, cancelToken],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontConverterConvertWoff2BatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "convert_woff2_batch",
+        argNames: ["fonts", "cancelToken"],
+      );
+
+  @override
+  Future<Uint8List> crateApiFontConverterConvertWoff2ToTtf({
+    required List<int> woff2Data,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(woff2Data, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontConverterConvertWoff2ToTtfConstMeta,
+        argValues: [woff2Data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontConverterConvertWoff2ToTtfConstMeta =>
+      const TaskConstMeta(
+        debugName: "convert_woff2_to_ttf",
+        argNames: ["woff2Data"],
+      );
+
+  @override
+  Stream<TtfChunk> crateApiFontConverterConvertWoff2ToTtfChunked({
+    required List<int> woff2Data,
+    int? chunkSize,
+  }) {
+    final sink = RustStreamSink<TtfChunk>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_list_prim_u_8_loose(woff2Data, serializer);
+            sse_encode_opt_box_autoadd_u_32(chunkSize, serializer);
+            sse_encode_StreamSink_ttf_chunk_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 44,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_api_error,
+          ),
+          constMeta: kCrateApiFontConverterConvertWoff2ToTtfChunkedConstMeta,
+          argValues: [woff2Data, chunkSize, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiFontConverterConvertWoff2ToTtfChunkedConstMeta =>
+      const TaskConstMeta(
+        debugName: "convert_woff2_to_ttf_chunked",
+        argNames: ["woff2Data", "chunkSize", "sink"],
+      );
+
+  @override
+  Future<int> crateApiFontConverterConvertWoff2ToTtfFile({
+    required List<int> woff2Data,
+    required String ttfPath,
+    int? cancelToken,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(woff2Data, serializer);
+          sse_encode_String(ttfPath, serializer);
+          sse_encode_opt_box_autoadd_u_32(cancelToken, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontConverterConvertWoff2ToTtfFileConstMeta,
+        argValues: [woff2Data, ttfPath, cancelToken],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontConverterConvertWoff2ToTtfFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "convert_woff2_to_ttf_file",
+        argNames: ["woff2Data", "ttfPath", "cancelToken"],
+      );
+
+  @override
+  Future<Uint8List> crateApiFontConverterConvertWoffToTtf({
+    required List<int> woffData,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(woffData, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontConverterConvertWoffToTtfConstMeta,
+        argValues: [woffData],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontConverterConvertWoffToTtfConstMeta =>
+      const TaskConstMeta(
+        debugName: "convert_woff_to_ttf",
+        argNames: ["woffData"],
+      );
+
+  @override
+  Future<BookStats> crateApiStatsCountBook({
+    required List<String> chapters,
+    required int charsPerMinute,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(chapters, serializer);
+          sse_encode_u_32(charsPerMinute, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 47,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_book_stats,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiStatsCountBookConstMeta,
+        argValues: [chapters, charsPerMinute],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStatsCountBookConstMeta =>
+      const TaskConstMeta(
+        debugName: "count_book",
+        argNames: ["chapters", "charsPerMinute"],
+      );
+
+  @override
+  Future<TextStats> crateApiStatsCountText({
+    required String text,
+    required int charsPerMinute,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          sse_encode_u_32(charsPerMinute, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 48,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_text_stats,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiStatsCountTextConstMeta,
+        argValues: [text, charsPerMinute],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStatsCountTextConstMeta =>
+      const TaskConstMeta(
+        debugName: "count_text",
+        argNames: ["text", "charsPerMinute"],
+      );
+
+  @override
+  Future<CoverPlaceholder> crateApiPlaceholderCoverPlaceholder({
+    required List<int> imageBytes,
+    required int thumbnailSize,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(imageBytes, serializer);
+          sse_encode_u_32(thumbnailSize, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 49,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cover_placeholder,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiPlaceholderCoverPlaceholderConstMeta,
+        argValues: [imageBytes, thumbnailSize],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlaceholderCoverPlaceholderConstMeta =>
+      const TaskConstMeta(
+        debugName: "cover_placeholder",
+        argNames: ["imageBytes", "thumbnailSize"],
+      );
+
+  @override
+  Future<TextAnchor> crateApiAnchorsCreateAnchor({
+    required String text,
+    required int start,
+    required int end,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          sse_encode_u_32(start, serializer);
+          sse_encode_u_32(end, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 50,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_text_anchor,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiAnchorsCreateAnchorConstMeta,
+        argValues: [text, start, end],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAnchorsCreateAnchorConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_anchor",
+        argNames: ["text", "start", "end"],
+      );
+
+  @override
+  Future<BackupInfo> crateApiBackupCreateBackup({
+    required String path,
+    required List<PurifyRule> purifyRules,
+    required String bookSourcesJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_list_purify_rule(purifyRules, serializer);
+          sse_encode_String(bookSourcesJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 51,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_backup_info,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiBackupCreateBackupConstMeta,
+        argValues: [path, purifyRules, bookSourcesJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBackupCreateBackupConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_backup",
+        argNames: ["path", "purifyRules", "bookSourcesJson"],
+      );
+
+  @override
+  Future<int> crateApiTaskCreateCancelToken() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 52,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTaskCreateCancelTokenConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTaskCreateCancelTokenConstMeta =>
+      const TaskConstMeta(debugName: "create_cancel_token", argNames: []);
+
+  @override
+  Future<BandwidthPolicy> crateApiDownloaderDefaultBandwidthPolicy() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bandwidth_policy,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDownloaderDefaultBandwidthPolicyConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloaderDefaultBandwidthPolicyConstMeta =>
+      const TaskConstMeta(debugName: "default_bandwidth_policy", argNames: []);
+
+  @override
+  Future<BionicOptions> crateApiBionicDefaultBionicOptions() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 54,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bionic_options,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBionicDefaultBionicOptionsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBionicDefaultBionicOptionsConstMeta =>
+      const TaskConstMeta(debugName: "default_bionic_options", argNames: []);
+
+  @override
+  Future<CoverImageOptions> crateApiImageDefaultCoverImageOptions() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cover_image_options,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiImageDefaultCoverImageOptionsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiImageDefaultCoverImageOptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "default_cover_image_options",
+        argNames: [],
+      );
+
+  @override
+  Future<CoverStyle> crateApiCoverDefaultCoverStyle({required String title}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(title, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 56,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cover_style,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCoverDefaultCoverStyleConstMeta,
+        argValues: [title],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCoverDefaultCoverStyleConstMeta =>
+      const TaskConstMeta(
+        debugName: "default_cover_style",
+        argNames: ["title"],
+      );
+
+  @override
+  Future<DarkImageOptions> crateApiDarkImageDefaultDarkImageOptions() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_dark_image_options,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDarkImageDefaultDarkImageOptionsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDarkImageDefaultDarkImageOptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "default_dark_image_options",
+        argNames: [],
+      );
+
+  @override
+  Future<DedupOptions> crateApiDedupDefaultDedupOptions() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 58,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_dedup_options,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDedupDefaultDedupOptionsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDedupDefaultDedupOptionsConstMeta =>
+      const TaskConstMeta(debugName: "default_dedup_options", argNames: []);
+
+  @override
+  Future<DownloaderConfig> crateApiDownloaderDefaultDownloaderConfig() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 59,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_downloader_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDownloaderDefaultDownloaderConfigConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloaderDefaultDownloaderConfigConstMeta =>
+      const TaskConstMeta(debugName: "default_downloader_config", argNames: []);
+
+  @override
+  Future<ImportOptions> crateApiImportDefaultImportOptions() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 60,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_import_options,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiImportDefaultImportOptionsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiImportDefaultImportOptionsConstMeta =>
+      const TaskConstMeta(debugName: "default_import_options", argNames: []);
+
+  @override
+  Future<KinsokuRules> crateApiLineBreakDefaultKinsokuRules() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 61,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_kinsoku_rules,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLineBreakDefaultKinsokuRulesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLineBreakDefaultKinsokuRulesConstMeta =>
+      const TaskConstMeta(debugName: "default_kinsoku_rules", argNames: []);
+
+  @override
+  Future<LoggingConfig> crateApiLoggingDefaultLoggingConfig() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 62,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_logging_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLoggingDefaultLoggingConfigConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoggingDefaultLoggingConfigConstMeta =>
+      const TaskConstMeta(debugName: "default_logging_config", argNames: []);
+
+  @override
+  Future<PrefetchConfig> crateApiPrefetchDefaultPrefetchConfig() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 63,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_prefetch_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPrefetchDefaultPrefetchConfigConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPrefetchDefaultPrefetchConfigConstMeta =>
+      const TaskConstMeta(debugName: "default_prefetch_config", argNames: []);
+
+  @override
+  Future<List<PurifyRule>> crateApiPurifyDefaultPurifyRules() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 64,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_purify_rule,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPurifyDefaultPurifyRulesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPurifyDefaultPurifyRulesConstMeta =>
+      const TaskConstMeta(debugName: "default_purify_rules", argNames: []);
+
+  @override
+  Future<RateLimit> crateApiRateLimitDefaultRateLimit() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 65,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_rate_limit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiRateLimitDefaultRateLimitConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRateLimitDefaultRateLimitConstMeta =>
+      const TaskConstMeta(debugName: "default_rate_limit", argNames: []);
+
+  @override
+  Future<SsmlOptions> crateApiTtsPrepDefaultSsmlOptions() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 66,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_ssml_options,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTtsPrepDefaultSsmlOptionsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTtsPrepDefaultSsmlOptionsConstMeta =>
+      const TaskConstMeta(debugName: "default_ssml_options", argNames: []);
+
+  @override
+  Future<SummaryOptions> crateApiSummaryDefaultSummaryOptions() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 67,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_summary_options,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSummaryDefaultSummaryOptionsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSummaryDefaultSummaryOptionsConstMeta =>
+      const TaskConstMeta(debugName: "default_summary_options", argNames: []);
+
+  @override
+  Future<TranslatorConfig> crateApiTranslateDefaultTranslatorConfig() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 68,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_translator_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTranslateDefaultTranslatorConfigConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTranslateDefaultTranslatorConfigConstMeta =>
+      const TaskConstMeta(debugName: "default_translator_config", argNames: []);
+
+  @override
+  Future<TtsOptions> crateApiTtsDefaultTtsOptions() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 69,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_tts_options,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTtsDefaultTtsOptionsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTtsDefaultTtsOptionsConstMeta =>
+      const TaskConstMeta(debugName: "default_tts_options", argNames: []);
+
+  @override
+  Future<List<TxtChapterRule>> crateApiTxtDefaultTxtRules() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 70,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_txt_chapter_rule,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTxtDefaultTxtRulesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTxtDefaultTxtRulesConstMeta =>
+      const TaskConstMeta(debugName: "default_txt_rules", argNames: []);
+
+  @override
+  Future<TypographyOptions> crateApiTypographyDefaultTypographyOptions() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 71,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_typography_options,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTypographyDefaultTypographyOptionsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTypographyDefaultTypographyOptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "default_typography_options",
+        argNames: [],
+      );
+
+  @override
+  Future<WebServerConfig> crateApiWebserverDefaultWebServerConfig() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 72,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_web_server_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWebserverDefaultWebServerConfigConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebserverDefaultWebServerConfigConstMeta =>
+      const TaskConstMeta(debugName: "default_web_server_config", argNames: []);
+
+  @override
+  Future<bool> crateApiDbDeleteBookmark({required PlatformInt64 id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 73,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDbDeleteBookmarkConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDbDeleteBookmarkConstMeta =>
+      const TaskConstMeta(debugName: "delete_bookmark", argNames: ["id"]);
+
+  @override
+  Future<int> crateApiDbDeleteBooks({required List<String> ids}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(ids, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 74,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDbDeleteBooksConstMeta,
+        argValues: [ids],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDbDeleteBooksConstMeta =>
+      const TaskConstMeta(debugName: "delete_books", argNames: ["ids"]);
+
+  @override
+  Future<bool> crateApiChapterStoreDeleteChapter({
+    required String bookId,
+    required int chapterIndex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          sse_encode_u_32(chapterIndex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 75,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiChapterStoreDeleteChapterConstMeta,
+        argValues: [bookId, chapterIndex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChapterStoreDeleteChapterConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_chapter",
+        argNames: ["bookId", "chapterIndex"],
+      );
+
+  @override
+  Future<bool> crateApiDbDeleteHighlight({required PlatformInt64 id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 76,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDbDeleteHighlightConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDbDeleteHighlightConstMeta =>
+      const TaskConstMeta(debugName: "delete_highlight", argNames: ["id"]);
+
+  @override
+  Future<void> crateApiChapterStoreDeleteStoredBook({required String bookId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 77,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiChapterStoreDeleteStoredBookConstMeta,
+        argValues: [bookId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChapterStoreDeleteStoredBookConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_stored_book",
+        argNames: ["bookId"],
+      );
+
+  @override
+  Future<List<LanguageGuess>> crateApiLangdetectDetect({required String text}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 78,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_language_guess,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLangdetectDetectConstMeta,
+        argValues: [text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLangdetectDetectConstMeta =>
+      const TaskConstMeta(debugName: "detect", argNames: ["text"]);
+
+  @override
+  Future<DecodedText> crateApiEncodingDetectAndDecodeText({
+    required List<int> bytes,
+    String? overrideEncoding,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(bytes, serializer);
+          sse_encode_opt_String(overrideEncoding, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 79,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_decoded_text,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiEncodingDetectAndDecodeTextConstMeta,
+        argValues: [bytes, overrideEncoding],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiEncodingDetectAndDecodeTextConstMeta =>
+      const TaskConstMeta(
+        debugName: "detect_and_decode_text",
+        argNames: ["bytes", "overrideEncoding"],
+      );
+
+  @override
+  Future<List<LanguageGuess>> crateApiLangdetectDetectBookLanguage({
+    required List<String> chapters,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(chapters, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 80,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_language_guess,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLangdetectDetectBookLanguageConstMeta,
+        argValues: [chapters],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLangdetectDetectBookLanguageConstMeta =>
+      const TaskConstMeta(
+        debugName: "detect_book_language",
+        argNames: ["chapters"],
+      );
+
+  @override
+  Future<DrmStatus> crateApiDrmDetectDrm({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 81,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_drm_status,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDrmDetectDrmConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDrmDetectDrmConstMeta =>
+      const TaskConstMeta(debugName: "detect_drm", argNames: ["path"]);
+
+  @override
+  Future<TxtChapterProposal> crateApiTxtDetectDetectTxtChapters({
+    required String path,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 82,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_txt_chapter_proposal,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiTxtDetectDetectTxtChaptersConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTxtDetectDetectTxtChaptersConstMeta =>
+      const TaskConstMeta(debugName: "detect_txt_chapters", argNames: ["path"]);
+
+  @override
+  Future<WebBook> crateApiWebImportDetectWebToc({required String url}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 83,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_web_book,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiWebImportDetectWebTocConstMeta,
+        argValues: [url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebImportDetectWebTocConstMeta =>
+      const TaskConstMeta(debugName: "detect_web_toc", argNames: ["url"]);
+
+  @override
+  Future<Uint8List?> crateApiDictDictionaryResource({
+    required int dictId,
+    required String path,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(dictId, serializer);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 84,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDictDictionaryResourceConstMeta,
+        argValues: [dictId, path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDictDictionaryResourceConstMeta =>
+      const TaskConstMeta(
+        debugName: "dictionary_resource",
+        argNames: ["dictId", "path"],
+      );
+
+  @override
+  Future<ChapterDiff> crateApiChapterDiffDiffChapters({
+    required String old,
+    required String new_,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(old, serializer);
+          sse_encode_String(new_, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 85,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_chapter_diff,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChapterDiffDiffChaptersConstMeta,
+        argValues: [old, new_],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChapterDiffDiffChaptersConstMeta =>
+      const TaskConstMeta(
+        debugName: "diff_chapters",
+        argNames: ["old", "new_"],
+      );
+
+  @override
+  Stream<DownloadEvent> crateApiDownloaderDownloadEvents() {
+    final sink = RustStreamSink<DownloadEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_download_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 86,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_api_error,
+          ),
+          constMeta: kCrateApiDownloaderDownloadEventsConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiDownloaderDownloadEventsConstMeta =>
+      const TaskConstMeta(debugName: "download_events", argNames: ["sink"]);
+
+  @override
+  Future<int> crateApiDownloaderEnqueueBackgroundDownload({
+    required DownloadRequest request,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_download_request(request, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 87,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDownloaderEnqueueBackgroundDownloadConstMeta,
+        argValues: [request],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloaderEnqueueBackgroundDownloadConstMeta =>
+      const TaskConstMeta(
+        debugName: "enqueue_background_download",
+        argNames: ["request"],
+      );
+
+  @override
+  Future<int> crateApiDownloaderEnqueueDownload({
+    required DownloadRequest request,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_download_request(request, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 88,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDownloaderEnqueueDownloadConstMeta,
+        argValues: [request],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloaderEnqueueDownloadConstMeta =>
+      const TaskConstMeta(debugName: "enqueue_download", argNames: ["request"]);
+
+  @override
+  Future<int> crateApiOpdsEnqueueOpdsDownload({
+    required String url,
+    required String destination,
+    OpdsCredentials? credentials,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          sse_encode_String(destination, serializer);
+          sse_encode_opt_box_autoadd_opds_credentials(credentials, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 89,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiOpdsEnqueueOpdsDownloadConstMeta,
+        argValues: [url, destination, credentials],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiOpdsEnqueueOpdsDownloadConstMeta =>
+      const TaskConstMeta(
+        debugName: "enqueue_opds_download",
+        argNames: ["url", "destination", "credentials"],
+      );
+
+  @override
+  Future<bool> crateApiFontCacheEvictFontCacheEntry({required String id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 90,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontCacheEvictFontCacheEntryConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontCacheEvictFontCacheEntryConstMeta =>
+      const TaskConstMeta(
+        debugName: "evict_font_cache_entry",
+        argNames: ["id"],
+      );
+
+  @override
+  Future<int> crateApiNotesExportExportBookNotes({
+    required String bookId,
+    required String path,
+    required NotesExportOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          sse_encode_String(path, serializer);
+          sse_encode_box_autoadd_notes_export_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 91,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiNotesExportExportBookNotesConstMeta,
+        argValues: [bookId, path, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNotesExportExportBookNotesConstMeta =>
+      const TaskConstMeta(
+        debugName: "export_book_notes",
+        argNames: ["bookId", "path", "options"],
+      );
+
+  @override
+  Future<String> crateApiCalibreExportCalibreBook({
+    required CalibreMetadata metadata,
+    required String bookPath,
+    Uint8List? cover,
+    required String libraryDir,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_calibre_metadata(metadata, serializer);
+          sse_encode_String(bookPath, serializer);
+          sse_encode_opt_list_prim_u_8_strict(cover, serializer);
+          sse_encode_String(libraryDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 92,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiCalibreExportCalibreBookConstMeta,
+        argValues: [metadata, bookPath, cover, libraryDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCalibreExportCalibreBookConstMeta =>
+      const TaskConstMeta(
+        debugName: "export_calibre_book",
+        argNames: ["metadata", "bookPath", "cover", "libraryDir"],
+      );
+
+  @override
+  Stream<EpubExportProgress> crateApiEpubExportExportEpub({
+    required BookMetadata metadata,
+    required List<EpubExportChapter> chapters,
+    Uint8List? cover,
+    Uint8List? font,
+    required String outputPath,
+    int? cancelToken,
+  }) {
+    final sink = RustStreamSink<EpubExportProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_box_autoadd_book_metadata(metadata, serializer);
+            sse_encode_list_epub_export_chapter(chapters, serializer);
+            sse_encode_opt_list_prim_u_8_strict(cover, serializer);
+            sse_encode_opt_list_prim_u_8_strict(font, serializer);
+            sse_encode_String(outputPath, serializer);
+            sse_encode_StreamSink_epub_export_progress_Sse(sink, serializer);
+            sse_encode_opt_box_autoadd_u_32(cancelToken, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 93,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_api_error,
+          ),
+          constMeta: kCrateApiEpubExportExportEpubConstMeta,
+          argValues: [
+            metadata,
+            chapters,
+            cover,
+            font,
+            outputPath,
+            sink,
+            cancelToken,
+          ],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiEpubExportExportEpubConstMeta =>
+      const TaskConstMeta(
+        debugName: "export_epub",
+        argNames: [
+          "metadata",
+          "chapters",
+          "cover",
+          "font",
+          "outputPath",
+          "sink",
+          "cancelToken",
+        ],
+      );
+
+  @override
+  Future<int> crateApiLoggingExportLogs({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 94,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiLoggingExportLogsConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoggingExportLogsConstMeta =>
+      const TaskConstMeta(debugName: "export_logs", argNames: ["path"]);
+
+  @override
+  Future<FontBundle> crateApiFontBundleExtendFontBundle({
+    required String bookId,
+    required String fontPath,
+    required String text,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          sse_encode_String(fontPath, serializer);
+          sse_encode_String(text, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 95,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_font_bundle,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontBundleExtendFontBundleConstMeta,
+        argValues: [bookId, fontPath, text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontBundleExtendFontBundleConstMeta =>
+      const TaskConstMeta(
+        debugName: "extend_font_bundle",
+        argNames: ["bookId", "fontPath", "text"],
+      );
+
+  @override
+  Future<CoverPalette> crateApiPaletteExtract({required List<int> imageBytes}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(imageBytes, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 96,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cover_palette,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiPaletteExtractConstMeta,
+        argValues: [imageBytes],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPaletteExtractConstMeta =>
+      const TaskConstMeta(debugName: "extract", argNames: ["imageBytes"]);
+
+  @override
+  Stream<ArchiveProgress> crateApiArchiveExtractArchive({
+    required String path,
+    required String destDir,
+    Uint32List? indices,
+    int? cancelToken,
+  }) {
+    final sink = RustStreamSink<ArchiveProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(path, serializer);
+            sse_encode_String(destDir, serializer);
+            sse_encode_opt_list_prim_u_32_strict(indices, serializer);
+            sse_encode_StreamSink_archive_progress_Sse(sink, serializer);
+            sse_encode_opt_box_autoadd_u_32(cancelToken, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 97,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_api_error,
+          ),
+          constMeta: kCrateApiArchiveExtractArchiveConstMeta,
+          argValues: [path, destDir, indices, sink, cancelToken],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiArchiveExtractArchiveConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_archive",
+        argNames: ["path", "destDir", "indices", "sink", "cancelToken"],
+      );
+
+  @override
+  Future<Uint8List> crateApiBookImageExtractBookImage({
+    required String path,
+    required String href,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_String(href, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 98,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiBookImageExtractBookImageConstMeta,
+        argValues: [path, href],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBookImageExtractBookImageConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_book_image",
+        argNames: ["path", "href"],
+      );
+
+  @override
+  Future<BookKeywords> crateApiKeywordsExtractBookKeywords({
+    required String bookId,
+    required int limit,
+    int? cancelToken,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          sse_encode_u_32(limit, serializer);
+          sse_encode_opt_box_autoadd_u_32(cancelToken, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 99,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_book_keywords,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiKeywordsExtractBookKeywordsConstMeta,
+        argValues: [bookId, limit, cancelToken],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiKeywordsExtractBookKeywordsConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_book_keywords",
+        argNames: ["bookId", "limit", "cancelToken"],
+      );
+
+  @override
+  Future<Uint8List> crateApiFontCollectionExtractCollectionFace({
+    required List<int> ttcData,
+    required int index,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(ttcData, serializer);
+          sse_encode_u_32(index, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 100,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontCollectionExtractCollectionFaceConstMeta,
+        argValues: [ttcData, index],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontCollectionExtractCollectionFaceConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_collection_face",
+        argNames: ["ttcData", "index"],
+      );
+
+  @override
+  Future<Extraction> crateApiExtractionExtractCss({
+    required String html,
+    required String selector,
+    String? getter,
+    required List<PostStep> steps,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(html, serializer);
+          sse_encode_String(selector, serializer);
+          sse_encode_opt_String(getter, serializer);
+          sse_encode_list_post_step(steps, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 101,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_extraction,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiExtractionExtractCssConstMeta,
+        argValues: [html, selector, getter, steps],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiExtractionExtractCssConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_css",
+        argNames: ["html", "selector", "getter", "steps"],
+      );
+
+  @override
+  Future<Uint8List> crateApiEpubExtractEpubResource({
+    required String path,
+    required String href,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_String(href, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 102,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiEpubExtractEpubResourceConstMeta,
+        argValues: [path, href],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiEpubExtractEpubResourceConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_epub_resource",
+        argNames: ["path", "href"],
+      );
+
+  @override
+  Future<Uint8List> crateApiFb2ExtractFb2Resource({
+    required String path,
+    required String href,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_String(href, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 103,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFb2ExtractFb2ResourceConstMeta,
+        argValues: [path, href],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFb2ExtractFb2ResourceConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_fb2_resource",
+        argNames: ["path", "href"],
+      );
+
+  @override
+  Future<Uint32List> crateApiFontConverterExtractInvisibleCodepoints({
+    required List<int> ttfData,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(ttfData, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 104,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_32_strict,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontConverterExtractInvisibleCodepointsConstMeta,
+        argValues: [ttfData],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontConverterExtractInvisibleCodepointsConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_invisible_codepoints",
+        argNames: ["ttfData"],
+      );
+
+  @override
+  Future<Extraction> crateApiExtractionExtractJsonpath({
+    required String json,
+    required String path,
+    required List<PostStep> steps,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(json, serializer);
+          sse_encode_String(path, serializer);
+          sse_encode_list_post_step(steps, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 105,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_extraction,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiExtractionExtractJsonpathConstMeta,
+        argValues: [json, path, steps],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiExtractionExtractJsonpathConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_jsonpath",
+        argNames: ["json", "path", "steps"],
+      );
+
+  @override
+  Future<BookKeywords> crateApiKeywordsExtractKeywords({
+    required List<String> texts,
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(texts, serializer);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 106,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_book_keywords,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiKeywordsExtractKeywordsConstMeta,
+        argValues: [texts, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiKeywordsExtractKeywordsConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_keywords",
+        argNames: ["texts", "limit"],
+      );
+
+  @override
+  Future<Uint8List> crateApiMobiExtractMobiResource({
+    required String path,
+    required String href,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_String(href, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 107,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiMobiExtractMobiResourceConstMeta,
+        argValues: [path, href],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMobiExtractMobiResourceConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_mobi_resource",
+        argNames: ["path", "href"],
+      );
+
+  @override
+  Future<ReadableContent> crateApiReadabilityExtractReadable({
+    required String html,
+    String? baseUrl,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(html, serializer);
+          sse_encode_opt_String(baseUrl, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 108,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_readable_content,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiReadabilityExtractReadableConstMeta,
+        argValues: [html, baseUrl],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReadabilityExtractReadableConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_readable",
+        argNames: ["html", "baseUrl"],
+      );
+
+  @override
+  Future<Uint8List> crateApiUmdExtractUmdResource({
+    required String path,
+    required String href,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_String(href, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 109,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiUmdExtractUmdResourceConstMeta,
+        argValues: [path, href],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUmdExtractUmdResourceConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_umd_resource",
+        argNames: ["path", "href"],
+      );
+
+  @override
+  Future<Extraction> crateApiExtractionExtractXpath({
+    required String html,
+    required String expression,
+    required List<PostStep> steps,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(html, serializer);
+          sse_encode_String(expression, serializer);
+          sse_encode_list_post_step(steps, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 110,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_extraction,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiExtractionExtractXpathConstMeta,
+        argValues: [html, expression, steps],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiExtractionExtractXpathConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_xpath",
+        argNames: ["html", "expression", "steps"],
+      );
+
+  @override
+  Future<AlternativeChapter> crateApiSourceSwitchFetchChapterFromAlternatives({
+    required List<BookSource> sources,
+    required String title,
+    String? author,
+    required String chapterTitle,
+    int? chapterIndex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_book_source(sources, serializer);
+          sse_encode_String(title, serializer);
+          sse_encode_opt_String(author, serializer);
+          sse_encode_String(chapterTitle, serializer);
+          sse_encode_opt_box_autoadd_u_32(chapterIndex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 111,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_alternative_chapter,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSourceSwitchFetchChapterFromAlternativesConstMeta,
+        argValues: [sources, title, author, chapterTitle, chapterIndex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSourceSwitchFetchChapterFromAlternativesConstMeta =>
+      const TaskConstMeta(
+        debugName: "fetch_chapter_from_alternatives",
+        argNames: [
+          "sources",
+          "title",
+          "author",
+          "chapterTitle",
+          "chapterIndex",
+        ],
+      );
+
+  @override
+  Future<Feed> crateApiFeedFetchFeed({required String url}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 112,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_feed,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFeedFetchFeedConstMeta,
+        argValues: [url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFeedFetchFeedConstMeta =>
+      const TaskConstMeta(debugName: "fetch_feed", argNames: ["url"]);
+
+  @override
+  Future<OpdsFeed> crateApiOpdsFetchOpdsFeed({
+    required String url,
+    OpdsCredentials? credentials,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          sse_encode_opt_box_autoadd_opds_credentials(credentials, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 113,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opds_feed,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiOpdsFetchOpdsFeedConstMeta,
+        argValues: [url, credentials],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiOpdsFetchOpdsFeedConstMeta =>
+      const TaskConstMeta(
+        debugName: "fetch_opds_feed",
+        argNames: ["url", "credentials"],
+      );
+
+  @override
+  Future<SourceBook> crateApiBookSourceFetchSourceBookInfo({
+    required BookSource source,
+    required String bookUrl,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_book_source(source, serializer);
+          sse_encode_String(bookUrl, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 114,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_source_book,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiBookSourceFetchSourceBookInfoConstMeta,
+        argValues: [source, bookUrl],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBookSourceFetchSourceBookInfoConstMeta =>
+      const TaskConstMeta(
+        debugName: "fetch_source_book_info",
+        argNames: ["source", "bookUrl"],
+      );
+
+  @override
+  Future<String> crateApiBookSourceFetchSourceContent({
+    required BookSource source,
+    required String chapterUrl,
+    String? nextChapterUrl,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_book_source(source, serializer);
+          sse_encode_String(chapterUrl, serializer);
+          sse_encode_opt_String(nextChapterUrl, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 115,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiBookSourceFetchSourceContentConstMeta,
+        argValues: [source, chapterUrl, nextChapterUrl],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBookSourceFetchSourceContentConstMeta =>
+      const TaskConstMeta(
+        debugName: "fetch_source_content",
+        argNames: ["source", "chapterUrl", "nextChapterUrl"],
+      );
+
+  @override
+  Future<List<SourceChapter>> crateApiBookSourceFetchSourceToc({
+    required BookSource source,
+    required String tocUrl,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_book_source(source, serializer);
+          sse_encode_String(tocUrl, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 116,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_source_chapter,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiBookSourceFetchSourceTocConstMeta,
+        argValues: [source, tocUrl],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBookSourceFetchSourceTocConstMeta =>
+      const TaskConstMeta(
+        debugName: "fetch_source_toc",
+        argNames: ["source", "tocUrl"],
+      );
+
+  @override
+  Future<List<AlternativeSource>> crateApiSourceSwitchFindAlternativeSources({
+    required List<BookSource> sources,
+    required String title,
+    String? author,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_book_source(sources, serializer);
+          sse_encode_String(title, serializer);
+          sse_encode_opt_String(author, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 117,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_alternative_source,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSourceSwitchFindAlternativeSourcesConstMeta,
+        argValues: [sources, title, author],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSourceSwitchFindAlternativeSourcesConstMeta =>
+      const TaskConstMeta(
+        debugName: "find_alternative_sources",
+        argNames: ["sources", "title", "author"],
+      );
+
+  @override
+  Future<List<DuplicateCluster>> crateApiDedupFindDuplicateBooks({
+    required DedupOptions options,
+    int? cancelToken,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_dedup_options(options, serializer);
+          sse_encode_opt_box_autoadd_u_32(cancelToken, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 118,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_duplicate_cluster,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDedupFindDuplicateBooksConstMeta,
+        argValues: [options, cancelToken],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDedupFindDuplicateBooksConstMeta =>
+      const TaskConstMeta(
+        debugName: "find_duplicate_books",
+        argNames: ["options", "cancelToken"],
+      );
+
+  @override
+  Future<List<DuplicateMatch>> crateApiDedupFindDuplicatesOf({
+    required String title,
+    String? author,
+    required DedupOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(title, serializer);
+          sse_encode_opt_String(author, serializer);
+          sse_encode_box_autoadd_dedup_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 119,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_duplicate_match,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDedupFindDuplicatesOfConstMeta,
+        argValues: [title, author, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDedupFindDuplicatesOfConstMeta =>
+      const TaskConstMeta(
+        debugName: "find_duplicates_of",
+        argNames: ["title", "author", "options"],
+      );
+
+  @override
+  Future<ChapterFingerprint> crateApiFingerprintFingerprintChapter({
+    required String text,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 120,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_chapter_fingerprint,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiFingerprintFingerprintChapterConstMeta,
+        argValues: [text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFingerprintFingerprintChapterConstMeta =>
+      const TaskConstMeta(debugName: "fingerprint_chapter", argNames: ["text"]);
+
+  @override
+  Future<double> crateApiFingerprintFingerprintSimilarity({
+    required ChapterFingerprint a,
+    required ChapterFingerprint b,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_chapter_fingerprint(a, serializer);
+          sse_encode_box_autoadd_chapter_fingerprint(b, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 121,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_f_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiFingerprintFingerprintSimilarityConstMeta,
+        argValues: [a, b],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFingerprintFingerprintSimilarityConstMeta =>
+      const TaskConstMeta(
+        debugName: "fingerprint_similarity",
+        argNames: ["a", "b"],
+      );
+
+  @override
+  Future<int?> crateApiReadChaptersFirstUnreadChapter({
+    required String bookId,
+    required int from,
+    required int chapterCount,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          sse_encode_u_32(from, serializer);
+          sse_encode_u_32(chapterCount, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 122,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiReadChaptersFirstUnreadChapterConstMeta,
+        argValues: [bookId, from, chapterCount],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReadChaptersFirstUnreadChapterConstMeta =>
+      const TaskConstMeta(
+        debugName: "first_unread_chapter",
+        argNames: ["bookId", "from", "chapterCount"],
+      );
+
+  @override
+  Future<String> crateApiFontCacheFontCacheId({required List<int> sourceData}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(sourceData, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 123,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiFontCacheFontCacheIdConstMeta,
+        argValues: [sourceData],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontCacheFontCacheIdConstMeta =>
+      const TaskConstMeta(debugName: "font_cache_id", argNames: ["sourceData"]);
+
+  @override
+  Future<FontCacheStats> crateApiFontCacheFontCacheStats() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 124,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_font_cache_stats,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontCacheFontCacheStatsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontCacheFontCacheStatsConstMeta =>
+      const TaskConstMeta(debugName: "font_cache_stats", argNames: []);
+
+  @override
+  Future<BlobGcReport> crateApiBlobsGc() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 125,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_blob_gc_report,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiBlobsGcConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBlobsGcConstMeta =>
+      const TaskConstMeta(debugName: "gc", argNames: []);
+
+  @override
+  Future<Uint8List> crateApiCoverGenerateCover({
+    required String title,
+    required String author,
+    required String fontPath,
+    required CoverStyle style,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(title, serializer);
+          sse_encode_String(author, serializer);
+          sse_encode_String(fontPath, serializer);
+          sse_encode_box_autoadd_cover_style(style, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 126,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiCoverGenerateCoverConstMeta,
+        argValues: [title, author, fontPath, style],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCoverGenerateCoverConstMeta =>
+      const TaskConstMeta(
+        debugName: "generate_cover",
+        argNames: ["title", "author", "fontPath", "style"],
+      );
+
+  @override
+  Future<Uint8List?> crateApiBlobsGet({required String hash}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(hash, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 127,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiBlobsGetConstMeta,
+        argValues: [hash],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBlobsGetConstMeta =>
+      const TaskConstMeta(debugName: "get", argNames: ["hash"]);
+
+  @override
+  Future<CachedAudio?> crateApiAudioCacheGetCachedAudio({
+    required String voice,
+    required String text,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(voice, serializer);
+          sse_encode_String(text, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 128,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_cached_audio,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiAudioCacheGetCachedAudioConstMeta,
+        argValues: [voice, text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAudioCacheGetCachedAudioConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_cached_audio",
+        argNames: ["voice", "text"],
+      );
+
+  @override
+  Future<String?> crateApiChapterStoreGetChapter({
+    required String bookId,
+    required int chapterIndex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          sse_encode_u_32(chapterIndex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 129,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiChapterStoreGetChapterConstMeta,
+        argValues: [bookId, chapterIndex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChapterStoreGetChapterConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_chapter",
+        argNames: ["bookId", "chapterIndex"],
+      );
+
+  @override
+  Future<LibraryBook?> crateApiDbGetLibraryBook({required String id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 130,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_library_book,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDbGetLibraryBookConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDbGetLibraryBookConstMeta =>
+      const TaskConstMeta(debugName: "get_library_book", argNames: ["id"]);
+
+  @override
+  Future<ReadingProgress?> crateApiDbGetProgress({required String bookId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 131,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_reading_progress,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDbGetProgressConstMeta,
+        argValues: [bookId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDbGetProgressConstMeta =>
+      const TaskConstMeta(debugName: "get_progress", argNames: ["bookId"]);
+
+  @override
+  Future<PlatformInt64> crateApiSessionsHeartbeatReadingSession({
+    required PlatformInt64 sessionId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(sessionId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 132,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSessionsHeartbeatReadingSessionConstMeta,
+        argValues: [sessionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionsHeartbeatReadingSessionConstMeta =>
+      const TaskConstMeta(
+        debugName: "heartbeat_reading_session",
+        argNames: ["sessionId"],
+      );
+
+  @override
+  Future<HttpCacheEntry?> crateApiHttpCacheHttpCacheEntry({
+    required String url,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 133,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_http_cache_entry,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiHttpCacheHttpCacheEntryConstMeta,
+        argValues: [url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHttpCacheHttpCacheEntryConstMeta =>
+      const TaskConstMeta(debugName: "http_cache_entry", argNames: ["url"]);
+
+  @override
+  Future<HttpCacheStats> crateApiHttpCacheHttpCacheStats() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 134,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_http_cache_stats,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiHttpCacheHttpCacheStatsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHttpCacheHttpCacheStatsConstMeta =>
+      const TaskConstMeta(debugName: "http_cache_stats", argNames: []);
+
+  @override
+  Future<String> crateApiHyphenationHyphenateText({
+    required String language,
+    required String text,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(language, serializer);
+          sse_encode_String(text, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 135,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiHyphenationHyphenateTextConstMeta,
+        argValues: [language, text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHyphenationHyphenateTextConstMeta =>
+      const TaskConstMeta(
+        debugName: "hyphenate_text",
+        argNames: ["language", "text"],
+      );
+
+  @override
+  Future<List<String>> crateApiHyphenationHyphenationLanguages() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 136,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiHyphenationHyphenationLanguagesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHyphenationHyphenationLanguagesConstMeta =>
+      const TaskConstMeta(debugName: "hyphenation_languages", argNames: []);
+
+  @override
+  Future<Uint32List> crateApiHyphenationHyphenationPoints({
+    required String language,
+    required String text,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(language, serializer);
+          sse_encode_String(text, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 137,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_32_strict,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiHyphenationHyphenationPointsConstMeta,
+        argValues: [language, text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHyphenationHyphenationPointsConstMeta =>
+      const TaskConstMeta(
+        debugName: "hyphenation_points",
+        argNames: ["language", "text"],
+      );
+
+  @override
+  Future<ImportReport> crateApiImportImportReportDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 138,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_import_report,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiImportImportReportDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiImportImportReportDefaultConstMeta =>
+      const TaskConstMeta(debugName: "import_report_default", argNames: []);
+
+  @override
+  Stream<WebImportProgress> crateApiWebImportImportWebBook({
+    required String bookId,
+    required List<WebChapter> chapters,
+    required List<PurifyRule> purifyRules,
+    int? cancelToken,
+  }) {
+    final sink = RustStreamSink<WebImportProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(bookId, serializer);
+            sse_encode_list_web_chapter(chapters, serializer);
+            sse_encode_list_purify_rule(purifyRules, serializer);
+            sse_encode_StreamSink_web_import_progress_Sse(sink, serializer);
+            sse_encode_opt_box_autoadd_u_32(cancelToken, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 139,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_api_error,
+          ),
+          constMeta: kCrateApiWebImportImportWebBookConstMeta,
+          argValues: [bookId, chapters, purifyRules, sink, cancelToken],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiWebImportImportWebBookConstMeta =>
+      const TaskConstMeta(
+        debugName: "import_web_book",
+        argNames: ["bookId", "chapters", "purifyRules", "sink", "cancelToken"],
+      );
+
+  @override
+  Future<void> crateApiSearchIndexChapters({
+    required String bookId,
+    required List<SearchChapter> chapters,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          sse_encode_list_search_chapter(chapters, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 140,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSearchIndexChaptersConstMeta,
+        argValues: [bookId, chapters],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSearchIndexChaptersConstMeta =>
+      const TaskConstMeta(
+        debugName: "index_chapters",
+        argNames: ["bookId", "chapters"],
+      );
+
+  @override
+  Future<TxtIndex> crateApiTxtIndexTxt({
+    required String path,
+    List<TxtChapterRule>? rules,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_list_txt_chapter_rule(rules, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 141,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_txt_index,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiTxtIndexTxtConstMeta,
+        argValues: [path, rules],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTxtIndexTxtConstMeta =>
+      const TaskConstMeta(debugName: "index_txt", argNames: ["path", "rules"]);
+
+  @override
+  Future<TxtIndex> crateApiTxtDetectIndexTxtAtHeadings({
+    required String path,
+    required List<TxtHeadingCandidate> headings,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_list_txt_heading_candidate(headings, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 142,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_txt_index,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiTxtDetectIndexTxtAtHeadingsConstMeta,
+        argValues: [path, headings],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTxtDetectIndexTxtAtHeadingsConstMeta =>
+      const TaskConstMeta(
+        debugName: "index_txt_at_headings",
+        argNames: ["path", "headings"],
+      );
+
+  @override
+  Future<Uint32List> crateApiSearchIndexedChapters({required String bookId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 143,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_32_strict,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSearchIndexedChaptersConstMeta,
+        argValues: [bookId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSearchIndexedChaptersConstMeta =>
+      const TaskConstMeta(debugName: "indexed_chapters", argNames: ["bookId"]);
+
+  @override
+  Future<void> crateApiErrorInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 144,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiErrorInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiErrorInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  Future<void> crateApiAudioCacheInitAudioCache({
+    required String cacheDir,
+    required BigInt maxBytes,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cacheDir, serializer);
+          sse_encode_u_64(maxBytes, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 145,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiAudioCacheInitAudioCacheConstMeta,
+        argValues: [cacheDir, maxBytes],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAudioCacheInitAudioCacheConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_audio_cache",
+        argNames: ["cacheDir", "maxBytes"],
+      );
+
+  @override
+  Future<void> crateApiBlobsInitBlobStore({required String storeDir}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(storeDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 146,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiBlobsInitBlobStoreConstMeta,
+        argValues: [storeDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBlobsInitBlobStoreConstMeta =>
+      const TaskConstMeta(debugName: "init_blob_store", argNames: ["storeDir"]);
+
+  @override
+  Future<void> crateApiChapterCacheInitChapterCache({
+    required String cacheDir,
+    Uint8List? key,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cacheDir, serializer);
+          sse_encode_opt_list_prim_u_8_strict(key, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 147,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiChapterCacheInitChapterCacheConstMeta,
+        argValues: [cacheDir, key],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChapterCacheInitChapterCacheConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_chapter_cache",
+        argNames: ["cacheDir", "key"],
+      );
+
+  @override
+  Future<void> crateApiChapterStoreInitChapterStore({
+    required String storeDir,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(storeDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 148,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiChapterStoreInitChapterStoreConstMeta,
+        argValues: [storeDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChapterStoreInitChapterStoreConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_chapter_store",
+        argNames: ["storeDir"],
+      );
+
+  @override
+  Future<void> crateApiCloudctlInitCloudctl({required String stateDir}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(stateDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 149,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiCloudctlInitCloudctlConstMeta,
+        argValues: [stateDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCloudctlInitCloudctlConstMeta =>
+      const TaskConstMeta(debugName: "init_cloudctl", argNames: ["stateDir"]);
+
+  @override
+  Future<void> crateApiCookiesInitCookieJar({required String jarDir}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(jarDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 150,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiCookiesInitCookieJarConstMeta,
+        argValues: [jarDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCookiesInitCookieJarConstMeta =>
+      const TaskConstMeta(debugName: "init_cookie_jar", argNames: ["jarDir"]);
+
+  @override
+  Future<void> crateApiFontBundleInitFontBundles({required String bundleDir}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bundleDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 151,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontBundleInitFontBundlesConstMeta,
+        argValues: [bundleDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontBundleInitFontBundlesConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_font_bundles",
+        argNames: ["bundleDir"],
+      );
+
+  @override
+  Future<void> crateApiFontCacheInitFontCache({
+    required String cacheDir,
+    required int maxBytes,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cacheDir, serializer);
+          sse_encode_u_32(maxBytes, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 152,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontCacheInitFontCacheConstMeta,
+        argValues: [cacheDir, maxBytes],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontCacheInitFontCacheConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_font_cache",
+        argNames: ["cacheDir", "maxBytes"],
+      );
+
+  @override
+  Future<void> crateApiHttpCacheInitHttpCache({
+    required String cacheDir,
+    required int maxBytes,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cacheDir, serializer);
+          sse_encode_u_32(maxBytes, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 153,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiHttpCacheInitHttpCacheConstMeta,
+        argValues: [cacheDir, maxBytes],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHttpCacheInitHttpCacheConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_http_cache",
+        argNames: ["cacheDir", "maxBytes"],
+      );
+
+  @override
+  Future<void> crateApiLayoutCacheInitLayoutCache({
+    required String cacheDir,
+    required int maxBytes,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cacheDir, serializer);
+          sse_encode_u_32(maxBytes, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 154,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiLayoutCacheInitLayoutCacheConstMeta,
+        argValues: [cacheDir, maxBytes],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLayoutCacheInitLayoutCacheConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_layout_cache",
+        argNames: ["cacheDir", "maxBytes"],
+      );
+
+  @override
+  Future<void> crateApiSearchInitSearchIndex({required String indexDir}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(indexDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 155,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSearchInitSearchIndexConstMeta,
+        argValues: [indexDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSearchInitSearchIndexConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_search_index",
+        argNames: ["indexDir"],
+      );
+
+  @override
+  Future<void> crateApiVaultInitTokenVault({
+    required String vaultDir,
+    required List<int> deviceSecret,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(vaultDir, serializer);
+          sse_encode_list_prim_u_8_loose(deviceSecret, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 156,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiVaultInitTokenVaultConstMeta,
+        argValues: [vaultDir, deviceSecret],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiVaultInitTokenVaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_token_vault",
+        argNames: ["vaultDir", "deviceSecret"],
+      );
+
+  @override
+  Future<void> crateApiTtsInitTtsRuntime({required String libraryPath}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(libraryPath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 157,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiTtsInitTtsRuntimeConstMeta,
+        argValues: [libraryPath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTtsInitTtsRuntimeConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_tts_runtime",
+        argNames: ["libraryPath"],
+      );
+
+  @override
+  Future<FontInfo> crateApiFontConverterInspectFont({
+    required List<int> fontData,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(fontData, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 158,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_font_info,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontConverterInspectFontConstMeta,
+        argValues: [fontData],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontConverterInspectFontConstMeta =>
+      const TaskConstMeta(debugName: "inspect_font", argNames: ["fontData"]);
+
+  @override
+  Future<Uint8List> crateApiFontConverterInstantiateVariableFont({
+    required List<int> ttfData,
+    required List<AxisValue> axes,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(ttfData, serializer);
+          sse_encode_list_axis_value(axes, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 159,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontConverterInstantiateVariableFontConstMeta,
+        argValues: [ttfData, axes],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontConverterInstantiateVariableFontConstMeta =>
+      const TaskConstMeta(
+        debugName: "instantiate_variable_font",
+        argNames: ["ttfData", "axes"],
+      );
+
+  @override
+  Future<LayoutCacheStats> crateApiLayoutCacheLayoutCacheStats() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 160,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_layout_cache_stats,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiLayoutCacheLayoutCacheStatsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLayoutCacheLayoutCacheStatsConstMeta =>
+      const TaskConstMeta(debugName: "layout_cache_stats", argNames: []);
+
+  @override
+  Future<List<LineBreak>> crateApiLineBreakLineBreakOpportunities({
+    required String text,
+    KinsokuRules? rules,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          sse_encode_opt_box_autoadd_kinsoku_rules(rules, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 161,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_line_break,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLineBreakLineBreakOpportunitiesConstMeta,
+        argValues: [text, rules],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLineBreakLineBreakOpportunitiesConstMeta =>
+      const TaskConstMeta(
+        debugName: "line_break_opportunities",
+        argNames: ["text", "rules"],
+      );
+
+  @override
+  Future<bool> crateApiFontCacheLinkFontCacheBook({
+    required String id,
+    required String bookId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          sse_encode_String(bookId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 162,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontCacheLinkFontCacheBookConstMeta,
+        argValues: [id, bookId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontCacheLinkFontCacheBookConstMeta =>
+      const TaskConstMeta(
+        debugName: "link_font_cache_book",
+        argNames: ["id", "bookId"],
+      );
+
+  @override
+  Future<ArchiveInfo> crateApiArchiveListArchive({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 163,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_archive_info,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiArchiveListArchiveConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiArchiveListArchiveConstMeta =>
+      const TaskConstMeta(debugName: "list_archive", argNames: ["path"]);
+
+  @override
+  Future<List<CollectionFace>> crateApiFontCollectionListCollectionFaces({
+    required List<int> ttcData,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(ttcData, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 164,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_collection_face,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontCollectionListCollectionFacesConstMeta,
+        argValues: [ttcData],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontCollectionListCollectionFacesConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_collection_faces",
+        argNames: ["ttcData"],
+      );
+
+  @override
+  Future<List<ComicPage>> crateApiComicListComicPages({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 165,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_comic_page,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiComicListComicPagesConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiComicListComicPagesConstMeta =>
+      const TaskConstMeta(debugName: "list_comic_pages", argNames: ["path"]);
+
+  @override
+  Future<List<StoredCookie>> crateApiCookiesListCookies({String? host}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(host, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 166,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_stored_cookie,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiCookiesListCookiesConstMeta,
+        argValues: [host],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCookiesListCookiesConstMeta =>
+      const TaskConstMeta(debugName: "list_cookies", argNames: ["host"]);
+
+  @override
+  Future<List<DictInfo>> crateApiDictListDictionaries() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 167,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_dict_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDictListDictionariesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDictListDictionariesConstMeta =>
+      const TaskConstMeta(debugName: "list_dictionaries", argNames: []);
+
+  @override
+  Future<List<EpubResource>> crateApiEpubListEpubResources({
+    required String path,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 168,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_epub_resource,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiEpubListEpubResourcesConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiEpubListEpubResourcesConstMeta =>
+      const TaskConstMeta(debugName: "list_epub_resources", argNames: ["path"]);
+
+  @override
+  Future<List<FontCacheEntry>> crateApiFontCacheListFontCacheEntries() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 169,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_font_cache_entry,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontCacheListFontCacheEntriesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontCacheListFontCacheEntriesConstMeta =>
+      const TaskConstMeta(debugName: "list_font_cache_entries", argNames: []);
+
+  @override
+  Future<BookImage> crateApiBookImageLoadBookImage({
+    required String path,
+    required String href,
+    required int maxWidth,
+    required int maxHeight,
+    int? cancelToken,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_String(href, serializer);
+          sse_encode_u_32(maxWidth, serializer);
+          sse_encode_u_32(maxHeight, serializer);
+          sse_encode_opt_box_autoadd_u_32(cancelToken, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 170,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_book_image,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiBookImageLoadBookImageConstMeta,
+        argValues: [path, href, maxWidth, maxHeight, cancelToken],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBookImageLoadBookImageConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_book_image",
+        argNames: ["path", "href", "maxWidth", "maxHeight", "cancelToken"],
+      );
+
+  @override
+  Future<DarkImage> crateApiDarkImageLoadDarkBookImage({
+    required String path,
+    required String href,
+    required int maxWidth,
+    required int maxHeight,
+    required DarkImageOptions options,
+    int? cancelToken,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_String(href, serializer);
+          sse_encode_u_32(maxWidth, serializer);
+          sse_encode_u_32(maxHeight, serializer);
+          sse_encode_box_autoadd_dark_image_options(options, serializer);
+          sse_encode_opt_box_autoadd_u_32(cancelToken, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 171,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_dark_image,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDarkImageLoadDarkBookImageConstMeta,
+        argValues: [path, href, maxWidth, maxHeight, options, cancelToken],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDarkImageLoadDarkBookImageConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_dark_book_image",
+        argNames: [
+          "path",
+          "href",
+          "maxWidth",
+          "maxHeight",
+          "options",
+          "cancelToken",
+        ],
+      );
+
+  @override
+  Future<StyledChapter> crateApiEpubLoadEpubChapterStyles({
+    required String path,
+    required String href,
+    required EpubLayout layout,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_String(href, serializer);
+          sse_encode_epub_layout(layout, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 172,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_styled_chapter,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiEpubLoadEpubChapterStylesConstMeta,
+        argValues: [path, href, layout],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiEpubLoadEpubChapterStylesConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_epub_chapter_styles",
+        argNames: ["path", "href", "layout"],
+      );
+
+  @override
+  Future<int> crateApiHyphenationLoadHyphenationPatterns({
+    required String language,
+    required String patterns,
+    String? exceptions,
+    int? leftMin,
+    int? rightMin,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(language, serializer);
+          sse_encode_String(patterns, serializer);
+          sse_encode_opt_String(exceptions, serializer);
+          sse_encode_opt_box_autoadd_u_32(leftMin, serializer);
+          sse_encode_opt_box_autoadd_u_32(rightMin, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 173,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiHyphenationLoadHyphenationPatternsConstMeta,
+        argValues: [language, patterns, exceptions, leftMin, rightMin],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHyphenationLoadHyphenationPatternsConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_hyphenation_patterns",
+        argNames: ["language", "patterns", "exceptions", "leftMin", "rightMin"],
+      );
+
+  @override
+  Future<TtsVoiceInfo> crateApiTtsLoadTtsVoice({
+    required String modelPath,
+    required String configPath,
+    String? lexiconPath,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(modelPath, serializer);
+          sse_encode_String(configPath, serializer);
+          sse_encode_opt_String(lexiconPath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 174,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_tts_voice_info,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiTtsLoadTtsVoiceConstMeta,
+        argValues: [modelPath, configPath, lexiconPath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTtsLoadTtsVoiceConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_tts_voice",
+        argNames: ["modelPath", "configPath", "lexiconPath"],
+      );
+
+  @override
+  Future<void> crateApiVaultLockTokenVault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 175,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiVaultLockTokenVaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiVaultLockTokenVaultConstMeta =>
+      const TaskConstMeta(debugName: "lock_token_vault", argNames: []);
+
+  @override
+  Future<List<String>> crateApiDictLookupPrefix({
+    required String prefix,
+    required int maxResults,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(prefix, serializer);
+          sse_encode_u_32(maxResults, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 176,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDictLookupPrefixConstMeta,
+        argValues: [prefix, maxResults],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDictLookupPrefixConstMeta =>
+      const TaskConstMeta(
+        debugName: "lookup_prefix",
+        argNames: ["prefix", "maxResults"],
+      );
+
+  @override
+  Future<List<DictDefinition>> crateApiDictLookupWord({
+    required String word,
+    required int maxResults,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(word, serializer);
+          sse_encode_u_32(maxResults, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 177,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_dict_definition,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDictLookupWordConstMeta,
+        argValues: [word, maxResults],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDictLookupWordConstMeta =>
+      const TaskConstMeta(
+        debugName: "lookup_word",
+        argNames: ["word", "maxResults"],
+      );
+
+  @override
+  Future<int> crateApiReadChaptersMarkChaptersRead({
+    required String bookId,
+    required int start,
+    required int end,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          sse_encode_u_32(start, serializer);
+          sse_encode_u_32(end, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 178,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiReadChaptersMarkChaptersReadConstMeta,
+        argValues: [bookId, start, end],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReadChaptersMarkChaptersReadConstMeta =>
+      const TaskConstMeta(
+        debugName: "mark_chapters_read",
+        argNames: ["bookId", "start", "end"],
+      );
+
+  @override
+  Future<int> crateApiReadChaptersMarkChaptersUnread({
+    required String bookId,
+    required int start,
+    required int end,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          sse_encode_u_32(start, serializer);
+          sse_encode_u_32(end, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 179,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiReadChaptersMarkChaptersUnreadConstMeta,
+        argValues: [bookId, start, end],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReadChaptersMarkChaptersUnreadConstMeta =>
+      const TaskConstMeta(
+        debugName: "mark_chapters_unread",
+        argNames: ["bookId", "start", "end"],
+      );
+
+  @override
+  Future<int> crateApiEpubToolsMergeEpubs({
+    required List<EpubMergeSource> sources,
+    required BookMetadata metadata,
+    required String outputPath,
+    int? cancelToken,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_epub_merge_source(sources, serializer);
+          sse_encode_box_autoadd_book_metadata(metadata, serializer);
+          sse_encode_String(outputPath, serializer);
+          sse_encode_opt_box_autoadd_u_32(cancelToken, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 180,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiEpubToolsMergeEpubsConstMeta,
+        argValues: [sources, metadata, outputPath, cancelToken],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiEpubToolsMergeEpubsConstMeta =>
+      const TaskConstMeta(
+        debugName: "merge_epubs",
+        argNames: ["sources", "metadata", "outputPath", "cancelToken"],
+      );
+
+  @override
+  Future<ChapterCacheMigration> crateApiChapterCacheMigrateChapterCache({
+    Uint8List? previousKey,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_list_prim_u_8_strict(previousKey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 181,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_chapter_cache_migration,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiChapterCacheMigrateChapterCacheConstMeta,
+        argValues: [previousKey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChapterCacheMigrateChapterCacheConstMeta =>
+      const TaskConstMeta(
+        debugName: "migrate_chapter_cache",
+        argNames: ["previousKey"],
+      );
+
+  @override
+  Future<List<String>> crateApiAudioCacheMissingCachedAudio({
+    required String voice,
+    required List<Utterance> utterances,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(voice, serializer);
+          sse_encode_list_utterance(utterances, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 182,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiAudioCacheMissingCachedAudioConstMeta,
+        argValues: [voice, utterances],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAudioCacheMissingCachedAudioConstMeta =>
+      const TaskConstMeta(
+        debugName: "missing_cached_audio",
+        argNames: ["voice", "utterances"],
+      );
+
+  @override
+  Future<String> crateApiTypographyNormalizeTypography({
+    required String text,
+    required TypographyOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          sse_encode_box_autoadd_typography_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 183,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTypographyNormalizeTypographyConstMeta,
+        argValues: [text, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTypographyNormalizeTypographyConstMeta =>
+      const TaskConstMeta(
+        debugName: "normalize_typography",
+        argNames: ["text", "options"],
+      );
+
+  @override
+  Future<String> crateApiOpdsOpdsSearchUrl({
+    required String template,
+    required String query,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(template, serializer);
+          sse_encode_String(query, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 184,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiOpdsOpdsSearchUrlConstMeta,
+        argValues: [template, query],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiOpdsOpdsSearchUrlConstMeta =>
+      const TaskConstMeta(
+        debugName: "opds_search_url",
+        argNames: ["template", "query"],
+      );
+
+  @override
+  Future<DictInfo> crateApiDictOpenDictionary({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 185,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_dict_info,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDictOpenDictionaryConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDictOpenDictionaryConstMeta =>
+      const TaskConstMeta(debugName: "open_dictionary", argNames: ["path"]);
+
+  @override
+  Future<void> crateApiDbOpenLibraryDb({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 186,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDbOpenLibraryDbConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDbOpenLibraryDbConstMeta =>
+      const TaskConstMeta(debugName: "open_library_db", argNames: ["path"]);
+
+  @override
+  Future<MappedTxtInfo> crateApiMappedTxtOpenMappedTxt({
+    required String path,
+    List<TxtChapterRule>? rules,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_list_txt_chapter_rule(rules, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 187,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_mapped_txt_info,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiMappedTxtOpenMappedTxtConstMeta,
+        argValues: [path, rules],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMappedTxtOpenMappedTxtConstMeta =>
+      const TaskConstMeta(
+        debugName: "open_mapped_txt",
+        argNames: ["path", "rules"],
+      );
+
+  @override
+  Future<String> crateApiVaultOpenSecret({
+    required String name,
+    required String sealed,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          sse_encode_String(sealed, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 188,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiVaultOpenSecretConstMeta,
+        argValues: [name, sealed],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiVaultOpenSecretConstMeta =>
+      const TaskConstMeta(
+        debugName: "open_secret",
+        argNames: ["name", "sealed"],
+      );
+
+  @override
+  Future<List<TextPage>> crateApiPaginationPaginateText({
+    required String text,
+    required String fontPath,
+    required PageLayout layout,
+    KinsokuRules? kinsoku,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          sse_encode_String(fontPath, serializer);
+          sse_encode_box_autoadd_page_layout(layout, serializer);
+          sse_encode_opt_box_autoadd_kinsoku_rules(kinsoku, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 189,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_text_page,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiPaginationPaginateTextConstMeta,
+        argValues: [text, fontPath, layout, kinsoku],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPaginationPaginateTextConstMeta =>
+      const TaskConstMeta(
+        debugName: "paginate_text",
+        argNames: ["text", "fontPath", "layout", "kinsoku"],
+      );
+
+  @override
+  Future<List<VerticalPage>> crateApiVerticalPaginateVerticalText({
+    required String text,
+    required String fontPath,
+    required PageLayout layout,
+    KinsokuRules? kinsoku,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          sse_encode_String(fontPath, serializer);
+          sse_encode_box_autoadd_page_layout(layout, serializer);
+          sse_encode_opt_box_autoadd_kinsoku_rules(kinsoku, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 190,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_vertical_page,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiVerticalPaginateVerticalTextConstMeta,
+        argValues: [text, fontPath, layout, kinsoku],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiVerticalPaginateVerticalTextConstMeta =>
+      const TaskConstMeta(
+        debugName: "paginate_vertical_text",
+        argNames: ["text", "fontPath", "layout", "kinsoku"],
+      );
+
+  @override
+  Future<List<BookSource>> crateApiBookSourceParseBookSources({
+    required String json,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(json, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 191,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_book_source,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiBookSourceParseBookSourcesConstMeta,
+        argValues: [json],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBookSourceParseBookSourcesConstMeta =>
+      const TaskConstMeta(debugName: "parse_book_sources", argNames: ["json"]);
+
+  @override
+  Future<ParsedBook> crateApiEpubParseEpub({
+    required String path,
+    int? cancelToken,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_box_autoadd_u_32(cancelToken, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 192,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_parsed_book,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiEpubParseEpubConstMeta,
+        argValues: [path, cancelToken],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiEpubParseEpubConstMeta =>
+      const TaskConstMeta(
+        debugName: "parse_epub",
+        argNames: ["path", "cancelToken"],
+      );
+
+  @override
+  Future<ParsedBook> crateApiFb2ParseFb2({
+    required String path,
+    int? cancelToken,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_box_autoadd_u_32(cancelToken, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 193,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_parsed_book,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFb2ParseFb2ConstMeta,
+        argValues: [path, cancelToken],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFb2ParseFb2ConstMeta =>
+      const TaskConstMeta(
+        debugName: "parse_fb2",
+        argNames: ["path", "cancelToken"],
+      );
+
+  @override
+  Future<Feed> crateApiFeedParseFeed({
+    required List<int> data,
+    required String url,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 194,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_feed,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFeedParseFeedConstMeta,
+        argValues: [data, url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFeedParseFeedConstMeta =>
+      const TaskConstMeta(debugName: "parse_feed", argNames: ["data", "url"]);
+
+  @override
+  Future<ParsedBook> crateApiMobiParseMobi({
+    required String path,
+    int? cancelToken,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_box_autoadd_u_32(cancelToken, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 195,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_parsed_book,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiMobiParseMobiConstMeta,
+        argValues: [path, cancelToken],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMobiParseMobiConstMeta =>
+      const TaskConstMeta(
+        debugName: "parse_mobi",
+        argNames: ["path", "cancelToken"],
+      );
+
+  @override
+  Future<ParsedBook> crateApiPdfParsePdf({
+    required String path,
+    int? cancelToken,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_box_autoadd_u_32(cancelToken, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 196,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_parsed_book,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiPdfParsePdfConstMeta,
+        argValues: [path, cancelToken],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPdfParsePdfConstMeta =>
+      const TaskConstMeta(
+        debugName: "parse_pdf",
+        argNames: ["path", "cancelToken"],
+      );
+
+  @override
+  Future<ParsedBook> crateApiTxtParseTxt({
+    required String path,
+    List<TxtChapterRule>? rules,
+    int? cancelToken,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_list_txt_chapter_rule(rules, serializer);
+          sse_encode_opt_box_autoadd_u_32(cancelToken, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 197,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_parsed_book,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiTxtParseTxtConstMeta,
+        argValues: [path, rules, cancelToken],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTxtParseTxtConstMeta =>
+      const TaskConstMeta(
+        debugName: "parse_txt",
+        argNames: ["path", "rules", "cancelToken"],
+      );
+
+  @override
+  Future<ParsedBook> crateApiUmdParseUmd({
+    required String path,
+    int? cancelToken,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_box_autoadd_u_32(cancelToken, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 198,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_parsed_book,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiUmdParseUmdConstMeta,
+        argValues: [path, cancelToken],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUmdParseUmdConstMeta =>
+      const TaskConstMeta(
+        debugName: "parse_umd",
+        argNames: ["path", "cancelToken"],
+      );
+
+  @override
+  Future<Uint8List> crateApiTtsPcmToWav({
+    required List<int> pcm,
+    required int sampleRate,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(pcm, serializer);
+          sse_encode_u_32(sampleRate, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 199,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTtsPcmToWavConstMeta,
+        argValues: [pcm, sampleRate],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTtsPcmToWavConstMeta =>
+      const TaskConstMeta(
+        debugName: "pcm_to_wav",
+        argNames: ["pcm", "sampleRate"],
+      );
+
+  @override
+  Future<bool> crateApiFontCachePinFontCacheEntry({
+    required String id,
+    required bool pinned,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          sse_encode_bool(pinned, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 200,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontCachePinFontCacheEntryConstMeta,
+        argValues: [id, pinned],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontCachePinFontCacheEntryConstMeta =>
+      const TaskConstMeta(
+        debugName: "pin_font_cache_entry",
+        argNames: ["id", "pinned"],
+      );
+
+  @override
+  Future<TtsPosition?> crateApiTtsTimelinePositionForTime({
+    required BigInt timeMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_64(timeMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 201,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_tts_position,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTtsTimelinePositionForTimeConstMeta,
+        argValues: [timeMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTtsTimelinePositionForTimeConstMeta =>
+      const TaskConstMeta(debugName: "position_for_time", argNames: ["timeMs"]);
+
+  @override
+  Future<PrefetchStatus> crateApiPrefetchPrefetchStatus() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 202,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_prefetch_status,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPrefetchPrefetchStatusConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPrefetchPrefetchStatusConstMeta =>
+      const TaskConstMeta(debugName: "prefetch_status", argNames: []);
+
+  @override
+  Future<List<Utterance>> crateApiTtsPrepPrepareTts({required String text}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 203,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_utterance,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTtsPrepPrepareTtsConstMeta,
+        argValues: [text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTtsPrepPrepareTtsConstMeta =>
+      const TaskConstMeta(debugName: "prepare_tts", argNames: ["text"]);
+
+  @override
+  Future<List<Utterance>> crateApiTtsPrepPrepareTtsSsml({
+    required String text,
+    required SsmlOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          sse_encode_box_autoadd_ssml_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 204,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_utterance,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTtsPrepPrepareTtsSsmlConstMeta,
+        argValues: [text, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTtsPrepPrepareTtsSsmlConstMeta =>
+      const TaskConstMeta(
+        debugName: "prepare_tts_ssml",
+        argNames: ["text", "options"],
+      );
+
+  @override
+  Future<int> crateApiAudioCachePresynthesizeAudio({
+    required String voice,
+    required List<Utterance> utterances,
+    required TtsOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(voice, serializer);
+          sse_encode_list_utterance(utterances, serializer);
+          sse_encode_box_autoadd_tts_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 205,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiAudioCachePresynthesizeAudioConstMeta,
+        argValues: [voice, utterances, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAudioCachePresynthesizeAudioConstMeta =>
+      const TaskConstMeta(
+        debugName: "presynthesize_audio",
+        argNames: ["voice", "utterances", "options"],
+      );
+
+  @override
+  Future<PurifyReport> crateApiPurifyPreviewPurify({
+    required String text,
+    required List<PurifyRule> rules,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          sse_encode_list_purify_rule(rules, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 206,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_purify_report,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiPurifyPreviewPurifyConstMeta,
+        argValues: [text, rules],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPurifyPreviewPurifyConstMeta =>
+      const TaskConstMeta(
+        debugName: "preview_purify",
+        argNames: ["text", "rules"],
+      );
+
+  @override
+  Stream<ImportEvent> crateApiImportProcessBooks({
+    required List<String> paths,
+    required ImportOptions options,
+    int? cancelToken,
+  }) {
+    final sink = RustStreamSink<ImportEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_list_String(paths, serializer);
+            sse_encode_box_autoadd_import_options(options, serializer);
+            sse_encode_StreamSink_import_event_Sse(sink, serializer);
+            sse_encode_opt_box_autoadd_u_32(cancelToken, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 207,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_api_error,
+          ),
+          constMeta: kCrateApiImportProcessBooksConstMeta,
+          argValues: [paths, options, sink, cancelToken],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiImportProcessBooksConstMeta =>
+      const TaskConstMeta(
+        debugName: "process_books",
+        argNames: ["paths", "options", "sink", "cancelToken"],
+      );
+
+  @override
+  Future<ProcessedImage> crateApiImageProcessCover({
+    required List<int> imageBytes,
+    required CoverImageOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(imageBytes, serializer);
+          sse_encode_box_autoadd_cover_image_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 208,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_processed_image,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiImageProcessCoverConstMeta,
+        argValues: [imageBytes, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiImageProcessCoverConstMeta =>
+      const TaskConstMeta(
+        debugName: "process_cover",
+        argNames: ["imageBytes", "options"],
+      );
+
+  @override
+  Future<String> crateApiPurifyPurifyText({
+    required String text,
+    required List<PurifyRule> rules,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          sse_encode_list_purify_rule(rules, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 209,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiPurifyPurifyTextConstMeta,
+        argValues: [text, rules],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPurifyPurifyTextConstMeta =>
+      const TaskConstMeta(
+        debugName: "purify_text",
+        argNames: ["text", "rules"],
+      );
+
+  @override
+  Future<String> crateApiBlobsPut({
+    required String owner,
+    required List<int> data,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(owner, serializer);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 210,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiBlobsPutConstMeta,
+        argValues: [owner, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBlobsPutConstMeta =>
+      const TaskConstMeta(debugName: "put", argNames: ["owner", "data"]);
+
+  @override
+  Future<void> crateApiAudioCachePutCachedAudio({
+    required String voice,
+    required String text,
+    required AudioFormat format,
+    required List<int> data,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(voice, serializer);
+          sse_encode_String(text, serializer);
+          sse_encode_audio_format(format, serializer);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 211,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiAudioCachePutCachedAudioConstMeta,
+        argValues: [voice, text, format, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAudioCachePutCachedAudioConstMeta =>
+      const TaskConstMeta(
+        debugName: "put_cached_audio",
+        argNames: ["voice", "text", "format", "data"],
+      );
+
+  @override
+  Future<void> crateApiChapterStorePutChapter({
+    required String bookId,
+    required int chapterIndex,
+    required String text,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          sse_encode_u_32(chapterIndex, serializer);
+          sse_encode_String(text, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 212,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiChapterStorePutChapterConstMeta,
+        argValues: [bookId, chapterIndex, text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChapterStorePutChapterConstMeta =>
+      const TaskConstMeta(
+        debugName: "put_chapter",
+        argNames: ["bookId", "chapterIndex", "text"],
+      );
+
+  @override
+  Future<List<Bookmark>> crateApiDbQueryBookmarks({
+    String? bookId,
+    required int offset,
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(bookId, serializer);
+          sse_encode_u_32(offset, serializer);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 213,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_bookmark,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDbQueryBookmarksConstMeta,
+        argValues: [bookId, offset, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDbQueryBookmarksConstMeta =>
+      const TaskConstMeta(
+        debugName: "query_bookmarks",
+        argNames: ["bookId", "offset", "limit"],
+      );
+
+  @override
+  Future<BookPage> crateApiDbQueryBooks({required BookQuery query}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_book_query(query, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 214,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_book_page,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDbQueryBooksConstMeta,
+        argValues: [query],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDbQueryBooksConstMeta =>
+      const TaskConstMeta(debugName: "query_books", argNames: ["query"]);
+
+  @override
+  Future<List<Highlight>> crateApiDbQueryHighlights({
+    String? bookId,
+    required int offset,
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(bookId, serializer);
+          sse_encode_u_32(offset, serializer);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 215,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_highlight,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDbQueryHighlightsConstMeta,
+        argValues: [bookId, offset, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDbQueryHighlightsConstMeta =>
+      const TaskConstMeta(
+        debugName: "query_highlights",
+        argNames: ["bookId", "offset", "limit"],
+      );
+
+  @override
+  Future<List<LibraryChapter>> crateApiDbQueryLibraryChapters({
+    required String bookId,
+    required int offset,
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          sse_encode_u_32(offset, serializer);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 216,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_library_chapter,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDbQueryLibraryChaptersConstMeta,
+        argValues: [bookId, offset, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDbQueryLibraryChaptersConstMeta =>
+      const TaskConstMeta(
+        debugName: "query_library_chapters",
+        argNames: ["bookId", "offset", "limit"],
+      );
+
+  @override
+  Stream<RateLimitStatus> crateApiRateLimitRateLimitEvents() {
+    final sink = RustStreamSink<RateLimitStatus>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_rate_limit_status_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 217,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_api_error,
+          ),
+          constMeta: kCrateApiRateLimitRateLimitEventsConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiRateLimitRateLimitEventsConstMeta =>
+      const TaskConstMeta(debugName: "rate_limit_events", argNames: ["sink"]);
+
+  @override
+  Future<List<RateLimitStatus>> crateApiRateLimitRateLimitStatus() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 218,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_rate_limit_status,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiRateLimitRateLimitStatusConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRateLimitRateLimitStatusConstMeta =>
+      const TaskConstMeta(debugName: "rate_limit_status", argNames: []);
+
+  @override
+  Future<BackupInfo> crateApiBackupReadBackupInfo({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 219,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_backup_info,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiBackupReadBackupInfoConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBackupReadBackupInfoConstMeta =>
+      const TaskConstMeta(debugName: "read_backup_info", argNames: ["path"]);
+
+  @override
+  Future<String?> crateApiChapterCacheReadCachedChapter({
+    required String bookId,
+    required int chapterIndex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          sse_encode_u_32(chapterIndex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 220,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiChapterCacheReadCachedChapterConstMeta,
+        argValues: [bookId, chapterIndex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChapterCacheReadCachedChapterConstMeta =>
+      const TaskConstMeta(
+        debugName: "read_cached_chapter",
+        argNames: ["bookId", "chapterIndex"],
+      );
+
+  @override
+  Future<ChapterFingerprint?> crateApiChapterCacheReadCachedFingerprint({
+    required String bookId,
+    required int chapterIndex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          sse_encode_u_32(chapterIndex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 221,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_chapter_fingerprint,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiChapterCacheReadCachedFingerprintConstMeta,
+        argValues: [bookId, chapterIndex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChapterCacheReadCachedFingerprintConstMeta =>
+      const TaskConstMeta(
+        debugName: "read_cached_fingerprint",
+        argNames: ["bookId", "chapterIndex"],
+      );
+
+  @override
+  Future<String?> crateApiChapterCacheReadCachedSummary({
+    required String bookId,
+    required int chapterIndex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          sse_encode_u_32(chapterIndex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 222,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiChapterCacheReadCachedSummaryConstMeta,
+        argValues: [bookId, chapterIndex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChapterCacheReadCachedSummaryConstMeta =>
+      const TaskConstMeta(
+        debugName: "read_cached_summary",
+        argNames: ["bookId", "chapterIndex"],
+      );
+
+  @override
+  Future<CalibreMetadata> crateApiCalibreReadCalibreOpf({
+    required String path,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 223,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_calibre_metadata,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiCalibreReadCalibreOpfConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCalibreReadCalibreOpfConstMeta =>
+      const TaskConstMeta(debugName: "read_calibre_opf", argNames: ["path"]);
+
+  @override
+  Future<List<ChapterRange>> crateApiReadChaptersReadChapterRanges({
+    required String bookId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 224,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_chapter_range,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiReadChaptersReadChapterRangesConstMeta,
+        argValues: [bookId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReadChaptersReadChapterRangesConstMeta =>
+      const TaskConstMeta(
+        debugName: "read_chapter_ranges",
+        argNames: ["bookId"],
+      );
+
+  @override
+  Future<Uint8List> crateApiComicReadComicPage({
+    required String path,
+    required int index,
+    int? maxWidth,
+    int? maxHeight,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_u_32(index, serializer);
+          sse_encode_opt_box_autoadd_u_32(maxWidth, serializer);
+          sse_encode_opt_box_autoadd_u_32(maxHeight, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 225,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiComicReadComicPageConstMeta,
+        argValues: [path, index, maxWidth, maxHeight],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiComicReadComicPageConstMeta =>
+      const TaskConstMeta(
+        debugName: "read_comic_page",
+        argNames: ["path", "index", "maxWidth", "maxHeight"],
+      );
+
+  @override
+  Future<Uint8List?> crateApiFontBundleReadFontBundle({
+    required String bookId,
+    required String fontPath,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          sse_encode_String(fontPath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 226,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontBundleReadFontBundleConstMeta,
+        argValues: [bookId, fontPath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontBundleReadFontBundleConstMeta =>
+      const TaskConstMeta(
+        debugName: "read_font_bundle",
+        argNames: ["bookId", "fontPath"],
+      );
+
+  @override
+  Future<String> crateApiMappedTxtReadMappedChapter({
+    required int id,
+    required int chapterIndex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(id, serializer);
+          sse_encode_u_32(chapterIndex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 227,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiMappedTxtReadMappedChapterConstMeta,
+        argValues: [id, chapterIndex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMappedTxtReadMappedChapterConstMeta =>
+      const TaskConstMeta(
+        debugName: "read_mapped_chapter",
+        argNames: ["id", "chapterIndex"],
+      );
+
+  @override
+  Future<MappedTextSlice> crateApiMappedTxtReadMappedText({
+    required int id,
+    required int start,
+    required int maxBytes,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(id, serializer);
+          sse_encode_u_32(start, serializer);
+          sse_encode_u_32(maxBytes, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 228,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_mapped_text_slice,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiMappedTxtReadMappedTextConstMeta,
+        argValues: [id, start, maxBytes],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMappedTxtReadMappedTextConstMeta =>
+      const TaskConstMeta(
+        debugName: "read_mapped_text",
+        argNames: ["id", "start", "maxBytes"],
+      );
+
+  @override
+  Future<String> crateApiTxtReadTxtChapter({
+    required String path,
+    required TxtChapter chapter,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_box_autoadd_txt_chapter(chapter, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 229,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiTxtReadTxtChapterConstMeta,
+        argValues: [path, chapter],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTxtReadTxtChapterConstMeta =>
+      const TaskConstMeta(
+        debugName: "read_txt_chapter",
+        argNames: ["path", "chapter"],
+      );
+
+  @override
+  Future<ReadingStats> crateApiSessionsReadingStats({
+    required PlatformInt64 from,
+    required PlatformInt64 to,
+    required int utcOffsetMinutes,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(from, serializer);
+          sse_encode_i_64(to, serializer);
+          sse_encode_i_32(utcOffsetMinutes, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 230,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_reading_stats,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSessionsReadingStatsConstMeta,
+        argValues: [from, to, utcOffsetMinutes],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionsReadingStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "reading_stats",
+        argNames: ["from", "to", "utcOffsetMinutes"],
+      );
+
+  @override
+  Future<List<LogRecord>> crateApiLoggingRecentLogs({
+    required LogLevel minLevel,
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_log_level(minLevel, serializer);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 231,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_log_record,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLoggingRecentLogsConstMeta,
+        argValues: [minLevel, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoggingRecentLogsConstMeta =>
+      const TaskConstMeta(
+        debugName: "recent_logs",
+        argNames: ["minLevel", "limit"],
+      );
+
+  @override
+  Future<void> crateApiTtsTimelineRecordTtsDuration({
+    required String utteranceId,
+    required BigInt durationMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(utteranceId, serializer);
+          sse_encode_u_64(durationMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 232,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTtsTimelineRecordTtsDurationConstMeta,
+        argValues: [utteranceId, durationMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTtsTimelineRecordTtsDurationConstMeta =>
+      const TaskConstMeta(
+        debugName: "record_tts_duration",
+        argNames: ["utteranceId", "durationMs"],
+      );
+
+  @override
+  Future<int> crateApiBlobsRelease({required String prefix}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(prefix, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 233,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiBlobsReleaseConstMeta,
+        argValues: [prefix],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBlobsReleaseConstMeta =>
+      const TaskConstMeta(debugName: "release", argNames: ["prefix"]);
+
+  @override
+  Future<void> crateApiTaskReleaseCancelToken({required int token}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(token, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 234,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTaskReleaseCancelTokenConstMeta,
+        argValues: [token],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTaskReleaseCancelTokenConstMeta =>
+      const TaskConstMeta(
+        debugName: "release_cancel_token",
+        argNames: ["token"],
+      );
+
+  @override
+  Future<void> crateApiSearchRemoveBookFromIndex({required String bookId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 235,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSearchRemoveBookFromIndexConstMeta,
+        argValues: [bookId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSearchRemoveBookFromIndexConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_book_from_index",
+        argNames: ["bookId"],
+      );
+
+  @override
+  Future<int> crateApiChapterCacheRemoveCachedBook({required String bookId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 236,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiChapterCacheRemoveCachedBookConstMeta,
+        argValues: [bookId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChapterCacheRemoveCachedBookConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_cached_book",
+        argNames: ["bookId"],
+      );
+
+  @override
+  Future<int> crateApiFontBundleRemoveFontBundles({required String bookId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 237,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontBundleRemoveFontBundlesConstMeta,
+        argValues: [bookId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontBundleRemoveFontBundlesConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_font_bundles",
+        argNames: ["bookId"],
+      );
+
+  @override
+  Future<bool> crateApiHttpCacheRemoveHttpCacheEntry({required String url}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 238,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiHttpCacheRemoveHttpCacheEntryConstMeta,
+        argValues: [url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHttpCacheRemoveHttpCacheEntryConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_http_cache_entry",
+        argNames: ["url"],
+      );
+
+  @override
+  Future<FontPreview> crateApiFontPreviewRender({
+    required List<int> fontBytes,
+    required String sampleText,
+    required double pxSize,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(fontBytes, serializer);
+          sse_encode_String(sampleText, serializer);
+          sse_encode_f_32(pxSize, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 239,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_font_preview,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontPreviewRenderConstMeta,
+        argValues: [fontBytes, sampleText, pxSize],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontPreviewRenderConstMeta =>
+      const TaskConstMeta(
+        debugName: "render",
+        argNames: ["fontBytes", "sampleText", "pxSize"],
+      );
+
+  @override
+  Future<String> crateApiNotesExportRenderBookNotes({
+    required String bookId,
+    required NotesExportOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          sse_encode_box_autoadd_notes_export_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 240,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiNotesExportRenderBookNotesConstMeta,
+        argValues: [bookId, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNotesExportRenderBookNotesConstMeta =>
+      const TaskConstMeta(
+        debugName: "render_book_notes",
+        argNames: ["bookId", "options"],
+      );
+
+  @override
+  Future<PunctuationRepair> crateApiPunctuationRepairPunctuation({
+    required String text,
+    required RepairLevel level,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          sse_encode_repair_level(level, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 241,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_punctuation_repair,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPunctuationRepairPunctuationConstMeta,
+        argValues: [text, level],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPunctuationRepairPunctuationConstMeta =>
+      const TaskConstMeta(
+        debugName: "repair_punctuation",
+        argNames: ["text", "level"],
+      );
+
+  @override
+  Future<Uint8List> crateApiFontValidationRepairTtf({
+    required List<int> ttfData,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(ttfData, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 242,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontValidationRepairTtfConstMeta,
+        argValues: [ttfData],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontValidationRepairTtfConstMeta =>
+      const TaskConstMeta(debugName: "repair_ttf", argNames: ["ttfData"]);
+
+  @override
+  Future<AnchorResolution?> crateApiAnchorsResolveAnchor({
+    required String text,
+    required TextAnchor anchor,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          sse_encode_box_autoadd_text_anchor(anchor, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 243,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_anchor_resolution,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAnchorsResolveAnchorConstMeta,
+        argValues: [text, anchor],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAnchorsResolveAnchorConstMeta =>
+      const TaskConstMeta(
+        debugName: "resolve_anchor",
+        argNames: ["text", "anchor"],
+      );
+
+  @override
+  Future<List<AnchorResolution?>> crateApiAnchorsResolveAnchors({
+    required String text,
+    required List<TextAnchor> anchors,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          sse_encode_list_text_anchor(anchors, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 244,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_opt_box_autoadd_anchor_resolution,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAnchorsResolveAnchorsConstMeta,
+        argValues: [text, anchors],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAnchorsResolveAnchorsConstMeta =>
+      const TaskConstMeta(
+        debugName: "resolve_anchors",
+        argNames: ["text", "anchors"],
+      );
+
+  @override
+  Future<ResplitOutcome> crateApiResplitResplitStoredBook({
+    required String bookId,
+    List<TxtChapterRule>? rules,
+    required bool dryRun,
+    int? cancelToken,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          sse_encode_opt_list_txt_chapter_rule(rules, serializer);
+          sse_encode_bool(dryRun, serializer);
+          sse_encode_opt_box_autoadd_u_32(cancelToken, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 245,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_resplit_outcome,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiResplitResplitStoredBookConstMeta,
+        argValues: [bookId, rules, dryRun, cancelToken],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiResplitResplitStoredBookConstMeta =>
+      const TaskConstMeta(
+        debugName: "resplit_stored_book",
+        argNames: ["bookId", "rules", "dryRun", "cancelToken"],
+      );
+
+  @override
+  Future<RestoreResult> crateApiBackupRestoreBackup({
+    required String path,
+    required List<PurifyRule> purifyRules,
+    required String bookSourcesJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_list_purify_rule(purifyRules, serializer);
+          sse_encode_String(bookSourcesJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 246,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_restore_result,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiBackupRestoreBackupConstMeta,
+        argValues: [path, purifyRules, bookSourcesJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBackupRestoreBackupConstMeta =>
+      const TaskConstMeta(
+        debugName: "restore_backup",
+        argNames: ["path", "purifyRules", "bookSourcesJson"],
+      );
+
+  @override
+  Future<BenchReport> crateApiBenchRun() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 247,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bench_report,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiBenchRunConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBenchRunConstMeta =>
+      const TaskConstMeta(debugName: "run", argNames: []);
+
+  @override
+  Future<String> crateApiSanitizeSanitizeText({required String text}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 248,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSanitizeSanitizeTextConstMeta,
+        argValues: [text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSanitizeSanitizeTextConstMeta =>
+      const TaskConstMeta(debugName: "sanitize_text", argNames: ["text"]);
+
+  @override
+  Future<List<CalibreBook>> crateApiCalibreScanCalibreLibrary({
+    required String libraryDir,
+    int? cancelToken,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(libraryDir, serializer);
+          sse_encode_opt_box_autoadd_u_32(cancelToken, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 249,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_calibre_book,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiCalibreScanCalibreLibraryConstMeta,
+        argValues: [libraryDir, cancelToken],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCalibreScanCalibreLibraryConstMeta =>
+      const TaskConstMeta(
+        debugName: "scan_calibre_library",
+        argNames: ["libraryDir", "cancelToken"],
+      );
+
+  @override
+  Future<WatermarkReport> crateApiWatermarkScanWatermarks({
+    required String text,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 250,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_watermark_report,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWatermarkScanWatermarksConstMeta,
+        argValues: [text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWatermarkScanWatermarksConstMeta =>
+      const TaskConstMeta(debugName: "scan_watermarks", argNames: ["text"]);
+
+  @override
+  Future<ScrapedMetadata> crateApiMetadataScrapeMetadata({
+    required String path,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 251,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_scraped_metadata,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiMetadataScrapeMetadataConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMetadataScrapeMetadataConstMeta =>
+      const TaskConstMeta(debugName: "scrape_metadata", argNames: ["path"]);
+
+  @override
+  Future<ScrapedMetadata> crateApiMetadataScrapeMetadataFrom({
+    required String fileName,
+    String? text,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(fileName, serializer);
+          sse_encode_opt_String(text, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 252,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_scraped_metadata,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMetadataScrapeMetadataFromConstMeta,
+        argValues: [fileName, text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMetadataScrapeMetadataFromConstMeta =>
+      const TaskConstMeta(
+        debugName: "scrape_metadata_from",
+        argNames: ["fileName", "text"],
+      );
+
+  @override
+  Future<String> crateApiVaultSealSecret({
+    required String name,
+    required String secret,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          sse_encode_String(secret, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 253,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiVaultSealSecretConstMeta,
+        argValues: [name, secret],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiVaultSealSecretConstMeta =>
+      const TaskConstMeta(
+        debugName: "seal_secret",
+        argNames: ["name", "secret"],
+      );
+
+  @override
+  Future<List<SearchHit>> crateApiSearchSearchBook({
+    required String bookId,
+    required String query,
+    required int maxResults,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          sse_encode_String(query, serializer);
+          sse_encode_u_32(maxResults, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 254,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_hit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSearchSearchBookConstMeta,
+        argValues: [bookId, query, maxResults],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSearchSearchBookConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_book",
+        argNames: ["bookId", "query", "maxResults"],
+      );
+
+  @override
+  Future<List<SourceBook>> crateApiBookSourceSearchBookSource({
+    required BookSource source,
+    required String key,
+    required int page,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_book_source(source, serializer);
+          sse_encode_String(key, serializer);
+          sse_encode_u_32(page, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 255,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_source_book,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiBookSourceSearchBookSourceConstMeta,
+        argValues: [source, key, page],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBookSourceSearchBookSourceConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_book_source",
+        argNames: ["source", "key", "page"],
+      );
+
+  @override
+  Future<List<SearchHit>> crateApiSearchSearchLibrary({
+    required String query,
+    required int maxResults,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_u_32(maxResults, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 256,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_hit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSearchSearchLibraryConstMeta,
+        argValues: [query, maxResults],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSearchSearchLibraryConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_library",
+        argNames: ["query", "maxResults"],
+      );
+
+  @override
+  Future<List<LibrarySearchResult>> crateApiLibrarySearchSearchLibraryBooks({
+    required String query,
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 257,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_library_search_result,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiLibrarySearchSearchLibraryBooksConstMeta,
+        argValues: [query, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLibrarySearchSearchLibraryBooksConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_library_books",
+        argNames: ["query", "limit"],
+      );
+
+  @override
+  Future<bool> crateApiVaultSecretsEqual({
+    required String a,
+    required String b,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(a, serializer);
+          sse_encode_String(b, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 258,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiVaultSecretsEqualConstMeta,
+        argValues: [a, b],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiVaultSecretsEqualConstMeta =>
+      const TaskConstMeta(debugName: "secrets_equal", argNames: ["a", "b"]);
+
+  @override
+  Future<List<TextSegment>> crateApiSegmentSegment({required String text}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 259,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_text_segment,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSegmentSegmentConstMeta,
+        argValues: [text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSegmentSegmentConstMeta =>
+      const TaskConstMeta(debugName: "segment", argNames: ["text"]);
+
+  @override
+  Future<void> crateApiDownloaderSetBandwidthPolicy({
+    required BandwidthPolicy policy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_bandwidth_policy(policy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 260,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDownloaderSetBandwidthPolicyConstMeta,
+        argValues: [policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloaderSetBandwidthPolicyConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_bandwidth_policy",
+        argNames: ["policy"],
+      );
+
+  @override
+  Future<void> crateApiNetworkSetDnsOverHttps({String? endpoint}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(endpoint, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 261,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiNetworkSetDnsOverHttpsConstMeta,
+        argValues: [endpoint],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNetworkSetDnsOverHttpsConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_dns_over_https",
+        argNames: ["endpoint"],
+      );
+
+  @override
+  Future<void> crateApiNetworkSetHostProxy({
+    required String host,
+    ProxyConfig? proxy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(host, serializer);
+          sse_encode_opt_box_autoadd_proxy_config(proxy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 262,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiNetworkSetHostProxyConstMeta,
+        argValues: [host, proxy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNetworkSetHostProxyConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_host_proxy",
+        argNames: ["host", "proxy"],
+      );
+
+  @override
+  Future<void> crateApiRateLimitSetHostRateLimit({
+    required String host,
+    RateLimit? limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(host, serializer);
+          sse_encode_opt_box_autoadd_rate_limit(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 263,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiRateLimitSetHostRateLimitConstMeta,
+        argValues: [host, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRateLimitSetHostRateLimitConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_host_rate_limit",
+        argNames: ["host", "limit"],
+      );
+
+  @override
+  Future<void> crateApiDbSetLibraryChapters({
+    required String bookId,
+    required List<LibraryChapter> chapters,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          sse_encode_list_library_chapter(chapters, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 264,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDbSetLibraryChaptersConstMeta,
+        argValues: [bookId, chapters],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDbSetLibraryChaptersConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_library_chapters",
+        argNames: ["bookId", "chapters"],
+      );
+
+  @override
+  Future<void> crateApiDownloaderSetNetworkType({
+    required NetworkType network,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_network_type(network, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 265,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDownloaderSetNetworkTypeConstMeta,
+        argValues: [network],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDownloaderSetNetworkTypeConstMeta =>
+      const TaskConstMeta(debugName: "set_network_type", argNames: ["network"]);
+
+  @override
+  Future<void> crateApiNetworkSetProxy({ProxyConfig? proxy}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_proxy_config(proxy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 266,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiNetworkSetProxyConstMeta,
+        argValues: [proxy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNetworkSetProxyConstMeta =>
+      const TaskConstMeta(debugName: "set_proxy", argNames: ["proxy"]);
+
+  @override
+  Future<void> crateApiRateLimitSetRateLimit({RateLimit? limit}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_rate_limit(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 267,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiRateLimitSetRateLimitConstMeta,
+        argValues: [limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRateLimitSetRateLimitConstMeta =>
+      const TaskConstMeta(debugName: "set_rate_limit", argNames: ["limit"]);
+
+  @override
+  Future<void> crateApiReadChaptersSetReadChapters({
+    required String bookId,
+    required List<int> chapters,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          sse_encode_list_prim_u_32_loose(chapters, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 268,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiReadChaptersSetReadChaptersConstMeta,
+        argValues: [bookId, chapters],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReadChaptersSetReadChaptersConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_read_chapters",
+        argNames: ["bookId", "chapters"],
+      );
+
+  @override
+  Future<void> crateApiSummarySetSummaryOptions({
+    required SummaryOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_summary_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 269,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSummarySetSummaryOptionsConstMeta,
+        argValues: [options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSummarySetSummaryOptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_summary_options",
+        argNames: ["options"],
+      );
+
+  @override
+  Future<List<LibraryChapter>> crateApiChapterSortSortLibraryChapters({
+    required List<LibraryChapter> chapters,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_library_chapter(chapters, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 270,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_library_chapter,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChapterSortSortLibraryChaptersConstMeta,
+        argValues: [chapters],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChapterSortSortLibraryChaptersConstMeta =>
+      const TaskConstMeta(
+        debugName: "sort_library_chapters",
+        argNames: ["chapters"],
+      );
+
+  @override
+  Future<List<EpubVolume>> crateApiEpubToolsSplitEpub({
+    required String path,
+    required EpubSplitOptions options,
+    required String outputDir,
+    int? cancelToken,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_box_autoadd_epub_split_options(options, serializer);
+          sse_encode_String(outputDir, serializer);
+          sse_encode_opt_box_autoadd_u_32(cancelToken, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 271,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_epub_volume,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiEpubToolsSplitEpubConstMeta,
+        argValues: [path, options, outputDir, cancelToken],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiEpubToolsSplitEpubConstMeta =>
+      const TaskConstMeta(
+        debugName: "split_epub",
+        argNames: ["path", "options", "outputDir", "cancelToken"],
+      );
+
+  @override
+  Future<PlatformInt64> crateApiSessionsStartReadingSession({
+    required String bookId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 272,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSessionsStartReadingSessionConstMeta,
+        argValues: [bookId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionsStartReadingSessionConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_reading_session",
+        argNames: ["bookId"],
+      );
+
+  @override
+  Future<void> crateApiTtsTimelineStartTtsTimeline({
+    required List<Utterance> utterances,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_utterance(utterances, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 273,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTtsTimelineStartTtsTimelineConstMeta,
+        argValues: [utterances],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTtsTimelineStartTtsTimelineConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_tts_timeline",
+        argNames: ["utterances"],
+      );
+
+  @override
+  Future<WebServerInfo> crateApiWebserverStartWebServer({
+    required WebServerConfig config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_web_server_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 274,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_web_server_info,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiWebserverStartWebServerConstMeta,
+        argValues: [config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebserverStartWebServerConstMeta =>
+      const TaskConstMeta(debugName: "start_web_server", argNames: ["config"]);
+
+  @override
+  Future<void> crateApiSessionsStopReadingSession({
+    required PlatformInt64 sessionId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(sessionId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 275,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSessionsStopReadingSessionConstMeta,
+        argValues: [sessionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionsStopReadingSessionConstMeta =>
+      const TaskConstMeta(
+        debugName: "stop_reading_session",
+        argNames: ["sessionId"],
+      );
+
+  @override
+  Future<bool> crateApiWebserverStopWebServer() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 276,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWebserverStopWebServerConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebserverStopWebServerConstMeta =>
+      const TaskConstMeta(debugName: "stop_web_server", argNames: []);
+
+  @override
+  Future<String> crateApiWatermarkStripWatermarks({required String text}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 277,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWatermarkStripWatermarksConstMeta,
+        argValues: [text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWatermarkStripWatermarksConstMeta =>
+      const TaskConstMeta(debugName: "strip_watermarks", argNames: ["text"]);
+
+  @override
+  Future<Uint8List> crateApiFontSubsetSubsetFont({
+    required List<int> ttfData,
+    required String text,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(ttfData, serializer);
+          sse_encode_String(text, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 278,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontSubsetSubsetFontConstMeta,
+        argValues: [ttfData, text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontSubsetSubsetFontConstMeta =>
+      const TaskConstMeta(
+        debugName: "subset_font",
+        argNames: ["ttfData", "text"],
+      );
+
+  @override
+  Future<String> crateApiSummarySummarizeChapter({
+    required String text,
+    required SummaryOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          sse_encode_box_autoadd_summary_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 279,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSummarySummarizeChapterConstMeta,
+        argValues: [text, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSummarySummarizeChapterConstMeta =>
+      const TaskConstMeta(
+        debugName: "summarize_chapter",
+        argNames: ["text", "options"],
+      );
+
+  @override
+  Future<SyncResult> crateApiSyncSyncHttp({
+    required String baseUrl,
+    String? bearerToken,
+    required String passphrase,
+    SyncState? state,
+    int? cancelToken,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(baseUrl, serializer);
+          sse_encode_opt_String(bearerToken, serializer);
+          sse_encode_String(passphrase, serializer);
+          sse_encode_opt_box_autoadd_sync_state(state, serializer);
+          sse_encode_opt_box_autoadd_u_32(cancelToken, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 280,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_result,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSyncSyncHttpConstMeta,
+        argValues: [baseUrl, bearerToken, passphrase, state, cancelToken],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSyncSyncHttpConstMeta =>
+      const TaskConstMeta(
+        debugName: "sync_http",
+        argNames: [
+          "baseUrl",
+          "bearerToken",
+          "passphrase",
+          "state",
+          "cancelToken",
+        ],
+      );
+
+  @override
+  Future<SyncResult> crateApiSyncSyncWebdav({
+    required WebDavConfig config,
+    required String remoteDir,
+    required String passphrase,
+    SyncState? state,
+    int? cancelToken,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_web_dav_config(config, serializer);
+          sse_encode_String(remoteDir, serializer);
+          sse_encode_String(passphrase, serializer);
+          sse_encode_opt_box_autoadd_sync_state(state, serializer);
+          sse_encode_opt_box_autoadd_u_32(cancelToken, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 281,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_result,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiSyncSyncWebdavConstMeta,
+        argValues: [config, remoteDir, passphrase, state, cancelToken],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSyncSyncWebdavConstMeta =>
+      const TaskConstMeta(
+        debugName: "sync_webdav",
+        argNames: ["config", "remoteDir", "passphrase", "state", "cancelToken"],
+      );
+
+  @override
+  Future<Uint8List> crateApiTtsSynthesizeSpeech({
+    required String text,
+    required TtsOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          sse_encode_box_autoadd_tts_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 282,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiTtsSynthesizeSpeechConstMeta,
+        argValues: [text, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTtsSynthesizeSpeechConstMeta =>
+      const TaskConstMeta(
+        debugName: "synthesize_speech",
+        argNames: ["text", "options"],
+      );
+
+  @override
+  Stream<TtsAudioChunk> crateApiTtsSynthesizeSpeechStream({
+    required List<Utterance> utterances,
+    required TtsOptions options,
+  }) {
+    final sink = RustStreamSink<TtsAudioChunk>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_list_utterance(utterances, serializer);
+            sse_encode_box_autoadd_tts_options(options, serializer);
+            sse_encode_StreamSink_tts_audio_chunk_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 283,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_api_error,
+          ),
+          constMeta: kCrateApiTtsSynthesizeSpeechStreamConstMeta,
+          argValues: [utterances, options, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiTtsSynthesizeSpeechStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "synthesize_speech_stream",
+        argNames: ["utterances", "options", "sink"],
+      );
+
+  @override
+  Future<ApiError?> crateApiErrorTakeLastPanic() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 284,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_api_error,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiErrorTakeLastPanicConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiErrorTakeLastPanicConstMeta =>
+      const TaskConstMeta(debugName: "take_last_panic", argNames: []);
+
+  @override
+  Future<RuleTest> crateApiRuleTestTestChapterRule({
+    required TxtChapterRule rule,
+    required String sampleText,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_txt_chapter_rule(rule, serializer);
+          sse_encode_String(sampleText, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 285,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_rule_test,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiRuleTestTestChapterRuleConstMeta,
+        argValues: [rule, sampleText],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRuleTestTestChapterRuleConstMeta =>
+      const TaskConstMeta(
+        debugName: "test_chapter_rule",
+        argNames: ["rule", "sampleText"],
+      );
+
+  @override
+  Future<RuleTest> crateApiRuleTestTestRule({
+    required PurifyRule rule,
+    required String sampleText,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_purify_rule(rule, serializer);
+          sse_encode_String(sampleText, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 286,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_rule_test,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiRuleTestTestRuleConstMeta,
+        argValues: [rule, sampleText],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRuleTestTestRuleConstMeta =>
+      const TaskConstMeta(
+        debugName: "test_rule",
+        argNames: ["rule", "sampleText"],
+      );
+
+  @override
+  Future<Uint8List> crateApiPlaceholderThumbhashToPng({
+    required String thumbhash,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(thumbhash, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 287,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiPlaceholderThumbhashToPngConstMeta,
+        argValues: [thumbhash],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPlaceholderThumbhashToPngConstMeta =>
+      const TaskConstMeta(
+        debugName: "thumbhash_to_png",
+        argNames: ["thumbhash"],
+      );
+
+  @override
+  Future<TierSettings> crateApiBenchTierSettings({
+    required PerformanceTier tier,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_performance_tier(tier, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 288,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_tier_settings,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBenchTierSettingsConstMeta,
+        argValues: [tier],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBenchTierSettingsConstMeta =>
+      const TaskConstMeta(debugName: "tier_settings", argNames: ["tier"]);
+
+  @override
+  Future<BigInt?> crateApiTtsTimelineTimeForPosition({required int offset}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(offset, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 289,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTtsTimelineTimeForPositionConstMeta,
+        argValues: [offset],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTtsTimelineTimeForPositionConstMeta =>
+      const TaskConstMeta(debugName: "time_for_position", argNames: ["offset"]);
+
+  @override
+  Future<DarkImage> crateApiDarkImageToneImageForDarkTheme({
+    required BookImage image,
+    required DarkImageOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_book_image(image, serializer);
+          sse_encode_box_autoadd_dark_image_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 290,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_dark_image,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDarkImageToneImageForDarkThemeConstMeta,
+        argValues: [image, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDarkImageToneImageForDarkThemeConstMeta =>
+      const TaskConstMeta(
+        debugName: "tone_image_for_dark_theme",
+        argNames: ["image", "options"],
+      );
+
+  @override
+  Future<List<TranslatedParagraph>> crateApiTranslateTranslateChapter({
+    required String text,
+    required TranslatorConfig config,
+    int? cancelToken,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          sse_encode_box_autoadd_translator_config(config, serializer);
+          sse_encode_opt_box_autoadd_u_32(cancelToken, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 291,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_translated_paragraph,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiTranslateTranslateChapterConstMeta,
+        argValues: [text, config, cancelToken],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTranslateTranslateChapterConstMeta =>
+      const TaskConstMeta(
+        debugName: "translate_chapter",
+        argNames: ["text", "config", "cancelToken"],
+      );
+
+  @override
+  Future<List<String>> crateApiTranslateTranslateParagraphs({
+    required List<String> paragraphs,
+    required TranslatorConfig config,
+    int? cancelToken,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(paragraphs, serializer);
+          sse_encode_box_autoadd_translator_config(config, serializer);
+          sse_encode_opt_box_autoadd_u_32(cancelToken, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 292,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiTranslateTranslateParagraphsConstMeta,
+        argValues: [paragraphs, config, cancelToken],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTranslateTranslateParagraphsConstMeta =>
+      const TaskConstMeta(
+        debugName: "translate_paragraphs",
+        argNames: ["paragraphs", "config", "cancelToken"],
+      );
+
+  @override
+  Future<int> crateApiFontCacheUnlinkFontCacheBook({required String bookId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 293,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontCacheUnlinkFontCacheBookConstMeta,
+        argValues: [bookId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontCacheUnlinkFontCacheBookConstMeta =>
+      const TaskConstMeta(
+        debugName: "unlink_font_cache_book",
+        argNames: ["bookId"],
+      );
+
+  @override
+  Future<void> crateApiTtsUnloadTtsVoice() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 294,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTtsUnloadTtsVoiceConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTtsUnloadTtsVoiceConstMeta =>
+      const TaskConstMeta(debugName: "unload_tts_voice", argNames: []);
+
+  @override
+  Future<int> crateApiReadChaptersUnreadChapterCount({
+    required String bookId,
+    required int chapterCount,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          sse_encode_u_32(chapterCount, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 295,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiReadChaptersUnreadChapterCountConstMeta,
+        argValues: [bookId, chapterCount],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReadChaptersUnreadChapterCountConstMeta =>
+      const TaskConstMeta(
+        debugName: "unread_chapter_count",
+        argNames: ["bookId", "chapterCount"],
+      );
+
+  @override
+  Future<PrefetchStatus> crateApiPrefetchUpdatePrefetch({
+    required PrefetchPlan plan,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_prefetch_plan(plan, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 296,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_prefetch_status,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiPrefetchUpdatePrefetchConstMeta,
+        argValues: [plan],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPrefetchUpdatePrefetchConstMeta =>
+      const TaskConstMeta(debugName: "update_prefetch", argNames: ["plan"]);
+
+  @override
+  Future<Int64List> crateApiDbUpsertBookmarks({
+    required List<Bookmark> bookmarks,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_bookmark(bookmarks, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 297,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_i_64_strict,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDbUpsertBookmarksConstMeta,
+        argValues: [bookmarks],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDbUpsertBookmarksConstMeta =>
+      const TaskConstMeta(
+        debugName: "upsert_bookmarks",
+        argNames: ["bookmarks"],
+      );
+
+  @override
+  Future<int> crateApiDbUpsertBooks({required List<LibraryBook> books}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_library_book(books, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 298,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDbUpsertBooksConstMeta,
+        argValues: [books],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDbUpsertBooksConstMeta =>
+      const TaskConstMeta(debugName: "upsert_books", argNames: ["books"]);
+
+  @override
+  Future<Int64List> crateApiDbUpsertHighlights({
+    required List<Highlight> highlights,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_highlight(highlights, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 299,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_i_64_strict,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDbUpsertHighlightsConstMeta,
+        argValues: [highlights],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDbUpsertHighlightsConstMeta =>
+      const TaskConstMeta(
+        debugName: "upsert_highlights",
+        argNames: ["highlights"],
+      );
+
+  @override
+  Future<void> crateApiDbUpsertProgress({
+    required List<ReadingProgress> progress,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_reading_progress(progress, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 300,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiDbUpsertProgressConstMeta,
+        argValues: [progress],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDbUpsertProgressConstMeta =>
+      const TaskConstMeta(debugName: "upsert_progress", argNames: ["progress"]);
+
+  @override
+  Future<ValidationReport> crateApiFontValidationValidateTtf({
+    required List<int> ttfData,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(ttfData, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 301,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_validation_report,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiFontValidationValidateTtfConstMeta,
+        argValues: [ttfData],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFontValidationValidateTtfConstMeta =>
+      const TaskConstMeta(debugName: "validate_ttf", argNames: ["ttfData"]);
+
+  @override
+  Future<List<StoredBookScan>> crateApiChapterStoreVerifyChapterStore({
+    required List<String> bookIds,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(bookIds, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 302,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_stored_book_scan,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiChapterStoreVerifyChapterStoreConstMeta,
+        argValues: [bookIds],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChapterStoreVerifyChapterStoreConstMeta =>
+      const TaskConstMeta(
+        debugName: "verify_chapter_store",
+        argNames: ["bookIds"],
+      );
+
+  @override
+  Future<CloudConfig> crateApiCloudctlVerifyCloudConfig({
+    required String envelope,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(envelope, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 303,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_cloud_config,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiCloudctlVerifyCloudConfigConstMeta,
+        argValues: [envelope],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCloudctlVerifyCloudConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "verify_cloud_config",
+        argNames: ["envelope"],
+      );
+
+  @override
+  Stream<WebUpload> crateApiWebserverWebServerEvents() {
+    final sink = RustStreamSink<WebUpload>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_web_upload_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 304,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_api_error,
+          ),
+          constMeta: kCrateApiWebserverWebServerEventsConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiWebserverWebServerEventsConstMeta =>
+      const TaskConstMeta(debugName: "web_server_events", argNames: ["sink"]);
+
+  @override
+  Future<void> crateApiWebdavWebdavDelete({
+    required WebDavConfig config,
+    required String remotePath,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_web_dav_config(config, serializer);
+          sse_encode_String(remotePath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 305,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiWebdavWebdavDeleteConstMeta,
+        argValues: [config, remotePath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebdavWebdavDeleteConstMeta =>
+      const TaskConstMeta(
+        debugName: "webdav_delete",
+        argNames: ["config", "remotePath"],
+      );
+
+  @override
+  Future<WebDavSyncRecord> crateApiWebdavWebdavDownload({
+    required WebDavConfig config,
+    required String remotePath,
+    required String localPath,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_web_dav_config(config, serializer);
+          sse_encode_String(remotePath, serializer);
+          sse_encode_String(localPath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 306,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_web_dav_sync_record,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiWebdavWebdavDownloadConstMeta,
+        argValues: [config, remotePath, localPath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebdavWebdavDownloadConstMeta =>
+      const TaskConstMeta(
+        debugName: "webdav_download",
+        argNames: ["config", "remotePath", "localPath"],
+      );
+
+  @override
+  Future<List<WebDavEntry>> crateApiWebdavWebdavList({
+    required WebDavConfig config,
+    required String path,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_web_dav_config(config, serializer);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 307,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_web_dav_entry,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiWebdavWebdavListConstMeta,
+        argValues: [config, path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebdavWebdavListConstMeta =>
+      const TaskConstMeta(
+        debugName: "webdav_list",
+        argNames: ["config", "path"],
+      );
+
+  @override
+  Future<WebDavSyncStatus> crateApiWebdavWebdavSyncStatus({
+    required WebDavConfig config,
+    required String localPath,
+    required String remotePath,
+    WebDavSyncRecord? lastSync,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_web_dav_config(config, serializer);
+          sse_encode_String(localPath, serializer);
+          sse_encode_String(remotePath, serializer);
+          sse_encode_opt_box_autoadd_web_dav_sync_record(lastSync, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 308,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_web_dav_sync_status,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiWebdavWebdavSyncStatusConstMeta,
+        argValues: [config, localPath, remotePath, lastSync],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebdavWebdavSyncStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "webdav_sync_status",
+        argNames: ["config", "localPath", "remotePath", "lastSync"],
+      );
+
+  @override
+  Future<void> crateApiWebdavWebdavTestConnection({
+    required WebDavConfig config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_web_dav_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 309,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiWebdavWebdavTestConnectionConstMeta,
+        argValues: [config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebdavWebdavTestConnectionConstMeta =>
+      const TaskConstMeta(
+        debugName: "webdav_test_connection",
+        argNames: ["config"],
+      );
+
+  @override
+  Future<WebDavSyncRecord> crateApiWebdavWebdavUpload({
+    required WebDavConfig config,
+    required String localPath,
+    required String remotePath,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_web_dav_config(config, serializer);
+          sse_encode_String(localPath, serializer);
+          sse_encode_String(remotePath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 310,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_web_dav_sync_record,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiWebdavWebdavUploadConstMeta,
+        argValues: [config, localPath, remotePath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebdavWebdavUploadConstMeta =>
+      const TaskConstMeta(
+        debugName: "webdav_upload",
+        argNames: ["config", "localPath", "remotePath"],
+      );
+
+  @override
+  Future<TextSegment?> crateApiSegmentWordAt({
+    required String text,
+    required int offset,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          sse_encode_u_32(offset, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 311,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_text_segment,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSegmentWordAtConstMeta,
+        argValues: [text, offset],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSegmentWordAtConstMeta =>
+      const TaskConstMeta(debugName: "word_at", argNames: ["text", "offset"]);
+
+  @override
+  Future<void> crateApiChapterCacheWriteCachedChapter({
+    required String bookId,
+    required int chapterIndex,
+    required String text,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bookId, serializer);
+          sse_encode_u_32(chapterIndex, serializer);
+          sse_encode_String(text, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 312,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_api_error,
+        ),
+        constMeta: kCrateApiChapterCacheWriteCachedChapterConstMeta,
+        argValues: [bookId, chapterIndex, text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChapterCacheWriteCachedChapterConstMeta =>
+      const TaskConstMeta(
+        debugName: "write_cached_chapter",
+        argNames: ["bookId", "chapterIndex", "text"],
+      );
+
+  @override
+  Future<void> crateApiLoggingWriteLog({
+    required LogLevel level,
+    required String target,
+    required String message,
+    required List<LogField> fields,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_log_level(level, serializer);
+          sse_encode_String(target, serializer);
+          sse_encode_String(message, serializer);
+          sse_encode_list_log_field(fields, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 313,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLoggingWriteLogConstMeta,
+        argValues: [level, target, message, fields],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoggingWriteLogConstMeta =>
+      const TaskConstMeta(
+        debugName: "write_log",
+        argNames: ["level", "target", "message", "fields"],
+      );
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  RustStreamSink<ArchiveProgress> dco_decode_StreamSink_archive_progress_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<DownloadEvent> dco_decode_StreamSink_download_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<EpubExportProgress> dco_decode_StreamSink_epub_export_progress_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<ImportEvent> dco_decode_StreamSink_import_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<RateLimitStatus> dco_decode_StreamSink_rate_limit_status_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<TtfChunk> dco_decode_StreamSink_ttf_chunk_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<TtsAudioChunk> dco_decode_StreamSink_tts_audio_chunk_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<WebImportProgress> dco_decode_StreamSink_web_import_progress_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<WebUpload> dco_decode_StreamSink_web_upload_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AlternativeChapter dco_decode_alternative_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return AlternativeChapter(
+      source: dco_decode_book_source(arr[0]),
+      book: dco_decode_source_book(arr[1]),
+      chapter: dco_decode_source_chapter(arr[2]),
+      content: dco_decode_String(arr[3]),
+      score: dco_decode_f_32(arr[4]),
+    );
+  }
+
+  @protected
+  AlternativeSource dco_decode_alternative_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AlternativeSource(
+      source: dco_decode_book_source(arr[0]),
+      book: dco_decode_source_book(arr[1]),
+      score: dco_decode_f_32(arr[2]),
+    );
+  }
+
+  @protected
+  AnchorResolution dco_decode_anchor_resolution(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AnchorResolution(
+      anchor: dco_decode_text_anchor(arr[0]),
+      exact: dco_decode_bool(arr[1]),
+      similarity: dco_decode_f_32(arr[2]),
+    );
+  }
+
+  @protected
+  ApiError dco_decode_api_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ApiError(
+      code: dco_decode_error_code(arr[0]),
+      message: dco_decode_String(arr[1]),
+      context: dco_decode_list_String(arr[2]),
+      location: dco_decode_opt_String(arr[3]),
+      backtrace: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  ArchiveEntry dco_decode_archive_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ArchiveEntry(
+      index: dco_decode_u_32(arr[0]),
+      name: dco_decode_String(arr[1]),
+      size: dco_decode_u_32(arr[2]),
+      kind: dco_decode_opt_box_autoadd_import_kind(arr[3]),
+      encrypted: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  ArchiveFormat dco_decode_archive_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ArchiveFormat.values[raw as int];
+  }
+
+  @protected
+  ArchiveInfo dco_decode_archive_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ArchiveInfo(
+      format: dco_decode_archive_format(arr[0]),
+      entries: dco_decode_list_archive_entry(arr[1]),
+    );
+  }
+
+  @protected
+  ArchiveProgress dco_decode_archive_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ArchiveProgress(
+      done: dco_decode_u_32(arr[0]),
+      total: dco_decode_u_32(arr[1]),
+      file: dco_decode_extracted_file(arr[2]),
+    );
+  }
+
+  @protected
+  AudioCacheStats dco_decode_audio_cache_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AudioCacheStats(
+      entryCount: dco_decode_u_32(arr[0]),
+      totalBytes: dco_decode_u_64(arr[1]),
+      maxBytes: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  AudioFormat dco_decode_audio_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AudioFormat.values[raw as int];
+  }
+
+  @protected
+  AudioSegment dco_decode_audio_segment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AudioSegment(
+      utteranceId: dco_decode_String(arr[0]),
+      startMs: dco_decode_u_64(arr[1]),
+      durationMs: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  AxisValue dco_decode_axis_value(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AxisValue(
+      tag: dco_decode_String(arr[0]),
+      value: dco_decode_f_32(arr[1]),
+    );
+  }
+
+  @protected
+  BackupInfo dco_decode_backup_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return BackupInfo(
+      version: dco_decode_u_32(arr[0]),
+      createdAt: dco_decode_i_64(arr[1]),
+      bookCount: dco_decode_u_32(arr[2]),
+      bookmarkCount: dco_decode_u_32(arr[3]),
+      highlightCount: dco_decode_u_32(arr[4]),
+      purifyRuleCount: dco_decode_u_32(arr[5]),
+      bookSourceCount: dco_decode_u_32(arr[6]),
+    );
+  }
+
+  @protected
+  BandwidthPolicy dco_decode_bandwidth_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return BandwidthPolicy(
+      maxKbps: dco_decode_u_32(arr[0]),
+      meteredMaxKbps: dco_decode_u_32(arr[1]),
+      pauseBackgroundWhenMetered: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  BenchReport dco_decode_bench_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return BenchReport(
+      inflateMbPerSec: dco_decode_f_64(arr[0]),
+      segmentCharsPerSec: dco_decode_f_64(arr[1]),
+      layoutCharsPerSec: dco_decode_f_64(arr[2]),
+      score: dco_decode_f_64(arr[3]),
+      tier: dco_decode_performance_tier(arr[4]),
+      settings: dco_decode_tier_settings(arr[5]),
+      elapsedMs: dco_decode_u_32(arr[6]),
+    );
+  }
+
+  @protected
+  BionicOptions dco_decode_bionic_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return BionicOptions(
+      latinRatio: dco_decode_f_32(arr[0]),
+      cjk: dco_decode_bool(arr[1]),
+    );
+  }
+
+  @protected
+  BlobGcReport dco_decode_blob_gc_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return BlobGcReport(
+      removed: dco_decode_u_32(arr[0]),
+      freedBytes: dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  BlobStoreStats dco_decode_blob_store_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return BlobStoreStats(
+      blobCount: dco_decode_u_32(arr[0]),
+      totalBytes: dco_decode_u_64(arr[1]),
+      referenceCount: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  BookAlias dco_decode_book_alias(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return BookAlias(
+      alias: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  BookChapter dco_decode_book_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return BookChapter(
+      index: dco_decode_u_32(arr[0]),
+      href: dco_decode_String(arr[1]),
+      title: dco_decode_opt_String(arr[2]),
+      html: dco_decode_String(arr[3]),
+      images: dco_decode_list_chapter_image(arr[4]),
+    );
+  }
+
+  @protected
+  BookFormat dco_decode_book_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BookFormat.values[raw as int];
+  }
+
+  @protected
+  BookImage dco_decode_book_image(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return BookImage(
+      rgba: dco_decode_list_prim_u_8_strict(arr[0]),
+      width: dco_decode_u_32(arr[1]),
+      height: dco_decode_u_32(arr[2]),
+      sourceWidth: dco_decode_u_32(arr[3]),
+      sourceHeight: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  BookInfoRules dco_decode_book_info_rules(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return BookInfoRules(
+      init: dco_decode_opt_String(arr[0]),
+      name: dco_decode_opt_String(arr[1]),
+      author: dco_decode_opt_String(arr[2]),
+      intro: dco_decode_opt_String(arr[3]),
+      kind: dco_decode_opt_String(arr[4]),
+      lastChapter: dco_decode_opt_String(arr[5]),
+      coverUrl: dco_decode_opt_String(arr[6]),
+      tocUrl: dco_decode_opt_String(arr[7]),
+      wordCount: dco_decode_opt_String(arr[8]),
+    );
+  }
+
+  @protected
+  BookKeywords dco_decode_book_keywords(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return BookKeywords(
+      keywords: dco_decode_list_keyword(arr[0]),
+      tags: dco_decode_list_tag_suggestion(arr[1]),
+    );
+  }
+
+  @protected
+  BookMetadata dco_decode_book_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return BookMetadata(
+      title: dco_decode_opt_String(arr[0]),
+      authors: dco_decode_list_String(arr[1]),
+      language: dco_decode_opt_String(arr[2]),
+      identifier: dco_decode_opt_String(arr[3]),
+      publisher: dco_decode_opt_String(arr[4]),
+      description: dco_decode_opt_String(arr[5]),
+      coverHref: dco_decode_opt_String(arr[6]),
+    );
+  }
+
+  @protected
+  BookPage dco_decode_book_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return BookPage(
+      books: dco_decode_list_library_book(arr[0]),
+      total: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  BookQuery dco_decode_book_query(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return BookQuery(
+      search: dco_decode_opt_String(arr[0]),
+      sort: dco_decode_book_sort(arr[1]),
+      offset: dco_decode_u_32(arr[2]),
+      limit: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  BookReadingTime dco_decode_book_reading_time(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return BookReadingTime(
+      bookId: dco_decode_String(arr[0]),
+      seconds: dco_decode_u_64(arr[1]),
+      sessions: dco_decode_u_32(arr[2]),
+      lastReadAt: dco_decode_i_64(arr[3]),
+    );
+  }
+
+  @protected
+  BookSort dco_decode_book_sort(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BookSort.values[raw as int];
+  }
+
+  @protected
+  BookSource dco_decode_book_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return BookSource(
+      url: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      group: dco_decode_opt_String(arr[2]),
+      enabled: dco_decode_bool(arr[3]),
+      headers: dco_decode_list_download_header(arr[4]),
+      searchUrl: dco_decode_opt_String(arr[5]),
+      search: dco_decode_search_rules(arr[6]),
+      bookInfo: dco_decode_book_info_rules(arr[7]),
+      toc: dco_decode_toc_rules(arr[8]),
+      content: dco_decode_content_rules(arr[9]),
+    );
+  }
+
+  @protected
+  BookStats dco_decode_book_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return BookStats(
+      total: dco_decode_text_stats(arr[0]),
+      chapters: dco_decode_list_text_stats(arr[1]),
+    );
+  }
+
+  @protected
+  BookUpdateSummary dco_decode_book_update_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return BookUpdateSummary(
+      bookId: dco_decode_String(arr[0]),
+      status: dco_decode_update_check_status(arr[1]),
+      newChapters: dco_decode_u_32(arr[2]),
+      removedChapters: dco_decode_u_32(arr[3]),
+      chapterCount: dco_decode_u_32(arr[4]),
+      latestChapterTitle: dco_decode_opt_String(arr[5]),
+      error: dco_decode_opt_String(arr[6]),
+    );
+  }
+
+  @protected
+  Bookmark dco_decode_bookmark(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return Bookmark(
+      id: dco_decode_opt_box_autoadd_i_64(arr[0]),
+      bookId: dco_decode_String(arr[1]),
+      chapterIndex: dco_decode_u_32(arr[2]),
+      chapterOffset: dco_decode_u_32(arr[3]),
+      excerpt: dco_decode_String(arr[4]),
+      note: dco_decode_opt_String(arr[5]),
+      createdAt: dco_decode_i_64(arr[6]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  AnchorResolution dco_decode_box_autoadd_anchor_resolution(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_anchor_resolution(raw);
+  }
+
+  @protected
+  ApiError dco_decode_box_autoadd_api_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_api_error(raw);
+  }
+
+  @protected
+  BandwidthPolicy dco_decode_box_autoadd_bandwidth_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_bandwidth_policy(raw);
+  }
+
+  @protected
+  BionicOptions dco_decode_box_autoadd_bionic_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_bionic_options(raw);
+  }
+
+  @protected
+  BookImage dco_decode_box_autoadd_book_image(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_book_image(raw);
+  }
+
+  @protected
+  BookMetadata dco_decode_box_autoadd_book_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_book_metadata(raw);
+  }
+
+  @protected
+  BookQuery dco_decode_box_autoadd_book_query(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_book_query(raw);
+  }
+
+  @protected
+  BookSource dco_decode_box_autoadd_book_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_book_source(raw);
+  }
+
+  @protected
+  CachedAudio dco_decode_box_autoadd_cached_audio(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cached_audio(raw);
+  }
+
+  @protected
+  CalibreMetadata dco_decode_box_autoadd_calibre_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_calibre_metadata(raw);
+  }
+
+  @protected
+  ChapterFingerprint dco_decode_box_autoadd_chapter_fingerprint(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_chapter_fingerprint(raw);
+  }
+
+  @protected
+  CoverImageOptions dco_decode_box_autoadd_cover_image_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cover_image_options(raw);
+  }
+
+  @protected
+  CoverStyle dco_decode_box_autoadd_cover_style(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cover_style(raw);
+  }
+
+  @protected
+  DarkImageOptions dco_decode_box_autoadd_dark_image_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_dark_image_options(raw);
+  }
+
+  @protected
+  DedupOptions dco_decode_box_autoadd_dedup_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_dedup_options(raw);
+  }
+
+  @protected
+  DownloadChecksum dco_decode_box_autoadd_download_checksum(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_download_checksum(raw);
+  }
+
+  @protected
+  DownloadRequest dco_decode_box_autoadd_download_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_download_request(raw);
+  }
+
+  @protected
+  DownloaderConfig dco_decode_box_autoadd_downloader_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_downloader_config(raw);
+  }
+
+  @protected
+  DrmScheme dco_decode_box_autoadd_drm_scheme(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_drm_scheme(raw);
+  }
+
+  @protected
+  EpubSplitOptions dco_decode_box_autoadd_epub_split_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_epub_split_options(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  HttpCacheEntry dco_decode_box_autoadd_http_cache_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_http_cache_entry(raw);
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  ImportKind dco_decode_box_autoadd_import_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_import_kind(raw);
+  }
+
+  @protected
+  ImportOptions dco_decode_box_autoadd_import_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_import_options(raw);
+  }
+
+  @protected
+  ImportReport dco_decode_box_autoadd_import_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_import_report(raw);
+  }
+
+  @protected
+  KinsokuRules dco_decode_box_autoadd_kinsoku_rules(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_kinsoku_rules(raw);
+  }
+
+  @protected
+  LibraryBook dco_decode_box_autoadd_library_book(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_library_book(raw);
+  }
+
+  @protected
+  LoggingConfig dco_decode_box_autoadd_logging_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_logging_config(raw);
+  }
+
+  @protected
+  MetadataGuess dco_decode_box_autoadd_metadata_guess(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_metadata_guess(raw);
+  }
+
+  @protected
+  NotesExportOptions dco_decode_box_autoadd_notes_export_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_notes_export_options(raw);
+  }
+
+  @protected
+  OpdsCredentials dco_decode_box_autoadd_opds_credentials(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_opds_credentials(raw);
+  }
+
+  @protected
+  PageLayout dco_decode_box_autoadd_page_layout(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_page_layout(raw);
+  }
+
+  @protected
+  PrefetchConfig dco_decode_box_autoadd_prefetch_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_prefetch_config(raw);
+  }
+
+  @protected
+  PrefetchPause dco_decode_box_autoadd_prefetch_pause(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_prefetch_pause(raw);
+  }
+
+  @protected
+  PrefetchPlan dco_decode_box_autoadd_prefetch_plan(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_prefetch_plan(raw);
+  }
+
+  @protected
+  ProcessedBook dco_decode_box_autoadd_processed_book(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_processed_book(raw);
+  }
+
+  @protected
+  ProxyConfig dco_decode_box_autoadd_proxy_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_proxy_config(raw);
+  }
+
+  @protected
+  PurifyRule dco_decode_box_autoadd_purify_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_purify_rule(raw);
+  }
+
+  @protected
+  RateLimit dco_decode_box_autoadd_rate_limit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_rate_limit(raw);
+  }
+
+  @protected
+  ReadingProgress dco_decode_box_autoadd_reading_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_reading_progress(raw);
+  }
+
+  @protected
+  SsmlOptions dco_decode_box_autoadd_ssml_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_ssml_options(raw);
+  }
+
+  @protected
+  SummaryOptions dco_decode_box_autoadd_summary_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_summary_options(raw);
+  }
+
+  @protected
+  SyncState dco_decode_box_autoadd_sync_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_sync_state(raw);
+  }
+
+  @protected
+  TextAnchor dco_decode_box_autoadd_text_anchor(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_text_anchor(raw);
+  }
+
+  @protected
+  TextSegment dco_decode_box_autoadd_text_segment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_text_segment(raw);
+  }
+
+  @protected
+  TranslatorConfig dco_decode_box_autoadd_translator_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_translator_config(raw);
+  }
+
+  @protected
+  TtsOptions dco_decode_box_autoadd_tts_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_tts_options(raw);
+  }
+
+  @protected
+  TtsPosition dco_decode_box_autoadd_tts_position(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_tts_position(raw);
+  }
+
+  @protected
+  TxtChapter dco_decode_box_autoadd_txt_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_txt_chapter(raw);
+  }
+
+  @protected
+  TxtChapterRule dco_decode_box_autoadd_txt_chapter_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_txt_chapter_rule(raw);
+  }
+
+  @protected
+  TypographyOptions dco_decode_box_autoadd_typography_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_typography_options(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  WebDavConfig dco_decode_box_autoadd_web_dav_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_web_dav_config(raw);
+  }
+
+  @protected
+  WebDavSyncRecord dco_decode_box_autoadd_web_dav_sync_record(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_web_dav_sync_record(raw);
+  }
+
+  @protected
+  WebServerConfig dco_decode_box_autoadd_web_server_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_web_server_config(raw);
+  }
+
+  @protected
+  CachedAudio dco_decode_cached_audio(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CachedAudio(
+      format: dco_decode_audio_format(arr[0]),
+      data: dco_decode_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  CalibreBook dco_decode_calibre_book(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return CalibreBook(
+      dir: dco_decode_String(arr[0]),
+      metadata: dco_decode_calibre_metadata(arr[1]),
+      coverPath: dco_decode_opt_String(arr[2]),
+      formats: dco_decode_list_calibre_format(arr[3]),
+    );
+  }
+
+  @protected
+  CalibreFormat dco_decode_calibre_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CalibreFormat(
+      path: dco_decode_String(arr[0]),
+      kind: dco_decode_import_kind(arr[1]),
+    );
+  }
+
+  @protected
+  CalibreIdentifier dco_decode_calibre_identifier(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CalibreIdentifier(
+      scheme: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  CalibreMetadata dco_decode_calibre_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 13)
+      throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+    return CalibreMetadata(
+      title: dco_decode_opt_String(arr[0]),
+      titleSort: dco_decode_opt_String(arr[1]),
+      authors: dco_decode_list_String(arr[2]),
+      authorSort: dco_decode_opt_String(arr[3]),
+      series: dco_decode_opt_String(arr[4]),
+      seriesIndex: dco_decode_opt_box_autoadd_f_64(arr[5]),
+      tags: dco_decode_list_String(arr[6]),
+      publisher: dco_decode_opt_String(arr[7]),
+      description: dco_decode_opt_String(arr[8]),
+      language: dco_decode_opt_String(arr[9]),
+      published: dco_decode_opt_String(arr[10]),
+      rating: dco_decode_opt_box_autoadd_u_32(arr[11]),
+      identifiers: dco_decode_list_calibre_identifier(arr[12]),
+    );
+  }
+
+  @protected
+  ChapterAudio dco_decode_chapter_audio(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ChapterAudio(
+      format: dco_decode_audio_format(arr[0]),
+      durationMs: dco_decode_u_64(arr[1]),
+      segments: dco_decode_list_audio_segment(arr[2]),
+      missing: dco_decode_list_String(arr[3]),
+    );
+  }
+
+  @protected
+  ChapterCacheMigration dco_decode_chapter_cache_migration(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ChapterCacheMigration(
+      converted: dco_decode_u_32(arr[0]),
+      unchanged: dco_decode_u_32(arr[1]),
+      removed: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  ChapterChange dco_decode_chapter_change(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ChapterChange.values[raw as int];
+  }
+
+  @protected
+  ChapterDiff dco_decode_chapter_diff(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ChapterDiff(
+      similarity: dco_decode_f_32(arr[0]),
+      unchangedParagraphs: dco_decode_u_32(arr[1]),
+      hunks: dco_decode_list_diff_hunk(arr[2]),
+    );
+  }
+
+  @protected
+  ChapterFingerprint dco_decode_chapter_fingerprint(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ChapterFingerprint(
+      hash: dco_decode_String(arr[0]),
+      simhash: dco_decode_u_64(arr[1]),
+      chars: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  ChapterImage dco_decode_chapter_image(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ChapterImage(
+      href: dco_decode_String(arr[0]),
+      alt: dco_decode_opt_String(arr[1]),
+    );
+  }
+
+  @protected
+  ChapterRange dco_decode_chapter_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ChapterRange(
+      start: dco_decode_u_32(arr[0]),
+      end: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  ChapterStoreStats dco_decode_chapter_store_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ChapterStoreStats(
+      chapterCount: dco_decode_u_32(arr[0]),
+      textBytes: dco_decode_u_64(arr[1]),
+      storedBytes: dco_decode_u_64(arr[2]),
+      garbageBytes: dco_decode_u_64(arr[3]),
+      segmentCount: dco_decode_u_32(arr[4]),
+      hasDictionary: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  ChapterText dco_decode_chapter_text(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ChapterText(
+      text: dco_decode_String(arr[0]),
+      imageOffsets: dco_decode_list_prim_u_32_strict(arr[1]),
+    );
+  }
+
+  @protected
+  ChapterUpdate dco_decode_chapter_update(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ChapterUpdate(
+      chapterIndex: dco_decode_u_32(arr[0]),
+      change: dco_decode_chapter_change(arr[1]),
+      similarity: dco_decode_f_32(arr[2]),
+    );
+  }
+
+  @protected
+  ChapterUpdateReport dco_decode_chapter_update_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ChapterUpdateReport(
+      newChapters: dco_decode_list_prim_u_32_strict(arr[0]),
+      chapters: dco_decode_list_chapter_update(arr[1]),
+    );
+  }
+
+  @protected
+  ChecksumAlgorithm dco_decode_checksum_algorithm(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ChecksumAlgorithm.values[raw as int];
+  }
+
+  @protected
+  ChineseConversion dco_decode_chinese_conversion(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ChineseConversion.values[raw as int];
+  }
+
+  @protected
+  CloudConfig dco_decode_cloud_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return CloudConfig(
+      issuedAt: dco_decode_i_64(arr[0]),
+      expiresAt: dco_decode_opt_box_autoadd_i_64(arr[1]),
+      complianceMode: dco_decode_bool(arr[2]),
+      disabledFeatures: dco_decode_list_String(arr[3]),
+      minAppVersion: dco_decode_opt_String(arr[4]),
+      announcement: dco_decode_opt_String(arr[5]),
+      values: dco_decode_list_cloud_config_value(arr[6]),
+    );
+  }
+
+  @protected
+  CloudConfigValue dco_decode_cloud_config_value(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CloudConfigValue(
+      key: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  CollectionFace dco_decode_collection_face(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return CollectionFace(
+      index: dco_decode_u_32(arr[0]),
+      familyName: dco_decode_String(arr[1]),
+      styleName: dco_decode_String(arr[2]),
+      postscriptName: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  ComicPage dco_decode_comic_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ComicPage(
+      index: dco_decode_u_32(arr[0]),
+      name: dco_decode_String(arr[1]),
+      size: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  ContentRules dco_decode_content_rules(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ContentRules(
+      content: dco_decode_opt_String(arr[0]),
+      nextContentUrl: dco_decode_opt_String(arr[1]),
+      replaceRegex: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  ConversionResult dco_decode_conversion_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ConversionResult(
+      name: dco_decode_String(arr[0]),
+      ttf: dco_decode_opt_list_prim_u_8_strict(arr[1]),
+      error: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  CoverImageOptions dco_decode_cover_image_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return CoverImageOptions(
+      maxWidth: dco_decode_u_32(arr[0]),
+      maxHeight: dco_decode_u_32(arr[1]),
+      aspectRatio: dco_decode_f_32(arr[2]),
+      quality: dco_decode_f_32(arr[3]),
+    );
+  }
+
+  @protected
+  CoverPalette dco_decode_cover_palette(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return CoverPalette(
+      dominant: dco_decode_u_32(arr[0]),
+      dark: dco_decode_u_32(arr[1]),
+      light: dco_decode_u_32(arr[2]),
+      swatches: dco_decode_list_palette_swatch(arr[3]),
+      lightTheme: dco_decode_theme_colors(arr[4]),
+      darkTheme: dco_decode_theme_colors(arr[5]),
+    );
+  }
+
+  @protected
+  CoverPlaceholder dco_decode_cover_placeholder(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return CoverPlaceholder(
+      blurhash: dco_decode_String(arr[0]),
+      thumbhash: dco_decode_String(arr[1]),
+      thumbnail: dco_decode_list_prim_u_8_strict(arr[2]),
+      thumbnailWidth: dco_decode_u_32(arr[3]),
+      thumbnailHeight: dco_decode_u_32(arr[4]),
+      width: dco_decode_u_32(arr[5]),
+      height: dco_decode_u_32(arr[6]),
+    );
+  }
+
+  @protected
+  CoverStyle dco_decode_cover_style(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return CoverStyle(
+      width: dco_decode_u_32(arr[0]),
+      height: dco_decode_u_32(arr[1]),
+      background: dco_decode_u_32(arr[2]),
+      gradientEnd: dco_decode_u_32(arr[3]),
+      textColor: dco_decode_u_32(arr[4]),
+      titleSize: dco_decode_f_32(arr[5]),
+      authorSize: dco_decode_f_32(arr[6]),
+      margin: dco_decode_f_32(arr[7]),
+    );
+  }
+
+  @protected
+  CoverageReport dco_decode_coverage_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return CoverageReport(
+      totalCodepoints: dco_decode_u_32(arr[0]),
+      missingCodepoints: dco_decode_list_prim_u_32_strict(arr[1]),
+      coverage: dco_decode_f_64(arr[2]),
+    );
+  }
+
+  @protected
+  DarkImage dco_decode_dark_image(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return DarkImage(
+      image: dco_decode_book_image(arr[0]),
+      mode: dco_decode_dark_image_mode(arr[1]),
+      backgroundShare: dco_decode_f_32(arr[2]),
+      lineArt: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  DarkImageMode dco_decode_dark_image_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DarkImageMode.values[raw as int];
+  }
+
+  @protected
+  DarkImageOptions dco_decode_dark_image_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return DarkImageOptions(
+      mode: dco_decode_dark_image_mode(arr[0]),
+      background: dco_decode_u_32(arr[1]),
+      foreground: dco_decode_u_32(arr[2]),
+      whiteLevel: dco_decode_u_8(arr[3]),
+      minBackgroundShare: dco_decode_f_32(arr[4]),
+      brightness: dco_decode_f_32(arr[5]),
+    );
+  }
+
+  @protected
+  DecodedText dco_decode_decoded_text(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return DecodedText(
+      text: dco_decode_String(arr[0]),
+      encoding: dco_decode_String(arr[1]),
+      confidence: dco_decode_f_32(arr[2]),
+      hadErrors: dco_decode_bool(arr[3]),
+      candidates: dco_decode_list_encoding_candidate(arr[4]),
+    );
+  }
+
+  @protected
+  DedupOptions dco_decode_dedup_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return DedupOptions(
+      minScore: dco_decode_f_32(arr[0]),
+      compareContent: dco_decode_bool(arr[1]),
+      aliases: dco_decode_list_book_alias(arr[2]),
+    );
+  }
+
+  @protected
+  DictDefinition dco_decode_dict_definition(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return DictDefinition(
+      dictId: dco_decode_u_32(arr[0]),
+      dictTitle: dco_decode_String(arr[1]),
+      headword: dco_decode_String(arr[2]),
+      html: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  DictFormat dco_decode_dict_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DictFormat.values[raw as int];
+  }
+
+  @protected
+  DictInfo dco_decode_dict_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return DictInfo(
+      id: dco_decode_u_32(arr[0]),
+      path: dco_decode_String(arr[1]),
+      format: dco_decode_dict_format(arr[2]),
+      title: dco_decode_String(arr[3]),
+      description: dco_decode_String(arr[4]),
+      entryCount: dco_decode_u_32(arr[5]),
+      resourceFiles: dco_decode_u_32(arr[6]),
+    );
+  }
+
+  @protected
+  DiffHunk dco_decode_diff_hunk(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return DiffHunk(
+      kind: dco_decode_diff_hunk_kind(arr[0]),
+      oldIndex: dco_decode_u_32(arr[1]),
+      newIndex: dco_decode_u_32(arr[2]),
+      oldText: dco_decode_String(arr[3]),
+      newText: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  DiffHunkKind dco_decode_diff_hunk_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DiffHunkKind.values[raw as int];
+  }
+
+  @protected
+  DownloadChecksum dco_decode_download_checksum(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return DownloadChecksum(
+      algorithm: dco_decode_checksum_algorithm(arr[0]),
+      digest: dco_decode_String(arr[1]),
+      partSize: dco_decode_u_64(arr[2]),
+      partDigests: dco_decode_list_String(arr[3]),
+    );
+  }
+
+  @protected
+  DownloadEvent dco_decode_download_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return DownloadEvent(
+      taskId: dco_decode_u_32(arr[0]),
+      state: dco_decode_download_state(arr[1]),
+      downloaded: dco_decode_u_64(arr[2]),
+      total: dco_decode_opt_box_autoadd_u_64(arr[3]),
+      attempt: dco_decode_u_32(arr[4]),
+      error: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  DownloadHeader dco_decode_download_header(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return DownloadHeader(
+      name: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  DownloadRequest dco_decode_download_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return DownloadRequest(
+      url: dco_decode_String(arr[0]),
+      destination: dco_decode_String(arr[1]),
+      headers: dco_decode_list_download_header(arr[2]),
+      checksum: dco_decode_opt_box_autoadd_download_checksum(arr[3]),
+    );
+  }
+
+  @protected
+  DownloadState dco_decode_download_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DownloadState.values[raw as int];
+  }
+
+  @protected
+  DownloaderConfig dco_decode_downloader_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return DownloaderConfig(
+      maxConcurrent: dco_decode_u_32(arr[0]),
+      maxPerHost: dco_decode_u_32(arr[1]),
+      maxRetries: dco_decode_u_32(arr[2]),
+      initialBackoffMs: dco_decode_u_32(arr[3]),
+      timeoutSecs: dco_decode_u_32(arr[4]),
+      userAgent: dco_decode_String(arr[5]),
+    );
+  }
+
+  @protected
+  DrmScheme dco_decode_drm_scheme(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DrmScheme.values[raw as int];
+  }
+
+  @protected
+  DrmStatus dco_decode_drm_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return DrmStatus(
+      format: dco_decode_book_format(arr[0]),
+      scheme: dco_decode_opt_box_autoadd_drm_scheme(arr[1]),
+    );
+  }
+
+  @protected
+  DuplicateCluster dco_decode_duplicate_cluster(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return DuplicateCluster(
+      books: dco_decode_list_library_book(arr[0]),
+      score: dco_decode_f_32(arr[1]),
+      contentMatch: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  DuplicateMatch dco_decode_duplicate_match(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return DuplicateMatch(
+      book: dco_decode_library_book(arr[0]),
+      score: dco_decode_f_32(arr[1]),
+    );
+  }
+
+  @protected
+  EmphasisSpan dco_decode_emphasis_span(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return EmphasisSpan(
+      start: dco_decode_u_32(arr[0]),
+      end: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  EncodingCandidate dco_decode_encoding_candidate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return EncodingCandidate(
+      encoding: dco_decode_String(arr[0]),
+      confidence: dco_decode_f_32(arr[1]),
+    );
+  }
+
+  @protected
+  EpubExportChapter dco_decode_epub_export_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return EpubExportChapter(
+      title: dco_decode_String(arr[0]),
+      text: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  EpubExportProgress dco_decode_epub_export_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return EpubExportProgress(
+      writtenChapters: dco_decode_u_32(arr[0]),
+      totalChapters: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  EpubLayout dco_decode_epub_layout(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return EpubLayout.values[raw as int];
+  }
+
+  @protected
+  EpubMergeSource dco_decode_epub_merge_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return EpubMergeSource(
+      path: dco_decode_String(arr[0]),
+      title: dco_decode_opt_String(arr[1]),
+    );
+  }
+
+  @protected
+  EpubResource dco_decode_epub_resource(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return EpubResource(
+      href: dco_decode_String(arr[0]),
+      mediaType: dco_decode_String(arr[1]),
+      kind: dco_decode_resource_kind(arr[2]),
+      size: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  EpubSplitOptions dco_decode_epub_split_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return EpubSplitOptions(
+      tocDepth: dco_decode_opt_box_autoadd_u_32(arr[0]),
+      maxVolumeBytes: dco_decode_opt_box_autoadd_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  EpubVolume dco_decode_epub_volume(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return EpubVolume(
+      path: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      chapterCount: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  ErrorCode dco_decode_error_code(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ErrorCode.values[raw as int];
+  }
+
+  @protected
+  ExtractedFile dco_decode_extracted_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ExtractedFile(
+      index: dco_decode_u_32(arr[0]),
+      name: dco_decode_String(arr[1]),
+      kind: dco_decode_opt_box_autoadd_import_kind(arr[2]),
+      path: dco_decode_opt_String(arr[3]),
+      error: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  Extraction dco_decode_extraction(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Extraction(
+      matched: dco_decode_list_String(arr[0]),
+      values: dco_decode_list_String(arr[1]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  Feed dco_decode_feed(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Feed(
+      title: dco_decode_String(arr[0]),
+      link: dco_decode_opt_String(arr[1]),
+      entries: dco_decode_list_feed_entry(arr[2]),
+    );
+  }
+
+  @protected
+  FeedEntry dco_decode_feed_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return FeedEntry(
+      id: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      link: dco_decode_opt_String(arr[2]),
+      published: dco_decode_opt_box_autoadd_i_64(arr[3]),
+      summary: dco_decode_opt_String(arr[4]),
+      author: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  FetchedChapter dco_decode_fetched_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FetchedChapter(
+      chapterIndex: dco_decode_u_32(arr[0]),
+      text: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  FontAxis dco_decode_font_axis(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return FontAxis(
+      tag: dco_decode_String(arr[0]),
+      name: dco_decode_opt_String(arr[1]),
+      minValue: dco_decode_f_32(arr[2]),
+      defaultValue: dco_decode_f_32(arr[3]),
+      maxValue: dco_decode_f_32(arr[4]),
+      hidden: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  FontBundle dco_decode_font_bundle(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FontBundle(
+      codepoints: dco_decode_u_32(arr[0]),
+      added: dco_decode_u_32(arr[1]),
+      bytes: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  FontCacheEntry dco_decode_font_cache_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return FontCacheEntry(
+      id: dco_decode_String(arr[0]),
+      familyName: dco_decode_opt_String(arr[1]),
+      sizeBytes: dco_decode_u_32(arr[2]),
+      lastUsed: dco_decode_i_64(arr[3]),
+      bookIds: dco_decode_list_String(arr[4]),
+      pinned: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  FontCacheStats dco_decode_font_cache_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FontCacheStats(
+      entryCount: dco_decode_u_32(arr[0]),
+      totalBytes: dco_decode_u_32(arr[1]),
+      maxBytes: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  FontInfo dco_decode_font_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 12)
+      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    return FontInfo(
+      familyName: dco_decode_String(arr[0]),
+      styleName: dco_decode_String(arr[1]),
+      fullName: dco_decode_opt_String(arr[2]),
+      postscriptName: dco_decode_opt_String(arr[3]),
+      weight: dco_decode_u_16(arr[4]),
+      isItalic: dco_decode_bool(arr[5]),
+      isMonospaced: dco_decode_bool(arr[6]),
+      isVariable: dco_decode_bool(arr[7]),
+      axes: dco_decode_list_font_axis(arr[8]),
+      namedInstances: dco_decode_list_named_instance(arr[9]),
+      unitsPerEm: dco_decode_u_16(arr[10]),
+      glyphCount: dco_decode_u_16(arr[11]),
+    );
+  }
+
+  @protected
+  FontPreview dco_decode_font_preview(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return FontPreview(
+      rgba: dco_decode_list_prim_u_8_strict(arr[0]),
+      png: dco_decode_list_prim_u_8_strict(arr[1]),
+      width: dco_decode_u_32(arr[2]),
+      height: dco_decode_u_32(arr[3]),
+      missingChars: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  Highlight dco_decode_highlight(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return Highlight(
+      id: dco_decode_opt_box_autoadd_i_64(arr[0]),
+      bookId: dco_decode_String(arr[1]),
+      chapterIndex: dco_decode_u_32(arr[2]),
+      startOffset: dco_decode_u_32(arr[3]),
+      endOffset: dco_decode_u_32(arr[4]),
+      text: dco_decode_String(arr[5]),
+      note: dco_decode_opt_String(arr[6]),
+      color: dco_decode_u_32(arr[7]),
+      createdAt: dco_decode_i_64(arr[8]),
+    );
+  }
+
+  @protected
+  HttpCacheEntry dco_decode_http_cache_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return HttpCacheEntry(
+      url: dco_decode_String(arr[0]),
+      contentType: dco_decode_opt_String(arr[1]),
+      size: dco_decode_u_32(arr[2]),
+      fresh: dco_decode_bool(arr[3]),
+      storedAt: dco_decode_i_64(arr[4]),
+    );
+  }
+
+  @protected
+  HttpCacheStats dco_decode_http_cache_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return HttpCacheStats(
+      entryCount: dco_decode_u_32(arr[0]),
+      totalBytes: dco_decode_u_32(arr[1]),
+      maxBytes: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  ImportEvent dco_decode_import_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return ImportEvent(
+      kind: dco_decode_import_event_kind(arr[0]),
+      fileIndex: dco_decode_u_32(arr[1]),
+      path: dco_decode_String(arr[2]),
+      done: dco_decode_u_32(arr[3]),
+      total: dco_decode_u_32(arr[4]),
+      message: dco_decode_opt_String(arr[5]),
+      book: dco_decode_opt_box_autoadd_processed_book(arr[6]),
+      report: dco_decode_opt_box_autoadd_import_report(arr[7]),
+    );
+  }
+
+  @protected
+  ImportEventKind dco_decode_import_event_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ImportEventKind.values[raw as int];
+  }
+
+  @protected
+  ImportIssue dco_decode_import_issue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ImportIssue(
+      path: dco_decode_String(arr[0]),
+      message: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  ImportKind dco_decode_import_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ImportKind.values[raw as int];
+  }
+
+  @protected
+  ImportOptions dco_decode_import_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ImportOptions(
+      overrideEncoding: dco_decode_opt_String(arr[0]),
+      headingRules: dco_decode_opt_list_txt_chapter_rule(arr[1]),
+      purifyRules: dco_decode_list_purify_rule(arr[2]),
+      charsPerMinute: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  ImportReport dco_decode_import_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ImportReport(
+      imported: dco_decode_u_32(arr[0]),
+      skipped: dco_decode_list_import_issue(arr[1]),
+      failed: dco_decode_list_import_issue(arr[2]),
+      warnings: dco_decode_list_import_issue(arr[3]),
+    );
+  }
+
+  @protected
+  Keyword dco_decode_keyword(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return Keyword(
+      term: dco_decode_String(arr[0]),
+      kind: dco_decode_keyword_kind(arr[1]),
+      count: dco_decode_u_32(arr[2]),
+      score: dco_decode_f_32(arr[3]),
+    );
+  }
+
+  @protected
+  KeywordKind dco_decode_keyword_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return KeywordKind.values[raw as int];
+  }
+
+  @protected
+  KinsokuRules dco_decode_kinsoku_rules(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return KinsokuRules(
+      noLineStart: dco_decode_String(arr[0]),
+      noLineEnd: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  LanguageGuess dco_decode_language_guess(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LanguageGuess(
+      code: dco_decode_String(arr[0]),
+      confidence: dco_decode_f_32(arr[1]),
+    );
+  }
+
+  @protected
+  LayoutCacheStats dco_decode_layout_cache_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return LayoutCacheStats(
+      entryCount: dco_decode_u_32(arr[0]),
+      totalBytes: dco_decode_u_32(arr[1]),
+      maxBytes: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  LibraryBook dco_decode_library_book(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 15)
+      throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
+    return LibraryBook(
+      id: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      author: dco_decode_opt_String(arr[2]),
+      intro: dco_decode_opt_String(arr[3]),
+      kind: dco_decode_opt_String(arr[4]),
+      coverUrl: dco_decode_opt_String(arr[5]),
+      sourceUrl: dco_decode_opt_String(arr[6]),
+      bookUrl: dco_decode_opt_String(arr[7]),
+      tocUrl: dco_decode_opt_String(arr[8]),
+      latestChapterTitle: dco_decode_opt_String(arr[9]),
+      chapterCount: dco_decode_u_32(arr[10]),
+      wordCount: dco_decode_opt_box_autoadd_u_32(arr[11]),
+      addedAt: dco_decode_i_64(arr[12]),
+      updatedAt: dco_decode_i_64(arr[13]),
+      lastReadAt: dco_decode_opt_box_autoadd_i_64(arr[14]),
+    );
+  }
+
+  @protected
+  LibraryChapter dco_decode_library_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return LibraryChapter(
+      index: dco_decode_u_32(arr[0]),
+      title: dco_decode_String(arr[1]),
+      url: dco_decode_opt_String(arr[2]),
+      isVolume: dco_decode_bool(arr[3]),
+      wordCount: dco_decode_opt_box_autoadd_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  LibrarySearchResult dco_decode_library_search_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return LibrarySearchResult(
+      book: dco_decode_library_book(arr[0]),
+      score: dco_decode_f_32(arr[1]),
+      titleMatches: dco_decode_list_match_range(arr[2]),
+      authorMatches: dco_decode_list_match_range(arr[3]),
+    );
+  }
+
+  @protected
+  LineBreak dco_decode_line_break(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LineBreak(
+      offset: dco_decode_u_32(arr[0]),
+      mandatory: dco_decode_bool(arr[1]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<AlternativeSource> dco_decode_list_alternative_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_alternative_source).toList();
+  }
+
+  @protected
+  List<ArchiveEntry> dco_decode_list_archive_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_archive_entry).toList();
+  }
+
+  @protected
+  List<AudioSegment> dco_decode_list_audio_segment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_audio_segment).toList();
+  }
+
+  @protected
+  List<AxisValue> dco_decode_list_axis_value(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_axis_value).toList();
+  }
+
+  @protected
+  List<BookAlias> dco_decode_list_book_alias(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_book_alias).toList();
+  }
+
+  @protected
+  List<BookChapter> dco_decode_list_book_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_book_chapter).toList();
+  }
+
+  @protected
+  List<BookReadingTime> dco_decode_list_book_reading_time(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_book_reading_time).toList();
+  }
+
+  @protected
+  List<BookSource> dco_decode_list_book_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_book_source).toList();
+  }
+
+  @protected
+  List<BookUpdateSummary> dco_decode_list_book_update_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_book_update_summary).toList();
+  }
+
+  @protected
+  List<Bookmark> dco_decode_list_bookmark(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_bookmark).toList();
+  }
+
+  @protected
+  List<CalibreBook> dco_decode_list_calibre_book(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_calibre_book).toList();
+  }
+
+  @protected
+  List<CalibreFormat> dco_decode_list_calibre_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_calibre_format).toList();
+  }
+
+  @protected
+  List<CalibreIdentifier> dco_decode_list_calibre_identifier(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_calibre_identifier).toList();
+  }
+
+  @protected
+  List<ChapterImage> dco_decode_list_chapter_image(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_chapter_image).toList();
+  }
+
+  @protected
+  List<ChapterRange> dco_decode_list_chapter_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_chapter_range).toList();
+  }
+
+  @protected
+  List<ChapterUpdate> dco_decode_list_chapter_update(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_chapter_update).toList();
+  }
+
+  @protected
+  List<CloudConfigValue> dco_decode_list_cloud_config_value(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_cloud_config_value).toList();
+  }
+
+  @protected
+  List<CollectionFace> dco_decode_list_collection_face(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_collection_face).toList();
+  }
+
+  @protected
+  List<ComicPage> dco_decode_list_comic_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_comic_page).toList();
+  }
+
+  @protected
+  List<ConversionResult> dco_decode_list_conversion_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_conversion_result).toList();
+  }
+
+  @protected
+  List<DictDefinition> dco_decode_list_dict_definition(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_dict_definition).toList();
+  }
+
+  @protected
+  List<DictInfo> dco_decode_list_dict_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_dict_info).toList();
+  }
+
+  @protected
+  List<DiffHunk> dco_decode_list_diff_hunk(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_diff_hunk).toList();
+  }
+
+  @protected
+  List<DownloadHeader> dco_decode_list_download_header(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_download_header).toList();
+  }
+
+  @protected
+  List<DuplicateCluster> dco_decode_list_duplicate_cluster(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_duplicate_cluster).toList();
+  }
+
+  @protected
+  List<DuplicateMatch> dco_decode_list_duplicate_match(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_duplicate_match).toList();
+  }
+
+  @protected
+  List<EmphasisSpan> dco_decode_list_emphasis_span(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_emphasis_span).toList();
+  }
+
+  @protected
+  List<EncodingCandidate> dco_decode_list_encoding_candidate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_encoding_candidate).toList();
+  }
+
+  @protected
+  List<EpubExportChapter> dco_decode_list_epub_export_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_epub_export_chapter).toList();
+  }
+
+  @protected
+  List<EpubMergeSource> dco_decode_list_epub_merge_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_epub_merge_source).toList();
+  }
+
+  @protected
+  List<EpubResource> dco_decode_list_epub_resource(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_epub_resource).toList();
+  }
+
+  @protected
+  List<EpubVolume> dco_decode_list_epub_volume(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_epub_volume).toList();
+  }
+
+  @protected
+  List<FeedEntry> dco_decode_list_feed_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_feed_entry).toList();
+  }
+
+  @protected
+  List<FetchedChapter> dco_decode_list_fetched_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_fetched_chapter).toList();
+  }
+
+  @protected
+  List<FontAxis> dco_decode_list_font_axis(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_font_axis).toList();
+  }
+
+  @protected
+  List<FontCacheEntry> dco_decode_list_font_cache_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_font_cache_entry).toList();
+  }
+
+  @protected
+  List<Highlight> dco_decode_list_highlight(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_highlight).toList();
+  }
+
+  @protected
+  List<ImportIssue> dco_decode_list_import_issue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_import_issue).toList();
+  }
+
+  @protected
+  List<Keyword> dco_decode_list_keyword(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_keyword).toList();
+  }
+
+  @protected
+  List<LanguageGuess> dco_decode_list_language_guess(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_language_guess).toList();
+  }
+
+  @protected
+  List<LibraryBook> dco_decode_list_library_book(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_library_book).toList();
+  }
+
+  @protected
+  List<LibraryChapter> dco_decode_list_library_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_library_chapter).toList();
+  }
+
+  @protected
+  List<LibrarySearchResult> dco_decode_list_library_search_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_library_search_result)
+        .toList();
+  }
+
+  @protected
+  List<LineBreak> dco_decode_list_line_break(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_line_break).toList();
+  }
+
+  @protected
+  List<LogField> dco_decode_list_log_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_log_field).toList();
+  }
+
+  @protected
+  List<LogRecord> dco_decode_list_log_record(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_log_record).toList();
+  }
+
+  @protected
+  List<MatchRange> dco_decode_list_match_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_match_range).toList();
+  }
+
+  @protected
+  List<NamedFont> dco_decode_list_named_font(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_named_font).toList();
+  }
+
+  @protected
+  List<NamedInstance> dco_decode_list_named_instance(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_named_instance).toList();
+  }
+
+  @protected
+  List<OpdsEntry> dco_decode_list_opds_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_opds_entry).toList();
+  }
+
+  @protected
+  List<OpdsLink> dco_decode_list_opds_link(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_opds_link).toList();
+  }
+
+  @protected
+  List<AnchorResolution?> dco_decode_list_opt_box_autoadd_anchor_resolution(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_opt_box_autoadd_anchor_resolution)
+        .toList();
+  }
+
+  @protected
+  List<PaletteSwatch> dco_decode_list_palette_swatch(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_palette_swatch).toList();
+  }
+
+  @protected
+  List<PinyinSpan> dco_decode_list_pinyin_span(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_pinyin_span).toList();
+  }
+
+  @protected
+  List<PostStep> dco_decode_list_post_step(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_post_step).toList();
+  }
+
+  @protected
+  List<PrefetchChapter> dco_decode_list_prefetch_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_prefetch_chapter).toList();
+  }
+
+  @protected
+  List<PrefetchTask> dco_decode_list_prefetch_task(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_prefetch_task).toList();
+  }
+
+  @protected
+  Int64List dco_decode_list_prim_i_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeInt64List(raw);
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_32_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint32List;
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<PurifyRule> dco_decode_list_purify_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_purify_rule).toList();
+  }
+
+  @protected
+  List<PurifyRuleMatch> dco_decode_list_purify_rule_match(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_purify_rule_match).toList();
+  }
+
+  @protected
+  List<RateLimitStatus> dco_decode_list_rate_limit_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_rate_limit_status).toList();
+  }
+
+  @protected
+  List<ReadableBlock> dco_decode_list_readable_block(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_readable_block).toList();
+  }
+
+  @protected
+  List<ReadableSpan> dco_decode_list_readable_span(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_readable_span).toList();
+  }
+
+  @protected
+  List<ReadingDay> dco_decode_list_reading_day(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_reading_day).toList();
+  }
+
+  @protected
+  List<ReadingProgress> dco_decode_list_reading_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_reading_progress).toList();
+  }
+
+  @protected
+  List<RuleTestMatch> dco_decode_list_rule_test_match(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_rule_test_match).toList();
+  }
+
+  @protected
+  List<SearchChapter> dco_decode_list_search_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_search_chapter).toList();
+  }
+
+  @protected
+  List<SearchHit> dco_decode_list_search_hit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_search_hit).toList();
+  }
+
+  @protected
+  List<SourceBook> dco_decode_list_source_book(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_source_book).toList();
+  }
+
+  @protected
+  List<SourceChapter> dco_decode_list_source_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_source_chapter).toList();
+  }
+
+  @protected
+  List<StoredBookScan> dco_decode_list_stored_book_scan(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_stored_book_scan).toList();
+  }
+
+  @protected
+  List<StoredCookie> dco_decode_list_stored_cookie(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_stored_cookie).toList();
+  }
+
+  @protected
+  List<TagSuggestion> dco_decode_list_tag_suggestion(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_tag_suggestion).toList();
+  }
+
+  @protected
+  List<TextAnchor> dco_decode_list_text_anchor(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_text_anchor).toList();
+  }
+
+  @protected
+  List<TextColumn> dco_decode_list_text_column(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_text_column).toList();
+  }
+
+  @protected
+  List<TextLine> dco_decode_list_text_line(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_text_line).toList();
+  }
+
+  @protected
+  List<TextPage> dco_decode_list_text_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_text_page).toList();
+  }
+
+  @protected
+  List<TextSegment> dco_decode_list_text_segment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_text_segment).toList();
+  }
+
+  @protected
+  List<TextStats> dco_decode_list_text_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_text_stats).toList();
+  }
+
+  @protected
+  List<TocEntry> dco_decode_list_toc_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_toc_entry).toList();
+  }
+
+  @protected
+  List<TranslatedParagraph> dco_decode_list_translated_paragraph(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_translated_paragraph).toList();
+  }
+
+  @protected
+  List<TxtChapter> dco_decode_list_txt_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_txt_chapter).toList();
+  }
+
+  @protected
+  List<TxtChapterRule> dco_decode_list_txt_chapter_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_txt_chapter_rule).toList();
+  }
+
+  @protected
+  List<TxtHeadingCandidate> dco_decode_list_txt_heading_candidate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_txt_heading_candidate)
+        .toList();
+  }
+
+  @protected
+  List<UpdateCheckBook> dco_decode_list_update_check_book(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_update_check_book).toList();
+  }
+
+  @protected
+  List<Utterance> dco_decode_list_utterance(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_utterance).toList();
+  }
+
+  @protected
+  List<ValidationIssue> dco_decode_list_validation_issue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_validation_issue).toList();
+  }
+
+  @protected
+  List<VerticalGlyph> dco_decode_list_vertical_glyph(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_vertical_glyph).toList();
+  }
+
+  @protected
+  List<VerticalPage> dco_decode_list_vertical_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_vertical_page).toList();
+  }
+
+  @protected
+  List<WatermarkChar> dco_decode_list_watermark_char(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_watermark_char).toList();
+  }
+
+  @protected
+  List<WebChapter> dco_decode_list_web_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_web_chapter).toList();
+  }
+
+  @protected
+  List<WebDavEntry> dco_decode_list_web_dav_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_web_dav_entry).toList();
+  }
+
+  @protected
+  LogField dco_decode_log_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LogField(
+      key: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  LogLevel dco_decode_log_level(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LogLevel.values[raw as int];
+  }
+
+  @protected
+  LogRecord dco_decode_log_record(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return LogRecord(
+      timeMs: dco_decode_i_64(arr[0]),
+      level: dco_decode_log_level(arr[1]),
+      target: dco_decode_String(arr[2]),
+      message: dco_decode_String(arr[3]),
+      fields: dco_decode_list_log_field(arr[4]),
+    );
+  }
+
+  @protected
+  LoggingConfig dco_decode_logging_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return LoggingConfig(
+      capacity: dco_decode_u_32(arr[0]),
+      minLevel: dco_decode_log_level(arr[1]),
+      directory: dco_decode_opt_String(arr[2]),
+      maxFileBytes: dco_decode_u_64(arr[3]),
+      maxFiles: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  MappedTextSlice dco_decode_mapped_text_slice(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MappedTextSlice(
+      text: dco_decode_String(arr[0]),
+      start: dco_decode_u_32(arr[1]),
+      end: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  MappedTxtInfo dco_decode_mapped_txt_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MappedTxtInfo(
+      id: dco_decode_u_32(arr[0]),
+      path: dco_decode_String(arr[1]),
+      index: dco_decode_txt_index(arr[2]),
+    );
+  }
+
+  @protected
+  MatchRange dco_decode_match_range(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return MatchRange(
+      start: dco_decode_u_32(arr[0]),
+      end: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  MetadataGuess dco_decode_metadata_guess(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MetadataGuess(
+      value: dco_decode_String(arr[0]),
+      confidence: dco_decode_f_32(arr[1]),
+      origin: dco_decode_metadata_origin(arr[2]),
+    );
+  }
+
+  @protected
+  MetadataOrigin dco_decode_metadata_origin(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MetadataOrigin.values[raw as int];
+  }
+
+  @protected
+  NamedFont dco_decode_named_font(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return NamedFont(
+      name: dco_decode_String(arr[0]),
+      data: dco_decode_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  NamedInstance dco_decode_named_instance(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return NamedInstance(
+      name: dco_decode_opt_String(arr[0]),
+      postscriptName: dco_decode_opt_String(arr[1]),
+      coordinates: dco_decode_list_axis_value(arr[2]),
+    );
+  }
+
+  @protected
+  NetworkType dco_decode_network_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return NetworkType.values[raw as int];
+  }
+
+  @protected
+  NotesExportOptions dco_decode_notes_export_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return NotesExportOptions(
+      format: dco_decode_notes_format(arr[0]),
+      includeBookmarks: dco_decode_bool(arr[1]),
+      utcOffsetMinutes: dco_decode_i_32(arr[2]),
+    );
+  }
+
+  @protected
+  NotesFormat dco_decode_notes_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return NotesFormat.values[raw as int];
+  }
+
+  @protected
+  OpdsCredentials dco_decode_opds_credentials(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return OpdsCredentials(
+      username: dco_decode_String(arr[0]),
+      password: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  OpdsEntry dco_decode_opds_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return OpdsEntry(
+      id: dco_decode_opt_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      authors: dco_decode_list_String(arr[2]),
+      summary: dco_decode_opt_String(arr[3]),
+      coverUrl: dco_decode_opt_String(arr[4]),
+      thumbnailUrl: dco_decode_opt_String(arr[5]),
+      navigationUrl: dco_decode_opt_String(arr[6]),
+      acquisitions: dco_decode_list_opds_link(arr[7]),
+    );
+  }
+
+  @protected
+  OpdsFeed dco_decode_opds_feed(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return OpdsFeed(
+      title: dco_decode_String(arr[0]),
+      entries: dco_decode_list_opds_entry(arr[1]),
+      startUrl: dco_decode_opt_String(arr[2]),
+      nextUrl: dco_decode_opt_String(arr[3]),
+      previousUrl: dco_decode_opt_String(arr[4]),
+      searchTemplate: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  OpdsLink dco_decode_opds_link(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return OpdsLink(
+      href: dco_decode_String(arr[0]),
+      rel: dco_decode_opt_String(arr[1]),
+      mediaType: dco_decode_opt_String(arr[2]),
+      title: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  AnchorResolution? dco_decode_opt_box_autoadd_anchor_resolution(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_anchor_resolution(raw);
+  }
+
+  @protected
+  ApiError? dco_decode_opt_box_autoadd_api_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_api_error(raw);
+  }
+
+  @protected
+  CachedAudio? dco_decode_opt_box_autoadd_cached_audio(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_cached_audio(raw);
+  }
+
+  @protected
+  ChapterFingerprint? dco_decode_opt_box_autoadd_chapter_fingerprint(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_chapter_fingerprint(raw);
+  }
+
+  @protected
+  DownloadChecksum? dco_decode_opt_box_autoadd_download_checksum(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_download_checksum(raw);
+  }
+
+  @protected
+  DrmScheme? dco_decode_opt_box_autoadd_drm_scheme(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_drm_scheme(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  HttpCacheEntry? dco_decode_opt_box_autoadd_http_cache_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_http_cache_entry(raw);
+  }
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  ImportKind? dco_decode_opt_box_autoadd_import_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_import_kind(raw);
+  }
+
+  @protected
+  ImportReport? dco_decode_opt_box_autoadd_import_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_import_report(raw);
+  }
+
+  @protected
+  KinsokuRules? dco_decode_opt_box_autoadd_kinsoku_rules(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_kinsoku_rules(raw);
+  }
+
+  @protected
+  LibraryBook? dco_decode_opt_box_autoadd_library_book(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_library_book(raw);
+  }
+
+  @protected
+  MetadataGuess? dco_decode_opt_box_autoadd_metadata_guess(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_metadata_guess(raw);
+  }
+
+  @protected
+  OpdsCredentials? dco_decode_opt_box_autoadd_opds_credentials(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_opds_credentials(raw);
+  }
+
+  @protected
+  PrefetchPause? dco_decode_opt_box_autoadd_prefetch_pause(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_prefetch_pause(raw);
+  }
+
+  @protected
+  ProcessedBook? dco_decode_opt_box_autoadd_processed_book(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_processed_book(raw);
+  }
+
+  @protected
+  ProxyConfig? dco_decode_opt_box_autoadd_proxy_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_proxy_config(raw);
+  }
+
+  @protected
+  RateLimit? dco_decode_opt_box_autoadd_rate_limit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_rate_limit(raw);
+  }
+
+  @protected
+  ReadingProgress? dco_decode_opt_box_autoadd_reading_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_reading_progress(raw);
+  }
+
+  @protected
+  SyncState? dco_decode_opt_box_autoadd_sync_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_sync_state(raw);
+  }
+
+  @protected
+  TextSegment? dco_decode_opt_box_autoadd_text_segment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_text_segment(raw);
+  }
+
+  @protected
+  TtsPosition? dco_decode_opt_box_autoadd_tts_position(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_tts_position(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  WebDavSyncRecord? dco_decode_opt_box_autoadd_web_dav_sync_record(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_web_dav_sync_record(raw);
+  }
+
+  @protected
+  Uint32List? dco_decode_opt_list_prim_u_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_prim_u_32_strict(raw);
+  }
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
+  }
+
+  @protected
+  List<TxtChapterRule>? dco_decode_opt_list_txt_chapter_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_txt_chapter_rule(raw);
+  }
+
+  @protected
+  PageLayout dco_decode_page_layout(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return PageLayout(
+      width: dco_decode_f_32(arr[0]),
+      height: dco_decode_f_32(arr[1]),
+      marginTop: dco_decode_f_32(arr[2]),
+      marginRight: dco_decode_f_32(arr[3]),
+      marginBottom: dco_decode_f_32(arr[4]),
+      marginLeft: dco_decode_f_32(arr[5]),
+      fontSize: dco_decode_f_32(arr[6]),
+      lineHeight: dco_decode_f_32(arr[7]),
+      paragraphSpacing: dco_decode_f_32(arr[8]),
+      indent: dco_decode_f_32(arr[9]),
+    );
+  }
+
+  @protected
+  PaletteSwatch dco_decode_palette_swatch(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PaletteSwatch(
+      color: dco_decode_u_32(arr[0]),
+      population: dco_decode_f_32(arr[1]),
+    );
+  }
+
+  @protected
+  ParsedBook dco_decode_parsed_book(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ParsedBook(
+      metadata: dco_decode_book_metadata(arr[0]),
+      chapters: dco_decode_list_book_chapter(arr[1]),
+      toc: dco_decode_list_toc_entry(arr[2]),
+    );
+  }
+
+  @protected
+  PerformanceTier dco_decode_performance_tier(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PerformanceTier.values[raw as int];
+  }
+
+  @protected
+  PinyinSpan dco_decode_pinyin_span(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PinyinSpan(
+      text: dco_decode_String(arr[0]),
+      pinyin: dco_decode_String(arr[1]),
+      start: dco_decode_u_32(arr[2]),
+      end: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  PostStep dco_decode_post_step(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PostStep(
+      kind: dco_decode_post_step_kind(arr[0]),
+      pattern: dco_decode_String(arr[1]),
+      replacement: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  PostStepKind dco_decode_post_step_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PostStepKind.values[raw as int];
+  }
+
+  @protected
+  PrefetchChapter dco_decode_prefetch_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PrefetchChapter(
+      index: dco_decode_u_32(arr[0]),
+      request: dco_decode_download_request(arr[1]),
+      length: dco_decode_opt_box_autoadd_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  PrefetchConfig dco_decode_prefetch_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PrefetchConfig(
+      maxChapters: dco_decode_u_32(arr[0]),
+      defaultChapters: dco_decode_u_32(arr[1]),
+      lookaheadMinutes: dco_decode_u_32(arr[2]),
+      allowMetered: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  PrefetchPause dco_decode_prefetch_pause(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PrefetchPause.values[raw as int];
+  }
+
+  @protected
+  PrefetchPlan dco_decode_prefetch_plan(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PrefetchPlan(
+      key: dco_decode_String(arr[0]),
+      currentIndex: dco_decode_u_32(arr[1]),
+      chapters: dco_decode_list_prefetch_chapter(arr[2]),
+      charsPerMinute: dco_decode_opt_box_autoadd_u_32(arr[3]),
+      network: dco_decode_network_type(arr[4]),
+    );
+  }
+
+  @protected
+  PrefetchStatus dco_decode_prefetch_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PrefetchStatus(
+      key: dco_decode_opt_String(arr[0]),
+      paused: dco_decode_opt_box_autoadd_prefetch_pause(arr[1]),
+      tasks: dco_decode_list_prefetch_task(arr[2]),
+    );
+  }
+
+  @protected
+  PrefetchTask dco_decode_prefetch_task(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PrefetchTask(
+      chapterIndex: dco_decode_u_32(arr[0]),
+      taskId: dco_decode_u_32(arr[1]),
+      state: dco_decode_download_state(arr[2]),
+    );
+  }
+
+  @protected
+  ProcessedBook dco_decode_processed_book(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ProcessedBook(
+      book: dco_decode_parsed_book(arr[0]),
+      encoding: dco_decode_String(arr[1]),
+      stats: dco_decode_book_stats(arr[2]),
+    );
+  }
+
+  @protected
+  ProcessedImage dco_decode_processed_image(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ProcessedImage(
+      data: dco_decode_list_prim_u_8_strict(arr[0]),
+      width: dco_decode_u_32(arr[1]),
+      height: dco_decode_u_32(arr[2]),
+      sourceWidth: dco_decode_u_32(arr[3]),
+      sourceHeight: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  ProxyConfig dco_decode_proxy_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ProxyConfig(
+      kind: dco_decode_proxy_kind(arr[0]),
+      host: dco_decode_String(arr[1]),
+      port: dco_decode_u_16(arr[2]),
+      username: dco_decode_opt_String(arr[3]),
+      password: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  ProxyKind dco_decode_proxy_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ProxyKind.values[raw as int];
+  }
+
+  @protected
+  PunctuationRepair dco_decode_punctuation_repair(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PunctuationRepair(
+      text: dco_decode_String(arr[0]),
+      changes: dco_decode_u_32(arr[1]),
+      spacesRemoved: dco_decode_u_32(arr[2]),
+      quotesFixed: dco_decode_u_32(arr[3]),
+      bracketsReplaced: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  PurifyReport dco_decode_purify_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PurifyReport(
+      text: dco_decode_String(arr[0]),
+      matches: dco_decode_list_purify_rule_match(arr[1]),
+    );
+  }
+
+  @protected
+  PurifyRule dco_decode_purify_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PurifyRule(
+      name: dco_decode_String(arr[0]),
+      kind: dco_decode_purify_rule_kind(arr[1]),
+      pattern: dco_decode_String(arr[2]),
+      replacement: dco_decode_String(arr[3]),
+      enabled: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  PurifyRuleKind dco_decode_purify_rule_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PurifyRuleKind.values[raw as int];
+  }
+
+  @protected
+  PurifyRuleMatch dco_decode_purify_rule_match(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PurifyRuleMatch(
+      ruleIndex: dco_decode_u_32(arr[0]),
+      name: dco_decode_String(arr[1]),
+      count: dco_decode_u_32(arr[2]),
+      samples: dco_decode_list_String(arr[3]),
+    );
+  }
+
+  @protected
+  RateLimit dco_decode_rate_limit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return RateLimit(
+      requestsPerSecond: dco_decode_f_64(arr[0]),
+      burst: dco_decode_u_32(arr[1]),
+      jitterMs: dco_decode_u_32(arr[2]),
+      cooldownSecs: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  RateLimitStatus dco_decode_rate_limit_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return RateLimitStatus(
+      host: dco_decode_String(arr[0]),
+      waiting: dco_decode_u_32(arr[1]),
+      available: dco_decode_u_32(arr[2]),
+      cooldownMs: dco_decode_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  ReadableBlock dco_decode_readable_block(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ReadableBlock(
+      kind: dco_decode_readable_block_kind(arr[0]),
+      spans: dco_decode_list_readable_span(arr[1]),
+    );
+  }
+
+  @protected
+  ReadableBlockKind dco_decode_readable_block_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ReadableBlockKind.values[raw as int];
+  }
+
+  @protected
+  ReadableContent dco_decode_readable_content(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ReadableContent(
+      title: dco_decode_opt_String(arr[0]),
+      blocks: dco_decode_list_readable_block(arr[1]),
+    );
+  }
+
+  @protected
+  ReadableSpan dco_decode_readable_span(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ReadableSpan(
+      text: dco_decode_String(arr[0]),
+      imageSrc: dco_decode_opt_String(arr[1]),
+      bold: dco_decode_bool(arr[2]),
+      italic: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  ReadingDay dco_decode_reading_day(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ReadingDay(
+      date: dco_decode_String(arr[0]),
+      seconds: dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  ReadingProgress dco_decode_reading_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ReadingProgress(
+      bookId: dco_decode_String(arr[0]),
+      chapterIndex: dco_decode_u_32(arr[1]),
+      chapterOffset: dco_decode_u_32(arr[2]),
+      percent: dco_decode_f_64(arr[3]),
+      updatedAt: dco_decode_i_64(arr[4]),
+    );
+  }
+
+  @protected
+  ReadingStats dco_decode_reading_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ReadingStats(
+      totalSeconds: dco_decode_u_64(arr[0]),
+      days: dco_decode_list_reading_day(arr[1]),
+      books: dco_decode_list_book_reading_time(arr[2]),
+      currentStreak: dco_decode_u_32(arr[3]),
+      longestStreak: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  RepairLevel dco_decode_repair_level(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RepairLevel.values[raw as int];
+  }
+
+  @protected
+  ResourceKind dco_decode_resource_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ResourceKind.values[raw as int];
+  }
+
+  @protected
+  ResplitOutcome dco_decode_resplit_outcome(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ResplitOutcome(
+      titles: dco_decode_list_String(arr[0]),
+      movedBookmarks: dco_decode_u_32(arr[1]),
+      movedHighlights: dco_decode_u_32(arr[2]),
+      chapterIndex: dco_decode_opt_box_autoadd_u_32(arr[3]),
+      chapterOffset: dco_decode_opt_box_autoadd_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  RestoreResult dco_decode_restore_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return RestoreResult(
+      booksAdded: dco_decode_u_32(arr[0]),
+      booksUpdated: dco_decode_u_32(arr[1]),
+      progressUpdated: dco_decode_u_32(arr[2]),
+      bookmarksAdded: dco_decode_u_32(arr[3]),
+      highlightsAdded: dco_decode_u_32(arr[4]),
+      purifyRulesAdded: dco_decode_u_32(arr[5]),
+      bookSourcesAdded: dco_decode_u_32(arr[6]),
+      purifyRules: dco_decode_list_purify_rule(arr[7]),
+      bookSourcesJson: dco_decode_String(arr[8]),
+    );
+  }
+
+  @protected
+  RuleTest dco_decode_rule_test(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return RuleTest(
+      matches: dco_decode_list_rule_test_match(arr[0]),
+      matchCount: dco_decode_u_32(arr[1]),
+      output: dco_decode_String(arr[2]),
+      compileUs: dco_decode_u_64(arr[3]),
+      matchUsPerMib: dco_decode_u_64(arr[4]),
+      slow: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  RuleTestMatch dco_decode_rule_test_match(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return RuleTestMatch(
+      start: dco_decode_u_32(arr[0]),
+      end: dco_decode_u_32(arr[1]),
+      text: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  ScrapedMetadata dco_decode_scraped_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ScrapedMetadata(
+      title: dco_decode_opt_box_autoadd_metadata_guess(arr[0]),
+      author: dco_decode_opt_box_autoadd_metadata_guess(arr[1]),
+      tags: dco_decode_list_String(arr[2]),
+    );
+  }
+
+  @protected
+  SearchChapter dco_decode_search_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SearchChapter(
+      chapterIndex: dco_decode_u_32(arr[0]),
+      title: dco_decode_String(arr[1]),
+      text: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  SearchHit dco_decode_search_hit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return SearchHit(
+      bookId: dco_decode_String(arr[0]),
+      chapterIndex: dco_decode_u_32(arr[1]),
+      chapterTitle: dco_decode_String(arr[2]),
+      offset: dco_decode_u_32(arr[3]),
+      length: dco_decode_u_32(arr[4]),
+      snippet: dco_decode_String(arr[5]),
+      highlightStart: dco_decode_u_32(arr[6]),
+      highlightEnd: dco_decode_u_32(arr[7]),
+    );
+  }
+
+  @protected
+  SearchRules dco_decode_search_rules(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return SearchRules(
+      bookList: dco_decode_opt_String(arr[0]),
+      name: dco_decode_opt_String(arr[1]),
+      author: dco_decode_opt_String(arr[2]),
+      intro: dco_decode_opt_String(arr[3]),
+      kind: dco_decode_opt_String(arr[4]),
+      lastChapter: dco_decode_opt_String(arr[5]),
+      coverUrl: dco_decode_opt_String(arr[6]),
+      bookUrl: dco_decode_opt_String(arr[7]),
+      wordCount: dco_decode_opt_String(arr[8]),
+    );
+  }
+
+  @protected
+  SourceBook dco_decode_source_book(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return SourceBook(
+      name: dco_decode_String(arr[0]),
+      author: dco_decode_opt_String(arr[1]),
+      intro: dco_decode_opt_String(arr[2]),
+      kind: dco_decode_opt_String(arr[3]),
+      lastChapter: dco_decode_opt_String(arr[4]),
+      coverUrl: dco_decode_opt_String(arr[5]),
+      wordCount: dco_decode_opt_String(arr[6]),
+      bookUrl: dco_decode_String(arr[7]),
+      tocUrl: dco_decode_String(arr[8]),
+    );
+  }
+
+  @protected
+  SourceChapter dco_decode_source_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SourceChapter(
+      title: dco_decode_String(arr[0]),
+      url: dco_decode_String(arr[1]),
+      isVolume: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  SsmlOptions dco_decode_ssml_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SsmlOptions(
+      language: dco_decode_String(arr[0]),
+      paragraphBreakMs: dco_decode_u_32(arr[1]),
+      dialoguePitch: dco_decode_opt_String(arr[2]),
+      dialogueRate: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  StoredBookScan dco_decode_stored_book_scan(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return StoredBookScan(
+      bookId: dco_decode_String(arr[0]),
+      corruptRecords: dco_decode_u_32(arr[1]),
+      lostChapters: dco_decode_list_prim_u_32_strict(arr[2]),
+    );
+  }
+
+  @protected
+  StoredCookie dco_decode_stored_cookie(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return StoredCookie(
+      name: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+      domain: dco_decode_String(arr[2]),
+      hostOnly: dco_decode_bool(arr[3]),
+      path: dco_decode_String(arr[4]),
+      expires: dco_decode_opt_box_autoadd_i_64(arr[5]),
+      secure: dco_decode_bool(arr[6]),
+      httpOnly: dco_decode_bool(arr[7]),
+    );
+  }
+
+  @protected
+  StyledChapter dco_decode_styled_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return StyledChapter(
+      html: dco_decode_String(arr[0]),
+      css: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  SummaryOptions dco_decode_summary_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SummaryOptions(
+      maxChars: dco_decode_u_32(arr[0]),
+      maxSentences: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  SyncResult dco_decode_sync_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return SyncResult(
+      version: dco_decode_u_32(arr[0]),
+      uploaded: dco_decode_u_32(arr[1]),
+      downloaded: dco_decode_u_32(arr[2]),
+      progressUpdated: dco_decode_u_32(arr[3]),
+      bookmarksAdded: dco_decode_u_32(arr[4]),
+      highlightsAdded: dco_decode_u_32(arr[5]),
+      bookmarksRemoved: dco_decode_u_32(arr[6]),
+      highlightsRemoved: dco_decode_u_32(arr[7]),
+      state: dco_decode_sync_state(arr[8]),
+    );
+  }
+
+  @protected
+  SyncState dco_decode_sync_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SyncState(
+      remoteId: dco_decode_String(arr[0]),
+      version: dco_decode_u_32(arr[1]),
+      fingerprints: dco_decode_list_String(arr[2]),
+    );
+  }
+
+  @protected
+  TagSuggestion dco_decode_tag_suggestion(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TagSuggestion(
+      tag: dco_decode_String(arr[0]),
+      density: dco_decode_f_32(arr[1]),
+    );
+  }
+
+  @protected
+  TextAnchor dco_decode_text_anchor(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return TextAnchor(
+      exact: dco_decode_String(arr[0]),
+      prefix: dco_decode_String(arr[1]),
+      suffix: dco_decode_String(arr[2]),
+      start: dco_decode_u_32(arr[3]),
+      end: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  TextColumn dco_decode_text_column(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return TextColumn(
+      start: dco_decode_u_32(arr[0]),
+      end: dco_decode_u_32(arr[1]),
+      paragraphStart: dco_decode_bool(arr[2]),
+      x: dco_decode_f_32(arr[3]),
+      height: dco_decode_f_32(arr[4]),
+      glyphs: dco_decode_list_vertical_glyph(arr[5]),
+    );
+  }
+
+  @protected
+  TextLine dco_decode_text_line(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return TextLine(
+      start: dco_decode_u_32(arr[0]),
+      end: dco_decode_u_32(arr[1]),
+      paragraphStart: dco_decode_bool(arr[2]),
+      width: dco_decode_f_32(arr[3]),
+      hyphenated: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  TextPage dco_decode_text_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TextPage(
+      start: dco_decode_u_32(arr[0]),
+      end: dco_decode_u_32(arr[1]),
+      lines: dco_decode_list_text_line(arr[2]),
+    );
+  }
+
+  @protected
+  TextSegment dco_decode_text_segment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TextSegment(
+      text: dco_decode_String(arr[0]),
+      start: dco_decode_u_32(arr[1]),
+      end: dco_decode_u_32(arr[2]),
+      isWord: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  TextStats dco_decode_text_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TextStats(
+      cjkChars: dco_decode_u_32(arr[0]),
+      latinWords: dco_decode_u_32(arr[1]),
+      punctuation: dco_decode_u_32(arr[2]),
+      readingMinutes: dco_decode_f_32(arr[3]),
+    );
+  }
+
+  @protected
+  ThemeColors dco_decode_theme_colors(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ThemeColors(
+      background: dco_decode_u_32(arr[0]),
+      foreground: dco_decode_u_32(arr[1]),
+      accent: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  TierSettings dco_decode_tier_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TierSettings(
+      prefetchDepth: dco_decode_u_32(arr[0]),
+      imageQuality: dco_decode_u_8(arr[1]),
+      animations: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  TocEntry dco_decode_toc_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TocEntry(
+      title: dco_decode_String(arr[0]),
+      href: dco_decode_String(arr[1]),
+      chapterIndex: dco_decode_opt_box_autoadd_u_32(arr[2]),
+      depth: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  TocRules dco_decode_toc_rules(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return TocRules(
+      chapterList: dco_decode_opt_String(arr[0]),
+      chapterName: dco_decode_opt_String(arr[1]),
+      chapterUrl: dco_decode_opt_String(arr[2]),
+      isVolume: dco_decode_opt_String(arr[3]),
+      nextTocUrl: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  TranslatedParagraph dco_decode_translated_paragraph(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TranslatedParagraph(
+      original: dco_decode_String(arr[0]),
+      translation: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  TranslationProvider dco_decode_translation_provider(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TranslationProvider.values[raw as int];
+  }
+
+  @protected
+  TranslatorConfig dco_decode_translator_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return TranslatorConfig(
+      provider: dco_decode_translation_provider(arr[0]),
+      endpoint: dco_decode_String(arr[1]),
+      apiKey: dco_decode_opt_String(arr[2]),
+      model: dco_decode_opt_String(arr[3]),
+      sourceLanguage: dco_decode_opt_String(arr[4]),
+      targetLanguage: dco_decode_String(arr[5]),
+      timeoutSecs: dco_decode_u_32(arr[6]),
+    );
+  }
+
+  @protected
+  TtfChunk dco_decode_ttf_chunk(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TtfChunk(
+      offset: dco_decode_u_32(arr[0]),
+      totalLen: dco_decode_u_32(arr[1]),
+      data: dco_decode_list_prim_u_8_strict(arr[2]),
+    );
+  }
+
+  @protected
+  TtsAudioChunk dco_decode_tts_audio_chunk(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TtsAudioChunk(
+      utteranceId: dco_decode_String(arr[0]),
+      sampleRate: dco_decode_u_32(arr[1]),
+      pcm: dco_decode_list_prim_u_8_strict(arr[2]),
+    );
+  }
+
+  @protected
+  TtsOptions dco_decode_tts_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TtsOptions(
+      speed: dco_decode_f_32(arr[0]),
+      pitch: dco_decode_f_32(arr[1]),
+      speaker: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  TtsPosition dco_decode_tts_position(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TtsPosition(
+      utteranceId: dco_decode_String(arr[0]),
+      start: dco_decode_u_32(arr[1]),
+      end: dco_decode_u_32(arr[2]),
+      offset: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  TtsVoiceInfo dco_decode_tts_voice_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TtsVoiceInfo(
+      sampleRate: dco_decode_u_32(arr[0]),
+      speakers: dco_decode_u_32(arr[1]),
+      language: dco_decode_String(arr[2]),
+      usesLexicon: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  TxtChapter dco_decode_txt_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TxtChapter(
+      title: dco_decode_opt_String(arr[0]),
+      start: dco_decode_u_32(arr[1]),
+      end: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  TxtChapterProposal dco_decode_txt_chapter_proposal(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TxtChapterProposal(
+      encoding: dco_decode_String(arr[0]),
+      fileSize: dco_decode_u_32(arr[1]),
+      headings: dco_decode_list_txt_heading_candidate(arr[2]),
+    );
+  }
+
+  @protected
+  TxtChapterRule dco_decode_txt_chapter_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TxtChapterRule(
+      name: dco_decode_String(arr[0]),
+      pattern: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  TxtHeadingCandidate dco_decode_txt_heading_candidate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return TxtHeadingCandidate(
+      title: dco_decode_String(arr[0]),
+      start: dco_decode_u_32(arr[1]),
+      line: dco_decode_u_32(arr[2]),
+      confidence: dco_decode_f_32(arr[3]),
+      selected: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  TxtIndex dco_decode_txt_index(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TxtIndex(
+      encoding: dco_decode_String(arr[0]),
+      fileSize: dco_decode_u_32(arr[1]),
+      chapters: dco_decode_list_txt_chapter(arr[2]),
+    );
+  }
+
+  @protected
+  TypographyOptions dco_decode_typography_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return TypographyOptions(
+      fullWidthPunctuation: dco_decode_bool(arr[0]),
+      collapseBlankLines: dco_decode_bool(arr[1]),
+      indentParagraphs: dco_decode_bool(arr[2]),
+      unifyEllipsesAndDashes: dco_decode_bool(arr[3]),
+      curlyQuotes: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  UpdateCheckBook dco_decode_update_check_book(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return UpdateCheckBook(
+      bookId: dco_decode_String(arr[0]),
+      source: dco_decode_book_source(arr[1]),
+      tocUrl: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  UpdateCheckStatus dco_decode_update_check_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return UpdateCheckStatus.values[raw as int];
+  }
+
+  @protected
+  Utterance dco_decode_utterance(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return Utterance(
+      id: dco_decode_String(arr[0]),
+      text: dco_decode_String(arr[1]),
+      start: dco_decode_u_32(arr[2]),
+      end: dco_decode_u_32(arr[3]),
+      ssml: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  ValidationIssue dco_decode_validation_issue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ValidationIssue(
+      kind: dco_decode_validation_issue_kind(arr[0]),
+      table: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  ValidationIssueKind dco_decode_validation_issue_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ValidationIssueKind.values[raw as int];
+  }
+
+  @protected
+  ValidationReport dco_decode_validation_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ValidationReport(
+      isValid: dco_decode_bool(arr[0]),
+      isRepairable: dco_decode_bool(arr[1]),
+      issues: dco_decode_list_validation_issue(arr[2]),
+    );
+  }
+
+  @protected
+  VerticalGlyph dco_decode_vertical_glyph(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return VerticalGlyph(
+      offset: dco_decode_u_32(arr[0]),
+      len: dco_decode_u_32(arr[1]),
+      text: dco_decode_String(arr[2]),
+      y: dco_decode_f_32(arr[3]),
+      advance: dco_decode_f_32(arr[4]),
+      rotated: dco_decode_bool(arr[5]),
+      vertFeature: dco_decode_bool(arr[6]),
+    );
+  }
+
+  @protected
+  VerticalPage dco_decode_vertical_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return VerticalPage(
+      start: dco_decode_u_32(arr[0]),
+      end: dco_decode_u_32(arr[1]),
+      columns: dco_decode_list_text_column(arr[2]),
+    );
+  }
+
+  @protected
+  WatermarkChar dco_decode_watermark_char(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return WatermarkChar(
+      codepoint: dco_decode_u_32(arr[0]),
+      name: dco_decode_String(arr[1]),
+      kind: dco_decode_watermark_kind(arr[2]),
+      replacement: dco_decode_String(arr[3]),
+      count: dco_decode_u_32(arr[4]),
+      offsets: dco_decode_list_prim_u_32_strict(arr[5]),
+    );
+  }
+
+  @protected
+  WatermarkKind dco_decode_watermark_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WatermarkKind.values[raw as int];
+  }
+
+  @protected
+  WatermarkReport dco_decode_watermark_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WatermarkReport(
+      chars: dco_decode_list_watermark_char(arr[0]),
+      total: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  WebBook dco_decode_web_book(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return WebBook(
+      title: dco_decode_opt_String(arr[0]),
+      author: dco_decode_opt_String(arr[1]),
+      url: dco_decode_String(arr[2]),
+      chapters: dco_decode_list_web_chapter(arr[3]),
+      confidence: dco_decode_f_32(arr[4]),
+    );
+  }
+
+  @protected
+  WebChapter dco_decode_web_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WebChapter(
+      index: dco_decode_u_32(arr[0]),
+      title: dco_decode_String(arr[1]),
+      url: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  WebDavConfig dco_decode_web_dav_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WebDavConfig(
+      baseUrl: dco_decode_String(arr[0]),
+      username: dco_decode_String(arr[1]),
+      password: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  WebDavEntry dco_decode_web_dav_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return WebDavEntry(
+      path: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      isDir: dco_decode_bool(arr[2]),
+      size: dco_decode_u_64(arr[3]),
+      modified: dco_decode_opt_box_autoadd_i_64(arr[4]),
+      etag: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  WebDavSyncRecord dco_decode_web_dav_sync_record(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WebDavSyncRecord(
+      hash: dco_decode_String(arr[0]),
+      etag: dco_decode_opt_String(arr[1]),
+      modified: dco_decode_opt_box_autoadd_i_64(arr[2]),
+    );
+  }
+
+  @protected
+  WebDavSyncStatus dco_decode_web_dav_sync_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WebDavSyncStatus.values[raw as int];
+  }
+
+  @protected
+  WebImportProgress dco_decode_web_import_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return WebImportProgress(
+      done: dco_decode_u_32(arr[0]),
+      total: dco_decode_u_32(arr[1]),
+      chapterIndex: dco_decode_u_32(arr[2]),
+      skipped: dco_decode_bool(arr[3]),
+      error: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  WebServerConfig dco_decode_web_server_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return WebServerConfig(
+      port: dco_decode_u_16(arr[0]),
+      uploadDir: dco_decode_opt_String(arr[1]),
+      accessToken: dco_decode_opt_String(arr[2]),
+      maxUploadMb: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  WebServerInfo dco_decode_web_server_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WebServerInfo(
+      port: dco_decode_u_16(arr[0]),
+      urls: dco_decode_list_String(arr[1]),
+    );
+  }
+
+  @protected
+  WebUpload dco_decode_web_upload(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WebUpload(
+      name: dco_decode_String(arr[0]),
+      path: dco_decode_String(arr[1]),
+      kind: dco_decode_import_kind(arr[2]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  RustStreamSink<ArchiveProgress> sse_decode_StreamSink_archive_progress_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<DownloadEvent> sse_decode_StreamSink_download_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<EpubExportProgress> sse_decode_StreamSink_epub_export_progress_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<ImportEvent> sse_decode_StreamSink_import_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<RateLimitStatus> sse_decode_StreamSink_rate_limit_status_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<TtfChunk> sse_decode_StreamSink_ttf_chunk_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<TtsAudioChunk> sse_decode_StreamSink_tts_audio_chunk_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<WebImportProgress> sse_decode_StreamSink_web_import_progress_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<WebUpload> sse_decode_StreamSink_web_upload_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  AlternativeChapter sse_decode_alternative_chapter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_source = sse_decode_book_source(deserializer);
+    var var_book = sse_decode_source_book(deserializer);
+    var var_chapter = sse_decode_source_chapter(deserializer);
+    var var_content = sse_decode_String(deserializer);
+    var var_score = sse_decode_f_32(deserializer);
+    return AlternativeChapter(
+      source: var_source,
+      book: var_book,
+      chapter: var_chapter,
+      content: var_content,
+      score: var_score,
+    );
+  }
+
+  @protected
+  AlternativeSource sse_decode_alternative_source(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_source = sse_decode_book_source(deserializer);
+    var var_book = sse_decode_source_book(deserializer);
+    var var_score = sse_decode_f_32(deserializer);
+    return AlternativeSource(
+      source: var_source,
+      book: var_book,
+      score: var_score,
+    );
+  }
+
+  @protected
+  AnchorResolution sse_decode_anchor_resolution(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_anchor = sse_decode_text_anchor(deserializer);
+    var var_exact = sse_decode_bool(deserializer);
+    var var_similarity = sse_decode_f_32(deserializer);
+    return AnchorResolution(
+      anchor: var_anchor,
+      exact: var_exact,
+      similarity: var_similarity,
+    );
+  }
+
+  @protected
+  ApiError sse_decode_api_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_code = sse_decode_error_code(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_context = sse_decode_list_String(deserializer);
+    var var_location = sse_decode_opt_String(deserializer);
+    var var_backtrace = sse_decode_opt_String(deserializer);
+    return ApiError(
+      code: var_code,
+      message: var_message,
+      context: var_context,
+      location: var_location,
+      backtrace: var_backtrace,
+    );
+  }
+
+  @protected
+  ArchiveEntry sse_decode_archive_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_index = sse_decode_u_32(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_size = sse_decode_u_32(deserializer);
+    var var_kind = sse_decode_opt_box_autoadd_import_kind(deserializer);
+    var var_encrypted = sse_decode_bool(deserializer);
+    return ArchiveEntry(
+      index: var_index,
+      name: var_name,
+      size: var_size,
+      kind: var_kind,
+      encrypted: var_encrypted,
+    );
+  }
+
+  @protected
+  ArchiveFormat sse_decode_archive_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ArchiveFormat.values[inner];
+  }
+
+  @protected
+  ArchiveInfo sse_decode_archive_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_format = sse_decode_archive_format(deserializer);
+    var var_entries = sse_decode_list_archive_entry(deserializer);
+    return ArchiveInfo(format: var_format, entries: var_entries);
+  }
+
+  @protected
+  ArchiveProgress sse_decode_archive_progress(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_done = sse_decode_u_32(deserializer);
+    var var_total = sse_decode_u_32(deserializer);
+    var var_file = sse_decode_extracted_file(deserializer);
+    return ArchiveProgress(done: var_done, total: var_total, file: var_file);
+  }
+
+  @protected
+  AudioCacheStats sse_decode_audio_cache_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_entryCount = sse_decode_u_32(deserializer);
+    var var_totalBytes = sse_decode_u_64(deserializer);
+    var var_maxBytes = sse_decode_u_64(deserializer);
+    return AudioCacheStats(
+      entryCount: var_entryCount,
+      totalBytes: var_totalBytes,
+      maxBytes: var_maxBytes,
+    );
+  }
+
+  @protected
+  AudioFormat sse_decode_audio_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return AudioFormat.values[inner];
+  }
+
+  @protected
+  AudioSegment sse_decode_audio_segment(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_utteranceId = sse_decode_String(deserializer);
+    var var_startMs = sse_decode_u_64(deserializer);
+    var var_durationMs = sse_decode_u_64(deserializer);
+    return AudioSegment(
+      utteranceId: var_utteranceId,
+      startMs: var_startMs,
+      durationMs: var_durationMs,
+    );
+  }
+
+  @protected
+  AxisValue sse_decode_axis_value(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_tag = sse_decode_String(deserializer);
+    var var_value = sse_decode_f_32(deserializer);
+    return AxisValue(tag: var_tag, value: var_value);
+  }
+
+  @protected
+  BackupInfo sse_decode_backup_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_version = sse_decode_u_32(deserializer);
+    var var_createdAt = sse_decode_i_64(deserializer);
+    var var_bookCount = sse_decode_u_32(deserializer);
+    var var_bookmarkCount = sse_decode_u_32(deserializer);
+    var var_highlightCount = sse_decode_u_32(deserializer);
+    var var_purifyRuleCount = sse_decode_u_32(deserializer);
+    var var_bookSourceCount = sse_decode_u_32(deserializer);
+    return BackupInfo(
+      version: var_version,
+      createdAt: var_createdAt,
+      bookCount: var_bookCount,
+      bookmarkCount: var_bookmarkCount,
+      highlightCount: var_highlightCount,
+      purifyRuleCount: var_purifyRuleCount,
+      bookSourceCount: var_bookSourceCount,
+    );
+  }
+
+  @protected
+  BandwidthPolicy sse_decode_bandwidth_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxKbps = sse_decode_u_32(deserializer);
+    var var_meteredMaxKbps = sse_decode_u_32(deserializer);
+    var var_pauseBackgroundWhenMetered = sse_decode_bool(deserializer);
+    return BandwidthPolicy(
+      maxKbps: var_maxKbps,
+      meteredMaxKbps: var_meteredMaxKbps,
+      pauseBackgroundWhenMetered: var_pauseBackgroundWhenMetered,
+    );
+  }
+
+  @protected
+  BenchReport sse_decode_bench_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_inflateMbPerSec = sse_decode_f_64(deserializer);
+    var var_segmentCharsPerSec = sse_decode_f_64(deserializer);
+    var var_layoutCharsPerSec = sse_decode_f_64(deserializer);
+    var var_score = sse_decode_f_64(deserializer);
+    var var_tier = sse_decode_performance_tier(deserializer);
+    var var_settings = sse_decode_tier_settings(deserializer);
+    var var_elapsedMs = sse_decode_u_32(deserializer);
+    return BenchReport(
+      inflateMbPerSec: var_inflateMbPerSec,
+      segmentCharsPerSec: var_segmentCharsPerSec,
+      layoutCharsPerSec: var_layoutCharsPerSec,
+      score: var_score,
+      tier: var_tier,
+      settings: var_settings,
+      elapsedMs: var_elapsedMs,
+    );
+  }
+
+  @protected
+  BionicOptions sse_decode_bionic_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_latinRatio = sse_decode_f_32(deserializer);
+    var var_cjk = sse_decode_bool(deserializer);
+    return BionicOptions(latinRatio: var_latinRatio, cjk: var_cjk);
+  }
+
+  @protected
+  BlobGcReport sse_decode_blob_gc_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_removed = sse_decode_u_32(deserializer);
+    var var_freedBytes = sse_decode_u_64(deserializer);
+    return BlobGcReport(removed: var_removed, freedBytes: var_freedBytes);
+  }
+
+  @protected
+  BlobStoreStats sse_decode_blob_store_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_blobCount = sse_decode_u_32(deserializer);
+    var var_totalBytes = sse_decode_u_64(deserializer);
+    var var_referenceCount = sse_decode_u_32(deserializer);
+    return BlobStoreStats(
+      blobCount: var_blobCount,
+      totalBytes: var_totalBytes,
+      referenceCount: var_referenceCount,
+    );
+  }
+
+  @protected
+  BookAlias sse_decode_book_alias(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_alias = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    return BookAlias(alias: var_alias, title: var_title);
+  }
+
+  @protected
+  BookChapter sse_decode_book_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_index = sse_decode_u_32(deserializer);
+    var var_href = sse_decode_String(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_html = sse_decode_String(deserializer);
+    var var_images = sse_decode_list_chapter_image(deserializer);
+    return BookChapter(
+      index: var_index,
+      href: var_href,
+      title: var_title,
+      html: var_html,
+      images: var_images,
+    );
+  }
+
+  @protected
+  BookFormat sse_decode_book_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return BookFormat.values[inner];
+  }
+
+  @protected
+  BookImage sse_decode_book_image(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rgba = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_sourceWidth = sse_decode_u_32(deserializer);
+    var var_sourceHeight = sse_decode_u_32(deserializer);
+    return BookImage(
+      rgba: var_rgba,
+      width: var_width,
+      height: var_height,
+      sourceWidth: var_sourceWidth,
+      sourceHeight: var_sourceHeight,
+    );
+  }
+
+  @protected
+  BookInfoRules sse_decode_book_info_rules(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_init = sse_decode_opt_String(deserializer);
+    var var_name = sse_decode_opt_String(deserializer);
+    var var_author = sse_decode_opt_String(deserializer);
+    var var_intro = sse_decode_opt_String(deserializer);
+    var var_kind = sse_decode_opt_String(deserializer);
+    var var_lastChapter = sse_decode_opt_String(deserializer);
+    var var_coverUrl = sse_decode_opt_String(deserializer);
+    var var_tocUrl = sse_decode_opt_String(deserializer);
+    var var_wordCount = sse_decode_opt_String(deserializer);
+    return BookInfoRules(
+      init: var_init,
+      name: var_name,
+      author: var_author,
+      intro: var_intro,
+      kind: var_kind,
+      lastChapter: var_lastChapter,
+      coverUrl: var_coverUrl,
+      tocUrl: var_tocUrl,
+      wordCount: var_wordCount,
+    );
+  }
+
+  @protected
+  BookKeywords sse_decode_book_keywords(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_keywords = sse_decode_list_keyword(deserializer);
+    var var_tags = sse_decode_list_tag_suggestion(deserializer);
+    return BookKeywords(keywords: var_keywords, tags: var_tags);
+  }
+
+  @protected
+  BookMetadata sse_decode_book_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_authors = sse_decode_list_String(deserializer);
+    var var_language = sse_decode_opt_String(deserializer);
+    var var_identifier = sse_decode_opt_String(deserializer);
+    var var_publisher = sse_decode_opt_String(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    var var_coverHref = sse_decode_opt_String(deserializer);
+    return BookMetadata(
+      title: var_title,
+      authors: var_authors,
+      language: var_language,
+      identifier: var_identifier,
+      publisher: var_publisher,
+      description: var_description,
+      coverHref: var_coverHref,
+    );
+  }
+
+  @protected
+  BookPage sse_decode_book_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_books = sse_decode_list_library_book(deserializer);
+    var var_total = sse_decode_u_32(deserializer);
+    return BookPage(books: var_books, total: var_total);
+  }
+
+  @protected
+  BookQuery sse_decode_book_query(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_search = sse_decode_opt_String(deserializer);
+    var var_sort = sse_decode_book_sort(deserializer);
+    var var_offset = sse_decode_u_32(deserializer);
+    var var_limit = sse_decode_u_32(deserializer);
+    return BookQuery(
+      search: var_search,
+      sort: var_sort,
+      offset: var_offset,
+      limit: var_limit,
+    );
+  }
+
+  @protected
+  BookReadingTime sse_decode_book_reading_time(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_bookId = sse_decode_String(deserializer);
+    var var_seconds = sse_decode_u_64(deserializer);
+    var var_sessions = sse_decode_u_32(deserializer);
+    var var_lastReadAt = sse_decode_i_64(deserializer);
+    return BookReadingTime(
+      bookId: var_bookId,
+      seconds: var_seconds,
+      sessions: var_sessions,
+      lastReadAt: var_lastReadAt,
+    );
+  }
+
+  @protected
+  BookSort sse_decode_book_sort(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return BookSort.values[inner];
+  }
+
+  @protected
+  BookSource sse_decode_book_source(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_group = sse_decode_opt_String(deserializer);
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_headers = sse_decode_list_download_header(deserializer);
+    var var_searchUrl = sse_decode_opt_String(deserializer);
+    var var_search = sse_decode_search_rules(deserializer);
+    var var_bookInfo = sse_decode_book_info_rules(deserializer);
+    var var_toc = sse_decode_toc_rules(deserializer);
+    var var_content = sse_decode_content_rules(deserializer);
+    return BookSource(
+      url: var_url,
+      name: var_name,
+      group: var_group,
+      enabled: var_enabled,
+      headers: var_headers,
+      searchUrl: var_searchUrl,
+      search: var_search,
+      bookInfo: var_bookInfo,
+      toc: var_toc,
+      content: var_content,
+    );
+  }
+
+  @protected
+  BookStats sse_decode_book_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_total = sse_decode_text_stats(deserializer);
+    var var_chapters = sse_decode_list_text_stats(deserializer);
+    return BookStats(total: var_total, chapters: var_chapters);
+  }
+
+  @protected
+  BookUpdateSummary sse_decode_book_update_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_bookId = sse_decode_String(deserializer);
+    var var_status = sse_decode_update_check_status(deserializer);
+    var var_newChapters = sse_decode_u_32(deserializer);
+    var var_removedChapters = sse_decode_u_32(deserializer);
+    var var_chapterCount = sse_decode_u_32(deserializer);
+    var var_latestChapterTitle = sse_decode_opt_String(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return BookUpdateSummary(
+      bookId: var_bookId,
+      status: var_status,
+      newChapters: var_newChapters,
+      removedChapters: var_removedChapters,
+      chapterCount: var_chapterCount,
+      latestChapterTitle: var_latestChapterTitle,
+      error: var_error,
+    );
+  }
+
+  @protected
+  Bookmark sse_decode_bookmark(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_bookId = sse_decode_String(deserializer);
+    var var_chapterIndex = sse_decode_u_32(deserializer);
+    var var_chapterOffset = sse_decode_u_32(deserializer);
+    var var_excerpt = sse_decode_String(deserializer);
+    var var_note = sse_decode_opt_String(deserializer);
+    var var_createdAt = sse_decode_i_64(deserializer);
+    return Bookmark(
+      id: var_id,
+      bookId: var_bookId,
+      chapterIndex: var_chapterIndex,
+      chapterOffset: var_chapterOffset,
+      excerpt: var_excerpt,
+      note: var_note,
+      createdAt: var_createdAt,
+    );
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  AnchorResolution sse_decode_box_autoadd_anchor_resolution(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_anchor_resolution(deserializer));
+  }
+
+  @protected
+  ApiError sse_decode_box_autoadd_api_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_api_error(deserializer));
+  }
+
+  @protected
+  BandwidthPolicy sse_decode_box_autoadd_bandwidth_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bandwidth_policy(deserializer));
+  }
+
+  @protected
+  BionicOptions sse_decode_box_autoadd_bionic_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bionic_options(deserializer));
+  }
+
+  @protected
+  BookImage sse_decode_box_autoadd_book_image(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_book_image(deserializer));
+  }
+
+  @protected
+  BookMetadata sse_decode_box_autoadd_book_metadata(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_book_metadata(deserializer));
+  }
+
+  @protected
+  BookQuery sse_decode_box_autoadd_book_query(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_book_query(deserializer));
+  }
+
+  @protected
+  BookSource sse_decode_box_autoadd_book_source(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_book_source(deserializer));
+  }
+
+  @protected
+  CachedAudio sse_decode_box_autoadd_cached_audio(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cached_audio(deserializer));
+  }
+
+  @protected
+  CalibreMetadata sse_decode_box_autoadd_calibre_metadata(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_calibre_metadata(deserializer));
+  }
+
+  @protected
+  ChapterFingerprint sse_decode_box_autoadd_chapter_fingerprint(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_chapter_fingerprint(deserializer));
+  }
+
+  @protected
+  CoverImageOptions sse_decode_box_autoadd_cover_image_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cover_image_options(deserializer));
+  }
+
+  @protected
+  CoverStyle sse_decode_box_autoadd_cover_style(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cover_style(deserializer));
+  }
+
+  @protected
+  DarkImageOptions sse_decode_box_autoadd_dark_image_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_dark_image_options(deserializer));
+  }
+
+  @protected
+  DedupOptions sse_decode_box_autoadd_dedup_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_dedup_options(deserializer));
+  }
+
+  @protected
+  DownloadChecksum sse_decode_box_autoadd_download_checksum(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_download_checksum(deserializer));
+  }
+
+  @protected
+  DownloadRequest sse_decode_box_autoadd_download_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_download_request(deserializer));
+  }
+
+  @protected
+  DownloaderConfig sse_decode_box_autoadd_downloader_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_downloader_config(deserializer));
+  }
+
+  @protected
+  DrmScheme sse_decode_box_autoadd_drm_scheme(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_drm_scheme(deserializer));
+  }
+
+  @protected
+  EpubSplitOptions sse_decode_box_autoadd_epub_split_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_epub_split_options(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_64(deserializer));
+  }
+
+  @protected
+  HttpCacheEntry sse_decode_box_autoadd_http_cache_entry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_http_cache_entry(deserializer));
+  }
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_64(deserializer));
+  }
+
+  @protected
+  ImportKind sse_decode_box_autoadd_import_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_import_kind(deserializer));
+  }
+
+  @protected
+  ImportOptions sse_decode_box_autoadd_import_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_import_options(deserializer));
+  }
+
+  @protected
+  ImportReport sse_decode_box_autoadd_import_report(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_import_report(deserializer));
+  }
+
+  @protected
+  KinsokuRules sse_decode_box_autoadd_kinsoku_rules(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_kinsoku_rules(deserializer));
+  }
+
+  @protected
+  LibraryBook sse_decode_box_autoadd_library_book(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_library_book(deserializer));
+  }
+
+  @protected
+  LoggingConfig sse_decode_box_autoadd_logging_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_logging_config(deserializer));
+  }
+
+  @protected
+  MetadataGuess sse_decode_box_autoadd_metadata_guess(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_metadata_guess(deserializer));
+  }
+
+  @protected
+  NotesExportOptions sse_decode_box_autoadd_notes_export_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_notes_export_options(deserializer));
+  }
+
+  @protected
+  OpdsCredentials sse_decode_box_autoadd_opds_credentials(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_opds_credentials(deserializer));
+  }
+
+  @protected
+  PageLayout sse_decode_box_autoadd_page_layout(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_page_layout(deserializer));
+  }
+
+  @protected
+  PrefetchConfig sse_decode_box_autoadd_prefetch_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_prefetch_config(deserializer));
+  }
+
+  @protected
+  PrefetchPause sse_decode_box_autoadd_prefetch_pause(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_prefetch_pause(deserializer));
+  }
+
+  @protected
+  PrefetchPlan sse_decode_box_autoadd_prefetch_plan(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_prefetch_plan(deserializer));
+  }
+
+  @protected
+  ProcessedBook sse_decode_box_autoadd_processed_book(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_processed_book(deserializer));
+  }
+
+  @protected
+  ProxyConfig sse_decode_box_autoadd_proxy_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_proxy_config(deserializer));
+  }
+
+  @protected
+  PurifyRule sse_decode_box_autoadd_purify_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_purify_rule(deserializer));
+  }
+
+  @protected
+  RateLimit sse_decode_box_autoadd_rate_limit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_rate_limit(deserializer));
+  }
+
+  @protected
+  ReadingProgress sse_decode_box_autoadd_reading_progress(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_reading_progress(deserializer));
+  }
+
+  @protected
+  SsmlOptions sse_decode_box_autoadd_ssml_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_ssml_options(deserializer));
+  }
+
+  @protected
+  SummaryOptions sse_decode_box_autoadd_summary_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_summary_options(deserializer));
+  }
+
+  @protected
+  SyncState sse_decode_box_autoadd_sync_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_sync_state(deserializer));
+  }
+
+  @protected
+  TextAnchor sse_decode_box_autoadd_text_anchor(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_text_anchor(deserializer));
+  }
+
+  @protected
+  TextSegment sse_decode_box_autoadd_text_segment(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_text_segment(deserializer));
+  }
+
+  @protected
+  TranslatorConfig sse_decode_box_autoadd_translator_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_translator_config(deserializer));
+  }
+
+  @protected
+  TtsOptions sse_decode_box_autoadd_tts_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_tts_options(deserializer));
+  }
+
+  @protected
+  TtsPosition sse_decode_box_autoadd_tts_position(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_tts_position(deserializer));
+  }
+
+  @protected
+  TxtChapter sse_decode_box_autoadd_txt_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_txt_chapter(deserializer));
+  }
+
+  @protected
+  TxtChapterRule sse_decode_box_autoadd_txt_chapter_rule(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_txt_chapter_rule(deserializer));
+  }
+
+  @protected
+  TypographyOptions sse_decode_box_autoadd_typography_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_typography_options(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  WebDavConfig sse_decode_box_autoadd_web_dav_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_web_dav_config(deserializer));
+  }
+
+  @protected
+  WebDavSyncRecord sse_decode_box_autoadd_web_dav_sync_record(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_web_dav_sync_record(deserializer));
+  }
+
+  @protected
+  WebServerConfig sse_decode_box_autoadd_web_server_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_web_server_config(deserializer));
+  }
+
+  @protected
+  CachedAudio sse_decode_cached_audio(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_format = sse_decode_audio_format(deserializer);
+    var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+    return CachedAudio(format: var_format, data: var_data);
+  }
+
+  @protected
+  CalibreBook sse_decode_calibre_book(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_dir = sse_decode_String(deserializer);
+    var var_metadata = sse_decode_calibre_metadata(deserializer);
+    var var_coverPath = sse_decode_opt_String(deserializer);
+    var var_formats = sse_decode_list_calibre_format(deserializer);
+    return CalibreBook(
+      dir: var_dir,
+      metadata: var_metadata,
+      coverPath: var_coverPath,
+      formats: var_formats,
+    );
+  }
+
+  @protected
+  CalibreFormat sse_decode_calibre_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_kind = sse_decode_import_kind(deserializer);
+    return CalibreFormat(path: var_path, kind: var_kind);
+  }
+
+  @protected
+  CalibreIdentifier sse_decode_calibre_identifier(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_scheme = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return CalibreIdentifier(scheme: var_scheme, value: var_value);
+  }
+
+  @protected
+  CalibreMetadata sse_decode_calibre_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_titleSort = sse_decode_opt_String(deserializer);
+    var var_authors = sse_decode_list_String(deserializer);
+    var var_authorSort = sse_decode_opt_String(deserializer);
+    var var_series = sse_decode_opt_String(deserializer);
+    var var_seriesIndex = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_tags = sse_decode_list_String(deserializer);
+    var var_publisher = sse_decode_opt_String(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    var var_language = sse_decode_opt_String(deserializer);
+    var var_published = sse_decode_opt_String(deserializer);
+    var var_rating = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_identifiers = sse_decode_list_calibre_identifier(deserializer);
+    return CalibreMetadata(
+      title: var_title,
+      titleSort: var_titleSort,
+      authors: var_authors,
+      authorSort: var_authorSort,
+      series: var_series,
+      seriesIndex: var_seriesIndex,
+      tags: var_tags,
+      publisher: var_publisher,
+      description: var_description,
+      language: var_language,
+      published: var_published,
+      rating: var_rating,
+      identifiers: var_identifiers,
+    );
+  }
+
+  @protected
+  ChapterAudio sse_decode_chapter_audio(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_format = sse_decode_audio_format(deserializer);
+    var var_durationMs = sse_decode_u_64(deserializer);
+    var var_segments = sse_decode_list_audio_segment(deserializer);
+    var var_missing = sse_decode_list_String(deserializer);
+    return ChapterAudio(
+      format: var_format,
+      durationMs: var_durationMs,
+      segments: var_segments,
+      missing: var_missing,
+    );
+  }
+
+  @protected
+  ChapterCacheMigration sse_decode_chapter_cache_migration(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_converted = sse_decode_u_32(deserializer);
+    var var_unchanged = sse_decode_u_32(deserializer);
+    var var_removed = sse_decode_u_32(deserializer);
+    return ChapterCacheMigration(
+      converted: var_converted,
+      unchanged: var_unchanged,
+      removed: var_removed,
+    );
+  }
+
+  @protected
+  ChapterChange sse_decode_chapter_change(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ChapterChange.values[inner];
+  }
+
+  @protected
+  ChapterDiff sse_decode_chapter_diff(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_similarity = sse_decode_f_32(deserializer);
+    var var_unchangedParagraphs = sse_decode_u_32(deserializer);
+    var var_hunks = sse_decode_list_diff_hunk(deserializer);
+    return ChapterDiff(
+      similarity: var_similarity,
+      unchangedParagraphs: var_unchangedParagraphs,
+      hunks: var_hunks,
+    );
+  }
+
+  @protected
+  ChapterFingerprint sse_decode_chapter_fingerprint(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_hash = sse_decode_String(deserializer);
+    var var_simhash = sse_decode_u_64(deserializer);
+    var var_chars = sse_decode_u_32(deserializer);
+    return ChapterFingerprint(
+      hash: var_hash,
+      simhash: var_simhash,
+      chars: var_chars,
+    );
+  }
+
+  @protected
+  ChapterImage sse_decode_chapter_image(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_href = sse_decode_String(deserializer);
+    var var_alt = sse_decode_opt_String(deserializer);
+    return ChapterImage(href: var_href, alt: var_alt);
+  }
+
+  @protected
+  ChapterRange sse_decode_chapter_range(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    return ChapterRange(start: var_start, end: var_end);
+  }
+
+  @protected
+  ChapterStoreStats sse_decode_chapter_store_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_chapterCount = sse_decode_u_32(deserializer);
+    var var_textBytes = sse_decode_u_64(deserializer);
+    var var_storedBytes = sse_decode_u_64(deserializer);
+    var var_garbageBytes = sse_decode_u_64(deserializer);
+    var var_segmentCount = sse_decode_u_32(deserializer);
+    var var_hasDictionary = sse_decode_bool(deserializer);
+    return ChapterStoreStats(
+      chapterCount: var_chapterCount,
+      textBytes: var_textBytes,
+      storedBytes: var_storedBytes,
+      garbageBytes: var_garbageBytes,
+      segmentCount: var_segmentCount,
+      hasDictionary: var_hasDictionary,
+    );
+  }
+
+  @protected
+  ChapterText sse_decode_chapter_text(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_text = sse_decode_String(deserializer);
+    var var_imageOffsets = sse_decode_list_prim_u_32_strict(deserializer);
+    return ChapterText(text: var_text, imageOffsets: var_imageOffsets);
+  }
+
+  @protected
+  ChapterUpdate sse_decode_chapter_update(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_chapterIndex = sse_decode_u_32(deserializer);
+    var var_change = sse_decode_chapter_change(deserializer);
+    var var_similarity = sse_decode_f_32(deserializer);
+    return ChapterUpdate(
+      chapterIndex: var_chapterIndex,
+      change: var_change,
+      similarity: var_similarity,
+    );
+  }
+
+  @protected
+  ChapterUpdateReport sse_decode_chapter_update_report(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_newChapters = sse_decode_list_prim_u_32_strict(deserializer);
+    var var_chapters = sse_decode_list_chapter_update(deserializer);
+    return ChapterUpdateReport(
+      newChapters: var_newChapters,
+      chapters: var_chapters,
+    );
+  }
+
+  @protected
+  ChecksumAlgorithm sse_decode_checksum_algorithm(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ChecksumAlgorithm.values[inner];
+  }
+
+  @protected
+  ChineseConversion sse_decode_chinese_conversion(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ChineseConversion.values[inner];
+  }
+
+  @protected
+  CloudConfig sse_decode_cloud_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_issuedAt = sse_decode_i_64(deserializer);
+    var var_expiresAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_complianceMode = sse_decode_bool(deserializer);
+    var var_disabledFeatures = sse_decode_list_String(deserializer);
+    var var_minAppVersion = sse_decode_opt_String(deserializer);
+    var var_announcement = sse_decode_opt_String(deserializer);
+    var var_values = sse_decode_list_cloud_config_value(deserializer);
+    return CloudConfig(
+      issuedAt: var_issuedAt,
+      expiresAt: var_expiresAt,
+      complianceMode: var_complianceMode,
+      disabledFeatures: var_disabledFeatures,
+      minAppVersion: var_minAppVersion,
+      announcement: var_announcement,
+      values: var_values,
+    );
+  }
+
+  @protected
+  CloudConfigValue sse_decode_cloud_config_value(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return CloudConfigValue(key: var_key, value: var_value);
+  }
+
+  @protected
+  CollectionFace sse_decode_collection_face(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_index = sse_decode_u_32(deserializer);
+    var var_familyName = sse_decode_String(deserializer);
+    var var_styleName = sse_decode_String(deserializer);
+    var var_postscriptName = sse_decode_opt_String(deserializer);
+    return CollectionFace(
+      index: var_index,
+      familyName: var_familyName,
+      styleName: var_styleName,
+      postscriptName: var_postscriptName,
+    );
+  }
+
+  @protected
+  ComicPage sse_decode_comic_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_index = sse_decode_u_32(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_size = sse_decode_u_32(deserializer);
+    return ComicPage(index: var_index, name: var_name, size: var_size);
+  }
+
+  @protected
+  ContentRules sse_decode_content_rules(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_content = sse_decode_opt_String(deserializer);
+    var var_nextContentUrl = sse_decode_opt_String(deserializer);
+    var var_replaceRegex = sse_decode_opt_String(deserializer);
+    return ContentRules(
+      content: var_content,
+      nextContentUrl: var_nextContentUrl,
+      replaceRegex: var_replaceRegex,
+    );
+  }
+
+  @protected
+  ConversionResult sse_decode_conversion_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_ttf = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return ConversionResult(name: var_name, ttf: var_ttf, error: var_error);
+  }
+
+  @protected
+  CoverImageOptions sse_decode_cover_image_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxWidth = sse_decode_u_32(deserializer);
+    var var_maxHeight = sse_decode_u_32(deserializer);
+    var var_aspectRatio = sse_decode_f_32(deserializer);
+    var var_quality = sse_decode_f_32(deserializer);
+    return CoverImageOptions(
+      maxWidth: var_maxWidth,
+      maxHeight: var_maxHeight,
+      aspectRatio: var_aspectRatio,
+      quality: var_quality,
+    );
+  }
+
+  @protected
+  CoverPalette sse_decode_cover_palette(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_dominant = sse_decode_u_32(deserializer);
+    var var_dark = sse_decode_u_32(deserializer);
+    var var_light = sse_decode_u_32(deserializer);
+    var var_swatches = sse_decode_list_palette_swatch(deserializer);
+    var var_lightTheme = sse_decode_theme_colors(deserializer);
+    var var_darkTheme = sse_decode_theme_colors(deserializer);
+    return CoverPalette(
+      dominant: var_dominant,
+      dark: var_dark,
+      light: var_light,
+      swatches: var_swatches,
+      lightTheme: var_lightTheme,
+      darkTheme: var_darkTheme,
+    );
+  }
+
+  @protected
+  CoverPlaceholder sse_decode_cover_placeholder(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_blurhash = sse_decode_String(deserializer);
+    var var_thumbhash = sse_decode_String(deserializer);
+    var var_thumbnail = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_thumbnailWidth = sse_decode_u_32(deserializer);
+    var var_thumbnailHeight = sse_decode_u_32(deserializer);
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    return CoverPlaceholder(
+      blurhash: var_blurhash,
+      thumbhash: var_thumbhash,
+      thumbnail: var_thumbnail,
+      thumbnailWidth: var_thumbnailWidth,
+      thumbnailHeight: var_thumbnailHeight,
+      width: var_width,
+      height: var_height,
+    );
+  }
+
+  @protected
+  CoverStyle sse_decode_cover_style(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_background = sse_decode_u_32(deserializer);
+    var var_gradientEnd = sse_decode_u_32(deserializer);
+    var var_textColor = sse_decode_u_32(deserializer);
+    var var_titleSize = sse_decode_f_32(deserializer);
+    var var_authorSize = sse_decode_f_32(deserializer);
+    var var_margin = sse_decode_f_32(deserializer);
+    return CoverStyle(
+      width: var_width,
+      height: var_height,
+      background: var_background,
+      gradientEnd: var_gradientEnd,
+      textColor: var_textColor,
+      titleSize: var_titleSize,
+      authorSize: var_authorSize,
+      margin: var_margin,
+    );
+  }
+
+  @protected
+  CoverageReport sse_decode_coverage_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_totalCodepoints = sse_decode_u_32(deserializer);
+    var var_missingCodepoints = sse_decode_list_prim_u_32_strict(deserializer);
+    var var_coverage = sse_decode_f_64(deserializer);
+    return CoverageReport(
+      totalCodepoints: var_totalCodepoints,
+      missingCodepoints: var_missingCodepoints,
+      coverage: var_coverage,
+    );
+  }
+
+  @protected
+  DarkImage sse_decode_dark_image(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_image = sse_decode_book_image(deserializer);
+    var var_mode = sse_decode_dark_image_mode(deserializer);
+    var var_backgroundShare = sse_decode_f_32(deserializer);
+    var var_lineArt = sse_decode_bool(deserializer);
+    return DarkImage(
+      image: var_image,
+      mode: var_mode,
+      backgroundShare: var_backgroundShare,
+      lineArt: var_lineArt,
+    );
+  }
+
+  @protected
+  DarkImageMode sse_decode_dark_image_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DarkImageMode.values[inner];
+  }
+
+  @protected
+  DarkImageOptions sse_decode_dark_image_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mode = sse_decode_dark_image_mode(deserializer);
+    var var_background = sse_decode_u_32(deserializer);
+    var var_foreground = sse_decode_u_32(deserializer);
+    var var_whiteLevel = sse_decode_u_8(deserializer);
+    var var_minBackgroundShare = sse_decode_f_32(deserializer);
+    var var_brightness = sse_decode_f_32(deserializer);
+    return DarkImageOptions(
+      mode: var_mode,
+      background: var_background,
+      foreground: var_foreground,
+      whiteLevel: var_whiteLevel,
+      minBackgroundShare: var_minBackgroundShare,
+      brightness: var_brightness,
+    );
+  }
+
+  @protected
+  DecodedText sse_decode_decoded_text(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_text = sse_decode_String(deserializer);
+    var var_encoding = sse_decode_String(deserializer);
+    var var_confidence = sse_decode_f_32(deserializer);
+    var var_hadErrors = sse_decode_bool(deserializer);
+    var var_candidates = sse_decode_list_encoding_candidate(deserializer);
+    return DecodedText(
+      text: var_text,
+      encoding: var_encoding,
+      confidence: var_confidence,
+      hadErrors: var_hadErrors,
+      candidates: var_candidates,
+    );
+  }
+
+  @protected
+  DedupOptions sse_decode_dedup_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_minScore = sse_decode_f_32(deserializer);
+    var var_compareContent = sse_decode_bool(deserializer);
+    var var_aliases = sse_decode_list_book_alias(deserializer);
+    return DedupOptions(
+      minScore: var_minScore,
+      compareContent: var_compareContent,
+      aliases: var_aliases,
+    );
+  }
+
+  @protected
+  DictDefinition sse_decode_dict_definition(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_dictId = sse_decode_u_32(deserializer);
+    var var_dictTitle = sse_decode_String(deserializer);
+    var var_headword = sse_decode_String(deserializer);
+    var var_html = sse_decode_String(deserializer);
+    return DictDefinition(
+      dictId: var_dictId,
+      dictTitle: var_dictTitle,
+      headword: var_headword,
+      html: var_html,
+    );
+  }
+
+  @protected
+  DictFormat sse_decode_dict_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DictFormat.values[inner];
+  }
+
+  @protected
+  DictInfo sse_decode_dict_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_u_32(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_format = sse_decode_dict_format(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_description = sse_decode_String(deserializer);
+    var var_entryCount = sse_decode_u_32(deserializer);
+    var var_resourceFiles = sse_decode_u_32(deserializer);
+    return DictInfo(
+      id: var_id,
+      path: var_path,
+      format: var_format,
+      title: var_title,
+      description: var_description,
+      entryCount: var_entryCount,
+      resourceFiles: var_resourceFiles,
+    );
+  }
+
+  @protected
+  DiffHunk sse_decode_diff_hunk(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_diff_hunk_kind(deserializer);
+    var var_oldIndex = sse_decode_u_32(deserializer);
+    var var_newIndex = sse_decode_u_32(deserializer);
+    var var_oldText = sse_decode_String(deserializer);
+    var var_newText = sse_decode_String(deserializer);
+    return DiffHunk(
+      kind: var_kind,
+      oldIndex: var_oldIndex,
+      newIndex: var_newIndex,
+      oldText: var_oldText,
+      newText: var_newText,
+    );
+  }
+
+  @protected
+  DiffHunkKind sse_decode_diff_hunk_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DiffHunkKind.values[inner];
+  }
+
+  @protected
+  DownloadChecksum sse_decode_download_checksum(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_algorithm = sse_decode_checksum_algorithm(deserializer);
+    var var_digest = sse_decode_String(deserializer);
+    var var_partSize = sse_decode_u_64(deserializer);
+    var var_partDigests = sse_decode_list_String(deserializer);
+    return DownloadChecksum(
+      algorithm: var_algorithm,
+      digest: var_digest,
+      partSize: var_partSize,
+      partDigests: var_partDigests,
+    );
+  }
+
+  @protected
+  DownloadEvent sse_decode_download_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_taskId = sse_decode_u_32(deserializer);
+    var var_state = sse_decode_download_state(deserializer);
+    var var_downloaded = sse_decode_u_64(deserializer);
+    var var_total = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_attempt = sse_decode_u_32(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return DownloadEvent(
+      taskId: var_taskId,
+      state: var_state,
+      downloaded: var_downloaded,
+      total: var_total,
+      attempt: var_attempt,
+      error: var_error,
+    );
+  }
+
+  @protected
+  DownloadHeader sse_decode_download_header(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return DownloadHeader(name: var_name, value: var_value);
+  }
+
+  @protected
+  DownloadRequest sse_decode_download_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_destination = sse_decode_String(deserializer);
+    var var_headers = sse_decode_list_download_header(deserializer);
+    var var_checksum = sse_decode_opt_box_autoadd_download_checksum(
+      deserializer,
+    );
+    return DownloadRequest(
+      url: var_url,
+      destination: var_destination,
+      headers: var_headers,
+      checksum: var_checksum,
+    );
+  }
+
+  @protected
+  DownloadState sse_decode_download_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DownloadState.values[inner];
+  }
+
+  @protected
+  DownloaderConfig sse_decode_downloader_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxConcurrent = sse_decode_u_32(deserializer);
+    var var_maxPerHost = sse_decode_u_32(deserializer);
+    var var_maxRetries = sse_decode_u_32(deserializer);
+    var var_initialBackoffMs = sse_decode_u_32(deserializer);
+    var var_timeoutSecs = sse_decode_u_32(deserializer);
+    var var_userAgent = sse_decode_String(deserializer);
+    return DownloaderConfig(
+      maxConcurrent: var_maxConcurrent,
+      maxPerHost: var_maxPerHost,
+      maxRetries: var_maxRetries,
+      initialBackoffMs: var_initialBackoffMs,
+      timeoutSecs: var_timeoutSecs,
+      userAgent: var_userAgent,
+    );
+  }
+
+  @protected
+  DrmScheme sse_decode_drm_scheme(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DrmScheme.values[inner];
+  }
+
+  @protected
+  DrmStatus sse_decode_drm_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_format = sse_decode_book_format(deserializer);
+    var var_scheme = sse_decode_opt_box_autoadd_drm_scheme(deserializer);
+    return DrmStatus(format: var_format, scheme: var_scheme);
+  }
+
+  @protected
+  DuplicateCluster sse_decode_duplicate_cluster(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_books = sse_decode_list_library_book(deserializer);
+    var var_score = sse_decode_f_32(deserializer);
+    var var_contentMatch = sse_decode_bool(deserializer);
+    return DuplicateCluster(
+      books: var_books,
+      score: var_score,
+      contentMatch: var_contentMatch,
+    );
+  }
+
+  @protected
+  DuplicateMatch sse_decode_duplicate_match(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_book = sse_decode_library_book(deserializer);
+    var var_score = sse_decode_f_32(deserializer);
+    return DuplicateMatch(book: var_book, score: var_score);
+  }
+
+  @protected
+  EmphasisSpan sse_decode_emphasis_span(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    return EmphasisSpan(start: var_start, end: var_end);
+  }
+
+  @protected
+  EncodingCandidate sse_decode_encoding_candidate(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_encoding = sse_decode_String(deserializer);
+    var var_confidence = sse_decode_f_32(deserializer);
+    return EncodingCandidate(
+      encoding: var_encoding,
+      confidence: var_confidence,
+    );
+  }
+
+  @protected
+  EpubExportChapter sse_decode_epub_export_chapter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_String(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    return EpubExportChapter(title: var_title, text: var_text);
+  }
+
+  @protected
+  EpubExportProgress sse_decode_epub_export_progress(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_writtenChapters = sse_decode_u_32(deserializer);
+    var var_totalChapters = sse_decode_u_32(deserializer);
+    return EpubExportProgress(
+      writtenChapters: var_writtenChapters,
+      totalChapters: var_totalChapters,
+    );
+  }
+
+  @protected
+  EpubLayout sse_decode_epub_layout(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return EpubLayout.values[inner];
+  }
+
+  @protected
+  EpubMergeSource sse_decode_epub_merge_source(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    return EpubMergeSource(path: var_path, title: var_title);
+  }
+
+  @protected
+  EpubResource sse_decode_epub_resource(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_href = sse_decode_String(deserializer);
+    var var_mediaType = sse_decode_String(deserializer);
+    var var_kind = sse_decode_resource_kind(deserializer);
+    var var_size = sse_decode_u_32(deserializer);
+    return EpubResource(
+      href: var_href,
+      mediaType: var_mediaType,
+      kind: var_kind,
+      size: var_size,
+    );
+  }
+
+  @protected
+  EpubSplitOptions sse_decode_epub_split_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_tocDepth = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_maxVolumeBytes = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return EpubSplitOptions(
+      tocDepth: var_tocDepth,
+      maxVolumeBytes: var_maxVolumeBytes,
+    );
+  }
+
+  @protected
+  EpubVolume sse_decode_epub_volume(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_chapterCount = sse_decode_u_32(deserializer);
+    return EpubVolume(
+      path: var_path,
+      title: var_title,
+      chapterCount: var_chapterCount,
+    );
+  }
+
+  @protected
+  ErrorCode sse_decode_error_code(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ErrorCode.values[inner];
+  }
+
+  @protected
+  ExtractedFile sse_decode_extracted_file(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_index = sse_decode_u_32(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_kind = sse_decode_opt_box_autoadd_import_kind(deserializer);
+    var var_path = sse_decode_opt_String(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return ExtractedFile(
+      index: var_index,
+      name: var_name,
+      kind: var_kind,
+      path: var_path,
+      error: var_error,
+    );
+  }
+
+  @protected
+  Extraction sse_decode_extraction(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_matched = sse_decode_list_String(deserializer);
+    var var_values = sse_decode_list_String(deserializer);
+    return Extraction(matched: var_matched, values: var_values);
+  }
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat32();
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  Feed sse_decode_feed(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_String(deserializer);
+    var var_link = sse_decode_opt_String(deserializer);
+    var var_entries = sse_decode_list_feed_entry(deserializer);
+    return Feed(title: var_title, link: var_link, entries: var_entries);
+  }
+
+  @protected
+  FeedEntry sse_decode_feed_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_link = sse_decode_opt_String(deserializer);
+    var var_published = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_summary = sse_decode_opt_String(deserializer);
+    var var_author = sse_decode_opt_String(deserializer);
+    return FeedEntry(
+      id: var_id,
+      title: var_title,
+      link: var_link,
+      published: var_published,
+      summary: var_summary,
+      author: var_author,
+    );
+  }
+
+  @protected
+  FetchedChapter sse_decode_fetched_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_chapterIndex = sse_decode_u_32(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    return FetchedChapter(chapterIndex: var_chapterIndex, text: var_text);
+  }
+
+  @protected
+  FontAxis sse_decode_font_axis(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_tag = sse_decode_String(deserializer);
+    var var_name = sse_decode_opt_String(deserializer);
+    var var_minValue = sse_decode_f_32(deserializer);
+    var var_defaultValue = sse_decode_f_32(deserializer);
+    var var_maxValue = sse_decode_f_32(deserializer);
+    var var_hidden = sse_decode_bool(deserializer);
+    return FontAxis(
+      tag: var_tag,
+      name: var_name,
+      minValue: var_minValue,
+      defaultValue: var_defaultValue,
+      maxValue: var_maxValue,
+      hidden: var_hidden,
+    );
+  }
+
+  @protected
+  FontBundle sse_decode_font_bundle(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_codepoints = sse_decode_u_32(deserializer);
+    var var_added = sse_decode_u_32(deserializer);
+    var var_bytes = sse_decode_u_32(deserializer);
+    return FontBundle(
+      codepoints: var_codepoints,
+      added: var_added,
+      bytes: var_bytes,
+    );
+  }
+
+  @protected
+  FontCacheEntry sse_decode_font_cache_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_familyName = sse_decode_opt_String(deserializer);
+    var var_sizeBytes = sse_decode_u_32(deserializer);
+    var var_lastUsed = sse_decode_i_64(deserializer);
+    var var_bookIds = sse_decode_list_String(deserializer);
+    var var_pinned = sse_decode_bool(deserializer);
+    return FontCacheEntry(
+      id: var_id,
+      familyName: var_familyName,
+      sizeBytes: var_sizeBytes,
+      lastUsed: var_lastUsed,
+      bookIds: var_bookIds,
+      pinned: var_pinned,
+    );
+  }
+
+  @protected
+  FontCacheStats sse_decode_font_cache_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_entryCount = sse_decode_u_32(deserializer);
+    var var_totalBytes = sse_decode_u_32(deserializer);
+    var var_maxBytes = sse_decode_u_32(deserializer);
+    return FontCacheStats(
+      entryCount: var_entryCount,
+      totalBytes: var_totalBytes,
+      maxBytes: var_maxBytes,
+    );
+  }
+
+  @protected
+  FontInfo sse_decode_font_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_familyName = sse_decode_String(deserializer);
+    var var_styleName = sse_decode_String(deserializer);
+    var var_fullName = sse_decode_opt_String(deserializer);
+    var var_postscriptName = sse_decode_opt_String(deserializer);
+    var var_weight = sse_decode_u_16(deserializer);
+    var var_isItalic = sse_decode_bool(deserializer);
+    var var_isMonospaced = sse_decode_bool(deserializer);
+    var var_isVariable = sse_decode_bool(deserializer);
+    var var_axes = sse_decode_list_font_axis(deserializer);
+    var var_namedInstances = sse_decode_list_named_instance(deserializer);
+    var var_unitsPerEm = sse_decode_u_16(deserializer);
+    var var_glyphCount = sse_decode_u_16(deserializer);
+    return FontInfo(
+      familyName: var_familyName,
+      styleName: var_styleName,
+      fullName: var_fullName,
+      postscriptName: var_postscriptName,
+      weight: var_weight,
+      isItalic: var_isItalic,
+      isMonospaced: var_isMonospaced,
+      isVariable: var_isVariable,
+      axes: var_axes,
+      namedInstances: var_namedInstances,
+      unitsPerEm: var_unitsPerEm,
+      glyphCount: var_glyphCount,
+    );
+  }
+
+  @protected
+  FontPreview sse_decode_font_preview(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rgba = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_png = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_missingChars = sse_decode_u_32(deserializer);
+    return FontPreview(
+      rgba: var_rgba,
+      png: var_png,
+      width: var_width,
+      height: var_height,
+      missingChars: var_missingChars,
+    );
+  }
+
+  @protected
+  Highlight sse_decode_highlight(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_bookId = sse_decode_String(deserializer);
+    var var_chapterIndex = sse_decode_u_32(deserializer);
+    var var_startOffset = sse_decode_u_32(deserializer);
+    var var_endOffset = sse_decode_u_32(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    var var_note = sse_decode_opt_String(deserializer);
+    var var_color = sse_decode_u_32(deserializer);
+    var var_createdAt = sse_decode_i_64(deserializer);
+    return Highlight(
+      id: var_id,
+      bookId: var_bookId,
+      chapterIndex: var_chapterIndex,
+      startOffset: var_startOffset,
+      endOffset: var_endOffset,
+      text: var_text,
+      note: var_note,
+      color: var_color,
+      createdAt: var_createdAt,
+    );
+  }
+
+  @protected
+  HttpCacheEntry sse_decode_http_cache_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_contentType = sse_decode_opt_String(deserializer);
+    var var_size = sse_decode_u_32(deserializer);
+    var var_fresh = sse_decode_bool(deserializer);
+    var var_storedAt = sse_decode_i_64(deserializer);
+    return HttpCacheEntry(
+      url: var_url,
+      contentType: var_contentType,
+      size: var_size,
+      fresh: var_fresh,
+      storedAt: var_storedAt,
+    );
+  }
+
+  @protected
+  HttpCacheStats sse_decode_http_cache_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_entryCount = sse_decode_u_32(deserializer);
+    var var_totalBytes = sse_decode_u_32(deserializer);
+    var var_maxBytes = sse_decode_u_32(deserializer);
+    return HttpCacheStats(
+      entryCount: var_entryCount,
+      totalBytes: var_totalBytes,
+      maxBytes: var_maxBytes,
+    );
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  ImportEvent sse_decode_import_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_import_event_kind(deserializer);
+    var var_fileIndex = sse_decode_u_32(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_done = sse_decode_u_32(deserializer);
+    var var_total = sse_decode_u_32(deserializer);
+    var var_message = sse_decode_opt_String(deserializer);
+    var var_book = sse_decode_opt_box_autoadd_processed_book(deserializer);
+    var var_report = sse_decode_opt_box_autoadd_import_report(deserializer);
+    return ImportEvent(
+      kind: var_kind,
+      fileIndex: var_fileIndex,
+      path: var_path,
+      done: var_done,
+      total: var_total,
+      message: var_message,
+      book: var_book,
+      report: var_report,
+    );
+  }
+
+  @protected
+  ImportEventKind sse_decode_import_event_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ImportEventKind.values[inner];
+  }
+
+  @protected
+  ImportIssue sse_decode_import_issue(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return ImportIssue(path: var_path, message: var_message);
+  }
+
+  @protected
+  ImportKind sse_decode_import_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ImportKind.values[inner];
+  }
+
+  @protected
+  ImportOptions sse_decode_import_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_overrideEncoding = sse_decode_opt_String(deserializer);
+    var var_headingRules = sse_decode_opt_list_txt_chapter_rule(deserializer);
+    var var_purifyRules = sse_decode_list_purify_rule(deserializer);
+    var var_charsPerMinute = sse_decode_u_32(deserializer);
+    return ImportOptions(
+      overrideEncoding: var_overrideEncoding,
+      headingRules: var_headingRules,
+      purifyRules: var_purifyRules,
+      charsPerMinute: var_charsPerMinute,
+    );
+  }
+
+  @protected
+  ImportReport sse_decode_import_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_imported = sse_decode_u_32(deserializer);
+    var var_skipped = sse_decode_list_import_issue(deserializer);
+    var var_failed = sse_decode_list_import_issue(deserializer);
+    var var_warnings = sse_decode_list_import_issue(deserializer);
+    return ImportReport(
+      imported: var_imported,
+      skipped: var_skipped,
+      failed: var_failed,
+      warnings: var_warnings,
+    );
+  }
+
+  @protected
+  Keyword sse_decode_keyword(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_term = sse_decode_String(deserializer);
+    var var_kind = sse_decode_keyword_kind(deserializer);
+    var var_count = sse_decode_u_32(deserializer);
+    var var_score = sse_decode_f_32(deserializer);
+    return Keyword(
+      term: var_term,
+      kind: var_kind,
+      count: var_count,
+      score: var_score,
+    );
+  }
+
+  @protected
+  KeywordKind sse_decode_keyword_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return KeywordKind.values[inner];
+  }
+
+  @protected
+  KinsokuRules sse_decode_kinsoku_rules(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_noLineStart = sse_decode_String(deserializer);
+    var var_noLineEnd = sse_decode_String(deserializer);
+    return KinsokuRules(noLineStart: var_noLineStart, noLineEnd: var_noLineEnd);
+  }
+
+  @protected
+  LanguageGuess sse_decode_language_guess(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_code = sse_decode_String(deserializer);
+    var var_confidence = sse_decode_f_32(deserializer);
+    return LanguageGuess(code: var_code, confidence: var_confidence);
+  }
+
+  @protected
+  LayoutCacheStats sse_decode_layout_cache_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_entryCount = sse_decode_u_32(deserializer);
+    var var_totalBytes = sse_decode_u_32(deserializer);
+    var var_maxBytes = sse_decode_u_32(deserializer);
+    return LayoutCacheStats(
+      entryCount: var_entryCount,
+      totalBytes: var_totalBytes,
+      maxBytes: var_maxBytes,
+    );
+  }
+
+  @protected
+  LibraryBook sse_decode_library_book(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_author = sse_decode_opt_String(deserializer);
+    var var_intro = sse_decode_opt_String(deserializer);
+    var var_kind = sse_decode_opt_String(deserializer);
+    var var_coverUrl = sse_decode_opt_String(deserializer);
+    var var_sourceUrl = sse_decode_opt_String(deserializer);
+    var var_bookUrl = sse_decode_opt_String(deserializer);
+    var var_tocUrl = sse_decode_opt_String(deserializer);
+    var var_latestChapterTitle = sse_decode_opt_String(deserializer);
+    var var_chapterCount = sse_decode_u_32(deserializer);
+    var var_wordCount = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_addedAt = sse_decode_i_64(deserializer);
+    var var_updatedAt = sse_decode_i_64(deserializer);
+    var var_lastReadAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return LibraryBook(
+      id: var_id,
+      title: var_title,
+      author: var_author,
+      intro: var_intro,
+      kind: var_kind,
+      coverUrl: var_coverUrl,
+      sourceUrl: var_sourceUrl,
+      bookUrl: var_bookUrl,
+      tocUrl: var_tocUrl,
+      latestChapterTitle: var_latestChapterTitle,
+      chapterCount: var_chapterCount,
+      wordCount: var_wordCount,
+      addedAt: var_addedAt,
+      updatedAt: var_updatedAt,
+      lastReadAt: var_lastReadAt,
+    );
+  }
+
+  @protected
+  LibraryChapter sse_decode_library_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_index = sse_decode_u_32(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_url = sse_decode_opt_String(deserializer);
+    var var_isVolume = sse_decode_bool(deserializer);
+    var var_wordCount = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return LibraryChapter(
+      index: var_index,
+      title: var_title,
+      url: var_url,
+      isVolume: var_isVolume,
+      wordCount: var_wordCount,
+    );
+  }
+
+  @protected
+  LibrarySearchResult sse_decode_library_search_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_book = sse_decode_library_book(deserializer);
+    var var_score = sse_decode_f_32(deserializer);
+    var var_titleMatches = sse_decode_list_match_range(deserializer);
+    var var_authorMatches = sse_decode_list_match_range(deserializer);
+    return LibrarySearchResult(
+      book: var_book,
+      score: var_score,
+      titleMatches: var_titleMatches,
+      authorMatches: var_authorMatches,
+    );
+  }
+
+  @protected
+  LineBreak sse_decode_line_break(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_offset = sse_decode_u_32(deserializer);
+    var var_mandatory = sse_decode_bool(deserializer);
+    return LineBreak(offset: var_offset, mandatory: var_mandatory);
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AlternativeSource> sse_decode_list_alternative_source(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AlternativeSource>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_alternative_source(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ArchiveEntry> sse_decode_list_archive_entry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ArchiveEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_archive_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AudioSegment> sse_decode_list_audio_segment(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AudioSegment>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_audio_segment(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AxisValue> sse_decode_list_axis_value(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AxisValue>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_axis_value(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BookAlias> sse_decode_list_book_alias(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BookAlias>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_book_alias(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BookChapter> sse_decode_list_book_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BookChapter>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_book_chapter(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BookReadingTime> sse_decode_list_book_reading_time(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BookReadingTime>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_book_reading_time(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BookSource> sse_decode_list_book_source(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BookSource>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_book_source(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BookUpdateSummary> sse_decode_list_book_update_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BookUpdateSummary>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_book_update_summary(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Bookmark> sse_decode_list_bookmark(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Bookmark>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_bookmark(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CalibreBook> sse_decode_list_calibre_book(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CalibreBook>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_calibre_book(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CalibreFormat> sse_decode_list_calibre_format(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CalibreFormat>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_calibre_format(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CalibreIdentifier> sse_decode_list_calibre_identifier(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CalibreIdentifier>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_calibre_identifier(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ChapterImage> sse_decode_list_chapter_image(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ChapterImage>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_chapter_image(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ChapterRange> sse_decode_list_chapter_range(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ChapterRange>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_chapter_range(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ChapterUpdate> sse_decode_list_chapter_update(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ChapterUpdate>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_chapter_update(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CloudConfigValue> sse_decode_list_cloud_config_value(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CloudConfigValue>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_cloud_config_value(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CollectionFace> sse_decode_list_collection_face(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CollectionFace>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_collection_face(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ComicPage> sse_decode_list_comic_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ComicPage>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_comic_page(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ConversionResult> sse_decode_list_conversion_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ConversionResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_conversion_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DictDefinition> sse_decode_list_dict_definition(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DictDefinition>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_dict_definition(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DictInfo> sse_decode_list_dict_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DictInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_dict_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DiffHunk> sse_decode_list_diff_hunk(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DiffHunk>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_diff_hunk(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DownloadHeader> sse_decode_list_download_header(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DownloadHeader>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_download_header(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DuplicateCluster> sse_decode_list_duplicate_cluster(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DuplicateCluster>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_duplicate_cluster(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DuplicateMatch> sse_decode_list_duplicate_match(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DuplicateMatch>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_duplicate_match(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EmphasisSpan> sse_decode_list_emphasis_span(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EmphasisSpan>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_emphasis_span(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EncodingCandidate> sse_decode_list_encoding_candidate(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EncodingCandidate>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_encoding_candidate(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EpubExportChapter> sse_decode_list_epub_export_chapter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EpubExportChapter>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_epub_export_chapter(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EpubMergeSource> sse_decode_list_epub_merge_source(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EpubMergeSource>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_epub_merge_source(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EpubResource> sse_decode_list_epub_resource(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EpubResource>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_epub_resource(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EpubVolume> sse_decode_list_epub_volume(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EpubVolume>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_epub_volume(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FeedEntry> sse_decode_list_feed_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FeedEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_feed_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FetchedChapter> sse_decode_list_fetched_chapter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FetchedChapter>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_fetched_chapter(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FontAxis> sse_decode_list_font_axis(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FontAxis>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_font_axis(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FontCacheEntry> sse_decode_list_font_cache_entry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FontCacheEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_font_cache_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Highlight> sse_decode_list_highlight(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Highlight>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_highlight(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ImportIssue> sse_decode_list_import_issue(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ImportIssue>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_import_issue(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Keyword> sse_decode_list_keyword(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Keyword>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_keyword(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LanguageGuess> sse_decode_list_language_guess(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LanguageGuess>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_language_guess(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LibraryBook> sse_decode_list_library_book(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LibraryBook>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_library_book(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LibraryChapter> sse_decode_list_library_chapter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LibraryChapter>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_library_chapter(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LibrarySearchResult> sse_decode_list_library_search_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LibrarySearchResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_library_search_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LineBreak> sse_decode_list_line_break(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LineBreak>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_line_break(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LogField> sse_decode_list_log_field(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LogField>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_log_field(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LogRecord> sse_decode_list_log_record(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LogRecord>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_log_record(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MatchRange> sse_decode_list_match_range(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MatchRange>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_match_range(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NamedFont> sse_decode_list_named_font(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NamedFont>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_named_font(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NamedInstance> sse_decode_list_named_instance(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NamedInstance>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_named_instance(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<OpdsEntry> sse_decode_list_opds_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <OpdsEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_opds_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<OpdsLink> sse_decode_list_opds_link(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <OpdsLink>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_opds_link(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AnchorResolution?> sse_decode_list_opt_box_autoadd_anchor_resolution(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AnchorResolution?>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_opt_box_autoadd_anchor_resolution(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PaletteSwatch> sse_decode_list_palette_swatch(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PaletteSwatch>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_palette_swatch(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PinyinSpan> sse_decode_list_pinyin_span(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PinyinSpan>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_pinyin_span(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PostStep> sse_decode_list_post_step(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PostStep>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_post_step(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PrefetchChapter> sse_decode_list_prefetch_chapter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PrefetchChapter>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_prefetch_chapter(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PrefetchTask> sse_decode_list_prefetch_task(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PrefetchTask>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_prefetch_task(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getInt64List(len_);
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_32_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint32List(len_);
+  }
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint32List(len_);
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<PurifyRule> sse_decode_list_purify_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PurifyRule>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_purify_rule(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PurifyRuleMatch> sse_decode_list_purify_rule_match(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PurifyRuleMatch>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_purify_rule_match(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RateLimitStatus> sse_decode_list_rate_limit_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RateLimitStatus>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_rate_limit_status(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ReadableBlock> sse_decode_list_readable_block(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ReadableBlock>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_readable_block(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ReadableSpan> sse_decode_list_readable_span(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ReadableSpan>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_readable_span(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ReadingDay> sse_decode_list_reading_day(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ReadingDay>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_reading_day(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ReadingProgress> sse_decode_list_reading_progress(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ReadingProgress>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_reading_progress(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RuleTestMatch> sse_decode_list_rule_test_match(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RuleTestMatch>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_rule_test_match(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SearchChapter> sse_decode_list_search_chapter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SearchChapter>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_search_chapter(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SearchHit> sse_decode_list_search_hit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SearchHit>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_search_hit(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SourceBook> sse_decode_list_source_book(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SourceBook>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_source_book(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SourceChapter> sse_decode_list_source_chapter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SourceChapter>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_source_chapter(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<StoredBookScan> sse_decode_list_stored_book_scan(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <StoredBookScan>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_stored_book_scan(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<StoredCookie> sse_decode_list_stored_cookie(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <StoredCookie>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_stored_cookie(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TagSuggestion> sse_decode_list_tag_suggestion(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TagSuggestion>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_tag_suggestion(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TextAnchor> sse_decode_list_text_anchor(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TextAnchor>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_text_anchor(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TextColumn> sse_decode_list_text_column(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TextColumn>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_text_column(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TextLine> sse_decode_list_text_line(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TextLine>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_text_line(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TextPage> sse_decode_list_text_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TextPage>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_text_page(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TextSegment> sse_decode_list_text_segment(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TextSegment>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_text_segment(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TextStats> sse_decode_list_text_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TextStats>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_text_stats(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TocEntry> sse_decode_list_toc_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TocEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_toc_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TranslatedParagraph> sse_decode_list_translated_paragraph(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TranslatedParagraph>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_translated_paragraph(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TxtChapter> sse_decode_list_txt_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TxtChapter>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_txt_chapter(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TxtChapterRule> sse_decode_list_txt_chapter_rule(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TxtChapterRule>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_txt_chapter_rule(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TxtHeadingCandidate> sse_decode_list_txt_heading_candidate(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TxtHeadingCandidate>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_txt_heading_candidate(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<UpdateCheckBook> sse_decode_list_update_check_book(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <UpdateCheckBook>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_update_check_book(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Utterance> sse_decode_list_utterance(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Utterance>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_utterance(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ValidationIssue> sse_decode_list_validation_issue(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ValidationIssue>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_validation_issue(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<VerticalGlyph> sse_decode_list_vertical_glyph(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <VerticalGlyph>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_vertical_glyph(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<VerticalPage> sse_decode_list_vertical_page(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <VerticalPage>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_vertical_page(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WatermarkChar> sse_decode_list_watermark_char(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WatermarkChar>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_watermark_char(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WebChapter> sse_decode_list_web_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WebChapter>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_web_chapter(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WebDavEntry> sse_decode_list_web_dav_entry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WebDavEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_web_dav_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  LogField sse_decode_log_field(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return LogField(key: var_key, value: var_value);
+  }
+
+  @protected
+  LogLevel sse_decode_log_level(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return LogLevel.values[inner];
+  }
+
+  @protected
+  LogRecord sse_decode_log_record(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_timeMs = sse_decode_i_64(deserializer);
+    var var_level = sse_decode_log_level(deserializer);
+    var var_target = sse_decode_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_fields = sse_decode_list_log_field(deserializer);
+    return LogRecord(
+      timeMs: var_timeMs,
+      level: var_level,
+      target: var_target,
+      message: var_message,
+      fields: var_fields,
+    );
+  }
+
+  @protected
+  LoggingConfig sse_decode_logging_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_capacity = sse_decode_u_32(deserializer);
+    var var_minLevel = sse_decode_log_level(deserializer);
+    var var_directory = sse_decode_opt_String(deserializer);
+    var var_maxFileBytes = sse_decode_u_64(deserializer);
+    var var_maxFiles = sse_decode_u_32(deserializer);
+    return LoggingConfig(
+      capacity: var_capacity,
+      minLevel: var_minLevel,
+      directory: var_directory,
+      maxFileBytes: var_maxFileBytes,
+      maxFiles: var_maxFiles,
+    );
+  }
+
+  @protected
+  MappedTextSlice sse_decode_mapped_text_slice(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_text = sse_decode_String(deserializer);
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    return MappedTextSlice(text: var_text, start: var_start, end: var_end);
+  }
+
+  @protected
+  MappedTxtInfo sse_decode_mapped_txt_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_u_32(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_index = sse_decode_txt_index(deserializer);
+    return MappedTxtInfo(id: var_id, path: var_path, index: var_index);
+  }
+
+  @protected
+  MatchRange sse_decode_match_range(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    return MatchRange(start: var_start, end: var_end);
+  }
+
+  @protected
+  MetadataGuess sse_decode_metadata_guess(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_value = sse_decode_String(deserializer);
+    var var_confidence = sse_decode_f_32(deserializer);
+    var var_origin = sse_decode_metadata_origin(deserializer);
+    return MetadataGuess(
+      value: var_value,
+      confidence: var_confidence,
+      origin: var_origin,
+    );
+  }
+
+  @protected
+  MetadataOrigin sse_decode_metadata_origin(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return MetadataOrigin.values[inner];
+  }
+
+  @protected
+  NamedFont sse_decode_named_font(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+    return NamedFont(name: var_name, data: var_data);
+  }
+
+  @protected
+  NamedInstance sse_decode_named_instance(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_opt_String(deserializer);
+    var var_postscriptName = sse_decode_opt_String(deserializer);
+    var var_coordinates = sse_decode_list_axis_value(deserializer);
+    return NamedInstance(
+      name: var_name,
+      postscriptName: var_postscriptName,
+      coordinates: var_coordinates,
+    );
+  }
+
+  @protected
+  NetworkType sse_decode_network_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return NetworkType.values[inner];
+  }
+
+  @protected
+  NotesExportOptions sse_decode_notes_export_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_format = sse_decode_notes_format(deserializer);
+    var var_includeBookmarks = sse_decode_bool(deserializer);
+    var var_utcOffsetMinutes = sse_decode_i_32(deserializer);
+    return NotesExportOptions(
+      format: var_format,
+      includeBookmarks: var_includeBookmarks,
+      utcOffsetMinutes: var_utcOffsetMinutes,
+    );
+  }
+
+  @protected
+  NotesFormat sse_decode_notes_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return NotesFormat.values[inner];
+  }
+
+  @protected
+  OpdsCredentials sse_decode_opds_credentials(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_username = sse_decode_String(deserializer);
+    var var_password = sse_decode_String(deserializer);
+    return OpdsCredentials(username: var_username, password: var_password);
+  }
+
+  @protected
+  OpdsEntry sse_decode_opds_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_opt_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_authors = sse_decode_list_String(deserializer);
+    var var_summary = sse_decode_opt_String(deserializer);
+    var var_coverUrl = sse_decode_opt_String(deserializer);
+    var var_thumbnailUrl = sse_decode_opt_String(deserializer);
+    var var_navigationUrl = sse_decode_opt_String(deserializer);
+    var var_acquisitions = sse_decode_list_opds_link(deserializer);
+    return OpdsEntry(
+      id: var_id,
+      title: var_title,
+      authors: var_authors,
+      summary: var_summary,
+      coverUrl: var_coverUrl,
+      thumbnailUrl: var_thumbnailUrl,
+      navigationUrl: var_navigationUrl,
+      acquisitions: var_acquisitions,
+    );
+  }
+
+  @protected
+  OpdsFeed sse_decode_opds_feed(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_String(deserializer);
+    var var_entries = sse_decode_list_opds_entry(deserializer);
+    var var_startUrl = sse_decode_opt_String(deserializer);
+    var var_nextUrl = sse_decode_opt_String(deserializer);
+    var var_previousUrl = sse_decode_opt_String(deserializer);
+    var var_searchTemplate = sse_decode_opt_String(deserializer);
+    return OpdsFeed(
+      title: var_title,
+      entries: var_entries,
+      startUrl: var_startUrl,
+      nextUrl: var_nextUrl,
+      previousUrl: var_previousUrl,
+      searchTemplate: var_searchTemplate,
+    );
+  }
+
+  @protected
+  OpdsLink sse_decode_opds_link(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_href = sse_decode_String(deserializer);
+    var var_rel = sse_decode_opt_String(deserializer);
+    var var_mediaType = sse_decode_opt_String(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    return OpdsLink(
+      href: var_href,
+      rel: var_rel,
+      mediaType: var_mediaType,
+      title: var_title,
+    );
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AnchorResolution? sse_decode_opt_box_autoadd_anchor_resolution(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_anchor_resolution(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ApiError? sse_decode_opt_box_autoadd_api_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_api_error(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  CachedAudio? sse_decode_opt_box_autoadd_cached_audio(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_cached_audio(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ChapterFingerprint? sse_decode_opt_box_autoadd_chapter_fingerprint(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_chapter_fingerprint(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DownloadChecksum? sse_decode_opt_box_autoadd_download_checksum(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_download_checksum(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DrmScheme? sse_decode_opt_box_autoadd_drm_scheme(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_drm_scheme(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  HttpCacheEntry? sse_decode_opt_box_autoadd_http_cache_entry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_http_cache_entry(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ImportKind? sse_decode_opt_box_autoadd_import_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_import_kind(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ImportReport? sse_decode_opt_box_autoadd_import_report(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_import_report(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  KinsokuRules? sse_decode_opt_box_autoadd_kinsoku_rules(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_kinsoku_rules(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  LibraryBook? sse_decode_opt_box_autoadd_library_book(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_library_book(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  MetadataGuess? sse_decode_opt_box_autoadd_metadata_guess(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_metadata_guess(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  OpdsCredentials? sse_decode_opt_box_autoadd_opds_credentials(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_opds_credentials(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PrefetchPause? sse_decode_opt_box_autoadd_prefetch_pause(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_prefetch_pause(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ProcessedBook? sse_decode_opt_box_autoadd_processed_book(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_processed_book(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ProxyConfig? sse_decode_opt_box_autoadd_proxy_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_proxy_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  RateLimit? sse_decode_opt_box_autoadd_rate_limit(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_rate_limit(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ReadingProgress? sse_decode_opt_box_autoadd_reading_progress(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_reading_progress(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SyncState? sse_decode_opt_box_autoadd_sync_state(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_sync_state(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  TextSegment? sse_decode_opt_box_autoadd_text_segment(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_text_segment(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  TtsPosition? sse_decode_opt_box_autoadd_tts_position(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_tts_position(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  WebDavSyncRecord? sse_decode_opt_box_autoadd_web_dav_sync_record(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_web_dav_sync_record(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Uint32List? sse_decode_opt_list_prim_u_32_strict(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_prim_u_32_strict(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_prim_u_8_strict(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<TxtChapterRule>? sse_decode_opt_list_txt_chapter_rule(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_txt_chapter_rule(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PageLayout sse_decode_page_layout(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_width = sse_decode_f_32(deserializer);
+    var var_height = sse_decode_f_32(deserializer);
+    var var_marginTop = sse_decode_f_32(deserializer);
+    var var_marginRight = sse_decode_f_32(deserializer);
+    var var_marginBottom = sse_decode_f_32(deserializer);
+    var var_marginLeft = sse_decode_f_32(deserializer);
+    var var_fontSize = sse_decode_f_32(deserializer);
+    var var_lineHeight = sse_decode_f_32(deserializer);
+    var var_paragraphSpacing = sse_decode_f_32(deserializer);
+    var var_indent = sse_decode_f_32(deserializer);
+    return PageLayout(
+      width: var_width,
+      height: var_height,
+      marginTop: var_marginTop,
+      marginRight: var_marginRight,
+      marginBottom: var_marginBottom,
+      marginLeft: var_marginLeft,
+      fontSize: var_fontSize,
+      lineHeight: var_lineHeight,
+      paragraphSpacing: var_paragraphSpacing,
+      indent: var_indent,
+    );
+  }
+
+  @protected
+  PaletteSwatch sse_decode_palette_swatch(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_color = sse_decode_u_32(deserializer);
+    var var_population = sse_decode_f_32(deserializer);
+    return PaletteSwatch(color: var_color, population: var_population);
+  }
+
+  @protected
+  ParsedBook sse_decode_parsed_book(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_metadata = sse_decode_book_metadata(deserializer);
+    var var_chapters = sse_decode_list_book_chapter(deserializer);
+    var var_toc = sse_decode_list_toc_entry(deserializer);
+    return ParsedBook(
+      metadata: var_metadata,
+      chapters: var_chapters,
+      toc: var_toc,
+    );
+  }
+
+  @protected
+  PerformanceTier sse_decode_performance_tier(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PerformanceTier.values[inner];
+  }
+
+  @protected
+  PinyinSpan sse_decode_pinyin_span(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_text = sse_decode_String(deserializer);
+    var var_pinyin = sse_decode_String(deserializer);
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    return PinyinSpan(
+      text: var_text,
+      pinyin: var_pinyin,
+      start: var_start,
+      end: var_end,
+    );
+  }
+
+  @protected
+  PostStep sse_decode_post_step(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_post_step_kind(deserializer);
+    var var_pattern = sse_decode_String(deserializer);
+    var var_replacement = sse_decode_String(deserializer);
+    return PostStep(
+      kind: var_kind,
+      pattern: var_pattern,
+      replacement: var_replacement,
+    );
+  }
+
+  @protected
+  PostStepKind sse_decode_post_step_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PostStepKind.values[inner];
+  }
+
+  @protected
+  PrefetchChapter sse_decode_prefetch_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_index = sse_decode_u_32(deserializer);
+    var var_request = sse_decode_download_request(deserializer);
+    var var_length = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return PrefetchChapter(
+      index: var_index,
+      request: var_request,
+      length: var_length,
+    );
+  }
+
+  @protected
+  PrefetchConfig sse_decode_prefetch_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxChapters = sse_decode_u_32(deserializer);
+    var var_defaultChapters = sse_decode_u_32(deserializer);
+    var var_lookaheadMinutes = sse_decode_u_32(deserializer);
+    var var_allowMetered = sse_decode_bool(deserializer);
+    return PrefetchConfig(
+      maxChapters: var_maxChapters,
+      defaultChapters: var_defaultChapters,
+      lookaheadMinutes: var_lookaheadMinutes,
+      allowMetered: var_allowMetered,
+    );
+  }
+
+  @protected
+  PrefetchPause sse_decode_prefetch_pause(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PrefetchPause.values[inner];
+  }
+
+  @protected
+  PrefetchPlan sse_decode_prefetch_plan(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_currentIndex = sse_decode_u_32(deserializer);
+    var var_chapters = sse_decode_list_prefetch_chapter(deserializer);
+    var var_charsPerMinute = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_network = sse_decode_network_type(deserializer);
+    return PrefetchPlan(
+      key: var_key,
+      currentIndex: var_currentIndex,
+      chapters: var_chapters,
+      charsPerMinute: var_charsPerMinute,
+      network: var_network,
+    );
+  }
+
+  @protected
+  PrefetchStatus sse_decode_prefetch_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_opt_String(deserializer);
+    var var_paused = sse_decode_opt_box_autoadd_prefetch_pause(deserializer);
+    var var_tasks = sse_decode_list_prefetch_task(deserializer);
+    return PrefetchStatus(key: var_key, paused: var_paused, tasks: var_tasks);
+  }
+
+  @protected
+  PrefetchTask sse_decode_prefetch_task(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_chapterIndex = sse_decode_u_32(deserializer);
+    var var_taskId = sse_decode_u_32(deserializer);
+    var var_state = sse_decode_download_state(deserializer);
+    return PrefetchTask(
+      chapterIndex: var_chapterIndex,
+      taskId: var_taskId,
+      state: var_state,
+    );
+  }
+
+  @protected
+  ProcessedBook sse_decode_processed_book(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_book = sse_decode_parsed_book(deserializer);
+    var var_encoding = sse_decode_String(deserializer);
+    var var_stats = sse_decode_book_stats(deserializer);
+    return ProcessedBook(
+      book: var_book,
+      encoding: var_encoding,
+      stats: var_stats,
+    );
+  }
+
+  @protected
+  ProcessedImage sse_decode_processed_image(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_sourceWidth = sse_decode_u_32(deserializer);
+    var var_sourceHeight = sse_decode_u_32(deserializer);
+    return ProcessedImage(
+      data: var_data,
+      width: var_width,
+      height: var_height,
+      sourceWidth: var_sourceWidth,
+      sourceHeight: var_sourceHeight,
+    );
+  }
+
+  @protected
+  ProxyConfig sse_decode_proxy_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_proxy_kind(deserializer);
+    var var_host = sse_decode_String(deserializer);
+    var var_port = sse_decode_u_16(deserializer);
+    var var_username = sse_decode_opt_String(deserializer);
+    var var_password = sse_decode_opt_String(deserializer);
+    return ProxyConfig(
+      kind: var_kind,
+      host: var_host,
+      port: var_port,
+      username: var_username,
+      password: var_password,
+    );
+  }
+
+  @protected
+  ProxyKind sse_decode_proxy_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ProxyKind.values[inner];
+  }
+
+  @protected
+  PunctuationRepair sse_decode_punctuation_repair(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_text = sse_decode_String(deserializer);
+    var var_changes = sse_decode_u_32(deserializer);
+    var var_spacesRemoved = sse_decode_u_32(deserializer);
+    var var_quotesFixed = sse_decode_u_32(deserializer);
+    var var_bracketsReplaced = sse_decode_u_32(deserializer);
+    return PunctuationRepair(
+      text: var_text,
+      changes: var_changes,
+      spacesRemoved: var_spacesRemoved,
+      quotesFixed: var_quotesFixed,
+      bracketsReplaced: var_bracketsReplaced,
+    );
+  }
+
+  @protected
+  PurifyReport sse_decode_purify_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_text = sse_decode_String(deserializer);
+    var var_matches = sse_decode_list_purify_rule_match(deserializer);
+    return PurifyReport(text: var_text, matches: var_matches);
+  }
+
+  @protected
+  PurifyRule sse_decode_purify_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_kind = sse_decode_purify_rule_kind(deserializer);
+    var var_pattern = sse_decode_String(deserializer);
+    var var_replacement = sse_decode_String(deserializer);
+    var var_enabled = sse_decode_bool(deserializer);
+    return PurifyRule(
+      name: var_name,
+      kind: var_kind,
+      pattern: var_pattern,
+      replacement: var_replacement,
+      enabled: var_enabled,
+    );
+  }
+
+  @protected
+  PurifyRuleKind sse_decode_purify_rule_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PurifyRuleKind.values[inner];
+  }
+
+  @protected
+  PurifyRuleMatch sse_decode_purify_rule_match(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ruleIndex = sse_decode_u_32(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_count = sse_decode_u_32(deserializer);
+    var var_samples = sse_decode_list_String(deserializer);
+    return PurifyRuleMatch(
+      ruleIndex: var_ruleIndex,
+      name: var_name,
+      count: var_count,
+      samples: var_samples,
+    );
+  }
+
+  @protected
+  RateLimit sse_decode_rate_limit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_requestsPerSecond = sse_decode_f_64(deserializer);
+    var var_burst = sse_decode_u_32(deserializer);
+    var var_jitterMs = sse_decode_u_32(deserializer);
+    var var_cooldownSecs = sse_decode_u_32(deserializer);
+    return RateLimit(
+      requestsPerSecond: var_requestsPerSecond,
+      burst: var_burst,
+      jitterMs: var_jitterMs,
+      cooldownSecs: var_cooldownSecs,
+    );
+  }
+
+  @protected
+  RateLimitStatus sse_decode_rate_limit_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_host = sse_decode_String(deserializer);
+    var var_waiting = sse_decode_u_32(deserializer);
+    var var_available = sse_decode_u_32(deserializer);
+    var var_cooldownMs = sse_decode_u_64(deserializer);
+    return RateLimitStatus(
+      host: var_host,
+      waiting: var_waiting,
+      available: var_available,
+      cooldownMs: var_cooldownMs,
+    );
+  }
+
+  @protected
+  ReadableBlock sse_decode_readable_block(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_readable_block_kind(deserializer);
+    var var_spans = sse_decode_list_readable_span(deserializer);
+    return ReadableBlock(kind: var_kind, spans: var_spans);
+  }
+
+  @protected
+  ReadableBlockKind sse_decode_readable_block_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ReadableBlockKind.values[inner];
+  }
+
+  @protected
+  ReadableContent sse_decode_readable_content(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_blocks = sse_decode_list_readable_block(deserializer);
+    return ReadableContent(title: var_title, blocks: var_blocks);
+  }
+
+  @protected
+  ReadableSpan sse_decode_readable_span(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_text = sse_decode_String(deserializer);
+    var var_imageSrc = sse_decode_opt_String(deserializer);
+    var var_bold = sse_decode_bool(deserializer);
+    var var_italic = sse_decode_bool(deserializer);
+    return ReadableSpan(
+      text: var_text,
+      imageSrc: var_imageSrc,
+      bold: var_bold,
+      italic: var_italic,
+    );
+  }
+
+  @protected
+  ReadingDay sse_decode_reading_day(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_date = sse_decode_String(deserializer);
+    var var_seconds = sse_decode_u_64(deserializer);
+    return ReadingDay(date: var_date, seconds: var_seconds);
+  }
+
+  @protected
+  ReadingProgress sse_decode_reading_progress(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_bookId = sse_decode_String(deserializer);
+    var var_chapterIndex = sse_decode_u_32(deserializer);
+    var var_chapterOffset = sse_decode_u_32(deserializer);
+    var var_percent = sse_decode_f_64(deserializer);
+    var var_updatedAt = sse_decode_i_64(deserializer);
+    return ReadingProgress(
+      bookId: var_bookId,
+      chapterIndex: var_chapterIndex,
+      chapterOffset: var_chapterOffset,
+      percent: var_percent,
+      updatedAt: var_updatedAt,
+    );
+  }
+
+  @protected
+  ReadingStats sse_decode_reading_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_totalSeconds = sse_decode_u_64(deserializer);
+    var var_days = sse_decode_list_reading_day(deserializer);
+    var var_books = sse_decode_list_book_reading_time(deserializer);
+    var var_currentStreak = sse_decode_u_32(deserializer);
+    var var_longestStreak = sse_decode_u_32(deserializer);
+    return ReadingStats(
+      totalSeconds: var_totalSeconds,
+      days: var_days,
+      books: var_books,
+      currentStreak: var_currentStreak,
+      longestStreak: var_longestStreak,
+    );
+  }
+
+  @protected
+  RepairLevel sse_decode_repair_level(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return RepairLevel.values[inner];
+  }
+
+  @protected
+  ResourceKind sse_decode_resource_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ResourceKind.values[inner];
+  }
+
+  @protected
+  ResplitOutcome sse_decode_resplit_outcome(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_titles = sse_decode_list_String(deserializer);
+    var var_movedBookmarks = sse_decode_u_32(deserializer);
+    var var_movedHighlights = sse_decode_u_32(deserializer);
+    var var_chapterIndex = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_chapterOffset = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return ResplitOutcome(
+      titles: var_titles,
+      movedBookmarks: var_movedBookmarks,
+      movedHighlights: var_movedHighlights,
+      chapterIndex: var_chapterIndex,
+      chapterOffset: var_chapterOffset,
+    );
+  }
+
+  @protected
+  RestoreResult sse_decode_restore_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_booksAdded = sse_decode_u_32(deserializer);
+    var var_booksUpdated = sse_decode_u_32(deserializer);
+    var var_progressUpdated = sse_decode_u_32(deserializer);
+    var var_bookmarksAdded = sse_decode_u_32(deserializer);
+    var var_highlightsAdded = sse_decode_u_32(deserializer);
+    var var_purifyRulesAdded = sse_decode_u_32(deserializer);
+    var var_bookSourcesAdded = sse_decode_u_32(deserializer);
+    var var_purifyRules = sse_decode_list_purify_rule(deserializer);
+    var var_bookSourcesJson = sse_decode_String(deserializer);
+    return RestoreResult(
+      booksAdded: var_booksAdded,
+      booksUpdated: var_booksUpdated,
+      progressUpdated: var_progressUpdated,
+      bookmarksAdded: var_bookmarksAdded,
+      highlightsAdded: var_highlightsAdded,
+      purifyRulesAdded: var_purifyRulesAdded,
+      bookSourcesAdded: var_bookSourcesAdded,
+      purifyRules: var_purifyRules,
+      bookSourcesJson: var_bookSourcesJson,
+    );
+  }
+
+  @protected
+  RuleTest sse_decode_rule_test(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_matches = sse_decode_list_rule_test_match(deserializer);
+    var var_matchCount = sse_decode_u_32(deserializer);
+    var var_output = sse_decode_String(deserializer);
+    var var_compileUs = sse_decode_u_64(deserializer);
+    var var_matchUsPerMib = sse_decode_u_64(deserializer);
+    var var_slow = sse_decode_bool(deserializer);
+    return RuleTest(
+      matches: var_matches,
+      matchCount: var_matchCount,
+      output: var_output,
+      compileUs: var_compileUs,
+      matchUsPerMib: var_matchUsPerMib,
+      slow: var_slow,
+    );
+  }
+
+  @protected
+  RuleTestMatch sse_decode_rule_test_match(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    return RuleTestMatch(start: var_start, end: var_end, text: var_text);
+  }
+
+  @protected
+  ScrapedMetadata sse_decode_scraped_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_opt_box_autoadd_metadata_guess(deserializer);
+    var var_author = sse_decode_opt_box_autoadd_metadata_guess(deserializer);
+    var var_tags = sse_decode_list_String(deserializer);
+    return ScrapedMetadata(
+      title: var_title,
+      author: var_author,
+      tags: var_tags,
+    );
+  }
+
+  @protected
+  SearchChapter sse_decode_search_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_chapterIndex = sse_decode_u_32(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    return SearchChapter(
+      chapterIndex: var_chapterIndex,
+      title: var_title,
+      text: var_text,
+    );
+  }
+
+  @protected
+  SearchHit sse_decode_search_hit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_bookId = sse_decode_String(deserializer);
+    var var_chapterIndex = sse_decode_u_32(deserializer);
+    var var_chapterTitle = sse_decode_String(deserializer);
+    var var_offset = sse_decode_u_32(deserializer);
+    var var_length = sse_decode_u_32(deserializer);
+    var var_snippet = sse_decode_String(deserializer);
+    var var_highlightStart = sse_decode_u_32(deserializer);
+    var var_highlightEnd = sse_decode_u_32(deserializer);
+    return SearchHit(
+      bookId: var_bookId,
+      chapterIndex: var_chapterIndex,
+      chapterTitle: var_chapterTitle,
+      offset: var_offset,
+      length: var_length,
+      snippet: var_snippet,
+      highlightStart: var_highlightStart,
+      highlightEnd: var_highlightEnd,
+    );
+  }
+
+  @protected
+  SearchRules sse_decode_search_rules(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_bookList = sse_decode_opt_String(deserializer);
+    var var_name = sse_decode_opt_String(deserializer);
+    var var_author = sse_decode_opt_String(deserializer);
+    var var_intro = sse_decode_opt_String(deserializer);
+    var var_kind = sse_decode_opt_String(deserializer);
+    var var_lastChapter = sse_decode_opt_String(deserializer);
+    var var_coverUrl = sse_decode_opt_String(deserializer);
+    var var_bookUrl = sse_decode_opt_String(deserializer);
+    var var_wordCount = sse_decode_opt_String(deserializer);
+    return SearchRules(
+      bookList: var_bookList,
+      name: var_name,
+      author: var_author,
+      intro: var_intro,
+      kind: var_kind,
+      lastChapter: var_lastChapter,
+      coverUrl: var_coverUrl,
+      bookUrl: var_bookUrl,
+      wordCount: var_wordCount,
+    );
+  }
+
+  @protected
+  SourceBook sse_decode_source_book(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_author = sse_decode_opt_String(deserializer);
+    var var_intro = sse_decode_opt_String(deserializer);
+    var var_kind = sse_decode_opt_String(deserializer);
+    var var_lastChapter = sse_decode_opt_String(deserializer);
+    var var_coverUrl = sse_decode_opt_String(deserializer);
+    var var_wordCount = sse_decode_opt_String(deserializer);
+    var var_bookUrl = sse_decode_String(deserializer);
+    var var_tocUrl = sse_decode_String(deserializer);
+    return SourceBook(
+      name: var_name,
+      author: var_author,
+      intro: var_intro,
+      kind: var_kind,
+      lastChapter: var_lastChapter,
+      coverUrl: var_coverUrl,
+      wordCount: var_wordCount,
+      bookUrl: var_bookUrl,
+      tocUrl: var_tocUrl,
+    );
+  }
+
+  @protected
+  SourceChapter sse_decode_source_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_String(deserializer);
+    var var_url = sse_decode_String(deserializer);
+    var var_isVolume = sse_decode_bool(deserializer);
+    return SourceChapter(
+      title: var_title,
+      url: var_url,
+      isVolume: var_isVolume,
+    );
+  }
+
+  @protected
+  SsmlOptions sse_decode_ssml_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_language = sse_decode_String(deserializer);
+    var var_paragraphBreakMs = sse_decode_u_32(deserializer);
+    var var_dialoguePitch = sse_decode_opt_String(deserializer);
+    var var_dialogueRate = sse_decode_opt_String(deserializer);
+    return SsmlOptions(
+      language: var_language,
+      paragraphBreakMs: var_paragraphBreakMs,
+      dialoguePitch: var_dialoguePitch,
+      dialogueRate: var_dialogueRate,
+    );
+  }
+
+  @protected
+  StoredBookScan sse_decode_stored_book_scan(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_bookId = sse_decode_String(deserializer);
+    var var_corruptRecords = sse_decode_u_32(deserializer);
+    var var_lostChapters = sse_decode_list_prim_u_32_strict(deserializer);
+    return StoredBookScan(
+      bookId: var_bookId,
+      corruptRecords: var_corruptRecords,
+      lostChapters: var_lostChapters,
+    );
+  }
+
+  @protected
+  StoredCookie sse_decode_stored_cookie(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    var var_domain = sse_decode_String(deserializer);
+    var var_hostOnly = sse_decode_bool(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_expires = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_secure = sse_decode_bool(deserializer);
+    var var_httpOnly = sse_decode_bool(deserializer);
+    return StoredCookie(
+      name: var_name,
+      value: var_value,
+      domain: var_domain,
+      hostOnly: var_hostOnly,
+      path: var_path,
+      expires: var_expires,
+      secure: var_secure,
+      httpOnly: var_httpOnly,
+    );
+  }
+
+  @protected
+  StyledChapter sse_decode_styled_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_html = sse_decode_String(deserializer);
+    var var_css = sse_decode_String(deserializer);
+    return StyledChapter(html: var_html, css: var_css);
+  }
+
+  @protected
+  SummaryOptions sse_decode_summary_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxChars = sse_decode_u_32(deserializer);
+    var var_maxSentences = sse_decode_u_32(deserializer);
+    return SummaryOptions(
+      maxChars: var_maxChars,
+      maxSentences: var_maxSentences,
+    );
+  }
+
+  @protected
+  SyncResult sse_decode_sync_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_version = sse_decode_u_32(deserializer);
+    var var_uploaded = sse_decode_u_32(deserializer);
+    var var_downloaded = sse_decode_u_32(deserializer);
+    var var_progressUpdated = sse_decode_u_32(deserializer);
+    var var_bookmarksAdded = sse_decode_u_32(deserializer);
+    var var_highlightsAdded = sse_decode_u_32(deserializer);
+    var var_bookmarksRemoved = sse_decode_u_32(deserializer);
+    var var_highlightsRemoved = sse_decode_u_32(deserializer);
+    var var_state = sse_decode_sync_state(deserializer);
+    return SyncResult(
+      version: var_version,
+      uploaded: var_uploaded,
+      downloaded: var_downloaded,
+      progressUpdated: var_progressUpdated,
+      bookmarksAdded: var_bookmarksAdded,
+      highlightsAdded: var_highlightsAdded,
+      bookmarksRemoved: var_bookmarksRemoved,
+      highlightsRemoved: var_highlightsRemoved,
+      state: var_state,
+    );
+  }
+
+  @protected
+  SyncState sse_decode_sync_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_remoteId = sse_decode_String(deserializer);
+    var var_version = sse_decode_u_32(deserializer);
+    var var_fingerprints = sse_decode_list_String(deserializer);
+    return SyncState(
+      remoteId: var_remoteId,
+      version: var_version,
+      fingerprints: var_fingerprints,
+    );
+  }
+
+  @protected
+  TagSuggestion sse_decode_tag_suggestion(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_tag = sse_decode_String(deserializer);
+    var var_density = sse_decode_f_32(deserializer);
+    return TagSuggestion(tag: var_tag, density: var_density);
+  }
+
+  @protected
+  TextAnchor sse_decode_text_anchor(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_exact = sse_decode_String(deserializer);
+    var var_prefix = sse_decode_String(deserializer);
+    var var_suffix = sse_decode_String(deserializer);
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    return TextAnchor(
+      exact: var_exact,
+      prefix: var_prefix,
+      suffix: var_suffix,
+      start: var_start,
+      end: var_end,
+    );
+  }
+
+  @protected
+  TextColumn sse_decode_text_column(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    var var_paragraphStart = sse_decode_bool(deserializer);
+    var var_x = sse_decode_f_32(deserializer);
+    var var_height = sse_decode_f_32(deserializer);
+    var var_glyphs = sse_decode_list_vertical_glyph(deserializer);
+    return TextColumn(
+      start: var_start,
+      end: var_end,
+      paragraphStart: var_paragraphStart,
+      x: var_x,
+      height: var_height,
+      glyphs: var_glyphs,
+    );
+  }
+
+  @protected
+  TextLine sse_decode_text_line(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    var var_paragraphStart = sse_decode_bool(deserializer);
+    var var_width = sse_decode_f_32(deserializer);
+    var var_hyphenated = sse_decode_bool(deserializer);
+    return TextLine(
+      start: var_start,
+      end: var_end,
+      paragraphStart: var_paragraphStart,
+      width: var_width,
+      hyphenated: var_hyphenated,
+    );
+  }
+
+  @protected
+  TextPage sse_decode_text_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    var var_lines = sse_decode_list_text_line(deserializer);
+    return TextPage(start: var_start, end: var_end, lines: var_lines);
+  }
+
+  @protected
+  TextSegment sse_decode_text_segment(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_text = sse_decode_String(deserializer);
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    var var_isWord = sse_decode_bool(deserializer);
+    return TextSegment(
+      text: var_text,
+      start: var_start,
+      end: var_end,
+      isWord: var_isWord,
+    );
+  }
+
+  @protected
+  TextStats sse_decode_text_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_cjkChars = sse_decode_u_32(deserializer);
+    var var_latinWords = sse_decode_u_32(deserializer);
+    var var_punctuation = sse_decode_u_32(deserializer);
+    var var_readingMinutes = sse_decode_f_32(deserializer);
+    return TextStats(
+      cjkChars: var_cjkChars,
+      latinWords: var_latinWords,
+      punctuation: var_punctuation,
+      readingMinutes: var_readingMinutes,
+    );
+  }
+
+  @protected
+  ThemeColors sse_decode_theme_colors(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_background = sse_decode_u_32(deserializer);
+    var var_foreground = sse_decode_u_32(deserializer);
+    var var_accent = sse_decode_u_32(deserializer);
+    return ThemeColors(
+      background: var_background,
+      foreground: var_foreground,
+      accent: var_accent,
+    );
+  }
+
+  @protected
+  TierSettings sse_decode_tier_settings(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_prefetchDepth = sse_decode_u_32(deserializer);
+    var var_imageQuality = sse_decode_u_8(deserializer);
+    var var_animations = sse_decode_bool(deserializer);
+    return TierSettings(
+      prefetchDepth: var_prefetchDepth,
+      imageQuality: var_imageQuality,
+      animations: var_animations,
+    );
+  }
+
+  @protected
+  TocEntry sse_decode_toc_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_String(deserializer);
+    var var_href = sse_decode_String(deserializer);
+    var var_chapterIndex = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_depth = sse_decode_u_32(deserializer);
+    return TocEntry(
+      title: var_title,
+      href: var_href,
+      chapterIndex: var_chapterIndex,
+      depth: var_depth,
+    );
+  }
+
+  @protected
+  TocRules sse_decode_toc_rules(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_chapterList = sse_decode_opt_String(deserializer);
+    var var_chapterName = sse_decode_opt_String(deserializer);
+    var var_chapterUrl = sse_decode_opt_String(deserializer);
+    var var_isVolume = sse_decode_opt_String(deserializer);
+    var var_nextTocUrl = sse_decode_opt_String(deserializer);
+    return TocRules(
+      chapterList: var_chapterList,
+      chapterName: var_chapterName,
+      chapterUrl: var_chapterUrl,
+      isVolume: var_isVolume,
+      nextTocUrl: var_nextTocUrl,
+    );
+  }
+
+  @protected
+  TranslatedParagraph sse_decode_translated_paragraph(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_original = sse_decode_String(deserializer);
+    var var_translation = sse_decode_String(deserializer);
+    return TranslatedParagraph(
+      original: var_original,
+      translation: var_translation,
+    );
+  }
+
+  @protected
+  TranslationProvider sse_decode_translation_provider(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TranslationProvider.values[inner];
+  }
+
+  @protected
+  TranslatorConfig sse_decode_translator_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_provider = sse_decode_translation_provider(deserializer);
+    var var_endpoint = sse_decode_String(deserializer);
+    var var_apiKey = sse_decode_opt_String(deserializer);
+    var var_model = sse_decode_opt_String(deserializer);
+    var var_sourceLanguage = sse_decode_opt_String(deserializer);
+    var var_targetLanguage = sse_decode_String(deserializer);
+    var var_timeoutSecs = sse_decode_u_32(deserializer);
+    return TranslatorConfig(
+      provider: var_provider,
+      endpoint: var_endpoint,
+      apiKey: var_apiKey,
+      model: var_model,
+      sourceLanguage: var_sourceLanguage,
+      targetLanguage: var_targetLanguage,
+      timeoutSecs: var_timeoutSecs,
+    );
+  }
+
+  @protected
+  TtfChunk sse_decode_ttf_chunk(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_offset = sse_decode_u_32(deserializer);
+    var var_totalLen = sse_decode_u_32(deserializer);
+    var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+    return TtfChunk(offset: var_offset, totalLen: var_totalLen, data: var_data);
+  }
+
+  @protected
+  TtsAudioChunk sse_decode_tts_audio_chunk(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_utteranceId = sse_decode_String(deserializer);
+    var var_sampleRate = sse_decode_u_32(deserializer);
+    var var_pcm = sse_decode_list_prim_u_8_strict(deserializer);
+    return TtsAudioChunk(
+      utteranceId: var_utteranceId,
+      sampleRate: var_sampleRate,
+      pcm: var_pcm,
+    );
+  }
+
+  @protected
+  TtsOptions sse_decode_tts_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_speed = sse_decode_f_32(deserializer);
+    var var_pitch = sse_decode_f_32(deserializer);
+    var var_speaker = sse_decode_u_32(deserializer);
+    return TtsOptions(speed: var_speed, pitch: var_pitch, speaker: var_speaker);
+  }
+
+  @protected
+  TtsPosition sse_decode_tts_position(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_utteranceId = sse_decode_String(deserializer);
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    var var_offset = sse_decode_u_32(deserializer);
+    return TtsPosition(
+      utteranceId: var_utteranceId,
+      start: var_start,
+      end: var_end,
+      offset: var_offset,
+    );
+  }
+
+  @protected
+  TtsVoiceInfo sse_decode_tts_voice_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sampleRate = sse_decode_u_32(deserializer);
+    var var_speakers = sse_decode_u_32(deserializer);
+    var var_language = sse_decode_String(deserializer);
+    var var_usesLexicon = sse_decode_bool(deserializer);
+    return TtsVoiceInfo(
+      sampleRate: var_sampleRate,
+      speakers: var_speakers,
+      language: var_language,
+      usesLexicon: var_usesLexicon,
+    );
+  }
+
+  @protected
+  TxtChapter sse_decode_txt_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    return TxtChapter(title: var_title, start: var_start, end: var_end);
+  }
+
+  @protected
+  TxtChapterProposal sse_decode_txt_chapter_proposal(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_encoding = sse_decode_String(deserializer);
+    var var_fileSize = sse_decode_u_32(deserializer);
+    var var_headings = sse_decode_list_txt_heading_candidate(deserializer);
+    return TxtChapterProposal(
+      encoding: var_encoding,
+      fileSize: var_fileSize,
+      headings: var_headings,
+    );
+  }
+
+  @protected
+  TxtChapterRule sse_decode_txt_chapter_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_pattern = sse_decode_String(deserializer);
+    return TxtChapterRule(name: var_name, pattern: var_pattern);
+  }
+
+  @protected
+  TxtHeadingCandidate sse_decode_txt_heading_candidate(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_String(deserializer);
+    var var_start = sse_decode_u_32(deserializer);
+    var var_line = sse_decode_u_32(deserializer);
+    var var_confidence = sse_decode_f_32(deserializer);
+    var var_selected = sse_decode_bool(deserializer);
+    return TxtHeadingCandidate(
+      title: var_title,
+      start: var_start,
+      line: var_line,
+      confidence: var_confidence,
+      selected: var_selected,
+    );
+  }
+
+  @protected
+  TxtIndex sse_decode_txt_index(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_encoding = sse_decode_String(deserializer);
+    var var_fileSize = sse_decode_u_32(deserializer);
+    var var_chapters = sse_decode_list_txt_chapter(deserializer);
+    return TxtIndex(
+      encoding: var_encoding,
+      fileSize: var_fileSize,
+      chapters: var_chapters,
+    );
+  }
+
+  @protected
+  TypographyOptions sse_decode_typography_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fullWidthPunctuation = sse_decode_bool(deserializer);
+    var var_collapseBlankLines = sse_decode_bool(deserializer);
+    var var_indentParagraphs = sse_decode_bool(deserializer);
+    var var_unifyEllipsesAndDashes = sse_decode_bool(deserializer);
+    var var_curlyQuotes = sse_decode_bool(deserializer);
+    return TypographyOptions(
+      fullWidthPunctuation: var_fullWidthPunctuation,
+      collapseBlankLines: var_collapseBlankLines,
+      indentParagraphs: var_indentParagraphs,
+      unifyEllipsesAndDashes: var_unifyEllipsesAndDashes,
+      curlyQuotes: var_curlyQuotes,
+    );
+  }
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  UpdateCheckBook sse_decode_update_check_book(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_bookId = sse_decode_String(deserializer);
+    var var_source = sse_decode_book_source(deserializer);
+    var var_tocUrl = sse_decode_String(deserializer);
+    return UpdateCheckBook(
+      bookId: var_bookId,
+      source: var_source,
+      tocUrl: var_tocUrl,
+    );
+  }
+
+  @protected
+  UpdateCheckStatus sse_decode_update_check_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return UpdateCheckStatus.values[inner];
+  }
+
+  @protected
+  Utterance sse_decode_utterance(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    var var_ssml = sse_decode_opt_String(deserializer);
+    return Utterance(
+      id: var_id,
+      text: var_text,
+      start: var_start,
+      end: var_end,
+      ssml: var_ssml,
+    );
+  }
+
+  @protected
+  ValidationIssue sse_decode_validation_issue(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_validation_issue_kind(deserializer);
+    var var_table = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return ValidationIssue(
+      kind: var_kind,
+      table: var_table,
+      message: var_message,
+    );
+  }
+
+  @protected
+  ValidationIssueKind sse_decode_validation_issue_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ValidationIssueKind.values[inner];
+  }
+
+  @protected
+  ValidationReport sse_decode_validation_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_isValid = sse_decode_bool(deserializer);
+    var var_isRepairable = sse_decode_bool(deserializer);
+    var var_issues = sse_decode_list_validation_issue(deserializer);
+    return ValidationReport(
+      isValid: var_isValid,
+      isRepairable: var_isRepairable,
+      issues: var_issues,
+    );
+  }
+
+  @protected
+  VerticalGlyph sse_decode_vertical_glyph(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_offset = sse_decode_u_32(deserializer);
+    var var_len = sse_decode_u_32(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    var var_y = sse_decode_f_32(deserializer);
+    var var_advance = sse_decode_f_32(deserializer);
+    var var_rotated = sse_decode_bool(deserializer);
+    var var_vertFeature = sse_decode_bool(deserializer);
+    return VerticalGlyph(
+      offset: var_offset,
+      len: var_len,
+      text: var_text,
+      y: var_y,
+      advance: var_advance,
+      rotated: var_rotated,
+      vertFeature: var_vertFeature,
+    );
+  }
+
+  @protected
+  VerticalPage sse_decode_vertical_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    var var_columns = sse_decode_list_text_column(deserializer);
+    return VerticalPage(start: var_start, end: var_end, columns: var_columns);
+  }
+
+  @protected
+  WatermarkChar sse_decode_watermark_char(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_codepoint = sse_decode_u_32(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_kind = sse_decode_watermark_kind(deserializer);
+    var var_replacement = sse_decode_String(deserializer);
+    var var_count = sse_decode_u_32(deserializer);
+    var var_offsets = sse_decode_list_prim_u_32_strict(deserializer);
+    return WatermarkChar(
+      codepoint: var_codepoint,
+      name: var_name,
+      kind: var_kind,
+      replacement: var_replacement,
+      count: var_count,
+      offsets: var_offsets,
+    );
+  }
+
+  @protected
+  WatermarkKind sse_decode_watermark_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return WatermarkKind.values[inner];
+  }
+
+  @protected
+  WatermarkReport sse_decode_watermark_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_chars = sse_decode_list_watermark_char(deserializer);
+    var var_total = sse_decode_u_32(deserializer);
+    return WatermarkReport(chars: var_chars, total: var_total);
+  }
+
+  @protected
+  WebBook sse_decode_web_book(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_author = sse_decode_opt_String(deserializer);
+    var var_url = sse_decode_String(deserializer);
+    var var_chapters = sse_decode_list_web_chapter(deserializer);
+    var var_confidence = sse_decode_f_32(deserializer);
+    return WebBook(
+      title: var_title,
+      author: var_author,
+      url: var_url,
+      chapters: var_chapters,
+      confidence: var_confidence,
+    );
+  }
+
+  @protected
+  WebChapter sse_decode_web_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_index = sse_decode_u_32(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_url = sse_decode_String(deserializer);
+    return WebChapter(index: var_index, title: var_title, url: var_url);
+  }
+
+  @protected
+  WebDavConfig sse_decode_web_dav_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_baseUrl = sse_decode_String(deserializer);
+    var var_username = sse_decode_String(deserializer);
+    var var_password = sse_decode_String(deserializer);
+    return WebDavConfig(
+      baseUrl: var_baseUrl,
+      username: var_username,
+      password: var_password,
+    );
+  }
+
+  @protected
+  WebDavEntry sse_decode_web_dav_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_isDir = sse_decode_bool(deserializer);
+    var var_size = sse_decode_u_64(deserializer);
+    var var_modified = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_etag = sse_decode_opt_String(deserializer);
+    return WebDavEntry(
+      path: var_path,
+      name: var_name,
+      isDir: var_isDir,
+      size: var_size,
+      modified: var_modified,
+      etag: var_etag,
+    );
+  }
+
+  @protected
+  WebDavSyncRecord sse_decode_web_dav_sync_record(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_hash = sse_decode_String(deserializer);
+    var var_etag = sse_decode_opt_String(deserializer);
+    var var_modified = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return WebDavSyncRecord(
+      hash: var_hash,
+      etag: var_etag,
+      modified: var_modified,
+    );
+  }
+
+  @protected
+  WebDavSyncStatus sse_decode_web_dav_sync_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return WebDavSyncStatus.values[inner];
+  }
+
+  @protected
+  WebImportProgress sse_decode_web_import_progress(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_done = sse_decode_u_32(deserializer);
+    var var_total = sse_decode_u_32(deserializer);
+    var var_chapterIndex = sse_decode_u_32(deserializer);
+    var var_skipped = sse_decode_bool(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return WebImportProgress(
+      done: var_done,
+      total: var_total,
+      chapterIndex: var_chapterIndex,
+      skipped: var_skipped,
+      error: var_error,
+    );
+  }
+
+  @protected
+  WebServerConfig sse_decode_web_server_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_port = sse_decode_u_16(deserializer);
+    var var_uploadDir = sse_decode_opt_String(deserializer);
+    var var_accessToken = sse_decode_opt_String(deserializer);
+    var var_maxUploadMb = sse_decode_u_32(deserializer);
+    return WebServerConfig(
+      port: var_port,
+      uploadDir: var_uploadDir,
+      accessToken: var_accessToken,
+      maxUploadMb: var_maxUploadMb,
+    );
+  }
+
+  @protected
+  WebServerInfo sse_decode_web_server_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_port = sse_decode_u_16(deserializer);
+    var var_urls = sse_decode_list_String(deserializer);
+    return WebServerInfo(port: var_port, urls: var_urls);
+  }
+
+  @protected
+  WebUpload sse_decode_web_upload(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_kind = sse_decode_import_kind(deserializer);
+    return WebUpload(name: var_name, path: var_path, kind: var_kind);
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_archive_progress_Sse(
+    RustStreamSink<ArchiveProgress> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_archive_progress,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_download_event_Sse(
+    RustStreamSink<DownloadEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_download_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_epub_export_progress_Sse(
+    RustStreamSink<EpubExportProgress> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_epub_export_progress,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_import_event_Sse(
+    RustStreamSink<ImportEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_import_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_rate_limit_status_Sse(
+    RustStreamSink<RateLimitStatus> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_rate_limit_status,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_ttf_chunk_Sse(
+    RustStreamSink<TtfChunk> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_ttf_chunk,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_tts_audio_chunk_Sse(
+    RustStreamSink<TtsAudioChunk> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_tts_audio_chunk,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_web_import_progress_Sse(
+    RustStreamSink<WebImportProgress> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_web_import_progress,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_web_upload_Sse(
+    RustStreamSink<WebUpload> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_web_upload,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_alternative_chapter(
+    AlternativeChapter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_book_source(self.source, serializer);
+    sse_encode_source_book(self.book, serializer);
+    sse_encode_source_chapter(self.chapter, serializer);
+    sse_encode_String(self.content, serializer);
+    sse_encode_f_32(self.score, serializer);
+  }
+
+  @protected
+  void sse_encode_alternative_source(
+    AlternativeSource self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_book_source(self.source, serializer);
+    sse_encode_source_book(self.book, serializer);
+    sse_encode_f_32(self.score, serializer);
+  }
+
+  @protected
+  void sse_encode_anchor_resolution(
+    AnchorResolution self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_text_anchor(self.anchor, serializer);
+    sse_encode_bool(self.exact, serializer);
+    sse_encode_f_32(self.similarity, serializer);
+  }
+
+  @protected
+  void sse_encode_api_error(ApiError self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_error_code(self.code, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_String(self.context, serializer);
+    sse_encode_opt_String(self.location, serializer);
+    sse_encode_opt_String(self.backtrace, serializer);
+  }
+
+  @protected
+  void sse_encode_archive_entry(ArchiveEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.index, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_u_32(self.size, serializer);
+    sse_encode_opt_box_autoadd_import_kind(self.kind, serializer);
+    sse_encode_bool(self.encrypted, serializer);
+  }
+
+  @protected
+  void sse_encode_archive_format(ArchiveFormat self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_archive_info(ArchiveInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_archive_format(self.format, serializer);
+    sse_encode_list_archive_entry(self.entries, serializer);
+  }
+
+  @protected
+  void sse_encode_archive_progress(
+    ArchiveProgress self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.done, serializer);
+    sse_encode_u_32(self.total, serializer);
+    sse_encode_extracted_file(self.file, serializer);
+  }
+
+  @protected
+  void sse_encode_audio_cache_stats(
+    AudioCacheStats self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.entryCount, serializer);
+    sse_encode_u_64(self.totalBytes, serializer);
+    sse_encode_u_64(self.maxBytes, serializer);
+  }
+
+  @protected
+  void sse_encode_audio_format(AudioFormat self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_audio_segment(AudioSegment self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.utteranceId, serializer);
+    sse_encode_u_64(self.startMs, serializer);
+    sse_encode_u_64(self.durationMs, serializer);
+  }
+
+  @protected
+  void sse_encode_axis_value(AxisValue self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.tag, serializer);
+    sse_encode_f_32(self.value, serializer);
+  }
+
+  @protected
+  void sse_encode_backup_info(BackupInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.version, serializer);
+    sse_encode_i_64(self.createdAt, serializer);
+    sse_encode_u_32(self.bookCount, serializer);
+    sse_encode_u_32(self.bookmarkCount, serializer);
+    sse_encode_u_32(self.highlightCount, serializer);
+    sse_encode_u_32(self.purifyRuleCount, serializer);
+    sse_encode_u_32(self.bookSourceCount, serializer);
+  }
+
+  @protected
+  void sse_encode_bandwidth_policy(
+    BandwidthPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.maxKbps, serializer);
+    sse_encode_u_32(self.meteredMaxKbps, serializer);
+    sse_encode_bool(self.pauseBackgroundWhenMetered, serializer);
+  }
+
+  @protected
+  void sse_encode_bench_report(BenchReport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self.inflateMbPerSec, serializer);
+    sse_encode_f_64(self.segmentCharsPerSec, serializer);
+    sse_encode_f_64(self.layoutCharsPerSec, serializer);
+    sse_encode_f_64(self.score, serializer);
+    sse_encode_performance_tier(self.tier, serializer);
+    sse_encode_tier_settings(self.settings, serializer);
+    sse_encode_u_32(self.elapsedMs, serializer);
+  }
+
+  @protected
+  void sse_encode_bionic_options(BionicOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self.latinRatio, serializer);
+    sse_encode_bool(self.cjk, serializer);
+  }
+
+  @protected
+  void sse_encode_blob_gc_report(BlobGcReport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.removed, serializer);
+    sse_encode_u_64(self.freedBytes, serializer);
+  }
+
+  @protected
+  void sse_encode_blob_store_stats(
+    BlobStoreStats self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.blobCount, serializer);
+    sse_encode_u_64(self.totalBytes, serializer);
+    sse_encode_u_32(self.referenceCount, serializer);
+  }
+
+  @protected
+  void sse_encode_book_alias(BookAlias self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.alias, serializer);
+    sse_encode_String(self.title, serializer);
+  }
+
+  @protected
+  void sse_encode_book_chapter(BookChapter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.index, serializer);
+    sse_encode_String(self.href, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_String(self.html, serializer);
+    sse_encode_list_chapter_image(self.images, serializer);
+  }
+
+  @protected
+  void sse_encode_book_format(BookFormat self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_book_image(BookImage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.rgba, serializer);
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_u_32(self.sourceWidth, serializer);
+    sse_encode_u_32(self.sourceHeight, serializer);
+  }
+
+  @protected
+  void sse_encode_book_info_rules(
+    BookInfoRules self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.init, serializer);
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_opt_String(self.author, serializer);
+    sse_encode_opt_String(self.intro, serializer);
+    sse_encode_opt_String(self.kind, serializer);
+    sse_encode_opt_String(self.lastChapter, serializer);
+    sse_encode_opt_String(self.coverUrl, serializer);
+    sse_encode_opt_String(self.tocUrl, serializer);
+    sse_encode_opt_String(self.wordCount, serializer);
+  }
+
+  @protected
+  void sse_encode_book_keywords(BookKeywords self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_keyword(self.keywords, serializer);
+    sse_encode_list_tag_suggestion(self.tags, serializer);
+  }
+
+  @protected
+  void sse_encode_book_metadata(BookMetadata self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_list_String(self.authors, serializer);
+    sse_encode_opt_String(self.language, serializer);
+    sse_encode_opt_String(self.identifier, serializer);
+    sse_encode_opt_String(self.publisher, serializer);
+    sse_encode_opt_String(self.description, serializer);
+    sse_encode_opt_String(self.coverHref, serializer);
+  }
+
+  @protected
+  void sse_encode_book_page(BookPage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_library_book(self.books, serializer);
+    sse_encode_u_32(self.total, serializer);
+  }
+
+  @protected
+  void sse_encode_book_query(BookQuery self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.search, serializer);
+    sse_encode_book_sort(self.sort, serializer);
+    sse_encode_u_32(self.offset, serializer);
+    sse_encode_u_32(self.limit, serializer);
+  }
+
+  @protected
+  void sse_encode_book_reading_time(
+    BookReadingTime self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.bookId, serializer);
+    sse_encode_u_64(self.seconds, serializer);
+    sse_encode_u_32(self.sessions, serializer);
+    sse_encode_i_64(self.lastReadAt, serializer);
+  }
+
+  @protected
+  void sse_encode_book_sort(BookSort self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_book_source(BookSource self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_String(self.group, serializer);
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_list_download_header(self.headers, serializer);
+    sse_encode_opt_String(self.searchUrl, serializer);
+    sse_encode_search_rules(self.search, serializer);
+    sse_encode_book_info_rules(self.bookInfo, serializer);
+    sse_encode_toc_rules(self.toc, serializer);
+    sse_encode_content_rules(self.content, serializer);
+  }
+
+  @protected
+  void sse_encode_book_stats(BookStats self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_text_stats(self.total, serializer);
+    sse_encode_list_text_stats(self.chapters, serializer);
+  }
+
+  @protected
+  void sse_encode_book_update_summary(
+    BookUpdateSummary self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.bookId, serializer);
+    sse_encode_update_check_status(self.status, serializer);
+    sse_encode_u_32(self.newChapters, serializer);
+    sse_encode_u_32(self.removedChapters, serializer);
+    sse_encode_u_32(self.chapterCount, serializer);
+    sse_encode_opt_String(self.latestChapterTitle, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
+
+  @protected
+  void sse_encode_bookmark(Bookmark self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_i_64(self.id, serializer);
+    sse_encode_String(self.bookId, serializer);
+    sse_encode_u_32(self.chapterIndex, serializer);
+    sse_encode_u_32(self.chapterOffset, serializer);
+    sse_encode_String(self.excerpt, serializer);
+    sse_encode_opt_String(self.note, serializer);
+    sse_encode_i_64(self.createdAt, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_anchor_resolution(
+    AnchorResolution self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_anchor_resolution(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_api_error(
+    ApiError self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_api_error(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_bandwidth_policy(
+    BandwidthPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bandwidth_policy(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_bionic_options(
+    BionicOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bionic_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_book_image(
+    BookImage self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_book_image(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_book_metadata(
+    BookMetadata self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_book_metadata(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_book_query(
+    BookQuery self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_book_query(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_book_source(
+    BookSource self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_book_source(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_cached_audio(
+    CachedAudio self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_cached_audio(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_calibre_metadata(
+    CalibreMetadata self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_calibre_metadata(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_chapter_fingerprint(
+    ChapterFingerprint self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_chapter_fingerprint(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_cover_image_options(
+    CoverImageOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_cover_image_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_cover_style(
+    CoverStyle self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_cover_style(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_dark_image_options(
+    DarkImageOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_dark_image_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_dedup_options(
+    DedupOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_dedup_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_download_checksum(
+    DownloadChecksum self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_download_checksum(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_download_request(
+    DownloadRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_download_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_downloader_config(
+    DownloaderConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_downloader_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_drm_scheme(
+    DrmScheme self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_drm_scheme(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_epub_split_options(
+    EpubSplitOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_epub_split_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_http_cache_entry(
+    HttpCacheEntry self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_http_cache_entry(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_import_kind(
+    ImportKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_import_kind(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_import_options(
+    ImportOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_import_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_import_report(
+    ImportReport self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_import_report(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_kinsoku_rules(
+    KinsokuRules self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_kinsoku_rules(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_library_book(
+    LibraryBook self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_library_book(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_logging_config(
+    LoggingConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_logging_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_metadata_guess(
+    MetadataGuess self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_metadata_guess(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_notes_export_options(
+    NotesExportOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_notes_export_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_opds_credentials(
+    OpdsCredentials self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opds_credentials(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_page_layout(
+    PageLayout self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_page_layout(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_prefetch_config(
+    PrefetchConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_prefetch_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_prefetch_pause(
+    PrefetchPause self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_prefetch_pause(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_prefetch_plan(
+    PrefetchPlan self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_prefetch_plan(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_processed_book(
+    ProcessedBook self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_processed_book(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_proxy_config(
+    ProxyConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_proxy_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_purify_rule(
+    PurifyRule self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_purify_rule(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_rate_limit(
+    RateLimit self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_rate_limit(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_reading_progress(
+    ReadingProgress self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_reading_progress(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_ssml_options(
+    SsmlOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_ssml_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_summary_options(
+    SummaryOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_summary_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_sync_state(
+    SyncState self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_sync_state(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_text_anchor(
+    TextAnchor self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_text_anchor(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_text_segment(
+    TextSegment self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_text_segment(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_translator_config(
+    TranslatorConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_translator_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_tts_options(
+    TtsOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_tts_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_tts_position(
+    TtsPosition self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_tts_position(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_txt_chapter(
+    TxtChapter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_txt_chapter(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_txt_chapter_rule(
+    TxtChapterRule self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_txt_chapter_rule(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_typography_options(
+    TypographyOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_typography_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_web_dav_config(
+    WebDavConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_web_dav_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_web_dav_sync_record(
+    WebDavSyncRecord self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_web_dav_sync_record(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_web_server_config(
+    WebServerConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_web_server_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_cached_audio(CachedAudio self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_audio_format(self.format, serializer);
+    sse_encode_list_prim_u_8_strict(self.data, serializer);
+  }
+
+  @protected
+  void sse_encode_calibre_book(CalibreBook self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.dir, serializer);
+    sse_encode_calibre_metadata(self.metadata, serializer);
+    sse_encode_opt_String(self.coverPath, serializer);
+    sse_encode_list_calibre_format(self.formats, serializer);
+  }
+
+  @protected
+  void sse_encode_calibre_format(CalibreFormat self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_import_kind(self.kind, serializer);
+  }
+
+  @protected
+  void sse_encode_calibre_identifier(
+    CalibreIdentifier self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.scheme, serializer);
+    sse_encode_String(self.value, serializer);
+  }
+
+  @protected
+  void sse_encode_calibre_metadata(
+    CalibreMetadata self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_String(self.titleSort, serializer);
+    sse_encode_list_String(self.authors, serializer);
+    sse_encode_opt_String(self.authorSort, serializer);
+    sse_encode_opt_String(self.series, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.seriesIndex, serializer);
+    sse_encode_list_String(self.tags, serializer);
+    sse_encode_opt_String(self.publisher, serializer);
+    sse_encode_opt_String(self.description, serializer);
+    sse_encode_opt_String(self.language, serializer);
+    sse_encode_opt_String(self.published, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.rating, serializer);
+    sse_encode_list_calibre_identifier(self.identifiers, serializer);
+  }
+
+  @protected
+  void sse_encode_chapter_audio(ChapterAudio self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_audio_format(self.format, serializer);
+    sse_encode_u_64(self.durationMs, serializer);
+    sse_encode_list_audio_segment(self.segments, serializer);
+    sse_encode_list_String(self.missing, serializer);
+  }
+
+  @protected
+  void sse_encode_chapter_cache_migration(
+    ChapterCacheMigration self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.converted, serializer);
+    sse_encode_u_32(self.unchanged, serializer);
+    sse_encode_u_32(self.removed, serializer);
+  }
+
+  @protected
+  void sse_encode_chapter_change(ChapterChange self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_chapter_diff(ChapterDiff self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self.similarity, serializer);
+    sse_encode_u_32(self.unchangedParagraphs, serializer);
+    sse_encode_list_diff_hunk(self.hunks, serializer);
+  }
+
+  @protected
+  void sse_encode_chapter_fingerprint(
+    ChapterFingerprint self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.hash, serializer);
+    sse_encode_u_64(self.simhash, serializer);
+    sse_encode_u_32(self.chars, serializer);
+  }
+
+  @protected
+  void sse_encode_chapter_image(ChapterImage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.href, serializer);
+    sse_encode_opt_String(self.alt, serializer);
+  }
+
+  @protected
+  void sse_encode_chapter_range(ChapterRange self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.end, serializer);
+  }
+
+  @protected
+  void sse_encode_chapter_store_stats(
+    ChapterStoreStats self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.chapterCount, serializer);
+    sse_encode_u_64(self.textBytes, serializer);
+    sse_encode_u_64(self.storedBytes, serializer);
+    sse_encode_u_64(self.garbageBytes, serializer);
+    sse_encode_u_32(self.segmentCount, serializer);
+    sse_encode_bool(self.hasDictionary, serializer);
+  }
+
+  @protected
+  void sse_encode_chapter_text(ChapterText self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.text, serializer);
+    sse_encode_list_prim_u_32_strict(self.imageOffsets, serializer);
+  }
+
+  @protected
+  void sse_encode_chapter_update(ChapterUpdate self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.chapterIndex, serializer);
+    sse_encode_chapter_change(self.change, serializer);
+    sse_encode_f_32(self.similarity, serializer);
+  }
+
+  @protected
+  void sse_encode_chapter_update_report(
+    ChapterUpdateReport self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_32_strict(self.newChapters, serializer);
+    sse_encode_list_chapter_update(self.chapters, serializer);
+  }
+
+  @protected
+  void sse_encode_checksum_algorithm(
+    ChecksumAlgorithm self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_chinese_conversion(
+    ChineseConversion self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_cloud_config(CloudConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.issuedAt, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.expiresAt, serializer);
+    sse_encode_bool(self.complianceMode, serializer);
+    sse_encode_list_String(self.disabledFeatures, serializer);
+    sse_encode_opt_String(self.minAppVersion, serializer);
+    sse_encode_opt_String(self.announcement, serializer);
+    sse_encode_list_cloud_config_value(self.values, serializer);
+  }
+
+  @protected
+  void sse_encode_cloud_config_value(
+    CloudConfigValue self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.key, serializer);
+    sse_encode_String(self.value, serializer);
+  }
+
+  @protected
+  void sse_encode_collection_face(
+    CollectionFace self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.index, serializer);
+    sse_encode_String(self.familyName, serializer);
+    sse_encode_String(self.styleName, serializer);
+    sse_encode_opt_String(self.postscriptName, serializer);
+  }
+
+  @protected
+  void sse_encode_comic_page(ComicPage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.index, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_u_32(self.size, serializer);
+  }
+
+  @protected
+  void sse_encode_content_rules(ContentRules self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.content, serializer);
+    sse_encode_opt_String(self.nextContentUrl, serializer);
+    sse_encode_opt_String(self.replaceRegex, serializer);
+  }
+
+  @protected
+  void sse_encode_conversion_result(
+    ConversionResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.ttf, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
+
+  @protected
+  void sse_encode_cover_image_options(
+    CoverImageOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.maxWidth, serializer);
+    sse_encode_u_32(self.maxHeight, serializer);
+    sse_encode_f_32(self.aspectRatio, serializer);
+    sse_encode_f_32(self.quality, serializer);
+  }
+
+  @protected
+  void sse_encode_cover_palette(CoverPalette self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.dominant, serializer);
+    sse_encode_u_32(self.dark, serializer);
+    sse_encode_u_32(self.light, serializer);
+    sse_encode_list_palette_swatch(self.swatches, serializer);
+    sse_encode_theme_colors(self.lightTheme, serializer);
+    sse_encode_theme_colors(self.darkTheme, serializer);
+  }
+
+  @protected
+  void sse_encode_cover_placeholder(
+    CoverPlaceholder self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.blurhash, serializer);
+    sse_encode_String(self.thumbhash, serializer);
+    sse_encode_list_prim_u_8_strict(self.thumbnail, serializer);
+    sse_encode_u_32(self.thumbnailWidth, serializer);
+    sse_encode_u_32(self.thumbnailHeight, serializer);
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+  }
+
+  @protected
+  void sse_encode_cover_style(CoverStyle self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_u_32(self.background, serializer);
+    sse_encode_u_32(self.gradientEnd, serializer);
+    sse_encode_u_32(self.textColor, serializer);
+    sse_encode_f_32(self.titleSize, serializer);
+    sse_encode_f_32(self.authorSize, serializer);
+    sse_encode_f_32(self.margin, serializer);
+  }
+
+  @protected
+  void sse_encode_coverage_report(
+    CoverageReport self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.totalCodepoints, serializer);
+    sse_encode_list_prim_u_32_strict(self.missingCodepoints, serializer);
+    sse_encode_f_64(self.coverage, serializer);
+  }
+
+  @protected
+  void sse_encode_dark_image(DarkImage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_book_image(self.image, serializer);
+    sse_encode_dark_image_mode(self.mode, serializer);
+    sse_encode_f_32(self.backgroundShare, serializer);
+    sse_encode_bool(self.lineArt, serializer);
+  }
+
+  @protected
+  void sse_encode_dark_image_mode(
+    DarkImageMode self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_dark_image_options(
+    DarkImageOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_dark_image_mode(self.mode, serializer);
+    sse_encode_u_32(self.background, serializer);
+    sse_encode_u_32(self.foreground, serializer);
+    sse_encode_u_8(self.whiteLevel, serializer);
+    sse_encode_f_32(self.minBackgroundShare, serializer);
+    sse_encode_f_32(self.brightness, serializer);
+  }
+
+  @protected
+  void sse_encode_decoded_text(DecodedText self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.text, serializer);
+    sse_encode_String(self.encoding, serializer);
+    sse_encode_f_32(self.confidence, serializer);
+    sse_encode_bool(self.hadErrors, serializer);
+    sse_encode_list_encoding_candidate(self.candidates, serializer);
+  }
+
+  @protected
+  void sse_encode_dedup_options(DedupOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self.minScore, serializer);
+    sse_encode_bool(self.compareContent, serializer);
+    sse_encode_list_book_alias(self.aliases, serializer);
+  }
+
+  @protected
+  void sse_encode_dict_definition(
+    DictDefinition self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.dictId, serializer);
+    sse_encode_String(self.dictTitle, serializer);
+    sse_encode_String(self.headword, serializer);
+    sse_encode_String(self.html, serializer);
+  }
+
+  @protected
+  void sse_encode_dict_format(DictFormat self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_dict_info(DictInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.id, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_dict_format(self.format, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.description, serializer);
+    sse_encode_u_32(self.entryCount, serializer);
+    sse_encode_u_32(self.resourceFiles, serializer);
+  }
+
+  @protected
+  void sse_encode_diff_hunk(DiffHunk self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_diff_hunk_kind(self.kind, serializer);
+    sse_encode_u_32(self.oldIndex, serializer);
+    sse_encode_u_32(self.newIndex, serializer);
+    sse_encode_String(self.oldText, serializer);
+    sse_encode_String(self.newText, serializer);
+  }
+
+  @protected
+  void sse_encode_diff_hunk_kind(DiffHunkKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_download_checksum(
+    DownloadChecksum self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_checksum_algorithm(self.algorithm, serializer);
+    sse_encode_String(self.digest, serializer);
+    sse_encode_u_64(self.partSize, serializer);
+    sse_encode_list_String(self.partDigests, serializer);
+  }
+
+  @protected
+  void sse_encode_download_event(DownloadEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.taskId, serializer);
+    sse_encode_download_state(self.state, serializer);
+    sse_encode_u_64(self.downloaded, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.total, serializer);
+    sse_encode_u_32(self.attempt, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
+
+  @protected
+  void sse_encode_download_header(
+    DownloadHeader self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.value, serializer);
+  }
+
+  @protected
+  void sse_encode_download_request(
+    DownloadRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_String(self.destination, serializer);
+    sse_encode_list_download_header(self.headers, serializer);
+    sse_encode_opt_box_autoadd_download_checksum(self.checksum, serializer);
+  }
+
+  @protected
+  void sse_encode_download_state(DownloadState self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_downloader_config(
+    DownloaderConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.maxConcurrent, serializer);
+    sse_encode_u_32(self.maxPerHost, serializer);
+    sse_encode_u_32(self.maxRetries, serializer);
+    sse_encode_u_32(self.initialBackoffMs, serializer);
+    sse_encode_u_32(self.timeoutSecs, serializer);
+    sse_encode_String(self.userAgent, serializer);
+  }
+
+  @protected
+  void sse_encode_drm_scheme(DrmScheme self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_drm_status(DrmStatus self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_book_format(self.format, serializer);
+    sse_encode_opt_box_autoadd_drm_scheme(self.scheme, serializer);
+  }
+
+  @protected
+  void sse_encode_duplicate_cluster(
+    DuplicateCluster self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_library_book(self.books, serializer);
+    sse_encode_f_32(self.score, serializer);
+    sse_encode_bool(self.contentMatch, serializer);
+  }
+
+  @protected
+  void sse_encode_duplicate_match(
+    DuplicateMatch self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_library_book(self.book, serializer);
+    sse_encode_f_32(self.score, serializer);
+  }
+
+  @protected
+  void sse_encode_emphasis_span(EmphasisSpan self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.end, serializer);
+  }
+
+  @protected
+  void sse_encode_encoding_candidate(
+    EncodingCandidate self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.encoding, serializer);
+    sse_encode_f_32(self.confidence, serializer);
+  }
+
+  @protected
+  void sse_encode_epub_export_chapter(
+    EpubExportChapter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.text, serializer);
+  }
+
+  @protected
+  void sse_encode_epub_export_progress(
+    EpubExportProgress self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.writtenChapters, serializer);
+    sse_encode_u_32(self.totalChapters, serializer);
+  }
+
+  @protected
+  void sse_encode_epub_layout(EpubLayout self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_epub_merge_source(
+    EpubMergeSource self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_opt_String(self.title, serializer);
+  }
+
+  @protected
+  void sse_encode_epub_resource(EpubResource self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.href, serializer);
+    sse_encode_String(self.mediaType, serializer);
+    sse_encode_resource_kind(self.kind, serializer);
+    sse_encode_u_32(self.size, serializer);
+  }
+
+  @protected
+  void sse_encode_epub_split_options(
+    EpubSplitOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_u_32(self.tocDepth, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.maxVolumeBytes, serializer);
+  }
+
+  @protected
+  void sse_encode_epub_volume(EpubVolume self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_u_32(self.chapterCount, serializer);
+  }
+
+  @protected
+  void sse_encode_error_code(ErrorCode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_extracted_file(ExtractedFile self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.index, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_box_autoadd_import_kind(self.kind, serializer);
+    sse_encode_opt_String(self.path, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
+
+  @protected
+  void sse_encode_extraction(Extraction self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.matched, serializer);
+    sse_encode_list_String(self.values, serializer);
+  }
+
+  @protected
+  void sse_encode_f_32(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat32(self);
+  }
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat64(self);
+  }
+
+  @protected
+  void sse_encode_feed(Feed self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.title, serializer);
+    sse_encode_opt_String(self.link, serializer);
+    sse_encode_list_feed_entry(self.entries, serializer);
+  }
+
+  @protected
+  void sse_encode_feed_entry(FeedEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_opt_String(self.link, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.published, serializer);
+    sse_encode_opt_String(self.summary, serializer);
+    sse_encode_opt_String(self.author, serializer);
+  }
+
+  @protected
+  void sse_encode_fetched_chapter(
+    FetchedChapter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.chapterIndex, serializer);
+    sse_encode_String(self.text, serializer);
+  }
+
+  @protected
+  void sse_encode_font_axis(FontAxis self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.tag, serializer);
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_f_32(self.minValue, serializer);
+    sse_encode_f_32(self.defaultValue, serializer);
+    sse_encode_f_32(self.maxValue, serializer);
+    sse_encode_bool(self.hidden, serializer);
+  }
+
+  @protected
+  void sse_encode_font_bundle(FontBundle self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.codepoints, serializer);
+    sse_encode_u_32(self.added, serializer);
+    sse_encode_u_32(self.bytes, serializer);
+  }
+
+  @protected
+  void sse_encode_font_cache_entry(
+    FontCacheEntry self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_opt_String(self.familyName, serializer);
+    sse_encode_u_32(self.sizeBytes, serializer);
+    sse_encode_i_64(self.lastUsed, serializer);
+    sse_encode_list_String(self.bookIds, serializer);
+    sse_encode_bool(self.pinned, serializer);
+  }
+
+  @protected
+  void sse_encode_font_cache_stats(
+    FontCacheStats self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.entryCount, serializer);
+    sse_encode_u_32(self.totalBytes, serializer);
+    sse_encode_u_32(self.maxBytes, serializer);
+  }
+
+  @protected
+  void sse_encode_font_info(FontInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.familyName, serializer);
+    sse_encode_String(self.styleName, serializer);
+    sse_encode_opt_String(self.fullName, serializer);
+    sse_encode_opt_String(self.postscriptName, serializer);
+    sse_encode_u_16(self.weight, serializer);
+    sse_encode_bool(self.isItalic, serializer);
+    sse_encode_bool(self.isMonospaced, serializer);
+    sse_encode_bool(self.isVariable, serializer);
+    sse_encode_list_font_axis(self.axes, serializer);
+    sse_encode_list_named_instance(self.namedInstances, serializer);
+    sse_encode_u_16(self.unitsPerEm, serializer);
+    sse_encode_u_16(self.glyphCount, serializer);
+  }
+
+  @protected
+  void sse_encode_font_preview(FontPreview self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.rgba, serializer);
+    sse_encode_list_prim_u_8_strict(self.png, serializer);
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_u_32(self.missingChars, serializer);
+  }
+
+  @protected
+  void sse_encode_highlight(Highlight self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_i_64(self.id, serializer);
+    sse_encode_String(self.bookId, serializer);
+    sse_encode_u_32(self.chapterIndex, serializer);
+    sse_encode_u_32(self.startOffset, serializer);
+    sse_encode_u_32(self.endOffset, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_opt_String(self.note, serializer);
+    sse_encode_u_32(self.color, serializer);
+    sse_encode_i_64(self.createdAt, serializer);
+  }
+
+  @protected
+  void sse_encode_http_cache_entry(
+    HttpCacheEntry self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_opt_String(self.contentType, serializer);
+    sse_encode_u_32(self.size, serializer);
+    sse_encode_bool(self.fresh, serializer);
+    sse_encode_i_64(self.storedAt, serializer);
+  }
+
+  @protected
+  void sse_encode_http_cache_stats(
+    HttpCacheStats self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.entryCount, serializer);
+    sse_encode_u_32(self.totalBytes, serializer);
+    sse_encode_u_32(self.maxBytes, serializer);
+  }
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
+  }
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putPlatformInt64(self);
+  }
+
+  @protected
+  void sse_encode_import_event(ImportEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_import_event_kind(self.kind, serializer);
+    sse_encode_u_32(self.fileIndex, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_u_32(self.done, serializer);
+    sse_encode_u_32(self.total, serializer);
+    sse_encode_opt_String(self.message, serializer);
+    sse_encode_opt_box_autoadd_processed_book(self.book, serializer);
+    sse_encode_opt_box_autoadd_import_report(self.report, serializer);
+  }
+
+  @protected
+  void sse_encode_import_event_kind(
+    ImportEventKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_import_issue(ImportIssue self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_import_kind(ImportKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_import_options(ImportOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.overrideEncoding, serializer);
+    sse_encode_opt_list_txt_chapter_rule(self.headingRules, serializer);
+    sse_encode_list_purify_rule(self.purifyRules, serializer);
+    sse_encode_u_32(self.charsPerMinute, serializer);
+  }
+
+  @protected
+  void sse_encode_import_report(ImportReport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.imported, serializer);
+    sse_encode_list_import_issue(self.skipped, serializer);
+    sse_encode_list_import_issue(self.failed, serializer);
+    sse_encode_list_import_issue(self.warnings, serializer);
+  }
+
+  @protected
+  void sse_encode_keyword(Keyword self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.term, serializer);
+    sse_encode_keyword_kind(self.kind, serializer);
+    sse_encode_u_32(self.count, serializer);
+    sse_encode_f_32(self.score, serializer);
+  }
+
+  @protected
+  void sse_encode_keyword_kind(KeywordKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_kinsoku_rules(KinsokuRules self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.noLineStart, serializer);
+    sse_encode_String(self.noLineEnd, serializer);
+  }
+
+  @protected
+  void sse_encode_language_guess(LanguageGuess self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.code, serializer);
+    sse_encode_f_32(self.confidence, serializer);
+  }
+
+  @protected
+  void sse_encode_layout_cache_stats(
+    LayoutCacheStats self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.entryCount, serializer);
+    sse_encode_u_32(self.totalBytes, serializer);
+    sse_encode_u_32(self.maxBytes, serializer);
+  }
+
+  @protected
+  void sse_encode_library_book(LibraryBook self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_opt_String(self.author, serializer);
+    sse_encode_opt_String(self.intro, serializer);
+    sse_encode_opt_String(self.kind, serializer);
+    sse_encode_opt_String(self.coverUrl, serializer);
+    sse_encode_opt_String(self.sourceUrl, serializer);
+    sse_encode_opt_String(self.bookUrl, serializer);
+    sse_encode_opt_String(self.tocUrl, serializer);
+    sse_encode_opt_String(self.latestChapterTitle, serializer);
+    sse_encode_u_32(self.chapterCount, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.wordCount, serializer);
+    sse_encode_i_64(self.addedAt, serializer);
+    sse_encode_i_64(self.updatedAt, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.lastReadAt, serializer);
+  }
+
+  @protected
+  void sse_encode_library_chapter(
+    LibraryChapter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.index, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_opt_String(self.url, serializer);
+    sse_encode_bool(self.isVolume, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.wordCount, serializer);
+  }
+
+  @protected
+  void sse_encode_library_search_result(
+    LibrarySearchResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_library_book(self.book, serializer);
+    sse_encode_f_32(self.score, serializer);
+    sse_encode_list_match_range(self.titleMatches, serializer);
+    sse_encode_list_match_range(self.authorMatches, serializer);
+  }
+
+  @protected
+  void sse_encode_line_break(LineBreak self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.offset, serializer);
+    sse_encode_bool(self.mandatory, serializer);
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_alternative_source(
+    List<AlternativeSource> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_alternative_source(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_archive_entry(
+    List<ArchiveEntry> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_archive_entry(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_audio_segment(
+    List<AudioSegment> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_audio_segment(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_axis_value(
+    List<AxisValue> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_axis_value(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_book_alias(
+    List<BookAlias> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_book_alias(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_book_chapter(
+    List<BookChapter> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_book_chapter(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_book_reading_time(
+    List<BookReadingTime> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_book_reading_time(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_book_source(
+    List<BookSource> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_book_source(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_book_update_summary(
+    List<BookUpdateSummary> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_book_update_summary(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_bookmark(List<Bookmark> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_bookmark(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_calibre_book(
+    List<CalibreBook> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_calibre_book(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_calibre_format(
+    List<CalibreFormat> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_calibre_format(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_calibre_identifier(
+    List<CalibreIdentifier> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_calibre_identifier(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_chapter_image(
+    List<ChapterImage> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_chapter_image(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_chapter_range(
+    List<ChapterRange> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_chapter_range(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_chapter_update(
+    List<ChapterUpdate> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_chapter_update(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_cloud_config_value(
+    List<CloudConfigValue> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_cloud_config_value(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_collection_face(
+    List<CollectionFace> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_collection_face(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_comic_page(
+    List<ComicPage> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_comic_page(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_conversion_result(
+    List<ConversionResult> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_conversion_result(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_dict_definition(
+    List<DictDefinition> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_dict_definition(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_dict_info(
+    List<DictInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_dict_info(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_diff_hunk(
+    List<DiffHunk> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_diff_hunk(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_download_header(
+    List<DownloadHeader> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_download_header(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_duplicate_cluster(
+    List<DuplicateCluster> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_duplicate_cluster(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_duplicate_match(
+    List<DuplicateMatch> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_duplicate_match(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_emphasis_span(
+    List<EmphasisSpan> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_emphasis_span(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_encoding_candidate(
+    List<EncodingCandidate> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_encoding_candidate(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_epub_export_chapter(
+    List<EpubExportChapter> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_epub_export_chapter(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_epub_merge_source(
+    List<EpubMergeSource> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_epub_merge_source(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_epub_resource(
+    List<EpubResource> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_epub_resource(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_epub_volume(
+    List<EpubVolume> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_epub_volume(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_feed_entry(
+    List<FeedEntry> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_feed_entry(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_fetched_chapter(
+    List<FetchedChapter> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_fetched_chapter(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_font_axis(
+    List<FontAxis> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_font_axis(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_font_cache_entry(
+    List<FontCacheEntry> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_font_cache_entry(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_highlight(
+    List<Highlight> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_highlight(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_import_issue(
+    List<ImportIssue> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_import_issue(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_keyword(List<Keyword> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_keyword(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_language_guess(
+    List<LanguageGuess> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_language_guess(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_library_book(
+    List<LibraryBook> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_library_book(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_library_chapter(
+    List<LibraryChapter> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_library_chapter(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_library_search_result(
+    List<LibrarySearchResult> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_library_search_result(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_line_break(
+    List<LineBreak> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_line_break(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_log_field(
+    List<LogField> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_log_field(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_log_record(
+    List<LogRecord> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_log_record(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_match_range(
+    List<MatchRange> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_match_range(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_named_font(
+    List<NamedFont> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_named_font(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_named_instance(
+    List<NamedInstance> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_named_instance(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_opds_entry(
+    List<OpdsEntry> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_opds_entry(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_opds_link(
+    List<OpdsLink> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_opds_link(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_opt_box_autoadd_anchor_resolution(
+    List<AnchorResolution?> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_opt_box_autoadd_anchor_resolution(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_palette_swatch(
+    List<PaletteSwatch> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_palette_swatch(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_pinyin_span(
+    List<PinyinSpan> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_pinyin_span(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_post_step(
+    List<PostStep> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_post_step(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_prefetch_chapter(
+    List<PrefetchChapter> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_prefetch_chapter(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_prefetch_task(
+    List<PrefetchTask> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_prefetch_task(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_prim_i_64_strict(
+    Int64List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putInt64List(self);
+  }
+
+  @protected
+  void sse_encode_list_prim_u_32_loose(
+    List<int> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint32List(
+      self is Uint32List ? self : Uint32List.fromList(self),
+    );
+  }
+
+  @protected
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint32List(self);
+  }
+
+  @protected
+  void sse_encode_list_prim_u_8_loose(
+    List<int> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(
+      self is Uint8List ? self : Uint8List.fromList(self),
+    );
+  }
+
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
+  }
+
+  @protected
+  void sse_encode_list_purify_rule(
+    List<PurifyRule> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_purify_rule(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_purify_rule_match(
+    List<PurifyRuleMatch> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_purify_rule_match(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_rate_limit_status(
+    List<RateLimitStatus> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_rate_limit_status(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_readable_block(
+    List<ReadableBlock> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_readable_block(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_readable_span(
+    List<ReadableSpan> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_readable_span(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_reading_day(
+    List<ReadingDay> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_reading_day(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_reading_progress(
+    List<ReadingProgress> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_reading_progress(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_rule_test_match(
+    List<RuleTestMatch> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_rule_test_match(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_search_chapter(
+    List<SearchChapter> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_search_chapter(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_search_hit(
+    List<SearchHit> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_search_hit(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_source_book(
+    List<SourceBook> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_source_book(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_source_chapter(
+    List<SourceChapter> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_source_chapter(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_stored_book_scan(
+    List<StoredBookScan> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_stored_book_scan(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_stored_cookie(
+    List<StoredCookie> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_stored_cookie(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_tag_suggestion(
+    List<TagSuggestion> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_tag_suggestion(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_text_anchor(
+    List<TextAnchor> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_text_anchor(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_text_column(
+    List<TextColumn> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_text_column(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_text_line(
+    List<TextLine> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_text_line(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_text_page(
+    List<TextPage> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_text_page(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_text_segment(
+    List<TextSegment> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_text_segment(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_text_stats(
+    List<TextStats> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_text_stats(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_toc_entry(
+    List<TocEntry> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_toc_entry(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_translated_paragraph(
+    List<TranslatedParagraph> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_translated_paragraph(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_txt_chapter(
+    List<TxtChapter> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_txt_chapter(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_txt_chapter_rule(
+    List<TxtChapterRule> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_txt_chapter_rule(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_txt_heading_candidate(
+    List<TxtHeadingCandidate> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_txt_heading_candidate(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_update_check_book(
+    List<UpdateCheckBook> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_update_check_book(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_utterance(
+    List<Utterance> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_utterance(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_validation_issue(
+    List<ValidationIssue> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_validation_issue(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_vertical_glyph(
+    List<VerticalGlyph> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_vertical_glyph(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_vertical_page(
+    List<VerticalPage> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_vertical_page(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_watermark_char(
+    List<WatermarkChar> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_watermark_char(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_web_chapter(
+    List<WebChapter> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_web_chapter(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_web_dav_entry(
+    List<WebDavEntry> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_web_dav_entry(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_log_field(LogField self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.key, serializer);
+    sse_encode_String(self.value, serializer);
+  }
+
+  @protected
+  void sse_encode_log_level(LogLevel self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_log_record(LogRecord self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.timeMs, serializer);
+    sse_encode_log_level(self.level, serializer);
+    sse_encode_String(self.target, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_log_field(self.fields, serializer);
+  }
+
+  @protected
+  void sse_encode_logging_config(LoggingConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.capacity, serializer);
+    sse_encode_log_level(self.minLevel, serializer);
+    sse_encode_opt_String(self.directory, serializer);
+    sse_encode_u_64(self.maxFileBytes, serializer);
+    sse_encode_u_32(self.maxFiles, serializer);
+  }
+
+  @protected
+  void sse_encode_mapped_text_slice(
+    MappedTextSlice self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.text, serializer);
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.end, serializer);
+  }
+
+  @protected
+  void sse_encode_mapped_txt_info(
+    MappedTxtInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.id, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_txt_index(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_match_range(MatchRange self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.end, serializer);
+  }
+
+  @protected
+  void sse_encode_metadata_guess(MetadataGuess self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.value, serializer);
+    sse_encode_f_32(self.confidence, serializer);
+    sse_encode_metadata_origin(self.origin, serializer);
+  }
+
+  @protected
+  void sse_encode_metadata_origin(
+    MetadataOrigin self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_named_font(NamedFont self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_list_prim_u_8_strict(self.data, serializer);
+  }
+
+  @protected
+  void sse_encode_named_instance(NamedInstance self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_opt_String(self.postscriptName, serializer);
+    sse_encode_list_axis_value(self.coordinates, serializer);
+  }
+
+  @protected
+  void sse_encode_network_type(NetworkType self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_notes_export_options(
+    NotesExportOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_notes_format(self.format, serializer);
+    sse_encode_bool(self.includeBookmarks, serializer);
+    sse_encode_i_32(self.utcOffsetMinutes, serializer);
+  }
+
+  @protected
+  void sse_encode_notes_format(NotesFormat self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_opds_credentials(
+    OpdsCredentials self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.username, serializer);
+    sse_encode_String(self.password, serializer);
+  }
+
+  @protected
+  void sse_encode_opds_entry(OpdsEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.id, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_list_String(self.authors, serializer);
+    sse_encode_opt_String(self.summary, serializer);
+    sse_encode_opt_String(self.coverUrl, serializer);
+    sse_encode_opt_String(self.thumbnailUrl, serializer);
+    sse_encode_opt_String(self.navigationUrl, serializer);
+    sse_encode_list_opds_link(self.acquisitions, serializer);
+  }
+
+  @protected
+  void sse_encode_opds_feed(OpdsFeed self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.title, serializer);
+    sse_encode_list_opds_entry(self.entries, serializer);
+    sse_encode_opt_String(self.startUrl, serializer);
+    sse_encode_opt_String(self.nextUrl, serializer);
+    sse_encode_opt_String(self.previousUrl, serializer);
+    sse_encode_opt_String(self.searchTemplate, serializer);
+  }
+
+  @protected
+  void sse_encode_opds_link(OpdsLink self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.href, serializer);
+    sse_encode_opt_String(self.rel, serializer);
+    sse_encode_opt_String(self.mediaType, serializer);
+    sse_encode_opt_String(self.title, serializer);
+  }
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_anchor_resolution(
+    AnchorResolution? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_anchor_resolution(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_api_error(
+    ApiError? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_api_error(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_cached_audio(
+    CachedAudio? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_cached_audio(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_chapter_fingerprint(
+    ChapterFingerprint? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_chapter_fingerprint(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_download_checksum(
+    DownloadChecksum? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_download_checksum(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_drm_scheme(
+    DrmScheme? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_drm_scheme(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_http_cache_entry(
+    HttpCacheEntry? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_http_cache_entry(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_import_kind(
+    ImportKind? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_import_kind(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_import_report(
+    ImportReport? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_import_report(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_kinsoku_rules(
+    KinsokuRules? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_kinsoku_rules(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_library_book(
+    LibraryBook? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_library_book(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_metadata_guess(
+    MetadataGuess? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_metadata_guess(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_opds_credentials(
+    OpdsCredentials? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_opds_credentials(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_prefetch_pause(
+    PrefetchPause? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_prefetch_pause(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_processed_book(
+    ProcessedBook? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_processed_book(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_proxy_config(
+    ProxyConfig? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_proxy_config(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_rate_limit(
+    RateLimit? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_rate_limit(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_reading_progress(
+    ReadingProgress? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_reading_progress(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_sync_state(
+    SyncState? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_sync_state(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_text_segment(
+    TextSegment? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_text_segment(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_tts_position(
+    TtsPosition? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_tts_position(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_web_dav_sync_record(
+    WebDavSyncRecord? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_web_dav_sync_record(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_list_prim_u_32_strict(
+    Uint32List? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_prim_u_32_strict(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_prim_u_8_strict(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_list_txt_chapter_rule(
+    List<TxtChapterRule>? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_txt_chapter_rule(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_page_layout(PageLayout self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self.width, serializer);
+    sse_encode_f_32(self.height, serializer);
+    sse_encode_f_32(self.marginTop, serializer);
+    sse_encode_f_32(self.marginRight, serializer);
+    sse_encode_f_32(self.marginBottom, serializer);
+    sse_encode_f_32(self.marginLeft, serializer);
+    sse_encode_f_32(self.fontSize, serializer);
+    sse_encode_f_32(self.lineHeight, serializer);
+    sse_encode_f_32(self.paragraphSpacing, serializer);
+    sse_encode_f_32(self.indent, serializer);
+  }
+
+  @protected
+  void sse_encode_palette_swatch(PaletteSwatch self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.color, serializer);
+    sse_encode_f_32(self.population, serializer);
+  }
+
+  @protected
+  void sse_encode_parsed_book(ParsedBook self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_book_metadata(self.metadata, serializer);
+    sse_encode_list_book_chapter(self.chapters, serializer);
+    sse_encode_list_toc_entry(self.toc, serializer);
+  }
+
+  @protected
+  void sse_encode_performance_tier(
+    PerformanceTier self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_pinyin_span(PinyinSpan self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.text, serializer);
+    sse_encode_String(self.pinyin, serializer);
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.end, serializer);
+  }
+
+  @protected
+  void sse_encode_post_step(PostStep self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_post_step_kind(self.kind, serializer);
+    sse_encode_String(self.pattern, serializer);
+    sse_encode_String(self.replacement, serializer);
+  }
+
+  @protected
+  void sse_encode_post_step_kind(PostStepKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_prefetch_chapter(
+    PrefetchChapter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.index, serializer);
+    sse_encode_download_request(self.request, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.length, serializer);
+  }
+
+  @protected
+  void sse_encode_prefetch_config(
+    PrefetchConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.maxChapters, serializer);
+    sse_encode_u_32(self.defaultChapters, serializer);
+    sse_encode_u_32(self.lookaheadMinutes, serializer);
+    sse_encode_bool(self.allowMetered, serializer);
+  }
+
+  @protected
+  void sse_encode_prefetch_pause(PrefetchPause self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_prefetch_plan(PrefetchPlan self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.key, serializer);
+    sse_encode_u_32(self.currentIndex, serializer);
+    sse_encode_list_prefetch_chapter(self.chapters, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.charsPerMinute, serializer);
+    sse_encode_network_type(self.network, serializer);
+  }
+
+  @protected
+  void sse_encode_prefetch_status(
+    PrefetchStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.key, serializer);
+    sse_encode_opt_box_autoadd_prefetch_pause(self.paused, serializer);
+    sse_encode_list_prefetch_task(self.tasks, serializer);
+  }
+
+  @protected
+  void sse_encode_prefetch_task(PrefetchTask self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.chapterIndex, serializer);
+    sse_encode_u_32(self.taskId, serializer);
+    sse_encode_download_state(self.state, serializer);
+  }
+
+  @protected
+  void sse_encode_processed_book(ProcessedBook self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_parsed_book(self.book, serializer);
+    sse_encode_String(self.encoding, serializer);
+    sse_encode_book_stats(self.stats, serializer);
+  }
+
+  @protected
+  void sse_encode_processed_image(
+    ProcessedImage self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.data, serializer);
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_u_32(self.sourceWidth, serializer);
+    sse_encode_u_32(self.sourceHeight, serializer);
+  }
+
+  @protected
+  void sse_encode_proxy_config(ProxyConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_proxy_kind(self.kind, serializer);
+    sse_encode_String(self.host, serializer);
+    sse_encode_u_16(self.port, serializer);
+    sse_encode_opt_String(self.username, serializer);
+    sse_encode_opt_String(self.password, serializer);
+  }
+
+  @protected
+  void sse_encode_proxy_kind(ProxyKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_punctuation_repair(
+    PunctuationRepair self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.text, serializer);
+    sse_encode_u_32(self.changes, serializer);
+    sse_encode_u_32(self.spacesRemoved, serializer);
+    sse_encode_u_32(self.quotesFixed, serializer);
+    sse_encode_u_32(self.bracketsReplaced, serializer);
+  }
+
+  @protected
+  void sse_encode_purify_report(PurifyReport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.text, serializer);
+    sse_encode_list_purify_rule_match(self.matches, serializer);
+  }
+
+  @protected
+  void sse_encode_purify_rule(PurifyRule self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_purify_rule_kind(self.kind, serializer);
+    sse_encode_String(self.pattern, serializer);
+    sse_encode_String(self.replacement, serializer);
+    sse_encode_bool(self.enabled, serializer);
+  }
+
+  @protected
+  void sse_encode_purify_rule_kind(
+    PurifyRuleKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_purify_rule_match(
+    PurifyRuleMatch self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.ruleIndex, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_u_32(self.count, serializer);
+    sse_encode_list_String(self.samples, serializer);
+  }
+
+  @protected
+  void sse_encode_rate_limit(RateLimit self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self.requestsPerSecond, serializer);
+    sse_encode_u_32(self.burst, serializer);
+    sse_encode_u_32(self.jitterMs, serializer);
+    sse_encode_u_32(self.cooldownSecs, serializer);
+  }
+
+  @protected
+  void sse_encode_rate_limit_status(
+    RateLimitStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.host, serializer);
+    sse_encode_u_32(self.waiting, serializer);
+    sse_encode_u_32(self.available, serializer);
+    sse_encode_u_64(self.cooldownMs, serializer);
+  }
+
+  @protected
+  void sse_encode_readable_block(ReadableBlock self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_readable_block_kind(self.kind, serializer);
+    sse_encode_list_readable_span(self.spans, serializer);
+  }
+
+  @protected
+  void sse_encode_readable_block_kind(
+    ReadableBlockKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_readable_content(
+    ReadableContent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_list_readable_block(self.blocks, serializer);
+  }
+
+  @protected
+  void sse_encode_readable_span(ReadableSpan self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.text, serializer);
+    sse_encode_opt_String(self.imageSrc, serializer);
+    sse_encode_bool(self.bold, serializer);
+    sse_encode_bool(self.italic, serializer);
+  }
+
+  @protected
+  void sse_encode_reading_day(ReadingDay self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.date, serializer);
+    sse_encode_u_64(self.seconds, serializer);
+  }
+
+  @protected
+  void sse_encode_reading_progress(
+    ReadingProgress self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.bookId, serializer);
+    sse_encode_u_32(self.chapterIndex, serializer);
+    sse_encode_u_32(self.chapterOffset, serializer);
+    sse_encode_f_64(self.percent, serializer);
+    sse_encode_i_64(self.updatedAt, serializer);
+  }
+
+  @protected
+  void sse_encode_reading_stats(ReadingStats self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.totalSeconds, serializer);
+    sse_encode_list_reading_day(self.days, serializer);
+    sse_encode_list_book_reading_time(self.books, serializer);
+    sse_encode_u_32(self.currentStreak, serializer);
+    sse_encode_u_32(self.longestStreak, serializer);
+  }
+
+  @protected
+  void sse_encode_repair_level(RepairLevel self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_resource_kind(ResourceKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_resplit_outcome(
+    ResplitOutcome self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.titles, serializer);
+    sse_encode_u_32(self.movedBookmarks, serializer);
+    sse_encode_u_32(self.movedHighlights, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.chapterIndex, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.chapterOffset, serializer);
+  }
+
+  @protected
+  void sse_encode_restore_result(RestoreResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.booksAdded, serializer);
+    sse_encode_u_32(self.booksUpdated, serializer);
+    sse_encode_u_32(self.progressUpdated, serializer);
+    sse_encode_u_32(self.bookmarksAdded, serializer);
+    sse_encode_u_32(self.highlightsAdded, serializer);
+    sse_encode_u_32(self.purifyRulesAdded, serializer);
+    sse_encode_u_32(self.bookSourcesAdded, serializer);
+    sse_encode_list_purify_rule(self.purifyRules, serializer);
+    sse_encode_String(self.bookSourcesJson, serializer);
+  }
+
+  @protected
+  void sse_encode_rule_test(RuleTest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_rule_test_match(self.matches, serializer);
+    sse_encode_u_32(self.matchCount, serializer);
+    sse_encode_String(self.output, serializer);
+    sse_encode_u_64(self.compileUs, serializer);
+    sse_encode_u_64(self.matchUsPerMib, serializer);
+    sse_encode_bool(self.slow, serializer);
+  }
+
+  @protected
+  void sse_encode_rule_test_match(
+    RuleTestMatch self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.end, serializer);
+    sse_encode_String(self.text, serializer);
+  }
+
+  @protected
+  void sse_encode_scraped_metadata(
+    ScrapedMetadata self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_metadata_guess(self.title, serializer);
+    sse_encode_opt_box_autoadd_metadata_guess(self.author, serializer);
+    sse_encode_list_String(self.tags, serializer);
+  }
+
+  @protected
+  void sse_encode_search_chapter(SearchChapter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.chapterIndex, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.text, serializer);
+  }
+
+  @protected
+  void sse_encode_search_hit(SearchHit self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.bookId, serializer);
+    sse_encode_u_32(self.chapterIndex, serializer);
+    sse_encode_String(self.chapterTitle, serializer);
+    sse_encode_u_32(self.offset, serializer);
+    sse_encode_u_32(self.length, serializer);
+    sse_encode_String(self.snippet, serializer);
+    sse_encode_u_32(self.highlightStart, serializer);
+    sse_encode_u_32(self.highlightEnd, serializer);
+  }
+
+  @protected
+  void sse_encode_search_rules(SearchRules self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.bookList, serializer);
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_opt_String(self.author, serializer);
+    sse_encode_opt_String(self.intro, serializer);
+    sse_encode_opt_String(self.kind, serializer);
+    sse_encode_opt_String(self.lastChapter, serializer);
+    sse_encode_opt_String(self.coverUrl, serializer);
+    sse_encode_opt_String(self.bookUrl, serializer);
+    sse_encode_opt_String(self.wordCount, serializer);
+  }
+
+  @protected
+  void sse_encode_source_book(SourceBook self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_String(self.author, serializer);
+    sse_encode_opt_String(self.intro, serializer);
+    sse_encode_opt_String(self.kind, serializer);
+    sse_encode_opt_String(self.lastChapter, serializer);
+    sse_encode_opt_String(self.coverUrl, serializer);
+    sse_encode_opt_String(self.wordCount, serializer);
+    sse_encode_String(self.bookUrl, serializer);
+    sse_encode_String(self.tocUrl, serializer);
+  }
+
+  @protected
+  void sse_encode_source_chapter(SourceChapter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.url, serializer);
+    sse_encode_bool(self.isVolume, serializer);
+  }
+
+  @protected
+  void sse_encode_ssml_options(SsmlOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.language, serializer);
+    sse_encode_u_32(self.paragraphBreakMs, serializer);
+    sse_encode_opt_String(self.dialoguePitch, serializer);
+    sse_encode_opt_String(self.dialogueRate, serializer);
+  }
+
+  @protected
+  void sse_encode_stored_book_scan(
+    StoredBookScan self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.bookId, serializer);
+    sse_encode_u_32(self.corruptRecords, serializer);
+    sse_encode_list_prim_u_32_strict(self.lostChapters, serializer);
+  }
+
+  @protected
+  void sse_encode_stored_cookie(StoredCookie self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.value, serializer);
+    sse_encode_String(self.domain, serializer);
+    sse_encode_bool(self.hostOnly, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.expires, serializer);
+    sse_encode_bool(self.secure, serializer);
+    sse_encode_bool(self.httpOnly, serializer);
+  }
+
+  @protected
+  void sse_encode_styled_chapter(StyledChapter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.html, serializer);
+    sse_encode_String(self.css, serializer);
+  }
+
+  @protected
+  void sse_encode_summary_options(
+    SummaryOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.maxChars, serializer);
+    sse_encode_u_32(self.maxSentences, serializer);
+  }
+
+  @protected
+  void sse_encode_sync_result(SyncResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.version, serializer);
+    sse_encode_u_32(self.uploaded, serializer);
+    sse_encode_u_32(self.downloaded, serializer);
+    sse_encode_u_32(self.progressUpdated, serializer);
+    sse_encode_u_32(self.bookmarksAdded, serializer);
+    sse_encode_u_32(self.highlightsAdded, serializer);
+    sse_encode_u_32(self.bookmarksRemoved, serializer);
+    sse_encode_u_32(self.highlightsRemoved, serializer);
+    sse_encode_sync_state(self.state, serializer);
+  }
+
+  @protected
+  void sse_encode_sync_state(SyncState self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.remoteId, serializer);
+    sse_encode_u_32(self.version, serializer);
+    sse_encode_list_String(self.fingerprints, serializer);
+  }
+
+  @protected
+  void sse_encode_tag_suggestion(TagSuggestion self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.tag, serializer);
+    sse_encode_f_32(self.density, serializer);
+  }
+
+  @protected
+  void sse_encode_text_anchor(TextAnchor self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.exact, serializer);
+    sse_encode_String(self.prefix, serializer);
+    sse_encode_String(self.suffix, serializer);
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.end, serializer);
+  }
+
+  @protected
+  void sse_encode_text_column(TextColumn self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.end, serializer);
+    sse_encode_bool(self.paragraphStart, serializer);
+    sse_encode_f_32(self.x, serializer);
+    sse_encode_f_32(self.height, serializer);
+    sse_encode_list_vertical_glyph(self.glyphs, serializer);
+  }
+
+  @protected
+  void sse_encode_text_line(TextLine self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.end, serializer);
+    sse_encode_bool(self.paragraphStart, serializer);
+    sse_encode_f_32(self.width, serializer);
+    sse_encode_bool(self.hyphenated, serializer);
+  }
+
+  @protected
+  void sse_encode_text_page(TextPage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.end, serializer);
+    sse_encode_list_text_line(self.lines, serializer);
+  }
+
+  @protected
+  void sse_encode_text_segment(TextSegment self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.text, serializer);
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.end, serializer);
+    sse_encode_bool(self.isWord, serializer);
+  }
+
+  @protected
+  void sse_encode_text_stats(TextStats self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.cjkChars, serializer);
+    sse_encode_u_32(self.latinWords, serializer);
+    sse_encode_u_32(self.punctuation, serializer);
+    sse_encode_f_32(self.readingMinutes, serializer);
+  }
+
+  @protected
+  void sse_encode_theme_colors(ThemeColors self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.background, serializer);
+    sse_encode_u_32(self.foreground, serializer);
+    sse_encode_u_32(self.accent, serializer);
+  }
+
+  @protected
+  void sse_encode_tier_settings(TierSettings self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.prefetchDepth, serializer);
+    sse_encode_u_8(self.imageQuality, serializer);
+    sse_encode_bool(self.animations, serializer);
+  }
 
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-        stem: 'novella_native',
-        ioDirectory: 'rust/target/release/',
-        webPrefix: 'pkg/',
-      );
-}
+  @protected
+  void sse_encode_toc_entry(TocEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.href, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.chapterIndex, serializer);
+    sse_encode_u_32(self.depth, serializer);
+  }
 
-abstract class RustLibApi extends BaseApi {
-  Future<Uint8List> crateApiFontConverterConvertWoff2ToTtf({
-    required List<int> woff2Data,
-  });
+  @protected
+  void sse_encode_toc_rules(TocRules self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.chapterList, serializer);
+    sse_encode_opt_String(self.chapterName, serializer);
+    sse_encode_opt_String(self.chapterUrl, serializer);
+    sse_encode_opt_String(self.isVolume, serializer);
+    sse_encode_opt_String(self.nextTocUrl, serializer);
+  }
 
-  Future<Uint32List> crateApiFontConverterExtractInvisibleCodepoints({
-    required List<int> ttfData,
-  });
-}
+  @protected
+  void sse_encode_translated_paragraph(
+    TranslatedParagraph self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.original, serializer);
+    sse_encode_String(self.translation, serializer);
+  }
 
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
+  @protected
+  void sse_encode_translation_provider(
+    TranslationProvider self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
 
-  @override
-  Future<Uint8List> crateApiFontConverterConvertWoff2ToTtf({
-    required List<int> woff2Data,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(woff2Data, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 1,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiFontConverterConvertWoff2ToTtfConstMeta,
-        argValues: [woff2Data],
-        apiImpl: this,
-      ),
-    );
+  @protected
+  void sse_encode_translator_config(
+    TranslatorConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_translation_provider(self.provider, serializer);
+    sse_encode_String(self.endpoint, serializer);
+    sse_encode_opt_String(self.apiKey, serializer);
+    sse_encode_opt_String(self.model, serializer);
+    sse_encode_opt_String(self.sourceLanguage, serializer);
+    sse_encode_String(self.targetLanguage, serializer);
+    sse_encode_u_32(self.timeoutSecs, serializer);
   }
 
-  TaskConstMeta get kCrateApiFontConverterConvertWoff2ToTtfConstMeta =>
-      const TaskConstMeta(
-        debugName: "convert_woff2_to_ttf",
-        argNames: ["woff2Data"],
-      );
+  @protected
+  void sse_encode_ttf_chunk(TtfChunk self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.offset, serializer);
+    sse_encode_u_32(self.totalLen, serializer);
+    sse_encode_list_prim_u_8_strict(self.data, serializer);
+  }
 
-  @override
-  Future<Uint32List> crateApiFontConverterExtractInvisibleCodepoints({
-    required List<int> ttfData,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(ttfData, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 2,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_32_strict,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiFontConverterExtractInvisibleCodepointsConstMeta,
-        argValues: [ttfData],
-        apiImpl: this,
-      ),
-    );
+  @protected
+  void sse_encode_tts_audio_chunk(
+    TtsAudioChunk self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.utteranceId, serializer);
+    sse_encode_u_32(self.sampleRate, serializer);
+    sse_encode_list_prim_u_8_strict(self.pcm, serializer);
   }
 
-  TaskConstMeta get kCrateApiFontConverterExtractInvisibleCodepointsConstMeta =>
-      const TaskConstMeta(
-        debugName: "extract_invisible_codepoints",
-        argNames: ["ttfData"],
-      );
+  @protected
+  void sse_encode_tts_options(TtsOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self.speed, serializer);
+    sse_encode_f_32(self.pitch, serializer);
+    sse_encode_u_32(self.speaker, serializer);
+  }
 
   @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+  void sse_encode_tts_position(TtsPosition self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.utteranceId, serializer);
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.end, serializer);
+    sse_encode_u_32(self.offset, serializer);
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  void sse_encode_tts_voice_info(TtsVoiceInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.sampleRate, serializer);
+    sse_encode_u_32(self.speakers, serializer);
+    sse_encode_String(self.language, serializer);
+    sse_encode_bool(self.usesLexicon, serializer);
   }
 
   @protected
-  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint32List;
+  void sse_encode_txt_chapter(TxtChapter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.end, serializer);
   }
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
+  void sse_encode_txt_chapter_proposal(
+    TxtChapterProposal self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.encoding, serializer);
+    sse_encode_u_32(self.fileSize, serializer);
+    sse_encode_list_txt_heading_candidate(self.headings, serializer);
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void sse_encode_txt_chapter_rule(
+    TxtChapterRule self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.pattern, serializer);
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_txt_heading_candidate(
+    TxtHeadingCandidate self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.title, serializer);
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.line, serializer);
+    sse_encode_f_32(self.confidence, serializer);
+    sse_encode_bool(self.selected, serializer);
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_txt_index(TxtIndex self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.encoding, serializer);
+    sse_encode_u_32(self.fileSize, serializer);
+    sse_encode_list_txt_chapter(self.chapters, serializer);
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_typography_options(
+    TypographyOptions self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    sse_encode_bool(self.fullWidthPunctuation, serializer);
+    sse_encode_bool(self.collapseBlankLines, serializer);
+    sse_encode_bool(self.indentParagraphs, serializer);
+    sse_encode_bool(self.unifyEllipsesAndDashes, serializer);
+    sse_encode_bool(self.curlyQuotes, serializer);
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_u_16(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    serializer.buffer.putUint16(self);
   }
 
   @protected
-  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer) {
+  void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint32List(len_);
+    serializer.buffer.putUint32(self);
   }
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    serializer.buffer.putBigUint64(self);
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_u_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    serializer.buffer.putUint8(self);
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_unit(void self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_update_check_book(
+    UpdateCheckBook self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_String(self.bookId, serializer);
+    sse_encode_book_source(self.source, serializer);
+    sse_encode_String(self.tocUrl, serializer);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_update_check_status(
+    UpdateCheckStatus self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_utterance(Utterance self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.end, serializer);
+    sse_encode_opt_String(self.ssml, serializer);
   }
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_validation_issue(
+    ValidationIssue self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_validation_issue_kind(self.kind, serializer);
+    sse_encode_opt_String(self.table, serializer);
     sse_encode_String(self.message, serializer);
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_validation_issue_kind(
+    ValidationIssueKind self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_32_strict(
-    Uint32List self,
+  void sse_encode_validation_report(
+    ValidationReport self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint32List(self);
+    sse_encode_bool(self.isValid, serializer);
+    sse_encode_bool(self.isRepairable, serializer);
+    sse_encode_list_validation_issue(self.issues, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_loose(
-    List<int> self,
+  void sse_encode_vertical_glyph(VerticalGlyph self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.offset, serializer);
+    sse_encode_u_32(self.len, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_f_32(self.y, serializer);
+    sse_encode_f_32(self.advance, serializer);
+    sse_encode_bool(self.rotated, serializer);
+    sse_encode_bool(self.vertFeature, serializer);
+  }
+
+  @protected
+  void sse_encode_vertical_page(VerticalPage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.end, serializer);
+    sse_encode_list_text_column(self.columns, serializer);
+  }
+
+  @protected
+  void sse_encode_watermark_char(WatermarkChar self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.codepoint, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_watermark_kind(self.kind, serializer);
+    sse_encode_String(self.replacement, serializer);
+    sse_encode_u_32(self.count, serializer);
+    sse_encode_list_prim_u_32_strict(self.offsets, serializer);
+  }
+
+  @protected
+  void sse_encode_watermark_kind(WatermarkKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_watermark_report(
+    WatermarkReport self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(
-      self is Uint8List ? self : Uint8List.fromList(self),
-    );
+    sse_encode_list_watermark_char(self.chars, serializer);
+    sse_encode_u_32(self.total, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_web_book(WebBook self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_String(self.author, serializer);
+    sse_encode_String(self.url, serializer);
+    sse_encode_list_web_chapter(self.chapters, serializer);
+    sse_encode_f_32(self.confidence, serializer);
+  }
+
+  @protected
+  void sse_encode_web_chapter(WebChapter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.index, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.url, serializer);
+  }
+
+  @protected
+  void sse_encode_web_dav_config(WebDavConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.baseUrl, serializer);
+    sse_encode_String(self.username, serializer);
+    sse_encode_String(self.password, serializer);
+  }
+
+  @protected
+  void sse_encode_web_dav_entry(WebDavEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_bool(self.isDir, serializer);
+    sse_encode_u_64(self.size, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.modified, serializer);
+    sse_encode_opt_String(self.etag, serializer);
+  }
+
+  @protected
+  void sse_encode_web_dav_sync_record(
+    WebDavSyncRecord self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sse_encode_String(self.hash, serializer);
+    sse_encode_opt_String(self.etag, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.modified, serializer);
   }
 
   @protected
-  void sse_encode_u_32(int self, SseSerializer serializer) {
+  void sse_encode_web_dav_sync_status(
+    WebDavSyncStatus self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint32(self);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
+  void sse_encode_web_import_progress(
+    WebImportProgress self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
+    sse_encode_u_32(self.done, serializer);
+    sse_encode_u_32(self.total, serializer);
+    sse_encode_u_32(self.chapterIndex, serializer);
+    sse_encode_bool(self.skipped, serializer);
+    sse_encode_opt_String(self.error, serializer);
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_web_server_config(
+    WebServerConfig self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    sse_encode_u_16(self.port, serializer);
+    sse_encode_opt_String(self.uploadDir, serializer);
+    sse_encode_opt_String(self.accessToken, serializer);
+    sse_encode_u_32(self.maxUploadMb, serializer);
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_web_server_info(
+    WebServerInfo self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_u_16(self.port, serializer);
+    sse_encode_list_String(self.urls, serializer);
+  }
+
+  @protected
+  void sse_encode_web_upload(WebUpload self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_import_kind(self.kind, serializer);
   }
 }
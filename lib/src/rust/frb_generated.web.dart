@@ -6,7 +6,101 @@
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
+import 'api/anchors.dart';
+import 'api/archive.dart';
+import 'api/audio_cache.dart';
+import 'api/backup.dart';
+import 'api/bench.dart';
+import 'api/bionic.dart';
+import 'api/blobs.dart';
+import 'api/book.dart';
+import 'api/book_image.dart';
+import 'api/book_source.dart';
+import 'api/calibre.dart';
+import 'api/chapter_cache.dart';
+import 'api/chapter_diff.dart';
+import 'api/chapter_sort.dart';
+import 'api/chapter_store.dart';
+import 'api/cloudctl.dart';
+import 'api/comic.dart';
+import 'api/cookies.dart';
+import 'api/cover.dart';
+import 'api/dark_image.dart';
+import 'api/db.dart';
+import 'api/dedup.dart';
+import 'api/dict.dart';
+import 'api/downloader.dart';
+import 'api/drm.dart';
+import 'api/encoding.dart';
+import 'api/epub.dart';
+import 'api/epub_export.dart';
+import 'api/epub_tools.dart';
+import 'api/error.dart';
+import 'api/extraction.dart';
+import 'api/fb2.dart';
+import 'api/feed.dart';
+import 'api/fingerprint.dart';
+import 'api/font_bundle.dart';
+import 'api/font_cache.dart';
+import 'api/font_collection.dart';
 import 'api/font_converter.dart';
+import 'api/font_preview.dart';
+import 'api/font_subset.dart';
+import 'api/font_validation.dart';
+import 'api/http_cache.dart';
+import 'api/hyphenation.dart';
+import 'api/image.dart';
+import 'api/import.dart';
+import 'api/keywords.dart';
+import 'api/langdetect.dart';
+import 'api/layout_cache.dart';
+import 'api/library_search.dart';
+import 'api/line_break.dart';
+import 'api/logging.dart';
+import 'api/mapped_txt.dart';
+import 'api/metadata.dart';
+import 'api/mobi.dart';
+import 'api/network.dart';
+import 'api/notes_export.dart';
+import 'api/opds.dart';
+import 'api/opencc.dart';
+import 'api/pagination.dart';
+import 'api/palette.dart';
+import 'api/pdf.dart';
+import 'api/placeholder.dart';
+import 'api/prefetch.dart';
+import 'api/punctuation.dart';
+import 'api/purify.dart';
+import 'api/rate_limit.dart';
+import 'api/read_chapters.dart';
+import 'api/readability.dart';
+import 'api/resplit.dart';
+import 'api/ruby.dart';
+import 'api/rule_test.dart';
+import 'api/sanitize.dart';
+import 'api/search.dart';
+import 'api/segment.dart';
+import 'api/sessions.dart';
+import 'api/source_switch.dart';
+import 'api/stats.dart';
+import 'api/summary.dart';
+import 'api/sync.dart';
+import 'api/task.dart';
+import 'api/translate.dart';
+import 'api/tts.dart';
+import 'api/tts_prep.dart';
+import 'api/tts_timeline.dart';
+import 'api/txt.dart';
+import 'api/txt_detect.dart';
+import 'api/typography.dart';
+import 'api/umd.dart';
+import 'api/update_check.dart';
+import 'api/vault.dart';
+import 'api/vertical.dart';
+import 'api/watermark.dart';
+import 'api/web_import.dart';
+import 'api/webdav.dart';
+import 'api/webserver.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
@@ -23,86 +117,5018 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
+  @protected
+  RustStreamSink<ArchiveProgress> dco_decode_StreamSink_archive_progress_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<DownloadEvent> dco_decode_StreamSink_download_event_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<EpubExportProgress> dco_decode_StreamSink_epub_export_progress_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<ImportEvent> dco_decode_StreamSink_import_event_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<RateLimitStatus> dco_decode_StreamSink_rate_limit_status_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<TtfChunk> dco_decode_StreamSink_ttf_chunk_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<TtsAudioChunk> dco_decode_StreamSink_tts_audio_chunk_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<WebImportProgress> dco_decode_StreamSink_web_import_progress_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<WebUpload> dco_decode_StreamSink_web_upload_Sse(dynamic raw);
+
   @protected
   String dco_decode_String(dynamic raw);
 
   @protected
-  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+  AlternativeChapter dco_decode_alternative_chapter(dynamic raw);
+
+  @protected
+  AlternativeSource dco_decode_alternative_source(dynamic raw);
+
+  @protected
+  AnchorResolution dco_decode_anchor_resolution(dynamic raw);
+
+  @protected
+  ApiError dco_decode_api_error(dynamic raw);
+
+  @protected
+  ArchiveEntry dco_decode_archive_entry(dynamic raw);
+
+  @protected
+  ArchiveFormat dco_decode_archive_format(dynamic raw);
+
+  @protected
+  ArchiveInfo dco_decode_archive_info(dynamic raw);
+
+  @protected
+  ArchiveProgress dco_decode_archive_progress(dynamic raw);
+
+  @protected
+  AudioCacheStats dco_decode_audio_cache_stats(dynamic raw);
+
+  @protected
+  AudioFormat dco_decode_audio_format(dynamic raw);
+
+  @protected
+  AudioSegment dco_decode_audio_segment(dynamic raw);
+
+  @protected
+  AxisValue dco_decode_axis_value(dynamic raw);
+
+  @protected
+  BackupInfo dco_decode_backup_info(dynamic raw);
+
+  @protected
+  BandwidthPolicy dco_decode_bandwidth_policy(dynamic raw);
+
+  @protected
+  BenchReport dco_decode_bench_report(dynamic raw);
+
+  @protected
+  BionicOptions dco_decode_bionic_options(dynamic raw);
+
+  @protected
+  BlobGcReport dco_decode_blob_gc_report(dynamic raw);
+
+  @protected
+  BlobStoreStats dco_decode_blob_store_stats(dynamic raw);
+
+  @protected
+  BookAlias dco_decode_book_alias(dynamic raw);
+
+  @protected
+  BookChapter dco_decode_book_chapter(dynamic raw);
+
+  @protected
+  BookFormat dco_decode_book_format(dynamic raw);
+
+  @protected
+  BookImage dco_decode_book_image(dynamic raw);
+
+  @protected
+  BookInfoRules dco_decode_book_info_rules(dynamic raw);
+
+  @protected
+  BookKeywords dco_decode_book_keywords(dynamic raw);
+
+  @protected
+  BookMetadata dco_decode_book_metadata(dynamic raw);
+
+  @protected
+  BookPage dco_decode_book_page(dynamic raw);
+
+  @protected
+  BookQuery dco_decode_book_query(dynamic raw);
+
+  @protected
+  BookReadingTime dco_decode_book_reading_time(dynamic raw);
+
+  @protected
+  BookSort dco_decode_book_sort(dynamic raw);
+
+  @protected
+  BookSource dco_decode_book_source(dynamic raw);
+
+  @protected
+  BookStats dco_decode_book_stats(dynamic raw);
+
+  @protected
+  BookUpdateSummary dco_decode_book_update_summary(dynamic raw);
+
+  @protected
+  Bookmark dco_decode_bookmark(dynamic raw);
+
+  @protected
+  bool dco_decode_bool(dynamic raw);
+
+  @protected
+  AnchorResolution dco_decode_box_autoadd_anchor_resolution(dynamic raw);
+
+  @protected
+  ApiError dco_decode_box_autoadd_api_error(dynamic raw);
+
+  @protected
+  BandwidthPolicy dco_decode_box_autoadd_bandwidth_policy(dynamic raw);
+
+  @protected
+  BionicOptions dco_decode_box_autoadd_bionic_options(dynamic raw);
+
+  @protected
+  BookImage dco_decode_box_autoadd_book_image(dynamic raw);
+
+  @protected
+  BookMetadata dco_decode_box_autoadd_book_metadata(dynamic raw);
+
+  @protected
+  BookQuery dco_decode_box_autoadd_book_query(dynamic raw);
+
+  @protected
+  BookSource dco_decode_box_autoadd_book_source(dynamic raw);
+
+  @protected
+  CachedAudio dco_decode_box_autoadd_cached_audio(dynamic raw);
+
+  @protected
+  CalibreMetadata dco_decode_box_autoadd_calibre_metadata(dynamic raw);
+
+  @protected
+  ChapterFingerprint dco_decode_box_autoadd_chapter_fingerprint(dynamic raw);
+
+  @protected
+  CoverImageOptions dco_decode_box_autoadd_cover_image_options(dynamic raw);
+
+  @protected
+  CoverStyle dco_decode_box_autoadd_cover_style(dynamic raw);
+
+  @protected
+  DarkImageOptions dco_decode_box_autoadd_dark_image_options(dynamic raw);
+
+  @protected
+  DedupOptions dco_decode_box_autoadd_dedup_options(dynamic raw);
+
+  @protected
+  DownloadChecksum dco_decode_box_autoadd_download_checksum(dynamic raw);
+
+  @protected
+  DownloadRequest dco_decode_box_autoadd_download_request(dynamic raw);
+
+  @protected
+  DownloaderConfig dco_decode_box_autoadd_downloader_config(dynamic raw);
+
+  @protected
+  DrmScheme dco_decode_box_autoadd_drm_scheme(dynamic raw);
+
+  @protected
+  EpubSplitOptions dco_decode_box_autoadd_epub_split_options(dynamic raw);
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  HttpCacheEntry dco_decode_box_autoadd_http_cache_entry(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  ImportKind dco_decode_box_autoadd_import_kind(dynamic raw);
+
+  @protected
+  ImportOptions dco_decode_box_autoadd_import_options(dynamic raw);
+
+  @protected
+  ImportReport dco_decode_box_autoadd_import_report(dynamic raw);
+
+  @protected
+  KinsokuRules dco_decode_box_autoadd_kinsoku_rules(dynamic raw);
+
+  @protected
+  LibraryBook dco_decode_box_autoadd_library_book(dynamic raw);
+
+  @protected
+  LoggingConfig dco_decode_box_autoadd_logging_config(dynamic raw);
+
+  @protected
+  MetadataGuess dco_decode_box_autoadd_metadata_guess(dynamic raw);
+
+  @protected
+  NotesExportOptions dco_decode_box_autoadd_notes_export_options(dynamic raw);
+
+  @protected
+  OpdsCredentials dco_decode_box_autoadd_opds_credentials(dynamic raw);
+
+  @protected
+  PageLayout dco_decode_box_autoadd_page_layout(dynamic raw);
+
+  @protected
+  PrefetchConfig dco_decode_box_autoadd_prefetch_config(dynamic raw);
+
+  @protected
+  PrefetchPause dco_decode_box_autoadd_prefetch_pause(dynamic raw);
+
+  @protected
+  PrefetchPlan dco_decode_box_autoadd_prefetch_plan(dynamic raw);
+
+  @protected
+  ProcessedBook dco_decode_box_autoadd_processed_book(dynamic raw);
+
+  @protected
+  ProxyConfig dco_decode_box_autoadd_proxy_config(dynamic raw);
+
+  @protected
+  PurifyRule dco_decode_box_autoadd_purify_rule(dynamic raw);
+
+  @protected
+  RateLimit dco_decode_box_autoadd_rate_limit(dynamic raw);
+
+  @protected
+  ReadingProgress dco_decode_box_autoadd_reading_progress(dynamic raw);
+
+  @protected
+  SsmlOptions dco_decode_box_autoadd_ssml_options(dynamic raw);
+
+  @protected
+  SummaryOptions dco_decode_box_autoadd_summary_options(dynamic raw);
+
+  @protected
+  SyncState dco_decode_box_autoadd_sync_state(dynamic raw);
+
+  @protected
+  TextAnchor dco_decode_box_autoadd_text_anchor(dynamic raw);
+
+  @protected
+  TextSegment dco_decode_box_autoadd_text_segment(dynamic raw);
+
+  @protected
+  TranslatorConfig dco_decode_box_autoadd_translator_config(dynamic raw);
+
+  @protected
+  TtsOptions dco_decode_box_autoadd_tts_options(dynamic raw);
+
+  @protected
+  TtsPosition dco_decode_box_autoadd_tts_position(dynamic raw);
+
+  @protected
+  TxtChapter dco_decode_box_autoadd_txt_chapter(dynamic raw);
+
+  @protected
+  TxtChapterRule dco_decode_box_autoadd_txt_chapter_rule(dynamic raw);
+
+  @protected
+  TypographyOptions dco_decode_box_autoadd_typography_options(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  WebDavConfig dco_decode_box_autoadd_web_dav_config(dynamic raw);
+
+  @protected
+  WebDavSyncRecord dco_decode_box_autoadd_web_dav_sync_record(dynamic raw);
+
+  @protected
+  WebServerConfig dco_decode_box_autoadd_web_server_config(dynamic raw);
+
+  @protected
+  CachedAudio dco_decode_cached_audio(dynamic raw);
+
+  @protected
+  CalibreBook dco_decode_calibre_book(dynamic raw);
+
+  @protected
+  CalibreFormat dco_decode_calibre_format(dynamic raw);
+
+  @protected
+  CalibreIdentifier dco_decode_calibre_identifier(dynamic raw);
+
+  @protected
+  CalibreMetadata dco_decode_calibre_metadata(dynamic raw);
+
+  @protected
+  ChapterAudio dco_decode_chapter_audio(dynamic raw);
+
+  @protected
+  ChapterCacheMigration dco_decode_chapter_cache_migration(dynamic raw);
+
+  @protected
+  ChapterChange dco_decode_chapter_change(dynamic raw);
+
+  @protected
+  ChapterDiff dco_decode_chapter_diff(dynamic raw);
+
+  @protected
+  ChapterFingerprint dco_decode_chapter_fingerprint(dynamic raw);
+
+  @protected
+  ChapterImage dco_decode_chapter_image(dynamic raw);
+
+  @protected
+  ChapterRange dco_decode_chapter_range(dynamic raw);
+
+  @protected
+  ChapterStoreStats dco_decode_chapter_store_stats(dynamic raw);
+
+  @protected
+  ChapterText dco_decode_chapter_text(dynamic raw);
+
+  @protected
+  ChapterUpdate dco_decode_chapter_update(dynamic raw);
+
+  @protected
+  ChapterUpdateReport dco_decode_chapter_update_report(dynamic raw);
+
+  @protected
+  ChecksumAlgorithm dco_decode_checksum_algorithm(dynamic raw);
+
+  @protected
+  ChineseConversion dco_decode_chinese_conversion(dynamic raw);
+
+  @protected
+  CloudConfig dco_decode_cloud_config(dynamic raw);
+
+  @protected
+  CloudConfigValue dco_decode_cloud_config_value(dynamic raw);
+
+  @protected
+  CollectionFace dco_decode_collection_face(dynamic raw);
+
+  @protected
+  ComicPage dco_decode_comic_page(dynamic raw);
+
+  @protected
+  ContentRules dco_decode_content_rules(dynamic raw);
+
+  @protected
+  ConversionResult dco_decode_conversion_result(dynamic raw);
+
+  @protected
+  CoverImageOptions dco_decode_cover_image_options(dynamic raw);
+
+  @protected
+  CoverPalette dco_decode_cover_palette(dynamic raw);
+
+  @protected
+  CoverPlaceholder dco_decode_cover_placeholder(dynamic raw);
+
+  @protected
+  CoverStyle dco_decode_cover_style(dynamic raw);
+
+  @protected
+  CoverageReport dco_decode_coverage_report(dynamic raw);
+
+  @protected
+  DarkImage dco_decode_dark_image(dynamic raw);
+
+  @protected
+  DarkImageMode dco_decode_dark_image_mode(dynamic raw);
+
+  @protected
+  DarkImageOptions dco_decode_dark_image_options(dynamic raw);
+
+  @protected
+  DecodedText dco_decode_decoded_text(dynamic raw);
+
+  @protected
+  DedupOptions dco_decode_dedup_options(dynamic raw);
+
+  @protected
+  DictDefinition dco_decode_dict_definition(dynamic raw);
+
+  @protected
+  DictFormat dco_decode_dict_format(dynamic raw);
+
+  @protected
+  DictInfo dco_decode_dict_info(dynamic raw);
+
+  @protected
+  DiffHunk dco_decode_diff_hunk(dynamic raw);
+
+  @protected
+  DiffHunkKind dco_decode_diff_hunk_kind(dynamic raw);
+
+  @protected
+  DownloadChecksum dco_decode_download_checksum(dynamic raw);
+
+  @protected
+  DownloadEvent dco_decode_download_event(dynamic raw);
+
+  @protected
+  DownloadHeader dco_decode_download_header(dynamic raw);
+
+  @protected
+  DownloadRequest dco_decode_download_request(dynamic raw);
+
+  @protected
+  DownloadState dco_decode_download_state(dynamic raw);
+
+  @protected
+  DownloaderConfig dco_decode_downloader_config(dynamic raw);
+
+  @protected
+  DrmScheme dco_decode_drm_scheme(dynamic raw);
+
+  @protected
+  DrmStatus dco_decode_drm_status(dynamic raw);
+
+  @protected
+  DuplicateCluster dco_decode_duplicate_cluster(dynamic raw);
+
+  @protected
+  DuplicateMatch dco_decode_duplicate_match(dynamic raw);
+
+  @protected
+  EmphasisSpan dco_decode_emphasis_span(dynamic raw);
+
+  @protected
+  EncodingCandidate dco_decode_encoding_candidate(dynamic raw);
+
+  @protected
+  EpubExportChapter dco_decode_epub_export_chapter(dynamic raw);
+
+  @protected
+  EpubExportProgress dco_decode_epub_export_progress(dynamic raw);
+
+  @protected
+  EpubLayout dco_decode_epub_layout(dynamic raw);
+
+  @protected
+  EpubMergeSource dco_decode_epub_merge_source(dynamic raw);
+
+  @protected
+  EpubResource dco_decode_epub_resource(dynamic raw);
+
+  @protected
+  EpubSplitOptions dco_decode_epub_split_options(dynamic raw);
+
+  @protected
+  EpubVolume dco_decode_epub_volume(dynamic raw);
+
+  @protected
+  ErrorCode dco_decode_error_code(dynamic raw);
+
+  @protected
+  ExtractedFile dco_decode_extracted_file(dynamic raw);
+
+  @protected
+  Extraction dco_decode_extraction(dynamic raw);
+
+  @protected
+  double dco_decode_f_32(dynamic raw);
+
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
+  @protected
+  Feed dco_decode_feed(dynamic raw);
+
+  @protected
+  FeedEntry dco_decode_feed_entry(dynamic raw);
+
+  @protected
+  FetchedChapter dco_decode_fetched_chapter(dynamic raw);
+
+  @protected
+  FontAxis dco_decode_font_axis(dynamic raw);
+
+  @protected
+  FontBundle dco_decode_font_bundle(dynamic raw);
+
+  @protected
+  FontCacheEntry dco_decode_font_cache_entry(dynamic raw);
+
+  @protected
+  FontCacheStats dco_decode_font_cache_stats(dynamic raw);
+
+  @protected
+  FontInfo dco_decode_font_info(dynamic raw);
+
+  @protected
+  FontPreview dco_decode_font_preview(dynamic raw);
+
+  @protected
+  Highlight dco_decode_highlight(dynamic raw);
+
+  @protected
+  HttpCacheEntry dco_decode_http_cache_entry(dynamic raw);
+
+  @protected
+  HttpCacheStats dco_decode_http_cache_stats(dynamic raw);
+
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw);
+
+  @protected
+  ImportEvent dco_decode_import_event(dynamic raw);
+
+  @protected
+  ImportEventKind dco_decode_import_event_kind(dynamic raw);
+
+  @protected
+  ImportIssue dco_decode_import_issue(dynamic raw);
+
+  @protected
+  ImportKind dco_decode_import_kind(dynamic raw);
+
+  @protected
+  ImportOptions dco_decode_import_options(dynamic raw);
+
+  @protected
+  ImportReport dco_decode_import_report(dynamic raw);
+
+  @protected
+  Keyword dco_decode_keyword(dynamic raw);
+
+  @protected
+  KeywordKind dco_decode_keyword_kind(dynamic raw);
+
+  @protected
+  KinsokuRules dco_decode_kinsoku_rules(dynamic raw);
+
+  @protected
+  LanguageGuess dco_decode_language_guess(dynamic raw);
+
+  @protected
+  LayoutCacheStats dco_decode_layout_cache_stats(dynamic raw);
+
+  @protected
+  LibraryBook dco_decode_library_book(dynamic raw);
+
+  @protected
+  LibraryChapter dco_decode_library_chapter(dynamic raw);
+
+  @protected
+  LibrarySearchResult dco_decode_library_search_result(dynamic raw);
+
+  @protected
+  LineBreak dco_decode_line_break(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<AlternativeSource> dco_decode_list_alternative_source(dynamic raw);
+
+  @protected
+  List<ArchiveEntry> dco_decode_list_archive_entry(dynamic raw);
+
+  @protected
+  List<AudioSegment> dco_decode_list_audio_segment(dynamic raw);
+
+  @protected
+  List<AxisValue> dco_decode_list_axis_value(dynamic raw);
+
+  @protected
+  List<BookAlias> dco_decode_list_book_alias(dynamic raw);
+
+  @protected
+  List<BookChapter> dco_decode_list_book_chapter(dynamic raw);
+
+  @protected
+  List<BookReadingTime> dco_decode_list_book_reading_time(dynamic raw);
+
+  @protected
+  List<BookSource> dco_decode_list_book_source(dynamic raw);
+
+  @protected
+  List<BookUpdateSummary> dco_decode_list_book_update_summary(dynamic raw);
+
+  @protected
+  List<Bookmark> dco_decode_list_bookmark(dynamic raw);
+
+  @protected
+  List<CalibreBook> dco_decode_list_calibre_book(dynamic raw);
+
+  @protected
+  List<CalibreFormat> dco_decode_list_calibre_format(dynamic raw);
+
+  @protected
+  List<CalibreIdentifier> dco_decode_list_calibre_identifier(dynamic raw);
+
+  @protected
+  List<ChapterImage> dco_decode_list_chapter_image(dynamic raw);
+
+  @protected
+  List<ChapterRange> dco_decode_list_chapter_range(dynamic raw);
+
+  @protected
+  List<ChapterUpdate> dco_decode_list_chapter_update(dynamic raw);
+
+  @protected
+  List<CloudConfigValue> dco_decode_list_cloud_config_value(dynamic raw);
+
+  @protected
+  List<CollectionFace> dco_decode_list_collection_face(dynamic raw);
+
+  @protected
+  List<ComicPage> dco_decode_list_comic_page(dynamic raw);
+
+  @protected
+  List<ConversionResult> dco_decode_list_conversion_result(dynamic raw);
+
+  @protected
+  List<DictDefinition> dco_decode_list_dict_definition(dynamic raw);
+
+  @protected
+  List<DictInfo> dco_decode_list_dict_info(dynamic raw);
+
+  @protected
+  List<DiffHunk> dco_decode_list_diff_hunk(dynamic raw);
+
+  @protected
+  List<DownloadHeader> dco_decode_list_download_header(dynamic raw);
+
+  @protected
+  List<DuplicateCluster> dco_decode_list_duplicate_cluster(dynamic raw);
+
+  @protected
+  List<DuplicateMatch> dco_decode_list_duplicate_match(dynamic raw);
+
+  @protected
+  List<EmphasisSpan> dco_decode_list_emphasis_span(dynamic raw);
+
+  @protected
+  List<EncodingCandidate> dco_decode_list_encoding_candidate(dynamic raw);
+
+  @protected
+  List<EpubExportChapter> dco_decode_list_epub_export_chapter(dynamic raw);
+
+  @protected
+  List<EpubMergeSource> dco_decode_list_epub_merge_source(dynamic raw);
+
+  @protected
+  List<EpubResource> dco_decode_list_epub_resource(dynamic raw);
+
+  @protected
+  List<EpubVolume> dco_decode_list_epub_volume(dynamic raw);
+
+  @protected
+  List<FeedEntry> dco_decode_list_feed_entry(dynamic raw);
+
+  @protected
+  List<FetchedChapter> dco_decode_list_fetched_chapter(dynamic raw);
+
+  @protected
+  List<FontAxis> dco_decode_list_font_axis(dynamic raw);
+
+  @protected
+  List<FontCacheEntry> dco_decode_list_font_cache_entry(dynamic raw);
+
+  @protected
+  List<Highlight> dco_decode_list_highlight(dynamic raw);
+
+  @protected
+  List<ImportIssue> dco_decode_list_import_issue(dynamic raw);
+
+  @protected
+  List<Keyword> dco_decode_list_keyword(dynamic raw);
+
+  @protected
+  List<LanguageGuess> dco_decode_list_language_guess(dynamic raw);
+
+  @protected
+  List<LibraryBook> dco_decode_list_library_book(dynamic raw);
+
+  @protected
+  List<LibraryChapter> dco_decode_list_library_chapter(dynamic raw);
+
+  @protected
+  List<LibrarySearchResult> dco_decode_list_library_search_result(dynamic raw);
+
+  @protected
+  List<LineBreak> dco_decode_list_line_break(dynamic raw);
+
+  @protected
+  List<LogField> dco_decode_list_log_field(dynamic raw);
+
+  @protected
+  List<LogRecord> dco_decode_list_log_record(dynamic raw);
+
+  @protected
+  List<MatchRange> dco_decode_list_match_range(dynamic raw);
+
+  @protected
+  List<NamedFont> dco_decode_list_named_font(dynamic raw);
+
+  @protected
+  List<NamedInstance> dco_decode_list_named_instance(dynamic raw);
+
+  @protected
+  List<OpdsEntry> dco_decode_list_opds_entry(dynamic raw);
+
+  @protected
+  List<OpdsLink> dco_decode_list_opds_link(dynamic raw);
+
+  @protected
+  List<AnchorResolution?> dco_decode_list_opt_box_autoadd_anchor_resolution(
+    dynamic raw,
+  );
+
+  @protected
+  List<PaletteSwatch> dco_decode_list_palette_swatch(dynamic raw);
+
+  @protected
+  List<PinyinSpan> dco_decode_list_pinyin_span(dynamic raw);
+
+  @protected
+  List<PostStep> dco_decode_list_post_step(dynamic raw);
+
+  @protected
+  List<PrefetchChapter> dco_decode_list_prefetch_chapter(dynamic raw);
+
+  @protected
+  List<PrefetchTask> dco_decode_list_prefetch_task(dynamic raw);
+
+  @protected
+  Int64List dco_decode_list_prim_i_64_strict(dynamic raw);
+
+  @protected
+  List<int> dco_decode_list_prim_u_32_loose(dynamic raw);
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  List<PurifyRule> dco_decode_list_purify_rule(dynamic raw);
+
+  @protected
+  List<PurifyRuleMatch> dco_decode_list_purify_rule_match(dynamic raw);
+
+  @protected
+  List<RateLimitStatus> dco_decode_list_rate_limit_status(dynamic raw);
+
+  @protected
+  List<ReadableBlock> dco_decode_list_readable_block(dynamic raw);
+
+  @protected
+  List<ReadableSpan> dco_decode_list_readable_span(dynamic raw);
+
+  @protected
+  List<ReadingDay> dco_decode_list_reading_day(dynamic raw);
+
+  @protected
+  List<ReadingProgress> dco_decode_list_reading_progress(dynamic raw);
+
+  @protected
+  List<RuleTestMatch> dco_decode_list_rule_test_match(dynamic raw);
+
+  @protected
+  List<SearchChapter> dco_decode_list_search_chapter(dynamic raw);
+
+  @protected
+  List<SearchHit> dco_decode_list_search_hit(dynamic raw);
+
+  @protected
+  List<SourceBook> dco_decode_list_source_book(dynamic raw);
+
+  @protected
+  List<SourceChapter> dco_decode_list_source_chapter(dynamic raw);
+
+  @protected
+  List<StoredBookScan> dco_decode_list_stored_book_scan(dynamic raw);
+
+  @protected
+  List<StoredCookie> dco_decode_list_stored_cookie(dynamic raw);
+
+  @protected
+  List<TagSuggestion> dco_decode_list_tag_suggestion(dynamic raw);
+
+  @protected
+  List<TextAnchor> dco_decode_list_text_anchor(dynamic raw);
+
+  @protected
+  List<TextColumn> dco_decode_list_text_column(dynamic raw);
+
+  @protected
+  List<TextLine> dco_decode_list_text_line(dynamic raw);
+
+  @protected
+  List<TextPage> dco_decode_list_text_page(dynamic raw);
+
+  @protected
+  List<TextSegment> dco_decode_list_text_segment(dynamic raw);
+
+  @protected
+  List<TextStats> dco_decode_list_text_stats(dynamic raw);
+
+  @protected
+  List<TocEntry> dco_decode_list_toc_entry(dynamic raw);
+
+  @protected
+  List<TranslatedParagraph> dco_decode_list_translated_paragraph(dynamic raw);
+
+  @protected
+  List<TxtChapter> dco_decode_list_txt_chapter(dynamic raw);
+
+  @protected
+  List<TxtChapterRule> dco_decode_list_txt_chapter_rule(dynamic raw);
+
+  @protected
+  List<TxtHeadingCandidate> dco_decode_list_txt_heading_candidate(dynamic raw);
+
+  @protected
+  List<UpdateCheckBook> dco_decode_list_update_check_book(dynamic raw);
+
+  @protected
+  List<Utterance> dco_decode_list_utterance(dynamic raw);
+
+  @protected
+  List<ValidationIssue> dco_decode_list_validation_issue(dynamic raw);
+
+  @protected
+  List<VerticalGlyph> dco_decode_list_vertical_glyph(dynamic raw);
+
+  @protected
+  List<VerticalPage> dco_decode_list_vertical_page(dynamic raw);
+
+  @protected
+  List<WatermarkChar> dco_decode_list_watermark_char(dynamic raw);
+
+  @protected
+  List<WebChapter> dco_decode_list_web_chapter(dynamic raw);
+
+  @protected
+  List<WebDavEntry> dco_decode_list_web_dav_entry(dynamic raw);
+
+  @protected
+  LogField dco_decode_log_field(dynamic raw);
+
+  @protected
+  LogLevel dco_decode_log_level(dynamic raw);
+
+  @protected
+  LogRecord dco_decode_log_record(dynamic raw);
+
+  @protected
+  LoggingConfig dco_decode_logging_config(dynamic raw);
+
+  @protected
+  MappedTextSlice dco_decode_mapped_text_slice(dynamic raw);
+
+  @protected
+  MappedTxtInfo dco_decode_mapped_txt_info(dynamic raw);
+
+  @protected
+  MatchRange dco_decode_match_range(dynamic raw);
+
+  @protected
+  MetadataGuess dco_decode_metadata_guess(dynamic raw);
+
+  @protected
+  MetadataOrigin dco_decode_metadata_origin(dynamic raw);
+
+  @protected
+  NamedFont dco_decode_named_font(dynamic raw);
+
+  @protected
+  NamedInstance dco_decode_named_instance(dynamic raw);
+
+  @protected
+  NetworkType dco_decode_network_type(dynamic raw);
+
+  @protected
+  NotesExportOptions dco_decode_notes_export_options(dynamic raw);
+
+  @protected
+  NotesFormat dco_decode_notes_format(dynamic raw);
+
+  @protected
+  OpdsCredentials dco_decode_opds_credentials(dynamic raw);
+
+  @protected
+  OpdsEntry dco_decode_opds_entry(dynamic raw);
+
+  @protected
+  OpdsFeed dco_decode_opds_feed(dynamic raw);
+
+  @protected
+  OpdsLink dco_decode_opds_link(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  AnchorResolution? dco_decode_opt_box_autoadd_anchor_resolution(dynamic raw);
+
+  @protected
+  ApiError? dco_decode_opt_box_autoadd_api_error(dynamic raw);
+
+  @protected
+  CachedAudio? dco_decode_opt_box_autoadd_cached_audio(dynamic raw);
+
+  @protected
+  ChapterFingerprint? dco_decode_opt_box_autoadd_chapter_fingerprint(
+    dynamic raw,
+  );
+
+  @protected
+  DownloadChecksum? dco_decode_opt_box_autoadd_download_checksum(dynamic raw);
+
+  @protected
+  DrmScheme? dco_decode_opt_box_autoadd_drm_scheme(dynamic raw);
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  HttpCacheEntry? dco_decode_opt_box_autoadd_http_cache_entry(dynamic raw);
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  ImportKind? dco_decode_opt_box_autoadd_import_kind(dynamic raw);
+
+  @protected
+  ImportReport? dco_decode_opt_box_autoadd_import_report(dynamic raw);
+
+  @protected
+  KinsokuRules? dco_decode_opt_box_autoadd_kinsoku_rules(dynamic raw);
+
+  @protected
+  LibraryBook? dco_decode_opt_box_autoadd_library_book(dynamic raw);
+
+  @protected
+  MetadataGuess? dco_decode_opt_box_autoadd_metadata_guess(dynamic raw);
+
+  @protected
+  OpdsCredentials? dco_decode_opt_box_autoadd_opds_credentials(dynamic raw);
+
+  @protected
+  PrefetchPause? dco_decode_opt_box_autoadd_prefetch_pause(dynamic raw);
+
+  @protected
+  ProcessedBook? dco_decode_opt_box_autoadd_processed_book(dynamic raw);
+
+  @protected
+  ProxyConfig? dco_decode_opt_box_autoadd_proxy_config(dynamic raw);
+
+  @protected
+  RateLimit? dco_decode_opt_box_autoadd_rate_limit(dynamic raw);
+
+  @protected
+  ReadingProgress? dco_decode_opt_box_autoadd_reading_progress(dynamic raw);
+
+  @protected
+  SyncState? dco_decode_opt_box_autoadd_sync_state(dynamic raw);
+
+  @protected
+  TextSegment? dco_decode_opt_box_autoadd_text_segment(dynamic raw);
+
+  @protected
+  TtsPosition? dco_decode_opt_box_autoadd_tts_position(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  WebDavSyncRecord? dco_decode_opt_box_autoadd_web_dav_sync_record(dynamic raw);
+
+  @protected
+  Uint32List? dco_decode_opt_list_prim_u_32_strict(dynamic raw);
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  List<TxtChapterRule>? dco_decode_opt_list_txt_chapter_rule(dynamic raw);
+
+  @protected
+  PageLayout dco_decode_page_layout(dynamic raw);
+
+  @protected
+  PaletteSwatch dco_decode_palette_swatch(dynamic raw);
+
+  @protected
+  ParsedBook dco_decode_parsed_book(dynamic raw);
+
+  @protected
+  PerformanceTier dco_decode_performance_tier(dynamic raw);
+
+  @protected
+  PinyinSpan dco_decode_pinyin_span(dynamic raw);
+
+  @protected
+  PostStep dco_decode_post_step(dynamic raw);
+
+  @protected
+  PostStepKind dco_decode_post_step_kind(dynamic raw);
+
+  @protected
+  PrefetchChapter dco_decode_prefetch_chapter(dynamic raw);
+
+  @protected
+  PrefetchConfig dco_decode_prefetch_config(dynamic raw);
+
+  @protected
+  PrefetchPause dco_decode_prefetch_pause(dynamic raw);
+
+  @protected
+  PrefetchPlan dco_decode_prefetch_plan(dynamic raw);
+
+  @protected
+  PrefetchStatus dco_decode_prefetch_status(dynamic raw);
+
+  @protected
+  PrefetchTask dco_decode_prefetch_task(dynamic raw);
+
+  @protected
+  ProcessedBook dco_decode_processed_book(dynamic raw);
+
+  @protected
+  ProcessedImage dco_decode_processed_image(dynamic raw);
+
+  @protected
+  ProxyConfig dco_decode_proxy_config(dynamic raw);
+
+  @protected
+  ProxyKind dco_decode_proxy_kind(dynamic raw);
+
+  @protected
+  PunctuationRepair dco_decode_punctuation_repair(dynamic raw);
+
+  @protected
+  PurifyReport dco_decode_purify_report(dynamic raw);
+
+  @protected
+  PurifyRule dco_decode_purify_rule(dynamic raw);
+
+  @protected
+  PurifyRuleKind dco_decode_purify_rule_kind(dynamic raw);
+
+  @protected
+  PurifyRuleMatch dco_decode_purify_rule_match(dynamic raw);
+
+  @protected
+  RateLimit dco_decode_rate_limit(dynamic raw);
+
+  @protected
+  RateLimitStatus dco_decode_rate_limit_status(dynamic raw);
+
+  @protected
+  ReadableBlock dco_decode_readable_block(dynamic raw);
+
+  @protected
+  ReadableBlockKind dco_decode_readable_block_kind(dynamic raw);
+
+  @protected
+  ReadableContent dco_decode_readable_content(dynamic raw);
+
+  @protected
+  ReadableSpan dco_decode_readable_span(dynamic raw);
+
+  @protected
+  ReadingDay dco_decode_reading_day(dynamic raw);
+
+  @protected
+  ReadingProgress dco_decode_reading_progress(dynamic raw);
+
+  @protected
+  ReadingStats dco_decode_reading_stats(dynamic raw);
+
+  @protected
+  RepairLevel dco_decode_repair_level(dynamic raw);
+
+  @protected
+  ResourceKind dco_decode_resource_kind(dynamic raw);
+
+  @protected
+  ResplitOutcome dco_decode_resplit_outcome(dynamic raw);
+
+  @protected
+  RestoreResult dco_decode_restore_result(dynamic raw);
+
+  @protected
+  RuleTest dco_decode_rule_test(dynamic raw);
+
+  @protected
+  RuleTestMatch dco_decode_rule_test_match(dynamic raw);
+
+  @protected
+  ScrapedMetadata dco_decode_scraped_metadata(dynamic raw);
+
+  @protected
+  SearchChapter dco_decode_search_chapter(dynamic raw);
+
+  @protected
+  SearchHit dco_decode_search_hit(dynamic raw);
+
+  @protected
+  SearchRules dco_decode_search_rules(dynamic raw);
+
+  @protected
+  SourceBook dco_decode_source_book(dynamic raw);
+
+  @protected
+  SourceChapter dco_decode_source_chapter(dynamic raw);
+
+  @protected
+  SsmlOptions dco_decode_ssml_options(dynamic raw);
+
+  @protected
+  StoredBookScan dco_decode_stored_book_scan(dynamic raw);
+
+  @protected
+  StoredCookie dco_decode_stored_cookie(dynamic raw);
+
+  @protected
+  StyledChapter dco_decode_styled_chapter(dynamic raw);
+
+  @protected
+  SummaryOptions dco_decode_summary_options(dynamic raw);
+
+  @protected
+  SyncResult dco_decode_sync_result(dynamic raw);
+
+  @protected
+  SyncState dco_decode_sync_state(dynamic raw);
+
+  @protected
+  TagSuggestion dco_decode_tag_suggestion(dynamic raw);
+
+  @protected
+  TextAnchor dco_decode_text_anchor(dynamic raw);
+
+  @protected
+  TextColumn dco_decode_text_column(dynamic raw);
+
+  @protected
+  TextLine dco_decode_text_line(dynamic raw);
+
+  @protected
+  TextPage dco_decode_text_page(dynamic raw);
+
+  @protected
+  TextSegment dco_decode_text_segment(dynamic raw);
+
+  @protected
+  TextStats dco_decode_text_stats(dynamic raw);
+
+  @protected
+  ThemeColors dco_decode_theme_colors(dynamic raw);
+
+  @protected
+  TierSettings dco_decode_tier_settings(dynamic raw);
+
+  @protected
+  TocEntry dco_decode_toc_entry(dynamic raw);
+
+  @protected
+  TocRules dco_decode_toc_rules(dynamic raw);
+
+  @protected
+  TranslatedParagraph dco_decode_translated_paragraph(dynamic raw);
+
+  @protected
+  TranslationProvider dco_decode_translation_provider(dynamic raw);
+
+  @protected
+  TranslatorConfig dco_decode_translator_config(dynamic raw);
+
+  @protected
+  TtfChunk dco_decode_ttf_chunk(dynamic raw);
+
+  @protected
+  TtsAudioChunk dco_decode_tts_audio_chunk(dynamic raw);
+
+  @protected
+  TtsOptions dco_decode_tts_options(dynamic raw);
+
+  @protected
+  TtsPosition dco_decode_tts_position(dynamic raw);
+
+  @protected
+  TtsVoiceInfo dco_decode_tts_voice_info(dynamic raw);
+
+  @protected
+  TxtChapter dco_decode_txt_chapter(dynamic raw);
+
+  @protected
+  TxtChapterProposal dco_decode_txt_chapter_proposal(dynamic raw);
+
+  @protected
+  TxtChapterRule dco_decode_txt_chapter_rule(dynamic raw);
+
+  @protected
+  TxtHeadingCandidate dco_decode_txt_heading_candidate(dynamic raw);
+
+  @protected
+  TxtIndex dco_decode_txt_index(dynamic raw);
+
+  @protected
+  TypographyOptions dco_decode_typography_options(dynamic raw);
+
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  UpdateCheckBook dco_decode_update_check_book(dynamic raw);
+
+  @protected
+  UpdateCheckStatus dco_decode_update_check_status(dynamic raw);
+
+  @protected
+  Utterance dco_decode_utterance(dynamic raw);
+
+  @protected
+  ValidationIssue dco_decode_validation_issue(dynamic raw);
+
+  @protected
+  ValidationIssueKind dco_decode_validation_issue_kind(dynamic raw);
+
+  @protected
+  ValidationReport dco_decode_validation_report(dynamic raw);
+
+  @protected
+  VerticalGlyph dco_decode_vertical_glyph(dynamic raw);
+
+  @protected
+  VerticalPage dco_decode_vertical_page(dynamic raw);
+
+  @protected
+  WatermarkChar dco_decode_watermark_char(dynamic raw);
+
+  @protected
+  WatermarkKind dco_decode_watermark_kind(dynamic raw);
+
+  @protected
+  WatermarkReport dco_decode_watermark_report(dynamic raw);
+
+  @protected
+  WebBook dco_decode_web_book(dynamic raw);
+
+  @protected
+  WebChapter dco_decode_web_chapter(dynamic raw);
+
+  @protected
+  WebDavConfig dco_decode_web_dav_config(dynamic raw);
+
+  @protected
+  WebDavEntry dco_decode_web_dav_entry(dynamic raw);
+
+  @protected
+  WebDavSyncRecord dco_decode_web_dav_sync_record(dynamic raw);
+
+  @protected
+  WebDavSyncStatus dco_decode_web_dav_sync_status(dynamic raw);
+
+  @protected
+  WebImportProgress dco_decode_web_import_progress(dynamic raw);
+
+  @protected
+  WebServerConfig dco_decode_web_server_config(dynamic raw);
+
+  @protected
+  WebServerInfo dco_decode_web_server_info(dynamic raw);
+
+  @protected
+  WebUpload dco_decode_web_upload(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<ArchiveProgress> sse_decode_StreamSink_archive_progress_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<DownloadEvent> sse_decode_StreamSink_download_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<EpubExportProgress> sse_decode_StreamSink_epub_export_progress_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<ImportEvent> sse_decode_StreamSink_import_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<RateLimitStatus> sse_decode_StreamSink_rate_limit_status_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<TtfChunk> sse_decode_StreamSink_ttf_chunk_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<TtsAudioChunk> sse_decode_StreamSink_tts_audio_chunk_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<WebImportProgress> sse_decode_StreamSink_web_import_progress_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<WebUpload> sse_decode_StreamSink_web_upload_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  AlternativeChapter sse_decode_alternative_chapter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AlternativeSource sse_decode_alternative_source(SseDeserializer deserializer);
+
+  @protected
+  AnchorResolution sse_decode_anchor_resolution(SseDeserializer deserializer);
+
+  @protected
+  ApiError sse_decode_api_error(SseDeserializer deserializer);
+
+  @protected
+  ArchiveEntry sse_decode_archive_entry(SseDeserializer deserializer);
+
+  @protected
+  ArchiveFormat sse_decode_archive_format(SseDeserializer deserializer);
+
+  @protected
+  ArchiveInfo sse_decode_archive_info(SseDeserializer deserializer);
+
+  @protected
+  ArchiveProgress sse_decode_archive_progress(SseDeserializer deserializer);
+
+  @protected
+  AudioCacheStats sse_decode_audio_cache_stats(SseDeserializer deserializer);
+
+  @protected
+  AudioFormat sse_decode_audio_format(SseDeserializer deserializer);
+
+  @protected
+  AudioSegment sse_decode_audio_segment(SseDeserializer deserializer);
+
+  @protected
+  AxisValue sse_decode_axis_value(SseDeserializer deserializer);
+
+  @protected
+  BackupInfo sse_decode_backup_info(SseDeserializer deserializer);
+
+  @protected
+  BandwidthPolicy sse_decode_bandwidth_policy(SseDeserializer deserializer);
+
+  @protected
+  BenchReport sse_decode_bench_report(SseDeserializer deserializer);
+
+  @protected
+  BionicOptions sse_decode_bionic_options(SseDeserializer deserializer);
+
+  @protected
+  BlobGcReport sse_decode_blob_gc_report(SseDeserializer deserializer);
+
+  @protected
+  BlobStoreStats sse_decode_blob_store_stats(SseDeserializer deserializer);
+
+  @protected
+  BookAlias sse_decode_book_alias(SseDeserializer deserializer);
+
+  @protected
+  BookChapter sse_decode_book_chapter(SseDeserializer deserializer);
+
+  @protected
+  BookFormat sse_decode_book_format(SseDeserializer deserializer);
+
+  @protected
+  BookImage sse_decode_book_image(SseDeserializer deserializer);
+
+  @protected
+  BookInfoRules sse_decode_book_info_rules(SseDeserializer deserializer);
+
+  @protected
+  BookKeywords sse_decode_book_keywords(SseDeserializer deserializer);
+
+  @protected
+  BookMetadata sse_decode_book_metadata(SseDeserializer deserializer);
+
+  @protected
+  BookPage sse_decode_book_page(SseDeserializer deserializer);
+
+  @protected
+  BookQuery sse_decode_book_query(SseDeserializer deserializer);
+
+  @protected
+  BookReadingTime sse_decode_book_reading_time(SseDeserializer deserializer);
+
+  @protected
+  BookSort sse_decode_book_sort(SseDeserializer deserializer);
+
+  @protected
+  BookSource sse_decode_book_source(SseDeserializer deserializer);
+
+  @protected
+  BookStats sse_decode_book_stats(SseDeserializer deserializer);
+
+  @protected
+  BookUpdateSummary sse_decode_book_update_summary(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Bookmark sse_decode_bookmark(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  AnchorResolution sse_decode_box_autoadd_anchor_resolution(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiError sse_decode_box_autoadd_api_error(SseDeserializer deserializer);
+
+  @protected
+  BandwidthPolicy sse_decode_box_autoadd_bandwidth_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  BionicOptions sse_decode_box_autoadd_bionic_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  BookImage sse_decode_box_autoadd_book_image(SseDeserializer deserializer);
+
+  @protected
+  BookMetadata sse_decode_box_autoadd_book_metadata(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  BookQuery sse_decode_box_autoadd_book_query(SseDeserializer deserializer);
+
+  @protected
+  BookSource sse_decode_box_autoadd_book_source(SseDeserializer deserializer);
+
+  @protected
+  CachedAudio sse_decode_box_autoadd_cached_audio(SseDeserializer deserializer);
+
+  @protected
+  CalibreMetadata sse_decode_box_autoadd_calibre_metadata(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ChapterFingerprint sse_decode_box_autoadd_chapter_fingerprint(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CoverImageOptions sse_decode_box_autoadd_cover_image_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CoverStyle sse_decode_box_autoadd_cover_style(SseDeserializer deserializer);
+
+  @protected
+  DarkImageOptions sse_decode_box_autoadd_dark_image_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DedupOptions sse_decode_box_autoadd_dedup_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DownloadChecksum sse_decode_box_autoadd_download_checksum(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DownloadRequest sse_decode_box_autoadd_download_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DownloaderConfig sse_decode_box_autoadd_downloader_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DrmScheme sse_decode_box_autoadd_drm_scheme(SseDeserializer deserializer);
+
+  @protected
+  EpubSplitOptions sse_decode_box_autoadd_epub_split_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  HttpCacheEntry sse_decode_box_autoadd_http_cache_entry(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  ImportKind sse_decode_box_autoadd_import_kind(SseDeserializer deserializer);
+
+  @protected
+  ImportOptions sse_decode_box_autoadd_import_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ImportReport sse_decode_box_autoadd_import_report(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  KinsokuRules sse_decode_box_autoadd_kinsoku_rules(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LibraryBook sse_decode_box_autoadd_library_book(SseDeserializer deserializer);
+
+  @protected
+  LoggingConfig sse_decode_box_autoadd_logging_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MetadataGuess sse_decode_box_autoadd_metadata_guess(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NotesExportOptions sse_decode_box_autoadd_notes_export_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  OpdsCredentials sse_decode_box_autoadd_opds_credentials(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PageLayout sse_decode_box_autoadd_page_layout(SseDeserializer deserializer);
+
+  @protected
+  PrefetchConfig sse_decode_box_autoadd_prefetch_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PrefetchPause sse_decode_box_autoadd_prefetch_pause(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PrefetchPlan sse_decode_box_autoadd_prefetch_plan(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProcessedBook sse_decode_box_autoadd_processed_book(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProxyConfig sse_decode_box_autoadd_proxy_config(SseDeserializer deserializer);
+
+  @protected
+  PurifyRule sse_decode_box_autoadd_purify_rule(SseDeserializer deserializer);
+
+  @protected
+  RateLimit sse_decode_box_autoadd_rate_limit(SseDeserializer deserializer);
+
+  @protected
+  ReadingProgress sse_decode_box_autoadd_reading_progress(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SsmlOptions sse_decode_box_autoadd_ssml_options(SseDeserializer deserializer);
+
+  @protected
+  SummaryOptions sse_decode_box_autoadd_summary_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SyncState sse_decode_box_autoadd_sync_state(SseDeserializer deserializer);
+
+  @protected
+  TextAnchor sse_decode_box_autoadd_text_anchor(SseDeserializer deserializer);
+
+  @protected
+  TextSegment sse_decode_box_autoadd_text_segment(SseDeserializer deserializer);
+
+  @protected
+  TranslatorConfig sse_decode_box_autoadd_translator_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TtsOptions sse_decode_box_autoadd_tts_options(SseDeserializer deserializer);
+
+  @protected
+  TtsPosition sse_decode_box_autoadd_tts_position(SseDeserializer deserializer);
+
+  @protected
+  TxtChapter sse_decode_box_autoadd_txt_chapter(SseDeserializer deserializer);
+
+  @protected
+  TxtChapterRule sse_decode_box_autoadd_txt_chapter_rule(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TypographyOptions sse_decode_box_autoadd_typography_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  WebDavConfig sse_decode_box_autoadd_web_dav_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WebDavSyncRecord sse_decode_box_autoadd_web_dav_sync_record(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WebServerConfig sse_decode_box_autoadd_web_server_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CachedAudio sse_decode_cached_audio(SseDeserializer deserializer);
+
+  @protected
+  CalibreBook sse_decode_calibre_book(SseDeserializer deserializer);
+
+  @protected
+  CalibreFormat sse_decode_calibre_format(SseDeserializer deserializer);
+
+  @protected
+  CalibreIdentifier sse_decode_calibre_identifier(SseDeserializer deserializer);
+
+  @protected
+  CalibreMetadata sse_decode_calibre_metadata(SseDeserializer deserializer);
+
+  @protected
+  ChapterAudio sse_decode_chapter_audio(SseDeserializer deserializer);
+
+  @protected
+  ChapterCacheMigration sse_decode_chapter_cache_migration(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ChapterChange sse_decode_chapter_change(SseDeserializer deserializer);
+
+  @protected
+  ChapterDiff sse_decode_chapter_diff(SseDeserializer deserializer);
+
+  @protected
+  ChapterFingerprint sse_decode_chapter_fingerprint(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ChapterImage sse_decode_chapter_image(SseDeserializer deserializer);
+
+  @protected
+  ChapterRange sse_decode_chapter_range(SseDeserializer deserializer);
+
+  @protected
+  ChapterStoreStats sse_decode_chapter_store_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ChapterText sse_decode_chapter_text(SseDeserializer deserializer);
+
+  @protected
+  ChapterUpdate sse_decode_chapter_update(SseDeserializer deserializer);
+
+  @protected
+  ChapterUpdateReport sse_decode_chapter_update_report(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ChecksumAlgorithm sse_decode_checksum_algorithm(SseDeserializer deserializer);
+
+  @protected
+  ChineseConversion sse_decode_chinese_conversion(SseDeserializer deserializer);
+
+  @protected
+  CloudConfig sse_decode_cloud_config(SseDeserializer deserializer);
+
+  @protected
+  CloudConfigValue sse_decode_cloud_config_value(SseDeserializer deserializer);
+
+  @protected
+  CollectionFace sse_decode_collection_face(SseDeserializer deserializer);
+
+  @protected
+  ComicPage sse_decode_comic_page(SseDeserializer deserializer);
+
+  @protected
+  ContentRules sse_decode_content_rules(SseDeserializer deserializer);
+
+  @protected
+  ConversionResult sse_decode_conversion_result(SseDeserializer deserializer);
+
+  @protected
+  CoverImageOptions sse_decode_cover_image_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CoverPalette sse_decode_cover_palette(SseDeserializer deserializer);
+
+  @protected
+  CoverPlaceholder sse_decode_cover_placeholder(SseDeserializer deserializer);
+
+  @protected
+  CoverStyle sse_decode_cover_style(SseDeserializer deserializer);
+
+  @protected
+  CoverageReport sse_decode_coverage_report(SseDeserializer deserializer);
+
+  @protected
+  DarkImage sse_decode_dark_image(SseDeserializer deserializer);
+
+  @protected
+  DarkImageMode sse_decode_dark_image_mode(SseDeserializer deserializer);
+
+  @protected
+  DarkImageOptions sse_decode_dark_image_options(SseDeserializer deserializer);
+
+  @protected
+  DecodedText sse_decode_decoded_text(SseDeserializer deserializer);
+
+  @protected
+  DedupOptions sse_decode_dedup_options(SseDeserializer deserializer);
+
+  @protected
+  DictDefinition sse_decode_dict_definition(SseDeserializer deserializer);
+
+  @protected
+  DictFormat sse_decode_dict_format(SseDeserializer deserializer);
+
+  @protected
+  DictInfo sse_decode_dict_info(SseDeserializer deserializer);
+
+  @protected
+  DiffHunk sse_decode_diff_hunk(SseDeserializer deserializer);
+
+  @protected
+  DiffHunkKind sse_decode_diff_hunk_kind(SseDeserializer deserializer);
+
+  @protected
+  DownloadChecksum sse_decode_download_checksum(SseDeserializer deserializer);
+
+  @protected
+  DownloadEvent sse_decode_download_event(SseDeserializer deserializer);
+
+  @protected
+  DownloadHeader sse_decode_download_header(SseDeserializer deserializer);
+
+  @protected
+  DownloadRequest sse_decode_download_request(SseDeserializer deserializer);
+
+  @protected
+  DownloadState sse_decode_download_state(SseDeserializer deserializer);
+
+  @protected
+  DownloaderConfig sse_decode_downloader_config(SseDeserializer deserializer);
+
+  @protected
+  DrmScheme sse_decode_drm_scheme(SseDeserializer deserializer);
+
+  @protected
+  DrmStatus sse_decode_drm_status(SseDeserializer deserializer);
+
+  @protected
+  DuplicateCluster sse_decode_duplicate_cluster(SseDeserializer deserializer);
+
+  @protected
+  DuplicateMatch sse_decode_duplicate_match(SseDeserializer deserializer);
+
+  @protected
+  EmphasisSpan sse_decode_emphasis_span(SseDeserializer deserializer);
+
+  @protected
+  EncodingCandidate sse_decode_encoding_candidate(SseDeserializer deserializer);
+
+  @protected
+  EpubExportChapter sse_decode_epub_export_chapter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EpubExportProgress sse_decode_epub_export_progress(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EpubLayout sse_decode_epub_layout(SseDeserializer deserializer);
+
+  @protected
+  EpubMergeSource sse_decode_epub_merge_source(SseDeserializer deserializer);
+
+  @protected
+  EpubResource sse_decode_epub_resource(SseDeserializer deserializer);
+
+  @protected
+  EpubSplitOptions sse_decode_epub_split_options(SseDeserializer deserializer);
+
+  @protected
+  EpubVolume sse_decode_epub_volume(SseDeserializer deserializer);
+
+  @protected
+  ErrorCode sse_decode_error_code(SseDeserializer deserializer);
+
+  @protected
+  ExtractedFile sse_decode_extracted_file(SseDeserializer deserializer);
+
+  @protected
+  Extraction sse_decode_extraction(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  Feed sse_decode_feed(SseDeserializer deserializer);
+
+  @protected
+  FeedEntry sse_decode_feed_entry(SseDeserializer deserializer);
+
+  @protected
+  FetchedChapter sse_decode_fetched_chapter(SseDeserializer deserializer);
+
+  @protected
+  FontAxis sse_decode_font_axis(SseDeserializer deserializer);
+
+  @protected
+  FontBundle sse_decode_font_bundle(SseDeserializer deserializer);
+
+  @protected
+  FontCacheEntry sse_decode_font_cache_entry(SseDeserializer deserializer);
+
+  @protected
+  FontCacheStats sse_decode_font_cache_stats(SseDeserializer deserializer);
+
+  @protected
+  FontInfo sse_decode_font_info(SseDeserializer deserializer);
+
+  @protected
+  FontPreview sse_decode_font_preview(SseDeserializer deserializer);
+
+  @protected
+  Highlight sse_decode_highlight(SseDeserializer deserializer);
+
+  @protected
+  HttpCacheEntry sse_decode_http_cache_entry(SseDeserializer deserializer);
+
+  @protected
+  HttpCacheStats sse_decode_http_cache_stats(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  ImportEvent sse_decode_import_event(SseDeserializer deserializer);
+
+  @protected
+  ImportEventKind sse_decode_import_event_kind(SseDeserializer deserializer);
+
+  @protected
+  ImportIssue sse_decode_import_issue(SseDeserializer deserializer);
+
+  @protected
+  ImportKind sse_decode_import_kind(SseDeserializer deserializer);
+
+  @protected
+  ImportOptions sse_decode_import_options(SseDeserializer deserializer);
+
+  @protected
+  ImportReport sse_decode_import_report(SseDeserializer deserializer);
+
+  @protected
+  Keyword sse_decode_keyword(SseDeserializer deserializer);
+
+  @protected
+  KeywordKind sse_decode_keyword_kind(SseDeserializer deserializer);
+
+  @protected
+  KinsokuRules sse_decode_kinsoku_rules(SseDeserializer deserializer);
+
+  @protected
+  LanguageGuess sse_decode_language_guess(SseDeserializer deserializer);
+
+  @protected
+  LayoutCacheStats sse_decode_layout_cache_stats(SseDeserializer deserializer);
+
+  @protected
+  LibraryBook sse_decode_library_book(SseDeserializer deserializer);
+
+  @protected
+  LibraryChapter sse_decode_library_chapter(SseDeserializer deserializer);
+
+  @protected
+  LibrarySearchResult sse_decode_library_search_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LineBreak sse_decode_line_break(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<AlternativeSource> sse_decode_list_alternative_source(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ArchiveEntry> sse_decode_list_archive_entry(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<AudioSegment> sse_decode_list_audio_segment(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<AxisValue> sse_decode_list_axis_value(SseDeserializer deserializer);
+
+  @protected
+  List<BookAlias> sse_decode_list_book_alias(SseDeserializer deserializer);
+
+  @protected
+  List<BookChapter> sse_decode_list_book_chapter(SseDeserializer deserializer);
+
+  @protected
+  List<BookReadingTime> sse_decode_list_book_reading_time(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<BookSource> sse_decode_list_book_source(SseDeserializer deserializer);
+
+  @protected
+  List<BookUpdateSummary> sse_decode_list_book_update_summary(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<Bookmark> sse_decode_list_bookmark(SseDeserializer deserializer);
+
+  @protected
+  List<CalibreBook> sse_decode_list_calibre_book(SseDeserializer deserializer);
+
+  @protected
+  List<CalibreFormat> sse_decode_list_calibre_format(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CalibreIdentifier> sse_decode_list_calibre_identifier(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ChapterImage> sse_decode_list_chapter_image(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ChapterRange> sse_decode_list_chapter_range(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ChapterUpdate> sse_decode_list_chapter_update(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CloudConfigValue> sse_decode_list_cloud_config_value(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CollectionFace> sse_decode_list_collection_face(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ComicPage> sse_decode_list_comic_page(SseDeserializer deserializer);
+
+  @protected
+  List<ConversionResult> sse_decode_list_conversion_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<DictDefinition> sse_decode_list_dict_definition(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<DictInfo> sse_decode_list_dict_info(SseDeserializer deserializer);
+
+  @protected
+  List<DiffHunk> sse_decode_list_diff_hunk(SseDeserializer deserializer);
+
+  @protected
+  List<DownloadHeader> sse_decode_list_download_header(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<DuplicateCluster> sse_decode_list_duplicate_cluster(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<DuplicateMatch> sse_decode_list_duplicate_match(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<EmphasisSpan> sse_decode_list_emphasis_span(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<EncodingCandidate> sse_decode_list_encoding_candidate(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<EpubExportChapter> sse_decode_list_epub_export_chapter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<EpubMergeSource> sse_decode_list_epub_merge_source(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<EpubResource> sse_decode_list_epub_resource(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<EpubVolume> sse_decode_list_epub_volume(SseDeserializer deserializer);
+
+  @protected
+  List<FeedEntry> sse_decode_list_feed_entry(SseDeserializer deserializer);
+
+  @protected
+  List<FetchedChapter> sse_decode_list_fetched_chapter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FontAxis> sse_decode_list_font_axis(SseDeserializer deserializer);
+
+  @protected
+  List<FontCacheEntry> sse_decode_list_font_cache_entry(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<Highlight> sse_decode_list_highlight(SseDeserializer deserializer);
+
+  @protected
+  List<ImportIssue> sse_decode_list_import_issue(SseDeserializer deserializer);
+
+  @protected
+  List<Keyword> sse_decode_list_keyword(SseDeserializer deserializer);
+
+  @protected
+  List<LanguageGuess> sse_decode_list_language_guess(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<LibraryBook> sse_decode_list_library_book(SseDeserializer deserializer);
+
+  @protected
+  List<LibraryChapter> sse_decode_list_library_chapter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<LibrarySearchResult> sse_decode_list_library_search_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<LineBreak> sse_decode_list_line_break(SseDeserializer deserializer);
+
+  @protected
+  List<LogField> sse_decode_list_log_field(SseDeserializer deserializer);
+
+  @protected
+  List<LogRecord> sse_decode_list_log_record(SseDeserializer deserializer);
+
+  @protected
+  List<MatchRange> sse_decode_list_match_range(SseDeserializer deserializer);
+
+  @protected
+  List<NamedFont> sse_decode_list_named_font(SseDeserializer deserializer);
+
+  @protected
+  List<NamedInstance> sse_decode_list_named_instance(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<OpdsEntry> sse_decode_list_opds_entry(SseDeserializer deserializer);
+
+  @protected
+  List<OpdsLink> sse_decode_list_opds_link(SseDeserializer deserializer);
+
+  @protected
+  List<AnchorResolution?> sse_decode_list_opt_box_autoadd_anchor_resolution(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PaletteSwatch> sse_decode_list_palette_swatch(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PinyinSpan> sse_decode_list_pinyin_span(SseDeserializer deserializer);
+
+  @protected
+  List<PostStep> sse_decode_list_post_step(SseDeserializer deserializer);
+
+  @protected
+  List<PrefetchChapter> sse_decode_list_prefetch_chapter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PrefetchTask> sse_decode_list_prefetch_task(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer);
+
+  @protected
+  List<int> sse_decode_list_prim_u_32_loose(SseDeserializer deserializer);
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<PurifyRule> sse_decode_list_purify_rule(SseDeserializer deserializer);
+
+  @protected
+  List<PurifyRuleMatch> sse_decode_list_purify_rule_match(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<RateLimitStatus> sse_decode_list_rate_limit_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ReadableBlock> sse_decode_list_readable_block(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ReadableSpan> sse_decode_list_readable_span(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ReadingDay> sse_decode_list_reading_day(SseDeserializer deserializer);
+
+  @protected
+  List<ReadingProgress> sse_decode_list_reading_progress(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<RuleTestMatch> sse_decode_list_rule_test_match(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SearchChapter> sse_decode_list_search_chapter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SearchHit> sse_decode_list_search_hit(SseDeserializer deserializer);
+
+  @protected
+  List<SourceBook> sse_decode_list_source_book(SseDeserializer deserializer);
+
+  @protected
+  List<SourceChapter> sse_decode_list_source_chapter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<StoredBookScan> sse_decode_list_stored_book_scan(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<StoredCookie> sse_decode_list_stored_cookie(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TagSuggestion> sse_decode_list_tag_suggestion(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TextAnchor> sse_decode_list_text_anchor(SseDeserializer deserializer);
+
+  @protected
+  List<TextColumn> sse_decode_list_text_column(SseDeserializer deserializer);
+
+  @protected
+  List<TextLine> sse_decode_list_text_line(SseDeserializer deserializer);
+
+  @protected
+  List<TextPage> sse_decode_list_text_page(SseDeserializer deserializer);
+
+  @protected
+  List<TextSegment> sse_decode_list_text_segment(SseDeserializer deserializer);
+
+  @protected
+  List<TextStats> sse_decode_list_text_stats(SseDeserializer deserializer);
+
+  @protected
+  List<TocEntry> sse_decode_list_toc_entry(SseDeserializer deserializer);
+
+  @protected
+  List<TranslatedParagraph> sse_decode_list_translated_paragraph(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TxtChapter> sse_decode_list_txt_chapter(SseDeserializer deserializer);
+
+  @protected
+  List<TxtChapterRule> sse_decode_list_txt_chapter_rule(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TxtHeadingCandidate> sse_decode_list_txt_heading_candidate(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<UpdateCheckBook> sse_decode_list_update_check_book(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<Utterance> sse_decode_list_utterance(SseDeserializer deserializer);
+
+  @protected
+  List<ValidationIssue> sse_decode_list_validation_issue(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<VerticalGlyph> sse_decode_list_vertical_glyph(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<VerticalPage> sse_decode_list_vertical_page(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<WatermarkChar> sse_decode_list_watermark_char(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<WebChapter> sse_decode_list_web_chapter(SseDeserializer deserializer);
+
+  @protected
+  List<WebDavEntry> sse_decode_list_web_dav_entry(SseDeserializer deserializer);
+
+  @protected
+  LogField sse_decode_log_field(SseDeserializer deserializer);
+
+  @protected
+  LogLevel sse_decode_log_level(SseDeserializer deserializer);
+
+  @protected
+  LogRecord sse_decode_log_record(SseDeserializer deserializer);
+
+  @protected
+  LoggingConfig sse_decode_logging_config(SseDeserializer deserializer);
+
+  @protected
+  MappedTextSlice sse_decode_mapped_text_slice(SseDeserializer deserializer);
+
+  @protected
+  MappedTxtInfo sse_decode_mapped_txt_info(SseDeserializer deserializer);
+
+  @protected
+  MatchRange sse_decode_match_range(SseDeserializer deserializer);
+
+  @protected
+  MetadataGuess sse_decode_metadata_guess(SseDeserializer deserializer);
+
+  @protected
+  MetadataOrigin sse_decode_metadata_origin(SseDeserializer deserializer);
+
+  @protected
+  NamedFont sse_decode_named_font(SseDeserializer deserializer);
+
+  @protected
+  NamedInstance sse_decode_named_instance(SseDeserializer deserializer);
+
+  @protected
+  NetworkType sse_decode_network_type(SseDeserializer deserializer);
+
+  @protected
+  NotesExportOptions sse_decode_notes_export_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NotesFormat sse_decode_notes_format(SseDeserializer deserializer);
+
+  @protected
+  OpdsCredentials sse_decode_opds_credentials(SseDeserializer deserializer);
+
+  @protected
+  OpdsEntry sse_decode_opds_entry(SseDeserializer deserializer);
+
+  @protected
+  OpdsFeed sse_decode_opds_feed(SseDeserializer deserializer);
+
+  @protected
+  OpdsLink sse_decode_opds_link(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  AnchorResolution? sse_decode_opt_box_autoadd_anchor_resolution(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiError? sse_decode_opt_box_autoadd_api_error(SseDeserializer deserializer);
+
+  @protected
+  CachedAudio? sse_decode_opt_box_autoadd_cached_audio(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ChapterFingerprint? sse_decode_opt_box_autoadd_chapter_fingerprint(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DownloadChecksum? sse_decode_opt_box_autoadd_download_checksum(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DrmScheme? sse_decode_opt_box_autoadd_drm_scheme(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  HttpCacheEntry? sse_decode_opt_box_autoadd_http_cache_entry(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  ImportKind? sse_decode_opt_box_autoadd_import_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ImportReport? sse_decode_opt_box_autoadd_import_report(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  KinsokuRules? sse_decode_opt_box_autoadd_kinsoku_rules(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LibraryBook? sse_decode_opt_box_autoadd_library_book(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MetadataGuess? sse_decode_opt_box_autoadd_metadata_guess(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  OpdsCredentials? sse_decode_opt_box_autoadd_opds_credentials(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PrefetchPause? sse_decode_opt_box_autoadd_prefetch_pause(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProcessedBook? sse_decode_opt_box_autoadd_processed_book(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProxyConfig? sse_decode_opt_box_autoadd_proxy_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RateLimit? sse_decode_opt_box_autoadd_rate_limit(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ReadingProgress? sse_decode_opt_box_autoadd_reading_progress(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SyncState? sse_decode_opt_box_autoadd_sync_state(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TextSegment? sse_decode_opt_box_autoadd_text_segment(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TtsPosition? sse_decode_opt_box_autoadd_tts_position(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  WebDavSyncRecord? sse_decode_opt_box_autoadd_web_dav_sync_record(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Uint32List? sse_decode_opt_list_prim_u_32_strict(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<TxtChapterRule>? sse_decode_opt_list_txt_chapter_rule(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PageLayout sse_decode_page_layout(SseDeserializer deserializer);
+
+  @protected
+  PaletteSwatch sse_decode_palette_swatch(SseDeserializer deserializer);
+
+  @protected
+  ParsedBook sse_decode_parsed_book(SseDeserializer deserializer);
+
+  @protected
+  PerformanceTier sse_decode_performance_tier(SseDeserializer deserializer);
+
+  @protected
+  PinyinSpan sse_decode_pinyin_span(SseDeserializer deserializer);
+
+  @protected
+  PostStep sse_decode_post_step(SseDeserializer deserializer);
+
+  @protected
+  PostStepKind sse_decode_post_step_kind(SseDeserializer deserializer);
+
+  @protected
+  PrefetchChapter sse_decode_prefetch_chapter(SseDeserializer deserializer);
+
+  @protected
+  PrefetchConfig sse_decode_prefetch_config(SseDeserializer deserializer);
+
+  @protected
+  PrefetchPause sse_decode_prefetch_pause(SseDeserializer deserializer);
+
+  @protected
+  PrefetchPlan sse_decode_prefetch_plan(SseDeserializer deserializer);
+
+  @protected
+  PrefetchStatus sse_decode_prefetch_status(SseDeserializer deserializer);
+
+  @protected
+  PrefetchTask sse_decode_prefetch_task(SseDeserializer deserializer);
+
+  @protected
+  ProcessedBook sse_decode_processed_book(SseDeserializer deserializer);
+
+  @protected
+  ProcessedImage sse_decode_processed_image(SseDeserializer deserializer);
+
+  @protected
+  ProxyConfig sse_decode_proxy_config(SseDeserializer deserializer);
+
+  @protected
+  ProxyKind sse_decode_proxy_kind(SseDeserializer deserializer);
+
+  @protected
+  PunctuationRepair sse_decode_punctuation_repair(SseDeserializer deserializer);
+
+  @protected
+  PurifyReport sse_decode_purify_report(SseDeserializer deserializer);
+
+  @protected
+  PurifyRule sse_decode_purify_rule(SseDeserializer deserializer);
+
+  @protected
+  PurifyRuleKind sse_decode_purify_rule_kind(SseDeserializer deserializer);
+
+  @protected
+  PurifyRuleMatch sse_decode_purify_rule_match(SseDeserializer deserializer);
+
+  @protected
+  RateLimit sse_decode_rate_limit(SseDeserializer deserializer);
+
+  @protected
+  RateLimitStatus sse_decode_rate_limit_status(SseDeserializer deserializer);
+
+  @protected
+  ReadableBlock sse_decode_readable_block(SseDeserializer deserializer);
+
+  @protected
+  ReadableBlockKind sse_decode_readable_block_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ReadableContent sse_decode_readable_content(SseDeserializer deserializer);
+
+  @protected
+  ReadableSpan sse_decode_readable_span(SseDeserializer deserializer);
+
+  @protected
+  ReadingDay sse_decode_reading_day(SseDeserializer deserializer);
+
+  @protected
+  ReadingProgress sse_decode_reading_progress(SseDeserializer deserializer);
+
+  @protected
+  ReadingStats sse_decode_reading_stats(SseDeserializer deserializer);
+
+  @protected
+  RepairLevel sse_decode_repair_level(SseDeserializer deserializer);
+
+  @protected
+  ResourceKind sse_decode_resource_kind(SseDeserializer deserializer);
+
+  @protected
+  ResplitOutcome sse_decode_resplit_outcome(SseDeserializer deserializer);
+
+  @protected
+  RestoreResult sse_decode_restore_result(SseDeserializer deserializer);
+
+  @protected
+  RuleTest sse_decode_rule_test(SseDeserializer deserializer);
+
+  @protected
+  RuleTestMatch sse_decode_rule_test_match(SseDeserializer deserializer);
+
+  @protected
+  ScrapedMetadata sse_decode_scraped_metadata(SseDeserializer deserializer);
+
+  @protected
+  SearchChapter sse_decode_search_chapter(SseDeserializer deserializer);
+
+  @protected
+  SearchHit sse_decode_search_hit(SseDeserializer deserializer);
+
+  @protected
+  SearchRules sse_decode_search_rules(SseDeserializer deserializer);
+
+  @protected
+  SourceBook sse_decode_source_book(SseDeserializer deserializer);
+
+  @protected
+  SourceChapter sse_decode_source_chapter(SseDeserializer deserializer);
+
+  @protected
+  SsmlOptions sse_decode_ssml_options(SseDeserializer deserializer);
+
+  @protected
+  StoredBookScan sse_decode_stored_book_scan(SseDeserializer deserializer);
+
+  @protected
+  StoredCookie sse_decode_stored_cookie(SseDeserializer deserializer);
+
+  @protected
+  StyledChapter sse_decode_styled_chapter(SseDeserializer deserializer);
+
+  @protected
+  SummaryOptions sse_decode_summary_options(SseDeserializer deserializer);
+
+  @protected
+  SyncResult sse_decode_sync_result(SseDeserializer deserializer);
+
+  @protected
+  SyncState sse_decode_sync_state(SseDeserializer deserializer);
+
+  @protected
+  TagSuggestion sse_decode_tag_suggestion(SseDeserializer deserializer);
+
+  @protected
+  TextAnchor sse_decode_text_anchor(SseDeserializer deserializer);
+
+  @protected
+  TextColumn sse_decode_text_column(SseDeserializer deserializer);
+
+  @protected
+  TextLine sse_decode_text_line(SseDeserializer deserializer);
+
+  @protected
+  TextPage sse_decode_text_page(SseDeserializer deserializer);
+
+  @protected
+  TextSegment sse_decode_text_segment(SseDeserializer deserializer);
+
+  @protected
+  TextStats sse_decode_text_stats(SseDeserializer deserializer);
+
+  @protected
+  ThemeColors sse_decode_theme_colors(SseDeserializer deserializer);
+
+  @protected
+  TierSettings sse_decode_tier_settings(SseDeserializer deserializer);
+
+  @protected
+  TocEntry sse_decode_toc_entry(SseDeserializer deserializer);
+
+  @protected
+  TocRules sse_decode_toc_rules(SseDeserializer deserializer);
+
+  @protected
+  TranslatedParagraph sse_decode_translated_paragraph(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TranslationProvider sse_decode_translation_provider(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TranslatorConfig sse_decode_translator_config(SseDeserializer deserializer);
+
+  @protected
+  TtfChunk sse_decode_ttf_chunk(SseDeserializer deserializer);
+
+  @protected
+  TtsAudioChunk sse_decode_tts_audio_chunk(SseDeserializer deserializer);
+
+  @protected
+  TtsOptions sse_decode_tts_options(SseDeserializer deserializer);
+
+  @protected
+  TtsPosition sse_decode_tts_position(SseDeserializer deserializer);
+
+  @protected
+  TtsVoiceInfo sse_decode_tts_voice_info(SseDeserializer deserializer);
+
+  @protected
+  TxtChapter sse_decode_txt_chapter(SseDeserializer deserializer);
+
+  @protected
+  TxtChapterProposal sse_decode_txt_chapter_proposal(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TxtChapterRule sse_decode_txt_chapter_rule(SseDeserializer deserializer);
+
+  @protected
+  TxtHeadingCandidate sse_decode_txt_heading_candidate(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TxtIndex sse_decode_txt_index(SseDeserializer deserializer);
+
+  @protected
+  TypographyOptions sse_decode_typography_options(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  UpdateCheckBook sse_decode_update_check_book(SseDeserializer deserializer);
+
+  @protected
+  UpdateCheckStatus sse_decode_update_check_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Utterance sse_decode_utterance(SseDeserializer deserializer);
+
+  @protected
+  ValidationIssue sse_decode_validation_issue(SseDeserializer deserializer);
+
+  @protected
+  ValidationIssueKind sse_decode_validation_issue_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ValidationReport sse_decode_validation_report(SseDeserializer deserializer);
+
+  @protected
+  VerticalGlyph sse_decode_vertical_glyph(SseDeserializer deserializer);
+
+  @protected
+  VerticalPage sse_decode_vertical_page(SseDeserializer deserializer);
+
+  @protected
+  WatermarkChar sse_decode_watermark_char(SseDeserializer deserializer);
+
+  @protected
+  WatermarkKind sse_decode_watermark_kind(SseDeserializer deserializer);
+
+  @protected
+  WatermarkReport sse_decode_watermark_report(SseDeserializer deserializer);
+
+  @protected
+  WebBook sse_decode_web_book(SseDeserializer deserializer);
+
+  @protected
+  WebChapter sse_decode_web_chapter(SseDeserializer deserializer);
+
+  @protected
+  WebDavConfig sse_decode_web_dav_config(SseDeserializer deserializer);
+
+  @protected
+  WebDavEntry sse_decode_web_dav_entry(SseDeserializer deserializer);
+
+  @protected
+  WebDavSyncRecord sse_decode_web_dav_sync_record(SseDeserializer deserializer);
+
+  @protected
+  WebDavSyncStatus sse_decode_web_dav_sync_status(SseDeserializer deserializer);
+
+  @protected
+  WebImportProgress sse_decode_web_import_progress(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WebServerConfig sse_decode_web_server_config(SseDeserializer deserializer);
+
+  @protected
+  WebServerInfo sse_decode_web_server_info(SseDeserializer deserializer);
+
+  @protected
+  WebUpload sse_decode_web_upload(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_archive_progress_Sse(
+    RustStreamSink<ArchiveProgress> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_download_event_Sse(
+    RustStreamSink<DownloadEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_epub_export_progress_Sse(
+    RustStreamSink<EpubExportProgress> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_import_event_Sse(
+    RustStreamSink<ImportEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_rate_limit_status_Sse(
+    RustStreamSink<RateLimitStatus> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_ttf_chunk_Sse(
+    RustStreamSink<TtfChunk> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_tts_audio_chunk_Sse(
+    RustStreamSink<TtsAudioChunk> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_web_import_progress_Sse(
+    RustStreamSink<WebImportProgress> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_web_upload_Sse(
+    RustStreamSink<WebUpload> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_alternative_chapter(
+    AlternativeChapter self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_alternative_source(
+    AlternativeSource self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_anchor_resolution(
+    AnchorResolution self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_error(ApiError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_archive_entry(ArchiveEntry self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_archive_format(ArchiveFormat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_archive_info(ArchiveInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_archive_progress(
+    ArchiveProgress self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_audio_cache_stats(
+    AudioCacheStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_audio_format(AudioFormat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_audio_segment(AudioSegment self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_axis_value(AxisValue self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_backup_info(BackupInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bandwidth_policy(
+    BandwidthPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_bench_report(BenchReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bionic_options(BionicOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_blob_gc_report(BlobGcReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_blob_store_stats(
+    BlobStoreStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_book_alias(BookAlias self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_book_chapter(BookChapter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_book_format(BookFormat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_book_image(BookImage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_book_info_rules(BookInfoRules self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_book_keywords(BookKeywords self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_book_metadata(BookMetadata self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_book_page(BookPage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_book_query(BookQuery self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_book_reading_time(
+    BookReadingTime self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_book_sort(BookSort self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_book_source(BookSource self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_book_stats(BookStats self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_book_update_summary(
+    BookUpdateSummary self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_bookmark(Bookmark self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_anchor_resolution(
+    AnchorResolution self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_api_error(
+    ApiError self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_bandwidth_policy(
+    BandwidthPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_bionic_options(
+    BionicOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_book_image(
+    BookImage self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_book_metadata(
+    BookMetadata self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_book_query(
+    BookQuery self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_book_source(
+    BookSource self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_cached_audio(
+    CachedAudio self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_calibre_metadata(
+    CalibreMetadata self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_chapter_fingerprint(
+    ChapterFingerprint self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_cover_image_options(
+    CoverImageOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_cover_style(
+    CoverStyle self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_dark_image_options(
+    DarkImageOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_dedup_options(
+    DedupOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_download_checksum(
+    DownloadChecksum self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_download_request(
+    DownloadRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_downloader_config(
+    DownloaderConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_drm_scheme(
+    DrmScheme self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_epub_split_options(
+    EpubSplitOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_http_cache_entry(
+    HttpCacheEntry self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_import_kind(
+    ImportKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_import_options(
+    ImportOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_import_report(
+    ImportReport self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_kinsoku_rules(
+    KinsokuRules self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_library_book(
+    LibraryBook self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_logging_config(
+    LoggingConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_metadata_guess(
+    MetadataGuess self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_notes_export_options(
+    NotesExportOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_opds_credentials(
+    OpdsCredentials self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_page_layout(
+    PageLayout self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_prefetch_config(
+    PrefetchConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_prefetch_pause(
+    PrefetchPause self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_prefetch_plan(
+    PrefetchPlan self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_processed_book(
+    ProcessedBook self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_proxy_config(
+    ProxyConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_purify_rule(
+    PurifyRule self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_rate_limit(
+    RateLimit self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_reading_progress(
+    ReadingProgress self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_ssml_options(
+    SsmlOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_summary_options(
+    SummaryOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_sync_state(
+    SyncState self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_text_anchor(
+    TextAnchor self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_text_segment(
+    TextSegment self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_translator_config(
+    TranslatorConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_tts_options(
+    TtsOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_tts_position(
+    TtsPosition self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_txt_chapter(
+    TxtChapter self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_txt_chapter_rule(
+    TxtChapterRule self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_typography_options(
+    TypographyOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_web_dav_config(
+    WebDavConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_web_dav_sync_record(
+    WebDavSyncRecord self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_web_server_config(
+    WebServerConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_cached_audio(CachedAudio self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_calibre_book(CalibreBook self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_calibre_format(CalibreFormat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_calibre_identifier(
+    CalibreIdentifier self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_calibre_metadata(
+    CalibreMetadata self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_chapter_audio(ChapterAudio self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_chapter_cache_migration(
+    ChapterCacheMigration self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_chapter_change(ChapterChange self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_chapter_diff(ChapterDiff self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_chapter_fingerprint(
+    ChapterFingerprint self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_chapter_image(ChapterImage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_chapter_range(ChapterRange self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_chapter_store_stats(
+    ChapterStoreStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_chapter_text(ChapterText self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_chapter_update(ChapterUpdate self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_chapter_update_report(
+    ChapterUpdateReport self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_checksum_algorithm(
+    ChecksumAlgorithm self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_chinese_conversion(
+    ChineseConversion self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_cloud_config(CloudConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_cloud_config_value(
+    CloudConfigValue self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_collection_face(
+    CollectionFace self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_comic_page(ComicPage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_content_rules(ContentRules self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_conversion_result(
+    ConversionResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_cover_image_options(
+    CoverImageOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_cover_palette(CoverPalette self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_cover_placeholder(
+    CoverPlaceholder self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_cover_style(CoverStyle self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_coverage_report(
+    CoverageReport self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_dark_image(DarkImage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_dark_image_mode(DarkImageMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_dark_image_options(
+    DarkImageOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_decoded_text(DecodedText self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_dedup_options(DedupOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_dict_definition(
+    DictDefinition self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_dict_format(DictFormat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_dict_info(DictInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_diff_hunk(DiffHunk self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_diff_hunk_kind(DiffHunkKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_download_checksum(
+    DownloadChecksum self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_download_event(DownloadEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_download_header(
+    DownloadHeader self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_download_request(
+    DownloadRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_download_state(DownloadState self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_downloader_config(
+    DownloaderConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_drm_scheme(DrmScheme self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_drm_status(DrmStatus self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_duplicate_cluster(
+    DuplicateCluster self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_duplicate_match(
+    DuplicateMatch self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_emphasis_span(EmphasisSpan self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_encoding_candidate(
+    EncodingCandidate self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_epub_export_chapter(
+    EpubExportChapter self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_epub_export_progress(
+    EpubExportProgress self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_epub_layout(EpubLayout self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_epub_merge_source(
+    EpubMergeSource self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_epub_resource(EpubResource self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_epub_split_options(
+    EpubSplitOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_epub_volume(EpubVolume self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_error_code(ErrorCode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_extracted_file(ExtractedFile self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_extraction(Extraction self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_f_32(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_feed(Feed self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_feed_entry(FeedEntry self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_fetched_chapter(
+    FetchedChapter self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_font_axis(FontAxis self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_font_bundle(FontBundle self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_font_cache_entry(
+    FontCacheEntry self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_font_cache_stats(
+    FontCacheStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_font_info(FontInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_font_preview(FontPreview self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_highlight(Highlight self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_http_cache_entry(
+    HttpCacheEntry self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_http_cache_stats(
+    HttpCacheStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_import_event(ImportEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_import_event_kind(
+    ImportEventKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_import_issue(ImportIssue self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_import_kind(ImportKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_import_options(ImportOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_import_report(ImportReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_keyword(Keyword self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_keyword_kind(KeywordKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_kinsoku_rules(KinsokuRules self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_language_guess(LanguageGuess self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_layout_cache_stats(
+    LayoutCacheStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_library_book(LibraryBook self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_library_chapter(
+    LibraryChapter self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_library_search_result(
+    LibrarySearchResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_line_break(LineBreak self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_alternative_source(
+    List<AlternativeSource> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_archive_entry(
+    List<ArchiveEntry> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_audio_segment(
+    List<AudioSegment> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_axis_value(
+    List<AxisValue> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_book_alias(
+    List<BookAlias> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_book_chapter(
+    List<BookChapter> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_book_reading_time(
+    List<BookReadingTime> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_book_source(
+    List<BookSource> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_book_update_summary(
+    List<BookUpdateSummary> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_bookmark(List<Bookmark> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_calibre_book(
+    List<CalibreBook> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_calibre_format(
+    List<CalibreFormat> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_calibre_identifier(
+    List<CalibreIdentifier> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_chapter_image(
+    List<ChapterImage> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_chapter_range(
+    List<ChapterRange> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_chapter_update(
+    List<ChapterUpdate> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_cloud_config_value(
+    List<CloudConfigValue> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_collection_face(
+    List<CollectionFace> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_comic_page(
+    List<ComicPage> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_conversion_result(
+    List<ConversionResult> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_dict_definition(
+    List<DictDefinition> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_dict_info(List<DictInfo> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_diff_hunk(List<DiffHunk> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_download_header(
+    List<DownloadHeader> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_duplicate_cluster(
+    List<DuplicateCluster> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_duplicate_match(
+    List<DuplicateMatch> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_emphasis_span(
+    List<EmphasisSpan> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_encoding_candidate(
+    List<EncodingCandidate> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_epub_export_chapter(
+    List<EpubExportChapter> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_epub_merge_source(
+    List<EpubMergeSource> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_epub_resource(
+    List<EpubResource> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_epub_volume(
+    List<EpubVolume> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_feed_entry(
+    List<FeedEntry> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_fetched_chapter(
+    List<FetchedChapter> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_font_axis(List<FontAxis> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_font_cache_entry(
+    List<FontCacheEntry> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_highlight(
+    List<Highlight> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_import_issue(
+    List<ImportIssue> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_keyword(List<Keyword> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_language_guess(
+    List<LanguageGuess> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_library_book(
+    List<LibraryBook> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_library_chapter(
+    List<LibraryChapter> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_library_search_result(
+    List<LibrarySearchResult> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_line_break(
+    List<LineBreak> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_log_field(List<LogField> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_log_record(
+    List<LogRecord> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_match_range(
+    List<MatchRange> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_named_font(
+    List<NamedFont> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_named_instance(
+    List<NamedInstance> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_opds_entry(
+    List<OpdsEntry> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_opds_link(List<OpdsLink> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_opt_box_autoadd_anchor_resolution(
+    List<AnchorResolution?> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_palette_swatch(
+    List<PaletteSwatch> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_pinyin_span(
+    List<PinyinSpan> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_post_step(List<PostStep> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prefetch_chapter(
+    List<PrefetchChapter> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prefetch_task(
+    List<PrefetchTask> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_i_64_strict(
+    Int64List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_32_loose(
+    List<int> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_purify_rule(
+    List<PurifyRule> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_purify_rule_match(
+    List<PurifyRuleMatch> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_rate_limit_status(
+    List<RateLimitStatus> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_readable_block(
+    List<ReadableBlock> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_readable_span(
+    List<ReadableSpan> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_reading_day(
+    List<ReadingDay> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_reading_progress(
+    List<ReadingProgress> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_rule_test_match(
+    List<RuleTestMatch> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_search_chapter(
+    List<SearchChapter> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_search_hit(
+    List<SearchHit> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_source_book(
+    List<SourceBook> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_source_chapter(
+    List<SourceChapter> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_stored_book_scan(
+    List<StoredBookScan> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_stored_cookie(
+    List<StoredCookie> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_tag_suggestion(
+    List<TagSuggestion> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_text_anchor(
+    List<TextAnchor> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_text_column(
+    List<TextColumn> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_text_line(List<TextLine> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_text_page(List<TextPage> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_text_segment(
+    List<TextSegment> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_text_stats(
+    List<TextStats> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_toc_entry(List<TocEntry> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_translated_paragraph(
+    List<TranslatedParagraph> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_txt_chapter(
+    List<TxtChapter> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_txt_chapter_rule(
+    List<TxtChapterRule> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_txt_heading_candidate(
+    List<TxtHeadingCandidate> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_update_check_book(
+    List<UpdateCheckBook> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_utterance(
+    List<Utterance> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_validation_issue(
+    List<ValidationIssue> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_vertical_glyph(
+    List<VerticalGlyph> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_vertical_page(
+    List<VerticalPage> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_watermark_char(
+    List<WatermarkChar> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_web_chapter(
+    List<WebChapter> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_web_dav_entry(
+    List<WebDavEntry> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_log_field(LogField self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_log_level(LogLevel self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_log_record(LogRecord self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_logging_config(LoggingConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_mapped_text_slice(
+    MappedTextSlice self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_mapped_txt_info(MappedTxtInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_match_range(MatchRange self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_metadata_guess(MetadataGuess self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_metadata_origin(
+    MetadataOrigin self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_named_font(NamedFont self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_named_instance(NamedInstance self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_network_type(NetworkType self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_notes_export_options(
+    NotesExportOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_notes_format(NotesFormat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opds_credentials(
+    OpdsCredentials self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opds_entry(OpdsEntry self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opds_feed(OpdsFeed self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opds_link(OpdsLink self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_anchor_resolution(
+    AnchorResolution? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_api_error(
+    ApiError? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_cached_audio(
+    CachedAudio? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_chapter_fingerprint(
+    ChapterFingerprint? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_download_checksum(
+    DownloadChecksum? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_drm_scheme(
+    DrmScheme? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_http_cache_entry(
+    HttpCacheEntry? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_import_kind(
+    ImportKind? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_import_report(
+    ImportReport? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_kinsoku_rules(
+    KinsokuRules? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_library_book(
+    LibraryBook? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_metadata_guess(
+    MetadataGuess? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_opds_credentials(
+    OpdsCredentials? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_prefetch_pause(
+    PrefetchPause? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_processed_book(
+    ProcessedBook? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_proxy_config(
+    ProxyConfig? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_rate_limit(
+    RateLimit? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_reading_progress(
+    ReadingProgress? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_sync_state(
+    SyncState? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_text_segment(
+    TextSegment? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_tts_position(
+    TtsPosition? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_web_dav_sync_record(
+    WebDavSyncRecord? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+  void sse_encode_opt_list_prim_u_32_strict(
+    Uint32List? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int dco_decode_u_32(dynamic raw);
+  void sse_encode_opt_list_txt_chapter_rule(
+    List<TxtChapterRule>? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  void sse_encode_page_layout(PageLayout self, SseSerializer serializer);
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+  void sse_encode_palette_swatch(PaletteSwatch self, SseSerializer serializer);
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  void sse_encode_parsed_book(ParsedBook self, SseSerializer serializer);
 
   @protected
-  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+  void sse_encode_performance_tier(
+    PerformanceTier self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+  void sse_encode_pinyin_span(PinyinSpan self, SseSerializer serializer);
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  void sse_encode_post_step(PostStep self, SseSerializer serializer);
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  void sse_encode_post_step_kind(PostStepKind self, SseSerializer serializer);
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  void sse_encode_prefetch_chapter(
+    PrefetchChapter self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  void sse_encode_prefetch_config(
+    PrefetchConfig self,
+    SseSerializer serializer,
+  );
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  void sse_encode_prefetch_pause(PrefetchPause self, SseSerializer serializer);
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_prefetch_plan(PrefetchPlan self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_prefetch_status(
+    PrefetchStatus self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  void sse_encode_prefetch_task(PrefetchTask self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_prim_u_32_strict(
-    Uint32List self,
+  void sse_encode_processed_book(ProcessedBook self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_processed_image(
+    ProcessedImage self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+  void sse_encode_proxy_config(ProxyConfig self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_proxy_kind(ProxyKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_punctuation_repair(
+    PunctuationRepair self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_purify_report(PurifyReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_purify_rule(PurifyRule self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_purify_rule_kind(
+    PurifyRuleKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_purify_rule_match(
+    PurifyRuleMatch self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_rate_limit(RateLimit self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_rate_limit_status(
+    RateLimitStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_readable_block(ReadableBlock self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_readable_block_kind(
+    ReadableBlockKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_readable_content(
+    ReadableContent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_readable_span(ReadableSpan self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_reading_day(ReadingDay self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_reading_progress(
+    ReadingProgress self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_reading_stats(ReadingStats self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_repair_level(RepairLevel self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_resource_kind(ResourceKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_resplit_outcome(
+    ResplitOutcome self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_restore_result(RestoreResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_rule_test(RuleTest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_rule_test_match(RuleTestMatch self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_scraped_metadata(
+    ScrapedMetadata self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_search_chapter(SearchChapter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_search_hit(SearchHit self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_search_rules(SearchRules self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_source_book(SourceBook self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_source_chapter(SourceChapter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_ssml_options(SsmlOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_stored_book_scan(
+    StoredBookScan self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_stored_cookie(StoredCookie self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_styled_chapter(StyledChapter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_summary_options(
+    SummaryOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_sync_result(SyncResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sync_state(SyncState self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_tag_suggestion(TagSuggestion self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_text_anchor(TextAnchor self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_text_column(TextColumn self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_text_line(TextLine self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_text_page(TextPage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_text_segment(TextSegment self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_text_stats(TextStats self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_theme_colors(ThemeColors self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_tier_settings(TierSettings self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_toc_entry(TocEntry self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_toc_rules(TocRules self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_translated_paragraph(
+    TranslatedParagraph self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_translation_provider(
+    TranslationProvider self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_translator_config(
+    TranslatorConfig self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_ttf_chunk(TtfChunk self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_tts_audio_chunk(TtsAudioChunk self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_tts_options(TtsOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_tts_position(TtsPosition self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_tts_voice_info(TtsVoiceInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_txt_chapter(TxtChapter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_txt_chapter_proposal(
+    TxtChapterProposal self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_txt_chapter_rule(
+    TxtChapterRule self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_txt_heading_candidate(
+    TxtHeadingCandidate self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_txt_index(TxtIndex self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_typography_options(
+    TypographyOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_unit(void self, SseSerializer serializer);
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_update_check_book(
+    UpdateCheckBook self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_update_check_status(
+    UpdateCheckStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_utterance(Utterance self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_validation_issue(
+    ValidationIssue self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_validation_issue_kind(
+    ValidationIssueKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_validation_report(
+    ValidationReport self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_vertical_glyph(VerticalGlyph self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_vertical_page(VerticalPage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_watermark_char(WatermarkChar self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_watermark_kind(WatermarkKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_watermark_report(
+    WatermarkReport self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_web_book(WebBook self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_web_chapter(WebChapter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_web_dav_config(WebDavConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_web_dav_entry(WebDavEntry self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_web_dav_sync_record(
+    WebDavSyncRecord self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_web_dav_sync_status(
+    WebDavSyncStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_web_import_progress(
+    WebImportProgress self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_web_server_config(
+    WebServerConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_web_server_info(WebServerInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_web_upload(WebUpload self, SseSerializer serializer);
 }
 
 // Section: wire_class
@@ -0,0 +1,332 @@
+//! Highlight and bookmark anchors that survive edits to the chapter text.
+//!
+//! An anchor stores the quoted text with a little context on each side,
+//! plus the offsets where it was last seen. Resolving it against a new
+//! version of the chapter first looks for the exact quote, preferring the
+//! occurrence whose context and position match best. If the quote itself
+//! changed, an approximate search allowing a quarter of its characters to
+//! differ runs near the old position, then over the whole chapter. Offsets
+//! are UTF-16 code units.
+
+use anyhow::{anyhow, Result};
+
+use crate::api::error::ApiError;
+
+/// Characters of context kept on each side of the quote.
+const CONTEXT_CHARS: usize = 32;
+
+/// Share of the quote that must survive for a fuzzy match.
+const MIN_QUOTE_SIMILARITY: f32 = 0.75;
+
+/// Characters searched on each side of the old position before falling
+/// back to the whole chapter.
+const WINDOW_CHARS: usize = 2000;
+
+/// Largest quote-by-text table the approximate search may use.
+const MAX_SEARCH_CELLS: usize = 4_000_000;
+
+#[derive(Debug, Clone)]
+pub struct TextAnchor {
+    /// The quoted text.
+    pub exact: String,
+    /// Text just before the quote.
+    pub prefix: String,
+    /// Text just after the quote.
+    pub suffix: String,
+    /// Where the quote was last found.
+    pub start: u32,
+    pub end: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct AnchorResolution {
+    /// The anchor with its quote, context and offsets taken from the new
+    /// text, to store in place of the old one.
+    pub anchor: TextAnchor,
+    /// Whether the quote was found unchanged.
+    pub exact: bool,
+    /// How much of the quote survived, from 0.75 to 1.
+    pub similarity: f32,
+}
+
+/// Make an anchor for a range of `text`.
+///
+/// # Arguments
+/// * `text` - Chapter text
+/// * `start` - Start of the range
+/// * `end` - End of the range, after `start`
+#[flutter_rust_bridge::frb]
+pub fn create_anchor(text: String, start: u32, end: u32) -> Result<TextAnchor, ApiError> {
+    let text = Text::new(&text);
+    let (Some(start), Some(end)) = (text.char_index(start), text.char_index(end)) else {
+        return Err(anyhow!("Anchor range {start}..{end} is not within the text").into());
+    };
+    if start >= end {
+        return Err(anyhow!("Anchor range {start}..{end} is empty").into());
+    }
+    Ok(text.anchor(start, end))
+}
+
+/// Find an anchor in a new version of the chapter, or `None` if the quoted
+/// text is gone.
+#[flutter_rust_bridge::frb]
+pub fn resolve_anchor(text: String, anchor: TextAnchor) -> Option<AnchorResolution> {
+    resolve(&Text::new(&text), &anchor)
+}
+
+/// Resolve several anchors against the same text, in input order.
+#[flutter_rust_bridge::frb]
+pub fn resolve_anchors(text: String, anchors: Vec<TextAnchor>) -> Vec<Option<AnchorResolution>> {
+    let text = Text::new(&text);
+    anchors
+        .iter()
+        .map(|anchor| resolve(&text, anchor))
+        .collect()
+}
+
+/// Chapter text as characters, with their UTF-16 offsets.
+struct Text {
+    chars: Vec<char>,
+    /// UTF-16 offset of each character, plus the total length.
+    utf16: Vec<u32>,
+}
+
+impl Text {
+    fn new(text: &str) -> Self {
+        let chars: Vec<char> = text.chars().collect();
+        let mut utf16 = Vec::with_capacity(chars.len() + 1);
+        let mut offset = 0;
+        for c in &chars {
+            utf16.push(offset);
+            offset += c.len_utf16() as u32;
+        }
+        utf16.push(offset);
+        Self { chars, utf16 }
+    }
+
+    /// The character at a UTF-16 offset, if it is a character boundary.
+    fn char_index(&self, offset: u32) -> Option<usize> {
+        self.utf16.binary_search(&offset).ok()
+    }
+
+    /// The character at or after a UTF-16 offset, clamped to the text.
+    fn nearest_char(&self, offset: u32) -> usize {
+        self.utf16
+            .partition_point(|&o| o < offset)
+            .min(self.chars.len())
+    }
+
+    fn slice(&self, start: usize, end: usize) -> String {
+        self.chars[start..end].iter().collect()
+    }
+
+    fn anchor(&self, start: usize, end: usize) -> TextAnchor {
+        TextAnchor {
+            exact: self.slice(start, end),
+            prefix: self.slice(start.saturating_sub(CONTEXT_CHARS), start),
+            suffix: self.slice(end, (end + CONTEXT_CHARS).min(self.chars.len())),
+            start: self.utf16[start],
+            end: self.utf16[end],
+        }
+    }
+}
+
+/// A place the quote may now be, in characters.
+struct Candidate {
+    start: usize,
+    end: usize,
+    similarity: f32,
+}
+
+fn resolve(text: &Text, anchor: &TextAnchor) -> Option<AnchorResolution> {
+    let quote: Vec<char> = anchor.exact.chars().collect();
+    if quote.is_empty() || text.chars.is_empty() {
+        return None;
+    }
+    let prefix: Vec<char> = anchor.prefix.chars().collect();
+    let suffix: Vec<char> = anchor.suffix.chars().collect();
+    let hint = text.nearest_char(anchor.start);
+    let score = |candidate: &Candidate| {
+        let before = &text.chars[candidate.start.saturating_sub(prefix.len())..candidate.start];
+        let after_end = (candidate.end + suffix.len()).min(text.chars.len());
+        let after = &text.chars[candidate.end..after_end];
+        let context = (similarity(&prefix, before) + similarity(&suffix, after)) / 2.0;
+        let distance = candidate.start.abs_diff(hint) as f32 / text.chars.len() as f32;
+        candidate.similarity * 0.6 + context * 0.3 + (1.0 - distance.min(1.0)) * 0.1
+    };
+    let best = |candidates: Vec<Candidate>| {
+        candidates
+            .into_iter()
+            .map(|candidate| (score(&candidate), candidate))
+            .max_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, candidate)| candidate)
+    };
+
+    let found = best(exact_matches(&text.chars, &quote))
+        .or_else(|| {
+            let start = hint.saturating_sub(WINDOW_CHARS);
+            let end = (hint + quote.len() + WINDOW_CHARS).min(text.chars.len());
+            best(fuzzy_matches(&text.chars, &quote, start, end))
+        })
+        .or_else(|| {
+            let cells = text.chars.len().saturating_mul(quote.len());
+            (cells <= MAX_SEARCH_CELLS)
+                .then(|| best(fuzzy_matches(&text.chars, &quote, 0, text.chars.len())))
+                .flatten()
+        })?;
+    Some(AnchorResolution {
+        anchor: text.anchor(found.start, found.end),
+        exact: found.similarity == 1.0,
+        similarity: found.similarity,
+    })
+}
+
+fn exact_matches(text: &[char], quote: &[char]) -> Vec<Candidate> {
+    if quote.len() > text.len() {
+        return Vec::new();
+    }
+    (0..=text.len() - quote.len())
+        .filter(|&start| text[start..start + quote.len()] == *quote)
+        .map(|start| Candidate {
+            start,
+            end: start + quote.len(),
+            similarity: 1.0,
+        })
+        .collect()
+}
+
+/// Approximate occurrences of `quote` within `text[from..to]`, found with
+/// Sellers' edit-distance search. Each is the closest match ending at a
+/// local minimum of the distance.
+fn fuzzy_matches(text: &[char], quote: &[char], from: usize, to: usize) -> Vec<Candidate> {
+    let max_edits = ((1.0 - MIN_QUOTE_SIMILARITY) * quote.len() as f32) as usize;
+    if to.saturating_sub(from) * quote.len() > MAX_SEARCH_CELLS {
+        return Vec::new();
+    }
+    // Distance and match start for each prefix of the quote, ending at the
+    // current text position.
+    let mut distance: Vec<usize> = (0..=quote.len()).collect();
+    let mut start = vec![from; quote.len() + 1];
+    let mut ends: Vec<(usize, usize, usize)> = Vec::new();
+    for (j, &c) in text.iter().enumerate().take(to).skip(from) {
+        let (mut diagonal, mut diagonal_start) = (distance[0], start[0]);
+        start[0] = j + 1;
+        for i in 1..=quote.len() {
+            let substitute = diagonal + usize::from(quote[i - 1] != c);
+            let (skip_quote, skip_text) = (distance[i - 1] + 1, distance[i] + 1);
+            let (next, next_start) = if substitute <= skip_quote && substitute <= skip_text {
+                (substitute, diagonal_start)
+            } else if skip_quote <= skip_text {
+                (skip_quote, start[i - 1])
+            } else {
+                (skip_text, start[i])
+            };
+            (diagonal, diagonal_start) = (distance[i], start[i]);
+            (distance[i], start[i]) = (next, next_start);
+        }
+        ends.push((distance[quote.len()], start[quote.len()], j + 1));
+    }
+
+    let mut candidates = Vec::new();
+    for (k, &(edits, start, end)) in ends.iter().enumerate() {
+        let previous = k.checked_sub(1).map_or(usize::MAX, |p| ends[p].0);
+        let next = ends.get(k + 1).map_or(usize::MAX, |n| n.0);
+        if edits <= max_edits && edits < previous && edits <= next && start < end {
+            candidates.push(Candidate {
+                start,
+                end,
+                similarity: 1.0 - edits as f32 / quote.len() as f32,
+            });
+        }
+    }
+    candidates
+}
+
+/// Edit-distance similarity of two short strings, from 0 to 1; empty
+/// strings are alike.
+fn similarity(a: &[char], b: &[char]) -> f32 {
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, &ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let next = (diagonal + usize::from(ca != cb))
+                .min(row[j] + 1)
+                .min(row[j + 1] + 1);
+            diagonal = row[j + 1];
+            row[j + 1] = next;
+        }
+    }
+    1.0 - row[b.len()] as f32 / longest as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHAPTER: &str = concat!(
+        "　　夜色渐深，长街上只剩下几盏灯笼还亮着。\n",
+        "　　他推开门，看见她正坐在窗边读信，烛火在信纸上跳动。\n",
+        "　　“你终于来了。”她说，没有回头。\n",
+    );
+
+    fn anchor_for(text: &str, quote: &str) -> TextAnchor {
+        let start = text.find(quote).unwrap();
+        let utf16 = |byte: usize| text[..byte].encode_utf16().count() as u32;
+        create_anchor(text.to_string(), utf16(start), utf16(start + quote.len())).unwrap()
+    }
+
+    fn quoted(text: &str, resolution: &AnchorResolution) -> String {
+        let units: Vec<u16> = text.encode_utf16().collect();
+        let range = resolution.anchor.start as usize..resolution.anchor.end as usize;
+        String::from_utf16(&units[range]).unwrap()
+    }
+
+    #[test]
+    fn test_exact_match_after_insertion() {
+        let anchor = anchor_for(CHAPTER, "看见她正坐在窗边读信");
+        assert!(CHAPTER.contains(&format!(
+            "{}{}{}",
+            anchor.prefix, anchor.exact, anchor.suffix
+        )));
+        let edited = format!("　　第一章 来信\n{CHAPTER}");
+        let resolution = resolve_anchor(edited.clone(), anchor).unwrap();
+        assert!(resolution.exact);
+        assert_eq!(quoted(&edited, &resolution), "看见她正坐在窗边读信");
+    }
+
+    #[test]
+    fn test_context_picks_between_repeats() {
+        let text = "他说：“好。”然后走了。\n她也说：“好。”然后留下。\n";
+        let anchor = anchor_for(text, "她也说：“好。”");
+        let mut second = anchor.clone();
+        second.exact = "“好。”".to_string();
+        second.prefix = "她也说：".to_string();
+        // Stale offsets point at the first occurrence; context wins.
+        second.start = 3;
+        let resolution = resolve_anchor(text.to_string(), second).unwrap();
+        assert_eq!(quoted(text, &resolution), "“好。”");
+        assert!(resolution.anchor.prefix.ends_with("她也说："));
+    }
+
+    #[test]
+    fn test_fuzzy_match_after_edit_and_loss() {
+        let anchor = anchor_for(CHAPTER, "烛火在信纸上跳动");
+        let edited = CHAPTER
+            .replace("烛火在信纸上跳动", "烛光在信纸上跳动")
+            .replace("夜色", "暮色");
+        let resolution = resolve_anchor(edited.clone(), anchor.clone()).unwrap();
+        assert!(!resolution.exact);
+        assert_eq!(quoted(&edited, &resolution), "烛光在信纸上跳动");
+        assert!(resolution.similarity >= MIN_QUOTE_SIMILARITY);
+
+        let rewritten = CHAPTER.replace("烛火在信纸上跳动", "窗外下起了大雨");
+        assert!(resolve_anchor(rewritten, anchor.clone()).is_none());
+        assert!(create_anchor(CHAPTER.to_string(), 5, 5).is_err());
+        assert!(create_anchor(CHAPTER.to_string(), 0, 10_000).is_err());
+    }
+}
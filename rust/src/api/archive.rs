@@ -0,0 +1,648 @@
+//! Bulk import from ZIP, RAR and 7z archives.
+//!
+//! `list_archive` shows what an archive holds and which entries are books;
+//! `extract_archive` copies the chosen entries into a directory, after
+//! which each file goes through the importer for its `ImportKind`. Entry
+//! names are sanitised so nothing lands outside the directory, existing
+//! files are never overwritten, and the bytes actually written are capped
+//! per entry, per archive and, where the packed size of an entry is known,
+//! by compression ratio, so a crafted archive cannot fill the disk.
+
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use unrar::Archive;
+use zip::ZipArchive;
+
+use crate::api::error::ApiError;
+use crate::api::task::{self, CancelToken};
+use crate::frb_generated::StreamSink;
+use crate::sevenz::SevenZip;
+
+/// Largest single entry extracted.
+const MAX_ENTRY_BYTES: u64 = 512 << 20;
+
+/// Largest total extracted from one archive.
+const MAX_TOTAL_BYTES: u64 = 4 << 30;
+
+/// Largest expansion allowed for an entry whose packed size is known.
+/// Plain text compresses well, so this is generous.
+const MAX_RATIO: u64 = 100;
+
+/// Entries may always expand to this size, whatever their ratio.
+const RATIO_FLOOR: u64 = 1 << 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    Zip,
+    Rar,
+    SevenZip,
+}
+
+/// Which importer handles an extracted file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportKind {
+    /// `parse_epub`
+    Epub,
+    /// `parse_mobi`, for MOBI, AZW and AZW3
+    Mobi,
+    /// `parse_pdf`
+    Pdf,
+    /// `parse_fb2`
+    Fb2,
+    /// `parse_umd`
+    Umd,
+    /// `process_books`
+    Txt,
+    /// `list_comic_pages`, for CBZ and CBR
+    Comic,
+}
+
+#[derive(Debug, Clone)]
+pub struct ArchiveInfo {
+    pub format: ArchiveFormat,
+    /// Files in archive order; directories are left out.
+    pub entries: Vec<ArchiveEntry>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ArchiveEntry {
+    /// Position in the archive; pass this to `extract_archive`.
+    pub index: u32,
+    /// Entry path inside the archive, with `/` separators.
+    pub name: String,
+    /// Uncompressed size in bytes, as the archive declares it.
+    pub size: u32,
+    /// The importer for the entry, or `None` if it is not a book.
+    pub kind: Option<ImportKind>,
+    /// Whether the entry is password-protected and cannot be extracted.
+    pub encrypted: bool,
+}
+
+/// Reported once per entry, whether or not it could be extracted.
+#[derive(Debug, Clone)]
+pub struct ArchiveProgress {
+    pub done: u32,
+    pub total: u32,
+    pub file: ExtractedFile,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExtractedFile {
+    pub index: u32,
+    pub name: String,
+    pub kind: Option<ImportKind>,
+    /// Where the entry was written, if it was.
+    pub path: Option<String>,
+    /// Why the entry was not extracted.
+    pub error: Option<String>,
+}
+
+/// List the files in a ZIP, RAR or 7z archive.
+///
+/// # Arguments
+/// * `path` - Path to the archive
+#[flutter_rust_bridge::frb]
+pub fn list_archive(path: String) -> Result<ArchiveInfo, ApiError> {
+    let format = detect_format(&path)?;
+    let entries = list_entries(&path, format)?
+        .into_iter()
+        .filter(|entry| !entry.is_dir)
+        .map(|entry| ArchiveEntry {
+            index: entry.index as u32,
+            kind: import_kind(&entry.name),
+            size: entry.size.min(u32::MAX as u64) as u32,
+            name: entry.name,
+            encrypted: entry.encrypted,
+        })
+        .collect();
+    Ok(ArchiveInfo { format, entries })
+}
+
+/// Extract entries of an archive into `dest_dir`, keeping their folders.
+/// An entry that cannot be extracted is reported with an error and the
+/// rest carry on; hitting the archive-wide size limit stops the call.
+///
+/// # Arguments
+/// * `path` - Path to the archive
+/// * `dest_dir` - Directory to extract into, created if missing
+/// * `indices` - Entries to extract; every unencrypted book if omitted
+/// * `sink` - Receives one event per entry
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+#[flutter_rust_bridge::frb]
+pub async fn extract_archive(
+    path: String,
+    dest_dir: String,
+    indices: Option<Vec<u32>>,
+    sink: StreamSink<ArchiveProgress>,
+    cancel_token: Option<u32>,
+) -> Result<(), ApiError> {
+    task::run_blocking(task::cancel_token(cancel_token)?, move |token| {
+        extract(
+            &path,
+            Path::new(&dest_dir),
+            indices,
+            token,
+            &mut |progress| {
+                sink.add(progress)
+                    .map_err(|_| anyhow!("Archive progress stream was closed"))
+            },
+        )
+    })
+    .await
+}
+
+/// An entry of any format.
+struct RawEntry {
+    index: usize,
+    name: String,
+    size: u64,
+    /// Packed size, when the format records it per entry.
+    packed: Option<u64>,
+    is_dir: bool,
+    encrypted: bool,
+}
+
+fn detect_format(path: &str) -> Result<ArchiveFormat> {
+    let mut magic = [0; 6];
+    let read = File::open(path)
+        .and_then(|mut file| file.read(&mut magic))
+        .map_err(|e| anyhow!("Failed to open {path}: {e}"))?;
+    match &magic[..read] {
+        [b'P', b'K', 3, 4, ..] | [b'P', b'K', 5, 6, ..] => Ok(ArchiveFormat::Zip),
+        [b'R', b'a', b'r', b'!', 0x1A, 0x07] => Ok(ArchiveFormat::Rar),
+        [b'7', b'z', 0xBC, 0xAF, 0x27, 0x1C] => Ok(ArchiveFormat::SevenZip),
+        _ => Err(anyhow!("{path} is not a ZIP, RAR or 7z archive")),
+    }
+}
+
+pub(crate) fn import_kind(name: &str) -> Option<ImportKind> {
+    let extension = Path::new(name).extension()?.to_str()?.to_ascii_lowercase();
+    Some(match extension.as_str() {
+        "epub" => ImportKind::Epub,
+        "mobi" | "azw" | "azw3" => ImportKind::Mobi,
+        "pdf" => ImportKind::Pdf,
+        "fb2" => ImportKind::Fb2,
+        "umd" => ImportKind::Umd,
+        "txt" => ImportKind::Txt,
+        "cbz" | "cbr" => ImportKind::Comic,
+        _ => return None,
+    })
+}
+
+fn rar_name(header: &unrar::FileHeader) -> String {
+    header.filename.to_string_lossy().replace('\\', "/")
+}
+
+fn list_entries(path: &str, format: ArchiveFormat) -> Result<Vec<RawEntry>> {
+    let invalid = |e: &dyn std::fmt::Display| anyhow!("Invalid archive {path}: {e}");
+    match format {
+        ArchiveFormat::Zip => {
+            let file = File::open(path).map_err(|e| anyhow!("Failed to open {path}: {e}"))?;
+            let mut zip = ZipArchive::new(file).map_err(|e| invalid(&e))?;
+            (0..zip.len())
+                .map(|index| {
+                    let file = zip.by_index_raw(index).map_err(|e| invalid(&e))?;
+                    Ok(RawEntry {
+                        index,
+                        name: file.name().replace('\\', "/"),
+                        size: file.size(),
+                        packed: Some(file.compressed_size()),
+                        is_dir: file.is_dir(),
+                        encrypted: file.encrypted(),
+                    })
+                })
+                .collect()
+        }
+        ArchiveFormat::Rar => {
+            let archive = Archive::new(path)
+                .open_for_listing()
+                .map_err(|e| invalid(&e))?;
+            archive
+                .enumerate()
+                .map(|(index, header)| {
+                    let header = header.map_err(|e| invalid(&e))?;
+                    Ok(RawEntry {
+                        index,
+                        name: rar_name(&header),
+                        size: header.unpacked_size,
+                        packed: None,
+                        is_dir: header.is_directory(),
+                        encrypted: header.is_encrypted(),
+                    })
+                })
+                .collect()
+        }
+        ArchiveFormat::SevenZip => {
+            let archive = SevenZip::open(Path::new(path))?;
+            Ok(archive
+                .entries
+                .iter()
+                .enumerate()
+                .map(|(index, entry)| RawEntry {
+                    index,
+                    name: entry.name.clone(),
+                    size: entry.size,
+                    packed: None,
+                    is_dir: entry.is_dir,
+                    encrypted: entry.is_encrypted(&archive),
+                })
+                .collect())
+        }
+    }
+}
+
+type Progress<'a> = &'a mut dyn FnMut(ArchiveProgress) -> Result<()>;
+
+fn extract(
+    path: &str,
+    dest: &Path,
+    indices: Option<Vec<u32>>,
+    token: &CancelToken,
+    progress: Progress,
+) -> Result<()> {
+    let format = detect_format(path)?;
+    let entries = list_entries(path, format)?;
+    let wanted: Vec<usize> = match indices {
+        Some(indices) => indices
+            .into_iter()
+            .map(|index| {
+                entries
+                    .get(index as usize)
+                    .filter(|entry| !entry.is_dir)
+                    .map(|entry| entry.index)
+                    .ok_or_else(|| anyhow!("No file at index {index} in {path}"))
+            })
+            .collect::<Result<_>>()?,
+        None => entries
+            .iter()
+            .filter(|e| !e.is_dir && !e.encrypted && import_kind(&e.name).is_some())
+            .map(|e| e.index)
+            .collect(),
+    };
+    fs::create_dir_all(dest).map_err(|e| anyhow!("Failed to create {}: {e}", dest.display()))?;
+
+    let mut extractor = Extractor {
+        dest,
+        entries: &entries,
+        token,
+        progress,
+        done: 0,
+        total: wanted.len() as u32,
+        written: 0,
+    };
+    match format {
+        ArchiveFormat::Zip => {
+            let file = File::open(path).map_err(|e| anyhow!("Failed to open {path}: {e}"))?;
+            let mut zip =
+                ZipArchive::new(file).map_err(|e| anyhow!("Invalid archive {path}: {e}"))?;
+            for index in wanted {
+                extractor.entry(index, |target, limit| {
+                    let mut file = zip
+                        .by_index(index)
+                        .map_err(|e| anyhow!("Failed to read entry: {e}"))?;
+                    write_limited(&mut file, target, limit)
+                })?;
+            }
+        }
+        ArchiveFormat::Rar => {
+            let mut wanted = wanted;
+            wanted.sort_unstable();
+            wanted.dedup();
+            let mut archive = Archive::new(path)
+                .open_for_processing()
+                .map_err(|e| anyhow!("Invalid archive {path}: {e}"))?;
+            let mut position = 0;
+            while let Some(header) = archive
+                .read_header()
+                .map_err(|e| anyhow!("Invalid archive {path}: {e}"))?
+            {
+                if wanted.binary_search(&position).is_err() {
+                    archive = header
+                        .skip()
+                        .map_err(|e| anyhow!("Invalid archive {path}: {e}"))?;
+                    position += 1;
+                    continue;
+                }
+                // unrar stops at the declared size, which is checked first.
+                let mut header = Some(header);
+                let mut next = None;
+                extractor.entry(position, |target, _| {
+                    let rest = header
+                        .take()
+                        .unwrap()
+                        .extract_to(target)
+                        .map_err(|e| anyhow!("Failed to read entry: {e}"))?;
+                    next = Some(rest);
+                    fs::metadata(target)
+                        .map(|metadata| metadata.len())
+                        .map_err(|e| anyhow!("Failed to write file: {e}"))
+                })?;
+                archive = match (next, header) {
+                    (Some(rest), _) => rest,
+                    (None, Some(header)) => header
+                        .skip()
+                        .map_err(|e| anyhow!("Invalid archive {path}: {e}"))?,
+                    (None, None) => {
+                        return Err(anyhow!("Invalid archive {path}: failed to extract entry"))
+                    }
+                };
+                position += 1;
+            }
+        }
+        ArchiveFormat::SevenZip => {
+            let archive = SevenZip::open(Path::new(path))?;
+            let encrypted: Vec<usize> = wanted
+                .iter()
+                .copied()
+                .filter(|&i| entries[i].encrypted)
+                .collect();
+            // Reported as failures without touching their folders.
+            for &index in &encrypted {
+                extractor.entry(index, |_, _| Ok(0))?;
+            }
+            let readable: Vec<usize> = wanted
+                .into_iter()
+                .filter(|i| !encrypted.contains(i))
+                .collect();
+            archive.extract(&readable, |index, reader| {
+                extractor.entry(index, |target, limit| write_limited(reader, target, limit))
+            })?;
+        }
+    }
+    Ok(())
+}
+
+struct Extractor<'a, 'p> {
+    dest: &'a Path,
+    entries: &'a [RawEntry],
+    token: &'a CancelToken,
+    progress: Progress<'p>,
+    done: u32,
+    total: u32,
+    /// Bytes written so far, against `MAX_TOTAL_BYTES`.
+    written: u64,
+}
+
+impl Extractor<'_, '_> {
+    /// Extract entry `index` with `write`, which gets the file to create and
+    /// the most bytes it may write, and returns the bytes written. Failures
+    /// of the entry are reported; only fatal ones are returned.
+    fn entry(&mut self, index: usize, write: impl FnOnce(&Path, u64) -> Result<u64>) -> Result<()> {
+        self.token.check()?;
+        let entry = &self.entries[index];
+        let result = self.write_entry(entry, write);
+        if self.written >= MAX_TOTAL_BYTES {
+            return Err(anyhow!(
+                "Archive expands to more than {} MB; extraction stopped",
+                MAX_TOTAL_BYTES >> 20
+            ));
+        }
+        self.done += 1;
+        let (path, error) = match result {
+            Ok(path) => (Some(path.to_string_lossy().into_owned()), None),
+            Err(e) => (None, Some(e.to_string())),
+        };
+        (self.progress)(ArchiveProgress {
+            done: self.done,
+            total: self.total,
+            file: ExtractedFile {
+                index: index as u32,
+                name: entry.name.clone(),
+                kind: import_kind(&entry.name),
+                path,
+                error,
+            },
+        })
+    }
+
+    fn write_entry(
+        &mut self,
+        entry: &RawEntry,
+        write: impl FnOnce(&Path, u64) -> Result<u64>,
+    ) -> Result<PathBuf> {
+        if entry.encrypted {
+            return Err(anyhow!("Encrypted entries are not supported"));
+        }
+        let mut limit = MAX_ENTRY_BYTES.min(MAX_TOTAL_BYTES - self.written);
+        if let Some(packed) = entry.packed {
+            limit = limit.min(packed.saturating_mul(MAX_RATIO).max(RATIO_FLOOR));
+        }
+        if entry.size > limit {
+            return Err(anyhow!("Entry is too large to extract"));
+        }
+        let relative =
+            safe_path(&entry.name).ok_or_else(|| anyhow!("Unsafe entry path {}", entry.name))?;
+        let target = unique_path(&self.dest.join(relative));
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| anyhow!("Failed to create {}: {e}", parent.display()))?;
+        }
+        let mut part = target.clone().into_os_string();
+        part.push(".part");
+        let part = PathBuf::from(part);
+        let written = write(&part, limit).and_then(|written| {
+            if written > limit {
+                return Err(anyhow!("Entry is too large to extract"));
+            }
+            fs::rename(&part, &target).map_err(|e| anyhow!("Failed to write file: {e}"))?;
+            Ok(written)
+        });
+        match written {
+            Ok(written) => {
+                self.written += written;
+                Ok(target)
+            }
+            Err(e) => {
+                let _ = fs::remove_file(&part);
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Copy at most `limit` bytes of `reader` to a new file at `target`,
+/// failing if there are more.
+fn write_limited(reader: &mut dyn Read, target: &Path, limit: u64) -> Result<u64> {
+    let mut file = File::create_new(target).map_err(|e| anyhow!("Failed to write file: {e}"))?;
+    let written = io::copy(&mut reader.take(limit + 1), &mut file)
+        .map_err(|e| anyhow!("Failed to extract entry: {e}"))?;
+    if written > limit {
+        return Err(anyhow!("Entry is too large to extract"));
+    }
+    file.flush()
+        .map_err(|e| anyhow!("Failed to write file: {e}"))?;
+    Ok(written)
+}
+
+/// `name` as a relative path made only of normal components, or `None` if
+/// it is absolute, climbs with `..`, names a drive or is empty.
+fn safe_path(name: &str) -> Option<PathBuf> {
+    let mut path = PathBuf::new();
+    for part in name.split(['/', '\\']) {
+        if part.is_empty() || part == "." {
+            continue;
+        }
+        if part == ".." || part.contains(':') || part.contains('\0') {
+            return None;
+        }
+        let mut components = Path::new(part).components();
+        if !matches!(components.next(), Some(Component::Normal(_))) || components.next().is_some() {
+            return None;
+        }
+        path.push(part);
+    }
+    if name.starts_with(['/', '\\']) || path.as_os_str().is_empty() {
+        return None;
+    }
+    Some(path)
+}
+
+/// `path`, or `name (2).ext`, `name (3).ext` and so on if it is taken.
+pub(crate) fn unique_path(path: &Path) -> PathBuf {
+    let taken = |path: &Path| {
+        let mut part = path.as_os_str().to_owned();
+        part.push(".part");
+        path.exists() || Path::new(&part).exists()
+    };
+    if !taken(path) {
+        return path.to_path_buf();
+    }
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let extension = path
+        .extension()
+        .map(|e| format!(".{}", e.to_string_lossy()))
+        .unwrap_or_default();
+    (2..)
+        .map(|n| path.with_file_name(format!("{stem} ({n}){extension}")))
+        .find(|candidate| !taken(candidate))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_dirs::temp_dir;
+    use zip::write::SimpleFileOptions;
+    use zip::{CompressionMethod, ZipWriter};
+
+    fn write_zip(path: &Path, entries: &[(&str, &[u8])]) {
+        let mut zip = ZipWriter::new(File::create(path).unwrap());
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+        for (name, data) in entries {
+            zip.start_file(*name, options).unwrap();
+            zip.write_all(data).unwrap();
+        }
+        zip.finish().unwrap();
+    }
+
+    fn run(path: &Path, dest: &Path, indices: Option<Vec<u32>>) -> Vec<ExtractedFile> {
+        let mut files = Vec::new();
+        extract(
+            path.to_str().unwrap(),
+            dest,
+            indices,
+            &CancelToken::default(),
+            &mut |progress| {
+                files.push(progress.file);
+                Ok(())
+            },
+        )
+        .unwrap();
+        files
+    }
+
+    #[test]
+    fn test_safe_path() {
+        assert_eq!(safe_path("a/b\\c.txt"), Some(PathBuf::from("a/b/c.txt")));
+        assert_eq!(safe_path("./a//b.txt"), Some(PathBuf::from("a/b.txt")));
+        for bad in [
+            "../a.txt",
+            "a/../../b.txt",
+            "/etc/passwd",
+            "\\a.txt",
+            "C:/a.txt",
+            "",
+        ] {
+            assert_eq!(safe_path(bad), None, "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_zip_lists_and_extracts_books() {
+        let dir = temp_dir("zip");
+        let archive = dir.join("library.zip");
+        write_zip(
+            &archive,
+            &[
+                ("Series/One.EPUB", b"epub"),
+                ("notes.md", b"notes"),
+                ("../escape.txt", b"evil"),
+                ("Two.txt", b"two"),
+            ],
+        );
+        let info = list_archive(archive.to_string_lossy().into_owned()).unwrap();
+        assert_eq!(info.format, ArchiveFormat::Zip);
+        let kinds: Vec<_> = info.entries.iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                Some(ImportKind::Epub),
+                None,
+                Some(ImportKind::Txt),
+                Some(ImportKind::Txt)
+            ]
+        );
+
+        let dest = dir.join("out");
+        fs::create_dir_all(&dest).unwrap();
+        fs::write(dest.join("Two.txt"), b"existing").unwrap();
+        let files = run(&archive, &dest, None);
+        assert_eq!(files.len(), 3);
+        assert_eq!(fs::read(dest.join("Series/One.EPUB")).unwrap(), b"epub");
+        assert!(files[1].error.as_deref().unwrap().contains("Unsafe"));
+        assert!(!dir.join("escape.txt").exists());
+        // The existing file is kept and the new one renamed.
+        assert_eq!(fs::read(dest.join("Two.txt")).unwrap(), b"existing");
+        assert_eq!(fs::read(dest.join("Two (2).txt")).unwrap(), b"two");
+        assert!(files[2].path.as_deref().unwrap().ends_with("Two (2).txt"));
+    }
+
+    #[test]
+    fn test_rejects_zip_bomb() {
+        let dir = temp_dir("bomb");
+        let archive = dir.join("bomb.zip");
+        let zeros = vec![0; 2 * RATIO_FLOOR as usize];
+        write_zip(&archive, &[("bomb.txt", &zeros), ("ok.txt", b"fine")]);
+        let dest = dir.join("out");
+        let files = run(&archive, &dest, Some(vec![0, 1]));
+        assert!(files[0].error.as_deref().unwrap().contains("too large"));
+        assert!(!dest.join("bomb.txt").exists());
+        assert!(!dest.join("bomb.txt.part").exists());
+        assert_eq!(fs::read(dest.join("ok.txt")).unwrap(), b"fine");
+    }
+
+    #[test]
+    fn test_extracts_from_7z() {
+        let dir = temp_dir("sevenz");
+        let archive = dir.join("library.7z");
+        let files: [(&str, &[u8]); 3] = [
+            ("books/", b""),
+            ("books/a.fb2", b"<FictionBook/>"),
+            ("books/b.azw3", b"mobi"),
+        ];
+        fs::write(&archive, crate::sevenz::tests::build(&files)).unwrap();
+        let info = list_archive(archive.to_string_lossy().into_owned()).unwrap();
+        assert_eq!(info.format, ArchiveFormat::SevenZip);
+        let indices: Vec<_> = info.entries.iter().map(|e| e.index).collect();
+        assert_eq!(indices, [1, 2]);
+
+        let dest = dir.join("out");
+        let extracted = run(&archive, &dest, Some(vec![2]));
+        assert_eq!(extracted[0].kind, Some(ImportKind::Mobi));
+        assert_eq!(fs::read(dest.join("books/b.azw3")).unwrap(), b"mobi");
+        assert!(!dest.join("books/a.fb2").exists());
+    }
+}
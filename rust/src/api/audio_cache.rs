@@ -0,0 +1,673 @@
+//! Persistent cache of synthesised speech for listening mode.
+//!
+//! Each utterance's audio is stored under an app-provided directory, named
+//! by the BLAKE3 hash of the voice and the text, so replaying a chapter or
+//! coming back to it later costs no synthesis. The voice string is opaque:
+//! it should name the engine, voice and any setting that changes the audio,
+//! such as speed. Entries are evicted least-recently-used first once the
+//! cache grows past its size cap, by file modification time as in the
+//! font cache.
+//!
+//! For background playback the next chapter's segments can be synthesised
+//! ahead of time with the local voice, then joined into one file that a
+//! player can seek in. WAV segments are joined into a single WAV and MP3
+//! segments are joined frame by frame, with their tags and per-file
+//! Xing/Info frames dropped. Segment start times are returned so the
+//! reader can follow along.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::time::SystemTime;
+
+use anyhow::{anyhow, Result};
+
+use crate::api::error::ApiError;
+use crate::api::font_converter;
+use crate::api::tts::{self, TtsOptions};
+use crate::api::tts_prep::Utterance;
+
+static AUDIO_CACHE: Mutex<Option<AudioCache>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioFormat {
+    Mp3,
+    Wav,
+}
+
+impl AudioFormat {
+    const ALL: [AudioFormat; 2] = [AudioFormat::Mp3, AudioFormat::Wav];
+
+    fn extension(self) -> &'static str {
+        match self {
+            AudioFormat::Mp3 => "mp3",
+            AudioFormat::Wav => "wav",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CachedAudio {
+    pub format: AudioFormat,
+    pub data: Vec<u8>,
+}
+
+/// Current size of the audio cache.
+#[derive(Debug, Clone)]
+pub struct AudioCacheStats {
+    pub entry_count: u32,
+    pub total_bytes: u64,
+    pub max_bytes: u64,
+}
+
+/// Where an utterance starts in an assembled file.
+#[derive(Debug, Clone)]
+pub struct AudioSegment {
+    pub utterance_id: String,
+    pub start_ms: u64,
+    pub duration_ms: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChapterAudio {
+    pub format: AudioFormat,
+    pub duration_ms: u64,
+    /// Segments in playback order.
+    pub segments: Vec<AudioSegment>,
+    /// Utterances left out because they are not cached.
+    pub missing: Vec<String>,
+}
+
+/// Enable the audio cache. Calling this again switches to the new
+/// directory or size cap.
+///
+/// # Arguments
+/// * `cache_dir` - Directory owned by the cache; created if missing
+/// * `max_bytes` - Total size of cached audio to keep
+#[flutter_rust_bridge::frb]
+pub fn init_audio_cache(cache_dir: String, max_bytes: u64) -> Result<(), ApiError> {
+    let cache = AudioCache::new(PathBuf::from(cache_dir), max_bytes)?;
+    cache.evict()?;
+    *lock() = Some(cache);
+    Ok(())
+}
+
+/// Store the audio of one utterance.
+///
+/// # Arguments
+/// * `voice` - Engine, voice and settings that produced the audio
+/// * `text` - Text that was spoken
+/// * `format` - Container of `data`
+/// * `data` - Audio bytes
+#[flutter_rust_bridge::frb]
+pub fn put_cached_audio(
+    voice: String,
+    text: String,
+    format: AudioFormat,
+    data: Vec<u8>,
+) -> Result<(), ApiError> {
+    Ok(current()?.put(&voice, &text, format, &data)?)
+}
+
+/// The cached audio of one utterance, if any.
+#[flutter_rust_bridge::frb]
+pub fn get_cached_audio(voice: String, text: String) -> Result<Option<CachedAudio>, ApiError> {
+    Ok(current()?.get(&voice, &text))
+}
+
+/// IDs of the utterances that have no cached audio, for fetching from an
+/// online engine.
+#[flutter_rust_bridge::frb]
+pub fn missing_cached_audio(
+    voice: String,
+    utterances: Vec<Utterance>,
+) -> Result<Vec<String>, ApiError> {
+    let cache = current()?;
+    Ok(utterances
+        .into_iter()
+        .filter(|utterance| cache.find(&voice, &utterance.text).is_none())
+        .map(|utterance| utterance.id)
+        .collect())
+}
+
+/// Synthesise the uncached utterances with the loaded local voice and
+/// cache them as WAV. Returns how many were synthesised.
+///
+/// # Arguments
+/// * `voice` - Cache key for the local voice and `options`
+/// * `utterances` - Utterances from `prepare_tts`
+/// * `options` - Speed, pitch and speaker
+#[flutter_rust_bridge::frb]
+pub fn presynthesize_audio(
+    voice: String,
+    utterances: Vec<Utterance>,
+    options: TtsOptions,
+) -> Result<u32, ApiError> {
+    let cache = current()?;
+    let mut synthesized = 0;
+    for utterance in utterances {
+        if cache.find(&voice, &utterance.text).is_some() {
+            continue;
+        }
+        let wav = tts::synthesize_wav(&utterance.text, &options)?;
+        cache.put(&voice, &utterance.text, AudioFormat::Wav, &wav)?;
+        synthesized += 1;
+    }
+    Ok(synthesized)
+}
+
+/// Join the cached audio of `utterances` into one file at `output_path`.
+/// Uncached utterances are skipped and reported; all cached segments must
+/// share one format.
+///
+/// # Arguments
+/// * `voice` - Cache key the audio was stored under
+/// * `utterances` - Utterances in playback order
+/// * `output_path` - File to write; replaced if it exists
+#[flutter_rust_bridge::frb]
+pub fn assemble_chapter_audio(
+    voice: String,
+    utterances: Vec<Utterance>,
+    output_path: String,
+) -> Result<ChapterAudio, ApiError> {
+    let cache = current()?;
+    let mut missing = Vec::new();
+    let mut parts = Vec::new();
+    for utterance in utterances {
+        match cache.get(&voice, &utterance.text) {
+            Some(audio) => parts.push((utterance.id, audio)),
+            None => missing.push(utterance.id),
+        }
+    }
+    let (data, chapter) = assemble(parts, missing)?;
+    font_converter::write_atomically(Path::new(&output_path), &data)?;
+    Ok(chapter)
+}
+
+/// Report the number and total size of cached segments.
+#[flutter_rust_bridge::frb]
+pub fn audio_cache_stats() -> Result<AudioCacheStats, ApiError> {
+    Ok(current()?.stats()?)
+}
+
+/// Delete every cached segment. The cache stays enabled.
+#[flutter_rust_bridge::frb]
+pub fn clear_audio_cache() -> Result<(), ApiError> {
+    Ok(current()?.clear()?)
+}
+
+fn lock() -> MutexGuard<'static, Option<AudioCache>> {
+    AUDIO_CACHE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn current() -> Result<AudioCache> {
+    lock()
+        .clone()
+        .ok_or_else(|| anyhow!("Audio cache is not initialized"))
+}
+
+#[derive(Clone)]
+struct AudioCache {
+    dir: PathBuf,
+    max_bytes: u64,
+}
+
+struct Entry {
+    path: PathBuf,
+    len: u64,
+    last_used: SystemTime,
+}
+
+impl AudioCache {
+    fn new(dir: PathBuf, max_bytes: u64) -> Result<Self> {
+        fs::create_dir_all(&dir).map_err(|e| anyhow!("Failed to create {}: {e}", dir.display()))?;
+        Ok(Self { dir, max_bytes })
+    }
+
+    fn entry_path(&self, voice: &str, text: &str, format: AudioFormat) -> PathBuf {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(voice.as_bytes());
+        hasher.update(&[0]);
+        hasher.update(text.as_bytes());
+        let hash = hasher.finalize().to_hex();
+        self.dir.join(format!("{hash}.{}", format.extension()))
+    }
+
+    fn find(&self, voice: &str, text: &str) -> Option<(PathBuf, AudioFormat)> {
+        AudioFormat::ALL
+            .into_iter()
+            .map(|format| (self.entry_path(voice, text, format), format))
+            .find(|(path, _)| path.is_file())
+    }
+
+    fn get(&self, voice: &str, text: &str) -> Option<CachedAudio> {
+        let (path, format) = self.find(voice, text)?;
+        let data = fs::read(&path).ok()?;
+        touch(&path);
+        Some(CachedAudio { format, data })
+    }
+
+    fn put(&self, voice: &str, text: &str, format: AudioFormat, data: &[u8]) -> Result<()> {
+        if data.len() as u64 > self.max_bytes {
+            return Ok(());
+        }
+        // Replacing an entry in another format must not leave both behind.
+        if let Some((path, _)) = self.find(voice, text) {
+            let _ = fs::remove_file(path);
+        }
+        font_converter::write_atomically(&self.entry_path(voice, text, format), data)?;
+        self.evict()
+    }
+
+    /// Remove least recently used entries until the cache fits its cap.
+    fn evict(&self) -> Result<()> {
+        let mut entries = self.entries()?;
+        let mut total: u64 = entries.iter().map(|entry| entry.len).sum();
+        entries.sort_by_key(|entry| entry.last_used);
+
+        for entry in entries {
+            if total <= self.max_bytes {
+                break;
+            }
+            if fs::remove_file(&entry.path).is_ok() {
+                total -= entry.len;
+            }
+        }
+        Ok(())
+    }
+
+    fn stats(&self) -> Result<AudioCacheStats> {
+        let entries = self.entries()?;
+        Ok(AudioCacheStats {
+            entry_count: entries.len() as u32,
+            total_bytes: entries.iter().map(|entry| entry.len).sum(),
+            max_bytes: self.max_bytes,
+        })
+    }
+
+    fn clear(&self) -> Result<()> {
+        for entry in self.entries()? {
+            fs::remove_file(&entry.path)
+                .map_err(|e| anyhow!("Failed to remove {}: {e}", entry.path.display()))?;
+        }
+        Ok(())
+    }
+
+    fn entries(&self) -> Result<Vec<Entry>> {
+        let dir = fs::read_dir(&self.dir)
+            .map_err(|e| anyhow!("Failed to read {}: {e}", self.dir.display()))?;
+
+        let mut entries = Vec::new();
+        for item in dir.flatten() {
+            let path = item.path();
+            let extension = path.extension().and_then(|ext| ext.to_str());
+            if !AudioFormat::ALL
+                .iter()
+                .any(|format| Some(format.extension()) == extension)
+            {
+                continue;
+            }
+            let Ok(metadata) = item.metadata() else {
+                continue;
+            };
+            entries.push(Entry {
+                path,
+                len: metadata.len(),
+                last_used: metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+            });
+        }
+        Ok(entries)
+    }
+}
+
+/// Mark a cache entry as recently used.
+fn touch(path: &Path) {
+    if let Ok(file) = fs::File::options().write(true).open(path) {
+        let _ = file.set_modified(SystemTime::now());
+    }
+}
+
+/// Join segments into one file and describe where each one starts.
+fn assemble(
+    parts: Vec<(String, CachedAudio)>,
+    missing: Vec<String>,
+) -> Result<(Vec<u8>, ChapterAudio)> {
+    let Some(format) = parts.first().map(|(_, audio)| audio.format) else {
+        return Err(anyhow!("No cached audio to assemble"));
+    };
+    if parts.iter().any(|(_, audio)| audio.format != format) {
+        return Err(anyhow!("Cached segments mix MP3 and WAV audio"));
+    }
+
+    let mut body = Vec::new();
+    let mut segments = Vec::new();
+    let mut fmt: Option<Vec<u8>> = None;
+    let mut elapsed_us: u64 = 0;
+    for (utterance_id, audio) in parts {
+        let duration_us = match format {
+            AudioFormat::Wav => {
+                let wav = parse_wav(&audio.data)?;
+                match &fmt {
+                    Some(fmt) if *fmt != wav.fmt => {
+                        return Err(anyhow!("Cached WAV segments have different formats"));
+                    }
+                    Some(_) => {}
+                    None => fmt = Some(wav.fmt.to_vec()),
+                }
+                body.extend_from_slice(wav.data);
+                wav.data.len() as u64 * 1_000_000 / wav.byte_rate.max(1) as u64
+            }
+            AudioFormat::Mp3 => {
+                let (frames, duration_us) = mp3_frames(&audio.data)?;
+                body.extend_from_slice(&frames);
+                duration_us
+            }
+        };
+        segments.push(AudioSegment {
+            utterance_id,
+            start_ms: elapsed_us / 1000,
+            duration_ms: duration_us / 1000,
+        });
+        elapsed_us += duration_us;
+    }
+
+    let data = match fmt {
+        Some(fmt) => {
+            let mut wav = Vec::with_capacity(20 + fmt.len() + body.len());
+            wav.extend_from_slice(b"RIFF");
+            wav.extend_from_slice(&((12 + fmt.len() + body.len()) as u32).to_le_bytes());
+            wav.extend_from_slice(b"WAVEfmt ");
+            wav.extend_from_slice(&(fmt.len() as u32).to_le_bytes());
+            wav.extend_from_slice(&fmt);
+            wav.extend_from_slice(b"data");
+            wav.extend_from_slice(&(body.len() as u32).to_le_bytes());
+            wav.extend_from_slice(&body);
+            wav
+        }
+        None => body,
+    };
+    Ok((
+        data,
+        ChapterAudio {
+            format,
+            duration_ms: elapsed_us / 1000,
+            segments,
+            missing,
+        },
+    ))
+}
+
+struct Wav<'a> {
+    fmt: &'a [u8],
+    byte_rate: u32,
+    data: &'a [u8],
+}
+
+fn parse_wav(data: &[u8]) -> Result<Wav<'_>> {
+    if data.len() < 12 || &data[..4] != b"RIFF" || &data[8..12] != b"WAVE" {
+        return Err(anyhow!("Cached audio is not a WAV file"));
+    }
+    let mut fmt = None;
+    let mut offset = 12;
+    while offset + 8 <= data.len() {
+        let id = &data[offset..offset + 4];
+        let len = u32::from_le_bytes(data[offset + 4..offset + 8].try_into().unwrap()) as usize;
+        let body = &data[offset + 8..(offset + 8 + len).min(data.len())];
+        match id {
+            b"fmt " if body.len() >= 16 => fmt = Some(body),
+            b"data" => {
+                let fmt = fmt.ok_or_else(|| anyhow!("WAV data comes before its format"))?;
+                let byte_rate = u32::from_le_bytes(fmt[8..12].try_into().unwrap());
+                return Ok(Wav {
+                    fmt,
+                    byte_rate,
+                    data: body,
+                });
+            }
+            _ => {}
+        }
+        // Chunks are padded to an even length.
+        offset += 8 + len + (len & 1);
+    }
+    Err(anyhow!("WAV file has no data"))
+}
+
+/// The MPEG audio frames of an MP3 file, without ID3 tags or a Xing/Info
+/// header frame, and their total duration in microseconds.
+fn mp3_frames(data: &[u8]) -> Result<(Vec<u8>, u64)> {
+    let mut offset = 0;
+    if data.len() >= 10 && &data[..3] == b"ID3" {
+        let size = data[6..10]
+            .iter()
+            .fold(0usize, |size, &b| (size << 7) | (b & 0x7F) as usize);
+        let footer = if data[5] & 0x10 != 0 { 10 } else { 0 };
+        offset = 10 + size + footer;
+    }
+    let mut end = data.len();
+    if end >= offset + 128 && &data[end - 128..end - 125] == b"TAG" {
+        end -= 128;
+    }
+
+    let mut frames = Vec::with_capacity(end.saturating_sub(offset));
+    let mut samples: u64 = 0;
+    let mut sample_rate = 0;
+    let mut first = true;
+    while offset + 4 <= end {
+        let Some(header) = FrameHeader::parse(&data[offset..offset + 4]) else {
+            // Skip junk between frames.
+            offset += 1;
+            continue;
+        };
+        let frame_end = (offset + header.len).min(end);
+        let frame = &data[offset..frame_end];
+        let info = first
+            && [&b"Xing"[..], b"Info"]
+                .iter()
+                .any(|tag| frame.windows(4).take(40).any(|window| window == *tag));
+        if !info {
+            frames.extend_from_slice(frame);
+            samples += header.samples as u64;
+            sample_rate = header.sample_rate;
+        }
+        first = false;
+        offset = frame_end;
+    }
+    if frames.is_empty() {
+        return Err(anyhow!("Cached audio has no MP3 frames"));
+    }
+    Ok((frames, samples * 1_000_000 / sample_rate as u64))
+}
+
+struct FrameHeader {
+    len: usize,
+    samples: u32,
+    sample_rate: u32,
+}
+
+impl FrameHeader {
+    /// Parse an MPEG-1/2/2.5 Layer III frame header.
+    fn parse(bytes: &[u8]) -> Option<Self> {
+        const MPEG1_BITRATES: [u32; 15] = [
+            0, 32, 40, 48, 56, 64, 80, 96, 112, 128, 160, 192, 224, 256, 320,
+        ];
+        const MPEG2_BITRATES: [u32; 15] =
+            [0, 8, 16, 24, 32, 40, 48, 56, 64, 80, 96, 112, 128, 144, 160];
+        const SAMPLE_RATES: [u32; 3] = [44100, 48000, 32000];
+
+        if bytes[0] != 0xFF || bytes[1] & 0xE0 != 0xE0 {
+            return None;
+        }
+        let version = (bytes[1] >> 3) & 0x03;
+        let layer = (bytes[1] >> 1) & 0x03;
+        let bitrate_index = (bytes[2] >> 4) as usize;
+        let rate_index = ((bytes[2] >> 2) & 0x03) as usize;
+        let padding = ((bytes[2] >> 1) & 0x01) as usize;
+        // Version 1 is reserved; layer 1 means Layer III.
+        if version == 1
+            || layer != 1
+            || bitrate_index == 0
+            || bitrate_index == 15
+            || rate_index == 3
+        {
+            return None;
+        }
+        let mpeg1 = version == 3;
+        let bitrate = if mpeg1 {
+            MPEG1_BITRATES[bitrate_index]
+        } else {
+            MPEG2_BITRATES[bitrate_index]
+        } * 1000;
+        let sample_rate = SAMPLE_RATES[rate_index]
+            / match version {
+                3 => 1,
+                2 => 2,
+                _ => 4,
+            };
+        let (coefficient, samples) = if mpeg1 { (144, 1152) } else { (72, 576) };
+        Some(Self {
+            len: (coefficient * bitrate / sample_rate) as usize + padding,
+            samples,
+            sample_rate,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_dirs::temp_dir;
+
+    fn temp_cache(name: &str, max_bytes: u64) -> AudioCache {
+        AudioCache::new(temp_dir(&format!("audio-cache-{name}")), max_bytes).unwrap()
+    }
+
+    /// 16 kHz 16-bit mono, so 32 bytes per millisecond.
+    fn wav(ms: usize) -> Vec<u8> {
+        tts::pcm_to_wav(vec![0; ms * 32], 16000)
+    }
+
+    /// MPEG-1 Layer III at 128 kbit/s and 44.1 kHz: 417-byte frames of
+    /// 1152 samples.
+    fn mp3(frames: usize, xing: bool) -> Vec<u8> {
+        let frame = |tag: &[u8]| {
+            let mut frame = vec![0xFF, 0xFB, 0x90, 0x00];
+            frame.resize(417, 0);
+            frame[36..40].copy_from_slice(tag);
+            frame
+        };
+        let mut data = b"ID3\x04\x00\x00\x00\x00\x00\x05hello".to_vec();
+        if xing {
+            data.extend(frame(b"Xing"));
+        }
+        for _ in 0..frames {
+            data.extend(frame(b"\0\0\0\0"));
+        }
+        data
+    }
+
+    #[test]
+    fn test_put_get_and_evict() {
+        let cache = temp_cache("lru", 100);
+        cache
+            .put("edge:xiaoxiao", "你好", AudioFormat::Mp3, &[1; 60])
+            .unwrap();
+        assert!(cache.get("edge:yunxi", "你好").is_none());
+        let audio = cache.get("edge:xiaoxiao", "你好").unwrap();
+        assert_eq!((audio.format, audio.data.len()), (AudioFormat::Mp3, 60));
+
+        // Replacing with another format keeps one entry.
+        cache
+            .put("edge:xiaoxiao", "你好", AudioFormat::Wav, &[2; 50])
+            .unwrap();
+        assert_eq!(cache.stats().unwrap().entry_count, 1);
+        assert_eq!(
+            cache.get("edge:xiaoxiao", "你好").unwrap().format,
+            AudioFormat::Wav
+        );
+
+        let old = cache.entry_path("edge:xiaoxiao", "你好", AudioFormat::Wav);
+        let file = fs::File::options().write(true).open(&old).unwrap();
+        file.set_modified(SystemTime::now() - std::time::Duration::from_secs(60))
+            .unwrap();
+        cache
+            .put("edge:xiaoxiao", "再见", AudioFormat::Wav, &[3; 60])
+            .unwrap();
+        assert!(cache.get("edge:xiaoxiao", "你好").is_none());
+        assert!(cache.get("edge:xiaoxiao", "再见").is_some());
+        fs::remove_dir_all(&cache.dir).unwrap();
+    }
+
+    #[test]
+    fn test_assembles_wav_with_offsets() {
+        let parts = vec![
+            (
+                "a".to_string(),
+                CachedAudio {
+                    format: AudioFormat::Wav,
+                    data: wav(250),
+                },
+            ),
+            (
+                "b".to_string(),
+                CachedAudio {
+                    format: AudioFormat::Wav,
+                    data: wav(500),
+                },
+            ),
+        ];
+        let (data, chapter) = assemble(parts, vec!["c".to_string()]).unwrap();
+        assert_eq!(chapter.duration_ms, 750);
+        let starts: Vec<u64> = chapter.segments.iter().map(|s| s.start_ms).collect();
+        assert_eq!(starts, vec![0, 250]);
+        assert_eq!(chapter.missing, vec!["c"]);
+        let joined = parse_wav(&data).unwrap();
+        assert_eq!(joined.data.len(), 750 * 32);
+
+        let mixed = vec![
+            (
+                "a".to_string(),
+                CachedAudio {
+                    format: AudioFormat::Wav,
+                    data: wav(10),
+                },
+            ),
+            (
+                "b".to_string(),
+                CachedAudio {
+                    format: AudioFormat::Mp3,
+                    data: mp3(1, false),
+                },
+            ),
+        ];
+        assert!(assemble(mixed, Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_assembles_mp3_frames() {
+        let (frames, duration_us) = mp3_frames(&mp3(10, true)).unwrap();
+        // The tag and the Xing frame are dropped.
+        assert_eq!(frames.len(), 10 * 417);
+        assert_eq!(duration_us, 10 * 1152 * 1_000_000 / 44100);
+
+        let parts = vec![
+            (
+                "a".to_string(),
+                CachedAudio {
+                    format: AudioFormat::Mp3,
+                    data: mp3(5, true),
+                },
+            ),
+            (
+                "b".to_string(),
+                CachedAudio {
+                    format: AudioFormat::Mp3,
+                    data: mp3(5, true),
+                },
+            ),
+        ];
+        let (data, chapter) = assemble(parts, Vec::new()).unwrap();
+        assert_eq!(data.len(), 10 * 417);
+        assert_eq!(chapter.segments[1].start_ms, 130);
+        assert!(mp3_frames(b"not audio").is_err());
+    }
+}
@@ -0,0 +1,648 @@
+//! One-file backups of the library.
+//!
+//! A backup is a zip of `manifest.json` and one JSON file per part: the
+//! library database, the purify rules and the imported book-source JSON.
+//! The manifest records the format version and the size and SHA-256 of
+//! every part, and restoring checks all of them before anything is changed.
+//! Restoring merges rather than overwrites: see `db::merge` for the
+//! library, while purify rules and book sources, which the app keeps
+//! itself, are passed in and handed back with the backup's new entries
+//! appended.
+
+use std::fs::File;
+use std::io::{Cursor, Read, Write};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::api::db::{self, Bookmark, Highlight, LibraryBook, LibraryDump, ReadingProgress};
+use crate::api::error::ApiError;
+use crate::api::font_converter;
+use crate::api::purify::{PurifyRule, PurifyRuleKind};
+
+const FORMAT: &str = "novella-backup";
+/// Backups with a newer version are refused.
+const VERSION: u32 = 1;
+
+const MANIFEST: &str = "manifest.json";
+const LIBRARY: &str = "library.json";
+const PURIFY_RULES: &str = "purify_rules.json";
+const BOOK_SOURCES: &str = "book_sources.json";
+
+#[derive(Debug, Clone)]
+pub struct BackupInfo {
+    pub version: u32,
+    /// Seconds since the Unix epoch.
+    pub created_at: i64,
+    pub book_count: u32,
+    pub bookmark_count: u32,
+    pub highlight_count: u32,
+    pub purify_rule_count: u32,
+    pub book_source_count: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct RestoreResult {
+    pub books_added: u32,
+    /// Stored books replaced by a copy updated later.
+    pub books_updated: u32,
+    pub progress_updated: u32,
+    pub bookmarks_added: u32,
+    pub highlights_added: u32,
+    pub purify_rules_added: u32,
+    pub book_sources_added: u32,
+    /// The current purify rules followed by the backup's new ones.
+    pub purify_rules: Vec<PurifyRule>,
+    /// The current book sources followed by the backup's new ones, as a
+    /// JSON array.
+    pub book_sources_json: String,
+}
+
+/// Write the open library database, purify rules and book sources to a
+/// backup file.
+///
+/// # Arguments
+/// * `path` - Backup file to write, replaced if it exists
+/// * `purify_rules` - Current purify rules
+/// * `book_sources_json` - Imported book sources as a JSON array or object
+#[flutter_rust_bridge::frb]
+pub fn create_backup(
+    path: String,
+    purify_rules: Vec<PurifyRule>,
+    book_sources_json: String,
+) -> Result<BackupInfo, ApiError> {
+    let dump = db::export_library()?;
+    let sources = source_list(&book_sources_json)?;
+    let created_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64);
+    let data = pack(&dump, &purify_rules, &sources, created_at)?;
+    font_converter::write_atomically(Path::new(&path), &data)?;
+    Ok(info(created_at, &dump, &purify_rules, &sources))
+}
+
+/// Check a backup file and describe what it holds, without restoring it.
+#[flutter_rust_bridge::frb]
+pub fn read_backup_info(path: String) -> Result<BackupInfo, ApiError> {
+    let backup = unpack(&read(&path)?)?;
+    Ok(info(
+        backup.created_at,
+        &backup.dump,
+        &backup.purify_rules,
+        &backup.book_sources,
+    ))
+}
+
+/// Check a backup file and merge it into the open library database.
+///
+/// # Arguments
+/// * `path` - Backup file
+/// * `purify_rules` - Current purify rules, to merge the backup's into
+/// * `book_sources_json` - Current book sources, to merge the backup's into
+#[flutter_rust_bridge::frb]
+pub fn restore_backup(
+    path: String,
+    purify_rules: Vec<PurifyRule>,
+    book_sources_json: String,
+) -> Result<RestoreResult, ApiError> {
+    let backup = unpack(&read(&path)?)?;
+    let current_sources = source_list(&book_sources_json)?;
+    let counts = db::merge_library(&backup.dump)?;
+    let (purify_rules, purify_rules_added) = merge_rules(purify_rules, backup.purify_rules);
+    let (sources, book_sources_added) = merge_sources(current_sources, backup.book_sources);
+    Ok(RestoreResult {
+        books_added: counts.books_added,
+        books_updated: counts.books_updated,
+        progress_updated: counts.progress_updated,
+        bookmarks_added: counts.bookmarks_added,
+        highlights_added: counts.highlights_added,
+        purify_rules_added,
+        book_sources_added,
+        purify_rules,
+        book_sources_json: Value::Array(sources).to_string(),
+    })
+}
+
+struct Backup {
+    created_at: i64,
+    dump: LibraryDump,
+    purify_rules: Vec<PurifyRule>,
+    book_sources: Vec<Value>,
+}
+
+fn read(path: &str) -> Result<Vec<u8>> {
+    let mut data = Vec::new();
+    File::open(path)
+        .and_then(|mut file| file.read_to_end(&mut data))
+        .map_err(|e| anyhow!("Failed to read {path}: {e}"))?;
+    Ok(data)
+}
+
+fn info(
+    created_at: i64,
+    dump: &LibraryDump,
+    purify_rules: &[PurifyRule],
+    book_sources: &[Value],
+) -> BackupInfo {
+    BackupInfo {
+        version: VERSION,
+        created_at,
+        book_count: dump.books.len() as u32,
+        bookmark_count: dump.bookmarks.len() as u32,
+        highlight_count: dump.highlights.len() as u32,
+        purify_rule_count: purify_rules.len() as u32,
+        book_source_count: book_sources.len() as u32,
+    }
+}
+
+fn pack(
+    dump: &LibraryDump,
+    purify_rules: &[PurifyRule],
+    book_sources: &[Value],
+    created_at: i64,
+) -> Result<Vec<u8>> {
+    let library = json!({
+        "books": dump.books.iter().map(book_json).collect::<Vec<_>>(),
+        "progress": dump.progress.iter().map(progress_json).collect::<Vec<_>>(),
+        "bookmarks": dump.bookmarks.iter().map(bookmark_json).collect::<Vec<_>>(),
+        "highlights": dump.highlights.iter().map(highlight_json).collect::<Vec<_>>(),
+    });
+    let rules: Vec<Value> = purify_rules.iter().map(rule_json).collect();
+    let parts = [
+        (LIBRARY, library.to_string()),
+        (PURIFY_RULES, Value::Array(rules).to_string()),
+        (
+            BOOK_SOURCES,
+            Value::Array(book_sources.to_vec()).to_string(),
+        ),
+    ];
+    let manifest = json!({
+        "format": FORMAT,
+        "version": VERSION,
+        "createdAt": created_at,
+        "files": parts
+            .iter()
+            .map(|(name, data)| json!({
+                "name": name,
+                "size": data.len(),
+                "sha256": sha256(data.as_bytes()),
+            }))
+            .collect::<Vec<_>>(),
+    });
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    for (name, data) in [(MANIFEST, manifest.to_string())].iter().chain(&parts) {
+        zip.start_file(*name, options)
+            .and_then(|()| zip.write_all(data.as_bytes()).map_err(Into::into))
+            .map_err(|e| anyhow!("Failed to write {name}: {e}"))?;
+    }
+    let data = zip
+        .finish()
+        .map_err(|e| anyhow!("Failed to write backup: {e}"))?;
+    Ok(data.into_inner())
+}
+
+fn unpack(data: &[u8]) -> Result<Backup> {
+    let mut zip =
+        ZipArchive::new(Cursor::new(data)).map_err(|e| anyhow!("Invalid backup file: {e}"))?;
+    let mut entry = |name: &str| -> Result<Vec<u8>> {
+        let mut file = zip
+            .by_name(name)
+            .map_err(|_| anyhow!("Backup is missing {name}"))?;
+        let mut data = Vec::new();
+        file.read_to_end(&mut data)
+            .map_err(|e| anyhow!("Failed to read {name} from backup: {e}"))?;
+        Ok(data)
+    };
+
+    let manifest: Value = serde_json::from_slice(&entry(MANIFEST)?)
+        .map_err(|e| anyhow!("Invalid backup manifest: {e}"))?;
+    if manifest["format"] != FORMAT {
+        return Err(anyhow!("Not a Novella backup"));
+    }
+    let version = manifest["version"].as_u64().unwrap_or(0);
+    if version == 0 || version > VERSION as u64 {
+        return Err(anyhow!(
+            "Backup version {version} is not supported by this app (up to {VERSION})"
+        ));
+    }
+    let files = manifest["files"].as_array().cloned().unwrap_or_default();
+    let mut part = |name: &str| -> Result<Value> {
+        let listed = files
+            .iter()
+            .find(|file| file["name"] == name)
+            .ok_or_else(|| anyhow!("Backup manifest does not list {name}"))?;
+        let data = entry(name)?;
+        if listed["size"].as_u64() != Some(data.len() as u64)
+            || listed["sha256"].as_str() != Some(sha256(&data).as_str())
+        {
+            return Err(anyhow!(
+                "Backup is corrupt: checksum of {name} does not match"
+            ));
+        }
+        serde_json::from_slice(&data).map_err(|e| anyhow!("Invalid {name} in backup: {e}"))
+    };
+    let library = part(LIBRARY)?;
+    let rules = part(PURIFY_RULES)?;
+    let sources = part(BOOK_SOURCES)?;
+
+    let list = |value: &Value, key: &str| value[key].as_array().cloned().unwrap_or_default();
+    let dump = LibraryDump {
+        books: list(&library, "books")
+            .iter()
+            .map(book_from)
+            .collect::<Result<_>>()?,
+        progress: list(&library, "progress")
+            .iter()
+            .map(progress_from)
+            .collect::<Result<_>>()?,
+        bookmarks: list(&library, "bookmarks")
+            .iter()
+            .map(bookmark_from)
+            .collect::<Result<_>>()?,
+        highlights: list(&library, "highlights")
+            .iter()
+            .map(highlight_from)
+            .collect::<Result<_>>()?,
+    };
+    Ok(Backup {
+        created_at: manifest["createdAt"].as_i64().unwrap_or(0),
+        dump,
+        purify_rules: rules
+            .as_array()
+            .map(|rules| rules.iter().map(rule_from).collect::<Result<_>>())
+            .transpose()?
+            .unwrap_or_default(),
+        book_sources: sources.as_array().cloned().unwrap_or_default(),
+    })
+}
+
+/// Book sources as a list of JSON objects, from an array or one object.
+fn source_list(json: &str) -> Result<Vec<Value>> {
+    if json.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    match serde_json::from_str(json) {
+        Ok(Value::Array(sources)) => Ok(sources),
+        Ok(source @ Value::Object(_)) => Ok(vec![source]),
+        Ok(_) => Err(anyhow!("Book sources must be a JSON array or object")),
+        Err(e) => Err(anyhow!("Invalid book source JSON: {e}")),
+    }
+}
+
+/// Append the backup's rules that differ from every current one.
+fn merge_rules(mut current: Vec<PurifyRule>, backup: Vec<PurifyRule>) -> (Vec<PurifyRule>, u32) {
+    let mut added = 0;
+    for rule in backup {
+        let same = |r: &PurifyRule| {
+            (r.kind, &r.pattern, &r.replacement) == (rule.kind, &rule.pattern, &rule.replacement)
+        };
+        if !current.iter().any(same) {
+            current.push(rule);
+            added += 1;
+        }
+    }
+    (current, added)
+}
+
+/// Append the backup's sources whose `bookSourceUrl` is not present yet.
+fn merge_sources(mut current: Vec<Value>, backup: Vec<Value>) -> (Vec<Value>, u32) {
+    let mut added = 0;
+    for source in backup {
+        let url = &source["bookSourceUrl"];
+        if url.is_null() || current.iter().any(|s| s["bookSourceUrl"] == *url) {
+            continue;
+        }
+        current.push(source);
+        added += 1;
+    }
+    (current, added)
+}
+
+fn sha256(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+fn book_json(book: &LibraryBook) -> Value {
+    json!({
+        "id": book.id,
+        "title": book.title,
+        "author": book.author,
+        "intro": book.intro,
+        "kind": book.kind,
+        "coverUrl": book.cover_url,
+        "sourceUrl": book.source_url,
+        "bookUrl": book.book_url,
+        "tocUrl": book.toc_url,
+        "latestChapterTitle": book.latest_chapter_title,
+        "chapterCount": book.chapter_count,
+        "wordCount": book.word_count,
+        "addedAt": book.added_at,
+        "updatedAt": book.updated_at,
+        "lastReadAt": book.last_read_at,
+    })
+}
+
+fn book_from(value: &Value) -> Result<LibraryBook> {
+    let fields = object(value, "book")?;
+    Ok(LibraryBook {
+        id: required(fields, "id")?,
+        title: required(fields, "title")?,
+        author: text(fields, "author"),
+        intro: text(fields, "intro"),
+        kind: text(fields, "kind"),
+        cover_url: text(fields, "coverUrl"),
+        source_url: text(fields, "sourceUrl"),
+        book_url: text(fields, "bookUrl"),
+        toc_url: text(fields, "tocUrl"),
+        latest_chapter_title: text(fields, "latestChapterTitle"),
+        chapter_count: number(fields, "chapterCount").unwrap_or(0) as u32,
+        word_count: number(fields, "wordCount").map(|n| n as u32),
+        added_at: number(fields, "addedAt").unwrap_or(0),
+        updated_at: number(fields, "updatedAt").unwrap_or(0),
+        last_read_at: number(fields, "lastReadAt"),
+    })
+}
+
+pub(crate) fn progress_json(progress: &ReadingProgress) -> Value {
+    json!({
+        "bookId": progress.book_id,
+        "chapterIndex": progress.chapter_index,
+        "chapterOffset": progress.chapter_offset,
+        "percent": progress.percent,
+        "updatedAt": progress.updated_at,
+    })
+}
+
+pub(crate) fn progress_from(value: &Value) -> Result<ReadingProgress> {
+    let fields = object(value, "progress")?;
+    Ok(ReadingProgress {
+        book_id: required(fields, "bookId")?,
+        chapter_index: number(fields, "chapterIndex").unwrap_or(0) as u32,
+        chapter_offset: number(fields, "chapterOffset").unwrap_or(0) as u32,
+        percent: fields.get("percent").and_then(Value::as_f64).unwrap_or(0.0),
+        updated_at: number(fields, "updatedAt").unwrap_or(0),
+    })
+}
+
+pub(crate) fn bookmark_json(bookmark: &Bookmark) -> Value {
+    json!({
+        "bookId": bookmark.book_id,
+        "chapterIndex": bookmark.chapter_index,
+        "chapterOffset": bookmark.chapter_offset,
+        "excerpt": bookmark.excerpt,
+        "note": bookmark.note,
+        "createdAt": bookmark.created_at,
+    })
+}
+
+pub(crate) fn bookmark_from(value: &Value) -> Result<Bookmark> {
+    let fields = object(value, "bookmark")?;
+    Ok(Bookmark {
+        id: None,
+        book_id: required(fields, "bookId")?,
+        chapter_index: number(fields, "chapterIndex").unwrap_or(0) as u32,
+        chapter_offset: number(fields, "chapterOffset").unwrap_or(0) as u32,
+        excerpt: text(fields, "excerpt").unwrap_or_default(),
+        note: text(fields, "note"),
+        created_at: number(fields, "createdAt").unwrap_or(0),
+    })
+}
+
+pub(crate) fn highlight_json(highlight: &Highlight) -> Value {
+    json!({
+        "bookId": highlight.book_id,
+        "chapterIndex": highlight.chapter_index,
+        "startOffset": highlight.start_offset,
+        "endOffset": highlight.end_offset,
+        "text": highlight.text,
+        "note": highlight.note,
+        "color": highlight.color,
+        "createdAt": highlight.created_at,
+    })
+}
+
+pub(crate) fn highlight_from(value: &Value) -> Result<Highlight> {
+    let fields = object(value, "highlight")?;
+    Ok(Highlight {
+        id: None,
+        book_id: required(fields, "bookId")?,
+        chapter_index: number(fields, "chapterIndex").unwrap_or(0) as u32,
+        start_offset: number(fields, "startOffset").unwrap_or(0) as u32,
+        end_offset: number(fields, "endOffset").unwrap_or(0) as u32,
+        text: text(fields, "text").unwrap_or_default(),
+        note: text(fields, "note"),
+        color: number(fields, "color").unwrap_or(0) as u32,
+        created_at: number(fields, "createdAt").unwrap_or(0),
+    })
+}
+
+fn rule_json(rule: &PurifyRule) -> Value {
+    let kind = match rule.kind {
+        PurifyRuleKind::Literal => "literal",
+        PurifyRuleKind::Regex => "regex",
+        PurifyRuleKind::Line => "line",
+        PurifyRuleKind::Watermark => "watermark",
+    };
+    json!({
+        "name": rule.name,
+        "kind": kind,
+        "pattern": rule.pattern,
+        "replacement": rule.replacement,
+        "enabled": rule.enabled,
+    })
+}
+
+fn rule_from(value: &Value) -> Result<PurifyRule> {
+    let fields = object(value, "purify rule")?;
+    let kind = match fields.get("kind").and_then(Value::as_str) {
+        Some("literal") => PurifyRuleKind::Literal,
+        Some("regex") => PurifyRuleKind::Regex,
+        Some("line") => PurifyRuleKind::Line,
+        Some("watermark") => PurifyRuleKind::Watermark,
+        kind => return Err(anyhow!("Unknown purify rule kind in backup: {kind:?}")),
+    };
+    Ok(PurifyRule {
+        name: text(fields, "name").unwrap_or_default(),
+        kind,
+        pattern: required(fields, "pattern")?,
+        replacement: text(fields, "replacement").unwrap_or_default(),
+        enabled: fields
+            .get("enabled")
+            .and_then(Value::as_bool)
+            .unwrap_or(true),
+    })
+}
+
+fn object<'a>(value: &'a Value, what: &str) -> Result<&'a Map<String, Value>> {
+    value
+        .as_object()
+        .ok_or_else(|| anyhow!("Invalid {what} in backup"))
+}
+
+fn text(fields: &Map<String, Value>, key: &str) -> Option<String> {
+    fields.get(key).and_then(Value::as_str).map(str::to_string)
+}
+
+fn required(fields: &Map<String, Value>, key: &str) -> Result<String> {
+    text(fields, key).ok_or_else(|| anyhow!("Backup entry is missing {key}"))
+}
+
+fn number(fields: &Map<String, Value>, key: &str) -> Option<i64> {
+    fields.get(key).and_then(Value::as_i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dump() -> LibraryDump {
+        LibraryDump {
+            books: vec![LibraryBook {
+                id: "b1".to_string(),
+                title: "三体".to_string(),
+                author: Some("刘慈欣".to_string()),
+                intro: None,
+                kind: None,
+                cover_url: None,
+                source_url: Some("https://example.com".to_string()),
+                book_url: None,
+                toc_url: None,
+                latest_chapter_title: None,
+                chapter_count: 10,
+                word_count: Some(200_000),
+                added_at: 1,
+                updated_at: 2,
+                last_read_at: Some(3),
+            }],
+            progress: vec![ReadingProgress {
+                book_id: "b1".to_string(),
+                chapter_index: 4,
+                chapter_offset: 120,
+                percent: 0.25,
+                updated_at: 3,
+            }],
+            bookmarks: vec![],
+            highlights: vec![Highlight {
+                id: Some(7),
+                book_id: "b1".to_string(),
+                chapter_index: 4,
+                start_offset: 10,
+                end_offset: 20,
+                text: "给岁月以文明".to_string(),
+                note: None,
+                color: 0xFFFFEB3B,
+                created_at: 3,
+            }],
+        }
+    }
+
+    fn rule(pattern: &str) -> PurifyRule {
+        PurifyRule {
+            name: pattern.to_string(),
+            kind: PurifyRuleKind::Regex,
+            pattern: pattern.to_string(),
+            replacement: String::new(),
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let sources = vec![json!({"bookSourceUrl": "https://a", "custom": [1, 2]})];
+        let data = pack(&dump(), &[rule("广告")], &sources, 100).unwrap();
+        let backup = unpack(&data).unwrap();
+        assert_eq!(backup.created_at, 100);
+        let book = &backup.dump.books[0];
+        assert_eq!(
+            (book.title.as_str(), book.word_count),
+            ("三体", Some(200_000))
+        );
+        assert_eq!(book.last_read_at, Some(3));
+        assert_eq!(backup.dump.progress[0].percent, 0.25);
+        let highlight = &backup.dump.highlights[0];
+        assert_eq!((highlight.id, highlight.color), (None, 0xFFFFEB3B));
+        assert_eq!(backup.purify_rules[0].kind, PurifyRuleKind::Regex);
+        // Unknown source fields survive.
+        assert_eq!(backup.book_sources, sources);
+    }
+
+    #[test]
+    fn test_rejects_invalid_backups() {
+        let data = pack(&dump(), &[], &[], 100).unwrap();
+        let mut zip = ZipArchive::new(Cursor::new(data.as_slice())).unwrap();
+        let mut files: Vec<(String, Vec<u8>)> = (0..zip.len())
+            .map(|i| {
+                let mut file = zip.by_index(i).unwrap();
+                let mut data = Vec::new();
+                file.read_to_end(&mut data).unwrap();
+                (file.name().to_string(), data)
+            })
+            .collect();
+        let repack = |files: &[(String, Vec<u8>)]| {
+            let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+            for (name, data) in files {
+                zip.start_file(name.as_str(), SimpleFileOptions::default())
+                    .unwrap();
+                zip.write_all(data).unwrap();
+            }
+            zip.finish().unwrap().into_inner()
+        };
+
+        let library = files.iter().position(|(n, _)| n == LIBRARY).unwrap();
+        let original = files[library].1.clone();
+        files[library].1 = String::from_utf8(original.clone())
+            .unwrap()
+            .replace("三体", "球状闪电")
+            .into_bytes();
+        let error = unpack(&repack(&files)).err().unwrap().to_string();
+        assert!(error.contains("checksum"), "{error}");
+
+        files[library].1 = original;
+        let manifest = String::from_utf8(files[0].1.clone()).unwrap();
+        files[0].1 = manifest
+            .replace("\"version\":1", "\"version\":2")
+            .into_bytes();
+        let error = unpack(&repack(&files)).err().unwrap().to_string();
+        assert!(error.contains("not supported"), "{error}");
+        assert!(unpack(b"not a zip").is_err());
+    }
+
+    #[test]
+    fn test_merges_rules_and_sources() {
+        let (rules, added) = merge_rules(
+            vec![rule("a"), rule("b")],
+            vec![
+                rule("b"),
+                rule("c"),
+                PurifyRule {
+                    kind: PurifyRuleKind::Line,
+                    ..rule("a")
+                },
+            ],
+        );
+        assert_eq!(added, 2);
+        assert_eq!(rules.len(), 4);
+
+        let current = source_list(r#"{"bookSourceUrl": "https://a", "bookSourceName": "A"}"#);
+        let backup = source_list(
+            r#"[{"bookSourceUrl": "https://a", "bookSourceName": "old"},
+                {"bookSourceUrl": "https://b"}, {"bookSourceName": "no url"}]"#,
+        );
+        let (sources, added) = merge_sources(current.unwrap(), backup.unwrap());
+        assert_eq!(added, 1);
+        assert_eq!(sources[0]["bookSourceName"], "A");
+        assert_eq!(sources[1]["bookSourceUrl"], "https://b");
+        assert!(source_list("").unwrap().is_empty());
+        assert!(source_list("42").is_err());
+    }
+}
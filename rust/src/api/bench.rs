@@ -0,0 +1,225 @@
+//! A quick benchmark to sort devices into performance tiers.
+//!
+//! `run` times the three kinds of work that dominate reading: inflating
+//! EPUB entries, segmenting Chinese text and breaking paragraphs into
+//! lines. Each is repeated on a generated chapter until it has run for at
+//! least `MIN_DURATION`, so the whole run stays well under a second on any
+//! device. Throughputs are compared with those of a mid-range phone, and
+//! their geometric mean picks the tier and the settings that go with it.
+
+use std::io::{Read, Write};
+use std::time::{Duration, Instant};
+
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+
+use crate::api::error::ApiError;
+use crate::api::line_break::Kinsoku;
+use crate::api::{pagination, segment, task};
+
+const MIN_DURATION: Duration = Duration::from_millis(60);
+
+/// Sentences the generated chapter is made of.
+const SENTENCES: &[&str] = &[
+    "天色渐晚，城门外的行人越来越少。",
+    "他把信折好，放进怀里，转身走进了风雪之中。",
+    "「你真的要走吗？」她低声问道。",
+    "远处传来钟声，一声接着一声，像是在催促着什么。",
+    "客栈的掌柜抬起头，看了看门口，又低下头去拨他的算盘。",
+    "This chapter was imported from a web source in 2024.",
+];
+
+/// Throughputs of a mid-range phone, which score 1.
+const REFERENCE_INFLATE_MB_PER_SEC: f64 = 120.0;
+const REFERENCE_SEGMENT_CHARS_PER_SEC: f64 = 1_500_000.0;
+const REFERENCE_LAYOUT_CHARS_PER_SEC: f64 = 4_000_000.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PerformanceTier {
+    Low,
+    Medium,
+    High,
+}
+
+/// Settings suited to a tier.
+#[derive(Debug, Clone)]
+pub struct TierSettings {
+    /// Chapters to prefetch ahead of the reading position.
+    pub prefetch_depth: u32,
+    /// JPEG quality for covers and comic pages, 1 to 100.
+    pub image_quality: u8,
+    /// Whether page-turn and other animations are on.
+    pub animations: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct BenchReport {
+    pub inflate_mb_per_sec: f64,
+    pub segment_chars_per_sec: f64,
+    pub layout_chars_per_sec: f64,
+    /// Geometric mean of the throughputs relative to a mid-range phone.
+    pub score: f64,
+    pub tier: PerformanceTier,
+    pub settings: TierSettings,
+    /// Wall time of the whole run.
+    pub elapsed_ms: u32,
+}
+
+/// Benchmark the device. Meant to be run once, e.g. on first launch, with
+/// the result stored.
+#[flutter_rust_bridge::frb]
+pub async fn run() -> Result<BenchReport, ApiError> {
+    task::run_blocking(task::cancel_token(None)?, |token| {
+        let started = Instant::now();
+        let text = chapter(64 * 1024);
+        let chars = text.chars().count() as f64;
+
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(text.as_bytes())?;
+        let compressed = encoder.finish()?;
+        let mut inflated = Vec::with_capacity(text.len());
+        let inflate = throughput(|| {
+            inflated.clear();
+            DeflateDecoder::new(&compressed[..])
+                .read_to_end(&mut inflated)
+                .expect("inflates its own output");
+        });
+        token.check()?;
+
+        // Loading the dictionary is a one-off cost, not part of the rate.
+        let jieba = segment::jieba();
+        let segment = throughput(|| {
+            jieba.cut(&text, true);
+        });
+        token.check()?;
+
+        let kinsoku = Kinsoku::standard();
+        let paragraphs: Vec<(&str, Vec<f32>)> = text
+            .lines()
+            .map(|paragraph| (paragraph, widths(paragraph)))
+            .collect();
+        let layout = throughput(|| {
+            for (paragraph, widths) in &paragraphs {
+                pagination::break_lines(paragraph, widths, 360.0, 32.0, 8.0, &kinsoku);
+            }
+        });
+
+        let inflate_mb_per_sec = text.len() as f64 / inflate / 1e6;
+        let segment_chars_per_sec = chars / segment;
+        let layout_chars_per_sec = chars / layout;
+        let ratios = [
+            inflate_mb_per_sec / REFERENCE_INFLATE_MB_PER_SEC,
+            segment_chars_per_sec / REFERENCE_SEGMENT_CHARS_PER_SEC,
+            layout_chars_per_sec / REFERENCE_LAYOUT_CHARS_PER_SEC,
+        ];
+        let score = ratios.iter().product::<f64>().cbrt();
+        let tier = tier(score);
+        Ok(BenchReport {
+            inflate_mb_per_sec,
+            segment_chars_per_sec,
+            layout_chars_per_sec,
+            score,
+            tier,
+            settings: tier_settings(tier),
+            elapsed_ms: started.elapsed().as_millis() as u32,
+        })
+    })
+    .await
+}
+
+/// The settings for a tier, e.g. one the user picked instead of the
+/// measured one.
+#[flutter_rust_bridge::frb]
+pub fn tier_settings(tier: PerformanceTier) -> TierSettings {
+    let (prefetch_depth, image_quality, animations) = match tier {
+        PerformanceTier::Low => (1, 70, false),
+        PerformanceTier::Medium => (3, 82, true),
+        PerformanceTier::High => (5, 90, true),
+    };
+    TierSettings {
+        prefetch_depth,
+        image_quality,
+        animations,
+    }
+}
+
+fn tier(score: f64) -> PerformanceTier {
+    if score < 0.6 {
+        PerformanceTier::Low
+    } else if score < 1.6 {
+        PerformanceTier::Medium
+    } else {
+        PerformanceTier::High
+    }
+}
+
+/// Seconds per run of `work`, averaged over runs lasting `MIN_DURATION`.
+fn throughput(mut work: impl FnMut()) -> f64 {
+    let started = Instant::now();
+    let mut runs = 0;
+    while runs == 0 || started.elapsed() < MIN_DURATION {
+        work();
+        runs += 1;
+    }
+    started.elapsed().as_secs_f64() / runs as f64
+}
+
+/// Paragraphs of sample sentences, about `bytes` long.
+fn chapter(bytes: usize) -> String {
+    let mut text = String::with_capacity(bytes + 256);
+    let mut n = 0;
+    while text.len() < bytes {
+        for i in 0..4 + n % 3 {
+            text.push_str(SENTENCES[(n * 7 + i * 3) % SENTENCES.len()]);
+        }
+        text.push('\n');
+        n += 1;
+    }
+    text
+}
+
+/// Prefix widths of `paragraph` as `pagination::prefix_widths` gives them,
+/// with CJK characters 16 px wide and others 8 px.
+fn widths(paragraph: &str) -> Vec<f32> {
+    let mut widths = vec![0.0; paragraph.len() + 1];
+    let mut total = 0.0;
+    for (i, c) in paragraph.char_indices() {
+        widths[i] = total;
+        total += if c.len_utf8() > 1 { 16.0 } else { 8.0 };
+    }
+    widths[paragraph.len()] = total;
+    widths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tiers() {
+        assert_eq!(tier(0.2), PerformanceTier::Low);
+        assert_eq!(tier(1.0), PerformanceTier::Medium);
+        assert_eq!(tier(4.0), PerformanceTier::High);
+        assert!(tier_settings(PerformanceTier::Low).prefetch_depth < 3);
+        assert!(!tier_settings(PerformanceTier::Low).animations);
+
+        let text = chapter(1000);
+        assert!(text.len() >= 1000 && text.ends_with('\n'));
+        let widths = widths("天色a");
+        assert_eq!(
+            (widths[0], widths[3], widths[6], widths[7]),
+            (0.0, 16.0, 32.0, 40.0)
+        );
+    }
+
+    #[test]
+    fn test_run_measures_throughputs() {
+        let report = task::block_on(run()).unwrap();
+        assert!(report.inflate_mb_per_sec > 0.0);
+        assert!(report.segment_chars_per_sec > 0.0);
+        assert!(report.layout_chars_per_sec > 0.0);
+        assert_eq!(report.tier, tier(report.score));
+        assert!(report.elapsed_ms >= 3 * MIN_DURATION.as_millis() as u32);
+    }
+}
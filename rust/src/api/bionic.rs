@@ -0,0 +1,169 @@
+//! Emphasis spans for "bionic reading".
+//!
+//! Bionic reading bolds the start of each word so the eye can skim the
+//! rest. The text itself is never changed: `bionic_spans` returns the
+//! ranges to draw in bold, so highlights, notes and reading positions keep
+//! the offsets of the original text. Latin words get the first
+//! `latin_ratio` of their letters. Chinese has no spaces to find words by,
+//! so when `cjk` is set the text is split with the word segmenter and the
+//! first character of each word of two or more characters is emphasised.
+//! Offsets are UTF-16 code units, matching Dart string indexes.
+
+use crate::api::segment;
+
+/// Bounds of `BionicOptions::latin_ratio`.
+const MIN_LATIN_RATIO: f32 = 0.3;
+const MAX_LATIN_RATIO: f32 = 0.5;
+
+#[derive(Debug, Clone)]
+pub struct BionicOptions {
+    /// Share of each Latin word's letters emphasised, rounded, between
+    /// 0.3 and 0.5. At least one letter is emphasised.
+    pub latin_ratio: f32,
+    /// Also emphasise the first character of Chinese words.
+    pub cjk: bool,
+}
+
+/// A range to draw in bold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmphasisSpan {
+    pub start: u32,
+    pub end: u32,
+}
+
+/// Latin words only, with 40% of each emphasised.
+#[flutter_rust_bridge::frb]
+pub fn default_bionic_options() -> BionicOptions {
+    BionicOptions {
+        latin_ratio: 0.4,
+        cjk: false,
+    }
+}
+
+/// The ranges of `text` to emphasise, in order and not overlapping.
+#[flutter_rust_bridge::frb]
+pub fn bionic_spans(text: String, options: BionicOptions) -> Vec<EmphasisSpan> {
+    let ratio = options.latin_ratio.clamp(MIN_LATIN_RATIO, MAX_LATIN_RATIO);
+    let mut spans = Vec::new();
+    let mut offset = 0;
+    for (kind, run) in runs(&text) {
+        match kind {
+            Run::Latin => {
+                let letters = run.chars().count();
+                let emphasised = ((letters as f32 * ratio).round() as usize).max(1);
+                let len: usize = run.chars().take(emphasised).map(char::len_utf16).sum();
+                spans.push(EmphasisSpan {
+                    start: offset,
+                    end: offset + len as u32,
+                });
+            }
+            Run::Han if options.cjk => {
+                let mut start = offset;
+                for token in segment::jieba().cut(run, true) {
+                    let mut chars = token.word.chars();
+                    if let (Some(first), Some(_)) = (chars.next(), chars.next()) {
+                        spans.push(EmphasisSpan {
+                            start,
+                            end: start + first.len_utf16() as u32,
+                        });
+                    }
+                    start += token.word.encode_utf16().count() as u32;
+                }
+            }
+            Run::Han | Run::Other => {}
+        }
+        offset += run.encode_utf16().count() as u32;
+    }
+    spans
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Run {
+    /// A word of Latin-script letters, apostrophes within it included.
+    Latin,
+    Han,
+    Other,
+}
+
+/// Split `text` into runs of Latin words, Han characters and the rest.
+fn runs(text: &str) -> Vec<(Run, &str)> {
+    let mut runs: Vec<(Run, &str)> = Vec::new();
+    let mut start = 0;
+    let mut current = None;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let kind = if is_latin_letter(c) {
+            Run::Latin
+        } else if is_han(c) {
+            Run::Han
+        } else if matches!(c, '\'' | '’') && current == Some(Run::Latin) {
+            // "don't" is one word, but a closing quote is not part of it.
+            match chars.peek() {
+                Some(&(_, next)) if is_latin_letter(next) => Run::Latin,
+                _ => Run::Other,
+            }
+        } else {
+            Run::Other
+        };
+        if current.is_some_and(|current| current != kind) {
+            runs.push((current.unwrap(), &text[start..i]));
+            start = i;
+        }
+        current = Some(kind);
+    }
+    if let Some(kind) = current {
+        runs.push((kind, &text[start..]));
+    }
+    runs
+}
+
+fn is_latin_letter(c: char) -> bool {
+    c.is_ascii_alphabetic()
+        || (matches!(c, '\u{00C0}'..='\u{024F}' | '\u{1E00}'..='\u{1EFF}') && c.is_alphabetic())
+}
+
+fn is_han(c: char) -> bool {
+    matches!(c, '\u{4E00}'..='\u{9FFF}' | '\u{3400}'..='\u{4DBF}')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn emphasised(text: &str, options: BionicOptions) -> Vec<String> {
+        let units: Vec<u16> = text.encode_utf16().collect();
+        bionic_spans(text.to_string(), options)
+            .iter()
+            .map(|span| String::from_utf16(&units[span.start as usize..span.end as usize]).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_latin_word_prefixes() {
+        let text = "“Don’t read a Café’s menu,” she said.";
+        assert_eq!(
+            emphasised(text, default_bionic_options()),
+            ["Do", "re", "a", "Ca", "me", "s", "sa"]
+        );
+        let options = BionicOptions {
+            latin_ratio: 0.9,
+            ..default_bionic_options()
+        };
+        // The ratio is capped at half of each word.
+        assert_eq!(emphasised("reading", options), ["read"]);
+    }
+
+    #[test]
+    fn test_cjk_word_initials() {
+        let text = "我们在𠀀图书馆看 Harry Potter。";
+        assert_eq!(emphasised(text, default_bionic_options()), ["Ha", "Po"]);
+        let options = BionicOptions {
+            cjk: true,
+            ..default_bionic_options()
+        };
+        let spans = bionic_spans(text.to_string(), options.clone());
+        assert_eq!(emphasised(text, options), ["我", "图", "Ha", "Po"]);
+        // Offsets count the supplementary character as two units.
+        assert_eq!(spans[1], EmphasisSpan { start: 5, end: 6 });
+    }
+}
@@ -0,0 +1,330 @@
+//! Content-addressed storage for covers and chapter images.
+//!
+//! Blobs are files named by the BLAKE3 hash of their bytes, fanned out into
+//! folders by the first two hex digits, so the same cover fetched from
+//! three sources is stored once. References are named by their owner, such
+//! as `cover:<book id>` or `image:<book id>/<chapter>/<n>`, and kept in a
+//! small SQLite index next to the blobs: putting under an owner replaces
+//! whatever it referenced before, so storing the same image again never
+//! inflates a count. `gc` deletes blobs nothing refers to any more, along
+//! with files a crash left behind without an index entry.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard};
+
+use anyhow::{anyhow, Result};
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::api::error::ApiError;
+use crate::api::font_converter;
+
+const INDEX: &str = "index.sqlite";
+
+static BLOB_STORE: Mutex<Option<BlobStore>> = Mutex::new(None);
+
+#[derive(Debug, Clone)]
+pub struct BlobStoreStats {
+    pub blob_count: u32,
+    pub total_bytes: u64,
+    pub reference_count: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct BlobGcReport {
+    pub removed: u32,
+    pub freed_bytes: u64,
+}
+
+/// Enable the blob store. Calling this again switches to the new directory.
+///
+/// # Arguments
+/// * `store_dir` - Directory owned by the store; created if missing
+#[flutter_rust_bridge::frb]
+pub fn init_blob_store(store_dir: String) -> Result<(), ApiError> {
+    *lock() = Some(BlobStore::open(PathBuf::from(store_dir))?);
+    Ok(())
+}
+
+/// Store a blob, point `owner` at it and return its hash. A blob with the
+/// same bytes is reused.
+///
+/// # Arguments
+/// * `owner` - Name of the reference, e.g. `cover:<book id>`
+/// * `data` - Blob bytes
+#[flutter_rust_bridge::frb]
+pub fn put(owner: String, data: Vec<u8>) -> Result<String, ApiError> {
+    Ok(with_store(|store| store.put(&owner, &data))?)
+}
+
+/// Read a blob by hash, or `None` if it is not stored.
+#[flutter_rust_bridge::frb]
+pub fn get(hash: String) -> Result<Option<Vec<u8>>, ApiError> {
+    Ok(with_store(|store| store.get(&hash))?)
+}
+
+/// The file holding a blob, for image widgets that read files directly,
+/// or `None` if it is not stored.
+#[flutter_rust_bridge::frb]
+pub fn blob_path(hash: String) -> Result<Option<String>, ApiError> {
+    Ok(with_store(|store| {
+        let path = store.path(&hash)?;
+        Ok(path.exists().then(|| path.to_string_lossy().into_owned()))
+    })?)
+}
+
+/// The hash `owner` refers to, if any.
+#[flutter_rust_bridge::frb]
+pub fn blob_for_owner(owner: String) -> Result<Option<String>, ApiError> {
+    Ok(with_store(|store| {
+        store
+            .index
+            .query_row("SELECT hash FROM refs WHERE owner = ?1", [owner], |row| {
+                row.get(0)
+            })
+            .optional()
+            .map_err(|e| anyhow!("Blob index error: {e}"))
+    })?)
+}
+
+/// Drop the references of every owner starting with `prefix`, such as all
+/// of a deleted book's images. Returns the number dropped; the blobs stay
+/// until `gc`.
+#[flutter_rust_bridge::frb]
+pub fn release(prefix: String) -> Result<u32, ApiError> {
+    Ok(with_store(|store| store.release(&prefix))?)
+}
+
+/// Delete blobs without references and files missing from the index.
+#[flutter_rust_bridge::frb]
+pub fn gc() -> Result<BlobGcReport, ApiError> {
+    Ok(with_store(|store| store.gc())?)
+}
+
+/// Report the number and total size of stored blobs.
+#[flutter_rust_bridge::frb]
+pub fn blob_store_stats() -> Result<BlobStoreStats, ApiError> {
+    Ok(with_store(|store| {
+        store
+            .index
+            .query_row(
+                "SELECT (SELECT COUNT(*) FROM blobs), (SELECT COALESCE(SUM(size), 0) FROM blobs),
+                        (SELECT COUNT(*) FROM refs)",
+                [],
+                |row| {
+                    Ok(BlobStoreStats {
+                        blob_count: row.get(0)?,
+                        total_bytes: row.get::<_, i64>(1)? as u64,
+                        reference_count: row.get(2)?,
+                    })
+                },
+            )
+            .map_err(|e| anyhow!("Blob index error: {e}"))
+    })?)
+}
+
+fn lock() -> MutexGuard<'static, Option<BlobStore>> {
+    BLOB_STORE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn with_store<T>(f: impl FnOnce(&mut BlobStore) -> Result<T>) -> Result<T> {
+    let mut store = lock();
+    f(store
+        .as_mut()
+        .ok_or_else(|| anyhow!("Blob store is not initialized"))?)
+}
+
+struct BlobStore {
+    dir: PathBuf,
+    index: Connection,
+}
+
+impl BlobStore {
+    fn open(dir: PathBuf) -> Result<Self> {
+        fs::create_dir_all(&dir).map_err(|e| anyhow!("Failed to create {}: {e}", dir.display()))?;
+        let path = dir.join(INDEX);
+        let index = Connection::open(&path)
+            .and_then(|index| {
+                index.execute_batch(
+                    "PRAGMA journal_mode = WAL;
+                     CREATE TABLE IF NOT EXISTS blobs (hash TEXT PRIMARY KEY, size INTEGER NOT NULL);
+                     CREATE TABLE IF NOT EXISTS refs (
+                        owner TEXT PRIMARY KEY,
+                        hash TEXT NOT NULL
+                     );
+                     CREATE INDEX IF NOT EXISTS refs_hash ON refs (hash);",
+                )?;
+                Ok(index)
+            })
+            .map_err(|e| anyhow!("Failed to open {}: {e}", path.display()))?;
+        Ok(Self { dir, index })
+    }
+
+    /// Where a blob is stored; fails for anything but a BLAKE3 hex hash.
+    fn path(&self, hash: &str) -> Result<PathBuf> {
+        if hash.len() != 64 || !hash.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(anyhow!("Invalid blob hash {hash}"));
+        }
+        let hash = hash.to_ascii_lowercase();
+        Ok(self.dir.join(&hash[..2]).join(hash))
+    }
+
+    fn put(&mut self, owner: &str, data: &[u8]) -> Result<String> {
+        let hash = blake3::hash(data).to_hex().to_string();
+        let path = self.path(&hash)?;
+        if !path.exists() {
+            let dir = path.parent().unwrap();
+            fs::create_dir_all(dir)
+                .map_err(|e| anyhow!("Failed to create {}: {e}", dir.display()))?;
+            font_converter::write_atomically(&path, data)?;
+        }
+        let tx = self
+            .index
+            .transaction()
+            .and_then(|tx| {
+                tx.execute(
+                    "INSERT OR IGNORE INTO blobs (hash, size) VALUES (?1, ?2)",
+                    params![hash, data.len() as i64],
+                )?;
+                tx.execute(
+                    "INSERT OR REPLACE INTO refs (owner, hash) VALUES (?1, ?2)",
+                    params![owner, hash],
+                )?;
+                Ok(tx)
+            })
+            .map_err(|e| anyhow!("Blob index error: {e}"))?;
+        tx.commit().map_err(|e| anyhow!("Blob index error: {e}"))?;
+        Ok(hash)
+    }
+
+    fn get(&self, hash: &str) -> Result<Option<Vec<u8>>> {
+        let path = self.path(hash)?;
+        match fs::read(&path) {
+            Ok(data) => Ok(Some(data)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(anyhow!("Failed to read {}: {e}", path.display())),
+        }
+    }
+
+    fn release(&mut self, prefix: &str) -> Result<u32> {
+        self.index
+            .execute(
+                "DELETE FROM refs WHERE substr(owner, 1, length(?1)) = ?1",
+                [prefix],
+            )
+            .map(|n| n as u32)
+            .map_err(|e| anyhow!("Blob index error: {e}"))
+    }
+
+    fn gc(&mut self) -> Result<BlobGcReport> {
+        let mut report = BlobGcReport {
+            removed: 0,
+            freed_bytes: 0,
+        };
+        let unreferenced: Vec<String> = self
+            .index
+            .prepare("SELECT hash FROM blobs WHERE hash NOT IN (SELECT hash FROM refs)")
+            .and_then(|mut statement| {
+                statement
+                    .query_map([], |row| row.get(0))?
+                    .collect::<rusqlite::Result<_>>()
+            })
+            .map_err(|e| anyhow!("Blob index error: {e}"))?;
+        for hash in unreferenced {
+            let path = self.path(&hash)?;
+            report.freed_bytes += fs::metadata(&path).map_or(0, |m| m.len());
+            match fs::remove_file(&path) {
+                Ok(()) => report.removed += 1,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(anyhow!("Failed to remove {}: {e}", path.display())),
+            }
+            self.index
+                .execute("DELETE FROM blobs WHERE hash = ?1", [&hash])
+                .map_err(|e| anyhow!("Blob index error: {e}"))?;
+        }
+
+        // Files written by a `put` that crashed before updating the index.
+        let folders = fs::read_dir(&self.dir)
+            .map_err(|e| anyhow!("Failed to read {}: {e}", self.dir.display()))?;
+        for folder in folders.flatten().filter(|item| item.path().is_dir()) {
+            for file in fs::read_dir(folder.path()).into_iter().flatten().flatten() {
+                let name = file.file_name().to_string_lossy().into_owned();
+                let indexed: bool = self
+                    .index
+                    .query_row(
+                        "SELECT EXISTS (SELECT 1 FROM blobs WHERE hash = ?1)",
+                        [&name],
+                        |row| row.get(0),
+                    )
+                    .map_err(|e| anyhow!("Blob index error: {e}"))?;
+                if !indexed {
+                    report.freed_bytes += file.metadata().map_or(0, |m| m.len());
+                    if fs::remove_file(file.path()).is_ok() {
+                        report.removed += 1;
+                    }
+                }
+            }
+        }
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_dirs::temp_dir;
+
+    #[test]
+    fn test_deduplicates_and_collects() {
+        let dir = temp_dir("gc");
+        let mut store = BlobStore::open(dir.clone()).unwrap();
+        let cover = store.put("cover:a", b"same cover").unwrap();
+        assert_eq!(store.put("cover:b", b"same cover").unwrap(), cover);
+        assert_eq!(store.put("cover:b", b"same cover").unwrap(), cover);
+        let image = store.put("image:a/1/0", b"inline image").unwrap();
+        let files = |dir: &PathBuf| {
+            fs::read_dir(dir)
+                .unwrap()
+                .flatten()
+                .filter(|item| item.path().is_dir())
+                .map(|item| fs::read_dir(item.path()).unwrap().count())
+                .sum::<usize>()
+        };
+        assert_eq!(files(&dir), 2);
+        assert_eq!(store.get(&cover).unwrap().unwrap(), b"same cover");
+
+        // Book `a` is deleted: its image goes, the shared cover stays.
+        assert_eq!(store.release("cover:a").unwrap(), 1);
+        assert_eq!(store.release("image:a/").unwrap(), 1);
+        let report = store.gc().unwrap();
+        assert_eq!((report.removed, report.freed_bytes), (1, 12));
+        assert_eq!(store.get(&image).unwrap(), None);
+        assert!(store.get(&cover).unwrap().is_some());
+
+        // Replacing the last reference leaves the old blob unreferenced.
+        store.put("cover:b", b"new cover").unwrap();
+        assert_eq!(store.gc().unwrap().removed, 1);
+        assert_eq!(store.get(&cover).unwrap(), None);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_removes_orphans_and_rejects_bad_hashes() {
+        let dir = temp_dir("orphans");
+        let mut store = BlobStore::open(dir.clone()).unwrap();
+        let kept = store.put("cover:a", b"kept").unwrap();
+        let orphan = blake3::hash(b"orphan").to_hex().to_string();
+        let path = store.path(&orphan).unwrap();
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, b"orphan").unwrap();
+        assert_eq!(store.gc().unwrap().removed, 1);
+        assert!(!path.exists());
+        assert!(store.get(&kept).unwrap().is_some());
+
+        // `_` is not a wildcard in prefixes.
+        store.put("cover_x", b"other").unwrap();
+        assert_eq!(store.release("cover_").unwrap(), 1);
+        assert!(store.get("../index.sqlite").is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
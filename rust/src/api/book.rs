@@ -0,0 +1,285 @@
+//! Format-neutral book model shared by the ebook importers.
+//!
+//! Every importer produces a `ParsedBook`: cleaned HTML chapters in reading
+//! order plus a flat table of contents. Image sources and links inside the
+//! chapter HTML are hrefs that the importer's own extraction function
+//! understands.
+//!
+//! Chapters also list their images, so the text-only reading pipeline can
+//! keep them: `chapter_text` flattens the HTML with a placeholder character
+//! where each image goes, and `load_book_image` fetches and decodes one
+//! when its page is drawn.
+
+use quick_xml::events::Event;
+
+use crate::xhtml;
+
+/// Stands in for an image in `chapter_text`.
+pub(crate) const IMAGE_PLACEHOLDER: char = '\u{fffc}';
+
+/// Elements that start a new line in `chapter_text`.
+const BLOCKS: &[&str] = &[
+    "p",
+    "div",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "li",
+    "dt",
+    "dd",
+    "blockquote",
+    "pre",
+    "figure",
+    "figcaption",
+    "table",
+    "tr",
+    "ul",
+    "ol",
+    "hr",
+    "section",
+    "article",
+    "aside",
+];
+
+/// A parsed book, ready for the reader to paginate.
+#[derive(Debug, Clone)]
+pub struct ParsedBook {
+    pub metadata: BookMetadata,
+    /// Chapters in reading order.
+    pub chapters: Vec<BookChapter>,
+    /// Table of contents in document order; nesting is given by `depth`.
+    pub toc: Vec<TocEntry>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BookMetadata {
+    pub title: Option<String>,
+    pub authors: Vec<String>,
+    pub language: Option<String>,
+    pub identifier: Option<String>,
+    pub publisher: Option<String>,
+    pub description: Option<String>,
+    /// Resource href of the cover image, if the book declares one.
+    pub cover_href: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BookChapter {
+    pub index: u32,
+    /// Source document path; chapter links use this to refer to each other.
+    pub href: String,
+    pub title: Option<String>,
+    /// Body content with scripts, styles and presentational attributes
+    /// removed. Links and image sources are resource hrefs.
+    pub html: String,
+    /// The `img` elements of `html`, in document order.
+    pub images: Vec<ChapterImage>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChapterImage {
+    /// Resource href, as taken by `load_book_image` and the importer's
+    /// extraction function.
+    pub href: String,
+    pub alt: Option<String>,
+}
+
+/// A chapter flattened for the text pipeline.
+#[derive(Debug, Clone)]
+pub struct ChapterText {
+    /// One paragraph per line. Each image is a line of its own holding
+    /// U+FFFC, the object replacement character.
+    pub text: String,
+    /// UTF-16 offset of each image's placeholder, in the order of
+    /// `BookChapter::images`.
+    pub image_offsets: Vec<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TocEntry {
+    pub title: String,
+    /// Chapter href, optionally followed by a `#fragment`.
+    pub href: String,
+    /// Chapter the entry points into, if it is part of the spine.
+    pub chapter_index: Option<u32>,
+    /// Nesting level, starting at 0 for top-level entries.
+    pub depth: u32,
+}
+
+/// Flatten chapter HTML into text, keeping the place of each image.
+///
+/// # Arguments
+/// * `html` - A chapter's `html`
+#[flutter_rust_bridge::frb]
+pub fn chapter_text(html: String) -> ChapterText {
+    let mut raw = String::with_capacity(html.len());
+    let mut reader = xhtml::reader(&html);
+    loop {
+        match reader.read_event() {
+            Ok(Event::Eof) | Err(_) => break,
+            Ok(Event::Start(element)) | Ok(Event::Empty(element)) => {
+                let name = xhtml::local_name(element.name().as_ref());
+                if name == "img" {
+                    if image(&element).is_some() {
+                        raw.push('\n');
+                        raw.push(IMAGE_PLACEHOLDER);
+                        raw.push('\n');
+                    }
+                } else if name == "br" || BLOCKS.contains(&name.as_str()) {
+                    raw.push('\n');
+                }
+            }
+            Ok(Event::End(element)) => {
+                if BLOCKS.contains(&xhtml::local_name(element.name().as_ref()).as_str()) {
+                    raw.push('\n');
+                }
+            }
+            Ok(Event::Text(content)) => {
+                // Only elements break lines; whitespace in the source does not.
+                raw.extend(
+                    xhtml::text(&content)
+                        .chars()
+                        .filter(|&c| c != IMAGE_PLACEHOLDER)
+                        .map(|c| if c.is_whitespace() { ' ' } else { c }),
+                );
+            }
+            Ok(Event::CData(content)) => raw.push_str(&String::from_utf8_lossy(&content)),
+            _ => {}
+        }
+    }
+
+    let text = raw
+        .lines()
+        .map(xhtml::normalize_whitespace)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let mut image_offsets = Vec::new();
+    let mut offset = 0;
+    for c in text.chars() {
+        if c == IMAGE_PLACEHOLDER {
+            image_offsets.push(offset);
+        }
+        offset += c.len_utf16() as u32;
+    }
+    ChapterText {
+        text,
+        image_offsets,
+    }
+}
+
+impl BookChapter {
+    /// A chapter whose images are taken from its HTML.
+    pub(crate) fn new(index: u32, href: String, title: Option<String>, html: String) -> Self {
+        Self {
+            index,
+            href,
+            title,
+            images: chapter_images(&html),
+            html,
+        }
+    }
+}
+
+impl BookMetadata {
+    pub(crate) fn empty() -> Self {
+        Self {
+            title: None,
+            authors: Vec::new(),
+            language: None,
+            identifier: None,
+            publisher: None,
+            description: None,
+            cover_href: None,
+        }
+    }
+}
+
+/// Href of an importer-synthesized chapter document, for formats whose
+/// chapters are not separate files.
+pub(crate) fn part_href(part: usize) -> String {
+    format!("part{part:04}.html")
+}
+
+/// Use the TOC wording as chapter titles, which is usually better than the
+/// first heading. The first entry pointing into a chapter wins.
+pub(crate) fn title_chapters(toc: &[TocEntry], chapters: &mut [BookChapter]) {
+    let mut titled = vec![false; chapters.len()];
+    for entry in toc {
+        if let Some(index) = entry.chapter_index {
+            let index = index as usize;
+            if index < chapters.len() && !titled[index] {
+                titled[index] = true;
+                chapters[index].title = Some(entry.title.clone());
+            }
+        }
+    }
+}
+
+/// The images of chapter HTML, in document order.
+pub(crate) fn chapter_images(html: &str) -> Vec<ChapterImage> {
+    let mut images = Vec::new();
+    let mut reader = xhtml::reader(html);
+    loop {
+        match reader.read_event() {
+            Ok(Event::Eof) | Err(_) => break,
+            Ok(Event::Start(element)) | Ok(Event::Empty(element))
+                if xhtml::local_name(element.name().as_ref()) == "img" =>
+            {
+                images.extend(image(&element));
+            }
+            _ => {}
+        }
+    }
+    images
+}
+
+fn image(element: &quick_xml::events::BytesStart) -> Option<ChapterImage> {
+    let href = xhtml::attribute(element, "src").filter(|src| !src.trim().is_empty())?;
+    Some(ChapterImage {
+        href,
+        alt: xhtml::attribute(element, "alt").filter(|alt| !alt.trim().is_empty()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HTML: &str = "<h2>插图</h2><p>天色&nbsp;渐晚，\n  城门外。</p>\
+        <p><img src=\"OEBPS/Images/01.jpg\" alt=\"城门\">他走了。</p><img alt=\"x\"><br>\
+        <div><img src=\"OEBPS/Images/02.png\"></div>";
+
+    #[test]
+    fn test_chapter_images() {
+        let chapter = BookChapter::new(0, part_href(0), None, HTML.to_string());
+        let images: Vec<(&str, Option<&str>)> = chapter
+            .images
+            .iter()
+            .map(|image| (image.href.as_str(), image.alt.as_deref()))
+            .collect();
+        assert_eq!(
+            images,
+            [
+                ("OEBPS/Images/01.jpg", Some("城门")),
+                ("OEBPS/Images/02.png", None)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_chapter_text_marks_images() {
+        let text = chapter_text(HTML.to_string());
+        assert_eq!(
+            text.text,
+            "插图\n天色 渐晚， 城门外。\n\u{fffc}\n他走了。\n\u{fffc}"
+        );
+        assert_eq!(text.image_offsets, [15, 22]);
+        let utf16: Vec<u16> = text.text.encode_utf16().collect();
+        assert_eq!(utf16[15], 0xfffc);
+        assert_eq!(utf16[22], 0xfffc);
+    }
+}
@@ -0,0 +1,158 @@
+//! Lazy loading of chapter images.
+//!
+//! Chapters only carry image hrefs (`BookChapter::images`); the reader
+//! loads an image when the page showing it is about to be drawn. The book's
+//! file extension picks the importer that extracts it, and the image is
+//! decoded and downsized to the size it is drawn at, so Dart receives
+//! pixels it can hand to the engine as they are.
+
+use ::image::imageops::FilterType;
+use anyhow::{anyhow, Result};
+
+use crate::api::archive::{self, ImportKind};
+use crate::api::error::ApiError;
+use crate::api::{epub, fb2, image, mobi, task, umd};
+use crate::xhtml;
+
+/// A decoded chapter image.
+#[derive(Debug, Clone)]
+pub struct BookImage {
+    /// RGBA pixels, row by row.
+    pub rgba: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+    /// Size of the image as stored in the book.
+    pub source_width: u32,
+    pub source_height: u32,
+}
+
+/// Extract and decode an image of a parsed book.
+///
+/// # Arguments
+/// * `path` - Path to the EPUB, MOBI/AZW, FB2 or UMD file
+/// * `href` - Image href from `BookChapter::images` or `cover_href`
+/// * `max_width` - Largest width to decode to, in pixels; 0 for no limit
+/// * `max_height` - Largest height to decode to, in pixels; 0 for no limit
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+#[flutter_rust_bridge::frb]
+pub async fn load_book_image(
+    path: String,
+    href: String,
+    max_width: u32,
+    max_height: u32,
+    cancel_token: Option<u32>,
+) -> Result<BookImage, ApiError> {
+    task::run_blocking(task::cancel_token(cancel_token)?, move |token| {
+        let data = extract(&path, &href)?;
+        token.check()?;
+        decode(&data, max_width, max_height)
+    })
+    .await
+}
+
+/// Extract an image of a parsed book without decoding it.
+///
+/// # Arguments
+/// * `path` - Path to the EPUB, MOBI/AZW, FB2 or UMD file
+/// * `href` - Image href from `BookChapter::images` or `cover_href`
+#[flutter_rust_bridge::frb]
+pub fn extract_book_image(path: String, href: String) -> Result<Vec<u8>, ApiError> {
+    Ok(extract(&path, &href)?)
+}
+
+pub(crate) fn extract(path: &str, href: &str) -> Result<Vec<u8>> {
+    let kind = if path.to_ascii_lowercase().ends_with(".fb2.zip") {
+        Some(ImportKind::Fb2)
+    } else {
+        archive::import_kind(path)
+    };
+    match kind {
+        Some(ImportKind::Epub) => epub::extract_resource(&mut epub::open_file(path)?, href),
+        Some(ImportKind::Mobi) => mobi::extract_resource(&mobi::read_file(path)?, href),
+        Some(ImportKind::Fb2) => {
+            fb2::extract_binary(&xhtml::decode(&fb2::read_document(path)?), href)
+        }
+        Some(ImportKind::Umd) => umd::extract_resource(&umd::read_file(path)?, href),
+        _ => Err(anyhow!("Books like {path} have no images")),
+    }
+}
+
+/// Decode an image to fit the size limits, keeping its shape and never
+/// upscaling.
+pub(crate) fn decode(data: &[u8], max_width: u32, max_height: u32) -> Result<BookImage> {
+    let decoded = image::decode(data)?;
+    let (source_width, source_height) = (decoded.width(), decoded.height());
+    let limit = |max: u32, actual: u32| if max == 0 { actual } else { max.min(actual) };
+    let (width, height) = (
+        limit(max_width, source_width),
+        limit(max_height, source_height),
+    );
+    let decoded = if (width, height) == (source_width, source_height) {
+        decoded
+    } else {
+        decoded.resize(width, height, FilterType::Triangle)
+    };
+    let rgba = decoded.to_rgba8();
+    Ok(BookImage {
+        width: rgba.width(),
+        height: rgba.height(),
+        rgba: rgba.into_raw(),
+        source_width,
+        source_height,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_dirs::temp_dir;
+    use ::image::{ImageFormat, Rgb, RgbImage};
+    use std::io::{Cursor, Write};
+    use zip::write::SimpleFileOptions;
+    use zip::ZipWriter;
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut data = Vec::new();
+        RgbImage::from_pixel(width, height, Rgb([200, 40, 40]))
+            .write_to(&mut Cursor::new(&mut data), ImageFormat::Png)
+            .unwrap();
+        data
+    }
+
+    #[test]
+    fn test_loads_epub_images_at_display_size() {
+        let dir = temp_dir("book-image");
+        let path = dir.join("illustrated.epub");
+        let mut writer = ZipWriter::new(std::fs::File::create(&path).unwrap());
+        writer
+            .start_file("OEBPS/Images/01.png", SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(&png(400, 200)).unwrap();
+        writer.finish().unwrap();
+        let path = path.to_string_lossy().to_string();
+
+        let image = task::block_on(load_book_image(
+            path.clone(),
+            "OEBPS/Images/01.png".to_string(),
+            100,
+            100,
+            None,
+        ))
+        .unwrap();
+        assert_eq!((image.width, image.height), (100, 50));
+        assert_eq!((image.source_width, image.source_height), (400, 200));
+        assert_eq!(image.rgba.len(), 100 * 50 * 4);
+        assert_eq!(&image.rgba[..4], &[200, 40, 40, 255]);
+
+        let unscaled = decode(&png(30, 20), 0, 100).unwrap();
+        assert_eq!((unscaled.width, unscaled.height), (30, 20));
+        assert!(extract_book_image(path, "OEBPS/Images/02.png".to_string()).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_rejects_books_without_images() {
+        let error = extract("/books/plain.txt", "images/1").unwrap_err();
+        assert!(error.to_string().contains("have no images"));
+    }
+}
@@ -0,0 +1,984 @@
+//! Book sources in the Legado (阅读) JSON format.
+//!
+//! A source describes one website: how to build its search URL and which
+//! rules pick the search results, book details, table of contents and
+//! chapter text out of its pages. Sources exported from Legado can be
+//! imported unchanged; see `source_rule` for the rule syntax. Scripts in
+//! rules can fetch pages with `java.ajax`, which goes through the same
+//! client and source headers as the pages themselves.
+//!
+//! URLs may carry Legado request options after a comma, e.g.
+//! `/search,{"method":"POST","body":"q={{key}}","charset":"gbk"}`. All
+//! returned URLs are absolute.
+
+use std::collections::{HashSet, VecDeque};
+use std::io::Read;
+use std::rc::Rc;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use encoding_rs::Encoding;
+use regex::Regex;
+use scraper::{ElementRef, Html};
+use serde_json::Value;
+use url::Url;
+
+use crate::api::downloader::DownloadHeader;
+use crate::api::error::ApiError;
+use crate::api::{encoding, network};
+use crate::source_rule::{Analyzer, Node};
+
+/// Pages larger than this are rejected rather than parsed.
+pub(crate) const MAX_PAGE_BYTES: u64 = 8 * 1024 * 1024;
+/// Limits on `nextTocUrl` and `nextContentUrl` pagination.
+const MAX_TOC_PAGES: usize = 200;
+const MAX_CONTENT_PAGES: usize = 50;
+
+/// Elements that start a new line in chapter text.
+const BLOCKS: &[&str] = &[
+    "address",
+    "article",
+    "blockquote",
+    "dd",
+    "div",
+    "dl",
+    "dt",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "hr",
+    "li",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "tr",
+    "ul",
+];
+
+#[derive(Debug, Clone)]
+pub struct BookSource {
+    /// `bookSourceUrl`; relative URLs resolve against it.
+    pub url: String,
+    pub name: String,
+    pub group: Option<String>,
+    pub enabled: bool,
+    /// Request headers from the source's `header` field.
+    pub headers: Vec<DownloadHeader>,
+    /// Search URL template using `{{key}}` and `{{page}}`.
+    pub search_url: Option<String>,
+    pub search: SearchRules,
+    pub book_info: BookInfoRules,
+    pub toc: TocRules,
+    pub content: ContentRules,
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchRules {
+    pub book_list: Option<String>,
+    pub name: Option<String>,
+    pub author: Option<String>,
+    pub intro: Option<String>,
+    pub kind: Option<String>,
+    pub last_chapter: Option<String>,
+    pub cover_url: Option<String>,
+    pub book_url: Option<String>,
+    pub word_count: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BookInfoRules {
+    /// Selects the part of the page the other rules apply to.
+    pub init: Option<String>,
+    pub name: Option<String>,
+    pub author: Option<String>,
+    pub intro: Option<String>,
+    pub kind: Option<String>,
+    pub last_chapter: Option<String>,
+    pub cover_url: Option<String>,
+    pub toc_url: Option<String>,
+    pub word_count: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TocRules {
+    pub chapter_list: Option<String>,
+    pub chapter_name: Option<String>,
+    pub chapter_url: Option<String>,
+    pub is_volume: Option<String>,
+    pub next_toc_url: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ContentRules {
+    pub content: Option<String>,
+    pub next_content_url: Option<String>,
+    /// `##regex##replacement` applied to the whole chapter text.
+    pub replace_regex: Option<String>,
+}
+
+/// A book found by a search or read from its detail page.
+#[derive(Debug, Clone)]
+pub struct SourceBook {
+    pub name: String,
+    pub author: Option<String>,
+    pub intro: Option<String>,
+    pub kind: Option<String>,
+    pub last_chapter: Option<String>,
+    pub cover_url: Option<String>,
+    pub word_count: Option<String>,
+    pub book_url: String,
+    /// Table of contents URL; the book URL until the details are fetched.
+    pub toc_url: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct SourceChapter {
+    pub title: String,
+    pub url: String,
+    /// A volume heading rather than a readable chapter.
+    pub is_volume: bool,
+}
+
+/// Parse one source or an array of sources exported from Legado.
+#[flutter_rust_bridge::frb]
+pub fn parse_book_sources(json: String) -> Result<Vec<BookSource>, ApiError> {
+    let value: Value =
+        serde_json::from_str(&json).map_err(|e| anyhow!("Invalid book source JSON: {e}"))?;
+    match value {
+        Value::Array(items) => Ok(items.iter().map(parse_source).collect::<Result<_>>()?),
+        value => Ok(vec![parse_source(&value)?]),
+    }
+}
+
+/// Search a source and return the books on one result page.
+///
+/// # Arguments
+/// * `source` - Source to search
+/// * `key` - Search keywords
+/// * `page` - Result page, starting at 1
+#[flutter_rust_bridge::frb]
+pub fn search_book_source(
+    source: BookSource,
+    key: String,
+    page: u32,
+) -> Result<Vec<SourceBook>, ApiError> {
+    let template = source
+        .search_url
+        .as_deref()
+        .ok_or_else(|| anyhow!("{} does not support search", source.name))?;
+    let base = source_base(&source)?;
+    let analyzer = analyzer(&source, &base);
+    analyzer.put("key", key);
+    analyzer.put("page", page.max(1).to_string());
+    let spec = analyzer.template(&Node::Text(String::new()), &page_choice(template, page))?;
+    let (body, url) = fetch(&source, &page_request(&spec, &base)?)?;
+    Ok(search_results(&source, &analyzer, &body, &url)?)
+}
+
+/// Fetch a book's detail page.
+///
+/// # Arguments
+/// * `source` - Source the book came from
+/// * `book_url` - `book_url` of a search result
+#[flutter_rust_bridge::frb]
+pub fn fetch_source_book_info(
+    source: BookSource,
+    book_url: String,
+) -> Result<SourceBook, ApiError> {
+    let base = source_base(&source)?;
+    let (body, url) = fetch(&source, &page_request(&book_url, &base)?)?;
+    Ok(book_info(
+        &source,
+        &analyzer(&source, &base),
+        &body,
+        &url,
+        &book_url,
+    )?)
+}
+
+/// Fetch a book's table of contents, following `nextTocUrl` pages.
+///
+/// # Arguments
+/// * `source` - Source the book came from
+/// * `toc_url` - `toc_url` from `fetch_source_book_info`
+#[flutter_rust_bridge::frb]
+pub fn fetch_source_toc(
+    source: BookSource,
+    toc_url: String,
+) -> Result<Vec<SourceChapter>, ApiError> {
+    let base = source_base(&source)?;
+    let analyzer = analyzer(&source, &base);
+    let mut chapters = Vec::new();
+    paginate(&source, &base, toc_url, None, MAX_TOC_PAGES, |body, url| {
+        let (page, next) = toc_page(&source, &analyzer, body, url)?;
+        chapters.extend(page);
+        Ok(next)
+    })?;
+    Ok(chapters)
+}
+
+/// Fetch a chapter's text, following `nextContentUrl` pages, as plain text
+/// with one paragraph per line.
+///
+/// # Arguments
+/// * `source` - Source the book came from
+/// * `chapter_url` - Chapter URL from `fetch_source_toc`
+/// * `next_chapter_url` - URL of the following chapter, where pagination
+///   stops if the next page link points there
+#[flutter_rust_bridge::frb]
+pub fn fetch_source_content(
+    source: BookSource,
+    chapter_url: String,
+    next_chapter_url: Option<String>,
+) -> Result<String, ApiError> {
+    let base = source_base(&source)?;
+    let analyzer = analyzer(&source, &base);
+    let mut parts = Vec::new();
+    paginate(
+        &source,
+        &base,
+        chapter_url,
+        next_chapter_url.as_deref(),
+        MAX_CONTENT_PAGES,
+        |body, url| {
+            let (text, next) = content_page(&source, &analyzer, body, url)?;
+            parts.push(text);
+            Ok(next)
+        },
+    )?;
+    let text = parts.join("\n");
+    match &source.content.replace_regex {
+        Some(rule) => Ok(analyzer.replace(&text, rule)?),
+        None => Ok(text),
+    }
+}
+
+fn parse_source(value: &Value) -> Result<BookSource> {
+    let url = string_field(value, "bookSourceUrl")
+        .ok_or_else(|| anyhow!("Book source is missing bookSourceUrl"))?;
+    let rules = |name: &str| -> Value {
+        match value.get(name) {
+            // Some exports store each rule group as a JSON string.
+            Some(Value::String(text)) => serde_json::from_str(text).unwrap_or(Value::Null),
+            Some(rules) => rules.clone(),
+            None => Value::Null,
+        }
+    };
+    let (search, info, toc, content) = (
+        rules("ruleSearch"),
+        rules("ruleBookInfo"),
+        rules("ruleToc"),
+        rules("ruleContent"),
+    );
+    Ok(BookSource {
+        name: string_field(value, "bookSourceName").unwrap_or_else(|| url.clone()),
+        group: string_field(value, "bookSourceGroup"),
+        enabled: value
+            .get("enabled")
+            .and_then(Value::as_bool)
+            .unwrap_or(true),
+        headers: string_field(value, "header")
+            .map(|header| parse_headers(&header))
+            .unwrap_or_default(),
+        search_url: string_field(value, "searchUrl"),
+        search: SearchRules {
+            book_list: string_field(&search, "bookList"),
+            name: string_field(&search, "name"),
+            author: string_field(&search, "author"),
+            intro: string_field(&search, "intro"),
+            kind: string_field(&search, "kind"),
+            last_chapter: string_field(&search, "lastChapter"),
+            cover_url: string_field(&search, "coverUrl"),
+            book_url: string_field(&search, "bookUrl"),
+            word_count: string_field(&search, "wordCount"),
+        },
+        book_info: BookInfoRules {
+            init: string_field(&info, "init"),
+            name: string_field(&info, "name"),
+            author: string_field(&info, "author"),
+            intro: string_field(&info, "intro"),
+            kind: string_field(&info, "kind"),
+            last_chapter: string_field(&info, "lastChapter"),
+            cover_url: string_field(&info, "coverUrl"),
+            toc_url: string_field(&info, "tocUrl"),
+            word_count: string_field(&info, "wordCount"),
+        },
+        toc: TocRules {
+            chapter_list: string_field(&toc, "chapterList"),
+            chapter_name: string_field(&toc, "chapterName"),
+            chapter_url: string_field(&toc, "chapterUrl"),
+            is_volume: string_field(&toc, "isVolume"),
+            next_toc_url: string_field(&toc, "nextTocUrl"),
+        },
+        content: ContentRules {
+            content: string_field(&content, "content"),
+            next_content_url: string_field(&content, "nextContentUrl"),
+            replace_regex: string_field(&content, "replaceRegex"),
+        },
+        url,
+    })
+}
+
+fn string_field(value: &Value, name: &str) -> Option<String> {
+    value
+        .get(name)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+}
+
+/// Headers are a JSON object, stored as a string. Headers computed by
+/// JavaScript are skipped.
+fn parse_headers(header: &str) -> Vec<DownloadHeader> {
+    let Ok(Value::Object(map)) = serde_json::from_str::<Value>(header) else {
+        return Vec::new();
+    };
+    map.into_iter()
+        .map(|(name, value)| DownloadHeader {
+            name,
+            value: match value {
+                Value::String(value) => value,
+                other => other.to_string(),
+            },
+        })
+        .collect()
+}
+
+/// An analyzer whose scripts make requests the way the source's pages are
+/// fetched.
+fn analyzer(source: &BookSource, base: &Url) -> Analyzer {
+    let mut analyzer = Analyzer::new();
+    analyzer.set_base_url(base.as_str());
+    let (source, base) = (source.clone(), base.clone());
+    analyzer.set_fetch(Rc::new(move |spec| {
+        Ok(fetch(&source, &page_request(spec, &base)?)?.0)
+    }));
+    analyzer
+}
+
+fn source_base(source: &BookSource) -> Result<Url> {
+    Url::parse(&source.url).map_err(|e| anyhow!("Invalid book source URL {}: {e}", source.url))
+}
+
+/// Resolve Legado's `<first,second,...>` page lists to the entry for `page`,
+/// the last entry serving every later page.
+fn page_choice(template: &str, page: u32) -> String {
+    let pattern = Regex::new(r"<([^<>]*,[^<>]*)>").unwrap();
+    pattern
+        .replace_all(template, |c: &regex::Captures| {
+            let choices: Vec<&str> = c[1].split(',').collect();
+            let index = (page.max(1) as usize - 1).min(choices.len() - 1);
+            choices[index].to_string()
+        })
+        .into_owned()
+}
+
+struct PageRequest {
+    url: Url,
+    method: String,
+    body: Option<String>,
+    charset: Option<&'static Encoding>,
+    headers: Vec<(String, String)>,
+}
+
+/// Build a request from a URL with optional Legado request options.
+fn page_request(spec: &str, base: &Url) -> Result<PageRequest> {
+    let (url, options) = match spec.split_once(",{") {
+        Some((url, options)) => {
+            let options: Value = serde_json::from_str(&format!("{{{options}"))
+                .map_err(|e| anyhow!("Invalid request options in {spec}: {e}"))?;
+            (url.trim(), options)
+        }
+        None => (spec.trim(), Value::Null),
+    };
+    let charset = string_field(&options, "charset")
+        .map(|label| {
+            Encoding::for_label(label.as_bytes()).ok_or_else(|| anyhow!("Unknown charset {label}"))
+        })
+        .transpose()?;
+    let encoding = charset.unwrap_or(encoding_rs::UTF_8);
+    let url = base
+        .join(&encode_non_ascii(url, encoding))
+        .map_err(|e| anyhow!("Invalid URL {url}: {e}"))?;
+    let body = match options.get("body") {
+        Some(Value::String(body)) => Some(encode_non_ascii(body, encoding)),
+        Some(Value::Null) | None => None,
+        Some(body) => Some(body.to_string()),
+    };
+    let headers = match options.get("headers") {
+        Some(Value::Object(map)) => map
+            .iter()
+            .map(|(name, value)| {
+                let value = value
+                    .as_str()
+                    .map_or_else(|| value.to_string(), String::from);
+                (name.clone(), value)
+            })
+            .collect(),
+        Some(Value::String(text)) => parse_headers(text)
+            .into_iter()
+            .map(|header| (header.name, header.value))
+            .collect(),
+        _ => Vec::new(),
+    };
+    Ok(PageRequest {
+        url,
+        method: string_field(&options, "method")
+            .map(|method| method.to_ascii_uppercase())
+            .unwrap_or_else(|| "GET".to_string()),
+        body,
+        charset,
+        headers,
+    })
+}
+
+/// Percent-encode the non-ASCII text in a URL or form body in the
+/// source's charset, as sites expecting GBK query strings need.
+fn encode_non_ascii(text: &str, encoding: &'static Encoding) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut pending = String::new();
+    let flush = |pending: &mut String, output: &mut String| {
+        if pending.is_empty() {
+            return;
+        }
+        let (bytes, _, _) = encoding.encode(pending);
+        for byte in bytes.iter() {
+            output.push_str(&format!("%{byte:02X}"));
+        }
+        pending.clear();
+    };
+    for c in text.chars() {
+        if c.is_ascii() {
+            flush(&mut pending, &mut output);
+            if c == ' ' {
+                output.push_str("%20");
+            } else {
+                output.push(c);
+            }
+        } else {
+            pending.push(c);
+        }
+    }
+    flush(&mut pending, &mut output);
+    output
+}
+
+fn fetch(source: &BookSource, request: &PageRequest) -> Result<(String, Url)> {
+    let agent = network::agent(
+        request.url.as_str(),
+        Duration::from_secs(30),
+        Duration::from_secs(60),
+        None,
+    )?;
+    let mut call = agent.request_url(&request.method, &request.url);
+    for header in &source.headers {
+        call = call.set(&header.name, &header.value);
+    }
+    for (name, value) in &request.headers {
+        call = call.set(name, value);
+    }
+    let result = match &request.body {
+        Some(body) => {
+            if call.header("Content-Type").is_none() {
+                let content_type = if body.starts_with('{') {
+                    "application/json"
+                } else {
+                    "application/x-www-form-urlencoded"
+                };
+                call = call.set("Content-Type", content_type);
+            }
+            call.send_string(body)
+        }
+        None => call.call(),
+    };
+    let url = &request.url;
+    let response = result.map_err(|e| match e {
+        ureq::Error::Status(code, _) => anyhow!("Failed to fetch {url}: HTTP {code}"),
+        e => anyhow!("Failed to fetch {url}: {e}"),
+    })?;
+    let final_url = Url::parse(response.get_url()).unwrap_or_else(|_| url.clone());
+    let content_type = response.header("Content-Type").map(str::to_string);
+    let mut bytes = Vec::new();
+    response
+        .into_reader()
+        .take(MAX_PAGE_BYTES + 1)
+        .read_to_end(&mut bytes)
+        .map_err(|e| anyhow!("Failed to read {url}: {e}"))?;
+    if bytes.len() as u64 > MAX_PAGE_BYTES {
+        return Err(anyhow!("{url} is larger than {MAX_PAGE_BYTES} bytes"));
+    }
+    Ok((
+        decode(&bytes, request.charset, content_type.as_deref()),
+        final_url,
+    ))
+}
+
+/// Decode a page using the requested charset, the `Content-Type` header, a
+/// `<meta>` declaration or, failing those, detection.
+pub(crate) fn decode(
+    bytes: &[u8],
+    charset: Option<&'static Encoding>,
+    content_type: Option<&str>,
+) -> String {
+    let label = Regex::new(r#"(?i)charset\s*=\s*["']?([\w-]+)"#).unwrap();
+    let declared = |text: &str| {
+        label
+            .captures(text)
+            .and_then(|c| Encoding::for_label(c[1].as_bytes()))
+    };
+    let head = String::from_utf8_lossy(&bytes[..bytes.len().min(2048)]);
+    let encoding = charset
+        .or_else(|| content_type.and_then(declared))
+        .or_else(|| declared(&head))
+        .unwrap_or_else(|| encoding::detect(bytes, true).encoding);
+    encoding.decode(bytes).0.into_owned()
+}
+
+/// Fetch `start` and the pages its `next` links lead to, breadth first,
+/// skipping pages already seen and stopping at `stop`.
+fn paginate(
+    source: &BookSource,
+    base: &Url,
+    start: String,
+    stop: Option<&str>,
+    max_pages: usize,
+    mut page: impl FnMut(&str, &Url) -> Result<Vec<String>>,
+) -> Result<()> {
+    let stop = stop.and_then(|stop| base.join(stop).ok());
+    let mut queue = VecDeque::from([start]);
+    let mut seen = HashSet::new();
+    while let Some(spec) = queue.pop_front() {
+        let request = page_request(&spec, base)?;
+        if !seen.insert(request.url.clone()) || Some(&request.url) == stop.as_ref() {
+            continue;
+        }
+        if seen.len() > max_pages {
+            break;
+        }
+        let (body, url) = fetch(source, &request)?;
+        queue.extend(page(&body, &url)?);
+    }
+    Ok(())
+}
+
+fn search_results(
+    source: &BookSource,
+    analyzer: &Analyzer,
+    body: &str,
+    url: &Url,
+) -> Result<Vec<SourceBook>> {
+    analyzer.set_base_url(url.as_str());
+    let rules = &source.search;
+    let list = rules
+        .book_list
+        .as_deref()
+        .ok_or_else(|| anyhow!("{} has no search result rule", source.name))?;
+    let page = Node::parse(body);
+    let mut books = Vec::new();
+    for item in analyzer.elements(&page, list)? {
+        let Some(name) = text(analyzer, &item, &rules.name, false)? else {
+            continue;
+        };
+        let book_url =
+            link(analyzer, &item, &rules.book_url, url)?.unwrap_or_else(|| url.to_string());
+        books.push(SourceBook {
+            name,
+            author: text(analyzer, &item, &rules.author, false)?,
+            intro: text(analyzer, &item, &rules.intro, true)?,
+            kind: text(analyzer, &item, &rules.kind, false)?,
+            last_chapter: text(analyzer, &item, &rules.last_chapter, false)?,
+            cover_url: link(analyzer, &item, &rules.cover_url, url)?,
+            word_count: text(analyzer, &item, &rules.word_count, false)?,
+            toc_url: book_url.clone(),
+            book_url,
+        });
+    }
+    Ok(books)
+}
+
+fn book_info(
+    source: &BookSource,
+    analyzer: &Analyzer,
+    body: &str,
+    url: &Url,
+    book_url: &str,
+) -> Result<SourceBook> {
+    analyzer.set_base_url(url.as_str());
+    let rules = &source.book_info;
+    let mut page = Node::parse(body);
+    if let Some(init) = &rules.init {
+        if let Some(first) = analyzer.elements(&page, init)?.into_iter().next() {
+            page = first;
+        }
+    }
+    Ok(SourceBook {
+        name: text(analyzer, &page, &rules.name, false)?.unwrap_or_default(),
+        author: text(analyzer, &page, &rules.author, false)?,
+        intro: text(analyzer, &page, &rules.intro, true)?,
+        kind: text(analyzer, &page, &rules.kind, false)?,
+        last_chapter: text(analyzer, &page, &rules.last_chapter, false)?,
+        cover_url: link(analyzer, &page, &rules.cover_url, url)?,
+        word_count: text(analyzer, &page, &rules.word_count, false)?,
+        toc_url: link(analyzer, &page, &rules.toc_url, url)?.unwrap_or_else(|| url.to_string()),
+        book_url: book_url.to_string(),
+    })
+}
+
+fn toc_page(
+    source: &BookSource,
+    analyzer: &Analyzer,
+    body: &str,
+    url: &Url,
+) -> Result<(Vec<SourceChapter>, Vec<String>)> {
+    analyzer.set_base_url(url.as_str());
+    let rules = &source.toc;
+    let list = rules
+        .chapter_list
+        .as_deref()
+        .ok_or_else(|| anyhow!("{} has no chapter list rule", source.name))?;
+    let page = Node::parse(body);
+    let mut chapters = Vec::new();
+    for item in analyzer.elements(&page, list)? {
+        let Some(title) = text(analyzer, &item, &rules.chapter_name, false)? else {
+            continue;
+        };
+        let is_volume = match &rules.is_volume {
+            Some(rule) => {
+                let value = analyzer.string(&item, rule)?;
+                !matches!(value.trim(), "" | "false" | "0" | "null")
+            }
+            None => false,
+        };
+        chapters.push(SourceChapter {
+            url: link(analyzer, &item, &rules.chapter_url, url)?.unwrap_or_else(|| url.to_string()),
+            title,
+            is_volume,
+        });
+    }
+    Ok((chapters, links(analyzer, &page, &rules.next_toc_url, url)?))
+}
+
+fn content_page(
+    source: &BookSource,
+    analyzer: &Analyzer,
+    body: &str,
+    url: &Url,
+) -> Result<(String, Vec<String>)> {
+    analyzer.set_base_url(url.as_str());
+    let rules = &source.content;
+    let rule = rules
+        .content
+        .as_deref()
+        .ok_or_else(|| anyhow!("{} has no content rule", source.name))?;
+    let page = Node::parse(body);
+    let text = html_to_text(&analyzer.string(&page, rule)?);
+    Ok((text, links(analyzer, &page, &rules.next_content_url, url)?))
+}
+
+/// A text field with markup removed; `multiline` keeps paragraph breaks.
+fn text(
+    analyzer: &Analyzer,
+    node: &Node,
+    rule: &Option<String>,
+    multiline: bool,
+) -> Result<Option<String>> {
+    let Some(rule) = rule else {
+        return Ok(None);
+    };
+    let text = html_to_text(&analyzer.string(node, rule)?);
+    let text = if multiline {
+        text
+    } else {
+        text.lines().collect::<Vec<_>>().join(" ")
+    };
+    Ok(Some(text).filter(|text| !text.is_empty()))
+}
+
+fn link(
+    analyzer: &Analyzer,
+    node: &Node,
+    rule: &Option<String>,
+    base: &Url,
+) -> Result<Option<String>> {
+    Ok(links(analyzer, node, rule, base)?.into_iter().next())
+}
+
+fn links(
+    analyzer: &Analyzer,
+    node: &Node,
+    rule: &Option<String>,
+    base: &Url,
+) -> Result<Vec<String>> {
+    let Some(rule) = rule else {
+        return Ok(Vec::new());
+    };
+    Ok(analyzer
+        .strings(node, rule)?
+        .iter()
+        .flat_map(|value| value.lines())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| absolute(base, value))
+        .collect())
+}
+
+/// Resolve a URL, keeping any request options after it.
+fn absolute(base: &Url, value: &str) -> String {
+    let (url, options) = match value.split_once(",{") {
+        Some((url, options)) => (url, Some(options)),
+        None => (value, None),
+    };
+    let url = base
+        .join(url.trim())
+        .map_or_else(|_| url.to_string(), |url| url.to_string());
+    match options {
+        Some(options) => format!("{url},{{{options}"),
+        None => url,
+    }
+}
+
+/// Flatten markup into text with one paragraph per line.
+fn html_to_text(value: &str) -> String {
+    let mut text = String::new();
+    if value.contains('<') {
+        let fragment = Html::parse_fragment(value);
+        flatten(fragment.root_element(), &mut text);
+    } else {
+        text.push_str(value);
+    }
+    text.lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn flatten(element: ElementRef, text: &mut String) {
+    for child in element.children() {
+        match child.value() {
+            scraper::Node::Text(content) => text.push_str(content),
+            scraper::Node::Element(tag) => {
+                let name = tag.name();
+                if matches!(name, "script" | "style") {
+                    continue;
+                }
+                if name == "br" {
+                    text.push('\n');
+                    continue;
+                }
+                let block = BLOCKS.contains(&name);
+                if block {
+                    text.push('\n');
+                }
+                if let Some(child) = ElementRef::wrap(child) {
+                    flatten(child, text);
+                }
+                if block {
+                    text.push('\n');
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    const SOURCE: &str = r###"{
+        "bookSourceUrl": "https://www.example.com",
+        "bookSourceName": "示例书源",
+        "bookSourceGroup": "测试",
+        "header": "{\"Referer\": \"https://www.example.com/\"}",
+        "searchUrl": "/search.php?q={{key}}<,&page={{page}}>",
+        "ruleSearch": {
+            "bookList": "class.result",
+            "name": "tag.h3@text",
+            "author": "class.author@text##作者：",
+            "bookUrl": "tag.a.0@href",
+            "coverUrl": "tag.img@src"
+        },
+        "ruleBookInfo": "{\"name\": \"@css:h1@text\", \"intro\": \"id.intro@html\", \"tocUrl\": \"//a[@class='toc']/@href\"}",
+        "ruleToc": {
+            "chapterList": "id.list@tag.li",
+            "chapterName": "tag.a@text||text",
+            "chapterUrl": "tag.a@href",
+            "isVolume": "class",
+            "nextTocUrl": "@css:a.next@href"
+        },
+        "ruleContent": {
+            "content": "id.content@html",
+            "nextContentUrl": "text.下一页@href",
+            "replaceRegex": "##本章未完.*"
+        }
+    }"###;
+
+    fn source() -> BookSource {
+        parse_book_sources(SOURCE.to_string()).unwrap().remove(0)
+    }
+
+    #[test]
+    fn test_parses_legado_sources() {
+        let source = source();
+        assert_eq!(source.name, "示例书源");
+        assert_eq!(source.group.as_deref(), Some("测试"));
+        assert!(source.enabled);
+        assert_eq!(source.headers[0].name, "Referer");
+        assert_eq!(source.book_info.name.as_deref(), Some("@css:h1@text"));
+        assert_eq!(source.toc.next_toc_url.as_deref(), Some("@css:a.next@href"));
+        assert!(
+            parse_book_sources(format!("[{SOURCE},{SOURCE}]"))
+                .unwrap()
+                .len()
+                == 2
+        );
+        assert!(parse_book_sources("{}".to_string()).is_err());
+
+        assert_eq!(
+            page_choice("/s?q={{key}}<,&page={{page}}>", 1),
+            "/s?q={{key}}"
+        );
+        assert_eq!(
+            page_choice("/s?q={{key}}<,&page={{page}}>", 3),
+            "/s?q={{key}}&page={{page}}"
+        );
+        let request = page_request(
+            r#"/search,{"method":"post","body":"q=三体","charset":"gbk"}"#,
+            &Url::parse("https://www.example.com/").unwrap(),
+        )
+        .unwrap();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.body.as_deref(), Some("q=%C8%FD%CC%E5"));
+        assert_eq!(request.url.as_str(), "https://www.example.com/search");
+    }
+
+    #[test]
+    fn test_applies_rules_to_pages() {
+        let source = source();
+        let analyzer = Analyzer::new();
+        let url = Url::parse("https://www.example.com/search.php?q=x").unwrap();
+        let books = search_results(
+            &source,
+            &analyzer,
+            r#"<div class="result"><a href="/book/1"><img src="/c/1.jpg"></a><h3>三体</h3><p class="author">作者：刘慈欣</p></div>
+               <div class="result"><h3></h3></div>"#,
+            &url,
+        )
+        .unwrap();
+        assert_eq!(books.len(), 1);
+        assert_eq!(books[0].author.as_deref(), Some("刘慈欣"));
+        assert_eq!(books[0].book_url, "https://www.example.com/book/1");
+        assert_eq!(
+            books[0].cover_url.as_deref(),
+            Some("https://www.example.com/c/1.jpg")
+        );
+
+        let book = book_info(
+            &source,
+            &analyzer,
+            r#"<h1>三体</h1><div id="intro"><p>第一段</p><p>第二段<br>第三段</p></div><a class="toc" href="list.html">目录</a>"#,
+            &Url::parse("https://www.example.com/book/1/").unwrap(),
+            "/book/1",
+        )
+        .unwrap();
+        assert_eq!(book.intro.as_deref(), Some("第一段\n第二段\n第三段"));
+        assert_eq!(book.toc_url, "https://www.example.com/book/1/list.html");
+
+        let (chapters, next) = toc_page(
+            &source,
+            &analyzer,
+            r#"<ul id="list"><li class="volume">第一卷</li><li><a href="1.html">第一章</a></li></ul><a class="next" href="list_2.html">下页</a>"#,
+            &Url::parse("https://www.example.com/book/1/list.html").unwrap(),
+        )
+        .unwrap();
+        assert_eq!(chapters.len(), 2);
+        assert!(chapters[0].is_volume && !chapters[1].is_volume);
+        assert_eq!(chapters[1].url, "https://www.example.com/book/1/1.html");
+        assert_eq!(next, ["https://www.example.com/book/1/list_2.html"]);
+    }
+
+    fn serve(pages: Vec<(&'static str, String)>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 2 {
+                    line.clear();
+                }
+                let path = request_line.split_whitespace().nth(1).unwrap_or("");
+                let body = pages
+                    .iter()
+                    .find(|(p, _)| *p == path)
+                    .map(|(_, body)| body.clone());
+                let response = match body {
+                    Some(body) => format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    ),
+                    None => "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                        .to_string(),
+                };
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        format!("http://{address}")
+    }
+
+    #[test]
+    fn test_follows_toc_and_content_pages() {
+        let base = serve(vec![
+            (
+                "/list.html",
+                r#"<ul id="list"><li><a href="/1.html">第一章</a></li></ul><a class="next" href="/list_2.html">下页</a>"#.to_string(),
+            ),
+            (
+                "/list_2.html",
+                r#"<ul id="list"><li><a href="/2.html">第二章</a></li></ul><a class="next" href="/list.html">上页</a>"#.to_string(),
+            ),
+            (
+                "/1.html",
+                r#"<div id="content">第一段<br>第二段 本章未完</div><a href="/1_2.html">下一页</a>"#.to_string(),
+            ),
+            (
+                "/1_2.html",
+                r#"<div id="content"><p>第三段</p></div><a href="/2.html">下一页</a>"#.to_string(),
+            ),
+        ]);
+        let mut source = source();
+        source.url = base.clone();
+        source.content.replace_regex = Some("##\\s*本章未完".to_string());
+
+        let chapters = fetch_source_toc(source.clone(), "/list.html".to_string()).unwrap();
+        let titles: Vec<&str> = chapters.iter().map(|c| c.title.as_str()).collect();
+        assert_eq!(titles, ["第一章", "第二章"]);
+
+        let text = fetch_source_content(
+            source.clone(),
+            chapters[0].url.clone(),
+            Some(chapters[1].url.clone()),
+        )
+        .unwrap();
+        assert_eq!(text, "第一段\n第二段\n第三段");
+        let analyzer = analyzer(&source, &Url::parse(&base).unwrap());
+        assert!(analyzer
+            .string(&Node::Text(String::new()), "@js:java.ajax('/list.html')")
+            .unwrap()
+            .contains("第一章"));
+        assert!(fetch_source_toc(source, "/missing.html".to_string()).is_err());
+    }
+}
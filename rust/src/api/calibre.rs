@@ -0,0 +1,627 @@
+//! Calibre library folders.
+//!
+//! Calibre keeps every book in a folder of its own, `Author/Title (id)/`,
+//! holding its formats, a `metadata.opf` and a `cover.jpg`.
+//! `scan_calibre_library` reads those folders, so pointing the app at an
+//! existing library brings titles, authors, series and tags along, and
+//! `export_calibre_book` writes a folder the same way for Calibre's "Add
+//! books from folders" to read back. Metadata is read both in the OPF 2
+//! form Calibre writes, with `calibre:` meta elements, and in the EPUB 3
+//! form with `belongs-to-collection` and `refines`; it is written in the
+//! OPF 2 form.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use quick_xml::escape::escape;
+use quick_xml::events::{BytesStart, Event};
+
+use crate::api::archive::{self, ImportKind};
+use crate::api::epub_export;
+use crate::api::error::ApiError;
+use crate::api::font_converter;
+use crate::api::image;
+use crate::api::task::{self, CancelToken};
+use crate::xhtml;
+
+const OPF_NAME: &str = "metadata.opf";
+const COVER_NAME: &str = "cover.jpg";
+
+/// Folders below the library root searched for books; Calibre uses two.
+const MAX_DEPTH: u32 = 3;
+
+/// Characters kept of a title or author in a file name.
+const MAX_NAME_CHARS: usize = 80;
+
+const COVER_QUALITY: u8 = 90;
+
+#[derive(Debug, Clone)]
+pub struct CalibreIdentifier {
+    /// Lowercase scheme, e.g. `isbn`, `uuid` or `calibre`.
+    pub scheme: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CalibreMetadata {
+    pub title: Option<String>,
+    /// Sort key of the title, e.g. `Three-Body Problem, The`.
+    pub title_sort: Option<String>,
+    pub authors: Vec<String>,
+    /// Sort key of the authors, e.g. `Liu, Cixin`.
+    pub author_sort: Option<String>,
+    pub series: Option<String>,
+    /// Position in the series; Calibre allows fractions such as 1.5.
+    pub series_index: Option<f64>,
+    pub tags: Vec<String>,
+    pub publisher: Option<String>,
+    /// Calibre's comments, usually HTML.
+    pub description: Option<String>,
+    pub language: Option<String>,
+    /// Publication date as written, usually ISO 8601.
+    pub published: Option<String>,
+    /// 0 to 10, two per star.
+    pub rating: Option<u32>,
+    pub identifiers: Vec<CalibreIdentifier>,
+}
+
+/// A book file in a Calibre folder.
+#[derive(Debug, Clone)]
+pub struct CalibreFormat {
+    pub path: String,
+    pub kind: ImportKind,
+}
+
+#[derive(Debug, Clone)]
+pub struct CalibreBook {
+    /// The book's folder.
+    pub dir: String,
+    pub metadata: CalibreMetadata,
+    /// `cover.jpg`, if the folder has one.
+    pub cover_path: Option<String>,
+    /// Files the app can import, by name; may be empty.
+    pub formats: Vec<CalibreFormat>,
+}
+
+/// Find every book folder of a Calibre library, by path.
+///
+/// # Arguments
+/// * `library_dir` - The library root, or any folder below it
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+#[flutter_rust_bridge::frb]
+pub async fn scan_calibre_library(
+    library_dir: String,
+    cancel_token: Option<u32>,
+) -> Result<Vec<CalibreBook>, ApiError> {
+    task::run_blocking(task::cancel_token(cancel_token)?, move |token| {
+        let root = Path::new(&library_dir);
+        if !root.is_dir() {
+            return Err(anyhow!("{library_dir} is not a folder"));
+        }
+        let mut books = Vec::new();
+        scan(root, 0, &mut books, token)?;
+        books.sort_by(|a, b| a.dir.cmp(&b.dir));
+        Ok(books)
+    })
+    .await
+}
+
+/// Read a `metadata.opf`, or any OPF package document.
+#[flutter_rust_bridge::frb]
+pub fn read_calibre_opf(path: String) -> Result<CalibreMetadata, ApiError> {
+    let data = fs::read(&path).map_err(|e| anyhow!("Failed to read {path}: {e}"))?;
+    Ok(parse_opf(&xhtml::decode(&data)))
+}
+
+/// Write a book as a Calibre folder, `Author/Title/`, holding a copy of the
+/// book file, `metadata.opf` and `cover.jpg`. Returns the folder.
+///
+/// # Arguments
+/// * `metadata` - Metadata for the OPF; a missing `uuid` identifier is
+///   derived from the title and authors
+/// * `book_path` - The book file to copy
+/// * `cover` - Cover image bytes (JPEG, PNG, GIF or WebP), saved as JPEG
+/// * `library_dir` - Calibre library, or any folder to collect books in
+#[flutter_rust_bridge::frb]
+pub fn export_calibre_book(
+    metadata: CalibreMetadata,
+    book_path: String,
+    cover: Option<Vec<u8>>,
+    library_dir: String,
+) -> Result<String, ApiError> {
+    let title = metadata.title.as_deref().unwrap_or("Unknown");
+    let author = metadata.authors.first().map_or("Unknown", String::as_str);
+    let dir = Path::new(&library_dir)
+        .join(file_name(author))
+        .join(file_name(title));
+    fs::create_dir_all(&dir).map_err(|e| anyhow!("Failed to create {}: {e}", dir.display()))?;
+
+    let extension = Path::new(&book_path)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .ok_or_else(|| anyhow!("{book_path} has no file extension"))?
+        .to_ascii_lowercase();
+    let target = dir.join(format!(
+        "{} - {}.{extension}",
+        file_name(title),
+        file_name(author)
+    ));
+    fs::copy(&book_path, &target).map_err(|e| anyhow!("Failed to copy {book_path}: {e}"))?;
+    if let Some(cover) = &cover {
+        let jpeg = image::to_jpeg(cover, COVER_QUALITY)?;
+        font_converter::write_atomically(&dir.join(COVER_NAME), &jpeg)?;
+    }
+    let opf = write_opf(&metadata, cover.is_some());
+    font_converter::write_atomically(&dir.join(OPF_NAME), opf.as_bytes())?;
+    Ok(dir.to_string_lossy().into_owned())
+}
+
+fn scan(dir: &Path, depth: u32, books: &mut Vec<CalibreBook>, token: &CancelToken) -> Result<()> {
+    token.check()?;
+    let opf = dir.join(OPF_NAME);
+    if opf.is_file() {
+        // A folder that cannot be read is skipped rather than failing
+        // the whole scan.
+        if let Ok(book) = read_folder(dir, &opf) {
+            books.push(book);
+        }
+        return Ok(());
+    }
+    if depth >= MAX_DEPTH {
+        return Ok(());
+    }
+    let entries =
+        fs::read_dir(dir).map_err(|e| anyhow!("Failed to list {}: {e}", dir.display()))?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let hidden = entry.file_name().to_string_lossy().starts_with('.');
+        // Calibre keeps deleted books in `.caltrash`.
+        if !hidden && path.is_dir() {
+            scan(&path, depth + 1, books, token)?;
+        }
+    }
+    Ok(())
+}
+
+fn read_folder(dir: &Path, opf: &Path) -> Result<CalibreBook> {
+    let metadata = parse_opf(&xhtml::decode(&fs::read(opf)?));
+    let mut formats: Vec<CalibreFormat> = fs::read_dir(dir)?
+        .flatten()
+        .filter(|entry| entry.path().is_file())
+        .filter_map(|entry| {
+            let path = entry.path().to_string_lossy().into_owned();
+            let kind = archive::import_kind(&path)?;
+            Some(CalibreFormat { path, kind })
+        })
+        .collect();
+    formats.sort_by(|a, b| a.path.cmp(&b.path));
+    let cover = dir.join(COVER_NAME);
+    Ok(CalibreBook {
+        dir: dir.to_string_lossy().into_owned(),
+        metadata,
+        cover_path: cover
+            .is_file()
+            .then(|| cover.to_string_lossy().into_owned()),
+        formats,
+    })
+}
+
+/// A metadata element, before `refines` are resolved.
+struct Element {
+    name: String,
+    id: Option<String>,
+    scheme: Option<String>,
+    role: Option<String>,
+    file_as: Option<String>,
+    /// `name` or `property` of a meta element.
+    property: Option<String>,
+    /// ID of the element a meta element refines.
+    refines: Option<String>,
+    /// Text content, or a meta element's `content`.
+    value: String,
+}
+
+impl Element {
+    fn new(name: String, start: &BytesStart) -> Self {
+        let attribute = |name| xhtml::attribute(start, name);
+        Self {
+            name,
+            id: attribute("id"),
+            scheme: attribute("scheme"),
+            role: attribute("role"),
+            file_as: attribute("file-as"),
+            property: attribute("name").or_else(|| attribute("property")),
+            refines: attribute("refines").map(|id| id.trim_start_matches('#').to_string()),
+            value: attribute("content").unwrap_or_default(),
+        }
+    }
+}
+
+fn parse_opf(opf: &str) -> CalibreMetadata {
+    let mut reader = xhtml::reader(opf);
+    let mut elements = Vec::new();
+    let mut in_metadata = false;
+    let mut open: Option<Element> = None;
+    loop {
+        let event = match reader.read_event() {
+            Ok(Event::Eof) | Err(_) => break,
+            Ok(event) => event,
+        };
+        match event {
+            Event::Start(start) => match xhtml::local_name(start.name().as_ref()).as_str() {
+                "metadata" => in_metadata = true,
+                name if in_metadata => open = Some(Element::new(name.to_string(), &start)),
+                _ => {}
+            },
+            Event::Empty(start) if in_metadata => {
+                let name = xhtml::local_name(start.name().as_ref());
+                elements.push(Element::new(name, &start));
+            }
+            Event::Text(text) => {
+                if let Some(element) = &mut open {
+                    element.value.push_str(&xhtml::text(&text));
+                }
+            }
+            Event::CData(text) => {
+                if let Some(element) = &mut open {
+                    element.value.push_str(&String::from_utf8_lossy(&text));
+                }
+            }
+            Event::End(end) => match xhtml::local_name(end.name().as_ref()).as_str() {
+                "metadata" => in_metadata = false,
+                name if open.as_ref().is_some_and(|element| element.name == name) => {
+                    elements.extend(open.take());
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+    interpret(&elements)
+}
+
+fn interpret(elements: &[Element]) -> CalibreMetadata {
+    let refined = |element: &Element, property: &str| -> Option<String> {
+        let id = element.id.as_deref()?;
+        elements
+            .iter()
+            .find(|e| e.refines.as_deref() == Some(id) && e.property.as_deref() == Some(property))
+            .map(|e| xhtml::normalize_whitespace(&e.value))
+    };
+    let mut metadata = CalibreMetadata::default();
+    for element in elements {
+        let value = xhtml::normalize_whitespace(&element.value);
+        if value.is_empty() {
+            continue;
+        }
+        let file_as = || {
+            element
+                .file_as
+                .clone()
+                .or_else(|| refined(element, "file-as"))
+        };
+        match element.name.as_str() {
+            "title" if metadata.title.is_none() => {
+                metadata.title = Some(value);
+                metadata.title_sort = metadata.title_sort.take().or_else(file_as);
+            }
+            "creator" => {
+                let role = element.role.clone().or_else(|| refined(element, "role"));
+                if role.is_none_or(|role| role == "aut") {
+                    metadata.authors.push(value);
+                    metadata.author_sort = metadata.author_sort.take().or_else(file_as);
+                }
+            }
+            "subject" => metadata.tags.push(value),
+            "publisher" => {
+                metadata.publisher.get_or_insert(value);
+            }
+            // Descriptions keep their markup and line breaks.
+            "description" => {
+                metadata
+                    .description
+                    .get_or_insert_with(|| element.value.trim().to_string());
+            }
+            "language" => {
+                metadata.language.get_or_insert(value);
+            }
+            // Calibre writes 0101-01-01 for an unknown date.
+            "date" if !value.starts_with("0101-") => {
+                metadata.published.get_or_insert(value);
+            }
+            "identifier" => metadata.identifiers.extend(identifier(element, &value)),
+            "meta" if element.refines.is_none() => match element.property.as_deref() {
+                Some("calibre:series") => metadata.series = Some(value),
+                Some("calibre:series_index") => metadata.series_index = value.parse().ok(),
+                Some("calibre:title_sort") => metadata.title_sort = Some(value),
+                Some("calibre:rating") => {
+                    metadata.rating = value
+                        .parse::<f64>()
+                        .ok()
+                        .map(|rating| rating.round().clamp(0.0, 10.0) as u32);
+                }
+                Some("belongs-to-collection")
+                    if metadata.series.is_none()
+                        && refined(element, "collection-type")
+                            .is_none_or(|kind| kind == "series") =>
+                {
+                    metadata.series_index =
+                        refined(element, "group-position").and_then(|index| index.parse().ok());
+                    metadata.series = Some(value);
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+    metadata
+}
+
+/// An identifier from its `opf:scheme`, or from a `scheme:value` or
+/// `urn:scheme:value` prefix as EPUB 3 writes them.
+fn identifier(element: &Element, value: &str) -> Option<CalibreIdentifier> {
+    if let Some(scheme) = &element.scheme {
+        return Some(CalibreIdentifier {
+            scheme: scheme.to_ascii_lowercase(),
+            value: value.to_string(),
+        });
+    }
+    let value = value.strip_prefix("urn:").unwrap_or(value);
+    let (scheme, rest) = value.split_once(':')?;
+    let known = scheme
+        .bytes()
+        .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-');
+    (known && !rest.is_empty()).then(|| CalibreIdentifier {
+        scheme: scheme.to_ascii_lowercase(),
+        value: rest.to_string(),
+    })
+}
+
+fn write_opf(metadata: &CalibreMetadata, has_cover: bool) -> String {
+    let text = |value: &str| escape(value).into_owned();
+    let title = metadata.title.as_deref().unwrap_or("Unknown");
+    let uuid = metadata
+        .identifiers
+        .iter()
+        .find(|id| id.scheme == "uuid")
+        .map(|id| id.value.clone())
+        .unwrap_or_else(|| {
+            let urn = epub_export::generated_identifier(title, &metadata.authors);
+            urn.trim_start_matches("urn:uuid:").to_string()
+        });
+
+    let mut lines = vec![
+        format!(
+            "<dc:identifier opf:scheme=\"uuid\" id=\"uuid_id\">{}</dc:identifier>",
+            text(&uuid)
+        ),
+        format!("<dc:title>{}</dc:title>", text(title)),
+    ];
+    let file_as = metadata
+        .author_sort
+        .as_deref()
+        .map(|sort| format!(" opf:file-as=\"{}\"", text(sort)))
+        .unwrap_or_default();
+    for author in &metadata.authors {
+        lines.push(format!(
+            "<dc:creator{file_as} opf:role=\"aut\">{}</dc:creator>",
+            text(author)
+        ));
+    }
+    for (name, value) in [
+        ("date", &metadata.published),
+        ("description", &metadata.description),
+        ("publisher", &metadata.publisher),
+    ] {
+        if let Some(value) = value {
+            lines.push(format!("<dc:{name}>{}</dc:{name}>", text(value)));
+        }
+    }
+    for id in metadata.identifiers.iter().filter(|id| id.scheme != "uuid") {
+        lines.push(format!(
+            "<dc:identifier opf:scheme=\"{}\">{}</dc:identifier>",
+            text(&id.scheme.to_ascii_uppercase()),
+            text(&id.value)
+        ));
+    }
+    if let Some(language) = &metadata.language {
+        lines.push(format!("<dc:language>{}</dc:language>", text(language)));
+    }
+    for tag in &metadata.tags {
+        lines.push(format!("<dc:subject>{}</dc:subject>", text(tag)));
+    }
+    let mut meta = |name: &str, content: String| {
+        lines.push(format!(
+            "<meta name=\"calibre:{name}\" content=\"{}\"/>",
+            text(&content)
+        ));
+    };
+    if let Some(series) = &metadata.series {
+        meta("series", series.clone());
+        meta(
+            "series_index",
+            metadata.series_index.unwrap_or(1.0).to_string(),
+        );
+    }
+    if let Some(rating) = metadata.rating {
+        meta("rating", rating.min(10).to_string());
+    }
+    if let Some(title_sort) = &metadata.title_sort {
+        meta("title_sort", title_sort.clone());
+    }
+
+    let guide = if has_cover {
+        format!(
+            "<guide>\n<reference type=\"cover\" title=\"Cover\" href=\"{COVER_NAME}\"/>\n</guide>\n"
+        )
+    } else {
+        String::new()
+    };
+    format!(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n\
+         <package xmlns=\"http://www.idpf.org/2007/opf\" unique-identifier=\"uuid_id\" version=\"2.0\">\n\
+         <metadata xmlns:dc=\"http://purl.org/dc/elements/1.1/\" xmlns:opf=\"http://www.idpf.org/2007/opf\">\n\
+         {}\n</metadata>\n{guide}</package>\n",
+        lines.join("\n")
+    )
+}
+
+/// `text` made safe as a file name on every platform.
+fn file_name(text: &str) -> String {
+    let cleaned: String = text
+        .chars()
+        .map(|c| {
+            if c.is_control() || "<>:\"/\\|?*".contains(c) {
+                '_'
+            } else {
+                c
+            }
+        })
+        .take(MAX_NAME_CHARS)
+        .collect();
+    // Windows drops trailing dots and spaces.
+    let trimmed = cleaned.trim().trim_end_matches(['.', ' ']);
+    if trimmed.is_empty() {
+        "_".to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_dirs::temp_dir;
+
+    const CALIBRE_OPF: &str = r#"<?xml version='1.0' encoding='utf-8'?>
+<package xmlns="http://www.idpf.org/2007/opf" unique-identifier="uuid_id" version="2.0">
+    <metadata xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:opf="http://www.idpf.org/2007/opf">
+        <dc:identifier opf:scheme="calibre" id="calibre_id">42</dc:identifier>
+        <dc:identifier opf:scheme="uuid" id="uuid_id">0b6f2a5e-8c1d-4e2a-9f3b-5d7c1a2b3c4d</dc:identifier>
+        <dc:title>三体</dc:title>
+        <dc:creator opf:file-as="Liu, Cixin" opf:role="aut">刘慈欣</dc:creator>
+        <dc:creator opf:role="trl">Ken Liu</dc:creator>
+        <dc:contributor opf:file-as="calibre" opf:role="bkp">calibre (7.0.0)</dc:contributor>
+        <dc:date>0101-01-01T00:00:00+00:00</dc:date>
+        <dc:description>&lt;p&gt;文化大革命如火如荼进行的同时。&lt;/p&gt;</dc:description>
+        <dc:publisher>重庆出版社</dc:publisher>
+        <dc:identifier opf:scheme="ISBN">9787536692930</dc:identifier>
+        <dc:language>zho</dc:language>
+        <dc:subject>科幻</dc:subject>
+        <dc:subject>硬科幻</dc:subject>
+        <meta name="calibre:series" content="地球往事"/>
+        <meta name="calibre:series_index" content="1.0"/>
+        <meta name="calibre:rating" content="8.0"/>
+        <meta name="calibre:title_sort" content="三体"/>
+    </metadata>
+    <guide>
+        <reference type="cover" title="Cover" href="cover.jpg"/>
+    </guide>
+</package>"#;
+
+    #[test]
+    fn test_reads_calibre_opf() {
+        let metadata = parse_opf(CALIBRE_OPF);
+        assert_eq!(metadata.title.as_deref(), Some("三体"));
+        assert_eq!(metadata.authors, ["刘慈欣"]);
+        assert_eq!(metadata.author_sort.as_deref(), Some("Liu, Cixin"));
+        assert_eq!(metadata.series.as_deref(), Some("地球往事"));
+        assert_eq!(metadata.series_index, Some(1.0));
+        assert_eq!(metadata.tags, ["科幻", "硬科幻"]);
+        assert_eq!(metadata.rating, Some(8));
+        assert_eq!(metadata.published, None);
+        assert_eq!(
+            metadata.description.as_deref(),
+            Some("<p>文化大革命如火如荼进行的同时。</p>")
+        );
+        let schemes: Vec<_> = metadata.identifiers.iter().map(|id| &id.scheme).collect();
+        assert_eq!(schemes, ["calibre", "uuid", "isbn"]);
+    }
+
+    #[test]
+    fn test_reads_epub3_collections_and_refines() {
+        let opf = r##"<package xmlns="http://www.idpf.org/2007/opf" version="3.0">
+            <metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+                <dc:identifier>urn:isbn:9787536692930</dc:identifier>
+                <dc:title id="t">The Three-Body Problem</dc:title>
+                <meta refines="#t" property="file-as">Three-Body Problem, The</meta>
+                <dc:creator id="a">Cixin Liu</dc:creator>
+                <meta refines="#a" property="role" scheme="marc:relators">aut</meta>
+                <meta refines="#a" property="file-as">Liu, Cixin</meta>
+                <dc:creator id="e">Some Editor</dc:creator>
+                <meta refines="#e" property="role">edt</meta>
+                <meta property="belongs-to-collection" id="c">Remembrance of Earth's Past</meta>
+                <meta refines="#c" property="collection-type">series</meta>
+                <meta refines="#c" property="group-position">1</meta>
+            </metadata>
+        </package>"##;
+        let metadata = parse_opf(opf);
+        assert_eq!(
+            metadata.title_sort.as_deref(),
+            Some("Three-Body Problem, The")
+        );
+        assert_eq!(metadata.authors, ["Cixin Liu"]);
+        assert_eq!(metadata.author_sort.as_deref(), Some("Liu, Cixin"));
+        assert_eq!(
+            metadata.series.as_deref(),
+            Some("Remembrance of Earth's Past")
+        );
+        assert_eq!(metadata.series_index, Some(1.0));
+        assert_eq!(metadata.identifiers[0].scheme, "isbn");
+        assert_eq!(metadata.identifiers[0].value, "9787536692930");
+    }
+
+    #[test]
+    fn test_export_round_trips_through_scan() {
+        let root = temp_dir("calibre");
+        let book = root.join("source.EPUB");
+        fs::write(&book, b"epub").unwrap();
+        let mut png = Vec::new();
+        ::image::RgbImage::new(4, 6)
+            .write_to(
+                &mut std::io::Cursor::new(&mut png),
+                ::image::ImageFormat::Png,
+            )
+            .unwrap();
+
+        let mut metadata = parse_opf(CALIBRE_OPF);
+        metadata.title = Some("三体: 地球往事?".to_string());
+        let library = root.join("library");
+        let dir = export_calibre_book(
+            metadata.clone(),
+            book.to_string_lossy().into_owned(),
+            Some(png),
+            library.to_string_lossy().into_owned(),
+        )
+        .unwrap();
+        assert!(dir.ends_with("刘慈欣/三体_ 地球往事_"), "{dir}");
+        assert!(fs::read(Path::new(&dir).join(COVER_NAME))
+            .unwrap()
+            .starts_with(b"\xFF\xD8\xFF"));
+        // Trash is not part of the library.
+        fs::create_dir_all(library.join(".caltrash/b/c")).unwrap();
+        fs::write(library.join(".caltrash/b/c").join(OPF_NAME), CALIBRE_OPF).unwrap();
+
+        let token = task::cancel_token(None).unwrap();
+        let mut books = Vec::new();
+        scan(&library, 0, &mut books, &token).unwrap();
+        assert_eq!(books.len(), 1);
+        let read = &books[0].metadata;
+        assert_eq!(read.title, metadata.title);
+        assert_eq!(
+            (&read.series, read.series_index),
+            (&metadata.series, Some(1.0))
+        );
+        assert_eq!(read.tags, metadata.tags);
+        assert_eq!(read.author_sort, metadata.author_sort);
+        assert_eq!(read.description, metadata.description);
+        assert_eq!(read.rating, Some(8));
+        assert_eq!(read.identifiers.len(), 3);
+        assert!(books[0].cover_path.is_some());
+        assert_eq!(books[0].formats.len(), 1);
+        assert_eq!(books[0].formats[0].kind, ImportKind::Epub);
+        assert!(books[0].formats[0].path.ends_with(".epub"));
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,629 @@
+//! On-disk cache of chapter text, optionally encrypted at rest.
+//!
+//! Chapters are stored under an app-provided directory, one folder per book
+//! named by the BLAKE3 hash of the book ID, so neither titles nor IDs show
+//! up in file names. With a key from the platform keystore, entries are
+//! sealed with AES-256-GCM under a random nonce, bound to their book and
+//! chapter so files cannot be swapped around. Plaintext entries left from
+//! before encryption was enabled are still read, and are encrypted the
+//! first time they are; `migrate_chapter_cache` converts them all at once,
+//! and also re-encrypts under a new key or decrypts when encryption is
+//! turned off.
+//!
+//! Each chapter is stored with its summary and its fingerprint, so an
+//! update check only needs the freshly fetched text of the chapters it
+//! samples to tell which were edited.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+use aes_gcm::aead::{Aead, Generate, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use anyhow::{anyhow, Result};
+
+use crate::api::error::ApiError;
+use crate::api::fingerprint::{self, ChapterFingerprint};
+use crate::api::font_converter;
+use crate::api::sanitize::sanitize;
+use crate::api::summary;
+
+const ENTRY_EXTENSION: &str = "chapter";
+const SUMMARY_EXTENSION: &str = "summary";
+const FINGERPRINT_EXTENSION: &str = "fingerprint";
+
+/// Starts every encrypted entry; chapter text never starts with a NUL.
+const MAGIC: &[u8] = b"\0NVCE1";
+
+const NONCE_LEN: usize = 12;
+
+static CHAPTER_CACHE: Mutex<Option<ChapterCache>> = Mutex::new(None);
+
+/// Outcome of `migrate_chapter_cache`.
+#[derive(Debug, Clone)]
+pub struct ChapterCacheMigration {
+    /// Entries rewritten in the current format.
+    pub converted: u32,
+    /// Entries already in the current format.
+    pub unchanged: u32,
+    /// Entries that could not be read with either key and were deleted.
+    pub removed: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChapterChange {
+    Unchanged,
+    /// The text differs a little, e.g. a silently fixed typo or paragraph.
+    Edited,
+    /// The text has little in common with the cached copy, e.g. because
+    /// chapters were inserted before it.
+    Replaced,
+    /// The chapter is not cached.
+    Uncached,
+}
+
+/// A chapter fetched for an update check.
+#[derive(Debug, Clone)]
+pub struct FetchedChapter {
+    pub chapter_index: u32,
+    pub text: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChapterUpdate {
+    pub chapter_index: u32,
+    pub change: ChapterChange,
+    /// Estimated similarity to the cached copy, from 0 to 1; 0 if uncached.
+    pub similarity: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChapterUpdateReport {
+    /// Chapters of the fresh table of contents after the last cached one.
+    pub new_chapters: Vec<u32>,
+    /// One per fetched chapter, in the order given.
+    pub chapters: Vec<ChapterUpdate>,
+}
+
+/// Enable the chapter cache. Calling this again switches to the new
+/// directory or key.
+///
+/// # Arguments
+/// * `cache_dir` - Directory owned by the cache; created if missing
+/// * `key` - 32-byte AES-256 key to encrypt entries with, or `None` to
+///   store plaintext
+#[flutter_rust_bridge::frb]
+pub fn init_chapter_cache(cache_dir: String, key: Option<Vec<u8>>) -> Result<(), ApiError> {
+    let cache = ChapterCache::new(PathBuf::from(cache_dir), key.as_deref())?;
+    *lock() = Some(cache);
+    Ok(())
+}
+
+/// Store the text of a chapter, replacing any cached copy. The text is
+/// sanitized first, as by `sanitize_text`, and its summary is cached with
+/// it using the options from `set_summary_options`.
+///
+/// # Arguments
+/// * `book_id` - Library ID of the book
+/// * `chapter_index` - Position of the chapter in the table of contents
+/// * `text` - Chapter content
+#[flutter_rust_bridge::frb]
+pub fn write_cached_chapter(
+    book_id: String,
+    chapter_index: u32,
+    text: String,
+) -> Result<(), ApiError> {
+    Ok(current()?.write(&book_id, chapter_index, &sanitize(&text))?)
+}
+
+/// Read a cached chapter, or `None` if it is not cached.
+///
+/// # Arguments
+/// * `book_id` - Library ID of the book
+/// * `chapter_index` - Position of the chapter in the table of contents
+#[flutter_rust_bridge::frb]
+pub fn read_cached_chapter(
+    book_id: String,
+    chapter_index: u32,
+) -> Result<Option<String>, ApiError> {
+    Ok(current()?.read(&book_id, chapter_index)?)
+}
+
+/// The cached summary of a chapter, or `None` if the chapter is not
+/// cached. Chapters cached before summaries were are summarized now.
+///
+/// # Arguments
+/// * `book_id` - Library ID of the book
+/// * `chapter_index` - Position of the chapter in the table of contents
+#[flutter_rust_bridge::frb]
+pub fn read_cached_summary(
+    book_id: String,
+    chapter_index: u32,
+) -> Result<Option<String>, ApiError> {
+    Ok(current()?.summary(&book_id, chapter_index)?)
+}
+
+/// The fingerprint of a cached chapter, or `None` if it is not cached.
+/// Chapters cached before fingerprints were are fingerprinted now.
+///
+/// # Arguments
+/// * `book_id` - Library ID of the book
+/// * `chapter_index` - Position of the chapter in the table of contents
+#[flutter_rust_bridge::frb]
+pub fn read_cached_fingerprint(
+    book_id: String,
+    chapter_index: u32,
+) -> Result<Option<ChapterFingerprint>, ApiError> {
+    Ok(current()?.fingerprint(&book_id, chapter_index)?)
+}
+
+/// Compare a book's cache with a freshly fetched table of contents and
+/// the text of some of its chapters, such as the last few cached ones.
+/// The cache is left as it is.
+///
+/// # Arguments
+/// * `book_id` - Library ID of the book
+/// * `chapter_count` - Number of chapters in the fresh table of contents
+/// * `chapters` - Freshly fetched chapters to compare with the cache
+#[flutter_rust_bridge::frb]
+pub fn check_chapter_updates(
+    book_id: String,
+    chapter_count: u32,
+    chapters: Vec<FetchedChapter>,
+) -> Result<ChapterUpdateReport, ApiError> {
+    Ok(current()?.check_updates(&book_id, chapter_count, &chapters)?)
+}
+
+/// Delete every cached chapter of a book. Returns the number deleted.
+#[flutter_rust_bridge::frb]
+pub fn remove_cached_book(book_id: String) -> Result<u32, ApiError> {
+    let cache = current()?;
+    let dir = cache.book_dir(&book_id);
+    let count = entries(&dir, &[ENTRY_EXTENSION])?.len() as u32;
+    match fs::remove_dir_all(&dir) {
+        Ok(()) => Ok(count),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(0),
+        Err(e) => Err(anyhow!("Failed to remove {}: {e}", dir.display()).into()),
+    }
+}
+
+/// Rewrite every entry with the current key: encrypt plaintext entries,
+/// re-encrypt ones sealed with `previous_key`, or decrypt them all when the
+/// cache was initialized without a key.
+///
+/// # Arguments
+/// * `previous_key` - Key the cache was encrypted with before, if any
+#[flutter_rust_bridge::frb]
+pub fn migrate_chapter_cache(
+    previous_key: Option<Vec<u8>>,
+) -> Result<ChapterCacheMigration, ApiError> {
+    let cache = current()?;
+    let previous = previous_key.as_deref().map(cipher).transpose()?;
+    Ok(cache.migrate(previous.as_ref())?)
+}
+
+fn lock() -> MutexGuard<'static, Option<ChapterCache>> {
+    CHAPTER_CACHE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn current() -> Result<ChapterCache> {
+    lock()
+        .clone()
+        .ok_or_else(|| anyhow!("Chapter cache is not initialized"))
+}
+
+fn cipher(key: &[u8]) -> Result<Aes256Gcm> {
+    Aes256Gcm::new_from_slice(key)
+        .map_err(|_| anyhow!("Chapter cache key must be 32 bytes, got {}", key.len()))
+}
+
+#[derive(Clone)]
+struct ChapterCache {
+    dir: PathBuf,
+    cipher: Option<Aes256Gcm>,
+}
+
+impl ChapterCache {
+    fn new(dir: PathBuf, key: Option<&[u8]>) -> Result<Self> {
+        let cipher = key.map(cipher).transpose()?;
+        fs::create_dir_all(&dir).map_err(|e| anyhow!("Failed to create {}: {e}", dir.display()))?;
+        Ok(Self { dir, cipher })
+    }
+
+    fn book_dir(&self, book_id: &str) -> PathBuf {
+        self.dir
+            .join(blake3::hash(book_id.as_bytes()).to_hex().as_str())
+    }
+
+    fn entry_path(&self, book_id: &str, chapter_index: u32) -> PathBuf {
+        self.book_dir(book_id)
+            .join(format!("{chapter_index}.{ENTRY_EXTENSION}"))
+    }
+
+    fn summary_path(&self, book_id: &str, chapter_index: u32) -> PathBuf {
+        self.book_dir(book_id)
+            .join(format!("{chapter_index}.{SUMMARY_EXTENSION}"))
+    }
+
+    fn fingerprint_path(&self, book_id: &str, chapter_index: u32) -> PathBuf {
+        self.book_dir(book_id)
+            .join(format!("{chapter_index}.{FINGERPRINT_EXTENSION}"))
+    }
+
+    fn write(&self, book_id: &str, chapter_index: u32, text: &str) -> Result<()> {
+        self.write_entry(&self.entry_path(book_id, chapter_index), text)?;
+        self.write_entry(
+            &self.fingerprint_path(book_id, chapter_index),
+            &fingerprint::encode(&fingerprint::fingerprint(text)),
+        )?;
+        // Chapters with nothing to summarize get no summary entry, and are
+        // summarized again if asked for one.
+        let path = self.summary_path(book_id, chapter_index);
+        let summary = summary::summarize(text, &summary::options());
+        if !summary.is_empty() {
+            return self.write_entry(&path, &summary);
+        }
+        match fs::remove_file(&path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                Err(anyhow!("Failed to remove {}: {e}", path.display()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn read(&self, book_id: &str, chapter_index: u32) -> Result<Option<String>> {
+        self.read_entry(&self.entry_path(book_id, chapter_index))
+    }
+
+    fn summary(&self, book_id: &str, chapter_index: u32) -> Result<Option<String>> {
+        let path = self.summary_path(book_id, chapter_index);
+        if let Some(summary) = self.read_entry(&path)? {
+            return Ok(Some(summary));
+        }
+        let Some(text) = self.read(book_id, chapter_index)? else {
+            return Ok(None);
+        };
+        let summary = summary::summarize(&text, &summary::options());
+        if !summary.is_empty() {
+            self.write_entry(&path, &summary)?;
+        }
+        Ok(Some(summary))
+    }
+
+    fn fingerprint(&self, book_id: &str, chapter_index: u32) -> Result<Option<ChapterFingerprint>> {
+        let path = self.fingerprint_path(book_id, chapter_index);
+        let stored = self.read_entry(&path)?;
+        if let Some(fingerprint) = stored.as_deref().and_then(fingerprint::decode) {
+            return Ok(Some(fingerprint));
+        }
+        let Some(text) = self.read(book_id, chapter_index)? else {
+            return Ok(None);
+        };
+        let fingerprint = fingerprint::fingerprint(&text);
+        self.write_entry(&path, &fingerprint::encode(&fingerprint))?;
+        Ok(Some(fingerprint))
+    }
+
+    fn check_updates(
+        &self,
+        book_id: &str,
+        chapter_count: u32,
+        chapters: &[FetchedChapter],
+    ) -> Result<ChapterUpdateReport> {
+        let last_cached = entries(&self.book_dir(book_id), &[ENTRY_EXTENSION])?
+            .iter()
+            .filter_map(|path| path.file_stem()?.to_str()?.parse::<u32>().ok())
+            .max();
+        let first_new = last_cached.map_or(0, |index| index + 1);
+        let mut updates = Vec::with_capacity(chapters.len());
+        for chapter in chapters {
+            let (change, similarity) = match self.fingerprint(book_id, chapter.chapter_index)? {
+                None => (ChapterChange::Uncached, 0.0),
+                Some(cached) => {
+                    let similarity =
+                        fingerprint::similarity(&cached, &fingerprint::fingerprint(&chapter.text));
+                    let change = if similarity == 1.0 {
+                        ChapterChange::Unchanged
+                    } else if similarity >= fingerprint::EDITED_SIMILARITY {
+                        ChapterChange::Edited
+                    } else {
+                        ChapterChange::Replaced
+                    };
+                    (change, similarity)
+                }
+            };
+            updates.push(ChapterUpdate {
+                chapter_index: chapter.chapter_index,
+                change,
+                similarity,
+            });
+        }
+        Ok(ChapterUpdateReport {
+            new_chapters: (first_new..chapter_count).collect(),
+            chapters: updates,
+        })
+    }
+
+    fn write_entry(&self, path: &Path, text: &str) -> Result<()> {
+        let dir = path.parent().unwrap();
+        fs::create_dir_all(dir).map_err(|e| anyhow!("Failed to create {}: {e}", dir.display()))?;
+        let data = match &self.cipher {
+            Some(cipher) => seal(cipher, path, text.as_bytes())?,
+            None => text.as_bytes().to_vec(),
+        };
+        font_converter::write_atomically(path, &data)
+    }
+
+    fn read_entry(&self, path: &Path) -> Result<Option<String>> {
+        let data = match fs::read(path) {
+            Ok(data) => data,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(anyhow!("Failed to read {}: {e}", path.display())),
+        };
+        let encrypted = data.starts_with(MAGIC);
+        let plain = if encrypted {
+            let cipher = self
+                .cipher
+                .as_ref()
+                .ok_or_else(|| anyhow!("Cached chapter is encrypted but no key is set"))?;
+            open(cipher, path, &data)?
+        } else {
+            data
+        };
+        let text = String::from_utf8(plain)
+            .map_err(|e| anyhow!("Cached chapter is not valid UTF-8: {e}"))?;
+        if !encrypted && self.cipher.is_some() {
+            // Upgrade an entry from before encryption was enabled.
+            self.write_entry(path, &text)?;
+        }
+        Ok(Some(text))
+    }
+
+    fn migrate(&self, previous: Option<&Aes256Gcm>) -> Result<ChapterCacheMigration> {
+        let mut migration = ChapterCacheMigration {
+            converted: 0,
+            unchanged: 0,
+            removed: 0,
+        };
+        let books = fs::read_dir(&self.dir)
+            .map_err(|e| anyhow!("Failed to read {}: {e}", self.dir.display()))?;
+        for book in books.flatten().filter(|item| item.path().is_dir()) {
+            let extensions = [ENTRY_EXTENSION, SUMMARY_EXTENSION, FINGERPRINT_EXTENSION];
+            for path in entries(&book.path(), &extensions)? {
+                let Ok(data) = fs::read(&path) else {
+                    continue;
+                };
+                let plain = if !data.starts_with(MAGIC) {
+                    if self.cipher.is_none() {
+                        migration.unchanged += 1;
+                        continue;
+                    }
+                    Some(data)
+                } else {
+                    let current = self.cipher.as_ref().map(|c| open(c, &path, &data));
+                    if matches!(current, Some(Ok(_))) {
+                        migration.unchanged += 1;
+                        continue;
+                    }
+                    previous.and_then(|c| open(c, &path, &data).ok())
+                };
+                let Some(plain) = plain else {
+                    fs::remove_file(&path)
+                        .map_err(|e| anyhow!("Failed to remove {}: {e}", path.display()))?;
+                    migration.removed += 1;
+                    continue;
+                };
+                let data = match &self.cipher {
+                    Some(cipher) => seal(cipher, &path, &plain)?,
+                    None => plain,
+                };
+                font_converter::write_atomically(&path, &data)?;
+                migration.converted += 1;
+            }
+        }
+        Ok(migration)
+    }
+}
+
+/// Entry files with one of `extensions` in a book folder.
+fn entries(dir: &Path, extensions: &[&str]) -> Result<Vec<PathBuf>> {
+    let items = match fs::read_dir(dir) {
+        Ok(items) => items,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(anyhow!("Failed to read {}: {e}", dir.display())),
+    };
+    Ok(items
+        .flatten()
+        .map(|item| item.path())
+        .filter(|path| {
+            let extension = path.extension().and_then(|ext| ext.to_str());
+            extension.is_some_and(|ext| extensions.contains(&ext))
+        })
+        .collect())
+}
+
+/// Associated data tying an entry to its place: the book folder and file
+/// name.
+fn associated_data(path: &Path) -> Vec<u8> {
+    let part = |p: Option<&std::ffi::OsStr>| p.map(|s| s.to_string_lossy().into_owned());
+    let book = part(path.parent().and_then(Path::file_name)).unwrap_or_default();
+    let file = part(path.file_name()).unwrap_or_default();
+    format!("{book}/{file}").into_bytes()
+}
+
+/// Encrypt an entry as the magic, the nonce, then ciphertext and tag.
+fn seal(cipher: &Aes256Gcm, path: &Path, plain: &[u8]) -> Result<Vec<u8>> {
+    let nonce = Nonce::generate();
+    let aad = associated_data(path);
+    let sealed = cipher
+        .encrypt(
+            &nonce,
+            Payload {
+                msg: plain,
+                aad: &aad,
+            },
+        )
+        .map_err(|_| anyhow!("Failed to encrypt chapter"))?;
+    let mut data = Vec::with_capacity(MAGIC.len() + NONCE_LEN + sealed.len());
+    data.extend_from_slice(MAGIC);
+    data.extend_from_slice(&nonce);
+    data.extend_from_slice(&sealed);
+    Ok(data)
+}
+
+fn open(cipher: &Aes256Gcm, path: &Path, data: &[u8]) -> Result<Vec<u8>> {
+    let body = &data[MAGIC.len()..];
+    if body.len() < NONCE_LEN {
+        return Err(anyhow!("Cached chapter is truncated"));
+    }
+    let (nonce, sealed) = body.split_at(NONCE_LEN);
+    let nonce = Nonce::try_from(nonce).map_err(|_| anyhow!("Cached chapter is truncated"))?;
+    let aad = associated_data(path);
+    cipher
+        .decrypt(
+            &nonce,
+            Payload {
+                msg: sealed,
+                aad: &aad,
+            },
+        )
+        .map_err(|_| anyhow!("Failed to decrypt cached chapter: wrong key or corrupt data"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_dirs::temp_dir;
+
+    const KEY: [u8; 32] = [7; 32];
+    const OTHER_KEY: [u8; 32] = [9; 32];
+
+    #[test]
+    fn test_encrypted_round_trip() {
+        let dir = temp_dir("round_trip");
+        let cache = ChapterCache::new(dir.clone(), Some(&KEY[..])).unwrap();
+        cache.write("book", 3, "第三章 秘密").unwrap();
+        let raw = fs::read(cache.entry_path("book", 3)).unwrap();
+        assert!(raw.starts_with(MAGIC));
+        assert!(!String::from_utf8_lossy(&raw).contains("秘密"));
+        assert_eq!(
+            cache.read("book", 3).unwrap().as_deref(),
+            Some("第三章 秘密")
+        );
+        assert_eq!(cache.read("book", 4).unwrap(), None);
+        cache
+            .write("book", 5, "夜里下着大雨，他独自守在城门口。")
+            .unwrap();
+        assert!(fs::read(cache.summary_path("book", 5))
+            .unwrap()
+            .starts_with(MAGIC));
+        assert_eq!(
+            cache.summary("book", 5).unwrap().as_deref(),
+            Some("夜里下着大雨，他独自守在城门口。")
+        );
+        assert_eq!(cache.summary("book", 3).unwrap().as_deref(), Some(""));
+        assert_eq!(cache.summary("book", 4).unwrap(), None);
+
+        // A file moved to another chapter fails authentication.
+        fs::copy(cache.entry_path("book", 3), cache.entry_path("book", 4)).unwrap();
+        assert!(cache.read("book", 4).is_err());
+        let wrong = ChapterCache::new(dir.clone(), Some(&OTHER_KEY[..])).unwrap();
+        assert!(wrong.read("book", 3).is_err());
+        let plain = ChapterCache::new(dir.clone(), None).unwrap();
+        assert!(plain.read("book", 3).is_err());
+        assert!(ChapterCache::new(dir.clone(), Some(&[0; 16][..])).is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_upgrades_plaintext_on_read() {
+        let dir = temp_dir("upgrade");
+        let plain = ChapterCache::new(dir.clone(), None).unwrap();
+        plain.write("book", 0, "序章").unwrap();
+        assert_eq!(
+            fs::read(plain.entry_path("book", 0)).unwrap(),
+            "序章".as_bytes()
+        );
+
+        let cache = ChapterCache::new(dir.clone(), Some(&KEY[..])).unwrap();
+        assert_eq!(cache.read("book", 0).unwrap().as_deref(), Some("序章"));
+        assert!(fs::read(cache.entry_path("book", 0))
+            .unwrap()
+            .starts_with(MAGIC));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_check_updates() {
+        let dir = temp_dir("updates");
+        let cache = ChapterCache::new(dir.clone(), Some(&KEY[..])).unwrap();
+        let chapter = "天色渐晚，城门外的行人越来越少。他把信折好，放进怀里，转身走进了风雪之中。";
+        cache.write("book", 0, chapter).unwrap();
+        cache.write("book", 1, chapter).unwrap();
+        cache
+            .write("book", 2, "远处传来钟声，一声接着一声。")
+            .unwrap();
+        // Entries cached before fingerprints are fingerprinted on demand.
+        fs::remove_file(cache.fingerprint_path("book", 1)).unwrap();
+
+        let fetched = |chapter_index, text: &str| FetchedChapter {
+            chapter_index,
+            text: text.to_string(),
+        };
+        let report = cache
+            .check_updates(
+                "book",
+                5,
+                &[
+                    fetched(0, &format!("  {chapter}\n")),
+                    fetched(1, &chapter.replace("越来越少", "越来越多")),
+                    fetched(2, "The next morning the gates opened early."),
+                    fetched(3, chapter),
+                ],
+            )
+            .unwrap();
+        let changes: Vec<ChapterChange> = report.chapters.iter().map(|u| u.change).collect();
+        assert_eq!(
+            changes,
+            [
+                ChapterChange::Unchanged,
+                ChapterChange::Edited,
+                ChapterChange::Replaced,
+                ChapterChange::Uncached
+            ]
+        );
+        assert_eq!(report.new_chapters, [3, 4]);
+        assert!(cache.fingerprint_path("book", 1).exists());
+        assert_eq!(cache.fingerprint("book", 3).unwrap(), None);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_migration() {
+        let dir = temp_dir("migrate");
+        let plain = ChapterCache::new(dir.clone(), None).unwrap();
+        plain.write("a", 0, "一").unwrap();
+        plain.write("b", 0, "二").unwrap();
+        let old = ChapterCache::new(dir.clone(), Some(&OTHER_KEY[..])).unwrap();
+        old.write("b", 1, "三").unwrap();
+        let stray = ChapterCache::new(dir.clone(), Some(&[1; 32][..])).unwrap();
+        stray.write("c", 0, "四").unwrap();
+
+        let cache = ChapterCache::new(dir.clone(), Some(&KEY[..])).unwrap();
+        let migration = cache.migrate(Some(&cipher(&OTHER_KEY).unwrap())).unwrap();
+        // Each chapter has its text and fingerprint entries.
+        assert_eq!(
+            (migration.converted, migration.unchanged, migration.removed),
+            (6, 0, 2)
+        );
+        assert_eq!(cache.read("b", 1).unwrap().as_deref(), Some("三"));
+        assert_eq!(cache.read("c", 0).unwrap(), None);
+
+        // Turning encryption off decrypts everything.
+        let cache = ChapterCache::new(dir.clone(), None).unwrap();
+        let migration = cache.migrate(Some(&cipher(&KEY).unwrap())).unwrap();
+        assert_eq!((migration.converted, migration.removed), (6, 0));
+        assert_eq!(fs::read(cache.entry_path("a", 0)).unwrap(), "一".as_bytes());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,285 @@
+//! Paragraph-level comparison of two versions of a chapter.
+//!
+//! Paragraphs are lines of text with surrounding whitespace trimmed; blank
+//! lines are ignored, so re-wrapped or re-indented text compares equal.
+//! Unchanged paragraphs are found with a longest common subsequence, and
+//! within each changed stretch a removed paragraph is paired with a later
+//! added one when they share most of their character bigrams.
+
+use std::collections::HashMap;
+
+/// Paragraphs with at least this bigram similarity count as modified
+/// rather than deleted and inserted.
+const MODIFIED_SIMILARITY: f32 = 0.5;
+
+/// Largest table the LCS may use; bigger changed stretches are reported
+/// as wholly replaced.
+const MAX_LCS_CELLS: usize = 4_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffHunkKind {
+    Insert,
+    Delete,
+    Modify,
+}
+
+/// One changed paragraph. Indexes count non-blank paragraphs; for an
+/// insert `old_index` is the old paragraph it was inserted before, and for
+/// a delete `new_index` is the new paragraph that now follows the gap.
+#[derive(Debug, Clone)]
+pub struct DiffHunk {
+    pub kind: DiffHunkKind,
+    pub old_index: u32,
+    pub new_index: u32,
+    /// Empty for inserts.
+    pub old_text: String,
+    /// Empty for deletes.
+    pub new_text: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChapterDiff {
+    /// Character-weighted similarity of the two versions, from 0 to 1.
+    pub similarity: f32,
+    pub unchanged_paragraphs: u32,
+    /// Changes in text order.
+    pub hunks: Vec<DiffHunk>,
+}
+
+/// Compare two versions of a chapter's text.
+#[flutter_rust_bridge::frb]
+pub fn diff_chapters(old: String, new: String) -> ChapterDiff {
+    let old = paragraphs(&old);
+    let new = paragraphs(&new);
+    let total_chars = old.iter().chain(&new).map(|p| char_len(p)).sum::<usize>();
+
+    let mut hunks = Vec::new();
+    let mut matched_chars = 0.0;
+    let mut unchanged = 0;
+    let (mut old_start, mut new_start) = (0, 0);
+    for (old_index, new_index) in common_paragraphs(&old, &new)
+        .into_iter()
+        .chain([(old.len(), new.len())])
+    {
+        matched_chars += pair_changes(
+            &old,
+            &new,
+            (old_start, old_index),
+            (new_start, new_index),
+            &mut hunks,
+        );
+        if old_index < old.len() {
+            matched_chars += 2.0 * char_len(old[old_index]) as f32;
+            unchanged += 1;
+        }
+        (old_start, new_start) = (old_index + 1, new_index + 1);
+    }
+
+    ChapterDiff {
+        similarity: if total_chars == 0 {
+            1.0
+        } else {
+            matched_chars / total_chars as f32
+        },
+        unchanged_paragraphs: unchanged,
+        hunks,
+    }
+}
+
+pub(crate) fn paragraphs(text: &str) -> Vec<&str> {
+    text.lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+fn char_len(text: &str) -> usize {
+    text.chars().count()
+}
+
+/// Index pairs of equal paragraphs, in order.
+fn common_paragraphs(old: &[&str], new: &[&str]) -> Vec<(usize, usize)> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_middle = &old[prefix..old.len() - suffix];
+    let new_middle = &new[prefix..new.len() - suffix];
+
+    let mut pairs: Vec<(usize, usize)> = (0..prefix).map(|i| (i, i)).collect();
+    if old_middle.len() * new_middle.len() <= MAX_LCS_CELLS {
+        pairs.extend(
+            lcs(old_middle, new_middle)
+                .into_iter()
+                .map(|(a, b)| (prefix + a, prefix + b)),
+        );
+    }
+    pairs.extend((0..suffix).map(|i| (old.len() - suffix + i, new.len() - suffix + i)));
+    pairs
+}
+
+fn lcs(old: &[&str], new: &[&str]) -> Vec<(usize, usize)> {
+    let width = new.len() + 1;
+    // lengths[i * width + j] is the LCS length of old[i..] and new[j..].
+    let mut lengths = vec![0u32; (old.len() + 1) * width];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i * width + j] = if old[i] == new[j] {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut pairs = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            pairs.push((i, j));
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * width + j] >= lengths[i * width + j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    pairs
+}
+
+/// Emit hunks for the changed stretch `old[old_range]` / `new[new_range]`,
+/// returning the characters matched by modified pairs, counted on both
+/// sides and weighted by similarity.
+fn pair_changes(
+    old: &[&str],
+    new: &[&str],
+    (old_start, old_end): (usize, usize),
+    (new_start, new_end): (usize, usize),
+    hunks: &mut Vec<DiffHunk>,
+) -> f32 {
+    let mut matched = 0.0;
+    let mut next_new = new_start;
+    let mut push = |kind, old_index: usize, new_index: usize| {
+        let text = |paragraphs: &[&str], index: usize, used: bool| {
+            if used {
+                paragraphs[index].to_string()
+            } else {
+                String::new()
+            }
+        };
+        hunks.push(DiffHunk {
+            kind,
+            old_index: old_index as u32,
+            new_index: new_index as u32,
+            old_text: text(old, old_index, kind != DiffHunkKind::Insert),
+            new_text: text(new, new_index, kind != DiffHunkKind::Delete),
+        });
+    };
+
+    for (old_index, old_text) in old.iter().enumerate().take(old_end).skip(old_start) {
+        let paired = new[next_new..new_end]
+            .iter()
+            .map(|new_text| similarity(old_text, new_text))
+            .position(|similarity| similarity >= MODIFIED_SIMILARITY);
+        let Some(offset) = paired else {
+            push(DiffHunkKind::Delete, old_index, next_new);
+            continue;
+        };
+        let new_index = next_new + offset;
+        for inserted in next_new..new_index {
+            push(DiffHunkKind::Insert, old_index, inserted);
+        }
+        push(DiffHunkKind::Modify, old_index, new_index);
+        let new_text = new[new_index];
+        matched +=
+            similarity(old_text, new_text) * (char_len(old_text) + char_len(new_text)) as f32;
+        next_new = new_index + 1;
+    }
+    for inserted in next_new..new_end {
+        push(DiffHunkKind::Insert, old_end, inserted);
+    }
+    matched
+}
+
+/// Dice coefficient of the two texts' character bigrams.
+pub(crate) fn similarity(a: &str, b: &str) -> f32 {
+    fn bigrams(text: &str) -> HashMap<(char, char), u32> {
+        let mut counts = HashMap::new();
+        for pair in text.chars().zip(text.chars().skip(1)) {
+            *counts.entry(pair).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    let (a_grams, b_grams) = (bigrams(a), bigrams(b));
+    let total: u32 = a_grams.values().sum::<u32>() + b_grams.values().sum::<u32>();
+    if total == 0 {
+        return if a == b { 1.0 } else { 0.0 };
+    }
+    let shared: u32 = a_grams
+        .iter()
+        .map(|(gram, count)| (*count).min(b_grams.get(gram).copied().unwrap_or(0)))
+        .sum();
+    2.0 * shared as f32 / total as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(diff: &ChapterDiff) -> Vec<(DiffHunkKind, u32, u32)> {
+        diff.hunks
+            .iter()
+            .map(|hunk| (hunk.kind, hunk.old_index, hunk.new_index))
+            .collect()
+    }
+
+    #[test]
+    fn test_identical_and_reformatted_text() {
+        let diff = diff_chapters(
+            "　　天色渐晚。\n\n　　城门外行人稀少。".to_string(),
+            "天色渐晚。\r\n城门外行人稀少。\n".to_string(),
+        );
+        assert!(diff.hunks.is_empty());
+        assert_eq!(diff.unchanged_paragraphs, 2);
+        assert_eq!(diff.similarity, 1.0);
+        assert_eq!(diff_chapters(String::new(), String::new()).similarity, 1.0);
+    }
+
+    #[test]
+    fn test_reports_insert_delete_and_modify() {
+        let old =
+            "第一段保持不变。\n第二段将被删除的内容。\n第三段里有一个错别字在这里。\n第四段。";
+        let new =
+            "第一段保持不变。\n第三段里有一个错字在这里。\n新加入的一段话。\n第四段。\n结尾。";
+        let diff = diff_chapters(old.to_string(), new.to_string());
+
+        assert_eq!(
+            summary(&diff),
+            vec![
+                (DiffHunkKind::Delete, 1, 1),
+                (DiffHunkKind::Modify, 2, 1),
+                (DiffHunkKind::Insert, 3, 2),
+                (DiffHunkKind::Insert, 4, 4),
+            ]
+        );
+        assert_eq!(diff.hunks[1].old_text, "第三段里有一个错别字在这里。");
+        assert_eq!(diff.hunks[1].new_text, "第三段里有一个错字在这里。");
+        assert_eq!(diff.unchanged_paragraphs, 2);
+        assert!(diff.similarity > 0.5 && diff.similarity < 1.0);
+    }
+
+    #[test]
+    fn test_unrelated_text_is_replaced() {
+        let diff = diff_chapters("甲乙丙丁".to_string(), "春夏秋冬".to_string());
+        assert_eq!(
+            summary(&diff),
+            vec![(DiffHunkKind::Delete, 0, 0), (DiffHunkKind::Insert, 1, 0)]
+        );
+        assert_eq!(diff.similarity, 0.0);
+    }
+}
@@ -0,0 +1,281 @@
+//! Natural ordering of chapter titles with Chinese numerals.
+//!
+//! Strings sort 第十章 before 第二章 and 第一百零二章 anywhere at all. Here
+//! every run of numerals in a title, Arabic, Chinese or a mix of the two
+//! such as `1百零2`, is read as a number: `两` counts as two, `廿`, `卅` and
+//! `卌` as twenty, thirty and forty, financial forms such as `贰拾` as their
+//! plain ones, and digit strings such as 二〇二四 digit by digit. Sort keys
+//! write each number zero-padded, so plain string comparison of keys gives
+//! the natural order, in SQL as well as here.
+
+use std::cmp::Ordering;
+
+use crate::api::db::LibraryChapter;
+
+/// Digits in a sort key; enough for any `u64`.
+const KEY_DIGITS: usize = 20;
+
+/// A key that sorts like the title in natural order: lowercase, with every
+/// number written as 20 zero-padded digits.
+#[flutter_rust_bridge::frb]
+pub fn chapter_sort_key(title: String) -> String {
+    let mut key = String::with_capacity(title.len());
+    for token in tokens(&title) {
+        match token {
+            Token::Number(value) => key.push_str(&format!("{value:0KEY_DIGITS$}")),
+            Token::Text(text) => key.extend(text.chars().flat_map(char::to_lowercase)),
+        }
+    }
+    key
+}
+
+/// Compare two titles in natural order: negative if `a` sorts first,
+/// positive if `b` does, 0 if they sort together.
+#[flutter_rust_bridge::frb]
+pub fn compare_chapter_titles(a: String, b: String) -> i32 {
+    match natural_cmp(&a, &b) {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    }
+}
+
+/// The chapter number of a title: the number after `第` if there is one,
+/// else the first number in it.
+#[flutter_rust_bridge::frb]
+pub fn chapter_number(title: String) -> Option<u64> {
+    let tokens = tokens(&title);
+    let after_di = tokens.windows(2).find_map(|pair| match pair {
+        [Token::Text(text), Token::Number(value)] if text.ends_with('第') => Some(*value),
+        _ => None,
+    });
+    after_di.or_else(|| {
+        tokens.iter().find_map(|token| match token {
+            Token::Number(value) => Some(*value),
+            Token::Text(_) => None,
+        })
+    })
+}
+
+/// Sort chapters by title in natural order and renumber them from 0, e.g.
+/// before `set_library_chapters` for chapters imported as loose files.
+/// Chapters that sort together keep their order.
+///
+/// # Arguments
+/// * `chapters` - Chapters in any order
+#[flutter_rust_bridge::frb]
+pub fn sort_library_chapters(chapters: Vec<LibraryChapter>) -> Vec<LibraryChapter> {
+    let mut chapters: Vec<(String, LibraryChapter)> = chapters
+        .into_iter()
+        .map(|chapter| (chapter_sort_key(chapter.title.clone()), chapter))
+        .collect();
+    chapters.sort_by(|a, b| a.0.cmp(&b.0));
+    chapters
+        .into_iter()
+        .enumerate()
+        .map(|(index, (_, chapter))| LibraryChapter {
+            index: index as u32,
+            ..chapter
+        })
+        .collect()
+}
+
+/// Compare strings in natural order, as `chapter_sort_key` does.
+pub(crate) fn natural_cmp(a: &str, b: &str) -> Ordering {
+    chapter_sort_key(a.to_string()).cmp(&chapter_sort_key(b.to_string()))
+}
+
+/// The value of a numeral such as 一百零五, 二〇二四, 廿三, 贰拾 or 1万2千.
+pub(crate) fn chinese_number(text: &str) -> Option<u64> {
+    let (mut total, mut myriads, mut section, mut number) = (0u64, 0u64, 0u64, 0u64);
+    for c in text.chars() {
+        if let Some(d) = digit(c) {
+            // Digits in a row, as in years.
+            number = number.checked_mul(10)?.checked_add(d)?;
+            continue;
+        }
+        let unit = match c {
+            '十' | '拾' => 10,
+            '百' | '佰' => 100,
+            '千' | '仟' => 1000,
+            '廿' | '卅' | '卌' => {
+                section = section.checked_add(tens(c)? * 10)?;
+                number = 0;
+                continue;
+            }
+            '万' | '萬' => {
+                let value = section.checked_add(number)?.max(1);
+                myriads = myriads.checked_add(value.checked_mul(10_000)?)?;
+                section = 0;
+                number = 0;
+                continue;
+            }
+            '亿' | '億' => {
+                let value = myriads.checked_add(section)?.checked_add(number)?.max(1);
+                total = total.checked_add(value.checked_mul(100_000_000)?)?;
+                myriads = 0;
+                section = 0;
+                number = 0;
+                continue;
+            }
+            _ => return None,
+        };
+        section = section.checked_add(number.max(1).checked_mul(unit)?)?;
+        number = 0;
+    }
+    total
+        .checked_add(myriads)?
+        .checked_add(section)?
+        .checked_add(number)
+}
+
+fn digit(c: char) -> Option<u64> {
+    match c {
+        '0'..='9' => Some(c as u64 - '0' as u64),
+        '０'..='９' => Some(c as u64 - '０' as u64),
+        '零' | '〇' => Some(0),
+        '一' | '壹' => Some(1),
+        '二' | '贰' | '貳' | '两' | '兩' => Some(2),
+        '三' | '叁' | '參' => Some(3),
+        '四' | '肆' => Some(4),
+        '五' | '伍' => Some(5),
+        '六' | '陆' | '陸' => Some(6),
+        '七' | '柒' => Some(7),
+        '八' | '捌' => Some(8),
+        '九' | '玖' => Some(9),
+        _ => None,
+    }
+}
+
+fn tens(c: char) -> Option<u64> {
+    match c {
+        '廿' => Some(2),
+        '卅' => Some(3),
+        '卌' => Some(4),
+        _ => None,
+    }
+}
+
+fn is_numeral(c: char) -> bool {
+    digit(c).is_some()
+        || tens(c).is_some()
+        || matches!(
+            c,
+            '十' | '拾' | '百' | '佰' | '千' | '仟' | '万' | '萬' | '亿' | '億'
+        )
+}
+
+#[derive(Debug, PartialEq)]
+enum Token<'a> {
+    Text(&'a str),
+    Number(u64),
+}
+
+/// Split text into numbers and the text between them. A run of numerals
+/// is text if it has only units, as the 万 of 万古, or does not parse.
+fn tokens(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut text_start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if !is_numeral(c) {
+            continue;
+        }
+        let mut end = start + c.len_utf8();
+        while let Some(&(i, c)) = chars.peek().filter(|(_, c)| is_numeral(*c)) {
+            end = i + c.len_utf8();
+            chars.next();
+        }
+        let run = &text[start..end];
+        let has_digit = run
+            .chars()
+            .any(|c| digit(c).is_some() || tens(c).is_some() || c == '十');
+        let Some(value) = chinese_number(run).filter(|_| has_digit) else {
+            continue;
+        };
+        if text_start < start {
+            tokens.push(Token::Text(&text[text_start..start]));
+        }
+        tokens.push(Token::Number(value));
+        text_start = end;
+    }
+    if text_start < text.len() {
+        tokens.push(Token::Text(&text[text_start..]));
+    }
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chinese_number_forms() {
+        for (text, value) in [
+            ("十", 10),
+            ("十二", 12),
+            ("一百零二", 102),
+            ("两万三千", 23_000),
+            ("二〇二四", 2024),
+            ("廿三", 23),
+            ("卅", 30),
+            ("贰拾肆", 24),
+            ("1百零2", 102),
+            ("1万2千", 12_000),
+            ("三亿零五万", 300_050_000),
+            ("１２", 12),
+        ] {
+            assert_eq!(chinese_number(text), Some(value), "{text}");
+        }
+        assert_eq!(chinese_number("章"), None);
+        assert_eq!(chinese_number("99999999999999999999"), None);
+    }
+
+    #[test]
+    fn test_natural_order_and_numbers() {
+        let mut titles = vec![
+            "第一百零二章 归来",
+            "第十章 风起",
+            "第2章 启程",
+            "第一章 开始",
+            "第廿一回",
+            "万古 第三章",
+            "序章",
+        ];
+        titles.sort_by(|a, b| natural_cmp(a, b));
+        assert_eq!(
+            titles,
+            [
+                "万古 第三章",
+                "序章",
+                "第一章 开始",
+                "第2章 启程",
+                "第十章 风起",
+                "第廿一回",
+                "第一百零二章 归来",
+            ]
+        );
+        assert_eq!(chapter_number("卷三 第十二章".to_string()), Some(12));
+        assert_eq!(chapter_number("Chapter 7".to_string()), Some(7));
+        assert_eq!(chapter_number("序章".to_string()), None);
+        assert_eq!(compare_chapter_titles("ch2".into(), "CH10".into()), -1);
+    }
+
+    #[test]
+    fn test_sort_library_chapters_renumbers() {
+        let chapter = |index: u32, title: &str| LibraryChapter {
+            index,
+            title: title.to_string(),
+            url: None,
+            is_volume: false,
+            word_count: None,
+        };
+        let sorted = sort_library_chapters(vec![
+            chapter(0, "第十章"),
+            chapter(1, "第二章"),
+            chapter(2, "第二章"),
+        ]);
+        let order: Vec<_> = sorted.iter().map(|c| (c.index, c.title.as_str())).collect();
+        assert_eq!(order, [(0, "第二章"), (1, "第二章"), (2, "第十章")]);
+    }
+}
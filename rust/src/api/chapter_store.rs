@@ -0,0 +1,822 @@
+//! Packed, compressed storage for chapter text.
+//!
+//! Each book gets a folder, named by the BLAKE3 hash of its ID, of
+//! append-only segment files. A segment starts with a magic number and a
+//! record is a 20-byte header (chapter index, text length, stored length,
+//! dictionary ID and a checksum of the rest, all little-endian u32) and the
+//! zstd-compressed text; writing a chapter again appends a new record and
+//! deleting one appends a tombstone, so the last record of a chapter wins.
+//! Each append is synced to disk before the write returns. The index is
+//! rebuilt by scanning the headers the first time a book is used: a record
+//! cut off by a crash is simply dropped, and one failing its checksum is
+//! skipped so the chapter's previous record, if any, is read instead.
+//! Segments from before checksums have no magic and 16-byte headers; they
+//! are still read, and compaction rewrites them.
+//!
+//! `verify_chapter_store` also decompresses every live record, and moves
+//! the segments holding corrupt ones to a `quarantine` folder after
+//! compacting the readable chapters out of them.
+//!
+//! Compaction rewrites the live records into fresh segments and deletes the
+//! old ones. It runs in the background once a book has enough chapters to
+//! train a zstd dictionary on, since chapters of one book share names and
+//! phrasing that a dictionary captures better than each chapter alone, and
+//! whenever superseded records take up more than half of a book's segments.
+
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+
+use anyhow::{anyhow, Result};
+
+use crate::api::error::ApiError;
+use crate::api::font_converter;
+use crate::api::sanitize::sanitize;
+
+const SEGMENT_EXTENSION: &str = "seg";
+const DICTIONARY_EXTENSION: &str = "zdict";
+
+/// A segment is closed once it grows past this size.
+const MAX_SEGMENT_BYTES: u64 = 4 * 1024 * 1024;
+const LEVEL: i32 = 6;
+/// Chapters a book needs before a dictionary is trained for it.
+const DICTIONARY_SAMPLES: usize = 8;
+/// Chapters the dictionary is trained on at most.
+const MAX_DICTIONARY_SAMPLES: usize = 200;
+const DICTIONARY_SIZE: usize = 16 * 1024;
+/// Books smaller than this are never compacted for garbage alone.
+const MIN_COMPACT_BYTES: u64 = 64 * 1024;
+
+/// Starts segments whose records have checksums.
+const SEGMENT_MAGIC: &[u8; 8] = b"NVCS\x02\0\0\0";
+const HEADER_LEN: u64 = 20;
+/// Header length of records in segments without `SEGMENT_MAGIC`.
+const LEGACY_HEADER_LEN: u64 = 16;
+const QUARANTINE_DIR: &str = "quarantine";
+const TOMBSTONE: u32 = u32::MAX;
+/// Dictionary ID of records compressed without one.
+const NO_DICTIONARY: u32 = 0;
+
+static CHAPTER_STORE: Mutex<Option<Arc<ChapterStore>>> = Mutex::new(None);
+
+#[derive(Debug, Clone)]
+pub struct ChapterStoreStats {
+    pub chapter_count: u32,
+    /// Size of the stored text before compression.
+    pub text_bytes: u64,
+    /// Size of the segment files.
+    pub stored_bytes: u64,
+    /// Part of `stored_bytes` taken by superseded records.
+    pub garbage_bytes: u64,
+    pub segment_count: u32,
+    pub has_dictionary: bool,
+}
+
+/// A book `verify_chapter_store` found corrupt records in.
+#[derive(Debug, Clone)]
+pub struct StoredBookScan {
+    pub book_id: String,
+    /// Records that failed their checksum or could not be decompressed.
+    pub corrupt_records: u32,
+    /// Chapters left with no readable copy, to be downloaded again.
+    pub lost_chapters: Vec<u32>,
+}
+
+/// Enable the chapter store. Calling this again switches to the new
+/// directory.
+///
+/// # Arguments
+/// * `store_dir` - Directory owned by the store; created if missing
+#[flutter_rust_bridge::frb]
+pub fn init_chapter_store(store_dir: String) -> Result<(), ApiError> {
+    let dir = PathBuf::from(store_dir);
+    fs::create_dir_all(&dir).map_err(|e| anyhow!("Failed to create {}: {e}", dir.display()))?;
+    *lock(&CHAPTER_STORE) = Some(Arc::new(ChapterStore {
+        dir,
+        books: Mutex::new(HashMap::new()),
+    }));
+    Ok(())
+}
+
+/// Store the text of a chapter, replacing any stored copy. The text is
+/// sanitized first, as by `sanitize_text`.
+///
+/// # Arguments
+/// * `book_id` - Library ID of the book
+/// * `chapter_index` - Position of the chapter in the table of contents
+/// * `text` - Chapter content
+#[flutter_rust_bridge::frb]
+pub fn put_chapter(book_id: String, chapter_index: u32, text: String) -> Result<(), ApiError> {
+    let store = current()?;
+    let book = store.book(&book_id)?;
+    let compact = {
+        let mut book = lock(&book);
+        book.put(chapter_index, sanitize(&text).as_bytes())?;
+        book.needs_compaction()
+    };
+    if compact {
+        thread::spawn(move || {
+            let mut book = lock(&book);
+            if book.needs_compaction() {
+                let _ = book.compact();
+            }
+        });
+    }
+    Ok(())
+}
+
+/// Read a stored chapter, or `None` if it is not stored.
+///
+/// # Arguments
+/// * `book_id` - Library ID of the book
+/// * `chapter_index` - Position of the chapter in the table of contents
+#[flutter_rust_bridge::frb]
+pub fn get_chapter(book_id: String, chapter_index: u32) -> Result<Option<String>, ApiError> {
+    let book = current()?.book(&book_id)?;
+    let text = lock(&book).get(chapter_index)?;
+    Ok(text
+        .map(|text| {
+            String::from_utf8(text).map_err(|e| anyhow!("Stored chapter is not valid UTF-8: {e}"))
+        })
+        .transpose()?)
+}
+
+/// Delete a stored chapter. Returns false if it was not stored.
+#[flutter_rust_bridge::frb]
+pub fn delete_chapter(book_id: String, chapter_index: u32) -> Result<bool, ApiError> {
+    let book = current()?.book(&book_id)?;
+    let mut book = lock(&book);
+    Ok(book.delete(chapter_index)?)
+}
+
+/// Delete every stored chapter of a book.
+#[flutter_rust_bridge::frb]
+pub fn delete_stored_book(book_id: String) -> Result<(), ApiError> {
+    let store = current()?;
+    let book = store.book(&book_id)?;
+    let book = lock(&book);
+    lock(&store.books).remove(&book_id);
+    match fs::remove_dir_all(&book.dir) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(anyhow!("Failed to remove {}: {e}", book.dir.display()).into()),
+    }
+}
+
+/// Compact a book's segments now, training a dictionary if it has enough
+/// chapters, and return the resulting sizes.
+#[flutter_rust_bridge::frb]
+pub fn compact_chapter_store(book_id: String) -> Result<ChapterStoreStats, ApiError> {
+    let book = current()?.book(&book_id)?;
+    let mut book = lock(&book);
+    book.compact()?;
+    Ok(book.stats())
+}
+
+/// Check the stored chapters of books, such as on startup after a crash.
+/// Corrupt records are quarantined so they are never read again; only
+/// books that had any are returned.
+///
+/// # Arguments
+/// * `book_ids` - Library IDs of the books to check
+#[flutter_rust_bridge::frb]
+pub fn verify_chapter_store(book_ids: Vec<String>) -> Result<Vec<StoredBookScan>, ApiError> {
+    let store = current()?;
+    let mut scans = Vec::new();
+    for book_id in book_ids {
+        let book = store.book(&book_id)?;
+        let mut book = lock(&book);
+        if let Some((corrupt_records, lost_chapters)) = book.verify()? {
+            scans.push(StoredBookScan {
+                book_id,
+                corrupt_records,
+                lost_chapters,
+            });
+        }
+    }
+    Ok(scans)
+}
+
+/// Report how a book's chapters are stored.
+#[flutter_rust_bridge::frb]
+pub fn chapter_store_stats(book_id: String) -> Result<ChapterStoreStats, ApiError> {
+    let book = current()?.book(&book_id)?;
+    let stats = lock(&book).stats();
+    Ok(stats)
+}
+
+/// Indices of a book's stored chapters, in order.
+pub(crate) fn stored_chapter_indices(book_id: &str) -> Result<Vec<u32>> {
+    let book = current()?.book(book_id)?;
+    let mut indices: Vec<u32> = lock(&book).index.keys().copied().collect();
+    indices.sort_unstable();
+    Ok(indices)
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn current() -> Result<Arc<ChapterStore>> {
+    lock(&CHAPTER_STORE)
+        .clone()
+        .ok_or_else(|| anyhow!("Chapter store is not initialized"))
+}
+
+struct ChapterStore {
+    dir: PathBuf,
+    /// Books opened so far, each locked on its own.
+    books: Mutex<HashMap<String, Arc<Mutex<Book>>>>,
+}
+
+impl ChapterStore {
+    fn book(&self, book_id: &str) -> Result<Arc<Mutex<Book>>> {
+        let mut books = lock(&self.books);
+        if let Some(book) = books.get(book_id) {
+            return Ok(Arc::clone(book));
+        }
+        let dir = self
+            .dir
+            .join(blake3::hash(book_id.as_bytes()).to_hex().as_str());
+        let book = Arc::new(Mutex::new(Book::open(dir)?));
+        books.insert(book_id.to_string(), Arc::clone(&book));
+        Ok(book)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Location {
+    segment: u32,
+    /// Offset of the record header.
+    offset: u64,
+    text_len: u32,
+    stored_len: u32,
+    dictionary: u32,
+    /// Whether the record has a checksum, being in a segment with magic.
+    checked: bool,
+}
+
+impl Location {
+    fn header_len(&self) -> u64 {
+        if self.checked {
+            HEADER_LEN
+        } else {
+            LEGACY_HEADER_LEN
+        }
+    }
+
+    fn record_len(&self) -> u64 {
+        self.header_len() + self.stored_len as u64
+    }
+}
+
+struct Book {
+    dir: PathBuf,
+    index: HashMap<u32, Location>,
+    /// Segment IDs and sizes, oldest first.
+    segments: Vec<(u32, u64)>,
+    dictionaries: HashMap<u32, Vec<u8>>,
+    garbage_bytes: u64,
+    /// Whether training a dictionary was tried since the book was opened.
+    tried_dictionary: bool,
+    /// Whether the last segment has no magic, so appends need a new one.
+    legacy_tail: bool,
+    /// Segments with corrupt records, quarantined by `verify`.
+    corrupt_segments: HashSet<u32>,
+    /// Chapters of the corrupt records, and how many were found.
+    corrupt_chapters: HashSet<u32>,
+    corrupt_records: u32,
+}
+
+impl Book {
+    fn open(dir: PathBuf) -> Result<Self> {
+        let mut book = Self {
+            dir,
+            index: HashMap::new(),
+            segments: Vec::new(),
+            dictionaries: HashMap::new(),
+            garbage_bytes: 0,
+            tried_dictionary: false,
+            legacy_tail: false,
+            corrupt_segments: HashSet::new(),
+            corrupt_chapters: HashSet::new(),
+            corrupt_records: 0,
+        };
+        let items = match fs::read_dir(&book.dir) {
+            Ok(items) => items,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(book),
+            Err(e) => return Err(anyhow!("Failed to read {}: {e}", book.dir.display())),
+        };
+        let mut segments = Vec::new();
+        for path in items.flatten().map(|item| item.path()) {
+            let id = path
+                .file_stem()
+                .and_then(|s| s.to_str()?.parse::<u32>().ok());
+            let extension = path.extension().and_then(|ext| ext.to_str());
+            match (id, extension) {
+                (Some(id), Some(SEGMENT_EXTENSION)) => segments.push(id),
+                (Some(id), Some(DICTIONARY_EXTENSION)) => {
+                    let data = fs::read(&path)
+                        .map_err(|e| anyhow!("Failed to read {}: {e}", path.display()))?;
+                    book.dictionaries.insert(id, data);
+                }
+                _ => {}
+            }
+        }
+        segments.sort_unstable();
+        let last = segments.last().copied();
+        for id in segments {
+            let len = book.scan(id, Some(id) == last)?;
+            book.segments.push((id, len));
+        }
+        Ok(book)
+    }
+
+    /// Index the records of a segment and return its length. A partial
+    /// record at the end of the last segment is cut off so appends follow
+    /// a whole record; records failing their checksum are left out.
+    fn scan(&mut self, segment: u32, last: bool) -> Result<u64> {
+        let path = self.segment_path(segment);
+        let data =
+            fs::read(&path).map_err(|e| anyhow!("Failed to read {}: {e}", path.display()))?;
+        let checked = data.starts_with(SEGMENT_MAGIC);
+        let header_len = if checked {
+            HEADER_LEN
+        } else {
+            LEGACY_HEADER_LEN
+        };
+        if last && !checked && !data.is_empty() {
+            self.legacy_tail = true;
+        }
+        let mut offset = if checked {
+            SEGMENT_MAGIC.len() as u64
+        } else {
+            0
+        };
+        while let Some(header) = data.get(offset as usize..(offset + header_len) as usize) {
+            let field = |i: usize| u32::from_le_bytes(header[i * 4..i * 4 + 4].try_into().unwrap());
+            let (chapter, text_len, stored_len, dictionary) =
+                (field(0), field(1), field(2), field(3));
+            let location = Location {
+                segment,
+                offset,
+                text_len,
+                stored_len: if stored_len == TOMBSTONE {
+                    0
+                } else {
+                    stored_len
+                },
+                dictionary,
+                checked,
+            };
+            let end = offset + location.record_len();
+            if end > data.len() as u64 {
+                break;
+            }
+            if checked {
+                let body = &data[(offset + header_len) as usize..end as usize];
+                if checksum(&header[..16], body) != field(4) {
+                    if last && end == data.len() as u64 {
+                        // Torn by a crash while being appended.
+                        break;
+                    }
+                    self.corrupt_segments.insert(segment);
+                    self.corrupt_chapters.insert(chapter);
+                    self.corrupt_records += 1;
+                    self.garbage_bytes += location.record_len();
+                    offset = end;
+                    continue;
+                }
+            }
+            let replaced = if stored_len == TOMBSTONE {
+                self.garbage_bytes += HEADER_LEN;
+                self.index.remove(&chapter)
+            } else {
+                self.index.insert(chapter, location)
+            };
+            self.garbage_bytes += replaced.map_or(0, |old| old.record_len());
+            offset += location.record_len();
+        }
+        if offset < data.len() as u64 && !last {
+            // Records after a damaged header cannot be found.
+            self.corrupt_segments.insert(segment);
+            self.corrupt_records += 1;
+        } else if offset < data.len() as u64 {
+            OpenOptions::new()
+                .write(true)
+                .open(&path)
+                .and_then(|file| file.set_len(offset))
+                .map_err(|e| anyhow!("Failed to repair {}: {e}", path.display()))?;
+        }
+        Ok(offset)
+    }
+
+    fn segment_path(&self, id: u32) -> PathBuf {
+        self.dir.join(format!("{id:06}.{SEGMENT_EXTENSION}"))
+    }
+
+    fn dictionary_path(&self, id: u32) -> PathBuf {
+        self.dir.join(format!("{id:06}.{DICTIONARY_EXTENSION}"))
+    }
+
+    /// The newest dictionary, used for new records.
+    fn dictionary(&self) -> Option<(u32, &[u8])> {
+        self.dictionaries
+            .iter()
+            .max_by_key(|(id, _)| **id)
+            .map(|(id, data)| (*id, data.as_slice()))
+    }
+
+    fn put(&mut self, chapter: u32, text: &[u8]) -> Result<()> {
+        let (dictionary, data) = compress(self.dictionary(), text)?;
+        let location = self.append(chapter, text.len() as u32, Some(&data), dictionary, true)?;
+        if let Some(old) = self.index.insert(chapter, location) {
+            self.garbage_bytes += old.record_len();
+        }
+        Ok(())
+    }
+
+    fn get(&self, chapter: u32) -> Result<Option<Vec<u8>>> {
+        let Some(location) = self.index.get(&chapter) else {
+            return Ok(None);
+        };
+        let path = self.segment_path(location.segment);
+        let mut record = vec![0; location.record_len() as usize];
+        File::open(&path)
+            .and_then(|mut file| {
+                file.seek(SeekFrom::Start(location.offset))?;
+                file.read_exact(&mut record)
+            })
+            .map_err(|e| anyhow!("Failed to read {}: {e}", path.display()))?;
+        let (header, data) = record.split_at(location.header_len() as usize);
+        if location.checked
+            && checksum(&header[..16], data) != u32::from_le_bytes(header[16..].try_into().unwrap())
+        {
+            return Err(anyhow!("Stored chapter {chapter} is corrupt"));
+        }
+        self.decompress(location, data).map(Some)
+    }
+
+    fn delete(&mut self, chapter: u32) -> Result<bool> {
+        if !self.index.contains_key(&chapter) {
+            return Ok(false);
+        }
+        self.append(chapter, 0, None, NO_DICTIONARY, true)?;
+        let old = self.index.remove(&chapter).unwrap();
+        self.garbage_bytes += old.record_len() + HEADER_LEN;
+        Ok(true)
+    }
+
+    /// Append a record, or a tombstone without `data`, to the last segment,
+    /// starting a new one when it is full. With `sync` the record is on
+    /// disk when this returns.
+    fn append(
+        &mut self,
+        chapter: u32,
+        text_len: u32,
+        data: Option<&[u8]>,
+        dictionary: u32,
+        sync: bool,
+    ) -> Result<Location> {
+        if self.legacy_tail
+            || self
+                .segments
+                .last()
+                .is_none_or(|(_, len)| *len >= MAX_SEGMENT_BYTES)
+        {
+            let id = self.segments.last().map_or(1, |(id, _)| id + 1);
+            self.segments.push((id, 0));
+            self.legacy_tail = false;
+        }
+        let (segment, mut offset) = *self.segments.last().unwrap();
+        let stored_len = data.map_or(TOMBSTONE, |data| data.len() as u32);
+        let data = data.unwrap_or_default();
+        let mut record = Vec::with_capacity(SEGMENT_MAGIC.len() + HEADER_LEN as usize + data.len());
+        if offset == 0 {
+            record.extend_from_slice(SEGMENT_MAGIC);
+            offset = SEGMENT_MAGIC.len() as u64;
+        }
+        let header_start = record.len();
+        for field in [chapter, text_len, stored_len, dictionary] {
+            record.extend_from_slice(&field.to_le_bytes());
+        }
+        let sum = checksum(&record[header_start..], data);
+        record.extend_from_slice(&sum.to_le_bytes());
+        record.extend_from_slice(data);
+
+        fs::create_dir_all(&self.dir)
+            .map_err(|e| anyhow!("Failed to create {}: {e}", self.dir.display()))?;
+        let path = self.segment_path(segment);
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .and_then(|mut file| {
+                file.write_all(&record)?;
+                if sync {
+                    file.sync_data()?;
+                }
+                Ok(())
+            })
+            .map_err(|e| anyhow!("Failed to write {}: {e}", path.display()))?;
+        self.segments.last_mut().unwrap().1 += record.len() as u64;
+        Ok(Location {
+            segment,
+            offset,
+            text_len,
+            stored_len: data.len() as u32,
+            dictionary,
+            checked: true,
+        })
+    }
+
+    fn decompress(&self, location: &Location, data: &[u8]) -> Result<Vec<u8>> {
+        let decompressor = match location.dictionary {
+            NO_DICTIONARY => zstd::bulk::Decompressor::new(),
+            id => {
+                let dictionary = self
+                    .dictionaries
+                    .get(&id)
+                    .ok_or_else(|| anyhow!("Stored chapter needs missing dictionary {id}"))?;
+                zstd::bulk::Decompressor::with_dictionary(dictionary)
+            }
+        };
+        decompressor
+            .and_then(|mut d| d.decompress(data, location.text_len as usize))
+            .map_err(|e| anyhow!("Failed to decompress stored chapter: {e}"))
+    }
+
+    fn stored_bytes(&self) -> u64 {
+        self.segments.iter().map(|(_, len)| len).sum()
+    }
+
+    fn needs_compaction(&self) -> bool {
+        let untrained = self.dictionaries.is_empty()
+            && !self.tried_dictionary
+            && self.index.len() >= DICTIONARY_SAMPLES;
+        let stored = self.stored_bytes();
+        untrained || stored >= MIN_COMPACT_BYTES && self.garbage_bytes * 2 > stored
+    }
+
+    /// Rewrite the live records into new segments, training a dictionary
+    /// first if there is none yet.
+    fn compact(&mut self) -> Result<()> {
+        let mut chapters: Vec<(u32, Location)> = self.index.iter().map(|(c, l)| (*c, *l)).collect();
+        chapters.sort_unstable_by_key(|(chapter, _)| *chapter);
+
+        let old_dictionaries: Vec<u32> = self.dictionaries.keys().copied().collect();
+        if self.dictionaries.is_empty() && chapters.len() >= DICTIONARY_SAMPLES {
+            self.tried_dictionary = true;
+            let step = chapters.len().div_ceil(MAX_DICTIONARY_SAMPLES);
+            let samples = chapters
+                .iter()
+                .step_by(step)
+                .filter_map(|(chapter, _)| self.get(*chapter).transpose())
+                .collect::<Result<Vec<_>>>()?;
+            // Too little or too uniform text fails to train; go without.
+            if let Ok(dictionary) = zstd::dict::from_samples(&samples, DICTIONARY_SIZE) {
+                let id = 1;
+                font_converter::write_atomically(&self.dictionary_path(id), &dictionary)?;
+                self.dictionaries.insert(id, dictionary);
+            }
+        }
+
+        let old_segments: Vec<u32> = self.segments.iter().map(|(id, _)| *id).collect();
+        let first = old_segments.last().map_or(1, |id| id + 1);
+        let mut compacted = Book {
+            dir: self.dir.clone(),
+            index: HashMap::new(),
+            segments: vec![(first, 0)],
+            dictionaries: HashMap::new(),
+            garbage_bytes: 0,
+            tried_dictionary: false,
+            legacy_tail: false,
+            corrupt_segments: HashSet::new(),
+            corrupt_chapters: HashSet::new(),
+            corrupt_records: 0,
+        };
+        let dictionary = self.dictionary();
+        for (chapter, _) in &chapters {
+            let text = self.get(*chapter)?.unwrap_or_default();
+            let (id, data) = compress(dictionary, &text)?;
+            let location = compacted.append(*chapter, text.len() as u32, Some(&data), id, false)?;
+            compacted.index.insert(*chapter, location);
+        }
+        for (id, _) in &compacted.segments {
+            let path = self.segment_path(*id);
+            if let Ok(file) = File::open(&path) {
+                file.sync_all()
+                    .map_err(|e| anyhow!("Failed to sync {}: {e}", path.display()))?;
+            }
+        }
+        let current = dictionary.map(|(id, _)| id);
+        for id in old_segments {
+            if self.corrupt_segments.remove(&id) {
+                self.quarantine(id)?;
+            } else {
+                let _ = fs::remove_file(self.segment_path(id));
+            }
+        }
+        for id in old_dictionaries
+            .into_iter()
+            .filter(|id| Some(*id) != current)
+        {
+            self.dictionaries.remove(&id);
+            let _ = fs::remove_file(self.dictionary_path(id));
+        }
+        self.index = compacted.index;
+        self.segments = compacted.segments;
+        self.garbage_bytes = 0;
+        Ok(())
+    }
+
+    /// Decompress every live record, then compact away the corrupt ones
+    /// found now or by the scan. Returns how many there were and the
+    /// chapters left unreadable, or `None` if all was well.
+    fn verify(&mut self) -> Result<Option<(u32, Vec<u32>)>> {
+        let mut chapters: Vec<u32> = self.index.keys().copied().collect();
+        chapters.sort_unstable();
+        for chapter in chapters {
+            let readable =
+                matches!(self.get(chapter), Ok(Some(text)) if std::str::from_utf8(&text).is_ok());
+            if !readable {
+                let location = self.index.remove(&chapter).unwrap();
+                self.garbage_bytes += location.record_len();
+                self.corrupt_segments.insert(location.segment);
+                self.corrupt_chapters.insert(chapter);
+                self.corrupt_records += 1;
+            }
+        }
+        if self.corrupt_records == 0 {
+            return Ok(None);
+        }
+        self.compact()?;
+        let mut lost: Vec<u32> = self
+            .corrupt_chapters
+            .drain()
+            .filter(|chapter| !self.index.contains_key(chapter))
+            .collect();
+        lost.sort_unstable();
+        let corrupt = std::mem::take(&mut self.corrupt_records);
+        Ok(Some((corrupt, lost)))
+    }
+
+    /// Move a segment out of the way, keeping it for inspection.
+    fn quarantine(&self, id: u32) -> Result<()> {
+        let dir = self.dir.join(QUARANTINE_DIR);
+        let path = self.segment_path(id);
+        fs::create_dir_all(&dir)
+            .and_then(|()| fs::rename(&path, dir.join(path.file_name().unwrap_or_default())))
+            .map_err(|e| anyhow!("Failed to quarantine {}: {e}", path.display()))
+    }
+
+    fn stats(&self) -> ChapterStoreStats {
+        ChapterStoreStats {
+            chapter_count: self.index.len() as u32,
+            text_bytes: self.index.values().map(|l| l.text_len as u64).sum(),
+            stored_bytes: self.stored_bytes(),
+            garbage_bytes: self.garbage_bytes,
+            segment_count: self.segments.len() as u32,
+            has_dictionary: !self.dictionaries.is_empty(),
+        }
+    }
+}
+
+/// Checksum of a record: its first four header fields and stored data.
+fn checksum(header: &[u8], data: &[u8]) -> u32 {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(header);
+    hasher.update(data);
+    u32::from_le_bytes(hasher.finalize().as_bytes()[..4].try_into().unwrap())
+}
+
+/// Compress `text`, with `dictionary` when there is one, and return the
+/// dictionary ID used with the data.
+fn compress(dictionary: Option<(u32, &[u8])>, text: &[u8]) -> Result<(u32, Vec<u8>)> {
+    let (id, compressor) = match dictionary {
+        Some((id, data)) => (id, zstd::bulk::Compressor::with_dictionary(LEVEL, data)),
+        None => (NO_DICTIONARY, zstd::bulk::Compressor::new(LEVEL)),
+    };
+    let data = compressor
+        .and_then(|mut c| c.compress(text))
+        .map_err(|e| anyhow!("Failed to compress chapter: {e}"))?;
+    Ok((id, data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_dirs::temp_dir;
+
+    fn chapter_text(index: u32) -> String {
+        let names = ["林动", "绫清竹", "应欢欢", "小貂", "青檀"];
+        (0..60)
+            .map(|line| {
+                let name = names[(index as usize + line) % names.len()];
+                format!("第{index}章第{line}段，{name}抬头望向远处的天空，心中暗自思量。\n")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_put_get_and_reopen() {
+        let dir = temp_dir("reopen");
+        let mut book = Book::open(dir.clone()).unwrap();
+        book.put(0, "序章".as_bytes()).unwrap();
+        book.put(1, chapter_text(1).as_bytes()).unwrap();
+        book.put(0, "序章（修订）".as_bytes()).unwrap();
+        book.put(2, b"").unwrap();
+        assert!(book.delete(1).unwrap());
+        assert!(!book.delete(1).unwrap());
+        assert_eq!(book.get(0).unwrap().unwrap(), "序章（修订）".as_bytes());
+        assert_eq!(book.get(2).unwrap().unwrap(), b"");
+        let stats = book.stats();
+
+        // A record cut off by a crash is dropped on the next scan.
+        let segment = book.segment_path(1);
+        let mut file = OpenOptions::new().append(true).open(&segment).unwrap();
+        file.write_all(&[3, 0, 0, 0, 9]).unwrap();
+        let mut book = Book::open(dir.clone()).unwrap();
+        assert_eq!(book.get(0).unwrap().unwrap(), "序章（修订）".as_bytes());
+        assert_eq!(book.get(1).unwrap(), None);
+        assert_eq!(book.get(2).unwrap().unwrap(), b"");
+        assert_eq!(book.garbage_bytes, stats.garbage_bytes);
+        assert_eq!(fs::metadata(&segment).unwrap().len(), stats.stored_bytes);
+        book.put(3, "后记".as_bytes()).unwrap();
+        let book = Book::open(dir.clone()).unwrap();
+        assert_eq!(book.get(3).unwrap().unwrap(), "后记".as_bytes());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_compaction() {
+        let dir = temp_dir("compact");
+        let mut book = Book::open(dir.clone()).unwrap();
+        for round in 0..3 {
+            for index in 0..40 {
+                let text = format!("{round}{}", chapter_text(index));
+                book.put(index, text.as_bytes()).unwrap();
+            }
+        }
+        assert!(book.needs_compaction());
+        let before = book.stats();
+        book.compact().unwrap();
+        let after = book.stats();
+        assert!(after.has_dictionary);
+        assert_eq!((after.chapter_count, after.garbage_bytes), (40, 0));
+        assert!(after.stored_bytes * 3 < before.stored_bytes);
+        assert!(!book.needs_compaction());
+        for index in 0..40 {
+            let text = format!("2{}", chapter_text(index));
+            assert_eq!(book.get(index).unwrap().unwrap(), text.as_bytes());
+        }
+
+        let book = Book::open(dir.clone()).unwrap();
+        assert_eq!(book.stats().chapter_count, 40);
+        assert_eq!(
+            book.get(7).unwrap().unwrap(),
+            format!("2{}", chapter_text(7)).as_bytes()
+        );
+        let segments = fs::read_dir(&dir)
+            .unwrap()
+            .flatten()
+            .filter(|item| item.path().extension().and_then(|e| e.to_str()) == Some("seg"))
+            .count();
+        assert_eq!(segments, after.segment_count as usize);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_verify_quarantines_corrupt_records() {
+        let dir = temp_dir("verify");
+        let mut book = Book::open(dir.clone()).unwrap();
+        book.put(0, "序章".as_bytes()).unwrap();
+        book.put(0, "序章（修订）".as_bytes()).unwrap();
+        book.put(1, chapter_text(1).as_bytes()).unwrap();
+        book.put(2, chapter_text(2).as_bytes()).unwrap();
+        let segment = book.segment_path(1);
+        let mut data = fs::read(&segment).unwrap();
+        for chapter in [0, 1] {
+            data[(book.index[&chapter].offset + HEADER_LEN) as usize + 1] ^= 0xff;
+        }
+        fs::write(&segment, &data).unwrap();
+        assert!(book.get(1).is_err());
+
+        // The scan passes over bad records, so the older copy is read.
+        let mut book = Book::open(dir.clone()).unwrap();
+        assert_eq!(book.get(0).unwrap().unwrap(), "序章".as_bytes());
+        assert_eq!(book.get(1).unwrap(), None);
+        assert_eq!(book.verify().unwrap(), Some((2, vec![1])));
+        assert!(dir.join(QUARANTINE_DIR).join("000001.seg").exists());
+        assert!(!segment.exists());
+
+        let mut book = Book::open(dir.clone()).unwrap();
+        assert_eq!(book.verify().unwrap(), None);
+        assert_eq!(book.stats().chapter_count, 2);
+        assert_eq!(book.get(2).unwrap().unwrap(), chapter_text(2).as_bytes());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,374 @@
+//! Signed remote ("cloud control") configuration.
+//!
+//! The config is served as a JSON envelope:
+//!
+//! ```json
+//! {"payload": "<base64 config JSON>", "signature": "<base64 Ed25519 signature>"}
+//! ```
+//!
+//! The signature covers the payload bytes exactly as decoded, so the
+//! server can format the JSON however it likes. It is checked against the
+//! public key baked into the binary at build time from the
+//! `NOVELLA_CLOUDCTL_PUBLIC_KEY` environment variable (64 hex digits);
+//! builds without one reject every config. A verified config must also be
+//! no older than the last one accepted: its `issuedAt` timestamp is
+//! remembered in the state directory, so a replayed old config cannot roll
+//! settings back. Re-verifying the accepted config itself is allowed.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Result};
+use base64::Engine;
+use ed25519_dalek::{Signature, VerifyingKey};
+use serde_json::{json, Value};
+
+use crate::api::error::ApiError;
+use crate::api::font_converter;
+
+const STATE_FILE: &str = "cloudctl.json";
+
+/// Configs issued further in the future than this are rejected, since
+/// accepting one would lock out every correctly dated config after it.
+const MAX_CLOCK_SKEW_SECS: i64 = 24 * 3600;
+
+const PUBLIC_KEY_HEX: Option<&str> = option_env!("NOVELLA_CLOUDCTL_PUBLIC_KEY");
+
+static STATE_DIR: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+/// Accepted-config state used when no state directory is set.
+static MEMORY: Mutex<Option<Watermark>> = Mutex::new(None);
+
+#[derive(Debug, Clone)]
+pub struct CloudConfig {
+    /// When the config was issued, in seconds since the Unix epoch.
+    pub issued_at: i64,
+    /// When the config stops applying, if it expires.
+    pub expires_at: Option<i64>,
+    /// Review-build mode: hide third-party sources and other features app
+    /// store review must not see.
+    pub compliance_mode: bool,
+    /// Feature keys the app should turn off.
+    pub disabled_features: Vec<String>,
+    /// Oldest app version still supported, e.g. `1.4.0`.
+    pub min_app_version: Option<String>,
+    /// Notice to show once, if any.
+    pub announcement: Option<String>,
+    /// Other string settings by key, in payload order.
+    pub values: Vec<CloudConfigValue>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CloudConfigValue {
+    pub key: String,
+    pub value: String,
+}
+
+/// Remember accepted configs under `state_dir`. Without this, staleness
+/// is only checked against configs accepted since launch.
+///
+/// # Arguments
+/// * `state_dir` - Directory for the small state file; created if missing
+#[flutter_rust_bridge::frb]
+pub fn init_cloudctl(state_dir: String) -> Result<(), ApiError> {
+    let dir = PathBuf::from(state_dir);
+    fs::create_dir_all(&dir).map_err(|e| anyhow!("Failed to create {}: {e}", dir.display()))?;
+    *lock() = Some(dir);
+    Ok(())
+}
+
+/// Verify a signed config envelope with the built-in key and return the
+/// config. Fails if the signature is wrong, the config is older than one
+/// already accepted, or it has expired.
+///
+/// # Arguments
+/// * `envelope` - Envelope JSON as fetched
+#[flutter_rust_bridge::frb]
+pub fn verify_cloud_config(envelope: String) -> Result<CloudConfig, ApiError> {
+    let key = public_key()?;
+    let state_dir = lock().clone();
+    let mut state = Watermark::load(state_dir.as_deref());
+    let (config, hash) = verify(&envelope, &key, &state, now())?;
+    state.issued_at = config.issued_at;
+    state.payload_hash = hash;
+    if let Some(dir) = state_dir {
+        state.save(&dir)?;
+    }
+    MEMORY
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .replace(state);
+    Ok(config)
+}
+
+fn lock() -> MutexGuard<'static, Option<PathBuf>> {
+    STATE_DIR.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn public_key() -> Result<VerifyingKey> {
+    let hex = PUBLIC_KEY_HEX.ok_or_else(|| anyhow!("No cloud config key is built in"))?;
+    parse_key(hex)
+}
+
+fn parse_key(hex: &str) -> Result<VerifyingKey> {
+    let hex = hex.trim();
+    let bytes: Option<Vec<u8>> = (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            hex.get(i..i + 2)
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+        })
+        .collect();
+    let bytes: [u8; 32] = bytes
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| anyhow!("Invalid cloud config key"))?;
+    VerifyingKey::from_bytes(&bytes).map_err(|e| anyhow!("Invalid cloud config key: {e}"))
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64)
+}
+
+/// The newest accepted config.
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Clone, Default)]
+struct Watermark {
+    issued_at: i64,
+    payload_hash: String,
+}
+
+impl Watermark {
+    fn load(dir: Option<&Path>) -> Self {
+        let Some(dir) = dir else {
+            return MEMORY
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .clone()
+                .unwrap_or_default();
+        };
+        let Ok(data) = fs::read(dir.join(STATE_FILE)) else {
+            return Self::default();
+        };
+        let value: Value = serde_json::from_slice(&data).unwrap_or_default();
+        Self {
+            issued_at: value["issuedAt"].as_i64().unwrap_or(0),
+            payload_hash: value["payloadHash"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+        }
+    }
+
+    fn save(&self, dir: &Path) -> Result<()> {
+        let value = json!({"issuedAt": self.issued_at, "payloadHash": self.payload_hash});
+        font_converter::write_atomically(&dir.join(STATE_FILE), value.to_string().as_bytes())
+    }
+}
+
+/// Check an envelope against `key` and the last accepted config, returning
+/// the config and the hash of its payload.
+fn verify(
+    envelope: &str,
+    key: &VerifyingKey,
+    state: &Watermark,
+    now: i64,
+) -> Result<(CloudConfig, String)> {
+    let envelope: Value =
+        serde_json::from_str(envelope).map_err(|e| anyhow!("Invalid cloud config: {e}"))?;
+    let field = |name: &str| -> Result<Vec<u8>> {
+        let text = envelope[name]
+            .as_str()
+            .ok_or_else(|| anyhow!("Cloud config has no {name}"))?;
+        base64::engine::general_purpose::STANDARD
+            .decode(text.trim())
+            .map_err(|e| anyhow!("Invalid cloud config {name}: {e}"))
+    };
+    let payload = field("payload")?;
+    let signature = Signature::from_slice(&field("signature")?)
+        .map_err(|e| anyhow!("Invalid cloud config signature: {e}"))?;
+    key.verify_strict(&payload, &signature)
+        .map_err(|_| anyhow!("Cloud config signature does not match"))?;
+
+    let config = parse_config(&payload)?;
+    let hash = blake3::hash(&payload).to_hex().to_string();
+    if config.issued_at < state.issued_at
+        || (config.issued_at == state.issued_at && hash != state.payload_hash)
+    {
+        return Err(anyhow!("Cloud config is older than the one in use"));
+    }
+    if config.issued_at > now + MAX_CLOCK_SKEW_SECS {
+        return Err(anyhow!("Cloud config is dated in the future"));
+    }
+    if config
+        .expires_at
+        .is_some_and(|expires_at| expires_at <= now)
+    {
+        return Err(anyhow!("Cloud config has expired"));
+    }
+    Ok((config, hash))
+}
+
+fn parse_config(payload: &[u8]) -> Result<CloudConfig> {
+    let config: Value = serde_json::from_slice(payload)
+        .map_err(|e| anyhow!("Invalid cloud config payload: {e}"))?;
+    let issued_at = config["issuedAt"]
+        .as_i64()
+        .ok_or_else(|| anyhow!("Cloud config has no issuedAt"))?;
+    let string = |name: &str| config[name].as_str().map(str::to_string);
+    Ok(CloudConfig {
+        issued_at,
+        expires_at: config["expiresAt"].as_i64(),
+        compliance_mode: config["complianceMode"].as_bool().unwrap_or(false),
+        disabled_features: config["disabledFeatures"]
+            .as_array()
+            .map(|features| {
+                features
+                    .iter()
+                    .filter_map(|feature| feature.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        min_app_version: string("minAppVersion"),
+        announcement: string("announcement"),
+        values: config["values"]
+            .as_object()
+            .map(|values| {
+                values
+                    .iter()
+                    .map(|(key, value)| CloudConfigValue {
+                        key: key.clone(),
+                        value: match value {
+                            Value::String(text) => text.clone(),
+                            other => other.to_string(),
+                        },
+                    })
+                    .collect()
+            })
+            .unwrap_or_default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_dirs::temp_dir;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    const NOW: i64 = 1_760_000_000;
+
+    fn signing_key() -> SigningKey {
+        SigningKey::from_bytes(&[7; 32])
+    }
+
+    fn envelope(payload: &str, key: &SigningKey) -> String {
+        let engine = base64::engine::general_purpose::STANDARD;
+        let signature = key.sign(payload.as_bytes());
+        json!({
+            "payload": engine.encode(payload),
+            "signature": engine.encode(signature.to_bytes()),
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn test_verifies_and_parses_config() {
+        let key = signing_key();
+        let payload = r#"{"issuedAt": 1759990000, "complianceMode": true,
+            "disabledFeatures": ["sources"], "minAppVersion": "1.4.0",
+            "values": {"maxDownloads": 3, "feedbackUrl": "https://example.com"}}"#;
+        let (config, _) = verify(
+            &envelope(payload, &key),
+            &key.verifying_key(),
+            &Watermark::default(),
+            NOW,
+        )
+        .unwrap();
+        assert!(config.compliance_mode);
+        assert_eq!(config.disabled_features, vec!["sources"]);
+        assert_eq!(config.min_app_version.as_deref(), Some("1.4.0"));
+        // Values keep the payload's order rather than being sorted by key.
+        assert_eq!(config.values[0].key, "maxDownloads");
+        assert_eq!(config.values[0].value, "3");
+        assert_eq!(config.values[1].key, "feedbackUrl");
+
+        // A tampered payload or a different key fails.
+        let other = SigningKey::from_bytes(&[8; 32]);
+        assert!(verify(
+            &envelope(payload, &other),
+            &key.verifying_key(),
+            &Watermark::default(),
+            NOW
+        )
+        .is_err());
+        let mut forged: Value = serde_json::from_str(&envelope(payload, &key)).unwrap();
+        forged["payload"] = base64::engine::general_purpose::STANDARD
+            .encode(payload.replace("true", "false"))
+            .into();
+        assert!(verify(
+            &forged.to_string(),
+            &key.verifying_key(),
+            &Watermark::default(),
+            NOW
+        )
+        .is_err());
+        let hex: String = key
+            .verifying_key()
+            .as_bytes()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+        assert_eq!(parse_key(&hex).unwrap(), key.verifying_key());
+    }
+
+    #[test]
+    fn test_rejects_stale_and_expired_configs() {
+        let key = signing_key();
+        let public = key.verifying_key();
+        let current = r#"{"issuedAt": 1759990000}"#;
+        let (_, hash) = verify(
+            &envelope(current, &key),
+            &public,
+            &Watermark::default(),
+            NOW,
+        )
+        .unwrap();
+        let state = Watermark {
+            issued_at: 1759990000,
+            payload_hash: hash,
+        };
+
+        // The accepted config itself still verifies; older or same-dated
+        // different ones do not.
+        assert!(verify(&envelope(current, &key), &public, &state, NOW).is_ok());
+        let older = r#"{"issuedAt": 1759980000}"#;
+        assert!(verify(&envelope(older, &key), &public, &state, NOW).is_err());
+        let same_time = r#"{"issuedAt": 1759990000, "complianceMode": true}"#;
+        assert!(verify(&envelope(same_time, &key), &public, &state, NOW).is_err());
+
+        let expired = r#"{"issuedAt": 1759995000, "expiresAt": 1759999999}"#;
+        assert!(verify(&envelope(expired, &key), &public, &state, NOW).is_err());
+        let future = r#"{"issuedAt": 1770000000}"#;
+        assert!(verify(&envelope(future, &key), &public, &state, NOW).is_err());
+    }
+
+    #[test]
+    fn test_state_round_trip() {
+        let dir = temp_dir("cloudctl-state");
+        assert_eq!(Watermark::load(Some(&dir)).issued_at, 0);
+        let state = Watermark {
+            issued_at: 42,
+            payload_hash: "abc".to_string(),
+        };
+        state.save(&dir).unwrap();
+        let loaded = Watermark::load(Some(&dir));
+        assert_eq!(
+            (loaded.issued_at, loaded.payload_hash.as_str()),
+            (42, "abc")
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
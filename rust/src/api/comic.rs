@@ -0,0 +1,413 @@
+//! Comic archive (CBZ/CBR) reading.
+//!
+//! Pages are the image entries of the archive in natural sort order, so
+//! `page2.jpg` comes before `page10.jpg`. Pages are read one at a time
+//! straight from the archive: CBZ entries are read by name, and CBR entries
+//! are found by stepping through the RAR headers without extracting the
+//! entries in between.
+
+use std::fs::File;
+use std::io::{Cursor, Read};
+
+use anyhow::{anyhow, Result};
+use image::codecs::jpeg::JpegEncoder;
+use image::imageops::FilterType;
+use image::{ImageFormat, ImageReader};
+use unrar::Archive;
+use zip::ZipArchive;
+
+use crate::api::chapter_sort::natural_cmp;
+use crate::api::error::ApiError;
+
+const IMAGE_EXTENSIONS: [&str; 6] = ["jpg", "jpeg", "png", "gif", "webp", "bmp"];
+
+/// JPEG quality used when re-encoding downscaled pages.
+const JPEG_QUALITY: u8 = 85;
+
+#[derive(Debug, Clone)]
+pub struct ComicPage {
+    /// Position in reading order; pass this to `read_comic_page`.
+    pub index: u32,
+    /// Entry path inside the archive.
+    pub name: String,
+    /// Uncompressed size in bytes.
+    pub size: u32,
+}
+
+/// List the page images of a CBZ or CBR archive in reading order.
+///
+/// # Arguments
+/// * `path` - Path to the comic archive
+#[flutter_rust_bridge::frb]
+pub fn list_comic_pages(path: String) -> Result<Vec<ComicPage>, ApiError> {
+    let mut pages = match detect_format(&path)? {
+        Format::Zip => list_zip(&path)?,
+        Format::Rar => list_rar(&path)?,
+    };
+    pages.retain(|(name, _)| is_page(name));
+    pages.sort_by(|a, b| natural_cmp(&a.0, &b.0));
+
+    Ok(pages
+        .into_iter()
+        .enumerate()
+        .map(|(index, (name, size))| ComicPage {
+            index: index as u32,
+            name,
+            size: size.min(u32::MAX as u64) as u32,
+        })
+        .collect())
+}
+
+/// Read one page image, optionally scaled down to fit a bounding box.
+///
+/// Pages that already fit are returned unchanged. Downscaled JPEGs are
+/// re-encoded as JPEG, other formats as PNG.
+///
+/// # Arguments
+/// * `path` - Path to the comic archive
+/// * `index` - Page index from `list_comic_pages`
+/// * `max_width` - Maximum width in pixels
+/// * `max_height` - Maximum height in pixels
+#[flutter_rust_bridge::frb]
+pub fn read_comic_page(
+    path: String,
+    index: u32,
+    max_width: Option<u32>,
+    max_height: Option<u32>,
+) -> Result<Vec<u8>, ApiError> {
+    let pages = list_comic_pages(path.clone())?;
+    let page = pages
+        .get(index as usize)
+        .ok_or_else(|| anyhow!("Page {index} out of range (0..{})", pages.len()))?;
+    let data = match detect_format(&path)? {
+        Format::Zip => read_zip_entry(&path, &page.name)?,
+        Format::Rar => read_rar_entry(&path, &page.name)?,
+    };
+
+    if max_width.is_none() && max_height.is_none() {
+        return Ok(data);
+    }
+    Ok(downscale(
+        data,
+        max_width.unwrap_or(u32::MAX),
+        max_height.unwrap_or(u32::MAX),
+    )?)
+}
+
+enum Format {
+    Zip,
+    Rar,
+}
+
+fn detect_format(path: &str) -> Result<Format> {
+    let mut magic = [0; 6];
+    File::open(path)
+        .and_then(|mut file| file.read_exact(&mut magic))
+        .map_err(|e| anyhow!("Failed to open {path}: {e}"))?;
+    if magic.starts_with(b"PK\x03\x04") {
+        Ok(Format::Zip)
+    } else if magic.starts_with(b"Rar!\x1A\x07") {
+        Ok(Format::Rar)
+    } else {
+        Err(anyhow!("Unsupported comic archive {path}"))
+    }
+}
+
+fn open_zip(path: &str) -> Result<ZipArchive<File>> {
+    let file = File::open(path).map_err(|e| anyhow!("Failed to open {path}: {e}"))?;
+    ZipArchive::new(file).map_err(|e| anyhow!("Invalid comic archive {path}: {e}"))
+}
+
+fn list_zip(path: &str) -> Result<Vec<(String, u64)>> {
+    let mut archive = open_zip(path)?;
+    let mut entries = Vec::new();
+    for i in 0..archive.len() {
+        let entry = archive
+            .by_index_raw(i)
+            .map_err(|e| anyhow!("Invalid comic archive {path}: {e}"))?;
+        if entry.is_file() {
+            entries.push((entry.name().to_string(), entry.size()));
+        }
+    }
+    Ok(entries)
+}
+
+fn read_zip_entry(path: &str, name: &str) -> Result<Vec<u8>> {
+    let mut archive = open_zip(path)?;
+    let mut entry = archive
+        .by_name(name)
+        .map_err(|e| anyhow!("Failed to read {name}: {e}"))?;
+    let mut data = Vec::with_capacity(entry.size() as usize);
+    entry
+        .read_to_end(&mut data)
+        .map_err(|e| anyhow!("Failed to read {name}: {e}"))?;
+    Ok(data)
+}
+
+fn rar_name(header: &unrar::FileHeader) -> String {
+    // RAR stores Windows separators on some hosts.
+    header.filename.to_string_lossy().replace('\\', "/")
+}
+
+fn list_rar(path: &str) -> Result<Vec<(String, u64)>> {
+    let archive = Archive::new(path)
+        .open_for_listing()
+        .map_err(|e| anyhow!("Invalid comic archive {path}: {e}"))?;
+    let mut entries = Vec::new();
+    for header in archive {
+        let header = header.map_err(|e| anyhow!("Invalid comic archive {path}: {e}"))?;
+        if header.is_encrypted() {
+            return Err(anyhow!("Encrypted comic archives are not supported"));
+        }
+        if header.is_file() {
+            entries.push((rar_name(&header), header.unpacked_size));
+        }
+    }
+    Ok(entries)
+}
+
+fn read_rar_entry(path: &str, name: &str) -> Result<Vec<u8>> {
+    let mut archive = Archive::new(path)
+        .open_for_processing()
+        .map_err(|e| anyhow!("Invalid comic archive {path}: {e}"))?;
+    loop {
+        let entry = archive
+            .read_header()
+            .map_err(|e| anyhow!("Invalid comic archive {path}: {e}"))?
+            .ok_or_else(|| anyhow!("Failed to read {name}: not found"))?;
+        if rar_name(entry.entry()) == name {
+            let (data, _) = entry
+                .read()
+                .map_err(|e| anyhow!("Failed to read {name}: {e}"))?;
+            return Ok(data);
+        }
+        archive = entry
+            .skip()
+            .map_err(|e| anyhow!("Invalid comic archive {path}: {e}"))?;
+    }
+}
+
+/// Whether an archive entry is a page image rather than metadata or
+/// resource-fork junk.
+fn is_page(name: &str) -> bool {
+    if name
+        .split('/')
+        .any(|part| part.starts_with('.') || part == "__MACOSX")
+    {
+        return false;
+    }
+    let extension = name
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_ascii_lowercase());
+    extension.is_some_and(|ext| IMAGE_EXTENSIONS.contains(&ext.as_str()))
+}
+
+fn downscale(data: Vec<u8>, max_width: u32, max_height: u32) -> Result<Vec<u8>> {
+    let reader = ImageReader::new(Cursor::new(&data))
+        .with_guessed_format()
+        .map_err(|e| anyhow!("Failed to read page image: {e}"))?;
+    let format = reader.format();
+    let image = reader
+        .decode()
+        .map_err(|e| anyhow!("Failed to decode page image: {e}"))?;
+    if image.width() <= max_width && image.height() <= max_height {
+        return Ok(data);
+    }
+
+    let resized = image.resize(max_width.max(1), max_height.max(1), FilterType::Triangle);
+    let mut output = Vec::new();
+    if format == Some(ImageFormat::Jpeg) {
+        JpegEncoder::new_with_quality(&mut output, JPEG_QUALITY)
+            .encode_image(&resized.to_rgb8())
+            .map_err(|e| anyhow!("Failed to encode page image: {e}"))?;
+    } else {
+        resized
+            .write_to(&mut Cursor::new(&mut output), ImageFormat::Png)
+            .map_err(|e| anyhow!("Failed to encode page image: {e}"))?;
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_dirs::temp_dir;
+    use image::{Rgb, RgbImage};
+    use std::io::Write;
+    use std::path::{Path, PathBuf};
+    use zip::write::SimpleFileOptions;
+    use zip::ZipWriter;
+
+    fn temp_path(name: &str) -> PathBuf {
+        temp_dir("comic").join(name)
+    }
+
+    /// Remove a file from `temp_path` along with its directory.
+    fn remove(path: impl AsRef<Path>) {
+        std::fs::remove_dir_all(path.as_ref().parent().unwrap()).unwrap();
+    }
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let image = RgbImage::from_pixel(width, height, Rgb([200, 40, 40]));
+        let mut data = Vec::new();
+        image
+            .write_to(&mut Cursor::new(&mut data), ImageFormat::Png)
+            .unwrap();
+        data
+    }
+
+    fn write_cbz(name: &str, entries: &[(&str, &[u8])]) -> String {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        for (entry, data) in entries {
+            writer
+                .start_file(*entry, SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(data).unwrap();
+        }
+        let path = temp_path(name);
+        std::fs::write(&path, writer.finish().unwrap().into_inner()).unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    /// Build a RAR 4 archive with stored (uncompressed) entries.
+    fn write_cbr(name: &str, entries: &[(&str, &[u8])]) -> String {
+        fn crc32(data: &[u8]) -> u32 {
+            let mut crc = flate2::Crc::new();
+            crc.update(data);
+            crc.sum()
+        }
+        fn block(body: &[u8]) -> Vec<u8> {
+            let mut block = (crc32(body) as u16).to_le_bytes().to_vec();
+            block.extend_from_slice(body);
+            block
+        }
+
+        let mut rar = b"Rar!\x1A\x07\x00".to_vec();
+        let mut main = vec![0x73, 0, 0];
+        main.extend_from_slice(&13u16.to_le_bytes());
+        main.extend_from_slice(&[0; 6]);
+        rar.extend(block(&main));
+        for (entry, data) in entries {
+            let mut header = vec![0x74, 0x00, 0x80];
+            header.extend_from_slice(&(32 + entry.len() as u16).to_le_bytes());
+            header.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            header.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            header.push(0);
+            header.extend_from_slice(&crc32(data).to_le_bytes());
+            header.extend_from_slice(&0x5A_21_00_00u32.to_le_bytes());
+            header.extend_from_slice(&[20, 0x30]);
+            header.extend_from_slice(&(entry.len() as u16).to_le_bytes());
+            header.extend_from_slice(&0x20u32.to_le_bytes());
+            header.extend_from_slice(entry.as_bytes());
+            rar.extend(block(&header));
+            rar.extend_from_slice(data);
+        }
+        rar.extend(block(&[0x7B, 0x00, 0x40, 0x07, 0x00]));
+
+        let path = temp_path(name);
+        std::fs::write(&path, rar).unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    fn names(pages: &[ComicPage]) -> Vec<&str> {
+        pages.iter().map(|page| page.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_natural_cmp() {
+        let mut names = vec![
+            "page10.jpg",
+            "page2.jpg",
+            "Page1.jpg",
+            "page02a.jpg",
+            "ch2/001.jpg",
+            "ch10/001.jpg",
+            "ch2/010.jpg",
+        ];
+        names.sort_by(|a, b| natural_cmp(a, b));
+        assert_eq!(
+            names,
+            vec![
+                "ch2/001.jpg",
+                "ch2/010.jpg",
+                "ch10/001.jpg",
+                "Page1.jpg",
+                "page2.jpg",
+                "page02a.jpg",
+                "page10.jpg",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cbz_lists_and_reads_pages() {
+        let page = png(4, 4);
+        let path = write_cbz(
+            "list.cbz",
+            &[
+                ("ComicInfo.xml", b"<ComicInfo/>"),
+                ("10.png", &page),
+                ("2.PNG", b"second"),
+                ("__MACOSX/._2.png", b"junk"),
+                (".thumb.jpg", b"junk"),
+            ],
+        );
+
+        let pages = list_comic_pages(path.clone()).unwrap();
+        assert_eq!(names(&pages), vec!["2.PNG", "10.png"]);
+        assert_eq!(pages[0].size, 6);
+        assert_eq!(
+            read_comic_page(path.clone(), 0, None, None).unwrap(),
+            b"second"
+        );
+        assert_eq!(read_comic_page(path.clone(), 1, None, None).unwrap(), page);
+        assert!(read_comic_page(path.clone(), 2, None, None).is_err());
+        remove(path);
+    }
+
+    #[test]
+    fn test_cbr_lists_and_reads_pages() {
+        let path = write_cbr(
+            "list.cbr",
+            &[
+                ("vol1\\page10.jpg", b"ten"),
+                ("vol1\\page9.jpg", b"nine"),
+                ("notes.txt", b"text"),
+            ],
+        );
+
+        let pages = list_comic_pages(path.clone()).unwrap();
+        assert_eq!(names(&pages), vec!["vol1/page9.jpg", "vol1/page10.jpg"]);
+        assert_eq!(
+            read_comic_page(path.clone(), 1, None, None).unwrap(),
+            b"ten"
+        );
+        assert_eq!(
+            read_comic_page(path.clone(), 0, None, None).unwrap(),
+            b"nine"
+        );
+        remove(path);
+    }
+
+    #[test]
+    fn test_downscale_fits_bounding_box() {
+        let page = png(400, 200);
+        let path = write_cbz("scale.cbz", &[("1.png", &page)]);
+
+        let scaled = read_comic_page(path.clone(), 0, Some(100), None).unwrap();
+        let image = image::load_from_memory(&scaled).unwrap();
+        assert_eq!((image.width(), image.height()), (100, 50));
+
+        let unchanged = read_comic_page(path.clone(), 0, Some(1000), Some(1000)).unwrap();
+        assert_eq!(unchanged, page);
+        remove(path);
+    }
+
+    #[test]
+    fn test_rejects_other_files() {
+        let path = temp_path("other.cbz");
+        std::fs::write(&path, b"7z\xBC\xAF\x27\x1C").unwrap();
+        assert!(list_comic_pages(path.to_string_lossy().into_owned()).is_err());
+        remove(path);
+    }
+}
@@ -0,0 +1,546 @@
+//! Persistent cookie jar shared by every network request.
+//!
+//! Once `init_cookie_jar` is called, agents from `network::agent` send the
+//! cookies stored for each request's URL and keep the ones responses set,
+//! so the downloader and the rules engine share a source's login session
+//! and it survives restarts. Domains and paths are matched as in RFC 6265,
+//! without a public suffix list: a `Domain` attribute needs at least two
+//! labels. Session cookies are kept too, since many sources issue nothing
+//! else on login; expired cookies are dropped when read and when saved.
+//!
+//! The jar follows redirects itself instead of leaving them to `ureq`, whose
+//! middleware only sees the last response, so cookies set on a redirect,
+//! as login forms usually do, are kept. It is sealed with the token vault
+//! key (see `init_token_vault`) before it is written.
+
+use std::cell::Cell;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+use url::Url;
+
+use crate::api::error::ApiError;
+use crate::api::{font_converter, http_cache, logging, vault};
+
+const JAR_FILE: &str = "cookies.jar";
+
+/// Starts the jar file, before the sealed JSON.
+const MAGIC: &[u8] = b"NVCJ1";
+
+/// Name the jar is sealed under.
+const SEAL_NAME: &str = "cookie_jar";
+
+/// Redirects followed before a request fails, as in `ureq`.
+const MAX_REDIRECTS: usize = 5;
+
+static COOKIE_JAR: Mutex<Option<CookieJar>> = Mutex::new(None);
+
+thread_local! {
+    /// Set while the jar follows a redirect, so the hop's own middleware
+    /// returns redirects instead of following them again.
+    static FOLLOWING: Cell<bool> = const { Cell::new(false) };
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredCookie {
+    pub name: String,
+    pub value: String,
+    /// Host or domain, lowercase and without a leading dot.
+    pub domain: String,
+    /// Only sent to `domain` itself, not to its subdomains.
+    pub host_only: bool,
+    pub path: String,
+    /// Seconds since the Unix epoch; `None` for a session cookie.
+    pub expires: Option<i64>,
+    /// Only sent over HTTPS.
+    pub secure: bool,
+    pub http_only: bool,
+}
+
+/// Load the cookie jar from `jar_dir` and start using it for requests. The
+/// token vault must be unlocked.
+///
+/// # Arguments
+/// * `jar_dir` - Directory owned by the jar; created if missing
+#[flutter_rust_bridge::frb]
+pub fn init_cookie_jar(jar_dir: String) -> Result<(), ApiError> {
+    let jar = CookieJar::open(Path::new(&jar_dir))?;
+    *lock() = Some(jar);
+    Ok(())
+}
+
+/// Cookies in the jar, for a cookie management screen.
+///
+/// # Arguments
+/// * `host` - Only cookies sent to this host, or `None` for all of them
+#[flutter_rust_bridge::frb]
+pub fn list_cookies(host: Option<String>) -> Result<Vec<StoredCookie>, ApiError> {
+    let mut jar = lock();
+    let jar = initialized(&mut jar)?;
+    let now = now();
+    Ok(jar
+        .cookies
+        .iter()
+        .filter(|cookie| !cookie.is_expired(now))
+        .filter(|cookie| host.as_deref().is_none_or(|host| cookie.matches_host(host)))
+        .cloned()
+        .collect())
+}
+
+/// Delete cookies, such as to log out of a source, and return how many
+/// were deleted.
+///
+/// # Arguments
+/// * `host` - Only cookies sent to this host, or `None` for all of them
+#[flutter_rust_bridge::frb]
+pub fn clear_cookies(host: Option<String>) -> Result<u32, ApiError> {
+    let mut jar = lock();
+    let jar = initialized(&mut jar)?;
+    let before = jar.cookies.len();
+    match &host {
+        Some(host) => jar.cookies.retain(|cookie| !cookie.matches_host(host)),
+        None => jar.cookies.clear(),
+    }
+    let removed = before - jar.cookies.len();
+    if removed > 0 {
+        jar.save()?;
+    }
+    Ok(removed as u32)
+}
+
+/// Store cookies as if a response from `url` had set them, such as the
+/// cookies of a WebView login.
+///
+/// # Arguments
+/// * `url` - Page the cookies belong to
+/// * `cookies` - `Set-Cookie` header values; the `name=value` pairs of a
+///   `Cookie` header work too, as session cookies
+#[flutter_rust_bridge::frb]
+pub fn add_cookies(url: String, cookies: Vec<String>) -> Result<u32, ApiError> {
+    let url = Url::parse(&url).map_err(|e| anyhow!("Invalid URL {url}: {e}"))?;
+    let mut jar = lock();
+    let jar = initialized(&mut jar)?;
+    let stored = jar.store(&url, cookies.iter().map(String::as_str), now());
+    if stored > 0 {
+        jar.save()?;
+    }
+    Ok(stored as u32)
+}
+
+/// Whether requests should go through `handle`.
+pub(crate) fn enabled() -> bool {
+    lock().is_some()
+}
+
+/// `ureq` middleware that sends and stores cookies and follows redirects,
+/// getting the agent for each hop from `agent`. The agent running it must
+/// not follow redirects itself.
+// The signature is fixed by `ureq::Middleware`.
+#[allow(clippy::result_large_err)]
+pub(crate) fn handle(
+    request: ureq::Request,
+    next: ureq::MiddlewareNext,
+    agent: &dyn Fn(&str) -> Result<ureq::Agent>,
+) -> Result<ureq::Response, ureq::Error> {
+    if FOLLOWING.get() {
+        return exchange(request, next);
+    }
+    let mut method = request.method().to_string();
+    let mut url = Url::parse(request.url()).map_err(|e| std::io::Error::other(e.to_string()))?;
+    let origin = url.host_str().map(str::to_string);
+    let headers: Vec<(String, String)> = request
+        .header_names()
+        .into_iter()
+        .filter_map(|name| Some((name.clone(), request.header(&name)?.to_string())))
+        .collect();
+    let mut response = exchange(request, next)?;
+
+    for _ in 0..MAX_REDIRECTS {
+        let status = response.status();
+        let location = match (status, response.header("Location")) {
+            (301 | 302 | 303 | 307 | 308, Some(location)) => location,
+            _ => return Ok(response),
+        };
+        let Ok(target) = url.join(location) else {
+            return Ok(response);
+        };
+        let keep_body = matches!(status, 307 | 308);
+        if keep_body && !matches!(method.as_str(), "GET" | "HEAD") {
+            // The body was already sent and cannot be replayed.
+            return Ok(response);
+        }
+        if !keep_body && method != "HEAD" {
+            method = "GET".to_string();
+        }
+        let hop = agent(target.as_str()).map_err(|e| std::io::Error::other(e.to_string()))?;
+        let mut request = hop.request_url(&method, &target);
+        let same_host = target.host_str().map(str::to_string) == origin;
+        for (name, value) in &headers {
+            let skipped = name.eq_ignore_ascii_case("Cookie")
+                || name.eq_ignore_ascii_case("Content-Length")
+                || (!keep_body && name.eq_ignore_ascii_case("Content-Type"))
+                || (!same_host && name.eq_ignore_ascii_case("Authorization"));
+            if !skipped {
+                request = request.set(name, value);
+            }
+        }
+        FOLLOWING.set(true);
+        let result = request.call();
+        FOLLOWING.set(false);
+        response = result?;
+        url = target;
+    }
+    Err(std::io::Error::other(format!("Too many redirects from {}", response.get_url())).into())
+}
+
+/// Send one request with the jar's cookies and store the cookies it sets.
+#[allow(clippy::result_large_err)]
+fn exchange(
+    mut request: ureq::Request,
+    next: ureq::MiddlewareNext,
+) -> Result<ureq::Response, ureq::Error> {
+    let Ok(url) = Url::parse(request.url()) else {
+        return next.handle(request);
+    };
+    // Cookies set by the caller, such as a source's `Cookie` header, win.
+    if request.header("Cookie").is_none() {
+        let header = lock().as_ref().and_then(|jar| jar.header_for(&url, now()));
+        if let Some(header) = header {
+            request = request.set("Cookie", &header);
+        }
+    }
+    let result = next.handle(request);
+    let response = match &result {
+        Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+        Err(_) => return result,
+    };
+    let set_cookies = response.all("Set-Cookie");
+    if !set_cookies.is_empty() {
+        if let Some(jar) = lock().as_mut() {
+            if jar.store(&url, set_cookies.into_iter(), now()) > 0 {
+                if let Err(e) = jar.save() {
+                    logging::warn("cookies", format!("Failed to save cookies: {e}"));
+                }
+            }
+        }
+    }
+    result
+}
+
+fn lock() -> MutexGuard<'static, Option<CookieJar>> {
+    COOKIE_JAR.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn initialized(jar: &mut Option<CookieJar>) -> Result<&mut CookieJar> {
+    jar.as_mut()
+        .ok_or_else(|| anyhow!("Cookie jar is not initialized"))
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+struct CookieJar {
+    path: PathBuf,
+    cookies: Vec<StoredCookie>,
+}
+
+impl CookieJar {
+    fn open(dir: &Path) -> Result<Self> {
+        fs::create_dir_all(dir).map_err(|e| anyhow!("Failed to create {}: {e}", dir.display()))?;
+        let path = dir.join(JAR_FILE);
+        let cookies = match fs::read(&path) {
+            Ok(data) => {
+                let sealed = data
+                    .strip_prefix(MAGIC)
+                    .ok_or_else(|| anyhow!("Cookie jar file is not recognized"))?;
+                let json = vault::open_data(SEAL_NAME, sealed)?;
+                parse_cookies(&json)?
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(anyhow!("Failed to read {}: {e}", path.display())),
+        };
+        let now = now();
+        let cookies = cookies.into_iter().filter(|c| !c.is_expired(now)).collect();
+        Ok(Self { path, cookies })
+    }
+
+    fn save(&mut self) -> Result<()> {
+        let now = now();
+        self.cookies.retain(|cookie| !cookie.is_expired(now));
+        let json = Value::Array(self.cookies.iter().map(cookie_json).collect()).to_string();
+        let mut data = MAGIC.to_vec();
+        data.extend_from_slice(&vault::seal_data(SEAL_NAME, json.as_bytes())?);
+        font_converter::write_atomically(&self.path, &data)
+    }
+
+    /// Store `Set-Cookie` values received from `url`, returning how many
+    /// were accepted. Expired ones delete the cookie they replace.
+    fn store<'a>(&mut self, url: &Url, headers: impl Iterator<Item = &'a str>, now: i64) -> usize {
+        let mut stored = 0;
+        for header in headers {
+            let Some(cookie) = parse_set_cookie(header, url, now) else {
+                continue;
+            };
+            self.cookies.retain(|c| {
+                (&c.name, &c.domain, &c.path) != (&cookie.name, &cookie.domain, &cookie.path)
+            });
+            if !cookie.is_expired(now) {
+                self.cookies.push(cookie);
+            }
+            stored += 1;
+        }
+        stored
+    }
+
+    /// The `Cookie` header for a request to `url`, longest paths first.
+    fn header_for(&self, url: &Url, now: i64) -> Option<String> {
+        let host = url.host_str()?;
+        let mut cookies: Vec<&StoredCookie> = self
+            .cookies
+            .iter()
+            .filter(|c| !c.is_expired(now) && c.matches_host(host))
+            .filter(|c| path_matches(url.path(), &c.path))
+            .filter(|c| !c.secure || url.scheme() == "https")
+            .collect();
+        if cookies.is_empty() {
+            return None;
+        }
+        cookies.sort_by_key(|c| std::cmp::Reverse(c.path.len()));
+        let pairs: Vec<String> = cookies
+            .iter()
+            .map(|c| format!("{}={}", c.name, c.value))
+            .collect();
+        Some(pairs.join("; "))
+    }
+}
+
+impl StoredCookie {
+    fn is_expired(&self, now: i64) -> bool {
+        self.expires.is_some_and(|expires| expires <= now)
+    }
+
+    fn matches_host(&self, host: &str) -> bool {
+        let host = host.to_ascii_lowercase();
+        host == self.domain
+            || (!self.host_only
+                && host
+                    .strip_suffix(self.domain.as_str())
+                    .is_some_and(|prefix| prefix.ends_with('.')))
+    }
+}
+
+/// Parse a `Set-Cookie` value received from `url`, or `None` if it is
+/// malformed or sets a cookie for another site.
+fn parse_set_cookie(header: &str, url: &Url, now: i64) -> Option<StoredCookie> {
+    let host = url.host_str()?.to_ascii_lowercase();
+    let mut parts = header.split(';');
+    let (name, value) = parts.next()?.split_once('=')?;
+    let name = name.trim();
+    if name.is_empty() {
+        return None;
+    }
+    let mut cookie = StoredCookie {
+        name: name.to_string(),
+        value: value.trim().trim_matches('"').to_string(),
+        domain: host.clone(),
+        host_only: true,
+        path: default_path(url.path()),
+        expires: None,
+        secure: false,
+        http_only: false,
+    };
+    let mut max_age = None;
+    for attribute in parts {
+        let (key, value) = attribute.split_once('=').unwrap_or((attribute, ""));
+        let value = value.trim();
+        match key.trim().to_ascii_lowercase().as_str() {
+            "expires" => {
+                if let Some(expires) = http_cache::parse_http_date(&value.replace('-', " ")) {
+                    cookie.expires = Some(expires);
+                }
+            }
+            "max-age" => max_age = value.parse::<i64>().ok(),
+            "domain" => {
+                let domain = value.trim_start_matches('.').to_ascii_lowercase();
+                if domain.is_empty() {
+                    continue;
+                }
+                let parent = host
+                    .strip_suffix(domain.as_str())
+                    .is_some_and(|prefix| prefix.ends_with('.'));
+                if domain != host && (!parent || !domain.contains('.')) {
+                    return None;
+                }
+                cookie.host_only = domain == host;
+                cookie.domain = domain;
+            }
+            "path" if value.starts_with('/') => cookie.path = value.to_string(),
+            "secure" => cookie.secure = true,
+            "httponly" => cookie.http_only = true,
+            _ => {}
+        }
+    }
+    if let Some(max_age) = max_age {
+        cookie.expires = Some(if max_age <= 0 {
+            i64::MIN
+        } else {
+            now.saturating_add(max_age)
+        });
+    }
+    Some(cookie)
+}
+
+/// The directory of a request path, the path of cookies that set none.
+fn default_path(path: &str) -> String {
+    match path.rfind('/') {
+        Some(0) | None => "/".to_string(),
+        Some(end) => path[..end].to_string(),
+    }
+}
+
+fn path_matches(request_path: &str, cookie_path: &str) -> bool {
+    request_path
+        .strip_prefix(cookie_path)
+        .is_some_and(|rest| cookie_path.ends_with('/') || rest.is_empty() || rest.starts_with('/'))
+}
+
+fn cookie_json(cookie: &StoredCookie) -> Value {
+    json!({
+        "name": cookie.name,
+        "value": cookie.value,
+        "domain": cookie.domain,
+        "host_only": cookie.host_only,
+        "path": cookie.path,
+        "expires": cookie.expires,
+        "secure": cookie.secure,
+        "http_only": cookie.http_only,
+    })
+}
+
+fn parse_cookies(json: &[u8]) -> Result<Vec<StoredCookie>> {
+    let value: Value =
+        serde_json::from_slice(json).map_err(|e| anyhow!("Failed to parse cookie jar: {e}"))?;
+    let text = |cookie: &Value, key: &str| cookie[key].as_str().map(str::to_string);
+    let flag = |cookie: &Value, key: &str| cookie[key].as_bool().unwrap_or(false);
+    Ok(value
+        .as_array()
+        .ok_or_else(|| anyhow!("Failed to parse cookie jar: not an array"))?
+        .iter()
+        .filter_map(|cookie| {
+            Some(StoredCookie {
+                name: text(cookie, "name")?,
+                value: text(cookie, "value")?,
+                domain: text(cookie, "domain")?,
+                host_only: flag(cookie, "host_only"),
+                path: text(cookie, "path")?,
+                expires: cookie["expires"].as_i64(),
+                secure: flag(cookie, "secure"),
+                http_only: flag(cookie, "http_only"),
+            })
+        })
+        .collect())
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_760_000_000;
+
+    fn jar() -> CookieJar {
+        CookieJar {
+            path: PathBuf::new(),
+            cookies: Vec::new(),
+        }
+    }
+
+    fn url(url: &str) -> Url {
+        Url::parse(url).unwrap()
+    }
+
+    #[test]
+    fn test_sends_cookies_by_domain_and_path() {
+        let mut jar = jar();
+        let login = url("https://www.example.com/account/login");
+        let stored = jar.store(
+            &login,
+            [
+                "sid=abc; Domain=.example.com; Path=/; HttpOnly",
+                "pref=1",
+                "token=\"t\"; Secure; Path=/account",
+                "evil=1; Domain=other.com",
+                "tld=1; Domain=com",
+                "=nameless",
+            ]
+            .into_iter(),
+            NOW,
+        );
+        assert_eq!(stored, 3);
+        assert_eq!(
+            jar.header_for(&url("https://www.example.com/account/books"), NOW),
+            Some("pref=1; token=t; sid=abc".to_string())
+        );
+        // `pref` is host-only, `token` needs HTTPS and its path.
+        assert_eq!(
+            jar.header_for(&url("http://m.example.com/account"), NOW),
+            Some("sid=abc".to_string())
+        );
+        assert_eq!(
+            jar.header_for(&url("https://www.example.com/accounts"), NOW),
+            Some("sid=abc".to_string())
+        );
+        assert_eq!(jar.header_for(&url("https://example.org/"), NOW), None);
+        assert!(jar.cookies[0].matches_host("Example.COM"));
+        assert!(!jar.cookies[0].matches_host("notexample.com"));
+    }
+
+    #[test]
+    fn test_expiry_and_replacement() {
+        let mut jar = jar();
+        let site = url("https://example.com/");
+        jar.store(
+            &site,
+            [
+                "a=1; Max-Age=60",
+                "b=1; Expires=Thu, 01-Jan-2004 00:00:00 GMT",
+                "c=1; Expires=Fri, 01 Jan 2100 00:00:00 GMT; Max-Age=10",
+            ]
+            .into_iter(),
+            NOW,
+        );
+        assert_eq!(jar.header_for(&site, NOW), Some("a=1; c=1".to_string()));
+        assert_eq!(jar.header_for(&site, NOW + 30), Some("a=1".to_string()));
+        assert_eq!(jar.header_for(&site, NOW + 60), None);
+
+        jar.store(&site, ["a=2"].into_iter(), NOW);
+        assert_eq!(jar.cookies.iter().filter(|c| c.name == "a").count(), 1);
+        assert_eq!(jar.header_for(&site, NOW + 60), Some("a=2".to_string()));
+        // Max-Age=0 logs out.
+        jar.store(&site, ["a=; Max-Age=0"].into_iter(), NOW);
+        assert_eq!(jar.header_for(&site, NOW), Some("c=1".to_string()));
+    }
+
+    #[test]
+    fn test_jar_json_round_trip() {
+        let mut jar = jar();
+        jar.store(
+            &url("https://www.example.com/a/b"),
+            ["sid=abc; Domain=example.com; Secure; Max-Age=60", "pref=1"].into_iter(),
+            NOW,
+        );
+        let json = Value::Array(jar.cookies.iter().map(cookie_json).collect()).to_string();
+        let cookies = parse_cookies(json.as_bytes()).unwrap();
+        assert_eq!(cookies, jar.cookies);
+        assert_eq!(cookies[1].path, "/a");
+        assert_eq!(cookies[0].expires, Some(NOW + 60));
+        assert!(parse_cookies(b"{}").is_err());
+    }
+}
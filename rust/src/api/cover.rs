@@ -0,0 +1,380 @@
+//! Generated covers for books that have none.
+//!
+//! The title and author are drawn onto a solid or vertical-gradient
+//! background. Text is shaped with rustybuzz and wrapped with the same
+//! UAX #14 line breaking as the reader, so CJK titles break between
+//! characters without leaving `。` or `」` at the start of a line. A title
+//! that needs too many lines is shrunk, and cut with `…` if it still does
+//! not fit. Glyphs are rasterised with anti-aliasing and blended onto the
+//! background; characters the font lacks come out as its missing-glyph
+//! box, so pass a font that covers the title's script.
+
+use std::io::Cursor;
+
+use ab_glyph_rasterizer::{point, Point, Rasterizer};
+use anyhow::{anyhow, Result};
+use image::{ImageFormat, Rgba, RgbaImage};
+use rustybuzz::{Face, UnicodeBuffer};
+use ttf_parser::{GlyphId, OutlineBuilder};
+
+use crate::api::error::ApiError;
+use crate::api::line_break::Kinsoku;
+use crate::api::pagination;
+
+/// Most title lines before the title is shrunk.
+const MAX_TITLE_LINES: usize = 4;
+/// Smallest title size as a fraction of the requested one.
+const MIN_TITLE_SCALE: f32 = 0.6;
+const LINE_HEIGHT: f32 = 1.3;
+const ELLIPSIS: &str = "…";
+
+/// Background top, background bottom and text colours of the default styles.
+const PALETTES: [(u32, u32, u32); 8] = [
+    (0xFF2E_4057, 0xFF1B_2635, 0xFFF4_EBD9),
+    (0xFF8C_2F39, 0xFF5C_1A22, 0xFFFC_EFE3),
+    (0xFF38_5E4C, 0xFF1F_3A2D, 0xFFEE_F2E6),
+    (0xFFE8_D8B8, 0xFFD4_BF96, 0xFF3A_2E22),
+    (0xFF4A_3B6B, 0xFF2A_2140, 0xFFEF_E8F7),
+    (0xFFD9_E4EC, 0xFFB8_C9D6, 0xFF22_3344),
+    (0xFF6B_4226, 0xFF3F_2614, 0xFFF6_E7D0),
+    (0xFF1F_1F1F, 0xFF3A_3A3A, 0xFFE6_C27A),
+];
+
+/// Size and colours of a generated cover. Colours are `0xAARRGGBB`.
+#[derive(Debug, Clone)]
+pub struct CoverStyle {
+    pub width: u32,
+    pub height: u32,
+    /// Top colour of the background.
+    pub background: u32,
+    /// Bottom colour of the background; equal to `background` for a solid fill.
+    pub gradient_end: u32,
+    pub text_color: u32,
+    /// Title font size in pixels.
+    pub title_size: f32,
+    /// Author font size in pixels.
+    pub author_size: f32,
+    /// Space kept clear around the text, in pixels.
+    pub margin: f32,
+}
+
+/// A 600×900 style whose colours are picked from a fixed set by the title,
+/// so the same book always gets the same cover and neighbours differ.
+///
+/// # Arguments
+/// * `title` - Book title
+#[flutter_rust_bridge::frb]
+pub fn default_cover_style(title: String) -> CoverStyle {
+    let hash = blake3::hash(title.as_bytes());
+    let (background, gradient_end, text_color) =
+        PALETTES[hash.as_bytes()[0] as usize % PALETTES.len()];
+    CoverStyle {
+        width: 600,
+        height: 900,
+        background,
+        gradient_end,
+        text_color,
+        title_size: 64.0,
+        author_size: 32.0,
+        margin: 56.0,
+    }
+}
+
+/// Draw a cover and return it as PNG.
+///
+/// # Arguments
+/// * `title` - Book title
+/// * `author` - Author name; empty to leave it out
+/// * `font_path` - TTF or OTF file to draw with; the first face of a collection
+/// * `style` - Size and colours
+#[flutter_rust_bridge::frb]
+pub fn generate_cover(
+    title: String,
+    author: String,
+    font_path: String,
+    style: CoverStyle,
+) -> Result<Vec<u8>, ApiError> {
+    let data = pagination::load_font(&font_path)?;
+    let face =
+        Face::from_slice(&data, 0).ok_or_else(|| anyhow!("Failed to parse font {font_path}"))?;
+    let image = render(title.trim(), author.trim(), &face, &style)?;
+    let mut png = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+        .map_err(|e| anyhow!("Failed to encode cover: {e}"))?;
+    Ok(png)
+}
+
+fn render(title: &str, author: &str, face: &Face, style: &CoverStyle) -> Result<RgbaImage> {
+    if style.width == 0 || style.height == 0 {
+        return Err(anyhow!("Cover size must be positive"));
+    }
+    if style.title_size <= 0.0 || style.author_size <= 0.0 {
+        return Err(anyhow!("Font sizes must be positive"));
+    }
+    let (width, height) = (style.width as f32, style.height as f32);
+    let text_width = width - 2.0 * style.margin;
+    if text_width <= 0.0 {
+        return Err(anyhow!("Margins leave no room for text"));
+    }
+
+    let top = channels(style.background);
+    let bottom = channels(style.gradient_end);
+    let mut image = RgbaImage::from_fn(style.width, style.height, |_, y| {
+        let t = if style.height > 1 {
+            y as f32 / (style.height - 1) as f32
+        } else {
+            0.0
+        };
+        Rgba([0, 1, 2, 3].map(|c| (top[c] + (bottom[c] - top[c]) * t).round() as u8))
+    });
+
+    let mut text = Rasterizer::new(style.width as usize, style.height as usize);
+    let (size, lines) = wrap_title(title, face, style.title_size, text_width);
+    let line_height = size * LINE_HEIGHT;
+    let block = line_height * lines.len() as f32;
+    // Centre the title a little above the middle, leaving room for the author.
+    let mut y = ((height * 0.42 - block / 2.0).max(style.margin)).round();
+    for line in &lines {
+        draw_line(&mut text, face, line, size, width, y + ascent(face, size));
+        y += line_height;
+    }
+
+    if !author.is_empty() {
+        let widths = pagination::prefix_widths(author, face, scale(face, style.author_size));
+        let size = style.author_size * (text_width / widths[author.len()]).min(1.0);
+        let baseline = height - style.margin - descent(face, size);
+        draw_line(&mut text, face, author, size, width, baseline);
+
+        // A short rule between title and author.
+        let rule_y = (y + baseline - size) / 2.0;
+        let rule_width = (width * 0.15).min(text_width);
+        let (left, right) = ((width - rule_width) / 2.0, (width + rule_width) / 2.0);
+        let half = (size / 16.0).max(1.0) / 2.0;
+        let corners = [
+            point(left, rule_y - half),
+            point(right, rule_y - half),
+            point(right, rule_y + half),
+            point(left, rule_y + half),
+        ];
+        for (i, corner) in corners.iter().enumerate() {
+            text.draw_line(*corner, corners[(i + 1) % corners.len()]);
+        }
+    }
+
+    let color = channels(style.text_color);
+    text.for_each_pixel_2d(|x, y, coverage| {
+        let alpha = coverage.min(1.0) * color[3] / 255.0;
+        if alpha <= 0.0 {
+            return;
+        }
+        let pixel = image.get_pixel_mut(x, y);
+        for c in 0..3 {
+            let base = pixel[c] as f32;
+            pixel[c] = (base + (color[c] - base) * alpha).round() as u8;
+        }
+    });
+    Ok(image)
+}
+
+/// The title's font size and lines, shrinking and then truncating it until
+/// it fits in `MAX_TITLE_LINES`.
+fn wrap_title(title: &str, face: &Face, size: f32, width: f32) -> (f32, Vec<String>) {
+    let wrap = |size: f32| {
+        let widths = pagination::prefix_widths(title, face, scale(face, size));
+        pagination::break_lines(title, &widths, width, 0.0, 0.0, &Kinsoku::standard())
+    };
+    let floor = size * MIN_TITLE_SCALE;
+    let mut size = size;
+    let mut lines = wrap(size);
+    while lines.len() > MAX_TITLE_LINES && size * 0.9 >= floor {
+        size *= 0.9;
+        lines = wrap(size);
+    }
+    let mut lines: Vec<String> = lines
+        .into_iter()
+        .map(|(start, end)| title[start..end].to_string())
+        .collect();
+    if lines.len() > MAX_TITLE_LINES {
+        lines.truncate(MAX_TITLE_LINES);
+        let last = lines.last_mut().expect("title has lines");
+        let scale = scale(face, size);
+        loop {
+            let candidate = format!("{}{ELLIPSIS}", last.trim_end());
+            let widths = pagination::prefix_widths(&candidate, face, scale);
+            if widths[candidate.len()] <= width || last.is_empty() {
+                *last = candidate;
+                break;
+            }
+            last.pop();
+        }
+    }
+    (size, lines)
+}
+
+/// Draw `text` centred horizontally on a canvas `canvas_width` wide.
+pub(crate) fn draw_line(
+    raster: &mut Rasterizer,
+    face: &Face,
+    text: &str,
+    size: f32,
+    canvas_width: f32,
+    baseline: f32,
+) {
+    let scale = scale(face, size);
+    let mut buffer = UnicodeBuffer::new();
+    buffer.push_str(text);
+    let glyphs = rustybuzz::shape(face, &[], buffer);
+    let advance: f32 = glyphs
+        .glyph_positions()
+        .iter()
+        .map(|p| p.x_advance as f32 * scale)
+        .sum();
+    let mut x = (canvas_width - advance) / 2.0;
+    for (info, position) in glyphs.glyph_infos().iter().zip(glyphs.glyph_positions()) {
+        let mut outline = Outline {
+            raster: &mut *raster,
+            scale,
+            origin: point(
+                x + position.x_offset as f32 * scale,
+                baseline - position.y_offset as f32 * scale,
+            ),
+            start: point(0.0, 0.0),
+            last: point(0.0, 0.0),
+        };
+        face.outline_glyph(GlyphId(info.glyph_id as u16), &mut outline);
+        x += position.x_advance as f32 * scale;
+    }
+}
+
+pub(crate) fn scale(face: &Face, size: f32) -> f32 {
+    size / face.units_per_em() as f32
+}
+
+pub(crate) fn ascent(face: &Face, size: f32) -> f32 {
+    face.ascender() as f32 * scale(face, size)
+}
+
+pub(crate) fn descent(face: &Face, size: f32) -> f32 {
+    -(face.descender() as f32) * scale(face, size)
+}
+
+fn channels(color: u32) -> [f32; 4] {
+    [16, 8, 0, 24].map(|shift| ((color >> shift) & 0xFF) as f32)
+}
+
+/// Feeds glyph outlines, in font units with y up, into the rasteriser.
+struct Outline<'a> {
+    raster: &'a mut Rasterizer,
+    scale: f32,
+    origin: Point,
+    start: Point,
+    last: Point,
+}
+
+impl Outline<'_> {
+    fn map(&self, x: f32, y: f32) -> Point {
+        point(
+            self.origin.x + x * self.scale,
+            self.origin.y - y * self.scale,
+        )
+    }
+}
+
+impl OutlineBuilder for Outline<'_> {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.start = self.map(x, y);
+        self.last = self.start;
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let p = self.map(x, y);
+        self.raster.draw_line(self.last, p);
+        self.last = p;
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (c, p) = (self.map(x1, y1), self.map(x, y));
+        self.raster.draw_quad(self.last, c, p);
+        self.last = p;
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (c1, c2, p) = (self.map(x1, y1), self.map(x2, y2), self.map(x, y));
+        self.raster.draw_cubic(self.last, c1, c2, p);
+        self.last = p;
+    }
+
+    fn close(&mut self) {
+        if self.last != self.start {
+            self.raster.draw_line(self.last, self.start);
+        }
+        self.last = self.start;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fonts::{FontBuilder, Glyph};
+
+    fn font() -> Vec<u8> {
+        FontBuilder::with_chars("abcdefghijklmnopqrstuvwxyz天色渐晚城门外。「」…")
+            .glyph(' ', Glyph::Empty { advance: 600 })
+            .build()
+    }
+
+    fn style() -> CoverStyle {
+        CoverStyle {
+            background: 0xFF00_0000,
+            gradient_end: 0xFF00_0000,
+            text_color: 0xFFFF_FFFF,
+            ..default_cover_style(String::new())
+        }
+    }
+
+    #[test]
+    fn test_draws_title_and_author() {
+        let data = font();
+        let face = Face::from_slice(&data, 0).unwrap();
+        let image = render("天色渐晚", "abc", &face, &style()).unwrap();
+        assert_eq!(image.dimensions(), (600, 900));
+        let lit =
+            |y0: u32, y1: u32| (y0..y1).any(|y| (0..600).any(|x| image.get_pixel(x, y)[0] > 200));
+        // Title around the upper middle, author near the bottom, corners clear.
+        assert!(lit(300, 450));
+        assert!(lit(780, 850));
+        assert_eq!(image.get_pixel(5, 5)[0], 0);
+        assert_eq!(image.get_pixel(594, 894)[0], 0);
+    }
+
+    #[test]
+    fn test_long_title_shrinks_then_truncates() {
+        let data = font();
+        let face = Face::from_slice(&data, 0).unwrap();
+        // 64px squares of 0.6em leave 12 characters per 488px line.
+        let (size, lines) = wrap_title("天色渐晚城门外", &face, 64.0, 488.0);
+        assert_eq!((size, lines.len()), (64.0, 1));
+
+        let long = "天色渐晚城门外".repeat(12);
+        let (size, lines) = wrap_title(&long, &face, 64.0, 488.0);
+        assert!((64.0 * MIN_TITLE_SCALE - 0.01..64.0).contains(&size));
+        assert_eq!(lines.len(), MAX_TITLE_LINES);
+        assert!(lines[MAX_TITLE_LINES - 1].ends_with(ELLIPSIS));
+    }
+
+    #[test]
+    fn test_gradient_background() {
+        let data = font();
+        let face = Face::from_slice(&data, 0).unwrap();
+        let style = CoverStyle {
+            background: 0xFF00_0000,
+            gradient_end: 0xFFFF_0000,
+            ..style()
+        };
+        let image = render("", "", &face, &style).unwrap();
+        assert_eq!(image.get_pixel(0, 0)[0], 0);
+        assert_eq!(image.get_pixel(0, 899)[0], 255);
+        assert!(default_cover_style("a".into()).title_size > 0.0);
+    }
+}
@@ -0,0 +1,409 @@
+//! Toning down chapter images for the dark theme.
+//!
+//! Scanned illustrations and line art usually sit on white paper, which
+//! glares on a dark page. The image is first classified: its background is
+//! light if most of its border is near-white or transparent, and it is line
+//! art if hardly any of its other pixels carry colour. Line art on a light
+//! background has its lightness inverted into the page's colours, so paper
+//! becomes the page background and ink becomes the text colour; hue and
+//! saturation are kept, so a red stamp stays red. Colour illustrations
+//! would look like negatives that way, so only their background — the
+//! near-white region reaching the border — is recoloured to the page
+//! background and the rest is dimmed. Anything else, such as a photo, is
+//! only dimmed.
+//!
+//! Colours are `0xAARRGGBB` values, the layout Flutter's `Color` takes.
+
+use std::collections::VecDeque;
+
+use anyhow::{anyhow, Result};
+
+use crate::api::book_image::{self, BookImage};
+use crate::api::error::ApiError;
+use crate::api::task;
+
+/// Pixels with less alpha than this count as transparent.
+const MIN_ALPHA: u8 = 128;
+/// Largest spread between channels of a pixel that still counts as grey.
+const MAX_GREY_CHROMA: u8 = 24;
+/// Smallest spread between channels of a pixel that counts as coloured.
+const MIN_COLOUR_CHROMA: u8 = 48;
+/// Line art has fewer coloured pixels than this share.
+const MAX_LINE_ART_COLOUR: f32 = 0.05;
+/// How far below `white_level` the background fades out, so anti-aliased
+/// edges blend into the page instead of leaving a light halo.
+const FEATHER: u8 = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DarkImageMode {
+    /// Pick one of the others from what the image looks like.
+    Auto,
+    /// Invert lightness into the page colours, keeping hue.
+    Invert,
+    /// Replace the light background with the page background and dim the rest.
+    Recolor,
+    /// Dim the whole image.
+    Dim,
+}
+
+#[derive(Debug, Clone)]
+pub struct DarkImageOptions {
+    pub mode: DarkImageMode,
+    /// Page background colour.
+    pub background: u32,
+    /// Page text colour, which black ink becomes when inverting.
+    pub foreground: u32,
+    /// Smallest value of every channel for a pixel to count as near-white.
+    pub white_level: u8,
+    /// Share of the border, from 0 to 1, that must be near-white or
+    /// transparent for the background to count as light.
+    pub min_background_share: f32,
+    /// Factor applied to pixels that are dimmed, from 0 to 1.
+    pub brightness: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct DarkImage {
+    pub image: BookImage,
+    /// What was done to the image; never `Auto`.
+    pub mode: DarkImageMode,
+    /// Share of the border that is near-white or transparent.
+    pub background_share: f32,
+    pub line_art: bool,
+}
+
+/// Options for the default night theme.
+#[flutter_rust_bridge::frb]
+pub fn default_dark_image_options() -> DarkImageOptions {
+    DarkImageOptions {
+        mode: DarkImageMode::Auto,
+        background: 0xFF12_1212,
+        foreground: 0xFFC8_C8C8,
+        white_level: 224,
+        min_background_share: 0.6,
+        brightness: 0.8,
+    }
+}
+
+/// Tone an image already loaded with `load_book_image` for the dark theme.
+///
+/// # Arguments
+/// * `image` - Decoded image
+/// * `options` - Page colours and how to tone the image
+#[flutter_rust_bridge::frb]
+pub fn tone_image_for_dark_theme(
+    image: BookImage,
+    options: DarkImageOptions,
+) -> Result<DarkImage, ApiError> {
+    Ok(tone(image, &options)?)
+}
+
+/// Extract and decode an image of a parsed book and tone it for the dark
+/// theme.
+///
+/// # Arguments
+/// * `path` - Path to the EPUB, MOBI/AZW, FB2 or UMD file
+/// * `href` - Image href from `BookChapter::images`
+/// * `max_width` - Largest width to decode to, in pixels; 0 for no limit
+/// * `max_height` - Largest height to decode to, in pixels; 0 for no limit
+/// * `options` - Page colours and how to tone the image
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+#[flutter_rust_bridge::frb]
+pub async fn load_dark_book_image(
+    path: String,
+    href: String,
+    max_width: u32,
+    max_height: u32,
+    options: DarkImageOptions,
+    cancel_token: Option<u32>,
+) -> Result<DarkImage, ApiError> {
+    task::run_blocking(task::cancel_token(cancel_token)?, move |token| {
+        let data = book_image::extract(&path, &href)?;
+        token.check()?;
+        let image = book_image::decode(&data, max_width, max_height)?;
+        token.check()?;
+        tone(image, &options)
+    })
+    .await
+}
+
+fn tone(mut image: BookImage, options: &DarkImageOptions) -> Result<DarkImage> {
+    let (width, height) = (image.width as usize, image.height as usize);
+    if width == 0 || height == 0 {
+        return Err(anyhow!("Image is empty"));
+    }
+    if image.rgba.len() != width * height * 4 {
+        return Err(anyhow!(
+            "Image has {} bytes of pixels, expected {} for {width}×{height}",
+            image.rgba.len(),
+            width * height * 4
+        ));
+    }
+    let pixels = Pixels {
+        rgba: &image.rgba,
+        width,
+        height,
+    };
+    let white_level = options.white_level;
+    let background_share = pixels.border_share(|p| is_transparent(p) || is_white(p, white_level));
+    let line_art = pixels.colour_share(white_level) < MAX_LINE_ART_COLOUR;
+    let light = background_share >= options.min_background_share;
+    let mode = match options.mode {
+        DarkImageMode::Auto if light && line_art => DarkImageMode::Invert,
+        DarkImageMode::Auto if light => DarkImageMode::Recolor,
+        DarkImageMode::Auto => DarkImageMode::Dim,
+        mode => mode,
+    };
+
+    let background = channels(options.background);
+    let foreground = channels(options.foreground);
+    let brightness = options.brightness.clamp(0.0, 1.0);
+    match mode {
+        DarkImageMode::Invert => {
+            for pixel in image.rgba.chunks_exact_mut(4) {
+                invert(pixel, foreground, background);
+            }
+        }
+        DarkImageMode::Recolor => {
+            let weights = pixels.background_weights(white_level);
+            for (pixel, weight) in image.rgba.chunks_exact_mut(4).zip(weights) {
+                dim(pixel, brightness);
+                blend(pixel, background, weight);
+            }
+        }
+        DarkImageMode::Dim | DarkImageMode::Auto => {
+            for pixel in image.rgba.chunks_exact_mut(4) {
+                dim(pixel, brightness);
+            }
+        }
+    }
+    Ok(DarkImage {
+        image,
+        mode,
+        background_share,
+        line_art,
+    })
+}
+
+struct Pixels<'a> {
+    rgba: &'a [u8],
+    width: usize,
+    height: usize,
+}
+
+impl Pixels<'_> {
+    fn get(&self, x: usize, y: usize) -> &[u8] {
+        let i = (y * self.width + x) * 4;
+        &self.rgba[i..i + 4]
+    }
+
+    /// Positions of the outermost ring of pixels; the image is not empty.
+    fn border(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let (w, h) = (self.width, self.height);
+        let rows = (0..w).flat_map(move |x| [(x, 0), (x, h - 1)]);
+        let columns = (1..h.saturating_sub(1)).flat_map(move |y| [(0, y), (w - 1, y)]);
+        rows.chain(columns)
+    }
+
+    fn border_share(&self, matches: impl Fn(&[u8]) -> bool) -> f32 {
+        let (mut total, mut matching) = (0usize, 0usize);
+        for (x, y) in self.border() {
+            total += 1;
+            matching += matches(self.get(x, y)) as usize;
+        }
+        if total == 0 {
+            return 0.0;
+        }
+        matching as f32 / total as f32
+    }
+
+    /// Share of the opaque, not near-white pixels that are clearly coloured.
+    fn colour_share(&self, white_level: u8) -> f32 {
+        let (mut total, mut coloured) = (0usize, 0usize);
+        for pixel in self.rgba.chunks_exact(4) {
+            if is_transparent(pixel) || is_white(pixel, white_level) {
+                continue;
+            }
+            total += 1;
+            coloured += (chroma(pixel) >= MIN_COLOUR_CHROMA) as usize;
+        }
+        if total == 0 {
+            return 0.0;
+        }
+        coloured as f32 / total as f32
+    }
+
+    /// How much of each pixel is background, from 0 to 1: light grey pixels
+    /// connected to the border, fading out over `FEATHER` levels below
+    /// `white_level`. Light areas enclosed by the drawing are kept.
+    fn background_weights(&self, white_level: u8) -> Vec<f32> {
+        let low = white_level.saturating_sub(FEATHER);
+        let weight = |pixel: &[u8]| {
+            if is_transparent(pixel) || chroma(pixel) > MAX_GREY_CHROMA * 2 {
+                return 0.0;
+            }
+            let level = pixel[..3].iter().copied().min().unwrap_or(0);
+            if level <= low {
+                return 0.0;
+            }
+            let fade = (level - low) as f32 / (white_level - low).max(1) as f32;
+            let grey = 1.0
+                - (chroma(pixel).saturating_sub(MAX_GREY_CHROMA) as f32 / MAX_GREY_CHROMA as f32);
+            fade.min(1.0) * grey
+        };
+        let mut weights = vec![0.0; self.width * self.height];
+        let mut seen = vec![false; self.width * self.height];
+        let mut queue: VecDeque<(usize, usize)> = self.border().collect();
+        while let Some((x, y)) = queue.pop_front() {
+            let i = y * self.width + x;
+            if std::mem::replace(&mut seen[i], true) {
+                continue;
+            }
+            let pixel = self.get(x, y);
+            // Transparent pixels already show the page, but the background
+            // may continue past them.
+            let w = weight(pixel);
+            if w == 0.0 && !is_transparent(pixel) {
+                continue;
+            }
+            weights[i] = w;
+            if x > 0 {
+                queue.push_back((x - 1, y));
+            }
+            if x + 1 < self.width {
+                queue.push_back((x + 1, y));
+            }
+            if y > 0 {
+                queue.push_back((x, y - 1));
+            }
+            if y + 1 < self.height {
+                queue.push_back((x, y + 1));
+            }
+        }
+        weights
+    }
+}
+
+fn is_transparent(pixel: &[u8]) -> bool {
+    pixel[3] < MIN_ALPHA
+}
+
+fn is_white(pixel: &[u8], white_level: u8) -> bool {
+    pixel[..3].iter().all(|&c| c >= white_level) && chroma(pixel) <= MAX_GREY_CHROMA
+}
+
+/// Spread between the largest and smallest colour channel.
+fn chroma(pixel: &[u8]) -> u8 {
+    let max = pixel[..3].iter().copied().max().unwrap_or(0);
+    let min = pixel[..3].iter().copied().min().unwrap_or(0);
+    max - min
+}
+
+/// Red, green and blue of an `0xAARRGGBB` colour.
+fn channels(color: u32) -> [f32; 3] {
+    [16, 8, 0].map(|shift| ((color >> shift) & 0xFF) as f32)
+}
+
+/// Map lightness so white becomes `background` and black `foreground`,
+/// keeping each channel's offset from the pixel's lightness.
+fn invert(pixel: &mut [u8], foreground: [f32; 3], background: [f32; 3]) {
+    let luma = 0.299 * pixel[0] as f32 + 0.587 * pixel[1] as f32 + 0.114 * pixel[2] as f32;
+    let t = luma / 255.0;
+    for c in 0..3 {
+        let grey = foreground[c] + (background[c] - foreground[c]) * t;
+        pixel[c] = (grey + pixel[c] as f32 - luma).round().clamp(0.0, 255.0) as u8;
+    }
+}
+
+fn dim(pixel: &mut [u8], brightness: f32) {
+    for c in &mut pixel[..3] {
+        *c = (*c as f32 * brightness).round() as u8;
+    }
+}
+
+fn blend(pixel: &mut [u8], color: [f32; 3], weight: f32) {
+    if weight <= 0.0 {
+        return;
+    }
+    for c in 0..3 {
+        let value = pixel[c] as f32 + (color[c] - pixel[c] as f32) * weight;
+        pixel[c] = value.round().clamp(0.0, 255.0) as u8;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(width: u32, height: u32, pixel: impl Fn(u32, u32) -> [u8; 4]) -> BookImage {
+        let mut rgba = Vec::new();
+        for y in 0..height {
+            for x in 0..width {
+                rgba.extend(pixel(x, y));
+            }
+        }
+        BookImage {
+            rgba,
+            width,
+            height,
+            source_width: width,
+            source_height: height,
+        }
+    }
+
+    fn at(image: &BookImage, x: u32, y: u32) -> [u8; 4] {
+        let i = ((y * image.width + x) * 4) as usize;
+        image.rgba[i..i + 4].try_into().unwrap()
+    }
+
+    #[test]
+    fn test_inverts_line_art_into_page_colours() {
+        // Black lines and a red stamp on white paper.
+        let drawing = image(20, 20, |x, y| match (x, y) {
+            (10, _) => [0, 0, 0, 255],
+            (3, 3) => [200, 30, 30, 255],
+            _ => [250, 250, 250, 255],
+        });
+        let result = tone(drawing, &default_dark_image_options()).unwrap();
+        assert_eq!(result.mode, DarkImageMode::Invert);
+        assert!(result.line_art && result.background_share > 0.9);
+        let paper = at(&result.image, 0, 0);
+        assert!(paper[..3].iter().all(|&c| c < 30));
+        assert_eq!(at(&result.image, 10, 5), [200, 200, 200, 255]);
+        let stamp = at(&result.image, 3, 3);
+        assert!(stamp[0] > stamp[1] + 100 && stamp[0] > stamp[2] + 100);
+    }
+
+    #[test]
+    fn test_recolors_only_the_outer_background_of_colour_art() {
+        // A blue square with a white window, on a white background.
+        let painting = image(30, 30, |x, y| {
+            let inside = |lo, hi| (lo..hi).contains(&x) && (lo..hi).contains(&y);
+            if inside(12, 18) {
+                [255, 255, 255, 255]
+            } else if inside(5, 25) {
+                [30, 60, 220, 255]
+            } else {
+                [255, 255, 255, 255]
+            }
+        });
+        let result = tone(painting, &default_dark_image_options()).unwrap();
+        assert_eq!(result.mode, DarkImageMode::Recolor);
+        assert!(!result.line_art);
+        assert_eq!(at(&result.image, 1, 1), [0x12, 0x12, 0x12, 255]);
+        assert_eq!(at(&result.image, 15, 15), [204, 204, 204, 255]);
+        assert_eq!(at(&result.image, 7, 7), [24, 48, 176, 255]);
+    }
+
+    #[test]
+    fn test_dims_photos_and_rejects_bad_pixels() {
+        let photo = image(10, 10, |x, _| [(x * 25) as u8, 120, 90, 255]);
+        let result = tone(photo, &default_dark_image_options()).unwrap();
+        assert_eq!(result.mode, DarkImageMode::Dim);
+        assert_eq!(at(&result.image, 4, 0), [80, 96, 72, 255]);
+
+        let mut broken = image(4, 4, |_, _| [0, 0, 0, 255]);
+        broken.rgba.pop();
+        assert!(tone(broken, &default_dark_image_options()).is_err());
+    }
+}
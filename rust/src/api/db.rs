@@ -0,0 +1,1439 @@
+//! The library database: books, their chapters, reading progress,
+//! bookmarks, reading sessions and read chapters, in SQLite.
+//!
+//! The database runs in WAL mode so reads from the UI are not blocked by a
+//! bulk import. The schema is versioned with `PRAGMA user_version`; opening
+//! a database applies the migrations it is missing, each in a transaction,
+//! and refuses one written by a newer version of the app. Writes take lists
+//! and run in a single transaction, and listing queries are paged.
+
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Result};
+use rusqlite::{params, Connection, OptionalExtension, Row};
+
+use crate::api::error::ApiError;
+
+/// Schema changes in order; the database's `user_version` is the number
+/// applied. Never edit an entry once released, only append.
+const MIGRATIONS: &[&str] = &[
+    "CREATE TABLE books (
+        id TEXT PRIMARY KEY,
+        title TEXT NOT NULL,
+        author TEXT,
+        intro TEXT,
+        kind TEXT,
+        cover_url TEXT,
+        source_url TEXT,
+        book_url TEXT,
+        toc_url TEXT,
+        latest_chapter_title TEXT,
+        chapter_count INTEGER NOT NULL DEFAULT 0,
+        word_count INTEGER,
+        added_at INTEGER NOT NULL,
+        updated_at INTEGER NOT NULL,
+        last_read_at INTEGER
+    );
+    CREATE INDEX books_title ON books (title COLLATE NOCASE);
+    CREATE INDEX books_last_read_at ON books (last_read_at);
+    CREATE TABLE chapters (
+        book_id TEXT NOT NULL REFERENCES books (id) ON DELETE CASCADE,
+        chapter_index INTEGER NOT NULL,
+        title TEXT NOT NULL,
+        url TEXT,
+        is_volume INTEGER NOT NULL DEFAULT 0,
+        word_count INTEGER,
+        PRIMARY KEY (book_id, chapter_index)
+    ) WITHOUT ROWID;
+    CREATE TABLE progress (
+        book_id TEXT PRIMARY KEY REFERENCES books (id) ON DELETE CASCADE,
+        chapter_index INTEGER NOT NULL,
+        chapter_offset INTEGER NOT NULL,
+        percent REAL NOT NULL,
+        updated_at INTEGER NOT NULL
+    );
+    CREATE TABLE bookmarks (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        book_id TEXT NOT NULL REFERENCES books (id) ON DELETE CASCADE,
+        chapter_index INTEGER NOT NULL,
+        chapter_offset INTEGER NOT NULL,
+        excerpt TEXT NOT NULL,
+        note TEXT,
+        created_at INTEGER NOT NULL
+    );
+    CREATE INDEX bookmarks_book ON bookmarks (book_id, chapter_index, chapter_offset);",
+    "CREATE TABLE highlights (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        book_id TEXT NOT NULL REFERENCES books (id) ON DELETE CASCADE,
+        chapter_index INTEGER NOT NULL,
+        start_offset INTEGER NOT NULL,
+        end_offset INTEGER NOT NULL,
+        text TEXT NOT NULL,
+        note TEXT,
+        color INTEGER NOT NULL,
+        created_at INTEGER NOT NULL
+    );
+    CREATE INDEX highlights_book ON highlights (book_id, chapter_index, start_offset);",
+    "CREATE TABLE tombstones (
+        key TEXT PRIMARY KEY,
+        deleted_at INTEGER NOT NULL
+    ) WITHOUT ROWID;",
+    "CREATE TABLE reading_sessions (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        book_id TEXT NOT NULL,
+        started_at INTEGER NOT NULL,
+        ended_at INTEGER NOT NULL,
+        closed INTEGER NOT NULL DEFAULT 0
+    );
+    CREATE INDEX reading_sessions_started_at ON reading_sessions (started_at);
+    CREATE INDEX reading_sessions_book ON reading_sessions (book_id, started_at);",
+    "CREATE TABLE read_chapters (
+        book_id TEXT PRIMARY KEY,
+        ranges BLOB NOT NULL,
+        updated_at INTEGER NOT NULL
+    ) WITHOUT ROWID;",
+];
+
+const BOOK_COLUMNS: &str = "id, title, author, intro, kind, cover_url, source_url, book_url, \
+    toc_url, latest_chapter_title, chapter_count, word_count, added_at, updated_at, last_read_at";
+
+const BOOKMARK_COLUMNS: &str =
+    "id, book_id, chapter_index, chapter_offset, excerpt, note, created_at";
+
+const HIGHLIGHT_COLUMNS: &str =
+    "id, book_id, chapter_index, start_offset, end_offset, text, note, color, created_at";
+
+static DB: Mutex<Option<Connection>> = Mutex::new(None);
+
+#[derive(Debug, Clone)]
+pub struct LibraryBook {
+    pub id: String,
+    pub title: String,
+    pub author: Option<String>,
+    pub intro: Option<String>,
+    pub kind: Option<String>,
+    pub cover_url: Option<String>,
+    /// Book source the book is read from; `None` for local files.
+    pub source_url: Option<String>,
+    pub book_url: Option<String>,
+    pub toc_url: Option<String>,
+    pub latest_chapter_title: Option<String>,
+    pub chapter_count: u32,
+    pub word_count: Option<u32>,
+    /// Seconds since the Unix epoch. Kept from the first insert.
+    pub added_at: i64,
+    pub updated_at: i64,
+    /// Set from saved progress; an upsert without it keeps the stored one.
+    pub last_read_at: Option<i64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct LibraryChapter {
+    pub index: u32,
+    pub title: String,
+    pub url: Option<String>,
+    pub is_volume: bool,
+    pub word_count: Option<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReadingProgress {
+    pub book_id: String,
+    pub chapter_index: u32,
+    /// Offset within the chapter, in UTF-16 code units.
+    pub chapter_offset: u32,
+    /// Position in the whole book, from 0 to 1.
+    pub percent: f64,
+    /// Seconds since the Unix epoch; older progress never replaces newer.
+    pub updated_at: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Bookmark {
+    /// `None` to add a bookmark, or the ID of one to replace.
+    pub id: Option<i64>,
+    pub book_id: String,
+    pub chapter_index: u32,
+    pub chapter_offset: u32,
+    pub excerpt: String,
+    pub note: Option<String>,
+    pub created_at: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Highlight {
+    /// `None` to add a highlight, or the ID of one to replace.
+    pub id: Option<i64>,
+    pub book_id: String,
+    pub chapter_index: u32,
+    /// Offsets of the highlighted range within the chapter, in UTF-16 code
+    /// units.
+    pub start_offset: u32,
+    pub end_offset: u32,
+    pub text: String,
+    pub note: Option<String>,
+    /// ARGB.
+    pub color: u32,
+    pub created_at: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookSort {
+    /// Most recently read first, then unread books by when they were added.
+    RecentlyRead,
+    RecentlyAdded,
+    RecentlyUpdated,
+    Title,
+    Author,
+}
+
+#[derive(Debug, Clone)]
+pub struct BookQuery {
+    /// Matched against title and author, case-insensitively.
+    pub search: Option<String>,
+    pub sort: BookSort,
+    pub offset: u32,
+    pub limit: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct BookPage {
+    pub books: Vec<LibraryBook>,
+    /// Books matching the query across all pages.
+    pub total: u32,
+}
+
+/// Open the library database, creating and migrating it as needed.
+/// Calling this again switches to the new file.
+///
+/// # Arguments
+/// * `path` - Database file; missing parent directories are created
+#[flutter_rust_bridge::frb]
+pub fn open_library_db(path: String) -> Result<(), ApiError> {
+    let path = Path::new(&path);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| anyhow!("Failed to create {}: {e}", parent.display()))?;
+    }
+    let mut connection =
+        Connection::open(path).map_err(|e| anyhow!("Failed to open {}: {e}", path.display()))?;
+    connection
+        .pragma_update(None, "journal_mode", "WAL")
+        .and_then(|_| connection.pragma_update(None, "synchronous", "NORMAL"))
+        .map_err(|e| anyhow!("Failed to enable WAL mode: {e}"))?;
+    setup(&mut connection)?;
+    *lock() = Some(connection);
+    Ok(())
+}
+
+/// Close the library database, checkpointing the WAL into the main file.
+#[flutter_rust_bridge::frb]
+pub fn close_library_db() -> Result<(), ApiError> {
+    if let Some(connection) = lock().take() {
+        connection
+            .close()
+            .map_err(|(_, e)| anyhow!("Failed to close the library database: {e}"))?;
+    }
+    Ok(())
+}
+
+/// Insert books or update them by ID. Returns the number written.
+///
+/// # Arguments
+/// * `books` - Books to write, in one transaction
+#[flutter_rust_bridge::frb]
+pub fn upsert_books(books: Vec<LibraryBook>) -> Result<u32, ApiError> {
+    Ok(with_db(|db| write_books(db, &books))?)
+}
+
+/// Look up a book by ID.
+#[flutter_rust_bridge::frb]
+pub fn get_library_book(id: String) -> Result<Option<LibraryBook>, ApiError> {
+    Ok(with_db(|db| {
+        db.query_row(
+            &format!("SELECT {BOOK_COLUMNS} FROM books WHERE id = ?1"),
+            [id],
+            book_row,
+        )
+        .optional()
+    })?)
+}
+
+/// Delete books with their chapters, progress and bookmarks. Returns the
+/// number of books deleted.
+///
+/// # Arguments
+/// * `ids` - IDs of the books to delete
+#[flutter_rust_bridge::frb]
+pub fn delete_books(ids: Vec<String>) -> Result<u32, ApiError> {
+    Ok(with_db(|db| {
+        let tx = db.transaction()?;
+        let mut deleted = 0;
+        {
+            let mut statement = tx.prepare_cached("DELETE FROM books WHERE id = ?1")?;
+            for id in &ids {
+                deleted += statement.execute([id])? as u32;
+            }
+        }
+        tx.commit()?;
+        Ok(deleted)
+    })?)
+}
+
+/// List one page of books.
+///
+/// # Arguments
+/// * `query` - Search text, sort order and page
+#[flutter_rust_bridge::frb]
+pub fn query_books(query: BookQuery) -> Result<BookPage, ApiError> {
+    Ok(with_db(|db| read_books(db, &query))?)
+}
+
+/// Replace a book's table of contents and update its chapter count.
+///
+/// # Arguments
+/// * `book_id` - ID of a stored book
+/// * `chapters` - The whole table of contents; indices should run from 0
+#[flutter_rust_bridge::frb]
+pub fn set_library_chapters(
+    book_id: String,
+    chapters: Vec<LibraryChapter>,
+) -> Result<(), ApiError> {
+    Ok(with_db(|db| write_chapters(db, &book_id, &chapters))?)
+}
+
+/// List one page of a book's chapters, in order.
+///
+/// # Arguments
+/// * `book_id` - ID of the book
+/// * `offset` - Chapters to skip
+/// * `limit` - Chapters to return at most
+#[flutter_rust_bridge::frb]
+pub fn query_library_chapters(
+    book_id: String,
+    offset: u32,
+    limit: u32,
+) -> Result<Vec<LibraryChapter>, ApiError> {
+    Ok(with_db(|db| read_chapters(db, &book_id, offset, limit))?)
+}
+
+/// Save reading progress for several books; entries older than the stored
+/// progress are skipped. Also updates each book's `last_read_at`.
+///
+/// # Arguments
+/// * `progress` - Progress to write, in one transaction
+#[flutter_rust_bridge::frb]
+pub fn upsert_progress(progress: Vec<ReadingProgress>) -> Result<(), ApiError> {
+    Ok(with_db(|db| write_progress(db, &progress))?)
+}
+
+/// Look up the reading progress of a book.
+#[flutter_rust_bridge::frb]
+pub fn get_progress(book_id: String) -> Result<Option<ReadingProgress>, ApiError> {
+    Ok(with_db(|db| {
+        db.query_row(
+            "SELECT book_id, chapter_index, chapter_offset, percent, updated_at
+             FROM progress WHERE book_id = ?1",
+            [book_id],
+            progress_row,
+        )
+        .optional()
+    })?)
+}
+
+/// Add or replace bookmarks and return their IDs, in order.
+///
+/// # Arguments
+/// * `bookmarks` - Bookmarks to write, in one transaction
+#[flutter_rust_bridge::frb]
+pub fn upsert_bookmarks(bookmarks: Vec<Bookmark>) -> Result<Vec<i64>, ApiError> {
+    Ok(with_db(|db| write_bookmarks(db, &bookmarks))?)
+}
+
+/// Delete a bookmark. Returns false if there was none with the ID.
+#[flutter_rust_bridge::frb]
+pub fn delete_bookmark(id: i64) -> Result<bool, ApiError> {
+    Ok(with_db(|db| remove_bookmark(db, id))?)
+}
+
+/// List one page of bookmarks by position in the book, or for all books
+/// newest first.
+///
+/// # Arguments
+/// * `book_id` - Book to list bookmarks of, or `None` for all books
+/// * `offset` - Bookmarks to skip
+/// * `limit` - Bookmarks to return at most
+#[flutter_rust_bridge::frb]
+pub fn query_bookmarks(
+    book_id: Option<String>,
+    offset: u32,
+    limit: u32,
+) -> Result<Vec<Bookmark>, ApiError> {
+    Ok(with_db(|db| {
+        read_bookmarks(db, book_id.as_deref(), offset, limit)
+    })?)
+}
+
+/// Add or replace highlights and return their IDs, in order.
+///
+/// # Arguments
+/// * `highlights` - Highlights to write, in one transaction
+#[flutter_rust_bridge::frb]
+pub fn upsert_highlights(highlights: Vec<Highlight>) -> Result<Vec<i64>, ApiError> {
+    Ok(with_db(|db| {
+        let tx = db.transaction()?;
+        let ids = insert_highlights(&tx, &highlights)?;
+        tx.commit()?;
+        Ok(ids)
+    })?)
+}
+
+/// Delete a highlight. Returns false if there was none with the ID.
+#[flutter_rust_bridge::frb]
+pub fn delete_highlight(id: i64) -> Result<bool, ApiError> {
+    Ok(with_db(|db| remove_highlight(db, id))?)
+}
+
+/// List one page of highlights by position in the book, or for all books
+/// newest first.
+///
+/// # Arguments
+/// * `book_id` - Book to list highlights of, or `None` for all books
+/// * `offset` - Highlights to skip
+/// * `limit` - Highlights to return at most
+#[flutter_rust_bridge::frb]
+pub fn query_highlights(
+    book_id: Option<String>,
+    offset: u32,
+    limit: u32,
+) -> Result<Vec<Highlight>, ApiError> {
+    Ok(with_db(|db| {
+        read_highlights(db, book_id.as_deref(), offset, limit)
+    })?)
+}
+
+/// Everything in the database except tables of contents, which can be
+/// fetched again.
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug, Clone, Default)]
+pub(crate) struct LibraryDump {
+    pub(crate) books: Vec<LibraryBook>,
+    pub(crate) progress: Vec<ReadingProgress>,
+    pub(crate) bookmarks: Vec<Bookmark>,
+    pub(crate) highlights: Vec<Highlight>,
+}
+
+/// Rows `merge_library` wrote.
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug, Clone, Default)]
+pub(crate) struct MergeCounts {
+    pub(crate) books_added: u32,
+    pub(crate) books_updated: u32,
+    pub(crate) progress_updated: u32,
+    pub(crate) bookmarks_added: u32,
+    pub(crate) highlights_added: u32,
+    pub(crate) bookmarks_removed: u32,
+    pub(crate) highlights_removed: u32,
+}
+
+/// A deleted bookmark or highlight, kept so sync can delete it on other
+/// devices instead of them bringing it back.
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Tombstone {
+    /// `bookmark_key` or `highlight_key` of the deleted row.
+    pub(crate) key: String,
+    /// Seconds since the Unix epoch.
+    pub(crate) deleted_at: i64,
+}
+
+/// What identifies a bookmark on every device: its content, without the
+/// local ID. Editing a bookmark makes it a new one.
+pub(crate) fn bookmark_key(bookmark: &Bookmark) -> String {
+    content_key(&[
+        "bookmark",
+        &bookmark.book_id,
+        &bookmark.chapter_index.to_string(),
+        &bookmark.chapter_offset.to_string(),
+        &bookmark.excerpt,
+        // No note hashes unlike any text; an empty one is still text.
+        bookmark.note.as_deref().unwrap_or("\0"),
+        &bookmark.created_at.to_string(),
+    ])
+}
+
+/// What identifies a highlight on every device; see `bookmark_key`.
+pub(crate) fn highlight_key(highlight: &Highlight) -> String {
+    content_key(&[
+        "highlight",
+        &highlight.book_id,
+        &highlight.chapter_index.to_string(),
+        &highlight.start_offset.to_string(),
+        &highlight.end_offset.to_string(),
+        &highlight.text,
+        highlight.note.as_deref().unwrap_or("\0"),
+        &highlight.color.to_string(),
+        &highlight.created_at.to_string(),
+    ])
+}
+
+pub(crate) fn export_library() -> Result<LibraryDump> {
+    with_db(|db| dump(db))
+}
+
+/// Merge a dump into the database in one transaction; see `merge`.
+pub(crate) fn merge_library(dump: &LibraryDump) -> Result<MergeCounts> {
+    with_db(|db| merge(db, dump))
+}
+
+pub(crate) fn export_tombstones() -> Result<Vec<Tombstone>> {
+    with_db(|db| read_tombstones(db))
+}
+
+/// Write records merged by sync in one transaction; see `apply_sync`.
+pub(crate) fn apply_sync_records(
+    records: &LibraryDump,
+    tombstones: &[Tombstone],
+) -> Result<MergeCounts> {
+    with_db(|db| apply_sync(db, records, tombstones))
+}
+
+fn lock() -> MutexGuard<'static, Option<Connection>> {
+    DB.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+pub(crate) fn with_db<T>(f: impl FnOnce(&mut Connection) -> rusqlite::Result<T>) -> Result<T> {
+    let mut db = lock();
+    let db = db
+        .as_mut()
+        .ok_or_else(|| anyhow!("Library database is not open"))?;
+    f(db).map_err(|e| anyhow!("Library database error: {e}"))
+}
+
+/// Enable foreign keys and apply missing migrations.
+pub(crate) fn setup(db: &mut Connection) -> Result<()> {
+    db.pragma_update(None, "foreign_keys", true)
+        .and_then(|_| db.busy_timeout(std::time::Duration::from_secs(5)))
+        .map_err(|e| anyhow!("Failed to configure the library database: {e}"))?;
+    let version: u32 = db
+        .pragma_query_value(None, "user_version", |row| row.get(0))
+        .map_err(|e| anyhow!("Failed to read the schema version: {e}"))?;
+    let version = version as usize;
+    if version > MIGRATIONS.len() {
+        return Err(anyhow!(
+            "Library database has schema version {version}, newer than this app supports ({})",
+            MIGRATIONS.len()
+        ));
+    }
+    for (index, migration) in MIGRATIONS.iter().enumerate().skip(version) {
+        let mut migrate = || -> rusqlite::Result<()> {
+            let tx = db.transaction()?;
+            tx.execute_batch(migration)?;
+            tx.pragma_update(None, "user_version", index as u32 + 1)?;
+            tx.commit()
+        };
+        migrate().map_err(|e| anyhow!("Failed to migrate to schema version {}: {e}", index + 1))?;
+    }
+    Ok(())
+}
+
+fn write_books(db: &mut Connection, books: &[LibraryBook]) -> rusqlite::Result<u32> {
+    let tx = db.transaction()?;
+    insert_books(&tx, books)?;
+    tx.commit()?;
+    Ok(books.len() as u32)
+}
+
+fn insert_books(db: &Connection, books: &[LibraryBook]) -> rusqlite::Result<()> {
+    let mut statement = db.prepare_cached(&format!(
+        "INSERT INTO books ({BOOK_COLUMNS})
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)
+             ON CONFLICT (id) DO UPDATE SET
+                title = excluded.title,
+                author = excluded.author,
+                intro = excluded.intro,
+                kind = excluded.kind,
+                cover_url = excluded.cover_url,
+                source_url = excluded.source_url,
+                book_url = excluded.book_url,
+                toc_url = excluded.toc_url,
+                latest_chapter_title = excluded.latest_chapter_title,
+                chapter_count = excluded.chapter_count,
+                word_count = excluded.word_count,
+                updated_at = excluded.updated_at,
+                last_read_at = COALESCE(excluded.last_read_at, books.last_read_at)"
+    ))?;
+    for book in books {
+        statement.execute(params![
+            book.id,
+            book.title,
+            book.author,
+            book.intro,
+            book.kind,
+            book.cover_url,
+            book.source_url,
+            book.book_url,
+            book.toc_url,
+            book.latest_chapter_title,
+            book.chapter_count,
+            book.word_count,
+            book.added_at,
+            book.updated_at,
+            book.last_read_at,
+        ])?;
+    }
+    Ok(())
+}
+
+fn read_books(db: &Connection, query: &BookQuery) -> rusqlite::Result<BookPage> {
+    let order = match query.sort {
+        BookSort::RecentlyRead => "last_read_at IS NULL, last_read_at DESC, added_at DESC",
+        BookSort::RecentlyAdded => "added_at DESC",
+        BookSort::RecentlyUpdated => "updated_at DESC",
+        BookSort::Title => "title COLLATE NOCASE, author COLLATE NOCASE",
+        BookSort::Author => "author IS NULL, author COLLATE NOCASE, title COLLATE NOCASE",
+    };
+    let pattern = query
+        .search
+        .as_deref()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            let escaped = s
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_");
+            format!("%{escaped}%")
+        });
+    let filter = "?1 IS NULL OR title LIKE ?1 ESCAPE '\\' OR author LIKE ?1 ESCAPE '\\'";
+    let total = db.query_row(
+        &format!("SELECT COUNT(*) FROM books WHERE {filter}"),
+        [&pattern],
+        |row| row.get(0),
+    )?;
+    let mut statement = db.prepare_cached(&format!(
+        "SELECT {BOOK_COLUMNS} FROM books WHERE {filter}
+         ORDER BY {order}, id LIMIT ?2 OFFSET ?3"
+    ))?;
+    let books = statement
+        .query_map(params![pattern, query.limit, query.offset], book_row)?
+        .collect::<rusqlite::Result<_>>()?;
+    Ok(BookPage { books, total })
+}
+
+pub(crate) fn write_chapters(
+    db: &mut Connection,
+    book_id: &str,
+    chapters: &[LibraryChapter],
+) -> rusqlite::Result<()> {
+    let tx = db.transaction()?;
+    replace_chapters(&tx, book_id, chapters)?;
+    tx.commit()
+}
+
+/// Replace a book's chapters within the caller's transaction.
+pub(crate) fn replace_chapters(
+    tx: &Connection,
+    book_id: &str,
+    chapters: &[LibraryChapter],
+) -> rusqlite::Result<()> {
+    tx.execute("DELETE FROM chapters WHERE book_id = ?1", [book_id])?;
+    {
+        let mut statement = tx.prepare_cached(
+            "INSERT OR REPLACE INTO chapters
+             (book_id, chapter_index, title, url, is_volume, word_count)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        )?;
+        for chapter in chapters {
+            statement.execute(params![
+                book_id,
+                chapter.index,
+                chapter.title,
+                chapter.url,
+                chapter.is_volume,
+                chapter.word_count,
+            ])?;
+        }
+    }
+    tx.execute(
+        "UPDATE books SET chapter_count = (SELECT COUNT(*) FROM chapters WHERE book_id = ?1)
+         WHERE id = ?1",
+        [book_id],
+    )?;
+    Ok(())
+}
+
+pub(crate) fn read_chapters(
+    db: &Connection,
+    book_id: &str,
+    offset: u32,
+    limit: u32,
+) -> rusqlite::Result<Vec<LibraryChapter>> {
+    let mut statement = db.prepare_cached(
+        "SELECT chapter_index, title, url, is_volume, word_count FROM chapters
+         WHERE book_id = ?1 ORDER BY chapter_index LIMIT ?2 OFFSET ?3",
+    )?;
+    let chapters = statement
+        .query_map(params![book_id, limit, offset], |row| {
+            Ok(LibraryChapter {
+                index: row.get(0)?,
+                title: row.get(1)?,
+                url: row.get(2)?,
+                is_volume: row.get(3)?,
+                word_count: row.get(4)?,
+            })
+        })?
+        .collect();
+    chapters
+}
+
+fn write_progress(db: &mut Connection, progress: &[ReadingProgress]) -> rusqlite::Result<()> {
+    let tx = db.transaction()?;
+    insert_progress(&tx, progress)?;
+    tx.commit()
+}
+
+/// Save progress newer than the stored one and return how many were saved.
+fn insert_progress(db: &Connection, progress: &[ReadingProgress]) -> rusqlite::Result<u32> {
+    let mut saved = 0;
+    let mut save = db.prepare_cached(
+        "INSERT INTO progress (book_id, chapter_index, chapter_offset, percent, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT (book_id) DO UPDATE SET
+                chapter_index = excluded.chapter_index,
+                chapter_offset = excluded.chapter_offset,
+                percent = excluded.percent,
+                updated_at = excluded.updated_at
+             WHERE excluded.updated_at >= progress.updated_at",
+    )?;
+    let mut touch = db.prepare_cached(
+        "UPDATE books SET last_read_at = MAX(COALESCE(last_read_at, 0), ?2) WHERE id = ?1",
+    )?;
+    for entry in progress {
+        saved += save.execute(params![
+            entry.book_id,
+            entry.chapter_index,
+            entry.chapter_offset,
+            entry.percent,
+            entry.updated_at,
+        ])? as u32;
+        touch.execute(params![entry.book_id, entry.updated_at])?;
+    }
+    Ok(saved)
+}
+
+fn write_bookmarks(db: &mut Connection, bookmarks: &[Bookmark]) -> rusqlite::Result<Vec<i64>> {
+    let tx = db.transaction()?;
+    let ids = insert_bookmarks(&tx, bookmarks)?;
+    tx.commit()?;
+    Ok(ids)
+}
+
+fn insert_bookmarks(db: &Connection, bookmarks: &[Bookmark]) -> rusqlite::Result<Vec<i64>> {
+    let mut ids = Vec::with_capacity(bookmarks.len());
+    let mut statement = db.prepare_cached(
+        "INSERT OR REPLACE INTO bookmarks
+             (id, book_id, chapter_index, chapter_offset, excerpt, note, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+    )?;
+    for bookmark in bookmarks {
+        if let Some(id) = bookmark.id {
+            let stored = db
+                .query_row(
+                    &format!("SELECT {BOOKMARK_COLUMNS} FROM bookmarks WHERE id = ?1"),
+                    [id],
+                    bookmark_row,
+                )
+                .optional()?;
+            // An edited bookmark is a new one; other devices drop the old.
+            let old = stored.map(|stored| bookmark_key(&stored));
+            if let Some(old) = old.filter(|old| *old != bookmark_key(bookmark)) {
+                bury(db, &old)?;
+            }
+        }
+        statement.execute(params![
+            bookmark.id,
+            bookmark.book_id,
+            bookmark.chapter_index,
+            bookmark.chapter_offset,
+            bookmark.excerpt,
+            bookmark.note,
+            bookmark.created_at,
+        ])?;
+        ids.push(db.last_insert_rowid());
+    }
+    Ok(ids)
+}
+
+fn insert_highlights(db: &Connection, highlights: &[Highlight]) -> rusqlite::Result<Vec<i64>> {
+    let mut statement = db.prepare_cached(
+        "INSERT OR REPLACE INTO highlights
+         (id, book_id, chapter_index, start_offset, end_offset, text, note, color, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+    )?;
+    let mut ids = Vec::with_capacity(highlights.len());
+    for highlight in highlights {
+        if let Some(id) = highlight.id {
+            let stored = db
+                .query_row(
+                    &format!("SELECT {HIGHLIGHT_COLUMNS} FROM highlights WHERE id = ?1"),
+                    [id],
+                    highlight_row,
+                )
+                .optional()?;
+            let old = stored.map(|stored| highlight_key(&stored));
+            if let Some(old) = old.filter(|old| *old != highlight_key(highlight)) {
+                bury(db, &old)?;
+            }
+        }
+        statement.execute(params![
+            highlight.id,
+            highlight.book_id,
+            highlight.chapter_index,
+            highlight.start_offset,
+            highlight.end_offset,
+            highlight.text,
+            highlight.note,
+            highlight.color,
+            highlight.created_at,
+        ])?;
+        ids.push(db.last_insert_rowid());
+    }
+    Ok(ids)
+}
+
+fn read_highlights(
+    db: &Connection,
+    book_id: Option<&str>,
+    offset: u32,
+    limit: u32,
+) -> rusqlite::Result<Vec<Highlight>> {
+    let order = match book_id {
+        Some(_) => "chapter_index, start_offset, id",
+        None => "created_at DESC, id DESC",
+    };
+    let mut statement = db.prepare_cached(&format!(
+        "SELECT {HIGHLIGHT_COLUMNS} FROM highlights WHERE ?1 IS NULL OR book_id = ?1
+         ORDER BY {order} LIMIT ?2 OFFSET ?3"
+    ))?;
+    let highlights = statement
+        .query_map(params![book_id, limit, offset], highlight_row)?
+        .collect();
+    highlights
+}
+
+fn dump(db: &Connection) -> rusqlite::Result<LibraryDump> {
+    let mut statement = db.prepare(&format!("SELECT {BOOK_COLUMNS} FROM books ORDER BY id"))?;
+    let books = statement
+        .query_map([], book_row)?
+        .collect::<rusqlite::Result<_>>()?;
+    let mut statement = db.prepare(
+        "SELECT book_id, chapter_index, chapter_offset, percent, updated_at
+         FROM progress ORDER BY book_id",
+    )?;
+    let progress = statement
+        .query_map([], progress_row)?
+        .collect::<rusqlite::Result<_>>()?;
+    Ok(LibraryDump {
+        books,
+        progress,
+        bookmarks: read_bookmarks(db, None, 0, u32::MAX)?,
+        highlights: read_highlights(db, None, 0, u32::MAX)?,
+    })
+}
+
+/// Add what the database is missing without losing anything: books are
+/// added, or replaced when the dump's copy was updated later; progress
+/// follows the usual newest-wins rule; bookmarks and highlights are added
+/// unless the same one, by position and creation time, is already stored.
+/// Rows of books in neither the database nor the dump are skipped.
+fn merge(db: &mut Connection, dump: &LibraryDump) -> rusqlite::Result<MergeCounts> {
+    let tx = db.transaction()?;
+    let mut counts = MergeCounts::default();
+    for book in &dump.books {
+        let stored: Option<i64> = tx
+            .query_row(
+                "SELECT updated_at FROM books WHERE id = ?1",
+                [&book.id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        match stored {
+            None => counts.books_added += 1,
+            Some(updated_at) if updated_at < book.updated_at => counts.books_updated += 1,
+            Some(_) => continue,
+        }
+        insert_books(&tx, std::slice::from_ref(book))?;
+    }
+    let exists = |id: &str| -> rusqlite::Result<bool> {
+        tx.query_row(
+            "SELECT EXISTS (SELECT 1 FROM books WHERE id = ?1)",
+            [id],
+            |row| row.get(0),
+        )
+    };
+
+    let mut progress = Vec::new();
+    for entry in &dump.progress {
+        if exists(&entry.book_id)? {
+            progress.push(entry.clone());
+        }
+    }
+    counts.progress_updated = insert_progress(&tx, &progress)?;
+
+    for bookmark in &dump.bookmarks {
+        let stored: bool = tx.query_row(
+            "SELECT EXISTS (SELECT 1 FROM bookmarks WHERE book_id = ?1 AND chapter_index = ?2
+             AND chapter_offset = ?3 AND created_at = ?4)",
+            params![
+                bookmark.book_id,
+                bookmark.chapter_index,
+                bookmark.chapter_offset,
+                bookmark.created_at
+            ],
+            |row| row.get(0),
+        )?;
+        if !stored && exists(&bookmark.book_id)? {
+            let bookmark = Bookmark {
+                id: None,
+                ..bookmark.clone()
+            };
+            insert_bookmarks(&tx, &[bookmark])?;
+            counts.bookmarks_added += 1;
+        }
+    }
+    for highlight in &dump.highlights {
+        let stored: bool = tx.query_row(
+            "SELECT EXISTS (SELECT 1 FROM highlights WHERE book_id = ?1 AND chapter_index = ?2
+             AND start_offset = ?3 AND end_offset = ?4 AND created_at = ?5)",
+            params![
+                highlight.book_id,
+                highlight.chapter_index,
+                highlight.start_offset,
+                highlight.end_offset,
+                highlight.created_at
+            ],
+            |row| row.get(0),
+        )?;
+        if !stored && exists(&highlight.book_id)? {
+            let highlight = Highlight {
+                id: None,
+                ..highlight.clone()
+            };
+            insert_highlights(&tx, &[highlight])?;
+            counts.highlights_added += 1;
+        }
+    }
+    tx.commit()?;
+    Ok(counts)
+}
+
+/// Write records merged by sync: tombstones are stored and the bookmarks
+/// and highlights they name deleted; progress follows the usual
+/// newest-wins rule; bookmarks and highlights are added unless the same
+/// one is stored or buried. Rows of books not in the database are skipped.
+fn apply_sync(
+    db: &mut Connection,
+    records: &LibraryDump,
+    tombstones: &[Tombstone],
+) -> rusqlite::Result<MergeCounts> {
+    let tx = db.transaction()?;
+    let mut counts = MergeCounts::default();
+    {
+        let mut statement = tx.prepare_cached(
+            "INSERT INTO tombstones (key, deleted_at) VALUES (?1, ?2)
+             ON CONFLICT (key) DO UPDATE SET deleted_at = MIN(deleted_at, excluded.deleted_at)",
+        )?;
+        for tombstone in tombstones {
+            statement.execute(params![tombstone.key, tombstone.deleted_at])?;
+        }
+    }
+    let buried = |key: &str| -> rusqlite::Result<bool> {
+        tx.query_row(
+            "SELECT EXISTS (SELECT 1 FROM tombstones WHERE key = ?1)",
+            [key],
+            |row| row.get(0),
+        )
+    };
+    let exists = |id: &str| -> rusqlite::Result<bool> {
+        tx.query_row(
+            "SELECT EXISTS (SELECT 1 FROM books WHERE id = ?1)",
+            [id],
+            |row| row.get(0),
+        )
+    };
+
+    let mut stored = HashSet::new();
+    for bookmark in read_bookmarks(&tx, None, 0, u32::MAX)? {
+        let key = bookmark_key(&bookmark);
+        if buried(&key)? {
+            tx.execute("DELETE FROM bookmarks WHERE id = ?1", [bookmark.id])?;
+            counts.bookmarks_removed += 1;
+        } else {
+            stored.insert(key);
+        }
+    }
+    for highlight in read_highlights(&tx, None, 0, u32::MAX)? {
+        let key = highlight_key(&highlight);
+        if buried(&key)? {
+            tx.execute("DELETE FROM highlights WHERE id = ?1", [highlight.id])?;
+            counts.highlights_removed += 1;
+        } else {
+            stored.insert(key);
+        }
+    }
+
+    let mut progress = Vec::new();
+    for entry in &records.progress {
+        if exists(&entry.book_id)? {
+            progress.push(entry.clone());
+        }
+    }
+    counts.progress_updated = insert_progress(&tx, &progress)?;
+
+    for bookmark in &records.bookmarks {
+        let key = bookmark_key(bookmark);
+        if !stored.contains(&key) && !buried(&key)? && exists(&bookmark.book_id)? {
+            let bookmark = Bookmark {
+                id: None,
+                ..bookmark.clone()
+            };
+            insert_bookmarks(&tx, &[bookmark])?;
+            stored.insert(key);
+            counts.bookmarks_added += 1;
+        }
+    }
+    for highlight in &records.highlights {
+        let key = highlight_key(highlight);
+        if !stored.contains(&key) && !buried(&key)? && exists(&highlight.book_id)? {
+            let highlight = Highlight {
+                id: None,
+                ..highlight.clone()
+            };
+            insert_highlights(&tx, &[highlight])?;
+            stored.insert(key);
+            counts.highlights_added += 1;
+        }
+    }
+    tx.commit()?;
+    Ok(counts)
+}
+
+fn remove_bookmark(db: &mut Connection, id: i64) -> rusqlite::Result<bool> {
+    let tx = db.transaction()?;
+    let stored = tx
+        .query_row(
+            &format!("SELECT {BOOKMARK_COLUMNS} FROM bookmarks WHERE id = ?1"),
+            [id],
+            bookmark_row,
+        )
+        .optional()?;
+    if let Some(bookmark) = &stored {
+        bury(&tx, &bookmark_key(bookmark))?;
+        tx.execute("DELETE FROM bookmarks WHERE id = ?1", [id])?;
+    }
+    tx.commit()?;
+    Ok(stored.is_some())
+}
+
+fn remove_highlight(db: &mut Connection, id: i64) -> rusqlite::Result<bool> {
+    let tx = db.transaction()?;
+    let stored = tx
+        .query_row(
+            &format!("SELECT {HIGHLIGHT_COLUMNS} FROM highlights WHERE id = ?1"),
+            [id],
+            highlight_row,
+        )
+        .optional()?;
+    if let Some(highlight) = &stored {
+        bury(&tx, &highlight_key(highlight))?;
+        tx.execute("DELETE FROM highlights WHERE id = ?1", [id])?;
+    }
+    tx.commit()?;
+    Ok(stored.is_some())
+}
+
+/// Record that the bookmark or highlight with `key` was deleted, unless it
+/// already was.
+fn bury(db: &Connection, key: &str) -> rusqlite::Result<()> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64);
+    db.prepare_cached("INSERT OR IGNORE INTO tombstones (key, deleted_at) VALUES (?1, ?2)")?
+        .execute(params![key, now])?;
+    Ok(())
+}
+
+fn read_tombstones(db: &Connection) -> rusqlite::Result<Vec<Tombstone>> {
+    let mut statement = db.prepare("SELECT key, deleted_at FROM tombstones ORDER BY key")?;
+    let tombstones = statement
+        .query_map([], |row| {
+            Ok(Tombstone {
+                key: row.get(0)?,
+                deleted_at: row.get(1)?,
+            })
+        })?
+        .collect();
+    tombstones
+}
+
+/// A hash of `fields`, each length-prefixed so no two lists collide.
+fn content_key(fields: &[&str]) -> String {
+    let mut hasher = blake3::Hasher::new();
+    for field in fields {
+        hasher.update(&(field.len() as u64).to_le_bytes());
+        hasher.update(field.as_bytes());
+    }
+    hasher.finalize().to_hex()[..32].to_string()
+}
+
+fn read_bookmarks(
+    db: &Connection,
+    book_id: Option<&str>,
+    offset: u32,
+    limit: u32,
+) -> rusqlite::Result<Vec<Bookmark>> {
+    let order = match book_id {
+        Some(_) => "chapter_index, chapter_offset, id",
+        None => "created_at DESC, id DESC",
+    };
+    let mut statement = db.prepare_cached(&format!(
+        "SELECT {BOOKMARK_COLUMNS} FROM bookmarks WHERE ?1 IS NULL OR book_id = ?1
+         ORDER BY {order} LIMIT ?2 OFFSET ?3"
+    ))?;
+    let bookmarks = statement
+        .query_map(params![book_id, limit, offset], bookmark_row)?
+        .collect();
+    bookmarks
+}
+
+fn book_row(row: &Row) -> rusqlite::Result<LibraryBook> {
+    Ok(LibraryBook {
+        id: row.get(0)?,
+        title: row.get(1)?,
+        author: row.get(2)?,
+        intro: row.get(3)?,
+        kind: row.get(4)?,
+        cover_url: row.get(5)?,
+        source_url: row.get(6)?,
+        book_url: row.get(7)?,
+        toc_url: row.get(8)?,
+        latest_chapter_title: row.get(9)?,
+        chapter_count: row.get(10)?,
+        word_count: row.get(11)?,
+        added_at: row.get(12)?,
+        updated_at: row.get(13)?,
+        last_read_at: row.get(14)?,
+    })
+}
+
+fn bookmark_row(row: &Row) -> rusqlite::Result<Bookmark> {
+    Ok(Bookmark {
+        id: row.get(0)?,
+        book_id: row.get(1)?,
+        chapter_index: row.get(2)?,
+        chapter_offset: row.get(3)?,
+        excerpt: row.get(4)?,
+        note: row.get(5)?,
+        created_at: row.get(6)?,
+    })
+}
+
+fn highlight_row(row: &Row) -> rusqlite::Result<Highlight> {
+    Ok(Highlight {
+        id: row.get(0)?,
+        book_id: row.get(1)?,
+        chapter_index: row.get(2)?,
+        start_offset: row.get(3)?,
+        end_offset: row.get(4)?,
+        text: row.get(5)?,
+        note: row.get(6)?,
+        color: row.get(7)?,
+        created_at: row.get(8)?,
+    })
+}
+
+fn progress_row(row: &Row) -> rusqlite::Result<ReadingProgress> {
+    Ok(ReadingProgress {
+        book_id: row.get(0)?,
+        chapter_index: row.get(1)?,
+        chapter_offset: row.get(2)?,
+        percent: row.get(3)?,
+        updated_at: row.get(4)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn memory_db() -> Connection {
+        let mut db = Connection::open_in_memory().unwrap();
+        setup(&mut db).unwrap();
+        db
+    }
+
+    fn book(id: &str, title: &str, added_at: i64) -> LibraryBook {
+        LibraryBook {
+            id: id.to_string(),
+            title: title.to_string(),
+            author: Some("作者".to_string()),
+            intro: None,
+            kind: None,
+            cover_url: None,
+            source_url: None,
+            book_url: None,
+            toc_url: None,
+            latest_chapter_title: None,
+            chapter_count: 0,
+            word_count: None,
+            added_at,
+            updated_at: added_at,
+            last_read_at: None,
+        }
+    }
+
+    fn query(search: Option<&str>, sort: BookSort, offset: u32, limit: u32) -> BookQuery {
+        BookQuery {
+            search: search.map(str::to_string),
+            sort,
+            offset,
+            limit,
+        }
+    }
+
+    #[test]
+    fn test_migrations() {
+        let mut db = memory_db();
+        let version: u32 = db
+            .pragma_query_value(None, "user_version", |row| row.get(0))
+            .unwrap();
+        assert_eq!(version as usize, MIGRATIONS.len());
+        // Migrating again is a no-op.
+        setup(&mut db).unwrap();
+        db.pragma_update(None, "user_version", MIGRATIONS.len() as u32 + 1)
+            .unwrap();
+        assert!(setup(&mut db).is_err());
+    }
+
+    #[test]
+    fn test_books_and_paging() {
+        let mut db = memory_db();
+        let books: Vec<LibraryBook> = (0..25)
+            .map(|i| book(&format!("b{i}"), &format!("Book {i:02}"), i))
+            .collect();
+        assert_eq!(write_books(&mut db, &books).unwrap(), 25);
+        // Updating keeps the original added time.
+        let mut renamed = book("b3", "100% Done_", 99);
+        renamed.updated_at = 100;
+        write_books(&mut db, &[renamed]).unwrap();
+
+        let page = read_books(&db, &query(None, BookSort::RecentlyAdded, 10, 10)).unwrap();
+        assert_eq!(page.total, 25);
+        let ids: Vec<&str> = page.books.iter().map(|b| b.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            ["b14", "b13", "b12", "b11", "b10", "b9", "b8", "b7", "b6", "b5"]
+        );
+
+        let page = read_books(&db, &query(Some("0% d"), BookSort::Title, 0, 10)).unwrap();
+        assert_eq!(page.total, 1);
+        assert_eq!(page.books[0].added_at, 3);
+        // `_` and `%` match literally.
+        let page = read_books(&db, &query(Some("book_"), BookSort::Title, 0, 10)).unwrap();
+        assert_eq!(page.total, 0);
+        let page = read_books(&db, &query(Some("作者"), BookSort::Title, 0, 5)).unwrap();
+        assert_eq!((page.total, page.books.len()), (25, 5));
+        assert_eq!(page.books[0].title, "100% Done_");
+    }
+
+    #[test]
+    fn test_chapters_progress_and_bookmarks() {
+        let mut db = memory_db();
+        write_books(&mut db, &[book("a", "A", 1), book("b", "B", 2)]).unwrap();
+        let chapters: Vec<LibraryChapter> = (0..5)
+            .map(|index| LibraryChapter {
+                index,
+                title: format!("第{}章", index + 1),
+                url: None,
+                is_volume: index == 0,
+                word_count: Some(1000),
+            })
+            .collect();
+        write_chapters(&mut db, "a", &chapters).unwrap();
+        write_chapters(&mut db, "a", &chapters[..3]).unwrap();
+        let stored = read_chapters(&db, "a", 1, 10).unwrap();
+        assert_eq!(stored.len(), 2);
+        assert_eq!(stored[0].title, "第2章");
+        assert!(!stored[0].is_volume);
+
+        let progress = |updated_at, chapter_index| ReadingProgress {
+            book_id: "b".to_string(),
+            chapter_index,
+            chapter_offset: 10,
+            percent: 0.5,
+            updated_at,
+        };
+        write_progress(&mut db, &[progress(50, 2), progress(40, 1)]).unwrap();
+        let stored = db
+            .query_row("SELECT * FROM progress", [], progress_row)
+            .unwrap();
+        assert_eq!((stored.chapter_index, stored.updated_at), (2, 50));
+        let page = read_books(&db, &query(None, BookSort::RecentlyRead, 0, 10)).unwrap();
+        assert_eq!(page.books[0].id, "b");
+        assert_eq!(page.books[0].last_read_at, Some(50));
+        assert_eq!(page.books[1].chapter_count, 3);
+
+        let bookmark = |book_id: &str, chapter_offset| Bookmark {
+            id: None,
+            book_id: book_id.to_string(),
+            chapter_index: 1,
+            chapter_offset,
+            excerpt: "摘录".to_string(),
+            note: None,
+            created_at: 0,
+        };
+        let ids = write_bookmarks(
+            &mut db,
+            &[bookmark("a", 30), bookmark("a", 20), bookmark("b", 0)],
+        )
+        .unwrap();
+        let offsets: Vec<u32> = read_bookmarks(&db, Some("a"), 0, 10)
+            .unwrap()
+            .iter()
+            .map(|b| b.chapter_offset)
+            .collect();
+        assert_eq!(offsets, [20, 30]);
+        assert_eq!(
+            read_bookmarks(&db, None, 0, 10).unwrap()[0].id,
+            Some(ids[2])
+        );
+
+        // Deleting a book takes its rows in other tables with it.
+        db.execute("DELETE FROM books WHERE id = 'a'", []).unwrap();
+        assert!(read_chapters(&db, "a", 0, 10).unwrap().is_empty());
+        assert_eq!(read_bookmarks(&db, None, 0, 10).unwrap().len(), 1);
+        assert!(write_progress(
+            &mut db,
+            &[ReadingProgress {
+                book_id: "missing".to_string(),
+                ..progress(60, 0)
+            }]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut db = memory_db();
+        let mut newer = book("a", "A (revised)", 1);
+        newer.updated_at = 10;
+        write_books(&mut db, &[newer, book("b", "B", 2)]).unwrap();
+        let bookmark = Bookmark {
+            id: None,
+            book_id: "a".to_string(),
+            chapter_index: 0,
+            chapter_offset: 5,
+            excerpt: String::new(),
+            note: None,
+            created_at: 1,
+        };
+        write_bookmarks(&mut db, std::slice::from_ref(&bookmark)).unwrap();
+
+        let mut older = book("a", "A", 1);
+        older.updated_at = 5;
+        let mut updated = book("b", "B (revised)", 2);
+        updated.updated_at = 20;
+        let backup = LibraryDump {
+            books: vec![older, updated, book("c", "C", 3)],
+            progress: vec![ReadingProgress {
+                book_id: "missing".to_string(),
+                chapter_index: 0,
+                chapter_offset: 0,
+                percent: 0.0,
+                updated_at: 1,
+            }],
+            bookmarks: vec![
+                Bookmark {
+                    id: Some(99),
+                    ..bookmark.clone()
+                },
+                Bookmark {
+                    created_at: 2,
+                    ..bookmark
+                },
+            ],
+            highlights: vec![],
+        };
+        let counts = merge(&mut db, &backup).unwrap();
+        assert_eq!(
+            (
+                counts.books_added,
+                counts.books_updated,
+                counts.progress_updated
+            ),
+            (1, 1, 0)
+        );
+        assert_eq!(counts.bookmarks_added, 1);
+        let titles: Vec<String> = dump(&db)
+            .unwrap()
+            .books
+            .into_iter()
+            .map(|b| b.title)
+            .collect();
+        assert_eq!(titles, ["A (revised)", "B (revised)", "C"]);
+        assert_eq!(read_bookmarks(&db, None, 0, 10).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_tombstones_and_apply_sync() {
+        let mut db = memory_db();
+        write_books(&mut db, &[book("a", "A", 1)]).unwrap();
+        let bookmark = |created_at| Bookmark {
+            id: None,
+            book_id: "a".to_string(),
+            chapter_index: 0,
+            chapter_offset: 5,
+            excerpt: "城门".to_string(),
+            note: None,
+            created_at,
+        };
+        let ids = write_bookmarks(&mut db, &[bookmark(1), bookmark(2)]).unwrap();
+        assert!(remove_bookmark(&mut db, ids[0]).unwrap());
+        assert!(!remove_bookmark(&mut db, ids[0]).unwrap());
+        // Editing the other buries its old content.
+        let edited = Bookmark {
+            id: Some(ids[1]),
+            note: Some("再读".to_string()),
+            ..bookmark(2)
+        };
+        write_bookmarks(&mut db, std::slice::from_ref(&edited)).unwrap();
+        let keys: Vec<String> = read_tombstones(&db)
+            .unwrap()
+            .into_iter()
+            .map(|t| t.key)
+            .collect();
+        let mut expected = vec![bookmark_key(&bookmark(1)), bookmark_key(&bookmark(2))];
+        expected.sort();
+        assert_eq!(keys, expected);
+
+        // Another device deleted the edited one and added a bookmark.
+        let records = LibraryDump {
+            bookmarks: vec![bookmark(1), bookmark(3)],
+            ..LibraryDump::default()
+        };
+        let tombstones = [Tombstone {
+            key: bookmark_key(&edited),
+            deleted_at: 9,
+        }];
+        let counts = apply_sync(&mut db, &records, &tombstones).unwrap();
+        assert_eq!((counts.bookmarks_added, counts.bookmarks_removed), (1, 1));
+        let stored = read_bookmarks(&db, None, 0, 10).unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].created_at, 3);
+        assert_eq!(read_tombstones(&db).unwrap().len(), 3);
+    }
+}
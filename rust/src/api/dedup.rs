@@ -0,0 +1,502 @@
+//! Finding the same book imported more than once.
+//!
+//! Titles and authors are normalized before comparing: Traditional
+//! characters become Simplified, full-width forms half-width, and release
+//! notes such as `（精校版）` or `全本` and an appended `作者：…` are removed.
+//! Aliases map other names of a book, such as `LOTM`, to its title. Pairs
+//! are scored by title edit distance, weighed down when the authors differ,
+//! and linked into clusters.
+//!
+//! With content comparison on, the first chapters in the chapter store or
+//! cache are fingerprinted as bottom-k sketches of their character
+//! shingles. Matching text links books whatever their titles, and clearly
+//! different text keeps same-titled books apart.
+
+use std::cmp::Reverse;
+use std::collections::BTreeSet;
+
+use anyhow::Result;
+use regex::Regex;
+
+use crate::api::db::{self, BookQuery, BookSort, LibraryBook};
+use crate::api::error::ApiError;
+use crate::api::opencc::{self, ChineseConversion};
+use crate::api::task::{self, CancelToken};
+use crate::api::webserver;
+
+/// Chapters read from the start of a book for its fingerprint.
+const FINGERPRINT_CHAPTERS: u32 = 5;
+/// Characters per shingle.
+const SHINGLE_CHARS: usize = 8;
+/// Hashes kept per fingerprint.
+const SKETCH_SIZE: usize = 128;
+/// Estimated Jaccard similarity from which texts are the same book.
+const SAME_CONTENT: f32 = 0.5;
+/// Estimated Jaccard similarity below which texts are different books.
+const DIFFERENT_CONTENT: f32 = 0.05;
+
+#[derive(Debug, Clone)]
+pub struct BookAlias {
+    /// Another name of the book, such as a translation or abbreviation.
+    pub alias: String,
+    pub title: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct DedupOptions {
+    /// Least score, from 0 to 1, for two books to be duplicates.
+    pub min_score: f32,
+    /// Compare the text of cached chapters as well as titles and authors.
+    pub compare_content: bool,
+    pub aliases: Vec<BookAlias>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DuplicateCluster {
+    /// The copy to keep first: the one with the most chapters, then the
+    /// most recently read.
+    pub books: Vec<LibraryBook>,
+    /// Score of the weakest link in the cluster.
+    pub score: f32,
+    /// Whether chapter text linked any of the books.
+    pub content_match: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct DuplicateMatch {
+    pub book: LibraryBook,
+    pub score: f32,
+}
+
+#[flutter_rust_bridge::frb]
+pub fn default_dedup_options() -> DedupOptions {
+    DedupOptions {
+        min_score: 0.85,
+        compare_content: false,
+        aliases: Vec::new(),
+    }
+}
+
+/// Group the library into clusters of probable duplicates. Books without a
+/// duplicate are left out.
+///
+/// # Arguments
+/// * `options` - Threshold, content comparison and aliases
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+#[flutter_rust_bridge::frb]
+pub async fn find_duplicate_books(
+    options: DedupOptions,
+    cancel_token: Option<u32>,
+) -> Result<Vec<DuplicateCluster>, ApiError> {
+    task::run_blocking(task::cancel_token(cancel_token)?, move |token| {
+        let books = all_books()?;
+        let sketches = if options.compare_content {
+            let mut sketches = Vec::with_capacity(books.len());
+            for book in &books {
+                token.check()?;
+                sketches.push(book_sketch(&book.id));
+            }
+            sketches
+        } else {
+            vec![None; books.len()]
+        };
+        cluster(books, &sketches, &options, token)
+    })
+    .await
+}
+
+/// Library books that are probably the same as a book about to be
+/// imported, best match first.
+///
+/// # Arguments
+/// * `title` - Title of the new book
+/// * `author` - Its author, if known
+/// * `options` - Threshold and aliases; content is not compared
+#[flutter_rust_bridge::frb]
+pub fn find_duplicates_of(
+    title: String,
+    author: Option<String>,
+    options: DedupOptions,
+) -> Result<Vec<DuplicateMatch>, ApiError> {
+    let aliases = Aliases::new(&options.aliases);
+    let wanted = Key::new(&title, author.as_deref(), &aliases);
+    let mut matches: Vec<DuplicateMatch> = all_books()?
+        .into_iter()
+        .filter_map(|book| {
+            let key = Key::new(&book.title, book.author.as_deref(), &aliases);
+            let score = wanted.score(&key);
+            (score >= options.min_score).then_some(DuplicateMatch { book, score })
+        })
+        .collect();
+    matches.sort_by(|a, b| b.score.total_cmp(&a.score));
+    Ok(matches)
+}
+
+fn all_books() -> Result<Vec<LibraryBook>> {
+    Ok(db::query_books(BookQuery {
+        search: None,
+        sort: BookSort::Title,
+        offset: 0,
+        limit: u32::MAX,
+    })?
+    .books)
+}
+
+fn cluster(
+    books: Vec<LibraryBook>,
+    sketches: &[Option<Sketch>],
+    options: &DedupOptions,
+    token: &CancelToken,
+) -> Result<Vec<DuplicateCluster>> {
+    let aliases = Aliases::new(&options.aliases);
+    let keys: Vec<Key> = books
+        .iter()
+        .map(|book| Key::new(&book.title, book.author.as_deref(), &aliases))
+        .collect();
+    // Candidate links as (score, by content, a, b).
+    let mut candidates = Vec::new();
+    for a in 0..books.len() {
+        token.check()?;
+        for b in a + 1..books.len() {
+            let content = match (&sketches[a], &sketches[b]) {
+                (Some(x), Some(y)) => Some(x.similarity(y)),
+                _ => None,
+            };
+            let link = match content {
+                Some(similarity) if similarity >= SAME_CONTENT => Some((similarity.max(0.9), true)),
+                Some(similarity) if similarity < DIFFERENT_CONTENT => None,
+                _ => Some((keys[a].score(&keys[b]), false)),
+            };
+            if let Some((score, by_content)) = link.filter(|(s, _)| *s >= options.min_score) {
+                candidates.push((score, by_content, a, b));
+            }
+        }
+    }
+
+    // Strongest links first, and none by title that would put two
+    // different authors together through a book without one.
+    candidates.sort_by(|x, y| y.0.total_cmp(&x.0));
+    let mut parents: Vec<usize> = (0..books.len()).collect();
+    let mut authors: Vec<Vec<&str>> = keys
+        .iter()
+        .map(|key| {
+            Some(key.author.as_str())
+                .filter(|a| !a.is_empty())
+                .into_iter()
+                .collect()
+        })
+        .collect();
+    let mut links = Vec::new();
+    for (score, by_content, a, b) in candidates {
+        let (root_a, root_b) = (root(&mut parents, a), root(&mut parents, b));
+        if root_a != root_b {
+            let conflict = authors[root_a]
+                .iter()
+                .any(|x| authors[root_b].iter().any(|y| similarity(x, y) < 0.5));
+            if conflict && !by_content {
+                continue;
+            }
+            parents[root_b] = root_a;
+            let merged = std::mem::take(&mut authors[root_b]);
+            authors[root_a].extend(merged);
+        }
+        links.push((score, by_content, a, b));
+    }
+
+    let mut clusters: Vec<(Vec<usize>, f32, bool)> = Vec::new();
+    let mut cluster_of = vec![usize::MAX; books.len()];
+    for (score, by_content, a, _) in &links {
+        let top = root(&mut parents, *a);
+        if cluster_of[top] == usize::MAX {
+            cluster_of[top] = clusters.len();
+            clusters.push((Vec::new(), f32::MAX, false));
+        }
+        let cluster = &mut clusters[cluster_of[top]];
+        cluster.1 = cluster.1.min(*score);
+        cluster.2 |= by_content;
+    }
+    for i in 0..books.len() {
+        let top = root(&mut parents, i);
+        if let Some(cluster) = clusters.get_mut(cluster_of[top]) {
+            cluster.0.push(i);
+        }
+    }
+    let mut books: Vec<Option<LibraryBook>> = books.into_iter().map(Some).collect();
+    Ok(clusters
+        .into_iter()
+        .map(|(members, score, content_match)| {
+            let mut books: Vec<LibraryBook> =
+                members.iter().filter_map(|&i| books[i].take()).collect();
+            books.sort_by_key(|book| {
+                (
+                    Reverse(book.chapter_count),
+                    Reverse(book.last_read_at.unwrap_or(0)),
+                    book.added_at,
+                )
+            });
+            DuplicateCluster {
+                books,
+                score,
+                content_match,
+            }
+        })
+        .collect())
+}
+
+fn root(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+    i
+}
+
+/// A book's normalized title and author.
+struct Key {
+    title: String,
+    author: String,
+}
+
+impl Key {
+    fn new(title: &str, author: Option<&str>, aliases: &Aliases) -> Self {
+        let title = aliases.resolve(normalize_title(title));
+        Key {
+            title,
+            author: author.map(normalize_author).unwrap_or_default(),
+        }
+    }
+
+    fn score(&self, other: &Key) -> f32 {
+        if self.title.is_empty() || other.title.is_empty() {
+            return 0.0;
+        }
+        let title = similarity(&self.title, &other.title);
+        if self.author.is_empty() || other.author.is_empty() {
+            return title * 0.9;
+        }
+        let author = similarity(&self.author, &other.author);
+        if author < 0.5 {
+            title * 0.5
+        } else {
+            title * (0.8 + 0.2 * author)
+        }
+    }
+}
+
+fn similarity(a: &str, b: &str) -> f32 {
+    let (a_len, b_len) = (a.chars().count(), b.chars().count());
+    // Too different in length to be close, without the edit distance.
+    if a_len.min(b_len) * 2 < a_len.max(b_len) {
+        return 0.0;
+    }
+    strsim::normalized_levenshtein(a, b) as f32
+}
+
+struct Aliases(Vec<(String, String)>);
+
+impl Aliases {
+    fn new(aliases: &[BookAlias]) -> Self {
+        Aliases(
+            aliases
+                .iter()
+                .map(|a| (normalize_title(&a.alias), normalize_title(&a.title)))
+                .filter(|(alias, title)| !alias.is_empty() && !title.is_empty())
+                .collect(),
+        )
+    }
+
+    fn resolve(&self, title: String) -> String {
+        match self.0.iter().find(|(alias, _)| *alias == title) {
+            Some((_, canonical)) => canonical.clone(),
+            None => title,
+        }
+    }
+}
+
+/// Simplified, half-width and lowercase, without punctuation.
+fn fold(text: &str) -> String {
+    let text = opencc::convert_chinese(text.to_string(), ChineseConversion::T2s)
+        .unwrap_or_else(|_| text.to_string());
+    text.chars()
+        .map(|c| match c {
+            '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+            '\u{3000}' => ' ',
+            c => c,
+        })
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn normalize_title(title: &str) -> String {
+    let noise = concat!(
+        "精校|校对|校订|修订|完结|完本|全本|全集|连载|txt|epub|mobi|无删减|",
+        "最新|新版|出版|实体|手打|典藏|珍藏|作者",
+    );
+    let title = fold(title);
+    let bracketed = Regex::new(&format!(
+        r"[(\[【〔<][^)\]】〕>]*(?:{noise})[^)\]】〕>]*[)\]】〕>]"
+    ))
+    .unwrap();
+    let title = bracketed.replace_all(&title, "");
+    let by_author = Regex::new(r"\s*(?:作者|by)\s*[:：].*$").unwrap();
+    let title = by_author.replace(&title, "");
+    let suffix = Regex::new(r"(?:txt全集|全[本集]|完[结本]|精校版?|最新章节)\s*$").unwrap();
+    let title = suffix.replace(&title, "");
+    title.chars().filter(|c| c.is_alphanumeric()).collect()
+}
+
+fn normalize_author(author: &str) -> String {
+    let author = fold(author);
+    let author = author.trim_start_matches("作者").trim_end_matches('著');
+    author.chars().filter(|c| c.is_alphanumeric()).collect()
+}
+
+/// The smallest hashes of a text's shingles.
+#[derive(Clone)]
+struct Sketch(BTreeSet<u64>);
+
+impl Sketch {
+    fn new(text: &str) -> Option<Self> {
+        let chars: Vec<char> = text.chars().filter(|c| c.is_alphanumeric()).collect();
+        let mut hashes = BTreeSet::new();
+        for shingle in chars.windows(SHINGLE_CHARS) {
+            hashes.insert(fnv1a(shingle));
+            if hashes.len() > SKETCH_SIZE {
+                hashes.pop_last();
+            }
+        }
+        // Too little text to say anything about.
+        (hashes.len() == SKETCH_SIZE).then_some(Sketch(hashes))
+    }
+
+    /// Estimated Jaccard similarity of the two shingle sets: the share of
+    /// the smallest hashes of their union that both have.
+    fn similarity(&self, other: &Sketch) -> f32 {
+        let union: Vec<&u64> = self.0.union(&other.0).take(SKETCH_SIZE).collect();
+        let shared = union
+            .iter()
+            .filter(|hash| self.0.contains(hash) && other.0.contains(hash))
+            .count();
+        shared as f32 / union.len().max(1) as f32
+    }
+}
+
+fn book_sketch(book_id: &str) -> Option<Sketch> {
+    let text: String = (0..FINGERPRINT_CHAPTERS)
+        .filter_map(|index| webserver::chapter_text(book_id, index))
+        .collect();
+    Sketch::new(&text)
+}
+
+fn fnv1a(chars: &[char]) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    for c in chars {
+        for byte in (*c as u32).to_le_bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn book(id: &str, title: &str, author: Option<&str>, chapter_count: u32) -> LibraryBook {
+        LibraryBook {
+            id: id.to_string(),
+            title: title.to_string(),
+            author: author.map(str::to_string),
+            intro: None,
+            kind: None,
+            cover_url: None,
+            source_url: None,
+            book_url: None,
+            toc_url: None,
+            latest_chapter_title: None,
+            chapter_count,
+            word_count: None,
+            added_at: 0,
+            updated_at: 0,
+            last_read_at: None,
+        }
+    }
+
+    fn ids(clusters: &[DuplicateCluster]) -> Vec<Vec<&str>> {
+        clusters
+            .iter()
+            .map(|c| c.books.iter().map(|b| b.id.as_str()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_normalizes_titles_and_authors() {
+        assert_eq!(normalize_title("《詭秘之主》（精校版）"), "诡秘之主");
+        assert_eq!(normalize_title("诡秘之主 作者：爱潜水的乌贼"), "诡秘之主");
+        assert_eq!(normalize_title("诡秘之主全本"), "诡秘之主");
+        assert_eq!(normalize_title("斗罗大陆（第二部）"), "斗罗大陆第二部");
+        assert_eq!(normalize_title("Ｌｏｒｄ of Mysteries"), "lordofmysteries");
+        assert_eq!(normalize_author("作者：愛潛水的烏賊 著"), "爱潜水的乌贼");
+    }
+
+    #[test]
+    fn test_clusters_by_title_author_and_alias() {
+        let books = vec![
+            book("a", "诡秘之主", Some("爱潜水的乌贼"), 100),
+            book("b", "詭秘之主(完本)", Some("愛潛水的烏賊"), 1400),
+            book("c", "LOTM", None, 10),
+            book("d", "诡秘之主", Some("别人"), 5),
+            book("e", "遮天", Some("辰东"), 1800),
+        ];
+        let options = DedupOptions {
+            aliases: vec![BookAlias {
+                alias: "lotm".to_string(),
+                title: "诡秘之主".to_string(),
+            }],
+            ..default_dedup_options()
+        };
+        let clusters = cluster(
+            books,
+            &[None, None, None, None, None],
+            &options,
+            &CancelToken::default(),
+        );
+        let clusters = clusters.unwrap();
+        assert_eq!(ids(&clusters), [vec!["b", "a", "c"]]);
+        assert!((clusters[0].score - 0.9).abs() < 1e-6);
+        assert!(!clusters[0].content_match);
+    }
+
+    #[test]
+    fn test_content_links_and_separates() {
+        let text = |step: u32| -> String {
+            (0..400)
+                .map(|i| char::from_u32(0x4E00 + (i * step + i * i) % 20000).unwrap())
+                .collect()
+        };
+        let same = Sketch::new(&text(7)).unwrap();
+        let other = Sketch::new(&text(13)).unwrap();
+        let mut edited = text(7);
+        edited.insert_str(0, "作者的话：谢谢大家。");
+        assert!(same.similarity(&Sketch::new(&edited).unwrap()) > 0.8);
+        assert!(same.similarity(&other) < DIFFERENT_CONTENT);
+        assert!(Sketch::new("太短").is_none());
+
+        let books = vec![
+            book("a", "某书", Some("甲"), 1),
+            book("b", "另一个名字", Some("乙"), 2),
+            book("c", "某书", Some("甲"), 3),
+        ];
+        let sketches = [Some(same.clone()), Some(same), Some(other)];
+        let clusters = cluster(
+            books,
+            &sketches,
+            &default_dedup_options(),
+            &CancelToken::default(),
+        )
+        .unwrap();
+        assert_eq!(ids(&clusters), [vec!["b", "a"]]);
+        assert!(clusters[0].content_match);
+    }
+}
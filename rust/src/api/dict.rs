@@ -0,0 +1,515 @@
+//! Offline dictionary lookup from user-provided dictionary files.
+//!
+//! MDict (`.mdx`, with resources in sibling `.mdd` files) and StarDict
+//! (`.ifo` with `.idx` and `.dict`/`.dict.dz`) dictionaries are supported.
+//! Opening one builds an in-memory index of its headwords; definitions are
+//! read from disk on demand, so only the blocks holding a looked-up word
+//! are ever decompressed. Lookups search every open dictionary in the
+//! order they were opened.
+
+use std::cmp::Ordering;
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicU32, Ordering as AtomicOrdering};
+use std::sync::{Mutex, MutexGuard};
+
+use anyhow::{anyhow, Result};
+
+use crate::api::error::ApiError;
+use crate::mdict::Mdict;
+use crate::stardict::StarDict;
+
+/// MDict redirects (`@@@LINK=word`) followed before giving up.
+const MAX_REDIRECTS: usize = 5;
+
+static DICTIONARIES: Mutex<Option<Vec<Dictionary>>> = Mutex::new(None);
+
+static NEXT_ID: AtomicU32 = AtomicU32::new(1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DictFormat {
+    Mdict,
+    StarDict,
+}
+
+#[derive(Debug, Clone)]
+pub struct DictInfo {
+    pub id: u32,
+    /// Path the dictionary was opened from.
+    pub path: String,
+    pub format: DictFormat,
+    pub title: String,
+    /// Description from the dictionary header; may contain HTML.
+    pub description: String,
+    /// Number of headwords.
+    pub entry_count: u32,
+    /// Number of resource files (`.mdd` files or a StarDict `res`
+    /// directory) found next to the dictionary.
+    pub resource_files: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct DictDefinition {
+    pub dict_id: u32,
+    pub dict_title: String,
+    /// Headword as written in the dictionary.
+    pub headword: String,
+    /// Definition HTML. Images and styles refer to resources by relative
+    /// path; load them with `dictionary_resource`.
+    pub html: String,
+}
+
+/// Open a dictionary and add it to the lookup list. Opening a dictionary
+/// that is already open returns it again.
+///
+/// # Arguments
+/// * `path` - An `.mdx` file, or the `.ifo` file of a StarDict dictionary
+#[flutter_rust_bridge::frb]
+pub fn open_dictionary(path: String) -> Result<DictInfo, ApiError> {
+    if let Some(dictionary) = lock()
+        .get_or_insert_with(Vec::new)
+        .iter()
+        .find(|dictionary| dictionary.path == path)
+    {
+        return Ok(dictionary.info());
+    }
+
+    let file = Path::new(&path);
+    let extension = file
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let source = match extension.as_str() {
+        "mdx" => Source::Mdict {
+            mdx: Mdict::open(file, false)?,
+            mdd: resource_files(file)
+                .iter()
+                .map(|mdd| Mdict::open(mdd, true))
+                .collect::<Result<_>>()?,
+        },
+        "ifo" => Source::StarDict(StarDict::open(file)?),
+        _ => return Err(anyhow!("Unsupported dictionary file: {path}").into()),
+    };
+    let dictionary = Dictionary {
+        id: NEXT_ID.fetch_add(1, AtomicOrdering::Relaxed),
+        path,
+        source,
+    };
+    let info = dictionary.info();
+    lock().get_or_insert_with(Vec::new).push(dictionary);
+    Ok(info)
+}
+
+/// Close a dictionary. Returns false if it was not open.
+#[flutter_rust_bridge::frb]
+pub fn close_dictionary(id: u32) -> bool {
+    let mut guard = lock();
+    let Some(dictionaries) = guard.as_mut() else {
+        return false;
+    };
+    let before = dictionaries.len();
+    dictionaries.retain(|dictionary| dictionary.id != id);
+    dictionaries.len() != before
+}
+
+/// List open dictionaries in lookup order.
+#[flutter_rust_bridge::frb]
+pub fn list_dictionaries() -> Vec<DictInfo> {
+    lock()
+        .as_ref()
+        .map(|dictionaries| dictionaries.iter().map(Dictionary::info).collect())
+        .unwrap_or_default()
+}
+
+/// Look a word up in every open dictionary. Matching ignores case and,
+/// where the dictionary asks for it, punctuation and spaces.
+///
+/// # Arguments
+/// * `word` - Word to look up; surrounding punctuation is ignored
+/// * `max_results` - Most definitions to return across all dictionaries
+#[flutter_rust_bridge::frb]
+pub fn lookup_word(word: String, max_results: u32) -> Result<Vec<DictDefinition>, ApiError> {
+    let word = word.trim_matches(|c: char| !c.is_alphanumeric());
+    let mut definitions: Vec<DictDefinition> = Vec::new();
+    if word.is_empty() {
+        return Ok(definitions);
+    }
+    let mut guard = lock();
+    for dictionary in guard.iter_mut().flatten() {
+        for (headword, html) in dictionary.define(word)? {
+            if definitions.len() >= max_results as usize {
+                return Ok(definitions);
+            }
+            let duplicate = definitions
+                .iter()
+                .any(|other| other.dict_id == dictionary.id && other.html == html);
+            if !duplicate {
+                definitions.push(DictDefinition {
+                    dict_id: dictionary.id,
+                    dict_title: dictionary.title().to_string(),
+                    headword,
+                    html,
+                });
+            }
+        }
+    }
+    Ok(definitions)
+}
+
+/// Headwords starting with `prefix` across all open dictionaries, sorted
+/// and without duplicates, for lookup suggestions.
+///
+/// # Arguments
+/// * `prefix` - Start of the word typed so far
+/// * `max_results` - Most headwords to return
+#[flutter_rust_bridge::frb]
+pub fn lookup_prefix(prefix: String, max_results: u32) -> Vec<String> {
+    let prefix = prefix.trim();
+    if prefix.is_empty() {
+        return Vec::new();
+    }
+    let mut words: Vec<String> = Vec::new();
+    for dictionary in lock().iter().flatten() {
+        words.extend(
+            dictionary
+                .index()
+                .prefix(prefix, max_results as usize)
+                .map(str::to_string),
+        );
+    }
+    words.sort_by_cached_key(|word| (word.to_lowercase(), word.clone()));
+    words.dedup();
+    words.truncate(max_results as usize);
+    words
+}
+
+/// Load a file a definition refers to, such as an image, sound or style
+/// sheet. Returns `None` if the dictionary does not have it.
+///
+/// # Arguments
+/// * `dict_id` - Dictionary the definition came from
+/// * `path` - Path as written in the definition HTML, e.g. `img/a.png`
+#[flutter_rust_bridge::frb]
+pub fn dictionary_resource(dict_id: u32, path: String) -> Result<Option<Vec<u8>>, ApiError> {
+    let mut guard = lock();
+    let dictionary = guard
+        .iter_mut()
+        .flatten()
+        .find(|dictionary| dictionary.id == dict_id)
+        .ok_or_else(|| anyhow!("Dictionary {dict_id} is not open"))?;
+    let path = path
+        .trim()
+        .trim_start_matches("file://")
+        .split(['?', '#'])
+        .next()
+        .unwrap_or_default();
+    match &mut dictionary.source {
+        Source::Mdict { mdd, .. } => {
+            let key = format!("\\{}", path.replace('/', "\\").trim_start_matches('\\'));
+            for resources in mdd {
+                if let Some((_, start, end)) = resources.index.find(&key).first().cloned() {
+                    return Ok(Some(resources.record(start, end)?));
+                }
+            }
+            Ok(None)
+        }
+        Source::StarDict(dict) => {
+            let Some(dir) = &dict.resource_dir else {
+                return Ok(None);
+            };
+            let relative = Path::new(path.trim_start_matches('/'));
+            if relative
+                .components()
+                .any(|part| !matches!(part, Component::Normal(_)))
+            {
+                return Ok(None);
+            }
+            match std::fs::read(dir.join(relative)) {
+                Ok(data) => Ok(Some(data)),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+                Err(e) => Err(anyhow!("Failed to read dictionary resource: {e}").into()),
+            }
+        }
+    }
+}
+
+fn lock() -> MutexGuard<'static, Option<Vec<Dictionary>>> {
+    DICTIONARIES.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// `name.mdd`, then `name.1.mdd`, `name.2.mdd` and so on.
+fn resource_files(mdx: &Path) -> Vec<PathBuf> {
+    let base = mdx.with_extension("");
+    let mut files = Vec::new();
+    let first = base.with_extension("mdd");
+    if first.exists() {
+        files.push(first);
+    }
+    for n in 1.. {
+        let next = PathBuf::from(format!("{}.{n}.mdd", base.display()));
+        if !next.exists() {
+            break;
+        }
+        files.push(next);
+    }
+    files
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+struct Dictionary {
+    id: u32,
+    path: String,
+    source: Source,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+enum Source {
+    Mdict { mdx: Mdict, mdd: Vec<Mdict> },
+    StarDict(StarDict),
+}
+
+impl Dictionary {
+    fn info(&self) -> DictInfo {
+        let (format, description, resource_files) = match &self.source {
+            Source::Mdict { mdx, mdd } => (DictFormat::Mdict, &mdx.description, mdd.len()),
+            Source::StarDict(dict) => (
+                DictFormat::StarDict,
+                &dict.description,
+                dict.resource_dir.iter().count(),
+            ),
+        };
+        DictInfo {
+            id: self.id,
+            path: self.path.clone(),
+            format,
+            title: self.title().to_string(),
+            description: description.clone(),
+            entry_count: self.index().len() as u32,
+            resource_files: resource_files as u32,
+        }
+    }
+
+    fn title(&self) -> &str {
+        match &self.source {
+            Source::Mdict { mdx, .. } => &mdx.title,
+            Source::StarDict(dict) => &dict.title,
+        }
+    }
+
+    fn index(&self) -> &KeyIndex {
+        match &self.source {
+            Source::Mdict { mdx, .. } => &mdx.index,
+            Source::StarDict(dict) => &dict.index,
+        }
+    }
+
+    /// Every `(headword, html)` definition of `word`, following redirects.
+    fn define(&mut self, word: &str) -> Result<Vec<(String, String)>> {
+        let entries = self.index().find(word).to_vec();
+        let mut definitions = Vec::with_capacity(entries.len());
+        for (headword, start, end) in entries {
+            let html = match &mut self.source {
+                Source::StarDict(dict) => dict.html(start, end)?,
+                Source::Mdict { mdx, .. } => {
+                    let mut html = mdx.text(start, end)?;
+                    for _ in 0..MAX_REDIRECTS {
+                        let Some(target) = html.strip_prefix("@@@LINK=") else {
+                            break;
+                        };
+                        let Some((_, start, end)) = mdx.index.find(target.trim()).first().cloned()
+                        else {
+                            break;
+                        };
+                        html = mdx.text(start, end)?;
+                    }
+                    html
+                }
+            };
+            definitions.push((headword, html));
+        }
+        Ok(definitions)
+    }
+}
+
+/// How headwords are normalised before comparing.
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Clone, Copy)]
+pub(crate) struct Fold {
+    pub(crate) case_sensitive: bool,
+    /// Ignore everything but letters and digits.
+    pub(crate) strip: bool,
+}
+
+impl Fold {
+    fn chars(self, key: &str) -> impl Iterator<Item = char> + '_ {
+        key.chars()
+            .filter(move |c| !self.strip || c.is_alphanumeric())
+            .flat_map(move |c| {
+                let (kept, lower) = if self.case_sensitive {
+                    (Some(c), None)
+                } else {
+                    (None, Some(c.to_lowercase()))
+                };
+                kept.into_iter().chain(lower.into_iter().flatten())
+            })
+    }
+
+    fn cmp(self, a: &str, b: &str) -> Ordering {
+        self.chars(a).cmp(self.chars(b))
+    }
+
+    fn has_prefix(self, key: &str, prefix: &str) -> bool {
+        let mut key = self.chars(key);
+        self.chars(prefix).all(|c| key.next() == Some(c))
+    }
+}
+
+/// Headwords with the byte range of their definitions, sorted for binary
+/// search under a `Fold`.
+#[flutter_rust_bridge::frb(ignore)]
+pub(crate) struct KeyIndex {
+    entries: Vec<(String, u64, u64)>,
+    fold: Fold,
+}
+
+impl KeyIndex {
+    pub(crate) fn new(mut entries: Vec<(String, u64, u64)>, fold: Fold) -> Self {
+        // Stable, so entries for the same word keep their file order.
+        entries.sort_by(|a, b| fold.cmp(&a.0, &b.0));
+        Self { entries, fold }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// All entries whose headword matches `key`.
+    pub(crate) fn find(&self, key: &str) -> &[(String, u64, u64)] {
+        let from = self.lower_bound(key);
+        let count = self.entries[from..]
+            .iter()
+            .take_while(|entry| self.fold.cmp(&entry.0, key) == Ordering::Equal)
+            .count();
+        &self.entries[from..from + count]
+    }
+
+    /// Up to `max` headwords starting with `prefix`, in index order.
+    pub(crate) fn prefix<'a>(
+        &'a self,
+        prefix: &'a str,
+        max: usize,
+    ) -> impl Iterator<Item = &'a str> {
+        self.entries[self.lower_bound(prefix)..]
+            .iter()
+            .take_while(move |entry| self.fold.has_prefix(&entry.0, prefix))
+            .map(|entry| entry.0.as_str())
+            .take(max)
+    }
+
+    fn lower_bound(&self, key: &str) -> usize {
+        self.entries
+            .partition_point(|entry| self.fold.cmp(&entry.0, key) == Ordering::Less)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_dirs::temp_dir;
+    use std::fs;
+
+    #[test]
+    fn test_key_index_folds_keys() {
+        let entries = ["Apple", "apple pie", "apple", "Banana", "a-b"]
+            .iter()
+            .enumerate()
+            .map(|(i, key)| (key.to_string(), i as u64, i as u64 + 1))
+            .collect();
+        let index = KeyIndex::new(
+            entries,
+            Fold {
+                case_sensitive: false,
+                strip: true,
+            },
+        );
+        let found: Vec<_> = index.find("APPLE").iter().map(|e| e.0.as_str()).collect();
+        assert_eq!(found, ["Apple", "apple"]);
+        assert_eq!(index.find("ab")[0].0, "a-b");
+        assert!(index.find("cherry").is_empty());
+        let words: Vec<_> = index.prefix("app", 10).collect();
+        assert_eq!(words, ["Apple", "apple", "apple pie"]);
+        assert_eq!(index.prefix("app", 1).count(), 1);
+    }
+
+    #[test]
+    fn test_mdict_lookup_and_resources() {
+        let dir = temp_dir("mdict");
+        let mdx = dir.join("fruit.mdx");
+        let entries: [(&str, &[u8]); 4] = [
+            (
+                "apple",
+                b"<link rel=\"stylesheet\" href=\"fruit.css\"><b>apple</b>\0",
+            ),
+            ("apples", b"@@@LINK=apple\r\n\0"),
+            ("banana", b"<img src=\"img/banana.png\">\0"),
+            ("cherry", b"<b>cherry</b>\0"),
+        ];
+        fs::write(&mdx, crate::mdict::tests::build(&entries, false, "Fruit")).unwrap();
+        let resources: [(&str, &[u8]); 2] = [
+            ("\\fruit.css", b"b { color: red }"),
+            ("\\img\\banana.png", b"PNG"),
+        ];
+        fs::write(
+            dir.join("fruit.mdd"),
+            crate::mdict::tests::build(&resources, true, "Fruit"),
+        )
+        .unwrap();
+
+        let path = mdx.to_string_lossy().into_owned();
+        let info = open_dictionary(path.clone()).unwrap();
+        assert_eq!(info.format, DictFormat::Mdict);
+        assert_eq!((info.entry_count, info.resource_files), (4, 1));
+        assert_eq!(open_dictionary(path).unwrap().id, info.id);
+
+        let found = lookup_word("“Apples,”".to_string(), 10).unwrap();
+        let found: Vec<_> = found.iter().filter(|d| d.dict_id == info.id).collect();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].headword, "apples");
+        assert!(found[0].html.ends_with("<b>apple</b>"));
+        assert!(lookup_prefix("APP".to_string(), 10).contains(&"apples".to_string()));
+
+        let image = dictionary_resource(info.id, "img/banana.png".to_string()).unwrap();
+        assert_eq!(image.as_deref(), Some(&b"PNG"[..]));
+        let css = dictionary_resource(info.id, "/FRUIT.CSS".to_string()).unwrap();
+        assert_eq!(css.as_deref(), Some(&b"b { color: red }"[..]));
+        assert!(dictionary_resource(info.id, "missing.png".to_string())
+            .unwrap()
+            .is_none());
+
+        assert!(close_dictionary(info.id));
+        assert!(!close_dictionary(info.id));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_stardict_lookup() {
+        let dir = temp_dir("stardict");
+        let ifo = crate::stardict::tests::build(&dir, "words", &[("zebra", "a striped animal")]);
+        fs::create_dir_all(dir.join("res")).unwrap();
+        fs::write(dir.join("res/zebra.jpg"), b"JPG").unwrap();
+
+        let info = open_dictionary(ifo.to_string_lossy().into_owned()).unwrap();
+        assert_eq!(info.format, DictFormat::StarDict);
+        assert_eq!(info.resource_files, 1);
+        let found = lookup_word("Zebra".to_string(), 10).unwrap();
+        let found = found.iter().find(|d| d.dict_id == info.id).unwrap();
+        assert_eq!(found.html, "<div>a striped animal</div>");
+
+        let image = dictionary_resource(info.id, "zebra.jpg".to_string()).unwrap();
+        assert_eq!(image.as_deref(), Some(&b"JPG"[..]));
+        assert!(dictionary_resource(info.id, "../words.ifo".to_string())
+            .unwrap()
+            .is_none());
+        close_dictionary(info.id);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,1436 @@
+//! Background downloads of chapters, covers and fonts.
+//!
+//! Tasks wait in a FIFO queue and start once both the global and the
+//! per-host concurrency limits allow, each on its own thread. Low-priority
+//! tasks, such as prefetched chapters, only start when no normal task is
+//! waiting for the slot. Data is written to `<destination>.part` and
+//! renamed over the destination when complete, so a download cut off by a
+//! crash or by the app being killed picks up with a range request when it
+//! is enqueued again. Network errors
+//! and 408, 429 and 5xx responses are retried with exponential backoff.
+//! Fresh responses in the HTTP cache are copied without a request, and
+//! stale ones are revalidated. Cancelling a running task frees its slot at
+//! once, even if its thread is stuck in a connect or read; the thread
+//! cleans up when the call finally returns.
+//!
+//! All downloads share one bandwidth cap, with a lower one for metered
+//! connections, so a large batch cannot use up a data plan. The app reports
+//! the connection with `set_network_type`. Offline, or on a metered
+//! connection when the policy says so, background tasks wait: queued ones
+//! stay queued and running ones go back to the front of the queue, keeping
+//! their partial data, to resume once the connection allows.
+//!
+//! A download is checked before it replaces its destination or enters the
+//! HTTP cache: against the checksum given with the request, or else a
+//! SHA-256 digest sent by the server. A mismatch is retried like a network
+//! error. When the request lists digests of fixed-size parts, only the
+//! data from the first bad part on is fetched again; otherwise the whole
+//! file is.
+
+use std::collections::{HashMap, VecDeque};
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Result};
+use base64::Engine;
+use sha2::{Digest, Sha256};
+use url::Url;
+
+use crate::api::error::ApiError;
+use crate::api::prefetch::NetworkType;
+use crate::api::{font_converter, http_cache, logging, network};
+use crate::frb_generated::StreamSink;
+
+/// Minimum time between two progress events of one task.
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+const BUFFER_SIZE: usize = 64 * 1024;
+
+/// Transfer time a capped download may catch up on after being idle.
+const BURST: Duration = Duration::from_millis(250);
+
+static DOWNLOADER: OnceLock<Arc<Downloader>> = OnceLock::new();
+
+#[derive(Debug, Clone)]
+pub struct DownloaderConfig {
+    /// Downloads running at once across all hosts.
+    pub max_concurrent: u32,
+    /// Downloads running at once against a single host.
+    pub max_per_host: u32,
+    /// Retries after the first attempt before a task fails.
+    pub max_retries: u32,
+    /// Delay before the first retry; each further retry doubles it.
+    pub initial_backoff_ms: u32,
+    /// Connect and read timeout.
+    pub timeout_secs: u32,
+    pub user_agent: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct BandwidthPolicy {
+    /// Cap on all downloads together, in KB/s; 0 for none.
+    pub max_kbps: u32,
+    /// Cap on a metered connection, in KB/s; 0 for only `max_kbps`.
+    pub metered_max_kbps: u32,
+    /// Whether background downloads, such as prefetched chapters and
+    /// covers, wait while the connection is metered.
+    pub pause_background_when_metered: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct DownloadHeader {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct DownloadRequest {
+    pub url: String,
+    /// File to write; missing parent directories are created.
+    pub destination: String,
+    /// Extra request headers, such as `Referer` or `Cookie`.
+    pub headers: Vec<DownloadHeader>,
+    /// Expected content, such as from a font or book manifest.
+    pub checksum: Option<DownloadChecksum>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChecksumAlgorithm {
+    Blake3,
+    Sha256,
+}
+
+#[derive(Debug, Clone)]
+pub struct DownloadChecksum {
+    pub algorithm: ChecksumAlgorithm,
+    /// Hex digest of the whole file.
+    pub digest: String,
+    /// Size of each part in `part_digests`.
+    pub part_size: u64,
+    /// Hex digests of the file's consecutive `part_size` byte parts, the
+    /// last one possibly shorter; may be empty.
+    pub part_digests: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DownloadState {
+    Queued,
+    Running,
+    /// Waiting to retry after a failed attempt.
+    Retrying,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+#[derive(Debug, Clone)]
+pub struct DownloadEvent {
+    pub task_id: u32,
+    pub state: DownloadState,
+    /// Bytes written so far, including a resumed partial download.
+    pub downloaded: u64,
+    /// Size of the whole file, when the server reports it.
+    pub total: Option<u64>,
+    /// Attempts started so far.
+    pub attempt: u32,
+    /// Why the task failed or is being retried.
+    pub error: Option<String>,
+}
+
+/// Settings used until `configure_downloader` is called.
+#[flutter_rust_bridge::frb]
+pub fn default_downloader_config() -> DownloaderConfig {
+    DownloaderConfig {
+        max_concurrent: 4,
+        max_per_host: 2,
+        max_retries: 3,
+        initial_backoff_ms: 1000,
+        timeout_secs: 30,
+        user_agent: network::USER_AGENT.to_string(),
+    }
+}
+
+/// No caps, and background downloads run on any connection.
+#[flutter_rust_bridge::frb]
+pub fn default_bandwidth_policy() -> BandwidthPolicy {
+    BandwidthPolicy {
+        max_kbps: 0,
+        metered_max_kbps: 0,
+        pause_background_when_metered: false,
+    }
+}
+
+/// Change the bandwidth caps and background policy; they apply to running
+/// downloads at once.
+#[flutter_rust_bridge::frb]
+pub fn set_bandwidth_policy(policy: BandwidthPolicy) {
+    downloader().set_bandwidth(|bandwidth| bandwidth.policy = policy);
+}
+
+/// Report the current connection; until then it is taken to be unmetered.
+#[flutter_rust_bridge::frb]
+pub fn set_network_type(network: NetworkType) {
+    downloader().set_bandwidth(|bandwidth| bandwidth.network = network);
+}
+
+/// Change the downloader settings. Running downloads keep the settings they
+/// started with.
+#[flutter_rust_bridge::frb]
+pub fn configure_downloader(config: DownloaderConfig) -> Result<(), ApiError> {
+    Ok(downloader().configure(config)?)
+}
+
+/// Receive events for every download task, replacing any previous stream.
+/// Nothing is buffered while no stream is listening; the final file on
+/// disk is the source of truth after a restart.
+#[flutter_rust_bridge::frb]
+pub fn download_events(sink: StreamSink<DownloadEvent>) -> Result<(), ApiError> {
+    downloader().listen(Box::new(move |event| {
+        let _ = sink.add(event);
+    }));
+    Ok(())
+}
+
+/// Queue a download and return its task ID.
+///
+/// A partial download left at the same destination by an earlier run is
+/// resumed when the server supports range requests.
+///
+/// # Arguments
+/// * `request` - URL, destination file and extra headers
+#[flutter_rust_bridge::frb]
+pub fn enqueue_download(request: DownloadRequest) -> Result<u32, ApiError> {
+    Ok(downloader().enqueue(request)?)
+}
+
+/// Queue a background download, such as a cover, and return its task ID.
+/// It starts only when no other download is waiting, and follows the
+/// background policy of `set_bandwidth_policy`.
+///
+/// # Arguments
+/// * `request` - URL, destination file and extra headers
+#[flutter_rust_bridge::frb]
+pub fn enqueue_background_download(request: DownloadRequest) -> Result<u32, ApiError> {
+    Ok(downloader().enqueue_with(request, Priority::Low)?)
+}
+
+/// Cancel a queued or running download and delete its partial data.
+/// Returns false if the task has already finished or does not exist.
+#[flutter_rust_bridge::frb]
+pub fn cancel_download(task_id: u32) -> bool {
+    downloader().cancel(task_id)
+}
+
+fn downloader() -> &'static Arc<Downloader> {
+    DOWNLOADER.get_or_init(|| {
+        Arc::new(Downloader::new(default_downloader_config()).expect("default config is valid"))
+    })
+}
+
+/// Queue a low-priority download, for prefetching, and return its task ID.
+pub(crate) fn enqueue_low_priority(request: DownloadRequest) -> Result<u32> {
+    downloader().enqueue_with(request, Priority::Low)
+}
+
+/// Wait until `bytes` more fit under the shared bandwidth cap; see `fetch`.
+pub(crate) fn pace(bytes: usize, cancelled: &AtomicBool) -> Result<(), Failure> {
+    downloader().pace(bytes, Priority::Normal, cancelled)
+}
+
+/// The state of a queued or running task; `None` once it has finished.
+pub(crate) fn task_state(task_id: u32) -> Option<DownloadState> {
+    let state = lock(&downloader().state);
+    if state.queue.iter().any(|task| task.id == task_id) {
+        return Some(DownloadState::Queued);
+    }
+    state.running.contains_key(&task_id).then(|| {
+        state
+            .retrying
+            .get(&task_id)
+            .copied()
+            .unwrap_or(DownloadState::Running)
+    })
+}
+
+type Listener = Box<dyn Fn(DownloadEvent) + Send + Sync>;
+
+struct Downloader {
+    state: Mutex<State>,
+    listener: Mutex<Option<Listener>>,
+    bandwidth: Mutex<Bandwidth>,
+}
+
+struct Bandwidth {
+    policy: BandwidthPolicy,
+    network: NetworkType,
+    /// When the data transferred so far is paid for at the cap.
+    next: Option<Instant>,
+}
+
+impl Bandwidth {
+    fn background_paused(&self) -> bool {
+        match self.network {
+            NetworkType::Unmetered => false,
+            NetworkType::Metered => self.policy.pause_background_when_metered,
+            NetworkType::Offline => true,
+        }
+    }
+
+    /// The cap in bytes per second, if any.
+    fn cap(&self) -> Option<f64> {
+        let metered = match self.network {
+            NetworkType::Metered => self.policy.metered_max_kbps,
+            _ => 0,
+        };
+        let kbps = [self.policy.max_kbps, metered]
+            .into_iter()
+            .filter(|&kbps| kbps > 0)
+            .min()?;
+        Some(kbps as f64 * 1024.0)
+    }
+
+    /// Account for `bytes` received at `now` and return how long to wait
+    /// before receiving more.
+    fn reserve(&mut self, bytes: usize, now: Instant) -> Duration {
+        let Some(rate) = self.cap() else {
+            self.next = None;
+            return Duration::ZERO;
+        };
+        let earliest = now.checked_sub(BURST).unwrap_or(now);
+        let start = self.next.map_or(earliest, |next| next.max(earliest));
+        let next = start + Duration::from_secs_f64(bytes as f64 / rate);
+        self.next = Some(next);
+        next.saturating_duration_since(now)
+    }
+}
+
+struct State {
+    config: DownloaderConfig,
+    next_id: u32,
+    queue: VecDeque<Task>,
+    running: HashMap<u32, Task>,
+    /// Running tasks waiting to retry.
+    retrying: HashMap<u32, DownloadState>,
+    /// Cancelled tasks whose thread has not returned yet. Their destination
+    /// stays reserved until it does.
+    cancelling: HashMap<u32, Task>,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Priority {
+    Normal,
+    Low,
+}
+
+#[derive(Clone)]
+struct Task {
+    id: u32,
+    request: DownloadRequest,
+    host: String,
+    priority: Priority,
+    cancelled: Arc<AtomicBool>,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+pub(crate) struct Progress {
+    pub(crate) downloaded: u64,
+    pub(crate) total: Option<u64>,
+    pub(crate) attempt: u32,
+}
+
+pub(crate) enum Failure {
+    Retry(String),
+    Fatal(String),
+    Cancelled,
+    /// A background task stopped by the network policy.
+    Paused,
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+impl Downloader {
+    fn new(config: DownloaderConfig) -> Result<Self> {
+        Ok(Self {
+            state: Mutex::new(State {
+                config: validate(config)?,
+                next_id: 1,
+                queue: VecDeque::new(),
+                running: HashMap::new(),
+                retrying: HashMap::new(),
+                cancelling: HashMap::new(),
+            }),
+            listener: Mutex::new(None),
+            bandwidth: Mutex::new(Bandwidth {
+                policy: default_bandwidth_policy(),
+                network: NetworkType::Unmetered,
+                next: None,
+            }),
+        })
+    }
+
+    fn set_bandwidth(self: &Arc<Self>, update: impl FnOnce(&mut Bandwidth)) {
+        update(&mut lock(&self.bandwidth));
+        // Background tasks may be allowed to start again.
+        self.schedule();
+    }
+
+    fn background_paused(&self) -> bool {
+        lock(&self.bandwidth).background_paused()
+    }
+
+    /// Wait until `bytes` more fit under the cap, or fail if the task is
+    /// cancelled or, for background tasks, paused.
+    fn pace(
+        &self,
+        bytes: usize,
+        priority: Priority,
+        cancelled: &AtomicBool,
+    ) -> Result<(), Failure> {
+        let now = Instant::now();
+        let deadline = now + lock(&self.bandwidth).reserve(bytes, now);
+        self.wait_until(deadline, priority, cancelled)
+    }
+
+    fn wait_until(
+        &self,
+        deadline: Instant,
+        priority: Priority,
+        cancelled: &AtomicBool,
+    ) -> Result<(), Failure> {
+        loop {
+            if cancelled.load(Ordering::Relaxed) {
+                return Err(Failure::Cancelled);
+            }
+            if priority == Priority::Low && self.background_paused() {
+                return Err(Failure::Paused);
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Ok(());
+            }
+            thread::sleep(Duration::from_millis(50).min(deadline - now));
+        }
+    }
+
+    fn configure(self: &Arc<Self>, config: DownloaderConfig) -> Result<()> {
+        lock(&self.state).config = validate(config)?;
+        // Raised limits may let queued tasks start.
+        self.schedule();
+        Ok(())
+    }
+
+    fn listen(&self, listener: Listener) {
+        *lock(&self.listener) = Some(listener);
+    }
+
+    fn emit(&self, task: &Task, state: DownloadState, progress: &Progress, error: Option<String>) {
+        // A cancelled task's thread may still be winding down.
+        if task.cancelled.load(Ordering::Relaxed) && state != DownloadState::Cancelled {
+            return;
+        }
+        if let Some(listener) = lock(&self.listener).as_ref() {
+            listener(DownloadEvent {
+                task_id: task.id,
+                state,
+                downloaded: progress.downloaded,
+                total: progress.total,
+                attempt: progress.attempt,
+                error,
+            });
+        }
+    }
+
+    fn enqueue(self: &Arc<Self>, request: DownloadRequest) -> Result<u32> {
+        self.enqueue_with(request, Priority::Normal)
+    }
+
+    fn enqueue_with(self: &Arc<Self>, request: DownloadRequest, priority: Priority) -> Result<u32> {
+        let url =
+            Url::parse(&request.url).map_err(|e| anyhow!("Invalid URL {}: {e}", request.url))?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(anyhow!("Unsupported URL scheme: {}", url.scheme()));
+        }
+        let host = url
+            .host_str()
+            .ok_or_else(|| anyhow!("URL has no host: {}", request.url))?
+            .to_string();
+
+        let task = {
+            let mut state = lock(&self.state);
+            let destination = Path::new(&request.destination);
+            if state
+                .queue
+                .iter()
+                .chain(state.running.values())
+                .chain(state.cancelling.values())
+                .any(|task| Path::new(&task.request.destination) == destination)
+            {
+                return Err(anyhow!(
+                    "{} is already being downloaded",
+                    request.destination
+                ));
+            }
+            let task = Task {
+                id: state.next_id,
+                request,
+                host,
+                priority,
+                cancelled: Arc::new(AtomicBool::new(false)),
+            };
+            state.next_id += 1;
+            state.queue.push_back(task.clone());
+            task
+        };
+        self.emit(&task, DownloadState::Queued, &Progress::default(), None);
+        self.schedule();
+        Ok(task.id)
+    }
+
+    fn cancel(self: &Arc<Self>, task_id: u32) -> bool {
+        let mut state = lock(&self.state);
+        if let Some(task) = state.running.remove(&task_id) {
+            // The worker stops at its next read and deletes the partial
+            // data; until then it no longer counts against the limits.
+            task.cancelled.store(true, Ordering::Relaxed);
+            state.retrying.remove(&task_id);
+            state.cancelling.insert(task_id, task.clone());
+            drop(state);
+            self.emit(&task, DownloadState::Cancelled, &Progress::default(), None);
+            self.schedule();
+            return true;
+        }
+        let Some(index) = state.queue.iter().position(|task| task.id == task_id) else {
+            return false;
+        };
+        let task = state.queue.remove(index).unwrap();
+        drop(state);
+        self.emit(&task, DownloadState::Cancelled, &Progress::default(), None);
+        true
+    }
+
+    /// Start queued tasks, normal priority first and oldest first within a
+    /// priority, while the limits and the network policy allow.
+    fn schedule(self: &Arc<Self>) {
+        let background_paused = self.background_paused();
+        let mut state = lock(&self.state);
+        for priority in [Priority::Normal, Priority::Low] {
+            if priority == Priority::Low && background_paused {
+                break;
+            }
+            let mut index = 0;
+            while index < state.queue.len()
+                && state.running.len() < state.config.max_concurrent as usize
+            {
+                let task = &state.queue[index];
+                let per_host = state
+                    .running
+                    .values()
+                    .filter(|t| t.host == task.host)
+                    .count();
+                if task.priority != priority || per_host >= state.config.max_per_host as usize {
+                    index += 1;
+                    continue;
+                }
+                let task = state.queue.remove(index).unwrap();
+                state.running.insert(task.id, task.clone());
+                let config = state.config.clone();
+                let downloader = Arc::clone(self);
+                thread::spawn(move || downloader.run(task, config));
+            }
+        }
+    }
+
+    fn run(self: Arc<Self>, task: Task, config: DownloaderConfig) {
+        let mut progress = Progress::default();
+        let timeout = Duration::from_secs(config.timeout_secs.max(1) as u64);
+        let outcome = network::agent(
+            &task.request.url,
+            timeout,
+            timeout,
+            Some(&config.user_agent),
+        )
+        .map_err(|e| Failure::Fatal(e.to_string()))
+        .and_then(|agent| self.download(&task, &agent, &config, &mut progress));
+        let abandoned = {
+            let mut state = lock(&self.state);
+            state.running.remove(&task.id);
+            state.retrying.remove(&task.id);
+            state.cancelling.remove(&task.id).is_some()
+        };
+        let destination = Path::new(&task.request.destination);
+        if abandoned {
+            // Cancelled while running; the event was sent by `cancel`.
+            if outcome.is_err() {
+                let _ = fs::remove_file(part_path(destination));
+                let _ = fs::remove_file(tag_path(destination));
+            }
+            self.schedule();
+            return;
+        }
+        match outcome {
+            Ok(()) => self.emit(&task, DownloadState::Completed, &progress, None),
+            Err(Failure::Cancelled) => {
+                let _ = fs::remove_file(part_path(destination));
+                let _ = fs::remove_file(tag_path(destination));
+                self.emit(&task, DownloadState::Cancelled, &progress, None);
+            }
+            Err(Failure::Paused) => {
+                // Its partial data is resumed when it starts again.
+                lock(&self.state).queue.push_front(task.clone());
+                self.emit(&task, DownloadState::Queued, &progress, None);
+            }
+            Err(Failure::Retry(error) | Failure::Fatal(error)) => {
+                logging::warn(
+                    "downloader",
+                    format!("Download of {} failed: {error}", task.request.url),
+                );
+                self.emit(&task, DownloadState::Failed, &progress, Some(error))
+            }
+        }
+        self.schedule();
+    }
+
+    fn download(
+        &self,
+        task: &Task,
+        agent: &ureq::Agent,
+        config: &DownloaderConfig,
+        progress: &mut Progress,
+    ) -> Result<(), Failure> {
+        loop {
+            progress.attempt += 1;
+            lock(&self.state).retrying.remove(&task.id);
+            self.emit(task, DownloadState::Running, progress, None);
+            let report =
+                |progress: &Progress| self.emit(task, DownloadState::Running, progress, None);
+            let pace = |bytes| self.pace(bytes, task.priority, &task.cancelled);
+            let error = match fetch(
+                agent,
+                &task.request,
+                &task.cancelled,
+                progress,
+                pace,
+                report,
+            ) {
+                Err(Failure::Retry(error)) if progress.attempt <= config.max_retries => error,
+                result => return result,
+            };
+            lock(&self.state)
+                .retrying
+                .insert(task.id, DownloadState::Retrying);
+            self.emit(task, DownloadState::Retrying, progress, Some(error));
+            let deadline = Instant::now() + backoff(config.initial_backoff_ms, progress.attempt);
+            self.wait_until(deadline, task.priority, &task.cancelled)?;
+        }
+    }
+}
+
+/// Make one attempt at downloading `request`, resuming from a partial file
+/// left by an earlier attempt. `pace` is called with the size of each read
+/// and waits for the bandwidth cap, and `report` is called periodically
+/// while data arrives.
+pub(crate) fn fetch(
+    agent: &ureq::Agent,
+    request: &DownloadRequest,
+    cancelled: &AtomicBool,
+    progress: &mut Progress,
+    mut pace: impl FnMut(usize) -> Result<(), Failure>,
+    mut report: impl FnMut(&Progress),
+) -> Result<(), Failure> {
+    pace(0)?;
+    let destination = Path::new(&request.destination);
+    let (part, tag) = (part_path(destination), tag_path(destination));
+    if let Some(parent) = destination.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| Failure::Fatal(format!("Failed to create {}: {e}", parent.display())))?;
+    }
+
+    let mut resume_from = fs::metadata(&part).map_or(0, |m| m.len());
+    if let Some(checksum) = &request.checksum {
+        // Data from an earlier run is only kept up to its last good part.
+        let good = verified_prefix(&part, checksum, resume_from, false);
+        if good < resume_from {
+            truncate(&part, good)?;
+            resume_from = good;
+        }
+    }
+    let mut call = agent.get(&request.url);
+    for header in &request.headers {
+        call = call.set(&header.name, &header.value);
+    }
+    let cache = http_cache::Lookup::new(&call);
+    if resume_from > 0 {
+        call = call.set("Range", &format!("bytes={resume_from}-"));
+        // Without a validator the server cannot tell us the file changed.
+        if let Ok(validator) = fs::read_to_string(&tag) {
+            call = call.set("If-Range", &validator);
+        }
+    } else if let Some(stored) = cache.stored.as_ref().filter(|stored| stored.is_fresh()) {
+        return write_cached(&cache, destination, &stored.body, request, progress);
+    } else {
+        call = cache.conditional(call);
+    }
+    let response = match call.call() {
+        Ok(response) => response,
+        Err(ureq::Error::Status(416, _)) => {
+            let _ = fs::remove_file(&part);
+            let _ = fs::remove_file(&tag);
+            return Err(Failure::Retry(
+                "Partial download does not match the file".to_string(),
+            ));
+        }
+        Err(ureq::Error::Status(code, _)) if matches!(code, 408 | 429 | 500..=599) => {
+            return Err(Failure::Retry(format!("HTTP {code}")));
+        }
+        Err(ureq::Error::Status(code, _)) => {
+            return Err(Failure::Fatal(format!("HTTP {code}")));
+        }
+        Err(e) => return Err(Failure::Retry(e.to_string())),
+    };
+
+    if response.status() == 304 {
+        if let Some(stored) = cache.revalidated(&response) {
+            return write_cached(&cache, destination, &stored.body, request, progress);
+        }
+    }
+
+    let range = response.header("Content-Range").and_then(content_range);
+    let resumed = response.status() == 206 && range.is_some_and(|(start, _)| start == resume_from);
+    let written = if resumed {
+        OpenOptions::new().append(true).open(&part)
+    } else {
+        File::create(&part)
+    };
+    let mut file =
+        written.map_err(|e| Failure::Fatal(format!("Failed to open {}: {e}", part.display())))?;
+    progress.downloaded = if resumed { resume_from } else { 0 };
+    progress.total = match range {
+        Some((_, total)) if resumed => total,
+        _ => response
+            .header("Content-Length")
+            .and_then(|len| len.parse().ok()),
+    };
+
+    // A digest of the whole file, unless the request brings its own.
+    let server_digest = request
+        .checksum
+        .is_none()
+        .then(|| server_digest(&response, resumed))
+        .flatten();
+
+    // Weak ETags cannot be used with If-Range.
+    let validator = response
+        .header("ETag")
+        .filter(|etag| !etag.starts_with("W/"))
+        .or_else(|| response.header("Last-Modified"));
+    match validator {
+        Some(validator) => {
+            let _ = fs::write(&tag, validator);
+        }
+        None => {
+            let _ = fs::remove_file(&tag);
+        }
+    }
+
+    // Resumed downloads are not cached, as only the tail passes through.
+    let cache_meta = http_cache::policy(&response).filter(|_| !resumed);
+    let mut reader = response.into_reader();
+    let mut buffer = vec![0; BUFFER_SIZE];
+    let mut reported = Instant::now();
+    loop {
+        if cancelled.load(Ordering::Relaxed) {
+            return Err(Failure::Cancelled);
+        }
+        let read = reader
+            .read(&mut buffer)
+            .map_err(|e| Failure::Retry(e.to_string()))?;
+        if read == 0 {
+            break;
+        }
+        file.write_all(&buffer[..read])
+            .map_err(|e| Failure::Fatal(format!("Failed to write {}: {e}", part.display())))?;
+        progress.downloaded += read as u64;
+        if reported.elapsed() >= PROGRESS_INTERVAL {
+            report(progress);
+            reported = Instant::now();
+        }
+        pace(read)?;
+    }
+    if progress
+        .total
+        .is_some_and(|total| progress.downloaded < total)
+    {
+        return Err(Failure::Retry("Connection closed early".to_string()));
+    }
+    if cancelled.load(Ordering::Relaxed) {
+        return Err(Failure::Cancelled);
+    }
+
+    let expected = request.checksum.clone().or(server_digest);
+    if let Some(checksum) = &expected {
+        verify_part(&part, &tag, checksum, progress.downloaded)?;
+    }
+    if let Some(meta) = cache_meta {
+        if cache.accepts(progress.downloaded) {
+            if let Ok(body) = fs::read(&part) {
+                cache.store(meta, &body);
+            }
+        }
+    }
+    file.sync_all()
+        .and_then(|()| fs::rename(&part, destination))
+        .map_err(|e| Failure::Fatal(format!("Failed to write {}: {e}", destination.display())))?;
+    let _ = fs::remove_file(&tag);
+    Ok(())
+}
+
+/// Write a body served by the HTTP cache to `destination`. A body that
+/// fails the request's checksum is dropped from the cache and retried.
+fn write_cached(
+    cache: &http_cache::Lookup,
+    destination: &Path,
+    body: &[u8],
+    request: &DownloadRequest,
+    progress: &mut Progress,
+) -> Result<(), Failure> {
+    if let Some(checksum) = &request.checksum {
+        if !digest_matches(checksum, &mut &body[..]) {
+            cache.discard();
+            return Err(Failure::Retry(
+                "Cached copy does not match the checksum".to_string(),
+            ));
+        }
+    }
+    font_converter::write_atomically(destination, body)
+        .map_err(|e| Failure::Fatal(e.to_string()))?;
+    let _ = fs::remove_file(tag_path(destination));
+    progress.downloaded = body.len() as u64;
+    progress.total = Some(progress.downloaded);
+    Ok(())
+}
+
+/// Check a complete partial file against `checksum`. On a mismatch the
+/// file is cut back to its last good part, or removed if that cannot be
+/// told, so the retry fetches only what is needed.
+fn verify_part(
+    part: &Path,
+    tag: &Path,
+    checksum: &DownloadChecksum,
+    len: u64,
+) -> Result<(), Failure> {
+    let file = File::open(part)
+        .map_err(|e| Failure::Fatal(format!("Failed to read {}: {e}", part.display())))?;
+    if digest_matches(checksum, &mut BufReader::new(file)) {
+        return Ok(());
+    }
+    let good = verified_prefix(part, checksum, len, true);
+    if good < len && !checksum.part_digests.is_empty() {
+        truncate(part, good)?;
+        return Err(Failure::Retry(format!(
+            "Checksum mismatch after byte {good}"
+        )));
+    }
+    let _ = fs::remove_file(part);
+    let _ = fs::remove_file(tag);
+    Err(Failure::Retry("Checksum mismatch".to_string()))
+}
+
+/// How many bytes at the start of the first `len` bytes of `part` are
+/// vouched for by `checksum`'s part digests. A short last part counts as
+/// still arriving unless the file is `complete`. Without part digests the
+/// whole length is kept.
+fn verified_prefix(part: &Path, checksum: &DownloadChecksum, len: u64, complete: bool) -> u64 {
+    if checksum.part_digests.is_empty() || checksum.part_size == 0 {
+        return len;
+    }
+    let Ok(file) = File::open(part) else {
+        return 0;
+    };
+    let mut file = BufReader::new(file);
+    let mut good = 0;
+    for expected in &checksum.part_digests {
+        if good >= len {
+            return len;
+        }
+        let size = checksum.part_size.min(len - good);
+        let matches = digest_of(checksum.algorithm, &mut (&mut file).take(size))
+            .is_some_and(|digest| digest.eq_ignore_ascii_case(expected.trim()));
+        if matches {
+            good += size;
+        } else if size < checksum.part_size && !complete {
+            return len;
+        } else {
+            return good;
+        }
+    }
+    // Anything past the listed parts cannot belong to the file.
+    good
+}
+
+fn digest_matches(checksum: &DownloadChecksum, data: &mut impl Read) -> bool {
+    digest_of(checksum.algorithm, data)
+        .is_some_and(|digest| digest.eq_ignore_ascii_case(checksum.digest.trim()))
+}
+
+fn digest_of(algorithm: ChecksumAlgorithm, data: &mut impl Read) -> Option<String> {
+    let mut buffer = vec![0; BUFFER_SIZE];
+    match algorithm {
+        ChecksumAlgorithm::Blake3 => {
+            let mut hasher = blake3::Hasher::new();
+            loop {
+                match data.read(&mut buffer).ok()? {
+                    0 => return Some(hasher.finalize().to_hex().to_string()),
+                    read => hasher.update(&buffer[..read]),
+                };
+            }
+        }
+        ChecksumAlgorithm::Sha256 => {
+            let mut hasher = Sha256::new();
+            loop {
+                match data.read(&mut buffer).ok()? {
+                    0 => break,
+                    read => hasher.update(&buffer[..read]),
+                }
+            }
+            Some(
+                hasher
+                    .finalize()
+                    .iter()
+                    .map(|b| format!("{b:02x}"))
+                    .collect(),
+            )
+        }
+    }
+}
+
+/// A SHA-256 digest of the whole file from `Repr-Digest`, or from
+/// `Content-Digest` or the older `Digest` when the body is the whole file.
+fn server_digest(response: &ureq::Response, resumed: bool) -> Option<DownloadChecksum> {
+    let mut headers = vec![("Repr-Digest", response.header("Repr-Digest"))];
+    if !resumed {
+        headers.push(("Content-Digest", response.header("Content-Digest")));
+        headers.push(("Digest", response.header("Digest")));
+    }
+    let digest = headers.into_iter().find_map(|(name, value)| {
+        value?.split(',').find_map(|item| {
+            let (algorithm, value) = item.trim().split_once('=')?;
+            if !algorithm.trim().eq_ignore_ascii_case("sha-256") {
+                return None;
+            }
+            // Structured fields wrap the base64 in colons; `Digest` does not.
+            let value = value.trim();
+            let value = match name {
+                "Digest" => value,
+                _ => value.strip_prefix(':')?.strip_suffix(':')?,
+            };
+            let bytes = base64::engine::general_purpose::STANDARD
+                .decode(value)
+                .ok()?;
+            (bytes.len() == 32).then(|| bytes.iter().map(|b| format!("{b:02x}")).collect())
+        })
+    })?;
+    Some(DownloadChecksum {
+        algorithm: ChecksumAlgorithm::Sha256,
+        digest,
+        part_size: 0,
+        part_digests: Vec::new(),
+    })
+}
+
+fn truncate(part: &Path, len: u64) -> Result<(), Failure> {
+    OpenOptions::new()
+        .write(true)
+        .open(part)
+        .and_then(|file| file.set_len(len))
+        .map_err(|e| Failure::Fatal(format!("Failed to write {}: {e}", part.display())))
+}
+
+fn validate(config: DownloaderConfig) -> Result<DownloaderConfig> {
+    if config.max_concurrent == 0 || config.max_per_host == 0 {
+        return Err(anyhow!("Download concurrency limits must be positive"));
+    }
+    Ok(config)
+}
+
+fn part_path(destination: &Path) -> PathBuf {
+    let mut name = destination.as_os_str().to_owned();
+    name.push(".part");
+    PathBuf::from(name)
+}
+
+/// The ETag or Last-Modified value the partial download was fetched with.
+fn tag_path(destination: &Path) -> PathBuf {
+    let mut name = destination.as_os_str().to_owned();
+    name.push(".part.tag");
+    PathBuf::from(name)
+}
+
+/// Delay before retry number `attempt`, counting from 1.
+fn backoff(initial_ms: u32, attempt: u32) -> Duration {
+    let factor = 1u32 << attempt.saturating_sub(1).min(16);
+    Duration::from_millis(initial_ms as u64)
+        .saturating_mul(factor)
+        .min(MAX_BACKOFF)
+}
+
+/// Parse `bytes <start>-<end>/<total>` into the start offset and, unless it
+/// is `*`, the total size.
+fn content_range(value: &str) -> Option<(u64, Option<u64>)> {
+    let range = value.trim().strip_prefix("bytes ")?;
+    let (span, total) = range.split_once('/')?;
+    let (start, _) = span.split_once('-')?;
+    Some((start.trim().parse().ok()?, total.trim().parse().ok()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_dirs::temp_dir;
+    use std::io::BufRead;
+    use std::net::TcpListener;
+    use std::sync::atomic::AtomicU32;
+    use std::sync::mpsc;
+
+    const BODY_LEN: usize = 1000;
+
+    fn body() -> Vec<u8> {
+        (0..BODY_LEN).map(|i| (i % 251) as u8).collect()
+    }
+
+    /// Serve `respond(request_number, headers)` on a local port.
+    fn serve(respond: impl Fn(u32, &[String]) -> Vec<u8> + Send + 'static) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let count = AtomicU32::new(0);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = std::io::BufReader::new(stream.try_clone().unwrap());
+                let mut headers = Vec::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    headers.push(line.trim().to_string());
+                }
+                let response = respond(count.fetch_add(1, Ordering::Relaxed), &headers);
+                let _ = stream.write_all(&response);
+            }
+        });
+        format!("http://{address}/file")
+    }
+
+    fn response(status: &str, headers: &[&str], body: &[u8]) -> Vec<u8> {
+        let mut out = format!("HTTP/1.1 {status}\r\nConnection: close\r\n");
+        out.push_str(&format!("Content-Length: {}\r\n", body.len()));
+        for header in headers {
+            out.push_str(&format!("{header}\r\n"));
+        }
+        out.push_str("\r\n");
+        let mut out = out.into_bytes();
+        out.extend_from_slice(body);
+        out
+    }
+
+    /// Run one download to completion and return its events.
+    fn download(
+        url: String,
+        destination: &Path,
+        checksum: Option<DownloadChecksum>,
+    ) -> Vec<DownloadEvent> {
+        let config = DownloaderConfig {
+            initial_backoff_ms: 1,
+            ..default_downloader_config()
+        };
+        let downloader = Arc::new(Downloader::new(config).unwrap());
+        let (sender, receiver) = mpsc::channel();
+        let sender = Mutex::new(sender);
+        downloader.listen(Box::new(move |event| {
+            let _ = lock(&sender).send(event);
+        }));
+        downloader
+            .enqueue(DownloadRequest {
+                url,
+                destination: destination.to_string_lossy().into_owned(),
+                headers: vec![],
+                checksum,
+            })
+            .unwrap();
+
+        let mut events = Vec::new();
+        loop {
+            let event = receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+            let done = matches!(
+                event.state,
+                DownloadState::Completed | DownloadState::Failed | DownloadState::Cancelled
+            );
+            events.push(event);
+            if done {
+                return events;
+            }
+        }
+    }
+
+    #[test]
+    fn test_resumes_partial_download() {
+        let url = serve(|_, headers| {
+            let has = |header: &str| headers.iter().any(|h| h.eq_ignore_ascii_case(header));
+            assert!(has("Range: bytes=400-"));
+            assert!(has("If-Range: \"v1\""));
+            response(
+                "206 Partial Content",
+                &["Content-Range: bytes 400-999/1000", "ETag: \"v1\""],
+                &body()[400..],
+            )
+        });
+        let dir = temp_dir("resume");
+        let destination = dir.join("nested").join("chapter.txt");
+        fs::create_dir_all(destination.parent().unwrap()).unwrap();
+        fs::write(part_path(&destination), &body()[..400]).unwrap();
+        fs::write(tag_path(&destination), "\"v1\"").unwrap();
+
+        let events = download(url, &destination, None);
+        let last = events.last().unwrap();
+        assert_eq!(last.state, DownloadState::Completed);
+        assert_eq!((last.downloaded, last.total), (1000, Some(1000)));
+        assert_eq!(fs::read(&destination).unwrap(), body());
+        assert!(!part_path(&destination).exists());
+        assert!(!tag_path(&destination).exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_retries_server_errors() {
+        let url = serve(|n, _| match n {
+            0 => response("503 Service Unavailable", &[], b""),
+            _ => response("200 OK", &[], &body()),
+        });
+        let dir = temp_dir("retry");
+        let destination = dir.join("cover.jpg");
+        let states: Vec<(DownloadState, u32)> = download(url, &destination, None)
+            .iter()
+            .map(|e| (e.state, e.attempt))
+            .collect();
+        assert_eq!(
+            states,
+            vec![
+                (DownloadState::Queued, 0),
+                (DownloadState::Running, 1),
+                (DownloadState::Retrying, 1),
+                (DownloadState::Running, 2),
+                (DownloadState::Completed, 2),
+            ]
+        );
+        assert_eq!(fs::read(&destination).unwrap(), body());
+
+        let url = serve(|_, _| response("404 Not Found", &[], b""));
+        let events = download(url, &dir.join("missing.jpg"), None);
+        let last = events.last().unwrap();
+        assert_eq!((last.state, last.attempt), (DownloadState::Failed, 1));
+        assert_eq!(last.error.as_deref(), Some("HTTP 404"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    fn blake3_hex(data: &[u8]) -> String {
+        blake3::hash(data).to_hex().to_string()
+    }
+
+    #[test]
+    fn test_refetches_from_first_bad_part() {
+        let mut corrupt = body();
+        corrupt[600] ^= 0xFF;
+        let url = serve(move |n, headers| match n {
+            0 => response("200 OK", &["ETag: \"v1\""], &corrupt),
+            _ => {
+                assert!(headers.iter().any(|h| h == "Range: bytes=500-"));
+                response(
+                    "206 Partial Content",
+                    &["Content-Range: bytes 500-999/1000", "ETag: \"v1\""],
+                    &body()[500..],
+                )
+            }
+        });
+        let checksum = DownloadChecksum {
+            algorithm: ChecksumAlgorithm::Blake3,
+            digest: blake3_hex(&body()),
+            part_size: 250,
+            part_digests: body().chunks(250).map(blake3_hex).collect(),
+        };
+        let dir = temp_dir("checksum_parts");
+        let destination = dir.join("font.woff2");
+        let events = download(url, &destination, Some(checksum));
+        let retry = events
+            .iter()
+            .find(|e| e.state == DownloadState::Retrying)
+            .unwrap();
+        assert_eq!(
+            retry.error.as_deref(),
+            Some("Checksum mismatch after byte 500")
+        );
+        assert_eq!(events.last().unwrap().state, DownloadState::Completed);
+        assert_eq!(fs::read(&destination).unwrap(), body());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_checks_server_digest() {
+        let digest = base64::engine::general_purpose::STANDARD.encode(Sha256::digest(body()));
+        let url = serve(move |n, _| {
+            let mut data = body();
+            if n == 0 {
+                data[10] ^= 0xFF;
+            }
+            let header = format!("Repr-Digest: sha-512=:AAAA:, sha-256=:{digest}:");
+            response("200 OK", &[&header], &data)
+        });
+        let dir = temp_dir("checksum_server");
+        let destination = dir.join("book.epub");
+        let states: Vec<DownloadState> = download(url, &destination, None)
+            .iter()
+            .map(|e| e.state)
+            .collect();
+        assert!(states.contains(&DownloadState::Retrying));
+        assert_eq!(states.last(), Some(&DownloadState::Completed));
+        assert_eq!(fs::read(&destination).unwrap(), body());
+        assert!(!part_path(&destination).exists());
+
+        // A partial file is kept up to its last good whole part.
+        let part = dir.join("partial");
+        let mut data = body()[..700].to_vec();
+        let checksum = DownloadChecksum {
+            algorithm: ChecksumAlgorithm::Sha256,
+            digest: String::new(),
+            part_size: 300,
+            part_digests: body()
+                .chunks(300)
+                .map(|chunk| {
+                    Sha256::digest(chunk)
+                        .iter()
+                        .map(|b| format!("{b:02x}"))
+                        .collect()
+                })
+                .collect(),
+        };
+        fs::write(&part, &data).unwrap();
+        assert_eq!(verified_prefix(&part, &checksum, 700, false), 700);
+        assert_eq!(verified_prefix(&part, &checksum, 700, true), 600);
+        data[350] ^= 0xFF;
+        fs::write(&part, &data).unwrap();
+        assert_eq!(verified_prefix(&part, &checksum, 700, false), 300);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_prefers_normal_priority() {
+        let url = serve(|n, _| {
+            if n == 0 {
+                thread::sleep(Duration::from_millis(300));
+            }
+            response("200 OK", &[], b"chapter")
+        });
+        let config = DownloaderConfig {
+            max_concurrent: 1,
+            ..default_downloader_config()
+        };
+        let downloader = Arc::new(Downloader::new(config).unwrap());
+        let (sender, receiver) = mpsc::channel();
+        let sender = Mutex::new(sender);
+        downloader.listen(Box::new(move |event| {
+            let _ = lock(&sender).send(event);
+        }));
+        let dir = temp_dir("priority");
+        let request = |name: &str| DownloadRequest {
+            url: url.clone(),
+            destination: dir.join(name).to_string_lossy().into_owned(),
+            headers: vec![],
+            checksum: None,
+        };
+        let first = downloader
+            .enqueue_with(request("1.txt"), Priority::Low)
+            .unwrap();
+        let low = downloader
+            .enqueue_with(request("2.txt"), Priority::Low)
+            .unwrap();
+        let normal = downloader.enqueue(request("3.txt")).unwrap();
+
+        let (mut started, mut completed) = (Vec::new(), 0);
+        while completed < 3 {
+            let event = receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+            match event.state {
+                DownloadState::Running => started.push(event.task_id),
+                DownloadState::Completed => completed += 1,
+                _ => {}
+            }
+        }
+        assert_eq!(started, vec![first, normal, low]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_cancel_frees_stuck_download() {
+        // The first connection is accepted but never answered.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/file", listener.local_addr().unwrap());
+        thread::spawn(move || {
+            let mut held = Vec::new();
+            for (n, stream) in listener.incoming().enumerate() {
+                let mut stream = stream.unwrap();
+                if n == 0 {
+                    held.push(stream);
+                    continue;
+                }
+                let mut request = [0; 1024];
+                let _ = stream.read(&mut request);
+                let _ = stream.write_all(&response("200 OK", &[], b"chapter"));
+            }
+        });
+        let config = DownloaderConfig {
+            max_concurrent: 1,
+            ..default_downloader_config()
+        };
+        let downloader = Arc::new(Downloader::new(config).unwrap());
+        let (sender, receiver) = mpsc::channel();
+        let sender = Mutex::new(sender);
+        downloader.listen(Box::new(move |event| {
+            let _ = lock(&sender).send(event);
+        }));
+        let dir = temp_dir("stuck");
+        let request = |name: &str| DownloadRequest {
+            url: url.clone(),
+            destination: dir.join(name).to_string_lossy().into_owned(),
+            headers: vec![],
+            checksum: None,
+        };
+        let stuck = downloader.enqueue(request("1.txt")).unwrap();
+        let next = downloader.enqueue(request("2.txt")).unwrap();
+        thread::sleep(Duration::from_millis(200));
+        assert!(downloader.cancel(stuck));
+        // The destination stays reserved until the stuck thread returns.
+        assert!(downloader.enqueue(request("1.txt")).is_err());
+
+        let mut finished = Vec::new();
+        while finished.len() < 2 {
+            let event = receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+            if matches!(
+                event.state,
+                DownloadState::Cancelled | DownloadState::Completed
+            ) {
+                finished.push((event.task_id, event.state));
+            }
+        }
+        assert_eq!(
+            finished,
+            vec![
+                (stuck, DownloadState::Cancelled),
+                (next, DownloadState::Completed)
+            ]
+        );
+        assert_eq!(fs::read(dir.join("2.txt")).unwrap(), b"chapter");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_background_waits_for_unmetered_network() {
+        let url = serve(|_, _| response("200 OK", &[], b"cover"));
+        let downloader = Arc::new(Downloader::new(default_downloader_config()).unwrap());
+        let (sender, receiver) = mpsc::channel();
+        let sender = Mutex::new(sender);
+        downloader.listen(Box::new(move |event| {
+            let _ = lock(&sender).send(event);
+        }));
+        downloader.set_bandwidth(|bandwidth| {
+            bandwidth.policy.pause_background_when_metered = true;
+            bandwidth.network = NetworkType::Metered;
+        });
+        let dir = temp_dir("metered");
+        let request = |name: &str| DownloadRequest {
+            url: url.clone(),
+            destination: dir.join(name).to_string_lossy().into_owned(),
+            headers: vec![],
+            checksum: None,
+        };
+        let cover = downloader
+            .enqueue_with(request("cover.jpg"), Priority::Low)
+            .unwrap();
+        let chapter = downloader.enqueue(request("1.txt")).unwrap();
+
+        let mut completed = Vec::new();
+        while completed.is_empty() {
+            let event = receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+            if event.state == DownloadState::Completed {
+                completed.push(event.task_id);
+            }
+        }
+        thread::sleep(Duration::from_millis(100));
+        assert!(lock(&downloader.state)
+            .queue
+            .iter()
+            .any(|task| task.id == cover));
+
+        downloader.set_bandwidth(|bandwidth| bandwidth.network = NetworkType::Unmetered);
+        while completed.len() < 2 {
+            let event = receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+            if event.state == DownloadState::Completed {
+                completed.push(event.task_id);
+            }
+        }
+        assert_eq!(completed, vec![chapter, cover]);
+        assert_eq!(fs::read(dir.join("cover.jpg")).unwrap(), b"cover");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_bandwidth_caps() {
+        let mut bandwidth = Bandwidth {
+            policy: BandwidthPolicy {
+                max_kbps: 100,
+                metered_max_kbps: 10,
+                pause_background_when_metered: false,
+            },
+            network: NetworkType::Unmetered,
+            next: None,
+        };
+        assert_eq!(bandwidth.cap(), Some(102_400.0));
+        bandwidth.network = NetworkType::Metered;
+        assert_eq!(bandwidth.cap(), Some(10_240.0));
+        assert!(!bandwidth.background_paused());
+
+        // A quarter second of data passes at once, then reads are spaced.
+        let now = Instant::now() + Duration::from_secs(1);
+        assert_eq!(bandwidth.reserve(2560, now), Duration::ZERO);
+        assert_eq!(bandwidth.reserve(10_240, now), Duration::from_secs(1));
+        assert_eq!(
+            bandwidth.reserve(0, now + Duration::from_secs(1)),
+            Duration::ZERO
+        );
+
+        bandwidth.policy = default_bandwidth_policy();
+        assert_eq!(bandwidth.cap(), None);
+        assert_eq!(bandwidth.reserve(1 << 20, now), Duration::ZERO);
+        bandwidth.network = NetworkType::Offline;
+        assert!(bandwidth.background_paused());
+    }
+
+    #[test]
+    fn test_helpers() {
+        assert_eq!(content_range("bytes 400-999/1000"), Some((400, Some(1000))));
+        assert_eq!(content_range("bytes 0-9/*"), Some((0, None)));
+        assert_eq!(content_range("items 0-9/10"), None);
+        assert_eq!(backoff(500, 1), Duration::from_millis(500));
+        assert_eq!(backoff(500, 3), Duration::from_millis(2000));
+        assert_eq!(backoff(500, 40), MAX_BACKOFF);
+    }
+}
@@ -0,0 +1,317 @@
+//! DRM detection for imported books.
+//!
+//! The format is sniffed from the file's first bytes, then the usual
+//! markers of each scheme are checked:
+//!
+//! - EPUB: Adobe ADEPT and Barnes & Noble keep a `META-INF/rights.xml`,
+//!   Readium LCP a `META-INF/license.lcpl` and Apple FairPlay a
+//!   `META-INF/sinf.xml`. Otherwise, an entry that `META-INF/encryption.xml`
+//!   encrypts with anything but font obfuscation means unknown DRM;
+//!   obfuscated fonts alone are not DRM.
+//! - MOBI/AZW: a non-zero encryption type in the first header record.
+//! - KFX: the `DRMION` container; Topaz: the `TPZ` magic.
+//! - PDF: an `/Encrypt` dictionary, with Adobe's `EBX_HANDLER` filter
+//!   reported as ADEPT and the standard filter as password protection.
+//!
+//! The importers run the same checks, so a protected book fails with
+//! "DRM-protected book" instead of a parse error.
+
+use std::fs::File;
+use std::io::{Read, Seek};
+
+use anyhow::{anyhow, Result};
+use quick_xml::events::Event;
+use regex::bytes::Regex;
+use zip::ZipArchive;
+
+use crate::api::epub;
+use crate::api::error::ApiError;
+use crate::xhtml;
+
+const RIGHTS_PATH: &str = "META-INF/rights.xml";
+const LCP_LICENSE_PATH: &str = "META-INF/license.lcpl";
+const FAIRPLAY_PATH: &str = "META-INF/sinf.xml";
+const KFX_DRM_MAGIC: &[u8] = b"\xeaDRMION\xee";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookFormat {
+    Epub,
+    Mobi,
+    Kfx,
+    Topaz,
+    Pdf,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrmScheme {
+    AdobeAdept,
+    BarnesAndNoble,
+    ReadiumLcp,
+    AppleFairPlay,
+    /// Mobipocket/Kindle encryption in a MOBI, AZW or AZW3 file.
+    Kindle,
+    KindleKfx,
+    KindleTopaz,
+    /// PDF standard security; such files open without a password when only
+    /// an owner password is set.
+    PdfPassword,
+    Unknown,
+}
+
+#[derive(Debug, Clone)]
+pub struct DrmStatus {
+    pub format: BookFormat,
+    /// `None` if the book is not protected.
+    pub scheme: Option<DrmScheme>,
+}
+
+/// Check whether a book file is DRM-protected.
+///
+/// # Arguments
+/// * `path` - Path to the book
+#[flutter_rust_bridge::frb]
+pub fn detect_drm(path: String) -> Result<DrmStatus, ApiError> {
+    let mut file = File::open(&path).map_err(|e| anyhow!("Failed to open {path}: {e}"))?;
+    let mut head = [0u8; 68];
+    let len = read_up_to(&mut file, &mut head)?;
+    let head = &head[..len];
+
+    let format = sniff(head);
+    let scheme = match format {
+        BookFormat::Epub => {
+            file.rewind()
+                .map_err(|e| anyhow!("Failed to read {path}: {e}"))?;
+            epub_scheme(&mut epub::open_archive(file)?)?
+        }
+        BookFormat::Mobi | BookFormat::Pdf => {
+            let data = std::fs::read(&path).map_err(|e| anyhow!("Failed to read {path}: {e}"))?;
+            if format == BookFormat::Mobi {
+                mobi_scheme(&data)
+            } else {
+                pdf_scheme(&data)
+            }
+        }
+        BookFormat::Kfx => Some(DrmScheme::KindleKfx),
+        BookFormat::Topaz => Some(DrmScheme::KindleTopaz),
+        BookFormat::Unknown => None,
+    };
+    Ok(DrmStatus { format, scheme })
+}
+
+/// The error importers return for a protected book.
+pub(crate) fn protected_error(scheme: DrmScheme) -> anyhow::Error {
+    anyhow!("DRM-protected book ({})", scheme_name(scheme))
+}
+
+/// The DRM scheme of an EPUB archive, if any.
+pub(crate) fn epub_scheme<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+) -> Result<Option<DrmScheme>> {
+    if epub::entry_name(archive, LCP_LICENSE_PATH).is_some() {
+        return Ok(Some(DrmScheme::ReadiumLcp));
+    }
+    if epub::entry_name(archive, FAIRPLAY_PATH).is_some() {
+        return Ok(Some(DrmScheme::AppleFairPlay));
+    }
+    if epub::entry_name(archive, RIGHTS_PATH).is_some() {
+        let rights =
+            String::from_utf8_lossy(&epub::read_entry(archive, RIGHTS_PATH)?).to_lowercase();
+        // B&N licenses are ADEPT rights files issued by their own operator.
+        let scheme = if rights.contains("barnesandnoble") || rights.contains("nook") {
+            DrmScheme::BarnesAndNoble
+        } else {
+            DrmScheme::AdobeAdept
+        };
+        return Ok(Some(scheme));
+    }
+    if epub::entry_name(archive, epub::ENCRYPTION_PATH).is_some() {
+        let encryption = xhtml::decode(&epub::read_entry(archive, epub::ENCRYPTION_PATH)?);
+        if encrypts_content(&encryption) {
+            return Ok(Some(DrmScheme::Unknown));
+        }
+    }
+    Ok(None)
+}
+
+/// The DRM scheme of a Palm database, from its first MOBI header.
+pub(crate) fn mobi_scheme(data: &[u8]) -> Option<DrmScheme> {
+    let record0 = record_offset(data, 0)?;
+    let encryption = u16::from_be_bytes(data.get(record0 + 12..record0 + 14)?.try_into().ok()?);
+    (encryption != 0).then_some(DrmScheme::Kindle)
+}
+
+pub(crate) fn pdf_scheme(data: &[u8]) -> Option<DrmScheme> {
+    let encrypt = Regex::new(r"/Encrypt\s*(?:\d+\s+\d+\s+R|<<)").unwrap();
+    if !encrypt.is_match(data) {
+        return None;
+    }
+    let filter = Regex::new(r"/Filter\s*/(EBX_HANDLER|Standard)\b").unwrap();
+    Some(match filter.captures(data).map(|c| c[1].to_vec()) {
+        Some(name) if name == b"EBX_HANDLER" => DrmScheme::AdobeAdept,
+        Some(_) => DrmScheme::PdfPassword,
+        None => DrmScheme::Unknown,
+    })
+}
+
+fn sniff(head: &[u8]) -> BookFormat {
+    if head.starts_with(b"PK\x03\x04") {
+        BookFormat::Epub
+    } else if head.starts_with(KFX_DRM_MAGIC) {
+        BookFormat::Kfx
+    } else if head.starts_with(b"TPZ") {
+        BookFormat::Topaz
+    } else if head.starts_with(b"%PDF") {
+        BookFormat::Pdf
+    } else if matches!(head.get(60..68), Some(b"BOOKMOBI" | b"TEXtREAd")) {
+        BookFormat::Mobi
+    } else {
+        BookFormat::Unknown
+    }
+}
+
+/// Whether `encryption.xml` encrypts anything with a real cipher rather
+/// than font obfuscation.
+fn encrypts_content(encryption: &str) -> bool {
+    let mut reader = xhtml::reader(encryption);
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(element) | Event::Empty(element))
+                if xhtml::local_name(element.name().as_ref()) == "encryptionmethod" =>
+            {
+                let algorithm = xhtml::attribute(&element, "algorithm").unwrap_or_default();
+                if algorithm != epub::IDPF_OBFUSCATION && algorithm != epub::ADOBE_OBFUSCATION {
+                    return true;
+                }
+            }
+            Ok(Event::Eof) | Err(_) => return false,
+            _ => {}
+        }
+    }
+}
+
+fn record_offset(data: &[u8], index: usize) -> Option<usize> {
+    let at = 78 + index * 8;
+    Some(u32::from_be_bytes(data.get(at..at + 4)?.try_into().ok()?) as usize)
+}
+
+fn scheme_name(scheme: DrmScheme) -> &'static str {
+    match scheme {
+        DrmScheme::AdobeAdept => "Adobe ADEPT",
+        DrmScheme::BarnesAndNoble => "Barnes & Noble",
+        DrmScheme::ReadiumLcp => "Readium LCP",
+        DrmScheme::AppleFairPlay => "Apple FairPlay",
+        DrmScheme::Kindle => "Kindle",
+        DrmScheme::KindleKfx => "Kindle KFX",
+        DrmScheme::KindleTopaz => "Kindle Topaz",
+        DrmScheme::PdfPassword => "PDF password",
+        DrmScheme::Unknown => "unknown scheme",
+    }
+}
+
+fn read_up_to(file: &mut File, buffer: &mut [u8]) -> Result<usize> {
+    let mut len = 0;
+    while len < buffer.len() {
+        match file.read(&mut buffer[len..]) {
+            Ok(0) => break,
+            Ok(n) => len += n,
+            Err(e) => return Err(anyhow!("Failed to read book: {e}")),
+        }
+    }
+    Ok(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_dirs::temp_dir;
+    use std::io::{Cursor, Write};
+    use zip::write::SimpleFileOptions;
+    use zip::ZipWriter;
+
+    fn epub(entries: &[(&str, &str)]) -> ZipArchive<Cursor<Vec<u8>>> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, content) in entries {
+            writer
+                .start_file(*name, SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(content.as_bytes()).unwrap();
+        }
+        epub::open_archive(Cursor::new(writer.finish().unwrap().into_inner())).unwrap()
+    }
+
+    fn encryption(algorithm: &str) -> String {
+        format!(
+            r#"<encryption xmlns="urn:oasis:names:tc:opendocument:xmlns:container"
+  xmlns:enc="http://www.w3.org/2001/04/xmlenc#">
+  <enc:EncryptedData>
+    <enc:EncryptionMethod Algorithm="{algorithm}"/>
+    <enc:CipherData><enc:CipherReference URI="OEBPS/a.xhtml"/></enc:CipherData>
+  </enc:EncryptedData>
+</encryption>"#
+        )
+    }
+
+    #[test]
+    fn test_epub_schemes() {
+        let rights = r#"<adept:rights xmlns:adept="http://ns.adobe.com/adept"/>"#;
+        assert_eq!(
+            epub_scheme(&mut epub(&[("META-INF/rights.xml", rights)])).unwrap(),
+            Some(DrmScheme::AdobeAdept)
+        );
+        assert_eq!(
+            epub_scheme(&mut epub(&[("META-INF/license.lcpl", "{}")])).unwrap(),
+            Some(DrmScheme::ReadiumLcp)
+        );
+        let aes = encryption("http://www.w3.org/2001/04/xmlenc#aes128-cbc");
+        assert_eq!(
+            epub_scheme(&mut epub(&[("META-INF/encryption.xml", &aes)])).unwrap(),
+            Some(DrmScheme::Unknown)
+        );
+        // Obfuscated fonts alone are not DRM.
+        let fonts = encryption(epub::IDPF_OBFUSCATION);
+        assert_eq!(
+            epub_scheme(&mut epub(&[("META-INF/encryption.xml", &fonts)])).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_mobi_and_pdf_schemes() {
+        let mut mobi = vec![0u8; 78 + 8 + 2 + 16];
+        mobi[60..68].copy_from_slice(b"BOOKMOBI");
+        mobi[76..78].copy_from_slice(&1u16.to_be_bytes());
+        mobi[78..82].copy_from_slice(&88u32.to_be_bytes());
+        assert_eq!(sniff(&mobi[..68]), BookFormat::Mobi);
+        assert_eq!(mobi_scheme(&mobi), None);
+        mobi[88 + 12..88 + 14].copy_from_slice(&2u16.to_be_bytes());
+        assert_eq!(mobi_scheme(&mobi), Some(DrmScheme::Kindle));
+
+        let pdf = b"%PDF-1.6\ntrailer << /Root 1 0 R /Encrypt 5 0 R >>\n5 0 obj << /Filter /EBX_HANDLER >>";
+        assert_eq!(sniff(pdf), BookFormat::Pdf);
+        assert_eq!(pdf_scheme(pdf), Some(DrmScheme::AdobeAdept));
+        assert_eq!(pdf_scheme(b"%PDF-1.4\ntrailer << /Root 1 0 R >>"), None);
+        assert_eq!(sniff(b"\xeaDRMION\xee..."), BookFormat::Kfx);
+    }
+
+    #[test]
+    fn test_detect_drm_reads_file() {
+        let dir = temp_dir("drm");
+        let path = dir.join("detect.epub");
+        let mut writer = ZipWriter::new(File::create(&path).unwrap());
+        writer
+            .start_file("META-INF/sinf.xml", SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(b"<fairplay/>").unwrap();
+        writer.finish().unwrap();
+
+        let status = detect_drm(path.to_string_lossy().into_owned()).unwrap();
+        assert_eq!(status.format, BookFormat::Epub);
+        assert_eq!(status.scheme, Some(DrmScheme::AppleFairPlay));
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            protected_error(DrmScheme::AppleFairPlay).to_string(),
+            "DRM-protected book (Apple FairPlay)"
+        );
+    }
+}
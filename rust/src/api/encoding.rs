@@ -0,0 +1,270 @@
+//! Charset detection for imported text.
+//!
+//! A BOM is trusted outright. Otherwise UTF-16 is recognised by its pattern
+//! of zero bytes, valid UTF-8 wins next, and legacy encodings are ranked by
+//! `chardetng` together with how cleanly each one decodes the input.
+//! Confidence values rank the candidates; they are not probabilities.
+
+use anyhow::{anyhow, Result};
+use chardetng::{EncodingDetector, Iso2022JpDetection, Utf8Detection};
+use encoding_rs::{
+    DecoderResult, Encoding, BIG5, EUC_KR, GBK, SHIFT_JIS, UTF_16BE, UTF_16LE, UTF_8, WINDOWS_1252,
+};
+
+use crate::api::error::ApiError;
+
+/// Only this much input is decoded when scoring candidates.
+const SCORE_SAMPLE_BYTES: usize = 1024 * 1024;
+
+/// Legacy encodings considered when the input is not Unicode.
+const LEGACY: [&Encoding; 5] = [GBK, BIG5, SHIFT_JIS, EUC_KR, WINDOWS_1252];
+
+/// Text converted to UTF-8, with the charset it was decoded from.
+#[derive(Debug, Clone)]
+pub struct DecodedText {
+    pub text: String,
+    /// Encoding label, e.g. `UTF-8`, `GBK` or `Shift_JIS`.
+    pub encoding: String,
+    /// Confidence of the chosen encoding, from 0 to 1; 1 for overrides.
+    pub confidence: f32,
+    /// Whether some bytes were invalid and replaced with U+FFFD.
+    pub had_errors: bool,
+    /// Detected encodings, most likely first.
+    pub candidates: Vec<EncodingCandidate>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EncodingCandidate {
+    pub encoding: String,
+    pub confidence: f32,
+}
+
+/// Detect the charset of `bytes` and decode them to UTF-8.
+///
+/// # Arguments
+/// * `bytes` - Raw file contents
+/// * `override_encoding` - Encoding label to decode with instead of the
+///   detected one, e.g. `big5`; the candidates are still reported
+#[flutter_rust_bridge::frb]
+pub fn detect_and_decode_text(
+    bytes: Vec<u8>,
+    override_encoding: Option<String>,
+) -> Result<DecodedText, ApiError> {
+    let detection = detect(&bytes, true);
+    let (encoding, confidence) = match override_encoding {
+        Some(label) => {
+            let encoding = Encoding::for_label(label.trim().as_bytes())
+                .ok_or_else(|| anyhow!("Unknown encoding {label}"))?;
+            (encoding, 1.0)
+        }
+        None => (detection.encoding, detection.candidates[0].1),
+    };
+
+    let bom_len = if encoding == detection.encoding {
+        detection.bom_len
+    } else {
+        0
+    };
+    let (text, had_errors) = encoding.decode_without_bom_handling(&bytes[bom_len..]);
+    Ok(DecodedText {
+        text: text.into_owned(),
+        encoding: encoding.name().to_string(),
+        confidence,
+        had_errors,
+        candidates: detection
+            .candidates
+            .into_iter()
+            .map(|(encoding, confidence)| EncodingCandidate {
+                encoding: encoding.name().to_string(),
+                confidence,
+            })
+            .collect(),
+    })
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+pub(crate) struct Detection {
+    pub encoding: &'static Encoding,
+    /// Length of the BOM to skip before decoding.
+    pub bom_len: usize,
+    /// Candidates with their confidence, best first; never empty.
+    pub candidates: Vec<(&'static Encoding, f32)>,
+}
+
+/// Detect the encoding of `bytes`. Pass `complete = false` when `bytes` is
+/// only the start of a longer input, so a truncated final character is not
+/// held against an encoding.
+pub(crate) fn detect(bytes: &[u8], complete: bool) -> Detection {
+    if let Some((encoding, bom_len)) = Encoding::for_bom(bytes) {
+        return Detection {
+            encoding,
+            bom_len,
+            candidates: vec![(encoding, 1.0)],
+        };
+    }
+
+    let (sample, complete) = if bytes.len() > SCORE_SAMPLE_BYTES {
+        (&bytes[..SCORE_SAMPLE_BYTES], false)
+    } else {
+        (bytes, complete)
+    };
+    let mut candidates = utf16_candidates(sample);
+    if candidates.is_empty() {
+        candidates = byte_candidates(sample, complete);
+    }
+    candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+    Detection {
+        encoding: candidates[0].0,
+        bom_len: 0,
+        candidates,
+    }
+}
+
+/// Unicode text without a BOM is UTF-16 if every other byte is mostly zero,
+/// as it is for Latin text.
+fn utf16_candidates(sample: &[u8]) -> Vec<(&'static Encoding, f32)> {
+    let pairs = sample.len() / 2;
+    if pairs < 2 {
+        return Vec::new();
+    }
+    let zeros = |offset: usize| {
+        (0..pairs).filter(|i| sample[i * 2 + offset] == 0).count() as f32 / pairs as f32
+    };
+    let (even, odd) = (zeros(0), zeros(1));
+
+    let mut candidates = Vec::new();
+    for (encoding, zeros, other) in [(UTF_16LE, odd, even), (UTF_16BE, even, odd)] {
+        if zeros >= 0.2 && other < 0.05 {
+            let clean = clean_ratio(encoding, sample, false);
+            candidates.push((encoding, clean * (0.5 + zeros / 2.0)));
+        }
+    }
+    candidates
+}
+
+fn byte_candidates(sample: &[u8], complete: bool) -> Vec<(&'static Encoding, f32)> {
+    let ascii = Encoding::ascii_valid_up_to(sample) == sample.len();
+    if ascii {
+        return vec![(UTF_8, 1.0)];
+    }
+    let utf8_valid = match std::str::from_utf8(sample) {
+        Ok(_) => true,
+        Err(e) => !complete && e.error_len().is_none(),
+    };
+    if utf8_valid {
+        // Multi-byte UTF-8 sequences rarely occur by accident in legacy text.
+        return vec![(UTF_8, 0.99)];
+    }
+
+    let mut detector = EncodingDetector::new(Iso2022JpDetection::Deny);
+    detector.feed(sample, complete);
+    let guess = detector.guess(None, Utf8Detection::Deny);
+
+    let mut candidates = vec![(UTF_8, clean_ratio(UTF_8, sample, complete) * 0.5)];
+    if !LEGACY.contains(&guess) {
+        candidates.push((guess, clean_ratio(guess, sample, complete) * 0.9));
+    }
+    for encoding in LEGACY {
+        let weight = if encoding == guess { 0.9 } else { 0.5 };
+        candidates.push((encoding, clean_ratio(encoding, sample, complete) * weight));
+    }
+    candidates
+}
+
+/// Fraction of non-ASCII characters that decode without error.
+fn clean_ratio(encoding: &'static Encoding, bytes: &[u8], last: bool) -> f32 {
+    let mut decoder = encoding.new_decoder_without_bom_handling();
+    let mut text = String::with_capacity(bytes.len() * 3);
+    let mut src = bytes;
+    let mut errors = 0;
+    loop {
+        let (result, read) = decoder.decode_to_string_without_replacement(src, &mut text, last);
+        src = &src[read..];
+        match result {
+            DecoderResult::InputEmpty => break,
+            DecoderResult::OutputFull => text.reserve(src.len() * 3 + 4),
+            DecoderResult::Malformed(_, _) => errors += 1,
+        }
+    }
+
+    let decoded = text.chars().filter(|c| !c.is_ascii()).count();
+    if decoded + errors == 0 {
+        return 1.0;
+    }
+    decoded as f32 / (decoded + errors) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHINESE: &str = "第一章 风起\n天色渐晚，城门外的官道上行人稀少。他抬头看了看远处的山。";
+    const JAPANESE: &str =
+        "第一章\n吾輩は猫である。名前はまだ無い。どこで生れたかとんと見当がつかぬ。";
+
+    fn encode(encoding: &'static Encoding, text: &str) -> Vec<u8> {
+        encoding.encode(text).0.into_owned()
+    }
+
+    #[test]
+    fn test_detects_cjk_legacy_encodings() {
+        for (encoding, text) in [
+            (GBK, CHINESE),
+            (
+                BIG5,
+                "第一章 風起\n天色漸晚，城門外的官道上行人稀少。他抬頭看了看遠處的山。",
+            ),
+            (SHIFT_JIS, JAPANESE),
+        ] {
+            let decoded = detect_and_decode_text(encode(encoding, text), None).unwrap();
+            assert_eq!(decoded.encoding, encoding.name());
+            assert_eq!(decoded.text, text);
+            assert!(!decoded.had_errors);
+            assert_eq!(decoded.candidates[0].encoding, encoding.name());
+            assert!(decoded.confidence > decoded.candidates[1].confidence);
+        }
+    }
+
+    #[test]
+    fn test_detects_unicode() {
+        let decoded = detect_and_decode_text(CHINESE.as_bytes().to_vec(), None).unwrap();
+        assert_eq!(
+            (decoded.encoding.as_str(), decoded.text.as_str()),
+            ("UTF-8", CHINESE)
+        );
+
+        let mut bom = vec![0xEF, 0xBB, 0xBF];
+        bom.extend_from_slice(CHINESE.as_bytes());
+        let decoded = detect_and_decode_text(bom, None).unwrap();
+        assert_eq!((decoded.text.as_str(), decoded.confidence), (CHINESE, 1.0));
+
+        let utf16: Vec<u8> = "Chapter 1\nIt was a dark night."
+            .encode_utf16()
+            .flat_map(u16::to_be_bytes)
+            .collect();
+        let decoded = detect_and_decode_text(utf16, None).unwrap();
+        assert_eq!(decoded.encoding, "UTF-16BE");
+        assert_eq!(decoded.text, "Chapter 1\nIt was a dark night.");
+    }
+
+    #[test]
+    fn test_override_encoding() {
+        let bytes = encode(GBK, CHINESE);
+        let decoded = detect_and_decode_text(bytes.clone(), Some("gb18030".to_string())).unwrap();
+        assert_eq!(decoded.encoding, "gb18030");
+        assert_eq!(decoded.confidence, 1.0);
+        assert_eq!(decoded.text, CHINESE);
+        assert_eq!(decoded.candidates[0].encoding, "GBK");
+
+        let decoded = detect_and_decode_text(bytes.clone(), Some("utf-8".to_string())).unwrap();
+        assert!(decoded.had_errors);
+        assert!(detect_and_decode_text(bytes, Some("klingon".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_truncated_sample_keeps_utf8() {
+        let bytes = CHINESE.as_bytes();
+        let detection = detect(&bytes[..bytes.len() - 1], false);
+        assert_eq!(detection.encoding, UTF_8);
+    }
+}
@@ -0,0 +1,1099 @@
+//! EPUB 2/3 parsing.
+//!
+//! Reads `META-INF/container.xml` to locate the OPF package, then walks the
+//! spine to produce one cleaned HTML chapter per content document. The table
+//! of contents comes from the EPUB 2 NCX when present, falling back to the
+//! EPUB 3 navigation document.
+//!
+//! Chapters come without the publisher's styling. `load_epub_chapter_styles`
+//! adds it back for books whose layout matters, either as published or
+//! cleaned of what would override the reading settings and night mode.
+//!
+//! Embedded resources are extracted by archive path. Fonts obfuscated as
+//! described in `META-INF/encryption.xml` are restored, and WOFF/WOFF2 fonts
+//! are converted to TTF so publisher fonts can be registered directly.
+
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{Read, Seek};
+
+use anyhow::{anyhow, Result};
+use quick_xml::events::Event;
+use zip::ZipArchive;
+
+use crate::api::book::{self, BookChapter, BookMetadata, ParsedBook, TocEntry};
+use crate::api::drm;
+use crate::api::error::ApiError;
+use crate::api::{font_converter, task};
+use crate::{css, xhtml};
+
+const CONTAINER_PATH: &str = "META-INF/container.xml";
+pub(crate) const ENCRYPTION_PATH: &str = "META-INF/encryption.xml";
+pub(crate) const NCX_MEDIA_TYPE: &str = "application/x-dtbncx+xml";
+
+/// IDPF font obfuscation: the first 1040 bytes are XORed with the SHA-1 of
+/// the package's unique identifier.
+pub(crate) const IDPF_OBFUSCATION: &str = "http://www.idpf.org/2008/embedding";
+const IDPF_OBFUSCATED_LEN: usize = 1040;
+/// Adobe font obfuscation: the first 1024 bytes are XORed with the 16 bytes
+/// of the book's UUID.
+pub(crate) const ADOBE_OBFUSCATION: &str = "http://ns.adobe.com/pdf/enc#RC";
+const ADOBE_OBFUSCATED_LEN: usize = 1024;
+
+/// Kind of an embedded EPUB resource, derived from its media type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceKind {
+    Image,
+    Stylesheet,
+    Font,
+    Other,
+}
+
+/// How much of the publisher's styling `load_epub_chapter_styles` keeps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EpubLayout {
+    /// The stylesheets as published, with links resolved to archive paths.
+    Original,
+    /// Colours, backgrounds, font families, fixed font sizes, line heights
+    /// and page margins removed so reading settings and night mode apply,
+    /// with indents, italics, alignment and separators kept.
+    Cleaned,
+}
+
+/// A chapter with the classes and styles its stylesheets need.
+#[derive(Debug, Clone)]
+pub struct StyledChapter {
+    /// Body HTML as in `BookChapter`, plus `class` and `style` attributes.
+    pub html: String,
+    /// The chapter's linked, imported and embedded stylesheets, in order.
+    pub css: String,
+}
+
+/// A non-document resource declared in the EPUB manifest.
+#[derive(Debug, Clone)]
+pub struct EpubResource {
+    /// Archive path, as used by chapter HTML and `extract_epub_resource`.
+    pub href: String,
+    pub media_type: String,
+    pub kind: ResourceKind,
+    /// Uncompressed size in bytes.
+    pub size: u32,
+}
+
+/// Parse a local EPUB file into chapters and a table of contents.
+///
+/// # Arguments
+/// * `path` - Path to the `.epub` file
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+#[flutter_rust_bridge::frb]
+pub async fn parse_epub(path: String, cancel_token: Option<u32>) -> Result<ParsedBook, ApiError> {
+    task::run_blocking(task::cancel_token(cancel_token)?, move |_| {
+        parse(&mut open_file(&path)?)
+    })
+    .await
+}
+
+/// List the images, stylesheets, fonts and other resources of an EPUB,
+/// sorted by archive path.
+#[flutter_rust_bridge::frb]
+pub fn list_epub_resources(path: String) -> Result<Vec<EpubResource>, ApiError> {
+    Ok(list_resources(&mut open_file(&path)?)?)
+}
+
+/// Extract an embedded resource by archive path.
+///
+/// Obfuscated fonts are restored and WOFF/WOFF2 fonts are returned as TTF;
+/// everything else is returned as stored.
+///
+/// # Arguments
+/// * `path` - Path to the `.epub` file
+/// * `href` - Archive path of the resource, e.g. an `img` `src` from chapter HTML
+#[flutter_rust_bridge::frb]
+pub fn extract_epub_resource(path: String, href: String) -> Result<Vec<u8>, ApiError> {
+    Ok(extract_resource(&mut open_file(&path)?, &href)?)
+}
+
+/// Load a chapter with its publisher styling, for books whose layout
+/// matters.
+///
+/// # Arguments
+/// * `path` - Path to the `.epub` file
+/// * `href` - The chapter's `BookChapter::href`
+/// * `layout` - Whether to keep the styling as published or cleaned
+#[flutter_rust_bridge::frb]
+pub fn load_epub_chapter_styles(
+    path: String,
+    href: String,
+    layout: EpubLayout,
+) -> Result<StyledChapter, ApiError> {
+    Ok(styled_chapter(&mut open_file(&path)?, &href, layout)?)
+}
+
+pub(crate) fn open_file(path: &str) -> Result<ZipArchive<File>> {
+    let file = File::open(path).map_err(|e| anyhow!("Failed to open {path}: {e}"))?;
+    open_archive(file)
+}
+
+pub(crate) fn open_archive<R: Read + Seek>(reader: R) -> Result<ZipArchive<R>> {
+    ZipArchive::new(reader).map_err(|e| anyhow!("Invalid EPUB archive: {e}"))
+}
+
+pub(crate) fn parse<R: Read + Seek>(archive: &mut ZipArchive<R>) -> Result<ParsedBook> {
+    if let Some(scheme) = drm::epub_scheme(archive)? {
+        return Err(drm::protected_error(scheme));
+    }
+    let package = read_package(archive)?;
+
+    let mut chapters = Vec::new();
+    for item in package.spine_items() {
+        if !item.is_document() {
+            continue;
+        }
+        let document = xhtml::decode(&read_entry(archive, &item.path)?);
+        let clean = xhtml::clean(&document, &item.path);
+        chapters.push(BookChapter::new(
+            chapters.len() as u32,
+            item.path.clone(),
+            clean.heading,
+            clean.html,
+        ));
+    }
+    if chapters.is_empty() {
+        return Err(anyhow!("EPUB spine contains no readable documents"));
+    }
+
+    let mut toc = read_toc(archive, &package)?;
+    link_toc(&mut toc, &mut chapters);
+
+    Ok(ParsedBook {
+        metadata: package.metadata,
+        chapters,
+        toc,
+    })
+}
+
+/// The table of contents, from the NCX or the navigation document.
+pub(crate) fn read_toc<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    package: &Package,
+) -> Result<Vec<TocEntry>> {
+    let Some(item) = package.toc_item() else {
+        return Ok(Vec::new());
+    };
+    let document = xhtml::decode(&read_entry(archive, &item.path)?);
+    Ok(if item.media_type == NCX_MEDIA_TYPE {
+        parse_ncx(&document, &item.path)
+    } else {
+        parse_nav(&document, &item.path)
+    })
+}
+
+fn list_resources<R: Read + Seek>(archive: &mut ZipArchive<R>) -> Result<Vec<EpubResource>> {
+    let package = read_package(archive)?;
+    let mut resources = Vec::new();
+    for item in package.manifest.values() {
+        if item.is_document() || item.media_type == NCX_MEDIA_TYPE {
+            continue;
+        }
+        let Some(name) = entry_name(archive, &item.path) else {
+            continue;
+        };
+        let size = archive.by_name(&name).map_or(0, |entry| entry.size());
+        resources.push(EpubResource {
+            href: item.path.clone(),
+            media_type: item.media_type.clone(),
+            kind: resource_kind(&item.media_type),
+            size: size as u32,
+        });
+    }
+    resources.sort_by(|a, b| a.href.cmp(&b.href));
+    Ok(resources)
+}
+
+pub(crate) fn extract_resource<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    href: &str,
+) -> Result<Vec<u8>> {
+    let (path, _) = xhtml::split_fragment(href);
+    let path = path.trim_start_matches('/');
+    let mut data = read_entry(archive, path)?;
+
+    if let Some(algorithm) = obfuscation_algorithm(archive, path)? {
+        let package = read_package(archive)?;
+        deobfuscate(&mut data, &algorithm, &package.identifiers)?;
+    }
+    if data.starts_with(b"wOFF") || data.starts_with(b"wOF2") {
+        return Ok(font_converter::convert_font_to_ttf(data)?);
+    }
+    Ok(data)
+}
+
+/// An entry as stored, except that obfuscated fonts are restored.
+pub(crate) fn read_resource<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    path: &str,
+    package: &Package,
+) -> Result<Vec<u8>> {
+    let mut data = read_entry(archive, path)?;
+    if let Some(algorithm) = obfuscation_algorithm(archive, path)? {
+        deobfuscate(&mut data, &algorithm, &package.identifiers)?;
+    }
+    Ok(data)
+}
+
+fn styled_chapter<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    href: &str,
+    layout: EpubLayout,
+) -> Result<StyledChapter> {
+    if let Some(scheme) = drm::epub_scheme(archive)? {
+        return Err(drm::protected_error(scheme));
+    }
+    let (path, _) = xhtml::split_fragment(href);
+    let document = xhtml::decode(&read_entry(archive, path)?);
+    let clean = layout == EpubLayout::Cleaned;
+    let base_dir = xhtml::parent(path);
+    let html = xhtml::clean_styled(&document, path, &|style| {
+        css::rewrite_declarations(style, base_dir, clean)
+    })
+    .html;
+
+    let mut styles = String::new();
+    let mut loaded = HashSet::new();
+    for source in xhtml::stylesheets(&document, path) {
+        match source {
+            xhtml::StyleSource::Linked(path) => {
+                push_stylesheet(archive, &path, clean, &mut loaded, &mut styles)?
+            }
+            xhtml::StyleSource::Inline(text) => {
+                let stylesheet = css::rewrite(&text, base_dir, clean);
+                for import in &stylesheet.imports {
+                    push_stylesheet(archive, import, clean, &mut loaded, &mut styles)?;
+                }
+                styles.push_str(&stylesheet.css);
+            }
+        }
+    }
+    Ok(StyledChapter { html, css: styles })
+}
+
+/// Append a stylesheet and the ones it imports, each once. Missing
+/// stylesheets are skipped, as browsers do.
+fn push_stylesheet<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    path: &str,
+    clean: bool,
+    loaded: &mut HashSet<String>,
+    styles: &mut String,
+) -> Result<()> {
+    let (path, _) = xhtml::split_fragment(path);
+    if entry_name(archive, path).is_none() || !loaded.insert(path.to_string()) {
+        return Ok(());
+    }
+    let data = read_entry(archive, path)?;
+    let text = String::from_utf8_lossy(&data);
+    let stylesheet = css::rewrite(
+        text.trim_start_matches('\u{feff}'),
+        xhtml::parent(path),
+        clean,
+    );
+    for import in &stylesheet.imports {
+        push_stylesheet(archive, import, clean, loaded, styles)?;
+    }
+    styles.push_str(&stylesheet.css);
+    Ok(())
+}
+
+fn resource_kind(media_type: &str) -> ResourceKind {
+    if media_type.starts_with("image/") {
+        ResourceKind::Image
+    } else if media_type == "text/css" {
+        ResourceKind::Stylesheet
+    } else if media_type.starts_with("font/")
+        || ["font", "opentype", "sfnt"]
+            .iter()
+            .any(|hint| media_type.contains(hint))
+    {
+        ResourceKind::Font
+    } else {
+        ResourceKind::Other
+    }
+}
+
+/// Algorithm URI `META-INF/encryption.xml` lists for `path`, if any.
+fn obfuscation_algorithm<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    path: &str,
+) -> Result<Option<String>> {
+    if entry_name(archive, ENCRYPTION_PATH).is_none() {
+        return Ok(None);
+    }
+    let encryption = xhtml::decode(&read_entry(archive, ENCRYPTION_PATH)?);
+    let mut reader = xhtml::reader(&encryption);
+    let mut algorithm = None;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(element) | Event::Empty(element)) => {
+                match xhtml::local_name(element.name().as_ref()).as_str() {
+                    "encryptionmethod" => algorithm = xhtml::attribute(&element, "algorithm"),
+                    "cipherreference" => {
+                        let uri = xhtml::attribute(&element, "uri").unwrap_or_default();
+                        if xhtml::resolve("", uri.trim()) == path {
+                            return Ok(algorithm);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Ok(Event::End(element))
+                if xhtml::local_name(element.name().as_ref()) == "encrypteddata" =>
+            {
+                algorithm = None;
+            }
+            Ok(Event::Eof) | Err(_) => return Ok(None),
+            _ => {}
+        }
+    }
+}
+
+fn deobfuscate(data: &mut [u8], algorithm: &str, identifiers: &[String]) -> Result<()> {
+    let (key, len) = match algorithm {
+        IDPF_OBFUSCATION => {
+            let identifier = identifiers
+                .first()
+                .ok_or_else(|| anyhow!("Obfuscated font requires a package identifier"))?;
+            let identifier: String = identifier
+                .chars()
+                .filter(|c| !matches!(c, ' ' | '\t' | '\r' | '\n'))
+                .collect();
+            let key = sha1_smol::Sha1::from(identifier).digest().bytes().to_vec();
+            (key, IDPF_OBFUSCATED_LEN)
+        }
+        ADOBE_OBFUSCATION => {
+            let key = identifiers
+                .iter()
+                .find(|id| id.to_ascii_lowercase().contains("uuid"))
+                .or(identifiers.first())
+                .and_then(|id| uuid_bytes(id))
+                .ok_or_else(|| anyhow!("Obfuscated font requires a UUID identifier"))?;
+            (key, ADOBE_OBFUSCATED_LEN)
+        }
+        _ => {
+            return Err(anyhow!(
+                "Resource is encrypted with unsupported algorithm {algorithm}"
+            ))
+        }
+    };
+
+    for (byte, k) in data.iter_mut().take(len).zip(key.iter().cycle()) {
+        *byte ^= k;
+    }
+    Ok(())
+}
+
+/// The 16 bytes of a UUID identifier such as `urn:uuid:…`.
+fn uuid_bytes(identifier: &str) -> Option<Vec<u8>> {
+    let uuid = identifier.rsplit(':').next().unwrap_or(identifier);
+    let digits: Vec<u32> = uuid.chars().filter_map(|c| c.to_digit(16)).collect();
+    (digits.len() == 32).then(|| {
+        digits
+            .chunks(2)
+            .map(|pair| (pair[0] * 16 + pair[1]) as u8)
+            .collect()
+    })
+}
+
+/// Stored name for `path`, falling back to a case-insensitive match since
+/// hrefs in the wild do not always agree with the archive.
+pub(crate) fn entry_name<R: Read + Seek>(archive: &ZipArchive<R>, path: &str) -> Option<String> {
+    if archive.index_for_name(path).is_some() {
+        return Some(path.to_string());
+    }
+    archive
+        .file_names()
+        .find(|name| name.eq_ignore_ascii_case(path))
+        .map(str::to_string)
+}
+
+pub(crate) fn read_entry<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    path: &str,
+) -> Result<Vec<u8>> {
+    let name = entry_name(archive, path).ok_or_else(|| anyhow!("Missing EPUB entry {path}"))?;
+    let mut entry = archive
+        .by_name(&name)
+        .map_err(|e| anyhow!("Failed to read EPUB entry {path}: {e}"))?;
+    let mut data = Vec::with_capacity(entry.size() as usize);
+    entry
+        .read_to_end(&mut data)
+        .map_err(|e| anyhow!("Failed to read EPUB entry {path}: {e}"))?;
+    Ok(data)
+}
+
+pub(crate) struct ManifestItem {
+    /// Archive path.
+    pub(crate) path: String,
+    pub(crate) media_type: String,
+    pub(crate) properties: String,
+}
+
+impl ManifestItem {
+    pub(crate) fn is_document(&self) -> bool {
+        matches!(
+            self.media_type.as_str(),
+            "application/xhtml+xml" | "text/html" | "application/xml" | ""
+        )
+    }
+
+    pub(crate) fn has_property(&self, property: &str) -> bool {
+        self.properties.split_whitespace().any(|p| p == property)
+    }
+}
+
+pub(crate) struct Package {
+    pub(crate) metadata: BookMetadata,
+    pub(crate) manifest: HashMap<String, ManifestItem>,
+    /// Manifest ids of linear spine items, in order.
+    pub(crate) spine: Vec<String>,
+    ncx_id: Option<String>,
+    /// `dc:identifier` values, the package's unique identifier first.
+    identifiers: Vec<String>,
+}
+
+impl Package {
+    pub(crate) fn spine_items(&self) -> impl Iterator<Item = &ManifestItem> {
+        self.spine.iter().filter_map(|id| self.manifest.get(id))
+    }
+
+    pub(crate) fn toc_item(&self) -> Option<&ManifestItem> {
+        self.ncx_id
+            .as_ref()
+            .and_then(|id| self.manifest.get(id))
+            .or_else(|| {
+                self.manifest
+                    .values()
+                    .find(|item| item.media_type == NCX_MEDIA_TYPE)
+            })
+            .or_else(|| self.manifest.values().find(|item| item.has_property("nav")))
+    }
+}
+
+pub(crate) fn read_package<R: Read + Seek>(archive: &mut ZipArchive<R>) -> Result<Package> {
+    let container = xhtml::decode(&read_entry(archive, CONTAINER_PATH)?);
+    let opf_path = rootfile_path(&container)
+        .ok_or_else(|| anyhow!("container.xml does not name a package document"))?;
+    let opf = xhtml::decode(&read_entry(archive, &opf_path)?);
+    Ok(parse_opf(&opf, &opf_path))
+}
+
+fn rootfile_path(container: &str) -> Option<String> {
+    let mut reader = xhtml::reader(container);
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(element) | Event::Empty(element))
+                if xhtml::local_name(element.name().as_ref()) == "rootfile" =>
+            {
+                if let Some(path) = xhtml::attribute(&element, "full-path") {
+                    return Some(path);
+                }
+            }
+            Ok(Event::Eof) | Err(_) => return None,
+            _ => {}
+        }
+    }
+}
+
+fn parse_opf(opf: &str, opf_path: &str) -> Package {
+    let base_dir = xhtml::parent(opf_path);
+    let mut reader = xhtml::reader(opf);
+    let mut metadata = BookMetadata::empty();
+    let mut manifest = HashMap::new();
+    let mut spine = Vec::new();
+    let mut ncx_id = None;
+    let mut cover_id = None;
+    let mut unique_id = None;
+    let mut identifiers = Vec::new();
+    let mut in_metadata = false;
+    // Metadata element whose text is being collected, and its `id`.
+    let mut field: Option<String> = None;
+    let mut field_id = None;
+    let mut value = String::new();
+
+    loop {
+        let event = match reader.read_event() {
+            Ok(Event::Eof) | Err(_) => break,
+            Ok(event) => event,
+        };
+        match event {
+            Event::Start(element) | Event::Empty(element) => {
+                match xhtml::local_name(element.name().as_ref()).as_str() {
+                    "package" => unique_id = xhtml::attribute(&element, "unique-identifier"),
+                    "metadata" => in_metadata = true,
+                    "meta" if xhtml::attribute(&element, "name").as_deref() == Some("cover") => {
+                        cover_id = xhtml::attribute(&element, "content");
+                    }
+                    name @ ("title" | "creator" | "language" | "identifier" | "publisher"
+                    | "description")
+                        if in_metadata =>
+                    {
+                        field = Some(name.to_string());
+                        field_id = xhtml::attribute(&element, "id");
+                        value.clear();
+                    }
+                    "item" => {
+                        let (Some(id), Some(href)) = (
+                            xhtml::attribute(&element, "id"),
+                            xhtml::attribute(&element, "href"),
+                        ) else {
+                            continue;
+                        };
+                        manifest.insert(
+                            id,
+                            ManifestItem {
+                                path: xhtml::resolve(base_dir, href.trim()),
+                                media_type: xhtml::attribute(&element, "media-type")
+                                    .unwrap_or_default(),
+                                properties: xhtml::attribute(&element, "properties")
+                                    .unwrap_or_default(),
+                            },
+                        );
+                    }
+                    "spine" => ncx_id = xhtml::attribute(&element, "toc"),
+                    "itemref" => {
+                        let linear = xhtml::attribute(&element, "linear");
+                        if let Some(idref) = xhtml::attribute(&element, "idref") {
+                            if linear.as_deref() != Some("no") {
+                                spine.push(idref);
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Event::Text(text) if field.is_some() => value.push_str(&xhtml::text(&text)),
+            Event::CData(text) if field.is_some() => {
+                value.push_str(&String::from_utf8_lossy(&text));
+            }
+            Event::End(element) => match xhtml::local_name(element.name().as_ref()).as_str() {
+                "metadata" => in_metadata = false,
+                name if field.as_deref() == Some(name) => {
+                    field = None;
+                    let text = xhtml::normalize_whitespace(&value);
+                    if text.is_empty() {
+                        continue;
+                    }
+                    let slot = match name {
+                        "creator" => {
+                            metadata.authors.push(text);
+                            continue;
+                        }
+                        "identifier" => {
+                            if unique_id.is_some() && field_id == unique_id {
+                                identifiers.insert(0, text);
+                            } else {
+                                identifiers.push(text);
+                            }
+                            continue;
+                        }
+                        "title" => &mut metadata.title,
+                        "language" => &mut metadata.language,
+                        "publisher" => &mut metadata.publisher,
+                        _ => &mut metadata.description,
+                    };
+                    slot.get_or_insert(text);
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    metadata.identifier = identifiers.first().cloned();
+    metadata.cover_href = manifest
+        .values()
+        .find(|item| item.has_property("cover-image"))
+        .or_else(|| cover_id.and_then(|id| manifest.get(&id)))
+        .map(|item| item.path.clone());
+
+    Package {
+        metadata,
+        manifest,
+        spine,
+        ncx_id,
+        identifiers,
+    }
+}
+
+/// Parse an EPUB 2 NCX `navMap`.
+fn parse_ncx(ncx: &str, ncx_path: &str) -> Vec<TocEntry> {
+    let base_dir = xhtml::parent(ncx_path);
+    let mut reader = xhtml::reader(ncx);
+    let mut entries = Vec::new();
+    // Open navPoints; an entry is emitted once its first child starts or it
+    // closes, so parents precede their children.
+    let mut open: Vec<Option<TocEntry>> = Vec::new();
+    let mut in_label = false;
+
+    loop {
+        let event = match reader.read_event() {
+            Ok(Event::Eof) | Err(_) => break,
+            Ok(event) => event,
+        };
+        match event {
+            Event::Start(element) | Event::Empty(element) => {
+                match xhtml::local_name(element.name().as_ref()).as_str() {
+                    "navpoint" => {
+                        if let Some(parent) = open.last_mut().and_then(Option::take) {
+                            entries.push(parent);
+                        }
+                        open.push(Some(TocEntry {
+                            title: String::new(),
+                            href: String::new(),
+                            chapter_index: None,
+                            depth: open.len() as u32,
+                        }));
+                    }
+                    "navlabel" => in_label = true,
+                    "content" => {
+                        if let (Some(Some(entry)), Some(src)) =
+                            (open.last_mut(), xhtml::attribute(&element, "src"))
+                        {
+                            entry.href = resolve_href(base_dir, &src);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Event::Text(text) if in_label => {
+                if let Some(Some(entry)) = open.last_mut() {
+                    entry.title.push_str(&xhtml::text(&text));
+                }
+            }
+            Event::End(element) => match xhtml::local_name(element.name().as_ref()).as_str() {
+                "navpoint" => {
+                    if let Some(entry) = open.pop().flatten() {
+                        entries.push(entry);
+                    }
+                }
+                "navlabel" => in_label = false,
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    finish_entries(entries)
+}
+
+/// Parse the `toc` nav of an EPUB 3 navigation document.
+fn parse_nav(nav: &str, nav_path: &str) -> Vec<TocEntry> {
+    let base_dir = xhtml::parent(nav_path);
+    let mut reader = xhtml::reader(nav);
+    let mut entries = Vec::new();
+    // Depth of open `nav` elements; only the first toc nav is read.
+    let mut nav_depth = 0usize;
+    let mut done = false;
+    let mut list_depth = 0u32;
+    let mut current: Option<TocEntry> = None;
+
+    while !done {
+        let event = match reader.read_event() {
+            Ok(Event::Eof) | Err(_) => break,
+            Ok(event) => event,
+        };
+        match event {
+            Event::Start(element) => {
+                let name = xhtml::local_name(element.name().as_ref());
+                if nav_depth == 0 {
+                    let is_toc = xhtml::attribute(&element, "type")
+                        .is_some_and(|kind| kind.split_whitespace().any(|k| k == "toc"));
+                    if name == "nav" && is_toc {
+                        nav_depth = 1;
+                    }
+                    continue;
+                }
+                match name.as_str() {
+                    "nav" => nav_depth += 1,
+                    "ol" => list_depth += 1,
+                    "a" => {
+                        current = xhtml::attribute(&element, "href").map(|href| TocEntry {
+                            title: String::new(),
+                            href: resolve_href(base_dir, &href),
+                            chapter_index: None,
+                            depth: list_depth.saturating_sub(1),
+                        });
+                    }
+                    _ => {}
+                }
+            }
+            Event::Text(text) if nav_depth > 0 => {
+                if let Some(entry) = current.as_mut() {
+                    entry.title.push_str(&xhtml::text(&text));
+                }
+            }
+            Event::End(element) if nav_depth > 0 => {
+                match xhtml::local_name(element.name().as_ref()).as_str() {
+                    "nav" => {
+                        nav_depth -= 1;
+                        done = nav_depth == 0;
+                    }
+                    "ol" => list_depth = list_depth.saturating_sub(1),
+                    "a" => entries.extend(current.take()),
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    finish_entries(entries)
+}
+
+fn resolve_href(base_dir: &str, href: &str) -> String {
+    let (path, fragment) = xhtml::split_fragment(href.trim());
+    let mut resolved = xhtml::resolve(base_dir, path);
+    if let Some(fragment) = fragment {
+        resolved.push('#');
+        resolved.push_str(fragment);
+    }
+    resolved
+}
+
+fn finish_entries(entries: Vec<TocEntry>) -> Vec<TocEntry> {
+    entries
+        .into_iter()
+        .filter_map(|mut entry| {
+            entry.title = xhtml::normalize_whitespace(&entry.title);
+            (!entry.title.is_empty() && !entry.href.is_empty()).then_some(entry)
+        })
+        .collect()
+}
+
+/// Point TOC entries at the chapters their hrefs name.
+fn link_toc(toc: &mut [TocEntry], chapters: &mut [BookChapter]) {
+    let index_by_href: HashMap<&str, u32> = chapters
+        .iter()
+        .map(|chapter| (chapter.href.as_str(), chapter.index))
+        .collect();
+    for entry in toc.iter_mut() {
+        let (path, _) = xhtml::split_fragment(&entry.href);
+        entry.chapter_index = index_by_href.get(path).copied();
+    }
+    book::title_chapters(toc, chapters);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fonts::FontBuilder;
+    use std::io::{Cursor, Write};
+    use zip::write::SimpleFileOptions;
+    use zip::ZipWriter;
+
+    const CONTAINER: &str = r#"<?xml version="1.0"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+  <rootfiles>
+    <rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/>
+  </rootfiles>
+</container>"#;
+
+    fn epub(entries: &[(&str, &str)]) -> ZipArchive<Cursor<Vec<u8>>> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, content) in entries {
+            writer
+                .start_file(*name, SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(content.as_bytes()).unwrap();
+        }
+        let data = writer.finish().unwrap().into_inner();
+        open_archive(Cursor::new(data)).unwrap()
+    }
+
+    fn chapter(body: &str) -> String {
+        format!(
+            r#"<html xmlns="http://www.w3.org/1999/xhtml"><head><title>t</title></head><body>{body}</body></html>"#
+        )
+    }
+
+    #[test]
+    fn test_parse_epub2_with_ncx() {
+        let opf = r#"<package xmlns="http://www.idpf.org/2007/opf" version="2.0">
+  <metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+    <dc:title>三体</dc:title>
+    <dc:creator>刘慈欣</dc:creator>
+    <dc:language>zh</dc:language>
+    <meta name="cover" content="cover-img"/>
+  </metadata>
+  <manifest>
+    <item id="ncx" href="toc.ncx" media-type="application/x-dtbncx+xml"/>
+    <item id="c1" href="Text/ch1.xhtml" media-type="application/xhtml+xml"/>
+    <item id="c2" href="Text/ch%202.xhtml" media-type="application/xhtml+xml"/>
+    <item id="notes" href="Text/notes.xhtml" media-type="application/xhtml+xml"/>
+    <item id="cover-img" href="Images/cover.jpg" media-type="image/jpeg"/>
+  </manifest>
+  <spine toc="ncx">
+    <itemref idref="c1"/>
+    <itemref idref="notes" linear="no"/>
+    <itemref idref="c2"/>
+  </spine>
+</package>"#;
+        let ncx = r#"<ncx xmlns="http://www.daisy.org/z3986/2005/ncx/"><navMap>
+  <navPoint id="p1"><navLabel><text>第一章</text></navLabel><content src="Text/ch1.xhtml"/>
+    <navPoint id="p1a"><navLabel><text>第一节</text></navLabel><content src="Text/ch1.xhtml#s1"/></navPoint>
+  </navPoint>
+  <navPoint id="p2"><navLabel><text>第二章</text></navLabel><content src="Text/ch%202.xhtml"/></navPoint>
+</navMap></ncx>"#;
+        let ch1 = chapter("<h1>Heading One</h1><p>一</p>");
+        let ch2 = chapter("<h2>Heading Two</h2><p>二</p>");
+        let mut archive = epub(&[
+            (CONTAINER_PATH, CONTAINER),
+            ("OEBPS/content.opf", opf),
+            ("OEBPS/toc.ncx", ncx),
+            ("OEBPS/Text/ch1.xhtml", &ch1),
+            ("OEBPS/Text/ch 2.xhtml", &ch2),
+            ("OEBPS/Text/notes.xhtml", &chapter("<p>notes</p>")),
+        ]);
+
+        let book = parse(&mut archive).unwrap();
+        assert_eq!(book.metadata.title.as_deref(), Some("三体"));
+        assert_eq!(book.metadata.authors, vec!["刘慈欣"]);
+        assert_eq!(book.metadata.language.as_deref(), Some("zh"));
+        assert_eq!(
+            book.metadata.cover_href.as_deref(),
+            Some("OEBPS/Images/cover.jpg")
+        );
+
+        assert_eq!(book.chapters.len(), 2);
+        assert_eq!(book.chapters[0].href, "OEBPS/Text/ch1.xhtml");
+        assert_eq!(book.chapters[0].title.as_deref(), Some("第一章"));
+        assert_eq!(book.chapters[0].html, "<h1>Heading One</h1><p>一</p>");
+        assert_eq!(book.chapters[1].href, "OEBPS/Text/ch 2.xhtml");
+
+        let toc: Vec<_> = book
+            .toc
+            .iter()
+            .map(|e| (e.title.as_str(), e.chapter_index, e.depth))
+            .collect();
+        assert_eq!(
+            toc,
+            vec![
+                ("第一章", Some(0), 0),
+                ("第一节", Some(0), 1),
+                ("第二章", Some(1), 0)
+            ]
+        );
+        assert_eq!(book.toc[1].href, "OEBPS/Text/ch1.xhtml#s1");
+    }
+
+    #[test]
+    fn test_parse_epub3_with_nav_document() {
+        let opf = r#"<package xmlns="http://www.idpf.org/2007/opf" version="3.0">
+  <metadata xmlns:dc="http://purl.org/dc/elements/1.1/"><dc:title>Book</dc:title></metadata>
+  <manifest>
+    <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+    <item id="c1" href="c1.xhtml" media-type="application/xhtml+xml"/>
+    <item id="cover" href="cover.png" media-type="image/png" properties="cover-image"/>
+  </manifest>
+  <spine><itemref idref="c1"/></spine>
+</package>"#;
+        let nav = r#"<html xmlns:epub="http://www.idpf.org/2007/ops"><body>
+  <nav epub:type="landmarks"><ol><li><a href="c1.xhtml">Start</a></li></ol></nav>
+  <nav epub:type="toc"><ol>
+    <li><a href="c1.xhtml">Part <b>One</b></a>
+      <ol><li><a href="c1.xhtml#x">Scene</a></li></ol>
+    </li>
+  </ol></nav>
+</body></html>"#;
+        let c1 = chapter("<h1>Untitled</h1>");
+        let mut archive = epub(&[
+            (CONTAINER_PATH, CONTAINER),
+            ("OEBPS/content.opf", opf),
+            ("OEBPS/nav.xhtml", nav),
+            ("OEBPS/c1.xhtml", &c1),
+        ]);
+
+        let book = parse(&mut archive).unwrap();
+        assert_eq!(book.metadata.cover_href.as_deref(), Some("OEBPS/cover.png"));
+        let toc: Vec<_> = book
+            .toc
+            .iter()
+            .map(|e| (e.title.as_str(), e.depth))
+            .collect();
+        assert_eq!(toc, vec![("Part One", 0), ("Scene", 1)]);
+        assert_eq!(book.chapters[0].title.as_deref(), Some("Part One"));
+    }
+
+    #[test]
+    fn test_parse_falls_back_to_heading_titles() {
+        let opf = r#"<package><metadata/><manifest>
+    <item id="c1" href="c1.xhtml" media-type="application/xhtml+xml"/>
+  </manifest><spine><itemref idref="c1"/></spine></package>"#;
+        let c1 = chapter("<h2> Chapter\n 1 </h2><p>x</p>");
+        let mut archive = epub(&[
+            (CONTAINER_PATH, CONTAINER),
+            ("OEBPS/content.opf", opf),
+            ("OEBPS/c1.xhtml", &c1),
+        ]);
+
+        let book = parse(&mut archive).unwrap();
+        assert!(book.toc.is_empty());
+        assert_eq!(book.chapters[0].title.as_deref(), Some("Chapter 1"));
+    }
+
+    const RESOURCE_OPF: &str = r#"<package unique-identifier="bookid">
+  <metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+    <dc:identifier id="isbn">978-7-5366-9293-0</dc:identifier>
+    <dc:identifier id="bookid">urn:uuid:0b5b8c2e-1a4d-4f5e-9c3b-2f6d7e8a9b0c</dc:identifier>
+  </metadata>
+  <manifest>
+    <item id="c1" href="c1.xhtml" media-type="application/xhtml+xml"/>
+    <item id="css" href="style.css" media-type="text/css"/>
+    <item id="img" href="Images/a.png" media-type="image/png"/>
+    <item id="font" href="Fonts/kai.woff2" media-type="font/woff2"/>
+  </manifest>
+  <spine><itemref idref="c1"/></spine>
+</package>"#;
+
+    fn obfuscation_xml(algorithm: &str) -> String {
+        format!(
+            r#"<encryption xmlns="urn:oasis:names:tc:opendocument:xmlns:container"
+    xmlns:enc="http://www.w3.org/2001/04/xmlenc#">
+  <enc:EncryptedData>
+    <enc:EncryptionMethod Algorithm="{algorithm}"/>
+    <enc:CipherData><enc:CipherReference URI="OEBPS/Fonts/kai.woff2"/></enc:CipherData>
+  </enc:EncryptedData>
+</encryption>"#
+        )
+    }
+
+    fn resource_epub(font: &[u8], encryption: Option<&str>) -> ZipArchive<Cursor<Vec<u8>>> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        let mut add = |name: &str, data: &[u8]| {
+            writer
+                .start_file(name, SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(data).unwrap();
+        };
+        add(CONTAINER_PATH, CONTAINER.as_bytes());
+        add("OEBPS/content.opf", RESOURCE_OPF.as_bytes());
+        add("OEBPS/c1.xhtml", chapter("<p/>").as_bytes());
+        add("OEBPS/style.css", b"p { margin: 0 }");
+        add("OEBPS/Images/a.png", b"\x89PNG");
+        add("OEBPS/Fonts/kai.woff2", font);
+        if let Some(encryption) = encryption {
+            add(ENCRYPTION_PATH, encryption.as_bytes());
+        }
+        let data = writer.finish().unwrap().into_inner();
+        open_archive(Cursor::new(data)).unwrap()
+    }
+
+    #[test]
+    fn test_list_resources_classifies_manifest_items() {
+        let mut archive = resource_epub(b"wOF2", None);
+        let resources = list_resources(&mut archive).unwrap();
+        let listed: Vec<_> = resources
+            .iter()
+            .map(|r| (r.href.as_str(), r.kind, r.size))
+            .collect();
+        assert_eq!(
+            listed,
+            vec![
+                ("OEBPS/Fonts/kai.woff2", ResourceKind::Font, 4),
+                ("OEBPS/Images/a.png", ResourceKind::Image, 4),
+                ("OEBPS/style.css", ResourceKind::Stylesheet, 15),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_converts_idpf_obfuscated_woff2() {
+        let ttf = FontBuilder::with_chars("楷").build();
+        let woff2 = font_converter::compress_ttf_to_woff2(ttf).unwrap();
+        let identifiers = vec!["urn:uuid:0b5b8c2e-1a4d-4f5e-9c3b-2f6d7e8a9b0c".to_string()];
+        let mut stored = woff2.clone();
+        deobfuscate(&mut stored, IDPF_OBFUSCATION, &identifiers).unwrap();
+        let encryption = obfuscation_xml(IDPF_OBFUSCATION);
+        let mut archive = resource_epub(&stored, Some(&encryption));
+
+        let extracted = extract_resource(&mut archive, "OEBPS/Fonts/kai.woff2").unwrap();
+        assert_eq!(
+            extracted,
+            font_converter::convert_woff2_to_ttf(woff2).unwrap()
+        );
+        assert_eq!(
+            extract_resource(&mut archive, "OEBPS/Images/a.png").unwrap(),
+            b"\x89PNG"
+        );
+    }
+
+    #[test]
+    fn test_adobe_obfuscation_round_trips() {
+        let identifiers = vec!["urn:uuid:0b5b8c2e-1a4d-4f5e-9c3b-2f6d7e8a9b0c".to_string()];
+        let original: Vec<u8> = (0..2000).map(|i| i as u8).collect();
+        let mut data = original.clone();
+
+        deobfuscate(&mut data, ADOBE_OBFUSCATION, &identifiers).unwrap();
+        assert_ne!(data[..1024], original[..1024]);
+        assert_eq!(data[1024..], original[1024..]);
+        deobfuscate(&mut data, ADOBE_OBFUSCATION, &identifiers).unwrap();
+        assert_eq!(data, original);
+    }
+
+    #[test]
+    fn test_extract_rejects_encrypted_resources() {
+        let encryption = obfuscation_xml("http://www.w3.org/2001/04/xmlenc#aes128-cbc");
+        let mut archive = resource_epub(b"wOF2", Some(&encryption));
+        assert!(extract_resource(&mut archive, "OEBPS/Fonts/kai.woff2").is_err());
+    }
+
+    #[test]
+    fn test_styled_chapter_layouts() {
+        let mut archive = epub(&[
+            (
+                "OEBPS/Text/ch1.xhtml",
+                r#"<html><head><link rel="stylesheet" href="../Styles/book.css"/>
+<style>.note { color: #999; font-style: italic }</style></head>
+<body><p class="first" style="background:#fff;text-indent:2em">你好</p></body></html>"#,
+            ),
+            (
+                "OEBPS/Styles/book.css",
+                "@import \"base.css\";\nbody { background: url(../Images/paper.jpg) }",
+            ),
+            (
+                "OEBPS/Styles/base.css",
+                "@import \"book.css\";\np { font-size: 16px; text-indent: 2em }",
+            ),
+        ]);
+
+        let original =
+            styled_chapter(&mut archive, "OEBPS/Text/ch1.xhtml", EpubLayout::Original).unwrap();
+        assert_eq!(
+            original.html,
+            "<p class=\"first\" style=\"background: #fff; text-indent: 2em\">你好</p>"
+        );
+        assert_eq!(
+            original.css,
+            "p { font-size: 16px; text-indent: 2em }\n\
+             body { background: url(\"OEBPS/Images/paper.jpg\") }\n\
+             .note { color: #999; font-style: italic }\n"
+        );
+
+        let cleaned =
+            styled_chapter(&mut archive, "OEBPS/Text/ch1.xhtml#p1", EpubLayout::Cleaned).unwrap();
+        assert_eq!(
+            cleaned.html,
+            "<p class=\"first\" style=\"text-indent: 2em\">你好</p>"
+        );
+        assert_eq!(
+            cleaned.css,
+            "p { text-indent: 2em }\n.note { font-style: italic }\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_missing_container() {
+        let mut archive = epub(&[("mimetype", "application/epub+zip")]);
+        assert!(parse(&mut archive).is_err());
+    }
+}
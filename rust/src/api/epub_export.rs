@@ -0,0 +1,530 @@
+//! EPUB 3 export of downloaded books.
+//!
+//! Chapters arrive as plain text (one paragraph per line) and are written as
+//! one XHTML document each, alongside the package document, a navigation
+//! document and an optional cover. An embedded font is subset to the
+//! characters the book actually uses, so archiving a CJK novel does not
+//! carry a full 10 MB font.
+
+use std::io::{Cursor, Write};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Result};
+use quick_xml::escape::escape;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use crate::api::book::BookMetadata;
+use crate::api::error::ApiError;
+use crate::api::{font_converter, font_subset, task};
+use crate::frb_generated::StreamSink;
+
+/// Font family name used for the embedded font in the stylesheet.
+const FONT_FAMILY: &str = "NovellaEmbedded";
+
+/// One chapter of the book to export.
+#[derive(Debug, Clone)]
+pub struct EpubExportChapter {
+    pub title: String,
+    /// Chapter text with one paragraph per line.
+    pub text: String,
+}
+
+/// Reported after each chapter is written.
+#[derive(Debug, Clone)]
+pub struct EpubExportProgress {
+    pub written_chapters: u32,
+    pub total_chapters: u32,
+}
+
+/// Write `chapters` as an EPUB 3 file.
+///
+/// # Arguments
+/// * `metadata` - Title, authors and other package metadata; `cover_href` is ignored
+/// * `chapters` - Chapters in reading order
+/// * `cover` - Cover image bytes (JPEG, PNG, GIF or WebP)
+/// * `font` - TrueType font to embed; it is subset to the book's text
+/// * `output_path` - Destination file; the parent directory must exist
+/// * `sink` - Receives progress after each chapter; closes when the file is written
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+#[flutter_rust_bridge::frb]
+pub async fn export_epub(
+    metadata: BookMetadata,
+    chapters: Vec<EpubExportChapter>,
+    cover: Option<Vec<u8>>,
+    font: Option<Vec<u8>>,
+    output_path: String,
+    sink: StreamSink<EpubExportProgress>,
+    cancel_token: Option<u32>,
+) -> Result<(), ApiError> {
+    task::run_blocking(task::cancel_token(cancel_token)?, move |token| {
+        let epub = build(
+            &metadata,
+            &chapters,
+            cover.as_deref(),
+            font.as_deref(),
+            |progress| {
+                token.check()?;
+                sink.add(progress)
+                    .map_err(|_| anyhow!("EPUB export progress stream was closed"))
+            },
+        )?;
+        token.check()?;
+        font_converter::write_atomically(Path::new(&output_path), &epub)
+    })
+    .await
+}
+
+fn build(
+    metadata: &BookMetadata,
+    chapters: &[EpubExportChapter],
+    cover: Option<&[u8]>,
+    font: Option<&[u8]>,
+    mut progress: impl FnMut(EpubExportProgress) -> Result<()>,
+) -> Result<Vec<u8>> {
+    if chapters.is_empty() {
+        return Err(anyhow!("Cannot export a book without chapters"));
+    }
+    let title = metadata
+        .title
+        .clone()
+        .unwrap_or_else(|| "Untitled".to_string());
+    let language = metadata
+        .language
+        .clone()
+        .unwrap_or_else(|| "und".to_string());
+    let cover = cover
+        .map(|data| {
+            cover_type(data)
+                .map(|(extension, media_type)| (data, extension, media_type))
+                .ok_or_else(|| anyhow!("Unsupported cover image format"))
+        })
+        .transpose()?;
+    let font = font
+        .map(|data| font_subset::subset_font(data.to_vec(), book_text(&title, chapters)))
+        .transpose()?;
+
+    let mut package = Package::new(&language);
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let stored = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+    let deflated = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    let add = |zip: &mut ZipWriter<Cursor<Vec<u8>>>, name: &str, data: &[u8]| {
+        zip.start_file(name, deflated)
+            .and_then(|()| zip.write_all(data).map_err(Into::into))
+            .map_err(|e| anyhow!("Failed to write {name}: {e}"))
+    };
+
+    // The mimetype must be the first entry and stored uncompressed.
+    zip.start_file("mimetype", stored)
+        .and_then(|()| zip.write_all(b"application/epub+zip").map_err(Into::into))
+        .map_err(|e| anyhow!("Failed to write mimetype: {e}"))?;
+    add(&mut zip, "META-INF/container.xml", CONTAINER.as_bytes())?;
+    add(
+        &mut zip,
+        "OEBPS/style.css",
+        stylesheet(font.is_some()).as_bytes(),
+    )?;
+    package.item("style", "style.css", "text/css", None);
+
+    if let Some(font) = &font {
+        add(&mut zip, "OEBPS/fonts/embedded.ttf", font)?;
+        package.item("font", "fonts/embedded.ttf", "font/ttf", None);
+    }
+    if let Some((data, extension, media_type)) = cover {
+        let href = format!("images/cover.{extension}");
+        add(&mut zip, &format!("OEBPS/{href}"), data)?;
+        package.item("cover-image", &href, media_type, Some("cover-image"));
+        let page = package.document(
+            "Cover",
+            &format!(
+                "<div class=\"cover\"><img src=\"{href}\" alt=\"{}\"/></div>",
+                escape(title.as_str())
+            ),
+            "",
+        );
+        add(&mut zip, "OEBPS/cover.xhtml", page.as_bytes())?;
+        package.item("cover", "cover.xhtml", "application/xhtml+xml", None);
+        package.spine.push("cover".to_string());
+    }
+
+    let total_chapters = chapters.len() as u32;
+    for (i, chapter) in chapters.iter().enumerate() {
+        let id = format!("chapter{:04}", i + 1);
+        let href = format!("text/{id}.xhtml");
+        let page = package.document(&chapter.title, &chapter_body(chapter), "../");
+        add(&mut zip, &format!("OEBPS/{href}"), page.as_bytes())?;
+        package.item(&id, &href, "application/xhtml+xml", None);
+        package.spine.push(id);
+        package.toc.push((chapter.title.clone(), href));
+        progress(EpubExportProgress {
+            written_chapters: i as u32 + 1,
+            total_chapters,
+        })?;
+    }
+
+    let nav = package.document("Contents", &nav_body(&package.toc), "");
+    add(&mut zip, "OEBPS/nav.xhtml", nav.as_bytes())?;
+    package.item("nav", "nav.xhtml", "application/xhtml+xml", Some("nav"));
+    add(
+        &mut zip,
+        "OEBPS/content.opf",
+        package.opf(metadata, &title).as_bytes(),
+    )?;
+
+    let cursor = zip
+        .finish()
+        .map_err(|e| anyhow!("Failed to finish EPUB: {e}"))?;
+    Ok(cursor.into_inner())
+}
+
+pub(crate) const CONTAINER: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+  <rootfiles>
+    <rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/>
+  </rootfiles>
+</container>
+"#;
+
+fn stylesheet(has_font: bool) -> String {
+    let mut css = String::new();
+    if has_font {
+        css.push_str(&format!(
+            "@font-face {{ font-family: \"{FONT_FAMILY}\"; src: url(\"fonts/embedded.ttf\"); }}\n\
+             body {{ font-family: \"{FONT_FAMILY}\", serif; }}\n"
+        ));
+    }
+    css.push_str(
+        "p { text-indent: 2em; margin: 0 0 0.5em 0; }\n\
+         h2 { text-align: center; margin: 1em 0; }\n\
+         .cover { text-align: center; }\n\
+         .cover img { max-width: 100%; max-height: 100%; }\n",
+    );
+    css
+}
+
+/// Every character that will be rendered in the embedded font.
+fn book_text(title: &str, chapters: &[EpubExportChapter]) -> String {
+    let mut text = title.to_string();
+    for chapter in chapters {
+        text.push_str(&chapter.title);
+        text.push_str(&chapter.text);
+    }
+    text
+}
+
+fn chapter_body(chapter: &EpubExportChapter) -> String {
+    let mut body = format!("<h2>{}</h2>\n", escape(chapter.title.as_str()));
+    let mut lines = chapter
+        .text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .peekable();
+    // Downloaded chapter text often repeats the title as its first line.
+    if lines.peek() == Some(&chapter.title.trim()) {
+        lines.next();
+    }
+    for line in lines {
+        body.push_str(&format!("<p>{}</p>\n", escape(line)));
+    }
+    body
+}
+
+fn nav_body(toc: &[(String, String)]) -> String {
+    let mut body = String::from("<nav epub:type=\"toc\" id=\"toc\">\n<h2>Contents</h2>\n<ol>\n");
+    for (title, href) in toc {
+        body.push_str(&format!(
+            "<li><a href=\"{}\">{}</a></li>\n",
+            escape(href.as_str()),
+            escape(title.as_str())
+        ));
+    }
+    body.push_str("</ol>\n</nav>\n");
+    body
+}
+
+/// Extension and media type of a cover image, from its signature.
+fn cover_type(data: &[u8]) -> Option<(&'static str, &'static str)> {
+    if data.starts_with(b"\xFF\xD8\xFF") {
+        Some(("jpg", "image/jpeg"))
+    } else if data.starts_with(b"\x89PNG\r\n\x1A\n") {
+        Some(("png", "image/png"))
+    } else if data.starts_with(b"GIF8") {
+        Some(("gif", "image/gif"))
+    } else if data.len() >= 12 && &data[..4] == b"RIFF" && &data[8..12] == b"WEBP" {
+        Some(("webp", "image/webp"))
+    } else {
+        None
+    }
+}
+
+/// Manifest, spine and TOC collected while writing the content documents.
+pub(crate) struct Package {
+    language: String,
+    manifest: Vec<String>,
+    pub(crate) spine: Vec<String>,
+    toc: Vec<(String, String)>,
+}
+
+impl Package {
+    pub(crate) fn new(language: &str) -> Self {
+        Self {
+            language: language.to_string(),
+            manifest: Vec::new(),
+            spine: Vec::new(),
+            toc: Vec::new(),
+        }
+    }
+
+    pub(crate) fn item(
+        &mut self,
+        id: &str,
+        href: &str,
+        media_type: &str,
+        properties: Option<&str>,
+    ) {
+        let properties = properties
+            .map(|properties| format!(" properties=\"{properties}\""))
+            .unwrap_or_default();
+        self.manifest.push(format!(
+            "<item id=\"{id}\" href=\"{}\" media-type=\"{media_type}\"{properties}/>",
+            escape(href)
+        ));
+    }
+
+    /// An XHTML content document; `root` is the relative path back to the
+    /// OEBPS directory.
+    fn document(&self, title: &str, body: &str, root: &str) -> String {
+        let language = escape(self.language.as_str());
+        format!(
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n\
+             <!DOCTYPE html>\n\
+             <html xmlns=\"http://www.w3.org/1999/xhtml\" xmlns:epub=\"http://www.idpf.org/2007/ops\" \
+             xml:lang=\"{language}\" lang=\"{language}\">\n\
+             <head>\n<meta charset=\"utf-8\"/>\n<title>{}</title>\n\
+             <link rel=\"stylesheet\" type=\"text/css\" href=\"{root}style.css\"/>\n</head>\n\
+             <body>\n{body}</body>\n</html>\n",
+            escape(title)
+        )
+    }
+
+    pub(crate) fn opf(&self, metadata: &BookMetadata, title: &str) -> String {
+        let identifier = metadata
+            .identifier
+            .clone()
+            .unwrap_or_else(|| generated_identifier(title, &metadata.authors));
+        let mut dc = vec![
+            format!(
+                "<dc:identifier id=\"book-id\">{}</dc:identifier>",
+                escape(identifier.as_str())
+            ),
+            format!("<dc:title>{}</dc:title>", escape(title)),
+            format!(
+                "<dc:language>{}</dc:language>",
+                escape(self.language.as_str())
+            ),
+        ];
+        for author in &metadata.authors {
+            dc.push(format!(
+                "<dc:creator>{}</dc:creator>",
+                escape(author.as_str())
+            ));
+        }
+        if let Some(publisher) = &metadata.publisher {
+            dc.push(format!(
+                "<dc:publisher>{}</dc:publisher>",
+                escape(publisher.as_str())
+            ));
+        }
+        if let Some(description) = &metadata.description {
+            dc.push(format!(
+                "<dc:description>{}</dc:description>",
+                escape(description.as_str())
+            ));
+        }
+        dc.push(format!(
+            "<meta property=\"dcterms:modified\">{}</meta>",
+            timestamp(SystemTime::now())
+        ));
+        if self
+            .manifest
+            .iter()
+            .any(|item| item.contains("id=\"cover-image\""))
+        {
+            dc.push("<meta name=\"cover\" content=\"cover-image\"/>".to_string());
+        }
+
+        let spine: Vec<String> = self
+            .spine
+            .iter()
+            .map(|id| format!("<itemref idref=\"{id}\"/>"))
+            .collect();
+        format!(
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n\
+             <package xmlns=\"http://www.idpf.org/2007/opf\" version=\"3.0\" unique-identifier=\"book-id\">\n\
+             <metadata xmlns:dc=\"http://purl.org/dc/elements/1.1/\">\n{}\n</metadata>\n\
+             <manifest>\n{}\n</manifest>\n\
+             <spine>\n{}\n</spine>\n\
+             </package>\n",
+            dc.join("\n"),
+            self.manifest.join("\n"),
+            spine.join("\n")
+        )
+    }
+}
+
+/// A stable `urn:uuid:` identifier derived from the title and authors, so
+/// re-exporting a book keeps its identity in other readers.
+pub(crate) fn generated_identifier(title: &str, authors: &[String]) -> String {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(title.as_bytes());
+    for author in authors {
+        hasher.update(b"\0");
+        hasher.update(author.as_bytes());
+    }
+    let mut bytes = [0; 16];
+    bytes.copy_from_slice(&hasher.finalize().as_bytes()[..16]);
+    // Version 8 (custom) UUID with the RFC 4122 variant.
+    bytes[6] = (bytes[6] & 0x0F) | 0x80;
+    bytes[8] = (bytes[8] & 0x3F) | 0x80;
+    let hex: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+    format!(
+        "urn:uuid:{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+/// Format a time as `YYYY-MM-DDThh:mm:ssZ`.
+pub(crate) fn timestamp(time: SystemTime) -> String {
+    let secs = time.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    let (days, rem) = (secs / 86_400, secs % 86_400);
+
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm).
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::epub;
+    use crate::test_fonts::FontBuilder;
+    use std::io::Read;
+    use std::time::Duration;
+
+    fn chapters() -> Vec<EpubExportChapter> {
+        vec![
+            EpubExportChapter {
+                title: "第一章 开始".to_string(),
+                text: "第一章 开始\n\n　　他推开门。\n外面 <下雨> 了。\n".to_string(),
+            },
+            EpubExportChapter {
+                title: "第二章 结束".to_string(),
+                text: "雨停了。".to_string(),
+            },
+        ]
+    }
+
+    fn metadata() -> BookMetadata {
+        let mut metadata = BookMetadata::empty();
+        metadata.title = Some("雨夜".to_string());
+        metadata.authors = vec!["某人".to_string()];
+        metadata.language = Some("zh".to_string());
+        metadata
+    }
+
+    #[test]
+    fn test_export_round_trips_through_epub_parser() {
+        let png = b"\x89PNG\r\n\x1A\n rest of image".to_vec();
+        let mut reports = Vec::new();
+        let data = build(&metadata(), &chapters(), Some(&png), None, |progress| {
+            reports.push((progress.written_chapters, progress.total_chapters));
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(reports, vec![(1, 2), (2, 2)]);
+
+        let mut archive = epub::open_archive(Cursor::new(data)).unwrap();
+        let mut mimetype = String::new();
+        archive
+            .by_index(0)
+            .unwrap()
+            .read_to_string(&mut mimetype)
+            .unwrap();
+        assert_eq!(mimetype, "application/epub+zip");
+
+        let book = epub::parse(&mut archive).unwrap();
+        assert_eq!(book.metadata.title.as_deref(), Some("雨夜"));
+        assert_eq!(book.metadata.authors, vec!["某人"]);
+        assert_eq!(
+            book.metadata.cover_href.as_deref(),
+            Some("OEBPS/images/cover.png")
+        );
+        assert!(book.metadata.identifier.unwrap().starts_with("urn:uuid:"));
+
+        let titles: Vec<_> = book.toc.iter().map(|e| e.title.as_str()).collect();
+        assert_eq!(titles, vec!["第一章 开始", "第二章 结束"]);
+        assert_eq!(book.chapters.len(), 3);
+        assert_eq!(
+            book.chapters[1].html,
+            "<h2>第一章 开始</h2>\n<p>他推开门。</p>\n<p>外面 &lt;下雨&gt; 了。</p>"
+        );
+        assert_eq!(book.chapters[2].title.as_deref(), Some("第二章 结束"));
+    }
+
+    #[test]
+    fn test_export_embeds_subset_font() {
+        let font = FontBuilder::with_chars("AB雨夜开始").build();
+        let data = build(&metadata(), &chapters(), None, Some(&font), |_| Ok(())).unwrap();
+
+        let mut archive = epub::open_archive(Cursor::new(data)).unwrap();
+        let css = epub::read_entry(&mut archive, "OEBPS/style.css").unwrap();
+        assert!(String::from_utf8(css).unwrap().contains("@font-face"));
+        let subset = epub::read_entry(&mut archive, "OEBPS/fonts/embedded.ttf").unwrap();
+        let face = ttf_parser::Face::parse(&subset, 0).unwrap();
+        assert!(face.glyph_index('雨').is_some());
+        assert!(face.glyph_index('B').is_some());
+        assert!(face.number_of_glyphs() < 10);
+    }
+
+    #[test]
+    fn test_export_rejects_invalid_input() {
+        assert!(build(&metadata(), &[], None, None, |_| Ok(())).is_err());
+        assert!(build(&metadata(), &chapters(), Some(b"BM??"), None, |_| Ok(())).is_err());
+        let stopped = build(&metadata(), &chapters(), None, None, |_| {
+            Err(anyhow!("closed"))
+        });
+        assert!(stopped.is_err());
+    }
+
+    #[test]
+    fn test_timestamp_and_identifier() {
+        let time = UNIX_EPOCH + Duration::from_secs(1_709_251_199);
+        assert_eq!(timestamp(time), "2024-02-29T23:59:59Z");
+        assert_eq!(timestamp(UNIX_EPOCH), "1970-01-01T00:00:00Z");
+
+        let id = generated_identifier("雨夜", &["某人".to_string()]);
+        assert_eq!(id, generated_identifier("雨夜", &["某人".to_string()]));
+        assert_ne!(id, generated_identifier("雨夜", &[]));
+        assert_eq!(id.len(), "urn:uuid:".len() + 36);
+    }
+}
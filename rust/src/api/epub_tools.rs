@@ -0,0 +1,830 @@
+//! Splitting and merging EPUB files.
+//!
+//! Serialized novels are often sold as dozens of tiny EPUBs, one per
+//! volume or even per chapter, while omnibus editions can run to thousands
+//! of chapters that readers choke on. `merge_epubs` joins books into one
+//! with a combined table of contents, and `split_epub` cuts a book into
+//! volumes at TOC entries or by size.
+//!
+//! Both copy the publisher's content documents, stylesheets, fonts and
+//! images as they are and only write a new package and navigation
+//! document, so the result looks like the originals. Entries keep their
+//! paths below the directory the source's manifest lives in, which keeps
+//! relative links between them working; merged books each get their own
+//! `partNNN/` directory so their files cannot collide. Obfuscated fonts are
+//! stored restored, as the obfuscation key is the source's identifier.
+
+use std::collections::{HashMap, HashSet};
+use std::io::{Cursor, Read, Seek, Write};
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use quick_xml::escape::escape;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::api::book::{BookMetadata, TocEntry};
+use crate::api::epub::{self, ManifestItem, Package};
+use crate::api::epub_export::{self, CONTAINER};
+use crate::api::error::ApiError;
+use crate::api::{drm, font_converter, task};
+use crate::xhtml;
+
+/// Manifest id and href of the navigation document written for the result.
+const NAV_ID: &str = "novella-nav";
+const NAV_HREF: &str = "novella-nav.xhtml";
+
+/// Where `split_epub` starts a new volume. With both limits set, a volume
+/// ends at whichever comes first.
+#[derive(Debug, Clone)]
+pub struct EpubSplitOptions {
+    /// Start a volume at each TOC entry this deep or shallower, 0 for
+    /// top-level entries only.
+    pub toc_depth: Option<u32>,
+    /// Start a volume before a chapter that would take the volume's content
+    /// past this many bytes, uncompressed. A volume always has at least one
+    /// chapter, however large.
+    pub max_volume_bytes: Option<u64>,
+}
+
+/// One file written by `split_epub`.
+#[derive(Debug, Clone)]
+pub struct EpubVolume {
+    pub path: String,
+    pub title: String,
+    /// Content documents in the volume's spine.
+    pub chapter_count: u32,
+}
+
+/// One book to merge.
+#[derive(Debug, Clone)]
+pub struct EpubMergeSource {
+    pub path: String,
+    /// Title of the TOC entry holding the book's own contents; defaults to
+    /// the book's title.
+    pub title: Option<String>,
+}
+
+/// Split an EPUB into volumes, written as `<name>-01.epub`, `<name>-02.epub`
+/// and so on. Content before the first TOC entry the book is split at, such
+/// as the cover and title page, goes into the first volume.
+///
+/// # Arguments
+/// * `path` - EPUB file to split
+/// * `options` - Where volumes start; at least one limit must be set
+/// * `output_dir` - Existing directory for the volumes; files of the same name are replaced
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+#[flutter_rust_bridge::frb]
+pub async fn split_epub(
+    path: String,
+    options: EpubSplitOptions,
+    output_dir: String,
+    cancel_token: Option<u32>,
+) -> Result<Vec<EpubVolume>, ApiError> {
+    task::run_blocking(task::cancel_token(cancel_token)?, move |token| {
+        let mut source = Source::open(epub::open_file(&path)?)?;
+        let stem = Path::new(&path)
+            .file_stem()
+            .map_or_else(|| "book".into(), |stem| stem.to_string_lossy());
+        let mut volumes = Vec::new();
+        split(&mut source, &options, |title, chapter_count, data| {
+            token.check()?;
+            let path = Path::new(&output_dir).join(format!("{stem}-{:02}.epub", volumes.len() + 1));
+            font_converter::write_atomically(&path, &data)?;
+            volumes.push(EpubVolume {
+                path: path.to_string_lossy().into_owned(),
+                title,
+                chapter_count,
+            });
+            Ok(())
+        })?;
+        Ok(volumes)
+    })
+    .await
+}
+
+/// Merge EPUBs into one, in the given order. The combined table of
+/// contents has an entry per book with the book's own entries beneath it.
+/// Returns the number of content documents in the merged spine.
+///
+/// # Arguments
+/// * `sources` - Books to merge, in reading order
+/// * `metadata` - Metadata of the merged book; a missing title, language or author list is taken from the first book
+/// * `output_path` - Destination file; the parent directory must exist
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+#[flutter_rust_bridge::frb]
+pub async fn merge_epubs(
+    sources: Vec<EpubMergeSource>,
+    metadata: BookMetadata,
+    output_path: String,
+    cancel_token: Option<u32>,
+) -> Result<u32, ApiError> {
+    task::run_blocking(task::cancel_token(cancel_token)?, move |token| {
+        let mut books = Vec::with_capacity(sources.len());
+        for source in &sources {
+            token.check()?;
+            books.push((
+                Source::open(epub::open_file(&source.path)?)?,
+                source.title.clone(),
+            ));
+        }
+        let (epub, chapter_count) = merge(&mut books, &metadata, || token.check())?;
+        token.check()?;
+        font_converter::write_atomically(Path::new(&output_path), &epub)?;
+        Ok(chapter_count)
+    })
+    .await
+}
+
+/// Write each volume of `source` with its title and chapter count.
+fn split<R: Read + Seek>(
+    source: &mut Source<R>,
+    options: &EpubSplitOptions,
+    mut volume: impl FnMut(String, u32, Vec<u8>) -> Result<()>,
+) -> Result<()> {
+    if options.toc_depth.is_none() && options.max_volume_bytes.is_none() {
+        return Err(anyhow!("No split point given"));
+    }
+    let documents = source.documents();
+    if documents.is_empty() {
+        return Err(anyhow!("EPUB has no content documents"));
+    }
+    let spine: HashSet<&str> = documents.iter().map(|(id, _)| id.as_str()).collect();
+
+    // Volumes start at these documents, named by their first TOC entry.
+    let mut headings: HashMap<String, String> = HashMap::new();
+    if let Some(depth) = options.toc_depth {
+        for entry in source.toc.iter().filter(|entry| entry.depth <= depth) {
+            let (path, _) = xhtml::split_fragment(&entry.href);
+            headings
+                .entry(path.to_string())
+                .or_insert_with(|| entry.title.clone());
+        }
+    }
+
+    // Every volume has the stylesheets, fonts, cover and documents outside
+    // the spine; other images go with the chapters that show them.
+    let cover = source.package.metadata.cover_href.clone();
+    let mut shared = Vec::new();
+    let mut images = Vec::new();
+    for id in source.items() {
+        if spine.contains(id.as_str()) {
+            continue;
+        }
+        let item = &source.package.manifest[&id];
+        if item.media_type.starts_with("image/") && cover.as_deref() != Some(item.path.as_str()) {
+            images.push(id);
+        } else {
+            shared.push(id);
+        }
+    }
+    let mut shared_text = String::new();
+    for id in &shared {
+        let item = &source.package.manifest[id];
+        if item.media_type == "text/css" || item.is_document() {
+            shared_text.push_str(&xhtml::decode(&source.read(id)?));
+        }
+    }
+    let (mut shown, images): (Vec<String>, Vec<String>) = images
+        .into_iter()
+        .partition(|id| shows(&shared_text, &source.package.manifest[id]));
+    shared.append(&mut shown);
+    let mut shared_bytes = 0;
+    for id in &shared {
+        shared_bytes += source.size(id)?;
+    }
+
+    let mut plans: Vec<Volume> = Vec::new();
+    for (id, path) in &documents {
+        let text = xhtml::decode(&source.read(id)?);
+        let shows_images: Vec<&String> = images
+            .iter()
+            .filter(|image| shows(&text, &source.package.manifest[*image]))
+            .collect();
+        let heading = headings.get(path.as_str());
+        let mut bytes = text.len() as u64;
+        if let Some(plan) = plans.last() {
+            for image in shows_images
+                .iter()
+                .filter(|image| !plan.images.contains(**image))
+            {
+                bytes += source.size(image)?;
+            }
+        }
+
+        let start = match plans.last() {
+            None => true,
+            Some(plan) => {
+                heading.is_some() && plan.heading.is_some()
+                    || options
+                        .max_volume_bytes
+                        .is_some_and(|max| plan.bytes + bytes > max)
+            }
+        };
+        if start {
+            plans.push(Volume {
+                documents: Vec::new(),
+                images: HashSet::new(),
+                bytes: shared_bytes,
+                heading: None,
+            });
+            bytes = text.len() as u64;
+            for image in &shows_images {
+                bytes += source.size(image)?;
+            }
+        }
+        let plan = plans.last_mut().unwrap();
+        plan.documents.push(id.clone());
+        plan.images.extend(shows_images.into_iter().cloned());
+        plan.bytes += bytes;
+        if plan.heading.is_none() {
+            plan.heading = heading.cloned();
+        }
+    }
+
+    let metadata = source.package.metadata.clone();
+    let title = metadata
+        .title
+        .clone()
+        .unwrap_or_else(|| "Untitled".to_string());
+    let language = metadata
+        .language
+        .clone()
+        .unwrap_or_else(|| "und".to_string());
+    for (n, plan) in plans.iter().enumerate() {
+        let volume_title = match &plan.heading {
+            Some(heading) if plans.len() > 1 => format!("{title} - {heading}"),
+            _ => format!("{title} ({})", n + 1),
+        };
+        let mut writer = Writer::new(&language)?;
+        for id in shared.iter().chain(&plan.images).chain(&plan.documents) {
+            let item = &source.package.manifest[id];
+            let href = source.href(&item.path).to_string();
+            let spine = plan.documents.contains(id);
+            let cover = cover.as_deref() == Some(item.path.as_str());
+            writer.copy(source, id, id, &href, spine, cover)?;
+        }
+
+        let paths: HashSet<String> = plan
+            .documents
+            .iter()
+            .map(|id| source.package.manifest[id].path.clone())
+            .collect();
+        for entry in &source.toc {
+            let (path, _) = xhtml::split_fragment(&entry.href);
+            if paths.contains(path) {
+                writer.nav.push((
+                    entry.title.clone(),
+                    source.href(&entry.href).to_string(),
+                    entry.depth,
+                ));
+            }
+        }
+        if writer.nav.is_empty() {
+            let first = &source.package.manifest[&plan.documents[0]].path;
+            writer
+                .nav
+                .push((volume_title.clone(), source.href(first).to_string(), 0));
+        }
+
+        let metadata = BookMetadata {
+            title: Some(volume_title.clone()),
+            identifier: None,
+            ..metadata.clone()
+        };
+        volume(
+            volume_title.clone(),
+            plan.documents.len() as u32,
+            writer.finish(&metadata, &volume_title)?,
+        )?;
+    }
+    Ok(())
+}
+
+/// The merged book and the number of documents in its spine. `check` is
+/// called before each book is copied.
+fn merge<R: Read + Seek>(
+    books: &mut [(Source<R>, Option<String>)],
+    metadata: &BookMetadata,
+    mut check: impl FnMut() -> Result<()>,
+) -> Result<(Vec<u8>, u32)> {
+    let Some((first, _)) = books.first() else {
+        return Err(anyhow!("No EPUB files to merge"));
+    };
+    let first = &first.package.metadata;
+    let title = metadata
+        .title
+        .clone()
+        .or_else(|| first.title.clone())
+        .unwrap_or_else(|| "Untitled".to_string());
+    let language = metadata
+        .language
+        .clone()
+        .or_else(|| first.language.clone())
+        .unwrap_or_else(|| "und".to_string());
+    let mut metadata = BookMetadata {
+        title: Some(title.clone()),
+        language: Some(language.clone()),
+        ..metadata.clone()
+    };
+    if metadata.authors.is_empty() {
+        metadata.authors = first.authors.clone();
+    }
+
+    let mut writer = Writer::new(&language)?;
+    let mut has_cover = false;
+    for (n, (source, part_title)) in books.iter_mut().enumerate() {
+        check()?;
+        let part = format!("part{:03}/", n + 1);
+        let prefix = format!("p{:03}-", n + 1);
+        let spine: HashSet<String> = source.documents().into_iter().map(|(id, _)| id).collect();
+        let cover = source.package.metadata.cover_href.clone();
+        let start = writer.package.spine.len();
+        for id in source.items() {
+            let item = &source.package.manifest[&id];
+            let href = format!("{part}{}", source.href(&item.path));
+            let is_cover = !has_cover && cover.as_deref() == Some(item.path.as_str());
+            has_cover |= is_cover;
+            writer.copy(
+                source,
+                &id,
+                &format!("{prefix}{id}"),
+                &href,
+                false,
+                is_cover,
+            )?;
+        }
+        // The spine keeps the book's order, which the manifest does not.
+        for id in source.package.spine.iter().filter(|id| spine.contains(*id)) {
+            writer.package.spine.push(format!("{prefix}{id}"));
+        }
+        let Some(first_id) = writer.package.spine.get(start) else {
+            continue;
+        };
+        let first_path = &source.package.manifest[&first_id[prefix.len()..]].path;
+        writer.nav.push((
+            part_title
+                .clone()
+                .or_else(|| source.package.metadata.title.clone())
+                .unwrap_or_else(|| format!("{} {}", title, n + 1)),
+            format!("{part}{}", source.href(first_path)),
+            0,
+        ));
+        for entry in &source.toc {
+            writer.nav.push((
+                entry.title.clone(),
+                format!("{part}{}", source.href(&entry.href)),
+                entry.depth + 1,
+            ));
+        }
+    }
+    let chapter_count = writer.package.spine.len() as u32;
+    if chapter_count == 0 {
+        return Err(anyhow!("The EPUB files have no content documents"));
+    }
+    Ok((writer.finish(&metadata, &title)?, chapter_count))
+}
+
+/// A volume planned by `split`.
+#[flutter_rust_bridge::frb(ignore)]
+struct Volume {
+    /// Manifest ids of the volume's spine documents.
+    documents: Vec<String>,
+    /// Manifest ids of the images its documents show.
+    images: HashSet<String>,
+    /// Uncompressed size of its content.
+    bytes: u64,
+    /// Title of the first TOC entry it was split at.
+    heading: Option<String>,
+}
+
+/// A book being split or merged.
+#[flutter_rust_bridge::frb(ignore)]
+struct Source<R> {
+    archive: ZipArchive<R>,
+    package: Package,
+    toc: Vec<TocEntry>,
+    /// Deepest directory holding every manifest item, `""` for the root.
+    root: String,
+}
+
+impl<R: Read + Seek> Source<R> {
+    fn open(mut archive: ZipArchive<R>) -> Result<Self> {
+        if let Some(scheme) = drm::epub_scheme(&mut archive)? {
+            return Err(drm::protected_error(scheme));
+        }
+        let package = epub::read_package(&mut archive)?;
+        let toc = epub::read_toc(&mut archive, &package)?;
+        let mut paths = package.manifest.values().map(|item| item.path.as_str());
+        let mut root = paths.next().map_or("", xhtml::parent);
+        for path in paths {
+            while !root.is_empty() && !path.starts_with(&format!("{root}/")) {
+                root = xhtml::parent(root);
+            }
+        }
+        let root = root.to_string();
+        Ok(Self {
+            archive,
+            package,
+            toc,
+            root,
+        })
+    }
+
+    /// Ids and paths of the spine's content documents, in order.
+    fn documents(&self) -> Vec<(String, String)> {
+        let archive = &self.archive;
+        self.package
+            .spine
+            .iter()
+            .filter_map(|id| Some((id, self.package.manifest.get(id)?)))
+            .filter(|(_, item)| {
+                item.is_document() && epub::entry_name(archive, &item.path).is_some()
+            })
+            .map(|(id, item)| (id.clone(), item.path.clone()))
+            .collect()
+    }
+
+    /// Ids of the manifest items to copy, by path: everything the archive
+    /// has except the old navigation documents.
+    fn items(&self) -> Vec<String> {
+        let mut items: Vec<(&String, &ManifestItem)> = self
+            .package
+            .manifest
+            .iter()
+            .filter(|(_, item)| {
+                !item.has_property("nav") && item.media_type != epub::NCX_MEDIA_TYPE
+            })
+            .filter(|(_, item)| epub::entry_name(&self.archive, &item.path).is_some())
+            .collect();
+        items.sort_by(|a, b| a.1.path.cmp(&b.1.path));
+        items.into_iter().map(|(id, _)| id.clone()).collect()
+    }
+
+    /// `path` below `root`.
+    fn href<'a>(&self, path: &'a str) -> &'a str {
+        if self.root.is_empty() {
+            return path;
+        }
+        path.strip_prefix(self.root.as_str())
+            .and_then(|rest| rest.strip_prefix('/'))
+            .unwrap_or(path)
+    }
+
+    /// The manifest item `id`, with fonts restored.
+    fn read(&mut self, id: &str) -> Result<Vec<u8>> {
+        let path = &self.package.manifest[id].path;
+        epub::read_resource(&mut self.archive, path, &self.package)
+    }
+
+    /// Uncompressed size of the manifest item `id`.
+    fn size(&mut self, id: &str) -> Result<u64> {
+        let path = &self.package.manifest[id].path;
+        let name = epub::entry_name(&self.archive, path)
+            .ok_or_else(|| anyhow!("Missing EPUB entry {path}"))?;
+        let entry = self
+            .archive
+            .by_name(&name)
+            .map_err(|e| anyhow!("Failed to read EPUB entry {path}: {e}"))?;
+        Ok(entry.size())
+    }
+}
+
+/// Whether a document or stylesheet refers to an image, judged by its file
+/// name. Mentioning a file of the same name elsewhere only costs a copy.
+fn shows(text: &str, image: &ManifestItem) -> bool {
+    let name = image.path.rsplit('/').next().unwrap_or(&image.path);
+    !name.is_empty() && text.contains(name)
+}
+
+/// An EPUB assembled from the entries of others, with its package document
+/// in `OEBPS/`.
+#[flutter_rust_bridge::frb(ignore)]
+struct Writer {
+    zip: ZipWriter<Cursor<Vec<u8>>>,
+    package: epub_export::Package,
+    /// Title, href and depth of each navigation entry.
+    nav: Vec<(String, String, u32)>,
+    language: String,
+}
+
+impl Writer {
+    fn new(language: &str) -> Result<Self> {
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        let stored = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+        // The mimetype must be the first entry and stored uncompressed.
+        zip.start_file("mimetype", stored)
+            .and_then(|()| zip.write_all(b"application/epub+zip").map_err(Into::into))
+            .map_err(|e| anyhow!("Failed to write mimetype: {e}"))?;
+        let mut writer = Self {
+            zip,
+            package: epub_export::Package::new(language),
+            nav: Vec::new(),
+            language: language.to_string(),
+        };
+        writer.add("META-INF/container.xml", CONTAINER.as_bytes())?;
+        Ok(writer)
+    }
+
+    fn add(&mut self, name: &str, data: &[u8]) -> Result<()> {
+        let deflated = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+        self.zip
+            .start_file(name, deflated)
+            .and_then(|()| self.zip.write_all(data).map_err(Into::into))
+            .map_err(|e| anyhow!("Failed to write {name}: {e}"))
+    }
+
+    /// Copy the manifest item `source_id` of `source` to `OEBPS/<href>` as
+    /// `id`, adding it to the spine if `spine` is set.
+    fn copy<R: Read + Seek>(
+        &mut self,
+        source: &mut Source<R>,
+        source_id: &str,
+        id: &str,
+        href: &str,
+        spine: bool,
+        cover: bool,
+    ) -> Result<()> {
+        let data = source.read(source_id)?;
+        let item = &source.package.manifest[source_id];
+        self.add(&format!("OEBPS/{href}"), &data)?;
+        let mut properties: Vec<&str> = item
+            .properties
+            .split_whitespace()
+            .filter(|property| !matches!(*property, "nav" | "cover-image"))
+            .collect();
+        if cover {
+            properties.push("cover-image");
+        }
+        let properties = properties.join(" ");
+        let media_type = match item.media_type.as_str() {
+            "" => "application/xhtml+xml",
+            media_type => media_type,
+        };
+        self.package.item(
+            id,
+            href,
+            media_type,
+            Some(properties.as_str()).filter(|properties| !properties.is_empty()),
+        );
+        if spine {
+            self.package.spine.push(id.to_string());
+        }
+        Ok(())
+    }
+
+    /// Write the navigation and package documents and close the archive.
+    fn finish(mut self, metadata: &BookMetadata, title: &str) -> Result<Vec<u8>> {
+        let nav = nav_document(&self.language, &self.nav);
+        self.add(&format!("OEBPS/{NAV_HREF}"), nav.as_bytes())?;
+        self.package
+            .item(NAV_ID, NAV_HREF, "application/xhtml+xml", Some("nav"));
+        let opf = self.package.opf(metadata, title);
+        self.add("OEBPS/content.opf", opf.as_bytes())?;
+        let cursor = self
+            .zip
+            .finish()
+            .map_err(|e| anyhow!("Failed to finish EPUB: {e}"))?;
+        Ok(cursor.into_inner())
+    }
+}
+
+/// A navigation document listing `entries`, nested by depth. An entry can
+/// only be one level deeper than the one before it, so deeper entries are
+/// raised to that level.
+fn nav_document(language: &str, entries: &[(String, String, u32)]) -> String {
+    let mut list = String::new();
+    let mut depth = 0;
+    for (i, (title, href, entry_depth)) in entries.iter().enumerate() {
+        let level = if i == 0 {
+            0
+        } else {
+            (*entry_depth).min(depth + 1)
+        };
+        if i > 0 && level > depth {
+            list.push_str("\n<ol>\n");
+        } else if i > 0 {
+            list.push_str("</li>\n");
+            for _ in level..depth {
+                list.push_str("</ol>\n</li>\n");
+            }
+        }
+        depth = level;
+        list.push_str(&format!(
+            "<li><a href=\"{}\">{}</a>",
+            escape(href.as_str()),
+            escape(title.as_str())
+        ));
+    }
+    if !entries.is_empty() {
+        list.push_str("</li>\n");
+        for _ in 0..depth {
+            list.push_str("</ol>\n</li>\n");
+        }
+    }
+    let language = escape(language);
+    format!(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n\
+         <!DOCTYPE html>\n\
+         <html xmlns=\"http://www.w3.org/1999/xhtml\" xmlns:epub=\"http://www.idpf.org/2007/ops\" \
+         xml:lang=\"{language}\" lang=\"{language}\">\n\
+         <head>\n<meta charset=\"utf-8\"/>\n<title>Contents</title>\n</head>\n\
+         <body>\n<nav epub:type=\"toc\" id=\"toc\">\n<ol>\n{list}</ol>\n</nav>\n</body>\n</html>\n"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::book::ParsedBook;
+
+    /// A book with a cover, an image in its second chapter and two parts.
+    fn book() -> Vec<u8> {
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        let stored = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+        let mut add = |name: &str, data: &[u8]| {
+            zip.start_file(name, stored).unwrap();
+            zip.write_all(data).unwrap();
+        };
+        add("mimetype", b"application/epub+zip");
+        add(
+            "META-INF/container.xml",
+            br#"<container xmlns="urn:oasis:names:tc:opendocument:xmlns:container"><rootfiles>
+                <rootfile full-path="OPS/book.opf" media-type="application/oebps-package+xml"/>
+                </rootfiles></container>"#,
+        );
+        add(
+            "OPS/book.opf",
+            br#"<package xmlns="http://www.idpf.org/2007/opf" version="3.0" unique-identifier="id">
+                <metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+                  <dc:identifier id="id">book</dc:identifier><dc:title>Serial</dc:title>
+                  <dc:language>zh</dc:language><dc:creator>Author</dc:creator>
+                </metadata>
+                <manifest>
+                  <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+                  <item id="css" href="css/book.css" media-type="text/css"/>
+                  <item id="cover" href="img/cover.png" media-type="image/png" properties="cover-image"/>
+                  <item id="map" href="img/map.png" media-type="image/png"/>
+                  <item id="c1" href="text/c1.xhtml" media-type="application/xhtml+xml"/>
+                  <item id="c2" href="text/c2.xhtml" media-type="application/xhtml+xml"/>
+                  <item id="c3" href="text/c3.xhtml" media-type="application/xhtml+xml"/>
+                  <item id="c4" href="text/c4.xhtml" media-type="application/xhtml+xml"/>
+                </manifest>
+                <spine><itemref idref="c1"/><itemref idref="c2"/><itemref idref="c3"/><itemref idref="c4"/></spine>
+                </package>"#,
+        );
+        add(
+            "OPS/nav.xhtml",
+            br#"<html xmlns="http://www.w3.org/1999/xhtml" xmlns:epub="http://www.idpf.org/2007/ops"><body>
+                <nav epub:type="toc"><ol>
+                  <li><a href="text/c1.xhtml">Part 1</a><ol>
+                    <li><a href="text/c1.xhtml#s">One</a></li><li><a href="text/c2.xhtml">Two</a></li>
+                  </ol></li>
+                  <li><a href="text/c3.xhtml">Part 2</a><ol>
+                    <li><a href="text/c3.xhtml">Three</a></li><li><a href="text/c4.xhtml">Four</a></li>
+                  </ol></li>
+                </ol></nav></body></html>"#,
+        );
+        add("OPS/css/book.css", b"p { text-indent: 2em; }");
+        add("OPS/img/cover.png", b"\x89PNG cover");
+        add("OPS/img/map.png", &[0; 4096]);
+        for (n, body) in [
+            "<p id=\"s\">One</p>",
+            "<p>Two</p><img src=\"../img/map.png\"/>",
+            "<p>Three</p>",
+            "<p>Four</p>",
+        ]
+        .iter()
+        .enumerate()
+        {
+            let page = format!(
+                "<html xmlns=\"http://www.w3.org/1999/xhtml\"><head>\
+                 <link rel=\"stylesheet\" href=\"../css/book.css\"/></head><body>{body}</body></html>"
+            );
+            add(&format!("OPS/text/c{}.xhtml", n + 1), page.as_bytes());
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    fn source(data: Vec<u8>) -> Source<Cursor<Vec<u8>>> {
+        Source::open(epub::open_archive(Cursor::new(data)).unwrap()).unwrap()
+    }
+
+    fn parse(data: &[u8]) -> (ParsedBook, Vec<String>) {
+        let mut archive = epub::open_archive(Cursor::new(data.to_vec())).unwrap();
+        let book = epub::parse(&mut archive).unwrap();
+        let names = archive.file_names().map(str::to_string).collect();
+        (book, names)
+    }
+
+    fn split_volumes(options: EpubSplitOptions) -> Vec<(String, Vec<u8>)> {
+        let mut volumes = Vec::new();
+        split(&mut source(book()), &options, |title, _, data| {
+            volumes.push((title, data));
+            Ok(())
+        })
+        .unwrap();
+        volumes
+    }
+
+    #[test]
+    fn test_split_by_toc_level() {
+        let volumes = split_volumes(EpubSplitOptions {
+            toc_depth: Some(0),
+            max_volume_bytes: None,
+        });
+        let titles: Vec<&str> = volumes.iter().map(|(title, _)| title.as_str()).collect();
+        assert_eq!(titles, ["Serial - Part 1", "Serial - Part 2"]);
+
+        let (first, names) = parse(&volumes[0].1);
+        let hrefs: Vec<&str> = first.chapters.iter().map(|c| c.href.as_str()).collect();
+        assert_eq!(hrefs, ["OEBPS/text/c1.xhtml", "OEBPS/text/c2.xhtml"]);
+        assert_eq!(first.metadata.title.as_deref(), Some("Serial - Part 1"));
+        assert_eq!(
+            first.metadata.cover_href.as_deref(),
+            Some("OEBPS/img/cover.png")
+        );
+        assert!(names.contains(&"OEBPS/img/map.png".to_string()));
+        assert!(names.contains(&"OEBPS/css/book.css".to_string()));
+        let toc: Vec<(&str, u32)> = first
+            .toc
+            .iter()
+            .map(|e| (e.title.as_str(), e.depth))
+            .collect();
+        assert_eq!(toc, [("Part 1", 0), ("One", 1), ("Two", 1)]);
+        assert_eq!(first.toc[1].href, "OEBPS/text/c1.xhtml#s");
+
+        // The map is only shown in the first part.
+        let (second, names) = parse(&volumes[1].1);
+        assert_eq!(second.chapters.len(), 2);
+        assert!(!names.contains(&"OEBPS/img/map.png".to_string()));
+        assert!(names.contains(&"OEBPS/img/cover.png".to_string()));
+        assert_ne!(first.metadata.identifier, second.metadata.identifier);
+    }
+
+    #[test]
+    fn test_split_by_size() {
+        let volumes = split_volumes(EpubSplitOptions {
+            toc_depth: None,
+            max_volume_bytes: Some(1000),
+        });
+        let counts: Vec<usize> = volumes
+            .iter()
+            .map(|(_, data)| parse(data).0.chapters.len())
+            .collect();
+        // The chapter showing the 4 KiB map is a volume of its own.
+        assert_eq!(counts, [1, 1, 2]);
+        assert_eq!(volumes[2].0, "Serial (3)");
+        assert!(split(
+            &mut source(book()),
+            &EpubSplitOptions {
+                toc_depth: None,
+                max_volume_bytes: None
+            },
+            |_, _, _| Ok(())
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_merge_nests_each_book() {
+        let mut books = vec![
+            (source(book()), Some("Volume 1".to_string())),
+            (source(book()), None),
+        ];
+        let metadata = BookMetadata {
+            title: Some("Omnibus".to_string()),
+            authors: Vec::new(),
+            language: None,
+            identifier: None,
+            publisher: None,
+            description: None,
+            cover_href: None,
+        };
+        let (data, chapter_count) = merge(&mut books, &metadata, || Ok(())).unwrap();
+        assert_eq!(chapter_count, 8);
+
+        let (merged, names) = parse(&data);
+        assert_eq!(merged.metadata.title.as_deref(), Some("Omnibus"));
+        assert_eq!(merged.metadata.authors, ["Author"]);
+        assert_eq!(merged.metadata.language.as_deref(), Some("zh"));
+        assert_eq!(
+            merged.metadata.cover_href.as_deref(),
+            Some("OEBPS/part001/img/cover.png")
+        );
+        assert_eq!(merged.chapters[4].href, "OEBPS/part002/text/c1.xhtml");
+        assert!(names.contains(&"OEBPS/part002/img/map.png".to_string()));
+        let toc: Vec<(&str, u32)> = merged
+            .toc
+            .iter()
+            .map(|e| (e.title.as_str(), e.depth))
+            .collect();
+        assert_eq!(
+            toc[..4],
+            [("Volume 1", 0), ("Part 1", 1), ("One", 2), ("Two", 2)]
+        );
+        assert_eq!(toc[7], ("Serial", 0));
+        assert_eq!(merged.toc[7].chapter_index, Some(4));
+        assert!(merge::<Cursor<Vec<u8>>>(&mut [], &metadata, || Ok(())).is_err());
+    }
+}
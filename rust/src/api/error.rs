@@ -0,0 +1,211 @@
+//! Errors and panics as they reach Dart.
+//!
+//! Every bridged function that can fail returns an `ApiError`: a code Dart
+//! can branch on, the message, the chain of context it was raised with,
+//! where in the Rust code it was raised and, when one was captured, a
+//! backtrace. Internally errors stay `anyhow` errors and are converted by
+//! `?` at the API boundary.
+//!
+//! `init_app` runs when the bridge starts and installs a panic hook that
+//! logs every panic with its location and backtrace. Panics in background
+//! tasks become `ErrorCode::Panic` errors of the call that ran them, and
+//! the last panic anywhere can be fetched with `take_last_panic`, e.g.
+//! after the bridge reports one on its own.
+
+use std::any::Any;
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::fmt;
+use std::io;
+use std::panic::{self, Location};
+use std::sync::Mutex;
+
+use crate::api::logging;
+
+static LAST_PANIC: Mutex<Option<ApiError>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    /// The call's cancel token was cancelled.
+    Cancelled,
+    /// A file or other item the call needed does not exist.
+    NotFound,
+    PermissionDenied,
+    /// The arguments or the data passed in are malformed.
+    InvalidInput,
+    /// Any other file system error.
+    Io,
+    /// A connection or HTTP error.
+    Network,
+    /// The Rust code panicked.
+    Panic,
+    Other,
+}
+
+#[derive(Debug, Clone)]
+pub struct ApiError {
+    pub code: ErrorCode,
+    /// The full message, context first.
+    pub message: String,
+    /// The message of each error in the chain, outermost first.
+    pub context: Vec<String>,
+    /// The source file and line the error was converted at: the API
+    /// function for calls that are not `async`, the task runner for those
+    /// that are.
+    pub location: Option<String>,
+    pub backtrace: Option<String>,
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+impl From<anyhow::Error> for ApiError {
+    #[track_caller]
+    fn from(error: anyhow::Error) -> Self {
+        // Errors that already crossed an API function keep their details.
+        let error = match error.downcast::<ApiError>() {
+            Ok(error) => return error,
+            Err(error) => error,
+        };
+        let backtrace = error.backtrace();
+        ApiError {
+            code: code(&error),
+            message: format!("{error:#}"),
+            context: error.chain().map(|e| e.to_string()).collect(),
+            location: Some(Location::caller().to_string()),
+            backtrace: (backtrace.status() == BacktraceStatus::Captured)
+                .then(|| backtrace.to_string()),
+        }
+    }
+}
+
+/// Install the panic hook. Called by the bridge on start-up.
+#[flutter_rust_bridge::frb(init)]
+pub fn init_app() {
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let message = panic_message(info.payload());
+        let location = info.location().map(|location| location.to_string());
+        let thread = std::thread::current();
+        let thread = thread.name().unwrap_or("unnamed");
+        logging::error(
+            "panic",
+            format!(
+                "Thread {thread} panicked at {}: {message}",
+                location.as_deref().unwrap_or("unknown location")
+            ),
+        );
+        *LAST_PANIC.lock().unwrap_or_else(|e| e.into_inner()) = Some(ApiError {
+            code: ErrorCode::Panic,
+            context: vec![message.clone()],
+            message,
+            location,
+            backtrace: Some(Backtrace::force_capture().to_string()),
+        });
+        previous(info);
+    }));
+}
+
+/// The most recent panic since the last call, if any.
+#[flutter_rust_bridge::frb]
+pub fn take_last_panic() -> Option<ApiError> {
+    LAST_PANIC.lock().unwrap_or_else(|e| e.into_inner()).take()
+}
+
+/// An error for a caught panic, with the details the hook recorded.
+pub(crate) fn panic_error(payload: &(dyn Any + Send)) -> anyhow::Error {
+    let message = panic_message(payload);
+    let recorded = LAST_PANIC
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+        .filter(|error| error.message == message);
+    anyhow::Error::new(recorded.unwrap_or(ApiError {
+        code: ErrorCode::Panic,
+        context: vec![message.clone()],
+        message,
+        location: None,
+        backtrace: None,
+    }))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "Panicked".to_string())
+}
+
+fn code(error: &anyhow::Error) -> ErrorCode {
+    for cause in error.chain() {
+        if let Some(error) = cause.downcast_ref::<io::Error>() {
+            return match error.kind() {
+                io::ErrorKind::NotFound => ErrorCode::NotFound,
+                io::ErrorKind::PermissionDenied => ErrorCode::PermissionDenied,
+                io::ErrorKind::InvalidData | io::ErrorKind::InvalidInput => ErrorCode::InvalidInput,
+                _ => ErrorCode::Io,
+            };
+        }
+        if cause.downcast_ref::<ureq::Error>().is_some() {
+            return ErrorCode::Network;
+        }
+    }
+    // Most errors are formatted messages; these follow fixed wordings.
+    let message = error.to_string();
+    if message == "Cancelled" {
+        ErrorCode::Cancelled
+    } else if message.starts_with("Invalid ") {
+        ErrorCode::InvalidInput
+    } else if message.contains(" is not open") || message.contains(" not found") {
+        ErrorCode::NotFound
+    } else if message.starts_with("HTTP ") || message.contains("Failed to fetch ") {
+        ErrorCode::Network
+    } else {
+        ErrorCode::Other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::{anyhow, Context};
+
+    fn open(path: &str) -> Result<String, ApiError> {
+        let text = std::fs::read_to_string(path).context(format!("Failed to read {path}"))?;
+        Ok(text)
+    }
+
+    #[test]
+    fn test_converts_anyhow_errors() {
+        let error = open("/nonexistent/novella.txt").unwrap_err();
+        assert_eq!(error.code, ErrorCode::NotFound);
+        assert!(error
+            .message
+            .starts_with("Failed to read /nonexistent/novella.txt: "));
+        assert_eq!(error.context.len(), 2);
+        assert!(error.location.unwrap().contains("error.rs"));
+
+        let convert = |error: anyhow::Error| ApiError::from(error).code;
+        assert_eq!(convert(anyhow!("Cancelled")), ErrorCode::Cancelled);
+        assert_eq!(
+            convert(anyhow!("Invalid backup file")),
+            ErrorCode::InvalidInput
+        );
+        assert_eq!(convert(anyhow!("HTTP 503")), ErrorCode::Network);
+        assert_eq!(convert(anyhow!("Book has no chapters")), ErrorCode::Other);
+    }
+
+    #[test]
+    fn test_panics_become_errors() {
+        let payload = panic::catch_unwind(|| panic!("index out of range")).unwrap_err();
+        let error = ApiError::from(panic_error(payload.as_ref()));
+        assert_eq!(error.code, ErrorCode::Panic);
+        assert_eq!(error.message, "index out of range");
+        assert_eq!(panic_message(&5u32), "Panicked");
+    }
+}
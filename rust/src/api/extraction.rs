@@ -0,0 +1,222 @@
+//! CSS, XPath and JSONPath extraction for trying out scraping rules.
+//!
+//! These are the primitives book sources are built from, without the rest
+//! of the rule language: a selector picks values out of a page, then each
+//! post-processing step transforms the list in turn. Both the raw matches
+//! and the final values are returned, so a debug screen can show what the
+//! selector found and what the steps made of it.
+
+use anyhow::{anyhow, Result};
+use regex::Regex;
+use scraper::{ElementRef, Html, Selector};
+use serde_json::Value;
+use serde_json_path::JsonPath;
+
+use crate::api::error::ApiError;
+use crate::source_rule;
+use crate::xpath::{self, Item};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PostStepKind {
+    /// Replace each value by its regex matches, one value per match: the
+    /// first capture group if there is one, else the whole match. Values
+    /// without a match are dropped.
+    Regex,
+    /// Regex replacement in each value; `$1` refers to groups.
+    Replace,
+    /// Trim whitespace and drop empty values.
+    Trim,
+    /// Join all values into one, with `pattern` as the separator.
+    Join,
+}
+
+#[derive(Debug, Clone)]
+pub struct PostStep {
+    pub kind: PostStepKind,
+    /// Regex for `Regex` and `Replace`, separator for `Join`.
+    pub pattern: String,
+    /// Replacement for `Replace`.
+    pub replacement: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Extraction {
+    /// What the selector matched, before post-processing.
+    pub matched: Vec<String>,
+    /// The values after every step.
+    pub values: Vec<String>,
+}
+
+/// Select from HTML with a CSS selector.
+///
+/// # Arguments
+/// * `html` - Page HTML
+/// * `selector` - CSS selector
+/// * `getter` - `text` (the default), `ownText`, `textNodes`, `html`, `all`
+///   for the outer HTML, or an attribute name such as `href`
+/// * `steps` - Post-processing, applied in order
+#[flutter_rust_bridge::frb]
+pub fn extract_css(
+    html: String,
+    selector: String,
+    getter: Option<String>,
+    steps: Vec<PostStep>,
+) -> Result<Extraction, ApiError> {
+    let parsed =
+        Selector::parse(&selector).map_err(|e| anyhow!("Invalid CSS selector {selector}: {e}"))?;
+    let document = Html::parse_document(&html);
+    let getter = getter.as_deref().unwrap_or("text");
+    let matched = document
+        .select(&parsed)
+        .flat_map(|element| source_rule::get(element, getter))
+        .collect();
+    Ok(finish(matched, &steps)?)
+}
+
+/// Select from HTML with an XPath expression. Elements give their outer
+/// HTML, other nodes and attributes their text.
+///
+/// # Arguments
+/// * `html` - Page HTML
+/// * `expression` - XPath 1.0 expression, such as `//a/@href`
+/// * `steps` - Post-processing, applied in order
+#[flutter_rust_bridge::frb]
+pub fn extract_xpath(
+    html: String,
+    expression: String,
+    steps: Vec<PostStep>,
+) -> Result<Extraction, ApiError> {
+    let document = Html::parse_document(&html);
+    let matched = xpath::select(document.tree.root(), &expression)?
+        .iter()
+        .map(|item| match item {
+            Item::Node(node) => match ElementRef::wrap(*node) {
+                Some(element) => element.html(),
+                None => item.string_value().trim().to_string(),
+            },
+            _ => item.string_value(),
+        })
+        .collect();
+    Ok(finish(matched, &steps)?)
+}
+
+/// Select from JSON with a JSONPath query. Strings give their value,
+/// arrays their items and objects their JSON text.
+///
+/// # Arguments
+/// * `json` - JSON document
+/// * `path` - JSONPath query, such as `$.data.list[*].name`
+/// * `steps` - Post-processing, applied in order
+#[flutter_rust_bridge::frb]
+pub fn extract_jsonpath(
+    json: String,
+    path: String,
+    steps: Vec<PostStep>,
+) -> Result<Extraction, ApiError> {
+    let value: Value = serde_json::from_str(&json).map_err(|e| anyhow!("Invalid JSON: {e}"))?;
+    let query = JsonPath::parse(&path).map_err(|e| anyhow!("Invalid JSONPath {path}: {e}"))?;
+    let matched = query
+        .query(&value)
+        .all()
+        .into_iter()
+        .flat_map(source_rule::json_strings)
+        .collect();
+    Ok(finish(matched, &steps)?)
+}
+
+fn finish(matched: Vec<String>, steps: &[PostStep]) -> Result<Extraction> {
+    let values = post_process(matched.clone(), steps)?;
+    Ok(Extraction { matched, values })
+}
+
+fn post_process(mut values: Vec<String>, steps: &[PostStep]) -> Result<Vec<String>> {
+    let regex =
+        |pattern: &str| Regex::new(pattern).map_err(|e| anyhow!("Invalid regex {pattern}: {e}"));
+    for step in steps {
+        values = match step.kind {
+            PostStepKind::Regex => {
+                let pattern = regex(&step.pattern)?;
+                values
+                    .iter()
+                    .flat_map(|value| {
+                        pattern.captures_iter(value).map(|captures| {
+                            captures
+                                .get(1)
+                                .or_else(|| captures.get(0))
+                                .map_or_else(String::new, |m| m.as_str().to_string())
+                        })
+                    })
+                    .collect()
+            }
+            PostStepKind::Replace => {
+                let pattern = regex(&step.pattern)?;
+                values
+                    .iter()
+                    .map(|value| {
+                        pattern
+                            .replace_all(value, step.replacement.as_str())
+                            .into_owned()
+                    })
+                    .collect()
+            }
+            PostStepKind::Trim => values
+                .iter()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+                .collect(),
+            PostStepKind::Join => vec![values.join(&step.pattern)],
+        };
+    }
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(kind: PostStepKind, pattern: &str, replacement: &str) -> PostStep {
+        PostStep {
+            kind,
+            pattern: pattern.to_string(),
+            replacement: replacement.to_string(),
+        }
+    }
+
+    const PAGE: &str = r#"<ul id="toc">
+        <li><a href="/c/1.html"> 第1章 开始 </a></li>
+        <li><a href="/c/2.html">第2章 继续</a></li></ul>"#;
+
+    #[test]
+    fn test_css_and_xpath_with_steps() {
+        let steps = vec![
+            step(PostStepKind::Regex, r"/c/(\d+)\.html", ""),
+            step(PostStepKind::Join, ",", ""),
+        ];
+        let css = extract_css(PAGE.into(), "#toc a".into(), Some("href".into()), steps).unwrap();
+        assert_eq!(css.matched, ["/c/1.html", "/c/2.html"]);
+        assert_eq!(css.values, ["1,2"]);
+
+        let steps = vec![
+            step(PostStepKind::Replace, r"第(\d+)章\s*", "[$1] "),
+            step(PostStepKind::Trim, "", ""),
+        ];
+        let xpath = extract_xpath(PAGE.into(), "//li/a/text()".into(), steps).unwrap();
+        assert_eq!(xpath.values, ["[1] 开始", "[2] 继续"]);
+        let count = extract_xpath(PAGE.into(), "count(//li)".into(), Vec::new()).unwrap();
+        assert_eq!(count.values, ["2"]);
+    }
+
+    #[test]
+    fn test_jsonpath_and_errors() {
+        let json = r#"{"data":{"list":[{"name":"甲","id":1},{"name":"乙","id":2}]}}"#;
+        let names = extract_jsonpath(json.into(), "$.data.list[*].name".into(), Vec::new());
+        assert_eq!(names.unwrap().values, ["甲", "乙"]);
+        let ids = extract_jsonpath(json.into(), "$..id".into(), Vec::new()).unwrap();
+        assert_eq!(ids.values, ["1", "2"]);
+
+        assert!(extract_css(PAGE.into(), "li[".into(), None, Vec::new()).is_err());
+        assert!(extract_jsonpath("{".into(), "$".into(), Vec::new()).is_err());
+        let bad_regex = vec![step(PostStepKind::Regex, "(", "")];
+        assert!(extract_css(PAGE.into(), "a".into(), None, bad_regex).is_err());
+    }
+}
@@ -0,0 +1,669 @@
+//! FictionBook 2 import.
+//!
+//! FB2 is a single XML document: `description` holds the metadata, each
+//! `body` holds nested `section`s, and images are base64 `binary` elements
+//! referenced by id. Every section of the main body starts a new chapter, so
+//! a "part" section that only carries a title becomes a short title chapter
+//! followed by its sub-sections. Note bodies become one chapter each.
+//!
+//! `.fb2.zip` archives are unpacked transparently.
+
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+
+use anyhow::{anyhow, Result};
+use base64::Engine;
+use quick_xml::escape::{escape, unescape};
+use quick_xml::events::{BytesStart, Event};
+use regex::{Captures, Regex};
+use zip::ZipArchive;
+
+use crate::api::book::{self, BookChapter, BookMetadata, ParsedBook, TocEntry};
+use crate::api::error::ApiError;
+use crate::api::task;
+use crate::xhtml;
+
+/// Parse a local `.fb2` or `.fb2.zip` file into chapters and a table of
+/// contents.
+///
+/// # Arguments
+/// * `path` - Path to the FictionBook file
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+#[flutter_rust_bridge::frb]
+pub async fn parse_fb2(path: String, cancel_token: Option<u32>) -> Result<ParsedBook, ApiError> {
+    task::run_blocking(task::cancel_token(cancel_token)?, move |_| {
+        parse(&xhtml::decode(&read_document(&path)?))
+    })
+    .await
+}
+
+/// Extract an embedded image by the href used in chapter HTML or
+/// `cover_href`.
+///
+/// # Arguments
+/// * `path` - Path to the FictionBook file
+/// * `href` - Binary id, e.g. `cover.jpg`
+#[flutter_rust_bridge::frb]
+pub fn extract_fb2_resource(path: String, href: String) -> Result<Vec<u8>, ApiError> {
+    Ok(extract_binary(
+        &xhtml::decode(&read_document(&path)?),
+        &href,
+    )?)
+}
+
+/// Read the FB2 document, unpacking it from a zip archive if needed.
+pub(crate) fn read_document(path: &str) -> Result<Vec<u8>> {
+    let data = std::fs::read(path).map_err(|e| anyhow!("Failed to open {path}: {e}"))?;
+    if !data.starts_with(b"PK\x03\x04") {
+        return Ok(data);
+    }
+    unzip_document(data)
+}
+
+fn unzip_document(data: Vec<u8>) -> Result<Vec<u8>> {
+    let mut archive =
+        ZipArchive::new(Cursor::new(data)).map_err(|e| anyhow!("Invalid FB2 archive: {e}"))?;
+    let name = archive
+        .file_names()
+        .find(|name| name.to_ascii_lowercase().ends_with(".fb2"))
+        .map(str::to_string)
+        .ok_or_else(|| anyhow!("Archive contains no .fb2 file"))?;
+    let mut entry = archive
+        .by_name(&name)
+        .map_err(|e| anyhow!("Failed to read {name}: {e}"))?;
+    let mut document = Vec::with_capacity(entry.size() as usize);
+    entry
+        .read_to_end(&mut document)
+        .map_err(|e| anyhow!("Failed to read {name}: {e}"))?;
+    Ok(document)
+}
+
+pub(crate) fn parse(document: &str) -> Result<ParsedBook> {
+    let mut reader = xhtml::reader(document);
+    let mut converter = Converter::default();
+    let mut path: Vec<String> = Vec::new();
+    let mut saw_root = false;
+
+    loop {
+        let event = match reader.read_event() {
+            Ok(Event::Eof) => break,
+            Ok(event) => event,
+            Err(e) => return Err(anyhow!("Invalid FB2 document: {e}")),
+        };
+        match event {
+            Event::Start(element) => {
+                let name = xhtml::local_name(element.name().as_ref());
+                saw_root |= name == "fictionbook";
+                converter.start(&path, &name, &element, false);
+                path.push(name);
+            }
+            Event::Empty(element) => {
+                let name = xhtml::local_name(element.name().as_ref());
+                converter.start(&path, &name, &element, true);
+            }
+            Event::End(_) => {
+                if let Some(name) = path.pop() {
+                    converter.end(&path, &name);
+                }
+            }
+            Event::Text(text) => converter.text(&path, &xhtml::text(&text)),
+            Event::CData(text) => converter.text(&path, &String::from_utf8_lossy(&text)),
+            _ => {}
+        }
+    }
+    if !saw_root {
+        return Err(anyhow!("Not a FictionBook document"));
+    }
+    converter.finish()
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+struct Chapter {
+    title: Option<String>,
+    html: String,
+}
+
+/// Title being collected: HTML for the heading and plain text for the TOC.
+struct Title {
+    text: String,
+    paragraphs: usize,
+    /// Section nesting level the title belongs to; `None` for body titles.
+    depth: Option<u32>,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+struct Converter {
+    metadata: Metadata,
+    chapters: Vec<Chapter>,
+    /// TOC entries as (title, chapter, depth).
+    toc: Vec<(String, usize, u32)>,
+    /// Chapter containing each element id, for link targets.
+    ids: HashMap<String, usize>,
+    in_body: bool,
+    in_notes_body: bool,
+    section_depth: u32,
+    title: Option<Title>,
+    /// Closing tag for each open content element (`None` when unwrapped).
+    closers: Vec<Option<String>>,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+struct Metadata {
+    title: Option<String>,
+    authors: Vec<String>,
+    author_parts: Vec<String>,
+    language: Option<String>,
+    identifier: Option<String>,
+    isbn: Option<String>,
+    publisher: Option<String>,
+    annotation: String,
+    cover: Option<String>,
+}
+
+fn in_title_info(path: &[String]) -> bool {
+    path.iter().any(|name| name == "title-info")
+}
+
+fn parent_is(path: &[String], name: &str) -> bool {
+    path.last().is_some_and(|last| last == name)
+}
+
+/// Elements whose direct text children are layout whitespace.
+fn is_block(name: &str) -> bool {
+    matches!(
+        name,
+        "body"
+            | "section"
+            | "title"
+            | "epigraph"
+            | "cite"
+            | "poem"
+            | "stanza"
+            | "annotation"
+            | "table"
+            | "tr"
+    )
+}
+
+fn link_target(element: &BytesStart) -> Option<String> {
+    xhtml::attribute(element, "href")
+}
+
+impl Converter {
+    fn start(&mut self, path: &[String], name: &str, element: &BytesStart, empty: bool) {
+        if !self.in_body {
+            match name {
+                "body" => {
+                    self.in_body = true;
+                    self.in_notes_body = xhtml::attribute(element, "name").is_some();
+                    if self.in_notes_body {
+                        self.chapters.push(Chapter::default());
+                    }
+                }
+                "image" if in_title_info(path) && parent_is(path, "coverpage") => {
+                    self.metadata.cover =
+                        link_target(element).map(|href| href.trim_start_matches('#').to_string());
+                }
+                "author" if in_title_info(path) => self.metadata.author_parts.clear(),
+                _ => {}
+            }
+            return;
+        }
+
+        if name == "section" {
+            self.section_depth += 1;
+            if !self.in_notes_body {
+                self.chapters.push(Chapter::default());
+            }
+            if let Some(id) = xhtml::attribute(element, "id") {
+                self.anchor(&id);
+            }
+            if empty {
+                self.section_depth -= 1;
+            }
+            return;
+        }
+        if name == "title" {
+            let level = (self.section_depth + 1).min(6);
+            self.push(&format!("<h{level}>"));
+            self.title = Some(Title {
+                text: String::new(),
+                paragraphs: 0,
+                depth: self.section_depth.checked_sub(1),
+            });
+            self.closers.push(Some(format!("h{level}")));
+            if empty {
+                self.end(path, name);
+            }
+            return;
+        }
+        if let Some(title) = self.title.as_mut() {
+            if name == "p" {
+                title.paragraphs += 1;
+                if title.paragraphs > 1 {
+                    title.text.push(' ');
+                    self.push("<br>");
+                }
+                self.closers.push(None);
+                return;
+            }
+        }
+
+        match name {
+            "image" => {
+                if let Some(href) = link_target(element) {
+                    let src = href.trim_start_matches('#');
+                    self.push(&format!("<img src=\"{}\">", escape(src)));
+                }
+                if !empty {
+                    self.closers.push(None);
+                }
+            }
+            "empty-line" => {
+                self.push("<br>");
+                if !empty {
+                    self.closers.push(None);
+                }
+            }
+            _ => {
+                let tag = match name {
+                    "p" | "v" | "text-author" => Some("p"),
+                    "emphasis" => Some("em"),
+                    "strong" => Some("strong"),
+                    "strikethrough" => Some("s"),
+                    "sub" | "sup" | "code" | "table" | "tr" | "td" | "th" | "a" => Some(name),
+                    "epigraph" | "cite" | "poem" | "annotation" => Some("blockquote"),
+                    "stanza" => Some("div"),
+                    "subtitle" => Some("h5"),
+                    _ => None,
+                };
+                let Some(tag) = tag else {
+                    if !empty {
+                        self.closers.push(None);
+                    }
+                    return;
+                };
+                let mut open = format!("<{tag}");
+                if let Some(id) = xhtml::attribute(element, "id") {
+                    let index = self.current();
+                    self.ids.insert(id.clone(), index);
+                    open.push_str(&format!(" id=\"{}\"", escape(id.as_str())));
+                }
+                if tag == "a" {
+                    if let Some(href) = link_target(element) {
+                        open.push_str(&format!(" href=\"{}\"", escape(href.as_str())));
+                    }
+                }
+                open.push('>');
+                self.push(&open);
+                if empty {
+                    self.push(&format!("</{tag}>"));
+                } else {
+                    self.closers.push(Some(tag.to_string()));
+                }
+            }
+        }
+    }
+
+    fn end(&mut self, path: &[String], name: &str) {
+        if !self.in_body {
+            if name == "author" && in_title_info(path) {
+                let author = self.metadata.author_parts.join(" ");
+                if !author.is_empty() {
+                    self.metadata.authors.push(author);
+                }
+            }
+            if name == "p" && path.iter().any(|n| n == "annotation") {
+                self.metadata.annotation.push('\n');
+            }
+            return;
+        }
+
+        match name {
+            "body" => {
+                self.in_body = false;
+                self.in_notes_body = false;
+            }
+            "section" => self.section_depth = self.section_depth.saturating_sub(1),
+            _ => {
+                if let Some(Some(tag)) = self.closers.pop() {
+                    self.push(&format!("</{tag}>"));
+                }
+                if name == "title" {
+                    self.finish_title();
+                }
+            }
+        }
+    }
+
+    fn text(&mut self, path: &[String], text: &str) {
+        if !self.in_body {
+            self.metadata_text(path, text);
+            return;
+        }
+        if let Some(title) = self.title.as_mut() {
+            title.text.push_str(text);
+        }
+        // Indentation between block elements is not content.
+        if text.trim().is_empty() && path.last().is_some_and(|name| is_block(name)) {
+            return;
+        }
+        self.push(&escape(text));
+    }
+
+    fn metadata_text(&mut self, path: &[String], text: &str) {
+        let Some(field) = path.last() else {
+            return;
+        };
+        if in_title_info(path) && path.iter().any(|name| name == "annotation") {
+            self.metadata.annotation.push_str(text);
+            return;
+        }
+        let text = xhtml::normalize_whitespace(text);
+        if text.is_empty() {
+            return;
+        }
+        let parent = path.len().checked_sub(2).map(|i| path[i].as_str());
+        let metadata = &mut self.metadata;
+        match (parent, field.as_str()) {
+            (Some("title-info"), "book-title") => metadata.title = Some(text),
+            (Some("title-info"), "lang") => metadata.language = Some(text),
+            (Some("author"), "first-name" | "middle-name" | "last-name") if in_title_info(path) => {
+                metadata.author_parts.push(text);
+            }
+            (Some("author"), "nickname")
+                if in_title_info(path) && metadata.author_parts.is_empty() =>
+            {
+                metadata.author_parts.push(text);
+            }
+            (Some("publish-info"), "publisher") => metadata.publisher = Some(text),
+            (Some("publish-info"), "isbn") => metadata.isbn = Some(text),
+            (Some("document-info"), "id") => metadata.identifier = Some(text),
+            _ => {}
+        }
+    }
+
+    /// Index of the chapter receiving content, creating one for content
+    /// that precedes the first section.
+    fn current(&mut self) -> usize {
+        if self.chapters.is_empty() {
+            self.chapters.push(Chapter::default());
+        }
+        self.chapters.len() - 1
+    }
+
+    fn push(&mut self, html: &str) {
+        let index = self.current();
+        self.chapters[index].html.push_str(html);
+    }
+
+    fn anchor(&mut self, id: &str) {
+        let index = self.current();
+        self.ids.insert(id.to_string(), index);
+        self.push(&format!("<a id=\"{}\"></a>", escape(id)));
+    }
+
+    fn finish_title(&mut self) {
+        let Some(title) = self.title.take() else {
+            return;
+        };
+        let text = xhtml::normalize_whitespace(&title.text);
+        if text.is_empty() {
+            return;
+        }
+        let index = self.current();
+        let chapter = &mut self.chapters[index];
+        match title.depth {
+            // Titles of sections inside a note body head individual notes.
+            Some(_) if self.in_notes_body => {}
+            Some(depth) if chapter.title.is_none() => {
+                chapter.title = Some(text.clone());
+                self.toc.push((text, index, depth));
+            }
+            Some(_) => {}
+            None if self.in_notes_body => {
+                chapter.title = Some(text.clone());
+                self.toc.push((text, index, 0));
+            }
+            // The main body title repeats the book title.
+            None => {}
+        }
+    }
+
+    fn finish(self) -> Result<ParsedBook> {
+        let link = Regex::new(r##"href="#([^"]*)""##).unwrap();
+        let ids = &self.ids;
+        let chapters: Vec<BookChapter> = self
+            .chapters
+            .into_iter()
+            .enumerate()
+            .map(|(index, chapter)| {
+                let html = link.replace_all(&chapter.html, |caps: &Captures| {
+                    let id = unescape(&caps[1])
+                        .map(|id| id.into_owned())
+                        .unwrap_or_default();
+                    match ids.get(&id) {
+                        Some(&target) => {
+                            format!("href=\"{}#{}\"", book::part_href(target), &caps[1])
+                        }
+                        None => caps[0].to_string(),
+                    }
+                });
+                BookChapter::new(
+                    index as u32,
+                    book::part_href(index),
+                    chapter.title,
+                    html.trim().to_string(),
+                )
+            })
+            .collect();
+        if chapters.is_empty() {
+            return Err(anyhow!("FB2 document has no body"));
+        }
+
+        let toc = self
+            .toc
+            .into_iter()
+            .map(|(title, chapter, depth)| TocEntry {
+                title,
+                href: book::part_href(chapter),
+                chapter_index: Some(chapter as u32),
+                depth,
+            })
+            .collect();
+
+        let metadata = self.metadata;
+        let mut book_metadata = BookMetadata::empty();
+        book_metadata.title = metadata.title;
+        book_metadata.authors = metadata.authors;
+        book_metadata.language = metadata.language;
+        book_metadata.identifier = metadata.isbn.or(metadata.identifier);
+        book_metadata.publisher = metadata.publisher;
+        let annotation: Vec<String> = metadata
+            .annotation
+            .lines()
+            .map(xhtml::normalize_whitespace)
+            .filter(|line| !line.is_empty())
+            .collect();
+        book_metadata.description = Some(annotation.join("\n")).filter(|text| !text.is_empty());
+        book_metadata.cover_href = metadata.cover;
+
+        Ok(ParsedBook {
+            metadata: book_metadata,
+            chapters,
+            toc,
+        })
+    }
+}
+
+/// Decode the base64 `binary` element with the given id.
+pub(crate) fn extract_binary(document: &str, href: &str) -> Result<Vec<u8>> {
+    let id = href.trim_start_matches('#');
+    let mut reader = xhtml::reader(document);
+    let mut collecting = false;
+    let mut content = String::new();
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(element))
+                if xhtml::local_name(element.name().as_ref()) == "binary"
+                    && xhtml::attribute(&element, "id").as_deref() == Some(id) =>
+            {
+                collecting = true;
+            }
+            Ok(Event::Text(text)) if collecting => content.push_str(&xhtml::text(&text)),
+            Ok(Event::End(_)) if collecting => break,
+            Ok(Event::Eof) | Err(_) => return Err(anyhow!("Unknown FB2 resource {href}")),
+            _ => {}
+        }
+    }
+
+    let content: String = content.split_whitespace().collect();
+    base64::engine::general_purpose::STANDARD
+        .decode(content)
+        .map_err(|e| anyhow!("Invalid FB2 binary {href}: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+    use zip::ZipWriter;
+
+    const BOOK: &str = r##"<?xml version="1.0" encoding="utf-8"?>
+<FictionBook xmlns="http://www.gribuser.ru/xml/fictionbook/2.0" xmlns:l="http://www.w3.org/1999/xlink">
+  <description>
+    <title-info>
+      <genre>sf</genre>
+      <author><first-name>Cixin</first-name><last-name>Liu</last-name></author>
+      <author><nickname>Translator</nickname></author>
+      <book-title>The Three-Body Problem</book-title>
+      <annotation><p>First line.</p><p>Second <emphasis>line</emphasis>.</p></annotation>
+      <lang>en</lang>
+      <coverpage><image l:href="#cover.png"/></coverpage>
+    </title-info>
+    <document-info><id>doc-1</id></document-info>
+    <publish-info><publisher>Tor</publisher><isbn>978-0765382030</isbn></publish-info>
+  </description>
+  <body>
+    <title><p>The Three-Body Problem</p></title>
+    <section>
+      <title><p>Part I</p><p>Silent Spring</p></title>
+      <section id="ch1">
+        <title><p>1. The Madness Years</p></title>
+        <p>Text with a <a l:href="#n1" type="note">note</a>.</p>
+        <empty-line/>
+        <image l:href="#cover.png"/>
+      </section>
+      <section>
+        <title><p>2. Silent Spring</p></title>
+        <p>See <a l:href="#ch1">chapter 1</a>.</p>
+      </section>
+    </section>
+  </body>
+  <body name="notes">
+    <title><p>Notes</p></title>
+    <section id="n1"><title><p>1</p></title><p>A note.</p></section>
+  </body>
+  <binary id="cover.png" content-type="image/png">iVBO
+  Rw==</binary>
+</FictionBook>"##;
+
+    #[test]
+    fn test_parse_maps_sections_to_chapters() {
+        let book = parse(BOOK).unwrap();
+
+        let metadata = &book.metadata;
+        assert_eq!(metadata.title.as_deref(), Some("The Three-Body Problem"));
+        assert_eq!(metadata.authors, vec!["Cixin Liu", "Translator"]);
+        assert_eq!(metadata.language.as_deref(), Some("en"));
+        assert_eq!(metadata.identifier.as_deref(), Some("978-0765382030"));
+        assert_eq!(metadata.publisher.as_deref(), Some("Tor"));
+        assert_eq!(
+            metadata.description.as_deref(),
+            Some("First line.\nSecond line.")
+        );
+        assert_eq!(metadata.cover_href.as_deref(), Some("cover.png"));
+
+        let chapters: Vec<_> = book
+            .chapters
+            .iter()
+            .map(|c| (c.title.as_deref(), c.html.as_str()))
+            .collect();
+        assert_eq!(
+            chapters,
+            vec![
+                (None, "<h1>The Three-Body Problem</h1>"),
+                (
+                    Some("Part I Silent Spring"),
+                    "<h2>Part I<br>Silent Spring</h2>"
+                ),
+                (
+                    Some("1. The Madness Years"),
+                    "<a id=\"ch1\"></a><h3>1. The Madness Years</h3>\
+                     <p>Text with a <a href=\"part0004.html#n1\">note</a>.</p>\
+                     <br><img src=\"cover.png\">"
+                ),
+                (
+                    Some("2. Silent Spring"),
+                    "<h3>2. Silent Spring</h3>\
+                     <p>See <a href=\"part0002.html#ch1\">chapter 1</a>.</p>"
+                ),
+                (
+                    Some("Notes"),
+                    "<h1>Notes</h1><a id=\"n1\"></a><h2>1</h2><p>A note.</p>"
+                ),
+            ]
+        );
+
+        let toc: Vec<_> = book
+            .toc
+            .iter()
+            .map(|e| (e.title.as_str(), e.chapter_index, e.depth))
+            .collect();
+        assert_eq!(
+            toc,
+            vec![
+                ("Part I Silent Spring", Some(1), 0),
+                ("1. The Madness Years", Some(2), 1),
+                ("2. Silent Spring", Some(3), 1),
+                ("Notes", Some(4), 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_binary_decodes_base64() {
+        assert_eq!(
+            extract_binary(BOOK, "cover.png").unwrap(),
+            [0x89, b'P', b'N', b'G']
+        );
+        assert!(extract_binary(BOOK, "missing.png").is_err());
+    }
+
+    #[test]
+    fn test_unzip_document_finds_fb2_entry() {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        writer
+            .start_file("readme.txt", SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(b"hi").unwrap();
+        writer
+            .start_file("book.FB2", SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(BOOK.as_bytes()).unwrap();
+        let data = writer.finish().unwrap().into_inner();
+
+        let document = unzip_document(data).unwrap();
+        assert_eq!(document, BOOK.as_bytes());
+    }
+
+    #[test]
+    fn test_parse_rejects_other_xml() {
+        assert!(parse("<html><body><p>x</p></body></html>").is_err());
+    }
+}
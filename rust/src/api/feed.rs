@@ -0,0 +1,420 @@
+//! RSS and Atom feeds, for following an author's or a site's updates.
+//!
+//! RSS 2.0, RSS 1.0 (RDF) and Atom 1.0 are reduced to one list of entries
+//! with a title, an absolute link and a Unix timestamp. Feeds in the wild
+//! are often sloppy: the charset comes from the BOM, the XML declaration or
+//! detection, markup errors after the first entries end parsing instead of
+//! failing it, and dates in RFC 822, RFC 3339 or plain `YYYY-MM-DD hh:mm`
+//! form are all accepted.
+
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use encoding_rs::Encoding;
+use quick_xml::events::{BytesStart, Event};
+use regex::Regex;
+use url::Url;
+
+use crate::api::encoding;
+use crate::api::error::ApiError;
+use crate::api::http_cache::{self, FetchError};
+use crate::api::network;
+use crate::xhtml;
+
+/// Feeds larger than this are rejected rather than parsed.
+const MAX_FEED_BYTES: u64 = 8 * 1024 * 1024;
+
+#[derive(Debug, Clone)]
+pub struct Feed {
+    pub title: String,
+    /// Website the feed belongs to.
+    pub link: Option<String>,
+    /// Entries in feed order, usually newest first.
+    pub entries: Vec<FeedEntry>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FeedEntry {
+    /// `guid` or `id`, else the link; stable across fetches.
+    pub id: String,
+    pub title: String,
+    pub link: Option<String>,
+    /// Publication time, or the last update when the feed has none, in
+    /// seconds since the Unix epoch.
+    pub published: Option<i64>,
+    /// Plain-text summary.
+    pub summary: Option<String>,
+    pub author: Option<String>,
+}
+
+/// Fetch and parse a feed.
+///
+/// # Arguments
+/// * `url` - Feed URL
+#[flutter_rust_bridge::frb]
+pub fn fetch_feed(url: String) -> Result<Feed, ApiError> {
+    let agent = network::agent(&url, Duration::from_secs(30), Duration::from_secs(60), None)?;
+    let request = agent.get(&url).set(
+        "Accept",
+        "application/rss+xml, application/atom+xml, application/xml;q=0.9, */*;q=0.5",
+    );
+    let response = match http_cache::get(request, MAX_FEED_BYTES) {
+        Ok(response) => response,
+        Err(FetchError::Status(code)) => {
+            return Err(anyhow!("Failed to fetch {url}: HTTP {code}").into())
+        }
+        Err(FetchError::Other(e)) => return Err(anyhow!("Failed to fetch {url}: {e}").into()),
+    };
+    parse_feed(response.body, response.url)
+}
+
+/// Parse a feed that was fetched some other way.
+///
+/// # Arguments
+/// * `data` - Feed document in any charset
+/// * `url` - URL the feed came from, for resolving relative links
+#[flutter_rust_bridge::frb]
+pub fn parse_feed(data: Vec<u8>, url: String) -> Result<Feed, ApiError> {
+    let base = Url::parse(&url).map_err(|e| anyhow!("Invalid URL {url}: {e}"))?;
+    Ok(parse(&decode(&data), &base)?)
+}
+
+fn decode(bytes: &[u8]) -> String {
+    let encoding = Encoding::for_bom(bytes)
+        .map(|(encoding, _)| encoding)
+        .or_else(|| xhtml::declared_encoding(bytes))
+        .unwrap_or_else(|| encoding::detect(bytes, true).encoding);
+    encoding.decode(bytes).0.into_owned()
+}
+
+/// An item or entry while it is being read.
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+struct PendingEntry {
+    id: Option<String>,
+    title: String,
+    link: Option<String>,
+    published: Option<i64>,
+    updated: Option<i64>,
+    summary: Option<String>,
+    content: Option<String>,
+    author: Option<String>,
+}
+
+impl PendingEntry {
+    fn finish(self) -> Option<FeedEntry> {
+        let id = self.id.or_else(|| self.link.clone())?;
+        Some(FeedEntry {
+            id,
+            title: self.title,
+            link: self.link,
+            published: self.published.or(self.updated),
+            summary: self.summary.or(self.content).filter(|s| !s.is_empty()),
+            author: self.author,
+        })
+    }
+}
+
+fn parse(xml: &str, base: &Url) -> Result<Feed> {
+    let mut reader = xhtml::reader(xml.trim_start_matches('\u{feff}'));
+    let mut feed = Feed {
+        title: String::new(),
+        link: None,
+        entries: Vec::new(),
+    };
+    let mut entry: Option<PendingEntry> = None;
+    let mut path: Vec<String> = Vec::new();
+    let mut text = String::new();
+    let mut saw_feed = false;
+
+    loop {
+        let event = match reader.read_event() {
+            Ok(Event::Eof) => break,
+            Ok(event) => event,
+            // Keep what was read before the markup broke.
+            Err(_) if saw_feed => break,
+            Err(e) => return Err(anyhow!("Invalid feed: {e}")),
+        };
+        match event {
+            Event::Empty(element) if xhtml::local_name(element.name().as_ref()) == "link" => {
+                atom_link(&mut feed, entry.as_mut(), &element, base);
+            }
+            Event::Start(element) => {
+                let name = xhtml::local_name(element.name().as_ref());
+                saw_feed |= matches!(name.as_str(), "rss" | "rdf" | "feed" | "channel");
+                match name.as_str() {
+                    "link" => atom_link(&mut feed, entry.as_mut(), &element, base),
+                    "item" | "entry" => entry = Some(PendingEntry::default()),
+                    _ => {}
+                }
+                text.clear();
+                path.push(name);
+            }
+            Event::Text(content) => text.push_str(&xhtml::text(&content)),
+            Event::CData(content) => text.push_str(&String::from_utf8_lossy(&content)),
+            Event::End(_) => {
+                let Some(name) = path.pop() else {
+                    continue;
+                };
+                let parent = path.last().map(String::as_str);
+                let value = xhtml::normalize_whitespace(&text);
+                match (entry.as_mut(), name.as_str()) {
+                    (Some(_), "item" | "entry") => {
+                        feed.entries
+                            .extend(entry.take().and_then(PendingEntry::finish));
+                    }
+                    (Some(entry), "title") if entry.title.is_empty() => entry.title = value,
+                    (Some(entry), "link") if !value.is_empty() && entry.link.is_none() => {
+                        entry.link = Some(resolve(base, &value));
+                    }
+                    (Some(entry), "guid" | "id") if !value.is_empty() => entry.id = Some(value),
+                    (Some(entry), "pubdate" | "published" | "issued" | "date") => {
+                        entry.published = entry.published.or(parse_date(&value));
+                    }
+                    (Some(entry), "updated" | "modified") => {
+                        entry.updated = entry.updated.or(parse_date(&value));
+                    }
+                    (Some(entry), "description" | "summary") => {
+                        entry.summary = Some(xhtml::strip_tags(&text));
+                    }
+                    (Some(entry), "encoded" | "content") => {
+                        entry.content = Some(xhtml::strip_tags(&text));
+                    }
+                    (Some(entry), "creator" | "author" | "name") if !value.is_empty() => {
+                        entry.author.get_or_insert(value);
+                    }
+                    (None, "title") if matches!(parent, Some("channel" | "feed")) => {
+                        feed.title = value;
+                    }
+                    (None, "link") if parent == Some("channel") && !value.is_empty() => {
+                        feed.link.get_or_insert(resolve(base, &value));
+                    }
+                    _ => {}
+                }
+                text.clear();
+            }
+            _ => {}
+        }
+    }
+    if !saw_feed {
+        return Err(anyhow!("Invalid feed: no RSS or Atom feed element"));
+    }
+    Ok(feed)
+}
+
+/// Record an Atom `<link href>`; only `alternate` links, the default, point
+/// at the page itself.
+fn atom_link(feed: &mut Feed, entry: Option<&mut PendingEntry>, element: &BytesStart, base: &Url) {
+    let Some(href) = xhtml::attribute(element, "href") else {
+        return;
+    };
+    if xhtml::attribute(element, "rel").is_some_and(|rel| rel != "alternate") {
+        return;
+    }
+    let href = resolve(base, &href);
+    match entry {
+        Some(entry) => entry.link = entry.link.take().or(Some(href)),
+        None => feed.link = feed.link.take().or(Some(href)),
+    }
+}
+
+fn resolve(base: &Url, href: &str) -> String {
+    base.join(href.trim())
+        .map_or_else(|_| href.to_string(), |url| url.to_string())
+}
+
+/// Seconds since the Unix epoch for an RFC 822, RFC 3339 or
+/// `YYYY-MM-DD hh:mm[:ss]` date. Dates without a zone are taken as UTC.
+fn parse_date(text: &str) -> Option<i64> {
+    let iso = Regex::new(
+        r"(?i)^(\d{4})-(\d{1,2})-(\d{1,2})(?:[T ](\d{1,2}):(\d{2})(?::(\d{2})(?:\.\d+)?)?)?\s*(Z|[+-]\d{2}:?\d{2})?$",
+    )
+    .unwrap();
+    if let Some(c) = iso.captures(text.trim()) {
+        let number = |i: usize| c.get(i).map_or(Some(0), |m| m.as_str().parse().ok());
+        let offset = c
+            .get(7)
+            .map_or(Some(0), |zone| numeric_offset(zone.as_str()))?;
+        return timestamp(
+            [number(1)?, number(2)?, number(3)?],
+            [number(4)?, number(5)?, number(6)?],
+            offset,
+        );
+    }
+
+    let rfc822 = Regex::new(
+        r"(?i)^(?:[a-z]+,?\s+)?(\d{1,2})\s+([a-z]{3})[a-z]*\.?\s+(\d{2,4})(?:\s+(\d{1,2}):(\d{2})(?::(\d{2}))?)?\s*([+-]\d{2}:?\d{2}|[a-z]+)?",
+    )
+    .unwrap();
+    let c = rfc822.captures(text.trim())?;
+    let number = |i: usize| c.get(i).map_or(Some(0), |m| m.as_str().parse::<i64>().ok());
+    let month = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ]
+    .iter()
+    .position(|month| c[2].eq_ignore_ascii_case(month))? as i64
+        + 1;
+    let year = match number(3)? {
+        year if year < 50 => year + 2000,
+        year if year < 100 => year + 1900,
+        year => year,
+    };
+    let offset = match c.get(7).map(|zone| zone.as_str().to_ascii_uppercase()) {
+        None => 0,
+        Some(zone) if zone.starts_with(['+', '-']) => numeric_offset(&zone)?,
+        Some(zone) => match zone.as_str() {
+            "EST" | "CDT" => -5 * 3600,
+            "EDT" => -4 * 3600,
+            "CST" | "MDT" => -6 * 3600,
+            "MST" | "PDT" => -7 * 3600,
+            "PST" => -8 * 3600,
+            _ => 0,
+        },
+    };
+    timestamp(
+        [year, month, number(1)?],
+        [number(4)?, number(5)?, number(6)?],
+        offset,
+    )
+}
+
+/// `+08:00`, `-0500` or `Z` in seconds east of UTC.
+fn numeric_offset(zone: &str) -> Option<i64> {
+    if zone.eq_ignore_ascii_case("z") {
+        return Some(0);
+    }
+    let digits = zone[1..].replace(':', "");
+    let hours: i64 = digits.get(..2)?.parse().ok()?;
+    let minutes: i64 = digits.get(2..4)?.parse().ok()?;
+    let offset = hours * 3600 + minutes * 60;
+    Some(if zone.starts_with('-') {
+        -offset
+    } else {
+        offset
+    })
+}
+
+fn timestamp(
+    [year, month, day]: [i64; 3],
+    [hour, minute, second]: [i64; 3],
+    offset: i64,
+) -> Option<i64> {
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return None;
+    }
+    // Days since 1970-01-01 from a civil date (Howard Hinnant's algorithm).
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+    Some(days * 86_400 + hour * 3600 + minute * 60 + second - offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: &str = "https://author.example.com/feeds/updates.xml";
+
+    #[test]
+    fn test_parses_legacy_encoded_rss() {
+        let rss = r#"<?xml version="1.0" encoding="gb2312"?>
+<rss version="2.0" xmlns:content="http://purl.org/rss/1.0/modules/content/" xmlns:dc="http://purl.org/dc/elements/1.1/">
+<channel>
+  <title>作者的更新</title>
+  <link>/</link>
+  <item>
+    <title>第一百章&nbsp;完结</title>
+    <link>/book/1/100.html</link>
+    <guid isPermaLink="false">chapter-100</guid>
+    <pubDate>Mon, 02 Sep 2024 20:30:00 +0800</pubDate>
+    <description><![CDATA[<p>本书<b>完结</b>了。</p>]]></description>
+    <dc:creator>某作者</dc:creator>
+  </item>
+  <item>
+    <title>第九十九章</title>
+    <link>https://author.example.com/book/1/99.html</link>
+    <pubDate>1 Sep 24 12:00 GMT</pubDate>
+  </item>
+  <item><title>No link or ID</title></item>
+</channel>
+</rss>"#;
+        let (bytes, _, _) = encoding_rs::GBK.encode(rss);
+        let feed = parse_feed(bytes.into_owned(), BASE.to_string()).unwrap();
+        assert_eq!(feed.title, "作者的更新");
+        assert_eq!(feed.link.as_deref(), Some("https://author.example.com/"));
+        assert_eq!(feed.entries.len(), 2);
+        let entry = &feed.entries[0];
+        assert_eq!(entry.title, "第一百章 完结");
+        assert_eq!(entry.id, "chapter-100");
+        assert_eq!(
+            entry.link.as_deref(),
+            Some("https://author.example.com/book/1/100.html")
+        );
+        assert_eq!(entry.published, Some(1_725_280_200));
+        assert_eq!(entry.summary.as_deref(), Some("本书完结了。"));
+        assert_eq!(entry.author.as_deref(), Some("某作者"));
+        assert_eq!(
+            feed.entries[1].id,
+            "https://author.example.com/book/1/99.html"
+        );
+        assert_eq!(feed.entries[1].published, Some(1_725_192_000));
+    }
+
+    #[test]
+    fn test_parses_atom() {
+        let atom = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+  <title type="text">Updates</title>
+  <link rel="self" href="/feeds/updates.xml"/>
+  <link href="https://author.example.com/"/>
+  <entry>
+    <title>Chapter 12</title>
+    <link rel="replies" href="/comments/12"/>
+    <link rel="alternate" type="text/html" href="/chapters/12"/>
+    <id>tag:author.example.com,2024:12</id>
+    <updated>2024-03-01T08:00:00.123+08:00</updated>
+    <author><name>Author</name></author>
+    <content type="html">&lt;p&gt;New chapter&lt;/p&gt;</content>
+  </entry>
+  <entry>
+    <title>Broken"#;
+        let feed = parse_feed(atom.as_bytes().to_vec(), BASE.to_string()).unwrap();
+        assert_eq!(feed.title, "Updates");
+        assert_eq!(feed.link.as_deref(), Some("https://author.example.com/"));
+        assert_eq!(feed.entries.len(), 1);
+        let entry = &feed.entries[0];
+        assert_eq!(
+            entry.link.as_deref(),
+            Some("https://author.example.com/chapters/12")
+        );
+        assert_eq!(entry.published, Some(1_709_251_200));
+        assert_eq!(entry.summary.as_deref(), Some("New chapter"));
+        assert_eq!(entry.author.as_deref(), Some("Author"));
+        assert!(parse_feed(
+            b"<html><body>Not a feed</body></html>".to_vec(),
+            BASE.to_string()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_parse_date() {
+        assert_eq!(parse_date("Thu, 01 Jan 1970 00:00:00 GMT"), Some(0));
+        assert_eq!(
+            parse_date("Sat, 29 Feb 2020 10:00:00 EST"),
+            Some(1_582_988_400)
+        );
+        assert_eq!(parse_date("2020-02-29T15:00:00Z"), Some(1_582_988_400));
+        assert_eq!(parse_date("2020-02-29 23:00"), Some(1_583_017_200));
+        assert_eq!(parse_date("2020-02-29"), Some(1_582_934_400));
+        assert_eq!(parse_date("yesterday"), None);
+        assert_eq!(parse_date("2020-13-01"), None);
+    }
+}
@@ -0,0 +1,145 @@
+//! Content fingerprints of chapters, for cheap update checks.
+//!
+//! A fingerprint is a BLAKE3 hash of the chapter's paragraphs, trimmed and
+//! with blank lines dropped as in `diff_chapters`, so re-wrapping or
+//! re-indenting does not change it, plus a 64-bit SimHash of its 4-character
+//! shingles. Equal hashes mean the text is unchanged; otherwise the number
+//! of differing SimHash bits tells a small edit from a different chapter.
+//! The chapter cache stores one with every chapter, see
+//! `check_chapter_updates`.
+
+use crate::api::chapter_diff;
+
+const SHINGLE_CHARS: usize = 4;
+
+/// Similarity at or above which a changed chapter counts as edited rather
+/// than replaced.
+pub(crate) const EDITED_SIMILARITY: f32 = 0.5;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChapterFingerprint {
+    /// BLAKE3 of the normalized text, in hex.
+    pub hash: String,
+    pub simhash: u64,
+    /// Characters of the normalized text, whitespace excluded.
+    pub chars: u32,
+}
+
+/// Fingerprint a chapter's text.
+#[flutter_rust_bridge::frb]
+pub fn fingerprint_chapter(text: String) -> ChapterFingerprint {
+    fingerprint(&text)
+}
+
+/// Estimate how similar two fingerprinted texts are, from 1 for equal text
+/// to about 0 for unrelated text.
+#[flutter_rust_bridge::frb]
+pub fn fingerprint_similarity(a: ChapterFingerprint, b: ChapterFingerprint) -> f32 {
+    similarity(&a, &b)
+}
+
+pub(crate) fn fingerprint(text: &str) -> ChapterFingerprint {
+    let paragraphs = chapter_diff::paragraphs(text);
+    let mut hasher = blake3::Hasher::new();
+    for paragraph in &paragraphs {
+        hasher.update(paragraph.as_bytes());
+        hasher.update(b"\n");
+    }
+    let chars: Vec<char> = paragraphs
+        .iter()
+        .flat_map(|paragraph| paragraph.chars())
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    ChapterFingerprint {
+        hash: hasher.finalize().to_hex().to_string(),
+        simhash: simhash(&chars),
+        chars: chars.len() as u32,
+    }
+}
+
+pub(crate) fn similarity(a: &ChapterFingerprint, b: &ChapterFingerprint) -> f32 {
+    if a.hash == b.hash {
+        return 1.0;
+    }
+    // Unrelated texts differ in about half the bits.
+    let distance = (a.simhash ^ b.simhash).count_ones() as f32;
+    (1.0 - distance / 32.0).max(0.0)
+}
+
+/// The fingerprint as stored in the cache: `hash simhash chars`.
+pub(crate) fn encode(fingerprint: &ChapterFingerprint) -> String {
+    format!(
+        "{} {:016x} {}",
+        fingerprint.hash, fingerprint.simhash, fingerprint.chars
+    )
+}
+
+pub(crate) fn decode(text: &str) -> Option<ChapterFingerprint> {
+    let mut fields = text.split(' ');
+    let fingerprint = ChapterFingerprint {
+        hash: fields.next()?.to_string(),
+        simhash: u64::from_str_radix(fields.next()?, 16).ok()?,
+        chars: fields.next()?.parse().ok()?,
+    };
+    (fingerprint.hash.len() == 64 && fields.next().is_none()).then_some(fingerprint)
+}
+
+fn simhash(chars: &[char]) -> u64 {
+    let mut weights = [0i32; 64];
+    let mut add = |shingle: &[char]| {
+        let hash = fnv1a(shingle);
+        for (bit, weight) in weights.iter_mut().enumerate() {
+            *weight += if hash >> bit & 1 == 1 { 1 } else { -1 };
+        }
+    };
+    if chars.len() < SHINGLE_CHARS {
+        add(chars);
+    } else {
+        chars.windows(SHINGLE_CHARS).for_each(add);
+    }
+    weights
+        .iter()
+        .enumerate()
+        .filter(|(_, &weight)| weight > 0)
+        .fold(0, |hash, (bit, _)| hash | 1 << bit)
+}
+
+/// 64-bit FNV-1a, which is stable across builds unlike `DefaultHasher`.
+fn fnv1a(chars: &[char]) -> u64 {
+    chars.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &c| {
+        (hash ^ c as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHAPTER: &str = "第一章 风雪\n\n天色渐晚，城门外的行人越来越少。他把信折好，放进怀里，\
+        转身走进了风雪之中。\n远处传来钟声，一声接着一声，像是在催促着什么。\n\
+        客栈的掌柜抬起头，看了看门口，又低下头去拨他的算盘。";
+
+    #[test]
+    fn test_fingerprints_ignore_layout_but_see_edits() {
+        let original = fingerprint(CHAPTER);
+        let rewrapped = fingerprint(&CHAPTER.replace('\n', "\n\n  "));
+        assert_eq!(original, rewrapped);
+
+        let edited = fingerprint(&CHAPTER.replace("越来越少", "越来越多"));
+        assert_ne!(edited.hash, original.hash);
+        assert!(similarity(&original, &edited) >= EDITED_SIMILARITY);
+
+        let other =
+            fingerprint("第二章 归来\nThe next morning the gates opened early, and nobody spoke.");
+        assert!(similarity(&original, &other) < EDITED_SIMILARITY);
+        assert_eq!(similarity(&original, &original), 1.0);
+    }
+
+    #[test]
+    fn test_encoding_round_trip() {
+        let fingerprint = fingerprint(CHAPTER);
+        assert_eq!(decode(&encode(&fingerprint)), Some(fingerprint));
+        assert_eq!(decode("abc 12 3"), None);
+        assert_eq!(fnv1a(&[]), 0xcbf2_9ce4_8422_2325);
+    }
+}
@@ -0,0 +1,251 @@
+//! Per-book font subsets that grow as the book is read.
+//!
+//! Embedding a font, in an exported EPUB or with the reader's "embed font"
+//! option, only needs the glyphs the book uses, but subsetting against the
+//! whole book means gathering the text of every chapter each time. A bundle
+//! instead remembers the code points seen so far for a book and font, and
+//! `extend_font_bundle` only rebuilds the subset when a chapter brings new
+//! ones. Subsets keep glyph IDs (see `subset_font`), so each rebuilt subset
+//! lays out already-read text exactly like the last.
+//!
+//! Bundles are stored under an app-provided directory, one folder per book
+//! named by the BLAKE3 hash of the book ID, with a `.codepoints` and a
+//! `.ttf` file per font named by the hash of the font data.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+use anyhow::{anyhow, Result};
+
+use crate::api::error::ApiError;
+use crate::api::{font_converter, font_subset, pagination};
+
+const CODEPOINTS_EXTENSION: &str = "codepoints";
+const SUBSET_EXTENSION: &str = "ttf";
+
+static FONT_BUNDLES: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+/// A bundle after `extend_font_bundle`.
+#[derive(Debug, Clone)]
+pub struct FontBundle {
+    /// Code points recorded for the book, besides the basic Latin and
+    /// punctuation every subset keeps.
+    pub codepoints: u32,
+    /// Code points the call added; 0 when the subset was reused.
+    pub added: u32,
+    /// Size of the subset TTF.
+    pub bytes: u32,
+}
+
+/// Enable font bundles. Calling this again switches to the new directory.
+///
+/// # Arguments
+/// * `bundle_dir` - Directory owned by the bundles; created if missing
+#[flutter_rust_bridge::frb]
+pub fn init_font_bundles(bundle_dir: String) -> Result<(), ApiError> {
+    fs::create_dir_all(&bundle_dir)
+        .map_err(|e| anyhow!("Failed to create font bundle directory {bundle_dir}: {e}"))?;
+    *lock() = Some(PathBuf::from(bundle_dir));
+    Ok(())
+}
+
+/// Add the characters of newly read text to a book's bundle, rebuilding
+/// its subset if any are new.
+///
+/// # Arguments
+/// * `book_id` - Stable identifier of the book
+/// * `font_path` - TTF, OTF, WOFF or WOFF2 file of the full font
+/// * `text` - Text of the chapter being read
+#[flutter_rust_bridge::frb]
+pub fn extend_font_bundle(
+    book_id: String,
+    font_path: String,
+    text: String,
+) -> Result<FontBundle, ApiError> {
+    // Held throughout so concurrent calls for a book do not lose code points.
+    let dir = lock();
+    Ok(extend(initialized(&dir)?, &book_id, &font_path, &text)?)
+}
+
+/// The cumulative subset of a book's bundle, ready for embedding, or
+/// `None` if nothing was added for this book and font yet.
+///
+/// # Arguments
+/// * `book_id` - Stable identifier of the book
+/// * `font_path` - The font file passed to `extend_font_bundle`
+#[flutter_rust_bridge::frb]
+pub fn read_font_bundle(book_id: String, font_path: String) -> Result<Option<Vec<u8>>, ApiError> {
+    let dir = lock();
+    let hash = font_hash(&pagination::load_font(&font_path)?);
+    let path = bundle_path(initialized(&dir)?, &book_id, &hash, SUBSET_EXTENSION);
+    match fs::read(&path) {
+        Ok(data) => Ok(Some(data)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(anyhow!("Failed to read {}: {e}", path.display()).into()),
+    }
+}
+
+/// Delete every bundle of a book and return how many fonts it had.
+///
+/// # Arguments
+/// * `book_id` - Stable identifier of the book
+#[flutter_rust_bridge::frb]
+pub fn remove_font_bundles(book_id: String) -> Result<u32, ApiError> {
+    let dir = lock();
+    Ok(remove(initialized(&dir)?, &book_id)?)
+}
+
+fn lock() -> MutexGuard<'static, Option<PathBuf>> {
+    FONT_BUNDLES.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn initialized(dir: &Option<PathBuf>) -> Result<&Path> {
+    dir.as_deref()
+        .ok_or_else(|| anyhow!("Font bundles are not initialized"))
+}
+
+fn extend(dir: &Path, book_id: &str, font_path: &str, text: &str) -> Result<FontBundle> {
+    let source = pagination::load_font(font_path)?;
+    let hash = font_hash(&source);
+    let codepoints_path = bundle_path(dir, book_id, &hash, CODEPOINTS_EXTENSION);
+    let subset_path = bundle_path(dir, book_id, &hash, SUBSET_EXTENSION);
+
+    let mut codepoints = read_codepoints(&codepoints_path)?;
+    let before = codepoints.len();
+    codepoints.extend(
+        text.chars()
+            .filter(|c| !c.is_control())
+            .map(u32::from)
+            .filter(|&c| !font_subset::is_basic(c)),
+    );
+    let added = codepoints.len() - before;
+
+    if added > 0 || !subset_path.exists() {
+        let kept = codepoints
+            .iter()
+            .copied()
+            .chain(font_subset::basic())
+            .collect();
+        let font = font_converter::convert_font_to_ttf(source.to_vec())?;
+        let subset = font_subset::subset_codepoints(&font, &kept)?;
+        let book_dir = codepoints_path.parent().unwrap_or(dir);
+        fs::create_dir_all(book_dir)
+            .map_err(|e| anyhow!("Failed to create {}: {e}", book_dir.display()))?;
+        // Subset first: if the code points are not written, the next call
+        // adds them again and rebuilds.
+        font_converter::write_atomically(&subset_path, &subset)?;
+        let data: Vec<u8> = codepoints.iter().flat_map(|c| c.to_le_bytes()).collect();
+        font_converter::write_atomically(&codepoints_path, &data)?;
+    }
+
+    let bytes = fs::metadata(&subset_path)
+        .map_err(|e| anyhow!("Failed to read {}: {e}", subset_path.display()))?
+        .len();
+    Ok(FontBundle {
+        codepoints: codepoints.len() as u32,
+        added: added as u32,
+        bytes: bytes as u32,
+    })
+}
+
+fn remove(dir: &Path, book_id: &str) -> Result<u32> {
+    let book_dir = dir.join(blake3::hash(book_id.as_bytes()).to_hex().as_str());
+    let Ok(entries) = fs::read_dir(&book_dir) else {
+        return Ok(0);
+    };
+    let fonts = entries
+        .flatten()
+        .filter(|entry| {
+            entry
+                .path()
+                .extension()
+                .is_some_and(|e| e == SUBSET_EXTENSION)
+        })
+        .count();
+    fs::remove_dir_all(&book_dir)
+        .map_err(|e| anyhow!("Failed to remove {}: {e}", book_dir.display()))?;
+    Ok(fonts as u32)
+}
+
+/// The hash naming a font's bundle files.
+fn font_hash(data: &[u8]) -> String {
+    blake3::hash(data).to_hex()[..32].to_string()
+}
+
+fn bundle_path(dir: &Path, book_id: &str, font_hash: &str, extension: &str) -> PathBuf {
+    dir.join(blake3::hash(book_id.as_bytes()).to_hex().as_str())
+        .join(format!("{font_hash}.{extension}"))
+}
+
+/// Recorded code points, stored as little-endian `u32`s.
+fn read_codepoints(path: &Path) -> Result<BTreeSet<u32>> {
+    match fs::read(path) {
+        Ok(data) => Ok(data
+            .chunks_exact(4)
+            .map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+            .collect()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(BTreeSet::new()),
+        Err(e) => Err(anyhow!("Failed to read {}: {e}", path.display())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_dirs::temp_dir;
+    use crate::test_fonts::FontBuilder;
+    use ttf_parser::Face;
+
+    fn setup(name: &str) -> (PathBuf, String) {
+        let dir = temp_dir(&format!("font-bundle-{name}"));
+        let font = dir.join("font.ttf");
+        fs::write(&font, FontBuilder::with_chars("A天色渐晚").build()).unwrap();
+        (dir.join("bundles"), font.to_string_lossy().to_string())
+    }
+
+    fn subset(dir: &Path, font: &str) -> Vec<u8> {
+        let hash = font_hash(&fs::read(font).unwrap());
+        fs::read(bundle_path(dir, "book", &hash, SUBSET_EXTENSION)).unwrap()
+    }
+
+    #[test]
+    fn test_bundle_grows_with_new_characters() {
+        let (dir, font) = setup("grow");
+        let bundle = extend(&dir, "book", &font, "天色\n A").unwrap();
+        assert_eq!((bundle.codepoints, bundle.added), (2, 2));
+        let data = subset(&dir, &font);
+        let face = Face::parse(&data, 0).unwrap();
+        assert!(face.glyph_index('天').is_some() && face.glyph_index('A').is_some());
+        assert!(face.glyph_index('渐').is_none());
+
+        // Known characters reuse the subset as it is.
+        let bundle = extend(&dir, "book", &font, "色天").unwrap();
+        assert_eq!((bundle.codepoints, bundle.added), (2, 0));
+        assert_eq!(bundle.bytes as usize, data.len());
+
+        let bundle = extend(&dir, "book", &font, "渐晚").unwrap();
+        assert_eq!((bundle.codepoints, bundle.added), (4, 2));
+        let data = subset(&dir, &font);
+        let face = Face::parse(&data, 0).unwrap();
+        assert!(face.glyph_index('天').is_some() && face.glyph_index('晚').is_some());
+        fs::remove_dir_all(dir.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_remove_book_bundles() {
+        let (dir, font) = setup("remove");
+        assert_eq!(remove(&dir, "book").unwrap(), 0);
+        extend(&dir, "book", &font, "天").unwrap();
+        extend(&dir, "other", &font, "天").unwrap();
+        assert_eq!(remove(&dir, "book").unwrap(), 1);
+        assert!(
+            read_codepoints(&bundle_path(&dir, "book", "x", CODEPOINTS_EXTENSION))
+                .unwrap()
+                .is_empty()
+        );
+        assert!(dir.join(blake3::hash(b"other").to_hex().as_str()).exists());
+        fs::remove_dir_all(dir.parent().unwrap()).unwrap();
+    }
+}
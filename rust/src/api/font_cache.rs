@@ -0,0 +1,580 @@
+//! Persistent cache of converted fonts.
+//!
+//! Converted TTFs are stored under an app-provided directory, named by the
+//! BLAKE3 hash of the source bytes, so the same WOFF2 is only decoded once
+//! across app launches. Once the cache grows past its size cap, entries are
+//! evicted least-recently-used first; recency is the file's modification
+//! time, which is refreshed on every hit.
+//!
+//! `index.json` next to the entries records what the files cannot: each
+//! font's family name, the books that use it and whether the user pinned
+//! it. Pinned entries are never evicted, even past the cap. An entry's ID
+//! is its hash, which `font_cache_id` computes from the source bytes.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::time::SystemTime;
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+use ttf_parser::{name_id, Face};
+
+use crate::api::error::ApiError;
+use crate::api::font_converter;
+use crate::sfnt;
+
+const ENTRY_EXTENSION: &str = "ttf";
+const INDEX_FILE: &str = "index.json";
+
+static FONT_CACHE: Mutex<Option<FontCache>> = Mutex::new(None);
+/// Held while reading and rewriting the index, which batch conversions
+/// update from several threads.
+static INDEX_LOCK: Mutex<()> = Mutex::new(());
+
+/// Current size of the font cache.
+#[derive(Debug, Clone)]
+pub struct FontCacheStats {
+    pub entry_count: u32,
+    pub total_bytes: u32,
+    pub max_bytes: u32,
+}
+
+/// One cached font, for storage management.
+#[derive(Debug, Clone)]
+pub struct FontCacheEntry {
+    /// Stable ID; see `font_cache_id`.
+    pub id: String,
+    /// Family name read from the font when it was cached.
+    pub family_name: Option<String>,
+    pub size_bytes: u32,
+    /// Seconds since the Unix epoch.
+    pub last_used: i64,
+    /// Books linked with `link_font_cache_book`.
+    pub book_ids: Vec<String>,
+    /// Pinned entries are never evicted.
+    pub pinned: bool,
+}
+
+/// Enable the persistent font cache.
+///
+/// Once enabled, `convert_woff2_to_ttf` (and the functions built on it)
+/// return cached TTFs for WOFF2 data it has converted before. Calling this
+/// again switches to the new directory or size cap.
+///
+/// # Arguments
+/// * `cache_dir` - Directory owned by the cache; created if missing
+/// * `max_bytes` - Total size of cached TTFs to keep
+#[flutter_rust_bridge::frb]
+pub fn init_font_cache(cache_dir: String, max_bytes: u32) -> Result<(), ApiError> {
+    let cache = FontCache::new(PathBuf::from(cache_dir), max_bytes)?;
+    cache.evict()?;
+    *lock() = Some(cache);
+    Ok(())
+}
+
+/// Report the number and total size of cached fonts.
+#[flutter_rust_bridge::frb]
+pub fn font_cache_stats() -> Result<FontCacheStats, ApiError> {
+    Ok(initialized()?.stats()?)
+}
+
+/// Delete every cached font. The cache stays enabled.
+#[flutter_rust_bridge::frb]
+pub fn clear_font_cache() -> Result<(), ApiError> {
+    Ok(initialized()?.clear()?)
+}
+
+/// List cached fonts, most recently used first.
+#[flutter_rust_bridge::frb]
+pub fn list_font_cache_entries() -> Result<Vec<FontCacheEntry>, ApiError> {
+    Ok(initialized()?.list()?)
+}
+
+/// The cache ID of a font, from the bytes it was converted from.
+///
+/// # Arguments
+/// * `source_data` - Font bytes as passed to `convert_woff2_to_ttf`
+#[flutter_rust_bridge::frb]
+pub fn font_cache_id(source_data: Vec<u8>) -> String {
+    blake3::hash(&source_data).to_hex().to_string()
+}
+
+/// Record that a book uses a cached font, for `list_font_cache_entries`.
+/// Returns false if the font is not cached.
+///
+/// # Arguments
+/// * `id` - ID from `font_cache_id`
+/// * `book_id` - Library ID of the book
+#[flutter_rust_bridge::frb]
+pub fn link_font_cache_book(id: String, book_id: String) -> Result<bool, ApiError> {
+    Ok(initialized()?.update(&id, |info| {
+        if !info.book_ids.contains(&book_id) {
+            info.book_ids.push(book_id);
+        }
+    })?)
+}
+
+/// Forget a book in every cached font, such as when it is deleted, and
+/// return how many fonts it was linked to.
+///
+/// # Arguments
+/// * `book_id` - Library ID of the book
+#[flutter_rust_bridge::frb]
+pub fn unlink_font_cache_book(book_id: String) -> Result<u32, ApiError> {
+    Ok(initialized()?.unlink(&book_id)?)
+}
+
+/// Pin or unpin a cached font. Returns false if the font is not cached.
+///
+/// # Arguments
+/// * `id` - ID from `font_cache_id` or `list_font_cache_entries`
+/// * `pinned` - Whether eviction must keep the font
+#[flutter_rust_bridge::frb]
+pub fn pin_font_cache_entry(id: String, pinned: bool) -> Result<bool, ApiError> {
+    let cache = initialized()?;
+    let found = cache.update(&id, |info| info.pinned = pinned)?;
+    if found && !pinned {
+        cache.evict()?;
+    }
+    Ok(found)
+}
+
+/// Delete one cached font, pinned or not. Returns false if it was not
+/// cached.
+///
+/// # Arguments
+/// * `id` - ID from `font_cache_id` or `list_font_cache_entries`
+#[flutter_rust_bridge::frb]
+pub fn evict_font_cache_entry(id: String) -> Result<bool, ApiError> {
+    Ok(initialized()?.remove(&id)?)
+}
+
+/// Return the cached conversion of `source`, or run `convert` and cache its
+/// result. Without an initialized cache this is just `convert()`.
+pub(crate) fn get_or_convert(
+    source: &[u8],
+    convert: impl FnOnce() -> Result<Vec<u8>>,
+) -> Result<Vec<u8>> {
+    let Some(cache) = current() else {
+        return convert();
+    };
+    if let Some(ttf) = cache.get(source) {
+        return Ok(ttf);
+    }
+
+    let ttf = convert()?;
+    // A full disk or a racing writer only costs a future re-conversion.
+    let _ = cache.put(source, &ttf);
+    Ok(ttf)
+}
+
+fn lock() -> MutexGuard<'static, Option<FontCache>> {
+    FONT_CACHE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn current() -> Option<FontCache> {
+    lock().clone()
+}
+
+fn initialized() -> Result<FontCache> {
+    current().ok_or_else(|| anyhow!("Font cache is not initialized"))
+}
+
+#[derive(Clone)]
+struct FontCache {
+    dir: PathBuf,
+    max_bytes: u64,
+}
+
+struct Entry {
+    id: String,
+    path: PathBuf,
+    len: u64,
+    last_used: SystemTime,
+}
+
+/// What the index records about an entry.
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug, Clone, Default, PartialEq)]
+struct EntryInfo {
+    family_name: Option<String>,
+    book_ids: Vec<String>,
+    pinned: bool,
+}
+
+/// The index, by entry ID.
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug, Default)]
+struct CacheIndex(HashMap<String, EntryInfo>);
+
+impl CacheIndex {
+    fn load(dir: &Path) -> Self {
+        let Ok(data) = fs::read(dir.join(INDEX_FILE)) else {
+            return Self::default();
+        };
+        let value: Value = serde_json::from_slice(&data).unwrap_or_default();
+        let Some(entries) = value.as_object() else {
+            return Self::default();
+        };
+        let entries = entries
+            .iter()
+            .map(|(id, entry)| {
+                let info = EntryInfo {
+                    family_name: entry["familyName"].as_str().map(str::to_string),
+                    book_ids: entry["bookIds"]
+                        .as_array()
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|id| id.as_str().map(str::to_string))
+                        .collect(),
+                    pinned: entry["pinned"].as_bool().unwrap_or(false),
+                };
+                (id.clone(), info)
+            })
+            .collect();
+        Self(entries)
+    }
+
+    fn save(&self, dir: &Path) -> Result<()> {
+        let value: serde_json::Map<String, Value> = self
+            .0
+            .iter()
+            .filter(|(_, info)| **info != EntryInfo::default())
+            .map(|(id, info)| {
+                let entry = json!({
+                    "familyName": info.family_name,
+                    "bookIds": info.book_ids,
+                    "pinned": info.pinned,
+                });
+                (id.clone(), entry)
+            })
+            .collect();
+        font_converter::write_atomically(
+            &dir.join(INDEX_FILE),
+            Value::Object(value).to_string().as_bytes(),
+        )
+    }
+}
+
+impl FontCache {
+    fn new(dir: PathBuf, max_bytes: u32) -> Result<Self> {
+        fs::create_dir_all(&dir).map_err(|e| anyhow!("Failed to create {}: {e}", dir.display()))?;
+        Ok(Self {
+            dir,
+            max_bytes: max_bytes as u64,
+        })
+    }
+
+    fn entry_path(&self, source: &[u8]) -> PathBuf {
+        self.id_path(&blake3::hash(source).to_hex())
+    }
+
+    fn id_path(&self, id: &str) -> PathBuf {
+        self.dir.join(format!("{id}.{ENTRY_EXTENSION}"))
+    }
+
+    /// Path of a cached font by ID, or `None` if it is not cached. IDs come
+    /// from the app, and anything but a hash could name another file.
+    fn cached_path(&self, id: &str) -> Option<PathBuf> {
+        let path = self.id_path(id);
+        let valid = !id.is_empty() && id.bytes().all(|b| b.is_ascii_hexdigit());
+        Some(path).filter(|path| valid && path.is_file())
+    }
+
+    /// Run `f` on the index and save it afterwards.
+    fn with_index<T>(&self, f: impl FnOnce(&mut CacheIndex) -> T) -> Result<T> {
+        let _guard = INDEX_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let mut index = CacheIndex::load(&self.dir);
+        let result = f(&mut index);
+        index.save(&self.dir)?;
+        Ok(result)
+    }
+
+    fn get(&self, source: &[u8]) -> Option<Vec<u8>> {
+        let path = self.entry_path(source);
+        let ttf = fs::read(&path).ok()?;
+        touch(&path);
+        Some(ttf)
+    }
+
+    fn put(&self, source: &[u8], ttf: &[u8]) -> Result<()> {
+        if ttf.len() as u64 > self.max_bytes {
+            return Ok(());
+        }
+        font_converter::write_atomically(&self.entry_path(source), ttf)?;
+        let family_name = Face::parse(ttf, 0).ok().and_then(|face| {
+            sfnt::name_string(&face, name_id::TYPOGRAPHIC_FAMILY)
+                .or_else(|| sfnt::name_string(&face, name_id::FAMILY))
+        });
+        if family_name.is_some() {
+            let id = blake3::hash(source).to_hex().to_string();
+            self.with_index(|index| index.0.entry(id).or_default().family_name = family_name)?;
+        }
+        self.evict()
+    }
+
+    /// Remove least recently used unpinned entries until the cache fits
+    /// its cap.
+    fn evict(&self) -> Result<()> {
+        let mut entries = self.entries()?;
+        let mut total: u64 = entries.iter().map(|entry| entry.len).sum();
+        if total <= self.max_bytes {
+            return Ok(());
+        }
+        entries.sort_by_key(|entry| entry.last_used);
+
+        self.with_index(|index| {
+            for entry in entries {
+                if total <= self.max_bytes {
+                    break;
+                }
+                if index.0.get(&entry.id).is_some_and(|info| info.pinned) {
+                    continue;
+                }
+                if fs::remove_file(&entry.path).is_ok() {
+                    total -= entry.len;
+                    index.0.remove(&entry.id);
+                }
+            }
+        })
+    }
+
+    fn list(&self) -> Result<Vec<FontCacheEntry>> {
+        let mut entries = self.entries()?;
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.last_used));
+        let mut index = {
+            let _guard = INDEX_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+            CacheIndex::load(&self.dir)
+        };
+        Ok(entries
+            .into_iter()
+            .map(|entry| {
+                let info = index.0.remove(&entry.id).unwrap_or_default();
+                FontCacheEntry {
+                    id: entry.id,
+                    family_name: info.family_name,
+                    size_bytes: entry.len as u32,
+                    last_used: entry
+                        .last_used
+                        .duration_since(SystemTime::UNIX_EPOCH)
+                        .map_or(0, |d| d.as_secs() as i64),
+                    book_ids: info.book_ids,
+                    pinned: info.pinned,
+                }
+            })
+            .collect())
+    }
+
+    /// Change the index entry of a cached font; false if it is not cached.
+    fn update(&self, id: &str, f: impl FnOnce(&mut EntryInfo)) -> Result<bool> {
+        if self.cached_path(id).is_none() {
+            return Ok(false);
+        }
+        self.with_index(|index| f(index.0.entry(id.to_string()).or_default()))?;
+        Ok(true)
+    }
+
+    fn unlink(&self, book_id: &str) -> Result<u32> {
+        self.with_index(|index| {
+            let mut unlinked = 0;
+            for info in index.0.values_mut() {
+                let before = info.book_ids.len();
+                info.book_ids.retain(|id| id != book_id);
+                unlinked += (info.book_ids.len() < before) as u32;
+            }
+            unlinked
+        })
+    }
+
+    fn remove(&self, id: &str) -> Result<bool> {
+        let Some(path) = self.cached_path(id) else {
+            return Ok(false);
+        };
+        fs::remove_file(&path).map_err(|e| anyhow!("Failed to remove {}: {e}", path.display()))?;
+        self.with_index(|index| index.0.remove(id))?;
+        Ok(true)
+    }
+
+    fn stats(&self) -> Result<FontCacheStats> {
+        let entries = self.entries()?;
+        Ok(FontCacheStats {
+            entry_count: entries.len() as u32,
+            total_bytes: entries.iter().map(|entry| entry.len).sum::<u64>() as u32,
+            max_bytes: self.max_bytes as u32,
+        })
+    }
+
+    fn clear(&self) -> Result<()> {
+        for entry in self.entries()? {
+            fs::remove_file(&entry.path)
+                .map_err(|e| anyhow!("Failed to remove {}: {e}", entry.path.display()))?;
+        }
+        self.with_index(|index| index.0.clear())
+    }
+
+    fn entries(&self) -> Result<Vec<Entry>> {
+        let dir = fs::read_dir(&self.dir)
+            .map_err(|e| anyhow!("Failed to read {}: {e}", self.dir.display()))?;
+
+        let mut entries = Vec::new();
+        for item in dir.flatten() {
+            let path = item.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some(ENTRY_EXTENSION) {
+                continue;
+            }
+            let Ok(metadata) = item.metadata() else {
+                continue;
+            };
+            let Some(id) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            entries.push(Entry {
+                id: id.to_string(),
+                path,
+                len: metadata.len(),
+                last_used: metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+            });
+        }
+        Ok(entries)
+    }
+}
+
+/// Mark a cache entry as recently used.
+fn touch(path: &Path) {
+    if let Ok(file) = fs::File::options().write(true).open(path) {
+        let _ = file.set_modified(SystemTime::now());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_dirs::temp_dir;
+    use std::time::Duration;
+
+    fn temp_cache(name: &str, max_bytes: u32) -> FontCache {
+        FontCache::new(temp_dir(&format!("font-cache-{name}")), max_bytes).unwrap()
+    }
+
+    fn set_last_used(cache: &FontCache, source: &[u8], ago_secs: u64) {
+        let file = fs::File::options()
+            .write(true)
+            .open(cache.entry_path(source))
+            .unwrap();
+        file.set_modified(SystemTime::now() - Duration::from_secs(ago_secs))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_put_then_get_returns_cached_bytes() {
+        let cache = temp_cache("hit", 1024);
+        assert!(cache.get(b"woff2").is_none());
+
+        cache.put(b"woff2", b"ttf bytes").unwrap();
+        assert_eq!(cache.get(b"woff2").unwrap(), b"ttf bytes");
+        assert!(cache.get(b"other woff2").is_none());
+        fs::remove_dir_all(&cache.dir).unwrap();
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used_first() {
+        let cache = temp_cache("lru", 20);
+        cache.put(b"a", &[0; 8]).unwrap();
+        cache.put(b"b", &[0; 8]).unwrap();
+        set_last_used(&cache, b"a", 7200);
+        set_last_used(&cache, b"b", 3600);
+
+        // Reading `a` makes `b` the oldest entry.
+        assert!(cache.get(b"a").is_some());
+        cache.put(b"c", &[0; 8]).unwrap();
+
+        assert!(cache.get(b"a").is_some());
+        assert!(cache.get(b"b").is_none());
+        assert!(cache.get(b"c").is_some());
+        let stats = cache.stats().unwrap();
+        assert_eq!((stats.entry_count, stats.total_bytes), (2, 16));
+        fs::remove_dir_all(&cache.dir).unwrap();
+    }
+
+    #[test]
+    fn test_skips_entries_larger_than_cap() {
+        let cache = temp_cache("oversized", 4);
+        cache.put(b"a", &[0; 8]).unwrap();
+        assert!(cache.get(b"a").is_none());
+        fs::remove_dir_all(&cache.dir).unwrap();
+    }
+
+    #[test]
+    fn test_pinned_entries_survive_eviction() {
+        let cache = temp_cache("pin", 20);
+        cache.put(b"a", &[0; 8]).unwrap();
+        cache.put(b"b", &[0; 8]).unwrap();
+        set_last_used(&cache, b"a", 3600);
+        let a = font_cache_id(b"a".to_vec());
+        assert!(cache.update(&a, |info| info.pinned = true).unwrap());
+        assert!(!cache.update("0123", |info| info.pinned = true).unwrap());
+
+        cache.put(b"c", &[0; 8]).unwrap();
+        assert!(cache.get(b"a").is_some());
+        assert!(cache.get(b"b").is_none());
+        // Past the cap, a pinned entry stays and others go.
+        cache.put(b"d", &[0; 16]).unwrap();
+        assert_eq!(cache.stats().unwrap().entry_count, 1);
+        assert!(cache.get(b"a").is_some());
+        fs::remove_dir_all(&cache.dir).unwrap();
+    }
+
+    #[test]
+    fn test_lists_and_removes_single_entries() {
+        let cache = temp_cache("list", 1024);
+        cache.put(b"a", &[0; 8]).unwrap();
+        cache.put(b"b", &[0; 4]).unwrap();
+        set_last_used(&cache, b"a", 3600);
+        let (a, b) = (font_cache_id(b"a".to_vec()), font_cache_id(b"b".to_vec()));
+        for book in ["book1", "book2", "book1"] {
+            cache
+                .update(&a, |info| {
+                    if !info.book_ids.iter().any(|id| id == book) {
+                        info.book_ids.push(book.to_string());
+                    }
+                })
+                .unwrap();
+        }
+        assert_eq!(cache.unlink("book2").unwrap(), 1);
+
+        let entries = cache.list().unwrap();
+        let summary: Vec<_> = entries
+            .iter()
+            .map(|e| (e.id.as_str(), e.size_bytes, e.book_ids.clone()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (b.as_str(), 4, vec![]),
+                (a.as_str(), 8, vec!["book1".to_string()])
+            ]
+        );
+        assert!(entries[0].last_used > entries[1].last_used);
+
+        assert!(!cache.remove("../index").unwrap());
+        assert!(cache.remove(&a).unwrap());
+        assert!(!cache.remove(&a).unwrap());
+        assert_eq!(cache.list().unwrap().len(), 1);
+        assert!(CacheIndex::load(&cache.dir).0.is_empty());
+        fs::remove_dir_all(&cache.dir).unwrap();
+    }
+
+    #[test]
+    fn test_clear_removes_only_entries() {
+        let cache = temp_cache("clear", 1024);
+        cache.put(b"a", b"ttf").unwrap();
+        fs::write(cache.dir.join("notes.txt"), b"keep").unwrap();
+
+        cache.clear().unwrap();
+        assert_eq!(cache.stats().unwrap().entry_count, 0);
+        assert!(cache.dir.join("notes.txt").exists());
+        fs::remove_dir_all(&cache.dir).unwrap();
+    }
+}
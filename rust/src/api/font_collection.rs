@@ -0,0 +1,145 @@
+//! TrueType/OpenType Collection (TTC/OTC) support.
+//!
+//! System fonts such as `Songti.ttc` bundle several faces sharing glyph data.
+//! Flutter's FontLoader only accepts standalone fonts, so a single face has
+//! to be copied out into its own SFNT container before registration.
+
+use anyhow::{anyhow, Result};
+use ttf_parser::{name_id, Face};
+
+use crate::api::error::ApiError;
+use crate::sfnt;
+
+const TTC_SIGNATURE: &[u8; 4] = b"ttcf";
+
+/// A face contained in a font collection.
+#[derive(Debug, Clone)]
+pub struct CollectionFace {
+    /// Index to pass to `extract_collection_face`.
+    pub index: u32,
+    pub family_name: String,
+    pub style_name: String,
+    pub postscript_name: Option<String>,
+}
+
+/// List all faces in a TTC/OTC font collection.
+///
+/// Typographic family/subfamily names (IDs 16/17) are preferred over the
+/// legacy four-style names so that e.g. "Songti SC Light" groups under
+/// "Songti SC".
+#[flutter_rust_bridge::frb]
+pub fn list_collection_faces(ttc_data: Vec<u8>) -> Result<Vec<CollectionFace>, ApiError> {
+    let count = face_count(&ttc_data)?;
+    let mut faces = Vec::with_capacity(count as usize);
+
+    for index in 0..count {
+        let face = Face::parse(&ttc_data, index)
+            .map_err(|_| anyhow!("Invalid font face at index {index}"))?;
+        let family_name = sfnt::name_string(&face, name_id::TYPOGRAPHIC_FAMILY)
+            .or_else(|| sfnt::name_string(&face, name_id::FAMILY))
+            .unwrap_or_default();
+        let style_name = sfnt::name_string(&face, name_id::TYPOGRAPHIC_SUBFAMILY)
+            .or_else(|| sfnt::name_string(&face, name_id::SUBFAMILY))
+            .unwrap_or_default();
+
+        faces.push(CollectionFace {
+            index,
+            family_name,
+            style_name,
+            postscript_name: sfnt::name_string(&face, name_id::POST_SCRIPT_NAME),
+        });
+    }
+
+    Ok(faces)
+}
+
+/// Extract one face of a TTC/OTC collection as a standalone TTF/OTF.
+///
+/// # Arguments
+/// * `ttc_data` - Raw collection bytes
+/// * `index` - Face index as reported by `list_collection_faces`
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` - Standalone font bytes ready for FontLoader
+/// * `Err(_)` - If the collection is malformed or `index` is out of range
+#[flutter_rust_bridge::frb]
+pub fn extract_collection_face(ttc_data: Vec<u8>, index: u32) -> Result<Vec<u8>, ApiError> {
+    Ok(extract_face(&ttc_data, index)?)
+}
+
+pub(crate) fn extract_face(ttc_data: &[u8], index: u32) -> Result<Vec<u8>> {
+    let count = face_count(ttc_data)?;
+    if index >= count {
+        return Err(anyhow!(
+            "Face index {index} out of range ({count} faces in collection)"
+        ));
+    }
+
+    let offset = sfnt::read_u32(ttc_data, 12 + index as usize * 4)? as usize;
+    Ok(sfnt::parse_at(ttc_data, offset)?.build())
+}
+
+pub(crate) fn is_collection(data: &[u8]) -> bool {
+    data.starts_with(TTC_SIGNATURE)
+}
+
+fn face_count(ttc_data: &[u8]) -> Result<u32> {
+    if ttc_data.is_empty() {
+        return Err(anyhow!("Empty font data"));
+    }
+    if !is_collection(ttc_data) {
+        return Err(anyhow!("Invalid TTC signature"));
+    }
+
+    let count = sfnt::read_u32(ttc_data, 8)?;
+    if count == 0 {
+        return Err(anyhow!("Font collection contains no faces"));
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fonts::{collection, FontBuilder};
+
+    fn sample_collection() -> Vec<u8> {
+        collection(&[
+            FontBuilder::with_chars("a")
+                .family("Songti SC", "Light")
+                .build(),
+            FontBuilder::with_chars("bc")
+                .family("Songti SC", "Bold")
+                .build(),
+        ])
+    }
+
+    #[test]
+    fn test_list_collection_faces() {
+        let faces = list_collection_faces(sample_collection()).unwrap();
+        assert_eq!(faces.len(), 2);
+        assert_eq!(faces[0].family_name, "Songti SC");
+        assert_eq!(faces[0].style_name, "Light");
+        assert_eq!(faces[1].style_name, "Bold");
+        assert_eq!(faces[1].postscript_name.as_deref(), Some("Songti-SC-Bold"));
+    }
+
+    #[test]
+    fn test_extract_collection_face() {
+        let font = extract_collection_face(sample_collection(), 1).unwrap();
+        let face = Face::parse(&font, 0).unwrap();
+        assert!(face.glyph_index('c').is_some());
+        assert!(face.glyph_index('a').is_none());
+    }
+
+    #[test]
+    fn test_extract_rejects_out_of_range_index() {
+        assert!(extract_collection_face(sample_collection(), 2).is_err());
+    }
+
+    #[test]
+    fn test_rejects_non_collection() {
+        let font = FontBuilder::with_chars("a").build();
+        assert!(list_collection_faces(font).is_err());
+    }
+}
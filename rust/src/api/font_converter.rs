@@ -1,21 +1,49 @@
-//! WOFF2 to TTF font converter for Flutter integration.
+//! WOFF/WOFF2 to TTF font converter for Flutter integration.
 //!
-//! This module provides in-memory conversion of WOFF2 font data to TTF format,
-//! which can then be loaded by Flutter's FontLoader on all platforms.
+//! This module provides in-memory conversion of WOFF and WOFF2 font data to
+//! TTF format, which can then be loaded by Flutter's FontLoader on all
+//! platforms, as well as WOFF2 encoding for compact on-disk caching.
+
+use std::collections::HashSet;
+use std::fs;
+use std::io::{Read, Write};
+use std::panic;
+use std::path::Path;
 
 use anyhow::{anyhow, Result};
-use ttf_parser::Face;
+use flate2::read::ZlibDecoder;
+use rayon::prelude::*;
+use ttf_parser::{name_id, Face, GlyphId, OutlineBuilder, Tag};
+
+use crate::api::error::ApiError;
+use crate::api::{error, font_cache, font_collection, font_validation, task};
+use crate::frb_generated::StreamSink;
+use crate::sfnt::{self, put_i16, put_u16, put_u32};
+
+const WOFF_SIGNATURE: &[u8; 4] = b"wOFF";
+const WOFF2_SIGNATURE: &[u8; 4] = b"wOF2";
+const WOFF_HEADER_LEN: usize = 44;
+const WOFF_TABLE_ENTRY_LEN: usize = 20;
+/// Brotli quality used for WOFF2 encoding. 8 is woff2's own default and keeps
+/// encoding of large CJK fonts fast enough on low-end devices.
+const WOFF2_BROTLI_QUALITY: usize = 8;
+/// Chunk size used by `convert_woff2_to_ttf_chunked` when none is given.
+const DEFAULT_CHUNK_SIZE: u32 = 1024 * 1024;
 
 /// Convert WOFF2 bytes to TTF bytes.
 ///
-/// This is a pure in-memory operation - no file I/O is performed.
+/// This is a pure in-memory operation unless `init_font_cache` has been
+/// called, in which case previously converted fonts are read from the cache.
+///
+/// The decoded font is passed through `repair_ttf`, so fonts with stale
+/// checksums or truncated tables are fixed before they reach FontLoader.
 ///
 /// # Arguments
 /// * `woff2_data` - Raw WOFF2 font bytes (e.g., downloaded from server)
 ///
 /// # Returns
 /// * `Ok(Vec<u8>)` - TTF font bytes ready for FontLoader
-/// * `Err(_)` - If WOFF2 decoding fails
+/// * `Err(_)` - If WOFF2 decoding fails or the decoded font is beyond repair
 ///
 /// # Example (Dart side)
 /// ```dart
@@ -25,9 +53,9 @@ use ttf_parser::Face;
 /// await fontLoader.load();
 /// ```
 #[flutter_rust_bridge::frb]
-pub fn convert_woff2_to_ttf(woff2_data: Vec<u8>) -> Result<Vec<u8>> {
+pub fn convert_woff2_to_ttf(woff2_data: Vec<u8>) -> Result<Vec<u8>, ApiError> {
     if woff2_data.is_empty() {
-        return Err(anyhow!("Empty WOFF2 data"));
+        return Err(anyhow!("Empty WOFF2 data").into());
     }
 
     // Validate WOFF2 signature: 'wOF2' (0x774F4632)
@@ -37,12 +65,839 @@ pub fn convert_woff2_to_ttf(woff2_data: Vec<u8>) -> Result<Vec<u8>> {
         || woff2_data[2] != 0x46
         || woff2_data[3] != 0x32
     {
-        return Err(anyhow!("Invalid WOFF2 signature"));
+        return Err(anyhow!("Invalid WOFF2 signature").into());
+    }
+
+    Ok(font_cache::get_or_convert(&woff2_data, || {
+        // Perform conversion using woofwoof crate
+        // decompress returns Option<Vec<u8>>
+        let ttf =
+            woofwoof::decompress(&woff2_data).ok_or_else(|| anyhow!("WOFF2 decode failed"))?;
+
+        // Fix up checksums/truncated tables that would crash the iOS font loader
+        font_validation::repair(ttf)
+    })?)
+}
+
+/// Convert WOFF2 bytes to a TTF file on disk.
+///
+/// flutter_rust_bridge 2's SSE codec has no `ZeroCopyBuffer`, so returning
+/// the TTF always copies it into Dart. Writing it straight to the font cache
+/// keeps a single copy of a 20 MB CJK font alive instead of two. The file is
+/// written next to `ttf_path` and renamed into place, so an interrupted write
+/// never leaves a truncated cache entry behind.
+///
+/// # Arguments
+/// * `woff2_data` - Raw WOFF2 font bytes
+/// * `ttf_path` - Destination of the TTF file; the parent directory must exist
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+///
+/// # Returns
+/// * `Ok(u32)` - Size of the written TTF in bytes
+/// * `Err(_)` - If WOFF2 decoding fails or the file cannot be written
+#[flutter_rust_bridge::frb]
+pub async fn convert_woff2_to_ttf_file(
+    woff2_data: Vec<u8>,
+    ttf_path: String,
+    cancel_token: Option<u32>,
+) -> Result<u32, ApiError> {
+    task::run_blocking(task::cancel_token(cancel_token)?, move |token| {
+        let ttf = convert_woff2_to_ttf(woff2_data)?;
+        // Do not replace the file for a call that was given up on.
+        token.check()?;
+        write_atomically(Path::new(&ttf_path), &ttf)?;
+        Ok(ttf.len() as u32)
+    })
+    .await
+}
+
+/// A font passed to `convert_woff2_batch`.
+#[derive(Debug, Clone)]
+pub struct NamedFont {
+    /// Identifies the font in the results, e.g. its file name.
+    pub name: String,
+    pub data: Vec<u8>,
+}
+
+/// The outcome of converting one font of a batch.
+#[derive(Debug, Clone)]
+pub struct ConversionResult {
+    pub name: String,
+    /// TTF bytes, if the conversion succeeded.
+    pub ttf: Option<Vec<u8>>,
+    /// Why the conversion failed.
+    pub error: Option<String>,
+}
+
+/// Convert several WOFF2 fonts to TTF in parallel, such as the weights of a
+/// font pack. A font that fails to convert does not fail the others.
+///
+/// # Arguments
+/// * `fonts` - WOFF2 fonts with names to tell the results apart
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+///
+/// # Returns
+/// * `Ok(Vec<ConversionResult>)` - One result per font, in the order given
+/// * `Err(_)` - Only if the call was cancelled
+#[flutter_rust_bridge::frb]
+pub async fn convert_woff2_batch(
+    fonts: Vec<NamedFont>,
+    cancel_token: Option<u32>,
+) -> Result<Vec<ConversionResult>, ApiError> {
+    task::run_blocking(task::cancel_token(cancel_token)?, move |token| {
+        fonts
+            .into_par_iter()
+            .map(|font| {
+                token.check()?;
+                // A panic in one decoder fails that font instead of the batch.
+                let result = panic::catch_unwind(|| convert_woff2_to_ttf(font.data))
+                    .unwrap_or_else(|payload| Err(error::panic_error(payload.as_ref()).into()));
+                Ok(ConversionResult {
+                    name: font.name,
+                    error: result.as_ref().err().map(|e| e.to_string()),
+                    ttf: result.ok(),
+                })
+            })
+            .collect()
+    })
+    .await
+}
+
+/// A piece of the TTF produced by `convert_woff2_to_ttf_chunked`.
+#[derive(Debug, Clone)]
+pub struct TtfChunk {
+    /// Position of `data` within the TTF.
+    pub offset: u32,
+    /// Size of the whole TTF, for progress reporting.
+    pub total_len: u32,
+    pub data: Vec<u8>,
+}
+
+/// Convert WOFF2 bytes to TTF and stream the result in chunks.
+///
+/// Dart can append each chunk to a file and update a progress indicator as
+/// it arrives, instead of receiving the whole font as a single buffer.
+///
+/// # Arguments
+/// * `woff2_data` - Raw WOFF2 font bytes
+/// * `chunk_size` - Maximum chunk size in bytes, 1 MiB if `None`
+/// * `sink` - Receives the chunks in order; the stream closes after the last one
+///
+/// # Example (Dart side)
+/// ```dart
+/// final out = File(ttfPath).openWrite();
+/// await for (final chunk in convertWoff2ToTtfChunked(woff2Data: woff2Bytes)) {
+///   out.add(chunk.data);
+///   onProgress((chunk.offset + chunk.data.length) / chunk.totalLen);
+/// }
+/// await out.close();
+/// ```
+#[flutter_rust_bridge::frb]
+pub fn convert_woff2_to_ttf_chunked(
+    woff2_data: Vec<u8>,
+    chunk_size: Option<u32>,
+    sink: StreamSink<TtfChunk>,
+) -> Result<(), ApiError> {
+    let ttf = convert_woff2_to_ttf(woff2_data)?;
+    Ok(for_each_chunk(
+        &ttf,
+        chunk_size.unwrap_or(DEFAULT_CHUNK_SIZE),
+        |chunk| {
+            sink.add(chunk)
+                .map_err(|_| anyhow!("TTF chunk stream was closed"))
+        },
+    )?)
+}
+
+/// Split `data` into chunks of at most `chunk_size` bytes.
+fn for_each_chunk(
+    data: &[u8],
+    chunk_size: u32,
+    mut emit: impl FnMut(TtfChunk) -> Result<()>,
+) -> Result<()> {
+    if chunk_size == 0 {
+        return Err(anyhow!("Chunk size must be positive"));
+    }
+
+    let total_len = data.len() as u32;
+    for (index, chunk) in data.chunks(chunk_size as usize).enumerate() {
+        emit(TtfChunk {
+            offset: index as u32 * chunk_size,
+            total_len,
+            data: chunk.to_vec(),
+        })?;
+    }
+    Ok(())
+}
+
+/// Write `data` to a temporary sibling of `path`, then rename it over `path`.
+pub(crate) fn write_atomically(path: &Path, data: &[u8]) -> Result<()> {
+    let mut temp_name = path.as_os_str().to_owned();
+    temp_name.push(".tmp");
+    let temp_path = Path::new(&temp_name);
+
+    let written = fs::File::create(temp_path)
+        .and_then(|mut file| file.write_all(data).and_then(|()| file.sync_all()))
+        .and_then(|()| fs::rename(temp_path, path));
+    if let Err(e) = written {
+        let _ = fs::remove_file(temp_path);
+        return Err(anyhow!("Failed to write {}: {e}", path.display()));
+    }
+    Ok(())
+}
+
+/// Compress TTF/OTF bytes to WOFF2.
+///
+/// This is the reverse of `convert_woff2_to_ttf`, intended for storing fonts
+/// compressed in the on-disk cache and only decompressing at load time.
+///
+/// # Arguments
+/// * `ttf_data` - Raw TTF/OTF font bytes
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` - WOFF2 font bytes
+/// * `Err(_)` - If the input is not an SFNT font or encoding fails
+#[flutter_rust_bridge::frb]
+pub fn compress_ttf_to_woff2(ttf_data: Vec<u8>) -> Result<Vec<u8>, ApiError> {
+    if ttf_data.is_empty() {
+        return Err(anyhow!("Empty TTF data").into());
+    }
+    if !is_sfnt(&ttf_data) {
+        return Err(anyhow!("Invalid TTF signature").into());
+    }
+
+    Ok(
+        woofwoof::compress(&ttf_data, "", WOFF2_BROTLI_QUALITY, true)
+            .ok_or_else(|| anyhow!("WOFF2 encode failed"))?,
+    )
+}
+
+/// Convert WOFF (version 1) bytes to TTF bytes.
+///
+/// WOFF 1.0 stores each SFNT table individually, optionally zlib-compressed.
+/// Tables are inflated, reassembled into a regular SFNT container and
+/// checked with `repair_ttf`.
+///
+/// # Arguments
+/// * `woff_data` - Raw WOFF font bytes
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` - TTF font bytes ready for FontLoader
+/// * `Err(_)` - If the header or any table is malformed
+#[flutter_rust_bridge::frb]
+pub fn convert_woff_to_ttf(woff_data: Vec<u8>) -> Result<Vec<u8>, ApiError> {
+    if woff_data.is_empty() {
+        return Err(anyhow!("Empty WOFF data").into());
+    }
+    if !woff_data.starts_with(WOFF_SIGNATURE) {
+        return Err(anyhow!("Invalid WOFF signature").into());
+    }
+    if woff_data.len() < WOFF_HEADER_LEN {
+        return Err(anyhow!("Truncated WOFF header").into());
+    }
+
+    let flavor = sfnt::read_u32(&woff_data, 4)?;
+    let num_tables = sfnt::read_u16(&woff_data, 12)? as usize;
+    let mut tables = Vec::with_capacity(num_tables);
+
+    for index in 0..num_tables {
+        let entry = WOFF_HEADER_LEN + index * WOFF_TABLE_ENTRY_LEN;
+        let tag = woff_data
+            .get(entry..entry + 4)
+            .ok_or_else(|| anyhow!("Truncated WOFF table directory"))?;
+        let offset = sfnt::read_u32(&woff_data, entry + 4)? as usize;
+        let comp_length = sfnt::read_u32(&woff_data, entry + 8)? as usize;
+        let orig_length = sfnt::read_u32(&woff_data, entry + 12)? as usize;
+
+        let raw = offset
+            .checked_add(comp_length)
+            .and_then(|end| woff_data.get(offset..end))
+            .ok_or_else(|| anyhow!("WOFF table out of bounds"))?;
+
+        let data = if comp_length < orig_length {
+            let mut inflated = Vec::with_capacity(orig_length);
+            ZlibDecoder::new(raw)
+                .take(orig_length as u64 + 1)
+                .read_to_end(&mut inflated)
+                .map_err(|e| anyhow!("WOFF table decompression failed: {e}"))?;
+            if inflated.len() != orig_length {
+                return Err(anyhow!("WOFF table length mismatch").into());
+            }
+            inflated
+        } else if comp_length == orig_length {
+            raw.to_vec()
+        } else {
+            return Err(anyhow!("WOFF table larger than its original size").into());
+        };
+
+        tables.push(sfnt::Table {
+            tag: [tag[0], tag[1], tag[2], tag[3]],
+            data,
+        });
+    }
+
+    Ok(font_validation::repair(sfnt::build(flavor, tables))?)
+}
+
+/// Convert any supported web font (WOFF or WOFF2) to TTF bytes.
+///
+/// The format is detected from the file signature. Plain TTF/OTF data is
+/// returned unchanged and collections yield their first face, so callers can
+/// feed every downloaded or imported font through a single FontLoader path.
+#[flutter_rust_bridge::frb]
+pub fn convert_font_to_ttf(font_data: Vec<u8>) -> Result<Vec<u8>, ApiError> {
+    if font_data.len() < 4 {
+        return Err(anyhow!("Empty font data").into());
+    }
+
+    match &font_data[..4] {
+        sig if sig == WOFF2_SIGNATURE => convert_woff2_to_ttf(font_data),
+        sig if sig == WOFF_SIGNATURE => convert_woff_to_ttf(font_data),
+        _ if font_collection::is_collection(&font_data) => {
+            Ok(font_collection::extract_face(&font_data, 0)?)
+        }
+        _ if is_sfnt(&font_data) => Ok(font_data),
+        _ => Err(anyhow!("Unrecognized font signature").into()),
+    }
+}
+
+fn is_sfnt(data: &[u8]) -> bool {
+    data.len() >= 4
+        && (data[..4] == sfnt::TRUETYPE_FLAVOR.to_be_bytes()
+            || &data[..4] == b"OTTO"
+            || &data[..4] == b"true")
+}
+
+/// Metadata describing a font face, read from its `name`, `OS/2` and `fvar`
+/// tables.
+#[derive(Debug, Clone)]
+pub struct FontInfo {
+    /// Typographic family name, falling back to the legacy family name.
+    pub family_name: String,
+    /// Typographic subfamily (style) name, falling back to the legacy one.
+    pub style_name: String,
+    pub full_name: Option<String>,
+    pub postscript_name: Option<String>,
+    /// `OS/2.usWeightClass` (100–900).
+    pub weight: u16,
+    pub is_italic: bool,
+    pub is_monospaced: bool,
+    pub is_variable: bool,
+    /// Variation axes; empty for static fonts.
+    pub axes: Vec<FontAxis>,
+    /// Named instances declared in `fvar` (e.g. "Bold", "Condensed Light").
+    pub named_instances: Vec<NamedInstance>,
+    pub units_per_em: u16,
+    pub glyph_count: u16,
+}
+
+/// A single variation axis of a variable font.
+#[derive(Debug, Clone)]
+pub struct FontAxis {
+    /// Four-character axis tag, e.g. `wght`.
+    pub tag: String,
+    pub name: Option<String>,
+    pub min_value: f32,
+    pub default_value: f32,
+    pub max_value: f32,
+    pub hidden: bool,
+}
+
+/// A named instance of a variable font.
+#[derive(Debug, Clone)]
+pub struct NamedInstance {
+    pub name: Option<String>,
+    pub postscript_name: Option<String>,
+    /// Axis values selecting this instance; pass to `instantiate_variable_font`.
+    pub coordinates: Vec<AxisValue>,
+}
+
+/// A user-space value for one variation axis.
+#[derive(Debug, Clone)]
+pub struct AxisValue {
+    /// Four-character axis tag, e.g. `wght`.
+    pub tag: String,
+    pub value: f32,
+}
+
+/// Inspect a font's real family name, weight, style and variation axes.
+///
+/// Accepts the same inputs as `convert_font_to_ttf` (TTF/OTF, WOFF, WOFF2 and
+/// collections, which report their first face), so metadata can be read
+/// straight from downloaded bytes before registration.
+#[flutter_rust_bridge::frb]
+pub fn inspect_font(font_data: Vec<u8>) -> Result<FontInfo, ApiError> {
+    let sfnt_data = convert_font_to_ttf(font_data)?;
+    let face = Face::parse(&sfnt_data, 0).map_err(|_| anyhow!("Invalid font data"))?;
+
+    let family_name = sfnt::name_string(&face, name_id::TYPOGRAPHIC_FAMILY)
+        .or_else(|| sfnt::name_string(&face, name_id::FAMILY))
+        .unwrap_or_default();
+    let style_name = sfnt::name_string(&face, name_id::TYPOGRAPHIC_SUBFAMILY)
+        .or_else(|| sfnt::name_string(&face, name_id::SUBFAMILY))
+        .unwrap_or_default();
+
+    let axes = face
+        .variation_axes()
+        .into_iter()
+        .map(|axis| FontAxis {
+            tag: String::from_utf8_lossy(&axis.tag.to_bytes()).into_owned(),
+            name: sfnt::name_string(&face, axis.name_id),
+            min_value: axis.min_value,
+            default_value: axis.def_value,
+            max_value: axis.max_value,
+            hidden: axis.hidden,
+        })
+        .collect();
+
+    Ok(FontInfo {
+        family_name,
+        style_name,
+        full_name: sfnt::name_string(&face, name_id::FULL_NAME),
+        postscript_name: sfnt::name_string(&face, name_id::POST_SCRIPT_NAME),
+        weight: face.weight().to_number(),
+        is_italic: face.is_italic(),
+        is_monospaced: face.is_monospaced(),
+        is_variable: face.is_variable(),
+        axes,
+        named_instances: named_instances(&face),
+        units_per_em: face.units_per_em(),
+        glyph_count: face.number_of_glyphs(),
+    })
+}
+
+/// Parse the named instance records of `fvar`, which ttf-parser skips.
+fn named_instances(face: &Face) -> Vec<NamedInstance> {
+    let Some(fvar) = face.raw_face().table(Tag::from_bytes(b"fvar")) else {
+        return Vec::new();
+    };
+    let read = |offset: usize| sfnt::read_u16(fvar, offset).ok();
+    let (Some(axes_offset), Some(axis_count), Some(axis_size), Some(count), Some(size)) =
+        (read(4), read(8), read(10), read(12), read(14))
+    else {
+        return Vec::new();
+    };
+
+    let tags: Vec<String> = face
+        .variation_axes()
+        .into_iter()
+        .map(|axis| String::from_utf8_lossy(&axis.tag.to_bytes()).into_owned())
+        .collect();
+    let (axis_count, size) = (axis_count as usize, size as usize);
+    let start = axes_offset as usize + axis_count * axis_size as usize;
+
+    let mut instances = Vec::new();
+    for index in 0..count as usize {
+        let record = start + index * size;
+        let Some(subfamily_id) = read(record) else {
+            break;
+        };
+        let mut coordinates = Vec::with_capacity(axis_count);
+        for (axis, tag) in tags.iter().enumerate().take(axis_count) {
+            let Ok(fixed) = sfnt::read_u32(fvar, record + 4 + axis * 4) else {
+                break;
+            };
+            coordinates.push(AxisValue {
+                tag: tag.clone(),
+                value: fixed as i32 as f32 / 65536.0,
+            });
+        }
+        // The optional postScriptNameID follows the coordinates.
+        let postscript_name = if size >= 6 + axis_count * 4 {
+            read(record + 4 + axis_count * 4)
+                .filter(|&id| id != 0xFFFF)
+                .and_then(|id| sfnt::name_string(face, id))
+        } else {
+            None
+        };
+
+        instances.push(NamedInstance {
+            name: sfnt::name_string(face, subfamily_id),
+            postscript_name,
+            coordinates,
+        });
+    }
+    instances
+}
+
+/// Tables that only make sense for a variable font, or whose contents refer
+/// to the original outlines' point numbering (hinting).
+const VARIATION_TABLES: &[&[u8; 4]] = &[
+    b"fvar", b"gvar", b"avar", b"cvar", b"HVAR", b"VVAR", b"MVAR", b"STAT", b"hdmx", b"LTSH",
+    b"VDMX", b"DSIG", b"fpgm", b"prep", b"cvt ",
+];
+
+/// Instantiate a static font from a TrueType variable font.
+///
+/// Every glyph is re-outlined at the requested axis location and written as
+/// a simple (non-composite) glyph, horizontal advances are taken from `HVAR`
+/// when present, and the variation tables are dropped so Flutter sees an
+/// ordinary static font. Axes not mentioned in `axes` keep their defaults;
+/// pass a named instance's coordinates from `inspect_font` to select it.
+///
+/// # Arguments
+/// * `ttf_data` - TrueType-flavored variable font bytes
+/// * `axes` - Axis values in user space, e.g. `wght = 700`
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` - Static TTF bytes ready for FontLoader
+/// * `Err(_)` - If the font is not variable, uses CFF2 outlines, or an axis tag is unknown
+#[flutter_rust_bridge::frb]
+pub fn instantiate_variable_font(
+    ttf_data: Vec<u8>,
+    axes: Vec<AxisValue>,
+) -> Result<Vec<u8>, ApiError> {
+    let mut face = Face::parse(&ttf_data, 0).map_err(|_| anyhow!("Invalid TTF data"))?;
+    if !face.is_variable() {
+        return Err(anyhow!("Font is not a variable font").into());
+    }
+    let mut font = sfnt::parse(&ttf_data)?;
+    if font.table(b"glyf").is_none() {
+        return Err(anyhow!("Only TrueType (glyf) variable fonts can be instantiated").into());
+    }
+
+    for axis in &axes {
+        let tag = axis.tag.as_bytes();
+        if tag.len() != 4 {
+            return Err(anyhow!("Invalid axis tag '{}'", axis.tag).into());
+        }
+        let tag = Tag::from_bytes(&[tag[0], tag[1], tag[2], tag[3]]);
+        if !face
+            .variation_axes()
+            .into_iter()
+            .any(|known| known.tag == tag)
+        {
+            return Err(anyhow!("Unknown variation axis '{}'", axis.tag).into());
+        }
+        face.set_variation(tag, axis.value)
+            .ok_or_else(|| anyhow!("Failed to set variation axis '{}'", axis.tag))?;
+    }
+
+    let num_glyphs = face.number_of_glyphs();
+    let mut glyf = Vec::new();
+    let mut loca = Vec::with_capacity((num_glyphs as usize + 1) * 4);
+    let mut hmtx = Vec::with_capacity(num_glyphs as usize * 4);
+    let mut bounds: Option<(i16, i16, i16, i16)> = None;
+    let (mut max_points, mut max_contours) = (0u16, 0u16);
+    let (mut max_advance, mut min_lsb, mut min_rsb, mut max_extent) =
+        (0u16, i16::MAX, i16::MAX, i16::MIN);
+
+    for glyph_id in 0..num_glyphs {
+        let glyph_id = GlyphId(glyph_id);
+        let mut collector = ContourCollector::default();
+        face.outline_glyph(glyph_id, &mut collector);
+        let contours = collector.finish();
+
+        put_u32(&mut loca, glyf.len() as u32);
+        let bbox = encode_simple_glyph(&contours, &mut glyf);
+        glyf.resize((glyf.len() + 3) & !3, 0);
+
+        let advance = face.glyph_hor_advance(glyph_id).unwrap_or(0);
+        let lsb = bbox.map_or(0, |(x_min, ..)| x_min);
+        put_u16(&mut hmtx, advance);
+        put_i16(&mut hmtx, lsb);
+        max_advance = max_advance.max(advance);
+
+        if let Some((x_min, y_min, x_max, y_max)) = bbox {
+            let points: usize = contours.iter().map(Vec::len).sum();
+            max_points = max_points.max(points as u16);
+            max_contours = max_contours.max(contours.len() as u16);
+            min_lsb = min_lsb.min(x_min);
+            min_rsb = min_rsb.min((advance as i32 - x_max as i32) as i16);
+            max_extent = max_extent.max(x_max);
+            bounds = Some(match bounds {
+                Some((a, b, c, d)) => (a.min(x_min), b.min(y_min), c.max(x_max), d.max(y_max)),
+                None => (x_min, y_min, x_max, y_max),
+            });
+        }
+    }
+    put_u32(&mut loca, glyf.len() as u32);
+
+    let mut head = font
+        .table(b"head")
+        .filter(|head| head.len() >= 54)
+        .ok_or_else(|| anyhow!("Missing or truncated head table"))?
+        .to_vec();
+    let (x_min, y_min, x_max, y_max) = bounds.unwrap_or_default();
+    for (offset, value) in [(36, x_min), (38, y_min), (40, x_max), (42, y_max)] {
+        head[offset..offset + 2].copy_from_slice(&value.to_be_bytes());
+    }
+    head[50..52].copy_from_slice(&1u16.to_be_bytes()); // long loca
+
+    let mut hhea = font
+        .table(b"hhea")
+        .filter(|hhea| hhea.len() >= 36)
+        .ok_or_else(|| anyhow!("Missing or truncated hhea table"))?
+        .to_vec();
+    if bounds.is_none() {
+        (min_lsb, min_rsb, max_extent) = (0, 0, 0);
+    }
+    hhea[10..12].copy_from_slice(&max_advance.to_be_bytes());
+    hhea[12..14].copy_from_slice(&min_lsb.to_be_bytes());
+    hhea[14..16].copy_from_slice(&min_rsb.to_be_bytes());
+    hhea[16..18].copy_from_slice(&max_extent.to_be_bytes());
+    hhea[34..36].copy_from_slice(&num_glyphs.to_be_bytes());
+
+    if let Some(maxp) = font.table(b"maxp").filter(|maxp| maxp.len() >= 32) {
+        let mut maxp = maxp.to_vec();
+        maxp[6..8].copy_from_slice(&max_points.to_be_bytes());
+        maxp[8..10].copy_from_slice(&max_contours.to_be_bytes());
+        maxp[10..14].fill(0); // maxCompositePoints/Contours
+        maxp[28..32].fill(0); // maxComponentElements/Depth
+        font.set_table(*b"maxp", maxp);
+    }
+
+    if let Some(os2) = font.table(b"OS/2").filter(|os2| os2.len() >= 8) {
+        let mut os2 = os2.to_vec();
+        for axis in &axes {
+            match axis.tag.as_str() {
+                "wght" => {
+                    let weight = axis.value.round().clamp(1.0, 1000.0) as u16;
+                    os2[4..6].copy_from_slice(&weight.to_be_bytes());
+                }
+                "wdth" => {
+                    os2[6..8].copy_from_slice(&width_class(axis.value).to_be_bytes());
+                }
+                _ => {}
+            }
+        }
+        font.set_table(*b"OS/2", os2);
+    }
+
+    font.set_table(*b"head", head);
+    font.set_table(*b"hhea", hhea);
+    font.set_table(*b"glyf", glyf);
+    font.set_table(*b"loca", loca);
+    font.set_table(*b"hmtx", hmtx);
+    for tag in VARIATION_TABLES {
+        font.remove_table(tag);
+    }
+
+    Ok(font.build())
+}
+
+/// Map a `wdth` axis percentage to the nearest `OS/2.usWidthClass`.
+fn width_class(percent: f32) -> u16 {
+    const CLASSES: [f32; 9] = [50.0, 62.5, 75.0, 87.5, 100.0, 112.5, 125.0, 150.0, 200.0];
+    CLASSES
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| {
+            (*a - percent)
+                .abs()
+                .partial_cmp(&(*b - percent).abs())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .map_or(5, |(index, _)| index as u16 + 1)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct ContourPoint {
+    x: f32,
+    y: f32,
+    on_curve: bool,
+}
+
+/// Collects a glyph outline as TrueType contours (on-curve points plus
+/// quadratic off-curve control points).
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+struct ContourCollector {
+    contours: Vec<Vec<ContourPoint>>,
+    current: Vec<ContourPoint>,
+}
+
+impl ContourCollector {
+    fn push(&mut self, x: f32, y: f32, on_curve: bool) {
+        self.current.push(ContourPoint { x, y, on_curve });
+    }
+
+    fn end_contour(&mut self) {
+        let mut contour = std::mem::take(&mut self.current);
+        // Outlines explicitly return to the start point; TrueType closes
+        // contours implicitly.
+        if contour.len() > 1 && contour.first() == contour.last() {
+            contour.pop();
+        }
+
+        // Drop on-curve points that are exactly the midpoint of two off-curve
+        // neighbors: TrueType implies them, and storing them rounded would
+        // both bloat `glyf` and shift the curve by half a unit.
+        let len = contour.len();
+        if len > 2 {
+            let implied: Vec<bool> = (0..len)
+                .map(|index| {
+                    let point = contour[index];
+                    let prev = contour[(index + len - 1) % len];
+                    let next = contour[(index + 1) % len];
+                    point.on_curve
+                        && !prev.on_curve
+                        && !next.on_curve
+                        && ((prev.x + next.x) / 2.0 - point.x).abs() < 1e-3
+                        && ((prev.y + next.y) / 2.0 - point.y).abs() < 1e-3
+                })
+                .collect();
+            let mut index = 0;
+            contour.retain(|_| {
+                index += 1;
+                !implied[index - 1]
+            });
+        }
+
+        if !contour.is_empty() {
+            self.contours.push(contour);
+        }
+    }
+
+    fn finish(mut self) -> Vec<Vec<ContourPoint>> {
+        self.end_contour();
+        self.contours
+    }
+}
+
+impl OutlineBuilder for ContourCollector {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.end_contour();
+        self.push(x, y, true);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.push(x, y, true);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.push(x1, y1, false);
+        self.push(x, y, true);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        // glyf outlines never produce cubics; approximate defensively with a
+        // single quadratic through the midpoint of the control polygon.
+        let (x0, y0) = self.current.last().map_or((x1, y1), |p| (p.x, p.y));
+        let cx = (3.0 * (x1 + x2) - x0 - x) / 4.0;
+        let cy = (3.0 * (y1 + y2) - y0 - y) / 4.0;
+        self.quad_to(cx, cy, x, y);
     }
 
-    // Perform conversion using woofwoof crate
-    // decompress returns Option<Vec<u8>>
-    woofwoof::decompress(&woff2_data).ok_or_else(|| anyhow!("WOFF2 decode failed"))
+    fn close(&mut self) {
+        self.end_contour();
+    }
+}
+
+/// Append a simple glyph without instructions to `out` and return its
+/// bounding box, or `None` (writing nothing) for empty glyphs.
+fn encode_simple_glyph(
+    contours: &[Vec<ContourPoint>],
+    out: &mut Vec<u8>,
+) -> Option<(i16, i16, i16, i16)> {
+    const ON_CURVE: u8 = 0x01;
+    const X_SHORT: u8 = 0x02;
+    const Y_SHORT: u8 = 0x04;
+    const X_SAME_OR_POSITIVE: u8 = 0x10;
+    const Y_SAME_OR_POSITIVE: u8 = 0x20;
+
+    let points: Vec<(i16, i16, bool)> = contours
+        .iter()
+        .flatten()
+        .map(|p| (p.x.round() as i16, p.y.round() as i16, p.on_curve))
+        .collect();
+    if points.is_empty() {
+        return None;
+    }
+
+    let x_min = points.iter().map(|p| p.0).min()?;
+    let y_min = points.iter().map(|p| p.1).min()?;
+    let x_max = points.iter().map(|p| p.0).max()?;
+    let y_max = points.iter().map(|p| p.1).max()?;
+
+    put_i16(out, contours.len() as i16);
+    for value in [x_min, y_min, x_max, y_max] {
+        put_i16(out, value);
+    }
+    let mut end = 0usize;
+    for contour in contours {
+        end += contour.len();
+        put_u16(out, (end - 1) as u16);
+    }
+    put_u16(out, 0); // instructionLength
+
+    let mut flags = Vec::with_capacity(points.len());
+    let mut xs = Vec::new();
+    let mut ys = Vec::new();
+    let (mut last_x, mut last_y) = (0i16, 0i16);
+    for &(x, y, on_curve) in &points {
+        let mut flag = if on_curve { ON_CURVE } else { 0 };
+        for (delta, short, same, coords) in [
+            (x.wrapping_sub(last_x), X_SHORT, X_SAME_OR_POSITIVE, &mut xs),
+            (y.wrapping_sub(last_y), Y_SHORT, Y_SAME_OR_POSITIVE, &mut ys),
+        ] {
+            if delta == 0 {
+                flag |= same;
+            } else if delta.unsigned_abs() < 256 {
+                flag |= short;
+                if delta > 0 {
+                    flag |= same;
+                }
+                coords.push(delta.unsigned_abs() as u8);
+            } else {
+                coords.extend_from_slice(&delta.to_be_bytes());
+            }
+        }
+        flags.push(flag);
+        (last_x, last_y) = (x, y);
+    }
+    out.extend_from_slice(&flags);
+    out.extend_from_slice(&xs);
+    out.extend_from_slice(&ys);
+
+    Some((x_min, y_min, x_max, y_max))
+}
+
+/// Result of checking a font's glyph coverage against some text.
+#[derive(Debug, Clone)]
+pub struct CoverageReport {
+    /// Number of distinct code points in the text that need a glyph.
+    pub total_codepoints: u32,
+    /// Distinct code points the font has no cmap entry for, in order of first
+    /// appearance.
+    pub missing_codepoints: Vec<u32>,
+    /// Fraction of `total_codepoints` the font covers, in `0.0..=1.0`.
+    pub coverage: f64,
+}
+
+/// Check which code points in `text` the font cannot render.
+///
+/// Whitespace and control characters are ignored since they never need a
+/// glyph. The app can use the report to decide whether to fall back to the
+/// system font for a chapter.
+#[flutter_rust_bridge::frb]
+pub fn check_coverage(font_data: Vec<u8>, text: String) -> Result<CoverageReport, ApiError> {
+    let sfnt_data = convert_font_to_ttf(font_data)?;
+    let face = Face::parse(&sfnt_data, 0).map_err(|_| anyhow!("Invalid font data"))?;
+
+    let mut seen = HashSet::new();
+    let mut missing_codepoints = Vec::new();
+    for ch in text.chars() {
+        if ch.is_whitespace() || ch.is_control() || !seen.insert(ch) {
+            continue;
+        }
+        if face.glyph_index(ch).is_none() {
+            missing_codepoints.push(ch as u32);
+        }
+    }
+
+    let total_codepoints = seen.len() as u32;
+    let coverage = if total_codepoints == 0 {
+        1.0
+    } else {
+        1.0 - missing_codepoints.len() as f64 / total_codepoints as f64
+    };
+
+    Ok(CoverageReport {
+        total_codepoints,
+        missing_codepoints,
+        coverage,
+    })
 }
 
 /// Find code points that map to zero-advance, empty glyphs in a TTF font.
@@ -50,9 +905,9 @@ pub fn convert_woff2_to_ttf(woff2_data: Vec<u8>) -> Result<Vec<u8>> {
 /// These are effectively invisible placeholders in the obfuscation font.
 /// Web rendering swallows them naturally, but Flutter may leave visible holes.
 #[flutter_rust_bridge::frb]
-pub fn extract_invisible_codepoints(ttf_data: Vec<u8>) -> Result<Vec<u32>> {
+pub fn extract_invisible_codepoints(ttf_data: Vec<u8>) -> Result<Vec<u32>, ApiError> {
     if ttf_data.is_empty() {
-        return Err(anyhow!("Empty TTF data"));
+        return Err(anyhow!("Empty TTF data").into());
     }
 
     let face = Face::parse(&ttf_data, 0).map_err(|_| anyhow!("Invalid TTF data"))?;
@@ -84,6 +939,8 @@ pub fn extract_invisible_codepoints(ttf_data: Vec<u8>) -> Result<Vec<u32>> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_dirs::temp_dir;
+    use crate::test_fonts::{fvar, FontBuilder, Glyph};
 
     #[test]
     fn test_empty_input() {
@@ -97,6 +954,346 @@ mod tests {
         assert!(result.is_err());
     }
 
+    /// Wrap raw SFNT tables into a WOFF 1.0 container, compressing the ones
+    /// that actually shrink.
+    fn encode_woff(flavor: u32, tables: &[([u8; 4], Vec<u8>)]) -> Vec<u8> {
+        use flate2::{write::ZlibEncoder, Compression};
+        use std::io::Write;
+
+        let mut directory = Vec::new();
+        let mut body = Vec::new();
+        let data_start = WOFF_HEADER_LEN + tables.len() * WOFF_TABLE_ENTRY_LEN;
+        for (tag, data) in tables {
+            let mut encoder = ZlibEncoder::new(Vec::new(), Compression::best());
+            encoder.write_all(data).unwrap();
+            let compressed = encoder.finish().unwrap();
+            let stored = if compressed.len() < data.len() {
+                compressed
+            } else {
+                data.clone()
+            };
+
+            directory.extend_from_slice(tag);
+            directory.extend_from_slice(&((data_start + body.len()) as u32).to_be_bytes());
+            directory.extend_from_slice(&(stored.len() as u32).to_be_bytes());
+            directory.extend_from_slice(&(data.len() as u32).to_be_bytes());
+            directory.extend_from_slice(&sfnt::checksum(data).to_be_bytes());
+            body.extend_from_slice(&stored);
+            body.resize((body.len() + 3) & !3, 0);
+        }
+
+        let mut out = Vec::new();
+        out.extend_from_slice(WOFF_SIGNATURE);
+        out.extend_from_slice(&flavor.to_be_bytes());
+        out.extend_from_slice(&((data_start + body.len()) as u32).to_be_bytes());
+        out.extend_from_slice(&(tables.len() as u16).to_be_bytes());
+        out.resize(WOFF_HEADER_LEN, 0);
+        out.extend_from_slice(&directory);
+        out.extend_from_slice(&body);
+        out
+    }
+
+    fn woff_from_font(font: &[u8]) -> Vec<u8> {
+        let parsed = sfnt::parse(font).unwrap();
+        let tables: Vec<_> = parsed
+            .tables
+            .iter()
+            .map(|table| (table.tag, table.data.clone()))
+            .collect();
+        encode_woff(parsed.flavor, &tables)
+    }
+
+    #[test]
+    fn test_woff_roundtrip_restores_tables() {
+        let font = FontBuilder::with_chars("字体").build();
+        let ttf = convert_woff_to_ttf(woff_from_font(&font)).unwrap();
+        assert_eq!(ttf, font);
+    }
+
+    #[test]
+    fn test_woff_rejects_out_of_bounds_table() {
+        let mut woff = woff_from_font(&FontBuilder::with_chars("a").build());
+        woff[WOFF_HEADER_LEN + 8..WOFF_HEADER_LEN + 12].copy_from_slice(&100_000u32.to_be_bytes());
+        assert!(convert_woff_to_ttf(woff).is_err());
+    }
+
+    #[test]
+    fn test_woff_rejects_unrenderable_font() {
+        let woff = encode_woff(sfnt::TRUETYPE_FLAVOR, &[(*b"glyf", vec![1, 2, 3, 4])]);
+        assert!(convert_woff_to_ttf(woff).is_err());
+    }
+
+    #[test]
+    fn test_convert_font_detects_format() {
+        let font = FontBuilder::with_chars("a").build();
+        assert_eq!(convert_font_to_ttf(woff_from_font(&font)).unwrap(), font);
+
+        let ttf = vec![0x00, 0x01, 0x00, 0x00, 0x00, 0x00];
+        assert_eq!(convert_font_to_ttf(ttf.clone()).unwrap(), ttf);
+
+        assert!(convert_font_to_ttf(b"GIF89a".to_vec()).is_err());
+    }
+
+    #[test]
+    fn test_woff2_roundtrip() {
+        let font = FontBuilder::with_chars("字体").build();
+        let woff2 = compress_ttf_to_woff2(font.clone()).unwrap();
+        assert!(woff2.starts_with(WOFF2_SIGNATURE));
+
+        let ttf = convert_woff2_to_ttf(woff2).unwrap();
+        let original = Face::parse(&font, 0).unwrap();
+        let restored = Face::parse(&ttf, 0).unwrap();
+        assert_eq!(restored.number_of_glyphs(), original.number_of_glyphs());
+        for ch in "字体".chars() {
+            let glyph = restored.glyph_index(ch).unwrap();
+            assert_eq!(original.glyph_index(ch), Some(glyph));
+            assert_eq!(
+                restored.glyph_bounding_box(glyph),
+                original.glyph_bounding_box(glyph)
+            );
+        }
+    }
+
+    #[test]
+    fn test_convert_to_file_writes_ttf() {
+        let font = FontBuilder::with_chars("字体").build();
+        let woff2 = compress_ttf_to_woff2(font).unwrap();
+        let expected = convert_woff2_to_ttf(woff2.clone()).unwrap();
+
+        let dir = temp_dir("ttf");
+        let path = dir.join("font.ttf");
+        let written = task::block_on(convert_woff2_to_ttf_file(
+            woff2,
+            path.to_string_lossy().into_owned(),
+            None,
+        ));
+
+        assert_eq!(written.unwrap() as usize, expected.len());
+        assert_eq!(fs::read(&path).unwrap(), expected);
+        assert!(!dir.join("font.ttf.tmp").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_batch_reports_each_font() {
+        let woff2 = compress_ttf_to_woff2(FontBuilder::with_chars("字").build()).unwrap();
+        let font = |name: &str, data: &[u8]| NamedFont {
+            name: name.to_string(),
+            data: data.to_vec(),
+        };
+        let fonts = vec![
+            font("regular.woff2", &woff2),
+            font("broken.woff2", b"wOF2 truncated"),
+            font("bold.woff2", &woff2),
+        ];
+        let results = task::block_on(convert_woff2_batch(fonts, None)).unwrap();
+        let names: Vec<_> = results.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["regular.woff2", "broken.woff2", "bold.woff2"]);
+        let expected = convert_woff2_to_ttf(woff2).unwrap();
+        assert_eq!(results[0].ttf.as_ref(), Some(&expected));
+        assert!(results[0].error.is_none());
+        assert!(results[1].ttf.is_none() && results[1].error.is_some());
+        assert_eq!(results[2].ttf.as_ref(), Some(&expected));
+    }
+
+    #[test]
+    fn test_convert_to_file_rejects_missing_directory() {
+        let woff2 = compress_ttf_to_woff2(FontBuilder::with_chars("字").build()).unwrap();
+        let path = temp_dir("ttf-missing").join("missing/font.ttf");
+        assert!(task::block_on(convert_woff2_to_ttf_file(
+            woff2,
+            path.to_string_lossy().into_owned(),
+            None,
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn test_for_each_chunk_covers_data_in_order() {
+        let data: Vec<u8> = (0..=255).collect();
+        let mut chunks = Vec::new();
+        for_each_chunk(&data, 100, |chunk| {
+            chunks.push(chunk);
+            Ok(())
+        })
+        .unwrap();
+
+        let offsets: Vec<u32> = chunks.iter().map(|chunk| chunk.offset).collect();
+        assert_eq!(offsets, vec![0, 100, 200]);
+        assert!(chunks.iter().all(|chunk| chunk.total_len == 256));
+        let joined: Vec<u8> = chunks.into_iter().flat_map(|chunk| chunk.data).collect();
+        assert_eq!(joined, data);
+
+        assert!(for_each_chunk(&data, 0, |_| Ok(())).is_err());
+    }
+
+    #[test]
+    fn test_compress_rejects_non_sfnt() {
+        assert!(compress_ttf_to_woff2(vec![]).is_err());
+        assert!(compress_ttf_to_woff2(b"wOF2\0\0\0\0".to_vec()).is_err());
+    }
+
+    #[test]
+    fn test_inspect_static_font() {
+        let font = FontBuilder::with_chars("ab")
+            .family("Novella Serif", "Bold Italic")
+            .weight(700)
+            .italic(true)
+            .build();
+        let info = inspect_font(font).unwrap();
+
+        assert_eq!(info.family_name, "Novella Serif");
+        assert_eq!(info.style_name, "Bold Italic");
+        assert_eq!(
+            info.postscript_name.as_deref(),
+            Some("Novella-Serif-Bold-Italic")
+        );
+        assert_eq!(info.weight, 700);
+        assert!(info.is_italic);
+        assert!(!info.is_variable);
+        assert!(info.axes.is_empty());
+        assert_eq!(info.glyph_count, 3);
+    }
+
+    #[test]
+    fn test_inspect_variable_font() {
+        let font = FontBuilder::with_chars("a")
+            .table(*b"fvar", fvar(&[(*b"wght", 100.0, 400.0, 900.0)]))
+            .build();
+        let info = inspect_font(font).unwrap();
+
+        assert!(info.is_variable);
+        assert_eq!(info.axes.len(), 1);
+        assert_eq!(info.axes[0].tag, "wght");
+        assert_eq!(info.axes[0].min_value, 100.0);
+        assert_eq!(info.axes[0].default_value, 400.0);
+        assert_eq!(info.axes[0].max_value, 900.0);
+    }
+
+    #[test]
+    fn test_check_coverage_reports_missing_codepoints() {
+        let font = FontBuilder::with_chars("你好").build();
+        let report = check_coverage(font, "你好，世界\n你好".to_string()).unwrap();
+
+        assert_eq!(report.total_codepoints, 5);
+        assert_eq!(
+            report.missing_codepoints,
+            vec!['，' as u32, '世' as u32, '界' as u32]
+        );
+        assert!((report.coverage - 0.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_check_coverage_empty_text_is_fully_covered() {
+        let font = FontBuilder::with_chars("a").build();
+        let report = check_coverage(font, " \n".to_string()).unwrap();
+        assert_eq!(report.total_codepoints, 0);
+        assert_eq!(report.coverage, 1.0);
+    }
+
+    /// A `gvar` table that moves the right edge of glyph 1 (a square) by
+    /// `delta` font units at the maximum of the single axis.
+    fn gvar_widening_glyph_one(glyph_count: u16, delta: i16) -> Vec<u8> {
+        let mut data = Vec::new();
+        put_u16(&mut data, 1); // tupleVariationCount
+        put_u16(&mut data, 10); // dataOffset
+        put_u16(&mut data, 19); // variationDataSize
+        put_u16(&mut data, 0x8000 | 0x2000); // embedded peak, private points
+        put_u16(&mut data, 0x4000); // peak: 1.0
+        data.push(0); // all points
+        data.push(0x40 | 7); // 8 word x deltas (4 points + 4 phantoms)
+        for dx in [0, delta, delta, 0, 0, 0, 0, 0] {
+            put_i16(&mut data, dx);
+        }
+        data.push(0x80 | 7); // 8 zero y deltas
+        data.push(0); // pad to an even length
+
+        let offsets_len = 2 * (glyph_count as usize + 1);
+        let mut out = Vec::new();
+        put_u16(&mut out, 1);
+        put_u16(&mut out, 0);
+        put_u16(&mut out, 1); // axisCount
+        put_u16(&mut out, 0); // sharedTupleCount
+        put_u32(&mut out, (20 + offsets_len) as u32);
+        put_u16(&mut out, glyph_count);
+        put_u16(&mut out, 0); // short offsets
+        put_u32(&mut out, (20 + offsets_len) as u32);
+        for glyph in 0..=glyph_count {
+            let offset = if glyph >= 2 { data.len() } else { 0 };
+            put_u16(&mut out, (offset / 2) as u16);
+        }
+        out.extend_from_slice(&data);
+        out
+    }
+
+    fn variable_font() -> Vec<u8> {
+        let mut builder = FontBuilder::with_chars("ab");
+        let component = 1;
+        builder = builder.glyph(
+            'c',
+            Glyph::Composite {
+                advance: 600,
+                components: vec![component],
+            },
+        );
+        builder
+            .table(*b"fvar", fvar(&[(*b"wght", 100.0, 400.0, 900.0)]))
+            .table(*b"gvar", gvar_widening_glyph_one(4, 100))
+            .build()
+    }
+
+    #[test]
+    fn test_instantiate_applies_glyph_variations() {
+        let font = instantiate_variable_font(
+            variable_font(),
+            vec![AxisValue {
+                tag: "wght".to_string(),
+                value: 900.0,
+            }],
+        )
+        .unwrap();
+        let face = Face::parse(&font, 0).unwrap();
+
+        assert!(!face.is_variable());
+        assert_eq!(face.weight().to_number(), 900);
+        let a = face.glyph_index('a').unwrap();
+        assert_eq!(face.glyph_bounding_box(a).unwrap().x_max, 700);
+        let b = face.glyph_index('b').unwrap();
+        assert_eq!(face.glyph_bounding_box(b).unwrap().x_max, 600);
+        // Composites are flattened and follow their (varied) component.
+        let c = face.glyph_index('c').unwrap();
+        assert_eq!(face.glyph_bounding_box(c).unwrap().x_max, 700);
+        assert!(sfnt::parse(&font).unwrap().table(b"gvar").is_none());
+    }
+
+    #[test]
+    fn test_instantiate_default_location_keeps_outlines() {
+        let font = instantiate_variable_font(variable_font(), vec![]).unwrap();
+        let face = Face::parse(&font, 0).unwrap();
+        let a = face.glyph_index('a').unwrap();
+        assert_eq!(face.glyph_bounding_box(a).unwrap().x_max, 600);
+        assert_eq!(face.glyph_hor_advance(a), Some(600));
+    }
+
+    #[test]
+    fn test_instantiate_rejects_static_font_and_unknown_axis() {
+        let font = FontBuilder::with_chars("a").build();
+        assert!(instantiate_variable_font(font, vec![]).is_err());
+
+        let unknown = vec![AxisValue {
+            tag: "wdth".to_string(),
+            value: 75.0,
+        }];
+        assert!(instantiate_variable_font(variable_font(), unknown).is_err());
+    }
+
+    #[test]
+    fn test_width_class_picks_nearest() {
+        assert_eq!(width_class(100.0), 5);
+        assert_eq!(width_class(70.0), 3);
+        assert_eq!(width_class(300.0), 9);
+    }
+
     #[test]
     fn test_extract_invisible_codepoints_rejects_empty_input() {
         let result = extract_invisible_codepoints(vec![]);
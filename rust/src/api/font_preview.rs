@@ -0,0 +1,143 @@
+//! Previews of fonts drawn with the fonts themselves.
+//!
+//! The font picker can only show font names in the system font, which says
+//! little about what a downloaded font looks like. `render` draws a sample
+//! in the font, shaped and rasterised the same way as generated covers (see
+//! `generate_cover`), one centred line per line of the sample. The text is
+//! black on a transparent background so Dart can tint it to the theme.
+
+use std::io::Cursor;
+
+use ab_glyph_rasterizer::Rasterizer;
+use anyhow::{anyhow, Result};
+use image::{ImageFormat, RgbaImage};
+use rustybuzz::Face;
+
+use crate::api::error::ApiError;
+use crate::api::{cover, font_converter, pagination};
+
+/// Sample used when the caller passes an empty one.
+const DEFAULT_SAMPLE: &str = "永和九年，岁在癸丑 Aa 123";
+const MAX_PX_SIZE: f32 = 256.0;
+/// Widest preview; longer lines are cut off.
+const MAX_WIDTH: f32 = 2048.0;
+const LINE_HEIGHT: f32 = 1.2;
+
+#[derive(Debug, Clone)]
+pub struct FontPreview {
+    /// RGBA pixels, row by row.
+    pub rgba: Vec<u8>,
+    /// The same image as PNG.
+    pub png: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+    /// Characters of the sample the font has no glyph for, whitespace
+    /// aside. They are drawn as the font's missing-glyph box.
+    pub missing_chars: u32,
+}
+
+/// Draw `sample_text` in a font.
+///
+/// # Arguments
+/// * `font_bytes` - TTF, OTF, WOFF or WOFF2 data; the first face of a collection
+/// * `sample_text` - Text to draw, with `\n` between lines; a default
+///   Chinese and Latin sample if empty
+/// * `px_size` - Font size in pixels, up to 256
+#[flutter_rust_bridge::frb]
+pub fn render(
+    font_bytes: Vec<u8>,
+    sample_text: String,
+    px_size: f32,
+) -> Result<FontPreview, ApiError> {
+    if !(px_size > 0.0 && px_size <= MAX_PX_SIZE) {
+        return Err(anyhow!("Invalid preview size {px_size}: must be between 0 and 256").into());
+    }
+    let data = font_converter::convert_font_to_ttf(font_bytes)?;
+    let face = Face::from_slice(&data, 0).ok_or_else(|| anyhow!("Failed to parse font"))?;
+    let sample = if sample_text.trim().is_empty() {
+        DEFAULT_SAMPLE
+    } else {
+        sample_text.trim_end()
+    };
+    let image = draw(&face, sample, px_size);
+    let mut png = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+        .map_err(|e| anyhow!("Failed to encode font preview: {e}"))?;
+    Ok(FontPreview {
+        width: image.width(),
+        height: image.height(),
+        rgba: image.into_raw(),
+        png,
+        missing_chars: sample
+            .chars()
+            .filter(|&c| !c.is_whitespace() && face.glyph_index(c).is_none())
+            .count() as u32,
+    })
+}
+
+fn draw(face: &Face, sample: &str, size: f32) -> RgbaImage {
+    let lines: Vec<&str> = sample.lines().collect();
+    let scale = cover::scale(face, size);
+    let padding = (size * 0.25).ceil();
+    let text_width = lines
+        .iter()
+        .map(|line| pagination::prefix_widths(line, face, scale)[line.len()])
+        .fold(0.0, f32::max);
+    let width = (text_width + 2.0 * padding).min(MAX_WIDTH).ceil();
+    let ascent = cover::ascent(face, size);
+    let descent = cover::descent(face, size);
+    let line_height = (ascent + descent).max(size) * LINE_HEIGHT;
+    let height = (line_height * lines.len() as f32 + 2.0 * padding).ceil();
+
+    let mut raster = Rasterizer::new(width as usize, height as usize);
+    let mut top = padding + (line_height - ascent - descent) / 2.0;
+    for line in &lines {
+        cover::draw_line(&mut raster, face, line, size, width, top + ascent);
+        top += line_height;
+    }
+    let mut image = RgbaImage::new(width as u32, height as u32);
+    raster.for_each_pixel_2d(|x, y, coverage| {
+        image.get_pixel_mut(x, y)[3] = (coverage.min(1.0) * 255.0).round() as u8;
+    });
+    image
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fonts::{FontBuilder, Glyph};
+
+    fn font() -> Vec<u8> {
+        FontBuilder::with_chars("Aa永和")
+            .glyph(' ', Glyph::Empty { advance: 600 })
+            .build()
+    }
+
+    #[test]
+    fn test_renders_sample_lines() {
+        let preview = render(font(), "永和 Aa\n永".to_string(), 32.0).unwrap();
+        assert_eq!(
+            preview.rgba.len(),
+            (preview.width * preview.height * 4) as usize
+        );
+        assert!(preview.height > 2 * 32 && preview.width > 4 * 16);
+        assert!(preview.rgba.chunks(4).any(|pixel| pixel[3] == 255));
+        assert!(preview.rgba.chunks(4).all(|pixel| pixel[..3] == [0, 0, 0]));
+        // Corners stay clear of the padding.
+        assert_eq!(preview.rgba[3], 0);
+        assert_eq!(preview.missing_chars, 0);
+        let decoded = image::load_from_memory(&preview.png).unwrap();
+        assert_eq!(decoded.width(), preview.width);
+    }
+
+    #[test]
+    fn test_counts_missing_chars_and_checks_size() {
+        let preview = render(font(), String::new(), 16.0).unwrap();
+        // The default sample has more than the test font covers.
+        assert!(preview.missing_chars > 0);
+        assert!(render(font(), "Aa".to_string(), 0.0).is_err());
+        assert!(render(font(), "Aa".to_string(), 1000.0).is_err());
+        assert!(render(b"not a font".to_vec(), "Aa".to_string(), 16.0).is_err());
+    }
+}
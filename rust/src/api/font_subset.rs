@@ -0,0 +1,333 @@
+//! Chapter-scoped font subsetting.
+//!
+//! Full CJK fonts are 10–20 MB, almost all of which is `glyf` outlines for
+//! characters a given chapter never uses. Subsetting keeps glyph IDs stable
+//! (unused glyphs become empty) so layout tables such as GSUB/GPOS/kern stay
+//! valid without being rewritten, while `glyf`, `loca`, `cmap` and `post`
+//! shrink to what the text actually needs.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::{anyhow, Result};
+use ttf_parser::Face;
+
+use crate::api::error::ApiError;
+use crate::sfnt::{self, put_u16, put_u32};
+
+/// Code points that are always kept, regardless of the chapter text:
+/// printable ASCII, common general punctuation, CJK symbols and punctuation,
+/// and full-width ASCII variants.
+const BASIC_RANGES: &[(u32, u32)] = &[
+    (0x0020, 0x007E),
+    (0x2010, 0x2027),
+    (0x3000, 0x303F),
+    (0xFF01, 0xFF5E),
+];
+
+/// Tables whose contents are per-glyph device metrics or signatures that
+/// become stale once outlines are dropped.
+const DROPPED_TABLES: &[&[u8; 4]] = &[b"DSIG", b"hdmx", b"LTSH", b"VDMX"];
+
+/// Subset a TrueType font to the glyphs needed for `text`.
+///
+/// Basic Latin and common CJK punctuation are always retained so UI strings
+/// around the chapter (titles, page numbers) still render with the font.
+///
+/// # Arguments
+/// * `ttf_data` - TrueType-flavored font bytes (e.g. output of `convert_woff2_to_ttf`)
+/// * `text` - Chapter text whose characters must remain renderable
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` - Subset TTF bytes ready for FontLoader
+/// * `Err(_)` - If the font is malformed or uses CFF outlines
+#[flutter_rust_bridge::frb]
+pub fn subset_font(ttf_data: Vec<u8>, text: String) -> Result<Vec<u8>, ApiError> {
+    if ttf_data.is_empty() {
+        return Err(anyhow!("Empty TTF data").into());
+    }
+
+    let codepoints: BTreeSet<u32> = text.chars().map(u32::from).chain(basic()).collect();
+
+    Ok(subset_codepoints(&ttf_data, &codepoints)?)
+}
+
+/// The code points in `BASIC_RANGES`.
+pub(crate) fn basic() -> impl Iterator<Item = u32> {
+    BASIC_RANGES.iter().flat_map(|&(start, end)| start..=end)
+}
+
+/// Whether `codepoint` is kept by every subset.
+pub(crate) fn is_basic(codepoint: u32) -> bool {
+    BASIC_RANGES
+        .iter()
+        .any(|&(start, end)| (start..=end).contains(&codepoint))
+}
+
+/// Subset `ttf_data` to exactly the given code points (plus `.notdef`).
+pub(crate) fn subset_codepoints(ttf_data: &[u8], codepoints: &BTreeSet<u32>) -> Result<Vec<u8>> {
+    let face = Face::parse(ttf_data, 0).map_err(|_| anyhow!("Invalid TTF data"))?;
+    let mut font = sfnt::parse(ttf_data)?;
+    if font.table(b"glyf").is_none() || font.table(b"loca").is_none() {
+        return Err(anyhow!("Only TrueType (glyf) outlines can be subset"));
+    }
+
+    let mut mappings = BTreeMap::new();
+    for &codepoint in codepoints {
+        let Some(ch) = char::from_u32(codepoint) else {
+            continue;
+        };
+        if let Some(glyph_id) = face.glyph_index(ch) {
+            mappings.insert(codepoint, glyph_id.0);
+        }
+    }
+
+    let locations = sfnt::glyph_locations(&font)?;
+    let glyf = font.table(b"glyf").unwrap_or_default();
+    let keep = glyph_closure(glyf, &locations, mappings.values().copied())?;
+
+    let mut new_glyf = Vec::new();
+    let mut offsets = Vec::with_capacity(locations.len() + 1);
+    for (glyph_id, range) in locations.iter().enumerate() {
+        offsets.push(new_glyf.len());
+        if keep.contains(&(glyph_id as u16)) {
+            new_glyf.extend_from_slice(&glyf[range.clone()]);
+            new_glyf.resize((new_glyf.len() + 3) & !3, 0);
+        }
+    }
+    offsets.push(new_glyf.len());
+
+    let short_loca = new_glyf.len() / 2 <= u16::MAX as usize;
+    let mut loca = Vec::with_capacity(offsets.len() * 4);
+    for offset in offsets {
+        if short_loca {
+            put_u16(&mut loca, (offset / 2) as u16);
+        } else {
+            put_u32(&mut loca, offset as u32);
+        }
+    }
+
+    let mut head = font
+        .table(b"head")
+        .ok_or_else(|| anyhow!("Missing head table"))?
+        .to_vec();
+    if head.len() < 54 {
+        return Err(anyhow!("Truncated head table"));
+    }
+    head[50..52].copy_from_slice(&u16::from(!short_loca).to_be_bytes());
+
+    font.set_table(*b"head", head);
+    font.set_table(*b"glyf", new_glyf);
+    font.set_table(*b"loca", loca);
+    font.set_table(*b"cmap", build_cmap(&mappings));
+    if let Some(post) = font.table(b"post").filter(|post| post.len() >= 32) {
+        // Version 3.0 carries no glyph names, which are large for CJK fonts.
+        let mut post = post[..32].to_vec();
+        post[..4].copy_from_slice(&0x0003_0000u32.to_be_bytes());
+        font.set_table(*b"post", post);
+    }
+    for tag in DROPPED_TABLES {
+        font.remove_table(tag);
+    }
+
+    Ok(font.build())
+}
+
+/// All glyphs reachable from `roots`, including `.notdef` and composite
+/// components.
+fn glyph_closure(
+    glyf: &[u8],
+    locations: &[std::ops::Range<usize>],
+    roots: impl Iterator<Item = u16>,
+) -> Result<BTreeSet<u16>> {
+    let mut keep = BTreeSet::new();
+    let mut pending: Vec<u16> = std::iter::once(0).chain(roots).collect();
+
+    while let Some(glyph_id) = pending.pop() {
+        if !keep.insert(glyph_id) {
+            continue;
+        }
+        let Some(range) = locations.get(glyph_id as usize) else {
+            continue;
+        };
+        pending.extend(sfnt::composite_components(&glyf[range.clone()])?);
+    }
+
+    Ok(keep)
+}
+
+/// Build a `cmap` table with a BMP format 4 subtable and a full-range
+/// format 12 subtable for the given mappings.
+fn build_cmap(mappings: &BTreeMap<u32, u16>) -> Vec<u8> {
+    let format12 = build_format12(mappings);
+    let format4 = build_format4(mappings);
+
+    // (platformID, encodingID, subtable index)
+    let mut records = Vec::new();
+    if format4.is_some() {
+        records.push((0u16, 3u16, 0usize));
+    }
+    records.push((0, 4, 1));
+    if format4.is_some() {
+        records.push((3, 1, 0));
+    }
+    records.push((3, 10, 1));
+
+    let header_len = 4 + records.len() * 8;
+    let format4_len = format4.as_ref().map_or(0, Vec::len);
+    let subtable_offsets = [header_len, header_len + format4_len];
+
+    let mut out = Vec::new();
+    put_u16(&mut out, 0);
+    put_u16(&mut out, records.len() as u16);
+    for (platform, encoding, subtable) in records {
+        put_u16(&mut out, platform);
+        put_u16(&mut out, encoding);
+        put_u32(&mut out, subtable_offsets[subtable] as u32);
+    }
+    if let Some(format4) = format4 {
+        out.extend_from_slice(&format4);
+    }
+    out.extend_from_slice(&format12);
+    out
+}
+
+/// Format 4 (segment mapping to delta values). Returns `None` when the BMP
+/// mappings need more segments than the 16-bit length field allows.
+fn build_format4(mappings: &BTreeMap<u32, u16>) -> Option<Vec<u8>> {
+    // (startCode, endCode, idDelta)
+    let mut segments: Vec<(u16, u16, u16)> = Vec::new();
+    for (&codepoint, &glyph_id) in mappings.range(..0xFFFF) {
+        let codepoint = codepoint as u16;
+        let delta = glyph_id.wrapping_sub(codepoint);
+        match segments.last_mut() {
+            Some((_, end, last_delta)) if *end + 1 == codepoint && *last_delta == delta => {
+                *end = codepoint;
+            }
+            _ => segments.push((codepoint, codepoint, delta)),
+        }
+    }
+    segments.push((0xFFFF, 0xFFFF, 1));
+
+    let seg_count = segments.len();
+    let length = 16 + seg_count * 8;
+    if length > u16::MAX as usize {
+        return None;
+    }
+
+    let mut entry_selector = 0u16;
+    while (2usize << entry_selector) <= seg_count {
+        entry_selector += 1;
+    }
+    let search_range = 2 * (1u16 << entry_selector);
+
+    let mut out = Vec::with_capacity(length);
+    put_u16(&mut out, 4);
+    put_u16(&mut out, length as u16);
+    put_u16(&mut out, 0); // language
+    put_u16(&mut out, (seg_count * 2) as u16);
+    put_u16(&mut out, search_range);
+    put_u16(&mut out, entry_selector);
+    put_u16(&mut out, (seg_count * 2) as u16 - search_range);
+    for &(_, end, _) in &segments {
+        put_u16(&mut out, end);
+    }
+    put_u16(&mut out, 0); // reservedPad
+    for &(start, _, _) in &segments {
+        put_u16(&mut out, start);
+    }
+    for &(_, _, delta) in &segments {
+        put_u16(&mut out, delta);
+    }
+    for _ in &segments {
+        put_u16(&mut out, 0); // idRangeOffset
+    }
+    Some(out)
+}
+
+/// Format 12 (segmented coverage) covering every mapping.
+fn build_format12(mappings: &BTreeMap<u32, u16>) -> Vec<u8> {
+    // (startCharCode, endCharCode, startGlyphID)
+    let mut groups: Vec<(u32, u32, u32)> = Vec::new();
+    for (&codepoint, &glyph_id) in mappings {
+        let glyph_id = glyph_id as u32;
+        match groups.last_mut() {
+            Some((start, end, start_glyph))
+                if *end + 1 == codepoint && *start_glyph + (codepoint - *start) == glyph_id =>
+            {
+                *end = codepoint;
+            }
+            _ => groups.push((codepoint, codepoint, glyph_id)),
+        }
+    }
+
+    let mut out = Vec::with_capacity(16 + groups.len() * 12);
+    put_u16(&mut out, 12);
+    put_u16(&mut out, 0); // reserved
+    put_u32(&mut out, (16 + groups.len() * 12) as u32);
+    put_u32(&mut out, 0); // language
+    put_u32(&mut out, groups.len() as u32);
+    for (start, end, start_glyph) in groups {
+        put_u32(&mut out, start);
+        put_u32(&mut out, end);
+        put_u32(&mut out, start_glyph);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fonts::{FontBuilder, Glyph};
+    use ttf_parser::GlyphId;
+
+    fn sample_font() -> Vec<u8> {
+        let mut builder = FontBuilder::with_chars("A中文");
+        let component = builder.unmapped_glyph(Glyph::Square { advance: 1000 });
+        builder
+            .glyph(
+                '国',
+                Glyph::Composite {
+                    advance: 1000,
+                    components: vec![component],
+                },
+            )
+            .build()
+    }
+
+    #[test]
+    fn test_subset_keeps_text_and_basic_glyphs() {
+        let subset = subset_font(sample_font(), "国".to_string()).unwrap();
+        let face = Face::parse(&subset, 0).unwrap();
+
+        assert_eq!(face.number_of_glyphs(), 6);
+        assert!(face.glyph_index('国').is_some());
+        assert!(face.glyph_index('A').is_some());
+        assert!(face.glyph_index('中').is_none());
+        assert!(face.glyph_index('文').is_none());
+
+        // The composite's component (glyph 4) survives; 中 (glyph 2) is emptied.
+        assert!(face.glyph_bounding_box(GlyphId(4)).is_some());
+        assert!(face.glyph_bounding_box(GlyphId(2)).is_none());
+    }
+
+    #[test]
+    fn test_subset_shrinks_glyf() {
+        let font = sample_font();
+        let subset = subset_font(font.clone(), String::new()).unwrap();
+        let before = sfnt::parse(&font).unwrap();
+        let after = sfnt::parse(&subset).unwrap();
+        assert!(after.table(b"glyf").unwrap().len() < before.table(b"glyf").unwrap().len());
+    }
+
+    #[test]
+    fn test_subset_rejects_fonts_without_glyf() {
+        let mut font = sfnt::parse(&sample_font()).unwrap();
+        font.remove_table(b"glyf");
+        assert!(subset_font(font.build(), "中".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_subset_rejects_empty_input() {
+        assert!(subset_font(vec![], "中".to_string()).is_err());
+    }
+}
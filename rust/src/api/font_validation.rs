@@ -0,0 +1,496 @@
+//! SFNT validation and repair.
+//!
+//! Fonts from untrusted sources sometimes decompress into TTFs with stale
+//! checksums, truncated tables or broken `loca` offsets. Most renderers shrug
+//! these off, but the iOS font loader crashes on them, so converted fonts are
+//! checked (and, where possible, repaired) before they reach FontLoader.
+
+use std::collections::HashSet;
+
+use anyhow::{anyhow, Result};
+
+use crate::api::error::ApiError;
+use crate::sfnt::{self, put_u16, put_u32, Table};
+
+/// Tables without which a font cannot be rendered at all.
+const REQUIRED_TABLES: &[&[u8; 4]] = &[b"cmap", b"head", b"hhea", b"hmtx", b"maxp"];
+
+/// Kind of problem found while validating a font.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationIssueKind {
+    /// A table's stored checksum does not match its contents.
+    ChecksumMismatch,
+    /// `head.checkSumAdjustment` is wrong for the font as a whole.
+    ChecksumAdjustmentMismatch,
+    /// A second record for an already-seen table tag.
+    DuplicateTable,
+    /// A table extends past the end of the file and was dropped.
+    CorruptTable,
+    /// `loca` points outside `glyf` or goes backwards.
+    InvalidGlyphLocations,
+    /// `hmtx` is shorter than `hhea`/`maxp` say it should be.
+    TruncatedMetrics,
+    /// A required table is missing or unusable. Not repairable.
+    MissingTable,
+    /// The table directory itself is unreadable. Not repairable.
+    InvalidHeader,
+}
+
+impl ValidationIssueKind {
+    /// Whether `repair_ttf` can fix this kind of issue.
+    fn is_repairable(self) -> bool {
+        !matches!(
+            self,
+            ValidationIssueKind::MissingTable | ValidationIssueKind::InvalidHeader
+        )
+    }
+}
+
+/// A single problem found in a font.
+#[derive(Debug, Clone)]
+pub struct ValidationIssue {
+    pub kind: ValidationIssueKind,
+    /// Tag of the affected table, if the issue is table-specific.
+    pub table: Option<String>,
+    pub message: String,
+}
+
+/// Outcome of `validate_ttf`.
+#[derive(Debug, Clone)]
+pub struct ValidationReport {
+    /// No issues were found.
+    pub is_valid: bool,
+    /// Every issue found can be fixed by `repair_ttf`.
+    pub is_repairable: bool,
+    pub issues: Vec<ValidationIssue>,
+}
+
+/// Check a TTF/OTF font's structure without modifying it.
+///
+/// Table bounds, checksums, `head.checkSumAdjustment`, `loca` and `hmtx`
+/// consistency, and the presence of required tables are verified.
+#[flutter_rust_bridge::frb]
+pub fn validate_ttf(ttf_data: Vec<u8>) -> Result<ValidationReport, ApiError> {
+    if ttf_data.is_empty() {
+        return Err(anyhow!("Empty TTF data").into());
+    }
+    Ok(analyze(&ttf_data).report)
+}
+
+/// Repair a TTF/OTF font so it can be safely registered.
+///
+/// Checksums are recomputed, truncated or duplicate tables are dropped,
+/// broken `loca` entries become empty glyphs and short `hmtx` tables are
+/// padded. Fonts without issues are returned unchanged.
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` - Repaired (or untouched) font bytes
+/// * `Err(_)` - If the font is missing required tables or has no readable table directory
+#[flutter_rust_bridge::frb]
+pub fn repair_ttf(ttf_data: Vec<u8>) -> Result<Vec<u8>, ApiError> {
+    if ttf_data.is_empty() {
+        return Err(anyhow!("Empty TTF data").into());
+    }
+    Ok(repair(ttf_data)?)
+}
+
+pub(crate) fn repair(ttf_data: Vec<u8>) -> Result<Vec<u8>> {
+    let analysis = analyze(&ttf_data);
+    if analysis.report.is_valid {
+        return Ok(ttf_data);
+    }
+    if let Some(issue) = analysis
+        .report
+        .issues
+        .iter()
+        .find(|issue| !issue.kind.is_repairable())
+    {
+        return Err(anyhow!("Font cannot be repaired: {}", issue.message));
+    }
+
+    Ok(sfnt::build(analysis.flavor, analysis.tables))
+}
+
+/// Validation findings plus the cleaned-up tables a repair would write.
+struct Analysis {
+    report: ValidationReport,
+    flavor: u32,
+    tables: Vec<Table>,
+}
+
+fn analyze(data: &[u8]) -> Analysis {
+    let mut issues = Vec::new();
+    let mut issue = |kind, table: Option<&[u8; 4]>, message: String| {
+        issues.push(ValidationIssue {
+            kind,
+            table: table.map(|tag| String::from_utf8_lossy(tag).into_owned()),
+            message,
+        });
+    };
+
+    let header = (|| -> Result<(u32, usize)> {
+        let flavor = sfnt::read_u32(data, 0)?;
+        let num_tables = sfnt::read_u16(data, 4)? as usize;
+        if data.len() < 12 + num_tables * 16 {
+            return Err(anyhow!("Truncated table directory"));
+        }
+        Ok((flavor, num_tables))
+    })();
+    let (flavor, num_tables) = match header {
+        Ok(header) => header,
+        Err(e) => {
+            issue(ValidationIssueKind::InvalidHeader, None, e.to_string());
+            return finish(issues, 0, Vec::new());
+        }
+    };
+
+    let mut seen = HashSet::new();
+    let mut tables = Vec::with_capacity(num_tables);
+    let mut stored_checksums = Vec::with_capacity(num_tables);
+    let mut head_adjustment = None;
+
+    for index in 0..num_tables {
+        let record = 12 + index * 16;
+        let tag = [
+            data[record],
+            data[record + 1],
+            data[record + 2],
+            data[record + 3],
+        ];
+        let stored = u32::from_be_bytes([
+            data[record + 4],
+            data[record + 5],
+            data[record + 6],
+            data[record + 7],
+        ]);
+        let offset = sfnt::read_u32(data, record + 8).unwrap_or(0) as usize;
+        let length = sfnt::read_u32(data, record + 12).unwrap_or(0) as usize;
+        let tag_name = String::from_utf8_lossy(&tag).into_owned();
+
+        if !seen.insert(tag) {
+            issue(
+                ValidationIssueKind::DuplicateTable,
+                Some(&tag),
+                format!("Duplicate '{tag_name}' table record"),
+            );
+            continue;
+        }
+
+        let Some(table) = offset
+            .checked_add(length)
+            .and_then(|end| data.get(offset..end))
+        else {
+            issue(
+                ValidationIssueKind::CorruptTable,
+                Some(&tag),
+                format!("Table '{tag_name}' extends past the end of the file"),
+            );
+            continue;
+        };
+
+        let mut table = table.to_vec();
+        if &tag == b"head" && table.len() >= 12 {
+            let stored = u32::from_be_bytes([table[8], table[9], table[10], table[11]]);
+            head_adjustment = Some((stored, offset));
+            // The table checksum of head is defined with this field zeroed.
+            table[8..12].fill(0);
+        }
+        stored_checksums.push((tag, stored));
+        tables.push(Table { tag, data: table });
+    }
+
+    for (tag, stored) in &stored_checksums {
+        let Some(table) = tables.iter().find(|table| &table.tag == tag) else {
+            continue;
+        };
+        if sfnt::checksum(&table.data) != *stored {
+            issue(
+                ValidationIssueKind::ChecksumMismatch,
+                Some(tag),
+                format!("Checksum mismatch in '{}'", String::from_utf8_lossy(tag)),
+            );
+        }
+    }
+
+    for tag in REQUIRED_TABLES {
+        if !tables.iter().any(|table| &table.tag == *tag) {
+            issue(
+                ValidationIssueKind::MissingTable,
+                Some(tag),
+                format!("Missing required '{}' table", String::from_utf8_lossy(*tag)),
+            );
+        }
+    }
+    let has_outlines = tables
+        .iter()
+        .any(|table| matches!(&table.tag, b"glyf" | b"CFF " | b"CFF2"));
+    if !has_outlines {
+        issue(
+            ValidationIssueKind::MissingTable,
+            None,
+            "Font has no glyf, CFF or CFF2 outlines".to_string(),
+        );
+    }
+
+    let mut font = sfnt::Font { flavor, tables };
+    if font.table(b"head").is_some_and(|head| head.len() < 54) {
+        issue(
+            ValidationIssueKind::MissingTable,
+            Some(b"head"),
+            "Truncated 'head' table".to_string(),
+        );
+    } else if font.table(b"glyf").is_some() {
+        check_glyph_locations(&mut font, &mut issue);
+    }
+    check_metrics(&mut font, &mut issue);
+
+    if let Some((stored, offset)) = head_adjustment {
+        // checkSumAdjustment is computed over the whole file with the field
+        // itself zeroed. A stale value is harmless to fix since the repair
+        // rebuild always recomputes it.
+        let file_sum = if offset % 4 == 0 {
+            sfnt::checksum(data).wrapping_sub(stored)
+        } else {
+            let mut zeroed = data.to_vec();
+            zeroed[offset + 8..offset + 12].fill(0);
+            sfnt::checksum(&zeroed)
+        };
+        if 0xB1B0_AFBAu32.wrapping_sub(file_sum) != stored {
+            issue(
+                ValidationIssueKind::ChecksumAdjustmentMismatch,
+                Some(b"head"),
+                "head.checkSumAdjustment is incorrect".to_string(),
+            );
+        }
+    }
+
+    finish(issues, flavor, font.tables)
+}
+
+fn finish(issues: Vec<ValidationIssue>, flavor: u32, tables: Vec<Table>) -> Analysis {
+    let is_repairable = issues.iter().all(|issue| issue.kind.is_repairable());
+    Analysis {
+        report: ValidationReport {
+            is_valid: issues.is_empty(),
+            is_repairable,
+            issues,
+        },
+        flavor,
+        tables,
+    }
+}
+
+/// Verify `loca` against `glyf`, rewriting it in place so bad entries become
+/// empty glyphs.
+fn check_glyph_locations(
+    font: &mut sfnt::Font,
+    issue: &mut impl FnMut(ValidationIssueKind, Option<&[u8; 4]>, String),
+) {
+    let (Some(head), Some(loca), Some(glyf), Ok(num_glyphs)) = (
+        font.table(b"head"),
+        font.table(b"loca"),
+        font.table(b"glyf"),
+        sfnt::num_glyphs(font),
+    ) else {
+        issue(
+            ValidationIssueKind::MissingTable,
+            Some(b"loca"),
+            "TrueType outlines without a usable 'loca' table".to_string(),
+        );
+        return;
+    };
+
+    let long_format = head[50..52] != [0, 0];
+    let entry_len = if long_format { 4 } else { 2 };
+    let count = num_glyphs as usize + 1;
+    let read = |index: usize| -> Option<usize> {
+        if long_format {
+            sfnt::read_u32(loca, index * 4).ok().map(|v| v as usize)
+        } else {
+            sfnt::read_u16(loca, index * 2).ok().map(|v| v as usize * 2)
+        }
+    };
+
+    let mut offsets = Vec::with_capacity(count);
+    let mut broken = loca.len() < count * entry_len;
+    let mut last = 0usize;
+    for index in 0..count {
+        let offset = match read(index) {
+            Some(offset) if offset >= last && offset <= glyf.len() => offset,
+            _ => {
+                broken = true;
+                last
+            }
+        };
+        offsets.push(offset);
+        last = offset;
+    }
+    if !broken {
+        return;
+    }
+
+    issue(
+        ValidationIssueKind::InvalidGlyphLocations,
+        Some(b"loca"),
+        "Glyph locations point outside 'glyf'; affected glyphs will be empty".to_string(),
+    );
+    // Broken entries collapse onto the previous offset, i.e. become empty.
+    let mut rebuilt = Vec::with_capacity(count * 4);
+    for offset in offsets {
+        put_u32(&mut rebuilt, offset as u32);
+    }
+    let mut head = head.to_vec();
+    head[50..52].copy_from_slice(&1u16.to_be_bytes());
+    font.set_table(*b"head", head);
+    font.set_table(*b"loca", rebuilt);
+}
+
+/// Verify `hmtx` is long enough, padding it with zero side bearings.
+fn check_metrics(
+    font: &mut sfnt::Font,
+    issue: &mut impl FnMut(ValidationIssueKind, Option<&[u8; 4]>, String),
+) {
+    let (Some(hhea), Some(hmtx), Ok(num_glyphs)) = (
+        font.table(b"hhea"),
+        font.table(b"hmtx"),
+        sfnt::num_glyphs(font),
+    ) else {
+        return;
+    };
+    let Ok(long_metrics) = sfnt::read_u16(hhea, 34) else {
+        issue(
+            ValidationIssueKind::MissingTable,
+            Some(b"hhea"),
+            "Truncated 'hhea' table".to_string(),
+        );
+        return;
+    };
+
+    let long_metrics = (long_metrics as usize).clamp(1, num_glyphs.max(1) as usize);
+    let expected = long_metrics * 4 + (num_glyphs as usize).saturating_sub(long_metrics) * 2;
+    if hmtx.len() >= expected {
+        return;
+    }
+
+    issue(
+        ValidationIssueKind::TruncatedMetrics,
+        Some(b"hmtx"),
+        format!("'hmtx' has {} bytes, expected {expected}", hmtx.len()),
+    );
+    let mut padded = hmtx.to_vec();
+    while padded.len() < long_metrics * 4 {
+        put_u16(&mut padded, 0);
+    }
+    padded.resize(expected, 0);
+    font.set_table(*b"hmtx", padded);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fonts::FontBuilder;
+
+    /// Offset of the table directory record for `tag`.
+    fn record_of(font: &[u8], tag: &[u8; 4]) -> usize {
+        let count = sfnt::read_u16(font, 4).unwrap() as usize;
+        (0..count)
+            .map(|index| 12 + index * 16)
+            .find(|&record| &font[record..record + 4] == tag)
+            .unwrap()
+    }
+
+    fn kinds(report: &ValidationReport) -> Vec<ValidationIssueKind> {
+        report.issues.iter().map(|issue| issue.kind).collect()
+    }
+
+    #[test]
+    fn test_valid_font_passes() {
+        let font = FontBuilder::with_chars("ab").build();
+        let report = validate_ttf(font.clone()).unwrap();
+        assert!(report.is_valid, "{:?}", report.issues);
+        assert_eq!(repair_ttf(font.clone()).unwrap(), font);
+    }
+
+    #[test]
+    fn test_checksum_mismatch_is_repaired() {
+        let mut font = FontBuilder::with_chars("ab").build();
+        let record = record_of(&font, b"glyf");
+        let offset = sfnt::read_u32(&font, record + 8).unwrap() as usize;
+        font[offset + 2] ^= 0x01;
+
+        let report = validate_ttf(font.clone()).unwrap();
+        assert_eq!(
+            kinds(&report),
+            vec![
+                ValidationIssueKind::ChecksumMismatch,
+                ValidationIssueKind::ChecksumAdjustmentMismatch
+            ]
+        );
+        assert!(report.is_repairable);
+
+        let repaired = repair_ttf(font).unwrap();
+        assert!(validate_ttf(repaired).unwrap().is_valid);
+    }
+
+    #[test]
+    fn test_truncated_table_is_dropped() {
+        let mut font = FontBuilder::with_chars("a").build();
+        let record = record_of(&font, b"post");
+        font[record + 12..record + 16].copy_from_slice(&0x00FF_FFFFu32.to_be_bytes());
+
+        let report = validate_ttf(font.clone()).unwrap();
+        assert!(kinds(&report).contains(&ValidationIssueKind::CorruptTable));
+
+        let repaired = repair_ttf(font).unwrap();
+        assert!(validate_ttf(repaired.clone()).unwrap().is_valid);
+        assert!(sfnt::parse(&repaired).unwrap().table(b"post").is_none());
+    }
+
+    #[test]
+    fn test_bad_loca_becomes_empty_glyph() {
+        let font = FontBuilder::with_chars("ab").build();
+        let mut parsed = sfnt::parse(&font).unwrap();
+        let mut loca = parsed.table(b"loca").unwrap().to_vec();
+        loca[8..12].copy_from_slice(&0xFFFFu32.to_be_bytes()); // start of glyph 2
+        parsed.set_table(*b"loca", loca);
+        let font = parsed.build();
+
+        let report = validate_ttf(font.clone()).unwrap();
+        assert_eq!(
+            kinds(&report),
+            vec![ValidationIssueKind::InvalidGlyphLocations]
+        );
+
+        let repaired = repair_ttf(font).unwrap();
+        let face = ttf_parser::Face::parse(&repaired, 0).unwrap();
+        assert!(face.glyph_bounding_box(ttf_parser::GlyphId(1)).is_none());
+        assert!(face.glyph_bounding_box(ttf_parser::GlyphId(2)).is_some());
+    }
+
+    #[test]
+    fn test_short_hmtx_is_padded() {
+        let mut parsed = sfnt::parse(&FontBuilder::with_chars("ab").build()).unwrap();
+        let hmtx = parsed.table(b"hmtx").unwrap()[..8].to_vec();
+        parsed.set_table(*b"hmtx", hmtx);
+
+        let report = validate_ttf(parsed.build()).unwrap();
+        assert_eq!(kinds(&report), vec![ValidationIssueKind::TruncatedMetrics]);
+    }
+
+    #[test]
+    fn test_missing_required_table_is_not_repairable() {
+        let mut parsed = sfnt::parse(&FontBuilder::with_chars("a").build()).unwrap();
+        parsed.remove_table(b"cmap");
+        let font = parsed.build();
+
+        let report = validate_ttf(font.clone()).unwrap();
+        assert!(!report.is_repairable);
+        assert!(repair_ttf(font).is_err());
+    }
+
+    #[test]
+    fn test_garbage_header_is_reported() {
+        let report = validate_ttf(vec![0, 1, 0, 0, 0, 9]).unwrap();
+        assert_eq!(kinds(&report), vec![ValidationIssueKind::InvalidHeader]);
+    }
+}
@@ -0,0 +1,632 @@
+//! Persistent HTTP cache shared by the downloader and the catalog clients.
+//!
+//! Successful GET responses are stored under an app-provided directory,
+//! keyed by the BLAKE3 hash of the URL and credentials. A response is reused
+//! without a request while `Cache-Control: max-age` or `Expires` says it is
+//! fresh; after that it is revalidated with `If-None-Match` or
+//! `If-Modified-Since`, so an unchanged cover costs a 304 instead of the
+//! whole image. Responses without explicit freshness are always
+//! revalidated. A stale entry is also served when the network is down.
+//! Once the cache grows past its size cap, entries are evicted
+//! least-recently-used first, by file modification time as in the font
+//! cache.
+
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Result};
+
+use crate::api::error::ApiError;
+use crate::api::font_converter;
+
+const ENTRY_EXTENSION: &str = "http";
+
+const MAGIC: &str = "NVHC1";
+
+static HTTP_CACHE: Mutex<Option<HttpCache>> = Mutex::new(None);
+
+/// Current size of the HTTP cache.
+#[derive(Debug, Clone)]
+pub struct HttpCacheStats {
+    pub entry_count: u32,
+    pub total_bytes: u32,
+    pub max_bytes: u32,
+}
+
+/// A cached response, as reported to Dart.
+#[derive(Debug, Clone)]
+pub struct HttpCacheEntry {
+    /// URL the response was finally served from, after redirects.
+    pub url: String,
+    pub content_type: Option<String>,
+    pub size: u32,
+    /// Whether the response can be used without revalidating it.
+    pub fresh: bool,
+    /// When the response was stored or last revalidated, in seconds since
+    /// the Unix epoch.
+    pub stored_at: i64,
+}
+
+/// Enable the persistent HTTP cache. Calling this again switches to the new
+/// directory or size cap.
+///
+/// # Arguments
+/// * `cache_dir` - Directory owned by the cache; created if missing
+/// * `max_bytes` - Total size of cached responses to keep
+#[flutter_rust_bridge::frb]
+pub fn init_http_cache(cache_dir: String, max_bytes: u32) -> Result<(), ApiError> {
+    let cache = HttpCache::new(PathBuf::from(cache_dir), max_bytes)?;
+    cache.evict()?;
+    *lock() = Some(cache);
+    Ok(())
+}
+
+/// Report the number and total size of cached responses.
+#[flutter_rust_bridge::frb]
+pub fn http_cache_stats() -> Result<HttpCacheStats, ApiError> {
+    Ok(current()
+        .ok_or_else(|| anyhow!("HTTP cache is not initialized"))?
+        .stats()?)
+}
+
+/// Look up the cached response for a URL fetched without credentials.
+#[flutter_rust_bridge::frb]
+pub fn http_cache_entry(url: String) -> Result<Option<HttpCacheEntry>, ApiError> {
+    let cache = current().ok_or_else(|| anyhow!("HTTP cache is not initialized"))?;
+    Ok(cache.read(&key(&url, None)).map(|stored| HttpCacheEntry {
+        url: stored.meta.url.clone(),
+        content_type: stored.meta.content_type.clone(),
+        size: stored.body.len() as u32,
+        fresh: stored.is_fresh(),
+        stored_at: stored.meta.stored_at,
+    }))
+}
+
+/// Remove the cached response for a URL. Returns false if there was none.
+#[flutter_rust_bridge::frb]
+pub fn remove_http_cache_entry(url: String) -> Result<bool, ApiError> {
+    let cache = current().ok_or_else(|| anyhow!("HTTP cache is not initialized"))?;
+    let path = cache.entry_path(&key(&url, None));
+    match fs::remove_file(&path) {
+        Ok(()) => Ok(true),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(anyhow!("Failed to remove {}: {e}", path.display()).into()),
+    }
+}
+
+/// Delete every cached response. The cache stays enabled.
+#[flutter_rust_bridge::frb]
+pub fn clear_http_cache() -> Result<(), ApiError> {
+    Ok(current()
+        .ok_or_else(|| anyhow!("HTTP cache is not initialized"))?
+        .clear()?)
+}
+
+/// A response body served from the network or the cache.
+pub(crate) struct CachedResponse {
+    pub(crate) body: Vec<u8>,
+    /// URL after redirects.
+    pub(crate) url: String,
+}
+
+/// Why `get` failed.
+pub(crate) enum FetchError {
+    Status(u16),
+    Other(String),
+}
+
+/// Send a GET request through the cache. Bodies longer than `max_bytes`
+/// are an error.
+pub(crate) fn get(request: ureq::Request, max_bytes: u64) -> Result<CachedResponse, FetchError> {
+    let lookup = Lookup::new(&request);
+    if let Some(stored) = lookup.stored.as_ref().filter(|stored| stored.is_fresh()) {
+        return Ok(stored.response());
+    }
+    let response = match lookup.conditional(request).call() {
+        Ok(response) => response,
+        Err(ureq::Error::Status(code, _)) => return Err(FetchError::Status(code)),
+        Err(e) => {
+            return match lookup.stored {
+                Some(stored) => Ok(stored.response()),
+                None => Err(FetchError::Other(e.to_string())),
+            };
+        }
+    };
+    if response.status() == 304 {
+        if let Some(stored) = lookup.revalidated(&response) {
+            return Ok(stored.response());
+        }
+    }
+
+    let meta = policy(&response);
+    let url = response.get_url().to_string();
+    let mut body = Vec::new();
+    response
+        .into_reader()
+        .take(max_bytes + 1)
+        .read_to_end(&mut body)
+        .map_err(|e| FetchError::Other(e.to_string()))?;
+    if body.len() as u64 > max_bytes {
+        return Err(FetchError::Other("Response is too large".to_string()));
+    }
+    if let Some(meta) = meta {
+        lookup.store(meta, &body);
+    }
+    Ok(CachedResponse { body, url })
+}
+
+/// The cache entry, if any, for one request.
+pub(crate) struct Lookup {
+    cache: Option<HttpCache>,
+    key: String,
+    pub(crate) stored: Option<Stored>,
+}
+
+impl Lookup {
+    /// Find the entry for `request`. Only GET requests are cached.
+    pub(crate) fn new(request: &ureq::Request) -> Self {
+        let key = key(request.url(), request.header("Authorization"));
+        let cache = current().filter(|_| request.method() == "GET");
+        let stored = cache.as_ref().and_then(|cache| cache.read(&key));
+        Self { cache, key, stored }
+    }
+
+    /// Add validators from the stored entry to `request`.
+    pub(crate) fn conditional(&self, mut request: ureq::Request) -> ureq::Request {
+        if let Some(stored) = &self.stored {
+            if let Some(etag) = &stored.meta.etag {
+                request = request.set("If-None-Match", etag);
+            }
+            if let Some(modified) = &stored.meta.last_modified {
+                request = request.set("If-Modified-Since", modified);
+            }
+        }
+        request
+    }
+
+    /// Refresh the stored entry after a 304 response and return it.
+    pub(crate) fn revalidated(&self, response: &ureq::Response) -> Option<&Stored> {
+        let stored = self.stored.as_ref()?;
+        if let Some(updated) = policy(response) {
+            let meta = Meta {
+                url: stored.meta.url.clone(),
+                content_type: stored.meta.content_type.clone(),
+                etag: updated.etag.or_else(|| stored.meta.etag.clone()),
+                last_modified: updated
+                    .last_modified
+                    .or_else(|| stored.meta.last_modified.clone()),
+                ..updated
+            };
+            self.store(meta, &stored.body);
+        }
+        Some(stored)
+    }
+
+    /// Store a response body; failures only cost a future refetch.
+    pub(crate) fn store(&self, meta: Meta, body: &[u8]) {
+        if let Some(cache) = &self.cache {
+            let _ = cache.put(&self.key, &meta, body);
+        }
+    }
+
+    /// Remove the stored entry, such as when its body turned out corrupt.
+    pub(crate) fn discard(&self) {
+        if let Some(cache) = &self.cache {
+            let _ = fs::remove_file(cache.entry_path(&self.key));
+        }
+    }
+
+    /// Whether a body of `len` bytes is worth storing. Bodies above a
+    /// quarter of the cap would evict too much else.
+    pub(crate) fn accepts(&self, len: u64) -> bool {
+        self.cache
+            .as_ref()
+            .is_some_and(|cache| len <= cache.max_bytes / 4)
+    }
+}
+
+/// Cache metadata stored in front of the body.
+#[derive(Clone)]
+pub(crate) struct Meta {
+    url: String,
+    content_type: Option<String>,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    stored_at: i64,
+    /// Unix time until which the response is fresh.
+    fresh_until: i64,
+}
+
+pub(crate) struct Stored {
+    meta: Meta,
+    pub(crate) body: Vec<u8>,
+}
+
+impl Stored {
+    pub(crate) fn is_fresh(&self) -> bool {
+        now() < self.meta.fresh_until
+    }
+
+    fn response(&self) -> CachedResponse {
+        CachedResponse {
+            body: self.body.clone(),
+            url: self.meta.url.clone(),
+        }
+    }
+}
+
+/// The metadata to store `response` with, or `None` if it must not be
+/// stored.
+pub(crate) fn policy(response: &ureq::Response) -> Option<Meta> {
+    if !matches!(response.status(), 200 | 304) {
+        return None;
+    }
+    let cache_control = response.all("Cache-Control").join(",").to_ascii_lowercase();
+    let directives: Vec<&str> = cache_control.split(',').map(str::trim).collect();
+    if directives.contains(&"no-store") || response.header("Vary") == Some("*") {
+        return None;
+    }
+
+    let stored_at = now();
+    let max_age = directives
+        .iter()
+        .find_map(|d| d.strip_prefix("max-age="))
+        .and_then(|age| age.trim_matches('"').parse::<i64>().ok());
+    let etag = response.header("ETag").map(str::to_string);
+    let last_modified = response.header("Last-Modified").map(str::to_string);
+    let fresh_until = if directives.contains(&"no-cache") {
+        stored_at
+    } else if let Some(age) = max_age {
+        stored_at.saturating_add(age)
+    } else {
+        response
+            .header("Expires")
+            .and_then(parse_http_date)
+            .unwrap_or(stored_at)
+    };
+    // A response that is never fresh and cannot be revalidated is useless.
+    if fresh_until <= stored_at && etag.is_none() && last_modified.is_none() {
+        return None;
+    }
+    Some(Meta {
+        url: response.get_url().to_string(),
+        content_type: response.header("Content-Type").map(str::to_string),
+        etag,
+        last_modified,
+        stored_at,
+        fresh_until,
+    })
+}
+
+/// Parse an RFC 1123 date such as `Sun, 06 Nov 1994 08:49:37 GMT` into
+/// seconds since the Unix epoch.
+pub(crate) fn parse_http_date(value: &str) -> Option<i64> {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    let mut parts = value.split_once(", ")?.1.split(' ');
+    let day: i64 = parts.next()?.parse().ok()?;
+    let month_name = parts.next()?;
+    let month = MONTHS.iter().position(|&m| m == month_name)? as i64 + 1;
+    let year: i64 = parts.next()?.parse().ok()?;
+    let mut time = parts
+        .next()?
+        .split(':')
+        .map(|part| part.parse::<i64>().ok());
+    let (hour, minute, second) = (time.next()??, time.next()??, time.next()??);
+
+    // Days from the civil date, after Howard Hinnant's algorithm.
+    let (y, m) = if month <= 2 {
+        (year - 1, month + 9)
+    } else {
+        (year, month - 3)
+    };
+    let era = y.div_euclid(400);
+    let year_of_era = y - era * 400;
+    let day_of_year = (153 * m + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+    Some(days * 86_400 + hour * 3600 + minute * 60 + second)
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64)
+}
+
+/// Responses fetched with different credentials are cached separately.
+fn key(url: &str, authorization: Option<&str>) -> String {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(url.as_bytes());
+    if let Some(authorization) = authorization {
+        hasher.update(b"\n");
+        hasher.update(authorization.as_bytes());
+    }
+    hasher.finalize().to_hex().to_string()
+}
+
+fn lock() -> MutexGuard<'static, Option<HttpCache>> {
+    HTTP_CACHE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn current() -> Option<HttpCache> {
+    lock().clone()
+}
+
+#[derive(Clone)]
+struct HttpCache {
+    dir: PathBuf,
+    max_bytes: u64,
+}
+
+struct Entry {
+    path: PathBuf,
+    len: u64,
+    last_used: SystemTime,
+}
+
+impl HttpCache {
+    fn new(dir: PathBuf, max_bytes: u32) -> Result<Self> {
+        fs::create_dir_all(&dir).map_err(|e| anyhow!("Failed to create {}: {e}", dir.display()))?;
+        Ok(Self {
+            dir,
+            max_bytes: max_bytes as u64,
+        })
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.{ENTRY_EXTENSION}"))
+    }
+
+    fn read(&self, key: &str) -> Option<Stored> {
+        let path = self.entry_path(key);
+        let data = fs::read(&path).ok()?;
+        let stored = decode(&data)?;
+        touch(&path);
+        Some(stored)
+    }
+
+    fn put(&self, key: &str, meta: &Meta, body: &[u8]) -> Result<()> {
+        let data = encode(meta, body);
+        if data.len() as u64 > self.max_bytes {
+            return Ok(());
+        }
+        font_converter::write_atomically(&self.entry_path(key), &data)?;
+        self.evict()
+    }
+
+    /// Remove least recently used entries until the cache fits its cap.
+    fn evict(&self) -> Result<()> {
+        let mut entries = self.entries()?;
+        let mut total: u64 = entries.iter().map(|entry| entry.len).sum();
+        entries.sort_by_key(|entry| entry.last_used);
+
+        for entry in entries {
+            if total <= self.max_bytes {
+                break;
+            }
+            if fs::remove_file(&entry.path).is_ok() {
+                total -= entry.len;
+            }
+        }
+        Ok(())
+    }
+
+    fn stats(&self) -> Result<HttpCacheStats> {
+        let entries = self.entries()?;
+        Ok(HttpCacheStats {
+            entry_count: entries.len() as u32,
+            total_bytes: entries.iter().map(|entry| entry.len).sum::<u64>() as u32,
+            max_bytes: self.max_bytes as u32,
+        })
+    }
+
+    fn clear(&self) -> Result<()> {
+        for entry in self.entries()? {
+            fs::remove_file(&entry.path)
+                .map_err(|e| anyhow!("Failed to remove {}: {e}", entry.path.display()))?;
+        }
+        Ok(())
+    }
+
+    fn entries(&self) -> Result<Vec<Entry>> {
+        let dir = fs::read_dir(&self.dir)
+            .map_err(|e| anyhow!("Failed to read {}: {e}", self.dir.display()))?;
+
+        let mut entries = Vec::new();
+        for item in dir.flatten() {
+            let path = item.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some(ENTRY_EXTENSION) {
+                continue;
+            }
+            let Ok(metadata) = item.metadata() else {
+                continue;
+            };
+            entries.push(Entry {
+                path,
+                len: metadata.len(),
+                last_used: metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+            });
+        }
+        Ok(entries)
+    }
+}
+
+/// Serialize an entry as header lines, a blank line and the body.
+fn encode(meta: &Meta, body: &[u8]) -> Vec<u8> {
+    let mut header = format!(
+        "{MAGIC}\nurl: {}\nstored-at: {}\nfresh-until: {}\n",
+        meta.url, meta.stored_at, meta.fresh_until
+    );
+    for (name, value) in [
+        ("content-type", &meta.content_type),
+        ("etag", &meta.etag),
+        ("last-modified", &meta.last_modified),
+    ] {
+        if let Some(value) = value {
+            header.push_str(&format!("{name}: {value}\n"));
+        }
+    }
+    header.push('\n');
+    let mut data = header.into_bytes();
+    data.extend_from_slice(body);
+    data
+}
+
+fn decode(data: &[u8]) -> Option<Stored> {
+    let end = data.windows(2).position(|w| w == b"\n\n")?;
+    let header = std::str::from_utf8(&data[..end]).ok()?;
+    let mut lines = header.lines();
+    if lines.next()? != MAGIC {
+        return None;
+    }
+    let mut meta = Meta {
+        url: String::new(),
+        content_type: None,
+        etag: None,
+        last_modified: None,
+        stored_at: 0,
+        fresh_until: 0,
+    };
+    for line in lines {
+        let (name, value) = line.split_once(": ")?;
+        let value = value.to_string();
+        match name {
+            "url" => meta.url = value,
+            "stored-at" => meta.stored_at = value.parse().ok()?,
+            "fresh-until" => meta.fresh_until = value.parse().ok()?,
+            "content-type" => meta.content_type = Some(value),
+            "etag" => meta.etag = Some(value),
+            "last-modified" => meta.last_modified = Some(value),
+            _ => {}
+        }
+    }
+    Some(Stored {
+        meta,
+        body: data[end + 2..].to_vec(),
+    })
+}
+
+/// Mark a cache entry as recently used.
+fn touch(path: &Path) {
+    if let Ok(file) = fs::File::options().write(true).open(path) {
+        let _ = file.set_modified(SystemTime::now());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_dirs::temp_dir;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+
+    /// Serve the given raw responses in order, reporting each request's
+    /// headers.
+    fn serve(responses: Vec<String>) -> (String, mpsc::Receiver<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for (stream, response) in listener.incoming().zip(responses) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut headers = Vec::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    headers.push(line.trim().to_ascii_lowercase());
+                }
+                let _ = sender.send(headers);
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+        (format!("http://{address}/cover.jpg"), receiver)
+    }
+
+    fn response(status: &str, headers: &str, body: &str) -> String {
+        format!(
+            "HTTP/1.1 {status}\r\nConnection: close\r\nContent-Length: {}\r\n{headers}\r\n{body}",
+            body.len()
+        )
+    }
+
+    fn fetch(url: &str) -> Vec<u8> {
+        match get(ureq::agent().get(url), 1024) {
+            Ok(response) => response.body,
+            Err(_) => panic!("request failed"),
+        }
+    }
+
+    // The cache is global, so everything that needs it runs in one test.
+    #[test]
+    fn test_fresh_and_revalidated_responses() {
+        let dir = temp_dir("http-cache");
+        init_http_cache(dir.to_string_lossy().into_owned(), 4096).unwrap();
+
+        let (url, requests) = serve(vec![response(
+            "200 OK",
+            "Cache-Control: max-age=3600\r\n",
+            "fresh",
+        )]);
+        assert_eq!(fetch(&url), b"fresh");
+        assert_eq!(fetch(&url), b"fresh");
+        assert_eq!(requests.try_iter().count(), 1);
+        let entry = http_cache_entry(url.clone()).unwrap().unwrap();
+        assert!(entry.fresh);
+        assert_eq!(entry.size, 5);
+
+        let (url, requests) = serve(vec![
+            response("200 OK", "ETag: \"v1\"\r\n", "body"),
+            response("304 Not Modified", "ETag: \"v1\"\r\n", ""),
+            response("200 OK", "Cache-Control: no-store\r\n", "new"),
+        ]);
+        assert_eq!(fetch(&url), b"body");
+        assert_eq!(fetch(&url), b"body");
+        assert_eq!(fetch(&url), b"new");
+        let headers: Vec<Vec<String>> = requests.try_iter().collect();
+        assert!(headers[1].contains(&"if-none-match: \"v1\"".to_string()));
+        // The stale entry is still served while the server is unreachable.
+        assert_eq!(fetch(&url), b"body");
+
+        assert!(remove_http_cache_entry(url.clone()).unwrap());
+        assert!(!remove_http_cache_entry(url).unwrap());
+        assert_eq!(http_cache_stats().unwrap().entry_count, 1);
+        clear_http_cache().unwrap();
+        assert_eq!(http_cache_stats().unwrap().entry_count, 0);
+        *lock() = None;
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_entry_round_trip() {
+        let meta = Meta {
+            url: "https://example.com/a".to_string(),
+            content_type: Some("image/jpeg".to_string()),
+            etag: None,
+            last_modified: Some("Sun, 06 Nov 1994 08:49:37 GMT".to_string()),
+            stored_at: 10,
+            fresh_until: 20,
+        };
+        let stored = decode(&encode(&meta, b"\n\nbody")).unwrap();
+        assert_eq!(stored.body, b"\n\nbody");
+        assert_eq!(stored.meta.url, meta.url);
+        assert_eq!(stored.meta.content_type, meta.content_type);
+        assert_eq!(stored.meta.last_modified, meta.last_modified);
+        assert_eq!((stored.meta.stored_at, stored.meta.fresh_until), (10, 20));
+        assert!(decode(b"garbage\n\nbody").is_none());
+        assert_eq!(
+            parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"),
+            Some(784_111_777)
+        );
+    }
+}
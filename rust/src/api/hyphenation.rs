@@ -0,0 +1,463 @@
+//! Knuth–Liang hyphenation for justified Latin-script text.
+//!
+//! The hyph-utf8 patterns for American English, German (1996 orthography)
+//! and French are built in and parsed the first time each language is
+//! used; `load_hyphenation_patterns` adds other languages or replaces the
+//! built-in ones. Both the plain `.pat.txt` format and `.tex` files with
+//! `\patterns{…}` and `\hyphenation{…}` blocks are accepted. Results are
+//! soft-hyphen positions in UTF-16 code units, or the text with U+00AD
+//! inserted, which `paginate_text` breaks at.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use anyhow::{anyhow, Result};
+
+use crate::api::error::ApiError;
+
+pub(crate) const SOFT_HYPHEN: char = '\u{ad}';
+
+static HYPHENATORS: Mutex<Option<HashMap<String, Arc<Hyphenator>>>> = Mutex::new(None);
+
+/// Built-in patterns and exceptions by language. German and French cover
+/// every region; American English is used for plain `en` but not for
+/// other regions, whose spelling differs.
+const BUNDLED: &[(&str, &str, &str)] = &[
+    (
+        "en-us",
+        include_str!("../../patterns/hyph-en-us.pat.txt"),
+        include_str!("../../patterns/hyph-en-us.hyp.txt"),
+    ),
+    (
+        "de",
+        include_str!("../../patterns/hyph-de-1996.pat.txt"),
+        "",
+    ),
+    ("fr", include_str!("../../patterns/hyph-fr.pat.txt"), ""),
+];
+
+/// Load hyphenation patterns for a language, replacing any loaded before.
+/// Returns the number of patterns read.
+///
+/// # Arguments
+/// * `language` - BCP 47 tag such as `en-US`, `de` or `fr`; lookups for
+///   `en-GB` fall back to `en`
+/// * `patterns` - Contents of a TeX pattern file
+/// * `exceptions` - Extra hyphenated words such as `ta-ble`, one per line
+///   or space separated, overriding the patterns
+/// * `left_min` - Fewest letters before a hyphen; 2 by default
+/// * `right_min` - Fewest letters after a hyphen; 3 for English, otherwise
+///   2 by default
+#[flutter_rust_bridge::frb]
+pub fn load_hyphenation_patterns(
+    language: String,
+    patterns: String,
+    exceptions: Option<String>,
+    left_min: Option<u32>,
+    right_min: Option<u32>,
+) -> Result<u32, ApiError> {
+    let key = language_key(&language);
+    let hyphenator =
+        Hyphenator::build(&key, &patterns, exceptions.as_deref(), left_min, right_min)?;
+    let count = hyphenator.pattern_count;
+    lock()
+        .get_or_insert_with(HashMap::new)
+        .insert(key, Arc::new(hyphenator));
+    Ok(count)
+}
+
+/// Languages with patterns loaded or built in, as normalised lowercase
+/// tags.
+#[flutter_rust_bridge::frb]
+pub fn hyphenation_languages() -> Vec<String> {
+    let mut languages: Vec<String> = lock()
+        .as_ref()
+        .map(|loaded| loaded.keys().cloned().collect())
+        .unwrap_or_default();
+    languages.extend(BUNDLED.iter().map(|(tag, ..)| tag.to_string()));
+    languages.sort();
+    languages.dedup();
+    languages
+}
+
+/// Where soft hyphens may go in `text`: UTF-16 offsets of the characters
+/// a hyphen may be placed before, in increasing order.
+///
+/// # Arguments
+/// * `language` - Language whose patterns were loaded
+/// * `text` - Any text; only runs of letters are hyphenated
+#[flutter_rust_bridge::frb]
+pub fn hyphenation_points(language: String, text: String) -> Result<Vec<u32>, ApiError> {
+    let hyphenator = hyphenator(&language)?;
+    let mut points = Vec::new();
+    let mut offset = 0u32;
+    for_each_word(&text, |piece, is_word| {
+        if is_word {
+            let mut units = offset;
+            let breaks = hyphenator.hyphenate(piece);
+            for (i, c) in piece.chars().enumerate() {
+                if breaks.contains(&i) {
+                    points.push(units);
+                }
+                units += c.len_utf16() as u32;
+            }
+        }
+        offset += piece.encode_utf16().count() as u32;
+    });
+    Ok(points)
+}
+
+/// Copy of `text` with a soft hyphen (U+00AD) at every hyphenation point.
+///
+/// # Arguments
+/// * `language` - Language whose patterns were loaded
+/// * `text` - Any text; only runs of letters are hyphenated
+#[flutter_rust_bridge::frb]
+pub fn hyphenate_text(language: String, text: String) -> Result<String, ApiError> {
+    let hyphenator = hyphenator(&language)?;
+    let mut out = String::with_capacity(text.len() + text.len() / 8);
+    for_each_word(&text, |piece, is_word| {
+        if !is_word {
+            out.push_str(piece);
+            return;
+        }
+        let breaks = hyphenator.hyphenate(piece);
+        for (i, c) in piece.chars().enumerate() {
+            if breaks.contains(&i) {
+                out.push(SOFT_HYPHEN);
+            }
+            out.push(c);
+        }
+    });
+    Ok(out)
+}
+
+fn lock() -> MutexGuard<'static, Option<HashMap<String, Arc<Hyphenator>>>> {
+    HYPHENATORS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn language_key(language: &str) -> String {
+    language.trim().replace('_', "-").to_lowercase()
+}
+
+/// The loaded hyphenator for `language` or its primary language, loading
+/// built-in patterns if there are none.
+fn hyphenator(language: &str) -> Result<Arc<Hyphenator>> {
+    let key = language_key(language);
+    let mut guard = lock();
+    let loaded = guard.get_or_insert_with(HashMap::new);
+    let primary = key.split('-').next().unwrap_or_default();
+    if let Some(hyphenator) = loaded.get(&key).or_else(|| loaded.get(primary)) {
+        return Ok(hyphenator.clone());
+    }
+    let (tag, patterns, exceptions) =
+        bundled(&key).ok_or_else(|| anyhow!("No hyphenation patterns loaded for {language}"))?;
+    let hyphenator = Arc::new(Hyphenator::build(
+        tag,
+        patterns,
+        Some(exceptions),
+        None,
+        None,
+    )?);
+    loaded.insert(tag.to_string(), hyphenator.clone());
+    Ok(hyphenator)
+}
+
+/// Built-in patterns for the normalised tag `key`.
+fn bundled(key: &str) -> Option<(&'static str, &'static str, &'static str)> {
+    let primary = key.split('-').next().unwrap_or_default();
+    BUNDLED.iter().copied().find(|&(tag, ..)| {
+        tag == key || tag == primary || (key == primary && tag.starts_with(&format!("{key}-")))
+    })
+}
+
+/// Split `text` into runs of letters (words) and everything else. Words
+/// that already contain soft hyphens are passed on as they are.
+fn for_each_word(text: &str, mut f: impl FnMut(&str, bool)) {
+    let mut start = 0;
+    let mut in_word = false;
+    for (i, c) in text.char_indices() {
+        let letter = c.is_alphabetic() || c == SOFT_HYPHEN;
+        if letter != in_word {
+            if i > start {
+                f(
+                    &text[start..i],
+                    in_word && !text[start..i].contains(SOFT_HYPHEN),
+                );
+            }
+            start = i;
+            in_word = letter;
+        }
+    }
+    if start < text.len() {
+        f(
+            &text[start..],
+            in_word && !text[start..].contains(SOFT_HYPHEN),
+        );
+    }
+}
+
+/// Patterns stored as a trie over characters, with per-word exceptions.
+#[flutter_rust_bridge::frb(ignore)]
+struct Hyphenator {
+    nodes: Vec<TrieNode>,
+    /// Lowercase words mapped to the character indices they break before.
+    exceptions: HashMap<String, Vec<usize>>,
+    pattern_count: u32,
+    left_min: usize,
+    right_min: usize,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+struct TrieNode {
+    children: HashMap<char, usize>,
+    /// Inter-letter values of the pattern ending here, one more than its
+    /// letter count.
+    values: Option<Vec<u8>>,
+}
+
+impl Hyphenator {
+    /// Parse `patterns` for the normalised tag `key`, with the default
+    /// minimums for its language unless given.
+    fn build(
+        key: &str,
+        patterns: &str,
+        exceptions: Option<&str>,
+        left_min: Option<u32>,
+        right_min: Option<u32>,
+    ) -> Result<Self> {
+        let english = key == "en" || key.starts_with("en-");
+        let mut hyphenator = Self::parse(patterns)?;
+        if let Some(exceptions) = exceptions {
+            hyphenator.add_exceptions(exceptions);
+        }
+        hyphenator.left_min = left_min.unwrap_or(2).max(1) as usize;
+        hyphenator.right_min = right_min.unwrap_or(if english { 3 } else { 2 }).max(1) as usize;
+        Ok(hyphenator)
+    }
+
+    fn parse(source: &str) -> Result<Self> {
+        let source: String = source
+            .lines()
+            .map(|line| line.split('%').next().unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let (patterns, exceptions) = if source.contains("\\patterns") {
+            (
+                tex_group(&source, "\\patterns").unwrap_or_default(),
+                tex_group(&source, "\\hyphenation").unwrap_or_default(),
+            )
+        } else {
+            (source.as_str(), "")
+        };
+
+        let mut hyphenator = Self {
+            nodes: vec![TrieNode::default()],
+            exceptions: HashMap::new(),
+            pattern_count: 0,
+            left_min: 2,
+            right_min: 2,
+        };
+        for pattern in patterns.split_whitespace() {
+            hyphenator.insert(pattern);
+        }
+        if hyphenator.pattern_count == 0 {
+            return Err(anyhow!("No hyphenation patterns found"));
+        }
+        hyphenator.add_exceptions(exceptions);
+        Ok(hyphenator)
+    }
+
+    fn insert(&mut self, pattern: &str) {
+        let mut values = vec![0];
+        let mut node = 0;
+        for c in pattern.chars() {
+            if let Some(digit) = c.to_digit(10) {
+                *values.last_mut().unwrap() = digit as u8;
+                continue;
+            }
+            let c = lowercase(c);
+            node = match self.nodes[node].children.get(&c) {
+                Some(&child) => child,
+                None => {
+                    self.nodes.push(TrieNode::default());
+                    let child = self.nodes.len() - 1;
+                    self.nodes[node].children.insert(c, child);
+                    child
+                }
+            };
+            values.push(0);
+        }
+        if node != 0 {
+            self.nodes[node].values = Some(values);
+            self.pattern_count += 1;
+        }
+    }
+
+    fn add_exceptions(&mut self, exceptions: &str) {
+        for word in exceptions.split_whitespace() {
+            let mut letters = String::new();
+            let mut breaks = Vec::new();
+            for c in word.chars() {
+                if c == '-' {
+                    breaks.push(letters.chars().count());
+                } else {
+                    letters.push(lowercase(c));
+                }
+            }
+            if !letters.is_empty() {
+                self.exceptions.insert(letters, breaks);
+            }
+        }
+    }
+
+    /// Character indices of `word` that a hyphen may go before.
+    fn hyphenate(&self, word: &str) -> Vec<usize> {
+        let letters: Vec<char> = word.chars().map(lowercase).collect();
+        let len = letters.len();
+        if len < self.left_min + self.right_min {
+            return Vec::new();
+        }
+        let allowed = |i: &usize| *i >= self.left_min && *i + self.right_min <= len;
+        let lowered: String = letters.iter().collect();
+        if let Some(breaks) = self.exceptions.get(&lowered) {
+            return breaks.iter().copied().filter(allowed).collect();
+        }
+
+        // Match every pattern against ".word." and keep the highest value
+        // between each pair of letters; odd values allow a break.
+        let mut padded = Vec::with_capacity(len + 2);
+        padded.push('.');
+        padded.extend_from_slice(&letters);
+        padded.push('.');
+        let mut values = vec![0u8; padded.len() + 1];
+        for start in 0..padded.len() {
+            let mut node = 0;
+            for &c in &padded[start..] {
+                let Some(&child) = self.nodes[node].children.get(&c) else {
+                    break;
+                };
+                node = child;
+                if let Some(pattern) = &self.nodes[node].values {
+                    for (k, &value) in pattern.iter().enumerate() {
+                        let slot = &mut values[start + k];
+                        *slot = (*slot).max(value);
+                    }
+                }
+            }
+        }
+        // `values[i + 1]` sits between letters `i - 1` and `i`.
+        (1..len)
+            .filter(|i| values[i + 1] % 2 == 1)
+            .filter(allowed)
+            .collect()
+    }
+}
+
+fn lowercase(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+/// Contents of the brace group following `command`.
+fn tex_group<'a>(source: &'a str, command: &str) -> Option<&'a str> {
+    let start = source.find(command)? + command.len();
+    let open = start + source[start..].find('{')? + 1;
+    let close = open + source[open..].find('}')?;
+    Some(&source[open..close])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The patterns from Liang's thesis that hyphenate "hyphenation".
+    const PATTERNS: &str = "% sample\nhy3ph he2n hena4 hen5at\n1na n2at 1tio 2io o2n\n";
+
+    fn marked(hyphenator: &Hyphenator, word: &str) -> String {
+        let breaks = hyphenator.hyphenate(word);
+        let mut out = String::new();
+        for (i, c) in word.chars().enumerate() {
+            if breaks.contains(&i) {
+                out.push('-');
+            }
+            out.push(c);
+        }
+        out
+    }
+
+    #[test]
+    fn test_liang_patterns() {
+        let mut hyphenator = Hyphenator::parse(PATTERNS).unwrap();
+        assert_eq!(hyphenator.pattern_count, 9);
+        assert_eq!(marked(&hyphenator, "hyphenation"), "hy-phen-ation");
+        assert_eq!(marked(&hyphenator, "Hyphenation"), "Hy-phen-ation");
+
+        // The minimums keep short fragments off line edges.
+        hyphenator.left_min = 3;
+        hyphenator.right_min = 5;
+        assert_eq!(marked(&hyphenator, "hyphenation"), "hyphen-ation");
+        assert_eq!(marked(&hyphenator, "hyp"), "hyp");
+    }
+
+    #[test]
+    fn test_tex_file_with_exceptions() {
+        let source = "\\patterns{ % comment }\n1ba 1bi }\n\\hyphenation{ ta-ble }";
+        let hyphenator = Hyphenator::parse(source).unwrap();
+        assert_eq!(hyphenator.pattern_count, 2);
+        // "a-bacus" is ruled out by the two-letter minimum.
+        assert_eq!(marked(&hyphenator, "abacus"), "abacus");
+        assert_eq!(marked(&hyphenator, "cabbies"), "cab-bies");
+        assert_eq!(marked(&hyphenator, "Table"), "Ta-ble");
+        assert!(Hyphenator::parse("% only comments").is_err());
+    }
+
+    #[test]
+    fn test_hyphenate_text_and_points() {
+        load_hyphenation_patterns(
+            "en_US".to_string(),
+            PATTERNS.to_string(),
+            Some("ex-cep-tion".to_string()),
+            None,
+            None,
+        )
+        .unwrap();
+        assert!(hyphenation_languages().contains(&"en-us".to_string()));
+
+        let text = "“Hyphenation” exception, hy\u{ad}phenation.".to_string();
+        let hyphenated = hyphenate_text("en-US".to_string(), text.clone()).unwrap();
+        assert_eq!(
+            hyphenated,
+            "“Hy\u{ad}phen\u{ad}ation” ex\u{ad}cep\u{ad}tion, hy\u{ad}phenation."
+        );
+        // Offsets count UTF-16 units and point at the letter after the break.
+        assert_eq!(
+            hyphenation_points("en-US".to_string(), text).unwrap(),
+            [3, 7, 16, 19]
+        );
+        assert!(hyphenate_text("en-GB".to_string(), String::new()).is_err());
+    }
+
+    #[test]
+    fn test_bundled_patterns() {
+        let hyphenate = |language: &str, word: &str| {
+            let (tag, patterns, exceptions) = bundled(&language_key(language)).unwrap();
+            marked(
+                &Hyphenator::build(tag, patterns, Some(exceptions), None, None).unwrap(),
+                word,
+            )
+        };
+        assert_eq!(hyphenate("en", "hyphenation"), "hy-phen-a-tion");
+        assert_eq!(hyphenate("en-US", "academy"), "acad-emy");
+        assert_eq!(hyphenate("de-AT", "Silbentrennung"), "Sil-ben-tren-nung");
+        assert_eq!(hyphenate("fr", "hyphénation"), "hy-phé-na-tion");
+        assert!(bundled("en-gb").is_none());
+
+        // Built-in languages work without loading anything.
+        assert_eq!(
+            hyphenate_text("fr-CA".to_string(), "nation".to_string()).unwrap(),
+            "na\u{ad}tion"
+        );
+        assert!(hyphenation_languages().contains(&"de".to_string()));
+    }
+}
@@ -0,0 +1,209 @@
+//! Cover image processing.
+//!
+//! Covers from sources are often multi-megabyte originals several thousand
+//! pixels tall. `process_cover` turns one into what the library actually
+//! shows: it decodes the image, cuts the centre to the library's aspect
+//! ratio, downsizes it with a Lanczos filter and encodes it as lossy WebP.
+//! Images already smaller than the target are cropped but never upscaled.
+//!
+//! JPEG, PNG, GIF and WebP always decode. AVIF needs the `avif` feature,
+//! which links the system dav1d library; without it AVIF covers fail with
+//! an error saying so.
+
+use std::io::Cursor;
+
+use ::image::codecs::jpeg::JpegEncoder;
+use ::image::imageops::FilterType;
+use ::image::{DynamicImage, ImageFormat, ImageReader};
+use anyhow::{anyhow, Result};
+
+use crate::api::error::ApiError;
+
+#[derive(Debug, Clone)]
+pub struct CoverImageOptions {
+    /// Largest output width in pixels; 0 for no limit.
+    pub max_width: u32,
+    /// Largest output height in pixels; 0 for no limit.
+    pub max_height: u32,
+    /// Width divided by height to crop to; 0 keeps the original shape.
+    pub aspect_ratio: f32,
+    /// WebP quality from 0 to 100.
+    pub quality: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProcessedImage {
+    /// WebP bytes.
+    pub data: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+    /// Size of the decoded original.
+    pub source_width: u32,
+    pub source_height: u32,
+}
+
+/// Options matching the 2:3 library grid.
+#[flutter_rust_bridge::frb]
+pub fn default_cover_image_options() -> CoverImageOptions {
+    CoverImageOptions {
+        max_width: 600,
+        max_height: 900,
+        aspect_ratio: 2.0 / 3.0,
+        quality: 80.0,
+    }
+}
+
+/// Decode, crop, resize and re-encode a cover as WebP.
+///
+/// # Arguments
+/// * `image_bytes` - Encoded image (JPEG, PNG, GIF, WebP, or AVIF with the `avif` feature)
+/// * `options` - Output size, shape and quality
+#[flutter_rust_bridge::frb]
+pub fn process_cover(
+    image_bytes: Vec<u8>,
+    options: CoverImageOptions,
+) -> Result<ProcessedImage, ApiError> {
+    let image = decode(&image_bytes)?;
+    let (source_width, source_height) = (image.width(), image.height());
+    if source_width == 0 || source_height == 0 {
+        return Err(anyhow!("Failed to decode image: image is empty").into());
+    }
+    let image = resize(crop(image, options.aspect_ratio), &options);
+    let rgba = image.to_rgba8();
+    let data = webp::Encoder::from_rgba(rgba.as_raw(), rgba.width(), rgba.height())
+        .encode(options.quality.clamp(0.0, 100.0))
+        .to_vec();
+    Ok(ProcessedImage {
+        data,
+        width: rgba.width(),
+        height: rgba.height(),
+        source_width,
+        source_height,
+    })
+}
+
+/// Re-encode an image as JPEG for formats that take nothing else; JPEG
+/// input is returned as is.
+pub(crate) fn to_jpeg(data: &[u8], quality: u8) -> Result<Vec<u8>> {
+    if data.starts_with(b"\xFF\xD8\xFF") {
+        return Ok(data.to_vec());
+    }
+    let rgb = decode(data)?.to_rgb8();
+    let mut jpeg = Vec::new();
+    JpegEncoder::new_with_quality(&mut jpeg, quality)
+        .encode_image(&rgb)
+        .map_err(|e| anyhow!("Failed to encode JPEG: {e}"))?;
+    Ok(jpeg)
+}
+
+pub(crate) fn decode(data: &[u8]) -> Result<DynamicImage> {
+    let reader = ImageReader::new(Cursor::new(data))
+        .with_guessed_format()
+        .map_err(|e| anyhow!("Failed to read image: {e}"))?;
+    if reader.format() == Some(ImageFormat::Avif) && !cfg!(feature = "avif") {
+        return Err(anyhow!(
+            "Failed to decode image: AVIF support is not built in"
+        ));
+    }
+    reader
+        .decode()
+        .map_err(|e| anyhow!("Failed to decode image: {e}"))
+}
+
+/// The largest centred region with the requested aspect ratio.
+fn crop(image: DynamicImage, aspect_ratio: f32) -> DynamicImage {
+    if !(aspect_ratio > 0.0 && aspect_ratio.is_finite()) {
+        return image;
+    }
+    let (width, height) = (image.width(), image.height());
+    let target = aspect_ratio as f64;
+    let current = width as f64 / height as f64;
+    let (crop_width, crop_height) = if current > target {
+        (
+            ((height as f64 * target).round() as u32).clamp(1, width),
+            height,
+        )
+    } else {
+        (
+            width,
+            ((width as f64 / target).round() as u32).clamp(1, height),
+        )
+    };
+    if (crop_width, crop_height) == (width, height) {
+        return image;
+    }
+    image.crop_imm(
+        (width - crop_width) / 2,
+        (height - crop_height) / 2,
+        crop_width,
+        crop_height,
+    )
+}
+
+/// Fit within the size limits, keeping the shape and never upscaling.
+fn resize(image: DynamicImage, options: &CoverImageOptions) -> DynamicImage {
+    let limit = |max: u32, actual: u32| if max == 0 { actual } else { max.min(actual) };
+    let max_width = limit(options.max_width, image.width());
+    let max_height = limit(options.max_height, image.height());
+    if max_width == image.width() && max_height == image.height() {
+        return image;
+    }
+    image.resize(max_width, max_height, FilterType::Lanczos3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::image::{Rgb, RgbImage};
+
+    fn encoded(width: u32, height: u32, format: ImageFormat) -> Vec<u8> {
+        let image = RgbImage::from_fn(width, height, |x, _| {
+            // Red left third, green middle, blue right third.
+            match x * 3 / width {
+                0 => Rgb([220, 20, 20]),
+                1 => Rgb([20, 220, 20]),
+                _ => Rgb([20, 20, 220]),
+            }
+        });
+        let mut data = Vec::new();
+        image.write_to(&mut Cursor::new(&mut data), format).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_crops_and_resizes_to_webp() {
+        // A wide 3000×1500 image becomes a centred 600×900 portrait.
+        let data = encoded(3000, 1500, ImageFormat::Jpeg);
+        let result = process_cover(data, default_cover_image_options()).unwrap();
+        assert_eq!((result.source_width, result.source_height), (3000, 1500));
+        assert_eq!((result.width, result.height), (600, 900));
+        assert_eq!(&result.data[..4], b"RIFF");
+        assert_eq!(&result.data[8..12], b"WEBP");
+        let decoded = ::image::load_from_memory(&result.data).unwrap().to_rgb8();
+        assert_eq!(decoded.dimensions(), (600, 900));
+        // Only the green middle third survives the crop.
+        let pixel = decoded.get_pixel(300, 450);
+        assert!(pixel[1] > 180 && pixel[0] < 80 && pixel[2] < 80);
+    }
+
+    #[test]
+    fn test_small_image_is_not_upscaled() {
+        let data = encoded(200, 200, ImageFormat::Png);
+        let options = CoverImageOptions {
+            aspect_ratio: 0.0,
+            ..default_cover_image_options()
+        };
+        let result = process_cover(data, options).unwrap();
+        assert_eq!((result.width, result.height), (200, 200));
+
+        let data = encoded(300, 300, ImageFormat::Png);
+        let result = process_cover(data, default_cover_image_options()).unwrap();
+        assert_eq!((result.width, result.height), (200, 300));
+    }
+
+    #[test]
+    fn test_rejects_invalid_image() {
+        let result = process_cover(b"nope".to_vec(), default_cover_image_options());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,816 @@
+//! Whole-book text import, spread over all cores.
+//!
+//! `txt::parse_txt` handles a book on one thread, a line and a chapter at a
+//! time. Here the file is cut into blocks at line breaks that are decoded
+//! and searched for headings in parallel, then every chapter is purified,
+//! converted to HTML and counted in parallel. The chapters found match
+//! `parse_txt`, except that fixed-size parts of books without headings are
+//! measured in decoded rather than file bytes.
+//!
+//! `process_books` takes a list of files, such as an extracted archive, and
+//! streams an `ImportEvent` for each step of each file: discovery, decoding,
+//! splitting, chapter progress and any warnings. EPUB, MOBI, PDF, FB2 and
+//! UMD files are read by their own importers and only counted here. A
+//! skipped or failed file does not stop the rest, and the `Finished` event
+//! carries a report of them all.
+
+use std::ops::Range;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+use anyhow::{anyhow, Result};
+use encoding_rs::{Encoding, UTF_16BE, UTF_16LE};
+use rayon::prelude::*;
+use regex::Regex;
+
+use crate::api::archive::{self, ImportKind};
+use crate::api::book::{self, BookChapter, BookMetadata, ParsedBook, TocEntry};
+use crate::api::encoding;
+use crate::api::error::ApiError;
+use crate::api::langdetect;
+use crate::api::purify::{self, PurifyRule};
+use crate::api::stats::{self, BookStats};
+use crate::api::task::{self, CancelToken};
+use crate::api::txt::{self, TxtChapterRule};
+use crate::api::{epub, fb2, mobi, pdf, umd};
+use crate::frb_generated::StreamSink;
+use crate::xhtml;
+
+/// Input is decoded in blocks of about this many bytes.
+const BLOCK_BYTES: usize = 256 * 1024;
+
+/// Detected encodings less certain than this are reported as a warning.
+const LOW_CONFIDENCE: f32 = 0.8;
+
+#[derive(Debug, Clone)]
+pub struct ImportOptions {
+    /// Encoding label to decode with instead of the detected one.
+    pub override_encoding: Option<String>,
+    /// Heading rules; `None` uses `default_txt_rules()`.
+    pub heading_rules: Option<Vec<TxtChapterRule>>,
+    /// Clean-up rules applied to every chapter of a text file; may be empty.
+    pub purify_rules: Vec<PurifyRule>,
+    /// Reading speed for the statistics, in CJK characters per minute.
+    pub chars_per_minute: u32,
+}
+
+/// What an `ImportEvent` reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportEventKind {
+    /// A file was queued. Sent for every file before any is read.
+    FileDiscovered,
+    /// `done` of `total` blocks of the file are decoded.
+    Decoding,
+    /// The file's text was split into chapters.
+    Splitting,
+    /// `done` of `total` chapters are purified and counted.
+    Processing,
+    /// Something in the file needs a look, such as an uncertain encoding;
+    /// the import carries on.
+    Warning,
+    /// The file was imported; `book` holds it.
+    FileImported,
+    /// The file was left alone, such as a comic or an empty file.
+    FileSkipped,
+    /// The file could not be imported; the rest carry on.
+    FileFailed,
+    /// Every file is done; `report` sums them up.
+    Finished,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportEvent {
+    pub kind: ImportEventKind,
+    /// Position of the file in the list passed in.
+    pub file_index: u32,
+    pub path: String,
+    pub done: u32,
+    pub total: u32,
+    /// The warning, or why the file was skipped or failed.
+    pub message: Option<String>,
+    /// The imported book, on `FileImported` only.
+    pub book: Option<ProcessedBook>,
+    /// On `Finished` only.
+    pub report: Option<ImportReport>,
+}
+
+/// What happened to the files of one call, for a post-import summary.
+#[derive(Debug, Clone, Default)]
+pub struct ImportReport {
+    pub imported: u32,
+    pub skipped: Vec<ImportIssue>,
+    pub failed: Vec<ImportIssue>,
+    /// Warnings of every file, imported or not.
+    pub warnings: Vec<ImportIssue>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportIssue {
+    pub path: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProcessedBook {
+    pub book: ParsedBook,
+    /// Encoding label a text file was decoded from, e.g. `GBK`; empty for
+    /// other formats.
+    pub encoding: String,
+    /// Statistics of the purified text, per chapter and in total.
+    pub stats: BookStats,
+}
+
+/// Options with the built-in rules, a typical reading speed and no
+/// purification.
+#[flutter_rust_bridge::frb]
+pub fn default_import_options() -> ImportOptions {
+    ImportOptions {
+        override_encoding: None,
+        heading_rules: None,
+        purify_rules: Vec::new(),
+        chars_per_minute: 400,
+    }
+}
+
+/// Import books one after another. Text files are decoded, split into
+/// chapters, and purified and counted a chapter at a time, all in
+/// parallel; other formats are read by their importer and counted. A file
+/// that cannot be imported is reported and the rest carry on.
+///
+/// # Arguments
+/// * `paths` - Books, such as those extracted by `extract_archive`; text
+///   files may be at most 50 MB, and comics are skipped
+/// * `options` - Encoding, heading, purify and statistics settings; only
+///   the statistics settings apply to formats other than text
+/// * `sink` - Receives every file's events, then a `Finished` event
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+#[flutter_rust_bridge::frb]
+pub async fn process_books(
+    paths: Vec<String>,
+    options: ImportOptions,
+    sink: StreamSink<ImportEvent>,
+    cancel_token: Option<u32>,
+) -> Result<(), ApiError> {
+    task::run_blocking(task::cancel_token(cancel_token)?, move |token| {
+        import(&paths, &options, token, &|event| {
+            sink.add(event)
+                .map_err(|_| anyhow!("Import event stream was closed"))
+        })
+    })
+    .await
+}
+
+type Events<'a> = &'a (dyn Fn(ImportEvent) -> Result<()> + Sync);
+
+fn import(
+    paths: &[String],
+    options: &ImportOptions,
+    token: &CancelToken,
+    events: Events,
+) -> Result<()> {
+    let event = |kind, file_index: usize, path: &str| ImportEvent {
+        kind,
+        file_index: file_index as u32,
+        path: path.to_string(),
+        done: file_index as u32 + 1,
+        total: paths.len() as u32,
+        message: None,
+        book: None,
+        report: None,
+    };
+    for (i, path) in paths.iter().enumerate() {
+        events(event(ImportEventKind::FileDiscovered, i, path))?;
+    }
+
+    let report = Mutex::new(ImportReport::default());
+    // Set when `events` fails, which ends the call rather than the file.
+    let closed = AtomicBool::new(false);
+    let issue = |path: &str, message: &str| ImportIssue {
+        path: path.to_string(),
+        message: message.to_string(),
+    };
+    for (i, path) in paths.iter().enumerate() {
+        token.check()?;
+        let step: Progress = &|kind, done, total, message: Option<String>| {
+            if let Some(message) = &message {
+                lock(&report).warnings.push(issue(path, message));
+            }
+            let sent = events(ImportEvent {
+                done,
+                total,
+                message,
+                ..event(kind, i, path)
+            });
+            closed.fetch_or(sent.is_err(), Ordering::Relaxed);
+            sent
+        };
+        let mut outcome = event(ImportEventKind::FileImported, i, path);
+        match import_file(path, options, token, step) {
+            Ok(Imported::Book(book)) => {
+                lock(&report).imported += 1;
+                outcome.book = Some(book);
+            }
+            Ok(Imported::Skipped(reason)) => {
+                lock(&report).skipped.push(issue(path, &reason));
+                outcome.kind = ImportEventKind::FileSkipped;
+                outcome.message = Some(reason);
+            }
+            Err(e) if token.is_cancelled() || closed.load(Ordering::Relaxed) => return Err(e),
+            Err(e) => {
+                lock(&report).failed.push(issue(path, &e.to_string()));
+                outcome.kind = ImportEventKind::FileFailed;
+                outcome.message = Some(e.to_string());
+            }
+        }
+        events(outcome)?;
+    }
+
+    events(ImportEvent {
+        kind: ImportEventKind::Finished,
+        file_index: 0,
+        path: String::new(),
+        done: paths.len() as u32,
+        total: paths.len() as u32,
+        message: None,
+        book: None,
+        report: Some(report.into_inner().unwrap_or_else(|e| e.into_inner())),
+    })
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+enum Imported {
+    Book(ProcessedBook),
+    Skipped(String),
+}
+
+fn import_file(
+    path: &str,
+    options: &ImportOptions,
+    token: &CancelToken,
+    progress: Progress,
+) -> Result<Imported> {
+    let kind = if path.to_ascii_lowercase().ends_with(".fb2.zip") {
+        Some(ImportKind::Fb2)
+    } else {
+        archive::import_kind(path)
+    };
+    // Files without a known book extension are read as text.
+    let kind = kind.unwrap_or(ImportKind::Txt);
+    if kind == ImportKind::Comic {
+        return Ok(Imported::Skipped(
+            "Not a book; open it with list_comic_pages".to_string(),
+        ));
+    }
+    let size = std::fs::metadata(path)
+        .map_err(|e| anyhow!("Failed to open {path}: {e}"))?
+        .len();
+    if size == 0 {
+        return Ok(Imported::Skipped("The file is empty".to_string()));
+    }
+    let title = Path::new(path)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned());
+    if kind != ImportKind::Txt {
+        let book = parse_book(path, kind)?;
+        token.check()?;
+        return Ok(Imported::Book(process_parsed(
+            book, title, options, token, progress,
+        )?));
+    }
+    if size > txt::MAX_PARSE_BYTES {
+        return Ok(Imported::Skipped(
+            "Too large to import at once; open it with index_txt".to_string(),
+        ));
+    }
+    let data = std::fs::read(path).map_err(|e| anyhow!("Failed to read {path}: {e}"))?;
+    Ok(Imported::Book(process(
+        &data, title, options, token, progress,
+    )?))
+}
+
+/// Read a book with the importer of its format, as `parse_epub` and the
+/// others do.
+fn parse_book(path: &str, kind: ImportKind) -> Result<ParsedBook> {
+    match kind {
+        ImportKind::Epub => epub::parse(&mut epub::open_file(path)?),
+        ImportKind::Mobi => mobi::parse(&mobi::read_file(path)?),
+        ImportKind::Pdf => {
+            pdf::parse(&std::fs::read(path).map_err(|e| anyhow!("Failed to open {path}: {e}"))?)
+        }
+        ImportKind::Fb2 => fb2::parse(&xhtml::decode(&fb2::read_document(path)?)),
+        ImportKind::Umd => umd::parse(&umd::read_file(path)?),
+        ImportKind::Txt | ImportKind::Comic => Err(anyhow!("{path} is not a book")),
+    }
+}
+
+/// Reports a stage's `done` and `total`, or a warning message.
+type Progress<'a> = &'a (dyn Fn(ImportEventKind, u32, u32, Option<String>) -> Result<()> + Sync);
+
+fn process(
+    data: &[u8],
+    title: Option<String>,
+    options: &ImportOptions,
+    token: &CancelToken,
+    progress: Progress,
+) -> Result<ProcessedBook> {
+    stats::check_speed(options.chars_per_minute)?;
+    let rules = txt::compile_rules(options.heading_rules.clone())?;
+    let report = |kind, done, total| progress(kind, done, total, None);
+    let warn = |message: String| progress(ImportEventKind::Warning, 0, 0, Some(message));
+
+    let (encoding, bom_len, confidence) =
+        pick_encoding(data, options.override_encoding.as_deref())?;
+    if confidence < LOW_CONFIDENCE {
+        warn(format!(
+            "The encoding is probably {}, but this is a guess",
+            encoding.name()
+        ))?;
+    }
+    let blocks = line_blocks(data, bom_len, encoding);
+    let decoded = AtomicU32::new(0);
+    let malformed = AtomicU32::new(0);
+    let blocks: Vec<String> = blocks
+        .par_iter()
+        .map(|range| {
+            token.check()?;
+            let (text, had_errors) = encoding.decode_without_bom_handling(&data[range.clone()]);
+            if had_errors {
+                malformed.fetch_add(1, Ordering::Relaxed);
+            }
+            let done = decoded.fetch_add(1, Ordering::Relaxed) + 1;
+            report(ImportEventKind::Decoding, done, blocks.len() as u32)?;
+            Ok(text.into_owned())
+        })
+        .collect::<Result<_>>()?;
+    let malformed = malformed.into_inner();
+    if malformed > 0 {
+        warn(format!(
+            "{malformed} of {} blocks had bytes that are not {}; they were replaced",
+            blocks.len(),
+            encoding.name()
+        ))?;
+    }
+
+    let chapters = split(&blocks, &rules);
+    let text = blocks.concat();
+    report(ImportEventKind::Splitting, 1, 1)?;
+    if chapters.iter().all(|(title, _)| title.is_none()) {
+        warn(format!(
+            "No chapter headings matched; the text was cut into parts of about {} KB",
+            txt::FALLBACK_CHAPTER_BYTES / 1024
+        ))?;
+    }
+
+    let processed = AtomicU32::new(0);
+    let total = chapters.len() as u32;
+    let chapters: Vec<(BookChapter, stats::TextStats, langdetect::Sample)> = chapters
+        .into_par_iter()
+        .enumerate()
+        .map(|(i, (title, range))| {
+            token.check()?;
+            let mut body = text[range].to_string();
+            for rule in options.purify_rules.iter().filter(|rule| rule.enabled) {
+                body = purify::apply(&body, rule, None)?;
+            }
+            let chapter_stats = stats::count(&body, options.chars_per_minute);
+            let mut sample = langdetect::Sample::default();
+            sample.add(&body);
+            let chapter = BookChapter {
+                index: i as u32,
+                href: book::part_href(i),
+                html: txt::chapter_html(&body, title.is_some()),
+                title,
+                images: Vec::new(),
+            };
+            let done = processed.fetch_add(1, Ordering::Relaxed) + 1;
+            report(ImportEventKind::Processing, done, total)?;
+            Ok((chapter, chapter_stats, sample))
+        })
+        .collect::<Result<_>>()?;
+
+    let mut sample = langdetect::Sample::default();
+    let mut chapter_stats = Vec::with_capacity(chapters.len());
+    let chapters: Vec<BookChapter> = chapters
+        .into_iter()
+        .map(|(chapter, stats, chapter_sample)| {
+            chapter_stats.push(stats);
+            sample.merge(chapter_sample);
+            chapter
+        })
+        .collect();
+    let toc = chapters
+        .iter()
+        .filter_map(|chapter| {
+            Some(TocEntry {
+                title: chapter.title.clone()?,
+                href: chapter.href.clone(),
+                chapter_index: Some(chapter.index),
+                depth: 0,
+            })
+        })
+        .collect();
+    let mut metadata = BookMetadata::empty();
+    metadata.title = title;
+    metadata.language = langdetect::book_language(&sample);
+    Ok(ProcessedBook {
+        book: ParsedBook {
+            metadata,
+            chapters,
+            toc,
+        },
+        encoding: encoding.name().to_string(),
+        stats: BookStats {
+            total: stats::sum(&chapter_stats, options.chars_per_minute),
+            chapters: chapter_stats,
+        },
+    })
+}
+
+/// Count the chapters of a book read by its format's importer. The book
+/// was decoded and split by the importer, so those stages report at once;
+/// its HTML is kept as it is, without purification.
+fn process_parsed(
+    mut book: ParsedBook,
+    title: Option<String>,
+    options: &ImportOptions,
+    token: &CancelToken,
+    progress: Progress,
+) -> Result<ProcessedBook> {
+    stats::check_speed(options.chars_per_minute)?;
+    progress(ImportEventKind::Decoding, 1, 1, None)?;
+    progress(ImportEventKind::Splitting, 1, 1, None)?;
+
+    let processed = AtomicU32::new(0);
+    let total = book.chapters.len() as u32;
+    let counted: Vec<(stats::TextStats, langdetect::Sample)> = book
+        .chapters
+        .par_iter()
+        .map(|chapter| {
+            token.check()?;
+            let text = xhtml::strip_tags(&chapter.html);
+            let mut sample = langdetect::Sample::default();
+            sample.add(&text);
+            let done = processed.fetch_add(1, Ordering::Relaxed) + 1;
+            progress(ImportEventKind::Processing, done, total, None)?;
+            Ok((stats::count(&text, options.chars_per_minute), sample))
+        })
+        .collect::<Result<_>>()?;
+
+    let mut sample = langdetect::Sample::default();
+    let mut chapter_stats = Vec::with_capacity(counted.len());
+    for (stats, chapter_sample) in counted {
+        chapter_stats.push(stats);
+        sample.merge(chapter_sample);
+    }
+    let metadata = &mut book.metadata;
+    if metadata.title.as_deref().is_none_or(str::is_empty) {
+        metadata.title = title;
+    }
+    if metadata.language.is_none() {
+        metadata.language = langdetect::book_language(&sample);
+    }
+    Ok(ProcessedBook {
+        book,
+        encoding: String::new(),
+        stats: BookStats {
+            total: stats::sum(&chapter_stats, options.chars_per_minute),
+            chapters: chapter_stats,
+        },
+    })
+}
+
+/// The encoding to decode with, the length of the BOM to skip and how
+/// sure the detection is.
+fn pick_encoding(data: &[u8], label: Option<&str>) -> Result<(&'static Encoding, usize, f32)> {
+    let detection = encoding::detect(data, true);
+    let Some(label) = label else {
+        let confidence = detection.candidates[0].1;
+        return Ok((detection.encoding, detection.bom_len, confidence));
+    };
+    let chosen = Encoding::for_label(label.trim().as_bytes())
+        .ok_or_else(|| anyhow!("Unknown encoding {label}"))?;
+    let bom_len = if chosen == detection.encoding {
+        detection.bom_len
+    } else {
+        0
+    };
+    Ok((chosen, bom_len, 1.0))
+}
+
+/// Cut `data` after `start` into blocks of about `BLOCK_BYTES` that end
+/// after a line break, so no character or line spans two blocks.
+fn line_blocks(data: &[u8], start: usize, encoding: &'static Encoding) -> Vec<Range<usize>> {
+    let mut blocks = Vec::new();
+    let mut begin = start;
+    while begin < data.len() {
+        let end = line_end(data, start, (begin + BLOCK_BYTES).min(data.len()), encoding);
+        blocks.push(begin..end);
+        begin = end;
+    }
+    blocks
+}
+
+/// The position just after the first line break at or after `from`, or the
+/// end of `data`.
+pub(crate) fn line_end(
+    data: &[u8],
+    start: usize,
+    from: usize,
+    encoding: &'static Encoding,
+) -> usize {
+    if encoding != UTF_16LE && encoding != UTF_16BE {
+        return data[from..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .map_or(data.len(), |i| from + i + 1);
+    }
+    // Only a whole `\n` code unit ends a UTF-16 line.
+    let unit = if encoding == UTF_16LE {
+        [b'\n', 0]
+    } else {
+        [0, b'\n']
+    };
+    let mut i = from + (from - start) % 2;
+    while i + 2 <= data.len() {
+        if data[i..i + 2] == unit {
+            return i + 2;
+        }
+        i += 2;
+    }
+    data.len()
+}
+
+/// What a line of the text is, for splitting.
+enum Line {
+    Heading(String),
+    Text,
+    Blank,
+}
+
+/// Find chapter titles and byte ranges in the concatenated `blocks`.
+fn split(blocks: &[String], rules: &[Regex]) -> Vec<(Option<String>, Range<usize>)> {
+    let lines: Vec<Vec<(usize, Line)>> = blocks
+        .par_iter()
+        .map(|block| {
+            let mut offset = 0;
+            block
+                .split_inclusive('\n')
+                .map(|line| {
+                    let kind = match txt::heading(line, rules) {
+                        Some(title) => Line::Heading(title.to_string()),
+                        None if line.trim().is_empty() => Line::Blank,
+                        None => Line::Text,
+                    };
+                    let start = offset;
+                    offset += line.len();
+                    (start, kind)
+                })
+                .collect()
+        })
+        .collect();
+
+    let len: usize = blocks.iter().map(String::len).sum();
+    let mut starts: Vec<(Option<String>, usize)> = Vec::new();
+    let mut preface = false;
+    let mut base = 0;
+    for (block, lines) in blocks.iter().zip(lines) {
+        for (offset, line) in lines {
+            match line {
+                Line::Heading(title) => {
+                    if starts.is_empty() && preface {
+                        starts.push((None, 0));
+                    }
+                    starts.push((Some(title), base + offset));
+                }
+                Line::Text if starts.is_empty() => preface = true,
+                _ => {}
+            }
+        }
+        base += block.len();
+    }
+    if starts.is_empty() {
+        starts = fallback_parts(&blocks.concat());
+    }
+
+    let ends: Vec<usize> = starts.iter().skip(1).map(|(_, start)| *start).collect();
+    starts
+        .into_iter()
+        .zip(ends.into_iter().chain([len]))
+        .map(|((title, start), end)| (title, start..end))
+        .collect()
+}
+
+/// Line starts roughly every `FALLBACK_CHAPTER_BYTES`, for text without
+/// headings.
+fn fallback_parts(text: &str) -> Vec<(Option<String>, usize)> {
+    let mut parts = vec![(None, 0)];
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        if (offset - parts[parts.len() - 1].1) as u64 >= txt::FALLBACK_CHAPTER_BYTES {
+            parts.push((None, offset));
+        }
+        offset += line.len();
+    }
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::purify::PurifyRuleKind;
+    use crate::test_dirs::temp_dir;
+    use std::fs;
+
+    type Step = (ImportEventKind, u32, u32, Option<String>);
+
+    fn run(data: &[u8], options: &ImportOptions) -> (ProcessedBook, Vec<Step>) {
+        let events = Mutex::new(Vec::new());
+        let token = CancelToken::default();
+        let book = process(
+            data,
+            Some("书".to_string()),
+            options,
+            &token,
+            &|kind, done, total, message| {
+                events.lock().unwrap().push((kind, done, total, message));
+                Ok(())
+            },
+        )
+        .unwrap();
+        (book, events.into_inner().unwrap())
+    }
+
+    #[test]
+    fn test_matches_sequential_import() {
+        let mut text = String::from("书名\n作者\n\n");
+        for i in 1..=300 {
+            text.push_str(&format!("第{i}章 标题\n"));
+            text.push_str(&"正文内容，很长的一段话。\n".repeat(200));
+        }
+        let (book, events) = run(text.as_bytes(), &default_import_options());
+        let sequential =
+            txt::parse(text.as_bytes(), None, &txt::compile_rules(None).unwrap()).unwrap();
+
+        assert_eq!(book.encoding, "UTF-8");
+        assert_eq!(book.book.chapters.len(), 301);
+        for (a, b) in book.book.chapters.iter().zip(&sequential.chapters) {
+            assert_eq!((&a.title, &a.html), (&b.title, &b.html));
+        }
+        assert_eq!(book.book.toc.len(), 300);
+        assert_eq!(book.stats.chapters.len(), 301);
+        assert_eq!(
+            book.stats.total.cjk_chars,
+            stats::count(&text, 400).cjk_chars
+        );
+
+        let decoding = events
+            .iter()
+            .filter(|e| e.0 == ImportEventKind::Decoding)
+            .count();
+        assert!(decoding > 1);
+        assert!(events.iter().all(|e| e.0 != ImportEventKind::Warning));
+        assert_eq!(
+            events.last().unwrap(),
+            &(ImportEventKind::Processing, 301, 301, None)
+        );
+    }
+
+    #[test]
+    fn test_utf16_blocks_split_on_code_units() {
+        let mut text = String::new();
+        for i in 1..=40 {
+            text.push_str(&format!("Chapter {i}\n"));
+            text.push_str(&"\u{0A0A}\u{4E0A} line of text\n".repeat(1000));
+        }
+        let mut data = vec![0xFF, 0xFE];
+        for unit in text.encode_utf16() {
+            data.extend_from_slice(&unit.to_le_bytes());
+        }
+        let (book, _) = run(&data, &default_import_options());
+        assert_eq!(book.encoding, "UTF-16LE");
+        assert_eq!(book.book.chapters.len(), 40);
+        assert!(book
+            .book
+            .chapters
+            .iter()
+            .all(|c| !c.html.contains('\u{FFFD}')));
+    }
+
+    #[test]
+    fn test_reports_every_file() {
+        let dir = temp_dir("import");
+        let file = |name: &str, data: &[u8]| {
+            let path = dir.join(name);
+            fs::write(&path, data).unwrap();
+            path.to_string_lossy().into_owned()
+        };
+        let mut broken = "没有标题的一段文字，".as_bytes().to_vec();
+        broken.extend_from_slice(&[0xFF, b'\n']);
+        let paths = vec![
+            file("a.txt", "第一章 开始\n正文。\n".as_bytes()),
+            file(
+                "b.fb2",
+                "<FictionBook><body><section><title><p>第一章</p></title><p>正文。</p>\
+                 </section></body></FictionBook>"
+                    .as_bytes(),
+            ),
+            file("c.txt", &broken),
+            dir.join("missing.txt").to_string_lossy().into_owned(),
+            file("e.cbz", b"PK"),
+        ];
+        let mut options = default_import_options();
+        options.override_encoding = Some("UTF-8".to_string());
+        let events = Mutex::new(Vec::new());
+        import(&paths, &options, &CancelToken::default(), &|event| {
+            events.lock().unwrap().push(event);
+            Ok(())
+        })
+        .unwrap();
+        let events = events.into_inner().unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        let kinds: Vec<(ImportEventKind, u32)> = events
+            .iter()
+            .filter(|e| {
+                !matches!(
+                    e.kind,
+                    ImportEventKind::Decoding
+                        | ImportEventKind::Splitting
+                        | ImportEventKind::Processing
+                )
+            })
+            .map(|e| (e.kind, e.file_index))
+            .collect();
+        use ImportEventKind::*;
+        assert_eq!(
+            kinds,
+            [
+                (FileDiscovered, 0),
+                (FileDiscovered, 1),
+                (FileDiscovered, 2),
+                (FileDiscovered, 3),
+                (FileDiscovered, 4),
+                (FileImported, 0),
+                (FileImported, 1),
+                (Warning, 2),
+                (Warning, 2),
+                (FileImported, 2),
+                (FileFailed, 3),
+                (FileSkipped, 4),
+                (Finished, 0),
+            ]
+        );
+        let first = events.iter().find(|e| e.kind == FileImported).unwrap();
+        assert_eq!(first.book.as_ref().unwrap().book.chapters.len(), 1);
+        // Other formats go through their own importer.
+        let fb2 = events
+            .iter()
+            .find(|e| e.kind == FileImported && e.file_index == 1)
+            .and_then(|e| e.book.as_ref())
+            .unwrap();
+        assert_eq!(fb2.book.chapters[0].title.as_deref(), Some("第一章"));
+        assert_eq!(fb2.book.metadata.title.as_deref(), Some("b"));
+        assert_eq!(fb2.stats.total.cjk_chars, 5);
+        assert!(events
+            .iter()
+            .any(|e| e.kind == Processing && e.file_index == 1));
+        let report = events.last().unwrap().report.clone().unwrap();
+        assert_eq!(report.imported, 3);
+        assert_eq!(
+            report.skipped[0].message,
+            "Not a book; open it with list_comic_pages"
+        );
+        assert_eq!(report.failed[0].path, paths[3]);
+        let warnings: Vec<&str> = report.warnings.iter().map(|w| w.message.as_str()).collect();
+        assert_eq!(
+            warnings,
+            [
+                "1 of 1 blocks had bytes that are not UTF-8; they were replaced",
+                "No chapter headings matched; the text was cut into parts of about 100 KB",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_purifies_chapters() {
+        let text = "第一章 开始\n正文\u{200B}一。\n请记住本站网址\n第二章 结束\n正文二。\n";
+        let mut options = default_import_options();
+        options.purify_rules = purify::default_purify_rules();
+        options.purify_rules.push(PurifyRule {
+            name: "替换".to_string(),
+            kind: PurifyRuleKind::Literal,
+            pattern: "正文二".to_string(),
+            replacement: "内容二".to_string(),
+            enabled: true,
+        });
+        let (book, _) = run(text.as_bytes(), &options);
+        let chapters = &book.book.chapters;
+        assert_eq!(chapters[0].html, "<h2>第一章 开始</h2><p>正文一。</p>");
+        assert_eq!(chapters[1].html, "<h2>第二章 结束</h2><p>内容二。</p>");
+    }
+}
@@ -0,0 +1,378 @@
+//! Keywords and suggested genre tags for books without metadata.
+//!
+//! Text is segmented and part-of-speech tagged by jieba, and only nouns
+//! are kept: names, places, organizations and other nouns. A term's
+//! weight is its frequency in the book times its inverse frequency in
+//! jieba's dictionary, the dictionary standing in for Chinese text in
+//! general, so 灵气 in a cultivation novel outweighs 时候 next to it.
+//! Words the dictionary lacks, mostly names found by the HMM, count as
+//! rare. Tags come from a lexicon of genre terms, counted whatever their
+//! part of speech: a genre is suggested when its terms are dense enough in
+//! the text.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+
+use crate::api::db;
+use crate::api::error::ApiError;
+use crate::api::segment;
+use crate::api::task;
+use crate::api::webserver;
+
+/// Chapters read from a book at most, spread evenly through it.
+const MAX_SAMPLED_CHAPTERS: u32 = 60;
+
+/// Characters read from each chapter at most.
+const MAX_CHAPTER_CHARS: usize = 20_000;
+
+/// Total frequency of jieba's bundled dictionary.
+const DICTIONARY_TOTAL: f64 = 60_101_964.0;
+
+/// Genre term occurrences per 10,000 words a tag needs.
+const MIN_TAG_DENSITY: f32 = 8.0;
+
+/// Tags suggested at most.
+const MAX_TAGS: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeywordKind {
+    Person,
+    Place,
+    Organization,
+    Term,
+}
+
+#[derive(Debug, Clone)]
+pub struct Keyword {
+    pub term: String,
+    pub kind: KeywordKind,
+    /// Occurrences in the text read.
+    pub count: u32,
+    /// Frequency in the text times rarity in general; only comparable
+    /// within one result.
+    pub score: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct TagSuggestion {
+    pub tag: String,
+    /// Genre term occurrences per 10,000 words.
+    pub density: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct BookKeywords {
+    /// Highest score first.
+    pub keywords: Vec<Keyword>,
+    /// Densest first.
+    pub tags: Vec<TagSuggestion>,
+}
+
+/// Extract keywords and suggest tags from a library book's cached
+/// chapters. Long books are sampled.
+///
+/// # Arguments
+/// * `book_id` - Library ID of the book
+/// * `limit` - Keywords to return at most
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+#[flutter_rust_bridge::frb]
+pub async fn extract_book_keywords(
+    book_id: String,
+    limit: u32,
+    cancel_token: Option<u32>,
+) -> Result<BookKeywords, ApiError> {
+    task::run_blocking(task::cancel_token(cancel_token)?, move |token| {
+        let book = db::get_library_book(book_id.clone())?
+            .ok_or_else(|| anyhow!("Book {book_id} is not in the library"))?;
+        let count = book.chapter_count;
+        let step = count.div_ceil(MAX_SAMPLED_CHAPTERS).max(1);
+        let mut counts = Counts::default();
+        for index in (0..count).step_by(step as usize) {
+            token.check()?;
+            if let Some(text) = webserver::chapter_text(&book_id, index) {
+                counts.add(&text);
+            }
+        }
+        if counts.words == 0 {
+            return Err(anyhow!("Book {book_id} has no cached chapters"));
+        }
+        Ok(counts.keywords(limit as usize))
+    })
+    .await
+}
+
+/// Extract keywords and suggest tags from text.
+///
+/// # Arguments
+/// * `texts` - Chapters or other plain text
+/// * `limit` - Keywords to return at most
+#[flutter_rust_bridge::frb]
+pub fn extract_keywords(texts: Vec<String>, limit: u32) -> BookKeywords {
+    let mut counts = Counts::default();
+    for text in &texts {
+        counts.add(text);
+    }
+    counts.keywords(limit as usize)
+}
+
+/// Word counts over the text read so far.
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+struct Counts {
+    /// Words of two or more characters, with their kind if they are nouns.
+    terms: HashMap<String, (Option<KeywordKind>, u32)>,
+    words: u32,
+}
+
+impl Counts {
+    fn add(&mut self, text: &str) {
+        let end = text
+            .char_indices()
+            .nth(MAX_CHAPTER_CHARS)
+            .map_or(text.len(), |(i, _)| i);
+        for tag in segment::jieba().tag(&text[..end], true) {
+            if !tag.word.chars().any(char::is_alphanumeric) {
+                continue;
+            }
+            self.words += 1;
+            if tag.word.chars().count() >= 2 {
+                let entry = self.terms.entry(tag.word.to_string());
+                entry.or_insert((kind(tag.tag), 0)).1 += 1;
+            }
+        }
+    }
+
+    fn keywords(&self, limit: usize) -> BookKeywords {
+        let mut keywords: Vec<Keyword> = self
+            .terms
+            .iter()
+            .filter_map(|(term, &(kind, count))| Some((term, kind?, count)))
+            .filter(|&(_, _, count)| count >= 2)
+            .map(|(term, kind, count)| {
+                let general = segment::jieba().suggest_freq(term) as f64;
+                let rarity = (DICTIONARY_TOTAL / general.max(1.0)).ln();
+                Keyword {
+                    term: term.clone(),
+                    kind,
+                    count,
+                    score: (count as f64 / self.words as f64 * rarity) as f32,
+                }
+            })
+            .collect();
+        keywords.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.term.cmp(&b.term)));
+        keywords.truncate(limit);
+
+        let mut tags: Vec<TagSuggestion> = GENRES
+            .iter()
+            .map(|(tag, terms)| {
+                let hits: u32 = terms
+                    .iter()
+                    .filter_map(|term| self.terms.get(*term))
+                    .map(|&(_, count)| count)
+                    .sum();
+                TagSuggestion {
+                    tag: tag.to_string(),
+                    density: hits as f32 * 10_000.0 / self.words.max(1) as f32,
+                }
+            })
+            .filter(|tag| tag.density >= MIN_TAG_DENSITY)
+            .collect();
+        tags.sort_by(|a, b| b.density.total_cmp(&a.density));
+        tags.truncate(MAX_TAGS);
+        BookKeywords { keywords, tags }
+    }
+}
+
+/// The keyword kind of a jieba part-of-speech tag, if it is a noun.
+fn kind(tag: &str) -> Option<KeywordKind> {
+    match tag {
+        "nr" | "nrfg" | "nrt" => Some(KeywordKind::Person),
+        "ns" => Some(KeywordKind::Place),
+        "nt" => Some(KeywordKind::Organization),
+        "n" | "nz" | "vn" | "ng" => Some(KeywordKind::Term),
+        _ => None,
+    }
+}
+
+/// Genre tags and terms that are common in that genre and rare outside it.
+const GENRES: [(&str, &[&str]); 12] = [
+    (
+        "修仙",
+        &[
+            "修士", "修为", "灵气", "灵力", "筑基", "金丹", "元婴", "化神", "渡劫", "飞升", "丹药",
+            "法宝", "宗门", "灵石", "仙人", "道友",
+        ],
+    ),
+    (
+        "玄幻",
+        &[
+            "斗气", "魔兽", "武魂", "魂力", "血脉", "神兽", "魔核", "斗者", "斗王", "天才", "秘境",
+            "神器",
+        ],
+    ),
+    (
+        "武侠",
+        &[
+            "江湖", "内力", "武功", "掌门", "少侠", "剑法", "刀法", "轻功", "门派", "大侠", "武林",
+            "镖局",
+        ],
+    ),
+    (
+        "悬疑",
+        &[
+            "凶手",
+            "案件",
+            "尸体",
+            "警方",
+            "线索",
+            "死者",
+            "嫌疑人",
+            "案子",
+            "现场",
+            "侦探",
+            "警察",
+            "法医",
+        ],
+    ),
+    (
+        "末世",
+        &[
+            "丧尸",
+            "末日",
+            "末世",
+            "异能",
+            "幸存者",
+            "变异",
+            "感染",
+            "病毒",
+            "物资",
+            "基地",
+            "晶核",
+        ],
+    ),
+    (
+        "科幻",
+        &[
+            "飞船",
+            "星球",
+            "舰队",
+            "机甲",
+            "外星人",
+            "星际",
+            "宇宙",
+            "太空",
+            "文明",
+            "能源",
+            "光年",
+        ],
+    ),
+    (
+        "都市",
+        &[
+            "公司",
+            "总裁",
+            "老板",
+            "董事长",
+            "集团",
+            "合同",
+            "股份",
+            "酒吧",
+            "别墅",
+            "手机",
+        ],
+    ),
+    (
+        "历史",
+        &[
+            "皇上", "朝廷", "陛下", "大人", "将军", "丞相", "太子", "皇帝", "朝堂", "圣旨", "官员",
+        ],
+    ),
+    (
+        "游戏",
+        &[
+            "玩家", "副本", "技能", "等级", "装备", "公会", "任务", "系统", "经验", "怪物",
+        ],
+    ),
+    (
+        "恐怖",
+        &[
+            "鬼魂", "厉鬼", "诡异", "阴气", "冤魂", "僵尸", "棺材", "恶鬼", "尸体", "坟墓",
+        ],
+    ),
+    (
+        "言情",
+        &[
+            "心动",
+            "婚礼",
+            "恋爱",
+            "男朋友",
+            "女朋友",
+            "表白",
+            "约会",
+            "婚姻",
+            "未婚夫",
+            "未婚妻",
+        ],
+    ),
+    (
+        "校园",
+        &[
+            "同学",
+            "老师",
+            "班级",
+            "考试",
+            "校园",
+            "学校",
+            "教室",
+            "班主任",
+            "高考",
+            "宿舍",
+        ],
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chapter(seed: usize) -> String {
+        let lines = [
+            "韩立盘膝坐在洞府之中，体内灵气缓缓流转，修为又精进了几分。",
+            "他取出一枚丹药服下，这是宗门长老赐下的筑基丹。",
+            "洞府外传来道友的声音，说是秘境即将开启，各派修士都在赶来。",
+            "韩立收起法宝，想到金丹大道还很遥远，心中不免有些感慨。",
+            "天色渐晚，山间的风吹过竹林，发出沙沙的声响。",
+        ];
+        (0..20)
+            .map(|i| lines[(i * 7 + seed) % lines.len()])
+            .collect()
+    }
+
+    #[test]
+    fn test_keywords_rank_rare_recurring_terms() {
+        let texts: Vec<String> = (0..3).map(chapter).collect();
+        let result = extract_keywords(texts, 5);
+        let terms: Vec<_> = result.keywords.iter().map(|k| k.term.as_str()).collect();
+        assert!(terms.contains(&"韩立"), "{terms:?}");
+        assert!(!terms.contains(&"天色"), "{terms:?}");
+        let han_li = result.keywords.iter().find(|k| k.term == "韩立").unwrap();
+        assert_eq!(han_li.kind, KeywordKind::Person);
+        assert!(result
+            .keywords
+            .windows(2)
+            .all(|pair| pair[0].score >= pair[1].score));
+    }
+
+    #[test]
+    fn test_suggests_dense_genres() {
+        let result = extract_keywords(vec![chapter(0)], 10);
+        let tags: Vec<_> = result.tags.iter().map(|t| t.tag.as_str()).collect();
+        assert_eq!(tags.first(), Some(&"修仙"), "{tags:?}");
+        assert!(!tags.contains(&"悬疑"));
+
+        let plain = extract_keywords(vec!["天色渐晚，山间的风吹过竹林。".repeat(10)], 10);
+        assert!(plain.tags.is_empty());
+        assert!(extract_keywords(Vec::new(), 10).keywords.is_empty());
+    }
+}
@@ -0,0 +1,371 @@
+//! Language detection for chapters and whole books.
+//!
+//! Most languages in a library can be told apart by script alone: Hangul
+//! is Korean, kana among Han characters is Japanese and Han without kana
+//! is Chinese. Cyrillic and Arabic text is split by letters only some of
+//! their languages use, and Latin text by how often each language's
+//! commonest words occur. Each language's confidence is its share of the
+//! letters in the text, with a CJK character counting as three letters
+//! since it carries about as much text as a short word. Latin letters in
+//! words none of the known languages use, such as names in a Chinese
+//! novel, go to no language at all.
+
+use std::collections::HashMap;
+
+/// Characters read from each text; enough to tell languages apart.
+const MAX_SAMPLE_CHARS: usize = 20_000;
+
+/// Weight of a CJK character or Hangul syllable, in letters.
+const CJK_WEIGHT: f32 = 3.0;
+
+/// Share of kana among Han and kana above which text is Japanese.
+const MIN_KANA_SHARE: f32 = 0.05;
+
+/// Languages with a smaller share of the text are not reported.
+const MIN_CONFIDENCE: f32 = 0.05;
+
+/// Confidence a book's main language needs to be recorded at import.
+const MIN_BOOK_CONFIDENCE: f32 = 0.5;
+
+#[derive(Debug, Clone)]
+pub struct LanguageGuess {
+    /// ISO 639-1 code such as `zh`, `ja` or `en`.
+    pub code: String,
+    /// Share of the text in this language, from 0 to 1.
+    pub confidence: f32,
+}
+
+/// The languages of a text, most likely first.
+///
+/// # Arguments
+/// * `text` - Plain text, such as a chapter; only the start of long texts
+///   is read
+#[flutter_rust_bridge::frb]
+pub fn detect(text: String) -> Vec<LanguageGuess> {
+    let mut sample = Sample::default();
+    sample.add(&text);
+    sample.guesses()
+}
+
+/// The languages of a book, most likely first, weighing each chapter by
+/// its length.
+///
+/// # Arguments
+/// * `chapters` - Plain text of each chapter
+#[flutter_rust_bridge::frb]
+pub fn detect_book_language(chapters: Vec<String>) -> Vec<LanguageGuess> {
+    let mut sample = Sample::default();
+    for chapter in &chapters {
+        sample.add(chapter);
+    }
+    sample.guesses()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Script {
+    Han,
+    Kana,
+    Hangul,
+    Latin,
+    Cyrillic,
+    Greek,
+    Arabic,
+    Hebrew,
+    Thai,
+    Devanagari,
+}
+
+/// Counts gathered from one or more texts.
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug, Default)]
+pub(crate) struct Sample {
+    letters: HashMap<Script, u32>,
+    /// Common words seen, per Latin-script language.
+    words: HashMap<&'static str, u32>,
+    /// Letters that only some Cyrillic or Arabic-script languages use.
+    markers: HashMap<&'static str, u32>,
+}
+
+impl Sample {
+    pub(crate) fn add(&mut self, text: &str) {
+        let mut word = String::new();
+        for c in text.chars().take(MAX_SAMPLE_CHARS) {
+            let Some(script) = script(c) else {
+                self.end_word(&mut word);
+                continue;
+            };
+            *self.letters.entry(script).or_default() += 1;
+            if let Some(language) = marker(c) {
+                *self.markers.entry(language).or_default() += 1;
+            }
+            if script == Script::Latin {
+                word.extend(c.to_lowercase());
+            } else {
+                self.end_word(&mut word);
+            }
+        }
+        self.end_word(&mut word);
+    }
+
+    pub(crate) fn merge(&mut self, other: Sample) {
+        for (script, count) in other.letters {
+            *self.letters.entry(script).or_default() += count;
+        }
+        for (language, count) in other.words {
+            *self.words.entry(language).or_default() += count;
+        }
+        for (language, count) in other.markers {
+            *self.markers.entry(language).or_default() += count;
+        }
+    }
+
+    fn end_word(&mut self, word: &mut String) {
+        if word.is_empty() {
+            return;
+        }
+        for (language, words) in COMMON_WORDS {
+            if words.contains(&word.as_str()) {
+                *self.words.entry(language).or_default() += 1;
+            }
+        }
+        word.clear();
+    }
+
+    fn letters(&self, script: Script) -> f32 {
+        let count = self.letters.get(&script).copied().unwrap_or(0) as f32;
+        match script {
+            Script::Han | Script::Kana | Script::Hangul => count * CJK_WEIGHT,
+            _ => count,
+        }
+    }
+
+    fn marker(&self, language: &str) -> u32 {
+        self.markers.get(language).copied().unwrap_or(0)
+    }
+
+    pub(crate) fn guesses(&self) -> Vec<LanguageGuess> {
+        let mut shares: HashMap<&str, f32> = HashMap::new();
+        let mut add = |code, letters: f32| {
+            if letters > 0.0 {
+                *shares.entry(code).or_default() += letters;
+            }
+        };
+
+        let han = self.letters(Script::Han);
+        let kana = self.letters(Script::Kana);
+        if kana > (han + kana) * MIN_KANA_SHARE {
+            add("ja", han + kana);
+        } else {
+            add("zh", han + kana);
+        }
+        add("ko", self.letters(Script::Hangul));
+        add("el", self.letters(Script::Greek));
+        add("he", self.letters(Script::Hebrew));
+        add("th", self.letters(Script::Thai));
+        add("hi", self.letters(Script::Devanagari));
+        let pick = |a: &'static str, b: &'static str| {
+            if self.marker(b) > self.marker(a) {
+                b
+            } else {
+                a
+            }
+        };
+        add(pick("ru", "uk"), self.letters(Script::Cyrillic));
+        add(pick("ar", "fa"), self.letters(Script::Arabic));
+
+        // Squaring the counts lets a language's own words outweigh the ones
+        // it shares with its neighbours.
+        let latin = self.letters(Script::Latin);
+        let squares: f32 = self.words.values().map(|&n| (n as f32).powi(2)).sum();
+        for (&language, &n) in &self.words {
+            add(language, latin * (n as f32).powi(2) / squares);
+        }
+
+        let total: f32 = self
+            .letters
+            .keys()
+            .map(|&script| self.letters(script))
+            .sum();
+        let mut guesses: Vec<LanguageGuess> = shares
+            .into_iter()
+            .map(|(code, letters)| LanguageGuess {
+                code: code.to_string(),
+                confidence: letters / total,
+            })
+            .filter(|guess| guess.confidence >= MIN_CONFIDENCE)
+            .collect();
+        guesses.sort_by(|a, b| {
+            b.confidence
+                .total_cmp(&a.confidence)
+                .then_with(|| a.code.cmp(&b.code))
+        });
+        guesses
+    }
+}
+
+/// The main language of a book, if it is clear enough to record.
+pub(crate) fn book_language(sample: &Sample) -> Option<String> {
+    sample
+        .guesses()
+        .into_iter()
+        .next()
+        .filter(|guess| guess.confidence >= MIN_BOOK_CONFIDENCE)
+        .map(|guess| guess.code)
+}
+
+fn script(c: char) -> Option<Script> {
+    let script = match c {
+        'a'..='z' | 'A'..='Z' | '\u{00C0}'..='\u{024F}' => Script::Latin,
+        '\u{0370}'..='\u{03FF}' => Script::Greek,
+        '\u{0400}'..='\u{04FF}' => Script::Cyrillic,
+        '\u{0590}'..='\u{05FF}' => Script::Hebrew,
+        '\u{0600}'..='\u{06FF}' => Script::Arabic,
+        '\u{0900}'..='\u{097F}' => Script::Devanagari,
+        '\u{0E00}'..='\u{0E7F}' => Script::Thai,
+        '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}' | '\u{AC00}'..='\u{D7AF}' => {
+            Script::Hangul
+        }
+        '\u{3041}'..='\u{309F}' | '\u{30A0}'..='\u{30FA}' | '\u{31F0}'..='\u{31FF}' => Script::Kana,
+        '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}' | '\u{20000}'..='\u{3FFFF}' => {
+            Script::Han
+        }
+        _ => return None,
+    };
+    c.is_alphabetic().then_some(script)
+}
+
+/// The language a letter points to among those sharing its script.
+fn marker(c: char) -> Option<&'static str> {
+    match c {
+        'і' | 'ї' | 'є' | 'ґ' | 'І' | 'Ї' | 'Є' | 'Ґ' => Some("uk"),
+        'ы' | 'э' | 'ъ' | 'ё' | 'Ы' | 'Э' | 'Ъ' | 'Ё' => Some("ru"),
+        'پ' | 'چ' | 'ژ' | 'گ' | 'ی' | 'ک' => Some("fa"),
+        'ة' | 'ى' | 'ي' | 'ك' => Some("ar"),
+        _ => None,
+    }
+}
+
+/// The commonest words of each Latin-script language.
+const COMMON_WORDS: [(&str, &[&str]); 7] = [
+    (
+        "en",
+        &[
+            "the", "and", "of", "to", "was", "he", "she", "it", "that", "you", "his", "her",
+            "with", "had", "for", "not", "is", "at",
+        ],
+    ),
+    (
+        "fr",
+        &[
+            "le", "la", "les", "et", "des", "une", "est", "il", "elle", "que", "pas", "dans",
+            "pour", "qui", "du", "au", "je", "vous",
+        ],
+    ),
+    (
+        "de",
+        &[
+            "der", "die", "das", "und", "ist", "nicht", "ein", "eine", "ich", "sie", "er", "mit",
+            "den", "dem", "zu", "auf", "es", "war",
+        ],
+    ),
+    (
+        "es",
+        &[
+            "el", "la", "los", "las", "y", "que", "de", "en", "un", "una", "es", "por", "con",
+            "no", "se", "del", "su", "pero",
+        ],
+    ),
+    (
+        "it",
+        &[
+            "il", "di", "che", "e", "un", "una", "non", "per", "con", "del", "della", "sono", "è",
+            "gli", "le", "ma", "si", "mi",
+        ],
+    ),
+    (
+        "pt",
+        &[
+            "o", "a", "os", "as", "que", "de", "em", "um", "uma", "não", "com", "por", "do", "da",
+            "é", "se", "mas", "ele",
+        ],
+    ),
+    (
+        "nl",
+        &[
+            "de", "het", "een", "en", "van", "ik", "te", "dat", "niet", "is", "zijn", "op", "aan",
+            "met", "hij", "ze", "maar", "wat",
+        ],
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn top(text: &str) -> (String, f32) {
+        let guess = detect(text.to_string()).remove(0);
+        (guess.code, (guess.confidence * 100.0).round() / 100.0)
+    }
+
+    #[test]
+    fn test_detects_languages() {
+        for (text, code) in [
+            ("天色渐晚，城门外行人稀少。他提着灯笼，慢慢走回家去。", "zh"),
+            ("夕暮れの町を、彼はゆっくりと歩いて帰った。", "ja"),
+            ("해가 지자 그는 천천히 집으로 걸어갔다.", "ko"),
+            (
+                "It was late, and the streets were empty when he walked home.",
+                "en",
+            ),
+            (
+                "Il était tard et les rues étaient vides quand il est rentré.",
+                "fr",
+            ),
+            (
+                "Es war spät, und die Straßen waren leer, als er nach Hause ging.",
+                "de",
+            ),
+            ("Было поздно, и улицы были пусты, когда он шёл домой.", "ru"),
+            (
+                "Було пізно, і вулиці були порожні, коли він ішов додому.",
+                "uk",
+            ),
+        ] {
+            assert_eq!(top(text).0, code, "{text}");
+        }
+        assert!(detect("12345，。！".to_string()).is_empty());
+    }
+
+    #[test]
+    fn test_confidence_is_share_of_text() {
+        // Latin names in a Chinese novel belong to no language.
+        assert_eq!(
+            top("哈利·波特（Harry Potter）站在门口。"),
+            ("zh".to_string(), 0.69)
+        );
+        let guesses = detect("他说：“It is the end of the road.” 然后转身离开了。".to_string());
+        let codes: Vec<_> = guesses.iter().map(|g| g.code.as_str()).collect();
+        assert_eq!(codes, ["zh", "en"]);
+    }
+
+    #[test]
+    fn test_book_language_needs_a_clear_majority() {
+        let mut sample = Sample::default();
+        sample.add("天色渐晚，城门外行人稀少。");
+        let mut other = Sample::default();
+        other.add("It was late, and the streets were empty.");
+        sample.merge(other);
+        assert_eq!(book_language(&sample), Some("zh".to_string()));
+
+        let aggregate = detect_book_language(vec![
+            "It was late, and the streets were empty when he walked home.".to_string(),
+            "The rain had not stopped for three days, and the river was high.".to_string(),
+        ]);
+        assert_eq!(aggregate[0].code, "en");
+        assert!(aggregate[0].confidence > 0.9);
+
+        let mut mixed = Sample::default();
+        mixed.add("天色渐晚。It was late. Было поздно.");
+        assert_eq!(book_language(&mixed), None);
+    }
+}
@@ -0,0 +1,447 @@
+//! Persistent cache of chapter page layouts.
+//!
+//! Once enabled, `paginate_text` stores the pages it lays out under an
+//! app-provided directory, so reopening a chapter with the same settings
+//! returns its line and page breaks without shaping a single paragraph.
+//! An entry is named by the BLAKE3 hash of everything the layout depends
+//! on: the chapter text, the font file's path, size and modification time,
+//! every `PageLayout` field, the kinsoku rules and the layout format. Any
+//! change to those gives a different name, so stale layouts are never read;
+//! they just age out. Past the size cap, entries are evicted least recently
+//! used first, with recency kept in the file's modification time as in the
+//! font cache.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Result};
+
+use crate::api::error::ApiError;
+use crate::api::font_converter;
+use crate::api::line_break::KinsokuRules;
+use crate::api::pagination::{PageLayout, TextLine, TextPage};
+
+const ENTRY_EXTENSION: &str = "layout";
+
+/// Starts every entry. Bump it when `paginate_text` would lay out the same
+/// input differently, so old entries are no longer found.
+const MAGIC: &[u8] = b"NVLC1";
+
+const PARAGRAPH_START: u8 = 1;
+const HYPHENATED: u8 = 2;
+
+static LAYOUT_CACHE: Mutex<Option<LayoutCache>> = Mutex::new(None);
+
+/// Current size of the layout cache.
+#[derive(Debug, Clone)]
+pub struct LayoutCacheStats {
+    pub entry_count: u32,
+    pub total_bytes: u32,
+    pub max_bytes: u32,
+}
+
+/// Enable the persistent layout cache. Calling this again switches to the
+/// new directory or size cap.
+///
+/// # Arguments
+/// * `cache_dir` - Directory owned by the cache; created if missing
+/// * `max_bytes` - Total size of cached layouts to keep
+#[flutter_rust_bridge::frb]
+pub fn init_layout_cache(cache_dir: String, max_bytes: u32) -> Result<(), ApiError> {
+    let cache = LayoutCache::new(PathBuf::from(cache_dir), max_bytes)?;
+    cache.evict()?;
+    *lock() = Some(cache);
+    Ok(())
+}
+
+/// Report the number and total size of cached layouts.
+#[flutter_rust_bridge::frb]
+pub fn layout_cache_stats() -> Result<LayoutCacheStats, ApiError> {
+    Ok(initialized()?.stats()?)
+}
+
+/// Delete every cached layout. The cache stays enabled.
+#[flutter_rust_bridge::frb]
+pub fn clear_layout_cache() -> Result<(), ApiError> {
+    Ok(initialized()?.clear()?)
+}
+
+/// Return the cached layout of `text`, or run `paginate` and cache its
+/// result. Without an initialized cache this is just `paginate()`.
+pub(crate) fn get_or_paginate(
+    text: &str,
+    font_path: &str,
+    layout: &PageLayout,
+    kinsoku: Option<&KinsokuRules>,
+    paginate: impl FnOnce() -> Result<Vec<TextPage>>,
+) -> Result<Vec<TextPage>> {
+    let Some(cache) = current() else {
+        return paginate();
+    };
+    // A font that cannot be read fails in `paginate` with a better error.
+    let Some(key) = layout_key(text, font_path, layout, kinsoku) else {
+        return paginate();
+    };
+    if let Some(pages) = cache.get(&key) {
+        return Ok(pages);
+    }
+
+    let pages = paginate()?;
+    // A full disk only costs a future re-layout.
+    let _ = cache.put(&key, &pages);
+    Ok(pages)
+}
+
+fn lock() -> MutexGuard<'static, Option<LayoutCache>> {
+    LAYOUT_CACHE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn current() -> Option<LayoutCache> {
+    lock().clone()
+}
+
+fn initialized() -> Result<LayoutCache> {
+    current().ok_or_else(|| anyhow!("Layout cache is not initialized"))
+}
+
+/// Hash of every input of a layout, or `None` if the font file is missing.
+fn layout_key(
+    text: &str,
+    font_path: &str,
+    layout: &PageLayout,
+    kinsoku: Option<&KinsokuRules>,
+) -> Option<String> {
+    let metadata = fs::metadata(font_path).ok()?;
+    let modified = metadata
+        .modified()
+        .ok()?
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_nanos());
+
+    let mut hasher = blake3::Hasher::new();
+    // Variable-length fields are prefixed with their length so that no two
+    // different inputs hash the same bytes.
+    let mut field = |bytes: &[u8]| {
+        hasher.update(&(bytes.len() as u64).to_le_bytes());
+        hasher.update(bytes);
+    };
+    field(MAGIC);
+    field(text.as_bytes());
+    field(font_path.as_bytes());
+    field(&metadata.len().to_le_bytes());
+    field(&modified.to_le_bytes());
+    for value in [
+        layout.width,
+        layout.height,
+        layout.margin_top,
+        layout.margin_right,
+        layout.margin_bottom,
+        layout.margin_left,
+        layout.font_size,
+        layout.line_height,
+        layout.paragraph_spacing,
+        layout.indent,
+    ] {
+        field(&value.to_le_bytes());
+    }
+    match kinsoku {
+        Some(rules) => {
+            field(b"kinsoku");
+            field(rules.no_line_start.as_bytes());
+            field(rules.no_line_end.as_bytes());
+        }
+        None => field(b"standard"),
+    }
+    Some(hasher.finalize().to_hex().to_string())
+}
+
+fn encode(pages: &[TextPage]) -> Vec<u8> {
+    let mut data = MAGIC.to_vec();
+    data.extend((pages.len() as u32).to_le_bytes());
+    for page in pages {
+        for value in [page.start, page.end, page.lines.len() as u32] {
+            data.extend(value.to_le_bytes());
+        }
+        for line in &page.lines {
+            data.extend(line.start.to_le_bytes());
+            data.extend(line.end.to_le_bytes());
+            data.extend(line.width.to_le_bytes());
+            data.push(
+                line.paragraph_start as u8 * PARAGRAPH_START + line.hyphenated as u8 * HYPHENATED,
+            );
+        }
+    }
+    data
+}
+
+/// Pages from `encode`, or `None` if the entry is truncated or malformed.
+fn decode(data: &[u8]) -> Option<Vec<TextPage>> {
+    let mut reader = EntryReader(data.strip_prefix(MAGIC)?);
+    let page_count = reader.u32()?;
+    let mut pages = Vec::new();
+    for _ in 0..page_count {
+        let (start, end, line_count) = (reader.u32()?, reader.u32()?, reader.u32()?);
+        // Bound the allocation by what the data can actually hold.
+        let mut lines = Vec::with_capacity((line_count as usize).min(reader.0.len() / 13));
+        for _ in 0..line_count {
+            let (line_start, line_end) = (reader.u32()?, reader.u32()?);
+            let width = f32::from_bits(reader.u32()?);
+            let flags = reader.u8()?;
+            lines.push(TextLine {
+                start: line_start,
+                end: line_end,
+                paragraph_start: flags & PARAGRAPH_START != 0,
+                width,
+                hyphenated: flags & HYPHENATED != 0,
+            });
+        }
+        pages.push(TextPage { start, end, lines });
+    }
+    reader.0.is_empty().then_some(pages)
+}
+
+struct EntryReader<'a>(&'a [u8]);
+
+impl EntryReader<'_> {
+    fn u8(&mut self) -> Option<u8> {
+        let (&byte, rest) = self.0.split_first()?;
+        self.0 = rest;
+        Some(byte)
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        let (bytes, rest) = self.0.split_first_chunk::<4>()?;
+        self.0 = rest;
+        Some(u32::from_le_bytes(*bytes))
+    }
+}
+
+#[derive(Clone)]
+struct LayoutCache {
+    dir: PathBuf,
+    max_bytes: u64,
+}
+
+struct Entry {
+    path: PathBuf,
+    len: u64,
+    last_used: SystemTime,
+}
+
+impl LayoutCache {
+    fn new(dir: PathBuf, max_bytes: u32) -> Result<Self> {
+        fs::create_dir_all(&dir).map_err(|e| anyhow!("Failed to create {}: {e}", dir.display()))?;
+        Ok(Self {
+            dir,
+            max_bytes: max_bytes as u64,
+        })
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.{ENTRY_EXTENSION}"))
+    }
+
+    fn get(&self, key: &str) -> Option<Vec<TextPage>> {
+        let path = self.entry_path(key);
+        let data = fs::read(&path).ok()?;
+        let Some(pages) = decode(&data) else {
+            let _ = fs::remove_file(&path);
+            return None;
+        };
+        touch(&path);
+        Some(pages)
+    }
+
+    fn put(&self, key: &str, pages: &[TextPage]) -> Result<()> {
+        let data = encode(pages);
+        if data.len() as u64 > self.max_bytes {
+            return Ok(());
+        }
+        font_converter::write_atomically(&self.entry_path(key), &data)?;
+        self.evict()
+    }
+
+    /// Remove least recently used entries until the cache fits its cap.
+    fn evict(&self) -> Result<()> {
+        let mut entries = self.entries()?;
+        let mut total: u64 = entries.iter().map(|entry| entry.len).sum();
+        entries.sort_by_key(|entry| entry.last_used);
+        for entry in entries {
+            if total <= self.max_bytes {
+                break;
+            }
+            if fs::remove_file(&entry.path).is_ok() {
+                total -= entry.len;
+            }
+        }
+        Ok(())
+    }
+
+    fn stats(&self) -> Result<LayoutCacheStats> {
+        let entries = self.entries()?;
+        Ok(LayoutCacheStats {
+            entry_count: entries.len() as u32,
+            total_bytes: entries.iter().map(|entry| entry.len).sum::<u64>() as u32,
+            max_bytes: self.max_bytes as u32,
+        })
+    }
+
+    fn clear(&self) -> Result<()> {
+        for entry in self.entries()? {
+            fs::remove_file(&entry.path)
+                .map_err(|e| anyhow!("Failed to remove {}: {e}", entry.path.display()))?;
+        }
+        Ok(())
+    }
+
+    fn entries(&self) -> Result<Vec<Entry>> {
+        let dir = fs::read_dir(&self.dir)
+            .map_err(|e| anyhow!("Failed to read {}: {e}", self.dir.display()))?;
+
+        let mut entries = Vec::new();
+        for item in dir.flatten() {
+            let path = item.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some(ENTRY_EXTENSION) {
+                continue;
+            }
+            let Ok(metadata) = item.metadata() else {
+                continue;
+            };
+            entries.push(Entry {
+                path,
+                len: metadata.len(),
+                last_used: metadata.modified().unwrap_or(UNIX_EPOCH),
+            });
+        }
+        Ok(entries)
+    }
+}
+
+/// Mark a cache entry as recently used.
+fn touch(path: &Path) {
+    if let Ok(file) = fs::File::options().write(true).open(path) {
+        let _ = file.set_modified(SystemTime::now());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_dirs::temp_dir;
+    use std::time::Duration;
+
+    fn layout() -> PageLayout {
+        PageLayout {
+            width: 360.0,
+            height: 640.0,
+            margin_top: 24.0,
+            margin_right: 16.0,
+            margin_bottom: 24.0,
+            margin_left: 16.0,
+            font_size: 18.0,
+            line_height: 1.6,
+            paragraph_spacing: 8.0,
+            indent: 2.0,
+        }
+    }
+
+    fn pages() -> Vec<TextPage> {
+        let line = |start, end, paragraph_start, hyphenated| TextLine {
+            start,
+            end,
+            paragraph_start,
+            width: 301.5,
+            hyphenated,
+        };
+        vec![
+            TextPage {
+                start: 0,
+                end: 40,
+                lines: vec![line(0, 20, true, true), line(20, 39, false, false)],
+            },
+            TextPage {
+                start: 40,
+                end: 52,
+                lines: vec![line(40, 52, true, false)],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_key_changes_with_every_input() {
+        let dir = temp_dir("key");
+        let font = dir.join("font.ttf");
+        fs::write(&font, b"font").unwrap();
+        let font = font.to_string_lossy().to_string();
+        let key = |text: &str, layout: &PageLayout, kinsoku: Option<&KinsokuRules>| {
+            layout_key(text, &font, layout, kinsoku).unwrap()
+        };
+
+        let base = key("天色渐晚", &layout(), None);
+        assert_eq!(base, key("天色渐晚", &layout(), None));
+        assert_ne!(base, key("天色渐晚。", &layout(), None));
+        let mut wider = layout();
+        wider.margin_left = 15.0;
+        assert_ne!(base, key("天色渐晚", &wider, None));
+        let rules = KinsokuRules {
+            no_line_start: "。".to_string(),
+            no_line_end: String::new(),
+        };
+        assert_ne!(base, key("天色渐晚", &layout(), Some(&rules)));
+
+        // Replacing the font file gives a new key even at the same path.
+        let file = fs::File::options().write(true).open(&font).unwrap();
+        file.set_modified(SystemTime::now() - Duration::from_secs(3600))
+            .unwrap();
+        assert_ne!(base, key("天色渐晚", &layout(), None));
+        assert!(layout_key("天色渐晚", "/missing/font.ttf", &layout(), None).is_none());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_cached_pages_round_trip() {
+        let cache = LayoutCache::new(temp_dir("round-trip"), 1 << 20).unwrap();
+        assert!(cache.get("a").is_none());
+        cache.put("a", &pages()).unwrap();
+        let cached = cache.get("a").unwrap();
+        assert_eq!(cached.len(), 2);
+        assert_eq!((cached[1].start, cached[1].end), (40, 52));
+        let line = &cached[0].lines[0];
+        assert_eq!((line.start, line.end, line.width), (0, 20, 301.5));
+        assert!(line.paragraph_start && line.hyphenated);
+        assert!(!cached[0].lines[1].paragraph_start);
+
+        // A damaged entry is a miss, and is removed.
+        let data = encode(&pages());
+        fs::write(cache.entry_path("b"), &data[..data.len() - 1]).unwrap();
+        assert!(cache.get("b").is_none());
+        assert!(!cache.entry_path("b").exists());
+        fs::remove_dir_all(&cache.dir).unwrap();
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used_first() {
+        let size = encode(&pages()).len() as u32;
+        let cache = LayoutCache::new(temp_dir("lru"), size * 2).unwrap();
+        cache.put("a", &pages()).unwrap();
+        cache.put("b", &pages()).unwrap();
+        for (key, ago_secs) in [("a", 7200), ("b", 3600)] {
+            let file = fs::File::options()
+                .write(true)
+                .open(cache.entry_path(key))
+                .unwrap();
+            file.set_modified(SystemTime::now() - Duration::from_secs(ago_secs))
+                .unwrap();
+        }
+
+        // Reading `a` makes `b` the oldest entry.
+        assert!(cache.get("a").is_some());
+        cache.put("c", &pages()).unwrap();
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("b").is_none());
+        assert_eq!(cache.stats().unwrap().entry_count, 2);
+        fs::remove_dir_all(&cache.dir).unwrap();
+    }
+}
@@ -0,0 +1,382 @@
+//! Ranked library search over titles and authors, with pinyin.
+//!
+//! Each book's title and author are indexed once as characters with their
+//! toneless pinyin, read in context as `ruby` does, and re-indexed when
+//! they change. A query matches a field as plain text, as full pinyin
+//! (`zhetian`), as initials (`zt`) or any mix of the two (`zhet`), as the
+//! query's characters in order (`诡主` in `诡秘之主`), or failing all of
+//! those by edit distance, in falling order of score. Matched ranges are
+//! returned for highlighting.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+
+use anyhow::Result;
+
+use crate::api::db::{self, BookQuery, BookSort, LibraryBook};
+use crate::api::error::ApiError;
+use crate::api::ruby;
+
+/// Authors rank below titles matched equally well.
+const AUTHOR_WEIGHT: f32 = 0.9;
+
+/// Least edit-distance similarity counted as a match.
+const MIN_SIMILARITY: f64 = 0.7;
+
+static INDEX: Mutex<Option<HashMap<String, IndexedBook>>> = Mutex::new(None);
+
+/// A matched range of a field, in UTF-16 code units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatchRange {
+    pub start: u32,
+    pub end: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct LibrarySearchResult {
+    pub book: LibraryBook,
+    /// From 0 to 1; results are sorted by it.
+    pub score: f32,
+    pub title_matches: Vec<MatchRange>,
+    pub author_matches: Vec<MatchRange>,
+}
+
+/// Search the library by title and author, including by pinyin.
+///
+/// # Arguments
+/// * `query` - Text, pinyin or pinyin initials
+/// * `limit` - Most results returned
+#[flutter_rust_bridge::frb]
+pub fn search_library_books(
+    query: String,
+    limit: u32,
+) -> Result<Vec<LibrarySearchResult>, ApiError> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Ok(Vec::new());
+    }
+    let books = db::query_books(BookQuery {
+        search: None,
+        sort: BookSort::Title,
+        offset: 0,
+        limit: u32::MAX,
+    })?
+    .books;
+
+    let mut index = lock();
+    let index = index.get_or_insert_with(HashMap::new);
+    index.retain(|id, _| books.iter().any(|book| &book.id == id));
+    let mut results = Vec::new();
+    for book in books {
+        let indexed = index
+            .entry(book.id.clone())
+            .and_modify(|indexed| {
+                if !indexed.is_of(&book) {
+                    *indexed = IndexedBook::new(&book);
+                }
+            })
+            .or_insert_with(|| IndexedBook::new(&book));
+        if let Some(result) = indexed.search(&query, book) {
+            results.push(result);
+        }
+    }
+    results.sort_by(|a, b| b.score.total_cmp(&a.score));
+    results.truncate(limit as usize);
+    Ok(results)
+}
+
+fn lock() -> MutexGuard<'static, Option<HashMap<String, IndexedBook>>> {
+    INDEX.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+struct IndexedBook {
+    title: Field,
+    author: Option<Field>,
+}
+
+impl IndexedBook {
+    fn new(book: &LibraryBook) -> Self {
+        IndexedBook {
+            title: Field::new(&book.title),
+            author: book.author.as_deref().map(Field::new),
+        }
+    }
+
+    fn is_of(&self, book: &LibraryBook) -> bool {
+        self.title.text == book.title
+            && self.author.as_ref().map(|a| a.text.as_str()) == book.author.as_deref()
+    }
+
+    fn search(&self, query: &str, book: LibraryBook) -> Option<LibrarySearchResult> {
+        let title = self.title.search(query);
+        let author = self.author.as_ref().and_then(|author| author.search(query));
+        let score = [
+            title.as_ref().map(|m| m.0),
+            author.as_ref().map(|m| m.0 * AUTHOR_WEIGHT),
+        ]
+        .into_iter()
+        .flatten()
+        .max_by(f32::total_cmp)?;
+        Some(LibrarySearchResult {
+            book,
+            score,
+            title_matches: title.map(|m| m.1).unwrap_or_default(),
+            author_matches: author.map(|m| m.1).unwrap_or_default(),
+        })
+    }
+}
+
+/// A title or author split into characters.
+struct Field {
+    text: String,
+    chars: Vec<FieldChar>,
+}
+
+struct FieldChar {
+    lower: String,
+    /// Toneless pinyin of a Han character.
+    pinyin: Option<String>,
+    start: u32,
+    end: u32,
+}
+
+impl FieldChar {
+    /// What the character matches in a pinyin query.
+    fn key(&self) -> &str {
+        self.pinyin.as_deref().unwrap_or(&self.lower)
+    }
+}
+
+impl Field {
+    fn new(text: &str) -> Self {
+        let mut chars = Vec::new();
+        for span in ruby::annotate_pinyin(text.to_string()) {
+            let mut offset = span.start;
+            for c in span.text.chars() {
+                let start = offset;
+                offset += c.len_utf16() as u32;
+                chars.push(FieldChar {
+                    lower: c.to_lowercase().collect(),
+                    pinyin: (!span.pinyin.is_empty()).then(|| toneless(&span.pinyin)),
+                    start,
+                    end: offset,
+                });
+            }
+        }
+        Field {
+            text: text.to_string(),
+            chars,
+        }
+    }
+
+    /// The best score of `query`, which is lowercase, and what it matched.
+    fn search(&self, query: &str) -> Option<(f32, Vec<MatchRange>)> {
+        self.text_match(query)
+            .or_else(|| self.pinyin_match(query))
+            .or_else(|| self.subsequence_match(query))
+            .or_else(|| self.fuzzy_match(query))
+    }
+
+    fn text_match(&self, query: &str) -> Option<(f32, Vec<MatchRange>)> {
+        let lower: Vec<&str> = self.chars.iter().map(|c| c.lower.as_str()).collect();
+        let query: Vec<String> = query.chars().map(|c| c.to_string()).collect();
+        let start = lower
+            .windows(query.len())
+            .position(|window| window == query)?;
+        let score = if query.len() == lower.len() {
+            1.0
+        } else if start == 0 {
+            0.95
+        } else {
+            0.85
+        };
+        Some((score, vec![self.range(start, start + query.len())]))
+    }
+
+    fn pinyin_match(&self, query: &str) -> Option<(f32, Vec<MatchRange>)> {
+        let query: String = query
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '\'')
+            .collect();
+        if query.is_empty() || !query.is_ascii() || !self.chars.iter().any(|c| c.pinyin.is_some()) {
+            return None;
+        }
+        (0..self.chars.len())
+            .filter(|&start| !self.chars[start].lower.trim().is_empty())
+            .find_map(|start| {
+                let (end, initials) = self.consume(start, &query, false)?;
+                let score = if initials { 0.7 } else { 0.8 };
+                let score = if start == 0 { score + 0.05 } else { score };
+                Some((score, vec![self.range(start, end)]))
+            })
+    }
+
+    /// Match `query` from character `i` on: each character by its whole
+    /// key or, if it has pinyin, by its initial, and the last one also by
+    /// a prefix. Returns the end and whether an initial was used.
+    fn consume(&self, i: usize, query: &str, initials: bool) -> Option<(usize, bool)> {
+        if query.is_empty() {
+            return Some((i, initials));
+        }
+        let c = self.chars.get(i)?;
+        if c.lower.trim().is_empty() {
+            return self.consume(i + 1, query, initials);
+        }
+        let key = c.key();
+        if let Some(rest) = query.strip_prefix(key) {
+            if let Some(found) = self.consume(i + 1, rest, initials) {
+                return Some(found);
+            }
+        }
+        if key.starts_with(query) {
+            return Some((i + 1, initials));
+        }
+        let pinyin = c.pinyin.as_deref()?;
+        let initial = ["zh", "ch", "sh"]
+            .into_iter()
+            .find(|initial| pinyin.starts_with(initial) && query.starts_with(initial))
+            .unwrap_or(&pinyin[..1]);
+        let rest = query.strip_prefix(initial)?;
+        self.consume(i + 1, rest, true)
+    }
+
+    fn subsequence_match(&self, query: &str) -> Option<(f32, Vec<MatchRange>)> {
+        if query.is_ascii() {
+            return None;
+        }
+        let mut ranges: Vec<MatchRange> = Vec::new();
+        let mut chars = self.chars.iter();
+        for q in query.chars().filter(|c| !c.is_whitespace()) {
+            let q = q.to_lowercase().to_string();
+            let c = chars.find(|c| c.lower == q)?;
+            match ranges.last_mut().filter(|range| range.end == c.start) {
+                Some(range) => range.end = c.end,
+                None => ranges.push(MatchRange {
+                    start: c.start,
+                    end: c.end,
+                }),
+            }
+        }
+        // Fewer, longer runs read as a better match.
+        Some((0.6 - 0.02 * ranges.len().min(10) as f32, ranges))
+    }
+
+    fn fuzzy_match(&self, query: &str) -> Option<(f32, Vec<MatchRange>)> {
+        if query.chars().count() < 3 {
+            return None;
+        }
+        let lower: String = self.chars.iter().map(|c| c.lower.as_str()).collect();
+        let pinyin: String = self
+            .chars
+            .iter()
+            .filter(|c| !c.lower.trim().is_empty())
+            .map(FieldChar::key)
+            .collect();
+        let similarity = strsim::normalized_levenshtein(query, &lower)
+            .max(strsim::normalized_levenshtein(query, &pinyin));
+        (similarity >= MIN_SIMILARITY).then(|| {
+            (
+                0.5 * similarity as f32,
+                vec![self.range(0, self.chars.len())],
+            )
+        })
+    }
+
+    fn range(&self, start: usize, end: usize) -> MatchRange {
+        MatchRange {
+            start: self.chars[start].start,
+            end: self.chars[end - 1].end,
+        }
+    }
+}
+
+/// Pinyin without tone marks, with `ü` as `v` as typed.
+fn toneless(pinyin: &str) -> String {
+    pinyin
+        .chars()
+        .map(|c| match c {
+            'ā' | 'á' | 'ǎ' | 'à' => 'a',
+            'ē' | 'é' | 'ě' | 'è' => 'e',
+            'ī' | 'í' | 'ǐ' | 'ì' => 'i',
+            'ō' | 'ó' | 'ǒ' | 'ò' => 'o',
+            'ū' | 'ú' | 'ǔ' | 'ù' => 'u',
+            'ǖ' | 'ǘ' | 'ǚ' | 'ǜ' | 'ü' => 'v',
+            'ń' | 'ň' | 'ǹ' => 'n',
+            'ḿ' => 'm',
+            c => c,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn search(text: &str, query: &str) -> Option<(f32, Vec<(u32, u32)>)> {
+        let (score, ranges) = Field::new(text).search(query)?;
+        let score = (score * 100.0).round() / 100.0;
+        Some((score, ranges.iter().map(|r| (r.start, r.end)).collect()))
+    }
+
+    #[test]
+    fn test_pinyin_full_initials_and_mixed() {
+        assert_eq!(search("遮天", "zhetian"), Some((0.85, vec![(0, 2)])));
+        assert_eq!(search("遮天", "zt"), Some((0.75, vec![(0, 2)])));
+        assert_eq!(search("遮天", "zhet"), Some((0.85, vec![(0, 2)])));
+        assert_eq!(search("遮天", "zht"), Some((0.75, vec![(0, 2)])));
+        assert_eq!(search("诡秘之主", "mizhi"), Some((0.8, vec![(1, 3)])));
+        assert_eq!(search("诡秘之主", "zt"), None);
+        assert_eq!(toneless("lǜ"), "lv");
+    }
+
+    #[test]
+    fn test_text_subsequence_and_fuzzy() {
+        assert_eq!(
+            search("Lord of Mysteries", "lord of mysteries").unwrap().0,
+            1.0
+        );
+        assert_eq!(
+            search("Lord of Mysteries", "myst"),
+            Some((0.85, vec![(8, 12)]))
+        );
+        assert_eq!(
+            search("诡秘之主", "诡主"),
+            Some((0.56, vec![(0, 1), (3, 4)]))
+        );
+        assert_eq!(
+            search("Lord of Mysteries", "lord of mystreies"),
+            Some((0.44, vec![(0, 17)]))
+        );
+        assert_eq!(search("遮天", "完美世界"), None);
+    }
+
+    #[test]
+    fn test_ranks_titles_above_authors() {
+        let book = |id: &str, title: &str, author: &str| LibraryBook {
+            id: id.to_string(),
+            title: title.to_string(),
+            author: Some(author.to_string()),
+            intro: None,
+            kind: None,
+            cover_url: None,
+            source_url: None,
+            book_url: None,
+            toc_url: None,
+            latest_chapter_title: None,
+            chapter_count: 0,
+            word_count: None,
+            added_at: 0,
+            updated_at: 0,
+            last_read_at: None,
+        };
+        let by_title = book("1", "辰东", "某人");
+        let by_author = book("2", "遮天", "辰东");
+        let title = IndexedBook::new(&by_title).search("chendong", by_title);
+        let author = IndexedBook::new(&by_author).search("chendong", by_author);
+        let (title, author) = (title.unwrap(), author.unwrap());
+        assert!(title.score > author.score);
+        assert!(author.title_matches.is_empty());
+        assert_eq!(author.author_matches, [MatchRange { start: 0, end: 2 }]);
+        assert!(!IndexedBook::new(&title.book).is_of(&author.book));
+    }
+}
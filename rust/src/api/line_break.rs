@@ -0,0 +1,157 @@
+//! Line-break opportunities: UAX #14 tightened by kinsoku shori (禁则).
+//!
+//! UAX #14 already keeps closing punctuation such as `，` off the start of
+//! a line and opening brackets such as `「` off the end when they sit
+//! directly next to the break, but it always allows a break after a space,
+//! so `说“ 好` or `23 ℃` can still split badly. Kinsoku rules look past the
+//! space. Pagination also applies them when it has to break inside a run
+//! too long for a line, where UAX #14 offers no opportunity at all. The
+//! sets are configurable, since publishers differ on the finer points.
+
+use std::collections::HashSet;
+
+use unicode_linebreak::{linebreaks, BreakOpportunity};
+
+/// Characters that may not start a line, used by `default_kinsoku_rules`.
+const NO_LINE_START: &str = concat!(
+    "!%),.:;?]}¢°’”‰′″℃、。〃〆〉》」』】〕〗〙〛〞〟",
+    "ゝゞーヽヾ々〻゠・ぁぃぅぇぉっゃゅょゎゕゖァィゥェォッャュョヮヵヶ",
+    "ㇰㇱㇲㇳㇴㇵㇶㇷㇸㇹㇺㇻㇼㇽㇾㇿ‐–〜～…‥",
+    "！％），．：；？］｝｡､｣･ｰ"
+);
+
+/// Characters that may not end a line, used by `default_kinsoku_rules`.
+const NO_LINE_END: &str = "([{£¥‘“〈《「『【〔〖〘〚〝（［｛｢￡￥＄";
+
+#[derive(Debug, Clone)]
+pub struct KinsokuRules {
+    /// Characters that may not begin a line.
+    pub no_line_start: String,
+    /// Characters that may not end a line.
+    pub no_line_end: String,
+}
+
+/// A place where a line may end.
+#[derive(Debug, Clone)]
+pub struct LineBreak {
+    /// UTF-16 offset where the next line would start.
+    pub offset: u32,
+    /// Whether the line must end here, as after a newline.
+    pub mandatory: bool,
+}
+
+/// The usual Chinese and Japanese kinsoku sets. Pass empty strings instead
+/// for plain UAX #14.
+#[flutter_rust_bridge::frb]
+pub fn default_kinsoku_rules() -> KinsokuRules {
+    KinsokuRules {
+        no_line_start: NO_LINE_START.to_string(),
+        no_line_end: NO_LINE_END.to_string(),
+    }
+}
+
+/// Every line-break opportunity in `text`, in order.
+///
+/// # Arguments
+/// * `text` - Any text
+/// * `rules` - Kinsoku rules; `default_kinsoku_rules` if omitted
+#[flutter_rust_bridge::frb]
+pub fn line_break_opportunities(text: String, rules: Option<KinsokuRules>) -> Vec<LineBreak> {
+    let kinsoku = Kinsoku::new(&rules.unwrap_or_else(default_kinsoku_rules));
+    let mut utf16 = 0;
+    let mut byte = 0;
+    kinsoku
+        .breaks(&text)
+        .into_iter()
+        .map(|(position, opportunity)| {
+            utf16 += text[byte..position].encode_utf16().count() as u32;
+            byte = position;
+            LineBreak {
+                offset: utf16,
+                mandatory: opportunity == BreakOpportunity::Mandatory,
+            }
+        })
+        .collect()
+}
+
+/// Kinsoku rules ready for lookups.
+#[flutter_rust_bridge::frb(ignore)]
+pub(crate) struct Kinsoku {
+    no_line_start: HashSet<char>,
+    no_line_end: HashSet<char>,
+}
+
+impl Kinsoku {
+    pub(crate) fn new(rules: &KinsokuRules) -> Self {
+        Self {
+            no_line_start: rules.no_line_start.chars().collect(),
+            no_line_end: rules.no_line_end.chars().collect(),
+        }
+    }
+
+    /// `Kinsoku::new(&default_kinsoku_rules())`.
+    pub(crate) fn standard() -> Self {
+        Self::new(&default_kinsoku_rules())
+    }
+
+    /// UAX #14 break positions of `text` that the rules allow. Mandatory
+    /// breaks are always kept.
+    pub(crate) fn breaks(&self, text: &str) -> Vec<(usize, BreakOpportunity)> {
+        linebreaks(text)
+            .filter(|&(position, opportunity)| {
+                opportunity == BreakOpportunity::Mandatory
+                    || position == text.len()
+                    || self.allows(text, position)
+            })
+            .collect()
+    }
+
+    /// Whether a line may end at byte `position`, ignoring UAX #14: the
+    /// next line may not start with a forbidden character, nor this one end
+    /// with one, not counting whitespace at the break.
+    pub(crate) fn allows(&self, text: &str, position: usize) -> bool {
+        let next = text[position..].chars().next();
+        let previous = text[..position].trim_end().chars().next_back();
+        !next.is_some_and(|c| self.no_line_start.contains(&c))
+            && !previous.is_some_and(|c| self.no_line_end.contains(&c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offsets(text: &str, rules: Option<KinsokuRules>) -> Vec<u32> {
+        line_break_opportunities(text.to_string(), rules)
+            .iter()
+            .map(|b| b.offset)
+            .collect()
+    }
+
+    #[test]
+    fn test_kinsoku_removes_breaks() {
+        let plain = KinsokuRules {
+            no_line_start: String::new(),
+            no_line_end: String::new(),
+        };
+        // UAX #14 breaks after the space; kinsoku looks past it.
+        assert_eq!(offsets("说“ 好", Some(plain.clone())), [3, 4]);
+        assert_eq!(offsets("说“ 好", None), [4]);
+        assert_eq!(offsets("23 ℃好", Some(plain.clone())), [3, 4, 5]);
+        assert_eq!(offsets("23 ℃好", None), [4, 5]);
+        assert_eq!(offsets("天 ー", Some(plain)), [2, 3]);
+        assert_eq!(offsets("天 ー", None), [3]);
+    }
+
+    #[test]
+    fn test_custom_rules_and_mandatory_breaks() {
+        let rules = KinsokuRules {
+            no_line_start: "色".to_string(),
+            no_line_end: String::new(),
+        };
+        let breaks = line_break_opportunities("天色\n渐😀晚".to_string(), Some(rules));
+        let found: Vec<_> = breaks.iter().map(|b| (b.offset, b.mandatory)).collect();
+        // UTF-16 offsets: the emoji counts as two units.
+        assert_eq!(found, [(3, true), (4, false), (6, false), (7, true)]);
+    }
+}
@@ -0,0 +1,274 @@
+//! Random access to huge text files through a memory map.
+//!
+//! `index_txt` and `read_txt_chapter` reopen the file and detect its
+//! encoding on every read. An open mapped file keeps the map, encoding and
+//! chapter index instead, so reading any chapter or any byte range is a
+//! slice of the map: opening a 300 MB book costs one pass over it, jumping
+//! anywhere in it is instant, and memory use stays flat since only pages
+//! that are read are loaded. Ranges are widened to whole lines, so they
+//! start and end between characters in any encoding.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Cursor;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use anyhow::{anyhow, Result};
+use encoding_rs::{Encoding, UTF_16BE, UTF_16LE, UTF_8};
+
+use crate::api::error::ApiError;
+use crate::api::import;
+use crate::api::txt::{self, TxtChapterRule, TxtIndex};
+use crate::mmap::Mmap;
+
+static MAPPED_FILES: Mutex<Option<HashMap<u32, Arc<MappedTxt>>>> = Mutex::new(None);
+
+static NEXT_ID: AtomicU32 = AtomicU32::new(1);
+
+#[derive(Debug, Clone)]
+pub struct MappedTxtInfo {
+    pub id: u32,
+    pub path: String,
+    pub index: TxtIndex,
+}
+
+/// Text read from a mapped file, with the byte range it came from.
+#[derive(Debug, Clone)]
+pub struct MappedTextSlice {
+    pub text: String,
+    pub start: u32,
+    /// Where the next slice starts; the file size at the end.
+    pub end: u32,
+}
+
+/// Map a text file and index its chapters. The file must not be changed
+/// while it is open.
+///
+/// # Arguments
+/// * `path` - Path to the text file, under 4 GB
+/// * `rules` - Heading rules; `None` uses `default_txt_rules()`
+#[flutter_rust_bridge::frb]
+pub fn open_mapped_txt(
+    path: String,
+    rules: Option<Vec<TxtChapterRule>>,
+) -> Result<MappedTxtInfo, ApiError> {
+    let rules = txt::compile_rules(rules)?;
+    let file = File::open(&path).map_err(|e| anyhow!("Failed to open {path}: {e}"))?;
+    let map = Mmap::map(&file).map_err(|e| anyhow!("{e}: {path}"))?;
+    if map.len() > u32::MAX as usize {
+        return Err(anyhow!("{path} is too large to open").into());
+    }
+    let index = txt::scan(Cursor::new(&map[..]), &rules)?;
+    let (encoding, bom_len) = txt::detect_encoding(&map[..map.len().min(txt::SAMPLE_BYTES)]);
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    let info = MappedTxtInfo {
+        id,
+        path,
+        index: index.clone(),
+    };
+    lock().get_or_insert_with(HashMap::new).insert(
+        id,
+        Arc::new(MappedTxt {
+            map,
+            encoding,
+            bom_len,
+            index,
+        }),
+    );
+    Ok(info)
+}
+
+/// Read one chapter of an open file as HTML.
+///
+/// # Arguments
+/// * `id` - ID from `open_mapped_txt`
+/// * `chapter_index` - Position of the chapter in the file's index
+#[flutter_rust_bridge::frb]
+pub fn read_mapped_chapter(id: u32, chapter_index: u32) -> Result<String, ApiError> {
+    let file = get(id)?;
+    let chapter = file
+        .index
+        .chapters
+        .get(chapter_index as usize)
+        .ok_or_else(|| anyhow!("Chapter {chapter_index} is not in the file"))?;
+    let bytes = &file.map[chapter.start as usize..chapter.end as usize];
+    Ok(txt::chapter_html(
+        &txt::decode(file.encoding, bytes),
+        chapter.title.is_some(),
+    ))
+}
+
+/// Read plain text from anywhere in an open file, e.g. to jump to a
+/// position in a book without chapters. The range is widened to start at
+/// the line containing `start` and to end at a line break, as long as the
+/// line is shorter than `max_bytes`.
+///
+/// # Arguments
+/// * `id` - ID from `open_mapped_txt`
+/// * `start` - Byte offset to read from
+/// * `max_bytes` - Bytes to read at most, before widening to line ends
+#[flutter_rust_bridge::frb]
+pub fn read_mapped_text(id: u32, start: u32, max_bytes: u32) -> Result<MappedTextSlice, ApiError> {
+    let file = get(id)?;
+    let data = &file.map[..];
+    let (begin, end) = file.line_range(start as usize, max_bytes as usize);
+    Ok(MappedTextSlice {
+        text: txt::decode(file.encoding, &data[begin..end]),
+        start: begin as u32,
+        end: end as u32,
+    })
+}
+
+/// Close an open file. Returns false if it was not open.
+#[flutter_rust_bridge::frb]
+pub fn close_mapped_txt(id: u32) -> bool {
+    lock()
+        .as_mut()
+        .is_some_and(|files| files.remove(&id).is_some())
+}
+
+fn lock() -> MutexGuard<'static, Option<HashMap<u32, Arc<MappedTxt>>>> {
+    MAPPED_FILES.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn get(id: u32) -> Result<Arc<MappedTxt>> {
+    lock()
+        .as_ref()
+        .and_then(|files| files.get(&id).cloned())
+        .ok_or_else(|| anyhow!("Mapped text file {id} is not open"))
+}
+
+struct MappedTxt {
+    map: Mmap,
+    encoding: &'static Encoding,
+    bom_len: usize,
+    index: TxtIndex,
+}
+
+impl MappedTxt {
+    /// The byte range of whole lines from the one containing `start` to the
+    /// first line break after `start + max_bytes`.
+    fn line_range(&self, start: usize, max_bytes: usize) -> (usize, usize) {
+        let data = &self.map[..];
+        let start = self.char_start(start.clamp(self.bom_len, data.len()));
+        let line_start = self.line_start(start);
+        // A text without line breaks is read from where it was asked.
+        let begin = if start - line_start > max_bytes {
+            start
+        } else {
+            line_start
+        };
+        let end = import::line_end(
+            data,
+            self.bom_len,
+            (start + max_bytes).min(data.len()),
+            self.encoding,
+        );
+        (begin, end)
+    }
+
+    fn is_utf16(&self) -> bool {
+        self.encoding == UTF_16LE || self.encoding == UTF_16BE
+    }
+
+    /// `offset`, moved back to the start of a UTF-16 code unit or forward
+    /// past UTF-8 continuation bytes. Other multibyte encodings cannot be
+    /// synchronized mid-line and are left alone.
+    fn char_start(&self, offset: usize) -> usize {
+        let data = &self.map[..];
+        if self.is_utf16() {
+            return offset - (offset - self.bom_len) % 2;
+        }
+        if self.encoding == UTF_8 {
+            let skip = data[offset..]
+                .iter()
+                .take_while(|&&b| b & 0xC0 == 0x80)
+                .count();
+            return offset + skip;
+        }
+        offset
+    }
+
+    /// The start of the line containing `offset`, a character start.
+    fn line_start(&self, offset: usize) -> usize {
+        let data = &self.map[..];
+        if !self.is_utf16() {
+            return data[self.bom_len..offset]
+                .iter()
+                .rposition(|&b| b == b'\n')
+                .map_or(self.bom_len, |i| self.bom_len + i + 1);
+        }
+        let unit = if self.encoding == UTF_16LE {
+            [b'\n', 0]
+        } else {
+            [0, b'\n']
+        };
+        let mut i = offset;
+        while i >= self.bom_len + 2 {
+            if data[i - 2..i] == unit {
+                return i;
+            }
+            i -= 2;
+        }
+        self.bom_len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_dirs::temp_dir;
+    use std::path::Path;
+
+    fn write(name: &str, bytes: &[u8]) -> String {
+        let path = temp_dir("mapped-txt").join(format!("{name}.txt"));
+        std::fs::write(&path, bytes).unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    /// Remove a file written by `write` along with its directory.
+    fn remove(path: impl AsRef<Path>) {
+        std::fs::remove_dir_all(path.as_ref().parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_reads_chapters_and_ranges() {
+        let text = "第一章 开始\n天色渐晚。\n第二章 归来\n城门外行人稀少。\n";
+        let path = write("utf8", text.as_bytes());
+        let info = open_mapped_txt(path.clone(), None).unwrap();
+        assert_eq!(info.index.chapters.len(), 2);
+        assert_eq!(
+            read_mapped_chapter(info.id, 1).unwrap(),
+            "<h2>第二章 归来</h2><p>城门外行人稀少。</p>"
+        );
+        assert!(read_mapped_chapter(info.id, 2).is_err());
+
+        // An offset inside 天 starts from its line.
+        let slice = read_mapped_text(info.id, 18, 4).unwrap();
+        assert_eq!(slice.text, "天色渐晚。\n");
+        assert_eq!(slice.start as usize, "第一章 开始\n".len());
+        let next = read_mapped_text(info.id, slice.end, 1).unwrap();
+        assert_eq!(next.text, "第二章 归来\n");
+
+        assert!(close_mapped_txt(info.id));
+        assert!(!close_mapped_txt(info.id));
+        assert!(read_mapped_chapter(info.id, 0).is_err());
+        remove(path);
+    }
+
+    #[test]
+    fn test_utf16_ranges_keep_code_units_whole() {
+        let mut bytes = vec![0xFF, 0xFE];
+        for unit in "第一章\n天色\n渐晚\n".encode_utf16() {
+            bytes.extend_from_slice(&unit.to_le_bytes());
+        }
+        let path = write("utf16", &bytes);
+        let info = open_mapped_txt(path.clone(), None).unwrap();
+        let slice = read_mapped_text(info.id, 11, 2).unwrap();
+        assert_eq!(slice.text, "天色\n");
+        assert_eq!(slice.start, 10);
+        close_mapped_txt(info.id);
+        remove(path);
+    }
+}
@@ -0,0 +1,398 @@
+//! Title and author guesses for imported files.
+//!
+//! Books shared online carry their metadata in the file name, wrapped in
+//! release notes: `[作者]书名(校对版).txt`, `《书名》作者：某人.txt`,
+//! `书名_作者_站名.txt` or `【www.site.com】书名 全本.txt`. The name is
+//! taken apart into a title, an author and tags such as `校对版`. Text
+//! files are also scanned up to their first chapter heading for lines like
+//! `书名：…` and `作者：…`, which rescue names such as `0001.txt`. Each
+//! guess has a confidence from 0 to 1; a value found in both places is
+//! more certain than either alone. The import screen shows the guesses for
+//! the user to confirm.
+
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use anyhow::anyhow;
+use regex::Regex;
+
+use crate::api::encoding;
+use crate::api::error::ApiError;
+use crate::api::txt;
+
+/// Bytes read from the start of a text file for metadata lines.
+const SCAN_BYTES: usize = 16 * 1024;
+/// Non-blank lines scanned before giving up on finding metadata lines.
+const SCAN_LINES: usize = 40;
+/// Longest author name accepted from a separated part of a file name.
+const MAX_NAME_AUTHOR_CHARS: usize = 12;
+
+/// Words marking a bracketed or trailing part of a name as a release note.
+const RELEASE_NOTES: &str = concat!(
+    "精校|校对|校订|修订|完结|完本|全本|全集|连载|无删减|最新|新版|出版|实体|",
+    "手打|典藏|珍藏|插图|图文|番外|txt|epub|mobi|azw3?|pdf",
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetadataOrigin {
+    FileName,
+    Content,
+    /// The file name and the content agree.
+    Both,
+}
+
+#[derive(Debug, Clone)]
+pub struct MetadataGuess {
+    pub value: String,
+    /// From 0 to 1.
+    pub confidence: f32,
+    pub origin: MetadataOrigin,
+}
+
+#[derive(Debug, Clone)]
+pub struct ScrapedMetadata {
+    pub title: Option<MetadataGuess>,
+    /// Several authors are kept together, as written.
+    pub author: Option<MetadataGuess>,
+    /// Release notes taken out of the file name, such as `校对版` or `全本`.
+    pub tags: Vec<String>,
+}
+
+/// Guess the title and author of a file to import from its name and, for
+/// text files, its first pages.
+///
+/// # Arguments
+/// * `path` - File to import; only `.txt` files are read
+#[flutter_rust_bridge::frb]
+pub fn scrape_metadata(path: String) -> Result<ScrapedMetadata, ApiError> {
+    let file_name = Path::new(&path)
+        .file_name()
+        .map_or_else(|| path.clone(), |name| name.to_string_lossy().into_owned());
+    let is_text = Path::new(&path)
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("txt"));
+    let text = if is_text {
+        let mut bytes = Vec::with_capacity(SCAN_BYTES);
+        File::open(&path)
+            .and_then(|file| file.take(SCAN_BYTES as u64).read_to_end(&mut bytes))
+            .map_err(|e| anyhow!("Failed to read {path}: {e}"))?;
+        let detection = encoding::detect(&bytes, false);
+        Some(txt::decode(detection.encoding, &bytes[detection.bom_len..]))
+    } else {
+        None
+    };
+    Ok(scrape_metadata_from(file_name, text))
+}
+
+/// Guess the title and author from a file name and text already read.
+///
+/// # Arguments
+/// * `file_name` - Name of the file, with or without its extension
+/// * `text` - The start of the book's text, if it has any
+#[flutter_rust_bridge::frb]
+pub fn scrape_metadata_from(file_name: String, text: Option<String>) -> ScrapedMetadata {
+    let mut scraped = from_file_name(&file_name);
+    if let Some(text) = text {
+        let (title, author) = from_content(&text);
+        scraped.title = combine(scraped.title, title);
+        scraped.author = combine(scraped.author, author);
+    }
+    scraped
+}
+
+fn from_file_name(file_name: &str) -> ScrapedMetadata {
+    let patterns = patterns();
+    let guess = |value: &str, confidence: f32| MetadataGuess {
+        value: value.to_string(),
+        confidence,
+        origin: MetadataOrigin::FileName,
+    };
+    let mut name = patterns
+        .extension
+        .replace(file_name.trim(), "")
+        .into_owned();
+    let mut title = None;
+    let mut author = None;
+    let mut tags = Vec::new();
+
+    // `《书名》` is a title whatever surrounds it.
+    if let Some(captures) = patterns.title_marks.captures(&name) {
+        title = clean_title(&captures[1], &mut tags).map(|value| guess(&value, 0.9));
+        name = patterns.title_marks.replace(&name, " ").into_owned();
+    }
+
+    // Bracketed parts are release notes, site names or the author.
+    let mut rest = String::new();
+    let mut last = 0;
+    for captures in patterns.bracketed.captures_iter(&name) {
+        let whole = captures.get(0).unwrap();
+        let inner = captures[1].trim();
+        rest.push_str(&name[last..whole.start()]);
+        rest.push(' ');
+        last = whole.end();
+        if inner.is_empty() || patterns.site.is_match(inner) {
+            continue;
+        }
+        if patterns.release_note.is_match(inner) {
+            tags.push(inner.to_string());
+            continue;
+        }
+        let leading = name[..whole.start()].trim().is_empty();
+        let labelled = patterns.author_label.is_match(inner);
+        let confidence = if labelled {
+            0.9
+        } else if leading {
+            0.7
+        } else {
+            0.5
+        };
+        if author.is_none() && (labelled || inner.chars().count() > 1) {
+            author = clean_author(inner).map(|value| guess(&value, confidence));
+        } else {
+            tags.push(inner.to_string());
+        }
+    }
+    rest.push_str(&name[last..]);
+
+    // Then `作者：某人` or ` by Someone` after the title.
+    let mut rest = rest.trim().to_string();
+    if let Some(captures) = patterns.author_suffix.captures(&rest) {
+        let value = clean_author(&captures[2]);
+        if author.as_ref().is_none_or(|author| author.confidence < 0.9) {
+            author = value.map(|value| guess(&value, 0.9));
+        }
+        rest = captures[1].to_string();
+    }
+
+    // And `书名_作者_站名`, or what is left after `《书名》`.
+    let parts: Vec<&str> = patterns
+        .separator
+        .split(&rest)
+        .map(str::trim)
+        .filter(|part| !part.is_empty() && !patterns.site.is_match(part))
+        .collect();
+    let mut parts = parts.into_iter();
+    if title.is_none() {
+        let separated = patterns.separator.is_match(&rest);
+        title = parts
+            .next()
+            .and_then(|part| clean_title(part, &mut tags))
+            .map(|value| {
+                let confidence = if !value.chars().any(char::is_alphabetic) {
+                    0.2
+                } else if separated {
+                    0.6
+                } else {
+                    0.7
+                };
+                guess(&value, confidence)
+            });
+    }
+    if author.is_none() {
+        author = parts
+            .next()
+            .filter(|part| part.chars().count() <= MAX_NAME_AUTHOR_CHARS)
+            .and_then(clean_author)
+            .map(|value| guess(&value, 0.5));
+    }
+    ScrapedMetadata {
+        title,
+        author,
+        tags,
+    }
+}
+
+/// Title and author lines before the first chapter heading.
+fn from_content(text: &str) -> (Option<MetadataGuess>, Option<MetadataGuess>) {
+    let patterns = patterns();
+    let rules = txt::compile_rules(None).unwrap_or_default();
+    let guess = |value: String, confidence: f32| MetadataGuess {
+        value,
+        confidence,
+        origin: MetadataOrigin::Content,
+    };
+    let mut title = None;
+    let mut author = None;
+    let text = text.trim_start_matches('\u{FEFF}');
+    let lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+    for line in lines.take(SCAN_LINES) {
+        if txt::heading(line, &rules).is_some() {
+            break;
+        }
+        if let Some(captures) = patterns.title_line.captures(line) {
+            title = title
+                .or_else(|| clean_title(&captures[1], &mut Vec::new()).map(|v| guess(v, 0.85)));
+        } else if let Some(captures) = patterns.author_line.captures(line) {
+            author = author.or_else(|| clean_author(&captures[1]).map(|v| guess(v, 0.85)));
+        } else if let Some(captures) = patterns.title_marks_line.captures(line) {
+            title = title
+                .or_else(|| clean_title(&captures[1], &mut Vec::new()).map(|v| guess(v, 0.75)));
+            if let Some(value) = captures.get(2) {
+                author = author.or_else(|| clean_author(value.as_str()).map(|v| guess(v, 0.85)));
+            }
+        }
+        if title.is_some() && author.is_some() {
+            break;
+        }
+    }
+    (title, author)
+}
+
+/// The better of two guesses, more certain when they agree.
+fn combine(name: Option<MetadataGuess>, content: Option<MetadataGuess>) -> Option<MetadataGuess> {
+    match (name, content) {
+        (Some(name), Some(content)) if comparable(&name.value) == comparable(&content.value) => {
+            Some(MetadataGuess {
+                confidence: (name.confidence.max(content.confidence) + 0.15).min(0.99),
+                origin: MetadataOrigin::Both,
+                ..name
+            })
+        }
+        (Some(name), Some(content)) if content.confidence > name.confidence => Some(content),
+        (name, content) => name.or(content),
+    }
+}
+
+fn comparable(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// A title without trailing release notes, which are added to `tags`.
+fn clean_title(title: &str, tags: &mut Vec<String>) -> Option<String> {
+    let patterns = patterns();
+    let mut title = title.trim();
+    while let Some(captures) = patterns.trailing_note.captures(title) {
+        let note = captures.get(1).unwrap();
+        tags.push(note.as_str().to_string());
+        title = title[..note.start()].trim_end();
+    }
+    let title = title.trim_matches(|c: char| c.is_whitespace() || "-_.·".contains(c));
+    Some(title.to_string()).filter(|title| !title.is_empty())
+}
+
+fn clean_author(author: &str) -> Option<String> {
+    let patterns = patterns();
+    let author = patterns.author_label.replace(author.trim(), "");
+    let author = author
+        .trim()
+        .trim_end_matches("著作")
+        .trim_end_matches('著')
+        .trim();
+    Some(author.to_string()).filter(|author| !author.is_empty())
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+struct Patterns {
+    extension: Regex,
+    title_marks: Regex,
+    bracketed: Regex,
+    site: Regex,
+    release_note: Regex,
+    trailing_note: Regex,
+    author_label: Regex,
+    author_suffix: Regex,
+    separator: Regex,
+    title_line: Regex,
+    author_line: Regex,
+    title_marks_line: Regex,
+}
+
+fn patterns() -> &'static Patterns {
+    static PATTERNS: OnceLock<Patterns> = OnceLock::new();
+    PATTERNS.get_or_init(|| {
+        let pattern = |p: &str| Regex::new(p).unwrap();
+        Patterns {
+            extension: pattern(r"(?i)\.(?:txt|epub|mobi|azw3?|pdf|umd|fb2|zip|rar|7z)$"),
+            title_marks: pattern(r"《([^》]+)》"),
+            bracketed: pattern(r"[\[【〔(（<〈「『]([^\]】〕)）>〉」』]*)[\]】〕)）>〉」』]"),
+            site: pattern(r"(?i)^(?:www\.|https?://)|\.(?:com|net|org|cc|cn|info|la|me|tw)\b"),
+            release_note: pattern(&format!(r"(?i){RELEASE_NOTES}|^[\d\s.+-]+$")),
+            trailing_note: pattern(&format!(
+                r"(?i)\s*((?:txt)?(?:{RELEASE_NOTES})[版本]?(?:章节)?)$"
+            )),
+            author_label: pattern(r"(?i)^\s*(?:作\s*者|著\s*者|author)\s*[:：]?\s*"),
+            author_suffix: pattern(r"(?i)^(.*?)\s*(?:作\s*者\s*[:：]?|\bby\s+)\s*(.+)$"),
+            separator: pattern(r"\s*(?:_+|--+|——|\s-\s)\s*"),
+            title_line: pattern(r"(?i)^(?:书\s*名|标\s*题|title)\s*[:：]\s*(.+)$"),
+            author_line: pattern(r"(?i)^(?:(?:作\s*者|著\s*者|author)\s*[:：]|文\s*/)\s*(.+)$"),
+            title_marks_line: pattern(r"^《([^》]+)》\s*(?:作\s*者\s*[:：]?\s*(.+))?$"),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guessed(scraped: &ScrapedMetadata) -> (Option<&str>, Option<&str>) {
+        (
+            scraped.title.as_ref().map(|guess| guess.value.as_str()),
+            scraped.author.as_ref().map(|guess| guess.value.as_str()),
+        )
+    }
+
+    #[test]
+    fn test_file_name_patterns() {
+        let cases = [
+            (
+                "[天蚕土豆]斗破苍穹(校对版).txt",
+                Some("斗破苍穹"),
+                Some("天蚕土豆"),
+            ),
+            (
+                "《诡秘之主》作者：爱潜水的乌贼.txt",
+                Some("诡秘之主"),
+                Some("爱潜水的乌贼"),
+            ),
+            (
+                "诡秘之主_爱潜水的乌贼_www.example.com.txt",
+                Some("诡秘之主"),
+                Some("爱潜水的乌贼"),
+            ),
+            (
+                "【www.qisuu.com】凡人修仙传 全本.txt",
+                Some("凡人修仙传"),
+                None,
+            ),
+            ("三体（刘慈欣）精校版.epub", Some("三体"), Some("刘慈欣")),
+            (
+                "The Martian by Andy Weir.epub",
+                Some("The Martian"),
+                Some("Andy Weir"),
+            ),
+        ];
+        for (name, title, author) in cases {
+            let scraped = scrape_metadata_from(name.to_string(), None);
+            assert_eq!(guessed(&scraped), (title, author), "{name}");
+        }
+        let scraped = scrape_metadata_from("[天蚕土豆]斗破苍穹(校对版).txt".to_string(), None);
+        assert_eq!(scraped.tags, ["校对版"]);
+        let scraped = scrape_metadata_from("凡人修仙传txt全集.txt".to_string(), None);
+        assert_eq!(scraped.tags, ["txt全集"]);
+        assert_eq!(scraped.title.unwrap().origin, MetadataOrigin::FileName);
+    }
+
+    #[test]
+    fn test_content_lines() {
+        let text = "\u{FEFF}书名：三体\n作　者：刘慈欣 著\n\n第一章 科学边界\n作者：别人\n";
+        let scraped = scrape_metadata_from("0001.txt".to_string(), Some(text.to_string()));
+        assert_eq!(guessed(&scraped), (Some("三体"), Some("刘慈欣")));
+        assert_eq!(scraped.title.unwrap().origin, MetadataOrigin::Content);
+
+        // Agreeing guesses are more certain than either.
+        let scraped = scrape_metadata_from(
+            "三体.txt".to_string(),
+            Some("《三体》\n文/刘慈欣\n".to_string()),
+        );
+        let title = scraped.title.unwrap();
+        assert_eq!(title.origin, MetadataOrigin::Both);
+        assert!(title.confidence > 0.85);
+        assert_eq!(scraped.author.unwrap().value, "刘慈欣");
+    }
+}
@@ -0,0 +1,1335 @@
+//! MOBI and AZW3 (KF8) import.
+//!
+//! Kindle books are Palm database files: record 0 carries the PalmDOC, MOBI
+//! and EXTH headers, followed by the compressed text records and then the
+//! images. Legacy MOBI text is one HTML stream that is split into chapters
+//! at `mbp:pagebreak`; KF8 text is reassembled from its skeleton and
+//! fragment indexes, one chapter per skeleton file. Joint files carry both
+//! versions of the book, and the KF8 one is preferred.
+//!
+//! Image sources are rewritten to `images/<n>` hrefs, where `n` is the
+//! 1-based image number `extract_mobi_resource` accepts. DRM-protected books
+//! are rejected.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+
+use anyhow::{anyhow, Result};
+use regex::bytes::{Captures, Regex};
+
+use crate::api::book::{self, BookChapter, BookMetadata, ParsedBook, TocEntry};
+use crate::api::drm::{self, DrmScheme};
+use crate::api::error::ApiError;
+use crate::api::task;
+use crate::xhtml;
+
+/// Record index fields use this value for "not present".
+const NO_INDEX: u32 = 0xFFFF_FFFF;
+
+const COMPRESSION_NONE: u16 = 1;
+const COMPRESSION_PALMDOC: u16 = 2;
+const COMPRESSION_HUFF_CDIC: u16 = 17480;
+
+const ENCODING_UTF8: u32 = 65001;
+
+const EXTH_AUTHOR: u32 = 100;
+const EXTH_PUBLISHER: u32 = 101;
+const EXTH_DESCRIPTION: u32 = 103;
+const EXTH_ISBN: u32 = 104;
+const EXTH_ASIN: u32 = 113;
+const EXTH_KF8_BOUNDARY: u32 = 121;
+const EXTH_COVER_OFFSET: u32 = 201;
+const EXTH_UPDATED_TITLE: u32 = 503;
+const EXTH_LANGUAGE: u32 = 524;
+
+const IMAGE_HREF_PREFIX: &str = "images/";
+
+/// Parse a local MOBI, AZW or DRM-free AZW3 file into chapters and a table
+/// of contents.
+///
+/// # Arguments
+/// * `path` - Path to the Kindle book
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+#[flutter_rust_bridge::frb]
+pub async fn parse_mobi(path: String, cancel_token: Option<u32>) -> Result<ParsedBook, ApiError> {
+    task::run_blocking(task::cancel_token(cancel_token)?, move |_| {
+        parse(&read_file(&path)?)
+    })
+    .await
+}
+
+/// Extract an image referenced by parsed chapter HTML.
+///
+/// # Arguments
+/// * `path` - Path to the Kindle book
+/// * `href` - Image href from chapter HTML, e.g. `images/3`
+#[flutter_rust_bridge::frb]
+pub fn extract_mobi_resource(path: String, href: String) -> Result<Vec<u8>, ApiError> {
+    Ok(extract_resource(&read_file(&path)?, &href)?)
+}
+
+pub(crate) fn read_file(path: &str) -> Result<Vec<u8>> {
+    fs::read(path).map_err(|e| anyhow!("Failed to open {path}: {e}"))
+}
+
+pub(crate) fn parse(data: &[u8]) -> Result<ParsedBook> {
+    let records = pdb_records(data)?;
+    let header = book_header(&records)?;
+    let text = read_text(&records, &header)?;
+    let ncx = read_ncx(&records, &header)?;
+
+    let (mut chapters, toc) = if header.skeleton_index != NO_INDEX {
+        kf8_chapters(&records, &header, &text, &ncx)?
+    } else {
+        mobi7_chapters(&header, &text, &ncx)
+    };
+    if chapters.is_empty() {
+        return Err(anyhow!("MOBI file contains no readable text"));
+    }
+    book::title_chapters(&toc, &mut chapters);
+
+    Ok(ParsedBook {
+        metadata: metadata(data, &header),
+        chapters,
+        toc,
+    })
+}
+
+pub(crate) fn extract_resource(data: &[u8], href: &str) -> Result<Vec<u8>> {
+    let records = pdb_records(data)?;
+    let header = book_header(&records)?;
+    let number = href
+        .strip_prefix(IMAGE_HREF_PREFIX)
+        .and_then(|n| n.parse::<usize>().ok())
+        .filter(|&n| n > 0)
+        .ok_or_else(|| anyhow!("Unknown MOBI resource {href}"))?;
+    if header.first_image == NO_INDEX {
+        return Err(anyhow!("MOBI file contains no images"));
+    }
+    records
+        .get(header.base + header.first_image as usize + number - 1)
+        .map(|record| record.to_vec())
+        .ok_or_else(|| anyhow!("Unknown MOBI resource {href}"))
+}
+
+fn be_u16(data: &[u8], offset: usize) -> Option<u16> {
+    let bytes = data.get(offset..offset + 2)?;
+    Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+fn be_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// Split a Palm database into its records.
+fn pdb_records(data: &[u8]) -> Result<Vec<&[u8]>> {
+    let kind = data
+        .get(60..68)
+        .ok_or_else(|| anyhow!("Truncated MOBI file"))?;
+    if kind != b"BOOKMOBI" && kind != b"TEXtREAd" {
+        return Err(anyhow!("Not a MOBI file"));
+    }
+    let count = be_u16(data, 76).ok_or_else(|| anyhow!("Truncated MOBI file"))? as usize;
+    let offsets = (0..count)
+        .map(|i| be_u32(data, 78 + i * 8).map(|offset| offset as usize))
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| anyhow!("Truncated MOBI record list"))?;
+
+    let mut records = Vec::with_capacity(count);
+    for (i, &start) in offsets.iter().enumerate() {
+        let end = offsets.get(i + 1).copied().unwrap_or(data.len());
+        let record = data
+            .get(start..end)
+            .ok_or_else(|| anyhow!("Invalid MOBI record {i}"))?;
+        records.push(record);
+    }
+    Ok(records)
+}
+
+/// The parts of record 0 (or the KF8 header record) the importer needs.
+struct Header<'a> {
+    /// Index of the header record; other record indexes are relative to it.
+    base: usize,
+    compression: u16,
+    text_length: usize,
+    text_record_count: usize,
+    encoding: u32,
+    full_name: Option<String>,
+    first_image: u32,
+    huff_record: u32,
+    huff_count: u32,
+    extra_flags: u16,
+    fdst_index: u32,
+    ncx_index: u32,
+    fragment_index: u32,
+    skeleton_index: u32,
+    exth: Vec<(u32, &'a [u8])>,
+}
+
+impl<'a> Header<'a> {
+    fn exth_values(&self, kind: u32) -> impl Iterator<Item = &'a [u8]> + '_ {
+        self.exth
+            .iter()
+            .filter(move |(k, _)| *k == kind)
+            .map(|(_, value)| *value)
+    }
+
+    fn exth_string(&self, kind: u32) -> Option<String> {
+        self.exth_values(kind)
+            .map(|value| decode(value, self.encoding))
+            .find(|value| !value.trim().is_empty())
+            .map(|value| value.trim().to_string())
+    }
+
+    fn exth_u32(&self, kind: u32) -> Option<u32> {
+        self.exth_values(kind).find_map(|value| be_u32(value, 0))
+    }
+}
+
+/// Read the header to import from: the KF8 one for joint files, which
+/// follows a `BOUNDARY` record.
+fn book_header<'a>(records: &[&'a [u8]]) -> Result<Header<'a>> {
+    let header = read_header(records, 0)?;
+    match header.exth_u32(EXTH_KF8_BOUNDARY) {
+        Some(kf8) if kf8 > 0 && records.get(kf8 as usize - 1) == Some(&&b"BOUNDARY"[..]) => {
+            read_header(records, kf8 as usize)
+        }
+        _ => Ok(header),
+    }
+}
+
+fn read_header<'a>(records: &[&'a [u8]], base: usize) -> Result<Header<'a>> {
+    let record = *records
+        .get(base)
+        .ok_or_else(|| anyhow!("Missing MOBI header record"))?;
+    let truncated = || anyhow!("Truncated MOBI header");
+    if be_u16(record, 12).ok_or_else(truncated)? != 0 {
+        return Err(drm::protected_error(DrmScheme::Kindle));
+    }
+
+    let mut header = Header {
+        base,
+        compression: be_u16(record, 0).ok_or_else(truncated)?,
+        text_length: be_u32(record, 4).ok_or_else(truncated)? as usize,
+        text_record_count: be_u16(record, 8).ok_or_else(truncated)? as usize,
+        encoding: 1252,
+        full_name: None,
+        first_image: NO_INDEX,
+        huff_record: NO_INDEX,
+        huff_count: 0,
+        extra_flags: 0,
+        fdst_index: NO_INDEX,
+        ncx_index: NO_INDEX,
+        fragment_index: NO_INDEX,
+        skeleton_index: NO_INDEX,
+        exth: Vec::new(),
+    };
+    // Plain PalmDOC files stop here.
+    if record.get(16..20) != Some(b"MOBI") {
+        return Ok(header);
+    }
+
+    let header_end = 16 + be_u32(record, 20).ok_or_else(truncated)? as usize;
+    let field = |offset: usize| {
+        if offset + 4 <= header_end {
+            be_u32(record, offset).unwrap_or(NO_INDEX)
+        } else {
+            NO_INDEX
+        }
+    };
+    header.encoding = field(0x1C);
+    header.first_image = field(0x6C);
+    header.huff_record = field(0x70);
+    header.huff_count = field(0x74);
+    header.ncx_index = field(0xF4);
+    if field(0x24) >= 8 {
+        header.fdst_index = field(0xC0);
+        header.fragment_index = field(0xF8);
+        header.skeleton_index = field(0xFC);
+    }
+    if header_end >= 0xF4 {
+        header.extra_flags = be_u16(record, 0xF2).unwrap_or(0);
+    }
+
+    let name_offset = field(0x54) as usize;
+    let name_length = field(0x58) as usize;
+    header.full_name = record
+        .get(name_offset..name_offset.saturating_add(name_length))
+        .map(|name| decode(name, header.encoding))
+        .filter(|name| !name.trim().is_empty());
+
+    if field(0x80) & 0x40 != 0 && field(0x80) != NO_INDEX {
+        header.exth = read_exth(record.get(header_end..).unwrap_or_default());
+    }
+    Ok(header)
+}
+
+fn read_exth(data: &[u8]) -> Vec<(u32, &[u8])> {
+    let mut entries = Vec::new();
+    if !data.starts_with(b"EXTH") {
+        return entries;
+    }
+    let count = be_u32(data, 8).unwrap_or(0);
+    let mut offset = 12;
+    for _ in 0..count {
+        let (Some(kind), Some(length)) = (be_u32(data, offset), be_u32(data, offset + 4)) else {
+            break;
+        };
+        let length = length as usize;
+        let Some(value) = data.get(offset + 8..offset + length.max(8)) else {
+            break;
+        };
+        entries.push((kind, value));
+        offset += length.max(8);
+    }
+    entries
+}
+
+fn metadata(data: &[u8], header: &Header) -> BookMetadata {
+    let mut metadata = BookMetadata::empty();
+    metadata.title = header
+        .exth_string(EXTH_UPDATED_TITLE)
+        .or_else(|| header.full_name.clone())
+        .or_else(|| {
+            let name = &data[..32.min(data.len())];
+            let name = name.split(|&b| b == 0).next().unwrap_or_default();
+            Some(decode(name, header.encoding)).filter(|name| !name.is_empty())
+        });
+    metadata.authors = header
+        .exth_values(EXTH_AUTHOR)
+        .map(|value| decode(value, header.encoding).trim().to_string())
+        .filter(|author| !author.is_empty())
+        .collect();
+    metadata.language = header.exth_string(EXTH_LANGUAGE);
+    metadata.identifier = header
+        .exth_string(EXTH_ISBN)
+        .or_else(|| header.exth_string(EXTH_ASIN));
+    metadata.publisher = header.exth_string(EXTH_PUBLISHER);
+    metadata.description = header.exth_string(EXTH_DESCRIPTION);
+    metadata.cover_href = header
+        .exth_u32(EXTH_COVER_OFFSET)
+        .filter(|&offset| offset != NO_INDEX)
+        .map(|offset| format!("{IMAGE_HREF_PREFIX}{}", offset + 1));
+    metadata
+}
+
+/// Decode MOBI text: UTF-8 or, for older books, Windows-1252.
+fn decode(data: &[u8], encoding: u32) -> String {
+    if encoding == ENCODING_UTF8 {
+        return String::from_utf8_lossy(data).into_owned();
+    }
+    const CP1252_HIGH: [char; 32] = [
+        '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8d}', 'Ž',
+        '\u{8f}', '\u{90}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{9d}',
+        'ž', 'Ÿ',
+    ];
+    data.iter()
+        .map(|&b| match b {
+            0x80..=0x9F => CP1252_HIGH[(b - 0x80) as usize],
+            _ => b as char,
+        })
+        .collect()
+}
+
+/// Decompress and concatenate the text records.
+fn read_text(records: &[&[u8]], header: &Header) -> Result<Vec<u8>> {
+    let mut huff = match header.compression {
+        COMPRESSION_NONE | COMPRESSION_PALMDOC => None,
+        COMPRESSION_HUFF_CDIC => {
+            let start = header.base + header.huff_record as usize;
+            let tables = records
+                .get(start..start + header.huff_count as usize)
+                .filter(|tables| !tables.is_empty())
+                .ok_or_else(|| anyhow!("Missing HUFF/CDIC records"))?;
+            Some(HuffCdic::new(tables)?)
+        }
+        other => return Err(anyhow!("Unsupported MOBI compression {other}")),
+    };
+
+    let mut text = Vec::with_capacity(header.text_length);
+    for i in 1..=header.text_record_count {
+        let record = records
+            .get(header.base + i)
+            .ok_or_else(|| anyhow!("Missing MOBI text record {i}"))?;
+        let record = strip_trailing_entries(record, header.extra_flags);
+        match huff.as_mut() {
+            Some(huff) => text.extend(huff.decompress(record)?),
+            None if header.compression == COMPRESSION_PALMDOC => {
+                text.extend(palmdoc_decompress(record))
+            }
+            None => text.extend_from_slice(record),
+        }
+    }
+    text.truncate(header.text_length);
+    Ok(text)
+}
+
+/// Remove the trailing entries the extra-data flags say each text record
+/// carries after its compressed data.
+fn strip_trailing_entries(record: &[u8], flags: u16) -> &[u8] {
+    let mut end = record.len();
+    for bit in 1..16 {
+        if flags & (1 << bit) == 0 {
+            continue;
+        }
+        // Entry sizes are stored backwards at the very end of the entry.
+        let mut size = 0usize;
+        let mut shift = 0;
+        let mut pos = end;
+        while pos > 0 {
+            pos -= 1;
+            let byte = record[pos];
+            size |= ((byte & 0x7F) as usize) << shift;
+            shift += 7;
+            if byte & 0x80 != 0 || shift >= 28 {
+                break;
+            }
+        }
+        end = end.saturating_sub(size);
+    }
+    if flags & 1 != 0 && end > 0 {
+        // Multibyte character overlap: the low two bits count extra bytes.
+        end = end.saturating_sub((record[end - 1] & 0x3) as usize + 1);
+    }
+    &record[..end]
+}
+
+/// PalmDOC LZ77 decompression.
+fn palmdoc_decompress(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len() * 2);
+    let mut i = 0;
+    while i < data.len() {
+        let byte = data[i];
+        i += 1;
+        match byte {
+            1..=8 => {
+                let end = (i + byte as usize).min(data.len());
+                out.extend_from_slice(&data[i..end]);
+                i = end;
+            }
+            0x80..=0xBF => {
+                let Some(&next) = data.get(i) else {
+                    break;
+                };
+                i += 1;
+                let pair = u16::from_be_bytes([byte, next]);
+                let distance = ((pair >> 3) & 0x7FF) as usize;
+                let length = (pair & 0x7) as usize + 3;
+                if distance == 0 || distance > out.len() {
+                    continue;
+                }
+                for _ in 0..length {
+                    out.push(out[out.len() - distance]);
+                }
+            }
+            0xC0..=0xFF => {
+                out.push(b' ');
+                out.push(byte ^ 0x80);
+            }
+            _ => out.push(byte),
+        }
+    }
+    out
+}
+
+/// Decoder for MOBI's HUFF/CDIC dictionary compression.
+struct HuffCdic {
+    /// Code length, terminal flag and max code per leading byte.
+    dict1: Vec<(u32, bool, u64)>,
+    min_codes: [u64; 33],
+    max_codes: [u64; 33],
+    /// Phrases and whether they are already decompressed; `None` while a
+    /// phrase is being expanded.
+    phrases: Vec<Option<(Vec<u8>, bool)>>,
+}
+
+impl HuffCdic {
+    fn new(records: &[&[u8]]) -> Result<Self> {
+        let huff = records[0];
+        if !huff.starts_with(b"HUFF\0\0\0\x18") {
+            return Err(anyhow!("Invalid HUFF record"));
+        }
+        let invalid = || anyhow!("Invalid HUFF record");
+        let off1 = be_u32(huff, 8).ok_or_else(invalid)? as usize;
+        let off2 = be_u32(huff, 12).ok_or_else(invalid)? as usize;
+
+        let mut dict1 = Vec::with_capacity(256);
+        for i in 0..256 {
+            let value = be_u32(huff, off1 + i * 4).ok_or_else(invalid)?;
+            let code_len = value & 0x1F;
+            if code_len == 0 {
+                return Err(invalid());
+            }
+            let max_code = (((value >> 8) as u64 + 1) << (32 - code_len)) - 1;
+            dict1.push((code_len, value & 0x80 != 0, max_code));
+        }
+
+        let mut min_codes = [0u64; 33];
+        let mut max_codes = [u32::MAX as u64; 33];
+        for code_len in 1..=32usize {
+            let offset = off2 + (code_len - 1) * 8;
+            let min = be_u32(huff, offset).ok_or_else(invalid)? as u64;
+            let max = be_u32(huff, offset + 4).ok_or_else(invalid)? as u64;
+            min_codes[code_len] = min << (32 - code_len);
+            max_codes[code_len] = ((max + 1) << (32 - code_len)) - 1;
+        }
+
+        let mut phrases = Vec::new();
+        for cdic in &records[1..] {
+            if !cdic.starts_with(b"CDIC\0\0\0\x10") {
+                return Err(anyhow!("Invalid CDIC record"));
+            }
+            let invalid = || anyhow!("Invalid CDIC record");
+            let total = be_u32(cdic, 8).ok_or_else(invalid)? as usize;
+            let bits = be_u32(cdic, 12).ok_or_else(invalid)?.min(31);
+            let count = (1usize << bits).min(total.saturating_sub(phrases.len()));
+            for i in 0..count {
+                let offset = be_u16(cdic, 16 + i * 2).ok_or_else(invalid)? as usize;
+                let length = be_u16(cdic, 16 + offset).ok_or_else(invalid)?;
+                let start = 18 + offset;
+                let phrase = cdic
+                    .get(start..start + (length & 0x7FFF) as usize)
+                    .ok_or_else(invalid)?;
+                phrases.push(Some((phrase.to_vec(), length & 0x8000 != 0)));
+            }
+        }
+
+        Ok(Self {
+            dict1,
+            min_codes,
+            max_codes,
+            phrases,
+        })
+    }
+
+    fn decompress(&mut self, data: &[u8]) -> Result<Vec<u8>> {
+        let mut padded = data.to_vec();
+        padded.extend_from_slice(&[0; 8]);
+        let window = |pos: usize| u64::from_be_bytes(padded[pos..pos + 8].try_into().unwrap());
+
+        let mut out = Vec::new();
+        let mut bits_left = data.len() as i64 * 8;
+        let mut pos = 0;
+        let mut x = window(pos);
+        let mut n: i32 = 32;
+        loop {
+            if n <= 0 {
+                pos += 4;
+                x = window(pos);
+                n += 32;
+            }
+            let code = (x >> n) & 0xFFFF_FFFF;
+            let (mut code_len, terminal, mut max_code) = self.dict1[(code >> 24) as usize];
+            if !terminal {
+                while code < self.min_codes[code_len as usize] {
+                    code_len += 1;
+                    if code_len > 32 {
+                        return Err(anyhow!("Corrupt HUFF/CDIC data"));
+                    }
+                }
+                max_code = self.max_codes[code_len as usize];
+            }
+            n -= code_len as i32;
+            bits_left -= code_len as i64;
+            if bits_left < 0 {
+                break;
+            }
+
+            let index = (max_code.wrapping_sub(code) >> (32 - code_len)) as usize;
+            let slot = self
+                .phrases
+                .get_mut(index)
+                .ok_or_else(|| anyhow!("Corrupt HUFF/CDIC data"))?;
+            let (phrase, decoded) = slot
+                .take()
+                .ok_or_else(|| anyhow!("Recursive HUFF/CDIC phrase"))?;
+            let phrase = if decoded {
+                phrase
+            } else {
+                self.decompress(&phrase)?
+            };
+            out.extend_from_slice(&phrase);
+            self.phrases[index] = Some((phrase, true));
+        }
+        Ok(out)
+    }
+}
+
+/// An entry of a MOBI index (`INDX`) table.
+struct IndexEntry {
+    name: Vec<u8>,
+    tags: HashMap<u8, Vec<u32>>,
+}
+
+impl IndexEntry {
+    fn tag(&self, tag: u8, i: usize) -> Option<u32> {
+        self.tags
+            .get(&tag)
+            .and_then(|values| values.get(i))
+            .copied()
+    }
+}
+
+struct Index {
+    entries: Vec<IndexEntry>,
+    /// Strings from the CNCX records, keyed by offset.
+    strings: HashMap<u32, Vec<u8>>,
+}
+
+/// Forward variable-width integer: 7 bits per byte, high bit marks the end.
+fn read_varint(data: &[u8], offset: &mut usize) -> Option<u32> {
+    let mut value = 0u32;
+    loop {
+        let byte = *data.get(*offset)?;
+        *offset += 1;
+        value = (value << 7) | (byte & 0x7F) as u32;
+        if byte & 0x80 != 0 {
+            return Some(value);
+        }
+    }
+}
+
+fn read_index(records: &[&[u8]], base: usize, index: u32) -> Result<Index> {
+    let invalid = || anyhow!("Invalid MOBI index");
+    let start = base + index as usize;
+    let header = *records.get(start).ok_or_else(invalid)?;
+    if !header.starts_with(b"INDX") {
+        return Err(invalid());
+    }
+    let header_len = be_u32(header, 4).ok_or_else(invalid)? as usize;
+    let record_count = be_u32(header, 24).ok_or_else(invalid)? as usize;
+    let cncx_count = be_u32(header, 52).ok_or_else(invalid)? as usize;
+
+    // TAGX: tag, values per entry, mask, end-of-control-byte flag.
+    let tagx = header.get(header_len..).ok_or_else(invalid)?;
+    if !tagx.starts_with(b"TAGX") {
+        return Err(invalid());
+    }
+    let tagx_len = be_u32(tagx, 4).ok_or_else(invalid)? as usize;
+    let control_bytes = be_u32(tagx, 8).ok_or_else(invalid)? as usize;
+    let tag_table: Vec<[u8; 4]> = tagx
+        .get(12..tagx_len)
+        .ok_or_else(invalid)?
+        .chunks_exact(4)
+        .map(|tag| [tag[0], tag[1], tag[2], tag[3]])
+        .collect();
+
+    let mut strings = HashMap::new();
+    for i in 0..cncx_count {
+        let cncx = *records
+            .get(start + record_count + 1 + i)
+            .ok_or_else(invalid)?;
+        let mut offset = 0;
+        while offset < cncx.len() && cncx[offset] != 0 {
+            let key = offset as u32 + (i as u32) * 0x10000;
+            let Some(len) = read_varint(cncx, &mut offset) else {
+                break;
+            };
+            let value = cncx.get(offset..offset + len as usize).unwrap_or_default();
+            strings.insert(key, value.to_vec());
+            offset += len as usize;
+        }
+    }
+
+    let mut entries = Vec::new();
+    for i in 0..record_count {
+        let record = *records.get(start + 1 + i).ok_or_else(invalid)?;
+        if !record.starts_with(b"INDX") {
+            return Err(invalid());
+        }
+        let idxt = be_u32(record, 20).ok_or_else(invalid)? as usize;
+        let count = be_u32(record, 24).ok_or_else(invalid)? as usize;
+        let mut positions = (0..count)
+            .map(|j| be_u16(record, idxt + 4 + j * 2).map(|pos| pos as usize))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(invalid)?;
+        positions.push(idxt);
+
+        for window in positions.windows(2) {
+            let entry = record.get(window[0]..window[1]).ok_or_else(invalid)?;
+            let name_len = *entry.first().ok_or_else(invalid)? as usize;
+            let name = entry.get(1..1 + name_len).ok_or_else(invalid)?.to_vec();
+            let tags =
+                read_tags(&entry[1 + name_len..], control_bytes, &tag_table).ok_or_else(invalid)?;
+            entries.push(IndexEntry { name, tags });
+        }
+    }
+    Ok(Index { entries, strings })
+}
+
+fn read_tags(
+    data: &[u8],
+    control_bytes: usize,
+    tag_table: &[[u8; 4]],
+) -> Option<HashMap<u8, Vec<u32>>> {
+    // Value count (or byte length) per tag, from the control bytes.
+    let mut counts = Vec::new();
+    let mut control_index = 0;
+    let mut offset = control_bytes;
+    for &[tag, values_per_entry, mask, end_flag] in tag_table {
+        if end_flag == 1 {
+            control_index += 1;
+            continue;
+        }
+        let value = data.get(control_index)? & mask;
+        if value == 0 {
+            continue;
+        }
+        if value == mask && mask.count_ones() > 1 {
+            let byte_len = read_varint(data, &mut offset)?;
+            counts.push((tag, values_per_entry, None, Some(byte_len as usize)));
+        } else {
+            counts.push((
+                tag,
+                values_per_entry,
+                Some(value >> mask.trailing_zeros()),
+                None,
+            ));
+        }
+    }
+
+    let mut tags = HashMap::new();
+    for (tag, values_per_entry, count, byte_len) in counts {
+        let mut values = Vec::new();
+        if let Some(count) = count {
+            for _ in 0..count as usize * values_per_entry as usize {
+                values.push(read_varint(data, &mut offset)?);
+            }
+        } else if let Some(byte_len) = byte_len {
+            let end = offset + byte_len;
+            while offset < end {
+                values.push(read_varint(data, &mut offset)?);
+            }
+        }
+        tags.insert(tag, values);
+    }
+    Some(tags)
+}
+
+/// A table of contents entry from the NCX index.
+struct NcxEntry {
+    label: String,
+    depth: u32,
+    /// Byte position in the text (legacy MOBI).
+    pos: Option<u32>,
+    /// Fragment id the entry points into (KF8).
+    fragment: Option<u32>,
+}
+
+fn read_ncx(records: &[&[u8]], header: &Header) -> Result<Vec<NcxEntry>> {
+    if header.ncx_index == NO_INDEX {
+        return Ok(Vec::new());
+    }
+    let index = read_index(records, header.base, header.ncx_index)?;
+    let entries = &index.entries;
+
+    // Entries are grouped by level; walk parent/child links to restore
+    // document order.
+    let mut order = Vec::with_capacity(entries.len());
+    let mut seen = HashSet::new();
+    fn visit(i: usize, entries: &[IndexEntry], order: &mut Vec<usize>, seen: &mut HashSet<usize>) {
+        if i >= entries.len() || !seen.insert(i) {
+            return;
+        }
+        order.push(i);
+        if let (Some(first), Some(last)) = (entries[i].tag(22, 0), entries[i].tag(23, 0)) {
+            for child in first..=last {
+                visit(child as usize, entries, order, seen);
+            }
+        }
+    }
+    for i in 0..entries.len() {
+        if entries[i].tag(21, 0).is_none() {
+            visit(i, entries, &mut order, &mut seen);
+        }
+    }
+
+    Ok(order
+        .into_iter()
+        .filter_map(|i| {
+            let entry = &entries[i];
+            let label = index.strings.get(&entry.tag(3, 0)?)?;
+            Some(NcxEntry {
+                label: xhtml::normalize_whitespace(&decode(label, header.encoding)),
+                depth: entry.tag(4, 0).unwrap_or(0),
+                pos: entry.tag(1, 0),
+                fragment: entry.tag(6, 0),
+            })
+        })
+        .filter(|entry| !entry.label.is_empty())
+        .collect())
+}
+
+/// Clean the raw HTML of each part, dropping parts left empty, and return
+/// the chapters with a map from part number to chapter index.
+fn build_chapters(parts: Vec<Vec<u8>>, encoding: u32) -> (Vec<BookChapter>, Vec<Option<u32>>) {
+    let mut chapters = Vec::new();
+    let mut chapter_of_part = Vec::with_capacity(parts.len());
+    for (part, raw) in parts.into_iter().enumerate() {
+        let href = book::part_href(part);
+        let clean = xhtml::clean(&decode(&raw, encoding), &href);
+        if clean.html.is_empty() {
+            chapter_of_part.push(None);
+            continue;
+        }
+        chapter_of_part.push(Some(chapters.len() as u32));
+        chapters.push(BookChapter::new(
+            chapters.len() as u32,
+            href,
+            clean.heading,
+            clean.html,
+        ));
+    }
+    (chapters, chapter_of_part)
+}
+
+/// Chapter showing `part`: the part itself or, if it was empty, the next
+/// non-empty one.
+fn chapter_for_part(chapter_of_part: &[Option<u32>], part: usize) -> Option<u32> {
+    chapter_of_part
+        .get(part..)?
+        .iter()
+        .flatten()
+        .next()
+        .copied()
+}
+
+/// Split legacy MOBI text at page breaks, turning `filepos` links into
+/// anchors and `recindex` images into image hrefs.
+fn mobi7_chapters(
+    header: &Header,
+    text: &[u8],
+    ncx: &[NcxEntry],
+) -> (Vec<BookChapter>, Vec<TocEntry>) {
+    let pagebreak = Regex::new(r"(?i)<mbp:pagebreak[^>]*>").unwrap();
+    let filepos = Regex::new(r#"(?i)(\s)filepos=["']?0*(\d+)["']?"#).unwrap();
+    let recindex = Regex::new(r#"(?i)(\s)recindex=["']?0*(\d+)["']?"#).unwrap();
+
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    for found in pagebreak.find_iter(text) {
+        ranges.push(start..found.start());
+        start = found.end();
+    }
+    ranges.push(start..text.len());
+    let part_of = |pos: usize| ranges.partition_point(|range| range.start <= pos).max(1) - 1;
+
+    let mut targets: Vec<usize> = filepos
+        .captures_iter(text)
+        .filter_map(|caps| std::str::from_utf8(&caps[2]).ok()?.parse().ok())
+        .chain(
+            ncx.iter()
+                .filter_map(|entry| entry.pos.map(|pos| pos as usize)),
+        )
+        .collect();
+    targets.sort_unstable();
+    targets.dedup();
+
+    let parts = ranges
+        .iter()
+        .map(|range| {
+            let part = insert_anchors(text, range.clone(), &targets);
+            let part = filepos.replace_all(&part, |caps: &Captures| {
+                let pos: usize = std::str::from_utf8(&caps[2])
+                    .ok()
+                    .and_then(|pos| pos.parse().ok())
+                    .unwrap_or(0);
+                let href = book::part_href(part_of(pos));
+                format!("{}href=\"{href}#filepos{pos}\"", ascii(&caps[1])).into_bytes()
+            });
+            recindex
+                .replace_all(&part, |caps: &Captures| {
+                    let number = ascii(&caps[2]).parse::<u32>().unwrap_or(0);
+                    format!("{}src=\"{IMAGE_HREF_PREFIX}{number}\"", ascii(&caps[1])).into_bytes()
+                })
+                .into_owned()
+        })
+        .collect();
+
+    let (chapters, chapter_of_part) = build_chapters(parts, header.encoding);
+    let toc = ncx
+        .iter()
+        .filter_map(|entry| {
+            let pos = entry.pos? as usize;
+            Some(TocEntry {
+                title: entry.label.clone(),
+                href: format!("{}#filepos{pos}", book::part_href(part_of(pos))),
+                chapter_index: chapter_for_part(&chapter_of_part, part_of(pos)),
+                depth: entry.depth,
+            })
+        })
+        .collect();
+    (chapters, toc)
+}
+
+fn ascii(bytes: &[u8]) -> &str {
+    std::str::from_utf8(bytes).unwrap_or_default()
+}
+
+/// Copy `text[range]`, inserting an `a id="filepos<n>"` anchor at each
+/// target position. Targets that fall inside a tag move to just before it.
+fn insert_anchors(text: &[u8], range: std::ops::Range<usize>, targets: &[usize]) -> Vec<u8> {
+    let part = &text[range.clone()];
+    let first = targets.partition_point(|&pos| pos < range.start);
+    let last = targets.partition_point(|&pos| pos < range.end);
+
+    let mut out = Vec::with_capacity(part.len() + (last - first) * 24);
+    let mut copied = 0;
+    for &target in &targets[first..last] {
+        let mut at = target - range.start;
+        let open = part[..at].iter().rposition(|&b| b == b'<');
+        let close = part[..at].iter().rposition(|&b| b == b'>');
+        if let Some(open) = open {
+            if close.is_none_or(|close| close < open) {
+                at = open;
+            }
+        }
+        let at = at.max(copied);
+        out.extend_from_slice(&part[copied..at]);
+        out.extend_from_slice(format!("<a id=\"filepos{target}\"></a>").as_bytes());
+        copied = at;
+    }
+    out.extend_from_slice(&part[copied..]);
+    out
+}
+
+/// Reassemble KF8 files from the skeleton and fragment indexes.
+fn kf8_chapters(
+    records: &[&[u8]],
+    header: &Header,
+    text: &[u8],
+    ncx: &[NcxEntry],
+) -> Result<(Vec<BookChapter>, Vec<TocEntry>)> {
+    let corrupt = || anyhow!("Corrupt KF8 skeleton index");
+
+    // Flow 0 is the HTML; later flows hold CSS and SVG.
+    let mut flow_end = text.len();
+    if header.fdst_index != NO_INDEX {
+        if let Some(fdst) = records.get(header.base + header.fdst_index as usize) {
+            if fdst.starts_with(b"FDST") && be_u32(fdst, 8).unwrap_or(0) > 0 {
+                flow_end = (be_u32(fdst, 16).unwrap_or(0) as usize).min(text.len());
+            }
+        }
+    }
+    let flow = &text[..flow_end];
+
+    let skeletons = read_index(records, header.base, header.skeleton_index)?;
+    let fragments = if header.fragment_index != NO_INDEX {
+        read_index(records, header.base, header.fragment_index)?.entries
+    } else {
+        Vec::new()
+    };
+
+    let mut parts = Vec::with_capacity(skeletons.entries.len());
+    let mut next_fragment = 0;
+    for skeleton in &skeletons.entries {
+        let count = skeleton.tag(1, 0).ok_or_else(corrupt)? as usize;
+        let start = skeleton.tag(6, 0).ok_or_else(corrupt)? as usize;
+        let length = skeleton.tag(6, 1).ok_or_else(corrupt)? as usize;
+        let mut part = flow
+            .get(start..start + length)
+            .ok_or_else(corrupt)?
+            .to_vec();
+        let mut cursor = start + length;
+
+        for fragment in fragments.iter().skip(next_fragment).take(count) {
+            let insert_at: usize = ascii(&fragment.name)
+                .trim()
+                .parse()
+                .map_err(|_| corrupt())?;
+            let length = fragment.tag(6, 1).ok_or_else(corrupt)? as usize;
+            let content = flow.get(cursor..cursor + length).ok_or_else(corrupt)?;
+            let at = insert_at.saturating_sub(start).min(part.len());
+            part.splice(at..at, content.iter().copied());
+            cursor += length;
+        }
+        next_fragment += count;
+        parts.push(part);
+    }
+
+    let embed = Regex::new(r"kindle:embed:([0-9A-Va-v]{4})(\?[^'\x22)\s]*)?").unwrap();
+    let position = Regex::new(r"kindle:pos:fid:([0-9A-Va-v]{4}):off:[0-9A-Va-v]{10}").unwrap();
+    let file_of_fragment =
+        |fid: u32| -> Option<usize> { fragments.get(fid as usize)?.tag(3, 0).map(|f| f as usize) };
+    let parts = parts
+        .into_iter()
+        .map(|part| {
+            let part = embed.replace_all(&part, |caps: &Captures| {
+                format!("{IMAGE_HREF_PREFIX}{}", base32(&caps[1])).into_bytes()
+            });
+            position
+                .replace_all(&part, |caps: &Captures| {
+                    file_of_fragment(base32(&caps[1]))
+                        .map(book::part_href)
+                        .unwrap_or_default()
+                        .into_bytes()
+                })
+                .into_owned()
+        })
+        .collect();
+
+    let (chapters, chapter_of_part) = build_chapters(parts, header.encoding);
+    let toc = ncx
+        .iter()
+        .filter_map(|entry| {
+            let part = file_of_fragment(entry.fragment?)?;
+            Some(TocEntry {
+                title: entry.label.clone(),
+                href: book::part_href(part),
+                chapter_index: chapter_for_part(&chapter_of_part, part),
+                depth: entry.depth,
+            })
+        })
+        .collect();
+    Ok((chapters, toc))
+}
+
+/// Kindle's base-32 digits `0-9A-V`.
+fn base32(digits: &[u8]) -> u32 {
+    digits.iter().fold(0, |value, &digit| {
+        value * 32 + (digit as char).to_digit(32).unwrap_or(0)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn varint(value: u32) -> Vec<u8> {
+        let mut bytes = vec![(value & 0x7F) as u8 | 0x80];
+        let mut value = value >> 7;
+        while value > 0 {
+            bytes.insert(0, (value & 0x7F) as u8);
+            value >>= 7;
+        }
+        bytes
+    }
+
+    fn pdb(records: &[Vec<u8>]) -> Vec<u8> {
+        let mut data = vec![0u8; 78];
+        data[..4].copy_from_slice(b"Test");
+        data[60..68].copy_from_slice(b"BOOKMOBI");
+        data[76..78].copy_from_slice(&(records.len() as u16).to_be_bytes());
+        let mut offset = 78 + records.len() * 8 + 2;
+        for record in records {
+            data.extend_from_slice(&(offset as u32).to_be_bytes());
+            data.extend_from_slice(&[0; 4]);
+            offset += record.len();
+        }
+        data.extend_from_slice(&[0; 2]);
+        for record in records {
+            data.extend_from_slice(record);
+        }
+        data
+    }
+
+    /// Record 0 with a MOBI header of `header_len` bytes; `fields` are
+    /// u32 values at record offsets.
+    fn header_record(
+        text: &[u8],
+        text_records: u16,
+        version: u32,
+        fields: &[(usize, u32)],
+        exth: &[(u32, &[u8])],
+    ) -> Vec<u8> {
+        let header_len = 0x108 - 16;
+        let mut record = vec![0u8; 16 + header_len];
+        record[0..2].copy_from_slice(&COMPRESSION_NONE.to_be_bytes());
+        record[4..8].copy_from_slice(&(text.len() as u32).to_be_bytes());
+        record[8..10].copy_from_slice(&text_records.to_be_bytes());
+        record[16..20].copy_from_slice(b"MOBI");
+        record[20..24].copy_from_slice(&(header_len as u32).to_be_bytes());
+        let mut set = |offset: usize, value: u32| {
+            record[offset..offset + 4].copy_from_slice(&value.to_be_bytes());
+        };
+        set(0x1C, ENCODING_UTF8);
+        set(0x24, version);
+        for offset in [0x6C, 0x70, 0xC0, 0xF4, 0xF8, 0xFC] {
+            set(offset, NO_INDEX);
+        }
+        set(0x80, 0x40);
+        for &(offset, value) in fields {
+            set(offset, value);
+        }
+
+        record.extend_from_slice(b"EXTH");
+        let body: Vec<u8> = exth
+            .iter()
+            .flat_map(|(kind, value)| {
+                let mut entry = kind.to_be_bytes().to_vec();
+                entry.extend_from_slice(&(value.len() as u32 + 8).to_be_bytes());
+                entry.extend_from_slice(value);
+                entry
+            })
+            .collect();
+        record.extend_from_slice(&(body.len() as u32 + 12).to_be_bytes());
+        record.extend_from_slice(&(exth.len() as u32).to_be_bytes());
+        record.extend_from_slice(&body);
+        record
+    }
+
+    type TagValues<'a> = &'a [(u8, &'a [u32])];
+
+    /// INDX header + one data record (+ CNCX) with a single control byte
+    /// and single-bit masks.
+    fn index(
+        tags: &[(u8, u8, u8)],
+        entries: &[(&[u8], TagValues)],
+        cncx: Option<&[u8]>,
+    ) -> Vec<Vec<u8>> {
+        let indx = |idxt: u32, count: u32, cncx_count: u32| {
+            let mut record = vec![0u8; 0xC0];
+            record[..4].copy_from_slice(b"INDX");
+            record[4..8].copy_from_slice(&0xC0u32.to_be_bytes());
+            record[20..24].copy_from_slice(&idxt.to_be_bytes());
+            record[24..28].copy_from_slice(&count.to_be_bytes());
+            record[52..56].copy_from_slice(&cncx_count.to_be_bytes());
+            record
+        };
+
+        let mut header = indx(0, 1, cncx.is_some() as u32);
+        header.extend_from_slice(b"TAGX");
+        header.extend_from_slice(&(12 + 4 * (tags.len() as u32 + 1)).to_be_bytes());
+        header.extend_from_slice(&1u32.to_be_bytes());
+        for &(tag, per_entry, mask) in tags {
+            header.extend_from_slice(&[tag, per_entry, mask, 0]);
+        }
+        header.extend_from_slice(&[0, 0, 0, 1]);
+
+        let mut body = Vec::new();
+        let mut positions = Vec::new();
+        for (name, values) in entries {
+            positions.push(0xC0 + body.len() as u16);
+            body.push(name.len() as u8);
+            body.extend_from_slice(name);
+            let control = tags
+                .iter()
+                .filter(|(tag, _, _)| values.iter().any(|(t, _)| t == tag))
+                .fold(0, |control, (_, _, mask)| control | mask);
+            body.push(control);
+            for (tag, _, _) in tags {
+                if let Some((_, values)) = values.iter().find(|(t, _)| t == tag) {
+                    body.extend(values.iter().flat_map(|&v| varint(v)));
+                }
+            }
+        }
+        let mut data = indx(0xC0 + body.len() as u32, entries.len() as u32, 0);
+        data.extend_from_slice(&body);
+        data.extend_from_slice(b"IDXT");
+        data.extend(positions.iter().flat_map(|pos| pos.to_be_bytes()));
+
+        let mut records = vec![header, data];
+        records.extend(cncx.map(<[u8]>::to_vec));
+        records
+    }
+
+    fn cncx(labels: &[&str]) -> (Vec<u8>, Vec<u32>) {
+        let mut data = Vec::new();
+        let mut offsets = Vec::new();
+        for label in labels {
+            offsets.push(data.len() as u32);
+            data.extend(varint(label.len() as u32));
+            data.extend_from_slice(label.as_bytes());
+        }
+        (data, offsets)
+    }
+
+    #[test]
+    fn test_palmdoc_decompress() {
+        // Literal, back-reference (distance 3, length 3), space pair and a
+        // literal run holding a byte that would otherwise be a command.
+        let data = [b'a', b'b', b'c', 0x80, 0x18, 0xC1, 0x02, 0xE9, b'!'];
+        assert_eq!(palmdoc_decompress(&data), b"abcabc A\xE9!");
+    }
+
+    #[test]
+    fn test_strip_trailing_entries() {
+        // One size-prefixed trailing entry (3 bytes) after a multibyte
+        // overlap byte that says one extra byte follows the text.
+        let record = [b'h', b'i', b'x', 0x01, 0xAA, 0xBB, 0x83];
+        assert_eq!(strip_trailing_entries(&record, 0b11), b"hi");
+        assert_eq!(strip_trailing_entries(&record, 0), &record);
+    }
+
+    #[test]
+    fn test_huff_cdic_decompress() {
+        // Every code is 8 bits long and terminal; byte `b` selects phrase
+        // `255 - b`.
+        let mut huff = b"HUFF\0\0\0\x18".to_vec();
+        huff.extend_from_slice(&24u32.to_be_bytes());
+        huff.extend_from_slice(&(24u32 + 1024).to_be_bytes());
+        huff.extend_from_slice(&[0; 8]);
+        for _ in 0..256 {
+            huff.extend_from_slice(&((255u32 << 8) | 0x80 | 8).to_be_bytes());
+        }
+        huff.extend_from_slice(&[0; 64 * 4]);
+
+        let phrases: Vec<&[u8]> = vec![b"Hello", b", ", b"world"];
+        let mut cdic = b"CDIC\0\0\0\x10".to_vec();
+        cdic.extend_from_slice(&256u32.to_be_bytes());
+        cdic.extend_from_slice(&8u32.to_be_bytes());
+        let mut table = Vec::new();
+        let mut entries = Vec::new();
+        for i in 0..256 {
+            table.extend_from_slice(&((512 + entries.len()) as u16).to_be_bytes());
+            let phrase = phrases.get(i).copied().unwrap_or(b"");
+            entries.extend_from_slice(&(phrase.len() as u16 | 0x8000).to_be_bytes());
+            entries.extend_from_slice(phrase);
+        }
+        cdic.extend_from_slice(&table);
+        cdic.extend_from_slice(&entries);
+
+        let mut decoder = HuffCdic::new(&[&huff, &cdic]).unwrap();
+        assert_eq!(
+            decoder.decompress(&[255, 254, 253]).unwrap(),
+            b"Hello, world"
+        );
+    }
+
+    #[test]
+    fn test_parse_mobi7_splits_at_page_breaks() {
+        let text = concat!(
+            "<html><head><guide></guide></head><body>",
+            "<h1>Contents</h1><p><a filepos=0000000108>Go</a></p>",
+            "<mbp:pagebreak/>",
+            "<h2>Chapter One</h2><p>Text</p><img recindex=\"00002\">",
+            "</body></html>"
+        )
+        .as_bytes();
+        let target = text.windows(4).position(|w| w == b"<h2>").unwrap();
+        assert_eq!(target, 108);
+        let record0 = header_record(
+            text,
+            1,
+            6,
+            &[(0x6C, 2)],
+            &[
+                (EXTH_AUTHOR, "刘慈欣".as_bytes()),
+                (EXTH_UPDATED_TITLE, "三体".as_bytes()),
+                (EXTH_COVER_OFFSET, &0u32.to_be_bytes()),
+            ],
+        );
+        let data = pdb(&[record0, text.to_vec(), b"cover".to_vec(), b"image".to_vec()]);
+
+        let book = parse(&data).unwrap();
+        assert_eq!(book.metadata.title.as_deref(), Some("三体"));
+        assert_eq!(book.metadata.authors, vec!["刘慈欣"]);
+        assert_eq!(book.metadata.cover_href.as_deref(), Some("images/1"));
+        assert_eq!(book.chapters.len(), 2);
+        assert_eq!(
+            book.chapters[0].html,
+            "<h1>Contents</h1><p><a href=\"part0001.html#filepos108\">Go</a></p>"
+        );
+        assert_eq!(book.chapters[1].title.as_deref(), Some("Chapter One"));
+        assert_eq!(
+            book.chapters[1].html,
+            "<a id=\"filepos108\"></a><h2>Chapter One</h2><p>Text</p><img src=\"images/2\">"
+        );
+        assert_eq!(extract_resource(&data, "images/2").unwrap(), b"image");
+        assert!(extract_resource(&data, "images/0").is_err());
+    }
+
+    #[test]
+    fn test_parse_kf8_reassembles_fragments() {
+        let skeleton = b"<html><body></body></html>";
+        let fragment1 = b"<h1>One</h1><img src=\"kindle:embed:0001?mime=image/jpeg\"/>";
+        let fragment2 = b"<p><a href=\"kindle:pos:fid:0000:off:0000000000\">back</a></p>";
+        let text = [&skeleton[..], fragment1, skeleton, fragment2].concat();
+        let second = skeleton.len() + fragment1.len();
+
+        let skel = index(
+            &[(1, 1, 0x01), (6, 2, 0x02)],
+            &[
+                (
+                    b"SKEL0000000",
+                    &[(1, &[1]), (6, &[0, skeleton.len() as u32])],
+                ),
+                (
+                    b"SKEL0000001",
+                    &[(1, &[1]), (6, &[second as u32, skeleton.len() as u32])],
+                ),
+            ],
+            None,
+        );
+        let insert1 = format!("{:010}", 12);
+        let insert2 = format!("{:010}", second + 12);
+        let frag = index(
+            &[(3, 1, 0x01), (6, 2, 0x02)],
+            &[
+                (
+                    insert1.as_bytes(),
+                    &[(3, &[0]), (6, &[12, fragment1.len() as u32])],
+                ),
+                (
+                    insert2.as_bytes(),
+                    &[(3, &[1]), (6, &[12, fragment2.len() as u32])],
+                ),
+            ],
+            None,
+        );
+        let (labels, offsets) = cncx(&["第一章", "第二章"]);
+        let ncx = index(
+            &[(3, 1, 0x01), (4, 1, 0x02), (6, 2, 0x04)],
+            &[
+                (b"0", &[(3, &[offsets[0]]), (4, &[0]), (6, &[0, 0])]),
+                (b"1", &[(3, &[offsets[1]]), (4, &[0]), (6, &[1, 0])]),
+            ],
+            Some(&labels),
+        );
+
+        // Records: header, text, 2 skeleton, 2 fragment, 3 NCX, image.
+        let record0 = header_record(
+            &text,
+            1,
+            8,
+            &[(0x6C, 9), (0xFC, 2), (0xF8, 4), (0xF4, 6)],
+            &[],
+        );
+        let mut records = vec![record0, text.clone()];
+        records.extend(skel);
+        records.extend(frag);
+        records.extend(ncx);
+        records.push(b"jpeg".to_vec());
+        let data = pdb(&records);
+
+        let book = parse(&data).unwrap();
+        assert_eq!(book.metadata.title.as_deref(), Some("Test"));
+        let chapters: Vec<_> = book
+            .chapters
+            .iter()
+            .map(|c| (c.href.as_str(), c.title.as_deref(), c.html.as_str()))
+            .collect();
+        assert_eq!(
+            chapters,
+            vec![
+                (
+                    "part0000.html",
+                    Some("第一章"),
+                    "<h1>One</h1><img src=\"images/1\">"
+                ),
+                (
+                    "part0001.html",
+                    Some("第二章"),
+                    "<p><a href=\"part0000.html\">back</a></p>"
+                ),
+            ]
+        );
+        let toc: Vec<_> = book
+            .toc
+            .iter()
+            .map(|e| (e.title.as_str(), e.chapter_index))
+            .collect();
+        assert_eq!(toc, vec![("第一章", Some(0)), ("第二章", Some(1))]);
+        assert_eq!(extract_resource(&data, "images/1").unwrap(), b"jpeg");
+    }
+
+    #[test]
+    fn test_parse_rejects_drm() {
+        let mut record0 = header_record(b"x", 1, 6, &[], &[]);
+        record0[12..14].copy_from_slice(&2u16.to_be_bytes());
+        let data = pdb(&[record0, b"x".to_vec()]);
+        assert!(parse(&data).is_err());
+        assert!(parse(b"not a mobi").is_err());
+    }
+}
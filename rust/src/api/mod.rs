@@ -1,3 +1,191 @@
+pub mod anchors;
+pub mod archive;
+pub mod audio_cache;
+pub mod backup;
+pub mod bench;
+pub mod bionic;
+pub mod blobs;
+pub mod book;
+pub mod book_image;
+pub mod book_source;
+pub mod calibre;
+pub mod chapter_cache;
+pub mod chapter_diff;
+pub mod chapter_sort;
+pub mod chapter_store;
+pub mod cloudctl;
+pub mod comic;
+pub mod cookies;
+pub mod cover;
+pub mod dark_image;
+pub mod db;
+pub mod dedup;
+pub mod dict;
+pub mod downloader;
+pub mod drm;
+pub mod encoding;
+pub mod epub;
+pub mod epub_export;
+pub mod epub_tools;
+pub mod error;
+pub mod extraction;
+pub mod fb2;
+pub mod feed;
+pub mod fingerprint;
+pub mod font_bundle;
+pub mod font_cache;
+pub mod font_collection;
 pub mod font_converter;
+pub mod font_preview;
+pub mod font_subset;
+pub mod font_validation;
+pub mod http_cache;
+pub mod hyphenation;
+pub mod image;
+pub mod import;
+pub mod keywords;
+pub mod langdetect;
+pub mod layout_cache;
+pub mod library_search;
+pub mod line_break;
+pub mod logging;
+pub mod mapped_txt;
+pub mod metadata;
+pub mod mobi;
+pub mod network;
+pub mod notes_export;
+pub mod opds;
+pub mod opencc;
+pub mod pagination;
+pub mod palette;
+pub mod pdf;
+pub mod placeholder;
+pub mod prefetch;
+pub mod punctuation;
+pub mod purify;
+pub mod rate_limit;
+pub mod read_chapters;
+pub mod readability;
+pub mod resplit;
+pub mod ruby;
+pub mod rule_test;
+pub mod sanitize;
+pub mod search;
+pub mod segment;
+pub mod sessions;
+pub mod source_switch;
+pub mod stats;
+pub mod summary;
+pub mod sync;
+pub mod task;
+pub mod translate;
+pub mod tts;
+pub mod tts_prep;
+pub mod tts_timeline;
+pub mod txt;
+pub mod txt_detect;
+pub mod typography;
+pub mod umd;
+pub mod update_check;
+pub mod vault;
+pub mod vertical;
+pub mod watermark;
+pub mod web_import;
+pub mod webdav;
+pub mod webserver;
 
+pub use self::image::*;
+pub use anchors::*;
+pub use archive::*;
+pub use audio_cache::*;
+pub use backup::*;
+pub use bench::*;
+pub use bionic::*;
+pub use blobs::*;
+pub use book::*;
+pub use book_image::*;
+pub use book_source::*;
+pub use calibre::*;
+pub use chapter_cache::*;
+pub use chapter_diff::*;
+pub use chapter_sort::*;
+pub use chapter_store::*;
+pub use cloudctl::*;
+pub use comic::*;
+pub use cookies::*;
+pub use cover::*;
+pub use dark_image::*;
+pub use db::*;
+pub use dedup::*;
+pub use dict::*;
+pub use downloader::*;
+pub use drm::*;
+pub use encoding::*;
+pub use epub::*;
+pub use epub_export::*;
+pub use epub_tools::*;
+pub use error::*;
+pub use extraction::*;
+pub use fb2::*;
+pub use feed::*;
+pub use fingerprint::*;
+pub use font_bundle::*;
+pub use font_cache::*;
+pub use font_collection::*;
 pub use font_converter::*;
+pub use font_preview::*;
+pub use font_subset::*;
+pub use font_validation::*;
+pub use http_cache::*;
+pub use hyphenation::*;
+pub use import::*;
+pub use keywords::*;
+pub use langdetect::*;
+pub use layout_cache::*;
+pub use library_search::*;
+pub use line_break::*;
+pub use logging::*;
+pub use mapped_txt::*;
+pub use metadata::*;
+pub use mobi::*;
+pub use network::*;
+pub use notes_export::*;
+pub use opds::*;
+pub use opencc::*;
+pub use pagination::*;
+pub use palette::*;
+pub use pdf::*;
+pub use placeholder::*;
+pub use prefetch::*;
+pub use punctuation::*;
+pub use purify::*;
+pub use rate_limit::*;
+pub use read_chapters::*;
+pub use readability::*;
+pub use resplit::*;
+pub use ruby::*;
+pub use rule_test::*;
+pub use sanitize::*;
+pub use search::*;
+pub use segment::*;
+pub use sessions::*;
+pub use source_switch::*;
+pub use stats::*;
+pub use summary::*;
+pub use sync::*;
+pub use task::*;
+pub use translate::*;
+pub use tts::*;
+pub use tts_prep::*;
+pub use tts_timeline::*;
+pub use txt::*;
+pub use txt_detect::*;
+pub use typography::*;
+pub use umd::*;
+pub use update_check::*;
+pub use vault::*;
+pub use vertical::*;
+pub use watermark::*;
+pub use web_import::*;
+pub use webdav::*;
+pub use webserver::*;
@@ -0,0 +1,280 @@
+//! Settings shared by every network request the crate makes.
+//!
+//! The downloader, the HTTP cache, the OPDS client and WebDAV sync all get
+//! their HTTP agents from `agent`, which applies the proxy configured for
+//! the request's host, the DNS-over-HTTPS resolver if one is set, the
+//! per-host rate limits and the cookie jar once it is initialized. Agents are reused while the settings stay the same,
+//! so connections are pooled across requests and changing a setting takes
+//! effect for the next request.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use url::Url;
+
+use crate::api::error::ApiError;
+use crate::api::{cookies, rate_limit};
+use crate::dns::DohResolver;
+
+/// User agent for requests that do not set their own.
+pub(crate) const USER_AGENT: &str = concat!("Novella/", env!("CARGO_PKG_VERSION"));
+
+static NETWORK: Mutex<Option<Network>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProxyKind {
+    /// An HTTP proxy; HTTPS requests are tunnelled through it with CONNECT.
+    Http,
+    Socks5,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProxyConfig {
+    pub kind: ProxyKind,
+    pub host: String,
+    pub port: u16,
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+/// Route requests through `proxy`, or connect directly when it is `None`.
+/// Hosts with their own setting from `set_host_proxy` are not affected.
+#[flutter_rust_bridge::frb]
+pub fn set_proxy(proxy: Option<ProxyConfig>) -> Result<(), ApiError> {
+    if let Some(proxy) = &proxy {
+        ureq_proxy(proxy)?;
+    }
+    let mut network = lock();
+    let network = network.get_or_insert_with(Network::default);
+    network.proxy = proxy;
+    network.agents.clear();
+    Ok(())
+}
+
+/// Override the proxy for one book source.
+///
+/// # Arguments
+/// * `host` - Host name, e.g. `www.example.com`; subdomains are not included
+/// * `proxy` - Proxy for this host, or `None` to connect to it directly
+#[flutter_rust_bridge::frb]
+pub fn set_host_proxy(host: String, proxy: Option<ProxyConfig>) -> Result<(), ApiError> {
+    if let Some(proxy) = &proxy {
+        ureq_proxy(proxy)?;
+    }
+    let mut network = lock();
+    let network = network.get_or_insert_with(Network::default);
+    network
+        .host_proxies
+        .insert(host.to_ascii_lowercase(), proxy);
+    network.agents.clear();
+    Ok(())
+}
+
+/// Remove a host's proxy override, so it uses the global proxy again.
+#[flutter_rust_bridge::frb]
+pub fn clear_host_proxy(host: String) {
+    if let Some(network) = lock().as_mut() {
+        network.host_proxies.remove(&host.to_ascii_lowercase());
+        network.agents.clear();
+    }
+}
+
+/// Resolve host names with DNS over HTTPS, falling back to the system
+/// resolver when the endpoint fails.
+///
+/// # Arguments
+/// * `endpoint` - RFC 8484 endpoint such as `https://cloudflare-dns.com/dns-query`
+///   or `https://dns.alidns.com/dns-query`, or `None` to use the system resolver
+#[flutter_rust_bridge::frb]
+pub fn set_dns_over_https(endpoint: Option<String>) -> Result<(), ApiError> {
+    let resolver = match endpoint {
+        Some(endpoint) => {
+            let url =
+                Url::parse(&endpoint).map_err(|e| anyhow!("Invalid DoH URL {endpoint}: {e}"))?;
+            if url.scheme() != "https" || url.host_str().is_none() {
+                return Err(anyhow!("DoH endpoint must be an https URL: {endpoint}").into());
+            }
+            Some(Arc::new(DohResolver::new(url)))
+        }
+        None => None,
+    };
+    let mut network = lock();
+    let network = network.get_or_insert_with(Network::default);
+    network.resolver = resolver;
+    network.agents.clear();
+    Ok(())
+}
+
+/// Forget the addresses cached by the DNS-over-HTTPS resolver.
+#[flutter_rust_bridge::frb]
+pub fn clear_dns_cache() {
+    if let Some(resolver) = lock().as_ref().and_then(|n| n.resolver.as_ref()) {
+        resolver.clear();
+    }
+}
+
+/// An agent for requests to `url`, using the proxy configured for its host
+/// and the DoH resolver.
+// The cookie middleware closure has the signature of `ureq::Middleware`.
+#[allow(clippy::result_large_err)]
+pub(crate) fn agent(
+    url: &str,
+    connect_timeout: Duration,
+    read_timeout: Duration,
+    user_agent: Option<&str>,
+) -> Result<ureq::Agent> {
+    let url = Url::parse(url).map_err(|e| anyhow!("Invalid URL {url}: {e}"))?;
+    let mut network = lock();
+    let network = network.get_or_insert_with(Network::default);
+    // The endpoint's own name has to come from the system resolver.
+    let resolver = network
+        .resolver
+        .clone()
+        .filter(|resolver| resolver.endpoint().host_str() != url.host_str());
+    let key = AgentKey {
+        proxy: network.proxy_for(url.host_str()),
+        doh: resolver.is_some(),
+        cookies: cookies::enabled(),
+        connect_timeout,
+        read_timeout,
+        user_agent: user_agent.unwrap_or(USER_AGENT).to_string(),
+    };
+    if let Some(agent) = network.agents.get(&key) {
+        return Ok(agent.clone());
+    }
+    let mut builder = ureq::AgentBuilder::new()
+        .timeout_connect(connect_timeout)
+        .timeout_read(read_timeout)
+        .user_agent(&key.user_agent)
+        .middleware(rate_limit::limit);
+    if let Some(proxy) = &key.proxy {
+        builder = builder.proxy(ureq_proxy(proxy)?);
+    }
+    if let Some(resolver) = resolver {
+        builder = builder.resolver(move |netloc: &str| resolver.resolve(netloc));
+    }
+    if key.cookies {
+        // The jar follows redirects so it sees every response.
+        let user_agent = user_agent.map(str::to_string);
+        builder = builder.redirects(0).middleware(
+            move |request: ureq::Request, next: ureq::MiddlewareNext| {
+                cookies::handle(request, next, &|url: &str| {
+                    agent(url, connect_timeout, read_timeout, user_agent.as_deref())
+                })
+            },
+        );
+    }
+    let agent = builder.build();
+    network.agents.insert(key, agent.clone());
+    Ok(agent)
+}
+
+fn lock() -> MutexGuard<'static, Option<Network>> {
+    NETWORK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+struct Network {
+    proxy: Option<ProxyConfig>,
+    /// Per-host overrides; `None` means connect directly.
+    host_proxies: HashMap<String, Option<ProxyConfig>>,
+    resolver: Option<Arc<DohResolver>>,
+    agents: HashMap<AgentKey, ureq::Agent>,
+}
+
+impl Network {
+    fn proxy_for(&self, host: Option<&str>) -> Option<ProxyConfig> {
+        let host = host.map(str::to_ascii_lowercase);
+        match host.and_then(|host| self.host_proxies.get(&host)) {
+            Some(proxy) => proxy.clone(),
+            None => self.proxy.clone(),
+        }
+    }
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(PartialEq, Eq, Hash)]
+struct AgentKey {
+    proxy: Option<ProxyConfig>,
+    doh: bool,
+    cookies: bool,
+    connect_timeout: Duration,
+    read_timeout: Duration,
+    user_agent: String,
+}
+
+fn ureq_proxy(proxy: &ProxyConfig) -> Result<ureq::Proxy> {
+    let scheme = match proxy.kind {
+        ProxyKind::Http => "http",
+        ProxyKind::Socks5 => "socks5",
+    };
+    let credentials = match (&proxy.username, &proxy.password) {
+        (Some(username), password) => {
+            if username.contains([':', '@']) {
+                return Err(anyhow!("Proxy username cannot contain ':' or '@'"));
+            }
+            format!("{username}:{}@", password.as_deref().unwrap_or(""))
+        }
+        (None, _) => String::new(),
+    };
+    if proxy.host.is_empty() || proxy.host.contains(['/', '@']) {
+        return Err(anyhow!("Invalid proxy host: {}", proxy.host));
+    }
+    ureq::Proxy::new(format!(
+        "{scheme}://{credentials}{}:{}",
+        proxy.host, proxy.port
+    ))
+    .map_err(|e| anyhow!("Invalid proxy: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn proxy(kind: ProxyKind, username: Option<&str>) -> ProxyConfig {
+        ProxyConfig {
+            kind,
+            host: "127.0.0.1".to_string(),
+            port: 1080,
+            username: username.map(str::to_string),
+            password: Some("secret".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_validates_proxies() {
+        assert!(ureq_proxy(&proxy(ProxyKind::Http, None)).is_ok());
+        assert!(ureq_proxy(&proxy(ProxyKind::Socks5, Some("user"))).is_ok());
+        assert!(ureq_proxy(&proxy(ProxyKind::Http, Some("a@b"))).is_err());
+        let mut bad = proxy(ProxyKind::Http, None);
+        bad.host = String::new();
+        assert!(ureq_proxy(&bad).is_err());
+    }
+
+    #[test]
+    fn test_host_overrides() {
+        let global = proxy(ProxyKind::Socks5, None);
+        let source = proxy(ProxyKind::Http, Some("user"));
+        let mut network = Network {
+            proxy: Some(global.clone()),
+            ..Network::default()
+        };
+        network
+            .host_proxies
+            .insert("direct.example.com".to_string(), None);
+        network
+            .host_proxies
+            .insert("source.example.com".to_string(), Some(source.clone()));
+
+        assert_eq!(network.proxy_for(Some("Direct.example.com")), None);
+        assert_eq!(network.proxy_for(Some("source.example.com")), Some(source));
+        assert_eq!(
+            network.proxy_for(Some("other.example.com")),
+            Some(global.clone())
+        );
+        assert_eq!(network.proxy_for(None), Some(global));
+    }
+}
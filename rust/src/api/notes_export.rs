@@ -0,0 +1,337 @@
+//! Export a book's highlights and bookmarks as Markdown or JSON.
+//!
+//! Markdown groups the notes under chapter headings in reading order, with
+//! each highlight as a block quote followed by its note and when it was
+//! made, ready to drop into Obsidian or Notion. JSON keeps every field,
+//! with ISO 8601 times and `#AARRGGBB` colours, for other tools.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::{Duration, UNIX_EPOCH};
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+
+use crate::api::db::{self, Bookmark, Highlight, LibraryBook, LibraryChapter};
+use crate::api::epub_export;
+use crate::api::error::ApiError;
+use crate::api::font_converter;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotesFormat {
+    Markdown,
+    Json,
+}
+
+#[derive(Debug, Clone)]
+pub struct NotesExportOptions {
+    pub format: NotesFormat,
+    /// Include bookmarks as well as highlights.
+    pub include_bookmarks: bool,
+    /// Minutes east of UTC to show Markdown times in; JSON is always UTC.
+    pub utc_offset_minutes: i32,
+}
+
+/// Render the notes of a book from the library database.
+///
+/// # Arguments
+/// * `book_id` - Book to export
+/// * `options` - Format, bookmarks and time zone
+#[flutter_rust_bridge::frb]
+pub fn render_book_notes(book_id: String, options: NotesExportOptions) -> Result<String, ApiError> {
+    Ok(render(&book_id, &options)?.0)
+}
+
+/// Write the notes of a book to a file, replacing it. Returns how many
+/// highlights and bookmarks were written.
+///
+/// # Arguments
+/// * `book_id` - Book to export
+/// * `path` - File to write, usually ending in `.md` or `.json`
+/// * `options` - Format, bookmarks and time zone
+#[flutter_rust_bridge::frb]
+pub fn export_book_notes(
+    book_id: String,
+    path: String,
+    options: NotesExportOptions,
+) -> Result<u32, ApiError> {
+    let (text, count) = render(&book_id, &options)?;
+    font_converter::write_atomically(Path::new(&path), text.as_bytes())?;
+    Ok(count)
+}
+
+/// The rendered notes and how many there are.
+fn render(book_id: &str, options: &NotesExportOptions) -> Result<(String, u32)> {
+    let book = db::get_library_book(book_id.to_string())?
+        .ok_or_else(|| anyhow!("No book with ID {book_id}"))?;
+    let chapters = db::query_library_chapters(book_id.to_string(), 0, u32::MAX)?;
+    let highlights = db::query_highlights(Some(book_id.to_string()), 0, u32::MAX)?;
+    let bookmarks = if options.include_bookmarks {
+        db::query_bookmarks(Some(book_id.to_string()), 0, u32::MAX)?
+    } else {
+        Vec::new()
+    };
+    let notes = Notes {
+        book: &book,
+        chapters: &chapters,
+        highlights: &highlights,
+        bookmarks: &bookmarks,
+    };
+    let text = match options.format {
+        NotesFormat::Markdown => notes.markdown(options.utc_offset_minutes),
+        NotesFormat::Json => notes.json(),
+    };
+    Ok((text, (highlights.len() + bookmarks.len()) as u32))
+}
+
+struct Notes<'a> {
+    book: &'a LibraryBook,
+    chapters: &'a [LibraryChapter],
+    highlights: &'a [Highlight],
+    bookmarks: &'a [Bookmark],
+}
+
+/// A highlight or bookmark, for ordering the two together.
+enum Note<'a> {
+    Highlight(&'a Highlight),
+    Bookmark(&'a Bookmark),
+}
+
+impl Note<'_> {
+    fn offset(&self) -> u32 {
+        match self {
+            Note::Highlight(highlight) => highlight.start_offset,
+            Note::Bookmark(bookmark) => bookmark.chapter_offset,
+        }
+    }
+}
+
+impl Notes<'_> {
+    /// Notes grouped by chapter index, each group in reading order.
+    fn by_chapter(&self) -> BTreeMap<u32, Vec<Note<'_>>> {
+        let mut chapters: BTreeMap<u32, Vec<Note>> = BTreeMap::new();
+        for highlight in self.highlights {
+            chapters
+                .entry(highlight.chapter_index)
+                .or_default()
+                .push(Note::Highlight(highlight));
+        }
+        for bookmark in self.bookmarks {
+            chapters
+                .entry(bookmark.chapter_index)
+                .or_default()
+                .push(Note::Bookmark(bookmark));
+        }
+        for notes in chapters.values_mut() {
+            notes.sort_by_key(Note::offset);
+        }
+        chapters
+    }
+
+    fn chapter_title(&self, index: u32) -> String {
+        self.chapters
+            .iter()
+            .find(|chapter| chapter.index == index)
+            .map(|chapter| chapter.title.trim().to_string())
+            .filter(|title| !title.is_empty())
+            .unwrap_or_else(|| format!("Chapter {}", index + 1))
+    }
+
+    fn markdown(&self, utc_offset_minutes: i32) -> String {
+        let time = |secs: i64| {
+            let local = secs.saturating_add(utc_offset_minutes as i64 * 60).max(0) as u64;
+            // `YYYY-MM-DDThh:mm:ssZ` to `YYYY-MM-DD hh:mm`.
+            let iso = epub_export::timestamp(UNIX_EPOCH + Duration::from_secs(local));
+            format!("{} {}", &iso[..10], &iso[11..16])
+        };
+        let mut out = format!("# {}\n", escape(&self.book.title));
+        if let Some(author) = self.book.author.as_deref().filter(|a| !a.is_empty()) {
+            out.push_str(&format!("\n*{}*\n", escape(author)));
+        }
+        for (index, notes) in self.by_chapter() {
+            out.push_str(&format!("\n## {}\n", escape(&self.chapter_title(index))));
+            for note in notes {
+                let (quote, note, label, created_at) = match note {
+                    Note::Highlight(h) => (&h.text, &h.note, "Highlighted", h.created_at),
+                    Note::Bookmark(b) => (&b.excerpt, &b.note, "Bookmarked", b.created_at),
+                };
+                out.push('\n');
+                for line in quote.trim().lines() {
+                    out.push_str(
+                        &format!("> {}\n", escape(line.trim_end())).replace("> \n", ">\n"),
+                    );
+                }
+                if let Some(note) = note.as_deref().map(str::trim).filter(|n| !n.is_empty()) {
+                    out.push_str(&format!("\n{}\n", escape(note)));
+                }
+                out.push_str(&format!("\n*{label} {}*\n", time(created_at)));
+            }
+        }
+        out
+    }
+
+    fn json(&self) -> String {
+        let time = |secs: i64| {
+            epub_export::timestamp(UNIX_EPOCH + Duration::from_secs(secs.max(0) as u64))
+        };
+        let chapters: Vec<Value> = self
+            .by_chapter()
+            .into_iter()
+            .map(|(index, notes)| {
+                let mut highlights = Vec::new();
+                let mut bookmarks = Vec::new();
+                for note in notes {
+                    match note {
+                        Note::Highlight(h) => highlights.push(json!({
+                            "startOffset": h.start_offset,
+                            "endOffset": h.end_offset,
+                            "text": h.text,
+                            "note": h.note,
+                            "color": format!("#{:08X}", h.color),
+                            "createdAt": time(h.created_at),
+                        })),
+                        Note::Bookmark(b) => bookmarks.push(json!({
+                            "offset": b.chapter_offset,
+                            "excerpt": b.excerpt,
+                            "note": b.note,
+                            "createdAt": time(b.created_at),
+                        })),
+                    }
+                }
+                json!({
+                    "index": index,
+                    "title": self.chapter_title(index),
+                    "highlights": highlights,
+                    "bookmarks": bookmarks,
+                })
+            })
+            .collect();
+        let document = json!({
+            "book": {
+                "id": self.book.id,
+                "title": self.book.title,
+                "author": self.book.author,
+            },
+            "chapters": chapters,
+        });
+        serde_json::to_string_pretty(&document).unwrap_or_default()
+    }
+}
+
+/// Backslash-escape characters Markdown would read as formatting.
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|' | '~'
+        ) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn book() -> LibraryBook {
+        LibraryBook {
+            id: "b1".to_string(),
+            title: "雪中*悍刀*行".to_string(),
+            author: Some("烽火戏诸侯".to_string()),
+            intro: None,
+            kind: None,
+            cover_url: None,
+            source_url: None,
+            book_url: None,
+            toc_url: None,
+            latest_chapter_title: None,
+            chapter_count: 2,
+            word_count: None,
+            added_at: 0,
+            updated_at: 0,
+            last_read_at: None,
+        }
+    }
+
+    fn chapter(index: u32, title: &str) -> LibraryChapter {
+        LibraryChapter {
+            index,
+            title: title.to_string(),
+            url: None,
+            is_volume: false,
+            word_count: None,
+        }
+    }
+
+    fn highlight(chapter_index: u32, start: u32, text: &str, note: Option<&str>) -> Highlight {
+        Highlight {
+            id: Some(start as i64),
+            book_id: "b1".to_string(),
+            chapter_index,
+            start_offset: start,
+            end_offset: start + text.chars().count() as u32,
+            text: text.to_string(),
+            note: note.map(str::to_string),
+            color: 0xFFFFEB3B,
+            created_at: 1_760_400_000,
+        }
+    }
+
+    #[test]
+    fn test_markdown_groups_by_chapter() {
+        let chapters = [chapter(0, "第一章 小二上酒"), chapter(1, "第二章")];
+        let highlights = [
+            highlight(1, 40, "后一句", None),
+            highlight(0, 5, "江湖是一张珠帘。\n大人物小人物", Some("好句 #1")),
+            highlight(1, 10, "前一句", None),
+        ];
+        let bookmarks = [Bookmark {
+            id: Some(1),
+            book_id: "b1".to_string(),
+            chapter_index: 1,
+            chapter_offset: 20,
+            excerpt: "书签处".to_string(),
+            note: None,
+            created_at: 1_760_400_000,
+        }];
+        let notes = Notes {
+            book: &book(),
+            chapters: &chapters,
+            highlights: &highlights,
+            bookmarks: &bookmarks,
+        };
+        let expected = "# 雪中\\*悍刀\\*行\n\n*烽火戏诸侯*\n\n\
+                        ## 第一章 小二上酒\n\n> 江湖是一张珠帘。\n> 大人物小人物\n\n好句 \\#1\n\n\
+                        *Highlighted 2025-10-14 08:00*\n\n\
+                        ## 第二章\n\n> 前一句\n\n*Highlighted 2025-10-14 08:00*\n\n\
+                        > 书签处\n\n*Bookmarked 2025-10-14 08:00*\n\n\
+                        > 后一句\n\n*Highlighted 2025-10-14 08:00*\n";
+        assert_eq!(notes.markdown(8 * 60), expected);
+    }
+
+    #[test]
+    fn test_json_keeps_fields() {
+        let highlights = [highlight(3, 7, "一句话", Some("笔记"))];
+        let notes = Notes {
+            book: &book(),
+            chapters: &[],
+            highlights: &highlights,
+            bookmarks: &[],
+        };
+        let value: Value = serde_json::from_str(&notes.json()).unwrap();
+        assert_eq!(value["book"]["title"], "雪中*悍刀*行");
+        let chapter = &value["chapters"][0];
+        assert_eq!(chapter["title"], "Chapter 4");
+        assert_eq!(chapter["highlights"][0]["endOffset"], 10);
+        assert_eq!(chapter["highlights"][0]["color"], "#FFFFEB3B");
+        assert_eq!(
+            chapter["highlights"][0]["createdAt"],
+            "2025-10-14T00:00:00Z"
+        );
+        assert_eq!(chapter["bookmarks"], json!([]));
+    }
+}
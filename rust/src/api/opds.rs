@@ -0,0 +1,629 @@
+//! OPDS catalog client for Calibre-web and other self-hosted libraries.
+//!
+//! OPDS 1.2 feeds are Atom documents; OPDS 2.0 feeds are JSON. Both are
+//! reduced to one feed model holding navigation entries, which open another
+//! feed, and publications with their acquisition links. OPDS 1.2 search
+//! links usually point to an OpenSearch description, which is fetched so
+//! the feed always carries a URL template. All URLs are absolute.
+
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use base64::Engine;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use quick_xml::events::{BytesStart, Event};
+use regex::Regex;
+use serde_json::Value;
+use url::Url;
+
+use crate::api::downloader::{self, DownloadHeader, DownloadRequest};
+use crate::api::error::ApiError;
+use crate::api::http_cache::{self, FetchError};
+use crate::api::network;
+use crate::xhtml;
+
+const ACQUISITION_REL: &str = "http://opds-spec.org/acquisition";
+
+/// Feeds larger than this are rejected rather than parsed.
+const MAX_FEED_BYTES: u64 = 16 * 1024 * 1024;
+
+#[derive(Debug, Clone)]
+pub struct OpdsCredentials {
+    pub username: String,
+    pub password: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct OpdsLink {
+    pub href: String,
+    pub rel: Option<String>,
+    /// MIME type, e.g. `application/epub+zip`.
+    pub media_type: Option<String>,
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct OpdsEntry {
+    pub id: Option<String>,
+    pub title: String,
+    pub authors: Vec<String>,
+    /// Plain-text description.
+    pub summary: Option<String>,
+    pub cover_url: Option<String>,
+    pub thumbnail_url: Option<String>,
+    /// Feed to open when the entry is a navigation entry.
+    pub navigation_url: Option<String>,
+    /// Download links of a publication, in feed order.
+    pub acquisitions: Vec<OpdsLink>,
+}
+
+#[derive(Debug, Clone)]
+pub struct OpdsFeed {
+    pub title: String,
+    pub entries: Vec<OpdsEntry>,
+    pub start_url: Option<String>,
+    pub next_url: Option<String>,
+    pub previous_url: Option<String>,
+    /// Search URL template; expand it with `opds_search_url`.
+    pub search_template: Option<String>,
+}
+
+/// Fetch and parse an OPDS 1.2 or 2.0 feed.
+///
+/// # Arguments
+/// * `url` - Feed URL, e.g. a Calibre-web `/opds` root or a search URL
+/// * `credentials` - HTTP basic auth credentials, if the catalog needs them
+#[flutter_rust_bridge::frb]
+pub fn fetch_opds_feed(
+    url: String,
+    credentials: Option<OpdsCredentials>,
+) -> Result<OpdsFeed, ApiError> {
+    let (body, final_url) = fetch(&url, credentials.as_ref())?;
+    let (mut feed, description) = parse_feed(&body, &final_url)?;
+    if let Some(description) = description {
+        // A catalog without a usable search description is still browsable.
+        feed.search_template = fetch(&description, credentials.as_ref())
+            .ok()
+            .and_then(|(body, base)| parse_search_description(&body, &base));
+    }
+    Ok(feed)
+}
+
+/// Expand a search template with `query`. Both OpenSearch templates
+/// (`{searchTerms}`) and OPDS 2.0 URI templates (`{?query}`) are supported;
+/// other parameters are left empty.
+#[flutter_rust_bridge::frb]
+pub fn opds_search_url(template: String, query: String) -> String {
+    let encoded = utf8_percent_encode(&query, NON_ALPHANUMERIC).to_string();
+    let expression = Regex::new(r"\{([?&]?)([^}]*)\}").unwrap();
+    expression
+        .replace_all(&template, |caps: &regex::Captures| {
+            let names = caps[2].split(',').map(|name| name.trim_end_matches('?'));
+            let mut values = names.filter(|name| matches!(*name, "searchTerms" | "query"));
+            match &caps[1] {
+                "" => values.next().map_or(String::new(), |_| encoded.clone()),
+                operator => values
+                    .enumerate()
+                    .map(|(i, name)| {
+                        let separator = if i == 0 { operator } else { "&" };
+                        format!("{separator}{name}={encoded}")
+                    })
+                    .collect(),
+            }
+        })
+        .into_owned()
+}
+
+/// Queue an acquisition link on the downloader, with the catalog's
+/// credentials, and return the task ID.
+///
+/// # Arguments
+/// * `url` - Acquisition link `href`
+/// * `destination` - File to write
+/// * `credentials` - HTTP basic auth credentials, if the catalog needs them
+#[flutter_rust_bridge::frb]
+pub fn enqueue_opds_download(
+    url: String,
+    destination: String,
+    credentials: Option<OpdsCredentials>,
+) -> Result<u32, ApiError> {
+    let headers = credentials
+        .iter()
+        .map(|credentials| DownloadHeader {
+            name: "Authorization".to_string(),
+            value: authorization(credentials),
+        })
+        .collect();
+    downloader::enqueue_download(DownloadRequest {
+        url,
+        destination,
+        headers,
+        checksum: None,
+    })
+}
+
+fn authorization(credentials: &OpdsCredentials) -> String {
+    let pair = format!("{}:{}", credentials.username, credentials.password);
+    format!(
+        "Basic {}",
+        base64::engine::general_purpose::STANDARD.encode(pair)
+    )
+}
+
+/// Fetch `url` through the HTTP cache and return its body and the URL
+/// after redirects.
+fn fetch(url: &str, credentials: Option<&OpdsCredentials>) -> Result<(String, String)> {
+    let agent = network::agent(url, Duration::from_secs(30), Duration::from_secs(60), None)?;
+    let mut request = agent.get(url).set(
+        "Accept",
+        "application/opds+json, application/atom+xml, application/xml;q=0.9, */*;q=0.5",
+    );
+    if let Some(credentials) = credentials {
+        request = request.set("Authorization", &authorization(credentials));
+    }
+    let response = match http_cache::get(request, MAX_FEED_BYTES) {
+        Ok(response) => response,
+        Err(FetchError::Status(401)) => {
+            return Err(anyhow!(
+                "OPDS catalog requires a valid username and password"
+            ))
+        }
+        Err(FetchError::Status(code)) => return Err(anyhow!("Failed to fetch {url}: HTTP {code}")),
+        Err(FetchError::Other(e)) => return Err(anyhow!("Failed to fetch {url}: {e}")),
+    };
+    Ok((
+        String::from_utf8_lossy(&response.body).into_owned(),
+        response.url,
+    ))
+}
+
+/// Parse a feed of either version. Returns the feed and, for OPDS 1.2, the
+/// OpenSearch description to fetch for the search template.
+fn parse_feed(body: &str, base_url: &str) -> Result<(OpdsFeed, Option<String>)> {
+    let base = Url::parse(base_url).map_err(|e| anyhow!("Invalid URL {base_url}: {e}"))?;
+    let body = body.trim_start_matches('\u{feff}').trim_start();
+    if body.starts_with('{') {
+        let json: Value =
+            serde_json::from_str(body).map_err(|e| anyhow!("Invalid OPDS 2.0 feed: {e}"))?;
+        Ok((parse_json_feed(&json, &base), None))
+    } else {
+        parse_atom_feed(body, &base)
+    }
+}
+
+fn resolve(base: &Url, href: &str) -> String {
+    base.join(href.trim())
+        .map_or_else(|_| href.to_string(), |url| url.to_string())
+}
+
+fn empty_entry() -> OpdsEntry {
+    OpdsEntry {
+        id: None,
+        title: String::new(),
+        authors: Vec::new(),
+        summary: None,
+        cover_url: None,
+        thumbnail_url: None,
+        navigation_url: None,
+        acquisitions: Vec::new(),
+    }
+}
+
+fn empty_feed() -> OpdsFeed {
+    OpdsFeed {
+        title: String::new(),
+        entries: Vec::new(),
+        start_url: None,
+        next_url: None,
+        previous_url: None,
+        search_template: None,
+    }
+}
+
+/// File a link under the feed's pagination and search links. Returns the
+/// OpenSearch description URL for search links that are not templates.
+fn feed_link(feed: &mut OpdsFeed, link: &OpdsLink) -> Option<String> {
+    let slot = match link.rel.as_deref()? {
+        "start" => &mut feed.start_url,
+        "next" => &mut feed.next_url,
+        "previous" | "prev" => &mut feed.previous_url,
+        "search" => {
+            let is_template = link.href.contains('{')
+                && !link
+                    .media_type
+                    .as_deref()
+                    .is_some_and(|t| t.contains("opensearchdescription"));
+            if !is_template {
+                return Some(link.href.clone());
+            }
+            &mut feed.search_template
+        }
+        _ => return None,
+    };
+    if slot.is_none() {
+        *slot = Some(link.href.clone());
+    }
+    None
+}
+
+/// File a link under an entry's navigation, image or acquisition links.
+fn entry_link(entry: &mut OpdsEntry, link: OpdsLink) {
+    let rel = link.rel.as_deref().unwrap_or("");
+    let media_type = link.media_type.as_deref().unwrap_or("");
+    if rel.starts_with(ACQUISITION_REL) {
+        entry.acquisitions.push(link);
+    } else if rel.ends_with("/image/thumbnail") || rel.ends_with("/thumbnail") {
+        entry.thumbnail_url.get_or_insert(link.href);
+    } else if rel.ends_with("/image") || rel.ends_with("/cover") {
+        entry.cover_url.get_or_insert(link.href);
+    } else if media_type.contains("opds-catalog")
+        || media_type.contains("application/atom+xml")
+        || media_type.contains("application/opds+json")
+    {
+        entry.navigation_url.get_or_insert(link.href);
+    }
+}
+
+fn parse_atom_feed(xml: &str, base: &Url) -> Result<(OpdsFeed, Option<String>)> {
+    let mut reader = xhtml::reader(xml);
+    let mut feed = empty_feed();
+    let mut description = None;
+    let mut entry: Option<OpdsEntry> = None;
+    let mut path: Vec<String> = Vec::new();
+    let mut text = String::new();
+    let mut saw_feed = false;
+
+    loop {
+        let event = match reader.read_event() {
+            Ok(Event::Eof) => break,
+            Ok(event) => event,
+            Err(e) => return Err(anyhow!("Invalid OPDS feed: {e}")),
+        };
+        match event {
+            Event::Empty(element) if xhtml::local_name(element.name().as_ref()) == "link" => {
+                let link = atom_link(&element, base);
+                add_atom_link(&mut feed, entry.as_mut(), &mut description, link);
+            }
+            Event::Start(element) => {
+                let name = xhtml::local_name(element.name().as_ref());
+                saw_feed |= name == "feed";
+                if name == "link" {
+                    let link = atom_link(&element, base);
+                    add_atom_link(&mut feed, entry.as_mut(), &mut description, link);
+                }
+                if name == "entry" {
+                    entry = Some(empty_entry());
+                }
+                if matches!(
+                    name.as_str(),
+                    "title" | "id" | "name" | "summary" | "content"
+                ) {
+                    text.clear();
+                }
+                path.push(name);
+            }
+            Event::Text(content) => text.push_str(&xhtml::text(&content)),
+            Event::CData(content) => text.push_str(&String::from_utf8_lossy(&content)),
+            Event::End(_) => {
+                let Some(name) = path.pop() else {
+                    continue;
+                };
+                let parent = path.last().map(String::as_str);
+                let value = || xhtml::normalize_whitespace(&text);
+                match (entry.as_mut(), name.as_str()) {
+                    (Some(_), "entry") => feed.entries.extend(entry.take()),
+                    (Some(entry), "title") if parent == Some("entry") => entry.title = value(),
+                    (Some(entry), "id") if parent == Some("entry") => entry.id = Some(value()),
+                    (Some(entry), "name") if parent == Some("author") => {
+                        entry.authors.push(value())
+                    }
+                    (Some(entry), "summary") => entry.summary = Some(xhtml::strip_tags(&text)),
+                    (Some(entry), "content") => {
+                        entry
+                            .summary
+                            .get_or_insert_with(|| xhtml::strip_tags(&text));
+                    }
+                    (None, "title") if parent == Some("feed") => feed.title = value(),
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+    if !saw_feed {
+        return Err(anyhow!("Invalid OPDS feed: no Atom feed element"));
+    }
+    Ok((feed, description))
+}
+
+fn add_atom_link(
+    feed: &mut OpdsFeed,
+    entry: Option<&mut OpdsEntry>,
+    description: &mut Option<String>,
+    link: OpdsLink,
+) {
+    match entry {
+        Some(entry) => entry_link(entry, link),
+        None => {
+            if let Some(url) = feed_link(feed, &link) {
+                description.get_or_insert(url);
+            }
+        }
+    }
+}
+
+fn atom_link(element: &BytesStart, base: &Url) -> OpdsLink {
+    OpdsLink {
+        href: resolve(base, &xhtml::attribute(element, "href").unwrap_or_default()),
+        rel: xhtml::attribute(element, "rel"),
+        media_type: xhtml::attribute(element, "type"),
+        title: xhtml::attribute(element, "title"),
+    }
+}
+
+/// The Atom URL template from an OpenSearch description document.
+fn parse_search_description(xml: &str, base_url: &str) -> Option<String> {
+    let base = Url::parse(base_url).ok()?;
+    let mut reader = xhtml::reader(xml);
+    let mut fallback = None;
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(element) | Event::Empty(element))
+                if xhtml::local_name(element.name().as_ref()) == "url" =>
+            {
+                let Some(template) = xhtml::attribute(&element, "template") else {
+                    continue;
+                };
+                // Keep `{…}` placeholders intact while resolving.
+                let template = resolve(&base, &template)
+                    .replace("%7B", "{")
+                    .replace("%7D", "}");
+                let media_type = xhtml::attribute(&element, "type").unwrap_or_default();
+                if media_type.contains("atom") || media_type.contains("opds") {
+                    return Some(template);
+                }
+                fallback.get_or_insert(template);
+            }
+            Ok(Event::Eof) | Err(_) => return fallback,
+            _ => {}
+        }
+    }
+}
+
+fn parse_json_feed(json: &Value, base: &Url) -> OpdsFeed {
+    let mut feed = empty_feed();
+    feed.title = str_field(&json["metadata"], "title").unwrap_or_default();
+    for link in json_links(&json["links"], base) {
+        // OPDS 2.0 search links are URI templates already.
+        feed_link(&mut feed, &link);
+    }
+    collect_json_entries(json, base, &mut feed.entries);
+    for group in json["groups"].as_array().into_iter().flatten() {
+        collect_json_entries(group, base, &mut feed.entries);
+    }
+    feed
+}
+
+fn collect_json_entries(collection: &Value, base: &Url, entries: &mut Vec<OpdsEntry>) {
+    for item in collection["navigation"].as_array().into_iter().flatten() {
+        let mut entry = empty_entry();
+        entry.title = str_field(item, "title").unwrap_or_default();
+        entry.navigation_url = str_field(item, "href").map(|href| resolve(base, &href));
+        entries.push(entry);
+    }
+    for publication in collection["publications"].as_array().into_iter().flatten() {
+        let metadata = &publication["metadata"];
+        let mut entry = empty_entry();
+        entry.id = str_field(metadata, "identifier");
+        entry.title = str_field(metadata, "title").unwrap_or_default();
+        entry.summary = str_field(metadata, "description").map(|text| xhtml::strip_tags(&text));
+        entry.authors = contributors(&metadata["author"]);
+        for link in json_links(&publication["links"], base) {
+            entry_link(&mut entry, link);
+        }
+        // Images are listed largest first by convention.
+        let images = json_links(&publication["images"], base);
+        if entry.cover_url.is_none() {
+            entry.cover_url = images.first().map(|image| image.href.clone());
+        }
+        if entry.thumbnail_url.is_none() && images.len() > 1 {
+            entry.thumbnail_url = images.last().map(|image| image.href.clone());
+        }
+        entries.push(entry);
+    }
+}
+
+fn json_links(links: &Value, base: &Url) -> Vec<OpdsLink> {
+    links
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|link| {
+            let href = str_field(link, "href")?;
+            let templated = link["templated"].as_bool().unwrap_or(false);
+            let href = match templated {
+                true => resolve(base, &href).replace("%7B", "{").replace("%7D", "}"),
+                false => resolve(base, &href),
+            };
+            // `rel` may be a string or an array of strings.
+            let rel = match &link["rel"] {
+                Value::String(rel) => Some(rel.clone()),
+                Value::Array(rels) => rels.iter().find_map(|rel| rel.as_str().map(str::to_string)),
+                _ => None,
+            };
+            Some(OpdsLink {
+                href,
+                rel,
+                media_type: str_field(link, "type"),
+                title: str_field(link, "title"),
+            })
+        })
+        .collect()
+}
+
+/// Names from a contributor field: a string, an object with `name`, or an
+/// array of either.
+fn contributors(value: &Value) -> Vec<String> {
+    match value {
+        Value::String(name) => vec![name.clone()],
+        Value::Object(_) => str_field(value, "name").into_iter().collect(),
+        Value::Array(items) => items.iter().flat_map(contributors).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// A string field; language maps like `{"en": "…"}` yield their first value.
+fn str_field(value: &Value, name: &str) -> Option<String> {
+    let text = match &value[name] {
+        Value::String(text) => text.as_str(),
+        Value::Object(map) => map.values().find_map(Value::as_str)?,
+        _ => return None,
+    };
+    Some(text.trim().to_string()).filter(|text| !text.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: &str = "https://books.example.com/opds/";
+
+    const ATOM_FEED: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom" xmlns:opds="http://opds-spec.org/2010/catalog">
+  <id>urn:uuid:root</id>
+  <title>Calibre-web</title>
+  <link rel="start" href="/opds" type="application/atom+xml;profile=opds-catalog;kind=navigation"/>
+  <link rel="search" href="/opds/osd" type="application/opensearchdescription+xml"/>
+  <link rel="next" href="new?offset=30" type="application/atom+xml;profile=opds-catalog"/>
+  <entry>
+    <title>Recently added</title>
+    <id>new</id>
+    <link rel="subsection" href="new" type="application/atom+xml;profile=opds-catalog;kind=acquisition"/>
+  </entry>
+  <entry>
+    <title>三体</title>
+    <id>urn:book:1</id>
+    <author><name>刘慈欣</name></author>
+    <summary type="html">&lt;p&gt;A &lt;b&gt;novel&lt;/b&gt;.&lt;/p&gt;</summary>
+    <link rel="http://opds-spec.org/image" href="/cover/1"/>
+    <link rel="http://opds-spec.org/image/thumbnail" href="/cover/1?thumb=1"/>
+    <link rel="http://opds-spec.org/acquisition" href="/download/1/epub" type="application/epub+zip" title="EPUB"/>
+  </entry>
+</feed>"#;
+
+    const JSON_FEED: &str = r#"{
+  "metadata": {"title": "Library"},
+  "links": [
+    {"rel": "self", "href": "/opds2"},
+    {"rel": ["next"], "href": "/opds2?page=2"},
+    {"rel": "search", "href": "/opds2/search{?query}", "templated": true}
+  ],
+  "navigation": [{"href": "/opds2/new", "title": "New"}],
+  "groups": [{
+    "metadata": {"title": "Popular"},
+    "publications": [{
+      "metadata": {"title": {"en": "Dune"}, "author": [{"name": "Frank Herbert"}], "identifier": "urn:isbn:1"},
+      "links": [{"rel": "http://opds-spec.org/acquisition/open-access", "href": "/get/1.epub", "type": "application/epub+zip"}],
+      "images": [{"href": "/img/1.jpg"}, {"href": "/img/1-small.jpg"}]
+    }]
+  }]
+}"#;
+
+    #[test]
+    fn test_parse_atom_feed() {
+        let (feed, description) = parse_feed(ATOM_FEED, BASE).unwrap();
+        assert_eq!(feed.title, "Calibre-web");
+        assert_eq!(
+            feed.start_url.as_deref(),
+            Some("https://books.example.com/opds")
+        );
+        assert_eq!(
+            feed.next_url.as_deref(),
+            Some("https://books.example.com/opds/new?offset=30")
+        );
+        assert_eq!(
+            description.as_deref(),
+            Some("https://books.example.com/opds/osd")
+        );
+
+        let navigation = &feed.entries[0];
+        assert_eq!(
+            navigation.navigation_url.as_deref(),
+            Some("https://books.example.com/opds/new")
+        );
+        let book = &feed.entries[1];
+        assert_eq!(
+            (book.title.as_str(), book.authors.clone()),
+            ("三体", vec!["刘慈欣".to_string()])
+        );
+        assert_eq!(book.summary.as_deref(), Some("A novel."));
+        assert_eq!(
+            book.cover_url.as_deref(),
+            Some("https://books.example.com/cover/1")
+        );
+        assert_eq!(
+            book.thumbnail_url.as_deref(),
+            Some("https://books.example.com/cover/1?thumb=1")
+        );
+        assert_eq!(book.acquisitions.len(), 1);
+        assert_eq!(
+            book.acquisitions[0].href,
+            "https://books.example.com/download/1/epub"
+        );
+        assert!(book.navigation_url.is_none());
+    }
+
+    #[test]
+    fn test_parse_json_feed() {
+        let (feed, _) = parse_feed(JSON_FEED, BASE).unwrap();
+        assert_eq!(feed.title, "Library");
+        assert_eq!(
+            feed.next_url.as_deref(),
+            Some("https://books.example.com/opds2?page=2")
+        );
+        assert_eq!(
+            feed.search_template.as_deref(),
+            Some("https://books.example.com/opds2/search{?query}")
+        );
+        assert_eq!(feed.entries.len(), 2);
+        let book = &feed.entries[1];
+        assert_eq!(book.title, "Dune");
+        assert_eq!(book.authors, vec!["Frank Herbert".to_string()]);
+        assert_eq!(
+            book.cover_url.as_deref(),
+            Some("https://books.example.com/img/1.jpg")
+        );
+        assert_eq!(
+            book.thumbnail_url.as_deref(),
+            Some("https://books.example.com/img/1-small.jpg")
+        );
+        assert_eq!(
+            book.acquisitions[0].media_type.as_deref(),
+            Some("application/epub+zip")
+        );
+    }
+
+    #[test]
+    fn test_search_templates() {
+        let description = r#"<OpenSearchDescription xmlns="http://a9.com/-/spec/opensearch/1.1/">
+  <Url type="text/html" template="/search?q={searchTerms}"/>
+  <Url type="application/atom+xml" template="/opds/search/{searchTerms}?page={startPage?}"/>
+</OpenSearchDescription>"#;
+        let template = parse_search_description(description, BASE).unwrap();
+        assert_eq!(
+            template,
+            "https://books.example.com/opds/search/{searchTerms}?page={startPage?}"
+        );
+        assert_eq!(
+            opds_search_url(template, "三体 2".to_string()),
+            "https://books.example.com/opds/search/%E4%B8%89%E4%BD%93%202?page="
+        );
+        assert_eq!(
+            opds_search_url(
+                "https://x.org/s{?query,page}".to_string(),
+                "a&b".to_string()
+            ),
+            "https://x.org/s?query=a%26b"
+        );
+    }
+}
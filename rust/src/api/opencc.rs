@@ -0,0 +1,134 @@
+//! Simplified/Traditional Chinese conversion.
+//!
+//! Conversions use OpenCC's dictionaries through `ferrous-opencc`, so
+//! phrases are converted as a whole (e.g. 头发 → 頭髮 rather than 頭發) and
+//! the regional variants apply Taiwan or Hong Kong character forms. Each
+//! converter is built on first use and kept for the life of the process.
+
+use std::sync::OnceLock;
+
+use anyhow::{anyhow, Result};
+use ferrous_opencc::config::BuiltinConfig;
+use ferrous_opencc::OpenCC;
+
+use crate::api::error::ApiError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChineseConversion {
+    /// Simplified to Traditional.
+    S2t,
+    /// Traditional to Simplified.
+    T2s,
+    /// Simplified to Taiwan Traditional characters.
+    S2tw,
+    /// Taiwan Traditional characters to Simplified.
+    Tw2s,
+    /// Simplified to Taiwan Traditional, with Taiwan phrasing
+    /// (e.g. 软件 → 軟體).
+    S2twp,
+    /// Taiwan Traditional with Taiwan phrasing to Simplified.
+    Tw2sp,
+    /// Simplified to Hong Kong Traditional characters.
+    S2hk,
+    /// Hong Kong Traditional characters to Simplified.
+    Hk2s,
+    /// Traditional to Taiwan Traditional characters.
+    T2tw,
+    /// Traditional to Hong Kong Traditional characters.
+    T2hk,
+}
+
+const CONVERSION_COUNT: usize = 10;
+
+static CONVERTERS: [OnceLock<OpenCC>; CONVERSION_COUNT] =
+    [const { OnceLock::new() }; CONVERSION_COUNT];
+
+/// Convert text, e.g. a chapter before rendering.
+#[flutter_rust_bridge::frb]
+pub fn convert_chinese(text: String, conversion: ChineseConversion) -> Result<String, ApiError> {
+    Ok(converter(conversion)?.convert(&text))
+}
+
+/// Convert several texts with the same converter, e.g. every chapter of a
+/// book on export. Results are in input order.
+#[flutter_rust_bridge::frb]
+pub fn convert_chinese_batch(
+    texts: Vec<String>,
+    conversion: ChineseConversion,
+) -> Result<Vec<String>, ApiError> {
+    let converter = converter(conversion)?;
+    Ok(texts.iter().map(|text| converter.convert(text)).collect())
+}
+
+fn converter(conversion: ChineseConversion) -> Result<&'static OpenCC> {
+    let cell = &CONVERTERS[conversion as usize];
+    if let Some(converter) = cell.get() {
+        return Ok(converter);
+    }
+
+    let config = match conversion {
+        ChineseConversion::S2t => BuiltinConfig::S2t,
+        ChineseConversion::T2s => BuiltinConfig::T2s,
+        ChineseConversion::S2tw => BuiltinConfig::S2tw,
+        ChineseConversion::Tw2s => BuiltinConfig::Tw2s,
+        ChineseConversion::S2twp => BuiltinConfig::S2twp,
+        ChineseConversion::Tw2sp => BuiltinConfig::Tw2sp,
+        ChineseConversion::S2hk => BuiltinConfig::S2hk,
+        ChineseConversion::Hk2s => BuiltinConfig::Hk2s,
+        ChineseConversion::T2tw => BuiltinConfig::T2tw,
+        ChineseConversion::T2hk => BuiltinConfig::T2hk,
+    };
+    let converter = OpenCC::from_config(config)
+        .map_err(|e| anyhow!("Failed to load {conversion:?} dictionaries: {e}"))?;
+    // A racing thread may have stored an identical converter first.
+    let _ = cell.set(converter);
+    Ok(cell.get().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(text: &str, conversion: ChineseConversion) -> String {
+        convert_chinese(text.to_string(), conversion).unwrap()
+    }
+
+    #[test]
+    fn test_converts_phrases_both_ways() {
+        assert_eq!(
+            convert("她的头发很长。", ChineseConversion::S2t),
+            "她的頭髮很長。"
+        );
+        assert_eq!(
+            convert("她的頭髮很長。", ChineseConversion::T2s),
+            "她的头发很长。"
+        );
+        assert_eq!(
+            convert("Chapter 1：开始", ChineseConversion::S2t),
+            "Chapter 1：開始"
+        );
+    }
+
+    #[test]
+    fn test_regional_variants() {
+        assert_eq!(
+            convert("软件和内存", ChineseConversion::S2twp),
+            "軟體和記憶體"
+        );
+        assert_eq!(
+            convert("軟體和記憶體", ChineseConversion::Tw2sp),
+            "软件和内存"
+        );
+        assert_eq!(convert("着", ChineseConversion::S2hk), "着");
+        assert_eq!(convert("着", ChineseConversion::S2tw), "著");
+    }
+
+    #[test]
+    fn test_batch_keeps_order() {
+        let texts = vec!["简体".to_string(), String::new(), "中文".to_string()];
+        assert_eq!(
+            convert_chinese_batch(texts, ChineseConversion::S2t).unwrap(),
+            vec!["簡體", "", "中文"]
+        );
+    }
+}
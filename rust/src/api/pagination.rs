@@ -0,0 +1,465 @@
+//! Page layout for plain-text chapters.
+//!
+//! Each paragraph is shaped once with rustybuzz, and lines are filled up to
+//! the last break opportunity that fits: UAX #14 with kinsoku (避头尾)
+//! rules on top, so closing punctuation such as `。` or `」` does not start
+//! a line and opening punctuation such as `「` does not end one.
+//! Soft hyphens (see `hyphenate_text`) are break opportunities too; a
+//! line ending at one is marked so a hyphen can be drawn after it.
+//! Lines are then stacked into pages. All offsets are UTF-16 code units so
+//! Dart can slice the original string directly.
+
+use std::fs;
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use anyhow::{anyhow, Result};
+use rustybuzz::{Face, UnicodeBuffer};
+use unicode_linebreak::BreakOpportunity;
+
+use crate::api::error::ApiError;
+use crate::api::hyphenation::SOFT_HYPHEN;
+use crate::api::layout_cache;
+use crate::api::line_break::{Kinsoku, KinsokuRules};
+
+/// Slack for floating-point rounding when fitting lines and pages.
+pub(crate) const EPSILON: f32 = 0.01;
+
+/// Font data keyed by path and modification time.
+type LoadedFont = (String, SystemTime, Arc<Vec<u8>>);
+
+/// The last paginated font, kept so repeated layouts skip the file read.
+static LOADED_FONT: Mutex<Option<LoadedFont>> = Mutex::new(None);
+
+/// Page geometry and text style, in logical pixels.
+#[derive(Debug, Clone)]
+pub struct PageLayout {
+    pub width: f32,
+    pub height: f32,
+    pub margin_top: f32,
+    pub margin_right: f32,
+    pub margin_bottom: f32,
+    pub margin_left: f32,
+    pub font_size: f32,
+    /// Line height as a multiple of the font size.
+    pub line_height: f32,
+    /// Extra space above a paragraph, except at the top of a page.
+    pub paragraph_spacing: f32,
+    /// First-line indent in ems.
+    pub indent: f32,
+}
+
+/// A laid-out line; `end` excludes trailing whitespace.
+#[derive(Debug, Clone)]
+pub struct TextLine {
+    pub start: u32,
+    pub end: u32,
+    /// Whether this is the first line of a paragraph, and so indented.
+    pub paragraph_start: bool,
+    /// Advance width of the line's text, without the indent.
+    pub width: f32,
+    /// Whether the line ends at a soft hyphen, which should be drawn as a
+    /// hyphen; `width` includes it.
+    pub hyphenated: bool,
+}
+
+/// A page of text. Pages cover the text contiguously: each `end` is the
+/// next page's `start`, and the last one is the text length.
+#[derive(Debug, Clone)]
+pub struct TextPage {
+    pub start: u32,
+    pub end: u32,
+    pub lines: Vec<TextLine>,
+}
+
+/// Split `text` into pages. Paragraphs are separated by newlines; blank
+/// lines are skipped. With `init_layout_cache`, a chapter laid out before
+/// with the same font and settings is returned from the cache.
+///
+/// # Arguments
+/// * `text` - Chapter text
+/// * `font_path` - TTF or OTF file used for measuring; the first face of a
+///   collection
+/// * `layout` - Page size, margins and text style
+/// * `kinsoku` - Characters kept off line edges; `default_kinsoku_rules`
+///   if omitted
+#[flutter_rust_bridge::frb]
+pub fn paginate_text(
+    text: String,
+    font_path: String,
+    layout: PageLayout,
+    kinsoku: Option<KinsokuRules>,
+) -> Result<Vec<TextPage>, ApiError> {
+    let pages =
+        layout_cache::get_or_paginate(&text, &font_path, &layout, kinsoku.as_ref(), || {
+            let data = load_font(&font_path)?;
+            let face = Face::from_slice(&data, 0)
+                .ok_or_else(|| anyhow!("Failed to parse font {font_path}"))?;
+            let kinsoku = match &kinsoku {
+                Some(rules) => Kinsoku::new(rules),
+                None => Kinsoku::standard(),
+            };
+            paginate(&text, &face, &layout, &kinsoku)
+        })?;
+    Ok(pages)
+}
+
+pub(crate) fn load_font(path: &str) -> Result<Arc<Vec<u8>>> {
+    let modified = fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .map_err(|e| anyhow!("Failed to open {path}: {e}"))?;
+    let mut loaded = LOADED_FONT.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some((loaded_path, loaded_modified, data)) = loaded.as_ref() {
+        if loaded_path == path && *loaded_modified == modified {
+            return Ok(data.clone());
+        }
+    }
+
+    let data = Arc::new(fs::read(path).map_err(|e| anyhow!("Failed to read {path}: {e}"))?);
+    *loaded = Some((path.to_string(), modified, data.clone()));
+    Ok(data)
+}
+
+fn paginate(
+    text: &str,
+    face: &Face,
+    layout: &PageLayout,
+    kinsoku: &Kinsoku,
+) -> Result<Vec<TextPage>> {
+    let content_width = layout.width - layout.margin_left - layout.margin_right;
+    let content_height = layout.height - layout.margin_top - layout.margin_bottom;
+    if content_width <= 0.0 || content_height <= 0.0 {
+        return Err(anyhow!("Margins leave no room for text"));
+    }
+    if layout.font_size <= 0.0 || layout.line_height <= 0.0 {
+        return Err(anyhow!("Font size and line height must be positive"));
+    }
+
+    let scale = layout.font_size / face.units_per_em() as f32;
+    let indent = layout.indent * layout.font_size;
+    let line_height = layout.font_size * layout.line_height;
+    let hyphen = prefix_widths("-", face, scale)[1];
+
+    let mut offsets = Utf16Offsets::new(text);
+    let mut pages = Vec::new();
+    let mut page = Vec::new();
+    let mut y = 0.0;
+    let mut paragraph_start = 0;
+    for paragraph in text.split('\n') {
+        let base = paragraph_start;
+        paragraph_start += paragraph.len() + 1;
+        let paragraph = paragraph.strip_suffix('\r').unwrap_or(paragraph);
+        if paragraph.trim().is_empty() {
+            continue;
+        }
+
+        let widths = prefix_widths(paragraph, face, scale);
+        for (index, (start, end)) in
+            break_lines(paragraph, &widths, content_width, indent, hyphen, kinsoku)
+                .into_iter()
+                .enumerate()
+        {
+            let first = index == 0;
+            let mut advance = line_height;
+            if first && !page.is_empty() {
+                advance += layout.paragraph_spacing;
+            }
+            if !page.is_empty() && y + advance > content_height + EPSILON {
+                pages.push(std::mem::take(&mut page));
+                y = 0.0;
+                advance = line_height;
+            }
+            y += advance;
+            let hyphenated = paragraph[..end].ends_with(SOFT_HYPHEN);
+            page.push(TextLine {
+                start: offsets.at(base + start),
+                end: offsets.at(base + end),
+                paragraph_start: first,
+                width: widths[end] - widths[start] + if hyphenated { hyphen } else { 0.0 },
+                hyphenated,
+            });
+        }
+    }
+    if !page.is_empty() {
+        pages.push(page);
+    }
+
+    let text_end = offsets.at(text.len());
+    let starts: Vec<u32> = pages.iter().map(|lines| lines[0].start).collect();
+    Ok(pages
+        .into_iter()
+        .enumerate()
+        .map(|(index, lines)| TextPage {
+            start: if index == 0 { 0 } else { starts[index] },
+            end: starts.get(index + 1).copied().unwrap_or(text_end),
+            lines,
+        })
+        .collect())
+}
+
+/// Advance width of `paragraph[..i]` for every byte index `i`; only values
+/// at character boundaries are meaningful.
+pub(crate) fn prefix_widths(paragraph: &str, face: &Face, scale: f32) -> Vec<f32> {
+    let mut buffer = UnicodeBuffer::new();
+    buffer.push_str(paragraph);
+    let glyphs = rustybuzz::shape(face, &[], buffer);
+
+    let mut advances = vec![0.0; paragraph.len() + 1];
+    for (info, position) in glyphs.glyph_infos().iter().zip(glyphs.glyph_positions()) {
+        advances[info.cluster as usize] += position.x_advance as f32 * scale;
+    }
+    let mut total = 0.0;
+    for advance in &mut advances {
+        let width = *advance;
+        *advance = total;
+        total += width;
+    }
+    advances
+}
+
+/// Byte ranges of the lines of `paragraph`, without trailing whitespace.
+/// A line ending at a soft hyphen needs room for a `hyphen` wide glyph.
+pub(crate) fn break_lines(
+    paragraph: &str,
+    widths: &[f32],
+    width: f32,
+    indent: f32,
+    hyphen: f32,
+    kinsoku: &Kinsoku,
+) -> Vec<(usize, usize)> {
+    let trim_end = |start: usize, end: usize| start + paragraph[start..end].trim_end().len();
+    let breaks = kinsoku.breaks(paragraph);
+
+    let mut lines = Vec::new();
+    let mut start = 0;
+    let mut next = 0;
+    while start < paragraph.len() {
+        let available = if lines.is_empty() {
+            width - indent
+        } else {
+            width
+        };
+        let mut end = None;
+        while let Some(&(position, opportunity)) = breaks.get(next) {
+            let extra = if paragraph[..position].ends_with(SOFT_HYPHEN) {
+                hyphen
+            } else {
+                0.0
+            };
+            if widths[trim_end(start, position)] - widths[start] + extra > available + EPSILON {
+                break;
+            }
+            end = Some(position);
+            next += 1;
+            if opportunity == BreakOpportunity::Mandatory {
+                break;
+            }
+        }
+
+        // A word wider than the line is broken between any two characters,
+        // keeping to the kinsoku rules where possible.
+        let end = end.unwrap_or_else(|| {
+            let chars = paragraph[start..]
+                .char_indices()
+                .skip(1)
+                .map(|(i, _)| start + i);
+            let first = chars.clone().next().unwrap_or(paragraph.len());
+            let fitting: Vec<usize> = chars
+                .take_while(|&i| widths[i] - widths[start] <= available + EPSILON)
+                .collect();
+            fitting
+                .iter()
+                .rev()
+                .find(|&&i| kinsoku.allows(paragraph, i))
+                .or(fitting.last())
+                .copied()
+                .unwrap_or(first)
+        });
+        let line_end = trim_end(start, end);
+        if line_end > start {
+            lines.push((start, line_end));
+        }
+        start = end;
+        while breaks
+            .get(next)
+            .is_some_and(|&(position, _)| position <= start)
+        {
+            next += 1;
+        }
+    }
+    lines
+}
+
+/// Converts increasing byte offsets of a string into UTF-16 offsets.
+#[flutter_rust_bridge::frb(ignore)]
+pub(crate) struct Utf16Offsets<'a> {
+    text: &'a str,
+    byte: usize,
+    utf16: u32,
+}
+
+impl<'a> Utf16Offsets<'a> {
+    pub(crate) fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            byte: 0,
+            utf16: 0,
+        }
+    }
+
+    pub(crate) fn at(&mut self, byte: usize) -> u32 {
+        self.utf16 += self.text[self.byte..byte]
+            .chars()
+            .map(|c| c.len_utf16() as u32)
+            .sum::<u32>();
+        self.byte = byte;
+        self.utf16
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fonts::{FontBuilder, Glyph};
+
+    /// Every glyph is 600 units wide, so 6px at a 10px font size.
+    fn font() -> Vec<u8> {
+        FontBuilder::with_chars("abcdefghijklmnopqrstuvwxyz-天色渐晚城门外。，「」😀")
+            .glyph(' ', Glyph::Empty { advance: 600 })
+            .build()
+    }
+
+    fn layout(width: f32, height: f32) -> PageLayout {
+        PageLayout {
+            width,
+            height,
+            margin_top: 0.0,
+            margin_right: 0.0,
+            margin_bottom: 0.0,
+            margin_left: 0.0,
+            font_size: 10.0,
+            line_height: 1.5,
+            paragraph_spacing: 0.0,
+            indent: 0.0,
+        }
+    }
+
+    fn line_texts(text: &str, pages: &[TextPage]) -> Vec<Vec<String>> {
+        let units: Vec<u16> = text.encode_utf16().collect();
+        pages
+            .iter()
+            .map(|page| {
+                page.lines
+                    .iter()
+                    .map(|line| {
+                        String::from_utf16(&units[line.start as usize..line.end as usize]).unwrap()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_breaks_words_and_pages() {
+        let data = font();
+        let face = Face::from_slice(&data, 0).unwrap();
+        let text = "aaa bbb ccc\n\ndddddddddd\nee";
+        // Seven characters per line, two lines per page.
+        let pages = paginate(text, &face, &layout(42.0, 30.0), &Kinsoku::standard()).unwrap();
+
+        assert_eq!(
+            line_texts(text, &pages),
+            vec![vec!["aaa bbb", "ccc"], vec!["ddddddd", "ddd"], vec!["ee"]]
+        );
+        assert_eq!(pages[0].start, 0);
+        assert_eq!(pages[0].end, pages[1].start);
+        assert_eq!(pages[2].end, text.len() as u32);
+        assert!(pages[1].lines[0].paragraph_start);
+        assert!(!pages[1].lines[1].paragraph_start);
+        assert_eq!(pages[0].lines[0].width, 42.0);
+    }
+
+    #[test]
+    fn test_keeps_cjk_punctuation_off_line_edges() {
+        let data = font();
+        let face = Face::from_slice(&data, 0).unwrap();
+        // Four characters fit; "。" may not start a line, so "晚" moves down.
+        let text = "天色渐晚。城门外";
+        let pages = paginate(text, &face, &layout(24.0, 100.0), &Kinsoku::standard()).unwrap();
+        assert_eq!(
+            line_texts(text, &pages),
+            vec![vec!["天色渐", "晚。城门", "外"]]
+        );
+
+        // "「" may not end a line.
+        let text = "天色渐「城门」";
+        let pages = paginate(text, &face, &layout(24.0, 100.0), &Kinsoku::standard()).unwrap();
+        assert_eq!(line_texts(text, &pages), vec![vec!["天色渐", "「城门」"]]);
+    }
+
+    #[test]
+    fn test_kinsoku_in_emergency_breaks() {
+        let data = font();
+        let face = Face::from_slice(&data, 0).unwrap();
+        // "aaaa，b" has no break that fits four characters, so the word is
+        // split, but not right before the comma.
+        let text = "aaaa，b";
+        let pages = paginate(text, &face, &layout(24.0, 100.0), &Kinsoku::standard()).unwrap();
+        assert_eq!(line_texts(text, &pages), vec![vec!["aaa", "a，b"]]);
+
+        let plain = Kinsoku::new(&KinsokuRules {
+            no_line_start: String::new(),
+            no_line_end: String::new(),
+        });
+        let pages = paginate(text, &face, &layout(24.0, 100.0), &plain).unwrap();
+        assert_eq!(line_texts(text, &pages), vec![vec!["aaaa", "，b"]]);
+    }
+
+    #[test]
+    fn test_indent_spacing_and_utf16_offsets() {
+        let data = font();
+        let face = Face::from_slice(&data, 0).unwrap();
+        let mut page = layout(60.0, 45.0);
+        page.indent = 1.0;
+        page.paragraph_spacing = 15.0;
+        let text = "aaaaaaaaa\n😀b";
+        let pages = paginate(text, &face, &page, &Kinsoku::standard()).unwrap();
+
+        // The indent leaves eight characters on the first line, and the
+        // paragraph spacing pushes the second paragraph to the next page.
+        assert_eq!(
+            line_texts(text, &pages),
+            vec![vec!["aaaaaaaa", "a"], vec!["😀b"]]
+        );
+        assert_eq!((pages[1].start, pages[1].end), (10, 13));
+
+        page.margin_left = 60.0;
+        assert!(paginate(text, &face, &page, &Kinsoku::standard()).is_err());
+    }
+
+    #[test]
+    fn test_breaks_at_soft_hyphens() {
+        let data = font();
+        let face = Face::from_slice(&data, 0).unwrap();
+        // "aaaa" plus the drawn hyphen just fills a five-character line.
+        let text = "aaaa\u{ad}bbbb cc";
+        let pages = paginate(text, &face, &layout(30.0, 100.0), &Kinsoku::standard()).unwrap();
+        assert_eq!(
+            line_texts(text, &pages),
+            vec![vec!["aaaa\u{ad}", "bbbb", "cc"]]
+        );
+        let lines = &pages[0].lines;
+        assert!(lines[0].hyphenated && !lines[1].hyphenated);
+        assert_eq!(lines[0].width, 30.0);
+
+        // "c aaa" would fit, but not with the hyphen after it.
+        let text = "c aaa\u{ad}bbb";
+        let pages = paginate(text, &face, &layout(30.0, 100.0), &Kinsoku::standard()).unwrap();
+        assert_eq!(
+            line_texts(text, &pages),
+            vec![vec!["c", "aaa\u{ad}", "bbb"]]
+        );
+        let pages = paginate(text, &face, &layout(36.0, 100.0), &Kinsoku::standard()).unwrap();
+        assert_eq!(line_texts(text, &pages), vec![vec!["c aaa\u{ad}", "bbb"]]);
+    }
+}
@@ -0,0 +1,362 @@
+//! Cover colour extraction.
+//!
+//! The cover is decoded and shrunk to a small thumbnail, then its opaque
+//! pixels are grouped with k-means. Clusters are seeded by farthest-point
+//! picking from the most common colour rather than at random, so the same
+//! cover always yields the same palette. The dominant colour is the largest
+//! cluster; the dark and light colours are the darkest and lightest
+//! clusters that cover a noticeable part of the image. From those the
+//! module derives background, foreground and accent colours for the light
+//! and dark themes, nudging the foreground until it meets WCAG contrast
+//! against its background.
+//!
+//! Colours are `0xAARRGGBB` values, the layout Flutter's `Color` takes.
+
+use anyhow::{anyhow, Result};
+use image::imageops::FilterType;
+
+use crate::api::error::ApiError;
+
+/// Longest side of the thumbnail that is clustered.
+const SAMPLE_SIZE: u32 = 64;
+/// Number of clusters.
+const CLUSTERS: usize = 6;
+const ITERATIONS: usize = 10;
+/// Clusters smaller than this share are ignored when picking dark and light.
+const MIN_POPULATION: f32 = 0.05;
+/// Pixels with less alpha than this are left out.
+const MIN_ALPHA: u8 = 128;
+/// WCAG AA contrast for body text.
+const TEXT_CONTRAST: f64 = 4.5;
+/// WCAG contrast for large text and UI elements.
+const ACCENT_CONTRAST: f64 = 3.0;
+
+#[derive(Debug, Clone)]
+pub struct PaletteSwatch {
+    pub color: u32,
+    /// Share of the sampled pixels in this cluster, from 0 to 1.
+    pub population: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct ThemeColors {
+    pub background: u32,
+    pub foreground: u32,
+    pub accent: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct CoverPalette {
+    pub dominant: u32,
+    pub dark: u32,
+    pub light: u32,
+    /// Clusters, largest first.
+    pub swatches: Vec<PaletteSwatch>,
+    pub light_theme: ThemeColors,
+    pub dark_theme: ThemeColors,
+}
+
+/// Extract the main colours of a cover image and suggest theme colours.
+///
+/// # Arguments
+/// * `image_bytes` - Encoded image (JPEG, PNG, GIF, WebP)
+#[flutter_rust_bridge::frb]
+pub fn extract(image_bytes: Vec<u8>) -> Result<CoverPalette, ApiError> {
+    let image = image::load_from_memory(&image_bytes)
+        .map_err(|e| anyhow!("Failed to decode cover image: {e}"))?;
+    let sample = image
+        .resize(SAMPLE_SIZE, SAMPLE_SIZE, FilterType::Triangle)
+        .to_rgba8();
+    let mut pixels: Vec<[f64; 3]> = sample
+        .pixels()
+        .filter(|p| p[3] >= MIN_ALPHA)
+        .map(|p| [p[0] as f64, p[1] as f64, p[2] as f64])
+        .collect();
+    if pixels.is_empty() {
+        // Fully transparent: fall back to every pixel so there is a result.
+        pixels = sample
+            .pixels()
+            .map(|p| [p[0] as f64, p[1] as f64, p[2] as f64])
+            .collect();
+    }
+    if pixels.is_empty() {
+        return Err(anyhow!("Failed to extract palette: image is empty").into());
+    }
+    Ok(build_palette(&cluster(&pixels)))
+}
+
+/// Cluster centres with their populations, largest first.
+fn cluster(pixels: &[[f64; 3]]) -> Vec<([f64; 3], f32)> {
+    let mut centres = seed(pixels);
+    let mut assignment = vec![0usize; pixels.len()];
+    for _ in 0..ITERATIONS {
+        let mut changed = false;
+        for (pixel, slot) in pixels.iter().zip(assignment.iter_mut()) {
+            let nearest = nearest(&centres, pixel);
+            changed |= nearest != *slot;
+            *slot = nearest;
+        }
+        let mut sums = vec![[0.0f64; 3]; centres.len()];
+        let mut counts = vec![0usize; centres.len()];
+        for (pixel, &slot) in pixels.iter().zip(&assignment) {
+            for c in 0..3 {
+                sums[slot][c] += pixel[c];
+            }
+            counts[slot] += 1;
+        }
+        for (i, centre) in centres.iter_mut().enumerate() {
+            if counts[i] > 0 {
+                *centre = sums[i].map(|sum| sum / counts[i] as f64);
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    let mut counts = vec![0usize; centres.len()];
+    for &slot in &assignment {
+        counts[slot] += 1;
+    }
+    let mut clusters: Vec<([f64; 3], f32)> = centres
+        .into_iter()
+        .zip(counts)
+        .filter(|(_, count)| *count > 0)
+        .map(|(centre, count)| (centre, count as f32 / pixels.len() as f32))
+        .collect();
+    clusters.sort_by(|a, b| b.1.total_cmp(&a.1));
+    clusters
+}
+
+/// Farthest-point seeding, starting from the most common coarse colour.
+fn seed(pixels: &[[f64; 3]]) -> Vec<[f64; 3]> {
+    let mut buckets = std::collections::HashMap::<(u8, u8, u8), usize>::new();
+    for pixel in pixels {
+        let key = (
+            (pixel[0] as u8) >> 4,
+            (pixel[1] as u8) >> 4,
+            (pixel[2] as u8) >> 4,
+        );
+        *buckets.entry(key).or_default() += 1;
+    }
+    let common = buckets
+        .into_iter()
+        .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0)))
+        .map(|(key, _)| key)
+        .unwrap_or_default();
+    let first = pixels
+        .iter()
+        .find(|p| ((p[0] as u8) >> 4, (p[1] as u8) >> 4, (p[2] as u8) >> 4) == common)
+        .copied()
+        .unwrap_or(pixels[0]);
+    let mut centres = vec![first];
+    let mut distances: Vec<f64> = pixels.iter().map(|p| distance(p, &first)).collect();
+    while centres.len() < CLUSTERS {
+        let Some((index, &far)) = distances
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+        else {
+            break;
+        };
+        if far == 0.0 {
+            break;
+        }
+        let centre = pixels[index];
+        centres.push(centre);
+        for (d, pixel) in distances.iter_mut().zip(pixels) {
+            *d = d.min(distance(pixel, &centre));
+        }
+    }
+    centres
+}
+
+fn nearest(centres: &[[f64; 3]], pixel: &[f64; 3]) -> usize {
+    centres
+        .iter()
+        .enumerate()
+        .min_by(|a, b| distance(a.1, pixel).total_cmp(&distance(b.1, pixel)))
+        .map(|(i, _)| i)
+        .unwrap_or(0)
+}
+
+fn distance(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    (0..3).map(|c| (a[c] - b[c]).powi(2)).sum()
+}
+
+fn build_palette(clusters: &[([f64; 3], f32)]) -> CoverPalette {
+    let swatches: Vec<PaletteSwatch> = clusters
+        .iter()
+        .map(|(centre, population)| PaletteSwatch {
+            color: to_argb(*centre),
+            population: *population,
+        })
+        .collect();
+    let dominant = swatches[0].color;
+    let significant: Vec<u32> = swatches
+        .iter()
+        .filter(|s| s.population >= MIN_POPULATION)
+        .map(|s| s.color)
+        .collect();
+    let by_luminance = |a: &u32, b: &u32| luminance(*a).total_cmp(&luminance(*b));
+    let dark = significant
+        .iter()
+        .copied()
+        .min_by(by_luminance)
+        .unwrap_or(dominant);
+    let light = significant
+        .iter()
+        .copied()
+        .max_by(by_luminance)
+        .unwrap_or(dominant);
+
+    let light_background = mix(light, 0xFFFF_FFFF, 0.85);
+    let dark_background = mix(dark, 0xFF00_0000, 0.85);
+    CoverPalette {
+        dominant,
+        dark,
+        light,
+        light_theme: ThemeColors {
+            background: light_background,
+            foreground: with_contrast(dark, light_background, TEXT_CONTRAST),
+            accent: accent(&swatches, light_background),
+        },
+        dark_theme: ThemeColors {
+            background: dark_background,
+            foreground: with_contrast(light, dark_background, TEXT_CONTRAST),
+            accent: accent(&swatches, dark_background),
+        },
+        swatches,
+    }
+}
+
+/// The most saturated swatch, adjusted to stand out on `background`.
+fn accent(swatches: &[PaletteSwatch], background: u32) -> u32 {
+    let color = swatches
+        .iter()
+        .filter(|s| s.population >= MIN_POPULATION)
+        .max_by(|a, b| saturation(a.color).total_cmp(&saturation(b.color)))
+        .map(|s| s.color)
+        .unwrap_or(swatches[0].color);
+    with_contrast(color, background, ACCENT_CONTRAST)
+}
+
+/// `color` moved towards black or white, whichever `background` is farther
+/// from, just far enough to reach `target` contrast.
+fn with_contrast(color: u32, background: u32, target: f64) -> u32 {
+    if contrast(color, background) >= target {
+        return color;
+    }
+    let toward = if luminance(background) > 0.5 {
+        0xFF00_0000
+    } else {
+        0xFFFF_FFFF
+    };
+    (1..=20)
+        .map(|step| mix(color, toward, step as f64 / 20.0))
+        .find(|&c| contrast(c, background) >= target)
+        .unwrap_or(toward)
+}
+
+fn mix(a: u32, b: u32, amount: f64) -> u32 {
+    let (a, b) = (channels(a), channels(b));
+    to_argb([0, 1, 2].map(|c| a[c] + (b[c] - a[c]) * amount))
+}
+
+fn channels(color: u32) -> [f64; 3] {
+    [16, 8, 0].map(|shift| ((color >> shift) & 0xFF) as f64)
+}
+
+fn to_argb(rgb: [f64; 3]) -> u32 {
+    let [r, g, b] = rgb.map(|c| c.round().clamp(0.0, 255.0) as u32);
+    0xFF00_0000 | (r << 16) | (g << 8) | b
+}
+
+/// WCAG relative luminance.
+fn luminance(color: u32) -> f64 {
+    let [r, g, b] = channels(color).map(|c| {
+        let c = c / 255.0;
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    });
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+fn contrast(a: u32, b: u32) -> f64 {
+    let (a, b) = (luminance(a), luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// HSL saturation.
+fn saturation(color: u32) -> f64 {
+    let rgb = channels(color).map(|c| c / 255.0);
+    let max = rgb.iter().copied().fold(0.0, f64::max);
+    let min = rgb.iter().copied().fold(1.0, f64::min);
+    let lightness = (max + min) / 2.0;
+    if max == min {
+        0.0
+    } else {
+        (max - min) / (1.0 - (2.0 * lightness - 1.0).abs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageFormat, Rgb, RgbImage};
+    use std::io::Cursor;
+
+    fn png(image: RgbImage) -> Vec<u8> {
+        let mut data = Vec::new();
+        image
+            .write_to(&mut Cursor::new(&mut data), ImageFormat::Png)
+            .unwrap();
+        data
+    }
+
+    #[test]
+    fn test_extracts_dominant_dark_and_light() {
+        // 70% navy, 20% cream, 10% red.
+        let image = RgbImage::from_fn(100, 100, |x, _| match x {
+            0..70 => Rgb([20, 30, 80]),
+            70..90 => Rgb([240, 230, 200]),
+            _ => Rgb([200, 30, 30]),
+        });
+        let palette = extract(png(image)).unwrap();
+        let near = |a: u32, b: u32| {
+            let (a, b) = (channels(a), channels(b));
+            (0..3).all(|c| (a[c] - b[c]).abs() < 12.0)
+        };
+        assert!(near(palette.dominant, 0xFF14_1E50));
+        assert!(near(palette.dark, 0xFF14_1E50));
+        assert!(near(palette.light, 0xFFF0_E6C8));
+        assert!(palette.swatches[0].population > 0.6);
+        assert!(near(palette.light_theme.accent, 0xFFC8_1E1E));
+    }
+
+    #[test]
+    fn test_theme_colors_meet_contrast() {
+        // A low-contrast grey cover still yields readable text colours.
+        let image = RgbImage::from_fn(40, 40, |x, y| {
+            if (x + y) % 2 == 0 {
+                Rgb([120, 120, 120])
+            } else {
+                Rgb([140, 140, 140])
+            }
+        });
+        let palette = extract(png(image)).unwrap();
+        for theme in [&palette.light_theme, &palette.dark_theme] {
+            assert!(contrast(theme.foreground, theme.background) >= TEXT_CONTRAST);
+            assert!(contrast(theme.accent, theme.background) >= ACCENT_CONTRAST);
+        }
+        assert!(luminance(palette.light_theme.background) > 0.5);
+        assert!(luminance(palette.dark_theme.background) < 0.1);
+    }
+
+    #[test]
+    fn test_rejects_invalid_image() {
+        assert!(extract(b"not an image".to_vec()).is_err());
+    }
+}
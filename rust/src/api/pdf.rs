@@ -0,0 +1,581 @@
+//! PDF text extraction and reflow.
+//!
+//! Characters are collected per page with their position and size, joined
+//! into text runs and lines, and the lines merged into paragraphs. Lines set
+//! noticeably larger than the body text are headings: the largest heading
+//! size starts a new chapter, smaller ones become nested TOC entries. PDFs
+//! without a text layer (scans) are rejected rather than imported empty.
+
+use anyhow::{anyhow, Result};
+use pdf_extract::{Document, MediaBox, OutputDev, OutputError, Transform};
+use quick_xml::escape::escape;
+
+use crate::api::book::{self, BookChapter, BookMetadata, ParsedBook, TocEntry};
+use crate::api::drm::{self, DrmScheme};
+use crate::api::error::ApiError;
+use crate::api::task;
+
+/// Lines at least this much larger than the body text are headings.
+const HEADING_SCALE: f32 = 1.2;
+
+/// Longer lines are never headings, whatever their size.
+const MAX_HEADING_CHARS: usize = 80;
+
+/// Heading sizes beyond this many levels share the deepest level.
+const MAX_HEADING_LEVELS: usize = 3;
+
+/// Pages per chapter when the document has no headings.
+const PAGES_PER_CHAPTER: usize = 10;
+
+/// Parse a text-based PDF into reflowable chapters.
+///
+/// # Arguments
+/// * `path` - Path to the PDF file
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+#[flutter_rust_bridge::frb]
+pub async fn parse_pdf(path: String, cancel_token: Option<u32>) -> Result<ParsedBook, ApiError> {
+    task::run_blocking(task::cancel_token(cancel_token)?, move |_| {
+        let data = std::fs::read(&path).map_err(|e| anyhow!("Failed to open {path}: {e}"))?;
+        parse(&data)
+    })
+    .await
+}
+
+pub(crate) fn parse(data: &[u8]) -> Result<ParsedBook> {
+    let document = Document::load_mem(data).map_err(|e| anyhow!("Invalid PDF: {e}"))?;
+    if document.is_encrypted() {
+        return Err(drm::protected_error(
+            drm::pdf_scheme(data).unwrap_or(DrmScheme::Unknown),
+        ));
+    }
+
+    let mut collector = Collector::default();
+    pdf_extract::output_doc(&document, &mut collector)
+        .map_err(|e| anyhow!("Failed to extract PDF text: {e}"))?;
+    let pages: Vec<Vec<Line>> = collector.pages.into_iter().map(lines).collect();
+    if pages.iter().all(|page| page.is_empty()) {
+        return Err(anyhow!("PDF has no text layer"));
+    }
+
+    let (chapters, toc) = reflow(pages);
+    Ok(ParsedBook {
+        metadata: metadata(&document),
+        chapters,
+        toc,
+    })
+}
+
+fn metadata(document: &Document) -> BookMetadata {
+    let mut metadata = BookMetadata::empty();
+    let info = document
+        .trailer
+        .get(b"Info")
+        .and_then(|info| document.dereference(info))
+        .and_then(|(_, info)| info.as_dict());
+    let Ok(info) = info else {
+        return metadata;
+    };
+    let field = |key: &[u8]| {
+        info.get(key)
+            .ok()
+            .and_then(|value| pdf_extract::decode_text_string(value).ok())
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+    metadata.title = field(b"Title");
+    metadata.authors = field(b"Author").into_iter().collect();
+    metadata.description = field(b"Subject");
+    metadata
+}
+
+/// A positioned character, in top-down page coordinates.
+struct Glyph {
+    text: String,
+    x: f32,
+    y: f32,
+    end: f32,
+    size: f32,
+}
+
+/// Text on one baseline: a run in a single font size, or a whole line.
+struct Line {
+    text: String,
+    x: f32,
+    end: f32,
+    y: f32,
+    size: f32,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+struct Collector {
+    pages: Vec<Vec<Glyph>>,
+    page_height: f64,
+}
+
+impl OutputDev for Collector {
+    fn begin_page(
+        &mut self,
+        _page_num: u32,
+        media_box: &MediaBox,
+        _art_box: Option<(f64, f64, f64, f64)>,
+    ) -> Result<(), OutputError> {
+        self.page_height = media_box.ury - media_box.lly;
+        self.pages.push(Vec::new());
+        Ok(())
+    }
+
+    fn end_page(&mut self) -> Result<(), OutputError> {
+        Ok(())
+    }
+
+    fn output_character(
+        &mut self,
+        trm: &Transform,
+        width: f64,
+        _spacing: f64,
+        font_size: f64,
+        char: &str,
+    ) -> Result<(), OutputError> {
+        // Scale of the text matrix, as the side of a square of equal area.
+        let scale_x = font_size * (trm.m11 + trm.m21);
+        let scale_y = font_size * (trm.m12 + trm.m22);
+        let size = (scale_x * scale_y).abs().sqrt();
+        let Some(page) = self.pages.last_mut() else {
+            return Ok(());
+        };
+        page.push(Glyph {
+            text: char.to_string(),
+            x: trm.m31 as f32,
+            y: (self.page_height - trm.m32) as f32,
+            end: (trm.m31 + width * size) as f32,
+            size: size as f32,
+        });
+        Ok(())
+    }
+
+    fn begin_word(&mut self) -> Result<(), OutputError> {
+        Ok(())
+    }
+
+    fn end_word(&mut self) -> Result<(), OutputError> {
+        Ok(())
+    }
+
+    fn end_line(&mut self) -> Result<(), OutputError> {
+        Ok(())
+    }
+}
+
+/// Join a page's glyphs into runs, then runs on the same baseline into
+/// lines. Glyphs are taken in content-stream order, which is reading order
+/// for nearly all text PDFs.
+fn lines(glyphs: Vec<Glyph>) -> Vec<Line> {
+    let mut runs: Vec<Line> = Vec::new();
+    for glyph in glyphs {
+        if let Some(run) = runs.last_mut() {
+            let same_line = (glyph.y - run.y).abs() < run.size * 0.5;
+            let same_size = (glyph.size - run.size).abs() < 0.5;
+            let gap = glyph.x - run.end;
+            if same_line && same_size && gap > -run.size && gap < run.size * 2.0 {
+                if gap > run.size * 0.1 && !run.text.ends_with(' ') && glyph.text != " " {
+                    run.text.push(' ');
+                }
+                run.text.push_str(&glyph.text);
+                run.end = glyph.end.max(run.end);
+                continue;
+            }
+        }
+        runs.push(Line {
+            text: glyph.text,
+            x: glyph.x,
+            end: glyph.end,
+            y: glyph.y,
+            size: glyph.size,
+        });
+    }
+
+    let mut lines: Vec<Line> = Vec::new();
+    for run in runs {
+        let text = run.text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if text.is_empty() {
+            continue;
+        }
+        if let Some(line) = lines.last_mut() {
+            if (run.y - line.y).abs() < line.size.min(run.size) * 0.5 {
+                line.text.push(' ');
+                line.text.push_str(&text);
+                line.x = line.x.min(run.x);
+                line.end = line.end.max(run.end);
+                line.size = line.size.max(run.size);
+                continue;
+            }
+        }
+        lines.push(Line {
+            text,
+            x: run.x,
+            end: run.end,
+            y: run.y,
+            size: run.size,
+        });
+    }
+
+    // Bare page numbers in the header or footer.
+    let is_folio = |line: &Line| {
+        line.text
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == '-' || c == ' ')
+    };
+    if lines.last().is_some_and(is_folio) {
+        lines.pop();
+    }
+    if lines.first().is_some_and(is_folio) {
+        lines.remove(0);
+    }
+    lines
+}
+
+/// Size rounded to half points, for grouping lines by font size.
+fn size_key(size: f32) -> u32 {
+    (size * 2.0).round() as u32
+}
+
+/// The font size covering the most characters.
+fn body_size(pages: &[Vec<Line>]) -> u32 {
+    let mut counts: Vec<(u32, usize)> = Vec::new();
+    for line in pages.iter().flatten() {
+        let key = size_key(line.size);
+        let chars = line.text.chars().count();
+        match counts.iter_mut().find(|(size, _)| *size == key) {
+            Some((_, count)) => *count += chars,
+            None => counts.push((key, chars)),
+        }
+    }
+    counts
+        .into_iter()
+        .max_by_key(|&(size, count)| (count, std::cmp::Reverse(size)))
+        .map_or(0, |(size, _)| size)
+}
+
+enum Block {
+    Heading { text: String, level: usize },
+    Paragraph(String),
+    PageBreak(usize),
+}
+
+fn reflow(pages: Vec<Vec<Line>>) -> (Vec<BookChapter>, Vec<TocEntry>) {
+    let body = body_size(&pages);
+    let is_heading = |line: &Line| {
+        size_key(line.size) as f32 >= body as f32 * HEADING_SCALE
+            && line.text.chars().count() <= MAX_HEADING_CHARS
+    };
+    let mut heading_sizes: Vec<u32> = pages
+        .iter()
+        .flatten()
+        .filter(|line| is_heading(line))
+        .map(|line| size_key(line.size))
+        .collect();
+    heading_sizes.sort_unstable_by(|a, b| b.cmp(a));
+    heading_sizes.dedup();
+    let level = |line: &Line| {
+        let rank = heading_sizes
+            .iter()
+            .position(|&size| size == size_key(line.size))
+            .unwrap_or(0);
+        rank.min(MAX_HEADING_LEVELS - 1)
+    };
+
+    let mut blocks = Vec::new();
+    for (page_index, lines) in pages.iter().enumerate() {
+        blocks.push(Block::PageBreak(page_index));
+        let right = lines.iter().map(|line| line.end).fold(0.0, f32::max);
+        let left = lines.iter().map(|line| line.x).fold(f32::MAX, f32::min);
+        let mut paragraph = String::new();
+        let mut previous: Option<&Line> = None;
+
+        for line in lines {
+            if is_heading(line) {
+                flush(&mut blocks, &mut paragraph);
+                // Multi-line headings continue at the same size.
+                if let (Some(prev), Some(Block::Heading { text, .. })) =
+                    (previous, blocks.last_mut())
+                {
+                    if is_heading(prev) && size_key(prev.size) == size_key(line.size) {
+                        text.push(' ');
+                        text.push_str(&line.text);
+                        previous = Some(line);
+                        continue;
+                    }
+                }
+                blocks.push(Block::Heading {
+                    text: line.text.clone(),
+                    level: level(line),
+                });
+                previous = Some(line);
+                continue;
+            }
+
+            if let Some(prev) = previous.filter(|prev| !is_heading(prev)) {
+                let gap = line.y - prev.y;
+                let breaks = gap > prev.size * 1.8
+                    || gap < 0.0
+                    || prev.end < right - prev.size * 4.0
+                    || line.x > left + line.size * 1.5;
+                if breaks {
+                    flush(&mut blocks, &mut paragraph);
+                }
+            }
+            join_line(&mut paragraph, &line.text);
+            previous = Some(line);
+        }
+        flush(&mut blocks, &mut paragraph);
+    }
+
+    build_chapters(blocks)
+}
+
+fn flush(blocks: &mut Vec<Block>, paragraph: &mut String) {
+    if !paragraph.is_empty() {
+        blocks.push(Block::Paragraph(std::mem::take(paragraph)));
+    }
+}
+
+/// Append a line to a paragraph, undoing end-of-line hyphenation and not
+/// adding spaces between CJK characters.
+fn join_line(paragraph: &mut String, line: &str) {
+    let Some(last) = paragraph.chars().last() else {
+        paragraph.push_str(line);
+        return;
+    };
+    let first = line.chars().next().unwrap_or(' ');
+    if last == '-' && first.is_lowercase() {
+        paragraph.pop();
+    } else if !(is_cjk(last) || is_cjk(first)) {
+        paragraph.push(' ');
+    }
+    paragraph.push_str(line);
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(c as u32,
+        0x3000..=0x30FF | 0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF | 0xFF00..=0xFFEF)
+}
+
+struct Chapter {
+    title: Option<String>,
+    html: String,
+}
+
+fn build_chapters(blocks: Vec<Block>) -> (Vec<BookChapter>, Vec<TocEntry>) {
+    let has_headings = blocks
+        .iter()
+        .any(|block| matches!(block, Block::Heading { .. }));
+    let mut chapters: Vec<Chapter> = Vec::new();
+    let mut toc = Vec::new();
+    let new_chapter = |chapters: &mut Vec<Chapter>, title: Option<String>| {
+        chapters.push(Chapter {
+            title,
+            html: String::new(),
+        });
+    };
+
+    for block in blocks {
+        match block {
+            Block::PageBreak(page) => {
+                if chapters.is_empty() || (!has_headings && page % PAGES_PER_CHAPTER == 0) {
+                    new_chapter(&mut chapters, None);
+                }
+            }
+            Block::Heading { text, level } => {
+                if level == 0 {
+                    let current = chapters.last().filter(|chapter| chapter.html.is_empty());
+                    if current.is_none() {
+                        new_chapter(&mut chapters, None);
+                    }
+                }
+                let index = chapters.len() - 1;
+                let chapter = &mut chapters[index];
+                if level == 0 {
+                    chapter.title = Some(text.clone());
+                }
+                let tag = format!("h{}", level + 2);
+                let id = format!("h{}", toc.len());
+                chapter.html.push_str(&format!(
+                    "<{tag} id=\"{id}\">{}</{tag}>",
+                    escape(text.as_str())
+                ));
+                toc.push(TocEntry {
+                    title: text,
+                    href: format!("{}#{id}", book::part_href(index)),
+                    chapter_index: Some(index as u32),
+                    depth: level as u32,
+                });
+            }
+            Block::Paragraph(text) => {
+                let chapter = chapters.last_mut().expect("page break precedes text");
+                chapter
+                    .html
+                    .push_str(&format!("<p>{}</p>", escape(text.as_str())));
+            }
+        }
+    }
+
+    let chapters = chapters
+        .into_iter()
+        .filter(|chapter| !chapter.html.is_empty())
+        .enumerate()
+        .map(|(index, chapter)| BookChapter {
+            index: index as u32,
+            href: book::part_href(index),
+            title: chapter.title,
+            html: chapter.html,
+            images: Vec::new(),
+        })
+        .collect();
+    (chapters, toc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pdf_extract::content::{Content, Operation};
+    use pdf_extract::{dictionary, Object, Stream};
+
+    /// Lines of (font size, x, y, text) per page, in PDF coordinates.
+    type PageLines<'a> = &'a [(i64, i64, i64, &'a str)];
+
+    fn build_pdf(pages: &[PageLines]) -> Vec<u8> {
+        let mut doc = Document::with_version("1.5");
+        let pages_id = doc.new_object_id();
+        let font_id = doc.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Helvetica",
+        });
+        let resources_id = doc.add_object(dictionary! {
+            "Font" => dictionary! { "F1" => font_id },
+        });
+
+        let mut kids = Vec::new();
+        for lines in pages {
+            let mut operations = Vec::new();
+            for &(size, x, y, text) in lines.iter() {
+                operations.push(Operation::new("BT", vec![]));
+                operations.push(Operation::new("Tf", vec!["F1".into(), size.into()]));
+                operations.push(Operation::new("Td", vec![x.into(), y.into()]));
+                operations.push(Operation::new("Tj", vec![Object::string_literal(text)]));
+                operations.push(Operation::new("ET", vec![]));
+            }
+            let content = Content { operations };
+            let content_id = doc.add_object(Stream::new(dictionary! {}, content.encode().unwrap()));
+            let page_id = doc.add_object(dictionary! {
+                "Type" => "Page",
+                "Parent" => pages_id,
+                "Contents" => content_id,
+            });
+            kids.push(page_id.into());
+        }
+
+        let count = kids.len() as i64;
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => kids,
+                "Count" => count,
+                "Resources" => resources_id,
+                "MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+            }),
+        );
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        let info_id = doc.add_object(dictionary! {
+            "Title" => Object::string_literal("Sample Report"),
+            "Author" => Object::string_literal("A. Writer"),
+        });
+        doc.trailer.set("Root", catalog_id);
+        doc.trailer.set("Info", info_id);
+
+        let mut data = Vec::new();
+        doc.save_to(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_parse_reflows_paragraphs_and_headings() {
+        let data = build_pdf(&[
+            &[
+                (24, 72, 720, "Introduction"),
+                (12, 72, 690, "The first paragraph starts here and"),
+                (12, 72, 676, "continues on a second line that is hyph-"),
+                (12, 72, 662, "enated."),
+                (12, 90, 640, "A second paragraph."),
+                (16, 72, 610, "Background"),
+                (12, 72, 590, "Details."),
+                (10, 300, 40, "1"),
+            ],
+            &[(24, 72, 720, "Method"), (12, 72, 690, "Last words.")],
+        ]);
+        let book = parse(&data).unwrap();
+
+        assert_eq!(book.metadata.title.as_deref(), Some("Sample Report"));
+        assert_eq!(book.metadata.authors, vec!["A. Writer"]);
+
+        let chapters: Vec<_> = book
+            .chapters
+            .iter()
+            .map(|c| (c.title.as_deref(), c.html.as_str()))
+            .collect();
+        assert_eq!(
+            chapters,
+            vec![
+                (
+                    Some("Introduction"),
+                    "<h2 id=\"h0\">Introduction</h2>\
+                     <p>The first paragraph starts here and continues on a second line \
+                     that is hyphenated.</p>\
+                     <p>A second paragraph.</p>\
+                     <h3 id=\"h1\">Background</h3><p>Details.</p>"
+                ),
+                (
+                    Some("Method"),
+                    "<h2 id=\"h2\">Method</h2><p>Last words.</p>"
+                ),
+            ]
+        );
+
+        let toc: Vec<_> = book
+            .toc
+            .iter()
+            .map(|e| (e.title.as_str(), e.href.as_str(), e.depth))
+            .collect();
+        assert_eq!(
+            toc,
+            vec![
+                ("Introduction", "part0000.html#h0", 0),
+                ("Background", "part0000.html#h1", 1),
+                ("Method", "part0001.html#h2", 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_without_headings_groups_pages() {
+        let page: PageLines = &[(12, 72, 700, "Body text.")];
+        let pages = vec![page; PAGES_PER_CHAPTER + 1];
+        let book = parse(&build_pdf(&pages)).unwrap();
+
+        assert_eq!(book.chapters.len(), 2);
+        assert!(book.toc.is_empty());
+        assert_eq!(book.chapters[1].html, "<p>Body text.</p>");
+    }
+
+    #[test]
+    fn test_parse_rejects_pdf_without_text() {
+        let error = parse(&build_pdf(&[&[]])).unwrap_err();
+        assert_eq!(error.to_string(), "PDF has no text layer");
+        assert!(parse(b"not a pdf").is_err());
+    }
+}
@@ -0,0 +1,181 @@
+//! Cover placeholders for the library grid.
+//!
+//! At import time a cover is reduced to three things the grid can show
+//! before the full image is decoded: a BlurHash string, a ThumbHash
+//! (base64, which also keeps the aspect ratio and alpha) and a small JPEG
+//! thumbnail. Both hashes are computed from a tiny downscaled copy, since
+//! their cost grows with the pixel count and the result looks the same.
+
+use std::io::Cursor;
+
+use anyhow::{anyhow, Result};
+use base64::Engine;
+use image::codecs::jpeg::JpegEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageFormat, RgbaImage};
+
+use crate::api::error::ApiError;
+
+/// ThumbHash only accepts images up to 100 px on a side.
+const HASH_SAMPLE_SIZE: u32 = 100;
+/// BlurHash cost is pixels × components, and 32 px loses nothing visible.
+const BLURHASH_SAMPLE_SIZE: u32 = 32;
+/// Components along the longer side; the shorter side gets fewer.
+const BLURHASH_COMPONENTS: u32 = 4;
+/// Longest thumbnail side used when the caller passes 0.
+const DEFAULT_THUMBNAIL_SIZE: u32 = 128;
+const JPEG_QUALITY: u8 = 80;
+
+#[derive(Debug, Clone)]
+pub struct CoverPlaceholder {
+    pub blurhash: String,
+    /// Base64 ThumbHash.
+    pub thumbhash: String,
+    /// JPEG thumbnail.
+    pub thumbnail: Vec<u8>,
+    pub thumbnail_width: u32,
+    pub thumbnail_height: u32,
+    /// Size of the original cover.
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Generate the placeholders of a cover image.
+///
+/// # Arguments
+/// * `image_bytes` - Encoded cover (JPEG, PNG, GIF, WebP)
+/// * `thumbnail_size` - Longest side of the thumbnail in pixels; 0 for the default
+#[flutter_rust_bridge::frb]
+pub fn cover_placeholder(
+    image_bytes: Vec<u8>,
+    thumbnail_size: u32,
+) -> Result<CoverPlaceholder, ApiError> {
+    let image = image::load_from_memory(&image_bytes)
+        .map_err(|e| anyhow!("Failed to decode cover image: {e}"))?;
+    if image.width() == 0 || image.height() == 0 {
+        return Err(anyhow!("Failed to decode cover image: image is empty").into());
+    }
+    let size = match thumbnail_size {
+        0 => DEFAULT_THUMBNAIL_SIZE,
+        size => size,
+    };
+    let thumbnail = shrink(&image, size);
+    let mut jpeg = Vec::new();
+    JpegEncoder::new_with_quality(&mut jpeg, JPEG_QUALITY)
+        .encode_image(&thumbnail.to_rgb8())
+        .map_err(|e| anyhow!("Failed to encode cover thumbnail: {e}"))?;
+
+    Ok(CoverPlaceholder {
+        blurhash: blurhash(&image)?,
+        thumbhash: thumbhash(&image),
+        thumbnail: jpeg,
+        thumbnail_width: thumbnail.width(),
+        thumbnail_height: thumbnail.height(),
+        width: image.width(),
+        height: image.height(),
+    })
+}
+
+/// Render a ThumbHash as a small PNG, for platforms without a Dart decoder.
+///
+/// # Arguments
+/// * `thumbhash` - Base64 ThumbHash from `cover_placeholder`
+#[flutter_rust_bridge::frb]
+pub fn thumbhash_to_png(thumbhash: String) -> Result<Vec<u8>, ApiError> {
+    let hash = base64::engine::general_purpose::STANDARD
+        .decode(thumbhash.trim())
+        .map_err(|e| anyhow!("Failed to decode ThumbHash: {e}"))?;
+    let (width, height, rgba) = thumbhash::thumb_hash_to_rgba(&hash)
+        .map_err(|_| anyhow!("Failed to decode ThumbHash: invalid hash"))?;
+    let image = RgbaImage::from_raw(width as u32, height as u32, rgba)
+        .ok_or_else(|| anyhow!("Failed to decode ThumbHash: invalid size"))?;
+    let mut png = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+        .map_err(|e| anyhow!("Failed to encode ThumbHash image: {e}"))?;
+    Ok(png)
+}
+
+/// Downscale so the longer side is at most `size`, never upscaling.
+fn shrink(image: &DynamicImage, size: u32) -> DynamicImage {
+    if image.width() <= size && image.height() <= size {
+        return image.clone();
+    }
+    image.resize(size, size, FilterType::Triangle)
+}
+
+fn blurhash(image: &DynamicImage) -> Result<String> {
+    let sample = shrink(image, BLURHASH_SAMPLE_SIZE).to_rgba8();
+    let (width, height) = sample.dimensions();
+    // Keep the components roughly square: a portrait cover gets 3×4.
+    let short = |a: u32, b: u32| {
+        (BLURHASH_COMPONENTS * a)
+            .div_ceil(b)
+            .clamp(1, BLURHASH_COMPONENTS)
+    };
+    let (x, y) = if width >= height {
+        (BLURHASH_COMPONENTS, short(height, width))
+    } else {
+        (short(width, height), BLURHASH_COMPONENTS)
+    };
+    blurhash::encode(x, y, width, height, sample.as_raw())
+        .map_err(|e| anyhow!("Failed to encode BlurHash: {e}"))
+}
+
+fn thumbhash(image: &DynamicImage) -> String {
+    let sample = shrink(image, HASH_SAMPLE_SIZE).to_rgba8();
+    let (width, height) = sample.dimensions();
+    let hash = thumbhash::rgba_to_thumb_hash(width as usize, height as usize, sample.as_raw());
+    base64::engine::general_purpose::STANDARD.encode(hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgb, RgbImage};
+
+    fn cover(width: u32, height: u32) -> Vec<u8> {
+        let image = RgbImage::from_fn(width, height, |x, y| {
+            Rgb([(x * 255 / width) as u8, (y * 255 / height) as u8, 120])
+        });
+        let mut data = Vec::new();
+        image
+            .write_to(&mut Cursor::new(&mut data), ImageFormat::Png)
+            .unwrap();
+        data
+    }
+
+    #[test]
+    fn test_generates_placeholders() {
+        let placeholder = cover_placeholder(cover(600, 900), 0).unwrap();
+        assert_eq!((placeholder.width, placeholder.height), (600, 900));
+        assert_eq!(placeholder.thumbnail_height, DEFAULT_THUMBNAIL_SIZE);
+        assert!((84..=86).contains(&placeholder.thumbnail_width));
+        let thumbnail = image::load_from_memory(&placeholder.thumbnail).unwrap();
+        assert_eq!(thumbnail.height(), DEFAULT_THUMBNAIL_SIZE);
+        // 3×4 components: six characters for the header and average colour,
+        // two for each of the other eleven.
+        assert_eq!(placeholder.blurhash.len(), 6 + 2 * 11);
+        assert!(!placeholder.thumbhash.is_empty());
+    }
+
+    #[test]
+    fn test_thumbhash_round_trip() {
+        let placeholder = cover_placeholder(cover(300, 450), 64).unwrap();
+        assert_eq!(placeholder.thumbnail_height, 64);
+        let png = thumbhash_to_png(placeholder.thumbhash).unwrap();
+        let image = image::load_from_memory(&png).unwrap();
+        // ThumbHash keeps the aspect ratio.
+        assert!(image.height() > image.width());
+        assert!(thumbhash_to_png("!!".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_small_cover_is_not_upscaled() {
+        let placeholder = cover_placeholder(cover(40, 60), 128).unwrap();
+        assert_eq!(
+            (placeholder.thumbnail_width, placeholder.thumbnail_height),
+            (40, 60)
+        );
+    }
+}
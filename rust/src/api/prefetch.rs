@@ -0,0 +1,361 @@
+//! Downloading the chapters after the one being read, ahead of time.
+//!
+//! The app calls `update_prefetch` whenever the reader moves to another
+//! chapter. The upcoming chapters are queued as low-priority downloads,
+//! enough to cover `lookahead_minutes` at the reader's speed, so they never
+//! hold up downloads the user asked for. A plan for another book or source
+//! cancels the previous plan's unfinished tasks, and nothing is prefetched
+//! offline or, unless allowed, on a metered connection.
+
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard};
+
+use anyhow::{anyhow, Result};
+
+use crate::api::downloader::{
+    cancel_download, enqueue_low_priority, task_state, DownloadRequest, DownloadState,
+};
+use crate::api::error::ApiError;
+
+/// Characters assumed for a chapter whose length is unknown.
+const DEFAULT_CHAPTER_CHARS: u32 = 3000;
+
+static CONFIG: Mutex<Option<PrefetchConfig>> = Mutex::new(None);
+static PREFETCH: Mutex<Option<Prefetch>> = Mutex::new(None);
+
+#[derive(Debug, Clone)]
+pub struct PrefetchConfig {
+    /// Chapters queued ahead at most.
+    pub max_chapters: u32,
+    /// Chapters queued ahead when the reading speed is unknown.
+    pub default_chapters: u32,
+    /// Reading time to keep downloaded ahead.
+    pub lookahead_minutes: u32,
+    /// Whether to prefetch on metered connections.
+    pub allow_metered: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkType {
+    /// Wi-Fi or ethernet without a data cap.
+    Unmetered,
+    /// Cellular data or a hotspot.
+    Metered,
+    Offline,
+}
+
+#[derive(Debug, Clone)]
+pub struct PrefetchChapter {
+    /// Position in the table of contents.
+    pub index: u32,
+    pub request: DownloadRequest,
+    /// Length in characters, if known.
+    pub length: Option<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PrefetchPlan {
+    /// Identifies the book and its source, e.g. `<book id>@<source url>`.
+    pub key: String,
+    /// Index of the chapter being read.
+    pub current_index: u32,
+    /// Chapters that may be prefetched, in any order; ones at or before
+    /// `current_index` and ones already downloaded are skipped.
+    pub chapters: Vec<PrefetchChapter>,
+    /// Reading speed in characters per minute, if known.
+    pub chars_per_minute: Option<u32>,
+    pub network: NetworkType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrefetchPause {
+    Offline,
+    /// On a metered connection, and `allow_metered` is off.
+    Metered,
+}
+
+#[derive(Debug, Clone)]
+pub struct PrefetchTask {
+    pub chapter_index: u32,
+    pub task_id: u32,
+    pub state: DownloadState,
+}
+
+#[derive(Debug, Clone)]
+pub struct PrefetchStatus {
+    /// Key of the current plan.
+    pub key: Option<String>,
+    /// Why the current plan queues nothing.
+    pub paused: Option<PrefetchPause>,
+    /// Tasks of the current plan, by chapter index.
+    pub tasks: Vec<PrefetchTask>,
+}
+
+struct Prefetch {
+    key: String,
+    paused: Option<PrefetchPause>,
+    tasks: Vec<Queued>,
+}
+
+struct Queued {
+    chapter_index: u32,
+    task_id: u32,
+    destination: String,
+}
+
+/// Settings used until `configure_prefetch` is called.
+#[flutter_rust_bridge::frb]
+pub fn default_prefetch_config() -> PrefetchConfig {
+    PrefetchConfig {
+        max_chapters: 5,
+        default_chapters: 2,
+        lookahead_minutes: 30,
+        allow_metered: false,
+    }
+}
+
+/// Change the prefetch settings; they apply from the next `update_prefetch`.
+#[flutter_rust_bridge::frb]
+pub fn configure_prefetch(config: PrefetchConfig) -> Result<(), ApiError> {
+    if config.max_chapters == 0 {
+        return Err(anyhow!("max_chapters must be at least 1").into());
+    }
+    *lock(&CONFIG) = Some(config);
+    Ok(())
+}
+
+/// Queue the chapters after the current one and cancel tasks no longer
+/// needed, then return the resulting status.
+///
+/// # Arguments
+/// * `plan` - Book, position, upcoming chapters, reading speed and network
+#[flutter_rust_bridge::frb]
+pub fn update_prefetch(plan: PrefetchPlan) -> Result<PrefetchStatus, ApiError> {
+    let config = lock(&CONFIG)
+        .clone()
+        .unwrap_or_else(default_prefetch_config);
+    let mut prefetch = lock(&PREFETCH);
+    if prefetch.as_ref().is_some_and(|p| p.key != plan.key) {
+        cancel(prefetch.take().unwrap().tasks);
+    }
+    let current = prefetch.get_or_insert_with(|| Prefetch {
+        key: plan.key.clone(),
+        paused: None,
+        tasks: Vec::new(),
+    });
+
+    current.paused = pause(plan.network, &config);
+    let wanted = if current.paused.is_some() {
+        Vec::new()
+    } else {
+        wanted(&plan, &config)
+    };
+    // Keep unfinished tasks that are still wanted, and finished ones ahead
+    // of the reader so their status stays visible. A finished task whose
+    // chapter is still wanted failed, and is queued again below.
+    let (keep, drop) = current.tasks.drain(..).partition(|task: &Queued| {
+        let wanted = wanted.iter().any(|c| c.index == task.chapter_index);
+        match task_state(task.task_id) {
+            Some(_) => wanted,
+            None => !wanted && task.chapter_index > plan.current_index,
+        }
+    });
+    current.tasks = keep;
+    cancel(drop);
+
+    for chapter in wanted {
+        if current
+            .tasks
+            .iter()
+            .any(|task| task.chapter_index == chapter.index)
+        {
+            continue;
+        }
+        // Skip chapters the user is already downloading.
+        if let Ok(task_id) = enqueue_low_priority(chapter.request.clone()) {
+            current.tasks.push(Queued {
+                chapter_index: chapter.index,
+                task_id,
+                destination: chapter.request.destination.clone(),
+            });
+        }
+    }
+    Ok(status(prefetch.as_ref()))
+}
+
+/// Cancel every unfinished prefetch task, as when switching sources, and
+/// forget the current plan. Returns the number of tasks cancelled.
+#[flutter_rust_bridge::frb]
+pub fn cancel_prefetch() -> u32 {
+    lock(&PREFETCH)
+        .take()
+        .map_or(0, |prefetch| cancel(prefetch.tasks))
+}
+
+/// The current plan and the state of its tasks.
+#[flutter_rust_bridge::frb]
+pub fn prefetch_status() -> PrefetchStatus {
+    status(lock(&PREFETCH).as_ref())
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn status(prefetch: Option<&Prefetch>) -> PrefetchStatus {
+    let Some(prefetch) = prefetch else {
+        return PrefetchStatus {
+            key: None,
+            paused: None,
+            tasks: Vec::new(),
+        };
+    };
+    let mut tasks: Vec<PrefetchTask> = prefetch
+        .tasks
+        .iter()
+        .map(|task| PrefetchTask {
+            chapter_index: task.chapter_index,
+            task_id: task.task_id,
+            state: task_state(task.task_id).unwrap_or_else(|| {
+                if Path::new(&task.destination).exists() {
+                    DownloadState::Completed
+                } else {
+                    DownloadState::Failed
+                }
+            }),
+        })
+        .collect();
+    tasks.sort_by_key(|task| task.chapter_index);
+    PrefetchStatus {
+        key: Some(prefetch.key.clone()),
+        paused: prefetch.paused,
+        tasks,
+    }
+}
+
+/// Cancel the tasks still queued or running and return how many there were.
+fn cancel(tasks: Vec<Queued>) -> u32 {
+    tasks
+        .into_iter()
+        .filter(|task| cancel_download(task.task_id))
+        .count() as u32
+}
+
+fn pause(network: NetworkType, config: &PrefetchConfig) -> Option<PrefetchPause> {
+    match network {
+        NetworkType::Unmetered => None,
+        NetworkType::Metered if config.allow_metered => None,
+        NetworkType::Metered => Some(PrefetchPause::Metered),
+        NetworkType::Offline => Some(PrefetchPause::Offline),
+    }
+}
+
+/// The chapters to have downloaded ahead: the next ones not yet on disk,
+/// covering `lookahead_minutes` at the reader's speed.
+fn wanted<'a>(plan: &'a PrefetchPlan, config: &PrefetchConfig) -> Vec<&'a PrefetchChapter> {
+    let mut upcoming: Vec<&PrefetchChapter> = plan
+        .chapters
+        .iter()
+        .filter(|c| c.index > plan.current_index)
+        .collect();
+    upcoming.sort_by_key(|c| c.index);
+    let count = chapter_count(&upcoming, plan.chars_per_minute, config);
+    upcoming
+        .into_iter()
+        .take(count)
+        .filter(|c| !Path::new(&c.request.destination).exists())
+        .collect()
+}
+
+/// How many of `upcoming` it takes to fill the lookahead time, at least
+/// one and at most `max_chapters`.
+fn chapter_count(
+    upcoming: &[&PrefetchChapter],
+    chars_per_minute: Option<u32>,
+    config: &PrefetchConfig,
+) -> usize {
+    let max = config.max_chapters as usize;
+    let Some(speed) = chars_per_minute.filter(|&s| s > 0) else {
+        return (config.default_chapters as usize).min(max);
+    };
+    let budget = config.lookahead_minutes as u64 * speed as u64;
+    let mut total = 0u64;
+    let mut count = 0;
+    for chapter in upcoming.iter().take(max) {
+        count += 1;
+        total += chapter.length.unwrap_or(DEFAULT_CHAPTER_CHARS) as u64;
+        if total >= budget {
+            break;
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_dirs::temp_dir;
+
+    fn chapter(index: u32, length: Option<u32>) -> PrefetchChapter {
+        PrefetchChapter {
+            index,
+            request: DownloadRequest {
+                url: format!("http://127.0.0.1:9/{index}"),
+                destination: temp_dir("prefetch")
+                    .join(format!("missing_{index}.txt"))
+                    .to_string_lossy()
+                    .into_owned(),
+                headers: vec![],
+                checksum: None,
+            },
+            length,
+        }
+    }
+
+    fn plan(current_index: u32, chars_per_minute: Option<u32>) -> PrefetchPlan {
+        PrefetchPlan {
+            key: "book@source".to_string(),
+            current_index,
+            chapters: (0..20).rev().map(|i| chapter(i, Some(4000))).collect(),
+            chars_per_minute,
+            network: NetworkType::Unmetered,
+        }
+    }
+
+    #[test]
+    fn test_wanted_chapters() {
+        let config = default_prefetch_config();
+        let indices = |plan: &PrefetchPlan| -> Vec<u32> {
+            wanted(plan, &config).iter().map(|c| c.index).collect()
+        };
+        // Unknown speed: the default count.
+        assert_eq!(indices(&plan(3, None)), vec![4, 5]);
+        // 30 minutes at 400 characters a minute is three 4000-character chapters.
+        assert_eq!(indices(&plan(3, Some(400))), vec![4, 5, 6]);
+        // A fast reader hits the cap.
+        assert_eq!(indices(&plan(3, Some(10_000))), vec![4, 5, 6, 7, 8]);
+        // At least the next chapter, and nothing past the end.
+        assert_eq!(indices(&plan(3, Some(1))), vec![4]);
+        assert!(indices(&plan(19, Some(400))).is_empty());
+    }
+
+    #[test]
+    fn test_metered_connections() {
+        let mut config = default_prefetch_config();
+        assert_eq!(pause(NetworkType::Unmetered, &config), None);
+        assert_eq!(
+            pause(NetworkType::Metered, &config),
+            Some(PrefetchPause::Metered)
+        );
+        assert_eq!(
+            pause(NetworkType::Offline, &config),
+            Some(PrefetchPause::Offline)
+        );
+        config.allow_metered = true;
+        assert_eq!(pause(NetworkType::Metered, &config), None);
+        assert_eq!(
+            pause(NetworkType::Offline, &config),
+            Some(PrefetchPause::Offline)
+        );
+    }
+}
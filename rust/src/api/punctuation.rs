@@ -0,0 +1,229 @@
+//! Punctuation repair for books converted from scans.
+//!
+//! OCR output tends to lose the direction of quotes, read `“”` or `「」`
+//! around dialogue as parentheses and see spaces between CJK characters
+//! that the page never had. `repair_punctuation` fixes these line by line
+//! and counts each kind of fix, so the app can show what would change
+//! before the text is saved. `RepairLevel::Conservative` only makes fixes
+//! that are safe on any book; `Aggressive` also guesses where the text is
+//! ambiguous, which suits badly scanned books.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepairLevel {
+    /// Remove spaces next to Han characters, pair quotes on lines with an
+    /// even number of them, and replace parentheses holding a sentence
+    /// after a colon, as in `他说：（走吧。）`.
+    Conservative,
+    /// Also remove ideographic spaces and spaces between CJK punctuation,
+    /// pair quotes on lines with an odd number, leaving the last one open,
+    /// and replace parentheses holding a whole paragraph that ends a sentence.
+    Aggressive,
+}
+
+#[derive(Debug, Clone)]
+pub struct PunctuationRepair {
+    /// The repaired text.
+    pub text: String,
+    /// Total of the counts below.
+    pub changes: u32,
+    /// Runs of spaces removed.
+    pub spaces_removed: u32,
+    /// Quotes whose direction or form changed.
+    pub quotes_fixed: u32,
+    /// Pairs of parentheses turned into quotes.
+    pub brackets_replaced: u32,
+}
+
+/// Repair the punctuation of chapter text. Line endings are kept.
+///
+/// # Arguments
+/// * `text` - Chapter text
+/// * `level` - How far to go where the intended punctuation is a guess
+#[flutter_rust_bridge::frb]
+pub fn repair_punctuation(text: String, level: RepairLevel) -> PunctuationRepair {
+    let mut repair = PunctuationRepair {
+        text: String::with_capacity(text.len()),
+        changes: 0,
+        spaces_removed: 0,
+        quotes_fixed: 0,
+        brackets_replaced: 0,
+    };
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\r', '\n']);
+        let mut chars: Vec<char> = content.chars().collect();
+        repair.brackets_replaced += replace_dialogue_brackets(&mut chars, level);
+        repair.quotes_fixed += pair_quotes(&mut chars, level);
+        repair.spaces_removed += remove_cjk_spaces(&mut chars, level);
+        repair.text.extend(chars);
+        repair.text.push_str(&line[content.len()..]);
+    }
+    repair.changes = repair.spaces_removed + repair.quotes_fixed + repair.brackets_replaced;
+    repair
+}
+
+/// Turn parentheses around dialogue into quotes and return how many pairs
+/// were replaced.
+fn replace_dialogue_brackets(chars: &mut [char], level: RepairLevel) -> u32 {
+    let mut replaced = 0;
+    let mut i = 0;
+    while i < chars.len() {
+        if !matches!(chars[i], '(' | '（') {
+            i += 1;
+            continue;
+        }
+        let Some(close) = chars[i + 1..]
+            .iter()
+            .position(|c| matches!(c, '(' | '（' | ')' | '）'))
+            .map(|offset| i + 1 + offset)
+            .filter(|&close| matches!(chars[close], ')' | '）'))
+        else {
+            i += 1;
+            continue;
+        };
+        let inner = &chars[i + 1..close];
+        let sentence = inner.iter().any(|&c| is_han(c))
+            && inner
+                .iter()
+                .rev()
+                .find(|c| !c.is_whitespace())
+                .is_some_and(|c| matches!(c, '。' | '！' | '？' | '…' | '!' | '?'));
+        let before = chars[..i].iter().rev().find(|c| !c.is_whitespace());
+        let after_colon = before.is_some_and(|c| matches!(c, ':' | '：'));
+        let whole_line = before.is_none() && chars[close + 1..].iter().all(|c| c.is_whitespace());
+        if sentence && (after_colon || level == RepairLevel::Aggressive && whole_line) {
+            chars[i] = '“';
+            chars[close] = '”';
+            replaced += 1;
+        }
+        i = close + 1;
+    }
+    replaced
+}
+
+/// Alternate opening and closing quotes and return how many changed.
+/// Double quotes are paired on every line, single quotes only on lines
+/// with CJK text, where they cannot be apostrophes.
+fn pair_quotes(chars: &mut [char], level: RepairLevel) -> u32 {
+    let cjk_line = chars.iter().any(|&c| is_han(c));
+    let letter = |i: Option<usize>| {
+        i.and_then(|i| chars.get(i))
+            .is_some_and(|c| c.is_alphanumeric())
+    };
+    let doubles: Vec<usize> = (0..chars.len())
+        .filter(|&i| matches!(chars[i], '"' | '“' | '”'))
+        .collect();
+    let singles: Vec<usize> = (0..chars.len())
+        .filter(|&i| cjk_line && matches!(chars[i], '\'' | '‘' | '’'))
+        .filter(|&i| !(letter(i.checked_sub(1)) && letter(Some(i + 1))))
+        .collect();
+
+    let mut fixed = 0;
+    for (positions, open, close) in [(doubles, '“', '”'), (singles, '‘', '’')] {
+        if positions.len() % 2 == 1 && level == RepairLevel::Conservative {
+            continue;
+        }
+        for (n, i) in positions.into_iter().enumerate() {
+            let quote = if n % 2 == 0 { open } else { close };
+            if chars[i] != quote {
+                chars[i] = quote;
+                fixed += 1;
+            }
+        }
+    }
+    fixed
+}
+
+/// Remove runs of spaces next to Han characters, between them and other
+/// CJK characters or quotes, and return how many. Leading and trailing
+/// spaces, such as paragraph indents, are kept.
+fn remove_cjk_spaces(chars: &mut Vec<char>, level: RepairLevel) -> u32 {
+    let space =
+        |c: char| matches!(c, ' ' | '\t') || c == '\u{3000}' && level == RepairLevel::Aggressive;
+    let mut removed = 0;
+    let mut out = Vec::with_capacity(chars.len());
+    let mut i = 0;
+    while i < chars.len() {
+        if !space(chars[i]) || out.is_empty() {
+            out.push(chars[i]);
+            i += 1;
+            continue;
+        }
+        let end = chars[i..]
+            .iter()
+            .position(|&c| !space(c))
+            .map_or(chars.len(), |offset| i + offset);
+        let before = *out.last().unwrap();
+        let spurious = chars.get(end).is_some_and(|&after| {
+            is_han(before) && (is_cjk(after) || is_quote(after))
+                || (is_cjk(before) || is_quote(before)) && is_han(after)
+                || level == RepairLevel::Aggressive && is_cjk(before) && is_cjk(after)
+        });
+        if spurious {
+            removed += 1;
+        } else {
+            out.extend_from_slice(&chars[i..end]);
+        }
+        i = end;
+    }
+    *chars = out;
+    removed
+}
+
+fn is_han(c: char) -> bool {
+    matches!(c, '\u{4E00}'..='\u{9FFF}' | '\u{3400}'..='\u{4DBF}')
+}
+
+fn is_quote(c: char) -> bool {
+    matches!(c, '“' | '”' | '‘' | '’')
+}
+
+/// Han, kana and CJK punctuation, but not quotes shared with Latin text.
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3001}'..='\u{303F}' // CJK punctuation, without the ideographic space
+        | '\u{3040}'..='\u{30FF}' // kana
+        | '\u{3400}'..='\u{4DBF}'
+        | '\u{4E00}'..='\u{9FFF}'
+        | '\u{FF01}'..='\u{FF60}') // full-width forms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conservative_repair() {
+        let text =
+            "　　他 说 ：(你 来了？)\r\n\"走吧,\"她说,\"天黑了.\"\n\"半句\n第 3 章 it's 'fine'";
+        let repair = repair_punctuation(text.to_string(), RepairLevel::Conservative);
+        assert_eq!(
+            repair.text,
+            "　　他说：“你来了？”\r\n“走吧,”她说,“天黑了.”\n\"半句\n第 3 章 it's ‘fine’"
+        );
+        assert_eq!(
+            (
+                repair.spaces_removed,
+                repair.quotes_fixed,
+                repair.brackets_replaced
+            ),
+            (3, 6, 1)
+        );
+        assert_eq!(repair.changes, 10);
+    }
+
+    #[test]
+    fn test_aggressive_repair() {
+        let text = "（你也来了？）\n他说　“好 ， 走”吧“\n(注：见后文。)\n'Tis said \"so";
+        let repair = repair_punctuation(text.to_string(), RepairLevel::Aggressive);
+        assert_eq!(
+            repair.text,
+            "“你也来了？”\n他说“好，走”吧“\n“注：见后文。”\n'Tis said “so"
+        );
+        let conservative = repair_punctuation(text.to_string(), RepairLevel::Conservative);
+        assert_eq!(
+            conservative.text,
+            "（你也来了？）\n他说　“好，走”吧“\n(注：见后文。)\n'Tis said \"so"
+        );
+        assert_eq!(conservative.changes, 2);
+    }
+}
@@ -0,0 +1,299 @@
+//! User-defined clean-up of chapter text.
+//!
+//! Rules run in order, each on the output of the previous one, so a later
+//! rule can tidy up after an earlier one. Compiled patterns are cached by
+//! pattern text, since the same rule list is applied to every chapter.
+//! Patterns are compiled with the limits of `user_regex`, and a rule that
+//! runs longer than `RULE_TIME_LIMIT` on a text fails instead of stalling
+//! the reader; `test_rule` measures a rule before it is saved.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use regex::Regex;
+
+use crate::api::error::ApiError;
+use crate::api::watermark;
+use crate::user_regex::{self, Deadline};
+
+/// Compiled patterns kept before the cache is cleared.
+const MAX_CACHED_PATTERNS: usize = 512;
+
+/// Matches reported per rule by `preview_purify`.
+const MAX_SAMPLES: usize = 5;
+
+/// Longest a regex rule may run on one text.
+pub(crate) const RULE_TIME_LIMIT: Duration = Duration::from_secs(2);
+
+static COMPILED: Mutex<Option<HashMap<String, Regex>>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PurifyRuleKind {
+    /// Replace every occurrence of `pattern` as plain text.
+    Literal,
+    /// Replace matches of a regular expression in Rust `regex` syntax.
+    /// `^` and `$` match at line boundaries, and `replacement` may refer to
+    /// groups as `$1` or `${name}`.
+    Regex,
+    /// Remove every line matching a regular expression.
+    Line,
+    /// Remove invisible watermark characters and replace look-alike letters,
+    /// as `strip_watermarks` does; `pattern` is ignored.
+    Watermark,
+}
+
+#[derive(Debug, Clone)]
+pub struct PurifyRule {
+    pub name: String,
+    pub kind: PurifyRuleKind,
+    pub pattern: String,
+    /// Ignored for `Line` and `Watermark` rules.
+    pub replacement: String,
+    pub enabled: bool,
+}
+
+/// What one rule matched during a preview.
+#[derive(Debug, Clone)]
+pub struct PurifyRuleMatch {
+    pub rule_index: u32,
+    pub name: String,
+    pub count: u32,
+    /// The first few matched texts; whole lines for `Line` rules and
+    /// character descriptions for `Watermark` rules.
+    pub samples: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PurifyReport {
+    /// The text after all rules.
+    pub text: String,
+    /// Rules that matched anything, in rule order.
+    pub matches: Vec<PurifyRuleMatch>,
+}
+
+/// Built-in rules for invisible characters and common site watermarks.
+#[flutter_rust_bridge::frb]
+pub fn default_purify_rules() -> Vec<PurifyRule> {
+    let rule = |name: &str, kind: PurifyRuleKind, pattern: &str| PurifyRule {
+        name: name.to_string(),
+        kind,
+        pattern: pattern.to_string(),
+        replacement: String::new(),
+        enabled: true,
+    };
+    vec![
+        rule("零宽字符与形近字", PurifyRuleKind::Watermark, ""),
+        rule(
+            "首发声明",
+            PurifyRuleKind::Line,
+            r"(?:本章|本书|本文)(?:由|来自).{1,20}(?:首发|提供|整理)",
+        ),
+        rule(
+            "网址提示",
+            PurifyRuleKind::Line,
+            r"(?:请记住本站|最新章节请|手机用户请(?:浏览|访问)|一秒记住)",
+        ),
+    ]
+}
+
+/// Apply enabled rules to `text` in order.
+#[flutter_rust_bridge::frb]
+pub fn purify_text(text: String, rules: Vec<PurifyRule>) -> Result<String, ApiError> {
+    let mut text = text;
+    for rule in rules.iter().filter(|rule| rule.enabled) {
+        text = apply(&text, rule, None)?;
+    }
+    Ok(text)
+}
+
+/// Apply enabled rules like `purify_text`, also reporting what each matched.
+#[flutter_rust_bridge::frb]
+pub fn preview_purify(text: String, rules: Vec<PurifyRule>) -> Result<PurifyReport, ApiError> {
+    let mut text = text;
+    let mut matches = Vec::new();
+    for (index, rule) in rules.iter().enumerate() {
+        if !rule.enabled {
+            continue;
+        }
+        let mut found = PurifyRuleMatch {
+            rule_index: index as u32,
+            name: rule.name.clone(),
+            count: 0,
+            samples: Vec::new(),
+        };
+        text = apply(&text, rule, Some(&mut found))?;
+        if found.count > 0 {
+            matches.push(found);
+        }
+    }
+    Ok(PurifyReport { text, matches })
+}
+
+pub(crate) fn apply(
+    text: &str,
+    rule: &PurifyRule,
+    found: Option<&mut PurifyRuleMatch>,
+) -> Result<String> {
+    let regex = match rule.kind {
+        PurifyRuleKind::Regex | PurifyRuleKind::Line => Some(compile(rule)?),
+        PurifyRuleKind::Literal | PurifyRuleKind::Watermark => None,
+    };
+    apply_compiled(text, rule, regex.as_ref(), found)
+}
+
+/// Apply a rule whose pattern is already compiled into `regex`, which
+/// `Regex` and `Line` rules need.
+pub(crate) fn apply_compiled(
+    text: &str,
+    rule: &PurifyRule,
+    regex: Option<&Regex>,
+    mut found: Option<&mut PurifyRuleMatch>,
+) -> Result<String> {
+    let mut record = |matched: &str| {
+        if let Some(found) = found.as_deref_mut() {
+            found.count += 1;
+            if found.samples.len() < MAX_SAMPLES {
+                found.samples.push(matched.to_string());
+            }
+        }
+    };
+
+    match rule.kind {
+        PurifyRuleKind::Literal => {
+            if rule.pattern.is_empty() {
+                return Ok(text.to_string());
+            }
+            for matched in text.matches(rule.pattern.as_str()) {
+                record(matched);
+            }
+            Ok(text.replace(&rule.pattern, &rule.replacement))
+        }
+        PurifyRuleKind::Regex => {
+            let regex =
+                regex.ok_or_else(|| anyhow!("Purify rule {} is not compiled", rule.name))?;
+            let deadline = Deadline::after(RULE_TIME_LIMIT);
+            let mut replaced = String::with_capacity(text.len());
+            let mut last = 0;
+            for captures in regex.captures_iter(text) {
+                deadline.check(&rule.name)?;
+                let matched = captures.get(0).unwrap();
+                record(matched.as_str());
+                replaced.push_str(&text[last..matched.start()]);
+                captures.expand(&rule.replacement, &mut replaced);
+                last = matched.end();
+            }
+            replaced.push_str(&text[last..]);
+            Ok(replaced)
+        }
+        PurifyRuleKind::Line => {
+            let regex =
+                regex.ok_or_else(|| anyhow!("Purify rule {} is not compiled", rule.name))?;
+            let deadline = Deadline::after(RULE_TIME_LIMIT);
+            let mut kept = String::with_capacity(text.len());
+            for line in text.split_inclusive('\n') {
+                deadline.check(&rule.name)?;
+                let content = line.trim_end_matches(['\r', '\n']);
+                if regex.is_match(content) {
+                    record(content);
+                } else {
+                    kept.push_str(line);
+                }
+            }
+            Ok(kept)
+        }
+        PurifyRuleKind::Watermark => Ok(watermark::strip(text, |found| record(&found.describe()))),
+    }
+}
+
+fn compile(rule: &PurifyRule) -> Result<Regex> {
+    let mut cache = COMPILED.lock().unwrap_or_else(|e| e.into_inner());
+    let cache = cache.get_or_insert_with(HashMap::new);
+    if let Some(regex) = cache.get(&rule.pattern) {
+        return Ok(regex.clone());
+    }
+
+    let regex = user_regex::compile(&rule.pattern, true)
+        .map_err(|e| anyhow!("Invalid purify rule {}: {e}", rule.name))?;
+    if cache.len() >= MAX_CACHED_PATTERNS {
+        cache.clear();
+    }
+    cache.insert(rule.pattern.clone(), regex.clone());
+    Ok(regex)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(kind: PurifyRuleKind, pattern: &str, replacement: &str) -> PurifyRule {
+        PurifyRule {
+            name: pattern.to_string(),
+            kind,
+            pattern: pattern.to_string(),
+            replacement: replacement.to_string(),
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn test_default_rules_strip_watermarks() {
+        let text =
+            "第一章\n天色\u{200B}渐晚。\n本章由某某网首发\n城门外行人稀少。\r\n请记住本站域名\n";
+        let cleaned = purify_text(text.to_string(), default_purify_rules()).unwrap();
+        assert_eq!(cleaned, "第一章\n天色渐晚。\n城门外行人稀少。\r\n");
+
+        let report = preview_purify("Hаrry\u{FEFF}".to_string(), default_purify_rules()).unwrap();
+        assert_eq!(report.text, "Harry");
+        assert_eq!(
+            report.matches[0].samples,
+            [
+                "U+0430 CYRILLIC LOOK-ALIKE → a",
+                "U+FEFF ZERO WIDTH NO-BREAK SPACE"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rules_run_in_order_with_captures() {
+        let rules = vec![
+            rule(PurifyRuleKind::Literal, "(广告)", ""),
+            rule(PurifyRuleKind::Regex, r"^　+(.+)$", "$1"),
+            rule(PurifyRuleKind::Regex, r"(\d+)元", "${1} 块"),
+            PurifyRule {
+                enabled: false,
+                ..rule(PurifyRuleKind::Line, "块", "")
+            },
+        ];
+        let text = "　　他花了(广告)5元。\n　　又花了3元。";
+        let cleaned = purify_text(text.to_string(), rules).unwrap();
+        assert_eq!(cleaned, "他花了5 块。\n又花了3 块。");
+    }
+
+    #[test]
+    fn test_preview_reports_matches() {
+        let rules = vec![
+            rule(PurifyRuleKind::Literal, "xx", "yy"),
+            rule(PurifyRuleKind::Line, "^---", ""),
+            rule(PurifyRuleKind::Regex, "never", ""),
+        ];
+        let report = preview_purify("axxbxx\n---\nc".to_string(), rules).unwrap();
+        assert_eq!(report.text, "ayybyy\nc");
+        let summary: Vec<_> = report
+            .matches
+            .iter()
+            .map(|m| (m.rule_index, m.count, m.samples.clone()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (0, 2, vec!["xx".to_string(), "xx".to_string()]),
+                (1, 1, vec!["---".to_string()]),
+            ]
+        );
+
+        let invalid = vec![rule(PurifyRuleKind::Regex, "(", "")];
+        assert!(purify_text("x".to_string(), invalid).is_err());
+    }
+}
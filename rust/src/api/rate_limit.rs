@@ -0,0 +1,407 @@
+//! Per-host rate limiting for crate-initiated requests.
+//!
+//! Every agent from `network::agent` sends its requests through `limit`,
+//! which takes a token from the host's bucket first. Requests block while
+//! the bucket is empty or while the host is cooling down after a 429 or
+//! 503 response, so bulk chapter caching cannot flood a source. Hosts
+//! without a limit are not tracked.
+
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+use std::sync::{Condvar, Mutex, MutexGuard, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Result};
+
+use crate::api::error::ApiError;
+use crate::api::http_cache::parse_http_date;
+use crate::frb_generated::StreamSink;
+
+/// Upper bound for a cool-down requested by `Retry-After`.
+const MAX_COOLDOWN: Duration = Duration::from_secs(15 * 60);
+
+static LIMITER: OnceLock<Limiter> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    /// Sustained request rate.
+    pub requests_per_second: f64,
+    /// Requests that may be sent back to back after a quiet period.
+    pub burst: u32,
+    /// Random delay of up to this many milliseconds added to each request.
+    pub jitter_ms: u32,
+    /// Pause after a 429 or 503 response; a longer `Retry-After` wins.
+    pub cooldown_secs: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct RateLimitStatus {
+    pub host: String,
+    /// Requests blocked waiting for their turn.
+    pub waiting: u32,
+    /// Requests that could be sent immediately.
+    pub available: u32,
+    /// Milliseconds until the host's cool-down ends, or 0.
+    pub cooldown_ms: u64,
+}
+
+/// Polite settings for a typical book source.
+#[flutter_rust_bridge::frb]
+pub fn default_rate_limit() -> RateLimit {
+    RateLimit {
+        requests_per_second: 2.0,
+        burst: 4,
+        jitter_ms: 250,
+        cooldown_secs: 30,
+    }
+}
+
+/// Limit requests to every host, or remove the limit with `None` (the
+/// default). Hosts with their own setting from `set_host_rate_limit` are
+/// not affected.
+#[flutter_rust_bridge::frb]
+pub fn set_rate_limit(limit: Option<RateLimit>) -> Result<(), ApiError> {
+    if let Some(limit) = &limit {
+        validate(limit)?;
+    }
+    limiter().update(|state| state.default = limit);
+    Ok(())
+}
+
+/// Override the rate limit for one book source.
+///
+/// # Arguments
+/// * `host` - Host name, e.g. `www.example.com`; subdomains are not included
+/// * `limit` - Limit for this host, or `None` to send its requests unthrottled
+#[flutter_rust_bridge::frb]
+pub fn set_host_rate_limit(host: String, limit: Option<RateLimit>) -> Result<(), ApiError> {
+    if let Some(limit) = &limit {
+        validate(limit)?;
+    }
+    limiter().update(|state| {
+        state.host_limits.insert(host.to_ascii_lowercase(), limit);
+    });
+    Ok(())
+}
+
+/// Remove a host's rate limit override, so it uses the global limit again.
+#[flutter_rust_bridge::frb]
+pub fn clear_host_rate_limit(host: String) {
+    limiter().update(|state| {
+        state.host_limits.remove(&host.to_ascii_lowercase());
+    });
+}
+
+/// The current state of every rate-limited host that has sent requests.
+#[flutter_rust_bridge::frb]
+pub fn rate_limit_status() -> Vec<RateLimitStatus> {
+    let limiter = limiter();
+    let state = lock(&limiter.state);
+    let now = Instant::now();
+    let mut statuses: Vec<_> = state
+        .buckets
+        .iter()
+        .filter_map(|(host, bucket)| Some(bucket.status(host, &state.limit_for(host)?, now)))
+        .collect();
+    statuses.sort_by(|a, b| a.host.cmp(&b.host));
+    statuses
+}
+
+/// Receive a host's status whenever one of its requests starts waiting,
+/// is sent or triggers a cool-down, replacing any previous stream.
+#[flutter_rust_bridge::frb]
+pub fn rate_limit_events(sink: StreamSink<RateLimitStatus>) -> Result<(), ApiError> {
+    *lock(&limiter().listener) = Some(Box::new(move |status| {
+        let _ = sink.add(status);
+    }));
+    Ok(())
+}
+
+/// `ureq` middleware that applies the limits.
+// The signature is fixed by `ureq::Middleware`.
+#[allow(clippy::result_large_err)]
+pub(crate) fn limit(
+    request: ureq::Request,
+    next: ureq::MiddlewareNext,
+) -> Result<ureq::Response, ureq::Error> {
+    let Ok(url) = request.request_url() else {
+        return next.handle(request);
+    };
+    let host = url.host().to_ascii_lowercase();
+    let limiter = limiter();
+    limiter.acquire(&host);
+    let result = next.handle(request);
+    if let Err(ureq::Error::Status(429 | 503, response)) = &result {
+        limiter.cool_down(&host, response.header("Retry-After"));
+    }
+    result
+}
+
+fn validate(limit: &RateLimit) -> Result<()> {
+    if !(limit.requests_per_second.is_finite() && limit.requests_per_second > 0.0) {
+        return Err(anyhow!("Requests per second must be positive"));
+    }
+    if limit.burst == 0 {
+        return Err(anyhow!("Burst must be at least 1"));
+    }
+    Ok(())
+}
+
+fn limiter() -> &'static Limiter {
+    LIMITER.get_or_init(|| Limiter {
+        state: Mutex::new(Limits::default()),
+        changed: Condvar::new(),
+        listener: Mutex::new(None),
+    })
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+type Listener = Box<dyn Fn(RateLimitStatus) + Send + Sync>;
+
+struct Limiter {
+    state: Mutex<Limits>,
+    /// Signalled when the limits change so waiting requests re-check them.
+    changed: Condvar,
+    listener: Mutex<Option<Listener>>,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+struct Limits {
+    default: Option<RateLimit>,
+    /// Per-host overrides; `None` means unthrottled.
+    host_limits: HashMap<String, Option<RateLimit>>,
+    buckets: HashMap<String, Bucket>,
+}
+
+impl Limits {
+    fn limit_for(&self, host: &str) -> Option<RateLimit> {
+        match self.host_limits.get(host) {
+            Some(limit) => *limit,
+            None => self.default,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+    cooldown_until: Option<Instant>,
+    waiting: u32,
+}
+
+impl Bucket {
+    fn new(limit: &RateLimit, now: Instant) -> Self {
+        Self {
+            tokens: limit.burst as f64,
+            updated: now,
+            cooldown_until: None,
+            waiting: 0,
+        }
+    }
+
+    fn refill(&mut self, limit: &RateLimit, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * limit.requests_per_second).min(limit.burst as f64);
+        self.updated = now;
+    }
+
+    /// Take a token, or return how long to wait before trying again.
+    fn take(&mut self, limit: &RateLimit, now: Instant) -> Result<(), Duration> {
+        if let Some(until) = self.cooldown_until {
+            if until > now {
+                return Err(until - now);
+            }
+            self.cooldown_until = None;
+            // Resume gently instead of releasing a full burst at once.
+            self.tokens = self.tokens.min(1.0);
+            self.updated = now;
+        }
+        self.refill(limit, now);
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - self.tokens) / limit.requests_per_second,
+            ))
+        }
+    }
+
+    fn status(&self, host: &str, limit: &RateLimit, now: Instant) -> RateLimitStatus {
+        let mut bucket = *self;
+        bucket.refill(limit, now);
+        let cooldown = self
+            .cooldown_until
+            .map_or(Duration::ZERO, |until| until.saturating_duration_since(now));
+        RateLimitStatus {
+            host: host.to_string(),
+            waiting: self.waiting,
+            available: if cooldown.is_zero() {
+                bucket.tokens as u32
+            } else {
+                0
+            },
+            cooldown_ms: cooldown.as_millis() as u64,
+        }
+    }
+}
+
+impl Limiter {
+    fn update(&self, change: impl FnOnce(&mut Limits)) {
+        change(&mut lock(&self.state));
+        self.changed.notify_all();
+    }
+
+    fn acquire(&self, host: &str) {
+        let mut state = lock(&self.state);
+        let mut queued = false;
+        let mut jitter = 0;
+        // The limit is looked up on every pass so a changed or removed
+        // limit applies to requests already waiting.
+        while let Some(limit) = state.limit_for(host) {
+            let now = Instant::now();
+            let bucket = state
+                .buckets
+                .entry(host.to_string())
+                .or_insert_with(|| Bucket::new(&limit, now));
+            let wait = match bucket.take(&limit, now) {
+                Ok(()) => {
+                    jitter = limit.jitter_ms;
+                    break;
+                }
+                Err(wait) => wait,
+            };
+            if !queued {
+                queued = true;
+                bucket.waiting += 1;
+                self.emit(&state, host);
+            }
+            state = self
+                .changed
+                .wait_timeout(state, wait)
+                .unwrap_or_else(|e| e.into_inner())
+                .0;
+        }
+        if let Some(bucket) = state.buckets.get_mut(host) {
+            if queued {
+                bucket.waiting -= 1;
+            }
+            self.emit(&state, host);
+        }
+        drop(state);
+        if jitter > 0 {
+            let random = RandomState::new().hash_one(Instant::now());
+            thread::sleep(Duration::from_millis(random % (jitter as u64 + 1)));
+        }
+    }
+
+    fn cool_down(&self, host: &str, retry_after: Option<&str>) {
+        let mut state = lock(&self.state);
+        let Some(limit) = state.limit_for(host) else {
+            return;
+        };
+        let now = Instant::now();
+        let pause = Duration::from_secs(limit.cooldown_secs as u64)
+            .max(retry_after.and_then(parse_retry_after).unwrap_or_default())
+            .min(MAX_COOLDOWN);
+        let bucket = state
+            .buckets
+            .entry(host.to_string())
+            .or_insert_with(|| Bucket::new(&limit, now));
+        let until = now + pause;
+        if bucket.cooldown_until.is_none_or(|current| current < until) {
+            bucket.cooldown_until = Some(until);
+        }
+        self.emit(&state, host);
+    }
+
+    fn emit(&self, state: &Limits, host: &str) {
+        let (Some(bucket), Some(limit)) = (state.buckets.get(host), state.limit_for(host)) else {
+            return;
+        };
+        if let Some(listener) = lock(&self.listener).as_ref() {
+            listener(bucket.status(host, &limit, Instant::now()));
+        }
+    }
+}
+
+/// A `Retry-After` value, given either in seconds or as an HTTP date.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs() as i64;
+    let seconds = parse_http_date(value)? - now;
+    Some(Duration::from_secs(seconds.max(0) as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limit(requests_per_second: f64, burst: u32) -> RateLimit {
+        RateLimit {
+            requests_per_second,
+            burst,
+            jitter_ms: 0,
+            cooldown_secs: 10,
+        }
+    }
+
+    #[test]
+    fn test_bucket_bursts_then_waits() {
+        let limit = limit(2.0, 3);
+        let start = Instant::now();
+        let mut bucket = Bucket::new(&limit, start);
+        for _ in 0..3 {
+            assert_eq!(bucket.take(&limit, start), Ok(()));
+        }
+        assert_eq!(bucket.take(&limit, start), Err(Duration::from_millis(500)));
+        let later = start + Duration::from_millis(500);
+        assert_eq!(bucket.take(&limit, later), Ok(()));
+        // Refills never exceed the burst.
+        let idle = later + Duration::from_secs(60);
+        assert_eq!(bucket.status("a", &limit, idle).available, 3);
+    }
+
+    #[test]
+    fn test_cooldown_blocks_and_resumes_gently() {
+        let limit = limit(1.0, 5);
+        let start = Instant::now();
+        let mut bucket = Bucket::new(&limit, start);
+        bucket.cooldown_until = Some(start + Duration::from_secs(10));
+        assert_eq!(
+            bucket.take(&limit, start + Duration::from_secs(4)),
+            Err(Duration::from_secs(6))
+        );
+        let status = bucket.status("a", &limit, start);
+        assert_eq!((status.available, status.cooldown_ms), (0, 10_000));
+
+        let after = start + Duration::from_secs(10);
+        assert_eq!(bucket.take(&limit, after), Ok(()));
+        assert!(bucket.take(&limit, after).is_err());
+    }
+
+    #[test]
+    fn test_parse_retry_after_and_validate() {
+        assert_eq!(parse_retry_after(" 120 "), Some(Duration::from_secs(120)));
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon"), None);
+
+        assert!(validate(&limit(0.5, 1)).is_ok());
+        assert!(validate(&limit(0.0, 1)).is_err());
+        assert!(validate(&limit(f64::NAN, 1)).is_err());
+        assert!(validate(&limit(1.0, 0)).is_err());
+    }
+}
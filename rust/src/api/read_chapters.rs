@@ -0,0 +1,306 @@
+//! Which chapters of a book have been read.
+//!
+//! Chapters are mostly read in order, so a book's read chapters form a few
+//! runs even in a 3000-chapter serial. They are kept as a sorted list of
+//! disjoint `start..end` runs, stored in the library database as one blob
+//! per book of little-endian `u32` pairs: a book read from the start takes
+//! 8 bytes, and each skipped stretch adds another 8. Counting and finding
+//! unread chapters only walks the runs.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::anyhow;
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::api::db;
+use crate::api::error::ApiError;
+
+/// A run of read chapters, `start` included and `end` not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChapterRange {
+    pub start: u32,
+    pub end: u32,
+}
+
+/// Mark chapters as read and return how many were not read before.
+///
+/// # Arguments
+/// * `book_id` - Book the chapters belong to
+/// * `start` - First chapter index
+/// * `end` - Index after the last chapter, e.g. `start + 1` for one chapter
+#[flutter_rust_bridge::frb]
+pub fn mark_chapters_read(book_id: String, start: u32, end: u32) -> Result<u32, ApiError> {
+    update(&book_id, start, end, true)
+}
+
+/// Mark chapters as unread and return how many were read before.
+///
+/// # Arguments
+/// * `book_id` - Book the chapters belong to
+/// * `start` - First chapter index
+/// * `end` - Index after the last chapter; `u32::MAX` for the rest of the book
+#[flutter_rust_bridge::frb]
+pub fn mark_chapters_unread(book_id: String, start: u32, end: u32) -> Result<u32, ApiError> {
+    update(&book_id, start, end, false)
+}
+
+/// Replace a book's read chapters, such as when moving over a list kept
+/// elsewhere.
+///
+/// # Arguments
+/// * `book_id` - Book the chapters belong to
+/// * `chapters` - Indexes of the read chapters, in any order
+#[flutter_rust_bridge::frb]
+pub fn set_read_chapters(book_id: String, chapters: Vec<u32>) -> Result<(), ApiError> {
+    let mut runs = Runs::default();
+    for chapter in chapters {
+        runs.insert(chapter, chapter.saturating_add(1));
+    }
+    Ok(db::with_db(|db| save(db, &book_id, &runs))?)
+}
+
+/// The runs of read chapters of a book, in order.
+///
+/// # Arguments
+/// * `book_id` - Book the chapters belong to
+#[flutter_rust_bridge::frb]
+pub fn read_chapter_ranges(book_id: String) -> Result<Vec<ChapterRange>, ApiError> {
+    let runs = db::with_db(|db| load(db, &book_id))?;
+    Ok(runs
+        .0
+        .into_iter()
+        .map(|(start, end)| ChapterRange { start, end })
+        .collect())
+}
+
+/// How many of a book's chapters have not been read.
+///
+/// # Arguments
+/// * `book_id` - Book the chapters belong to
+/// * `chapter_count` - Chapters the book has; read chapters past it are ignored
+#[flutter_rust_bridge::frb]
+pub fn unread_chapter_count(book_id: String, chapter_count: u32) -> Result<u32, ApiError> {
+    let runs = db::with_db(|db| load(db, &book_id))?;
+    Ok(chapter_count - runs.count_below(chapter_count))
+}
+
+/// The first chapter at or after `from` that has not been read, or `None`
+/// if all the rest have been.
+///
+/// # Arguments
+/// * `book_id` - Book the chapters belong to
+/// * `from` - Index to look from, 0 for the start of the book
+/// * `chapter_count` - Chapters the book has
+#[flutter_rust_bridge::frb]
+pub fn first_unread_chapter(
+    book_id: String,
+    from: u32,
+    chapter_count: u32,
+) -> Result<Option<u32>, ApiError> {
+    let runs = db::with_db(|db| load(db, &book_id))?;
+    Ok(Some(runs.first_gap(from)).filter(|&chapter| chapter < chapter_count))
+}
+
+/// Carry a book's read chapters over to a new chapter list: chapter `i`
+/// of the `chapter_count` new ones is read if `old_chapter(i)` was.
+pub(crate) fn remap(
+    db: &Connection,
+    book_id: &str,
+    chapter_count: u32,
+    old_chapter: impl Fn(u32) -> u32,
+) -> rusqlite::Result<()> {
+    let old = load(db, book_id)?;
+    let mut runs = Runs::default();
+    for chapter in (0..chapter_count).filter(|&i| old.contains(old_chapter(i))) {
+        runs.insert(chapter, chapter + 1);
+    }
+    save(db, book_id, &runs)
+}
+
+fn update(book_id: &str, start: u32, end: u32, read: bool) -> Result<u32, ApiError> {
+    if start >= end {
+        return Err(anyhow!("Invalid chapter range {start}..{end}").into());
+    }
+    Ok(db::with_db(|db| {
+        let tx = db.transaction()?;
+        let mut runs = load(&tx, book_id)?;
+        let changed = if read {
+            runs.insert(start, end)
+        } else {
+            runs.remove(start, end)
+        };
+        if changed > 0 {
+            save(&tx, book_id, &runs)?;
+        }
+        tx.commit()?;
+        Ok(changed)
+    })?)
+}
+
+fn load(db: &Connection, book_id: &str) -> rusqlite::Result<Runs> {
+    let blob: Option<Vec<u8>> = db
+        .query_row(
+            "SELECT ranges FROM read_chapters WHERE book_id = ?1",
+            [book_id],
+            |row| row.get(0),
+        )
+        .optional()?;
+    Ok(blob.map_or_else(Runs::default, |blob| Runs::decode(&blob)))
+}
+
+fn save(db: &Connection, book_id: &str, runs: &Runs) -> rusqlite::Result<()> {
+    if runs.0.is_empty() {
+        db.execute("DELETE FROM read_chapters WHERE book_id = ?1", [book_id])?;
+        return Ok(());
+    }
+    db.execute(
+        "INSERT INTO read_chapters (book_id, ranges, updated_at) VALUES (?1, ?2, ?3)
+             ON CONFLICT (book_id) DO UPDATE SET
+                ranges = excluded.ranges,
+                updated_at = excluded.updated_at",
+        params![book_id, runs.encode(), now()],
+    )?;
+    Ok(())
+}
+
+/// Sorted, disjoint and non-touching `start..end` runs.
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Runs(Vec<(u32, u32)>);
+
+impl Runs {
+    /// Add `start..end` and return how many chapters were added.
+    fn insert(&mut self, start: u32, end: u32) -> u32 {
+        if start >= end {
+            return 0;
+        }
+        // Runs overlapping or touching the new one are merged into it.
+        let first = self.0.partition_point(|&(_, e)| e < start);
+        let last = self.0.partition_point(|&(s, _)| s <= end);
+        let covered: u32 = self.0[first..last]
+            .iter()
+            .map(|&(s, e)| e.min(end).saturating_sub(s.max(start)))
+            .sum();
+        let merged = self.0[first..last]
+            .iter()
+            .fold((start, end), |(s, e), &(rs, re)| (s.min(rs), e.max(re)));
+        self.0.splice(first..last, [merged]);
+        end - start - covered
+    }
+
+    /// Take `start..end` out and return how many chapters were removed.
+    fn remove(&mut self, start: u32, end: u32) -> u32 {
+        let mut removed = 0;
+        let mut kept = Vec::with_capacity(self.0.len() + 1);
+        for &(s, e) in &self.0 {
+            if e <= start || s >= end {
+                kept.push((s, e));
+                continue;
+            }
+            removed += e.min(end) - s.max(start);
+            if s < start {
+                kept.push((s, start));
+            }
+            if e > end {
+                kept.push((end, e));
+            }
+        }
+        self.0 = kept;
+        removed
+    }
+
+    /// Chapters in the runs before `limit`.
+    fn count_below(&self, limit: u32) -> u32 {
+        self.0
+            .iter()
+            .map(|&(s, e)| e.min(limit).saturating_sub(s))
+            .sum()
+    }
+
+    fn contains(&self, chapter: u32) -> bool {
+        let i = self.0.partition_point(|&(_, e)| e <= chapter);
+        self.0.get(i).is_some_and(|&(s, _)| s <= chapter)
+    }
+
+    /// The first chapter at or after `from` outside every run.
+    fn first_gap(&self, from: u32) -> u32 {
+        match self.0.iter().find(|&&(_, e)| e > from) {
+            Some(&(s, e)) if s <= from => e,
+            _ => from,
+        }
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.0
+            .iter()
+            .flat_map(|&(s, e)| s.to_le_bytes().into_iter().chain(e.to_le_bytes()))
+            .collect()
+    }
+
+    fn decode(data: &[u8]) -> Self {
+        let mut runs = Runs::default();
+        for pair in data.chunks_exact(8) {
+            let field = |i: usize| u32::from_le_bytes(pair[i..i + 4].try_into().unwrap());
+            runs.insert(field(0), field(4));
+        }
+        runs
+    }
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_runs_merge_and_split() {
+        let mut runs = Runs::default();
+        assert_eq!(runs.insert(0, 10), 10);
+        assert_eq!(runs.insert(20, 30), 10);
+        assert_eq!(runs.insert(5, 12), 2);
+        assert_eq!(runs.0, [(0, 12), (20, 30)]);
+        // Touching runs join.
+        assert_eq!(runs.insert(12, 20), 8);
+        assert_eq!(runs.0, [(0, 30)]);
+        assert_eq!(runs.insert(3, 4), 0);
+
+        assert_eq!(runs.remove(10, 15), 5);
+        assert_eq!(runs.remove(28, u32::MAX), 2);
+        assert_eq!(runs.0, [(0, 10), (15, 28)]);
+        assert_eq!(runs.count_below(20), 15);
+        assert_eq!(
+            (runs.first_gap(0), runs.first_gap(12), runs.first_gap(16)),
+            (10, 12, 28)
+        );
+        assert_eq!(Runs::decode(&runs.encode()), runs);
+    }
+
+    #[test]
+    fn test_read_chapters_are_stored() {
+        let mut db = Connection::open_in_memory().unwrap();
+        db::setup(&mut db).unwrap();
+        let mut runs = load(&db, "book").unwrap();
+        assert_eq!(runs.first_gap(0), 0);
+        runs.insert(0, 3000);
+        runs.remove(1500, 1501);
+        save(&db, "book", &runs).unwrap();
+        let blob: Vec<u8> = db
+            .query_row("SELECT ranges FROM read_chapters", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(blob.len(), 16);
+        let runs = load(&db, "book").unwrap();
+        assert_eq!(3000 - runs.count_below(3000), 1);
+        assert_eq!(runs.first_gap(0), 1500);
+
+        save(&db, "book", &Runs::default()).unwrap();
+        let rows: u32 = db
+            .query_row("SELECT COUNT(*) FROM read_chapters", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(rows, 0);
+    }
+}
@@ -0,0 +1,506 @@
+//! Boilerplate removal for scraped chapter pages.
+//!
+//! Book sources serve chapter text wrapped in navigation bars, ad slots and
+//! inline styles. The element holding the most non-link text (counting its
+//! own text, inline children and `<p>` children) is taken as the content,
+//! with class and id names nudging the score. Its content is then flattened
+//! into paragraphs: `<br>` and block elements end a paragraph, emphasis and
+//! images are kept as styled spans, everything else becomes plain text.
+
+use anyhow::{anyhow, Result};
+use regex::Regex;
+use scraper::node::Node;
+use scraper::{ElementRef, Html};
+use url::Url;
+
+use crate::api::error::ApiError;
+
+/// Elements that never contain chapter text.
+const DROPPED: &[&str] = &[
+    "script", "style", "noscript", "template", "iframe", "object", "embed", "form", "button",
+    "input", "select", "textarea", "nav", "footer", "aside", "svg", "head",
+];
+
+/// Elements that start a new paragraph.
+const BLOCKS: &[&str] = &[
+    "address",
+    "article",
+    "blockquote",
+    "center",
+    "dd",
+    "div",
+    "dl",
+    "dt",
+    "figcaption",
+    "figure",
+    "header",
+    "hr",
+    "li",
+    "main",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "td",
+    "th",
+    "tr",
+    "ul",
+];
+
+/// Elements whose text counts towards their parent's score.
+const INLINE: &[&str] = &[
+    "a", "abbr", "b", "cite", "code", "em", "font", "i", "mark", "q", "s", "small", "span",
+    "strong", "sub", "sup", "u",
+];
+
+/// Attributes lazy-loading scripts keep the real image URL in.
+const IMAGE_SOURCES: &[&str] = &["data-original", "data-src", "data-lazy-src", "src"];
+
+/// Content extracted from a chapter page.
+#[derive(Debug, Clone)]
+pub struct ReadableContent {
+    /// Page heading, or the document title if there is no `<h1>`.
+    pub title: Option<String>,
+    pub blocks: Vec<ReadableBlock>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadableBlockKind {
+    Paragraph,
+    Heading,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReadableBlock {
+    pub kind: ReadableBlockKind,
+    pub spans: Vec<ReadableSpan>,
+}
+
+/// A run of text in one style, or an inline image.
+#[derive(Debug, Clone)]
+pub struct ReadableSpan {
+    /// Text of the run; the alt text for images.
+    pub text: String,
+    /// Absolute image URL when this span is an image.
+    pub image_src: Option<String>,
+    pub bold: bool,
+    pub italic: bool,
+}
+
+/// Extract the main text of a scraped chapter page.
+///
+/// # Arguments
+/// * `html` - Page HTML
+/// * `base_url` - URL the page was loaded from, for resolving image sources
+#[flutter_rust_bridge::frb]
+pub fn extract_readable(
+    html: String,
+    base_url: Option<String>,
+) -> Result<ReadableContent, ApiError> {
+    let base = base_url
+        .map(|url| Url::parse(&url).map_err(|e| anyhow!("Invalid base URL {url}: {e}")))
+        .transpose()?;
+    Ok(extract(&html, base.as_ref()))
+}
+
+pub(crate) fn extract(html: &str, base: Option<&Url>) -> ReadableContent {
+    let document = Html::parse_document(html);
+    let hints = Hints::new();
+    let root = document.root_element();
+
+    let mut best: Option<(f32, ElementRef)> = None;
+    find_content(root, &hints, &mut best);
+    let content = best.map_or(root, |(_, element)| element);
+
+    let mut builder = Builder::new(base, &hints);
+    builder.walk(content);
+    builder.end_block(ReadableBlockKind::Paragraph);
+
+    ReadableContent {
+        title: title(&document),
+        blocks: builder.blocks,
+    }
+}
+
+fn title(document: &Html) -> Option<String> {
+    let first_text = |name: &str| {
+        document
+            .root_element()
+            .descendent_elements()
+            .filter(|element| element.value().name() == name)
+            .map(|element| normalize(&element.text().collect::<String>()))
+            .find(|text| !text.is_empty())
+    };
+    first_text("h1").or_else(|| first_text("title"))
+}
+
+/// Collapse whitespace runs, including no-break and ideographic spaces.
+fn normalize(text: &str) -> String {
+    text.split(|c: char| c.is_whitespace())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Class and id patterns for boilerplate and for likely content.
+struct Hints {
+    unlikely: Regex,
+    likely: Regex,
+}
+
+impl Hints {
+    fn new() -> Self {
+        Self {
+            unlikely: Regex::new(
+                r"(?i)(?:^|[\s_-])(?:ad|ads|adv|advert\w*|banner|breadcrumbs?|comments?|copyright|footer|menu|nav\w*|pager|pagination|recommend\w*|related|share|sidebar|social|sponsor\w*|toolbar|topbar)(?:$|[\s_-])",
+            )
+            .unwrap(),
+            likely: Regex::new(
+                r"(?i)(?:^|[\s_-])(?:article|booktext|chapter\w*|content\w*|entry|main|post|read\w*|text|txt)(?:$|[\s_-])",
+            )
+            .unwrap(),
+        }
+    }
+
+    fn names(element: ElementRef) -> String {
+        let element = element.value();
+        format!(
+            "{} {}",
+            element.id().unwrap_or_default(),
+            element.attr("class").unwrap_or_default()
+        )
+    }
+
+    fn is_unlikely(&self, element: ElementRef) -> bool {
+        let names = Self::names(element);
+        self.unlikely.is_match(&names) && !self.likely.is_match(&names)
+    }
+
+    fn weight(&self, element: ElementRef) -> f32 {
+        if self.likely.is_match(&Self::names(element)) {
+            1.25
+        } else {
+            1.0
+        }
+    }
+}
+
+fn is_dropped(element: ElementRef, hints: &Hints) -> bool {
+    DROPPED.contains(&element.value().name()) || hints.is_unlikely(element)
+}
+
+/// Score every element outside boilerplate and keep the best one.
+fn find_content<'a>(
+    element: ElementRef<'a>,
+    hints: &Hints,
+    best: &mut Option<(f32, ElementRef<'a>)>,
+) {
+    let score = content_score(element) * hints.weight(element);
+    if score > 0.0 && best.is_none_or(|(best_score, _)| score > best_score) {
+        *best = Some((score, element));
+    }
+    for child in element.child_elements() {
+        if !is_dropped(child, hints) {
+            find_content(child, hints, best);
+        }
+    }
+}
+
+fn content_score(element: ElementRef) -> f32 {
+    let chars = |element: ElementRef| -> usize {
+        element.text().map(|text| text.trim().chars().count()).sum()
+    };
+    let link_chars = |element: ElementRef| -> usize {
+        if element.value().name() == "a" {
+            return chars(element);
+        }
+        element
+            .descendent_elements()
+            .filter(|e| e.value().name() == "a")
+            .map(chars)
+            .sum()
+    };
+
+    let (mut text, mut links, mut breaks) = (0, 0, 0.0);
+    for child in element.children() {
+        match child.value() {
+            Node::Text(node) => text += node.trim().chars().count(),
+            Node::Element(_) => {
+                let Some(child) = ElementRef::wrap(child) else {
+                    continue;
+                };
+                let name = child.value().name();
+                if name == "br" {
+                    breaks += 0.5;
+                } else if name == "p" || INLINE.contains(&name) {
+                    text += chars(child);
+                    links += link_chars(child);
+                    if name == "p" {
+                        breaks += 1.0;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    text.saturating_sub(links) as f32 + breaks * 10.0
+}
+
+struct Builder<'a> {
+    base: Option<&'a Url>,
+    hints: &'a Hints,
+    blocks: Vec<ReadableBlock>,
+    spans: Vec<ReadableSpan>,
+    bold: u32,
+    italic: u32,
+    preformatted: u32,
+}
+
+impl<'a> Builder<'a> {
+    fn new(base: Option<&'a Url>, hints: &'a Hints) -> Self {
+        Self {
+            base,
+            hints,
+            blocks: Vec::new(),
+            spans: Vec::new(),
+            bold: 0,
+            italic: 0,
+            preformatted: 0,
+        }
+    }
+
+    fn walk(&mut self, element: ElementRef) {
+        for child in element.children() {
+            match child.value() {
+                Node::Text(node) => self.text(node),
+                Node::Element(_) => {
+                    if let Some(child) = ElementRef::wrap(child) {
+                        self.element(child);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn element(&mut self, element: ElementRef) {
+        if is_dropped(element, self.hints) {
+            return;
+        }
+        match element.value().name() {
+            "br" => self.end_block(ReadableBlockKind::Paragraph),
+            "img" => self.image(element),
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.end_block(ReadableBlockKind::Paragraph);
+                self.walk(element);
+                self.end_block(ReadableBlockKind::Heading);
+            }
+            "b" | "strong" => {
+                self.bold += 1;
+                self.walk(element);
+                self.bold -= 1;
+            }
+            "i" | "em" => {
+                self.italic += 1;
+                self.walk(element);
+                self.italic -= 1;
+            }
+            "pre" => {
+                self.end_block(ReadableBlockKind::Paragraph);
+                self.preformatted += 1;
+                self.walk(element);
+                self.preformatted -= 1;
+                self.end_block(ReadableBlockKind::Paragraph);
+            }
+            name if BLOCKS.contains(&name) => {
+                self.end_block(ReadableBlockKind::Paragraph);
+                self.walk(element);
+                self.end_block(ReadableBlockKind::Paragraph);
+            }
+            _ => self.walk(element),
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.preformatted > 0 {
+            let mut lines = text.split('\n');
+            if let Some(first) = lines.next() {
+                self.push_text(first);
+            }
+            for line in lines {
+                self.end_block(ReadableBlockKind::Paragraph);
+                self.push_text(line);
+            }
+        } else {
+            self.push_text(text);
+        }
+    }
+
+    /// Append text, collapsing whitespace the way a browser would.
+    fn push_text(&mut self, text: &str) {
+        let mut collapsed = String::with_capacity(text.len());
+        for c in text.chars() {
+            let c = if c.is_ascii_whitespace() || c == '\u{A0}' {
+                ' '
+            } else {
+                c
+            };
+            if c == ' ' && collapsed.ends_with(' ') {
+                continue;
+            }
+            collapsed.push(c);
+        }
+        if collapsed.is_empty() {
+            return;
+        }
+
+        let (bold, italic) = (self.bold > 0, self.italic > 0);
+        if let Some(last) = self.spans.last_mut() {
+            if last.image_src.is_none() && last.bold == bold && last.italic == italic {
+                if last.text.ends_with(' ') && collapsed.starts_with(' ') {
+                    collapsed.remove(0);
+                }
+                last.text.push_str(&collapsed);
+                return;
+            }
+        }
+        self.spans.push(ReadableSpan {
+            text: collapsed,
+            image_src: None,
+            bold,
+            italic,
+        });
+    }
+
+    fn image(&mut self, element: ElementRef) {
+        let value = element.value();
+        let Some(src) = IMAGE_SOURCES
+            .iter()
+            .filter_map(|name| value.attr(name))
+            .map(str::trim)
+            .find(|src| !src.is_empty() && !src.starts_with("data:"))
+        else {
+            return;
+        };
+        let src = match self.base {
+            Some(base) => match base.join(src) {
+                Ok(url) => url.to_string(),
+                Err(_) => return,
+            },
+            None => src.to_string(),
+        };
+        self.spans.push(ReadableSpan {
+            text: normalize(value.attr("alt").unwrap_or_default()),
+            image_src: Some(src),
+            bold: self.bold > 0,
+            italic: self.italic > 0,
+        });
+    }
+
+    /// Finish the current paragraph, trimming indentation and dropping it if
+    /// nothing is left.
+    fn end_block(&mut self, kind: ReadableBlockKind) {
+        let mut spans = std::mem::take(&mut self.spans);
+        if let Some(first) = spans.first_mut().filter(|span| span.image_src.is_none()) {
+            first.text = first.text.trim_start().to_string();
+        }
+        if let Some(last) = spans.last_mut().filter(|span| span.image_src.is_none()) {
+            last.text = last.text.trim_end().to_string();
+        }
+        spans.retain(|span| span.image_src.is_some() || !span.text.is_empty());
+        if !spans.is_empty() {
+            self.blocks.push(ReadableBlock { kind, spans });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(content: &ReadableContent) -> Vec<String> {
+        content
+            .blocks
+            .iter()
+            .map(|block| block.spans.iter().map(|span| span.text.as_str()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_extracts_br_separated_novel_text() {
+        let html = r#"<html><head><title>第一章 风起_某某小说网</title>
+            <script>var a = 1 < 2;</script></head><body>
+            <div class="nav"><a href="/">首页</a> <a href="/list">目录</a></div>
+            <div class="bookname"><h1>第一章 风起</h1></div>
+            <div class="ad-box">广告：点击下载 APP</div>
+            <div id="content">&nbsp;&nbsp;&nbsp;&nbsp;天色渐晚。<br /><br />
+            &nbsp;&nbsp;&nbsp;&nbsp;城门外<em>行人</em>稀少。<br><br>
+            　　他抬头看了看远处的山。<img data-original="/img/map.png" src="/loading.gif" alt="地图"></div>
+            <div class="footer">Copyright 2024</div>
+            </body></html>"#;
+        let base = Url::parse("https://example.com/book/1/2.html").unwrap();
+        let content = extract(html, Some(&base));
+
+        assert_eq!(content.title.as_deref(), Some("第一章 风起"));
+        assert_eq!(
+            texts(&content),
+            vec![
+                "天色渐晚。",
+                "城门外行人稀少。",
+                "他抬头看了看远处的山。地图"
+            ]
+        );
+        let spans = &content.blocks[1].spans;
+        assert_eq!(
+            spans
+                .iter()
+                .map(|s| (s.text.as_str(), s.italic))
+                .collect::<Vec<_>>(),
+            vec![("城门外", false), ("行人", true), ("稀少。", false)]
+        );
+        let image = &content.blocks[2].spans[1];
+        assert_eq!(
+            image.image_src.as_deref(),
+            Some("https://example.com/img/map.png")
+        );
+    }
+
+    #[test]
+    fn test_prefers_article_over_link_lists() {
+        let links: String = (0..40)
+            .map(|i| format!("<li><a href=\"/c/{i}\">第{i}章 很长很长的推荐章节标题</a></li>"))
+            .collect();
+        let html = format!(
+            r#"<body><ul class="list">{links}</ul>
+            <article><h2>Part <b>One</b></h2>
+            <p>The <strong>first</strong> paragraph.</p>
+            <p style="color:red">The second   paragraph
+            continues here.</p>
+            <pre>line one
+line two</pre></article></body>"#
+        );
+        let content = extract(&html, None);
+
+        assert_eq!(content.blocks[0].kind, ReadableBlockKind::Heading);
+        assert_eq!(
+            texts(&content),
+            vec![
+                "Part One",
+                "The first paragraph.",
+                "The second paragraph continues here.",
+                "line one",
+                "line two",
+            ]
+        );
+        assert!(content.blocks[1].spans[1].bold);
+        assert_eq!(content.title, None);
+    }
+
+    #[test]
+    fn test_rejects_invalid_base_url() {
+        assert!(extract_readable("<p>x</p>".to_string(), Some("not a url".to_string())).is_err());
+        let content = extract_readable("<p>x</p>".to_string(), None).unwrap();
+        assert_eq!(texts(&content), vec!["x"]);
+    }
+}
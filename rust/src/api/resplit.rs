@@ -0,0 +1,412 @@
+//! Splitting a stored book into chapters again with different rules.
+//!
+//! A book imported with the wrong heading rules is often only noticed
+//! after reading has started. `resplit_stored_book` joins the chapters in
+//! the chapter store back into one text, splits it with the new rules and
+//! replaces both the stored chapters and the library's chapter list. Every
+//! position saved against the old chapters — reading progress, bookmarks,
+//! highlights and read chapters — is moved to the same character of the
+//! text under the new chapters rather than dropped. Offsets are in UTF-16
+//! code units, as everywhere else in the library.
+
+use std::io::Cursor;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Result};
+use regex::Regex;
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::api::chapter_store;
+use crate::api::db::{self, LibraryChapter};
+use crate::api::error::ApiError;
+use crate::api::read_chapters;
+use crate::api::task;
+use crate::api::txt::{self, TxtChapterRule};
+
+#[derive(Debug, Clone)]
+pub struct ResplitOutcome {
+    /// Titles of the new chapters, in order; empty for text before the
+    /// first heading.
+    pub titles: Vec<String>,
+    /// Bookmarks whose chapter or offset changed.
+    pub moved_bookmarks: u32,
+    /// Highlights whose chapter or offsets changed.
+    pub moved_highlights: u32,
+    /// Reading position in the new chapters, if the book has progress.
+    pub chapter_index: Option<u32>,
+    pub chapter_offset: Option<u32>,
+}
+
+/// Split a book's stored chapters again and move its saved positions onto
+/// the new chapters. Every chapter of the book must be in the chapter store.
+///
+/// # Arguments
+/// * `book_id` - Library ID of the book
+/// * `rules` - Heading rules, or `None` for `default_txt_rules`
+/// * `dry_run` - Only work out the outcome, without changing anything
+/// * `cancel_token` - Token from `create_cancel_token`, or `None`
+#[flutter_rust_bridge::frb]
+pub async fn resplit_stored_book(
+    book_id: String,
+    rules: Option<Vec<TxtChapterRule>>,
+    dry_run: bool,
+    cancel_token: Option<u32>,
+) -> Result<ResplitOutcome, ApiError> {
+    task::run_blocking(task::cancel_token(cancel_token)?, move |token| {
+        let rules = txt::compile_rules(rules)?;
+        let indices = chapter_store::stored_chapter_indices(&book_id)?;
+        if let Some(missing) = (0..).zip(&indices).find(|(i, index)| i != *index) {
+            return Err(anyhow!("Chapter {} of the book is not stored", missing.0));
+        }
+        let mut texts = Vec::with_capacity(indices.len());
+        for &index in &indices {
+            token.check()?;
+            let text = chapter_store::get_chapter(book_id.clone(), index)?
+                .ok_or_else(|| anyhow!("Chapter {index} of the book is not stored"))?;
+            texts.push(text);
+        }
+        let split = Split::new(&texts, &rules)?;
+        token.check()?;
+
+        if dry_run {
+            return db::with_db(|db| remap(db, &book_id, &split, false));
+        }
+        // The store is written first and put back if the library can't be
+        // updated, so the chapter list never runs ahead of the chapters.
+        let new_texts: Vec<String> = split.chapters.iter().map(|c| c.text.clone()).collect();
+        let written = replace_stored(&book_id, &new_texts, indices.len()).and_then(|_| {
+            db::with_db(|db| {
+                let tx = db.transaction()?;
+                let outcome = remap(&tx, &book_id, &split, true)?;
+                tx.commit()?;
+                Ok(outcome)
+            })
+        });
+        if written.is_err() {
+            let _ = replace_stored(&book_id, &texts, new_texts.len().max(texts.len()));
+        }
+        written
+    })
+    .await
+}
+
+/// Store `texts` as the book's chapters and delete the rest of the first
+/// `stored` indices.
+fn replace_stored(book_id: &str, texts: &[String], stored: usize) -> Result<()> {
+    for (index, text) in (0..).zip(texts) {
+        chapter_store::put_chapter(book_id.to_string(), index, text.clone())?;
+    }
+    for index in texts.len()..stored {
+        chapter_store::delete_chapter(book_id.to_string(), index as u32)?;
+    }
+    Ok(())
+}
+
+/// Old and new chapters of the same text, with where each starts in it.
+#[flutter_rust_bridge::frb(ignore)]
+struct Split {
+    /// Start and length of each old chapter, in UTF-16 code units.
+    old: Vec<(u32, u32)>,
+    chapters: Vec<NewChapter>,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+struct NewChapter {
+    title: Option<String>,
+    text: String,
+    /// Start in the joined text, in UTF-16 code units.
+    start: u32,
+    len: u32,
+}
+
+impl Split {
+    /// Join `texts` by line and split the result where `rules` match.
+    fn new(texts: &[String], rules: &[Regex]) -> Result<Self> {
+        let mut joined = String::new();
+        let mut old = Vec::with_capacity(texts.len());
+        let mut units = 0;
+        for text in texts {
+            let len = utf16_len(text);
+            old.push((units, len));
+            joined.push_str(text);
+            units += len;
+            if !text.ends_with('\n') {
+                joined.push('\n');
+                units += 1;
+            }
+        }
+
+        let index = txt::scan(Cursor::new(joined.as_bytes()), rules)?;
+        if index.chapters.iter().all(|chapter| chapter.title.is_none()) {
+            return Err(anyhow!("No chapter headings match the rules"));
+        }
+        // `scan` works in bytes; chapters are contiguous, so code units are
+        // counted once across them.
+        let mut start = 0;
+        let chapters = index
+            .chapters
+            .into_iter()
+            .map(|chapter| {
+                let text = &joined[chapter.start as usize..chapter.end as usize];
+                let len = utf16_len(text);
+                let text = text.strip_suffix('\n').unwrap_or(text);
+                let new = NewChapter {
+                    title: chapter.title,
+                    text: text.to_string(),
+                    start,
+                    len: utf16_len(text),
+                };
+                start += len;
+                new
+            })
+            .collect();
+        Ok(Split { old, chapters })
+    }
+
+    /// New chapter and offset of `offset` in old chapter `chapter`.
+    fn position(&self, chapter: u32, offset: u32) -> (u32, u32) {
+        let new = self.global(chapter, offset);
+        let index = self.chapter_at(new);
+        (index, new - self.chapters[index as usize].start)
+    }
+
+    /// Offset into the joined text, or its end for chapters past the old ones.
+    fn global(&self, chapter: u32, offset: u32) -> u32 {
+        match self.old.get(chapter as usize) {
+            Some(&(start, len)) => start + offset.min(len),
+            None => self.old.last().map_or(0, |&(start, len)| start + len),
+        }
+    }
+
+    fn chapter_at(&self, global: u32) -> u32 {
+        let after = self.chapters.partition_point(|c| c.start <= global);
+        after.saturating_sub(1) as u32
+    }
+
+    /// The old chapter the start of new chapter `index` was in.
+    fn old_chapter(&self, index: u32) -> u32 {
+        let start = self.chapters[index as usize].start;
+        let after = self.old.partition_point(|&(s, _)| s <= start);
+        after.saturating_sub(1) as u32
+    }
+}
+
+/// Move the book's saved positions onto `split`'s new chapters and, if
+/// `write`, replace its chapter list.
+fn remap(
+    db: &Connection,
+    book_id: &str,
+    split: &Split,
+    write: bool,
+) -> rusqlite::Result<ResplitOutcome> {
+    let mut outcome = ResplitOutcome {
+        titles: split
+            .chapters
+            .iter()
+            .map(|chapter| chapter.title.clone().unwrap_or_default())
+            .collect(),
+        moved_bookmarks: 0,
+        moved_highlights: 0,
+        chapter_index: None,
+        chapter_offset: None,
+    };
+
+    let progress: Option<(u32, u32)> = db
+        .query_row(
+            "SELECT chapter_index, chapter_offset FROM progress WHERE book_id = ?1",
+            [book_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?;
+    if let Some((chapter, offset)) = progress {
+        let (chapter, offset) = split.position(chapter, offset);
+        outcome.chapter_index = Some(chapter);
+        outcome.chapter_offset = Some(offset);
+        if write {
+            // Newer than any copy still split the old way.
+            db.execute(
+                "UPDATE progress SET chapter_index = ?2, chapter_offset = ?3, updated_at = ?4
+                 WHERE book_id = ?1",
+                params![book_id, chapter, offset, now()],
+            )?;
+        }
+    }
+
+    let bookmarks: Vec<(i64, u32, u32)> = db
+        .prepare("SELECT id, chapter_index, chapter_offset FROM bookmarks WHERE book_id = ?1")?
+        .query_map([book_id], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+        .collect::<rusqlite::Result<_>>()?;
+    for (id, chapter, offset) in bookmarks {
+        let moved = split.position(chapter, offset);
+        if moved == (chapter, offset) {
+            continue;
+        }
+        outcome.moved_bookmarks += 1;
+        if write {
+            db.execute(
+                "UPDATE bookmarks SET chapter_index = ?2, chapter_offset = ?3 WHERE id = ?1",
+                params![id, moved.0, moved.1],
+            )?;
+        }
+    }
+
+    let highlights: Vec<(i64, u32, u32, u32)> = db
+        .prepare(
+            "SELECT id, chapter_index, start_offset, end_offset FROM highlights
+             WHERE book_id = ?1",
+        )?
+        .query_map([book_id], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+        })?
+        .collect::<rusqlite::Result<_>>()?;
+    for (id, chapter, start, end) in highlights {
+        let (index, new_start) = split.position(chapter, start);
+        // A highlight across a new chapter break keeps its first part.
+        let new_end = (split.global(chapter, end) - split.chapters[index as usize].start)
+            .min(split.chapters[index as usize].len);
+        if (index, new_start, new_end) == (chapter, start, end) {
+            continue;
+        }
+        outcome.moved_highlights += 1;
+        if write {
+            db.execute(
+                "UPDATE highlights SET chapter_index = ?2, start_offset = ?3, end_offset = ?4
+                 WHERE id = ?1",
+                params![id, index, new_start, new_end],
+            )?;
+        }
+    }
+
+    if write {
+        let chapters: Vec<LibraryChapter> = (0..)
+            .zip(&split.chapters)
+            .map(|(index, chapter)| LibraryChapter {
+                index,
+                title: chapter.title.clone().unwrap_or_default(),
+                url: None,
+                is_volume: false,
+                word_count: None,
+            })
+            .collect();
+        db::replace_chapters(db, book_id, &chapters)?;
+        read_chapters::remap(db, book_id, chapters.len() as u32, |i| split.old_chapter(i))?;
+    }
+    Ok(outcome)
+}
+
+fn utf16_len(text: &str) -> u32 {
+    text.encode_utf16().count() as u32
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules() -> Vec<Regex> {
+        txt::compile_rules(None).unwrap()
+    }
+
+    #[test]
+    fn test_positions_follow_the_text() {
+        // Two old chapters that each hold two real ones.
+        let texts = [
+            "第一章 出发\n天亮了。\n第二章 路上\n下雨了。".to_string(),
+            "第三章 进城\n人很多。\n第四章 回家\n".to_string(),
+        ];
+        let split = Split::new(&texts, &rules()).unwrap();
+        let titles: Vec<_> = split.chapters.iter().map(|c| c.title.clone()).collect();
+        assert_eq!(
+            titles,
+            ["第一章 出发", "第二章 路上", "第三章 进城", "第四章 回家"]
+                .map(|t| Some(t.to_string()))
+        );
+        assert_eq!(split.chapters[1].text, "第二章 路上\n下雨了。");
+        // "下" in old chapter 0 and "人" in old chapter 1.
+        assert_eq!(split.position(0, 19), (1, 7));
+        assert_eq!(split.position(1, 7), (2, 7));
+        assert_eq!(split.position(0, 0), (0, 0));
+        // Offsets past the end of a chapter stay in it.
+        assert_eq!(split.position(0, 999), (1, 11));
+        assert_eq!(split.old_chapter(3), 1);
+
+        let flat = ["没有标题的一段。\n".to_string()];
+        assert!(Split::new(&flat, &rules()).is_err());
+    }
+
+    #[test]
+    fn test_offsets_count_utf16_units() {
+        // The emoji is two UTF-16 code units but one character.
+        let texts = ["第一章 出发\n😀天亮了。\n第二章 路上\n下雨了。".to_string()];
+        let split = Split::new(&texts, &rules()).unwrap();
+        assert_eq!(split.chapters[1].start, 14);
+        assert_eq!(split.position(0, 9), (0, 9));
+        // "下", after the emoji.
+        assert_eq!(split.position(0, 21), (1, 7));
+        assert_eq!(split.position(0, 999), (1, 11));
+    }
+
+    #[test]
+    fn test_saved_positions_are_remapped() {
+        let mut db = Connection::open_in_memory().unwrap();
+        db::setup(&mut db).unwrap();
+        db.execute_batch(
+            "INSERT INTO books (id, title, added_at, updated_at) VALUES ('b', 'B', 0, 0);
+             INSERT INTO progress VALUES ('b', 1, 7, 0.5, 0);
+             INSERT INTO bookmarks (book_id, chapter_index, chapter_offset, excerpt, created_at)
+                VALUES ('b', 0, 2, '', 0), ('b', 0, 19, '', 0);
+             INSERT INTO highlights
+                (book_id, chapter_index, start_offset, end_offset, text, color, created_at)
+                VALUES ('b', 0, 13, 30, '', 0, 0);
+             INSERT INTO read_chapters VALUES ('b', X'0000000001000000', 0);",
+        )
+        .unwrap();
+        let texts = [
+            "第一章 出发\n天亮了。\n第二章 路上\n下雨了。".to_string(),
+            "第三章 进城\n人很多。".to_string(),
+        ];
+        let split = Split::new(&texts, &rules()).unwrap();
+
+        let dry = remap(&db, "b", &split, false).unwrap();
+        assert_eq!((dry.moved_bookmarks, dry.moved_highlights), (1, 1));
+        let stored: u32 = db
+            .query_row("SELECT chapter_index FROM progress", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(stored, 1);
+
+        let outcome = remap(&db, "b", &split, true).unwrap();
+        assert_eq!(
+            (outcome.chapter_index, outcome.chapter_offset),
+            (Some(2), Some(7))
+        );
+        let progress: (u32, u32) = db
+            .query_row(
+                "SELECT chapter_index, chapter_offset FROM progress",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!(progress, (2, 7));
+        let highlight: (u32, u32, u32) = db
+            .query_row(
+                "SELECT chapter_index, start_offset, end_offset FROM highlights",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap();
+        assert_eq!(highlight, (1, 1, 11));
+        let chapters = db::read_chapters(&db, "b", 0, 10).unwrap();
+        assert_eq!(chapters.len(), 3);
+        assert_eq!(chapters[2].title, "第三章 进城");
+        // Old chapter 0 was read, so both chapters cut from it are.
+        let read: Vec<u8> = db
+            .query_row("SELECT ranges FROM read_chapters", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(read, [0, 0, 0, 0, 2, 0, 0, 0]);
+    }
+}
@@ -0,0 +1,326 @@
+//! Pinyin annotation for ruby text.
+//!
+//! Text is segmented into words first, because most polyphonic characters
+//! are settled by the word they appear in: 银行 is háng but 行走 is xíng.
+//! Words in the phrase table below take their readings from it, and so do
+//! the longest table entries inside longer words, such as 银行 and 行长 in
+//! 银行行长. Other characters use their most common reading, except for
+//! particles such as 了, 着 and 得 standing alone, which are read in the
+//! neutral tone.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use pinyin::ToPinyin;
+
+use crate::api::segment;
+
+/// Readings of common words containing polyphonic characters.
+const PHRASES: &[(&str, &str)] = &[
+    ("银行", "yín háng"),
+    ("行业", "háng yè"),
+    ("行列", "háng liè"),
+    ("行情", "háng qíng"),
+    ("内行", "nèi háng"),
+    ("外行", "wài háng"),
+    ("排行", "pái háng"),
+    ("重新", "chóng xīn"),
+    ("重复", "chóng fù"),
+    ("重庆", "chóng qìng"),
+    ("重叠", "chóng dié"),
+    ("重逢", "chóng féng"),
+    ("长大", "zhǎng dà"),
+    ("生长", "shēng zhǎng"),
+    ("成长", "chéng zhǎng"),
+    ("增长", "zēng zhǎng"),
+    ("长辈", "zhǎng bèi"),
+    ("长老", "zhǎng lǎo"),
+    ("家长", "jiā zhǎng"),
+    ("校长", "xiào zhǎng"),
+    ("班长", "bān zhǎng"),
+    ("队长", "duì zhǎng"),
+    ("部长", "bù zhǎng"),
+    ("市长", "shì zhǎng"),
+    ("行长", "háng zhǎng"),
+    ("董事长", "dǒng shì zhǎng"),
+    ("院长", "yuàn zhǎng"),
+    ("厂长", "chǎng zhǎng"),
+    ("村长", "cūn zhǎng"),
+    ("组长", "zǔ zhǎng"),
+    ("长得", "zhǎng de"),
+    ("长相", "zhǎng xiàng"),
+    ("长子", "zhǎng zǐ"),
+    ("长高", "zhǎng gāo"),
+    ("长出", "zhǎng chū"),
+    ("音乐", "yīn yuè"),
+    ("乐器", "yuè qì"),
+    ("乐队", "yuè duì"),
+    ("了解", "liǎo jiě"),
+    ("了结", "liǎo jié"),
+    ("了不起", "liǎo bu qǐ"),
+    ("受不了", "shòu bu liǎo"),
+    ("睡觉", "shuì jiào"),
+    ("午觉", "wǔ jiào"),
+    ("觉得", "jué de"),
+    ("还钱", "huán qián"),
+    ("归还", "guī huán"),
+    ("偿还", "cháng huán"),
+    ("还给", "huán gěi"),
+    ("记得", "jì de"),
+    ("值得", "zhí de"),
+    ("懂得", "dǒng de"),
+    ("晓得", "xiǎo de"),
+    ("显得", "xiǎn de"),
+    ("使得", "shǐ de"),
+    ("变得", "biàn de"),
+    ("舍得", "shě de"),
+    ("不得不", "bù dé bù"),
+    ("的确", "dí què"),
+    ("目的", "mù dì"),
+    ("首都", "shǒu dū"),
+    ("都市", "dū shì"),
+    ("成都", "chéng dū"),
+    ("成为", "chéng wéi"),
+    ("认为", "rèn wéi"),
+    ("以为", "yǐ wéi"),
+    ("作为", "zuò wéi"),
+    ("行为", "xíng wéi"),
+    ("为难", "wéi nán"),
+    ("着急", "zháo jí"),
+    ("睡着", "shuì zháo"),
+    ("着火", "zháo huǒ"),
+    ("着想", "zhuó xiǎng"),
+    ("着陆", "zhuó lù"),
+    ("打中", "dǎ zhòng"),
+    ("中毒", "zhòng dú"),
+    ("中奖", "zhòng jiǎng"),
+    ("命中", "mìng zhòng"),
+    ("爱好", "ài hào"),
+    ("好奇", "hào qí"),
+    ("好客", "hào kè"),
+    ("便宜", "pián yi"),
+    ("少年", "shào nián"),
+    ("少女", "shào nǚ"),
+    ("多少", "duō shao"),
+    ("头发", "tóu fa"),
+    ("理发", "lǐ fà"),
+    ("会计", "kuài jì"),
+    ("暖和", "nuǎn huo"),
+    ("大夫", "dài fu"),
+    ("传记", "zhuàn jì"),
+    ("自传", "zì zhuàn"),
+    ("出差", "chū chāi"),
+    ("差别", "chā bié"),
+    ("参差", "cēn cī"),
+    ("上当", "shàng dàng"),
+    ("恰当", "qià dàng"),
+    ("当作", "dàng zuò"),
+    ("数数", "shǔ shù"),
+    ("什么", "shén me"),
+    ("东西", "dōng xi"),
+    ("先生", "xiān sheng"),
+    ("朋友", "péng you"),
+    ("知道", "zhī dao"),
+    ("明白", "míng bai"),
+    ("衣服", "yī fu"),
+    ("事情", "shì qing"),
+    ("时候", "shí hou"),
+    ("喜欢", "xǐ huan"),
+    ("认识", "rèn shi"),
+    ("意思", "yì si"),
+    ("姑娘", "gū niang"),
+    ("地方", "dì fang"),
+];
+
+/// Readings of single-character words, where the usual default is the
+/// rarer one in running text.
+const CHAR_READINGS: &[(char, &str)] = &[
+    ('长', "cháng"),
+    ('了', "le"),
+    ('着', "zhe"),
+    ('得', "de"),
+    ('的', "de"),
+    ('么', "me"),
+    ('吗', "ma"),
+    ('呢', "ne"),
+    ('吧', "ba"),
+    ('谁', "shéi"),
+];
+
+static PHRASE_TABLE: OnceLock<HashMap<&'static str, Vec<&'static str>>> = OnceLock::new();
+
+/// A run of text and its pinyin. Han characters get one span each; other
+/// text is grouped into spans with empty pinyin. Offsets are UTF-16 code
+/// units.
+#[derive(Debug, Clone)]
+pub struct PinyinSpan {
+    pub text: String,
+    pub pinyin: String,
+    pub start: u32,
+    pub end: u32,
+}
+
+/// Annotate `text` with tone-marked pinyin. The spans cover the whole text
+/// in order.
+#[flutter_rust_bridge::frb]
+pub fn annotate_pinyin(text: String) -> Vec<PinyinSpan> {
+    let phrases = PHRASE_TABLE.get_or_init(|| {
+        PHRASES
+            .iter()
+            .map(|(word, readings)| (*word, readings.split(' ').collect()))
+            .collect()
+    });
+
+    let mut spans: Vec<PinyinSpan> = Vec::new();
+    let mut offset = 0;
+    let words = segment::jieba().cut(&text, true);
+    for (index, word) in words.iter().enumerate() {
+        let phrase = phrase_readings(word.word, phrases);
+        for (i, c) in word.word.chars().enumerate() {
+            let start = offset;
+            offset += c.len_utf16() as u32;
+            let reading = match phrase[i] {
+                Some(reading) => Some(reading),
+                None => char_reading(
+                    c,
+                    word.word,
+                    words.get(index + 1).map(|w| w.word),
+                    index > 0,
+                ),
+            };
+            match reading {
+                Some(reading) => spans.push(PinyinSpan {
+                    text: c.to_string(),
+                    pinyin: reading.to_string(),
+                    start,
+                    end: offset,
+                }),
+                None => match spans.last_mut().filter(|span| span.pinyin.is_empty()) {
+                    Some(span) => {
+                        span.text.push(c);
+                        span.end = offset;
+                    }
+                    None => spans.push(PinyinSpan {
+                        text: c.to_string(),
+                        pinyin: String::new(),
+                        start,
+                        end: offset,
+                    }),
+                },
+            }
+        }
+    }
+    spans
+}
+
+/// Readings of the characters of `word` taken from the phrase table: the
+/// whole word if it is listed, otherwise the longest listed words inside
+/// it, from the left. `None` for characters no entry covers.
+fn phrase_readings(
+    word: &str,
+    phrases: &HashMap<&'static str, Vec<&'static str>>,
+) -> Vec<Option<&'static str>> {
+    let chars: Vec<(usize, char)> = word.char_indices().collect();
+    let mut readings = vec![None; chars.len()];
+    let mut i = 0;
+    while i < chars.len() {
+        let found = (2..=chars.len() - i).rev().find_map(|len| {
+            let end = chars.get(i + len).map_or(word.len(), |&(at, _)| at);
+            phrases
+                .get(&word[chars[i].0..end])
+                .filter(|entry| entry.len() == len)
+        });
+        match found {
+            Some(entry) => {
+                for (slot, reading) in readings[i..].iter_mut().zip(entry) {
+                    *slot = Some(*reading);
+                }
+                i += entry.len();
+            }
+            None => i += 1,
+        }
+    }
+    readings
+}
+
+/// Reading of `c` within `word` when no phrase table entry covers it.
+fn char_reading(
+    c: char,
+    word: &str,
+    next: Option<&str>,
+    has_previous: bool,
+) -> Option<&'static str> {
+    if word.chars().count() == 1 {
+        // 地 after an adverbial and before a verb, as in 慢慢地走.
+        if c == '地'
+            && has_previous
+            && next.is_some_and(|next| next.chars().next().is_some_and(is_han))
+        {
+            return Some("de");
+        }
+        if let Some((_, reading)) = CHAR_READINGS.iter().find(|(ch, _)| *ch == c) {
+            return Some(reading);
+        }
+    } else if c == '长' {
+        // Words read zhǎng are listed in the phrase table.
+        return Some("cháng");
+    }
+    c.to_pinyin().map(|pinyin| pinyin.with_tone())
+}
+
+fn is_han(c: char) -> bool {
+    c.to_pinyin().is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn readings(text: &str) -> String {
+        annotate_pinyin(text.to_string())
+            .iter()
+            .filter(|span| !span.pinyin.is_empty())
+            .map(|span| span.pinyin.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn test_polyphones_follow_words() {
+        assert_eq!(readings("他去银行"), "tā qù yín háng");
+        assert_eq!(readings("行走"), "xíng zǒu");
+        assert_eq!(readings("他长大了"), "tā zhǎng dà le");
+        assert_eq!(readings("很长"), "hěn cháng");
+        assert_eq!(readings("我觉得要睡觉"), "wǒ jué de yào shuì jiào");
+        assert_eq!(readings("他慢慢地走"), "tā màn màn de zǒu");
+        assert_eq!(readings("土地"), "tǔ dì");
+    }
+
+    #[test]
+    fn test_phrases_inside_longer_words() {
+        assert_eq!(readings("他是银行行长"), "tā shì yín háng háng zhǎng");
+        assert_eq!(readings("孩子长得很快"), "hái zi zhǎng de hěn kuài");
+        assert_eq!(readings("我觉得好"), "wǒ jué de hǎo");
+    }
+
+    #[test]
+    fn test_spans_cover_text() {
+        let spans = annotate_pinyin("第1章：😀你好 ok".to_string());
+        let summary: Vec<(&str, &str, u32, u32)> = spans
+            .iter()
+            .map(|s| (s.text.as_str(), s.pinyin.as_str(), s.start, s.end))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("第", "dì", 0, 1),
+                ("1", "", 1, 2),
+                ("章", "zhāng", 2, 3),
+                ("：😀", "", 3, 6),
+                ("你", "nǐ", 6, 7),
+                ("好", "hǎo", 7, 8),
+                (" ok", "", 8, 11),
+            ]
+        );
+    }
+}
@@ -0,0 +1,282 @@
+//! Trying out purify and chapter rules in the rule editor.
+//!
+//! `test_rule` and `test_chapter_rule` run one rule on sample text and
+//! report what it matched and how long it took. A sample pasted into the
+//! editor is usually short enough to be matched in microseconds by any
+//! rule, so the matching time is measured on the sample repeated to
+//! `MEASURED_BYTES` and reported per MiB, which makes rules that would
+//! stall on whole books stand out.
+
+use std::time::Instant;
+
+use anyhow::anyhow;
+
+use crate::api::error::ApiError;
+use crate::api::purify::{self, PurifyRule, PurifyRuleKind, PurifyRuleMatch};
+use crate::api::txt::{self, TxtChapterRule};
+use crate::user_regex;
+
+/// Matches listed by a test; the count includes the rest.
+const MAX_TEST_MATCHES: usize = 100;
+/// Text the matching time is measured on.
+const MEASURED_BYTES: usize = 64 * 1024;
+/// Compiling or matching a MiB slower than this marks a rule as slow.
+const SLOW_COMPILE_US: u64 = 50_000;
+const SLOW_MATCH_US: u64 = 200_000;
+
+/// What a rule did to sample text.
+#[derive(Debug, Clone)]
+pub struct RuleTest {
+    /// The first matches, in order.
+    pub matches: Vec<RuleTestMatch>,
+    pub match_count: u32,
+    /// The sample after a purify rule; empty for chapter rules.
+    pub output: String,
+    /// Time to compile the pattern, in microseconds.
+    pub compile_us: u64,
+    /// Time to apply the rule to a MiB of text like the sample.
+    pub match_us_per_mib: u64,
+    /// Whether either time is long enough to be felt in the reader.
+    pub slow: bool,
+}
+
+/// A match in the sample. Offsets are UTF-16 code units.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleTestMatch {
+    pub start: u32,
+    pub end: u32,
+    /// The matched text; the whole line for `Line` rules.
+    pub text: String,
+}
+
+/// Run a purify rule on sample text, whether or not it is enabled.
+///
+/// # Arguments
+/// * `rule` - The rule being edited
+/// * `sample_text` - Text to run it on, such as a chapter of the book
+#[flutter_rust_bridge::frb]
+pub fn test_rule(rule: PurifyRule, sample_text: String) -> Result<RuleTest, ApiError> {
+    let started = Instant::now();
+    let regex = match rule.kind {
+        PurifyRuleKind::Regex | PurifyRuleKind::Line => Some(
+            user_regex::compile(&rule.pattern, true)
+                .map_err(|e| anyhow!("Invalid purify rule {}: {e}", rule.name))?,
+        ),
+        PurifyRuleKind::Literal | PurifyRuleKind::Watermark => None,
+    };
+    let compile_us = started.elapsed().as_micros() as u64;
+
+    let mut found = PurifyRuleMatch {
+        rule_index: 0,
+        name: rule.name.clone(),
+        count: 0,
+        samples: Vec::new(),
+    };
+    let output = purify::apply_compiled(&sample_text, &rule, regex.as_ref(), Some(&mut found))?;
+    let mut offsets = Utf16Offsets::new(&sample_text);
+    let ranges: Vec<(usize, usize)> = match (&rule.kind, &regex) {
+        (PurifyRuleKind::Regex, Some(regex)) => regex
+            .find_iter(&sample_text)
+            .take(MAX_TEST_MATCHES)
+            .map(|m| (m.start(), m.end()))
+            .collect(),
+        (PurifyRuleKind::Line, Some(regex)) => lines(&sample_text)
+            .filter(|&(start, end)| regex.is_match(&sample_text[start..end]))
+            .take(MAX_TEST_MATCHES)
+            .collect(),
+        (PurifyRuleKind::Literal, _) if !rule.pattern.is_empty() => sample_text
+            .match_indices(rule.pattern.as_str())
+            .take(MAX_TEST_MATCHES)
+            .map(|(start, matched)| (start, start + matched.len()))
+            .collect(),
+        _ => Vec::new(),
+    };
+    let matches = ranges
+        .into_iter()
+        .map(|range| offsets.range(range))
+        .collect();
+
+    let match_us_per_mib = time_per_mib(&sample_text, |text| {
+        // Running out of time still measures the rule as slow.
+        let _ = purify::apply_compiled(text, &rule, regex.as_ref(), None);
+    });
+    Ok(RuleTest {
+        matches,
+        match_count: found.count,
+        output,
+        compile_us,
+        match_us_per_mib,
+        slow: is_slow(compile_us, match_us_per_mib),
+    })
+}
+
+/// Run a chapter heading rule on the lines of sample text.
+///
+/// # Arguments
+/// * `rule` - The rule being edited
+/// * `sample_text` - Text to run it on, such as the start of a TXT file
+#[flutter_rust_bridge::frb]
+pub fn test_chapter_rule(rule: TxtChapterRule, sample_text: String) -> Result<RuleTest, ApiError> {
+    let started = Instant::now();
+    let rules = txt::compile_rules(Some(vec![rule]))?;
+    let compile_us = started.elapsed().as_micros() as u64;
+
+    let headings = |text: &str| {
+        lines(text)
+            .filter_map(|(start, end)| {
+                let heading = txt::heading(&text[start..end], &rules)?;
+                // `heading` trims the line; find where the trimmed text is.
+                let start = heading.as_ptr() as usize - text.as_ptr() as usize;
+                Some((start, start + heading.len()))
+            })
+            .collect::<Vec<_>>()
+    };
+    let ranges = headings(&sample_text);
+    let mut offsets = Utf16Offsets::new(&sample_text);
+    let matches = ranges
+        .iter()
+        .take(MAX_TEST_MATCHES)
+        .map(|&range| offsets.range(range))
+        .collect();
+
+    let match_us_per_mib = time_per_mib(&sample_text, |text| {
+        headings(text);
+    });
+    Ok(RuleTest {
+        matches,
+        match_count: ranges.len() as u32,
+        output: String::new(),
+        compile_us,
+        match_us_per_mib,
+        slow: is_slow(compile_us, match_us_per_mib),
+    })
+}
+
+/// Byte ranges of the lines of `text`, without line endings.
+fn lines(text: &str) -> impl Iterator<Item = (usize, usize)> + '_ {
+    text.split_inclusive('\n').scan(0, |offset, line| {
+        let start = *offset;
+        *offset += line.len();
+        Some((start, start + line.trim_end_matches(['\r', '\n']).len()))
+    })
+}
+
+/// Whether a rule with these timings should be flagged as slow.
+fn is_slow(compile_us: u64, match_us_per_mib: u64) -> bool {
+    compile_us > SLOW_COMPILE_US || match_us_per_mib > SLOW_MATCH_US
+}
+
+/// Microseconds `run` takes per MiB, measured on `sample` repeated to
+/// `MEASURED_BYTES`.
+fn time_per_mib(sample: &str, run: impl Fn(&str)) -> u64 {
+    if sample.is_empty() {
+        return 0;
+    }
+    let text = sample.repeat(MEASURED_BYTES.div_ceil(sample.len()));
+    let started = Instant::now();
+    run(&text);
+    let elapsed = started.elapsed().as_micros() as u64;
+    elapsed * (1024 * 1024) / text.len() as u64
+}
+
+/// Converts increasing byte offsets into UTF-16 offsets.
+struct Utf16Offsets<'a> {
+    text: &'a str,
+    byte: usize,
+    unit: u32,
+}
+
+impl<'a> Utf16Offsets<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            byte: 0,
+            unit: 0,
+        }
+    }
+
+    fn at(&mut self, byte: usize) -> u32 {
+        self.unit += self.text[self.byte..byte].encode_utf16().count() as u32;
+        self.byte = byte;
+        self.unit
+    }
+
+    fn range(&mut self, (start, end): (usize, usize)) -> RuleTestMatch {
+        RuleTestMatch {
+            start: self.at(start),
+            end: self.at(end),
+            text: self.text[start..end].to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(kind: PurifyRuleKind, pattern: &str, replacement: &str) -> PurifyRule {
+        PurifyRule {
+            name: "test".to_string(),
+            kind,
+            pattern: pattern.to_string(),
+            replacement: replacement.to_string(),
+            enabled: false,
+        }
+    }
+
+    #[test]
+    fn test_purify_rule_matches() {
+        let sample = "𝄞花了5元。\n广告：本站\n又花了30元。".to_string();
+        let test = test_rule(
+            rule(PurifyRuleKind::Regex, r"(\d+)元", "$1 块"),
+            sample.clone(),
+        )
+        .unwrap();
+        assert_eq!(test.output, "𝄞花了5 块。\n广告：本站\n又花了30 块。");
+        assert_eq!(test.match_count, 2);
+        // The clef is two UTF-16 units.
+        assert_eq!(
+            test.matches[0],
+            RuleTestMatch {
+                start: 4,
+                end: 6,
+                text: "5元".to_string()
+            }
+        );
+
+        let test = test_rule(rule(PurifyRuleKind::Line, "^广告", ""), sample.clone()).unwrap();
+        assert_eq!(test.output, "𝄞花了5元。\n又花了30元。");
+        assert_eq!((test.matches[0].start, test.matches[0].end), (8, 13));
+        assert_eq!(test.matches[0].text, "广告：本站");
+
+        assert!(test_rule(rule(PurifyRuleKind::Regex, "(", ""), sample).is_err());
+    }
+
+    #[test]
+    fn test_chapter_rule_matches() {
+        let rule = TxtChapterRule {
+            name: "numbered".to_string(),
+            pattern: r"^第\d+章".to_string(),
+        };
+        let sample = "序\n  第1章 开始\r\n正文第2章\n第2章 继续".to_string();
+        let test = test_chapter_rule(rule, sample).unwrap();
+        let headings: Vec<_> = test
+            .matches
+            .iter()
+            .map(|m| (m.start, m.end, m.text.as_str()))
+            .collect();
+        assert_eq!(headings, [(4, 10, "第1章 开始"), (18, 24, "第2章 继续")]);
+        assert_eq!(test.match_count, 2);
+        assert!(test.output.is_empty());
+    }
+
+    #[test]
+    fn test_slow_rule_thresholds() {
+        assert!(!is_slow(0, 0));
+        assert!(!is_slow(SLOW_COMPILE_US, SLOW_MATCH_US));
+        assert!(is_slow(SLOW_COMPILE_US + 1, 0));
+        assert!(is_slow(0, SLOW_MATCH_US + 1));
+        // An empty sample is never run, so it takes no time.
+        assert_eq!(time_per_mib("", |_| panic!("ran on nothing")), 0);
+    }
+}
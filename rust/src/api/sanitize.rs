@@ -0,0 +1,78 @@
+//! Unicode clean-up of chapter text before it is stored.
+//!
+//! Text from sites and files can carry the same letter in composed and
+//! decomposed forms, byte order marks in the middle of a chapter after
+//! files were concatenated, stray bidi controls and C0/C1 control
+//! characters. None of them show, but they stop a query from matching the
+//! text it looks identical to and shift highlight offsets. Sanitized text
+//! is in NFC with all of those removed, except tabs and line breaks, and
+//! direction marks next to right-to-left text, where they do real work.
+
+use std::borrow::Cow;
+
+use unicode_normalization::{is_nfc_quick, IsNormalized, UnicodeNormalization};
+
+use crate::api::watermark::is_rtl;
+
+/// Sanitize text: normalize to NFC and remove byte order marks, bidi
+/// controls and control characters other than tabs and line breaks.
+#[flutter_rust_bridge::frb]
+pub fn sanitize_text(text: String) -> String {
+    sanitize(&text).into_owned()
+}
+
+/// `sanitize_text`, borrowing text that needs no change.
+pub(crate) fn sanitize(text: &str) -> Cow<'_, str> {
+    let chars: Vec<char> = text.chars().collect();
+    let removed = |i: usize| {
+        let prev = i.checked_sub(1).map(|i| chars[i]);
+        is_removed(chars[i], prev, chars.get(i + 1).copied())
+    };
+    let clean = !(0..chars.len()).any(removed) && !chars.contains(&'\u{0085}');
+    if clean && is_nfc_quick(chars.iter().copied()) == IsNormalized::Yes {
+        return Cow::Borrowed(text);
+    }
+    let kept = (0..chars.len())
+        .filter(|&i| !removed(i))
+        .map(|i| match chars[i] {
+            // NEXT LINE is a line break in EBCDIC-derived text.
+            '\u{0085}' => '\n',
+            c => c,
+        });
+    Cow::Owned(kept.nfc().collect())
+}
+
+fn is_removed(c: char, prev: Option<char>, next: Option<char>) -> bool {
+    match c {
+        '\t' | '\n' | '\r' | '\u{0085}' => false,
+        '\u{0000}'..='\u{001F}' | '\u{007F}'..='\u{009F}' => true,
+        '\u{FEFF}' | '\u{FFFE}' => true,
+        '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}' => true,
+        '\u{200E}' | '\u{200F}' | '\u{061C}' => {
+            !(prev.is_some_and(is_rtl) || next.is_some_and(is_rtl))
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_removes_controls_and_normalizes() {
+        let text = "\u{FEFF}第一章\r\n\u{202E}Cafe\u{0301}\u{0007}，\t说\u{FEFF}话\u{0085}完";
+        assert_eq!(
+            sanitize_text(text.to_string()),
+            "第一章\r\nCafé，\t说话\n完"
+        );
+        assert_eq!(sanitize_text("שלום\u{200F}!".to_string()), "שלום\u{200F}!");
+        assert_eq!(sanitize_text("a\u{200E}b".to_string()), "ab");
+    }
+
+    #[test]
+    fn test_sanitize_borrows_clean_text() {
+        assert!(matches!(sanitize("天色渐晚。\nCafé"), Cow::Borrowed(_)));
+        assert!(matches!(sanitize("Cafe\u{0301}"), Cow::Owned(_)));
+    }
+}
@@ -0,0 +1,995 @@
+//! Full-text search over cached chapters.
+//!
+//! Each book has two files under the index directory. The `.idx` file is
+//! append-only and holds the chapters' text, deflated: re-indexing a chapter
+//! appends a new record, and the file is rewritten once superseded records
+//! make up most of it. The `.post` file holds the inverted index from
+//! character bigrams to chapters, along with where each live chapter's
+//! record sits in the `.idx` file; bigrams work for CJK text, which has no
+//! word separators. It is rewritten after every change and stamped with the
+//! size and modification time of the `.idx` file it describes. If the two
+//! disagree, such as after a crash between the writes, the `.idx` file is
+//! replayed once and the postings rebuilt.
+//!
+//! Searching a book loads its postings and reads only the records of the
+//! chapters that contain every bigram of the query; chapter text is never
+//! kept in memory. The postings of the `MAX_OPEN_BOOKS` most recently used
+//! books stay loaded. Queries are matched as phrases, case-insensitively,
+//! and every candidate chapter is confirmed against its text, so the index
+//! never produces false hits.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::UNIX_EPOCH;
+
+use anyhow::{anyhow, Result};
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+
+use crate::api::error::ApiError;
+use crate::api::font_converter;
+use crate::api::sanitize::sanitize;
+
+const MAGIC: &[u8; 5] = b"NVSI1";
+const POSTINGS_MAGIC: &[u8; 5] = b"NVSP1";
+const ENTRY_EXTENSION: &str = "idx";
+const POSTINGS_EXTENSION: &str = "post";
+const RECORD_UPSERT: u8 = 1;
+
+/// Longest book ID read from a file header.
+const MAX_ID_LEN: u32 = 4096;
+
+/// Books whose postings are kept in memory.
+const MAX_OPEN_BOOKS: usize = 8;
+
+/// Characters of context on each side of a match in a snippet.
+const SNIPPET_CONTEXT: usize = 30;
+
+/// Rewrite a book's file when it exceeds its live records by this much.
+const COMPACT_SLACK_BYTES: u64 = 64 * 1024;
+
+static SEARCH_INDEX: Mutex<Option<SearchIndex>> = Mutex::new(None);
+
+#[derive(Debug, Clone)]
+pub struct SearchChapter {
+    pub chapter_index: u32,
+    pub title: String,
+    pub text: String,
+}
+
+/// An occurrence of the query. Offsets are UTF-16 code units.
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    pub book_id: String,
+    pub chapter_index: u32,
+    pub chapter_title: String,
+    /// Start of the match in the chapter text.
+    pub offset: u32,
+    pub length: u32,
+    /// Text around the match on a single line.
+    pub snippet: String,
+    pub highlight_start: u32,
+    pub highlight_end: u32,
+}
+
+/// Enable the search index, stored under `index_dir`. Calling this again
+/// switches to the new directory.
+#[flutter_rust_bridge::frb]
+pub fn init_search_index(index_dir: String) -> Result<(), ApiError> {
+    let index = SearchIndex::new(PathBuf::from(index_dir))?;
+    *lock() = Some(index);
+    Ok(())
+}
+
+/// Add chapters to a book's index, replacing earlier versions of the same
+/// chapter indexes. Titles and text are sanitized first, as by
+/// `sanitize_text`.
+#[flutter_rust_bridge::frb]
+pub fn index_chapters(book_id: String, chapters: Vec<SearchChapter>) -> Result<(), ApiError> {
+    let chapters = chapters
+        .into_iter()
+        .map(|chapter| SearchChapter {
+            title: sanitize(&chapter.title).into_owned(),
+            text: sanitize(&chapter.text).into_owned(),
+            ..chapter
+        })
+        .collect();
+    Ok(with_index(|index| index.book(&book_id)?.upsert(chapters))?)
+}
+
+/// Chapter indexes already in a book's index, in ascending order.
+#[flutter_rust_bridge::frb]
+pub fn indexed_chapters(book_id: String) -> Result<Vec<u32>, ApiError> {
+    Ok(with_index(|index| {
+        Ok(index.book(&book_id)?.chapters.keys().copied().collect())
+    })?)
+}
+
+/// Drop a book from the index and delete its file.
+#[flutter_rust_bridge::frb]
+pub fn remove_book_from_index(book_id: String) -> Result<(), ApiError> {
+    Ok(with_index(|index| index.remove(&book_id))?)
+}
+
+/// Find `query` in one book, in chapter order.
+#[flutter_rust_bridge::frb]
+pub fn search_book(
+    book_id: String,
+    query: String,
+    max_results: u32,
+) -> Result<Vec<SearchHit>, ApiError> {
+    Ok(with_index(|index| {
+        let mut hits = Vec::new();
+        index
+            .book(&book_id)?
+            .search(&query, max_results as usize, &mut hits);
+        Ok(hits)
+    })?)
+}
+
+/// Find `query` in every indexed book, ordered by book ID then chapter.
+#[flutter_rust_bridge::frb]
+pub fn search_library(query: String, max_results: u32) -> Result<Vec<SearchHit>, ApiError> {
+    Ok(with_index(|index| {
+        let mut ids = index.book_ids()?;
+        ids.sort();
+        let mut hits = Vec::new();
+        for id in ids {
+            if hits.len() >= max_results as usize {
+                break;
+            }
+            index
+                .book(&id)?
+                .search(&query, max_results as usize, &mut hits);
+        }
+        Ok(hits)
+    })?)
+}
+
+fn lock() -> std::sync::MutexGuard<'static, Option<SearchIndex>> {
+    SEARCH_INDEX.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn with_index<T>(f: impl FnOnce(&mut SearchIndex) -> Result<T>) -> Result<T> {
+    let mut index = lock();
+    f(index
+        .as_mut()
+        .ok_or_else(|| anyhow!("Search index is not initialized"))?)
+}
+
+/// Lowercase `text` without changing any character's UTF-8 length, so byte
+/// offsets in the folded text are valid in the original.
+fn fold(text: &str) -> String {
+    text.chars()
+        .map(|c| {
+            let mut lower = c.to_lowercase();
+            match (lower.next(), lower.next()) {
+                (Some(l), None) if l.len_utf8() == c.len_utf8() => l,
+                _ => c,
+            }
+        })
+        .collect()
+}
+
+fn bigrams(text: &str) -> impl Iterator<Item = (char, char)> + '_ {
+    text.chars().zip(text.chars().skip(1))
+}
+
+/// Size and modification time in nanoseconds of a book's `.idx` file.
+type Stamp = (u64, u64);
+
+fn stamp(metadata: &fs::Metadata) -> Stamp {
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |d| d.as_nanos() as u64);
+    (metadata.len(), modified)
+}
+
+/// Hash of a chapter's title and text, to skip re-indexing unchanged ones.
+fn digest(title: &str, text: &str) -> [u8; 32] {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&(title.len() as u64).to_le_bytes());
+    hasher.update(title.as_bytes());
+    hasher.update(text.as_bytes());
+    *hasher.finalize().as_bytes()
+}
+
+/// The book ID in the header of an `.idx` file, reading nothing past it.
+fn read_header(path: &Path) -> Option<String> {
+    let mut file = fs::File::open(path).ok()?;
+    let mut fixed = [0; MAGIC.len() + 4];
+    file.read_exact(&mut fixed).ok()?;
+    if &fixed[..MAGIC.len()] != MAGIC {
+        return None;
+    }
+    let len = u32::from_le_bytes(fixed[MAGIC.len()..].try_into().ok()?);
+    if len > MAX_ID_LEN {
+        return None;
+    }
+    let mut id = vec![0; len as usize];
+    file.read_exact(&mut id).ok()?;
+    String::from_utf8(id).ok()
+}
+
+struct SearchIndex {
+    dir: PathBuf,
+    books: HashMap<String, BookIndex>,
+    /// Bumped on every access, to find the least recently used book.
+    clock: u64,
+}
+
+impl SearchIndex {
+    fn new(dir: PathBuf) -> Result<Self> {
+        fs::create_dir_all(&dir).map_err(|e| anyhow!("Failed to create {}: {e}", dir.display()))?;
+        Ok(Self {
+            dir,
+            books: HashMap::new(),
+            clock: 0,
+        })
+    }
+
+    fn book_path(&self, book_id: &str, extension: &str) -> PathBuf {
+        let hash = blake3::hash(book_id.as_bytes()).to_hex();
+        self.dir.join(format!("{hash}.{extension}"))
+    }
+
+    /// The index of a book, loading its postings on first use and closing
+    /// the least recently used book past `MAX_OPEN_BOOKS`.
+    fn book(&mut self, book_id: &str) -> Result<&mut BookIndex> {
+        self.clock += 1;
+        if !self.books.contains_key(book_id) {
+            let book = BookIndex::open(
+                book_id,
+                self.book_path(book_id, ENTRY_EXTENSION),
+                self.book_path(book_id, POSTINGS_EXTENSION),
+            )?;
+            if self.books.len() >= MAX_OPEN_BOOKS {
+                let oldest = self
+                    .books
+                    .iter()
+                    .min_by_key(|(_, book)| book.last_used)
+                    .map(|(id, _)| id.clone());
+                if let Some(id) = oldest {
+                    self.books.remove(&id);
+                }
+            }
+            self.books.insert(book_id.to_string(), book);
+        }
+        let book = self.books.get_mut(book_id).unwrap();
+        book.last_used = self.clock;
+        Ok(book)
+    }
+
+    fn remove(&mut self, book_id: &str) -> Result<()> {
+        self.books.remove(book_id);
+        for extension in [ENTRY_EXTENSION, POSTINGS_EXTENSION] {
+            let path = self.book_path(book_id, extension);
+            match fs::remove_file(&path) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                    return Err(anyhow!("Failed to remove {}: {e}", path.display()));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// IDs of every book with an index file, read from the file headers.
+    fn book_ids(&self) -> Result<Vec<String>> {
+        let dir = fs::read_dir(&self.dir)
+            .map_err(|e| anyhow!("Failed to read {}: {e}", self.dir.display()))?;
+
+        let mut ids = Vec::new();
+        for item in dir.flatten() {
+            let path = item.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some(ENTRY_EXTENSION) {
+                continue;
+            }
+            if let Some(id) = read_header(&path) {
+                ids.push(id);
+            }
+        }
+        Ok(ids)
+    }
+}
+
+struct Chapter {
+    title: String,
+    digest: [u8; 32],
+    /// Position and size of the chapter's record in the `.idx` file.
+    offset: u64,
+    record_len: u64,
+}
+
+struct BookIndex {
+    id: String,
+    path: PathBuf,
+    postings_path: PathBuf,
+    chapters: BTreeMap<u32, Chapter>,
+    /// Chapters containing each bigram of their folded text, ascending.
+    postings: HashMap<(char, char), Vec<u32>>,
+    file_len: u64,
+    last_used: u64,
+}
+
+impl BookIndex {
+    fn open(id: &str, path: PathBuf, postings_path: PathBuf) -> Result<Self> {
+        let mut book = Self {
+            id: id.to_string(),
+            path,
+            postings_path,
+            chapters: BTreeMap::new(),
+            postings: HashMap::new(),
+            file_len: 0,
+            last_used: 0,
+        };
+        let metadata = match fs::metadata(&book.path) {
+            Ok(metadata) => metadata,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(book),
+            Err(e) => return Err(anyhow!("Failed to read {}: {e}", book.path.display())),
+        };
+        let stamp = stamp(&metadata);
+        let saved = fs::read(&book.postings_path).ok();
+        match saved.and_then(|data| Reader::new(&data).postings(id, stamp)) {
+            Some((chapters, postings)) => {
+                book.chapters = chapters;
+                book.postings = postings;
+                book.file_len = stamp.0;
+            }
+            None => book.replay()?,
+        }
+        Ok(book)
+    }
+
+    /// Rebuild the chapter table and postings from the `.idx` file.
+    fn replay(&mut self) -> Result<()> {
+        let data = fs::read(&self.path)
+            .map_err(|e| anyhow!("Failed to read {}: {e}", self.path.display()))?;
+        let mut reader = Reader::new(&data);
+        if reader.header().as_deref() != Some(self.id.as_str()) {
+            // Unreadable or colliding files are replaced on the next write.
+            self.file_len = u64::MAX;
+            return Ok(());
+        }
+        let mut texts = BTreeMap::new();
+        loop {
+            let offset = reader.pos;
+            let Some((index, title, text)) = reader.record() else {
+                break;
+            };
+            let chapter = Chapter {
+                digest: digest(&title, &text),
+                title,
+                offset: offset as u64,
+                record_len: (reader.pos - offset) as u64,
+            };
+            self.chapters.insert(index, chapter);
+            texts.insert(index, text);
+        }
+        for (index, text) in texts {
+            self.post(index, &text);
+        }
+        // A torn final record from an interrupted write forces a rewrite.
+        if reader.pos == data.len() {
+            self.file_len = data.len() as u64;
+            // A failed write only costs another replay next time.
+            let _ = self.save_postings();
+        } else {
+            self.file_len = u64::MAX;
+        }
+        Ok(())
+    }
+
+    fn upsert(&mut self, chapters: Vec<SearchChapter>) -> Result<()> {
+        let mut pending = BTreeMap::new();
+        for chapter in chapters {
+            let digest = digest(&chapter.title, &chapter.text);
+            let index = chapter.chapter_index;
+            if self
+                .chapters
+                .get(&index)
+                .is_some_and(|c| c.digest == digest)
+            {
+                pending.remove(&index);
+            } else {
+                pending.insert(index, (chapter, digest));
+            }
+        }
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        // Replaced chapters leave the postings, so read their text before
+        // a rewrite drops it.
+        let mut replaced = Vec::new();
+        let mut file = None;
+        for index in pending.keys() {
+            let Some(chapter) = self.chapters.get(index) else {
+                continue;
+            };
+            if file.is_none() {
+                file = fs::File::open(&self.path).ok();
+            }
+            let text = file.as_mut().and_then(|file| read_text(file, chapter));
+            replaced.push((*index, text));
+        }
+        drop(file);
+
+        let mut records = Vec::new();
+        let mut added = Vec::new();
+        for (index, (chapter, digest)) in pending {
+            let start = records.len();
+            write_upsert(&mut records, &chapter)?;
+            let entry = Chapter {
+                title: chapter.title,
+                digest,
+                offset: start as u64,
+                record_len: (records.len() - start) as u64,
+            };
+            added.push((index, entry, chapter.text));
+        }
+
+        let replaced_len: u64 = added
+            .iter()
+            .filter_map(|(index, ..)| self.chapters.get(index))
+            .map(|chapter| chapter.record_len)
+            .sum();
+        let live_len = self.live_len() - replaced_len + records.len() as u64;
+        let new_len = self.file_len.saturating_add(records.len() as u64);
+        let base = if self.file_len == 0 || new_len > live_len * 2 + COMPACT_SLACK_BYTES {
+            let replaced: HashSet<u32> = added.iter().map(|(index, ..)| *index).collect();
+            self.rewrite(&records, &replaced)?
+        } else {
+            self.append(&records)?
+        };
+
+        for (index, text) in replaced {
+            if let Some(text) = text {
+                self.unpost(index, &text);
+            }
+        }
+        for (index, mut chapter, text) in added {
+            chapter.offset += base;
+            self.chapters.insert(index, chapter);
+            self.post(index, &text);
+        }
+        // A failed write only costs a replay the next time the book opens.
+        let _ = self.save_postings();
+        Ok(())
+    }
+
+    fn live_len(&self) -> u64 {
+        header(&self.id).len() as u64
+            + self
+                .chapters
+                .values()
+                .map(|chapter| chapter.record_len)
+                .sum::<u64>()
+    }
+
+    /// Append records and return the offset they start at.
+    fn append(&mut self, records: &[u8]) -> Result<u64> {
+        let appended = fs::File::options()
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| file.write_all(records).and_then(|()| file.sync_all()));
+        if let Err(e) = appended {
+            return Err(anyhow!("Failed to write {}: {e}", self.path.display()));
+        }
+        let base = self.file_len;
+        self.file_len += records.len() as u64;
+        Ok(base)
+    }
+
+    /// Write a fresh file holding the live records not in `replaced`, then
+    /// `records`, and return the offset `records` start at.
+    fn rewrite(&mut self, records: &[u8], replaced: &HashSet<u32>) -> Result<u64> {
+        let mut data = header(&self.id);
+        let kept: Vec<u32> = self
+            .chapters
+            .keys()
+            .filter(|index| !replaced.contains(index))
+            .copied()
+            .collect();
+        let old = if kept.is_empty() {
+            Vec::new()
+        } else {
+            fs::read(&self.path)
+                .map_err(|e| anyhow!("Failed to read {}: {e}", self.path.display()))?
+        };
+        let mut moved = Vec::new();
+        for index in kept {
+            let chapter = &self.chapters[&index];
+            let start = chapter.offset as usize;
+            let record = start
+                .checked_add(chapter.record_len as usize)
+                .and_then(|end| old.get(start..end));
+            moved.push((index, record.map(|_| data.len() as u64)));
+            data.extend_from_slice(record.unwrap_or_default());
+        }
+        let base = data.len() as u64;
+        data.extend_from_slice(records);
+        font_converter::write_atomically(&self.path, &data)?;
+
+        for (index, offset) in moved {
+            match offset {
+                Some(offset) => self.chapters.get_mut(&index).unwrap().offset = offset,
+                // Records missing from a damaged file are dropped; their
+                // stale postings only cost a candidate that does not match.
+                None => {
+                    self.chapters.remove(&index);
+                }
+            }
+        }
+        self.file_len = data.len() as u64;
+        Ok(base)
+    }
+
+    fn post(&mut self, index: u32, text: &str) {
+        let unique: HashSet<(char, char)> = bigrams(&fold(text)).collect();
+        for bigram in unique {
+            let list = self.postings.entry(bigram).or_default();
+            if let Err(position) = list.binary_search(&index) {
+                list.insert(position, index);
+            }
+        }
+    }
+
+    fn unpost(&mut self, index: u32, text: &str) {
+        let unique: HashSet<(char, char)> = bigrams(&fold(text)).collect();
+        for bigram in unique {
+            let Some(list) = self.postings.get_mut(&bigram) else {
+                continue;
+            };
+            if let Ok(position) = list.binary_search(&index) {
+                list.remove(position);
+            }
+            if list.is_empty() {
+                self.postings.remove(&bigram);
+            }
+        }
+    }
+
+    fn save_postings(&self) -> Result<()> {
+        let metadata = fs::metadata(&self.path)
+            .map_err(|e| anyhow!("Failed to read {}: {e}", self.path.display()))?;
+        let (len, modified) = stamp(&metadata);
+        let mut out = POSTINGS_MAGIC.to_vec();
+        put_bytes(&mut out, self.id.as_bytes());
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&modified.to_le_bytes());
+        out.extend_from_slice(&(self.chapters.len() as u32).to_le_bytes());
+        for (index, chapter) in &self.chapters {
+            out.extend_from_slice(&index.to_le_bytes());
+            put_bytes(&mut out, chapter.title.as_bytes());
+            out.extend_from_slice(&chapter.digest);
+            out.extend_from_slice(&chapter.offset.to_le_bytes());
+            out.extend_from_slice(&chapter.record_len.to_le_bytes());
+        }
+        out.extend_from_slice(&(self.postings.len() as u32).to_le_bytes());
+        for (&(first, second), list) in &self.postings {
+            out.extend_from_slice(&(first as u32).to_le_bytes());
+            out.extend_from_slice(&(second as u32).to_le_bytes());
+            out.extend_from_slice(&(list.len() as u32).to_le_bytes());
+            for index in list {
+                out.extend_from_slice(&index.to_le_bytes());
+            }
+        }
+        font_converter::write_atomically(&self.postings_path, &out)
+    }
+
+    /// Chapters that contain every bigram of `query`.
+    fn candidates(&self, query: &str) -> Vec<u32> {
+        if query.chars().nth(1).is_none() {
+            return self.chapters.keys().copied().collect();
+        }
+        let unique: HashSet<(char, char)> = bigrams(query).collect();
+        let mut lists: Vec<&Vec<u32>> = Vec::new();
+        for bigram in unique {
+            match self.postings.get(&bigram) {
+                Some(list) => lists.push(list),
+                None => return Vec::new(),
+            }
+        }
+        lists.sort_by_key(|list| list.len());
+        let mut result = lists[0].clone();
+        for list in &lists[1..] {
+            result.retain(|index| list.binary_search(index).is_ok());
+        }
+        // Stale postings of dropped chapters are skipped.
+        result.retain(|index| self.chapters.contains_key(index));
+        result
+    }
+
+    fn search(&self, query: &str, max_results: usize, hits: &mut Vec<SearchHit>) {
+        let query = fold(sanitize(query).trim());
+        if query.is_empty() {
+            return;
+        }
+        let candidates = self.candidates(&query);
+        if candidates.is_empty() {
+            return;
+        }
+        let Ok(mut file) = fs::File::open(&self.path) else {
+            return;
+        };
+        for index in candidates {
+            let chapter = &self.chapters[&index];
+            let Some(text) = read_text(&mut file, chapter) else {
+                continue;
+            };
+            let folded = fold(&text);
+            let mut utf16 = 0;
+            let mut counted = 0;
+            for (start, _) in folded.match_indices(&query) {
+                if hits.len() >= max_results {
+                    return;
+                }
+                utf16 += text[counted..start].encode_utf16().count() as u32;
+                counted = start;
+                let end = start + query.len();
+                let (snippet, highlight_start, highlight_end) = snippet(&text, start, end);
+                hits.push(SearchHit {
+                    book_id: self.id.clone(),
+                    chapter_index: index,
+                    chapter_title: chapter.title.clone(),
+                    offset: utf16,
+                    length: text[start..end].encode_utf16().count() as u32,
+                    snippet,
+                    highlight_start,
+                    highlight_end,
+                });
+            }
+        }
+    }
+}
+
+/// The text of a chapter's record in an open `.idx` file.
+fn read_text(file: &mut fs::File, chapter: &Chapter) -> Option<String> {
+    file.seek(SeekFrom::Start(chapter.offset)).ok()?;
+    let mut record = Vec::new();
+    Read::take(file, chapter.record_len)
+        .read_to_end(&mut record)
+        .ok()?;
+    let (_, _, text) = Reader::new(&record).record()?;
+    Some(text)
+}
+
+/// The match `text[start..end]` with some context, line breaks flattened,
+/// and the UTF-16 range of the match within it.
+fn snippet(text: &str, start: usize, end: usize) -> (String, u32, u32) {
+    let before_start = text[..start]
+        .char_indices()
+        .rev()
+        .nth(SNIPPET_CONTEXT - 1)
+        .map_or(0, |(i, _)| i);
+    let after_end = text[end..]
+        .char_indices()
+        .nth(SNIPPET_CONTEXT)
+        .map_or(text.len(), |(i, _)| end + i);
+
+    let flatten = |s: &str| -> String {
+        s.chars()
+            .map(|c| if c.is_control() { ' ' } else { c })
+            .collect()
+    };
+    let before = flatten(text[before_start..start].trim_start());
+    let matched = flatten(&text[start..end]);
+    let after = flatten(text[end..after_end].trim_end());
+
+    let highlight_start = before.encode_utf16().count() as u32;
+    let highlight_end = highlight_start + matched.encode_utf16().count() as u32;
+    (before + &matched + &after, highlight_start, highlight_end)
+}
+
+fn header(book_id: &str) -> Vec<u8> {
+    let mut out = MAGIC.to_vec();
+    put_bytes(&mut out, book_id.as_bytes());
+    out
+}
+
+fn put_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    out.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+    out.extend_from_slice(bytes);
+}
+
+fn write_upsert(out: &mut Vec<u8>, chapter: &SearchChapter) -> Result<()> {
+    let mut encoder = DeflateEncoder::new(Vec::new(), Compression::fast());
+    encoder
+        .write_all(chapter.text.as_bytes())
+        .and_then(|()| encoder.flush())
+        .map_err(|e| anyhow!("Failed to compress chapter {}: {e}", chapter.chapter_index))?;
+    let body = encoder
+        .finish()
+        .map_err(|e| anyhow!("Failed to compress chapter {}: {e}", chapter.chapter_index))?;
+
+    out.push(RECORD_UPSERT);
+    out.extend_from_slice(&chapter.chapter_index.to_le_bytes());
+    put_bytes(out, chapter.title.as_bytes());
+    put_bytes(out, &body);
+    Ok(())
+}
+
+/// Parser for book and postings files that stops at the first malformed
+/// record.
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let bytes = self.data.get(self.pos..self.pos.checked_add(len)?)?;
+        self.pos += len;
+        Some(bytes)
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_le_bytes(self.take(4)?.try_into().ok()?))
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        Some(u64::from_le_bytes(self.take(8)?.try_into().ok()?))
+    }
+
+    fn bytes(&mut self) -> Option<&'a [u8]> {
+        let len = self.u32()? as usize;
+        self.take(len)
+    }
+
+    fn string(&mut self) -> Option<String> {
+        String::from_utf8(self.bytes()?.to_vec()).ok()
+    }
+
+    /// The book ID.
+    fn header(&mut self) -> Option<String> {
+        if self.take(MAGIC.len())? != MAGIC {
+            return None;
+        }
+        self.string()
+    }
+
+    /// The next chapter's index, title and text.
+    fn record(&mut self) -> Option<(u32, String, String)> {
+        let start = self.pos;
+        let record = self.parse_record();
+        if record.is_none() {
+            self.pos = start;
+        }
+        record
+    }
+
+    fn parse_record(&mut self) -> Option<(u32, String, String)> {
+        if self.take(1)? != [RECORD_UPSERT] {
+            return None;
+        }
+        let index = self.u32()?;
+        let title = self.string()?;
+        let mut text = String::new();
+        DeflateDecoder::new(self.bytes()?)
+            .read_to_string(&mut text)
+            .ok()?;
+        Some((index, title, text))
+    }
+
+    /// The chapter table and postings of a `.post` file, if it belongs to
+    /// `book_id` and describes the `.idx` file with `stamp`.
+    #[allow(clippy::type_complexity)]
+    fn postings(
+        &mut self,
+        book_id: &str,
+        stamp: Stamp,
+    ) -> Option<(BTreeMap<u32, Chapter>, HashMap<(char, char), Vec<u32>>)> {
+        if self.take(POSTINGS_MAGIC.len())? != POSTINGS_MAGIC
+            || self.string()? != book_id
+            || (self.u64()?, self.u64()?) != stamp
+        {
+            return None;
+        }
+        let mut chapters = BTreeMap::new();
+        for _ in 0..self.u32()? {
+            let index = self.u32()?;
+            let chapter = Chapter {
+                title: self.string()?,
+                digest: self.take(32)?.try_into().ok()?,
+                offset: self.u64()?,
+                record_len: self.u64()?,
+            };
+            if chapter.offset.checked_add(chapter.record_len)? > stamp.0 {
+                return None;
+            }
+            chapters.insert(index, chapter);
+        }
+        let mut postings = HashMap::new();
+        for _ in 0..self.u32()? {
+            let bigram = (char::from_u32(self.u32()?)?, char::from_u32(self.u32()?)?);
+            let len = self.u32()?;
+            let mut list = Vec::new();
+            for _ in 0..len {
+                list.push(self.u32()?);
+            }
+            postings.insert(bigram, list);
+        }
+        (self.pos == self.data.len()).then_some((chapters, postings))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_dirs::temp_dir;
+
+    fn temp_index(name: &str) -> SearchIndex {
+        SearchIndex::new(temp_dir(&format!("search-{name}"))).unwrap()
+    }
+
+    fn chapter(chapter_index: u32, title: &str, text: &str) -> SearchChapter {
+        SearchChapter {
+            chapter_index,
+            title: title.to_string(),
+            text: text.to_string(),
+        }
+    }
+
+    fn search(index: &mut SearchIndex, book_id: &str, query: &str) -> Vec<SearchHit> {
+        let mut hits = Vec::new();
+        index.book(book_id).unwrap().search(query, 100, &mut hits);
+        hits
+    }
+
+    #[test]
+    fn test_finds_phrases_with_offsets_and_snippets() {
+        let mut index = temp_index("phrase");
+        index
+            .book("book")
+            .unwrap()
+            .upsert(vec![
+                chapter(0, "第一章", "天色渐晚。\n城门外的官道上行人稀少。"),
+                chapter(1, "第二章", "😀官道尽头，The Road 通向远山；官道漫长。"),
+                chapter(2, "第三章", "道官"),
+            ])
+            .unwrap();
+
+        let hits = search(&mut index, "book", "官道");
+        let found: Vec<_> = hits.iter().map(|h| (h.chapter_index, h.offset)).collect();
+        assert_eq!(found, vec![(0, 10), (1, 2), (1, 21)]);
+        assert_eq!(hits[0].snippet, "天色渐晚。 城门外的官道上行人稀少。");
+        assert_eq!((hits[0].highlight_start, hits[0].highlight_end), (10, 12));
+        assert_eq!(hits[0].chapter_title, "第一章");
+
+        let hits = search(&mut index, "book", "the road");
+        assert_eq!(
+            (hits[0].chapter_index, hits[0].offset, hits[0].length),
+            (1, 7, 8)
+        );
+        assert!(search(&mut index, "book", "官道上行人很多").is_empty());
+        assert_eq!(search(&mut index, "book", "道").len(), 4);
+        fs::remove_dir_all(&index.dir).unwrap();
+    }
+
+    #[test]
+    fn test_updates_persist_across_reloads() {
+        let mut index = temp_index("persist");
+        let book = index.book("book").unwrap();
+        book.upsert(vec![chapter(0, "一", "旧的内容"), chapter(1, "二", "其他")])
+            .unwrap();
+        book.upsert(vec![chapter(0, "一", "新的内容")]).unwrap();
+        assert!(search(&mut index, "book", "旧的").is_empty());
+
+        let dir = index.dir.clone();
+        let mut index = SearchIndex::new(dir).unwrap();
+        assert_eq!(search(&mut index, "book", "新的").len(), 1);
+        assert!(search(&mut index, "book", "旧的").is_empty());
+        assert_eq!(index.book_ids().unwrap(), vec!["book".to_string()]);
+
+        // Superseded records are compacted away once they dominate the file.
+        let book = index.book("book").unwrap();
+        let mut seed = 1u32;
+        let big: String = (0..100_000)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                char::from_u32(0x4E00 + (seed >> 16) % 0x5000).unwrap()
+            })
+            .collect();
+        book.upsert(vec![chapter(2, "三", &big)]).unwrap();
+        book.upsert(vec![chapter(2, "三", &format!("{big}尾"))])
+            .unwrap();
+        book.upsert(vec![chapter(2, "三", &format!("{big}末"))])
+            .unwrap();
+        let file_len = fs::metadata(&book.path).unwrap().len();
+        assert!(file_len < book.live_len() * 2);
+        let mut file = fs::File::open(&book.path).unwrap();
+        let text = read_text(&mut file, &book.chapters[&2]).unwrap();
+        assert!(text.ends_with('末'));
+        assert_eq!(search(&mut index, "book", "新的").len(), 1);
+
+        index.remove("book").unwrap();
+        assert!(index.book_ids().unwrap().is_empty());
+        assert_eq!(fs::read_dir(&index.dir).unwrap().count(), 0);
+        fs::remove_dir_all(&index.dir).unwrap();
+    }
+
+    #[test]
+    fn test_ignores_torn_final_record() {
+        let mut index = temp_index("torn");
+        let book = index.book("book").unwrap();
+        book.upsert(vec![chapter(0, "一", "完整的章节")]).unwrap();
+        book.upsert(vec![chapter(1, "二", "写了一半的章节")])
+            .unwrap();
+        let path = book.path.clone();
+        let data = fs::read(&path).unwrap();
+        fs::write(&path, &data[..data.len() - 3]).unwrap();
+
+        let mut index = SearchIndex::new(index.dir.clone()).unwrap();
+        assert_eq!(index.book("book").unwrap().chapters.len(), 1);
+        index
+            .book("book")
+            .unwrap()
+            .upsert(vec![chapter(1, "二", "重新写入的章节")])
+            .unwrap();
+
+        let mut index = SearchIndex::new(index.dir.clone()).unwrap();
+        assert_eq!(search(&mut index, "book", "章节").len(), 2);
+        fs::remove_dir_all(&index.dir).unwrap();
+    }
+
+    #[test]
+    fn test_postings_are_reused_until_stale() {
+        let mut index = temp_index("postings");
+        let book = index.book("book").unwrap();
+        book.upsert(vec![
+            chapter(0, "一", "城门外的官道"),
+            chapter(1, "二", "远山"),
+        ])
+        .unwrap();
+        let (path, postings_path) = (book.path.clone(), book.postings_path.clone());
+        let stamp = stamp(&fs::metadata(&path).unwrap());
+        let saved = fs::read(&postings_path).unwrap();
+        assert!(Reader::new(&saved).postings("book", stamp).is_some());
+        assert!(Reader::new(&saved).postings("other", stamp).is_none());
+
+        // A changed `.idx` file no longer matches, and is replayed instead.
+        let data = fs::read(&path).unwrap();
+        let stale = (stamp.0, stamp.1 + 1);
+        assert!(Reader::new(&saved).postings("book", stale).is_none());
+        fs::write(&path, &data).unwrap();
+        let file = fs::File::options().write(true).open(&path).unwrap();
+        file.set_modified(UNIX_EPOCH + std::time::Duration::from_secs(1))
+            .unwrap();
+        drop(file);
+        let mut index = SearchIndex::new(index.dir.clone()).unwrap();
+        assert_eq!(search(&mut index, "book", "官道").len(), 1);
+        let stamp = super::stamp(&fs::metadata(&path).unwrap());
+        let saved = fs::read(&postings_path).unwrap();
+        assert!(Reader::new(&saved).postings("book", stamp).is_some());
+        fs::remove_dir_all(&index.dir).unwrap();
+    }
+
+    #[test]
+    fn test_keeps_a_bounded_number_of_books_open() {
+        let mut index = temp_index("bounded");
+        for i in 0..MAX_OPEN_BOOKS + 3 {
+            index
+                .book(&format!("book{i}"))
+                .unwrap()
+                .upsert(vec![chapter(0, "一", &format!("第{i}本书的内容"))])
+                .unwrap();
+        }
+        assert_eq!(index.books.len(), MAX_OPEN_BOOKS);
+        assert!(!index.books.contains_key("book0"));
+        assert_eq!(search(&mut index, "book0", "第0本").len(), 1);
+        assert_eq!(index.books.len(), MAX_OPEN_BOOKS);
+        assert!(!index.books.contains_key("book1"));
+        fs::remove_dir_all(&index.dir).unwrap();
+    }
+}
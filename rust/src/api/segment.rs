@@ -0,0 +1,115 @@
+//! Chinese word segmentation for long-press lookup.
+//!
+//! Segmentation uses jieba's bundled dictionary, with its HMM enabled so
+//! names and other out-of-dictionary words still group into one token.
+//! The dictionary takes a moment to load, so it is loaded once on first use.
+//! Offsets are UTF-16 code units, matching Dart string indexes.
+
+use std::sync::OnceLock;
+
+use jieba_rs::Jieba;
+
+static JIEBA: OnceLock<Jieba> = OnceLock::new();
+
+#[derive(Debug, Clone)]
+pub struct TextSegment {
+    pub text: String,
+    pub start: u32,
+    pub end: u32,
+    /// False for runs of whitespace and punctuation.
+    pub is_word: bool,
+}
+
+/// Split `text` into words. The segments cover the whole text in order.
+#[flutter_rust_bridge::frb]
+pub fn segment(text: String) -> Vec<TextSegment> {
+    segments(&text, 0)
+}
+
+/// The word containing the character at `offset`, or `None` if that
+/// character is whitespace or punctuation or `offset` is past the end.
+///
+/// Only the paragraph around `offset` is segmented, so this is cheap to
+/// call with a whole chapter.
+#[flutter_rust_bridge::frb]
+pub fn word_at(text: String, offset: u32) -> Option<TextSegment> {
+    let byte = byte_offset(&text, offset)?;
+    let start = text[..byte].rfind('\n').map_or(0, |i| i + 1);
+    let end = text[byte..].find('\n').map_or(text.len(), |i| byte + i);
+    let base = text[..start].encode_utf16().count() as u32;
+
+    segments(&text[start..end], base)
+        .into_iter()
+        .find(|segment| segment.end > offset)
+        .filter(|segment| segment.is_word)
+}
+
+pub(crate) fn jieba() -> &'static Jieba {
+    JIEBA.get_or_init(Jieba::new)
+}
+
+/// Byte index of the character starting at UTF-16 `offset`, or the
+/// character containing it.
+fn byte_offset(text: &str, offset: u32) -> Option<usize> {
+    let mut utf16 = 0;
+    for (byte, c) in text.char_indices() {
+        utf16 += c.len_utf16() as u32;
+        if utf16 > offset {
+            return Some(byte);
+        }
+    }
+    None
+}
+
+fn segments(text: &str, base: u32) -> Vec<TextSegment> {
+    let mut start = base;
+    jieba()
+        .cut(text, true)
+        .into_iter()
+        .map(|token| {
+            let end = start + token.word.encode_utf16().count() as u32;
+            let segment = TextSegment {
+                text: token.word.to_string(),
+                start,
+                end,
+                is_word: token.word.chars().any(char::is_alphanumeric),
+            };
+            start = end;
+            segment
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segments_cover_text() {
+        let text = "我们去北京大学，看看 Rust。";
+        let segments = segment(text.to_string());
+        let words: Vec<&str> = segments
+            .iter()
+            .filter(|s| s.is_word)
+            .map(|s| s.text.as_str())
+            .collect();
+        assert_eq!(words, vec!["我们", "去", "北京大学", "看看", "Rust"]);
+        let joined: String = segments.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(joined, text);
+        assert_eq!(segments.last().unwrap().end, 16);
+    }
+
+    #[test]
+    fn test_word_at_offset() {
+        let text = "第一章\n😀他走进了图书馆。".to_string();
+        let word = word_at(text.clone(), 10).unwrap();
+        assert_eq!(
+            (word.text.as_str(), word.start, word.end),
+            ("图书馆", 10, 13)
+        );
+        assert_eq!(word_at(text.clone(), 12).unwrap().text, "图书馆");
+        assert_eq!(word_at(text.clone(), 1).unwrap().text, "第一章");
+        assert!(word_at(text.clone(), 13).is_none());
+        assert!(word_at(text, 40).is_none());
+    }
+}
@@ -0,0 +1,406 @@
+//! Reading sessions and the statistics built from them.
+//!
+//! The reader starts a session when a book is opened, sends a heartbeat
+//! every minute or so while pages are on screen and stops it when the book
+//! is closed or the app is paused. Each heartbeat moves the session's end
+//! in the library database, so a crash loses at most the time since the
+//! last one. A heartbeat after more than `MAX_HEARTBEAT_GAP` seconds, such
+//! as after the device slept, does not count the gap: the old session
+//! ends at its last heartbeat and a new one starts.
+//!
+//! Sessions may overlap, for example when two readers are open at once or
+//! a crashed session was never stopped, so statistics merge overlapping
+//! time before adding it up. Days are calendar days at the caller's UTC offset.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::anyhow;
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::api::db;
+use crate::api::error::ApiError;
+
+/// Longest pause between heartbeats still counted as reading, in seconds.
+const MAX_HEARTBEAT_GAP: i64 = 300;
+
+/// Reading a day needs for it to count towards a streak, in seconds.
+const STREAK_MIN_SECONDS: u64 = 60;
+
+const DAY_SECONDS: i64 = 86_400;
+
+#[derive(Debug, Clone)]
+pub struct ReadingStats {
+    /// Reading time in the range, overlaps counted once.
+    pub total_seconds: u64,
+    /// Days with reading in the range, in date order.
+    pub days: Vec<ReadingDay>,
+    /// Books read in the range, most read first.
+    pub books: Vec<BookReadingTime>,
+    /// Consecutive days with reading up to the end of the range; the last
+    /// day not having been read yet does not break it.
+    pub current_streak: u32,
+    /// Longest run of consecutive days with reading up to the end of the range.
+    pub longest_streak: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadingDay {
+    /// Local date as `YYYY-MM-DD`.
+    pub date: String,
+    pub seconds: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BookReadingTime {
+    pub book_id: String,
+    pub seconds: u64,
+    pub sessions: u32,
+    /// End of the last session, in seconds since the Unix epoch.
+    pub last_read_at: i64,
+}
+
+/// Start a reading session now and return its ID.
+///
+/// # Arguments
+/// * `book_id` - Book being read
+#[flutter_rust_bridge::frb]
+pub fn start_reading_session(book_id: String) -> Result<i64, ApiError> {
+    Ok(db::with_db(|db| start(db, &book_id, now()))?)
+}
+
+/// Record that the reader is still reading. Returns the ID to use from
+/// now on, which is a new session's after a long pause.
+///
+/// # Arguments
+/// * `session_id` - ID from `start_reading_session` or the last heartbeat
+#[flutter_rust_bridge::frb]
+pub fn heartbeat_reading_session(session_id: i64) -> Result<i64, ApiError> {
+    db::with_db(|db| beat(db, session_id, now(), false))?
+        .ok_or_else(|| anyhow!("Unknown reading session {session_id}").into())
+}
+
+/// End a reading session now.
+///
+/// # Arguments
+/// * `session_id` - ID from `start_reading_session` or the last heartbeat
+#[flutter_rust_bridge::frb]
+pub fn stop_reading_session(session_id: i64) -> Result<(), ApiError> {
+    db::with_db(|db| beat(db, session_id, now(), true))?
+        .ok_or_else(|| anyhow!("Unknown reading session {session_id}"))?;
+    Ok(())
+}
+
+/// Reading statistics for a time range.
+///
+/// # Arguments
+/// * `from` - Start of the range, in seconds since the Unix epoch
+/// * `to` - End of the range, exclusive
+/// * `utc_offset_minutes` - Offset of the reader's time zone, e.g. 480 for
+///   China Standard Time
+#[flutter_rust_bridge::frb]
+pub fn reading_stats(
+    from: i64,
+    to: i64,
+    utc_offset_minutes: i32,
+) -> Result<ReadingStats, ApiError> {
+    if from >= to {
+        return Err(anyhow!("Invalid range: {from} is not before {to}").into());
+    }
+    let offset = utc_offset_minutes as i64 * 60;
+    Ok(db::with_db(|db| stats(db, from, to, offset))?)
+}
+
+/// Delete recorded sessions and return how many were deleted.
+///
+/// # Arguments
+/// * `book_id` - Only this book's sessions, or `None` for all of them
+#[flutter_rust_bridge::frb]
+pub fn clear_reading_sessions(book_id: Option<String>) -> Result<u32, ApiError> {
+    Ok(db::with_db(|db| {
+        let deleted = match &book_id {
+            Some(book_id) => {
+                db.execute("DELETE FROM reading_sessions WHERE book_id = ?1", [book_id])?
+            }
+            None => db.execute("DELETE FROM reading_sessions", [])?,
+        };
+        Ok(deleted as u32)
+    })?)
+}
+
+fn start(db: &Connection, book_id: &str, now: i64) -> rusqlite::Result<i64> {
+    db.execute(
+        "INSERT INTO reading_sessions (book_id, started_at, ended_at) VALUES (?1, ?2, ?2)",
+        params![book_id, now],
+    )?;
+    Ok(db.last_insert_rowid())
+}
+
+/// Extend a session to `now`, or close it when `close` is set. Returns the
+/// session that is current afterwards, or `None` for an unknown ID.
+fn beat(db: &Connection, id: i64, now: i64, close: bool) -> rusqlite::Result<Option<i64>> {
+    let session = db
+        .query_row(
+            "SELECT book_id, ended_at, closed FROM reading_sessions WHERE id = ?1",
+            [id],
+            |row| Ok((row.get::<_, String>(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .optional()?;
+    let Some((book_id, ended_at, closed)) = session else {
+        return Ok(None);
+    };
+    let ended_at: i64 = ended_at;
+    if closed || now - ended_at > MAX_HEARTBEAT_GAP {
+        // The time since the last heartbeat was not spent reading.
+        db.execute("UPDATE reading_sessions SET closed = 1 WHERE id = ?1", [id])?;
+        return if close {
+            Ok(Some(id))
+        } else {
+            start(db, &book_id, now).map(Some)
+        };
+    }
+    db.execute(
+        "UPDATE reading_sessions SET ended_at = MAX(ended_at, ?2), closed = ?3 WHERE id = ?1",
+        params![id, now, close],
+    )?;
+    Ok(Some(id))
+}
+
+fn stats(db: &Connection, from: i64, to: i64, offset: i64) -> rusqlite::Result<ReadingStats> {
+    let mut statement = db.prepare_cached(
+        "SELECT book_id, started_at, ended_at FROM reading_sessions
+             WHERE started_at < ?1 AND ended_at > started_at
+             ORDER BY started_at",
+    )?;
+    let sessions = statement
+        .query_map([to], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, i64>(1)?,
+                row.get::<_, i64>(2)?.min(to),
+            ))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    // Streaks look at the whole history, the rest only at the range.
+    let history = merge(
+        sessions
+            .iter()
+            .map(|(_, start, end)| (*start, *end))
+            .collect(),
+    );
+    let read_days: BTreeSet<i64> = day_totals(&history, offset)
+        .into_iter()
+        .filter(|&(_, seconds)| seconds >= STREAK_MIN_SECONDS)
+        .map(|(day, _)| day)
+        .collect();
+    let (current_streak, longest_streak) =
+        streaks(&read_days, (to - 1 + offset).div_euclid(DAY_SECONDS));
+
+    let mut by_book: HashMap<&str, Vec<(i64, i64)>> = HashMap::new();
+    let mut in_range = Vec::new();
+    for (book_id, start, end) in &sessions {
+        let (start, end) = ((*start).max(from), *end);
+        if start >= end {
+            continue;
+        }
+        in_range.push((start, end));
+        by_book.entry(book_id).or_default().push((start, end));
+    }
+    let in_range = merge(in_range);
+    let mut books: Vec<BookReadingTime> = by_book
+        .into_iter()
+        .map(|(book_id, intervals)| BookReadingTime {
+            book_id: book_id.to_string(),
+            sessions: intervals.len() as u32,
+            last_read_at: intervals.iter().map(|&(_, end)| end).max().unwrap_or(0),
+            seconds: seconds(&merge(intervals)),
+        })
+        .collect();
+    books.sort_by(|a, b| b.seconds.cmp(&a.seconds).then(a.book_id.cmp(&b.book_id)));
+
+    Ok(ReadingStats {
+        total_seconds: seconds(&in_range),
+        days: day_totals(&in_range, offset)
+            .into_iter()
+            .map(|(day, seconds)| ReadingDay {
+                date: date(day),
+                seconds,
+            })
+            .collect(),
+        books,
+        current_streak,
+        longest_streak,
+    })
+}
+
+/// Sort intervals and join the ones that overlap or touch.
+fn merge(mut intervals: Vec<(i64, i64)>) -> Vec<(i64, i64)> {
+    intervals.sort_unstable();
+    let mut merged: Vec<(i64, i64)> = Vec::with_capacity(intervals.len());
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+fn seconds(intervals: &[(i64, i64)]) -> u64 {
+    intervals
+        .iter()
+        .map(|(start, end)| (end - start) as u64)
+        .sum()
+}
+
+/// Seconds of each local day, by days since the epoch, splitting intervals
+/// at midnight.
+fn day_totals(intervals: &[(i64, i64)], offset: i64) -> BTreeMap<i64, u64> {
+    let mut days = BTreeMap::new();
+    for &(start, end) in intervals {
+        let (mut start, end) = (start + offset, end + offset);
+        while start < end {
+            let day = start.div_euclid(DAY_SECONDS);
+            let next = ((day + 1) * DAY_SECONDS).min(end);
+            *days.entry(day).or_insert(0) += (next - start) as u64;
+            start = next;
+        }
+    }
+    days
+}
+
+/// The current and longest streaks of `days`, up to `last_day`.
+fn streaks(days: &BTreeSet<i64>, last_day: i64) -> (u32, u32) {
+    let mut longest = 0;
+    let mut run = 0;
+    let mut previous = None;
+    for &day in days.range(..=last_day) {
+        run = if previous == Some(day - 1) {
+            run + 1
+        } else {
+            1
+        };
+        longest = longest.max(run);
+        previous = Some(day);
+    }
+    let current = match previous {
+        Some(day) if day >= last_day - 1 => run,
+        _ => 0,
+    };
+    (current, longest)
+}
+
+/// Format days since the epoch as `YYYY-MM-DD`, after Howard Hinnant's
+/// civil-from-days algorithm.
+fn date(days: i64) -> String {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let m = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * m + 2) / 5 + 1;
+    let month = if m < 10 { m + 3 } else { m - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2026-10-14 00:00 in UTC+8.
+    const DAY: i64 = 1_791_907_200;
+    const OFFSET: i64 = 8 * 3600;
+
+    fn memory_db() -> Connection {
+        let mut db = Connection::open_in_memory().unwrap();
+        db::setup(&mut db).unwrap();
+        db
+    }
+
+    #[test]
+    fn test_heartbeats_and_gaps() {
+        let db = memory_db();
+        let id = start(&db, "a", DAY).unwrap();
+        assert_eq!(beat(&db, id, DAY + 60, false).unwrap(), Some(id));
+        assert_eq!(beat(&db, id, DAY + 120, false).unwrap(), Some(id));
+        // After sleeping for an hour the reader is in a new session.
+        let next = beat(&db, id, DAY + 3720, false).unwrap().unwrap();
+        assert_ne!(next, id);
+        assert_eq!(beat(&db, next, DAY + 3780, true).unwrap(), Some(next));
+        // A closed session is not extended.
+        let after = beat(&db, next, DAY + 3800, false).unwrap().unwrap();
+        assert_ne!(after, next);
+        assert_eq!(beat(&db, 999, DAY, false).unwrap(), None);
+
+        let totals = stats(&db, DAY, DAY + DAY_SECONDS, OFFSET).unwrap();
+        assert_eq!(totals.total_seconds, 180);
+        assert_eq!(totals.books[0].sessions, 2);
+    }
+
+    #[test]
+    fn test_stats_merge_overlaps_and_split_days() {
+        let db = memory_db();
+        let session = |book: &str, start: i64, end: i64| {
+            db.execute(
+                "INSERT INTO reading_sessions (book_id, started_at, ended_at) VALUES (?1, ?2, ?3)",
+                params![book, start, end],
+            )
+            .unwrap();
+        };
+        session("a", DAY - 600, DAY + 600);
+        // Overlaps the first one, from another device.
+        session("a", DAY + 300, DAY + 900);
+        session("b", DAY + 800, DAY + 1000);
+        session("b", DAY + 2 * DAY_SECONDS, DAY + 2 * DAY_SECONDS + 120);
+
+        let totals = stats(&db, DAY - DAY_SECONDS, DAY + DAY_SECONDS, OFFSET).unwrap();
+        assert_eq!(totals.total_seconds, 1600);
+        assert_eq!(
+            totals.days,
+            [
+                ReadingDay {
+                    date: "2026-10-13".to_string(),
+                    seconds: 600
+                },
+                ReadingDay {
+                    date: "2026-10-14".to_string(),
+                    seconds: 1000
+                },
+            ]
+        );
+        assert_eq!(totals.books[0].book_id, "a");
+        assert_eq!(
+            (totals.books[0].seconds, totals.books[0].sessions),
+            (1500, 2)
+        );
+        assert_eq!(
+            (totals.books[1].seconds, totals.books[1].last_read_at),
+            (200, DAY + 1000)
+        );
+        assert_eq!((totals.current_streak, totals.longest_streak), (2, 2));
+
+        // The 15th is not read, so the streak ends with the 16th's reading.
+        let later = stats(&db, DAY, DAY + 3 * DAY_SECONDS, OFFSET).unwrap();
+        assert_eq!((later.current_streak, later.longest_streak), (1, 2));
+        let gone = stats(&db, DAY, DAY + 5 * DAY_SECONDS, OFFSET).unwrap();
+        assert_eq!(gone.current_streak, 0);
+    }
+
+    #[test]
+    fn test_dates() {
+        assert_eq!(date(0), "1970-01-01");
+        assert_eq!(date((DAY + OFFSET) / DAY_SECONDS), "2026-10-14");
+        assert_eq!(date(-1), "1969-12-31");
+        assert_eq!(date(11_016), "2000-02-29");
+    }
+}
@@ -0,0 +1,317 @@
+//! Finding a book on other sources when its current source fails.
+//!
+//! Every enabled source is searched for the book's title in parallel, and
+//! its results are matched on a normalized title and author. To replace a
+//! chapter, each matching book's table of contents is fetched (also in
+//! parallel) and the chapter is matched by title: chapter numbers are
+//! compared as numbers, so 第一百章 matches 第100章 but never 第101章, and the
+//! rest of the title by character-bigram similarity. Content is then
+//! fetched from the best match, falling back to the next on failure.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use anyhow::{anyhow, Result};
+use regex::Regex;
+
+use crate::api::book_source::{
+    fetch_source_book_info, fetch_source_content, fetch_source_toc, search_book_source, BookSource,
+    SourceBook, SourceChapter,
+};
+use crate::api::chapter_diff::similarity;
+use crate::api::chapter_sort::chinese_number;
+use crate::api::error::ApiError;
+
+/// Search results scoring lower than this are a different book.
+const MIN_BOOK_SCORE: f32 = 0.8;
+/// Chapters scoring lower than this are a different chapter.
+const MIN_CHAPTER_SCORE: f32 = 0.6;
+/// Sources queried at the same time.
+const MAX_PARALLEL: usize = 8;
+
+#[derive(Debug, Clone)]
+pub struct AlternativeSource {
+    pub source: BookSource,
+    /// The book as found on `source`.
+    pub book: SourceBook,
+    /// How well the book matches the title and author, from 0 to 1.
+    pub score: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct AlternativeChapter {
+    pub source: BookSource,
+    pub book: SourceBook,
+    pub chapter: SourceChapter,
+    /// Plain text, as from `fetch_source_content`.
+    pub content: String,
+    /// How well the chapter title matches, from 0 to 1.
+    pub score: f32,
+}
+
+/// Search `sources` for a book, best match first. Sources that fail or
+/// have no match are left out.
+///
+/// # Arguments
+/// * `sources` - Sources to try; leave out the one that failed
+/// * `title` - Book title
+/// * `author` - Book author, if known; a different author rules a result out
+#[flutter_rust_bridge::frb]
+pub fn find_alternative_sources(
+    sources: Vec<BookSource>,
+    title: String,
+    author: Option<String>,
+) -> Vec<AlternativeSource> {
+    let sources: Vec<BookSource> = sources.into_iter().filter(|s| s.enabled).collect();
+    let mut found: Vec<AlternativeSource> = parallel(&sources, |source| {
+        let results = search_book_source(source.clone(), title.clone(), 1).ok()?;
+        let (book, score) = best_book(results, &title, author.as_deref())?;
+        Some(AlternativeSource {
+            source: source.clone(),
+            book,
+            score,
+        })
+    });
+    found.sort_by(|a, b| b.score.total_cmp(&a.score));
+    found
+}
+
+/// Fetch a chapter from the best source that has it.
+///
+/// # Arguments
+/// * `sources` - Sources to try; leave out the one that failed
+/// * `title` - Book title
+/// * `author` - Book author, if known
+/// * `chapter_title` - Title of the missing chapter
+/// * `chapter_index` - Position of the chapter in the current table of
+///   contents, used to choose between equally good matches
+#[flutter_rust_bridge::frb]
+pub fn fetch_chapter_from_alternatives(
+    sources: Vec<BookSource>,
+    title: String,
+    author: Option<String>,
+    chapter_title: String,
+    chapter_index: Option<u32>,
+) -> Result<AlternativeChapter, ApiError> {
+    let books = find_alternative_sources(sources, title.clone(), author);
+    let mut matches = parallel(&books, |found| {
+        let book = fetch_source_book_info(found.source.clone(), found.book.book_url.clone())
+            .unwrap_or_else(|_| found.book.clone());
+        let toc = fetch_source_toc(found.source.clone(), book.toc_url.clone()).ok()?;
+        let (index, score) = best_chapter(&toc, &chapter_title, chapter_index)?;
+        Some((found.clone(), book, toc, index, score))
+    });
+    matches.sort_by(|a, b| {
+        (b.4, b.0.score)
+            .partial_cmp(&(a.4, a.0.score))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let mut last_error = None;
+    for (found, book, toc, index, score) in matches {
+        let next_url = toc[index + 1..]
+            .iter()
+            .find(|chapter| !chapter.is_volume)
+            .map(|chapter| chapter.url.clone());
+        match fetch_source_content(found.source.clone(), toc[index].url.clone(), next_url) {
+            Ok(content) if !content.trim().is_empty() => {
+                return Ok(AlternativeChapter {
+                    source: found.source,
+                    book,
+                    chapter: toc[index].clone(),
+                    content,
+                    score,
+                })
+            }
+            Ok(_) => last_error = Some(anyhow!("{} returned an empty chapter", found.source.name)),
+            Err(e) => last_error = Some(e.into()),
+        }
+    }
+    Err(last_error
+        .unwrap_or_else(|| anyhow!("No other source has chapter {chapter_title} of {title}"))
+        .into())
+}
+
+/// Run `task` on every item on up to `MAX_PARALLEL` threads and collect
+/// the results it returns.
+fn parallel<T: Sync, R: Send>(items: &[T], task: impl Fn(&T) -> Option<R> + Sync) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::new());
+    thread::scope(|scope| {
+        for _ in 0..items.len().min(MAX_PARALLEL) {
+            scope.spawn(|| {
+                while let Some(item) = items.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    if let Some(result) = task(item) {
+                        results
+                            .lock()
+                            .unwrap_or_else(|e| e.into_inner())
+                            .push(result);
+                    }
+                }
+            });
+        }
+    });
+    results.into_inner().unwrap_or_else(|e| e.into_inner())
+}
+
+/// The search result that is the wanted book, with its score.
+fn best_book(
+    results: Vec<SourceBook>,
+    title: &str,
+    author: Option<&str>,
+) -> Option<(SourceBook, f32)> {
+    let title = normalize(title);
+    let author = author.map(normalize).filter(|a| !a.is_empty());
+    results
+        .into_iter()
+        .filter_map(|book| {
+            let name = normalize(&book.name);
+            let mut score = if name == title {
+                1.0
+            } else {
+                similarity(&name, &title)
+            };
+            let found_author = book.author.as_deref().map(normalize).unwrap_or_default();
+            if let Some(author) = &author {
+                if !found_author.is_empty()
+                    && !found_author.contains(author.as_str())
+                    && !author.contains(found_author.as_str())
+                {
+                    return None;
+                }
+                if found_author.is_empty() {
+                    score *= 0.9;
+                }
+            }
+            Some((book, score)).filter(|(_, score)| *score >= MIN_BOOK_SCORE)
+        })
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+/// The chapter of `toc` matching `title`, preferring the one nearest
+/// `index`, or else the first, among equal scores.
+fn best_chapter(toc: &[SourceChapter], title: &str, index: Option<u32>) -> Option<(usize, f32)> {
+    let wanted = chapter_key(title);
+    let index = index.unwrap_or(0) as i64;
+    let distance = |i: usize| (i as i64 - index).unsigned_abs();
+    toc.iter()
+        .enumerate()
+        .filter(|(_, chapter)| !chapter.is_volume)
+        .map(|(i, chapter)| (i, chapter_score(&wanted, &chapter_key(&chapter.title))))
+        .filter(|(_, score)| *score >= MIN_CHAPTER_SCORE)
+        .max_by(|a, b| {
+            a.1.total_cmp(&b.1)
+                .then_with(|| distance(b.0).cmp(&distance(a.0)))
+        })
+}
+
+fn chapter_score(wanted: &str, found: &str) -> f32 {
+    if wanted == found {
+        return 1.0;
+    }
+    let number = Regex::new(r"#(\d+)").unwrap();
+    let first = |key: &str| number.captures(key).map(|c| c[1].to_string());
+    match (first(wanted), first(found)) {
+        (Some(a), Some(b)) if a != b => 0.0,
+        _ => similarity(wanted, found),
+    }
+}
+
+/// Lowercase text without whitespace or punctuation.
+fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// A chapter title with its number written `#123`, whether it was 第123章,
+/// 第一百二十三章 or `123.`, and without punctuation.
+fn chapter_key(title: &str) -> String {
+    let numerals = Regex::new("[零〇一二两三四五六七八九十百千万]+").unwrap();
+    let title = numerals.replace_all(title, |c: &regex::Captures| {
+        chinese_number(&c[0]).map_or_else(|| c[0].to_string(), |n| n.to_string())
+    });
+    let numbered = Regex::new(r"第?\s*0*(\d+)\s*[章节回话集]?").unwrap();
+    let title = numbered.replace(&title, "#${1} ");
+    title
+        .chars()
+        .filter(|c| c.is_alphanumeric() || *c == '#')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chapter(title: &str) -> SourceChapter {
+        SourceChapter {
+            title: title.to_string(),
+            url: String::new(),
+            is_volume: false,
+        }
+    }
+
+    fn book(name: &str, author: Option<&str>) -> SourceBook {
+        SourceBook {
+            name: name.to_string(),
+            author: author.map(str::to_string),
+            intro: None,
+            kind: None,
+            last_chapter: None,
+            cover_url: None,
+            word_count: None,
+            book_url: name.to_string(),
+            toc_url: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_chapter_keys() {
+        assert_eq!(chinese_number("一百零五"), Some(105));
+        assert_eq!(chinese_number("十二"), Some(12));
+        assert_eq!(chinese_number("两万三千"), Some(23_000));
+        assert_eq!(chinese_number("二〇二四"), Some(2024));
+        assert_eq!(chapter_key("第一百章 大结局！"), "#100大结局");
+        assert_eq!(chapter_key("第100章：大结局"), "#100大结局");
+        assert_eq!(chapter_key("100. 大结局"), "#100大结局");
+        assert_eq!(chapter_score("#100大结局", "#101大结局"), 0.0);
+    }
+
+    #[test]
+    fn test_best_chapter() {
+        let toc = [
+            chapter("第九十九章 前夜"),
+            chapter("第100章 大结局（上）"),
+            chapter("第101章 大结局"),
+            chapter("第一百章 大结局上"),
+        ];
+        assert_eq!(
+            best_chapter(&toc, "第一百章 大结局(上)", None).unwrap().0,
+            1
+        );
+        // Two equal matches: the one near the current position wins.
+        assert_eq!(
+            best_chapter(&toc, "第100章 大结局上", Some(3)).unwrap().0,
+            3
+        );
+        assert!(best_chapter(&toc, "第二百章", None).is_none());
+    }
+
+    #[test]
+    fn test_best_book() {
+        let results = vec![
+            book("三体前传", Some("刘慈欣")),
+            book("三体", Some("某某")),
+            book("《三体》", Some("[刘慈欣]")),
+        ];
+        let (found, score) = best_book(results.clone(), "三体", Some("刘慈欣")).unwrap();
+        assert_eq!(found.name, "《三体》");
+        assert_eq!(score, 1.0);
+        assert!(best_book(results, "球状闪电", None).is_none());
+        let (found, _) = best_book(vec![book("三体", None)], "三体", Some("刘慈欣")).unwrap();
+        assert_eq!(found.author, None);
+    }
+}
@@ -0,0 +1,180 @@
+//! Reading statistics that count what a reader sees.
+//!
+//! Chinese, Japanese and Korean characters count one each and Latin text
+//! counts whole words, where UTF-16 lengths would count each letter and
+//! count rare Han characters twice. Reading time treats a Latin word like one CJK character, which
+//! roughly matches typical reading speeds in each script.
+
+use anyhow::{anyhow, Result};
+
+use crate::api::error::ApiError;
+
+#[derive(Debug, Clone)]
+pub struct TextStats {
+    /// Han characters, kana and hangul.
+    pub cjk_chars: u32,
+    /// Runs of letters and digits outside CJK scripts.
+    pub latin_words: u32,
+    pub punctuation: u32,
+    pub reading_minutes: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct BookStats {
+    pub total: TextStats,
+    /// Per-chapter statistics, in input order.
+    pub chapters: Vec<TextStats>,
+}
+
+/// Count the characters and words of `text`.
+///
+/// # Arguments
+/// * `text` - Chapter text
+/// * `chars_per_minute` - Reading speed in CJK characters per minute
+#[flutter_rust_bridge::frb]
+pub fn count_text(text: String, chars_per_minute: u32) -> Result<TextStats, ApiError> {
+    check_speed(chars_per_minute)?;
+    Ok(count(&text, chars_per_minute))
+}
+
+/// Count every chapter of a book, plus the book's total.
+///
+/// # Arguments
+/// * `chapters` - Chapter texts
+/// * `chars_per_minute` - Reading speed in CJK characters per minute
+#[flutter_rust_bridge::frb]
+pub fn count_book(chapters: Vec<String>, chars_per_minute: u32) -> Result<BookStats, ApiError> {
+    check_speed(chars_per_minute)?;
+    let chapters: Vec<TextStats> = chapters
+        .iter()
+        .map(|text| count(text, chars_per_minute))
+        .collect();
+
+    Ok(BookStats {
+        total: sum(&chapters, chars_per_minute),
+        chapters,
+    })
+}
+
+/// Add up the statistics of several chapters.
+pub(crate) fn sum(chapters: &[TextStats], chars_per_minute: u32) -> TextStats {
+    let mut total = TextStats {
+        cjk_chars: 0,
+        latin_words: 0,
+        punctuation: 0,
+        reading_minutes: 0.0,
+    };
+    for chapter in chapters {
+        total.cjk_chars += chapter.cjk_chars;
+        total.latin_words += chapter.latin_words;
+        total.punctuation += chapter.punctuation;
+    }
+    total.reading_minutes = reading_minutes(&total, chars_per_minute);
+    total
+}
+
+pub(crate) fn check_speed(chars_per_minute: u32) -> Result<()> {
+    if chars_per_minute == 0 {
+        return Err(anyhow!("Reading speed must be positive"));
+    }
+    Ok(())
+}
+
+pub(crate) fn count(text: &str, chars_per_minute: u32) -> TextStats {
+    let mut stats = TextStats {
+        cjk_chars: 0,
+        latin_words: 0,
+        punctuation: 0,
+        reading_minutes: 0.0,
+    };
+    let word_char = |c: char| c.is_alphanumeric() && !is_cjk(c);
+    let chars: Vec<char> = text.chars().collect();
+    let mut in_word = false;
+    for (i, &c) in chars.iter().enumerate() {
+        if word_char(c) {
+            if !in_word {
+                stats.latin_words += 1;
+            }
+            in_word = true;
+            continue;
+        }
+        // An apostrophe or hyphen between letters continues the word.
+        let joins =
+            matches!(c, '\'' | '’' | '-') && chars.get(i + 1).is_some_and(|&n| word_char(n));
+        if in_word && joins {
+            continue;
+        }
+        in_word = false;
+        if is_cjk(c) {
+            stats.cjk_chars += 1;
+        } else if is_punctuation(c) {
+            stats.punctuation += 1;
+        }
+    }
+    stats.reading_minutes = reading_minutes(&stats, chars_per_minute);
+    stats
+}
+
+fn reading_minutes(stats: &TextStats, chars_per_minute: u32) -> f32 {
+    (stats.cjk_chars + stats.latin_words) as f32 / chars_per_minute as f32
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{30FF}' // kana
+        | '\u{3400}'..='\u{4DBF}'
+        | '\u{4E00}'..='\u{9FFF}'
+        | '\u{F900}'..='\u{FAFF}'
+        | '\u{AC00}'..='\u{D7AF}' // hangul
+        | '\u{20000}'..='\u{2FA1F}')
+        && c != '\u{30FB}' // katakana middle dot
+}
+
+fn is_punctuation(c: char) -> bool {
+    c.is_ascii_punctuation()
+        || matches!(c,
+            '\u{2010}'..='\u{2027}' // dashes, quotes, ellipsis
+            | '\u{3001}'..='\u{3003}'
+            | '\u{3008}'..='\u{3011}'
+            | '\u{3014}'..='\u{301F}'
+            | '\u{30FB}'
+            | '\u{FF01}'..='\u{FF0F}'
+            | '\u{FF1A}'..='\u{FF20}'
+            | '\u{FF3B}'..='\u{FF40}'
+            | '\u{FF5B}'..='\u{FF65}'
+            | '\u{00A1}' | '\u{00AB}' | '\u{00B7}' | '\u{00BB}' | '\u{00BF}')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(stats: &TextStats) -> (u32, u32, u32) {
+        (stats.cjk_chars, stats.latin_words, stats.punctuation)
+    }
+
+    #[test]
+    fn test_counts_scripts_separately() {
+        let stats = count_text(
+            "　　他说：“Don't panic，这是 well-known 的事。”😀".to_string(),
+            300,
+        )
+        .unwrap();
+        assert_eq!(summary(&stats), (6, 3, 5));
+
+        let stats = count_text("𠀀字 2024年 ひらがな 한국어".to_string(), 10).unwrap();
+        assert_eq!(summary(&stats), (10, 1, 0));
+        assert_eq!(stats.reading_minutes, 1.1);
+        assert!(count_text(String::new(), 0).is_err());
+    }
+
+    #[test]
+    fn test_book_totals() {
+        let book =
+            count_book(vec!["第一章。".to_string(), "Hello world!".to_string()], 60).unwrap();
+        assert_eq!(summary(&book.chapters[0]), (3, 0, 1));
+        assert_eq!(summary(&book.chapters[1]), (0, 2, 1));
+        assert_eq!(summary(&book.total), (3, 2, 2));
+        assert_eq!(book.total.reading_minutes, 5.0 / 60.0);
+    }
+}
@@ -0,0 +1,209 @@
+//! Extractive one-line chapter summaries for the chapter list.
+//!
+//! A summary is one or two sentences taken from the chapter as written.
+//! Sentences are scored by the words they share with the rest of the
+//! chapter, words being jieba segments so Chinese text needs no spaces,
+//! with a word counting by the log of how often it occurs: a name or
+//! object the chapter keeps returning to makes a sentence more central,
+//! while words used once add nothing. Early sentences get a boost, as
+//! chapters tend to open on what they are about. Headings and separator
+//! lines are never picked. The chosen sentences keep their order in the
+//! chapter.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, MutexGuard};
+
+use anyhow::{anyhow, Result};
+
+use crate::api::error::ApiError;
+use crate::api::{segment, tts_prep};
+
+/// Sentences with fewer letters than this are not picked.
+const MIN_SENTENCE_LETTERS: usize = 6;
+
+/// Lines this short without terminal punctuation are taken as headings.
+const MAX_HEADING_CHARS: usize = 30;
+
+/// Extra weight of the first sentence; the n-th gets `1 / n` of it.
+const POSITION_BOOST: f32 = 1.0;
+
+static SUMMARY_OPTIONS: Mutex<Option<SummaryOptions>> = Mutex::new(None);
+
+#[derive(Debug, Clone)]
+pub struct SummaryOptions {
+    /// Longest summary, in characters; a longer single sentence is cut
+    /// and ends in `…`.
+    pub max_chars: u32,
+    pub max_sentences: u32,
+}
+
+/// Two sentences of at most 80 characters.
+#[flutter_rust_bridge::frb]
+pub fn default_summary_options() -> SummaryOptions {
+    SummaryOptions {
+        max_chars: 80,
+        max_sentences: 2,
+    }
+}
+
+/// Set the options used for summaries written to the chapter cache.
+/// Chapters cached earlier keep their summaries.
+#[flutter_rust_bridge::frb]
+pub fn set_summary_options(options: SummaryOptions) -> Result<(), ApiError> {
+    if options.max_chars == 0 || options.max_sentences == 0 {
+        return Err(anyhow!("Summary length must be at least one character and sentence").into());
+    }
+    *lock() = Some(options);
+    Ok(())
+}
+
+/// Summarize a chapter in a sentence or two taken from its text. Returns
+/// an empty string if the chapter has no sentence to take.
+///
+/// # Arguments
+/// * `text` - Plain chapter text
+/// * `options` - Length limits
+#[flutter_rust_bridge::frb]
+pub fn summarize_chapter(text: String, options: SummaryOptions) -> String {
+    summarize(&text, &options)
+}
+
+fn lock() -> MutexGuard<'static, Option<SummaryOptions>> {
+    SUMMARY_OPTIONS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// The options set by `set_summary_options`, or the defaults.
+pub(crate) fn options() -> SummaryOptions {
+    lock().clone().unwrap_or_else(default_summary_options)
+}
+
+pub(crate) fn summarize(text: &str, options: &SummaryOptions) -> String {
+    let sentences: Vec<&str> = tts_prep::sentences(text)
+        .into_iter()
+        .map(|(start, end)| &text[start..end])
+        .filter(|sentence| is_candidate(sentence))
+        .collect();
+    let words: Vec<Vec<String>> = sentences.iter().map(|sentence| words(sentence)).collect();
+    let mut frequency: HashMap<&str, u32> = HashMap::new();
+    for word in words.iter().flatten() {
+        *frequency.entry(word).or_default() += 1;
+    }
+
+    let mut ranked: Vec<(usize, f32)> = words
+        .iter()
+        .enumerate()
+        .map(|(i, words)| {
+            let unique: HashSet<&str> = words.iter().map(String::as_str).collect();
+            let weight: f32 = unique.iter().map(|w| (frequency[w] as f32).ln()).sum();
+            let position = 1.0 + POSITION_BOOST / (i + 1) as f32;
+            (i, weight / (words.len().max(1) as f32).sqrt() * position)
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    let max_chars = options.max_chars as usize;
+    let mut chosen = Vec::new();
+    let mut length = 0;
+    for &(i, _) in &ranked {
+        if chosen.len() >= options.max_sentences as usize {
+            break;
+        }
+        let chars = sentences[i].chars().count();
+        if length + chars > max_chars {
+            break;
+        }
+        chosen.push(i);
+        length += chars;
+    }
+    let Some(&(best, _)) = ranked.first() else {
+        return String::new();
+    };
+    if chosen.is_empty() {
+        let cut: String = sentences[best]
+            .chars()
+            .take(max_chars.saturating_sub(1))
+            .collect();
+        return format!("{}…", cut.trim_end());
+    }
+
+    chosen.sort_unstable();
+    let mut summary = String::new();
+    for i in chosen {
+        if summary.ends_with(|c: char| c.is_ascii_punctuation()) {
+            summary.push(' ');
+        }
+        summary.push_str(sentences[i]);
+    }
+    summary
+}
+
+/// Whether a sentence can be part of a summary: long enough, and not a
+/// heading or separator line.
+fn is_candidate(sentence: &str) -> bool {
+    let letters = sentence.chars().filter(|c| c.is_alphanumeric()).count();
+    let ended = sentence.ends_with(tts_prep::TERMINATORS)
+        || sentence.ends_with(tts_prep::CLOSERS)
+        || sentence.ends_with('.');
+    letters >= MIN_SENTENCE_LETTERS && (ended || sentence.chars().count() > MAX_HEADING_CHARS)
+}
+
+/// The words of a sentence that count towards scores, lowercased.
+fn words(sentence: &str) -> Vec<String> {
+    segment::jieba()
+        .cut(sentence, true)
+        .into_iter()
+        .map(|token| token.word)
+        .filter(|word| word.chars().count() >= 2 && word.chars().all(char::is_alphanumeric))
+        .map(str::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHAPTER: &str = "第三章 夜雨\n\
+        那天夜里下着大雨，林远独自守在城门口。\n\
+        街上没有行人。\n\
+        远处传来马蹄声，林远握紧了手中的长剑。\n\
+        ——\n\
+        他想起师父临别时说的话，心里一阵发紧。\n\
+        马蹄声越来越近，林远终于看清了来人。";
+
+    #[test]
+    fn test_picks_central_sentences_in_order() {
+        let summary = summarize(CHAPTER, &default_summary_options());
+        assert_eq!(
+            summary,
+            "远处传来马蹄声，林远握紧了手中的长剑。马蹄声越来越近，林远终于看清了来人。"
+        );
+        let one = SummaryOptions {
+            max_chars: 80,
+            max_sentences: 1,
+        };
+        assert_eq!(
+            summarize(CHAPTER, &one),
+            "马蹄声越来越近，林远终于看清了来人。"
+        );
+    }
+
+    #[test]
+    fn test_limits_and_empty_chapters() {
+        let short = SummaryOptions {
+            max_chars: 10,
+            max_sentences: 2,
+        };
+        assert_eq!(summarize(CHAPTER, &short), "马蹄声越来越近，林…");
+        assert_eq!(summarize("第一章\n——\n", &short), "");
+        let english = "Chapter 1\nThe rain fell all night. Lin waited at the gate in the rain.";
+        assert_eq!(
+            summarize(english, &default_summary_options()),
+            "The rain fell all night. Lin waited at the gate in the rain."
+        );
+        assert!(set_summary_options(SummaryOptions {
+            max_chars: 0,
+            max_sentences: 1
+        })
+        .is_err());
+    }
+}
@@ -0,0 +1,1087 @@
+//! End-to-end encrypted sync of reading progress, bookmarks and highlights.
+//!
+//! The server is a dumb blob store, a WebDAV directory or the app's own
+//! HTTP API, and only ever holds ciphertext. Everything is sealed with
+//! XChaCha20-Poly1305 under a key derived from the user's passphrase with
+//! PBKDF2, so the server learns neither the passphrase nor what is read.
+//!
+//! The remote directory holds a `manifest.json`, in the clear, with the
+//! key derivation salt, a sealed check value that tells a wrong passphrase
+//! apart from corrupt data, the current version and the blobs making it
+//! up. A sync uploads only the records that changed since the device's
+//! last sync, as a delta blob for the next version, and once enough deltas
+//! pile up the device that syncs next folds them into a snapshot blob.
+//! Blob names carry a random part, so an upload abandoned halfway never
+//! blocks the next, and blobs are sealed with their name and the remote ID
+//! so the server cannot swap or replay them. The manifest is replaced with
+//! `If-Match`, and a sync that loses the race with another device starts
+//! over, merging first.
+//!
+//! What is synced is a state-based CRDT, a `Replica`. Per book, progress
+//! is a last-writer-wins register ordered by update time, ties broken by
+//! content hash so every device picks the same winner. Bookmarks and
+//! highlights are grow-only sets keyed by their content, with tombstones:
+//! deleting one records its key, and a buried key never comes back, so
+//! editing one is burying the old and adding the new. Merging replicas is
+//! commutative, associative and idempotent, so devices converge whatever
+//! order they sync in, and a highlight made on one device is only ever
+//! dropped because it was deleted on another.
+
+use std::collections::{HashMap, HashSet};
+
+use aes_gcm::aead::Generate;
+use anyhow::{anyhow, Result};
+use base64::Engine;
+use serde_json::{json, Value};
+use zeroize::Zeroizing;
+
+use crate::api::backup;
+use crate::api::db::{
+    self, Bookmark, Highlight, LibraryDump, MergeCounts, ReadingProgress, Tombstone,
+};
+use crate::api::error::ApiError;
+use crate::api::task::{self, CancelToken};
+use crate::api::webdav::WebDavConfig;
+use crate::crypto::{self, KEY_LEN, NONCE_LEN};
+use crate::remote::{Condition, HttpStore, RemoteStore, WebDavStore};
+
+const FORMAT: &str = "novella-sync";
+/// Remotes with a newer format version are refused.
+const FORMAT_VERSION: u32 = 1;
+
+const MANIFEST: &str = "manifest.json";
+
+/// PBKDF2 iterations for new remotes; existing ones keep theirs.
+const ITERATIONS: u32 = 600_000;
+
+const SALT_LEN: usize = 16;
+
+/// Sealed under the key in the manifest to check passphrases.
+const CHECK: &[u8] = b"novella-sync-check";
+
+const SNAPSHOT: &str = "snapshot";
+const DELTA: &str = "delta";
+
+/// Deltas after the snapshot that trigger folding them into a new one.
+const MAX_DELTAS: usize = 20;
+
+/// Syncs started over after losing a race before giving up.
+const ATTEMPTS: u32 = 3;
+
+const MAX_BLOB_BYTES: u64 = 64 * 1024 * 1024;
+
+/// What a device knew after its last sync. Store it and pass it to the
+/// next sync; without it, everything is uploaded again.
+#[derive(Debug, Clone)]
+pub struct SyncState {
+    /// ID of the remote data; it changes when the remote is reset.
+    pub remote_id: String,
+    /// Remote version merged last.
+    pub version: u32,
+    /// Hashes of the records on the remote, to find what changed locally.
+    pub fingerprints: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SyncResult {
+    /// Remote version after the sync.
+    pub version: u32,
+    /// Records uploaded, 0 if nothing changed locally.
+    pub uploaded: u32,
+    /// Records from other devices merged into the library.
+    pub downloaded: u32,
+    pub progress_updated: u32,
+    pub bookmarks_added: u32,
+    pub highlights_added: u32,
+    /// Deleted because they were deleted on another device.
+    pub bookmarks_removed: u32,
+    pub highlights_removed: u32,
+    /// Store and pass to the next sync.
+    pub state: SyncState,
+}
+
+/// Sync the open library's reading data through a WebDAV directory.
+///
+/// # Arguments
+/// * `config` - Server and credentials
+/// * `remote_dir` - Directory for sync data, relative to the base URL;
+///   created if missing
+/// * `passphrase` - Sync passphrase, the same on every device
+/// * `state` - State returned by the last sync, if any
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+#[flutter_rust_bridge::frb]
+pub async fn sync_webdav(
+    config: WebDavConfig,
+    remote_dir: String,
+    passphrase: String,
+    state: Option<SyncState>,
+    cancel_token: Option<u32>,
+) -> Result<SyncResult, ApiError> {
+    let passphrase = Zeroizing::new(passphrase);
+    task::run_blocking_io(task::cancel_token(cancel_token)?, move |token| {
+        let store = WebDavStore::new(&config, &remote_dir)?;
+        sync(&store, &passphrase, state, &token)
+    })
+    .await
+}
+
+/// Sync the open library's reading data through an HTTP blob store that
+/// answers `GET`, `PUT` and `DELETE` on `<base_url>/<name>` and honours
+/// `If-Match` and `If-None-Match` with HTTP 412.
+///
+/// # Arguments
+/// * `base_url` - URL of the user's sync area
+/// * `bearer_token` - Sent as `Authorization: Bearer`, if any
+/// * `passphrase` - Sync passphrase, the same on every device
+/// * `state` - State returned by the last sync, if any
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+#[flutter_rust_bridge::frb]
+pub async fn sync_http(
+    base_url: String,
+    bearer_token: Option<String>,
+    passphrase: String,
+    state: Option<SyncState>,
+    cancel_token: Option<u32>,
+) -> Result<SyncResult, ApiError> {
+    let passphrase = Zeroizing::new(passphrase);
+    task::run_blocking_io(task::cancel_token(cancel_token)?, move |token| {
+        let store = HttpStore::new(&base_url, bearer_token)?;
+        sync(&store, &passphrase, state, &token)
+    })
+    .await
+}
+
+/// Reading data in the open library, with what was deleted from it.
+fn export_replica() -> Result<Replica> {
+    let dump = db::export_library()?;
+    Ok(Replica::new(&dump, &db::export_tombstones()?))
+}
+
+fn apply_replica(changes: &Replica) -> Result<MergeCounts> {
+    db::apply_sync_records(&changes.records(), &changes.tombstone_list())
+}
+
+/// Reading data as a CRDT; see the module docs. Bookmarks and highlights
+/// are keyed by `db::bookmark_key` and `db::highlight_key`, and have no
+/// local IDs.
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug, Clone, Default)]
+struct Replica {
+    progress: HashMap<String, ReadingProgress>,
+    bookmarks: HashMap<String, Bookmark>,
+    highlights: HashMap<String, Highlight>,
+    /// Buried keys, with the earliest time they were deleted.
+    tombstones: HashMap<String, i64>,
+}
+
+impl Replica {
+    fn new(records: &LibraryDump, tombstones: &[Tombstone]) -> Self {
+        let mut replica = Self::default();
+        for tombstone in tombstones {
+            replica.bury(&tombstone.key, tombstone.deleted_at);
+        }
+        for progress in &records.progress {
+            replica.add_progress(progress);
+        }
+        for bookmark in &records.bookmarks {
+            replica.add_bookmark(bookmark);
+        }
+        for highlight in &records.highlights {
+            replica.add_highlight(highlight);
+        }
+        replica
+    }
+
+    /// Keep `progress` if it beats the book's, by update time and then
+    /// fingerprint.
+    fn add_progress(&mut self, progress: &ReadingProgress) {
+        let rank = |p: &ReadingProgress| (p.updated_at, progress_fingerprint(p));
+        if self
+            .progress
+            .get(&progress.book_id)
+            .is_none_or(|stored| rank(progress) > rank(stored))
+        {
+            self.progress
+                .insert(progress.book_id.clone(), progress.clone());
+        }
+    }
+
+    fn add_bookmark(&mut self, bookmark: &Bookmark) {
+        let key = db::bookmark_key(bookmark);
+        if !self.tombstones.contains_key(&key) {
+            self.bookmarks.entry(key).or_insert_with(|| Bookmark {
+                id: None,
+                ..bookmark.clone()
+            });
+        }
+    }
+
+    fn add_highlight(&mut self, highlight: &Highlight) {
+        let key = db::highlight_key(highlight);
+        if !self.tombstones.contains_key(&key) {
+            self.highlights.entry(key).or_insert_with(|| Highlight {
+                id: None,
+                ..highlight.clone()
+            });
+        }
+    }
+
+    fn bury(&mut self, key: &str, deleted_at: i64) {
+        let earliest = self.tombstones.entry(key.to_string()).or_insert(deleted_at);
+        *earliest = (*earliest).min(deleted_at);
+        self.bookmarks.remove(key);
+        self.highlights.remove(key);
+    }
+
+    fn merge(&mut self, other: &Replica) {
+        for (key, &deleted_at) in &other.tombstones {
+            self.bury(key, deleted_at);
+        }
+        for progress in other.progress.values() {
+            self.add_progress(progress);
+        }
+        for bookmark in other.bookmarks.values() {
+            self.add_bookmark(bookmark);
+        }
+        for highlight in other.highlights.values() {
+            self.add_highlight(highlight);
+        }
+    }
+
+    fn len(&self) -> u32 {
+        (self.progress.len() + self.bookmarks.len() + self.highlights.len() + self.tombstones.len())
+            as u32
+    }
+
+    /// Hashes of every element, to find what another replica lacks.
+    fn fingerprints(&self) -> HashSet<String> {
+        let progress = self.progress.values().map(progress_fingerprint);
+        let bookmarks = self.bookmarks.keys().cloned();
+        let highlights = self.highlights.keys().cloned();
+        let tombstones = self.tombstones.keys().map(|key| tombstone_fingerprint(key));
+        progress
+            .chain(bookmarks)
+            .chain(highlights)
+            .chain(tombstones)
+            .collect()
+    }
+
+    /// The elements whose fingerprints are not `known`.
+    fn without(&self, known: &HashSet<String>) -> Replica {
+        Replica {
+            progress: self
+                .progress
+                .iter()
+                .filter(|(_, p)| !known.contains(&progress_fingerprint(p)))
+                .map(|(id, p)| (id.clone(), p.clone()))
+                .collect(),
+            bookmarks: self
+                .bookmarks
+                .iter()
+                .filter(|(key, _)| !known.contains(*key))
+                .map(|(key, b)| (key.clone(), b.clone()))
+                .collect(),
+            highlights: self
+                .highlights
+                .iter()
+                .filter(|(key, _)| !known.contains(*key))
+                .map(|(key, h)| (key.clone(), h.clone()))
+                .collect(),
+            tombstones: self
+                .tombstones
+                .iter()
+                .filter(|(key, _)| !known.contains(&tombstone_fingerprint(key)))
+                .map(|(key, &deleted_at)| (key.clone(), deleted_at))
+                .collect(),
+        }
+    }
+
+    /// The members, by book and creation time.
+    fn records(&self) -> LibraryDump {
+        let mut progress: Vec<ReadingProgress> = self.progress.values().cloned().collect();
+        progress.sort_by(|a, b| a.book_id.cmp(&b.book_id));
+        let mut bookmarks: Vec<Bookmark> = self.bookmarks.values().cloned().collect();
+        bookmarks.sort_by(|a, b| (&a.book_id, a.created_at).cmp(&(&b.book_id, b.created_at)));
+        let mut highlights: Vec<Highlight> = self.highlights.values().cloned().collect();
+        highlights.sort_by(|a, b| (&a.book_id, a.created_at).cmp(&(&b.book_id, b.created_at)));
+        LibraryDump {
+            books: Vec::new(),
+            progress,
+            bookmarks,
+            highlights,
+        }
+    }
+
+    fn tombstone_list(&self) -> Vec<Tombstone> {
+        let mut tombstones: Vec<Tombstone> = self
+            .tombstones
+            .iter()
+            .map(|(key, &deleted_at)| Tombstone {
+                key: key.clone(),
+                deleted_at,
+            })
+            .collect();
+        tombstones.sort_by(|a, b| a.key.cmp(&b.key));
+        tombstones
+    }
+}
+
+/// The parsed manifest and the ETag it was read with.
+struct Manifest {
+    id: String,
+    salt: Vec<u8>,
+    iterations: u32,
+    check: Vec<u8>,
+    version: u32,
+    /// The snapshot, if any, then deltas, oldest first.
+    blobs: Vec<String>,
+    etag: Option<String>,
+}
+
+impl Manifest {
+    fn to_json(&self) -> String {
+        let base64 = base64::engine::general_purpose::STANDARD;
+        json!({
+            "format": FORMAT,
+            "formatVersion": FORMAT_VERSION,
+            "id": self.id,
+            "salt": base64.encode(&self.salt),
+            "iterations": self.iterations,
+            "check": base64.encode(&self.check),
+            "version": self.version,
+            "blobs": self.blobs,
+        })
+        .to_string()
+    }
+
+    fn parse(data: &[u8], etag: Option<String>) -> Result<Self> {
+        let value: Value = serde_json::from_slice(data)
+            .map_err(|e| anyhow!("Failed to parse the sync manifest: {e}"))?;
+        if value["format"] != FORMAT {
+            return Err(anyhow!("Remote directory does not hold Novella sync data"));
+        }
+        let format_version = value["formatVersion"].as_u64().unwrap_or(0);
+        if format_version > FORMAT_VERSION as u64 {
+            return Err(anyhow!(
+                "Sync data has format version {format_version}, newer than this app supports"
+            ));
+        }
+        let base64 = base64::engine::general_purpose::STANDARD;
+        let bytes = |key: &str| {
+            value[key]
+                .as_str()
+                .and_then(|text| base64.decode(text).ok())
+                .ok_or_else(|| anyhow!("Sync manifest is missing {key}"))
+        };
+        let number = |key: &str| {
+            value[key]
+                .as_u64()
+                .and_then(|n| u32::try_from(n).ok())
+                .ok_or_else(|| anyhow!("Sync manifest is missing {key}"))
+        };
+        let manifest = Self {
+            id: value["id"]
+                .as_str()
+                .ok_or_else(|| anyhow!("Sync manifest is missing id"))?
+                .to_string(),
+            salt: bytes("salt")?,
+            iterations: number("iterations")?,
+            check: bytes("check")?,
+            version: number("version")?,
+            blobs: value["blobs"]
+                .as_array()
+                .ok_or_else(|| anyhow!("Sync manifest is missing blobs"))?
+                .iter()
+                .map(|name| name.as_str().map(str::to_string))
+                .collect::<Option<_>>()
+                .ok_or_else(|| anyhow!("Sync manifest is corrupt"))?,
+            etag,
+        };
+        let valid = |name: &String| blob_version(name).is_some_and(|v| v <= manifest.version);
+        if manifest.iterations == 0 || !manifest.blobs.iter().all(valid) {
+            return Err(anyhow!("Sync manifest is corrupt"));
+        }
+        Ok(manifest)
+    }
+
+    /// Names of the blobs holding everything after version `since`,
+    /// oldest first. A snapshot holds every version up to its own.
+    fn blobs_after(&self, since: u32) -> Vec<String> {
+        self.blobs
+            .iter()
+            .filter(|name| blob_version(name).is_some_and(|version| version > since))
+            .cloned()
+            .collect()
+    }
+
+    fn delta_count(&self) -> usize {
+        self.blobs
+            .iter()
+            .filter(|name| name.starts_with(DELTA))
+            .count()
+    }
+}
+
+/// A new blob name for `version`.
+fn blob_name(kind: &str, version: u32) -> String {
+    format!("{kind}-{version}-{}.bin", hex(&<[u8; 4]>::generate()))
+}
+
+fn blob_version(name: &str) -> Option<u32> {
+    name.split('-').nth(1)?.parse().ok()
+}
+
+/// The derived key, with the manifest it belongs to.
+struct Key {
+    id: String,
+    salt: Vec<u8>,
+    key: Zeroizing<[u8; KEY_LEN]>,
+}
+
+impl Key {
+    fn derive(manifest: &Manifest, passphrase: &str) -> Self {
+        let mut key = Zeroizing::new([0; KEY_LEN]);
+        crypto::pbkdf2_sha256(
+            passphrase.as_bytes(),
+            &manifest.salt,
+            manifest.iterations,
+            key.as_mut_slice(),
+        );
+        Self {
+            id: manifest.id.clone(),
+            salt: manifest.salt.clone(),
+            key,
+        }
+    }
+
+    /// Seal as the nonce followed by the ciphertext and tag.
+    fn seal(&self, name: &str, plain: &[u8]) -> Vec<u8> {
+        let nonce = <[u8; NONCE_LEN]>::generate();
+        let aad = format!("{}/{name}", self.id);
+        let mut data = nonce.to_vec();
+        data.extend_from_slice(&crypto::seal(&self.key, &nonce, aad.as_bytes(), plain));
+        data
+    }
+
+    fn open(&self, name: &str, data: &[u8]) -> Result<Vec<u8>> {
+        if data.len() < NONCE_LEN {
+            return Err(anyhow!("Sync blob {name} is truncated"));
+        }
+        let (nonce, sealed) = data.split_at(NONCE_LEN);
+        let aad = format!("{}/{name}", self.id);
+        crypto::open(
+            &self.key,
+            nonce.try_into().expect("nonce length"),
+            aad.as_bytes(),
+            sealed,
+        )
+        .map_err(|_| anyhow!("Sync blob {name} is corrupt"))
+    }
+}
+
+/// Sync the open library through `store`.
+fn sync(
+    store: &dyn RemoteStore,
+    passphrase: &str,
+    state: Option<SyncState>,
+    token: &CancelToken,
+) -> Result<SyncResult> {
+    sync_with(
+        store,
+        &export_replica,
+        &apply_replica,
+        passphrase,
+        state,
+        token,
+        ITERATIONS,
+    )
+}
+
+/// `sync` with the library passed as functions, creating missing remotes
+/// with `iterations` PBKDF2 rounds. `apply` writes what the library lacks.
+fn sync_with(
+    store: &dyn RemoteStore,
+    export: &dyn Fn() -> Result<Replica>,
+    apply: &dyn Fn(&Replica) -> Result<MergeCounts>,
+    passphrase: &str,
+    state: Option<SyncState>,
+    token: &CancelToken,
+    iterations: u32,
+) -> Result<SyncResult> {
+    let mut derived: Option<Key> = None;
+    let mut counts = MergeCounts::default();
+    let mut downloaded = 0;
+    for _ in 0..ATTEMPTS {
+        token.check()?;
+        let manifest = read_manifest(store, iterations, passphrase)?;
+        let key = match &mut derived {
+            Some(key) if key.id == manifest.id && key.salt == manifest.salt => key,
+            slot => slot.insert(Key::derive(&manifest, passphrase)),
+        };
+        key.open(MANIFEST, &manifest.check)
+            .ok()
+            .filter(|check| check == CHECK)
+            .ok_or_else(|| anyhow!("Wrong sync passphrase"))?;
+
+        let known_state = state
+            .as_ref()
+            .filter(|state| state.remote_id == manifest.id && state.version <= manifest.version);
+        let since = known_state.map_or(0, |state| state.version);
+        // Folding deltas into a snapshot needs all of them; merging what
+        // an earlier sync merged changes nothing.
+        let compact = manifest.delta_count() >= MAX_DELTAS;
+        let mut remote = Replica::default();
+        for name in manifest.blobs_after(if compact { 0 } else { since }) {
+            token.check()?;
+            let blob = store
+                .get(&name)?
+                .ok_or_else(|| anyhow!("Sync blob {name} is missing on the server"))?;
+            remote.merge(&decode(&key.open(&name, &blob.data)?)?);
+        }
+
+        let local = export()?;
+        let mut merged = local.clone();
+        merged.merge(&remote);
+        let incoming = merged.without(&local.fingerprints());
+        if incoming.len() > 0 {
+            add_counts(&mut counts, &apply(&incoming)?);
+            downloaded += incoming.len();
+        }
+
+        let mut known = remote.fingerprints();
+        known.extend(
+            known_state
+                .iter()
+                .flat_map(|state| state.fingerprints.iter().cloned()),
+        );
+        let changed = merged.without(&known);
+        let uploaded = changed.len();
+        if uploaded == 0 && !compact {
+            return Ok(result(&manifest, 0, downloaded, &counts, &merged));
+        }
+
+        let version = manifest.version + 1;
+        let (name, replica) = if compact {
+            (blob_name(SNAPSHOT, version), &merged)
+        } else {
+            (blob_name(DELTA, version), &changed)
+        };
+        token.check()?;
+        let sealed = key.seal(&name, &encode(replica)?);
+        if !store.put(&name, &sealed, Condition::Absent)? {
+            continue;
+        }
+        let mut blobs = if compact {
+            Vec::new()
+        } else {
+            manifest.blobs.clone()
+        };
+        blobs.push(name.clone());
+        let next = Manifest {
+            id: manifest.id.clone(),
+            salt: manifest.salt.clone(),
+            iterations: manifest.iterations,
+            check: manifest.check.clone(),
+            version,
+            blobs,
+            etag: None,
+        };
+        if !store.put(
+            MANIFEST,
+            next.to_json().as_bytes(),
+            manifest_condition(&manifest),
+        )? {
+            store.delete(&name)?;
+            continue;
+        }
+        if compact {
+            // The new snapshot holds everything; old blobs are garbage.
+            for old in &manifest.blobs {
+                let _ = store.delete(old);
+            }
+        }
+        return Ok(result(&next, uploaded, downloaded, &counts, &merged));
+    }
+    Err(anyhow!(
+        "Sync kept conflicting with another device; try again"
+    ))
+}
+
+fn manifest_condition(manifest: &Manifest) -> Condition<'_> {
+    match &manifest.etag {
+        Some(etag) => Condition::Matches(etag),
+        // Servers without ETags get last-writer-wins.
+        None => Condition::Any,
+    }
+}
+
+/// Read the manifest, creating it for a new remote.
+fn read_manifest(store: &dyn RemoteStore, iterations: u32, passphrase: &str) -> Result<Manifest> {
+    if let Some(file) = store.get(MANIFEST)? {
+        return Manifest::parse(&file.data, file.etag);
+    }
+    let mut manifest = Manifest {
+        id: hex(&<[u8; 16]>::generate()),
+        salt: <[u8; SALT_LEN]>::generate().to_vec(),
+        iterations,
+        check: Vec::new(),
+        version: 0,
+        blobs: Vec::new(),
+        etag: None,
+    };
+    manifest.check = Key::derive(&manifest, passphrase).seal(MANIFEST, CHECK);
+    if !store.put(MANIFEST, manifest.to_json().as_bytes(), Condition::Absent)? {
+        // Another device created it first.
+        let file = store
+            .get(MANIFEST)?
+            .ok_or_else(|| anyhow!("Sync manifest vanished from the server"))?;
+        return Manifest::parse(&file.data, file.etag);
+    }
+    // Read it back for the ETag.
+    match store.get(MANIFEST)? {
+        Some(file) => Manifest::parse(&file.data, file.etag),
+        None => Err(anyhow!("Sync manifest vanished from the server")),
+    }
+}
+
+fn result(
+    manifest: &Manifest,
+    uploaded: u32,
+    downloaded: u32,
+    counts: &MergeCounts,
+    merged: &Replica,
+) -> SyncResult {
+    let mut fingerprints: Vec<String> = merged.fingerprints().into_iter().collect();
+    fingerprints.sort_unstable();
+    SyncResult {
+        version: manifest.version,
+        uploaded,
+        downloaded,
+        progress_updated: counts.progress_updated,
+        bookmarks_added: counts.bookmarks_added,
+        highlights_added: counts.highlights_added,
+        bookmarks_removed: counts.bookmarks_removed,
+        highlights_removed: counts.highlights_removed,
+        state: SyncState {
+            remote_id: manifest.id.clone(),
+            version: manifest.version,
+            fingerprints,
+        },
+    }
+}
+
+fn add_counts(total: &mut MergeCounts, counts: &MergeCounts) {
+    total.progress_updated += counts.progress_updated;
+    total.bookmarks_added += counts.bookmarks_added;
+    total.highlights_added += counts.highlights_added;
+    total.bookmarks_removed += counts.bookmarks_removed;
+    total.highlights_removed += counts.highlights_removed;
+}
+
+fn fingerprint(kind: &str, record: Value) -> String {
+    let text = format!("{kind}:{record}");
+    blake3::hash(text.as_bytes()).to_hex()[..32].to_string()
+}
+
+fn progress_fingerprint(progress: &ReadingProgress) -> String {
+    fingerprint("progress", backup::progress_json(progress))
+}
+
+/// Unlike the key it buries, which is a bookmark's or highlight's own.
+fn tombstone_fingerprint(key: &str) -> String {
+    fingerprint("tombstone", json!(key))
+}
+
+/// A replica as compressed JSON.
+fn encode(replica: &Replica) -> Result<Vec<u8>> {
+    let records = replica.records();
+    let tombstones: Vec<Value> = replica
+        .tombstone_list()
+        .iter()
+        .map(|t| json!({"key": t.key, "deletedAt": t.deleted_at}))
+        .collect();
+    let value = json!({
+        "progress": records.progress.iter().map(backup::progress_json).collect::<Vec<_>>(),
+        "bookmarks": records.bookmarks.iter().map(backup::bookmark_json).collect::<Vec<_>>(),
+        "highlights": records.highlights.iter().map(backup::highlight_json).collect::<Vec<_>>(),
+        "tombstones": tombstones,
+    });
+    zstd::bulk::compress(value.to_string().as_bytes(), 3)
+        .map_err(|e| anyhow!("Failed to compress sync data: {e}"))
+}
+
+fn decode(data: &[u8]) -> Result<Replica> {
+    let json = zstd::bulk::decompress(data, MAX_BLOB_BYTES as usize * 8)
+        .map_err(|e| anyhow!("Failed to decompress sync data: {e}"))?;
+    let value: Value =
+        serde_json::from_slice(&json).map_err(|e| anyhow!("Failed to parse sync data: {e}"))?;
+    let list = |key: &str| value[key].as_array().map(Vec::as_slice).unwrap_or_default();
+    let records = LibraryDump {
+        books: Vec::new(),
+        progress: list("progress")
+            .iter()
+            .map(backup::progress_from)
+            .collect::<Result<_>>()?,
+        bookmarks: list("bookmarks")
+            .iter()
+            .map(backup::bookmark_from)
+            .collect::<Result<_>>()?,
+        highlights: list("highlights")
+            .iter()
+            .map(backup::highlight_from)
+            .collect::<Result<_>>()?,
+    };
+    let tombstones = list("tombstones")
+        .iter()
+        .map(|t| {
+            Some(Tombstone {
+                key: t["key"].as_str()?.to_string(),
+                deleted_at: t["deletedAt"].as_i64().unwrap_or(0),
+            })
+        })
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| anyhow!("Sync data has a corrupt tombstone"))?;
+    Ok(Replica::new(&records, &tombstones))
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+    use crate::remote::RemoteFile;
+
+    #[derive(Default)]
+    struct MemoryStore {
+        blobs: RefCell<HashMap<String, (Vec<u8>, u32)>>,
+        next_etag: RefCell<u32>,
+    }
+
+    impl RemoteStore for MemoryStore {
+        fn get(&self, name: &str) -> Result<Option<RemoteFile>> {
+            let blobs = self.blobs.borrow();
+            Ok(blobs.get(name).map(|(data, etag)| RemoteFile {
+                data: data.clone(),
+                etag: Some(etag.to_string()),
+            }))
+        }
+
+        fn put(&self, name: &str, data: &[u8], condition: Condition) -> Result<bool> {
+            let mut blobs = self.blobs.borrow_mut();
+            let current = blobs.get(name).map(|(_, etag)| etag.to_string());
+            let allowed = match condition {
+                Condition::Any => true,
+                Condition::Absent => current.is_none(),
+                Condition::Matches(etag) => current.as_deref() == Some(etag),
+            };
+            if allowed {
+                *self.next_etag.borrow_mut() += 1;
+                blobs.insert(name.to_string(), (data.to_vec(), *self.next_etag.borrow()));
+            }
+            Ok(allowed)
+        }
+
+        fn delete(&self, name: &str) -> Result<()> {
+            self.blobs.borrow_mut().remove(name);
+            Ok(())
+        }
+    }
+
+    /// A library that applies changes like `db::apply_sync`.
+    #[derive(Default)]
+    struct MemoryLibrary(RefCell<Replica>);
+
+    impl MemoryLibrary {
+        fn export(&self) -> Result<Replica> {
+            Ok(self.0.borrow().clone())
+        }
+
+        fn apply(&self, changes: &Replica) -> Result<MergeCounts> {
+            let mut replica = self.0.borrow_mut();
+            let before = replica.clone();
+            replica.merge(changes);
+            fn added<T>(a: &HashMap<String, T>, b: &HashMap<String, T>) -> u32 {
+                b.keys().filter(|key| !a.contains_key(*key)).count() as u32
+            }
+            Ok(MergeCounts {
+                progress_updated: changes.progress.len() as u32,
+                bookmarks_added: added(&before.bookmarks, &replica.bookmarks),
+                highlights_added: added(&before.highlights, &replica.highlights),
+                bookmarks_removed: added(&replica.bookmarks, &before.bookmarks),
+                highlights_removed: added(&replica.highlights, &before.highlights),
+                ..MergeCounts::default()
+            })
+        }
+
+        fn progress(&self, book_id: &str) -> ReadingProgress {
+            self.0.borrow().progress[book_id].clone()
+        }
+
+        fn bookmark_count(&self) -> usize {
+            self.0.borrow().bookmarks.len()
+        }
+    }
+
+    fn progress(book_id: &str, updated_at: i64) -> ReadingProgress {
+        ReadingProgress {
+            book_id: book_id.to_string(),
+            chapter_index: updated_at as u32,
+            chapter_offset: 0,
+            percent: 0.5,
+            updated_at,
+        }
+    }
+
+    fn bookmark(book_id: &str, created_at: i64) -> Bookmark {
+        Bookmark {
+            id: None,
+            book_id: book_id.to_string(),
+            chapter_index: 3,
+            chapter_offset: 120,
+            excerpt: "城门外行人稀少".to_string(),
+            note: None,
+            created_at,
+        }
+    }
+
+    fn highlight(book_id: &str, created_at: i64) -> Highlight {
+        Highlight {
+            id: None,
+            book_id: book_id.to_string(),
+            chapter_index: 1,
+            start_offset: 4,
+            end_offset: 9,
+            text: "天色渐晚".to_string(),
+            note: None,
+            color: 2,
+            created_at,
+        }
+    }
+
+    fn run(
+        store: &MemoryStore,
+        library: &MemoryLibrary,
+        passphrase: &str,
+        state: Option<SyncState>,
+    ) -> Result<SyncResult> {
+        let token = task::cancel_token(None).unwrap();
+        let export = || library.export();
+        let apply = |changes: &Replica| library.apply(changes);
+        sync_with(store, &export, &apply, passphrase, state, &token, 1)
+    }
+
+    #[test]
+    fn test_devices_exchange_only_changes() {
+        let store = MemoryStore::default();
+        let phone = MemoryLibrary::default();
+        phone
+            .0
+            .borrow_mut()
+            .add_progress(&progress("three-body", 10));
+        phone
+            .0
+            .borrow_mut()
+            .add_bookmark(&bookmark("three-body", 5));
+        let first = run(&store, &phone, "secret", None).unwrap();
+        assert_eq!((first.version, first.uploaded, first.downloaded), (1, 2, 0));
+
+        let tablet = MemoryLibrary::default();
+        let synced = run(&store, &tablet, "secret", None).unwrap();
+        assert_eq!((synced.uploaded, synced.downloaded), (0, 2));
+        assert_eq!(synced.bookmarks_added, 1);
+        assert_eq!(tablet.progress("three-body").updated_at, 10);
+
+        tablet
+            .0
+            .borrow_mut()
+            .add_progress(&progress("three-body", 20));
+        let synced = run(&store, &tablet, "secret", Some(synced.state)).unwrap();
+        assert_eq!((synced.version, synced.uploaded), (2, 1));
+        let again = run(&store, &tablet, "secret", Some(synced.state)).unwrap();
+        assert_eq!((again.version, again.uploaded, again.downloaded), (2, 0, 0));
+
+        let back = run(&store, &phone, "secret", Some(first.state)).unwrap();
+        assert_eq!((back.uploaded, back.downloaded), (0, 1));
+        assert_eq!(phone.progress("three-body").updated_at, 20);
+
+        // The server holds no readable records, and the passphrase matters.
+        for (data, _) in store.blobs.borrow().values() {
+            let text = String::from_utf8_lossy(data);
+            assert!(!text.contains("three-body") && !text.contains("城门"));
+        }
+        let error = run(&store, &MemoryLibrary::default(), "wrong", None).unwrap_err();
+        assert_eq!(error.to_string(), "Wrong sync passphrase");
+    }
+
+    /// A store where another device syncs just before the first manifest
+    /// update.
+    struct RacingStore<'a> {
+        inner: &'a MemoryStore,
+        rival: RefCell<Option<Box<dyn FnOnce() + 'a>>>,
+    }
+
+    impl RemoteStore for RacingStore<'_> {
+        fn get(&self, name: &str) -> Result<Option<RemoteFile>> {
+            self.inner.get(name)
+        }
+
+        fn put(&self, name: &str, data: &[u8], condition: Condition) -> Result<bool> {
+            if name == MANIFEST {
+                if let Some(rival) = self.rival.borrow_mut().take() {
+                    rival();
+                }
+            }
+            self.inner.put(name, data, condition)
+        }
+
+        fn delete(&self, name: &str) -> Result<()> {
+            self.inner.delete(name)
+        }
+    }
+
+    #[test]
+    fn test_deltas_fold_into_snapshot_and_races_start_over() {
+        let store = MemoryStore::default();
+        let library = MemoryLibrary::default();
+        let mut state = None;
+        for i in 1..=MAX_DELTAS + 1 {
+            let bookmark = bookmark("b", i as i64);
+            library.0.borrow_mut().add_bookmark(&bookmark);
+            state = Some(run(&store, &library, "secret", state).unwrap().state);
+        }
+        let names: Vec<String> = store.blobs.borrow().keys().cloned().collect();
+        assert_eq!(names.len(), 2, "{names:?}");
+        assert!(names.iter().any(|name| name.starts_with("snapshot-21-")));
+
+        let fresh = MemoryLibrary::default();
+        let synced = run(&store, &fresh, "secret", None).unwrap();
+        assert_eq!(synced.downloaded, MAX_DELTAS as u32 + 1);
+        assert_eq!(fresh.bookmark_count(), MAX_DELTAS + 1);
+
+        // The rival's bookmark lands first; ours is merged after it.
+        let rival = MemoryLibrary::default();
+        rival.0.borrow_mut().add_bookmark(&bookmark("rival", 1));
+        let racing = RacingStore {
+            inner: &store,
+            rival: RefCell::new(Some(Box::new(|| {
+                run(&store, &rival, "secret", None).unwrap();
+            }))),
+        };
+        library.0.borrow_mut().add_progress(&progress("b", 99));
+        let token = task::cancel_token(None).unwrap();
+        let export = || library.export();
+        let apply = |changes: &Replica| library.apply(changes);
+        let result = sync_with(&racing, &export, &apply, "secret", state, &token, 1).unwrap();
+        assert_eq!((result.version, result.uploaded), (23, 1));
+        assert!(library
+            .0
+            .borrow()
+            .bookmarks
+            .values()
+            .any(|b| b.book_id == "rival"));
+        assert_eq!(store.blobs.borrow().len(), 4);
+    }
+
+    #[test]
+    fn test_concurrent_edits_converge_without_losing_highlights() {
+        let store = MemoryStore::default();
+        let phone = MemoryLibrary::default();
+        let shared = bookmark("three-body", 1);
+        phone.0.borrow_mut().add_bookmark(&shared);
+        phone
+            .0
+            .borrow_mut()
+            .add_highlight(&highlight("three-body", 1));
+        let phone_state = run(&store, &phone, "secret", None).unwrap().state;
+        let tablet = MemoryLibrary::default();
+        let tablet_state = run(&store, &tablet, "secret", None).unwrap().state;
+
+        // Offline, the phone deletes the shared bookmark and both devices
+        // highlight and read to the same second in different places.
+        phone.0.borrow_mut().bury(&db::bookmark_key(&shared), 50);
+        phone
+            .0
+            .borrow_mut()
+            .add_highlight(&highlight("three-body", 2));
+        tablet
+            .0
+            .borrow_mut()
+            .add_highlight(&highlight("three-body", 3));
+        tablet
+            .0
+            .borrow_mut()
+            .add_bookmark(&bookmark("three-body", 4));
+        let mut reading = progress("three-body", 60);
+        phone.0.borrow_mut().add_progress(&reading);
+        reading.chapter_index = 9;
+        tablet.0.borrow_mut().add_progress(&reading);
+
+        let phone_state = run(&store, &phone, "secret", Some(phone_state))
+            .unwrap()
+            .state;
+        let synced = run(&store, &tablet, "secret", Some(tablet_state)).unwrap();
+        assert_eq!((synced.bookmarks_removed, synced.highlights_added), (1, 1));
+        run(&store, &phone, "secret", Some(phone_state)).unwrap();
+
+        for device in [&phone, &tablet] {
+            let replica = device.0.borrow();
+            assert_eq!(replica.highlights.len(), 3);
+            assert_eq!(replica.bookmarks.len(), 1);
+            assert!(!replica.bookmarks.contains_key(&db::bookmark_key(&shared)));
+        }
+        assert_eq!(
+            progress_fingerprint(&phone.progress("three-body")),
+            progress_fingerprint(&tablet.progress("three-body"))
+        );
+
+        // The deleted bookmark stays deleted, even if a device re-adds it.
+        tablet.0.borrow_mut().add_bookmark(&shared);
+        assert_eq!(tablet.bookmark_count(), 1);
+    }
+
+    #[test]
+    fn test_merge_is_commutative_associative_and_idempotent() {
+        let replica = |bookmarks: &[i64], buried: &[i64], progress_at: i64| {
+            let mut replica = Replica::default();
+            for &at in bookmarks {
+                replica.add_bookmark(&bookmark("b", at));
+            }
+            for &at in buried {
+                replica.bury(&db::bookmark_key(&bookmark("b", at)), at * 10);
+            }
+            replica.add_progress(&progress("b", progress_at));
+            replica
+        };
+        let merge = |a: &Replica, b: &Replica| {
+            let mut merged = a.clone();
+            merged.merge(b);
+            merged
+        };
+        let summary = |r: &Replica| {
+            let mut fingerprints: Vec<String> = r.fingerprints().into_iter().collect();
+            fingerprints.sort();
+            (fingerprints, r.tombstone_list())
+        };
+        let a = replica(&[1, 2], &[3], 5);
+        let b = replica(&[2, 3], &[], 7);
+        let c = replica(&[4], &[1, 3], 7);
+        assert_eq!(summary(&merge(&a, &b)), summary(&merge(&b, &a)));
+        assert_eq!(
+            summary(&merge(&merge(&a, &b), &c)),
+            summary(&merge(&a, &merge(&b, &c)))
+        );
+        assert_eq!(summary(&merge(&a, &a)), summary(&a));
+        let all = merge(&merge(&a, &b), &c);
+        let created: Vec<i64> = all
+            .records()
+            .bookmarks
+            .iter()
+            .map(|b| b.created_at)
+            .collect();
+        assert_eq!(created, [2, 4]);
+    }
+}
@@ -0,0 +1,357 @@
+//! Cancellation and background execution for long-running calls.
+//!
+//! Heavy entry points are `async` and hand their work to a dedicated
+//! thread pool, so the bridge's worker threads stay free for quick calls
+//! while a large book parses or a font converts. Work that mostly waits on
+//! the network, such as syncing or fetching a web book, goes to a separate
+//! and larger pool with `run_blocking_io`, so it can't hold up the
+//! CPU-sized pool. Each takes an optional
+//! cancel token: Dart creates one with `create_cancel_token`, passes it in
+//! and calls `cancel_task` to give up. The call then fails straight away
+//! with a "Cancelled" error. Work that checks its token stops early; other
+//! work runs to completion on the pool and its result is dropped. Errors
+//! from work that was not cancelled are logged, and panics fail the call
+//! rather than the app.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
+use std::task::{Context, Poll, Waker};
+
+use anyhow::{anyhow, Result};
+
+use crate::api::{error, logging};
+
+static TOKENS: Mutex<Option<HashMap<u32, CancelToken>>> = Mutex::new(None);
+
+static NEXT_TOKEN: AtomicU32 = AtomicU32::new(1);
+
+static POOL: OnceLock<rayon::ThreadPool> = OnceLock::new();
+
+static IO_POOL: OnceLock<rayon::ThreadPool> = OnceLock::new();
+
+/// Threads of the pool for work that waits on the network.
+const IO_THREADS: usize = 16;
+
+/// Create a cancel token to pass to a long-running call.
+#[flutter_rust_bridge::frb]
+pub fn create_cancel_token() -> u32 {
+    let id = NEXT_TOKEN.fetch_add(1, Ordering::Relaxed);
+    lock()
+        .get_or_insert_with(HashMap::new)
+        .insert(id, CancelToken::default());
+    id
+}
+
+/// Cancel every call made with `token`, including ones made later.
+/// Returns false if the token does not exist.
+#[flutter_rust_bridge::frb]
+pub fn cancel_task(token: u32) -> bool {
+    match lock().as_ref().and_then(|tokens| tokens.get(&token)) {
+        Some(token) => {
+            token.cancel();
+            true
+        }
+        None => false,
+    }
+}
+
+/// Forget a token once the calls using it have finished. Cancelling a call
+/// still needs the token, so do not release it while one is running.
+#[flutter_rust_bridge::frb]
+pub fn release_cancel_token(token: u32) {
+    if let Some(tokens) = lock().as_mut() {
+        tokens.remove(&token);
+    }
+}
+
+fn lock() -> MutexGuard<'static, Option<HashMap<u32, CancelToken>>> {
+    TOKENS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Look up the token passed to an entry point; `None` gives a token that is
+/// never cancelled.
+pub(crate) fn cancel_token(token: Option<u32>) -> Result<CancelToken> {
+    let Some(id) = token else {
+        return Ok(CancelToken::default());
+    };
+    lock()
+        .as_ref()
+        .and_then(|tokens| tokens.get(&id))
+        .cloned()
+        .ok_or_else(|| anyhow!("Unknown cancel token {id}"))
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Clone, Default)]
+pub(crate) struct CancelToken {
+    inner: Arc<Inner>,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    /// Calls waiting on the pool, by `Pending::key`, woken when the token
+    /// is cancelled.
+    wakers: Mutex<Vec<(usize, Waker)>>,
+}
+
+impl CancelToken {
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Fail with the cancellation error once the token is cancelled.
+    pub(crate) fn check(&self) -> Result<()> {
+        if self.is_cancelled() {
+            return Err(cancelled());
+        }
+        Ok(())
+    }
+
+    fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::Relaxed);
+        let wakers = std::mem::take(&mut *self.wakers());
+        for (_, waker) in wakers {
+            waker.wake();
+        }
+    }
+
+    fn wakers(&self) -> MutexGuard<'_, Vec<(usize, Waker)>> {
+        self.inner.wakers.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn cancelled() -> anyhow::Error {
+    anyhow!("Cancelled")
+}
+
+/// Run `work` on the background pool and wait for it without blocking the
+/// caller's thread. Resolves with the cancellation error as soon as `token`
+/// is cancelled, whether or not `work` notices. Errors are converted to the
+/// caller's error type, such as `ApiError` for API functions.
+pub(crate) async fn run_blocking<T, E, F>(token: CancelToken, work: F) -> Result<T, E>
+where
+    T: Send + 'static,
+    E: From<anyhow::Error>,
+    F: FnOnce(&CancelToken) -> Result<T> + Send + 'static,
+{
+    run_on(pool(), token, work).await
+}
+
+/// Like `run_blocking`, for work that spends most of its time waiting on
+/// the network rather than computing.
+pub(crate) async fn run_blocking_io<T, E, F>(token: CancelToken, work: F) -> Result<T, E>
+where
+    T: Send + 'static,
+    E: From<anyhow::Error>,
+    F: FnOnce(&CancelToken) -> Result<T> + Send + 'static,
+{
+    run_on(io_pool(), token, work).await
+}
+
+async fn run_on<T, E, F>(pool: &rayon::ThreadPool, token: CancelToken, work: F) -> Result<T, E>
+where
+    T: Send + 'static,
+    E: From<anyhow::Error>,
+    F: FnOnce(&CancelToken) -> Result<T> + Send + 'static,
+{
+    token.check()?;
+    let slot = Arc::new(Mutex::new(Slot {
+        result: None,
+        waker: None,
+    }));
+    let job = Arc::clone(&slot);
+    let job_token = token.clone();
+    pool.spawn(move || {
+        // A panic on the pool would abort the app; it fails the call instead.
+        let result = panic::catch_unwind(AssertUnwindSafe(|| work(&job_token)))
+            .unwrap_or_else(|payload| Err(error::panic_error(payload.as_ref())));
+        if let Err(e) = &result {
+            if !job_token.is_cancelled() {
+                logging::error("task", format!("{e:#}"));
+            }
+        }
+        let mut slot = job.lock().unwrap_or_else(|e| e.into_inner());
+        slot.result = Some(result);
+        if let Some(waker) = slot.waker.take() {
+            waker.wake();
+        }
+    });
+    Ok(Pending { slot, token }.await?)
+}
+
+fn pool() -> &'static rayon::ThreadPool {
+    POOL.get_or_init(|| {
+        rayon::ThreadPoolBuilder::new()
+            .thread_name(|i| format!("novella-task-{i}"))
+            .build()
+            .expect("thread pool starts")
+    })
+}
+
+fn io_pool() -> &'static rayon::ThreadPool {
+    IO_POOL.get_or_init(|| {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(IO_THREADS)
+            .thread_name(|i| format!("novella-io-{i}"))
+            .build()
+            .expect("thread pool starts")
+    })
+}
+
+struct Slot<T> {
+    result: Option<Result<T>>,
+    waker: Option<Waker>,
+}
+
+/// Waits for a job on the pool or for its token to be cancelled.
+struct Pending<T> {
+    slot: Arc<Mutex<Slot<T>>>,
+    token: CancelToken,
+}
+
+impl<T> Pending<T> {
+    /// Identifies this call's waker among those of its token.
+    fn key(&self) -> usize {
+        Arc::as_ptr(&self.slot) as *const () as usize
+    }
+}
+
+impl<T> Drop for Pending<T> {
+    fn drop(&mut self) {
+        let key = self.key();
+        self.token.wakers().retain(|(k, _)| *k != key);
+    }
+}
+
+impl<T> Future for Pending<T> {
+    type Output = Result<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<T>> {
+        let mut slot = self.slot.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(result) = slot.result.take() {
+            return Poll::Ready(result);
+        }
+        if self.token.is_cancelled() {
+            return Poll::Ready(Err(cancelled()));
+        }
+        slot.waker = Some(cx.waker().clone());
+        drop(slot);
+
+        let key = self.key();
+        let mut wakers = self.token.wakers();
+        wakers.retain(|(k, _)| *k != key);
+        wakers.push((key, cx.waker().clone()));
+        drop(wakers);
+        // The token may have been cancelled before the waker was added.
+        if self.token.is_cancelled() {
+            return Poll::Ready(Err(cancelled()));
+        }
+        Poll::Pending
+    }
+}
+
+/// Drive a future to completion on the current thread.
+#[cfg(test)]
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    use std::task::Wake;
+
+    struct Thread(std::thread::Thread);
+    impl Wake for Thread {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Waker::from(Arc::new(Thread(std::thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        std::thread::park();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    #[test]
+    fn test_run_blocking_returns_result() {
+        let token = CancelToken::default();
+        let answer: Result<u32> = block_on(run_blocking(token, |_| Ok(6 * 7)));
+        assert_eq!(answer.unwrap(), 42);
+        let failed: Result<()> =
+            block_on(run_blocking(CancelToken::default(), |_| -> Result<()> {
+                Err(anyhow!("boom"))
+            }));
+        assert_eq!(failed.unwrap_err().to_string(), "boom");
+
+        let panicked: Result<()> =
+            block_on(run_blocking(CancelToken::default(), |_| -> Result<()> {
+                panic!("bad chapter index")
+            }));
+        let error = error::ApiError::from(panicked.unwrap_err());
+        assert_eq!(error.code, error::ErrorCode::Panic);
+        assert_eq!(error.message, "bad chapter index");
+    }
+
+    #[test]
+    fn test_cancel_abandons_stuck_work() {
+        let id = create_cancel_token();
+        let token = cancel_token(Some(id)).unwrap();
+        let (release, stuck) = mpsc::channel::<()>();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            assert!(cancel_task(id));
+        });
+        // The job ignores its token and blocks until released.
+        let result: Result<()> = block_on(run_blocking(token.clone(), move |_| {
+            let _ = stuck.recv();
+            Ok(())
+        }));
+        assert_eq!(result.unwrap_err().to_string(), "Cancelled");
+        assert!(token.check().is_err());
+        drop(release);
+
+        release_cancel_token(id);
+        assert!(!cancel_task(id));
+        assert!(cancel_token(Some(id)).is_err());
+    }
+
+    #[test]
+    fn test_wakers_are_dropped_when_work_finishes() {
+        let token = CancelToken::default();
+        for _ in 0..3 {
+            let result: Result<String> = block_on(run_blocking_io(token.clone(), |_| {
+                std::thread::sleep(Duration::from_millis(20));
+                Ok(std::thread::current()
+                    .name()
+                    .unwrap_or_default()
+                    .to_string())
+            }));
+            assert!(result.unwrap().starts_with("novella-io-"));
+        }
+        assert!(token.wakers().is_empty());
+    }
+
+    #[test]
+    fn test_cancelled_token_skips_work() {
+        let id = create_cancel_token();
+        let token = cancel_token(Some(id)).unwrap();
+        cancel_task(id);
+        // Already cancelled: the work never starts.
+        let result: Result<()> = block_on(run_blocking(token, |_| Ok(())));
+        assert!(result.is_err());
+        release_cancel_token(id);
+    }
+}
@@ -0,0 +1,413 @@
+//! Paragraph-aligned machine translation of chapter text.
+//!
+//! Translation goes through an HTTP service, which can run on the device or
+//! the local network for offline use: a LibreTranslate server, a DeepL
+//! account, or any OpenAI-compatible chat endpoint, which includes local
+//! model runners such as Ollama and llama.cpp. Paragraphs are sent in
+//! batches and come back one translation per paragraph, so the reader can
+//! show the original and the translation side by side or interleaved.
+
+use std::io::Read;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+
+use crate::api::error::ApiError;
+use crate::api::network;
+use crate::api::task::{self, CancelToken};
+
+/// Most paragraphs sent in one request.
+const BATCH_PARAGRAPHS: usize = 24;
+/// Most characters sent in one request; a longer paragraph goes alone.
+const BATCH_CHARS: usize = 3000;
+/// Largest response body read.
+const MAX_RESPONSE_BYTES: u64 = 8 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TranslationProvider {
+    /// A LibreTranslate server; `endpoint` is its base URL.
+    LibreTranslate,
+    /// The DeepL API; `endpoint` is `https://api-free.deepl.com` or
+    /// `https://api.deepl.com`.
+    DeepL,
+    /// An OpenAI-compatible chat completions API; `endpoint` is its base URL
+    /// such as `https://api.openai.com/v1` or `http://localhost:11434/v1`.
+    OpenAi,
+}
+
+#[derive(Debug, Clone)]
+pub struct TranslatorConfig {
+    pub provider: TranslationProvider,
+    pub endpoint: String,
+    pub api_key: Option<String>,
+    /// Model name, required for `OpenAi`.
+    pub model: Option<String>,
+    /// Language code of the text, or `None` to detect it.
+    pub source_language: Option<String>,
+    /// Language code to translate into, such as `en` or `zh`.
+    pub target_language: String,
+    /// Seconds to wait for each response.
+    pub timeout_secs: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct TranslatedParagraph {
+    pub original: String,
+    /// Empty for blank paragraphs.
+    pub translation: String,
+}
+
+/// A config for a LibreTranslate server on this device.
+#[flutter_rust_bridge::frb]
+pub fn default_translator_config() -> TranslatorConfig {
+    TranslatorConfig {
+        provider: TranslationProvider::LibreTranslate,
+        endpoint: "http://127.0.0.1:5000".to_string(),
+        api_key: None,
+        model: None,
+        source_language: None,
+        target_language: "en".to_string(),
+        timeout_secs: 120,
+    }
+}
+
+/// Translate paragraphs, returning one translation per paragraph in the
+/// same order. Blank paragraphs are not sent and translate to empty
+/// strings.
+///
+/// # Arguments
+/// * `paragraphs` - Paragraphs to translate
+/// * `config` - Service and languages
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+#[flutter_rust_bridge::frb]
+pub async fn translate_paragraphs(
+    paragraphs: Vec<String>,
+    config: TranslatorConfig,
+    cancel_token: Option<u32>,
+) -> Result<Vec<String>, ApiError> {
+    task::run_blocking_io(task::cancel_token(cancel_token)?, move |token| {
+        translate(&paragraphs, &config, token)
+    })
+    .await
+}
+
+/// Translate chapter text paragraph by paragraph, one paragraph per line.
+///
+/// # Arguments
+/// * `text` - Chapter text
+/// * `config` - Service and languages
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+#[flutter_rust_bridge::frb]
+pub async fn translate_chapter(
+    text: String,
+    config: TranslatorConfig,
+    cancel_token: Option<u32>,
+) -> Result<Vec<TranslatedParagraph>, ApiError> {
+    task::run_blocking_io(task::cancel_token(cancel_token)?, move |token| {
+        let paragraphs: Vec<String> = text.lines().map(str::to_string).collect();
+        let translations = translate(&paragraphs, &config, token)?;
+        Ok(paragraphs
+            .into_iter()
+            .zip(translations)
+            .map(|(original, translation)| TranslatedParagraph {
+                original,
+                translation,
+            })
+            .collect())
+    })
+    .await
+}
+
+fn translate(
+    paragraphs: &[String],
+    config: &TranslatorConfig,
+    token: &CancelToken,
+) -> Result<Vec<String>> {
+    if config.target_language.trim().is_empty() {
+        return Err(anyhow!("No target language set"));
+    }
+    if config.provider == TranslationProvider::OpenAi
+        && config.model.as_deref().is_none_or(|m| m.trim().is_empty())
+    {
+        return Err(anyhow!("No model set for the translation service"));
+    }
+    let mut translations = vec![String::new(); paragraphs.len()];
+    for batch in batches(paragraphs) {
+        token.check()?;
+        let texts: Vec<&str> = batch.iter().map(|&i| paragraphs[i].trim()).collect();
+        for (index, translation) in batch.into_iter().zip(request(config, &texts)?) {
+            translations[index] = translation;
+        }
+    }
+    Ok(translations)
+}
+
+/// Indices of the non-blank paragraphs, grouped into requests.
+fn batches(paragraphs: &[String]) -> Vec<Vec<usize>> {
+    let mut batches: Vec<Vec<usize>> = Vec::new();
+    let mut chars = 0;
+    for (index, paragraph) in paragraphs.iter().enumerate() {
+        let len = paragraph.trim().chars().count();
+        if len == 0 {
+            continue;
+        }
+        match batches.last_mut() {
+            Some(batch) if batch.len() < BATCH_PARAGRAPHS && chars + len <= BATCH_CHARS => {
+                batch.push(index);
+                chars += len;
+            }
+            _ => {
+                batches.push(vec![index]);
+                chars = len;
+            }
+        }
+    }
+    batches
+}
+
+/// Translations of `texts`, one each.
+fn request(config: &TranslatorConfig, texts: &[&str]) -> Result<Vec<String>> {
+    let source = config.source_language.as_deref().filter(|s| !s.is_empty());
+    let target = config.target_language.trim();
+    let translations = match config.provider {
+        TranslationProvider::LibreTranslate => {
+            let mut body = json!({
+                "q": texts,
+                "source": source.unwrap_or("auto"),
+                "target": target,
+                "format": "text",
+            });
+            if let Some(key) = &config.api_key {
+                body["api_key"] = json!(key);
+            }
+            let response = post(config, "/translate", &body)?;
+            strings(&response["translatedText"])
+        }
+        TranslationProvider::DeepL => {
+            let mut body = json!({
+                "text": texts,
+                "target_lang": target.to_ascii_uppercase(),
+            });
+            if let Some(source) = source {
+                body["source_lang"] = json!(source.to_ascii_uppercase());
+            }
+            let response = post(config, "/v2/translate", &body)?;
+            response["translations"].as_array().map(|translations| {
+                translations
+                    .iter()
+                    .map(|t| t["text"].as_str().unwrap_or_default().to_string())
+                    .collect()
+            })
+        }
+        TranslationProvider::OpenAi => return chat(config, texts, source, target),
+    };
+    match translations {
+        Some(translations) if translations.len() == texts.len() => Ok(translations),
+        _ => Err(anyhow!("Unexpected response from the translation service")),
+    }
+}
+
+/// Ask a chat model for a JSON array of translations. Models sometimes
+/// merge or split items, so a batch that comes back misaligned is retried
+/// one paragraph at a time.
+fn chat(
+    config: &TranslatorConfig,
+    texts: &[&str],
+    source: Option<&str>,
+    target: &str,
+) -> Result<Vec<String>> {
+    let from = source.map_or_else(|| "its language".to_string(), |s| format!("'{s}'"));
+    let prompt = format!(
+        "Translate each string in the JSON array from {from} to the language with code \
+         '{target}'. Keep names consistent and do not add notes. Reply with only a JSON \
+         array of the translations, one for each string, in the same order."
+    );
+    let body = json!({
+        "model": config.model,
+        "temperature": 0,
+        "messages": [
+            {"role": "system", "content": prompt},
+            {"role": "user", "content": json!(texts).to_string()},
+        ],
+    });
+    let response = post(config, "/chat/completions", &body)?;
+    let content = response["choices"][0]["message"]["content"]
+        .as_str()
+        .ok_or_else(|| anyhow!("Unexpected response from the translation service"))?;
+    match json_array(content) {
+        Some(translations) if translations.len() == texts.len() => Ok(translations),
+        // A lone paragraph answered in plain text is still its translation.
+        _ if texts.len() == 1 => Ok(vec![content.trim().to_string()]),
+        _ => texts
+            .iter()
+            .map(|text| Ok(chat(config, &[text], source, target)?.remove(0)))
+            .collect(),
+    }
+}
+
+fn post(config: &TranslatorConfig, path: &str, body: &Value) -> Result<Value> {
+    let url = endpoint_url(&config.endpoint, path);
+    let agent = network::agent(
+        &url,
+        Duration::from_secs(30),
+        Duration::from_secs(config.timeout_secs.max(1) as u64),
+        None,
+    )?;
+    let mut request = agent.post(&url).set("Content-Type", "application/json");
+    if let Some(key) = config.api_key.as_deref().filter(|k| !k.is_empty()) {
+        request = match config.provider {
+            TranslationProvider::LibreTranslate => request,
+            TranslationProvider::DeepL => {
+                request.set("Authorization", &format!("DeepL-Auth-Key {key}"))
+            }
+            TranslationProvider::OpenAi => request.set("Authorization", &format!("Bearer {key}")),
+        };
+    }
+    let response = request
+        .send_string(&body.to_string())
+        .map_err(|e| match e {
+            ureq::Error::Status(401 | 403, _) => {
+                anyhow!("Translation service rejected the API key")
+            }
+            ureq::Error::Status(code, _) => anyhow!("Translation failed: HTTP {code}"),
+            e => anyhow!("Translation failed: {e}"),
+        })?;
+    let mut text = String::new();
+    response
+        .into_reader()
+        .take(MAX_RESPONSE_BYTES)
+        .read_to_string(&mut text)
+        .map_err(|e| anyhow!("Failed to read translation: {e}"))?;
+    serde_json::from_str(&text).map_err(|e| anyhow!("Invalid translation response: {e}"))
+}
+
+/// `endpoint` with `path` appended, unless it already ends with it.
+fn endpoint_url(endpoint: &str, path: &str) -> String {
+    let endpoint = endpoint.trim().trim_end_matches('/');
+    if endpoint.ends_with(path) {
+        endpoint.to_string()
+    } else {
+        format!("{endpoint}{path}")
+    }
+}
+
+fn strings(value: &Value) -> Option<Vec<String>> {
+    value
+        .as_array()?
+        .iter()
+        .map(|item| item.as_str().map(str::to_string))
+        .collect()
+}
+
+/// The JSON array of strings in a model's reply, which may be wrapped in a
+/// code fence or surrounded by chatter.
+fn json_array(content: &str) -> Option<Vec<String>> {
+    let start = content.find('[')?;
+    let end = content.rfind(']')?;
+    let value: Value = serde_json::from_str(content.get(start..=end)?).ok()?;
+    strings(&value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+
+    /// Answer requests with the given JSON bodies in order, reporting each
+    /// request body.
+    fn serve(responses: Vec<String>) -> (String, mpsc::Receiver<Value>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for (stream, response) in listener.incoming().zip(responses) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim().to_ascii_lowercase();
+                    if line.is_empty() {
+                        break;
+                    }
+                    if let Some(value) = line.strip_prefix("content-length:") {
+                        length = value.trim().parse().unwrap();
+                    }
+                }
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                let _ = sender.send(serde_json::from_slice(&body).unwrap());
+                let _ = stream.write_all(
+                    format!(
+                        "HTTP/1.1 200 OK\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{response}",
+                        response.len()
+                    )
+                    .as_bytes(),
+                );
+            }
+        });
+        (format!("http://{address}"), receiver)
+    }
+
+    fn config(provider: TranslationProvider, endpoint: String) -> TranslatorConfig {
+        TranslatorConfig {
+            provider,
+            endpoint,
+            model: Some("test".to_string()),
+            target_language: "en".to_string(),
+            timeout_secs: 5,
+            ..default_translator_config()
+        }
+    }
+
+    #[test]
+    fn test_keeps_paragraph_alignment() {
+        let (endpoint, requests) = serve(vec![
+            json!({"translatedText": ["Chapter One", "He left."]}).to_string(),
+        ]);
+        let paragraphs = ["第一章", "", "　　他走了。"].map(str::to_string);
+        let config = config(TranslationProvider::LibreTranslate, endpoint);
+        let translated = translate(&paragraphs, &config, &CancelToken::default()).unwrap();
+        assert_eq!(translated, ["Chapter One", "", "He left."]);
+        let request = requests.recv().unwrap();
+        assert_eq!(request["q"], json!(["第一章", "他走了。"]));
+        assert_eq!(request["source"], "auto");
+    }
+
+    #[test]
+    fn test_chat_reply_falls_back_per_paragraph() {
+        let reply =
+            |content: &str| json!({"choices": [{"message": {"content": content}}]}).to_string();
+        let (endpoint, requests) = serve(vec![
+            reply("[\"Both merged.\"]"),
+            reply("```json\n[\"One.\"]\n```"),
+            reply("Two."),
+        ]);
+        let paragraphs = ["一。", "二。"].map(str::to_string);
+        let config = config(TranslationProvider::OpenAi, endpoint);
+        let translated = translate(&paragraphs, &config, &CancelToken::default()).unwrap();
+        assert_eq!(translated, ["One.", "Two."]);
+        let first = requests.recv().unwrap();
+        assert_eq!(first["messages"][1]["content"], "[\"一。\",\"二。\"]");
+    }
+
+    #[test]
+    fn test_batches_and_endpoint() {
+        let long = "字".repeat(BATCH_CHARS);
+        let paragraphs = ["a".to_string(), long, " ".to_string(), "b".to_string()];
+        assert_eq!(batches(&paragraphs), [vec![0], vec![1], vec![3]]);
+        assert_eq!(
+            endpoint_url("http://host/v1/", "/chat/completions"),
+            "http://host/v1/chat/completions"
+        );
+        assert_eq!(
+            endpoint_url("http://host/translate", "/translate"),
+            "http://host/translate"
+        );
+    }
+}
@@ -0,0 +1,496 @@
+//! On-device neural text-to-speech with Piper/VITS ONNX voices.
+//!
+//! The subsystem is optional: nothing happens until the app calls
+//! `init_tts_runtime` with the path of the ONNX Runtime library it ships,
+//! which is loaded at run time rather than linked, and then loads a voice.
+//! A voice is a Piper-style model (`.onnx`) with its `.onnx.json` config,
+//! which maps phoneme symbols to model input ids.
+//!
+//! Piper voices trained on eSpeak phonemes need eSpeak itself to turn text
+//! into phonemes, which is not bundled. Voices work here when their symbols
+//! come from a lexicon file (`word symbol symbol …` per line, the format
+//! the Chinese VITS voices ship with) or when the config's `phoneme_type`
+//! is `text`, meaning the symbols are plain characters. Chinese text is
+//! segmented with jieba before lexicon lookup, and words missing from the
+//! lexicon fall back to one lookup per character.
+//!
+//! Speed is passed to the model as its length scale. Pitch has no model
+//! input, so it is shifted by synthesising the utterance longer by the
+//! pitch factor and resampling it back to the original length, which
+//! raises or lowers the pitch without changing the tempo. Audio comes out
+//! as 16-bit little-endian mono PCM at the voice's sample rate;
+//! `pcm_to_wav` wraps it for players that need a container.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard};
+
+use anyhow::{anyhow, Result};
+use ort::session::Session;
+use ort::value::Tensor;
+use serde_json::Value;
+
+use crate::api::error::ApiError;
+use crate::api::tts_prep::Utterance;
+use crate::api::{segment, tts_timeline};
+use crate::frb_generated::StreamSink;
+
+const PAD: &str = "_";
+const BOS: &str = "^";
+const EOS: &str = "$";
+
+/// Full-width punctuation folded to the ASCII symbols voices are trained on.
+const PUNCTUATION: [(char, char); 10] = [
+    ('，', ','),
+    ('、', ','),
+    ('。', '.'),
+    ('！', '!'),
+    ('？', '?'),
+    ('；', ';'),
+    ('：', ':'),
+    ('…', '.'),
+    ('—', ','),
+    ('～', ','),
+];
+
+static VOICE: Mutex<Option<Voice>> = Mutex::new(None);
+
+#[derive(Debug, Clone)]
+pub struct TtsVoiceInfo {
+    pub sample_rate: u32,
+    pub speakers: u32,
+    /// Language code from the config, e.g. `zh_CN`; empty if missing.
+    pub language: String,
+    pub uses_lexicon: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct TtsOptions {
+    /// Playback rate; 1.0 is the voice's natural speed.
+    pub speed: f32,
+    /// Pitch factor; 1.0 keeps the voice's pitch, 1.1 is about 1.6 semitones up.
+    pub pitch: f32,
+    /// Speaker of a multi-speaker voice; ignored otherwise.
+    pub speaker: u32,
+}
+
+/// One synthesised utterance of a stream.
+#[derive(Debug, Clone)]
+pub struct TtsAudioChunk {
+    /// ID of the utterance the audio speaks.
+    pub utterance_id: String,
+    pub sample_rate: u32,
+    /// 16-bit little-endian mono PCM.
+    pub pcm: Vec<u8>,
+}
+
+/// Load the ONNX Runtime library. Only the first call in a process takes
+/// effect; later calls return without reloading.
+///
+/// # Arguments
+/// * `library_path` - Path of `libonnxruntime.so`, `onnxruntime.dll` or the
+///   framework binary on Apple platforms
+#[flutter_rust_bridge::frb]
+pub fn init_tts_runtime(library_path: String) -> Result<(), ApiError> {
+    if !Path::new(&library_path).is_file() {
+        return Err(anyhow!("ONNX Runtime library not found: {library_path}").into());
+    }
+    // ort panics when the library cannot be loaded.
+    std::panic::catch_unwind(|| ort::init_from(library_path).with_name("novella").commit())
+        .map_err(|_| anyhow!("Failed to load ONNX Runtime"))?
+        .map_err(|e| anyhow!("Failed to initialise ONNX Runtime: {e}"))?;
+    Ok(())
+}
+
+/// Load a voice, replacing the current one.
+///
+/// # Arguments
+/// * `model_path` - Voice model (`.onnx`)
+/// * `config_path` - Voice config (`.onnx.json`)
+/// * `lexicon_path` - Optional lexicon mapping words to phoneme symbols
+#[flutter_rust_bridge::frb]
+pub fn load_tts_voice(
+    model_path: String,
+    config_path: String,
+    lexicon_path: Option<String>,
+) -> Result<TtsVoiceInfo, ApiError> {
+    let config = fs::read_to_string(&config_path)
+        .map_err(|e| anyhow!("Failed to read {config_path}: {e}"))?;
+    let lexicon = match &lexicon_path {
+        Some(path) => Some(Lexicon::parse(
+            &fs::read_to_string(path).map_err(|e| anyhow!("Failed to read {path}: {e}"))?,
+        )),
+        None => None,
+    };
+    let config = VoiceConfig::parse(&config, lexicon)?;
+    let session = std::panic::catch_unwind(|| {
+        Session::builder().and_then(|builder| builder.commit_from_file(&model_path))
+    })
+    .map_err(|_| anyhow!("Failed to load voice: ONNX Runtime is not initialised"))?
+    .map_err(|e| anyhow!("Failed to load voice {model_path}: {e}"))?;
+    let info = config.info();
+    *lock() = Some(Voice { config, session });
+    Ok(info)
+}
+
+/// Unload the current voice and free its model.
+#[flutter_rust_bridge::frb]
+pub fn unload_tts_voice() {
+    *lock() = None;
+}
+
+#[flutter_rust_bridge::frb]
+pub fn default_tts_options() -> TtsOptions {
+    TtsOptions {
+        speed: 1.0,
+        pitch: 1.0,
+        speaker: 0,
+    }
+}
+
+/// Synthesise one utterance with the current voice.
+///
+/// # Arguments
+/// * `text` - Sentence-sized text; see `prepare_tts`
+/// * `options` - Speed, pitch and speaker
+#[flutter_rust_bridge::frb]
+pub fn synthesize_speech(text: String, options: TtsOptions) -> Result<Vec<u8>, ApiError> {
+    let mut voice = lock();
+    let voice = voice
+        .as_mut()
+        .ok_or_else(|| anyhow!("No TTS voice is loaded"))?;
+    Ok(voice.synthesize(&text, &options)?)
+}
+
+/// Synthesise utterances in order, sending each as soon as it is ready so
+/// playback can start after the first sentence. Each one's duration is
+/// recorded in the TTS timeline (see `start_tts_timeline`).
+///
+/// # Arguments
+/// * `utterances` - Utterances from `prepare_tts`
+/// * `options` - Speed, pitch and speaker
+#[flutter_rust_bridge::frb]
+pub fn synthesize_speech_stream(
+    utterances: Vec<Utterance>,
+    options: TtsOptions,
+    sink: StreamSink<TtsAudioChunk>,
+) -> Result<(), ApiError> {
+    for utterance in utterances {
+        let mut voice = lock();
+        let voice = voice
+            .as_mut()
+            .ok_or_else(|| anyhow!("No TTS voice is loaded"))?;
+        let pcm = voice.synthesize(&utterance.text, &options)?;
+        tts_timeline::record_pcm(&utterance.id, pcm.len(), voice.config.sample_rate);
+        let chunk = TtsAudioChunk {
+            utterance_id: utterance.id,
+            sample_rate: voice.config.sample_rate,
+            pcm,
+        };
+        sink.add(chunk)
+            .map_err(|_| anyhow!("TTS audio stream was closed"))?;
+    }
+    Ok(())
+}
+
+/// Wrap 16-bit mono PCM in a WAV header.
+///
+/// # Arguments
+/// * `pcm` - 16-bit little-endian mono samples
+/// * `sample_rate` - Samples per second
+#[flutter_rust_bridge::frb]
+pub fn pcm_to_wav(pcm: Vec<u8>, sample_rate: u32) -> Vec<u8> {
+    let mut wav = Vec::with_capacity(44 + pcm.len());
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + pcm.len() as u32).to_le_bytes());
+    wav.extend_from_slice(b"WAVEfmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    wav.extend_from_slice(&1u16.to_le_bytes()); // mono
+    wav.extend_from_slice(&sample_rate.to_le_bytes());
+    wav.extend_from_slice(&(sample_rate * 2).to_le_bytes());
+    wav.extend_from_slice(&2u16.to_le_bytes());
+    wav.extend_from_slice(&16u16.to_le_bytes());
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&(pcm.len() as u32).to_le_bytes());
+    wav.extend_from_slice(&pcm);
+    wav
+}
+
+/// Synthesise one utterance with the current voice as a WAV file.
+pub(crate) fn synthesize_wav(text: &str, options: &TtsOptions) -> Result<Vec<u8>> {
+    let mut voice = lock();
+    let voice = voice
+        .as_mut()
+        .ok_or_else(|| anyhow!("No TTS voice is loaded"))?;
+    let pcm = voice.synthesize(text, options)?;
+    Ok(pcm_to_wav(pcm, voice.config.sample_rate))
+}
+
+fn lock() -> MutexGuard<'static, Option<Voice>> {
+    VOICE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+struct Voice {
+    config: VoiceConfig,
+    session: Session,
+}
+
+impl Voice {
+    fn synthesize(&mut self, text: &str, options: &TtsOptions) -> Result<Vec<u8>> {
+        if !(options.speed > 0.0 && options.pitch > 0.0) {
+            return Err(anyhow!("Speed and pitch must be positive"));
+        }
+        let ids = self.config.phoneme_ids(text);
+        if ids.len() <= 3 {
+            return Ok(Vec::new());
+        }
+        let config = &self.config;
+        let length_scale = config.length_scale / options.speed * options.pitch;
+        let scales = vec![config.noise_scale, length_scale, config.noise_w];
+        let length = ids.len() as i64;
+        let tensor = |shape: Vec<i64>, data: Vec<i64>| {
+            Tensor::from_array((shape, data)).map_err(|e| anyhow!("Failed to build TTS input: {e}"))
+        };
+        let mut inputs = ort::inputs! {
+            "input" => tensor(vec![1, length], ids)?,
+            "input_lengths" => tensor(vec![1], vec![length])?,
+            "scales" => Tensor::from_array((vec![3i64], scales))
+                .map_err(|e| anyhow!("Failed to build TTS input: {e}"))?,
+        };
+        if config.speakers > 1 {
+            let speaker = options.speaker.min(config.speakers - 1) as i64;
+            inputs.push(("sid".into(), tensor(vec![1], vec![speaker])?.into()));
+        }
+        let outputs = self
+            .session
+            .run(inputs)
+            .map_err(|e| anyhow!("Failed to synthesise speech: {e}"))?;
+        let (_, samples) = outputs[0]
+            .try_extract_tensor::<f32>()
+            .map_err(|e| anyhow!("Failed to read synthesised audio: {e}"))?;
+        Ok(to_pcm(&resample(samples, options.pitch)))
+    }
+}
+
+/// The parts of a Piper voice config used for synthesis.
+struct VoiceConfig {
+    sample_rate: u32,
+    speakers: u32,
+    language: String,
+    noise_scale: f32,
+    length_scale: f32,
+    noise_w: f32,
+    symbols: HashMap<String, Vec<i64>>,
+    lexicon: Option<Lexicon>,
+}
+
+impl VoiceConfig {
+    fn parse(json: &str, lexicon: Option<Lexicon>) -> Result<Self> {
+        let config: Value =
+            serde_json::from_str(json).map_err(|e| anyhow!("Failed to parse voice config: {e}"))?;
+        let symbols: HashMap<String, Vec<i64>> = config["phoneme_id_map"]
+            .as_object()
+            .ok_or_else(|| anyhow!("Voice config has no phoneme_id_map"))?
+            .iter()
+            .map(|(symbol, ids)| {
+                let ids = ids
+                    .as_array()
+                    .map(|ids| ids.iter().filter_map(Value::as_i64).collect())
+                    .unwrap_or_default();
+                (symbol.clone(), ids)
+            })
+            .collect();
+        let phoneme_type = config["phoneme_type"].as_str().unwrap_or("espeak");
+        if lexicon.is_none() && phoneme_type != "text" {
+            return Err(anyhow!(
+                "Voice uses {phoneme_type} phonemes; load it with a lexicon"
+            ));
+        }
+        let inference = &config["inference"];
+        let number = |value: &Value, default: f32| value.as_f64().map_or(default, |v| v as f32);
+        Ok(Self {
+            sample_rate: config["audio"]["sample_rate"].as_u64().unwrap_or(22050) as u32,
+            speakers: config["num_speakers"].as_u64().unwrap_or(1).max(1) as u32,
+            language: config["language"]["code"]
+                .as_str()
+                .or_else(|| config["espeak"]["voice"].as_str())
+                .unwrap_or_default()
+                .to_string(),
+            noise_scale: number(&inference["noise_scale"], 0.667),
+            length_scale: number(&inference["length_scale"], 1.0),
+            noise_w: number(&inference["noise_w"], 0.8),
+            symbols,
+            lexicon,
+        })
+    }
+
+    fn info(&self) -> TtsVoiceInfo {
+        TtsVoiceInfo {
+            sample_rate: self.sample_rate,
+            speakers: self.speakers,
+            language: self.language.clone(),
+            uses_lexicon: self.lexicon.is_some(),
+        }
+    }
+
+    /// Model input ids for `text`: BOS, then every symbol followed by the
+    /// pad symbol, then EOS. Symbols the voice does not know are dropped.
+    fn phoneme_ids(&self, text: &str) -> Vec<i64> {
+        let mut ids = Vec::new();
+        let mut push = |symbol: &str| {
+            if let Some(symbol_ids) = self.symbols.get(symbol) {
+                ids.extend_from_slice(symbol_ids);
+                if symbol != EOS {
+                    ids.extend(self.symbols.get(PAD).into_iter().flatten());
+                }
+            }
+        };
+        push(BOS);
+        for symbol in self.symbols_of(text) {
+            push(&symbol);
+        }
+        push(EOS);
+        ids
+    }
+
+    fn symbols_of(&self, text: &str) -> Vec<String> {
+        let fold = |c: char| {
+            PUNCTUATION
+                .iter()
+                .find(|(from, _)| *from == c)
+                .map_or(c, |(_, to)| *to)
+        };
+        let Some(lexicon) = &self.lexicon else {
+            return text.chars().map(|c| fold(c).to_string()).collect();
+        };
+        let mut symbols = Vec::new();
+        for token in segment::jieba().cut(text, true) {
+            let word = token.word;
+            if let Some(phones) = lexicon.get(word) {
+                symbols.extend(phones.iter().cloned());
+                continue;
+            }
+            for c in word.chars() {
+                let key = c.to_string();
+                match lexicon.get(&key) {
+                    Some(phones) => symbols.extend(phones.iter().cloned()),
+                    None if c.is_whitespace() => symbols.push(" ".to_string()),
+                    None => symbols.push(fold(c).to_string()),
+                }
+            }
+        }
+        symbols
+    }
+}
+
+/// Word to phoneme symbols. Keys are lowercased so English entries match
+/// whatever case the text uses.
+struct Lexicon(HashMap<String, Vec<String>>);
+
+impl Lexicon {
+    fn parse(text: &str) -> Self {
+        let mut entries = HashMap::new();
+        for line in text.lines() {
+            let mut fields = line.split_whitespace();
+            let Some(word) = fields.next() else {
+                continue;
+            };
+            let phones: Vec<String> = fields.map(str::to_string).collect();
+            if !phones.is_empty() {
+                entries.entry(word.to_lowercase()).or_insert(phones);
+            }
+        }
+        Self(entries)
+    }
+
+    fn get(&self, word: &str) -> Option<&Vec<String>> {
+        self.0.get(&word.to_lowercase())
+    }
+}
+
+/// Squeeze or stretch `samples` by `factor` with linear interpolation; a
+/// factor above 1 shortens the audio and raises its pitch.
+fn resample(samples: &[f32], factor: f32) -> Vec<f32> {
+    if (factor - 1.0).abs() < 1e-3 || samples.len() < 2 {
+        return samples.to_vec();
+    }
+    let length = ((samples.len() as f32) / factor).round() as usize;
+    (0..length)
+        .map(|i| {
+            let position = i as f32 * factor;
+            let index = position as usize;
+            let next = (index + 1).min(samples.len() - 1);
+            let fraction = position - index as f32;
+            let current = samples[index.min(samples.len() - 1)];
+            current + (samples[next] - current) * fraction
+        })
+        .collect()
+}
+
+fn to_pcm(samples: &[f32]) -> Vec<u8> {
+    samples
+        .iter()
+        .flat_map(|sample| {
+            ((sample.clamp(-1.0, 1.0) * i16::MAX as f32).round() as i16).to_le_bytes()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"{
+        "audio": {"sample_rate": 16000},
+        "language": {"code": "zh_CN"},
+        "num_speakers": 1,
+        "phoneme_type": "pinyin",
+        "inference": {"noise_scale": 0.5, "length_scale": 1.2, "noise_w": 0.7},
+        "phoneme_id_map": {
+            "_": [0], "^": [1], "$": [2], " ": [3], ",": [4], ".": [5],
+            "n": [10], "i3": [11], "h": [12], "ao3": [13], "sh": [14], "u1": [15]
+        }
+    }"#;
+
+    #[test]
+    fn test_lexicon_phoneme_ids() {
+        let lexicon = Lexicon::parse("你好 n i3 h ao3\n书 sh u1\n\nbad\n");
+        let config = VoiceConfig::parse(CONFIG, Some(lexicon)).unwrap();
+        let info = config.info();
+        assert_eq!((info.sample_rate, info.language.as_str()), (16000, "zh_CN"));
+        assert_eq!(config.length_scale, 1.2);
+        // Whole word, full-width comma, a single-character word, and an
+        // unknown character that is dropped.
+        assert_eq!(
+            config.phoneme_ids("你好，书啊。"),
+            vec![1, 0, 10, 0, 11, 0, 12, 0, 13, 0, 4, 0, 14, 0, 15, 0, 5, 0, 2]
+        );
+    }
+
+    #[test]
+    fn test_requires_lexicon_for_non_text_phonemes() {
+        assert!(VoiceConfig::parse(CONFIG, None).is_err());
+        let text = CONFIG.replace("\"pinyin\"", "\"text\"");
+        let config = VoiceConfig::parse(&text, None).unwrap();
+        assert_eq!(config.phoneme_ids("n,"), vec![1, 0, 10, 0, 4, 0, 2]);
+        assert!(VoiceConfig::parse("{}", Some(Lexicon::parse(""))).is_err());
+    }
+
+    #[test]
+    fn test_resample_and_wav() {
+        let samples: Vec<f32> = (0..1000).map(|i| (i as f32 / 1000.0) - 0.5).collect();
+        assert_eq!(resample(&samples, 1.25).len(), 800);
+        assert_eq!(resample(&samples, 0.5).len(), 2000);
+        assert_eq!(resample(&samples, 1.0), samples);
+
+        let pcm = to_pcm(&[1.0, -2.0, 0.0]);
+        assert_eq!(pcm, [0xFF, 0x7F, 0x01, 0x80, 0x00, 0x00]);
+        let wav = pcm_to_wav(pcm, 16000);
+        assert_eq!(&wav[..4], b"RIFF");
+        assert_eq!(u32::from_le_bytes(wav[24..28].try_into().unwrap()), 16000);
+        assert_eq!(u32::from_le_bytes(wav[40..44].try_into().unwrap()), 6);
+        assert_eq!(wav.len(), 50);
+    }
+}
@@ -0,0 +1,615 @@
+//! Text preparation for listening mode.
+//!
+//! Chapter text is split into sentence-sized utterances. Each keeps the
+//! UTF-16 range of the original sentence, for highlighting, next to the
+//! text to speak, which has bracketed annotations removed and, in Chinese
+//! sentences, numbers, dates and times spelled out. Utterance IDs hash the
+//! sentence text, so they survive re-running on the same or edited text.
+//!
+//! Online engines that accept SSML get a document per utterance from
+//! `prepare_tts_ssml` instead: numbers are marked with `say-as` for the
+//! engine to read, paragraphs end with a pause and dialogue between paired
+//! quotes can be spoken with its own pitch and rate. The plain text stays
+//! on each utterance for engines without SSML.
+
+use std::collections::HashMap;
+
+use regex::{Captures, Regex};
+
+/// Sentences longer than this many characters are split at a comma.
+const MAX_UTTERANCE_CHARS: usize = 120;
+
+/// Integers longer than this are read digit by digit, as phone and ID
+/// numbers are.
+const MAX_NUMBER_DIGITS: usize = 8;
+
+/// Longest integer read as an amount when written with thousands
+/// separators, which `integer_zh` can name up to 亿.
+const MAX_GROUPED_DIGITS: usize = 12;
+
+pub(crate) const TERMINATORS: &[char] = &['。', '！', '？', '!', '?', '；', ';', '…'];
+pub(crate) const CLOSERS: &[char] = &['」', '』', '”', '’', '"', '\'', '）', ')', '》', '】'];
+const COMMAS: &[char] = &['，', ',', '、', '：', ':'];
+const DIGITS: [char; 10] = ['零', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+
+#[derive(Debug, Clone)]
+pub struct Utterance {
+    pub id: String,
+    /// Text to speak.
+    pub text: String,
+    /// UTF-16 range of the sentence in the chapter text.
+    pub start: u32,
+    pub end: u32,
+    /// SSML document to send instead of `text` to engines that accept it;
+    /// only set by `prepare_tts_ssml`.
+    pub ssml: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SsmlOptions {
+    /// Language of the text for `xml:lang`, e.g. `zh-CN`.
+    pub language: String,
+    /// Pause after each paragraph, in milliseconds; 0 for none.
+    pub paragraph_break_ms: u32,
+    /// Prosody pitch for quoted dialogue, e.g. `+5%`, or `None` to leave it
+    /// to the engine.
+    pub dialogue_pitch: Option<String>,
+    /// Prosody rate for quoted dialogue, e.g. `95%`.
+    pub dialogue_rate: Option<String>,
+}
+
+#[flutter_rust_bridge::frb]
+pub fn default_ssml_options() -> SsmlOptions {
+    SsmlOptions {
+        language: "zh-CN".to_string(),
+        paragraph_break_ms: 600,
+        dialogue_pitch: Some("+5%".to_string()),
+        dialogue_rate: None,
+    }
+}
+
+/// Split chapter text into utterances in reading order. Sentences with
+/// nothing to speak, such as separator lines, are skipped.
+#[flutter_rust_bridge::frb]
+pub fn prepare_tts(text: String) -> Vec<Utterance> {
+    utterances(&text, None)
+}
+
+/// Split chapter text into utterances as `prepare_tts` does, each with an
+/// SSML document next to its plain text.
+///
+/// # Arguments
+/// * `text` - Chapter text
+/// * `options` - Language, paragraph pauses and dialogue prosody
+#[flutter_rust_bridge::frb]
+pub fn prepare_tts_ssml(text: String, options: SsmlOptions) -> Vec<Utterance> {
+    utterances(&text, Some(&options))
+}
+
+fn utterances(text: &str, ssml: Option<&SsmlOptions>) -> Vec<Utterance> {
+    let patterns = Patterns::new();
+    let mut seen: HashMap<String, u32> = HashMap::new();
+    let mut utterances: Vec<Utterance> = Vec::new();
+    let mut utf16 = 0;
+    let mut counted = 0;
+    // Whether the text so far has an unclosed quote, and where each SSML
+    // utterance ends a paragraph.
+    let mut quoted = false;
+    let mut paragraph_ends = Vec::new();
+    for (start, end) in sentences(text) {
+        let sentence = &text[start..end];
+        let spoken = patterns.spoken(sentence);
+        if !spoken.chars().any(char::is_alphanumeric) {
+            continue;
+        }
+        if text[counted..start].contains('\n') {
+            quoted = false;
+            if let Some(last) = paragraph_ends.last_mut() {
+                *last = true;
+            }
+        }
+
+        utf16 += text[counted..start].encode_utf16().count() as u32;
+        let utf16_end = utf16 + sentence.encode_utf16().count() as u32;
+        counted = start;
+
+        let hash = blake3::hash(sentence.as_bytes()).to_hex();
+        let repeats = seen.entry(hash.to_string()).or_insert(0);
+        let id = match *repeats {
+            0 => hash[..16].to_string(),
+            n => format!("{}-{n}", &hash[..16]),
+        };
+        *repeats += 1;
+
+        utterances.push(Utterance {
+            id,
+            text: spoken,
+            start: utf16,
+            end: utf16_end,
+            ssml: ssml.map(|options| patterns.ssml(sentence, &mut quoted, options)),
+        });
+        paragraph_ends.push(false);
+    }
+    if let Some(last) = paragraph_ends.last_mut() {
+        *last = true;
+    }
+
+    if let Some(options) = ssml {
+        for (utterance, paragraph_end) in utterances.iter_mut().zip(paragraph_ends) {
+            let body = utterance.ssml.take().unwrap_or_default();
+            let pause = match options.paragraph_break_ms {
+                ms if paragraph_end && ms > 0 => format!("<break time=\"{ms}ms\"/>"),
+                _ => String::new(),
+            };
+            utterance.ssml = Some(format!(
+                "<speak version=\"1.0\" xmlns=\"http://www.w3.org/2001/10/synthesis\" \
+                 xml:lang=\"{}\">{body}{pause}</speak>",
+                escape(&options.language)
+            ));
+        }
+    }
+    utterances
+}
+
+/// Byte ranges of sentences, trimmed. Sentences end at line breaks and at
+/// terminal punctuation with any closing quotes after it.
+pub(crate) fn sentences(text: &str) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut push = |start: usize, end: usize| {
+        let sentence = &text[start..end];
+        let trimmed = sentence.trim_start();
+        let start = start + sentence.len() - trimmed.len();
+        let end = start + trimmed.trim_end().len();
+        if end > start {
+            split_long(text, start, end, &mut ranges);
+        }
+    };
+
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if c == '\n' {
+            push(start, i);
+            start = i + 1;
+        } else if TERMINATORS.contains(&c) {
+            let mut end = i + c.len_utf8();
+            while let Some(&(j, next)) = chars.peek() {
+                if !TERMINATORS.contains(&next) && !CLOSERS.contains(&next) {
+                    break;
+                }
+                end = j + next.len_utf8();
+                chars.next();
+            }
+            push(start, end);
+            start = end;
+        }
+    }
+    push(start, text.len());
+    ranges
+}
+
+/// Split `text[start..end]` at commas into pieces of at most
+/// `MAX_UTTERANCE_CHARS` characters where possible.
+fn split_long(text: &str, mut start: usize, end: usize, ranges: &mut Vec<(usize, usize)>) {
+    while text[start..end].chars().count() > MAX_UTTERANCE_CHARS {
+        let limit = text[start..end]
+            .char_indices()
+            .nth(MAX_UTTERANCE_CHARS)
+            .map_or(end, |(i, _)| start + i);
+        let Some(comma) = text[start..limit].rfind(COMMAS) else {
+            break;
+        };
+        let split = start + comma + text[start + comma..].chars().next().unwrap().len_utf8();
+        ranges.push((start, split));
+        start = split + (text[split..end].len() - text[split..end].trim_start().len());
+    }
+    ranges.push((start, end));
+}
+
+struct Patterns {
+    brackets: Regex,
+    numbers: Regex,
+    whitespace: Regex,
+}
+
+impl Patterns {
+    fn new() -> Self {
+        Self {
+            brackets: Regex::new(r"\([^()]*\)|（[^（）]*）|\[[^\[\]]*\]|【[^【】]*】|〔[^〔〕]*〕")
+                .unwrap(),
+            numbers: Regex::new(concat!(
+                r"(?P<date>(?P<y>\d{4})[-/.年](?P<mo>\d{1,2})[-/.月](?P<d>\d{1,2})日?)",
+                r"|(?P<time>(?P<h>\d{1,2}):(?P<mi>\d{2}))",
+                r"|(?P<percent>(?P<pn>\d+(?:\.\d+)?)[%％])",
+                r"|(?P<year>(?P<yy>\d{4})年)",
+                r"|(?P<fraction>(?P<fn>\d{1,4})/(?P<fd>\d{1,4}))",
+                r"|(?P<number>-?(?:\d{1,3}(?:,\d{3})+(?:\.\d+)?|\d+(?:\.\d+)?))",
+            ))
+            .unwrap(),
+            whitespace: Regex::new(r"\s+").unwrap(),
+        }
+    }
+
+    /// `sentence` without annotations and with ASCII digits.
+    fn normalized(&self, sentence: &str) -> String {
+        self.brackets
+            .replace_all(sentence, "")
+            .chars()
+            .map(|c| match c {
+                '０'..='９' => char::from(b'0' + (c as u32 - '０' as u32) as u8),
+                _ => c,
+            })
+            .collect()
+    }
+
+    fn spoken(&self, sentence: &str) -> String {
+        let text = self.normalized(sentence);
+        let text = if text.chars().any(is_han) {
+            self.numbers
+                .replace_all(&text, |caps: &Captures| {
+                    expand(caps, after_word(&text, caps))
+                })
+                .into_owned()
+        } else {
+            text
+        };
+        self.whitespace.replace_all(text.trim(), " ").into_owned()
+    }
+
+    /// SSML body of a sentence. `quoted` says whether the sentence starts
+    /// inside dialogue and is left saying whether it ends inside it.
+    fn ssml(&self, sentence: &str, quoted: &mut bool, options: &SsmlOptions) -> String {
+        let text = self.normalized(sentence);
+        let text = self.whitespace.replace_all(text.trim(), " ");
+        let mut out = String::new();
+        let mut segment = String::new();
+        for c in text.chars() {
+            let opens = matches!(c, '“' | '「' | '『') || c == '"' && !*quoted;
+            let closes = matches!(c, '”' | '」' | '』') || c == '"' && *quoted;
+            if opens && !*quoted {
+                self.push_segment(&mut out, &segment, false, options);
+                segment.clear();
+                segment.push(c);
+                *quoted = true;
+            } else if closes && *quoted {
+                segment.push(c);
+                self.push_segment(&mut out, &segment, true, options);
+                segment.clear();
+                *quoted = false;
+            } else {
+                segment.push(c);
+            }
+        }
+        self.push_segment(&mut out, &segment, *quoted, options);
+        out
+    }
+
+    fn push_segment(&self, out: &mut String, segment: &str, dialogue: bool, options: &SsmlOptions) {
+        if segment.is_empty() {
+            return;
+        }
+        let mut prosody = String::new();
+        if dialogue {
+            for (name, value) in [
+                ("pitch", &options.dialogue_pitch),
+                ("rate", &options.dialogue_rate),
+            ] {
+                if let Some(value) = value {
+                    prosody.push_str(&format!(" {name}=\"{}\"", escape(value)));
+                }
+            }
+        }
+        if !prosody.is_empty() {
+            out.push_str(&format!("<prosody{prosody}>"));
+        }
+        let mut last = 0;
+        for caps in self.numbers.captures_iter(segment) {
+            let whole = caps.get(0).unwrap();
+            out.push_str(&escape(&segment[last..whole.start()]));
+            out.push_str(&say_as(&caps, after_word(segment, &caps)));
+            last = whole.end();
+        }
+        out.push_str(&escape(&segment[last..]));
+        if !prosody.is_empty() {
+            out.push_str("</prosody>");
+        }
+    }
+}
+
+/// Whether the number in `caps` directly follows an ASCII letter or digit, so a
+/// leading `-` is a hyphen, as in `3-5`, rather than a minus sign.
+fn after_word(text: &str, caps: &Captures) -> bool {
+    let start = caps.get(0).map_or(0, |m| m.start());
+    text[..start]
+        .chars()
+        .next_back()
+        .is_some_and(|c| c.is_ascii_alphanumeric())
+}
+
+/// `say-as` markup for a number matched by `Patterns::numbers`.
+fn say_as(caps: &Captures, after_word: bool) -> String {
+    let group = |name: &str| caps.name(name).map_or("", |m| m.as_str());
+    let tag = |kind: &str, text: &str| format!("<say-as interpret-as=\"{kind}\">{text}</say-as>");
+    if caps.name("date").is_some() {
+        format!(
+            "<say-as interpret-as=\"date\" format=\"ymd\">{}-{:0>2}-{:0>2}</say-as>",
+            group("y"),
+            group("mo"),
+            group("d")
+        )
+    } else if caps.name("time").is_some() {
+        format!(
+            "<say-as interpret-as=\"time\" format=\"hms24\">{}:{}</say-as>",
+            group("h"),
+            group("mi")
+        )
+    } else if caps.name("percent").is_some() {
+        format!("{}%", tag("cardinal", group("pn")))
+    } else if caps.name("year").is_some() {
+        format!("{}年", tag("characters", group("yy")))
+    } else if caps.name("fraction").is_some() {
+        tag("fraction", &format!("{}/{}", group("fn"), group("fd")))
+    } else {
+        let (hyphen, number) = match group("number").strip_prefix('-') {
+            Some(rest) if after_word => ("-", rest),
+            _ => ("", group("number")),
+        };
+        let unsigned = number.trim_start_matches('-');
+        let kind = if read_as_amount(unsigned) {
+            "cardinal"
+        } else {
+            "characters"
+        };
+        format!("{hyphen}{}", tag(kind, &number.replace(',', "")))
+    }
+}
+
+/// Whether the integer part of the unsigned `number` is read as an amount
+/// rather than digit by digit.
+fn read_as_amount(number: &str) -> bool {
+    let integer = number.split('.').next().unwrap_or_default();
+    let limit = if integer.contains(',') {
+        MAX_GROUPED_DIGITS
+    } else {
+        MAX_NUMBER_DIGITS
+    };
+    let integer = integer.replace(',', "");
+    integer.len() <= limit && !(integer.len() > 1 && integer.starts_with('0'))
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn is_han(c: char) -> bool {
+    matches!(c, '\u{4E00}'..='\u{9FFF}' | '\u{3400}'..='\u{4DBF}')
+}
+
+fn expand(caps: &Captures, after_word: bool) -> String {
+    let group = |name: &str| caps.name(name).map_or("", |m| m.as_str());
+    if caps.name("date").is_some() {
+        format!(
+            "{}年{}月{}日",
+            digits(group("y")),
+            count(group("mo")),
+            count(group("d"))
+        )
+    } else if caps.name("time").is_some() {
+        match group("mi") {
+            "00" => format!("{}点", count(group("h"))),
+            minutes => format!("{}点{}分", count(group("h")), number(minutes)),
+        }
+    } else if caps.name("percent").is_some() {
+        format!("百分之{}", number(group("pn")))
+    } else if caps.name("year").is_some() {
+        format!("{}年", digits(group("yy")))
+    } else if caps.name("fraction").is_some() {
+        format!("{}分之{}", number(group("fd")), number(group("fn")))
+    } else {
+        match group("number").strip_prefix('-') {
+            Some(rest) if after_word => format!("-{}", number(rest)),
+            Some(rest) => format!("负{}", number(rest)),
+            None => number(group("number")),
+        }
+    }
+}
+
+/// Read a short integer, ignoring leading zeros as in `05`.
+fn count(text: &str) -> String {
+    integer_zh(text.parse().unwrap_or(0))
+}
+
+/// Read each digit separately, as in years and phone numbers.
+fn digits(text: &str) -> String {
+    text.chars()
+        .filter_map(|c| c.to_digit(10))
+        .map(|d| DIGITS[d as usize])
+        .collect()
+}
+
+/// Read a non-negative decimal number, e.g. `10.5` as 十点五, or
+/// `1,000` as 一千. Long integers are read digit by digit.
+fn number(text: &str) -> String {
+    let (integer, fraction) = text.split_once('.').unwrap_or((text, ""));
+    let amount = read_as_amount(integer);
+    let integer = integer.replace(',', "");
+    let mut out = match integer.parse::<u64>() {
+        Ok(n) if amount => integer_zh(n),
+        _ => digits(&integer),
+    };
+    if !fraction.is_empty() {
+        out.push('点');
+        out.push_str(&digits(fraction));
+    }
+    out
+}
+
+fn integer_zh(n: u64) -> String {
+    if n == 0 {
+        return "零".to_string();
+    }
+    const UNITS: [&str; 4] = ["", "十", "百", "千"];
+    const SECTIONS: [&str; 3] = ["", "万", "亿"];
+
+    let mut sections = Vec::new();
+    let mut rest = n;
+    while rest > 0 {
+        sections.push((rest % 10_000) as usize);
+        rest /= 10_000;
+    }
+
+    let mut out = String::new();
+    let mut pending_zero = false;
+    for (index, &section) in sections.iter().enumerate().rev() {
+        if section == 0 {
+            pending_zero = !out.is_empty();
+            continue;
+        }
+        if !out.is_empty() && (pending_zero || section < 1000) {
+            out.push('零');
+        }
+        pending_zero = false;
+        let (mut emitted, mut zero) = (false, false);
+        for position in (0..4).rev() {
+            let digit = section / 10usize.pow(position as u32) % 10;
+            if digit == 0 {
+                zero = emitted && section % 10usize.pow(position as u32) != 0;
+                continue;
+            }
+            if zero {
+                out.push('零');
+                zero = false;
+            }
+            emitted = true;
+            out.push(DIGITS[digit]);
+            out.push_str(UNITS[position]);
+        }
+        out.push_str(SECTIONS[index]);
+    }
+    // 一十五 is read 十五.
+    if out.starts_with("一十") {
+        out.remove(0);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_splits_sentences_with_offsets() {
+        let text = "　　他说：“走吧！”她没有回答。\n\n😀——\n第二段（注：此处有删节）结束";
+        let utterances = prepare_tts(text.to_string());
+        let units: Vec<u16> = text.encode_utf16().collect();
+        let spans: Vec<(String, String)> = utterances
+            .iter()
+            .map(|u| {
+                let original = String::from_utf16(&units[u.start as usize..u.end as usize]);
+                (original.unwrap(), u.text.clone())
+            })
+            .collect();
+        assert_eq!(
+            spans,
+            vec![
+                ("他说：“走吧！”".to_string(), "他说：“走吧！”".to_string()),
+                ("她没有回答。".to_string(), "她没有回答。".to_string()),
+                (
+                    "第二段（注：此处有删节）结束".to_string(),
+                    "第二段结束".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ids_are_stable_and_unique() {
+        let a = prepare_tts("好。好。前言。".to_string());
+        let b = prepare_tts("新增一句。好。好。前言。".to_string());
+        assert_eq!(a.len(), 3);
+        assert_ne!(a[0].id, a[1].id);
+        let ids = |u: &[Utterance]| u.iter().map(|u| u.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&a), ids(&b)[1..]);
+    }
+
+    #[test]
+    fn test_expands_numbers_in_chinese() {
+        let spoken = |text: &str| prepare_tts(text.to_string())[0].text.clone();
+        assert_eq!(
+            spoken("2024-05-01 早上8:30，他跑了10086米。"),
+            "二零二四年五月一日 早上八点三十分，他跑了一万零八十六米。"
+        );
+        assert_eq!(
+            spoken("1998年涨了15.5%，共1,000,200元"),
+            "一九九八年涨了百分之十五点五，共一百万零二百元"
+        );
+        assert_eq!(spoken("第１０５章，编号007"), "第一百零五章，编号零零七");
+        assert_eq!(spoken("Chapter 12 [1]"), "Chapter 12");
+        // Phone numbers are read digit by digit.
+        assert_eq!(spoken("电话13800138000"), "电话一三八零零一三八零零零");
+        assert_eq!(
+            spoken("气温-5度，降了1/2，第3-5章"),
+            "气温负五度，降了二分之一，第三-五章"
+        );
+    }
+
+    #[test]
+    fn test_ssml_marks_numbers_dialogue_and_paragraphs() {
+        let text = "他说：“明天8:30见，带上007号\n文件。”\n\n2024年5月1日，R&D涨了15%。";
+        let utterances = prepare_tts_ssml(text.to_string(), default_ssml_options());
+        let plain = prepare_tts(text.to_string());
+        assert_eq!(utterances.len(), 3);
+        for (ssml, plain) in utterances.iter().zip(&plain) {
+            assert_eq!((&ssml.id, &ssml.text), (&plain.id, &plain.text));
+            assert!(plain.ssml.is_none());
+        }
+        let bodies: Vec<String> = utterances
+            .iter()
+            .map(|u| {
+                let ssml = u.ssml.as_deref().unwrap();
+                assert!(ssml.starts_with("<speak version=\"1.0\""));
+                assert!(ssml.contains("xml:lang=\"zh-CN\""));
+                let body = &ssml[ssml.find('>').unwrap() + 1..];
+                body.strip_suffix("</speak>").unwrap().to_string()
+            })
+            .collect();
+        assert_eq!(
+            bodies,
+            [
+                concat!(
+                    "他说：<prosody pitch=\"+5%\">“明天",
+                    "<say-as interpret-as=\"time\" format=\"hms24\">8:30</say-as>见，带上",
+                    "<say-as interpret-as=\"characters\">007</say-as>号</prosody>",
+                    "<break time=\"600ms\"/>"
+                ),
+                // The line break ended the paragraph, and the quote with it.
+                "文件。”<break time=\"600ms\"/>",
+                concat!(
+                    "<say-as interpret-as=\"date\" format=\"ymd\">2024-05-01</say-as>，",
+                    "R&amp;D涨了<say-as interpret-as=\"cardinal\">15</say-as>%。",
+                    "<break time=\"600ms\"/>"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_integer_reading() {
+        for (n, expected) in [
+            (0, "零"),
+            (10, "十"),
+            (11, "十一"),
+            (101, "一百零一"),
+            (110, "一百一十"),
+            (1001, "一千零一"),
+            (10_000, "一万"),
+            (100_000_001, "一亿零一"),
+            (20_030_000, "二千零三万"),
+        ] {
+            assert_eq!(integer_zh(n), expected, "{n}");
+        }
+    }
+}
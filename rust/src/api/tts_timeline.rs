@@ -0,0 +1,194 @@
+//! Mapping between listening-mode audio time and chapter text.
+//!
+//! `start_tts_timeline` takes the utterances of the chapter being read
+//! aloud and each utterance's duration is recorded as its audio is made:
+//! `synthesize_speech_stream` records the local voice's chunks itself, and
+//! audio from an online engine or the audio cache is recorded with
+//! `record_tts_duration`. Utterances play back to back, so the recorded
+//! durations place every sentence on the playback clock. Within a sentence
+//! the position is interpolated by UTF-16 offset, which follows the pace
+//! of the speech closely enough to land on the right line.
+
+use std::sync::{Mutex, MutexGuard};
+
+use crate::api::tts_prep::Utterance;
+
+static TIMELINE: Mutex<Option<Timeline>> = Mutex::new(None);
+
+/// Where playback is in the chapter text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TtsPosition {
+    pub utterance_id: String,
+    /// UTF-16 range of the sentence being spoken.
+    pub start: u32,
+    pub end: u32,
+    /// Estimated UTF-16 offset being spoken within the sentence.
+    pub offset: u32,
+}
+
+/// Start the timeline of a chapter, forgetting the previous one.
+///
+/// # Arguments
+/// * `utterances` - Utterances in playback order, from `prepare_tts`
+#[flutter_rust_bridge::frb]
+pub fn start_tts_timeline(utterances: Vec<Utterance>) {
+    *lock() = Some(Timeline::new(utterances));
+}
+
+/// Record the duration of an utterance's audio. Utterances skipped in
+/// playback should be recorded with a duration of 0.
+///
+/// # Arguments
+/// * `utterance_id` - ID from `prepare_tts`; unknown IDs are ignored
+/// * `duration_ms` - Length of its audio in milliseconds
+#[flutter_rust_bridge::frb]
+pub fn record_tts_duration(utterance_id: String, duration_ms: u64) {
+    if let Some(timeline) = lock().as_mut() {
+        timeline.record(&utterance_id, duration_ms);
+    }
+}
+
+/// The text being spoken at a playback time, or `None` if it is past the
+/// utterances whose duration is known.
+///
+/// # Arguments
+/// * `time_ms` - Playback time from the start of the chapter's audio
+#[flutter_rust_bridge::frb]
+pub fn position_for_time(time_ms: u64) -> Option<TtsPosition> {
+    lock().as_ref()?.position_for_time(time_ms)
+}
+
+/// The playback time at which the sentence containing `offset` starts, or
+/// the next sentence if `offset` is between sentences. `None` if that
+/// sentence comes after an utterance whose duration is not known yet.
+///
+/// # Arguments
+/// * `offset` - UTF-16 offset in the chapter text, e.g. the reading position
+#[flutter_rust_bridge::frb]
+pub fn time_for_position(offset: u32) -> Option<u64> {
+    lock().as_ref()?.time_for_position(offset)
+}
+
+/// Record the duration of 16-bit mono PCM synthesised for `utterance_id`.
+pub(crate) fn record_pcm(utterance_id: &str, pcm_len: usize, sample_rate: u32) {
+    let samples = (pcm_len / 2) as u64;
+    record_tts_duration(
+        utterance_id.to_string(),
+        samples * 1000 / sample_rate.max(1) as u64,
+    );
+}
+
+fn lock() -> MutexGuard<'static, Option<Timeline>> {
+    TIMELINE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+struct Timeline {
+    utterances: Vec<Utterance>,
+    /// Duration of each utterance, once recorded.
+    durations: Vec<Option<u64>>,
+}
+
+impl Timeline {
+    fn new(utterances: Vec<Utterance>) -> Self {
+        let durations = vec![None; utterances.len()];
+        Self {
+            utterances,
+            durations,
+        }
+    }
+
+    fn record(&mut self, utterance_id: &str, duration_ms: u64) {
+        if let Some(index) = self.utterances.iter().position(|u| u.id == utterance_id) {
+            self.durations[index] = Some(duration_ms);
+        }
+    }
+
+    /// Start times and durations of the utterances, up to the first one
+    /// with an unknown duration.
+    fn starts(&self) -> impl Iterator<Item = (usize, u64, Option<u64>)> + '_ {
+        let mut elapsed = Some(0);
+        self.durations
+            .iter()
+            .enumerate()
+            .map_while(move |(index, &duration)| {
+                let start = elapsed?;
+                elapsed = duration.map(|duration| start + duration);
+                Some((index, start, duration))
+            })
+    }
+
+    fn position_for_time(&self, time_ms: u64) -> Option<TtsPosition> {
+        let (index, start_ms, duration) = self
+            .starts()
+            .filter_map(|(index, start, duration)| Some((index, start, duration?)))
+            .find(|&(_, start, duration)| time_ms < start + duration)?;
+        let utterance = &self.utterances[index];
+        let length = (utterance.end - utterance.start) as u64;
+        let spoken = (length * (time_ms - start_ms) / duration) as u32;
+        Some(TtsPosition {
+            utterance_id: utterance.id.clone(),
+            start: utterance.start,
+            end: utterance.end,
+            offset: utterance.start + spoken,
+        })
+    }
+
+    fn time_for_position(&self, offset: u32) -> Option<u64> {
+        let index = self.utterances.iter().position(|u| offset < u.end)?;
+        self.starts()
+            .find(|&(i, _, _)| i == index)
+            .map(|(_, start, _)| start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timeline() -> Timeline {
+        let utterance = |id: &str, start, end| Utterance {
+            id: id.to_string(),
+            text: String::new(),
+            start,
+            end,
+            ssml: None,
+        };
+        Timeline::new(vec![
+            utterance("a", 0, 10),
+            utterance("b", 12, 22),
+            utterance("c", 23, 30),
+        ])
+    }
+
+    #[test]
+    fn test_position_for_time() {
+        let mut timeline = timeline();
+        timeline.record("a", 2000);
+        timeline.record("c", 500);
+        let position = timeline.position_for_time(1000).unwrap();
+        assert_eq!((position.utterance_id.as_str(), position.offset), ("a", 5));
+        assert_eq!((position.start, position.end), (0, 10));
+        // `b` is not recorded, so nothing after it is placed.
+        assert_eq!(timeline.position_for_time(2000), None);
+
+        timeline.record("b", 1000);
+        timeline.record("unknown", 1000);
+        let position = timeline.position_for_time(2900).unwrap();
+        assert_eq!((position.utterance_id.as_str(), position.offset), ("b", 21));
+        assert_eq!(timeline.position_for_time(3000).unwrap().utterance_id, "c");
+        assert_eq!(timeline.position_for_time(3500), None);
+    }
+
+    #[test]
+    fn test_time_for_position() {
+        let mut timeline = timeline();
+        timeline.record("a", 2000);
+        assert_eq!(timeline.time_for_position(4), Some(0));
+        assert_eq!(timeline.time_for_position(10), Some(2000));
+        assert_eq!(timeline.time_for_position(23), None);
+
+        timeline.record("b", 0);
+        assert_eq!(timeline.time_for_position(25), Some(2000));
+        assert_eq!(timeline.time_for_position(30), None);
+    }
+}
@@ -0,0 +1,511 @@
+//! Plain-text import with chapter detection.
+//!
+//! Chapters are found by matching each short line against heading rules,
+//! such as `第十二章 风起` or `Chapter 12`. The file is scanned line by line
+//! and only chapter offsets are kept, so `index_txt` works on books of any
+//! size; `parse_txt` builds a whole `ParsedBook` and is limited to files of
+//! at most `MAX_PARSE_BYTES`.
+//!
+//! The encoding is detected from the first `SAMPLE_BYTES` of the file with
+//! `encoding::detect`.
+
+use std::fs::File;
+use std::io::{BufRead, BufReader, Cursor, Read, Seek, SeekFrom};
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use encoding_rs::{Encoding, UTF_16BE, UTF_16LE, UTF_8};
+use quick_xml::escape::escape;
+use regex::Regex;
+
+use crate::api::book::{self, BookChapter, BookMetadata, ParsedBook, TocEntry};
+use crate::api::error::ApiError;
+use crate::api::{encoding, langdetect, task};
+use crate::user_regex;
+
+/// Files larger than this are only imported through `index_txt`.
+pub(crate) const MAX_PARSE_BYTES: u64 = 50 * 1024 * 1024;
+
+/// Bytes sampled from the start of the file for encoding detection.
+pub(crate) const SAMPLE_BYTES: usize = 64 * 1024;
+
+/// Lines longer than this (in characters) are never headings.
+pub(crate) const MAX_HEADING_CHARS: usize = 40;
+
+/// Part size used when no heading rule matches anywhere in the file.
+pub(crate) const FALLBACK_CHAPTER_BYTES: u64 = 100 * 1024;
+
+/// A chapter heading pattern, matched against each trimmed line.
+#[derive(Debug, Clone)]
+pub struct TxtChapterRule {
+    pub name: String,
+    /// Regular expression in Rust `regex` syntax.
+    pub pattern: String,
+}
+
+/// Byte range of one chapter in the source file.
+#[derive(Debug, Clone)]
+pub struct TxtChapter {
+    /// Heading line, or `None` for text before the first heading and for
+    /// fixed-size parts of files without headings.
+    pub title: Option<String>,
+    pub start: u32,
+    pub end: u32,
+}
+
+/// Chapter offsets of a text file.
+#[derive(Debug, Clone)]
+pub struct TxtIndex {
+    /// Detected encoding label, e.g. `UTF-8` or `GBK`.
+    pub encoding: String,
+    pub file_size: u32,
+    pub chapters: Vec<TxtChapter>,
+}
+
+/// The built-in heading rules used when no custom rules are given.
+#[flutter_rust_bridge::frb]
+pub fn default_txt_rules() -> Vec<TxtChapterRule> {
+    let rule = |name: &str, pattern: &str| TxtChapterRule {
+        name: name.to_string(),
+        pattern: pattern.to_string(),
+    };
+    vec![
+        rule(
+            "第X章/回/节/卷",
+            r"^第[0-9０-９零〇一二两三四五六七八九十百千万壹贰叁肆伍陆柒捌玖拾佰仟]+[章回节節卷集部篇](?:[\s：:、.．·—-].*)?$",
+        ),
+        rule(
+            "序章/楔子/番外",
+            r"^(?:序章|序言|序|楔子|引子|前言|后记|後記|尾声|尾聲|终章|番外.*)(?:[\s：:、].*)?$",
+        ),
+        rule(
+            "Chapter N",
+            r"(?i)^(?:chapter|part|book)\s+(?:\d+|[ivxlcdm]+)\b(?:[\s.:：-].*)?$",
+        ),
+        rule(
+            "Prologue/Epilogue",
+            r"(?i)^(?:prologue|epilogue)(?:[\s.:：-].*)?$",
+        ),
+    ]
+}
+
+/// Scan a text file for chapter headings without loading it into memory.
+///
+/// # Arguments
+/// * `path` - Path to the text file
+/// * `rules` - Heading rules; `None` uses `default_txt_rules()`
+#[flutter_rust_bridge::frb]
+pub fn index_txt(path: String, rules: Option<Vec<TxtChapterRule>>) -> Result<TxtIndex, ApiError> {
+    let rules = compile_rules(rules)?;
+    let file = File::open(&path).map_err(|e| anyhow!("Failed to open {path}: {e}"))?;
+    Ok(scan(BufReader::with_capacity(SAMPLE_BYTES, file), &rules)?)
+}
+
+/// Read one chapter of an indexed file as HTML.
+///
+/// # Arguments
+/// * `path` - Path to the text file
+/// * `chapter` - Chapter from `index_txt` for the same file
+#[flutter_rust_bridge::frb]
+pub fn read_txt_chapter(path: String, chapter: TxtChapter) -> Result<String, ApiError> {
+    let mut file = File::open(&path).map_err(|e| anyhow!("Failed to open {path}: {e}"))?;
+    let encoding = detect_encoding(&read_sample(&mut file)?).0;
+
+    let len = chapter.end.saturating_sub(chapter.start) as usize;
+    let mut bytes = vec![0; len];
+    file.seek(SeekFrom::Start(chapter.start as u64))
+        .and_then(|_| file.read_exact(&mut bytes))
+        .map_err(|e| anyhow!("Failed to read {path}: {e}"))?;
+    Ok(chapter_html(
+        &decode(encoding, &bytes),
+        chapter.title.is_some(),
+    ))
+}
+
+/// Parse a whole text file into chapters, using the file name as the title.
+///
+/// # Arguments
+/// * `path` - Path to the text file, at most 50 MB
+/// * `rules` - Heading rules; `None` uses `default_txt_rules()`
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+#[flutter_rust_bridge::frb]
+pub async fn parse_txt(
+    path: String,
+    rules: Option<Vec<TxtChapterRule>>,
+    cancel_token: Option<u32>,
+) -> Result<ParsedBook, ApiError> {
+    task::run_blocking(task::cancel_token(cancel_token)?, move |_| {
+        parse_file(&path, rules)
+    })
+    .await
+}
+
+fn parse_file(path: &str, rules: Option<Vec<TxtChapterRule>>) -> Result<ParsedBook> {
+    let rules = compile_rules(rules)?;
+    let size = std::fs::metadata(path)
+        .map_err(|e| anyhow!("Failed to open {path}: {e}"))?
+        .len();
+    if size > MAX_PARSE_BYTES {
+        return Err(anyhow!(
+            "{path} is too large to import at once; use index_txt"
+        ));
+    }
+    let data = std::fs::read(path).map_err(|e| anyhow!("Failed to read {path}: {e}"))?;
+
+    let title = Path::new(path)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned());
+    parse(&data, title, &rules)
+}
+
+pub(crate) fn compile_rules(rules: Option<Vec<TxtChapterRule>>) -> Result<Vec<Regex>> {
+    rules
+        .unwrap_or_else(default_txt_rules)
+        .iter()
+        .map(|rule| {
+            user_regex::compile(&rule.pattern, false)
+                .map_err(|e| anyhow!("Invalid chapter rule {}: {e}", rule.name))
+        })
+        .collect()
+}
+
+/// Return the heading text if `line` is a chapter heading.
+pub(crate) fn heading<'a>(line: &'a str, rules: &[Regex]) -> Option<&'a str> {
+    let line = line.trim();
+    if line.is_empty() || line.chars().count() > MAX_HEADING_CHARS {
+        return None;
+    }
+    rules.iter().any(|rule| rule.is_match(line)).then_some(line)
+}
+
+pub(crate) fn parse(data: &[u8], title: Option<String>, rules: &[Regex]) -> Result<ParsedBook> {
+    let index = scan(Cursor::new(data), rules)?;
+    let encoding = Encoding::for_label(index.encoding.as_bytes()).unwrap_or(UTF_8);
+
+    let mut chapters = Vec::new();
+    let mut toc = Vec::new();
+    let mut sample = langdetect::Sample::default();
+    for (i, chapter) in index.chapters.into_iter().enumerate() {
+        let bytes = &data[chapter.start as usize..chapter.end as usize];
+        let text = decode(encoding, bytes);
+        sample.add(&text);
+        let html = chapter_html(&text, chapter.title.is_some());
+        if let Some(title) = &chapter.title {
+            toc.push(TocEntry {
+                title: title.clone(),
+                href: book::part_href(i),
+                chapter_index: Some(i as u32),
+                depth: 0,
+            });
+        }
+        chapters.push(BookChapter {
+            index: i as u32,
+            href: book::part_href(i),
+            title: chapter.title,
+            html,
+            images: Vec::new(),
+        });
+    }
+
+    let mut metadata = BookMetadata::empty();
+    metadata.title = title;
+    metadata.language = langdetect::book_language(&sample);
+    Ok(ParsedBook {
+        metadata,
+        chapters,
+        toc,
+    })
+}
+
+/// Split a file into chapters in a single pass over its lines.
+pub(crate) fn scan(mut reader: impl BufRead, rules: &[Regex]) -> Result<TxtIndex> {
+    let sample = reader
+        .fill_buf()
+        .map_err(|e| anyhow!("Failed to read text: {e}"))?;
+    let (encoding, bom_len) = detect_encoding(sample);
+    reader.consume(bom_len);
+
+    let mut chapters: Vec<TxtChapter> = Vec::new();
+    // Line starts roughly every FALLBACK_CHAPTER_BYTES, in case nothing
+    // matches a rule.
+    let mut parts = vec![bom_len as u64];
+    let mut offset = bom_len as u64;
+    let mut preface = false;
+    let mut line = Vec::new();
+
+    loop {
+        line.clear();
+        let len = read_line(&mut reader, encoding, &mut line)
+            .map_err(|e| anyhow!("Failed to read text: {e}"))?;
+        if len == 0 {
+            break;
+        }
+        let text = decode(encoding, &line);
+        if let Some(title) = heading(&text, rules) {
+            if chapters.is_empty() && preface {
+                chapters.push(TxtChapter {
+                    title: None,
+                    start: bom_len as u32,
+                    end: 0,
+                });
+            }
+            chapters.push(TxtChapter {
+                title: Some(title.to_string()),
+                start: offset as u32,
+                end: 0,
+            });
+        } else if chapters.is_empty() && !text.trim().is_empty() {
+            preface = true;
+        }
+        if offset - parts[parts.len() - 1] >= FALLBACK_CHAPTER_BYTES {
+            parts.push(offset);
+        }
+        offset += len as u64;
+        if offset > u32::MAX as u64 {
+            return Err(anyhow!("Text files over 4 GiB are not supported"));
+        }
+    }
+
+    if chapters.is_empty() {
+        chapters = parts
+            .into_iter()
+            .map(|start| TxtChapter {
+                title: None,
+                start: start as u32,
+                end: 0,
+            })
+            .collect();
+    }
+    let starts: Vec<u32> = chapters.iter().skip(1).map(|c| c.start).collect();
+    for (chapter, end) in chapters
+        .iter_mut()
+        .zip(starts.into_iter().chain([offset as u32]))
+    {
+        chapter.end = end;
+    }
+
+    Ok(TxtIndex {
+        encoding: encoding.name().to_string(),
+        file_size: offset as u32,
+        chapters,
+    })
+}
+
+pub(crate) fn read_sample(file: &mut File) -> Result<Vec<u8>> {
+    let mut sample = Vec::with_capacity(SAMPLE_BYTES);
+    file.by_ref()
+        .take(SAMPLE_BYTES as u64)
+        .read_to_end(&mut sample)
+        .map_err(|e| anyhow!("Failed to read text: {e}"))?;
+    Ok(sample)
+}
+
+/// Pick the encoding of a file from its first bytes; also returns the BOM
+/// length.
+pub(crate) fn detect_encoding(sample: &[u8]) -> (&'static Encoding, usize) {
+    let detection = encoding::detect(sample, sample.len() < SAMPLE_BYTES);
+    (detection.encoding, detection.bom_len)
+}
+
+/// Read one line including its terminator; returns the number of bytes
+/// read, 0 at end of input.
+pub(crate) fn read_line(
+    reader: &mut impl BufRead,
+    encoding: &Encoding,
+    line: &mut Vec<u8>,
+) -> std::io::Result<usize> {
+    // `\n` never occurs inside a multi-byte character of the other
+    // detectable encodings.
+    if encoding != UTF_16LE && encoding != UTF_16BE {
+        return reader.read_until(b'\n', line);
+    }
+
+    // In UTF-16 a 0x0A byte only ends the line if it is half of a `\n` unit.
+    let little_endian = encoding == UTF_16LE;
+    loop {
+        if reader.read_until(b'\n', line)? == 0 || line.last() != Some(&b'\n') {
+            break;
+        }
+        let pos = line.len() - 1;
+        if little_endian && pos.is_multiple_of(2) {
+            let mut high = [0; 1];
+            if reader.read(&mut high)? == 0 {
+                break;
+            }
+            line.push(high[0]);
+            if high[0] == 0 {
+                break;
+            }
+        } else if !little_endian && pos % 2 == 1 && line[pos - 1] == 0 {
+            break;
+        }
+    }
+    Ok(line.len())
+}
+
+pub(crate) fn decode(encoding: &'static Encoding, bytes: &[u8]) -> String {
+    encoding.decode_without_bom_handling(bytes).0.into_owned()
+}
+
+/// Convert chapter text to HTML: one paragraph per non-blank line, with the
+/// heading line as `h2`.
+pub(crate) fn chapter_html(text: &str, has_heading: bool) -> String {
+    let mut html = String::new();
+    let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+    if has_heading {
+        if let Some(heading) = lines.next() {
+            html.push_str(&format!("<h2>{}</h2>", escape(heading)));
+        }
+    }
+    for line in lines {
+        html.push_str(&format!("<p>{}</p>", escape(line)));
+    }
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules() -> Vec<Regex> {
+        compile_rules(None).unwrap()
+    }
+
+    fn titles(index: &TxtIndex) -> Vec<Option<&str>> {
+        index.chapters.iter().map(|c| c.title.as_deref()).collect()
+    }
+
+    #[test]
+    fn test_default_rules_match_common_headings() {
+        let rules = rules();
+        for line in [
+            "第一章 风起",
+            "　　第12章：重逢",
+            "第三百二十一回",
+            "第二卷 天下",
+            "楔子",
+            "番外一 旧事",
+            "Chapter 7",
+            "CHAPTER IV. The Storm",
+            "Prologue",
+        ] {
+            assert!(heading(line, &rules).is_some(), "{line}");
+        }
+        for line in [
+            "他说第一章写得不好，然后离开了。",
+            "第一章节的内容和后面的章节之间隔了很长一段时间，没有人知道为什么",
+            "Chapters are hard.",
+            "",
+        ] {
+            assert!(heading(line, &rules).is_none(), "{line}");
+        }
+    }
+
+    #[test]
+    fn test_index_splits_at_headings() {
+        let text = "书名\n作者\n\n第一章 开始\n正文一。\n\n第二章 继续\n正文二。\n";
+        let index = scan(Cursor::new(text.as_bytes()), &rules()).unwrap();
+
+        assert_eq!(index.encoding, "UTF-8");
+        assert_eq!(
+            titles(&index),
+            vec![None, Some("第一章 开始"), Some("第二章 继续")]
+        );
+        let ranges: Vec<_> = index
+            .chapters
+            .iter()
+            .map(|c| &text.as_bytes()[c.start as usize..c.end as usize])
+            .collect();
+        assert_eq!(ranges[0], "书名\n作者\n\n".as_bytes());
+        assert_eq!(ranges[2], "第二章 继续\n正文二。\n".as_bytes());
+        assert_eq!(index.file_size as usize, text.len());
+    }
+
+    #[test]
+    fn test_index_detects_gbk_and_utf16() {
+        let text = "第一章 开始\r\n正文。\r\n第二章 结束\r\n";
+        let (gbk, _, _) = encoding_rs::GBK.encode(text);
+        let index = scan(Cursor::new(gbk.as_ref()), &rules()).unwrap();
+        assert_eq!(index.encoding, "GBK");
+        assert_eq!(
+            titles(&index),
+            vec![Some("第一章 开始"), Some("第二章 结束")]
+        );
+
+        let mut utf16 = vec![0xFF, 0xFE];
+        for unit in text.encode_utf16() {
+            utf16.extend_from_slice(&unit.to_le_bytes());
+        }
+        let index = scan(Cursor::new(utf16.as_slice()), &rules()).unwrap();
+        assert_eq!(index.encoding, "UTF-16LE");
+        assert_eq!(
+            titles(&index),
+            vec![Some("第一章 开始"), Some("第二章 结束")]
+        );
+        assert_eq!(index.chapters[0].start, 2);
+        let second = &utf16[index.chapters[1].start as usize..];
+        assert_eq!(decode(UTF_16LE, second), "第二章 结束\r\n");
+    }
+
+    #[test]
+    fn test_custom_rules_replace_defaults() {
+        let rules = compile_rules(Some(vec![TxtChapterRule {
+            name: "numbered".to_string(),
+            pattern: r"^\d+\.$".to_string(),
+        }]))
+        .unwrap();
+        let text = "1.\nfirst\n第一章 not a heading here\n2.\nsecond\n";
+        let index = scan(Cursor::new(text.as_bytes()), &rules).unwrap();
+        assert_eq!(titles(&index), vec![Some("1."), Some("2.")]);
+
+        let invalid = compile_rules(Some(vec![TxtChapterRule {
+            name: "broken".to_string(),
+            pattern: "(".to_string(),
+        }]));
+        assert!(invalid.is_err());
+    }
+
+    #[test]
+    fn test_index_without_headings_uses_fixed_parts() {
+        let line = "没有章节标题的一行文字。\n";
+        let text = line.repeat(FALLBACK_CHAPTER_BYTES as usize / line.len() * 5 / 2);
+        let index = scan(Cursor::new(text.as_bytes()), &rules()).unwrap();
+
+        assert_eq!(titles(&index), vec![None, None, None]);
+        assert_eq!(index.chapters[0].start, 0);
+        assert_eq!(index.chapters[2].end as usize, text.len());
+        for chapter in &index.chapters {
+            assert_eq!(chapter.start as usize % line.len(), 0);
+        }
+    }
+
+    #[test]
+    fn test_parse_builds_chapters_and_toc() {
+        let text = "\n第一章 开始\n  正文 <一>。\n\n第二章 结束\n正文二。";
+        let book = parse(text.as_bytes(), Some("书".to_string()), &rules()).unwrap();
+
+        assert_eq!(book.metadata.title.as_deref(), Some("书"));
+        let chapters: Vec<_> = book
+            .chapters
+            .iter()
+            .map(|c| (c.title.as_deref(), c.href.as_str(), c.html.as_str()))
+            .collect();
+        assert_eq!(
+            chapters,
+            vec![
+                (
+                    Some("第一章 开始"),
+                    "part0000.html",
+                    "<h2>第一章 开始</h2><p>正文 &lt;一&gt;。</p>"
+                ),
+                (
+                    Some("第二章 结束"),
+                    "part0001.html",
+                    "<h2>第二章 结束</h2><p>正文二。</p>"
+                ),
+            ]
+        );
+        assert_eq!(book.toc.len(), 2);
+        assert_eq!(book.toc[1].chapter_index, Some(1));
+    }
+}
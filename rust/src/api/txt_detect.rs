@@ -0,0 +1,516 @@
+//! Scored chapter heading detection for text files the rules miss.
+//!
+//! Books from the web often number chapters their own way, such as
+//! `001峥嵘初露` or `【第二幕】`, and the fixed rules in `txt` either miss
+//! them or split at the wrong lines. Here every short line that looks like a
+//! label (a number with an optional `第`, unit or brackets) or a keyword such
+//! as `楔子` is scored on its length, punctuation and the blank lines around
+//! it, then on how it fits with the lines of the same shape: labels whose
+//! numbers count up one by one are almost certainly the chapter list, and
+//! labels with no text between them are a table of contents. The user confirms
+//! the proposal before `index_txt_at_headings` splits the file.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+use anyhow::{anyhow, Result};
+use regex::Regex;
+
+use crate::api::chapter_sort;
+use crate::api::error::ApiError;
+use crate::api::txt::{self, TxtChapter, TxtIndex};
+
+/// Lines scoring below this are not proposed at all.
+const MIN_CONFIDENCE: f32 = 0.25;
+
+/// Lines scoring at least this are selected in the proposal.
+const SELECT_CONFIDENCE: f32 = 0.5;
+
+/// How many later lines of the same shape are searched for the next
+/// number.
+const SEQUENCE_LOOKAHEAD: usize = 3;
+
+/// Characters that end a sentence rather than a heading.
+const SENTENCE_ENDS: &[char] = &[
+    '。', '，', ',', '！', '!', '？', '?', '；', ';', '…', '”', '"', '’',
+];
+
+/// A line that may be a chapter heading.
+#[derive(Debug, Clone)]
+pub struct TxtHeadingCandidate {
+    pub title: String,
+    /// Byte offset of the line in the file.
+    pub start: u32,
+    /// Zero-based line number.
+    pub line: u32,
+    /// How likely the line is a heading, from 0 to 1.
+    pub confidence: f32,
+    /// Whether the line is part of the proposed split.
+    pub selected: bool,
+}
+
+/// Proposed chapter split of a text file.
+#[derive(Debug, Clone)]
+pub struct TxtChapterProposal {
+    /// Detected encoding label, e.g. `UTF-8` or `GBK`.
+    pub encoding: String,
+    pub file_size: u32,
+    /// Candidates in file order.
+    pub headings: Vec<TxtHeadingCandidate>,
+}
+
+/// Score every line of a text file as a possible chapter heading.
+///
+/// # Arguments
+/// * `path` - Path to the text file
+#[flutter_rust_bridge::frb]
+pub fn detect_txt_chapters(path: String) -> Result<TxtChapterProposal, ApiError> {
+    let file = File::open(&path).map_err(|e| anyhow!("Failed to open {path}: {e}"))?;
+    Ok(detect(BufReader::with_capacity(txt::SAMPLE_BYTES, file))?)
+}
+
+/// Split a text file at confirmed headings, as `index_txt` would at rule
+/// matches.
+///
+/// # Arguments
+/// * `path` - Path to the text file
+/// * `headings` - Headings to split at, usually the selected candidates
+///   from `detect_txt_chapters` for the same file
+#[flutter_rust_bridge::frb]
+pub fn index_txt_at_headings(
+    path: String,
+    headings: Vec<TxtHeadingCandidate>,
+) -> Result<TxtIndex, ApiError> {
+    let mut file = File::open(&path).map_err(|e| anyhow!("Failed to open {path}: {e}"))?;
+    let sample = txt::read_sample(&mut file)?;
+    let (encoding, bom_len) = txt::detect_encoding(&sample);
+    let file_size = file
+        .metadata()
+        .map_err(|e| anyhow!("Failed to open {path}: {e}"))?
+        .len();
+    if file_size > u32::MAX as u64 {
+        return Err(anyhow!("Text files over 4 GiB are not supported").into());
+    }
+    let chapters = split(&sample, bom_len as u32, file_size as u32, headings);
+    if chapters.is_empty() {
+        return Err(anyhow!("No headings to split {path} at").into());
+    }
+    Ok(TxtIndex {
+        encoding: encoding.name().to_string(),
+        file_size: file_size as u32,
+        chapters,
+    })
+}
+
+/// Chapters starting at `headings`, with the text before the first one as
+/// an untitled chapter unless it is blank.
+fn split(
+    sample: &[u8],
+    bom_len: u32,
+    file_size: u32,
+    mut headings: Vec<TxtHeadingCandidate>,
+) -> Vec<TxtChapter> {
+    headings.retain(|heading| heading.start >= bom_len && heading.start < file_size);
+    headings.sort_by_key(|heading| heading.start);
+    headings.dedup_by_key(|heading| heading.start);
+    let Some(first) = headings.first() else {
+        return Vec::new();
+    };
+
+    let mut chapters = Vec::new();
+    let preface = match sample.get(bom_len as usize..first.start as usize) {
+        Some(bytes) => {
+            let encoding = txt::detect_encoding(sample).0;
+            !txt::decode(encoding, bytes).trim().is_empty()
+        }
+        // Longer than the sample, so surely not blank.
+        None => true,
+    };
+    if preface {
+        chapters.push(TxtChapter {
+            title: None,
+            start: bom_len,
+            end: first.start,
+        });
+    }
+    let ends: Vec<u32> = headings
+        .iter()
+        .skip(1)
+        .map(|heading| heading.start)
+        .chain([file_size])
+        .collect();
+    for (heading, end) in headings.into_iter().zip(ends) {
+        chapters.push(TxtChapter {
+            title: Some(heading.title),
+            start: heading.start,
+            end,
+        });
+    }
+    chapters
+}
+
+/// A candidate line before scoring.
+struct Line {
+    title: String,
+    start: u32,
+    line: u32,
+    /// Non-blank lines before this one.
+    text_before: u32,
+    prev_blank: bool,
+    next_blank: bool,
+    shape: Shape,
+}
+
+/// What a candidate looks like, for grouping lines of the same kind.
+struct Shape {
+    /// The line with the number and title text abstracted away, e.g.
+    /// `第c章 *` for `第三章 风起` or `d*` for `001峥嵘初露`.
+    signature: String,
+    number: Option<u64>,
+    /// Score from the form of the label alone.
+    base: f32,
+}
+
+fn detect(mut reader: impl BufRead) -> Result<TxtChapterProposal> {
+    let sample = reader
+        .fill_buf()
+        .map_err(|e| anyhow!("Failed to read text: {e}"))?;
+    let (encoding, bom_len) = txt::detect_encoding(sample);
+    reader.consume(bom_len);
+
+    let patterns = Patterns::new();
+    let mut lines: Vec<Line> = Vec::new();
+    let mut offset = bom_len as u64;
+    let mut number = 0u32;
+    let mut text_lines = 0u32;
+    let mut prev_blank = true;
+    let mut bytes = Vec::new();
+    loop {
+        bytes.clear();
+        let len = txt::read_line(&mut reader, encoding, &mut bytes)
+            .map_err(|e| anyhow!("Failed to read text: {e}"))?;
+        if len == 0 {
+            break;
+        }
+        let text = txt::decode(encoding, &bytes);
+        let text = text.trim();
+        if let Some(last) = lines.last_mut().filter(|last| last.line + 1 == number) {
+            last.next_blank = text.is_empty();
+        }
+        if let Some(shape) = patterns.shape(text) {
+            lines.push(Line {
+                title: text.to_string(),
+                start: offset as u32,
+                line: number,
+                text_before: text_lines,
+                prev_blank,
+                next_blank: true,
+                shape,
+            });
+        }
+        prev_blank = text.is_empty();
+        if !prev_blank {
+            text_lines += 1;
+        }
+        offset += len as u64;
+        number += 1;
+        if offset > u32::MAX as u64 {
+            return Err(anyhow!("Text files over 4 GiB are not supported"));
+        }
+    }
+
+    let headings = score(&lines)
+        .into_iter()
+        .zip(lines)
+        .filter(|(confidence, _)| *confidence >= MIN_CONFIDENCE)
+        .map(|(confidence, line)| TxtHeadingCandidate {
+            title: line.title,
+            start: line.start,
+            line: line.line,
+            confidence,
+            selected: confidence >= SELECT_CONFIDENCE,
+        })
+        .collect();
+    Ok(TxtChapterProposal {
+        encoding: encoding.name().to_string(),
+        file_size: offset as u32,
+        headings,
+    })
+}
+
+/// Confidence of each line, from its own features and those of the other
+/// lines with the same signature.
+fn score(lines: &[Line]) -> Vec<f32> {
+    let mut clusters: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (i, line) in lines.iter().enumerate() {
+        clusters.entry(&line.shape.signature).or_default().push(i);
+    }
+    let mut in_sequence = vec![false; lines.len()];
+    let mut empty = vec![false; lines.len()];
+    let mut size = vec![0; lines.len()];
+    for members in clusters.values() {
+        for (k, &i) in members.iter().enumerate() {
+            // The next number within the following few members, so a
+            // stray line of the same shape does not break the run.
+            if let Some(number) = lines[i].shape.number {
+                let next = members[k + 1..].iter().take(SEQUENCE_LOOKAHEAD);
+                if let Some(&j) = next
+                    .into_iter()
+                    .find(|&&j| lines[j].shape.number == Some(number + 1))
+                {
+                    in_sequence[i] = true;
+                    in_sequence[j] = true;
+                }
+            }
+            // Nothing but blank lines before the next heading: a table of
+            // contents entry.
+            if let Some(&j) = members.get(k + 1) {
+                if lines[j].text_before == lines[i].text_before + 1 {
+                    empty[i] = true;
+                }
+            }
+        }
+        for &i in members {
+            size[i] = members.len();
+        }
+    }
+
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let mut score = line.shape.base;
+            match line.title.chars().count() {
+                0..=20 => score += 0.1,
+                21..=30 => {}
+                _ => score -= 0.15,
+            }
+            if line.prev_blank {
+                score += 0.1;
+            }
+            if line.next_blank {
+                score += 0.05;
+            }
+            if line.title.ends_with(SENTENCE_ENDS) {
+                score -= 0.3;
+            } else if line.title.contains(['，', '。', '！', '？']) {
+                score -= 0.15;
+            }
+            if in_sequence[i] {
+                score += 0.3;
+            }
+            if size[i] >= 3 {
+                score += 0.1 * size[i].min(10) as f32 / 10.0;
+            } else if size[i] == 1 && line.shape.number.is_some() {
+                score -= 0.1;
+            }
+            if empty[i] {
+                score -= 0.5;
+            }
+            score.clamp(0.0, 1.0)
+        })
+        .collect()
+}
+
+struct Patterns {
+    label: Regex,
+    keyword: Regex,
+}
+
+impl Patterns {
+    fn new() -> Self {
+        Patterns {
+            label: Regex::new(concat!(
+                r"(?i)^([【\[〔「『(（]?)\s*(第|chapter|ch\.?|part)?\s*",
+                r"([0-9]+|[０-９]+|[ivxlcdm]+|[零〇一二两三四五六七八九十百千万]+)\s*",
+                r"([章回节節卷集部篇幕话話]?)\s*([】\]〕」』)）]?)([\s:：、.．·—-]*)(.*)$",
+            ))
+            .unwrap(),
+            keyword: Regex::new(concat!(
+                r"(?i)^(?:(?:序章|序言|序|楔子|引子|前言|后记|後記|尾声|尾聲|终章|",
+                r"完本感言|prologue|epilogue)(?:[\s：:、.·—-].*)?|番外.*)$",
+            ))
+            .unwrap(),
+        }
+    }
+
+    fn shape(&self, line: &str) -> Option<Shape> {
+        if line.is_empty() || line.chars().count() > txt::MAX_HEADING_CHARS {
+            return None;
+        }
+        if self.keyword.is_match(line) {
+            return Some(Shape {
+                signature: "keyword".to_string(),
+                number: None,
+                base: 0.4,
+            });
+        }
+        let captures = self.label.captures(line)?;
+        let part = |i| captures.get(i).map_or("", |m| m.as_str());
+        let (open, prefix, digits, unit, close, separator, rest) = (
+            part(1),
+            part(2),
+            part(3),
+            part(4),
+            part(5),
+            part(6),
+            part(7),
+        );
+        let prefix = prefix.to_lowercase();
+        let numbered_word = !prefix.is_empty() && prefix != "第";
+        let (kind, number) = match digits.chars().next()? {
+            '0'..='9' => ("d", digits.parse().ok()),
+            '０'..='９' => ("d", fullwidth_number(digits)),
+            c if c.is_ascii_alphabetic() => {
+                // Roman numerals only after `Chapter` and the like, and not
+                // as the start of a word.
+                if !numbered_word || (separator.is_empty() && !rest.is_empty()) {
+                    return None;
+                }
+                ("r", roman_number(digits))
+            }
+            _ => ("c", chapter_sort::chinese_number(digits)),
+        };
+        let framed = !prefix.is_empty() || !unit.is_empty();
+        let bracketed = !open.is_empty() && !close.is_empty();
+        // `一个人` starts with a numeral but is not a label.
+        if kind == "c" && !framed && !bracketed && separator.is_empty() && !rest.is_empty() {
+            return None;
+        }
+        let base = if (prefix == "第" && !unit.is_empty()) || numbered_word {
+            0.45
+        } else if bracketed {
+            0.3
+        } else {
+            0.15
+        };
+        let signature = format!(
+            "{open}{prefix}{kind}{unit}{close}{}{}",
+            if separator.is_empty() { "" } else { " " },
+            if rest.is_empty() { "" } else { "*" },
+        );
+        Some(Shape {
+            signature,
+            number,
+            base,
+        })
+    }
+}
+
+fn fullwidth_number(digits: &str) -> Option<u64> {
+    digits.chars().try_fold(0u64, |n, c| {
+        let digit = (c as u32).checked_sub('０' as u32)?;
+        n.checked_mul(10)?.checked_add(digit as u64)
+    })
+}
+
+fn roman_number(text: &str) -> Option<u64> {
+    let (mut total, mut largest) = (0u64, 0u64);
+    for c in text.chars().rev() {
+        let value = match c.to_ascii_lowercase() {
+            'i' => 1,
+            'v' => 5,
+            'x' => 10,
+            'l' => 50,
+            'c' => 100,
+            'd' => 500,
+            'm' => 1000,
+            _ => return None,
+        };
+        if value < largest {
+            total = total.checked_sub(value)?;
+        } else {
+            total += value;
+            largest = value;
+        }
+    }
+    Some(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn selected(proposal: &TxtChapterProposal) -> Vec<&str> {
+        proposal
+            .headings
+            .iter()
+            .filter(|heading| heading.selected)
+            .map(|heading| heading.title.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_detects_inconsistent_headings() {
+        let text = "作者：某人\n\
+                    001峥嵘初露\n他走进城门。\n2024年的冬天很冷。\n\
+                    002风起云涌\n一个人站在街口。\n\
+                    003尘埃落定\n结束了。\n\n\
+                    【第一幕】\n\n台上有人。\n\n【第二幕】\n\n幕布落下。\n\n\
+                    番外 旧事\n很久以前。\n";
+        let proposal = detect(Cursor::new(text.as_bytes())).unwrap();
+        assert_eq!(
+            selected(&proposal),
+            [
+                "001峥嵘初露",
+                "002风起云涌",
+                "003尘埃落定",
+                "【第一幕】",
+                "【第二幕】",
+                "番外 旧事"
+            ]
+        );
+        assert!(proposal
+            .headings
+            .iter()
+            .all(|h| !h.title.starts_with("2024")));
+        let first = &proposal.headings[0];
+        assert_eq!(first.line, 1);
+        assert_eq!(
+            &text[first.start as usize..][..first.title.len()],
+            first.title
+        );
+    }
+
+    #[test]
+    fn test_table_of_contents_is_not_selected() {
+        let mut text = String::from("目录\n第一章 开始\n第二章 继续\n第三章 结束\n\n");
+        for title in ["第一章 开始", "第二章 继续", "第三章 结束"] {
+            text.push_str(&format!("{title}\n\n正文。\n\n"));
+        }
+        text.push_str("Chapter IV. The End\n\nDone.\n");
+        let proposal = detect(Cursor::new(text.as_bytes())).unwrap();
+        let lines: Vec<u32> = proposal
+            .headings
+            .iter()
+            .filter(|heading| heading.selected)
+            .map(|heading| heading.line)
+            .collect();
+        assert_eq!(lines, [5, 9, 13, 17]);
+        assert_eq!(roman_number("IV"), Some(4));
+        assert_eq!(fullwidth_number("１２"), Some(12));
+    }
+
+    #[test]
+    fn test_split_at_confirmed_headings() {
+        let text = "简介\n001甲\n正文\n002乙\n正文\n";
+        let proposal = detect(Cursor::new(text.as_bytes())).unwrap();
+        let headings = proposal.headings.into_iter().rev().collect();
+        let chapters = split(text.as_bytes(), 0, text.len() as u32, headings);
+        let ranges: Vec<_> = chapters
+            .iter()
+            .map(|c| (c.title.as_deref(), &text[c.start as usize..c.end as usize]))
+            .collect();
+        assert_eq!(
+            ranges,
+            [
+                (None, "简介\n"),
+                (Some("001甲"), "001甲\n正文\n"),
+                (Some("002乙"), "002乙\n正文\n"),
+            ]
+        );
+        assert!(split(text.as_bytes(), 0, 10, Vec::new()).is_empty());
+    }
+}
@@ -0,0 +1,200 @@
+//! Typographic clean-up of chapter text before pagination.
+//!
+//! Each pass can be switched off. Punctuation is only made full-width next
+//! to CJK text, so English passages and numbers like `3.5` are left alone.
+//! Line endings are normalized to `\n`.
+
+use regex::Regex;
+
+const INDENT: &str = "\u{3000}\u{3000}";
+
+#[derive(Debug, Clone)]
+pub struct TypographyOptions {
+    /// Convert `,.?!:;()` next to CJK text to their full-width forms.
+    pub full_width_punctuation: bool,
+    /// Keep at most one blank line between paragraphs.
+    pub collapse_blank_lines: bool,
+    /// Start every paragraph with a two-character ideographic indent,
+    /// replacing any other leading whitespace.
+    pub indent_paragraphs: bool,
+    /// Write ellipses as `……` and dashes as `——`.
+    pub unify_ellipses_and_dashes: bool,
+    /// Turn straight quotes into curly ones.
+    pub curly_quotes: bool,
+}
+
+/// The passes applied by default; curly quotes are opt-in.
+#[flutter_rust_bridge::frb]
+pub fn default_typography_options() -> TypographyOptions {
+    TypographyOptions {
+        full_width_punctuation: true,
+        collapse_blank_lines: true,
+        indent_paragraphs: true,
+        unify_ellipses_and_dashes: true,
+        curly_quotes: false,
+    }
+}
+
+/// Normalize the typography of chapter text.
+#[flutter_rust_bridge::frb]
+pub fn normalize_typography(text: String, options: TypographyOptions) -> String {
+    let ellipses = Regex::new(r"\.{3,}|。{3,}|…+|·{3,}").unwrap();
+    let dashes = Regex::new(r"—+|―+|－{2,}|-{2,3}").unwrap();
+
+    let mut lines: Vec<String> = Vec::new();
+    for line in text.lines() {
+        let blank = line.trim().is_empty();
+        if blank {
+            let previous_blank = lines.last().is_some_and(|line| line.is_empty());
+            if !(options.collapse_blank_lines && previous_blank) {
+                lines.push(String::new());
+            }
+            continue;
+        }
+
+        let mut line = line.trim_end().to_string();
+        if options.unify_ellipses_and_dashes {
+            line = ellipses.replace_all(&line, "……").into_owned();
+            line = dashes.replace_all(&line, "——").into_owned();
+        }
+        if options.full_width_punctuation {
+            line = full_width(&line);
+        }
+        if options.curly_quotes {
+            line = curly_quotes(&line);
+        }
+        if options.indent_paragraphs {
+            line = format!("{INDENT}{}", line.trim_start());
+        }
+        lines.push(line);
+    }
+    if options.collapse_blank_lines {
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+    }
+    lines.join("\n")
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3000}'..='\u{303F}' // CJK punctuation
+        | '\u{3040}'..='\u{30FF}' // kana
+        | '\u{3400}'..='\u{4DBF}'
+        | '\u{4E00}'..='\u{9FFF}'
+        | '\u{AC00}'..='\u{D7AF}' // hangul
+        | '\u{FF01}'..='\u{FF60}' // full-width forms
+        | '“' | '”' | '‘' | '’' | '…' | '—')
+}
+
+fn full_width(line: &str) -> String {
+    let chars: Vec<char> = line.chars().collect();
+    let mut out = String::with_capacity(line.len());
+    let mut skip_spaces = false;
+    for (i, &c) in chars.iter().enumerate() {
+        if skip_spaces && c == ' ' {
+            continue;
+        }
+        skip_spaces = false;
+
+        let before = out.chars().rev().find(|c| *c != ' ');
+        let after = chars[i + 1..].iter().copied().find(|c| *c != ' ');
+        let cjk_before = before.is_some_and(is_cjk);
+        let cjk_after = after.is_some_and(is_cjk);
+        let converted = match c {
+            // A period only ends a CJK sentence; elsewhere it may be a
+            // decimal point or an abbreviation.
+            '.' if cjk_before && !after.is_some_and(|c| c.is_ascii_alphanumeric()) => Some('。'),
+            ',' | '?' | '!' | ':' | ';' | ')' if cjk_before => Some(to_full_width(c)),
+            ',' | '?' | '!' | ':' | ';' | '('
+                if cjk_after && !before.is_some_and(|c| c.is_ascii_alphanumeric()) =>
+            {
+                Some(to_full_width(c))
+            }
+            '(' if cjk_before || cjk_after => Some('（'),
+            _ => None,
+        };
+        match converted {
+            Some(full) => {
+                // Full-width punctuation carries its own spacing.
+                while out.ends_with(' ') {
+                    out.pop();
+                }
+                out.push(full);
+                skip_spaces = true;
+            }
+            None => out.push(c),
+        }
+    }
+    out
+}
+
+fn to_full_width(c: char) -> char {
+    char::from_u32(c as u32 - 0x21 + 0xFF01).unwrap_or(c)
+}
+
+/// Alternate opening and closing double quotes within a line; single
+/// quotes between letters are apostrophes.
+fn curly_quotes(line: &str) -> String {
+    let chars: Vec<char> = line.chars().collect();
+    let (mut double_open, mut single_open) = (false, false);
+    let mut out = String::with_capacity(line.len());
+    for (i, &c) in chars.iter().enumerate() {
+        match c {
+            '"' => {
+                double_open = !double_open;
+                out.push(if double_open { '“' } else { '”' });
+            }
+            '\'' => {
+                let letter = |c: Option<&char>| c.is_some_and(|c| c.is_alphanumeric());
+                if letter(i.checked_sub(1).and_then(|i| chars.get(i))) && letter(chars.get(i + 1)) {
+                    out.push('’');
+                } else {
+                    single_open = !single_open;
+                    out.push(if single_open { '‘' } else { '’' });
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalize(text: &str, options: TypographyOptions) -> String {
+        normalize_typography(text.to_string(), options)
+    }
+
+    #[test]
+    fn test_default_passes() {
+        let text = "第一章\r\n\r\n\r\n  他说: 你好, 世界!\n  价格是3.5元.\n\n\n等等...然后--走了.\nHello, world. Ok?\n\n";
+        assert_eq!(
+            normalize(text, default_typography_options()),
+            "　　第一章\n\n　　他说：你好，世界！\n　　价格是3.5元。\n\n　　等等……然后——走了。\n　　Hello, world. Ok?"
+        );
+    }
+
+    #[test]
+    fn test_passes_can_be_disabled() {
+        let options = TypographyOptions {
+            full_width_punctuation: false,
+            collapse_blank_lines: false,
+            indent_paragraphs: false,
+            unify_ellipses_and_dashes: false,
+            curly_quotes: true,
+        };
+        assert_eq!(
+            normalize("他说:\"走吧.\"\n\n\nit's 'fine'", options),
+            "他说:“走吧.”\n\n\nit’s ‘fine’"
+        );
+    }
+
+    #[test]
+    fn test_full_width_only_in_cjk_context() {
+        assert_eq!(full_width("(注)第1.5版, v2.0。"), "（注）第1.5版，v2.0。");
+        assert_eq!(full_width("see (note), ok."), "see (note), ok.");
+    }
+}
@@ -0,0 +1,454 @@
+//! UMD import.
+//!
+//! UMD is the ebook format of Chinese feature phones. After the magic
+//! number a file is a stream of records: `#` chunks carry a function id and
+//! a few bytes of data, and `$` blocks carry either the payload of the chunk
+//! right before them or the book content. Strings are UTF-16LE.
+//!
+//! Text books store their content as zlib-compressed UTF-16LE blocks, with
+//! chapters given as byte offsets into the joined text. Cartoon books store
+//! one image per block, with chapters given as page offsets; their chapter
+//! HTML is the pages as `img` elements with `images/<n>` hrefs, counted
+//! from 0. The cover, of either kind, is `cover`.
+
+use std::fs;
+use std::io::Read;
+
+use anyhow::{anyhow, Result};
+use flate2::read::ZlibDecoder;
+use quick_xml::escape::escape;
+
+use crate::api::book::{self, BookChapter, BookMetadata, ParsedBook, TocEntry};
+use crate::api::error::ApiError;
+use crate::api::{langdetect, task, txt};
+
+const MAGIC: &[u8] = b"\x89\x9b\x9a\xde";
+
+const CHUNK_TYPE: u16 = 0x01;
+const CHUNK_TITLE: u16 = 0x02;
+const CHUNK_AUTHOR: u16 = 0x03;
+const CHUNK_PUBLISHER: u16 = 0x08;
+const CHUNK_END: u16 = 0x0c;
+const CHUNK_CONTENT_INDEX: u16 = 0x81;
+const CHUNK_COVER: u16 = 0x82;
+const CHUNK_CHAPTER_OFFSETS: u16 = 0x83;
+const CHUNK_CHAPTER_TITLES: u16 = 0x84;
+const CHUNK_PAGE_OFFSETS: u16 = 0x87;
+
+/// Chunks whose payload is the `$` block that follows them.
+const BLOCK_CHUNKS: &[u16] = &[
+    CHUNK_CONTENT_INDEX,
+    CHUNK_COVER,
+    CHUNK_CHAPTER_OFFSETS,
+    CHUNK_CHAPTER_TITLES,
+    CHUNK_PAGE_OFFSETS,
+];
+
+const TYPE_TEXT: u8 = 1;
+const TYPE_CARTOON: u8 = 2;
+
+const COVER_HREF: &str = "cover";
+const IMAGE_HREF_PREFIX: &str = "images/";
+
+/// Parse a local `.umd` file, text or cartoon, into chapters and a table of
+/// contents.
+///
+/// # Arguments
+/// * `path` - Path to the UMD file
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+#[flutter_rust_bridge::frb]
+pub async fn parse_umd(path: String, cancel_token: Option<u32>) -> Result<ParsedBook, ApiError> {
+    task::run_blocking(task::cancel_token(cancel_token)?, move |_| {
+        parse(&read_file(&path)?)
+    })
+    .await
+}
+
+/// Extract the cover or a cartoon page by the href used in chapter HTML or
+/// `cover_href`.
+///
+/// # Arguments
+/// * `path` - Path to the UMD file
+/// * `href` - `cover`, or a page href from chapter HTML, e.g. `images/3`
+#[flutter_rust_bridge::frb]
+pub fn extract_umd_resource(path: String, href: String) -> Result<Vec<u8>, ApiError> {
+    Ok(extract_resource(&read_file(&path)?, &href)?)
+}
+
+pub(crate) fn read_file(path: &str) -> Result<Vec<u8>> {
+    fs::read(path).map_err(|e| anyhow!("Failed to open {path}: {e}"))
+}
+
+/// The records of a UMD file.
+#[derive(Default)]
+struct Umd<'a> {
+    kind: u8,
+    title: Option<String>,
+    author: Option<String>,
+    publisher: Option<String>,
+    offsets: Vec<u32>,
+    titles: Vec<String>,
+    cover: Option<&'a [u8]>,
+    /// Compressed text blocks or cartoon pages, in file order.
+    content: Vec<&'a [u8]>,
+}
+
+impl<'a> Umd<'a> {
+    fn read(data: &'a [u8]) -> Result<Self> {
+        if !data.starts_with(MAGIC) {
+            return Err(anyhow!("Not a UMD file"));
+        }
+        let mut umd = Umd::default();
+        let mut pos = MAGIC.len();
+        // The chunk the next block belongs to, if it carries one.
+        let mut owner = None;
+        while pos < data.len() {
+            match data[pos] {
+                b'#' => {
+                    let id = le_u16(data, pos + 1).ok_or_else(|| truncated(pos))?;
+                    let len = *data.get(pos + 4).ok_or_else(|| truncated(pos))? as usize;
+                    let body = data
+                        .get(pos + 5..pos + len.max(5))
+                        .ok_or_else(|| truncated(pos))?;
+                    if id == CHUNK_END {
+                        break;
+                    }
+                    umd.chunk(id, body);
+                    owner = BLOCK_CHUNKS.contains(&id).then_some(id);
+                    pos += len.max(5);
+                }
+                b'$' => {
+                    let len = le_u32(data, pos + 5).ok_or_else(|| truncated(pos))? as usize;
+                    let body = data
+                        .get(pos + 9..pos + len.max(9))
+                        .ok_or_else(|| truncated(pos))?;
+                    match owner.take() {
+                        Some(id) => umd.block(id, body),
+                        None => umd.content.push(body),
+                    }
+                    pos += len.max(9);
+                }
+                _ => return Err(anyhow!("Invalid UMD record at offset {pos}")),
+            }
+        }
+        match umd.kind {
+            TYPE_TEXT | TYPE_CARTOON => Ok(umd),
+            0 => Err(anyhow!("UMD file declares no type")),
+            kind => Err(anyhow!("Unsupported UMD type {kind}")),
+        }
+    }
+
+    fn chunk(&mut self, id: u16, body: &[u8]) {
+        match id {
+            CHUNK_TYPE => self.kind = body.first().copied().unwrap_or(0),
+            CHUNK_TITLE => self.title = utf16(body),
+            CHUNK_AUTHOR => self.author = utf16(body),
+            CHUNK_PUBLISHER => self.publisher = utf16(body),
+            _ => {}
+        }
+    }
+
+    fn block(&mut self, id: u16, body: &'a [u8]) {
+        match id {
+            CHUNK_CHAPTER_OFFSETS => {
+                self.offsets = body
+                    .chunks_exact(4)
+                    .map(|offset| u32::from_le_bytes([offset[0], offset[1], offset[2], offset[3]]))
+                    .collect();
+            }
+            CHUNK_CHAPTER_TITLES => {
+                let mut rest = body;
+                while let Some((&len, tail)) = rest.split_first() {
+                    let len = (len as usize).min(tail.len());
+                    self.titles.push(utf16(&tail[..len]).unwrap_or_default());
+                    rest = &tail[len..];
+                }
+            }
+            CHUNK_COVER => self.cover = Some(body),
+            _ => {}
+        }
+    }
+
+    /// Ranges of the chapters, in bytes of text or in pages.
+    fn chapter_ranges(&self, total: usize) -> Vec<(usize, usize)> {
+        if self.offsets.is_empty() {
+            return vec![(0, total)];
+        }
+        (0..self.offsets.len())
+            .map(|i| {
+                let start = (self.offsets[i] as usize).min(total);
+                let end = self
+                    .offsets
+                    .get(i + 1)
+                    .map_or(total, |&end| (end as usize).clamp(start, total));
+                (start, end)
+            })
+            .collect()
+    }
+}
+
+pub(crate) fn parse(data: &[u8]) -> Result<ParsedBook> {
+    let umd = Umd::read(data)?;
+    let mut metadata = BookMetadata::empty();
+    metadata.title = umd.title.clone();
+    metadata.authors = umd.author.iter().cloned().collect();
+    metadata.publisher = umd.publisher.clone();
+    metadata.cover_href = umd.cover.map(|_| COVER_HREF.to_string());
+
+    let bodies = if umd.kind == TYPE_CARTOON {
+        umd.chapter_ranges(umd.content.len())
+            .into_iter()
+            .map(|(start, end)| {
+                (start..end)
+                    .map(|page| format!("<img src=\"{IMAGE_HREF_PREFIX}{page}\">"))
+                    .collect()
+            })
+            .collect()
+    } else {
+        let text = inflate(&umd.content)?;
+        let mut sample = langdetect::Sample::default();
+        let bodies: Vec<String> = umd
+            .chapter_ranges(text.len())
+            .into_iter()
+            .map(|(start, end)| {
+                // Offsets count bytes of UTF-16 text.
+                let text = utf16(&text[start & !1..end & !1]).unwrap_or_default();
+                let text = text.replace('\u{2029}', "\n");
+                sample.add(&text);
+                text
+            })
+            .collect();
+        metadata.language = langdetect::book_language(&sample);
+        bodies
+    };
+
+    let mut chapters = Vec::new();
+    let mut toc = Vec::new();
+    for (i, body) in bodies.into_iter().enumerate() {
+        let title = umd.titles.get(i).filter(|title| !title.is_empty()).cloned();
+        let html = match (&title, umd.kind) {
+            (_, TYPE_CARTOON) => body,
+            (Some(title), _) => {
+                // Chapter text often repeats its title as the first line.
+                let text = body.trim_start();
+                let text = text.strip_prefix(title.as_str()).unwrap_or(text);
+                format!(
+                    "<h2>{}</h2>{}",
+                    escape(title.as_str()),
+                    txt::chapter_html(text, false)
+                )
+            }
+            (None, _) => txt::chapter_html(&body, false),
+        };
+        if let Some(title) = &title {
+            toc.push(TocEntry {
+                title: title.clone(),
+                href: book::part_href(i),
+                chapter_index: Some(i as u32),
+                depth: 0,
+            });
+        }
+        chapters.push(BookChapter::new(i as u32, book::part_href(i), title, html));
+    }
+    if chapters.iter().all(|chapter| chapter.html.is_empty()) {
+        return Err(anyhow!("UMD file contains no content"));
+    }
+    Ok(ParsedBook {
+        metadata,
+        chapters,
+        toc,
+    })
+}
+
+pub(crate) fn extract_resource(data: &[u8], href: &str) -> Result<Vec<u8>> {
+    let umd = Umd::read(data)?;
+    let resource = if href == COVER_HREF {
+        umd.cover
+    } else {
+        href.strip_prefix(IMAGE_HREF_PREFIX)
+            .and_then(|n| n.parse::<usize>().ok())
+            .filter(|_| umd.kind == TYPE_CARTOON)
+            .and_then(|n| umd.content.get(n).copied())
+    };
+    resource
+        .map(<[u8]>::to_vec)
+        .ok_or_else(|| anyhow!("Unknown UMD resource {href}"))
+}
+
+/// Join the decompressed text blocks.
+fn inflate(blocks: &[&[u8]]) -> Result<Vec<u8>> {
+    let mut text = Vec::new();
+    for (i, block) in blocks.iter().enumerate() {
+        ZlibDecoder::new(*block)
+            .read_to_end(&mut text)
+            .map_err(|e| anyhow!("Invalid UMD content block {i}: {e}"))?;
+    }
+    Ok(text)
+}
+
+fn utf16(bytes: &[u8]) -> Option<String> {
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+        .collect();
+    let text = String::from_utf16_lossy(&units);
+    let text = text.trim_matches(|c: char| c == '\0' || c.is_whitespace());
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+fn truncated(pos: usize) -> anyhow::Error {
+    anyhow!("Invalid UMD file: record at offset {pos} is truncated")
+}
+
+fn le_u16(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(
+        data.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn le_u32(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(
+        data.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::ZlibEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    fn chunk(id: u16, body: &[u8]) -> Vec<u8> {
+        let mut record = vec![b'#'];
+        record.extend(id.to_le_bytes());
+        record.push(0);
+        record.push(5 + body.len() as u8);
+        record.extend(body);
+        record
+    }
+
+    fn block(body: &[u8]) -> Vec<u8> {
+        let mut record = vec![b'$'];
+        record.extend(0x1234u32.to_le_bytes());
+        record.extend((9 + body.len() as u32).to_le_bytes());
+        record.extend(body);
+        record
+    }
+
+    fn utf16le(text: &str) -> Vec<u8> {
+        text.encode_utf16().flat_map(u16::to_le_bytes).collect()
+    }
+
+    fn titles(titles: &[&str]) -> Vec<u8> {
+        titles
+            .iter()
+            .flat_map(|title| {
+                let title = utf16le(title);
+                std::iter::once(title.len() as u8).chain(title)
+            })
+            .collect()
+    }
+
+    fn offsets(offsets: &[u32]) -> Vec<u8> {
+        offsets
+            .iter()
+            .flat_map(|offset| offset.to_le_bytes())
+            .collect()
+    }
+
+    fn umd(kind: u8, records: Vec<Vec<u8>>) -> Vec<u8> {
+        let mut data = MAGIC.to_vec();
+        data.extend(chunk(CHUNK_TYPE, &[kind, 0x12, 0x34]));
+        data.extend(chunk(CHUNK_TITLE, &utf16le("旧书")));
+        data.extend(chunk(CHUNK_AUTHOR, &utf16le("佚名")));
+        data.extend(records.concat());
+        data.extend(chunk(CHUNK_END, &(0u32).to_le_bytes()));
+        data
+    }
+
+    #[test]
+    fn test_parse_text_book() {
+        let one = "第一章 开始\u{2029}天色渐晚，城门外的行人越来越少。\u{2029}";
+        let two = "他把信折好，放进怀里。\u{2029}转身走进了风雪之中。";
+        let text = utf16le(&format!("{one}{two}"));
+        let compress = |bytes: &[u8]| {
+            let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(bytes).unwrap();
+            encoder.finish().unwrap()
+        };
+        let data = umd(
+            TYPE_TEXT,
+            vec![
+                chunk(CHUNK_CHAPTER_OFFSETS, &[0; 4]),
+                block(&offsets(&[0, utf16le(one).len() as u32])),
+                chunk(CHUNK_CHAPTER_TITLES, &[0; 4]),
+                block(&titles(&["第一章 开始", "第二章 风雪"])),
+                // Content blocks may split a character's bytes.
+                block(&compress(&text[..7])),
+                block(&compress(&text[7..])),
+                chunk(CHUNK_CONTENT_INDEX, &[0; 4]),
+                block(&[0; 8]),
+                chunk(CHUNK_COVER, &[1, 0, 0, 0, 0]),
+                block(b"jpeg"),
+            ],
+        );
+
+        let book = parse(&data).unwrap();
+        assert_eq!(book.metadata.title.as_deref(), Some("旧书"));
+        assert_eq!(book.metadata.authors, ["佚名"]);
+        assert_eq!(book.metadata.cover_href.as_deref(), Some("cover"));
+        assert_eq!(book.chapters.len(), 2);
+        assert_eq!(
+            book.chapters[0].html,
+            "<h2>第一章 开始</h2><p>天色渐晚，城门外的行人越来越少。</p>"
+        );
+        assert_eq!(
+            book.chapters[1].html,
+            "<h2>第二章 风雪</h2><p>他把信折好，放进怀里。</p><p>转身走进了风雪之中。</p>"
+        );
+        assert_eq!(book.toc[1].title, "第二章 风雪");
+        assert_eq!(book.toc[1].href, "part0001.html");
+        assert_eq!(extract_resource(&data, "cover").unwrap(), b"jpeg");
+        assert!(extract_resource(&data, "images/0").is_err());
+    }
+
+    #[test]
+    fn test_parse_cartoon_book() {
+        let data = umd(
+            TYPE_CARTOON,
+            vec![
+                chunk(CHUNK_CHAPTER_OFFSETS, &[0; 4]),
+                block(&offsets(&[0, 2])),
+                chunk(CHUNK_CHAPTER_TITLES, &[0; 4]),
+                block(&titles(&["第1话", "第2话"])),
+                block(b"page0"),
+                block(b"page1"),
+                block(b"page2"),
+            ],
+        );
+
+        let book = parse(&data).unwrap();
+        assert_eq!(book.metadata.cover_href, None);
+        assert_eq!(
+            book.chapters[0].html,
+            "<img src=\"images/0\"><img src=\"images/1\">"
+        );
+        assert_eq!(book.chapters[1].html, "<img src=\"images/2\">");
+        assert_eq!(book.chapters[1].title.as_deref(), Some("第2话"));
+        assert_eq!(extract_resource(&data, "images/2").unwrap(), b"page2");
+        assert!(extract_resource(&data, "images/3").is_err());
+    }
+
+    #[test]
+    fn test_rejects_invalid_files() {
+        assert!(parse(b"PK\x03\x04").is_err());
+        let mut data = umd(TYPE_TEXT, vec![block(b"x")]);
+        data.truncate(data.len() - 12);
+        assert!(parse(&data).unwrap_err().to_string().contains("truncated"));
+        assert!(parse(&umd(7, Vec::new()))
+            .unwrap_err()
+            .to_string()
+            .contains("Unsupported UMD type 7"));
+    }
+}
@@ -0,0 +1,381 @@
+//! Checking books being read for new chapters, from a background task.
+//!
+//! `check_book_updates` fetches the table of contents of each book from its
+//! source and compares it with the chapters stored in the library database.
+//! Books on the same host are checked one after another and different
+//! hosts in parallel, so the per-host rate limiter is never raced; each
+//! request still goes through it. Mobile background tasks are killed when
+//! they overrun, so the call returns when its time budget is spent even if
+//! requests are still running: their books are reported as not checked
+//! and whatever they fetch afterwards is dropped.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use anyhow::Result;
+use rusqlite::{params, Connection};
+
+use crate::api::book_source::{self, BookSource, SourceChapter};
+use crate::api::db::{self, LibraryChapter};
+use crate::api::error::ApiError;
+use crate::api::task::{self, CancelToken};
+
+/// Hosts checked at the same time.
+const MAX_PARALLEL: usize = 8;
+/// How often waiting for results looks at the cancel token.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// A book to check, with the source it is read from.
+#[derive(Debug, Clone)]
+pub struct UpdateCheckBook {
+    /// ID of the book in the library database.
+    pub book_id: String,
+    pub source: BookSource,
+    /// Table of contents URL, as stored with the book.
+    pub toc_url: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateCheckStatus {
+    /// The source lists chapters the library does not have.
+    Updated,
+    Unchanged,
+    /// Fetching or reading the table of contents failed.
+    Failed,
+    /// The time budget ran out before the book was checked.
+    NotChecked,
+}
+
+#[derive(Debug, Clone)]
+pub struct BookUpdateSummary {
+    pub book_id: String,
+    pub status: UpdateCheckStatus,
+    /// Chapters on the source that are not stored, volume headings excluded.
+    pub new_chapters: u32,
+    /// Stored chapters the source no longer lists.
+    pub removed_chapters: u32,
+    /// Entries in the fetched table of contents.
+    pub chapter_count: u32,
+    pub latest_chapter_title: Option<String>,
+    pub error: Option<String>,
+}
+
+/// Check books for new chapters and return a summary per book, in the
+/// order given.
+///
+/// # Arguments
+/// * `books` - Books to check, such as those being read
+/// * `time_budget_ms` - Return after this long, whether or not every book has been checked
+/// * `save_chapters` - Store fetched tables of contents that changed, so the next check
+///   compares with them; otherwise only report
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+#[flutter_rust_bridge::frb]
+pub async fn check_book_updates(
+    books: Vec<UpdateCheckBook>,
+    time_budget_ms: u32,
+    save_chapters: bool,
+    cancel_token: Option<u32>,
+) -> Result<Vec<BookUpdateSummary>, ApiError> {
+    task::run_blocking_io(task::cancel_token(cancel_token)?, move |token| {
+        let budget = Duration::from_millis(time_budget_ms.into());
+        let ids: Vec<String> = books.iter().map(|book| book.book_id.clone()).collect();
+        let fetched = fetch_all(books, budget, &token, |book| {
+            Ok(book_source::fetch_source_toc(
+                book.source.clone(),
+                book.toc_url.clone(),
+            )?)
+        })?;
+        Ok(ids
+            .into_iter()
+            .zip(fetched)
+            .map(|(book_id, fetched)| {
+                let mut summary = BookUpdateSummary {
+                    book_id,
+                    status: UpdateCheckStatus::NotChecked,
+                    new_chapters: 0,
+                    removed_chapters: 0,
+                    chapter_count: 0,
+                    latest_chapter_title: None,
+                    error: None,
+                };
+                let compared = fetched.map(|toc| {
+                    toc.and_then(|toc| {
+                        db::with_db(|db| compare(db, &mut summary, &toc, save_chapters))
+                    })
+                });
+                if let Some(Err(e)) = compared {
+                    summary.status = UpdateCheckStatus::Failed;
+                    summary.error = Some(format!("{e:#}"));
+                }
+                summary
+            })
+            .collect())
+    })
+    .await
+}
+
+/// The table of contents of each book, in order, or `None` for books not
+/// fetched within `budget`.
+fn fetch_all(
+    books: Vec<UpdateCheckBook>,
+    budget: Duration,
+    token: &CancelToken,
+    fetch: impl Fn(&UpdateCheckBook) -> Result<Vec<SourceChapter>> + Send + Sync + 'static,
+) -> Result<Vec<Option<Result<Vec<SourceChapter>>>>> {
+    let deadline = Instant::now() + budget;
+    let mut fetched: Vec<Option<Result<Vec<SourceChapter>>>> = books.iter().map(|_| None).collect();
+
+    let mut hosts: HashMap<String, Vec<(usize, UpdateCheckBook)>> = HashMap::new();
+    for (i, book) in books.into_iter().enumerate() {
+        let host = host(&book.toc_url).or_else(|| host(&book.source.url));
+        hosts
+            .entry(host.unwrap_or_default())
+            .or_default()
+            .push((i, book));
+    }
+    let workers = hosts.len().min(MAX_PARALLEL);
+    let queue = Arc::new(Mutex::new(hosts.into_values().collect::<VecDeque<_>>()));
+    let stopped = Arc::new(AtomicBool::new(false));
+    let fetch = Arc::new(fetch);
+    let (results, received) = mpsc::channel();
+    for _ in 0..workers {
+        let (queue, stopped, fetch, results) = (
+            queue.clone(),
+            stopped.clone(),
+            fetch.clone(),
+            results.clone(),
+        );
+        // Detached, so a slow request cannot hold the call past its budget.
+        thread::spawn(move || loop {
+            let Some(books) = queue.lock().unwrap_or_else(|e| e.into_inner()).pop_front() else {
+                return;
+            };
+            for (i, book) in books {
+                if stopped.load(Ordering::Relaxed) || results.send((i, fetch(&book))).is_err() {
+                    return;
+                }
+            }
+        });
+    }
+    drop(results);
+
+    let result = loop {
+        let now = Instant::now();
+        if now >= deadline {
+            break Ok(fetched);
+        }
+        if let Err(e) = token.check() {
+            break Err(e);
+        }
+        match received.recv_timeout(POLL_INTERVAL.min(deadline - now)) {
+            Ok((i, toc)) => fetched[i] = Some(toc),
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break Ok(fetched),
+        }
+    };
+    stopped.store(true, Ordering::Relaxed);
+    result
+}
+
+/// Fill in `summary` from a fetched table of contents, saving it if asked
+/// and it changed.
+fn compare(
+    db: &mut Connection,
+    summary: &mut BookUpdateSummary,
+    toc: &[SourceChapter],
+    save: bool,
+) -> rusqlite::Result<()> {
+    let stored = db::read_chapters(db, &summary.book_id, 0, u32::MAX)?;
+    let (new_chapters, removed_chapters) = diff(&stored, toc);
+    summary.new_chapters = new_chapters;
+    summary.removed_chapters = removed_chapters;
+    summary.chapter_count = toc.len() as u32;
+    summary.latest_chapter_title = toc
+        .iter()
+        .rev()
+        .find(|chapter| !chapter.is_volume)
+        .map(|chapter| chapter.title.clone());
+    summary.status = if new_chapters > 0 {
+        UpdateCheckStatus::Updated
+    } else {
+        UpdateCheckStatus::Unchanged
+    };
+
+    let changed = new_chapters > 0 || removed_chapters > 0 || stored.len() != toc.len();
+    if !(save && changed) {
+        return Ok(());
+    }
+    // Word counts of chapters that are still there are kept.
+    let word_counts: HashMap<&str, u32> = stored
+        .iter()
+        .filter_map(|chapter| Some((chapter.url.as_deref()?, chapter.word_count?)))
+        .collect();
+    let chapters: Vec<LibraryChapter> = toc
+        .iter()
+        .enumerate()
+        .map(|(i, chapter)| LibraryChapter {
+            index: i as u32,
+            title: chapter.title.clone(),
+            url: Some(chapter.url.clone()),
+            is_volume: chapter.is_volume,
+            word_count: word_counts.get(chapter.url.as_str()).copied(),
+        })
+        .collect();
+    db::write_chapters(db, &summary.book_id, &chapters)?;
+    db.execute(
+        "UPDATE books SET latest_chapter_title = ?2, updated_at = ?3 WHERE id = ?1",
+        params![summary.book_id, summary.latest_chapter_title, now()],
+    )?;
+    Ok(())
+}
+
+/// Chapters in `toc` but not `stored`, and the other way round. Chapters
+/// are matched by URL, or by title where the stored chapter has none.
+fn diff(stored: &[LibraryChapter], toc: &[SourceChapter]) -> (u32, u32) {
+    let key = |title: &str, url: Option<&str>| match url {
+        Some(url) if !url.is_empty() => format!("url:{url}"),
+        _ => format!("title:{}", title.trim()),
+    };
+    let by_url = stored.iter().any(|chapter| chapter.url.is_some());
+    let stored_keys: HashSet<String> = stored
+        .iter()
+        .filter(|chapter| !chapter.is_volume)
+        .map(|chapter| key(&chapter.title, chapter.url.as_deref()))
+        .collect();
+    let fetched_keys: HashSet<String> = toc
+        .iter()
+        .filter(|chapter| !chapter.is_volume)
+        .map(|chapter| key(&chapter.title, by_url.then_some(chapter.url.as_str())))
+        .collect();
+    (
+        fetched_keys.difference(&stored_keys).count() as u32,
+        stored_keys.difference(&fetched_keys).count() as u32,
+    )
+}
+
+fn host(url: &str) -> Option<String> {
+    let url = url::Url::parse(url).ok()?;
+    url.host_str().map(str::to_ascii_lowercase)
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::book_source::parse_book_sources;
+
+    fn book(book_id: &str, toc_url: &str) -> UpdateCheckBook {
+        let source = r#"{"bookSourceUrl": "https://example.com", "bookSourceName": "Example"}"#;
+        UpdateCheckBook {
+            book_id: book_id.to_string(),
+            source: parse_book_sources(source.to_string()).unwrap().remove(0),
+            toc_url: toc_url.to_string(),
+        }
+    }
+
+    fn chapter(title: &str, url: &str) -> SourceChapter {
+        SourceChapter {
+            title: title.to_string(),
+            url: url.to_string(),
+            is_volume: false,
+        }
+    }
+
+    #[test]
+    fn test_fetch_all_returns_within_budget() {
+        let books = vec![
+            book("slow", "https://slow.example.com/1/"),
+            book("queued", "https://slow.example.com/2/"),
+            book("fast", "https://fast.example.com/1/"),
+        ];
+        let fetched = fetch_all(
+            books,
+            Duration::from_secs(1),
+            &CancelToken::default(),
+            |book| {
+                if book.book_id == "slow" {
+                    thread::sleep(Duration::from_secs(30));
+                }
+                Ok(vec![chapter(&book.book_id, &book.toc_url)])
+            },
+        )
+        .unwrap();
+        // Books on the slow host wait for the slow one.
+        assert!(fetched[0].is_none() && fetched[1].is_none());
+        assert_eq!(
+            fetched[2].as_ref().unwrap().as_ref().unwrap()[0].title,
+            "fast"
+        );
+    }
+
+    #[test]
+    fn test_compare_counts_and_saves_new_chapters() {
+        let mut db = Connection::open_in_memory().unwrap();
+        db::setup(&mut db).unwrap();
+        db.execute(
+            "INSERT INTO books (id, title, added_at, updated_at) VALUES ('book', 'Book', 0, 0)",
+            [],
+        )
+        .unwrap();
+        let stored: Vec<LibraryChapter> = ["1", "2", "3"]
+            .iter()
+            .enumerate()
+            .map(|(i, n)| LibraryChapter {
+                index: i as u32,
+                title: format!("第{n}章"),
+                url: Some(format!("/c/{n}")),
+                is_volume: false,
+                word_count: Some(1000),
+            })
+            .collect();
+        db::write_chapters(&mut db, "book", &stored).unwrap();
+
+        // Chapter 3 was taken down, 4 and 5 are new.
+        let toc = vec![
+            chapter("第1章", "/c/1"),
+            chapter("第2章 改名", "/c/2"),
+            SourceChapter {
+                is_volume: true,
+                ..chapter("第二卷", "/v/2")
+            },
+            chapter("第4章", "/c/4"),
+            chapter("第5章", "/c/5"),
+        ];
+        let mut summary = BookUpdateSummary {
+            book_id: "book".to_string(),
+            status: UpdateCheckStatus::NotChecked,
+            new_chapters: 0,
+            removed_chapters: 0,
+            chapter_count: 0,
+            latest_chapter_title: None,
+            error: None,
+        };
+        compare(&mut db, &mut summary, &toc, true).unwrap();
+        assert_eq!(summary.status, UpdateCheckStatus::Updated);
+        assert_eq!((summary.new_chapters, summary.removed_chapters), (2, 1));
+        assert_eq!(summary.latest_chapter_title.as_deref(), Some("第5章"));
+
+        let saved = db::read_chapters(&db, "book", 0, u32::MAX).unwrap();
+        assert_eq!(saved.len(), 5);
+        assert_eq!(saved[1].word_count, Some(1000));
+        let latest: String = db
+            .query_row("SELECT latest_chapter_title FROM books", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(latest, "第5章");
+
+        compare(&mut db, &mut summary, &toc, true).unwrap();
+        assert_eq!(summary.status, UpdateCheckStatus::Unchanged);
+        assert_eq!(summary.new_chapters, 0);
+    }
+}
@@ -0,0 +1,273 @@
+//! Encrypted storage for small secrets such as refresh tokens.
+//!
+//! Secrets are sealed with AES-256-GCM under a random vault key kept in the
+//! vault directory. That key is itself sealed with a key derived from
+//! device-bound material supplied by the platform keystore (Android
+//! Keystore, iOS Keychain), so a copied vault directory or preferences
+//! file is useless on another device. Sealed secrets are short strings the
+//! app can keep in ordinary preferences; each is bound to a name so one
+//! cannot be substituted for another. Key material and intermediate
+//! plaintext are zeroized once no longer needed.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+use aes_gcm::aead::{Aead, Generate, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use anyhow::{anyhow, Result};
+use base64::Engine;
+use subtle::ConstantTimeEq;
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::api::error::ApiError;
+use crate::api::font_converter;
+
+const KEY_FILE: &str = "vault.key";
+
+/// Starts the sealed vault key file.
+const KEY_MAGIC: &[u8] = b"NVTV1";
+
+/// Starts every sealed secret string.
+const SECRET_PREFIX: &str = "nv1.";
+
+const NONCE_LEN: usize = 12;
+
+/// Shortest device secret accepted, in bytes.
+const MIN_DEVICE_SECRET_LEN: usize = 16;
+
+const KDF_CONTEXT: &str = "Novella token vault key-encryption key v1";
+
+static VAULT: Mutex<Option<Vault>> = Mutex::new(None);
+
+/// Unlock the vault in `vault_dir`, creating it on first use. Fails if the
+/// vault was created with different device material; delete the directory
+/// to start over, which makes previously sealed secrets unreadable.
+///
+/// # Arguments
+/// * `vault_dir` - Directory owned by the vault; created if missing
+/// * `device_secret` - At least 16 bytes of device-bound material from the
+///   platform keystore; the same bytes must be passed on every launch
+#[flutter_rust_bridge::frb]
+pub fn init_token_vault(vault_dir: String, device_secret: Vec<u8>) -> Result<(), ApiError> {
+    let device_secret = Zeroizing::new(device_secret);
+    let vault = Vault::open(Path::new(&vault_dir), &device_secret)?;
+    *lock() = Some(vault);
+    Ok(())
+}
+
+/// Forget the unlocked vault key until `init_token_vault` is called again.
+#[flutter_rust_bridge::frb]
+pub fn lock_token_vault() {
+    *lock() = None;
+}
+
+/// Encrypt a secret, returning a string that is safe to store anywhere.
+///
+/// # Arguments
+/// * `name` - What the secret is, e.g. `refresh_token`; needed to open it
+/// * `secret` - Plaintext secret
+#[flutter_rust_bridge::frb]
+pub fn seal_secret(name: String, secret: String) -> Result<String, ApiError> {
+    let secret = Zeroizing::new(secret);
+    Ok(current(|vault| vault.seal(&name, secret.as_bytes()))?)
+}
+
+/// Decrypt a string returned by `seal_secret` under the same name.
+///
+/// # Arguments
+/// * `name` - Name the secret was sealed under
+/// * `sealed` - Sealed secret string
+#[flutter_rust_bridge::frb]
+pub fn open_secret(name: String, sealed: String) -> Result<String, ApiError> {
+    let plain = current(|vault| vault.open_sealed(&name, &sealed))?;
+    Ok(String::from_utf8(plain).map_err(|e| {
+        e.into_bytes().zeroize();
+        anyhow!("Sealed secret is not text")
+    })?)
+}
+
+/// Compare two secrets in constant time, so the time taken does not reveal
+/// how much of a guess was right. Only the lengths can be told apart.
+#[flutter_rust_bridge::frb]
+pub fn secrets_equal(a: String, b: String) -> bool {
+    let (a, b) = (Zeroizing::new(a), Zeroizing::new(b));
+    a.as_bytes().ct_eq(b.as_bytes()).into()
+}
+
+/// Encrypt data the crate keeps on disk, such as the cookie jar, under the
+/// vault key.
+pub(crate) fn seal_data(name: &str, data: &[u8]) -> Result<Vec<u8>> {
+    current(|vault| seal(&vault.cipher, name.as_bytes(), data))
+}
+
+/// Decrypt data from `seal_data` under the same name.
+pub(crate) fn open_data(name: &str, data: &[u8]) -> Result<Vec<u8>> {
+    current(|vault| {
+        open(&vault.cipher, name.as_bytes(), data)
+            .map_err(|_| anyhow!("Failed to open {name}: wrong device or corrupt data"))
+    })
+}
+
+fn lock() -> MutexGuard<'static, Option<Vault>> {
+    VAULT.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn current<T>(f: impl FnOnce(&Vault) -> Result<T>) -> Result<T> {
+    let guard = lock();
+    let vault = guard
+        .as_ref()
+        .ok_or_else(|| anyhow!("Token vault is locked"))?;
+    f(vault)
+}
+
+/// Cipher under the unlocked vault key; zeroized when dropped.
+struct Vault {
+    cipher: Aes256Gcm,
+}
+
+impl Vault {
+    fn open(dir: &Path, device_secret: &[u8]) -> Result<Self> {
+        if device_secret.len() < MIN_DEVICE_SECRET_LEN {
+            return Err(anyhow!(
+                "Device secret must be at least {MIN_DEVICE_SECRET_LEN} bytes, got {}",
+                device_secret.len()
+            ));
+        }
+        fs::create_dir_all(dir).map_err(|e| anyhow!("Failed to create {}: {e}", dir.display()))?;
+        let kek = Zeroizing::new(blake3::derive_key(KDF_CONTEXT, device_secret));
+        let kek = Aes256Gcm::new_from_slice(kek.as_slice())
+            .map_err(|_| anyhow!("Invalid vault key length"))?;
+        let path: PathBuf = dir.join(KEY_FILE);
+
+        let key = match fs::read(&path) {
+            Ok(data) => unwrap_key(&kek, &data)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                let key = Zeroizing::new(<[u8; 32]>::generate());
+                let mut data = KEY_MAGIC.to_vec();
+                data.extend_from_slice(&seal(&kek, KEY_MAGIC, key.as_slice())?);
+                font_converter::write_atomically(&path, &data)?;
+                key
+            }
+            Err(e) => return Err(anyhow!("Failed to read {}: {e}", path.display())),
+        };
+        let cipher = Aes256Gcm::new_from_slice(key.as_slice())
+            .map_err(|_| anyhow!("Invalid vault key length"))?;
+        Ok(Self { cipher })
+    }
+
+    fn seal(&self, name: &str, secret: &[u8]) -> Result<String> {
+        let sealed = seal(&self.cipher, name.as_bytes(), secret)?;
+        let encoded = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(sealed);
+        Ok(format!("{SECRET_PREFIX}{encoded}"))
+    }
+
+    fn open_sealed(&self, name: &str, sealed: &str) -> Result<Vec<u8>> {
+        let encoded = sealed
+            .trim()
+            .strip_prefix(SECRET_PREFIX)
+            .ok_or_else(|| anyhow!("Not a sealed secret"))?;
+        let data = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(encoded)
+            .map_err(|_| anyhow!("Not a sealed secret"))?;
+        open(&self.cipher, name.as_bytes(), &data).map_err(|_| {
+            anyhow!("Failed to open sealed secret: wrong name, device or corrupt data")
+        })
+    }
+}
+
+fn unwrap_key(kek: &Aes256Gcm, data: &[u8]) -> Result<Zeroizing<[u8; 32]>> {
+    let body = data
+        .strip_prefix(KEY_MAGIC)
+        .ok_or_else(|| anyhow!("Vault key file is not recognized"))?;
+    let plain = Zeroizing::new(
+        open(kek, KEY_MAGIC, body)
+            .map_err(|_| anyhow!("Vault was created on another device or with another key"))?,
+    );
+    let mut key = Zeroizing::new([0; 32]);
+    if plain.len() != key.len() {
+        return Err(anyhow!("Vault key file is corrupt"));
+    }
+    key.copy_from_slice(&plain);
+    Ok(key)
+}
+
+/// Encrypt as the nonce, then ciphertext and tag.
+fn seal(cipher: &Aes256Gcm, aad: &[u8], plain: &[u8]) -> Result<Vec<u8>> {
+    let nonce = Nonce::generate();
+    let sealed = cipher
+        .encrypt(&nonce, Payload { msg: plain, aad })
+        .map_err(|_| anyhow!("Failed to encrypt secret"))?;
+    let mut data = Vec::with_capacity(NONCE_LEN + sealed.len());
+    data.extend_from_slice(&nonce);
+    data.extend_from_slice(&sealed);
+    Ok(data)
+}
+
+fn open(cipher: &Aes256Gcm, aad: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+    if data.len() < NONCE_LEN {
+        return Err(anyhow!("Sealed data is truncated"));
+    }
+    let (nonce, sealed) = data.split_at(NONCE_LEN);
+    let nonce = Nonce::try_from(nonce).map_err(|_| anyhow!("Sealed data is truncated"))?;
+    cipher
+        .decrypt(&nonce, Payload { msg: sealed, aad })
+        .map_err(|_| anyhow!("Failed to decrypt"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_dirs::temp_dir;
+
+    const DEVICE: &[u8] = b"device-bound keystore material";
+
+    #[test]
+    fn test_seal_round_trip() {
+        let dir = temp_dir("round_trip");
+        let vault = Vault::open(&dir, DEVICE).unwrap();
+        let sealed = vault.seal("refresh_token", b"rt-12345").unwrap();
+        assert!(sealed.starts_with(SECRET_PREFIX));
+        assert!(!sealed.contains("rt-12345"));
+        assert_eq!(
+            vault.open_sealed("refresh_token", &sealed).unwrap(),
+            b"rt-12345"
+        );
+
+        // Bound to its name, and tampering is detected.
+        assert!(vault.open_sealed("session_token", &sealed).is_err());
+        let mut tampered = sealed.clone().into_bytes();
+        let last = tampered.len() - 1;
+        tampered[last] = if tampered[last] == b'A' { b'B' } else { b'A' };
+        let tampered = String::from_utf8(tampered).unwrap();
+        assert!(vault.open_sealed("refresh_token", &tampered).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_vault_is_device_bound() {
+        let dir = temp_dir("device_bound");
+        let sealed = Vault::open(&dir, DEVICE)
+            .unwrap()
+            .seal("refresh_token", b"secret")
+            .unwrap();
+
+        // Reopening with the same material reads secrets sealed earlier.
+        let reopened = Vault::open(&dir, DEVICE).unwrap();
+        assert_eq!(
+            reopened.open_sealed("refresh_token", &sealed).unwrap(),
+            b"secret"
+        );
+
+        assert!(Vault::open(&dir, b"some other device material").is_err());
+        assert!(Vault::open(&dir, b"short").is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_secrets_equal() {
+        assert!(secrets_equal("token".to_string(), "token".to_string()));
+        assert!(!secrets_equal("token".to_string(), "tokem".to_string()));
+        assert!(!secrets_equal("token".to_string(), "token2".to_string()));
+    }
+}
@@ -0,0 +1,458 @@
+//! Vertical (竖排) page layout for CJK text.
+//!
+//! Characters run top to bottom and columns right to left. CJK characters
+//! stand upright on a one-em advance; Latin letters, digits and other
+//! horizontal scripts are turned 90° clockwise and take their horizontal
+//! advance, roughly following UAX #50. Punctuation needs its vertical
+//! form: the font's `vert` glyph when it has one, otherwise the Unicode
+//! vertical presentation form, otherwise brackets and dashes are rotated.
+//! Line breaking is the same as `paginate_text`, applied to column height.
+//! Positions are in logical pixels from the page's top-left corner.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+use rustybuzz::{Face, Feature, UnicodeBuffer};
+
+use crate::api::error::ApiError;
+use crate::api::line_break::{Kinsoku, KinsokuRules};
+use crate::api::pagination::{self, PageLayout, Utf16Offsets, EPSILON};
+
+/// Ideographic punctuation and its vertical presentation form.
+const VERTICAL_FORMS: &[(char, char)] = &[
+    ('，', '︐'),
+    ('、', '︑'),
+    ('。', '︒'),
+    ('：', '︓'),
+    ('；', '︔'),
+    ('！', '︕'),
+    ('？', '︖'),
+    ('〖', '︗'),
+    ('〗', '︘'),
+    ('…', '︙'),
+    ('‥', '︰'),
+    ('—', '︱'),
+    ('–', '︲'),
+    ('（', '︵'),
+    ('）', '︶'),
+    ('｛', '︷'),
+    ('｝', '︸'),
+    ('〔', '︹'),
+    ('〕', '︺'),
+    ('【', '︻'),
+    ('】', '︼'),
+    ('《', '︽'),
+    ('》', '︾'),
+    ('〈', '︿'),
+    ('〉', '﹀'),
+    ('「', '﹁'),
+    ('」', '﹂'),
+    ('『', '﹃'),
+    ('』', '﹄'),
+    ('［', '﹇'),
+    ('］', '﹈'),
+];
+
+/// Punctuation that stays upright when the font has no vertical form; the
+/// rest of `VERTICAL_FORMS` is rotated instead.
+const UPRIGHT_FALLBACK: &str = "，、。：；！？";
+
+/// A character placed in a column.
+#[derive(Debug, Clone)]
+pub struct VerticalGlyph {
+    /// UTF-16 offset of the character in the text.
+    pub offset: u32,
+    /// Length in UTF-16 code units.
+    pub len: u32,
+    /// What to draw: the character itself or its vertical presentation
+    /// form.
+    pub text: String,
+    /// Top of the glyph's cell.
+    pub y: f32,
+    /// Height of the glyph's cell.
+    pub advance: f32,
+    /// Draw turned 90° clockwise.
+    pub rotated: bool,
+    /// Draw with the `vert` font feature enabled.
+    pub vert_feature: bool,
+}
+
+/// A laid-out column; `end` excludes trailing whitespace.
+#[derive(Debug, Clone)]
+pub struct TextColumn {
+    pub start: u32,
+    pub end: u32,
+    /// Whether this is the first column of a paragraph, and so indented.
+    pub paragraph_start: bool,
+    /// Left edge of the column, which is one line height wide.
+    pub x: f32,
+    /// Advance height of the column's text, without the indent.
+    pub height: f32,
+    pub glyphs: Vec<VerticalGlyph>,
+}
+
+/// A page of vertical text; pages cover the text contiguously like
+/// `TextPage`.
+#[derive(Debug, Clone)]
+pub struct VerticalPage {
+    pub start: u32,
+    pub end: u32,
+    /// Columns from right to left.
+    pub columns: Vec<TextColumn>,
+}
+
+/// Split `text` into pages of vertical columns. `layout` is read as for
+/// `paginate_text`, with the line height giving the column width and the
+/// indent and paragraph spacing applied down and across.
+///
+/// # Arguments
+/// * `text` - Chapter text
+/// * `font_path` - TTF or OTF file used for measuring; the first face of a
+///   collection
+/// * `layout` - Page size, margins and text style
+/// * `kinsoku` - Characters kept off column edges; `default_kinsoku_rules`
+///   if omitted
+#[flutter_rust_bridge::frb]
+pub fn paginate_vertical_text(
+    text: String,
+    font_path: String,
+    layout: PageLayout,
+    kinsoku: Option<KinsokuRules>,
+) -> Result<Vec<VerticalPage>, ApiError> {
+    let data = pagination::load_font(&font_path)?;
+    let face =
+        Face::from_slice(&data, 0).ok_or_else(|| anyhow!("Failed to parse font {font_path}"))?;
+    let kinsoku = match kinsoku {
+        Some(rules) => Kinsoku::new(&rules),
+        None => Kinsoku::standard(),
+    };
+    Ok(paginate_vertical(&text, &face, &layout, &kinsoku)?)
+}
+
+/// How a character is drawn in a column.
+#[derive(Clone, Copy)]
+enum Form {
+    Upright,
+    Rotated,
+    VertFeature,
+    Presentation(char),
+}
+
+fn paginate_vertical(
+    text: &str,
+    face: &Face,
+    layout: &PageLayout,
+    kinsoku: &Kinsoku,
+) -> Result<Vec<VerticalPage>> {
+    let content_width = layout.width - layout.margin_left - layout.margin_right;
+    let content_height = layout.height - layout.margin_top - layout.margin_bottom;
+    if content_width <= 0.0 || content_height <= 0.0 {
+        return Err(anyhow!("Margins leave no room for text"));
+    }
+    if layout.font_size <= 0.0 || layout.line_height <= 0.0 {
+        return Err(anyhow!("Font size and line height must be positive"));
+    }
+
+    let scale = layout.font_size / face.units_per_em() as f32;
+    let indent = layout.indent * layout.font_size;
+    let column_width = layout.font_size * layout.line_height;
+    let mut forms = FormCache::new(face);
+
+    let mut offsets = Utf16Offsets::new(text);
+    let mut pages = Vec::new();
+    let mut page: Vec<TextColumn> = Vec::new();
+    let mut used = 0.0;
+    let mut paragraph_start = 0;
+    for paragraph in text.split('\n') {
+        let base = paragraph_start;
+        paragraph_start += paragraph.len() + 1;
+        let paragraph = paragraph.strip_suffix('\r').unwrap_or(paragraph);
+        if paragraph.trim().is_empty() {
+            continue;
+        }
+
+        // Prefix heights per byte index, as `prefix_widths` gives widths.
+        let widths = pagination::prefix_widths(paragraph, face, scale);
+        let mut heights = vec![0.0; paragraph.len() + 1];
+        let mut paragraph_forms = Vec::new();
+        let mut total = 0.0;
+        for (i, c) in paragraph.char_indices() {
+            let form = forms.get(c);
+            let next = i + c.len_utf8();
+            heights[i] = total;
+            total += match form {
+                Form::Rotated => widths[next] - widths[i],
+                _ => layout.font_size,
+            };
+            heights[i + 1..next].fill(total);
+            paragraph_forms.push((i, c, form));
+        }
+        heights[paragraph.len()] = total;
+
+        let columns =
+            pagination::break_lines(paragraph, &heights, content_height, indent, 0.0, kinsoku);
+        let mut next_char = 0;
+        for (index, (start, end)) in columns.into_iter().enumerate() {
+            let first = index == 0;
+            let mut advance = column_width;
+            if first && !page.is_empty() {
+                advance += layout.paragraph_spacing;
+            }
+            if !page.is_empty() && used + advance > content_width + EPSILON {
+                pages.push(std::mem::take(&mut page));
+                used = 0.0;
+                advance = column_width;
+            }
+            used += advance;
+
+            let top = layout.margin_top + if first { indent } else { 0.0 };
+            let column_start = offsets.at(base + start);
+            let mut glyphs = Vec::new();
+            while let Some(&(i, c, form)) = paragraph_forms.get(next_char) {
+                if i >= end {
+                    break;
+                }
+                next_char += 1;
+                if i < start || c.is_whitespace() && !matches!(form, Form::Upright) {
+                    continue;
+                }
+                glyphs.push(VerticalGlyph {
+                    offset: offsets.at(base + i),
+                    len: c.len_utf16() as u32,
+                    text: match form {
+                        Form::Presentation(vertical) => vertical.to_string(),
+                        _ => c.to_string(),
+                    },
+                    y: top + heights[i] - heights[start],
+                    advance: heights[i + c.len_utf8()] - heights[i],
+                    rotated: matches!(form, Form::Rotated),
+                    vert_feature: matches!(form, Form::VertFeature),
+                });
+            }
+            page.push(TextColumn {
+                start: column_start,
+                end: offsets.at(base + end),
+                paragraph_start: first,
+                x: layout.margin_left + content_width - used,
+                height: heights[end] - heights[start],
+                glyphs,
+            });
+        }
+    }
+    if !page.is_empty() {
+        pages.push(page);
+    }
+
+    let text_end = offsets.at(text.len());
+    let starts: Vec<u32> = pages.iter().map(|columns| columns[0].start).collect();
+    Ok(pages
+        .into_iter()
+        .enumerate()
+        .map(|(index, columns)| VerticalPage {
+            start: if index == 0 { 0 } else { starts[index] },
+            end: starts.get(index + 1).copied().unwrap_or(text_end),
+            columns,
+        })
+        .collect())
+}
+
+/// Decides each character's form, shaping punctuation once to see whether
+/// the font's `vert` feature replaces it.
+struct FormCache<'a> {
+    face: &'a Face<'a>,
+    vert: Feature,
+    forms: HashMap<char, Form>,
+}
+
+impl<'a> FormCache<'a> {
+    fn new(face: &'a Face<'a>) -> Self {
+        Self {
+            face,
+            vert: Feature::from_str("vert").expect("valid feature"),
+            forms: HashMap::new(),
+        }
+    }
+
+    fn get(&mut self, c: char) -> Form {
+        if let Some(&form) = self.forms.get(&c) {
+            return form;
+        }
+        let form = match VERTICAL_FORMS.iter().find(|(from, _)| *from == c) {
+            Some(&(_, vertical)) => {
+                if self.has_vert_glyph(c) {
+                    Form::VertFeature
+                } else if self.face.glyph_index(vertical).is_some() {
+                    Form::Presentation(vertical)
+                } else if UPRIGHT_FALLBACK.contains(c) {
+                    Form::Upright
+                } else {
+                    Form::Rotated
+                }
+            }
+            None if upright(c) => Form::Upright,
+            None => Form::Rotated,
+        };
+        self.forms.insert(c, form);
+        form
+    }
+
+    fn has_vert_glyph(&self, c: char) -> bool {
+        let glyph = |features: &[Feature]| {
+            let mut buffer = UnicodeBuffer::new();
+            buffer.push_str(c.encode_utf8(&mut [0; 4]));
+            rustybuzz::shape(self.face, features, buffer)
+                .glyph_infos()
+                .first()
+                .map(|info| info.glyph_id)
+        };
+        glyph(&[]) != glyph(std::slice::from_ref(&self.vert))
+    }
+}
+
+/// Whether `c` stands upright in vertical text: CJK scripts, symbols and
+/// full-width forms, and emoji.
+fn upright(c: char) -> bool {
+    matches!(c as u32,
+        0x1100..=0x11FF
+        | 0x2E80..=0x2FFF
+        | 0x3000..=0x303F
+        | 0x3040..=0x33FF
+        | 0x3400..=0x4DBF
+        | 0x4E00..=0x9FFF
+        | 0xA960..=0xA97F
+        | 0xAC00..=0xD7FF
+        | 0xF900..=0xFAFF
+        | 0xFE10..=0xFE1F
+        | 0xFE30..=0xFE4F
+        | 0xFF01..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x1F000..=0x1FAFF
+        | 0x20000..=0x3FFFF)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fonts::{FontBuilder, Glyph};
+
+    /// Single substitutions under `vert`, as a minimal GSUB table.
+    fn vert_gsub(pairs: &[(u16, u16)]) -> Vec<u8> {
+        let mut out = Vec::new();
+        let mut put = |values: &[u16]| {
+            for value in values {
+                out.extend_from_slice(&value.to_be_bytes());
+            }
+        };
+        // Header, then script, feature and lookup lists at 10, 30 and 44.
+        put(&[1, 0, 10, 30, 44]);
+        put(&[1]);
+        out_tag(&mut put, b"DFLT");
+        put(&[8, 4, 0, 0, 0xFFFF, 1, 0]);
+        put(&[1]);
+        out_tag(&mut put, b"vert");
+        put(&[8, 0, 1, 0]);
+        let n = pairs.len() as u16;
+        put(&[1, 4, 1, 0, 1, 8]);
+        put(&[2, 6 + 2 * n, n]);
+        put(&pairs.iter().map(|pair| pair.1).collect::<Vec<_>>());
+        put(&[1, n]);
+        put(&pairs.iter().map(|pair| pair.0).collect::<Vec<_>>());
+        out
+    }
+
+    fn out_tag(put: &mut impl FnMut(&[u16]), tag: &[u8; 4]) {
+        put(&[
+            u16::from_be_bytes([tag[0], tag[1]]),
+            u16::from_be_bytes([tag[2], tag[3]]),
+        ]);
+    }
+
+    fn layout(width: f32, height: f32) -> PageLayout {
+        PageLayout {
+            width,
+            height,
+            margin_top: 0.0,
+            margin_right: 0.0,
+            margin_bottom: 0.0,
+            margin_left: 0.0,
+            font_size: 10.0,
+            line_height: 2.0,
+            paragraph_spacing: 0.0,
+            indent: 0.0,
+        }
+    }
+
+    fn column_texts(pages: &[VerticalPage]) -> Vec<Vec<String>> {
+        pages
+            .iter()
+            .map(|page| {
+                page.columns
+                    .iter()
+                    .map(|column| column.glyphs.iter().map(|g| g.text.as_str()).collect())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_columns_run_right_to_left() {
+        let data = FontBuilder::with_chars("天色渐晚城门外").build();
+        let face = Face::from_slice(&data, 0).unwrap();
+        // Three characters per column, two 20px columns per page.
+        let text = "天色渐晚城\n门外";
+        let pages =
+            paginate_vertical(text, &face, &layout(40.0, 30.0), &Kinsoku::standard()).unwrap();
+        assert_eq!(
+            column_texts(&pages),
+            vec![vec!["天色渐", "晚城"], vec!["门外"]]
+        );
+        let columns = &pages[0].columns;
+        assert_eq!((columns[0].x, columns[1].x), (20.0, 0.0));
+        assert_eq!(columns[1].glyphs[1].y, 10.0);
+        assert_eq!(columns[0].height, 30.0);
+        assert_eq!((pages[0].end, pages[1].start, pages[1].end), (6, 6, 8));
+    }
+
+    #[test]
+    fn test_punctuation_forms() {
+        // The font has a `vert` glyph for "，", a presentation form for
+        // "「", and nothing for "」", which is rotated.
+        let mut builder = FontBuilder::with_chars("天，「」﹁");
+        let alternate = builder.unmapped_glyph(Glyph::Square { advance: 600 });
+        let data = builder
+            .table(*b"GSUB", vert_gsub(&[(2, alternate)]))
+            .build();
+        let face = Face::from_slice(&data, 0).unwrap();
+        let pages = paginate_vertical(
+            "天，「天」",
+            &face,
+            &layout(20.0, 100.0),
+            &Kinsoku::standard(),
+        )
+        .unwrap();
+        let glyphs = &pages[0].columns[0].glyphs;
+        assert_eq!(column_texts(&pages), vec![vec!["天，﹁天」"]]);
+        assert!(glyphs[1].vert_feature && !glyphs[1].rotated);
+        assert!(!glyphs[2].vert_feature && !glyphs[2].rotated);
+        assert!(glyphs[4].rotated);
+        assert_eq!(glyphs[2].offset, 2);
+    }
+
+    #[test]
+    fn test_latin_is_rotated() {
+        let data = FontBuilder::with_chars("天ab")
+            .glyph(' ', Glyph::Empty { advance: 300 })
+            .build();
+        let face = Face::from_slice(&data, 0).unwrap();
+        let pages = paginate_vertical("天ab ab", &face, &layout(20.0, 100.0), &Kinsoku::standard())
+            .unwrap();
+        let glyphs = &pages[0].columns[0].glyphs;
+        // The rotated space is skipped but still takes its advance.
+        let texts: Vec<_> = glyphs.iter().map(|g| g.text.as_str()).collect();
+        assert_eq!(texts, ["天", "a", "b", "a", "b"]);
+        assert!(!glyphs[0].rotated && glyphs[1].rotated);
+        assert_eq!((glyphs[1].advance, glyphs[3].y), (6.0, 25.0));
+        assert_eq!(pages[0].columns[0].height, 37.0);
+    }
+}
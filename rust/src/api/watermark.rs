@@ -0,0 +1,329 @@
+//! Invisible-character and look-alike watermarks in chapter text.
+//!
+//! Sites mark copies of a chapter with characters a reader never sees:
+//! zero-width spaces and joiners, bidi controls, tags and variation
+//! selectors, or letters swapped for look-alikes from another script such
+//! as a Cyrillic `а` in a Latin word, a Kangxi radical `⼀` for `一` or a
+//! CJK compatibility ideograph. A character is only reported where it
+//! cannot be doing its real job: joiners next to emoji or joining scripts,
+//! direction marks next to right-to-left text and keycap variation
+//! selectors are kept, and Cyrillic and Greek letters only count inside
+//! words that also have Latin letters.
+
+use unicode_normalization::UnicodeNormalization;
+
+/// Offsets reported per character.
+const MAX_OFFSETS: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatermarkKind {
+    /// Removed when stripping.
+    Invisible,
+    /// Replaced by the character it imitates when stripping.
+    Homoglyph,
+}
+
+/// One watermark character and where it occurs.
+#[derive(Debug, Clone)]
+pub struct WatermarkChar {
+    pub codepoint: u32,
+    pub name: String,
+    pub kind: WatermarkKind,
+    /// What a homoglyph is replaced by; empty for invisible characters.
+    pub replacement: String,
+    pub count: u32,
+    /// UTF-16 offsets of the first 100 occurrences.
+    pub offsets: Vec<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WatermarkReport {
+    /// Characters in order of first occurrence.
+    pub chars: Vec<WatermarkChar>,
+    pub total: u32,
+}
+
+/// Find watermark characters without changing the text.
+#[flutter_rust_bridge::frb]
+pub fn scan_watermarks(text: String) -> WatermarkReport {
+    let mut chars: Vec<WatermarkChar> = Vec::new();
+    let mut total = 0;
+    strip(&text, |found| {
+        total += 1;
+        let index = match chars.iter().position(|c| c.codepoint == found.c as u32) {
+            Some(index) => index,
+            None => {
+                chars.push(WatermarkChar {
+                    codepoint: found.c as u32,
+                    name: found.name.to_string(),
+                    kind: found.kind(),
+                    replacement: found.replacement.clone().unwrap_or_default(),
+                    count: 0,
+                    offsets: Vec::new(),
+                });
+                chars.len() - 1
+            }
+        };
+        let entry = &mut chars[index];
+        entry.count += 1;
+        if entry.offsets.len() < MAX_OFFSETS {
+            entry.offsets.push(found.offset);
+        }
+    });
+    WatermarkReport { chars, total }
+}
+
+/// Remove invisible watermark characters and replace look-alikes.
+#[flutter_rust_bridge::frb]
+pub fn strip_watermarks(text: String) -> String {
+    strip(&text, |_| {})
+}
+
+/// A watermark character found by `strip`.
+pub(crate) struct Found {
+    pub(crate) c: char,
+    /// UTF-16 offset in the original text.
+    pub(crate) offset: u32,
+    pub(crate) name: &'static str,
+    /// `None` for characters that are removed.
+    pub(crate) replacement: Option<String>,
+}
+
+impl Found {
+    fn kind(&self) -> WatermarkKind {
+        match self.replacement {
+            Some(_) => WatermarkKind::Homoglyph,
+            None => WatermarkKind::Invisible,
+        }
+    }
+
+    /// A short description, such as `U+200B ZERO WIDTH SPACE`.
+    pub(crate) fn describe(&self) -> String {
+        match &self.replacement {
+            Some(replacement) => format!("U+{:04X} {} → {replacement}", self.c as u32, self.name),
+            None => format!("U+{:04X} {}", self.c as u32, self.name),
+        }
+    }
+}
+
+/// The text without watermarks, calling `on_found` for each one.
+pub(crate) fn strip(text: &str, mut on_found: impl FnMut(&Found)) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut offset = 0;
+    for (i, &c) in chars.iter().enumerate() {
+        let prev = i.checked_sub(1).map(|i| chars[i]);
+        let next = chars.get(i + 1).copied();
+        let found = match invisible(c, prev, next) {
+            Some(name) => Some((name, None)),
+            None => homoglyph(&chars, i).map(|(name, to)| (name, Some(to))),
+        };
+        match found {
+            Some((name, replacement)) => {
+                if let Some(replacement) = &replacement {
+                    out.push_str(replacement);
+                }
+                on_found(&Found {
+                    c,
+                    offset,
+                    name,
+                    replacement,
+                });
+            }
+            None => out.push(c),
+        }
+        offset += c.len_utf16() as u32;
+    }
+    out
+}
+
+/// The name of `c` if it is an invisible character with no job here.
+fn invisible(c: char, prev: Option<char>, next: Option<char>) -> Option<&'static str> {
+    let near = |test: fn(char) -> bool| prev.is_some_and(test) || next.is_some_and(test);
+    let name = match c {
+        '\u{200B}' => "ZERO WIDTH SPACE",
+        '\u{200C}' if !near(needs_joiner) => "ZERO WIDTH NON-JOINER",
+        '\u{200D}' if !near(needs_joiner) => "ZERO WIDTH JOINER",
+        '\u{200E}' if !near(is_rtl) => "LEFT-TO-RIGHT MARK",
+        '\u{200F}' if !near(is_rtl) => "RIGHT-TO-LEFT MARK",
+        '\u{061C}' if !near(is_rtl) => "ARABIC LETTER MARK",
+        '\u{202A}' => "LEFT-TO-RIGHT EMBEDDING",
+        '\u{202B}' => "RIGHT-TO-LEFT EMBEDDING",
+        '\u{202C}' => "POP DIRECTIONAL FORMATTING",
+        '\u{202D}' => "LEFT-TO-RIGHT OVERRIDE",
+        '\u{202E}' => "RIGHT-TO-LEFT OVERRIDE",
+        '\u{2066}' => "LEFT-TO-RIGHT ISOLATE",
+        '\u{2067}' => "RIGHT-TO-LEFT ISOLATE",
+        '\u{2068}' => "FIRST STRONG ISOLATE",
+        '\u{2069}' => "POP DIRECTIONAL ISOLATE",
+        '\u{2060}' => "WORD JOINER",
+        '\u{2061}'..='\u{2064}' => "INVISIBLE OPERATOR",
+        '\u{FEFF}' => "ZERO WIDTH NO-BREAK SPACE",
+        '\u{00AD}' => "SOFT HYPHEN",
+        '\u{034F}' => "COMBINING GRAPHEME JOINER",
+        '\u{180E}' => "MONGOLIAN VOWEL SEPARATOR",
+        '\u{115F}' | '\u{1160}' | '\u{3164}' | '\u{FFA0}' => "HANGUL FILLER",
+        // Variation selectors on letters and ideographs, but not keycaps
+        // such as 1️⃣.
+        '\u{FE00}'..='\u{FE0F}'
+            if prev.is_some_and(char::is_alphanumeric) && next != Some('\u{20E3}') =>
+        {
+            "VARIATION SELECTOR"
+        }
+        // Tags, except in emoji flag sequences such as 🏴 for Scotland.
+        '\u{E0001}' | '\u{E0020}'..='\u{E007F}'
+            if !prev
+                .is_some_and(|p| p == '\u{1F3F4}' || ('\u{E0020}'..='\u{E007F}').contains(&p)) =>
+        {
+            "TAG"
+        }
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// Emoji and scripts that use zero-width joiners.
+fn needs_joiner(c: char) -> bool {
+    matches!(c,
+        '\u{0600}'..='\u{06FF}' | '\u{0900}'..='\u{0DFF}' | '\u{2600}'..='\u{27BF}'
+        | '\u{FE0F}' | '\u{1F000}'..='\u{1FAFF}')
+}
+
+pub(crate) fn is_rtl(c: char) -> bool {
+    matches!(c, '\u{0590}'..='\u{08FF}' | '\u{FB1D}'..='\u{FDFF}' | '\u{FE70}'..='\u{FEFC}')
+}
+
+/// The name and replacement of the character at `i` if it imitates
+/// another.
+fn homoglyph(chars: &[char], i: usize) -> Option<(&'static str, String)> {
+    let c = chars[i];
+    match c {
+        '\u{2E80}'..='\u{2FDF}' | '\u{F900}'..='\u{FAFF}' | '\u{2F800}'..='\u{2FA1F}' => {
+            let name = if ('\u{2E80}'..='\u{2FDF}').contains(&c) {
+                "KANGXI RADICAL"
+            } else {
+                "CJK COMPATIBILITY IDEOGRAPH"
+            };
+            let normalized: String = c.to_string().nfkc().collect();
+            (normalized.chars().count() == 1 && normalized != c.to_string())
+                .then_some((name, normalized))
+        }
+        _ => {
+            let (name, latin) = lookalike(c)?;
+            // Only inside a word that also has Latin letters.
+            let is_letter = |c: &char| c.is_alphabetic() && !is_han(*c);
+            let before = chars[..i].iter().rev().take_while(|c| is_letter(c));
+            let after = chars[i + 1..].iter().take_while(|c| is_letter(c));
+            before
+                .chain(after)
+                .any(char::is_ascii_alphabetic)
+                .then(|| (name, latin.to_string()))
+        }
+    }
+}
+
+fn is_han(c: char) -> bool {
+    matches!(c, '\u{3400}'..='\u{9FFF}' | '\u{20000}'..='\u{3FFFF}')
+}
+
+/// Cyrillic and Greek letters that look like Latin ones.
+fn lookalike(c: char) -> Option<(&'static str, char)> {
+    let latin = match c {
+        'а' => 'a',
+        'е' => 'e',
+        'о' => 'o',
+        'р' => 'p',
+        'с' => 'c',
+        'у' => 'y',
+        'х' => 'x',
+        'і' => 'i',
+        'ј' => 'j',
+        'ѕ' => 's',
+        'ԁ' => 'd',
+        'А' => 'A',
+        'В' => 'B',
+        'Е' => 'E',
+        'К' => 'K',
+        'М' => 'M',
+        'Н' => 'H',
+        'О' => 'O',
+        'Р' => 'P',
+        'С' => 'C',
+        'Т' => 'T',
+        'Х' => 'X',
+        'І' => 'I',
+        'Ј' => 'J',
+        'Ѕ' => 'S',
+        'ο' => 'o',
+        'ν' => 'v',
+        'Α' => 'A',
+        'Β' => 'B',
+        'Ε' => 'E',
+        'Ζ' => 'Z',
+        'Η' => 'H',
+        'Ι' => 'I',
+        'Κ' => 'K',
+        'Μ' => 'M',
+        'Ν' => 'N',
+        'Ο' => 'O',
+        'Ρ' => 'P',
+        'Τ' => 'T',
+        'Υ' => 'Y',
+        'Χ' => 'X',
+        _ => return None,
+    };
+    let name = if ('\u{0370}'..='\u{03FF}').contains(&c) {
+        "GREEK LOOK-ALIKE"
+    } else {
+        "CYRILLIC LOOK-ALIKE"
+    };
+    Some((name, latin))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan_reports_offsets() {
+        let text = "天\u{200B}色渐\u{200B}晚，Hаrry说\u{202E}。";
+        let report = scan_watermarks(text.to_string());
+        assert_eq!(report.total, 4);
+        let summary: Vec<_> = report
+            .chars
+            .iter()
+            .map(|c| {
+                (
+                    c.codepoint,
+                    c.kind,
+                    c.replacement.as_str(),
+                    c.offsets.clone(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (0x200B, WatermarkKind::Invisible, "", vec![1, 4]),
+                (0x0430, WatermarkKind::Homoglyph, "a", vec![8]),
+                (0x202E, WatermarkKind::Invisible, "", vec![13]),
+            ]
+        );
+        assert_eq!(strip_watermarks(text.to_string()), "天色渐晚，Harry说。");
+    }
+
+    #[test]
+    fn test_keeps_characters_doing_their_job() {
+        for text in [
+            "👨\u{200D}👩\u{200D}👧",
+            "1\u{FE0F}\u{20E3}",
+            "שלום\u{200F}",
+            "Привет, мир",
+            "♥\u{FE0F}",
+        ] {
+            assert_eq!(scan_watermarks(text.to_string()).total, 0, "{text}");
+        }
+        assert_eq!(strip_watermarks("⼀个\u{F900}".to_string()), "一个豈");
+        assert_eq!(strip_watermarks("他\u{FE01}说".to_string()), "他说");
+    }
+}
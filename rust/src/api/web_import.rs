@@ -0,0 +1,518 @@
+//! Import a web novel from its index page, without a book source.
+//!
+//! `detect_web_toc` fetches the index and picks the chapter list out of it:
+//! links are grouped by the list, table or `div` holding them, and each
+//! group is scored by how many links it has, how many of their texts look
+//! like chapter titles (第十二章, `Chapter 12`, `012`, 番外 and so on), how
+//! much of the group's text is link text and how many links stay on the
+//! site. Lists in reverse order are flipped, and index pages split over
+//! several pages are followed through their "next page" links.
+//!
+//! `import_web_book` then fetches every chapter, takes its text with the
+//! readability extractor, following "next page" links within the chapter,
+//! purifies it and writes it to the chapter cache. Chapters already cached
+//! are skipped, so an interrupted import picks up where it stopped.
+
+use std::collections::{HashMap, HashSet};
+use std::io::Read;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use regex::Regex;
+use scraper::{ElementRef, Html, Selector};
+use url::Url;
+
+use crate::api::book_source;
+use crate::api::chapter_cache;
+use crate::api::chapter_sort;
+use crate::api::error::ApiError;
+use crate::api::network;
+use crate::api::purify::{self, PurifyRule};
+use crate::api::readability::{self, ReadableBlockKind};
+use crate::api::task::{self, CancelToken};
+use crate::frb_generated::StreamSink;
+
+/// Index pages followed at most.
+const MAX_TOC_PAGES: usize = 20;
+
+/// Pages of a single chapter followed at most.
+const MAX_CHAPTER_PAGES: usize = 10;
+
+/// Fewest links a chapter list can have.
+const MIN_CHAPTERS: usize = 3;
+
+/// Elements links are grouped by.
+const CONTAINERS: &[&str] = &[
+    "ul", "ol", "dl", "table", "div", "section", "article", "body",
+];
+
+#[derive(Debug, Clone)]
+pub struct WebBook {
+    pub title: Option<String>,
+    pub author: Option<String>,
+    /// The index page, after redirects.
+    pub url: String,
+    pub chapters: Vec<WebChapter>,
+    /// How sure the detector is that `chapters` is the chapter list, from 0
+    /// to 1. Below about 0.5 the user should check the list.
+    pub confidence: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct WebChapter {
+    pub index: u32,
+    pub title: String,
+    pub url: String,
+}
+
+/// Reported after each chapter.
+#[derive(Debug, Clone)]
+pub struct WebImportProgress {
+    pub done: u32,
+    pub total: u32,
+    pub chapter_index: u32,
+    /// Whether the chapter was already cached and not fetched again.
+    pub skipped: bool,
+    /// Why the chapter could not be imported; the others carry on.
+    pub error: Option<String>,
+}
+
+/// Fetch a web novel's index page and detect its chapter list.
+///
+/// # Arguments
+/// * `url` - Index page, the one listing the chapters
+#[flutter_rust_bridge::frb]
+pub fn detect_web_toc(url: String) -> Result<WebBook, ApiError> {
+    let start = Url::parse(&url).map_err(|e| anyhow!("Invalid URL {url}: {e}"))?;
+    let (html, base) = fetch(&start)?;
+    let mut detected = detect(&html, &base);
+    let mut seen_pages = HashSet::from([start, base.clone()]);
+    let mut seen_chapters: HashSet<String> = detected
+        .book
+        .chapters
+        .iter()
+        .map(|c| c.url.clone())
+        .collect();
+    let mut next = detected.next_page.take();
+    while let Some(page) = next.take() {
+        if seen_pages.len() >= MAX_TOC_PAGES || !seen_pages.insert(page.clone()) {
+            break;
+        }
+        let (html, base) = fetch(&page)?;
+        let more = detect(&html, &base);
+        for chapter in more.book.chapters {
+            if seen_chapters.insert(chapter.url.clone()) {
+                detected.book.chapters.push(chapter);
+            }
+        }
+        next = more.next_page;
+    }
+    for (index, chapter) in detected.book.chapters.iter_mut().enumerate() {
+        chapter.index = index as u32;
+    }
+    if detected.book.chapters.is_empty() {
+        return Err(anyhow!("No chapter list found on {url}").into());
+    }
+    Ok(detected.book)
+}
+
+/// Fetch chapters, purify them and write them to the chapter cache, which
+/// must be set up with `init_chapter_cache`.
+///
+/// # Arguments
+/// * `book_id` - Library ID the chapters are cached under
+/// * `chapters` - Chapters to import, usually from `detect_web_toc`
+/// * `purify_rules` - Clean-up rules applied to every chapter; may be empty
+/// * `sink` - Receives one event per chapter
+/// * `cancel_token` - Token from `create_cancel_token` to abandon the call
+#[flutter_rust_bridge::frb]
+pub async fn import_web_book(
+    book_id: String,
+    chapters: Vec<WebChapter>,
+    purify_rules: Vec<PurifyRule>,
+    sink: StreamSink<WebImportProgress>,
+    cancel_token: Option<u32>,
+) -> Result<(), ApiError> {
+    task::run_blocking_io(task::cancel_token(cancel_token)?, move |token| {
+        import(&book_id, &chapters, &purify_rules, token, &mut |progress| {
+            sink.add(progress)
+                .map_err(|_| anyhow!("Web import progress stream was closed"))
+        })
+    })
+    .await
+}
+
+fn import(
+    book_id: &str,
+    chapters: &[WebChapter],
+    purify_rules: &[PurifyRule],
+    token: &CancelToken,
+    progress: &mut dyn FnMut(WebImportProgress) -> Result<()>,
+) -> Result<()> {
+    for (done, chapter) in chapters.iter().enumerate() {
+        token.check()?;
+        let cached = chapter_cache::read_cached_chapter(book_id.to_string(), chapter.index)?;
+        let result = match cached {
+            Some(_) => Ok(true),
+            None => {
+                let next_chapter = chapters.get(done + 1).map(|c| c.url.as_str());
+                chapter_text(chapter, next_chapter)
+                    .and_then(|text| Ok(purify::purify_text(text, purify_rules.to_vec())?))
+                    .and_then(|text| {
+                        Ok(chapter_cache::write_cached_chapter(
+                            book_id.to_string(),
+                            chapter.index,
+                            text,
+                        )?)
+                    })
+                    .map(|_| false)
+            }
+        };
+        progress(WebImportProgress {
+            done: done as u32 + 1,
+            total: chapters.len() as u32,
+            chapter_index: chapter.index,
+            skipped: result.as_ref().is_ok_and(|&skipped| skipped),
+            error: result.err().map(|e| e.to_string()),
+        })?;
+    }
+    Ok(())
+}
+
+fn fetch(url: &Url) -> Result<(String, Url)> {
+    let agent = network::agent(
+        url.as_str(),
+        Duration::from_secs(30),
+        Duration::from_secs(60),
+        None,
+    )?;
+    let response = agent.request_url("GET", url).call().map_err(|e| match e {
+        ureq::Error::Status(code, _) => anyhow!("Failed to fetch {url}: HTTP {code}"),
+        e => anyhow!("Failed to fetch {url}: {e}"),
+    })?;
+    let final_url = Url::parse(response.get_url()).unwrap_or_else(|_| url.clone());
+    let content_type = response.header("Content-Type").map(str::to_string);
+    let mut bytes = Vec::new();
+    response
+        .into_reader()
+        .take(book_source::MAX_PAGE_BYTES + 1)
+        .read_to_end(&mut bytes)
+        .map_err(|e| anyhow!("Failed to read {url}: {e}"))?;
+    if bytes.len() as u64 > book_source::MAX_PAGE_BYTES {
+        return Err(anyhow!("{url} is too large"));
+    }
+    Ok((
+        book_source::decode(&bytes, None, content_type.as_deref()),
+        final_url,
+    ))
+}
+
+/// The text of a chapter, one paragraph per line, with its continuation
+/// pages appended.
+fn chapter_text(chapter: &WebChapter, next_chapter: Option<&str>) -> Result<String> {
+    let mut url =
+        Url::parse(&chapter.url).map_err(|e| anyhow!("Invalid URL {}: {e}", chapter.url))?;
+    let stem = path_stem(&url);
+    let mut seen = HashSet::new();
+    let mut paragraphs: Vec<String> = Vec::new();
+    loop {
+        seen.insert(url.clone());
+        let (html, base) = fetch(&url)?;
+        let content = readability::extract(&html, Some(&base));
+        for block in content.blocks {
+            let text: String = block
+                .spans
+                .iter()
+                .filter(|span| span.image_src.is_none())
+                .map(|span| span.text.as_str())
+                .collect();
+            let text = text.trim();
+            let repeated_title = block.kind == ReadableBlockKind::Heading
+                && (paragraphs.is_empty() || text == chapter.title.trim());
+            if !text.is_empty() && !repeated_title {
+                paragraphs.push(text.to_string());
+            }
+        }
+        // Continuation pages share the chapter's path, as in 12.html and
+        // 12_2.html; anything else is the next chapter.
+        let next = next_page(&Html::parse_document(&html), &base).filter(|next| {
+            Some(next.as_str()) != next_chapter
+                && path_stem(next).starts_with(&stem)
+                && !seen.contains(next)
+        });
+        match next {
+            Some(next) if seen.len() < MAX_CHAPTER_PAGES => url = next,
+            _ => break,
+        }
+    }
+    if paragraphs.is_empty() {
+        return Err(anyhow!("No text found on {}", chapter.url));
+    }
+    Ok(paragraphs.join("\n"))
+}
+
+/// A URL's path without its extension.
+fn path_stem(url: &Url) -> String {
+    let path = url.path();
+    match path.rfind('.') {
+        Some(dot) if !path[dot..].contains('/') => path[..dot].to_string(),
+        _ => path.to_string(),
+    }
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+struct Detected {
+    book: WebBook,
+    next_page: Option<Url>,
+}
+
+/// A link in a candidate chapter list.
+#[flutter_rust_bridge::frb(ignore)]
+struct TocLink {
+    title: String,
+    url: Url,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+struct LinkGroup {
+    links: Vec<TocLink>,
+    /// Characters of link text, for the link density.
+    link_chars: usize,
+}
+
+fn detect(html: &str, base: &Url) -> Detected {
+    let document = Html::parse_document(html);
+    let anchors = Selector::parse("a[href]").unwrap();
+
+    let mut groups: HashMap<ego_tree::NodeId, LinkGroup> = HashMap::new();
+    let mut containers: HashMap<ego_tree::NodeId, ElementRef> = HashMap::new();
+    for anchor in document.select(&anchors) {
+        let href = anchor.value().attr("href").unwrap_or_default().trim();
+        if href.is_empty() || href.starts_with('#') || href.starts_with("javascript:") {
+            continue;
+        }
+        let Ok(url) = base.join(href) else { continue };
+        let title = normalize(&anchor.text().collect::<String>());
+        if title.is_empty() || !matches!(url.scheme(), "http" | "https") || url == *base {
+            continue;
+        }
+        let Some(container) = anchor
+            .ancestors()
+            .filter_map(ElementRef::wrap)
+            .find(|element| CONTAINERS.contains(&element.value().name()))
+        else {
+            continue;
+        };
+        containers.insert(container.id(), container);
+        let group = groups.entry(container.id()).or_default();
+        group.link_chars += title.chars().count();
+        group.links.push(TocLink { title, url });
+    }
+
+    let pattern = title_pattern();
+    let best = groups
+        .into_iter()
+        .filter_map(|(id, mut group)| {
+            let mut seen = HashSet::new();
+            group.links.retain(|link| seen.insert(link.url.clone()));
+            if group.links.len() < MIN_CHAPTERS {
+                return None;
+            }
+            let count = group.links.len() as f32;
+            let titled = group
+                .links
+                .iter()
+                .filter(|l| pattern.is_match(&l.title))
+                .count();
+            let title_ratio = titled as f32 / count;
+            let same_site = group
+                .links
+                .iter()
+                .filter(|link| link.url.host_str() == base.host_str())
+                .count() as f32
+                / count;
+            let text_chars = containers[&id]
+                .text()
+                .flat_map(str::chars)
+                .filter(|c| !c.is_whitespace())
+                .count()
+                .max(1);
+            let density = (group.link_chars as f32 / text_chars as f32).min(1.0);
+            let score = count * (0.1 + title_ratio) * (0.2 + density) * same_site;
+            let confidence = (0.6 * title_ratio + 0.2 * density + 0.2 * same_site).min(1.0);
+            Some((score, confidence, group))
+        })
+        .max_by(|a, b| a.0.total_cmp(&b.0));
+
+    let (confidence, mut links) = best.map_or((0.0, Vec::new()), |(_, c, group)| (c, group.links));
+    if is_descending(&links, &pattern) {
+        links.reverse();
+    }
+    let chapters = links
+        .into_iter()
+        .enumerate()
+        .map(|(index, link)| WebChapter {
+            index: index as u32,
+            title: link.title,
+            url: link.url.to_string(),
+        })
+        .collect();
+    let (title, author) = book_details(&document);
+    Detected {
+        book: WebBook {
+            title,
+            author,
+            url: base.to_string(),
+            chapters,
+            confidence,
+        },
+        next_page: next_page(&document, base),
+    }
+}
+
+fn title_pattern() -> Regex {
+    Regex::new(concat!(
+        r"(?i)^\s*[【\[(（]?\s*(?:",
+        r"第\s*[0-9零〇一二两三四五六七八九十百千万]+\s*[章节回话集卷幕部篇]",
+        r"|chapter\s*\d+|ch\.?\s*\d+|\d{1,5}",
+        r"|序章|序言|楔子|引子|番外|尾声|后记|终章|完本感言)"
+    ))
+    .unwrap()
+}
+
+/// Whether the numbered titles mostly count down, as in lists that put
+/// the newest chapter first.
+fn is_descending(links: &[TocLink], pattern: &Regex) -> bool {
+    let values: Vec<u64> = links
+        .iter()
+        .filter(|link| pattern.is_match(&link.title))
+        .filter_map(|link| chapter_sort::chapter_number(link.title.clone()))
+        .collect();
+    let (mut up, mut down) = (0, 0);
+    for pair in values.windows(2) {
+        match pair[0].cmp(&pair[1]) {
+            std::cmp::Ordering::Less => up += 1,
+            std::cmp::Ordering::Greater => down += 1,
+            std::cmp::Ordering::Equal => {}
+        }
+    }
+    down > up * 2 && down >= MIN_CHAPTERS - 1
+}
+
+/// The link to the next page of a paginated list or chapter.
+fn next_page(document: &Html, base: &Url) -> Option<Url> {
+    let next = Regex::new(r"(?i)^(?:下一页|下页|下一頁|next\s*page|next\s*›|›|»)$").unwrap();
+    let anchors = Selector::parse("a[href]").unwrap();
+    document
+        .select(&anchors)
+        .find(|a| next.is_match(&normalize(&a.text().collect::<String>())))
+        .and_then(|a| base.join(a.value().attr("href")?).ok())
+        .filter(|url| url != base && matches!(url.scheme(), "http" | "https"))
+}
+
+/// Title and author from the `og:novel:*` tags many novel sites carry,
+/// else the first `<h1>` or the document title.
+fn book_details(document: &Html) -> (Option<String>, Option<String>) {
+    let meta = |property: &str| {
+        let selector = Selector::parse(&format!("meta[property=\"{property}\"]")).ok()?;
+        document
+            .select(&selector)
+            .find_map(|element| element.value().attr("content"))
+            .map(normalize)
+            .filter(|text| !text.is_empty())
+    };
+    let first_text = |name: &str| {
+        let selector = Selector::parse(name).ok()?;
+        document
+            .select(&selector)
+            .map(|element| normalize(&element.text().collect::<String>()))
+            .find(|text| !text.is_empty())
+    };
+    let title = meta("og:novel:book_name")
+        .or_else(|| first_text("h1"))
+        .or_else(|| {
+            // Site names follow separators such as `书名_作者_站名`.
+            first_text("title")
+                .and_then(|title| title.split(['_', '|', '-', '–']).next().map(normalize))
+        })
+        .filter(|title| !title.is_empty());
+    (title, meta("og:novel:author"))
+}
+
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base() -> Url {
+        Url::parse("https://novel.example/book/42/").unwrap()
+    }
+
+    fn titles(detected: &Detected) -> Vec<&str> {
+        detected
+            .book
+            .chapters
+            .iter()
+            .map(|c| c.title.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_picks_chapter_list_over_navigation() {
+        let html = r#"<html><head><title>剑来_烽火_某某书屋</title>
+            <meta property="og:novel:author" content="烽火"></head><body>
+            <div class="nav"><a href="/">首页</a><a href="/top">排行榜</a>
+              <a href="/full">完本小说</a><a href="/new">最新更新</a></div>
+            <div class="latest"><a href="3.html">第三章 新</a></div>
+            <dl><dt>正文</dt>
+              <dd><a href="1.html">第一章 惊蛰</a></dd>
+              <dd><a href="2.html">第二章 开门</a></dd>
+              <dd><a href="2.html">第二章 开门</a></dd>
+              <dd><a href="3.html">第三章 草鞋</a></dd>
+              <dd><a href="4.html">番外 一</a></dd>
+            </dl>
+            <a href="index_2.html">下一页</a></body></html>"#;
+        let detected = detect(html, &base());
+        assert_eq!(
+            titles(&detected),
+            ["第一章 惊蛰", "第二章 开门", "第三章 草鞋", "番外 一"]
+        );
+        assert_eq!(
+            detected.book.chapters[2].url,
+            "https://novel.example/book/42/3.html"
+        );
+        assert_eq!(detected.book.title.as_deref(), Some("剑来"));
+        assert_eq!(detected.book.author.as_deref(), Some("烽火"));
+        assert!(detected.book.confidence > 0.8);
+        assert_eq!(
+            detected.next_page.unwrap().as_str(),
+            "https://novel.example/book/42/index_2.html"
+        );
+    }
+
+    #[test]
+    fn test_reverses_newest_first_lists() {
+        let html = r#"<ul>
+            <li><a href="c/4">004 风起</a></li><li><a href="c/3">003 云涌</a></li>
+            <li><a href="c/2">002 暗潮</a></li><li><a href="c/1">001 峥嵘初露</a></li>
+            </ul>"#;
+        let detected = detect(html, &base());
+        assert_eq!(
+            titles(&detected),
+            ["001 峥嵘初露", "002 暗潮", "003 云涌", "004 风起"]
+        );
+        assert!(detected.next_page.is_none());
+    }
+
+    #[test]
+    fn test_path_stem() {
+        let url = |s: &str| Url::parse(s).unwrap();
+        assert_eq!(path_stem(&url("https://a.example/b/12.html")), "/b/12");
+        assert!(path_stem(&url("https://a.example/b/12_2.html")).starts_with("/b/12"));
+        assert_eq!(path_stem(&url("https://a.example/v1.2/12")), "/v1.2/12");
+    }
+}
@@ -0,0 +1,619 @@
+//! WebDAV backup and sync, for Nutstore (坚果云) and self-hosted servers.
+//!
+//! Remote paths are relative to the configured base URL and use `/`
+//! separators. Every uploaded file gets a `<name>.blake3` sidecar holding
+//! the BLAKE3 hash of its content, so two devices can tell whether they
+//! hold the same backup without downloading it. Downloads go through the
+//! downloader's resumable fetch: a download that fails midway picks up from
+//! its partial file when retried.
+
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use base64::Engine;
+use percent_encoding::percent_decode_str;
+use quick_xml::events::Event;
+use url::Url;
+
+use crate::api::downloader::{self, DownloadHeader, DownloadRequest, Failure, Progress};
+use crate::api::error::ApiError;
+use crate::api::http_cache::parse_http_date;
+use crate::api::network;
+use crate::remote::RemoteFile;
+use crate::xhtml;
+
+const HASH_SUFFIX: &str = ".blake3";
+
+const PROPFIND_BODY: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:propfind xmlns:d="DAV:"><d:prop>
+<d:resourcetype/><d:getcontentlength/><d:getlastmodified/><d:getetag/>
+</d:prop></d:propfind>"#;
+
+#[derive(Debug, Clone)]
+pub struct WebDavConfig {
+    /// Root of the backup area, e.g. `https://dav.jianguoyun.com/dav/Novella/`.
+    pub base_url: String,
+    pub username: String,
+    /// Password, or the app password Nutstore issues for third-party apps.
+    pub password: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct WebDavEntry {
+    /// Path relative to the base URL, without a trailing slash.
+    pub path: String,
+    pub name: String,
+    pub is_dir: bool,
+    pub size: u64,
+    /// Last modification time in seconds since the Unix epoch.
+    pub modified: Option<i64>,
+    pub etag: Option<String>,
+}
+
+/// What a device knew about a file after it last uploaded or downloaded
+/// it. Store it and pass it to `webdav_sync_status` next time.
+#[derive(Debug, Clone)]
+pub struct WebDavSyncRecord {
+    /// BLAKE3 hash of the file content, in hex.
+    pub hash: String,
+    pub etag: Option<String>,
+    pub modified: Option<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebDavSyncStatus {
+    InSync,
+    /// Only the local file changed since the last sync; upload it.
+    LocalChanged,
+    /// Only the remote file changed since the last sync; download it.
+    RemoteChanged,
+    /// Both sides changed, or they differ and there is no sync record.
+    Conflict,
+    RemoteMissing,
+}
+
+/// Check that the server is reachable and the credentials are accepted.
+/// The base directory is created if it does not exist.
+#[flutter_rust_bridge::frb]
+pub fn webdav_test_connection(config: WebDavConfig) -> Result<(), ApiError> {
+    let client = Client::new(&config)?;
+    if client.stat("")?.is_none() {
+        client.execute(client.request("MKCOL", "")?, Body::Empty, &[])?;
+    }
+    Ok(())
+}
+
+/// List the entries of a remote directory. Hash sidecars are left out.
+///
+/// # Arguments
+/// * `config` - Server and credentials
+/// * `path` - Directory relative to the base URL; empty for the base itself
+#[flutter_rust_bridge::frb]
+pub fn webdav_list(config: WebDavConfig, path: String) -> Result<Vec<WebDavEntry>, ApiError> {
+    let client = Client::new(&config)?;
+    let entries = client
+        .propfind(&path, "1")?
+        .ok_or_else(|| anyhow!("{path} does not exist on the server"))?;
+    let own_path = normalize(&path);
+    Ok(entries
+        .into_iter()
+        .filter(|entry| entry.path != own_path && !entry.name.ends_with(HASH_SUFFIX))
+        .collect())
+}
+
+/// Upload a local file, creating missing remote directories, and return
+/// the record to store for later sync checks.
+///
+/// # Arguments
+/// * `config` - Server and credentials
+/// * `local_path` - File to upload
+/// * `remote_path` - Destination relative to the base URL
+#[flutter_rust_bridge::frb]
+pub fn webdav_upload(
+    config: WebDavConfig,
+    local_path: String,
+    remote_path: String,
+) -> Result<WebDavSyncRecord, ApiError> {
+    let client = Client::new(&config)?;
+    let hash = hash_file(Path::new(&local_path))?;
+    let file = File::open(&local_path).map_err(|e| anyhow!("Failed to open {local_path}: {e}"))?;
+    let size = file
+        .metadata()
+        .map_err(|e| anyhow!("Failed to read {local_path}: {e}"))?
+        .len();
+
+    if let Some((parent, _)) = normalize(&remote_path).rsplit_once('/') {
+        client.make_dirs(parent)?;
+    }
+    let request = client
+        .request("PUT", &remote_path)?
+        .set("Content-Length", &size.to_string());
+    client.execute(request, Body::Reader(Box::new(file)), &[])?;
+    let request = client.request("PUT", &format!("{remote_path}{HASH_SUFFIX}"))?;
+    client.execute(request, Body::Text(&hash), &[])?;
+    Ok(client.record(&remote_path, hash)?)
+}
+
+/// Download a remote file and return the record to store for later sync
+/// checks. The local file is replaced only once the download is complete.
+///
+/// # Arguments
+/// * `config` - Server and credentials
+/// * `remote_path` - File relative to the base URL
+/// * `local_path` - Destination; missing parent directories are created
+#[flutter_rust_bridge::frb]
+pub fn webdav_download(
+    config: WebDavConfig,
+    remote_path: String,
+    local_path: String,
+) -> Result<WebDavSyncRecord, ApiError> {
+    let client = Client::new(&config)?;
+    let request = DownloadRequest {
+        url: client.url(&remote_path)?.to_string(),
+        destination: local_path.clone(),
+        headers: vec![DownloadHeader {
+            name: "Authorization".to_string(),
+            value: client.authorization.clone(),
+        }],
+        checksum: None,
+    };
+    let mut progress = Progress::default();
+    let cancelled = AtomicBool::new(false);
+    downloader::fetch(
+        &client.agent,
+        &request,
+        &cancelled,
+        &mut progress,
+        |bytes| downloader::pace(bytes, &cancelled),
+        |_| {},
+    )
+    .map_err(|failure| match failure {
+        Failure::Retry(e) | Failure::Fatal(e) => anyhow!("Failed to download {remote_path}: {e}"),
+        // Only background downloads are paused.
+        Failure::Cancelled | Failure::Paused => anyhow!("Download of {remote_path} was cancelled"),
+    })?;
+    Ok(client.record(&remote_path, hash_file(Path::new(&local_path))?)?)
+}
+
+/// Delete a remote file or directory, along with a file's hash sidecar.
+#[flutter_rust_bridge::frb]
+pub fn webdav_delete(config: WebDavConfig, remote_path: String) -> Result<(), ApiError> {
+    let client = Client::new(&config)?;
+    client.delete(&remote_path)?;
+    Ok(client.delete(&format!("{}{HASH_SUFFIX}", normalize(&remote_path)))?)
+}
+
+/// Compare a local file with its remote copy.
+///
+/// # Arguments
+/// * `config` - Server and credentials
+/// * `local_path` - Local copy of the file
+/// * `remote_path` - Remote copy, relative to the base URL
+/// * `last_sync` - Record returned by the last upload or download, if any
+#[flutter_rust_bridge::frb]
+pub fn webdav_sync_status(
+    config: WebDavConfig,
+    local_path: String,
+    remote_path: String,
+    last_sync: Option<WebDavSyncRecord>,
+) -> Result<WebDavSyncStatus, ApiError> {
+    let client = Client::new(&config)?;
+    let local_hash = hash_file(Path::new(&local_path))?;
+    let Some(remote) = client.stat(&remote_path)? else {
+        return Ok(WebDavSyncStatus::RemoteMissing);
+    };
+    let remote_hash = client.read_text(&format!("{remote_path}{HASH_SUFFIX}"))?;
+    Ok(sync_status(
+        &local_hash,
+        &remote,
+        remote_hash.as_deref().map(str::trim),
+        last_sync.as_ref(),
+    ))
+}
+
+fn sync_status(
+    local_hash: &str,
+    remote: &WebDavEntry,
+    remote_hash: Option<&str>,
+    last_sync: Option<&WebDavSyncRecord>,
+) -> WebDavSyncStatus {
+    if remote_hash == Some(local_hash) {
+        return WebDavSyncStatus::InSync;
+    }
+    let Some(record) = last_sync else {
+        return WebDavSyncStatus::Conflict;
+    };
+    let local_changed = local_hash != record.hash;
+    // Prefer the content hash; fall back to the ETag, then the timestamp,
+    // for files uploaded by other clients.
+    let remote_changed = match (remote_hash, &remote.etag, &record.etag) {
+        (Some(hash), _, _) => hash != record.hash,
+        (None, Some(etag), Some(known)) => etag != known,
+        _ => remote.modified != record.modified,
+    };
+    match (local_changed, remote_changed) {
+        (false, false) => WebDavSyncStatus::InSync,
+        (true, false) => WebDavSyncStatus::LocalChanged,
+        (false, true) => WebDavSyncStatus::RemoteChanged,
+        (true, true) => WebDavSyncStatus::Conflict,
+    }
+}
+
+enum Body<'a> {
+    Empty,
+    Text(&'a str),
+    Bytes(&'a [u8]),
+    Reader(Box<dyn Read + Send + Sync>),
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+pub(crate) struct Client {
+    agent: ureq::Agent,
+    base: Url,
+    authorization: String,
+}
+
+impl Client {
+    pub(crate) fn new(config: &WebDavConfig) -> Result<Self> {
+        let mut base = Url::parse(&config.base_url)
+            .map_err(|e| anyhow!("Invalid WebDAV URL {}: {e}", config.base_url))?;
+        if !matches!(base.scheme(), "http" | "https") {
+            return Err(anyhow!("Unsupported URL scheme: {}", base.scheme()));
+        }
+        // Treat the base as a directory so relative paths resolve inside it.
+        if !base.path().ends_with('/') {
+            base.set_path(&format!("{}/", base.path()));
+        }
+        let credentials = format!("{}:{}", config.username, config.password);
+        Ok(Self {
+            agent: network::agent(
+                base.as_str(),
+                Duration::from_secs(30),
+                Duration::from_secs(60),
+                None,
+            )?,
+            base,
+            authorization: format!(
+                "Basic {}",
+                base64::engine::general_purpose::STANDARD.encode(credentials)
+            ),
+        })
+    }
+
+    fn url(&self, path: &str) -> Result<Url> {
+        let mut url = self.base.clone();
+        url.path_segments_mut()
+            .map_err(|()| anyhow!("Invalid WebDAV URL {}", self.base))?
+            .pop_if_empty()
+            .extend(normalize(path).split('/').filter(|s| !s.is_empty()));
+        Ok(url)
+    }
+
+    fn request(&self, method: &str, path: &str) -> Result<ureq::Request> {
+        Ok(self
+            .agent
+            .request_url(method, &self.url(path)?)
+            .set("Authorization", &self.authorization))
+    }
+
+    /// Send a request, turning HTTP errors other than `allowed` into errors.
+    fn execute(
+        &self,
+        request: ureq::Request,
+        body: Body,
+        allowed: &[u16],
+    ) -> Result<Option<ureq::Response>> {
+        let method = request.method().to_string();
+        let result = match body {
+            Body::Empty => request.call(),
+            Body::Text(text) => request.send_string(text),
+            Body::Bytes(bytes) => request.send_bytes(bytes),
+            Body::Reader(reader) => request.send(reader),
+        };
+        match result {
+            Ok(response) => Ok(Some(response)),
+            Err(ureq::Error::Status(code, _)) if allowed.contains(&code) => Ok(None),
+            Err(ureq::Error::Status(401, _)) => Err(anyhow!("WebDAV authentication failed")),
+            Err(ureq::Error::Status(code, _)) => {
+                Err(anyhow!("WebDAV {method} failed: HTTP {code}"))
+            }
+            Err(e) => Err(anyhow!("WebDAV {method} failed: {e}")),
+        }
+    }
+
+    /// Entries at `path` and, with depth 1, its children; `None` if `path`
+    /// does not exist.
+    fn propfind(&self, path: &str, depth: &str) -> Result<Option<Vec<WebDavEntry>>> {
+        let request = self
+            .request("PROPFIND", path)?
+            .set("Depth", depth)
+            .set("Content-Type", "application/xml; charset=utf-8");
+        let Some(response) = self.execute(request, Body::Text(PROPFIND_BODY), &[404])? else {
+            return Ok(None);
+        };
+        let xml = response
+            .into_string()
+            .map_err(|e| anyhow!("Failed to read WebDAV listing: {e}"))?;
+        parse_multistatus(&xml, self.base.path()).map(Some)
+    }
+
+    fn stat(&self, path: &str) -> Result<Option<WebDavEntry>> {
+        Ok(self
+            .propfind(path, "0")?
+            .and_then(|entries| entries.into_iter().next()))
+    }
+
+    fn read_text(&self, path: &str) -> Result<Option<String>> {
+        let Some(response) = self.execute(self.request("GET", path)?, Body::Empty, &[404])? else {
+            return Ok(None);
+        };
+        response
+            .into_string()
+            .map(Some)
+            .map_err(|e| anyhow!("Failed to read {path}: {e}"))
+    }
+
+    /// A file's content and ETag; `None` if it does not exist.
+    pub(crate) fn read_bytes(&self, path: &str, max_bytes: u64) -> Result<Option<RemoteFile>> {
+        let Some(response) = self.execute(self.request("GET", path)?, Body::Empty, &[404])? else {
+            return Ok(None);
+        };
+        let etag = response.header("ETag").map(str::to_string);
+        let mut data = Vec::new();
+        response
+            .into_reader()
+            .take(max_bytes + 1)
+            .read_to_end(&mut data)
+            .map_err(|e| anyhow!("Failed to read {path}: {e}"))?;
+        if data.len() as u64 > max_bytes {
+            return Err(anyhow!("{path} is larger than {max_bytes} bytes"));
+        }
+        Ok(Some(RemoteFile { data, etag }))
+    }
+
+    /// Write a file, sending `condition` as a header, e.g. `If-Match` with
+    /// an ETag. Returns false if the server refused it with HTTP 412.
+    pub(crate) fn write_bytes(
+        &self,
+        path: &str,
+        data: &[u8],
+        condition: Option<(&str, &str)>,
+    ) -> Result<bool> {
+        let mut request = self.request("PUT", path)?;
+        if let Some((name, value)) = condition {
+            request = request.set(name, value);
+        }
+        Ok(self.execute(request, Body::Bytes(data), &[412])?.is_some())
+    }
+
+    /// Create `path` and its missing ancestors.
+    pub(crate) fn make_dirs(&self, path: &str) -> Result<()> {
+        let mut current = String::new();
+        for segment in normalize(path).split('/').filter(|s| !s.is_empty()) {
+            current = if current.is_empty() {
+                segment.to_string()
+            } else {
+                format!("{current}/{segment}")
+            };
+            // 405 means the collection already exists.
+            self.execute(self.request("MKCOL", &current)?, Body::Empty, &[405])?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn delete(&self, path: &str) -> Result<()> {
+        self.execute(self.request("DELETE", path)?, Body::Empty, &[404])?;
+        Ok(())
+    }
+
+    fn record(&self, remote_path: &str, hash: String) -> Result<WebDavSyncRecord> {
+        let entry = self
+            .stat(remote_path)?
+            .ok_or_else(|| anyhow!("{remote_path} is missing on the server"))?;
+        Ok(WebDavSyncRecord {
+            hash,
+            etag: entry.etag,
+            modified: entry.modified,
+        })
+    }
+}
+
+fn hash_file(path: &Path) -> Result<String> {
+    let file = File::open(path).map_err(|e| anyhow!("Failed to open {}: {e}", path.display()))?;
+    let mut hasher = blake3::Hasher::new();
+    hasher
+        .update_reader(file)
+        .map_err(|e| anyhow!("Failed to read {}: {e}", path.display()))?;
+    Ok(hasher.finalize().to_hex().to_string())
+}
+
+fn normalize(path: &str) -> String {
+    path.trim_matches('/').to_string()
+}
+
+/// Parse a PROPFIND response. Entry paths are made relative to `base_path`.
+fn parse_multistatus(xml: &str, base_path: &str) -> Result<Vec<WebDavEntry>> {
+    let mut reader = xhtml::reader(xml);
+    let mut entries = Vec::new();
+    let mut entry: Option<WebDavEntry> = None;
+    let mut element = String::new();
+    loop {
+        let event = match reader.read_event() {
+            Ok(Event::Eof) => break,
+            Ok(event) => event,
+            Err(e) => return Err(anyhow!("Invalid WebDAV response: {e}")),
+        };
+        match event {
+            Event::Start(start) | Event::Empty(start) => {
+                element = xhtml::local_name(start.name().as_ref());
+                match element.as_str() {
+                    "response" => {
+                        entry = Some(WebDavEntry {
+                            path: String::new(),
+                            name: String::new(),
+                            is_dir: false,
+                            size: 0,
+                            modified: None,
+                            etag: None,
+                        })
+                    }
+                    "collection" => {
+                        if let Some(entry) = entry.as_mut() {
+                            entry.is_dir = true;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Event::End(end) => {
+                if xhtml::local_name(end.name().as_ref()) == "response" {
+                    entries.extend(entry.take());
+                }
+                element.clear();
+            }
+            Event::Text(text) => {
+                let Some(entry) = entry.as_mut() else {
+                    continue;
+                };
+                let value = xhtml::text(&text);
+                let value = value.trim();
+                match element.as_str() {
+                    "href" => {
+                        entry.path = relative_path(value, base_path);
+                        entry.name = entry.path.rsplit('/').next().unwrap_or("").to_string();
+                    }
+                    "getcontentlength" => entry.size = value.parse().unwrap_or(0),
+                    "getlastmodified" => entry.modified = parse_http_date(value),
+                    "getetag" if !value.is_empty() => entry.etag = Some(value.to_string()),
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(entries)
+}
+
+/// Decode an `href`, which may be a full URL or an absolute path, and strip
+/// the base path from it.
+fn relative_path(href: &str, base_path: &str) -> String {
+    let path = match Url::parse(href) {
+        Ok(url) => url.path().to_string(),
+        Err(_) => href.to_string(),
+    };
+    let path = percent_decode_str(&path).decode_utf8_lossy();
+    let base = percent_decode_str(base_path).decode_utf8_lossy();
+    let path = path.strip_prefix(base.as_ref()).unwrap_or(&path);
+    normalize(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LISTING: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:multistatus xmlns:d="DAV:">
+  <d:response>
+    <d:href>/dav/Novella/</d:href>
+    <d:propstat><d:prop>
+      <d:resourcetype><d:collection/></d:resourcetype>
+      <d:getlastmodified>Sun, 06 Nov 1994 08:49:37 GMT</d:getlastmodified>
+    </d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat>
+  </d:response>
+  <d:response>
+    <d:href>https://dav.example.com/dav/Novella/%E5%A4%87%E4%BB%BD%201.zip</d:href>
+    <d:propstat><d:prop>
+      <d:resourcetype/>
+      <d:getcontentlength>2048</d:getcontentlength>
+      <d:getlastmodified>Mon, 14 Oct 2024 12:00:00 GMT</d:getlastmodified>
+      <d:getetag>"abc"</d:getetag>
+    </d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat>
+  </d:response>
+</d:multistatus>"#;
+
+    fn entry(etag: Option<&str>, modified: Option<i64>) -> WebDavEntry {
+        WebDavEntry {
+            path: "backup.zip".to_string(),
+            name: "backup.zip".to_string(),
+            is_dir: false,
+            size: 0,
+            modified,
+            etag: etag.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_parse_multistatus() {
+        let entries = parse_multistatus(LISTING, "/dav/Novella/").unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!((entries[0].path.as_str(), entries[0].is_dir), ("", true));
+        let file = &entries[1];
+        assert_eq!(file.path, "备份 1.zip");
+        assert_eq!(file.name, "备份 1.zip");
+        assert!(!file.is_dir);
+        assert_eq!(file.size, 2048);
+        assert_eq!(file.modified, Some(1_728_907_200));
+        assert_eq!(file.etag.as_deref(), Some("\"abc\""));
+    }
+
+    #[test]
+    fn test_sync_status() {
+        use WebDavSyncStatus::*;
+        let record = WebDavSyncRecord {
+            hash: "old".to_string(),
+            etag: Some("\"1\"".to_string()),
+            modified: Some(100),
+        };
+        let same = entry(Some("\"1\""), Some(100));
+        let changed = entry(Some("\"2\""), Some(200));
+        assert_eq!(sync_status("new", &changed, Some("new"), None), InSync);
+        assert_eq!(sync_status("new", &changed, Some("other"), None), Conflict);
+        assert_eq!(sync_status("old", &same, None, Some(&record)), InSync);
+        assert_eq!(
+            sync_status("new", &same, Some("old"), Some(&record)),
+            LocalChanged
+        );
+        assert_eq!(
+            sync_status("old", &changed, None, Some(&record)),
+            RemoteChanged
+        );
+        assert_eq!(
+            sync_status("new", &changed, Some("x"), Some(&record)),
+            Conflict
+        );
+        // Without ETags the modification time decides.
+        let record = WebDavSyncRecord {
+            etag: None,
+            ..record
+        };
+        let touched = entry(None, Some(300));
+        assert_eq!(
+            sync_status("old", &touched, None, Some(&record)),
+            RemoteChanged
+        );
+    }
+
+    #[test]
+    fn test_urls_and_dates() {
+        let client = Client::new(&WebDavConfig {
+            base_url: "https://dav.example.com/dav/Novella".to_string(),
+            username: "user".to_string(),
+            password: "pass".to_string(),
+        })
+        .unwrap();
+        assert_eq!(
+            client.url("/备份/a#1.zip").unwrap().as_str(),
+            "https://dav.example.com/dav/Novella/%E5%A4%87%E4%BB%BD/a%231.zip"
+        );
+        assert_eq!(client.authorization, "Basic dXNlcjpwYXNz");
+        assert_eq!(
+            parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"),
+            Some(784_111_777)
+        );
+        assert_eq!(parse_http_date("not a date"), None);
+    }
+}
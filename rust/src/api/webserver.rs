@@ -0,0 +1,689 @@
+//! A small web server for reading the library from a browser on the LAN.
+//!
+//! Pages are plain server-rendered HTML: the bookshelf, a book's table of
+//! contents and a reading page per chapter, whose text comes from the
+//! chapter store or the chapter cache. Nothing on the pages changes the
+//! library; the one write is uploading a TXT or EPUB file, which is saved
+//! to the upload directory and announced through `web_server_events` so
+//! the app can import it. An optional access token keeps other people on
+//! the network out: it is passed once as `?token=` and then kept in a
+//! cookie.
+//!
+//! Each connection is served on its own thread and closed after one
+//! response. At most `MAX_CONNECTIONS` are served at once; clients past
+//! that are answered with a 503 straight away.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::net::{Ipv4Addr, Shutdown, SocketAddr, TcpListener, TcpStream, UdpSocket};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use percent_encoding::{percent_decode_str, utf8_percent_encode, NON_ALPHANUMERIC};
+use quick_xml::escape::escape;
+use subtle::ConstantTimeEq;
+
+use crate::api::archive::{self, ImportKind};
+use crate::api::db::{self, BookQuery, BookSort};
+use crate::api::error::ApiError;
+use crate::api::{chapter_cache, chapter_store};
+use crate::frb_generated::StreamSink;
+
+/// Largest request head read before giving up on a client.
+const MAX_HEAD_BYTES: usize = 16 * 1024;
+
+const IO_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Connections served at once; each holds a thread until it is answered.
+const MAX_CONNECTIONS: usize = 32;
+
+const TOKEN_COOKIE: &str = "novella_token";
+
+/// Books listed on the bookshelf at most.
+const MAX_SHELF_BOOKS: u32 = 1000;
+
+const STYLE: &str = "body{max-width:42em;margin:0 auto;padding:1em;font:18px/1.8 serif;\
+color:#222;background:#fbfaf7}a{color:#35659c;text-decoration:none}\
+nav{display:flex;justify-content:space-between;margin:2em 0;font-family:sans-serif}\
+ul{padding:0;list-style:none}li{padding:.3em 0;border-bottom:1px solid #e5e2dc}\
+.meta{color:#888;font-size:.8em}p{text-indent:2em;margin:.6em 0}\
+@media(prefers-color-scheme:dark){body{color:#ccc;background:#1b1b1b}\
+a{color:#8ab4e8}li{border-color:#333}}";
+
+static SERVER: Mutex<Option<Running>> = Mutex::new(None);
+static LISTENER: Mutex<Option<Listener>> = Mutex::new(None);
+
+#[derive(Debug, Clone)]
+pub struct WebServerConfig {
+    /// Port to listen on; 0 picks a free one.
+    pub port: u16,
+    /// Where uploaded books are saved; `None` turns uploading off.
+    pub upload_dir: Option<String>,
+    /// Secret visitors must present once; `None` lets anyone on the
+    /// network in.
+    pub access_token: Option<String>,
+    /// Largest upload accepted, in megabytes.
+    pub max_upload_mb: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct WebServerInfo {
+    pub port: u16,
+    /// Addresses to open in a browser, best guess first, with the token.
+    pub urls: Vec<String>,
+}
+
+/// A book uploaded through the web page, ready to import.
+#[derive(Debug, Clone)]
+pub struct WebUpload {
+    /// File name the browser sent.
+    pub name: String,
+    /// Where the file was saved.
+    pub path: String,
+    /// `Txt` or `Epub`.
+    pub kind: ImportKind,
+}
+
+/// Uploads off, no token and a 200 MB upload limit, on port 8080.
+#[flutter_rust_bridge::frb]
+pub fn default_web_server_config() -> WebServerConfig {
+    WebServerConfig {
+        port: 8080,
+        upload_dir: None,
+        access_token: None,
+        max_upload_mb: 200,
+    }
+}
+
+/// Start serving on all interfaces, stopping a server already running.
+///
+/// # Arguments
+/// * `config` - Port, uploads and access token
+#[flutter_rust_bridge::frb]
+pub fn start_web_server(config: WebServerConfig) -> Result<WebServerInfo, ApiError> {
+    stop_web_server();
+    let listener = TcpListener::bind((Ipv4Addr::UNSPECIFIED, config.port))
+        .map_err(|e| anyhow!("Failed to listen on port {}: {e}", config.port))?;
+    let port = listener
+        .local_addr()
+        .map_err(|e| anyhow!("Failed to listen on port {}: {e}", config.port))?
+        .port();
+    if let Some(dir) = &config.upload_dir {
+        fs::create_dir_all(dir).map_err(|e| anyhow!("Failed to create {dir}: {e}"))?;
+    }
+
+    let query = config
+        .access_token
+        .as_ref()
+        .map(|token| format!("/?token={}", utf8_percent_encode(token, NON_ALPHANUMERIC)))
+        .unwrap_or_else(|| "/".to_string());
+    let urls = [lan_address(), Some(Ipv4Addr::LOCALHOST)]
+        .into_iter()
+        .flatten()
+        .map(|ip| format!("http://{ip}:{port}{query}"))
+        .collect();
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let config = Arc::new(config);
+    let stopped = Arc::clone(&stop);
+    let active = Arc::new(AtomicUsize::new(0));
+    let accept = thread::spawn(move || {
+        for stream in listener.incoming() {
+            if stopped.load(Ordering::Relaxed) {
+                break;
+            }
+            let Ok(stream) = stream else { continue };
+            if active.fetch_add(1, Ordering::AcqRel) >= MAX_CONNECTIONS {
+                active.fetch_sub(1, Ordering::AcqRel);
+                turn_away(stream);
+                continue;
+            }
+            let config = Arc::clone(&config);
+            let active = Arc::clone(&active);
+            thread::spawn(move || {
+                serve(stream, &config);
+                active.fetch_sub(1, Ordering::AcqRel);
+            });
+        }
+    });
+    *lock(&SERVER) = Some(Running { port, stop, accept });
+    Ok(WebServerInfo { port, urls })
+}
+
+/// Stop the server. Returns false if none was running. The port is free
+/// again on return; requests already being served are finished.
+#[flutter_rust_bridge::frb]
+pub fn stop_web_server() -> bool {
+    let Some(running) = lock(&SERVER).take() else {
+        return false;
+    };
+    running.stop.store(true, Ordering::Relaxed);
+    // Wake the accept loop so it sees the flag, and wait for it to drop
+    // the listener.
+    let woken = TcpStream::connect_timeout(
+        &SocketAddr::from((Ipv4Addr::LOCALHOST, running.port)),
+        Duration::from_secs(1),
+    );
+    if woken.is_ok() {
+        let _ = running.accept.join();
+    }
+    true
+}
+
+/// Receive uploaded books, replacing any previous stream. Uploads made
+/// while nothing listens stay in the upload directory unannounced.
+#[flutter_rust_bridge::frb]
+pub fn web_server_events(sink: StreamSink<WebUpload>) -> Result<(), ApiError> {
+    *lock(&LISTENER) = Some(Box::new(move |upload| {
+        let _ = sink.add(upload);
+    }));
+    Ok(())
+}
+
+type Listener = Box<dyn Fn(WebUpload) + Send + Sync>;
+
+struct Running {
+    port: u16,
+    stop: Arc<AtomicBool>,
+    accept: JoinHandle<()>,
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// The address other devices most likely reach this one on: the source
+/// address of the default route. Connecting a UDP socket sends nothing.
+fn lan_address() -> Option<Ipv4Addr> {
+    let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)).ok()?;
+    socket.connect((Ipv4Addr::new(8, 8, 8, 8), 53)).ok()?;
+    match socket.local_addr().ok()? {
+        SocketAddr::V4(addr) if !addr.ip().is_unspecified() && !addr.ip().is_loopback() => {
+            Some(*addr.ip())
+        }
+        _ => None,
+    }
+}
+
+struct Request {
+    method: String,
+    path: String,
+    query: HashMap<String, String>,
+    /// Header names are lower-cased.
+    headers: HashMap<String, String>,
+}
+
+struct Response {
+    status: u16,
+    content_type: &'static str,
+    body: Vec<u8>,
+    set_cookie: Option<String>,
+}
+
+impl Response {
+    fn html(status: u16, body: String) -> Self {
+        Self {
+            status,
+            content_type: "text/html; charset=utf-8",
+            body: body.into_bytes(),
+            set_cookie: None,
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self::html(
+            status,
+            page(message, &format!("<p>{}</p>", escape(message))),
+        )
+    }
+}
+
+fn serve(mut stream: TcpStream, config: &WebServerConfig) {
+    let _ = stream.set_read_timeout(Some(IO_TIMEOUT));
+    let _ = stream.set_write_timeout(Some(IO_TIMEOUT));
+    let response = match read_head(&mut stream) {
+        Ok((request, body_start)) => route(&request, config, &mut body_start.chain(&stream)),
+        Err(_) => Response::error(400, "Bad request"),
+    };
+    let _ = write_response(&mut stream, &response);
+    let _ = stream.shutdown(Shutdown::Both);
+}
+
+/// Answer a connection past `MAX_CONNECTIONS` without reading it.
+fn turn_away(mut stream: TcpStream) {
+    let _ = stream.set_write_timeout(Some(Duration::from_secs(1)));
+    let _ = write_response(&mut stream, &Response::error(503, "Server busy"));
+    let _ = stream.shutdown(Shutdown::Both);
+}
+
+/// Read the request head and return it with the body bytes read past it.
+fn read_head(stream: &mut impl Read) -> Result<(Request, io::Cursor<Vec<u8>>)> {
+    let mut data = Vec::new();
+    let mut chunk = [0; 4096];
+    let end = loop {
+        if let Some(end) = data.windows(4).position(|w| w == b"\r\n\r\n") {
+            break end;
+        }
+        if data.len() > MAX_HEAD_BYTES {
+            return Err(anyhow!("Request head too large"));
+        }
+        let read = stream.read(&mut chunk)?;
+        if read == 0 {
+            return Err(anyhow!("Connection closed"));
+        }
+        data.extend_from_slice(&chunk[..read]);
+    };
+    let head = std::str::from_utf8(&data[..end])?;
+    let mut lines = head.split("\r\n");
+    let mut parts = lines.next().unwrap_or_default().split(' ');
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Err(anyhow!("Malformed request line"));
+    };
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let request = Request {
+        method: method.to_string(),
+        path: path.to_string(),
+        query: query
+            .split('&')
+            .filter_map(|pair| {
+                let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                let decode = |s: &str| {
+                    percent_decode_str(&s.replace('+', " "))
+                        .decode_utf8_lossy()
+                        .into_owned()
+                };
+                (!key.is_empty()).then(|| (decode(key), decode(value)))
+            })
+            .collect(),
+        headers: lines
+            .filter_map(|line| line.split_once(':'))
+            .map(|(name, value)| (name.trim().to_ascii_lowercase(), value.trim().to_string()))
+            .collect(),
+    };
+    Ok((request, io::Cursor::new(data[end + 4..].to_vec())))
+}
+
+fn write_response(stream: &mut impl Write, response: &Response) -> io::Result<()> {
+    let reason = match response.status {
+        200 => "OK",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        411 => "Length Required",
+        413 => "Payload Too Large",
+        415 => "Unsupported Media Type",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    };
+    let mut head = format!(
+        "HTTP/1.1 {} {reason}\r\nContent-Type: {}\r\nContent-Length: {}\r\n\
+         Cache-Control: no-store\r\nConnection: close\r\n",
+        response.status,
+        response.content_type,
+        response.body.len()
+    );
+    if let Some(cookie) = &response.set_cookie {
+        head.push_str(&format!("Set-Cookie: {cookie}\r\n"));
+    }
+    head.push_str("\r\n");
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(&response.body)?;
+    stream.flush()
+}
+
+fn route(request: &Request, config: &WebServerConfig, body: &mut dyn Read) -> Response {
+    let mut set_cookie = None;
+    if let Some(token) = &config.access_token {
+        let cookie = request.headers.get("cookie").and_then(|cookies| {
+            cookies
+                .split(';')
+                .filter_map(|c| c.trim().split_once('='))
+                .find(|(name, _)| *name == TOKEN_COOKIE)
+                .map(|(_, value)| value.to_string())
+        });
+        let matches = |given: &str| bool::from(given.as_bytes().ct_eq(token.as_bytes()));
+        if request.query.get("token").is_some_and(|t| matches(t)) {
+            set_cookie = Some(format!(
+                "{TOKEN_COOKIE}={}; Path=/; HttpOnly; SameSite=Strict",
+                utf8_percent_encode(token, NON_ALPHANUMERIC)
+            ));
+        } else if !cookie
+            .map(|c| percent_decode_str(&c).decode_utf8_lossy().into_owned())
+            .is_some_and(|c| matches(&c))
+        {
+            return Response::error(
+                401,
+                "Open the address shown in the app, including its token.",
+            );
+        }
+    }
+
+    let segments: Vec<String> = request
+        .path
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .map(|s| percent_decode_str(s).decode_utf8_lossy().into_owned())
+        .collect();
+    let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+    let result = match (request.method.as_str(), segments.as_slice()) {
+        ("GET", []) => shelf_page(config),
+        ("GET", ["book", id]) => book_page(id),
+        ("GET", ["book", id, index]) => match index.parse() {
+            Ok(index) => chapter_page(id, index),
+            Err(_) => Ok(Response::error(404, "Not found")),
+        },
+        ("POST", ["upload"]) => upload(request, config, body),
+        ("GET" | "POST", _) => Ok(Response::error(404, "Not found")),
+        _ => Ok(Response::error(405, "Method not allowed")),
+    };
+    let mut response = result.unwrap_or_else(|e| Response::error(500, &e.to_string()));
+    response.set_cookie = set_cookie;
+    response
+}
+
+fn page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\">\
+         <meta name=\"viewport\" content=\"width=device-width,initial-scale=1\">\
+         <title>{}</title><style>{STYLE}</style></head><body>{body}</body></html>",
+        escape(title)
+    )
+}
+
+fn book_link(id: &str) -> String {
+    format!("/book/{}", utf8_percent_encode(id, NON_ALPHANUMERIC))
+}
+
+fn shelf_page(config: &WebServerConfig) -> Result<Response> {
+    let shelf = db::query_books(BookQuery {
+        search: None,
+        sort: BookSort::RecentlyRead,
+        offset: 0,
+        limit: MAX_SHELF_BOOKS,
+    })?;
+    let mut body = String::from("<h1>Novella</h1><ul>");
+    for book in &shelf.books {
+        body.push_str(&format!(
+            "<li><a href=\"{}\">{}</a> <span class=\"meta\">{}</span></li>",
+            book_link(&book.id),
+            escape(&book.title),
+            escape(book.author.as_deref().unwrap_or_default())
+        ));
+    }
+    body.push_str("</ul>");
+    if config.upload_dir.is_some() {
+        body.push_str(UPLOAD_FORM);
+    }
+    Ok(Response::html(200, page("Novella", &body)))
+}
+
+/// Sends each chosen file as the raw body of its own request.
+const UPLOAD_FORM: &str = "<h2>Upload</h2><p class=\"meta\">TXT or EPUB</p>\
+<input type=\"file\" id=\"files\" accept=\".txt,.epub\" multiple>\
+<p id=\"status\" class=\"meta\"></p><script>\
+document.getElementById('files').onchange=async e=>{\
+const s=document.getElementById('status');\
+for(const f of e.target.files){s.textContent=f.name+' …';\
+const r=await fetch('/upload?name='+encodeURIComponent(f.name),{method:'POST',body:f});\
+s.textContent=f.name+': '+(await r.text());}e.target.value='';};</script>";
+
+fn book_page(id: &str) -> Result<Response> {
+    let Some(book) = db::get_library_book(id.to_string())? else {
+        return Ok(Response::error(404, "No such book"));
+    };
+    let chapters = db::query_library_chapters(id.to_string(), 0, u32::MAX)?;
+    let link = book_link(id);
+    let mut body = format!(
+        "<nav><a href=\"/\">← Bookshelf</a></nav><h1>{}</h1>",
+        escape(&book.title)
+    );
+    if let Some(progress) = db::get_progress(id.to_string())? {
+        body.push_str(&format!(
+            "<p><a href=\"{link}/{}\">Continue reading</a></p>",
+            progress.chapter_index
+        ));
+    }
+    body.push_str("<ul>");
+    for chapter in &chapters {
+        if chapter.is_volume {
+            body.push_str(&format!("<li><b>{}</b></li>", escape(&chapter.title)));
+        } else {
+            body.push_str(&format!(
+                "<li><a href=\"{link}/{}\">{}</a></li>",
+                chapter.index,
+                escape(&chapter.title)
+            ));
+        }
+    }
+    body.push_str("</ul>");
+    Ok(Response::html(200, page(&book.title, &body)))
+}
+
+fn chapter_page(id: &str, index: u32) -> Result<Response> {
+    let Some(book) = db::get_library_book(id.to_string())? else {
+        return Ok(Response::error(404, "No such book"));
+    };
+    let chapters = db::query_library_chapters(id.to_string(), 0, u32::MAX)?;
+    let readable: Vec<_> = chapters.iter().filter(|c| !c.is_volume).collect();
+    let Some(position) = readable.iter().position(|c| c.index == index) else {
+        return Ok(Response::error(404, "No such chapter"));
+    };
+    let link = book_link(id);
+    let nav = |label: &str, chapter: Option<&&db::LibraryChapter>| {
+        chapter
+            .map(|c| format!("<a href=\"{link}/{}\">{label}</a>", c.index))
+            .unwrap_or_else(|| "<span></span>".to_string())
+    };
+    let navigation = format!(
+        "<nav>{}<a href=\"{link}\">Contents</a>{}</nav>",
+        nav(
+            "← Previous",
+            position.checked_sub(1).and_then(|p| readable.get(p))
+        ),
+        nav("Next →", readable.get(position + 1))
+    );
+    let title = &readable[position].title;
+    let mut body = format!("{navigation}<h2>{}</h2>", escape(title));
+    match chapter_text(id, index) {
+        Some(text) => body.push_str(&paragraphs(&text)),
+        None => body.push_str("<p class=\"meta\">This chapter has not been downloaded yet.</p>"),
+    }
+    body.push_str(&navigation);
+    Ok(Response::html(
+        200,
+        page(&format!("{title} - {}", book.title), &body),
+    ))
+}
+
+/// Chapter text from the chapter store, else the chapter cache. Stores
+/// that are not set up count as not having it.
+pub(crate) fn chapter_text(id: &str, index: u32) -> Option<String> {
+    chapter_store::get_chapter(id.to_string(), index)
+        .ok()
+        .flatten()
+        .or_else(|| {
+            chapter_cache::read_cached_chapter(id.to_string(), index)
+                .ok()
+                .flatten()
+        })
+}
+
+fn paragraphs(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| format!("<p>{}</p>", escape(line)))
+        .collect()
+}
+
+fn upload(request: &Request, config: &WebServerConfig, body: &mut dyn Read) -> Result<Response> {
+    let Some(dir) = &config.upload_dir else {
+        return Ok(Response::error(403, "Uploading is turned off"));
+    };
+    let name = request
+        .query
+        .get("name")
+        .and_then(|name| name.rsplit(['/', '\\']).next())
+        .map(str::trim)
+        .filter(|name| !name.is_empty() && !name.starts_with('.'))
+        .unwrap_or_default()
+        .to_string();
+    let kind = match archive::import_kind(&name) {
+        Some(kind @ (ImportKind::Txt | ImportKind::Epub)) => kind,
+        _ => {
+            return Ok(Response::error(
+                415,
+                "Only TXT and EPUB files can be uploaded",
+            ))
+        }
+    };
+    let Some(length) = request
+        .headers
+        .get("content-length")
+        .and_then(|length| length.parse::<u64>().ok())
+    else {
+        return Ok(Response::error(411, "Content-Length is required"));
+    };
+    if length > config.max_upload_mb as u64 * 1024 * 1024 {
+        return Ok(Response::error(413, "The file is too large"));
+    }
+
+    let target = archive::unique_path(&Path::new(dir).join(&name));
+    let mut part = target.clone().into_os_string();
+    part.push(".part");
+    let part = PathBuf::from(part);
+    let saved = File::create_new(&part)
+        .and_then(|mut file| {
+            let copied = io::copy(&mut body.take(length), &mut file)?;
+            if copied < length {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "upload cut off",
+                ));
+            }
+            file.flush()
+        })
+        .and_then(|_| fs::rename(&part, &target));
+    if let Err(e) = saved {
+        let _ = fs::remove_file(&part);
+        return Err(anyhow!("Failed to save {name}: {e}"));
+    }
+
+    if let Some(listener) = lock(&LISTENER).as_ref() {
+        listener(WebUpload {
+            name,
+            path: target.to_string_lossy().into_owned(),
+            kind,
+        });
+    }
+    Ok(Response {
+        status: 200,
+        content_type: "text/plain; charset=utf-8",
+        body: b"Uploaded".to_vec(),
+        set_cookie: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_dirs::temp_dir;
+    use std::sync::mpsc;
+
+    fn send(port: u16, request: &str, body: &[u8]) -> String {
+        let mut stream = TcpStream::connect((Ipv4Addr::LOCALHOST, port)).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        stream.write_all(body).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn test_parses_request_head() {
+        let raw =
+            b"GET /book/a%20b?token=x%2By&flag HTTP/1.1\r\nHost: h\r\nCookie: a=1\r\n\r\nbody";
+        let (request, mut rest) = read_head(&mut &raw[..]).unwrap();
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.path, "/book/a%20b");
+        assert_eq!(request.query["token"], "x+y");
+        assert_eq!(request.query["flag"], "");
+        assert_eq!(request.headers["cookie"], "a=1");
+        let mut body = String::new();
+        rest.read_to_string(&mut body).unwrap();
+        assert_eq!(body, "body");
+    }
+
+    #[test]
+    fn test_renders_paragraphs() {
+        assert_eq!(
+            paragraphs("  第一段<b>\n\n\u{3000}第二段  \n"),
+            "<p>第一段&lt;b&gt;</p><p>第二段</p>"
+        );
+    }
+
+    #[test]
+    fn test_token_and_upload() {
+        let dir = temp_dir("webserver-upload");
+        let (sender, receiver) = mpsc::channel();
+        *lock(&LISTENER) = Some(Box::new(move |upload| sender.send(upload).unwrap()));
+        let info = start_web_server(WebServerConfig {
+            port: 0,
+            upload_dir: Some(dir.to_string_lossy().into_owned()),
+            access_token: Some("s3cret".to_string()),
+            max_upload_mb: 1,
+        })
+        .unwrap();
+        assert!(info.urls.iter().any(|url| url.ends_with("/?token=s3cret")));
+
+        let post = |extra: &str, name: &str, body: &[u8]| {
+            let head = format!(
+                "POST /upload?name={name} HTTP/1.1\r\n{extra}Content-Length: {}\r\n\r\n",
+                body.len()
+            );
+            send(info.port, &head, body)
+        };
+        assert!(post("", "a.txt", b"x").starts_with("HTTP/1.1 401"));
+        let cookie = "Cookie: novella_token=s3cret\r\n";
+        assert!(post(cookie, "a.exe", b"x").starts_with("HTTP/1.1 415"));
+        let response = post(cookie, "..%2F..%2F%E4%B9%A6.txt", "第一章".as_bytes());
+        assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+
+        let upload = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(upload.name, "书.txt");
+        assert_eq!(upload.kind, ImportKind::Txt);
+        assert_eq!(Path::new(&upload.path), dir.join("书.txt"));
+        assert_eq!(fs::read_to_string(&upload.path).unwrap(), "第一章");
+
+        // A token in the query sets the cookie.
+        let response = send(info.port, "GET /nothing?token=s3cret HTTP/1.1\r\n\r\n", b"");
+        assert!(response.starts_with("HTTP/1.1 404"));
+        assert!(response.contains("Set-Cookie: novella_token=s3cret;"));
+
+        // Restarting on the same port waits for the old listener to close.
+        let config = WebServerConfig {
+            port: info.port,
+            ..default_web_server_config()
+        };
+        for _ in 0..3 {
+            assert_eq!(start_web_server(config.clone()).unwrap().port, info.port);
+        }
+
+        // Connections past the limit are turned away while the rest wait.
+        let idle: Vec<TcpStream> = (0..MAX_CONNECTIONS)
+            .map(|_| TcpStream::connect((Ipv4Addr::LOCALHOST, info.port)).unwrap())
+            .collect();
+        let response = send(info.port, "GET / HTTP/1.1\r\n\r\n", b"");
+        assert!(response.starts_with("HTTP/1.1 503"), "{response}");
+        drop(idle);
+        assert!(stop_web_server());
+        *lock(&LISTENER) = None;
+    }
+}
@@ -0,0 +1,100 @@
+//! Authenticated encryption and key stretching for user data.
+//!
+//! Data is sealed with XChaCha20-Poly1305: the ChaCha20-Poly1305 AEAD of
+//! RFC 8439 with the 24-byte nonce of draft-irtf-cfrg-xchacha, long enough
+//! to be picked at random for every message without tracking which were
+//! used. Passphrases are stretched into keys with PBKDF2-HMAC-SHA256
+//! (RFC 8018). Both come from the RustCrypto crates; the sealed layout,
+//! ciphertext followed by the 16-byte tag, is the one they produce.
+
+use anyhow::{anyhow, Result};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::XChaCha20Poly1305;
+use hmac::Hmac;
+use sha2::Sha256;
+
+pub(crate) const KEY_LEN: usize = 32;
+pub(crate) const NONCE_LEN: usize = 24;
+pub(crate) const TAG_LEN: usize = 16;
+
+/// Encrypt `plain` and append the tag.
+pub(crate) fn seal(
+    key: &[u8; KEY_LEN],
+    nonce: &[u8; NONCE_LEN],
+    aad: &[u8],
+    plain: &[u8],
+) -> Vec<u8> {
+    XChaCha20Poly1305::new(key.into())
+        .encrypt(nonce.into(), Payload { msg: plain, aad })
+        .expect("XChaCha20-Poly1305 seals messages of any size held in memory")
+}
+
+/// Check the tag at the end of `sealed` and decrypt the rest.
+pub(crate) fn open(
+    key: &[u8; KEY_LEN],
+    nonce: &[u8; NONCE_LEN],
+    aad: &[u8],
+    sealed: &[u8],
+) -> Result<Vec<u8>> {
+    if sealed.len() < TAG_LEN {
+        return Err(anyhow!("Sealed data is truncated"));
+    }
+    XChaCha20Poly1305::new(key.into())
+        .decrypt(nonce.into(), Payload { msg: sealed, aad })
+        .map_err(|_| anyhow!("Sealed data failed authentication"))
+}
+
+/// PBKDF2 with HMAC-SHA256, filling `out`.
+pub(crate) fn pbkdf2_sha256(password: &[u8], salt: &[u8], iterations: u32, out: &mut [u8]) {
+    pbkdf2::pbkdf2::<Hmac<Sha256>>(password, salt, iterations, out)
+        .expect("HMAC takes keys of any length");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(text: &str) -> Vec<u8> {
+        (0..text.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&text[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_pbkdf2_vector() {
+        // RFC 7914, section 11.
+        let mut out = [0u8; 64];
+        pbkdf2_sha256(b"passwd", b"salt", 1, &mut out);
+        assert_eq!(
+            out.to_vec(),
+            hex(
+                "55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc\
+                 49ca9cccf179b645991664b39d77ef317c71b845b1e30bd509112041d3a19783"
+            )
+        );
+    }
+
+    #[test]
+    fn test_xchacha20_poly1305_vector() {
+        // draft-irtf-cfrg-xchacha-03, appendix A.3.1.
+        let plain = b"Ladies and Gentlemen of the class of '99: If I could offer you \
+            only one tip for the future, sunscreen would be it.";
+        let aad = hex("50515253c0c1c2c3c4c5c6c7");
+        let key: [u8; 32] = std::array::from_fn(|i| 0x80 + i as u8);
+        let nonce: [u8; 24] = std::array::from_fn(|i| 0x40 + i as u8);
+        let sealed = seal(&key, &nonce, &aad, plain);
+        assert_eq!(sealed[..8].to_vec(), hex("bd6d179d3e83d43b"));
+        assert_eq!(
+            sealed[plain.len()..].to_vec(),
+            hex("c0875924c1c7987947deafd8780acf49")
+        );
+        assert_eq!(open(&key, &nonce, &aad, &sealed).unwrap(), plain);
+
+        let mut tampered = sealed.clone();
+        tampered[3] ^= 1;
+        assert!(open(&key, &nonce, &aad, &tampered).is_err());
+        assert!(open(&key, &nonce, b"other", &sealed).is_err());
+        assert!(open(&key, &nonce, &aad, &sealed[..10]).is_err());
+    }
+}
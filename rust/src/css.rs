@@ -0,0 +1,427 @@
+//! Internal CSS helpers for publisher stylesheets.
+//!
+//! Stylesheets are parsed just far enough to rewrite them rule by rule:
+//! `url()` references are resolved to archive paths, and when cleaning,
+//! declarations that fight the reader's own settings are dropped. Those are
+//! colours and backgrounds, which break night mode, font families, fixed
+//! font sizes, line heights and the margins of `html` and `body`, which the
+//! page margins replace. Indents, italics, alignment, relative sizes and
+//! borders stay; colours in borders become `currentColor` so separator
+//! lines follow the text colour.
+
+use std::sync::OnceLock;
+
+use regex::{Captures, Regex};
+
+use crate::xhtml;
+
+/// At-rules whose blocks hold further rules.
+const GROUPING_RULES: &[&str] = &["media", "supports", "document", "-moz-document", "layer"];
+
+/// Properties whose colour values are replaced by `currentColor`.
+const COLORED_PROPERTIES: &[&str] = &[
+    "border",
+    "outline",
+    "text-decoration",
+    "column-rule",
+    "text-emphasis",
+];
+
+const FIXED_SIZE_KEYWORDS: &[&str] = &[
+    "xx-small",
+    "x-small",
+    "small",
+    "medium",
+    "large",
+    "x-large",
+    "xx-large",
+    "xxx-large",
+];
+
+const ABSOLUTE_UNITS: &[&str] = &["px", "pt", "pc", "cm", "mm", "in", "q"];
+
+const NAMED_COLORS: &[&str] = &[
+    "black", "white", "gray", "grey", "silver", "red", "maroon", "orange", "yellow", "olive",
+    "lime", "green", "aqua", "cyan", "teal", "blue", "navy", "fuchsia", "magenta", "purple",
+    "brown", "pink", "gold",
+];
+
+/// A stylesheet after `rewrite`.
+pub(crate) struct Stylesheet {
+    pub css: String,
+    /// Archive paths of `@import`ed stylesheets, which are left out of `css`.
+    pub imports: Vec<String>,
+}
+
+/// Rewrite a stylesheet whose links are relative to `base_dir`, cleaning
+/// it if `clean` is set.
+pub(crate) fn rewrite(css: &str, base_dir: &str, clean: bool) -> Stylesheet {
+    let mut stylesheet = Stylesheet {
+        css: String::with_capacity(css.len()),
+        imports: Vec::new(),
+    };
+    rules(&strip_comments(css), base_dir, clean, &mut stylesheet);
+    stylesheet
+}
+
+/// Rewrite the declarations of a `style` attribute.
+pub(crate) fn rewrite_declarations(style: &str, base_dir: &str, clean: bool) -> String {
+    declarations(&strip_comments(style), base_dir, clean, false)
+}
+
+fn rules(css: &str, base_dir: &str, clean: bool, stylesheet: &mut Stylesheet) {
+    let mut rest = css;
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            break;
+        }
+        let end = scan(rest, |c| matches!(c, '{' | ';' | '}'));
+        let prelude = rest[..end].trim();
+        if !rest[end..].starts_with('{') {
+            if prelude.starts_with('@') {
+                statement(prelude, base_dir, stylesheet);
+            }
+            rest = rest.get(end + 1..).unwrap_or("");
+            continue;
+        }
+        let body = &rest[end + 1..];
+        let close = block_end(body);
+        block(prelude, &body[..close], base_dir, clean, stylesheet);
+        rest = body.get(close + 1..).unwrap_or("");
+    }
+}
+
+fn statement(prelude: &str, base_dir: &str, stylesheet: &mut Stylesheet) {
+    match at_rule_name(prelude).as_str() {
+        "import" => {
+            let target = prelude["@import".len()..].trim();
+            let link = url_regex()
+                .captures(target)
+                .and_then(|captures| url_argument(&captures).map(str::to_string))
+                .or_else(|| quoted(target));
+            if let Some(path) = link.and_then(|link| resolve_link(base_dir, &link)) {
+                stylesheet.imports.push(path);
+            }
+        }
+        "charset" => {}
+        _ => {
+            stylesheet.css.push_str(prelude);
+            stylesheet.css.push_str(";\n");
+        }
+    }
+}
+
+fn block(prelude: &str, body: &str, base_dir: &str, clean: bool, stylesheet: &mut Stylesheet) {
+    let prelude = xhtml::normalize_whitespace(prelude);
+    if prelude.starts_with('@') {
+        let name = at_rule_name(&prelude);
+        if GROUPING_RULES.contains(&name.as_str()) {
+            let mut inner = Stylesheet {
+                css: String::new(),
+                imports: Vec::new(),
+            };
+            rules(body, base_dir, clean, &mut inner);
+            if !inner.css.is_empty() {
+                stylesheet
+                    .css
+                    .push_str(&format!("{prelude} {{\n{}}}\n", inner.css));
+            }
+        } else if name == "font-face" || name == "page" {
+            // Publisher fonts and page boxes only matter to the original layout.
+            if !clean {
+                push_rule(
+                    &mut stylesheet.css,
+                    &prelude,
+                    &declarations(body, base_dir, false, false),
+                );
+            }
+        } else {
+            stylesheet.css.push_str(&format!("{prelude} {{{body}}}\n"));
+        }
+        return;
+    }
+    let root = prelude
+        .split(',')
+        .all(|selector| matches!(selector.trim(), "html" | "body" | ":root"));
+    push_rule(
+        &mut stylesheet.css,
+        &prelude,
+        &declarations(body, base_dir, clean, root),
+    );
+}
+
+fn push_rule(css: &mut String, selector: &str, declarations: &str) {
+    if !declarations.is_empty() {
+        css.push_str(&format!("{selector} {{ {declarations} }}\n"));
+    }
+}
+
+/// Rewrite a declaration block. `root` is set for rules that only select
+/// `html` or `body`.
+fn declarations(block: &str, base_dir: &str, clean: bool, root: bool) -> String {
+    let mut kept = Vec::new();
+    let mut rest = block;
+    while !rest.is_empty() {
+        let end = scan(rest, |c| c == ';');
+        if let Some((property, value)) = rest[..end].split_once(':') {
+            let property = property.trim().to_ascii_lowercase();
+            let value = resolve_urls(value.trim(), base_dir);
+            if property.is_empty() || value.is_empty() {
+            } else if !clean {
+                kept.push(format!("{property}: {value}"));
+            } else if let Some(declaration) = clean_declaration(&property, &value, root) {
+                kept.push(declaration);
+            }
+        }
+        rest = rest.get(end + 1..).unwrap_or("");
+    }
+    kept.join("; ")
+}
+
+/// The declaration to keep in place of `property: value`, if any.
+fn clean_declaration(property: &str, value: &str, root: bool) -> Option<String> {
+    let lower = value.to_ascii_lowercase();
+    let bare = lower.trim_end_matches("!important").trim_end();
+    let dropped = matches!(
+        property,
+        "color" | "font-family" | "line-height" | "text-shadow" | "-webkit-text-fill-color"
+    ) || property.starts_with("background")
+        || (property == "font-size" && is_fixed_size(bare))
+        || (root && (property.starts_with("margin") || property.starts_with("padding")));
+    if dropped {
+        return None;
+    }
+    if property == "font" {
+        // Keep the style and weight of the shorthand, not the family or size.
+        let tokens = split_tokens(bare);
+        let mut kept = Vec::new();
+        if let Some(style) = tokens.iter().find(|t| matches!(**t, "italic" | "oblique")) {
+            kept.push(format!("font-style: {style}"));
+        }
+        if let Some(weight) = tokens.iter().find(|t| {
+            matches!(**t, "bold" | "bolder" | "lighter")
+                || (t.len() == 3
+                    && t.ends_with("00")
+                    && t.starts_with(|c: char| c.is_ascii_digit()))
+        }) {
+            kept.push(format!("font-weight: {weight}"));
+        }
+        return (!kept.is_empty()).then(|| kept.join("; "));
+    }
+    if COLORED_PROPERTIES.iter().any(|p| property.starts_with(p)) {
+        let tokens: Vec<&str> = split_tokens(value)
+            .into_iter()
+            .map(|token| {
+                if is_color(token) {
+                    "currentColor"
+                } else {
+                    token
+                }
+            })
+            .collect();
+        return Some(format!("{property}: {}", tokens.join(" ")));
+    }
+    Some(format!("{property}: {value}"))
+}
+
+fn is_fixed_size(value: &str) -> bool {
+    if FIXED_SIZE_KEYWORDS.contains(&value) {
+        return true;
+    }
+    let unit =
+        value.trim_start_matches(|c: char| c.is_ascii_digit() || matches!(c, '.' | '-' | '+'));
+    unit.len() < value.len() && ABSOLUTE_UNITS.contains(&unit)
+}
+
+fn is_color(token: &str) -> bool {
+    let lower = token.to_ascii_lowercase();
+    lower.starts_with('#')
+        || [
+            "rgb(", "rgba(", "hsl(", "hsla(", "hwb(", "lab(", "lch(", "color(",
+        ]
+        .iter()
+        .any(|function| lower.starts_with(function))
+        || NAMED_COLORS.contains(&lower.as_str())
+}
+
+/// Whitespace-separated tokens of a value, keeping functions whole.
+fn split_tokens(value: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut rest = value.trim();
+    while !rest.is_empty() {
+        let end = scan(rest, char::is_whitespace);
+        tokens.push(&rest[..end]);
+        rest = rest[end..].trim_start();
+    }
+    tokens
+}
+
+fn resolve_urls(value: &str, base_dir: &str) -> String {
+    url_regex()
+        .replace_all(value, |captures: &Captures| {
+            let link = url_argument(captures).unwrap_or("");
+            match resolve_link(base_dir, link) {
+                Some(path) => format!("url(\"{}\")", path.replace('"', "%22")),
+                None => captures[0].to_string(),
+            }
+        })
+        .into_owned()
+}
+
+/// Archive path of a stylesheet link. Data URLs, web links and fragments
+/// are returned as they are.
+fn resolve_link(base_dir: &str, link: &str) -> Option<String> {
+    if link.is_empty() {
+        return None;
+    }
+    if link.starts_with('#') || link.contains(':') {
+        return Some(link.to_string());
+    }
+    let (path, fragment) = xhtml::split_fragment(link);
+    let mut resolved = xhtml::resolve(base_dir, path);
+    if let Some(fragment) = fragment {
+        resolved.push('#');
+        resolved.push_str(fragment);
+    }
+    Some(resolved)
+}
+
+fn url_regex() -> &'static Regex {
+    static URL: OnceLock<Regex> = OnceLock::new();
+    URL.get_or_init(|| Regex::new(r#"(?i)url\(\s*(?:"([^"]*)"|'([^']*)'|([^)\s]*))\s*\)"#).unwrap())
+}
+
+fn url_argument<'a>(captures: &Captures<'a>) -> Option<&'a str> {
+    (1..=3)
+        .find_map(|group| captures.get(group))
+        .map(|link| link.as_str().trim())
+}
+
+fn quoted(text: &str) -> Option<String> {
+    let quote = text.chars().next().filter(|c| matches!(c, '"' | '\''))?;
+    text[1..].split(quote).next().map(str::to_string)
+}
+
+fn at_rule_name(prelude: &str) -> String {
+    prelude[1..]
+        .split(|c: char| c.is_whitespace() || matches!(c, '(' | '"' | '\'' | '{'))
+        .next()
+        .unwrap_or("")
+        .to_ascii_lowercase()
+}
+
+/// Byte index of the first character matching `stop` outside strings and
+/// parentheses, or the length of `text`.
+fn scan(text: &str, stop: impl Fn(char) -> bool) -> usize {
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut escaped = false;
+    for (i, c) in text.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if let Some(q) = quote {
+            if c == q {
+                quote = None;
+            }
+        } else if matches!(c, '"' | '\'') {
+            quote = Some(c);
+        } else if depth == 0 && stop(c) {
+            return i;
+        } else if c == '(' {
+            depth += 1;
+        } else if c == ')' {
+            depth = depth.saturating_sub(1);
+        }
+    }
+    text.len()
+}
+
+/// Byte index of the `}` closing a block whose content starts `text`, or
+/// the length of `text` if it is never closed.
+fn block_end(text: &str) -> usize {
+    let mut depth = 0usize;
+    let mut offset = 0;
+    loop {
+        let i = offset + scan(&text[offset..], |c| matches!(c, '{' | '}'));
+        match text[i..].chars().next() {
+            Some('{') => depth += 1,
+            Some(_) if depth > 0 => depth -= 1,
+            _ => return i,
+        }
+        offset = i + 1;
+    }
+}
+
+fn strip_comments(css: &str) -> String {
+    let mut out = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(start) = rest.find("/*") {
+        out.push_str(&rest[..start]);
+        rest = rest[start + 2..]
+            .find("*/")
+            .map_or("", |end| &rest[start + 2 + end + 2..]);
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CSS: &str = r#"@charset "utf-8";
+@import url("../Styles/fonts.css");
+/* publisher defaults */
+@font-face { font-family: "Kai"; src: url(../Fonts/kai.ttf) }
+body { margin: 5%; background-color: #fff; color: #000; font-family: "Kai", serif }
+p.text { text-indent: 2em; font-size: 12pt; line-height: 1.8; margin: 0 }
+em, .italic { font: italic bold 14px/1.5 "Kai"; color: rgb(0, 0, 0) }
+hr.sep { border-top: 1px solid #333; width: 30% }
+@media (min-width: 600px) { h1 { font-size: 1.5em; color: red } div.bg { background: url(bg.png) } }
+"#;
+
+    #[test]
+    fn test_original_resolves_links_only() {
+        let original = rewrite(CSS, "OEBPS/Styles", false);
+        assert_eq!(original.imports, ["OEBPS/Styles/fonts.css"]);
+        assert!(original
+            .css
+            .contains("@font-face { font-family: \"Kai\"; src: url(\"OEBPS/Fonts/kai.ttf\") }"));
+        assert!(original.css.contains("background-color: #fff; color: #000"));
+        assert!(original
+            .css
+            .contains("div.bg { background: url(\"OEBPS/Styles/bg.png\") }"));
+        assert!(!original.css.contains("charset") && !original.css.contains("publisher"));
+    }
+
+    #[test]
+    fn test_clean_drops_conflicting_declarations() {
+        let clean = rewrite(CSS, "OEBPS/Styles", true).css;
+        assert_eq!(
+            clean,
+            "p.text { text-indent: 2em; margin: 0 }\n\
+             em, .italic { font-style: italic; font-weight: bold }\n\
+             hr.sep { border-top: 1px solid currentColor; width: 30% }\n\
+             @media (min-width: 600px) {\nh1 { font-size: 1.5em }\n}\n"
+        );
+        assert_eq!(
+            rewrite_declarations("color:#fff; font-style:italic; font-size: large", "", true),
+            "font-style: italic"
+        );
+    }
+
+    #[test]
+    fn test_scan_skips_strings_and_functions() {
+        assert_eq!(scan("a: url(x;y); b", |c| c == ';'), 11);
+        assert_eq!(scan(r#"content: "}"; x"#, |c| c == '}'), 15);
+        assert_eq!(block_end("a { b } } c"), 8);
+        assert_eq!(strip_comments("a /* b */c/* d"), "a c");
+        assert_eq!(
+            split_tokens("1px  solid rgb(0, 0, 0)"),
+            ["1px", "solid", "rgb(0, 0, 0)"]
+        );
+    }
+}
@@ -0,0 +1,263 @@
+//! DNS-over-HTTPS resolver for the HTTP client.
+//!
+//! Queries use the RFC 8484 wire format with GET requests, which every
+//! public DoH service accepts. Answers are cached per host for their TTL.
+//! When the DoH server cannot be reached or has no address for a host, the
+//! system resolver is used instead so a broken endpoint never takes the
+//! whole network stack down with it.
+
+use std::collections::HashMap;
+use std::io::{self, Read};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, ToSocketAddrs};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use base64::Engine;
+use url::Url;
+
+use crate::api::network;
+
+const TYPE_A: u16 = 1;
+const TYPE_AAAA: u16 = 28;
+const MIN_TTL: u32 = 30;
+const MAX_TTL: u32 = 3600;
+const MAX_RESPONSE_BYTES: u64 = 64 * 1024;
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+pub(crate) struct DohResolver {
+    endpoint: Url,
+    cache: Mutex<HashMap<String, CachedHost>>,
+}
+
+struct CachedHost {
+    addresses: Vec<IpAddr>,
+    expires: Instant,
+}
+
+impl DohResolver {
+    pub(crate) fn new(endpoint: Url) -> Self {
+        Self {
+            endpoint,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(crate) fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    pub(crate) fn clear(&self) {
+        self.cache.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+
+    /// Resolve a `host:port` pair the way `ureq::Resolver` expects.
+    pub(crate) fn resolve(&self, netloc: &str) -> io::Result<Vec<SocketAddr>> {
+        let (host, port) = netloc
+            .rsplit_once(':')
+            .and_then(|(host, port)| Some((host, port.parse::<u16>().ok()?)))
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Invalid address"))?;
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+        if let Ok(ip) = host.parse::<IpAddr>() {
+            return Ok(vec![SocketAddr::new(ip, port)]);
+        }
+        match self.lookup(&host.to_ascii_lowercase()) {
+            Some(addresses) => Ok(addresses
+                .into_iter()
+                .map(|ip| SocketAddr::new(ip, port))
+                .collect()),
+            None => netloc.to_socket_addrs().map(Iterator::collect),
+        }
+    }
+
+    fn lookup(&self, host: &str) -> Option<Vec<IpAddr>> {
+        let now = Instant::now();
+        if let Some(cached) = self
+            .cache
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(host)
+            .filter(|cached| cached.expires > now)
+        {
+            return Some(cached.addresses.clone());
+        }
+
+        let mut addresses = Vec::new();
+        let mut ttl = MAX_TTL;
+        for record_type in [TYPE_A, TYPE_AAAA] {
+            // A failed AAAA query still leaves the IPv4 addresses usable.
+            if let Ok(answer) = self.query(host, record_type) {
+                addresses.extend(answer.addresses);
+                ttl = ttl.min(answer.ttl);
+            }
+        }
+        if addresses.is_empty() {
+            return None;
+        }
+        self.cache.lock().unwrap_or_else(|e| e.into_inner()).insert(
+            host.to_string(),
+            CachedHost {
+                addresses: addresses.clone(),
+                expires: now + Duration::from_secs(ttl.max(MIN_TTL) as u64),
+            },
+        );
+        Some(addresses)
+    }
+
+    fn query(&self, host: &str, record_type: u16) -> Result<Answer, String> {
+        let message = encode_query(host, record_type)?;
+        let agent = network::agent(self.endpoint.as_str(), TIMEOUT, TIMEOUT, None)
+            .map_err(|e| e.to_string())?;
+        let response = agent
+            .request_url("GET", &self.endpoint)
+            .query(
+                "dns",
+                &base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(message),
+            )
+            .set("Accept", "application/dns-message")
+            .call()
+            .map_err(|e| e.to_string())?;
+        let mut body = Vec::new();
+        response
+            .into_reader()
+            .take(MAX_RESPONSE_BYTES)
+            .read_to_end(&mut body)
+            .map_err(|e| e.to_string())?;
+        decode_answer(&body)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Answer {
+    addresses: Vec<IpAddr>,
+    ttl: u32,
+}
+
+fn encode_query(host: &str, record_type: u16) -> Result<Vec<u8>, String> {
+    // ID 0 keeps GET requests cacheable, as RFC 8484 recommends.
+    let mut message = vec![0, 0, 0x01, 0x00, 0, 1, 0, 0, 0, 0, 0, 0];
+    for label in host.trim_end_matches('.').split('.') {
+        if label.is_empty() || label.len() > 63 {
+            return Err(format!("Invalid host name: {host}"));
+        }
+        message.push(label.len() as u8);
+        message.extend_from_slice(label.as_bytes());
+    }
+    message.push(0);
+    message.extend_from_slice(&record_type.to_be_bytes());
+    message.extend_from_slice(&1u16.to_be_bytes());
+    Ok(message)
+}
+
+fn decode_answer(message: &[u8]) -> Result<Answer, String> {
+    let malformed = || "Malformed DNS response".to_string();
+    let u16_at = |offset: usize| -> Result<u16, String> {
+        let bytes = message.get(offset..offset + 2).ok_or_else(malformed)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    };
+    let flags = u16_at(2)?;
+    if flags & 0x000f != 0 {
+        return Err(format!("DNS error code {}", flags & 0x000f));
+    }
+    let questions = u16_at(4)?;
+    let answers = u16_at(6)?;
+
+    let mut offset = 12;
+    for _ in 0..questions {
+        offset = skip_name(message, offset).ok_or_else(malformed)? + 4;
+    }
+    let mut addresses = Vec::new();
+    let mut ttl = MAX_TTL;
+    for _ in 0..answers {
+        offset = skip_name(message, offset).ok_or_else(malformed)?;
+        let record_type = u16_at(offset)?;
+        let record_ttl = u32::from(u16_at(offset + 4)?) << 16 | u32::from(u16_at(offset + 6)?);
+        let length = u16_at(offset + 8)? as usize;
+        let data = message
+            .get(offset + 10..offset + 10 + length)
+            .ok_or_else(malformed)?;
+        offset += 10 + length;
+        // CNAME records are skipped; the server follows them and includes
+        // the final addresses in the same answer.
+        let address = match (record_type, data.len()) {
+            (TYPE_A, 4) => IpAddr::V4(Ipv4Addr::new(data[0], data[1], data[2], data[3])),
+            (TYPE_AAAA, 16) => {
+                let octets: [u8; 16] = data.try_into().map_err(|_| malformed())?;
+                IpAddr::V6(Ipv6Addr::from(octets))
+            }
+            _ => continue,
+        };
+        addresses.push(address);
+        ttl = ttl.min(record_ttl);
+    }
+    Ok(Answer { addresses, ttl })
+}
+
+/// The offset just past the (possibly compressed) name at `offset`.
+fn skip_name(message: &[u8], mut offset: usize) -> Option<usize> {
+    loop {
+        let length = *message.get(offset)? as usize;
+        match length {
+            0 => return Some(offset + 1),
+            // A compression pointer ends the name.
+            l if l & 0xc0 == 0xc0 => return Some(offset + 2),
+            l => offset += 1 + l,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_query() {
+        let message = encode_query("www.example.com.", TYPE_A).unwrap();
+        assert_eq!(&message[..12], &[0, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(
+            &message[12..],
+            b"\x03www\x07example\x03com\x00\x00\x01\x00\x01"
+        );
+        assert!(encode_query("bad..host", TYPE_A).is_err());
+    }
+
+    #[test]
+    fn test_decode_answer() {
+        let mut message = encode_query("book.example.com", TYPE_A).unwrap();
+        message[2] = 0x81;
+        message[3] = 0x80;
+        message[7] = 3;
+        // CNAME to another name, then two A records pointing at the
+        // question name.
+        message.extend_from_slice(b"\xc0\x0c\x00\x05\x00\x01\x00\x00\x0e\x10\x00\x02\xc0\x0c");
+        message
+            .extend_from_slice(b"\xc0\x0c\x00\x01\x00\x01\x00\x00\x01\x2c\x00\x04\x5d\xb8\xd8\x22");
+        message
+            .extend_from_slice(b"\xc0\x0c\x00\x01\x00\x01\x00\x00\x00\x3c\x00\x04\x0a\x00\x00\x01");
+        let answer = decode_answer(&message).unwrap();
+        assert_eq!(
+            answer.addresses,
+            vec![
+                IpAddr::V4(Ipv4Addr::new(93, 184, 216, 34)),
+                IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+            ]
+        );
+        assert_eq!(answer.ttl, 60);
+
+        message[3] = 0x83;
+        assert!(decode_answer(&message).unwrap_err().contains("code 3"));
+        assert!(decode_answer(&message[..20]).is_err());
+    }
+
+    #[test]
+    fn test_resolves_literals_without_querying() {
+        let resolver = DohResolver::new(Url::parse("https://doh.invalid/dns-query").unwrap());
+        assert_eq!(
+            resolver.resolve("127.0.0.1:8080").unwrap(),
+            vec!["127.0.0.1:8080".parse::<SocketAddr>().unwrap()]
+        );
+        assert_eq!(
+            resolver.resolve("[::1]:443").unwrap(),
+            vec!["[::1]:443".parse::<SocketAddr>().unwrap()]
+        );
+    }
+}
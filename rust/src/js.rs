@@ -0,0 +1,498 @@
+//! Sandboxed JavaScript for book source rules.
+//!
+//! Each script runs in a fresh QuickJS context with the standard built-ins
+//! only: there is no module loader, file system, timer or process access.
+//! The runtime is capped at `MEMORY_LIMIT` bytes and interrupted after
+//! `TIME_LIMIT`. Scripts see Legado's `result`, `baseUrl`, `key` and `page`
+//! globals and a `java` object with the helpers sources commonly use:
+//!
+//! * `get(name)` / `put(name, value)` - rule variables
+//! * `ajax(url)` / `post(url, body, headers)` - HTTP through the app's
+//!   client, when the caller allows it
+//! * `base64Encode`, `base64Decode`, `hexEncodeToString`,
+//!   `hexDecodeToString`, `encodeURI(text, charset)`
+//! * `md5Encode`, `md5Encode16`, `digestHex(text, algorithm)`
+//! * `aesBase64DecodeToString` / `aesEncodeToBase64String(text, key,
+//!   transformation, iv)` for AES in CBC or ECB mode
+//! * `t2s`, `s2t`, `log`
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use aes::cipher::block_padding::{NoPadding, Pkcs7};
+use aes::cipher::{
+    BlockCipherDecrypt, BlockCipherEncrypt, BlockModeDecrypt, BlockModeEncrypt, KeyInit, KeyIvInit,
+};
+use anyhow::{anyhow, Result};
+use base64::Engine;
+use md5::{Digest, Md5};
+use rquickjs::function::Opt;
+use rquickjs::{Context, Ctx, Exception, Function, Object, Runtime};
+use serde_json::Value;
+use sha2::Sha256;
+
+use crate::api::opencc::{convert_chinese, ChineseConversion};
+
+const MEMORY_LIMIT: usize = 32 * 1024 * 1024;
+const STACK_LIMIT: usize = 1024 * 1024;
+const TIME_LIMIT: Duration = Duration::from_secs(5);
+
+/// Fetches a URL, with optional Legado request options, and returns the body.
+pub(crate) type Fetch = Rc<dyn Fn(&str) -> Result<String>>;
+
+/// What a script can see and change.
+pub(crate) struct Scope {
+    /// The value the script transforms: a page, a rule's output or a JSON
+    /// item.
+    pub(crate) result: Value,
+    pub(crate) base_url: String,
+    pub(crate) variables: Rc<RefCell<HashMap<String, String>>>,
+    pub(crate) fetch: Option<Fetch>,
+}
+
+/// Run a script and return its completion value. Strings stay strings,
+/// other values come back as JSON, and `undefined` becomes `null`.
+pub(crate) fn eval(code: &str, scope: &Scope) -> Result<Value> {
+    let runtime =
+        Runtime::new().map_err(|e| anyhow!("Failed to start the JavaScript engine: {e}"))?;
+    runtime.set_memory_limit(MEMORY_LIMIT);
+    runtime.set_max_stack_size(STACK_LIMIT);
+    let deadline = Instant::now() + TIME_LIMIT;
+    runtime.set_interrupt_handler(Some(Box::new(move || Instant::now() > deadline)));
+    let context = Context::full(&runtime)
+        .map_err(|e| anyhow!("Failed to start the JavaScript engine: {e}"))?;
+    context.with(|ctx| {
+        let run = || -> rquickjs::Result<Value> {
+            install(&ctx, scope)?;
+            let value: rquickjs::Value = ctx.eval(code)?;
+            to_json(&ctx, value)
+        };
+        run().map_err(|e| {
+            if Instant::now() > deadline {
+                anyhow!("JavaScript rule timed out after {}s", TIME_LIMIT.as_secs())
+            } else {
+                anyhow!("JavaScript error: {}", message(&ctx, e))
+            }
+        })
+    })
+}
+
+fn message(ctx: &Ctx, error: rquickjs::Error) -> String {
+    if !matches!(error, rquickjs::Error::Exception) {
+        return error.to_string();
+    }
+    let thrown = ctx.catch();
+    if let Some(message) = thrown.as_exception().and_then(Exception::message) {
+        return message;
+    }
+    ctx.json_stringify(thrown)
+        .ok()
+        .flatten()
+        .and_then(|text| text.to_string().ok())
+        .unwrap_or_else(|| "unknown exception".to_string())
+}
+
+fn to_json<'js>(ctx: &Ctx<'js>, value: rquickjs::Value<'js>) -> rquickjs::Result<Value> {
+    if let Some(text) = value.as_string() {
+        return Ok(Value::String(text.to_string()?));
+    }
+    let Some(json) = ctx.json_stringify(value)? else {
+        return Ok(Value::Null);
+    };
+    Ok(serde_json::from_str(&json.to_string()?).unwrap_or(Value::Null))
+}
+
+fn install<'js>(ctx: &Ctx<'js>, scope: &Scope) -> rquickjs::Result<()> {
+    let globals = ctx.globals();
+    let result = match &scope.result {
+        Value::String(text) => rquickjs::String::from_str(ctx.clone(), text)?.into_value(),
+        value => ctx.json_parse(value.to_string())?,
+    };
+    globals.set("result", result)?;
+    globals.set("baseUrl", scope.base_url.as_str())?;
+    {
+        let variables = scope.variables.borrow();
+        if let Some(key) = variables.get("key") {
+            globals.set("key", key.as_str())?;
+        }
+        if let Some(page) = variables.get("page") {
+            match page.parse::<f64>() {
+                Ok(page) => globals.set("page", page)?,
+                Err(_) => globals.set("page", page.as_str())?,
+            }
+        }
+    }
+
+    let java = Object::new(ctx.clone())?;
+    let variables = Rc::clone(&scope.variables);
+    java.set(
+        "get",
+        Function::new(ctx.clone(), move |name: String| {
+            variables.borrow().get(&name).cloned().unwrap_or_default()
+        })?,
+    )?;
+    let variables = Rc::clone(&scope.variables);
+    java.set(
+        "put",
+        Function::new(ctx.clone(), move |name: String, value: String| {
+            variables.borrow_mut().insert(name, value.clone());
+            value
+        })?,
+    )?;
+
+    let fetch = scope.fetch.clone();
+    java.set(
+        "ajax",
+        Function::new(ctx.clone(), move |ctx: Ctx<'js>, url: String| {
+            request(&ctx, fetch.as_ref(), &url)
+        })?,
+    )?;
+    let fetch = scope.fetch.clone();
+    java.set(
+        "post",
+        Function::new(
+            ctx.clone(),
+            move |ctx: Ctx<'js>,
+                  url: String,
+                  body: String,
+                  headers: Opt<HashMap<String, String>>| {
+                let options = serde_json::json!({
+                    "method": "POST",
+                    "body": body,
+                    "headers": headers.0.unwrap_or_default(),
+                });
+                request(&ctx, fetch.as_ref(), &format!("{url},{options}"))
+            },
+        )?,
+    )?;
+
+    java.set(
+        "base64Encode",
+        Function::new(ctx.clone(), |text: String| {
+            base64::engine::general_purpose::STANDARD.encode(text)
+        })?,
+    )?;
+    java.set(
+        "base64Decode",
+        Function::new(ctx.clone(), |ctx: Ctx<'js>, text: String| {
+            let bytes = decode_base64(&text).map_err(|e| throw(&ctx, &e))?;
+            rquickjs::Result::Ok(String::from_utf8_lossy(&bytes).into_owned())
+        })?,
+    )?;
+    java.set(
+        "hexEncodeToString",
+        Function::new(ctx.clone(), |text: String| hex(text.as_bytes()))?,
+    )?;
+    java.set(
+        "hexDecodeToString",
+        Function::new(ctx.clone(), |ctx: Ctx<'js>, text: String| {
+            let bytes = decode_hex(&text).map_err(|e| throw(&ctx, &e))?;
+            rquickjs::Result::Ok(String::from_utf8_lossy(&bytes).into_owned())
+        })?,
+    )?;
+    java.set(
+        "encodeURI",
+        Function::new(
+            ctx.clone(),
+            |ctx: Ctx<'js>, text: String, charset: Opt<String>| {
+                let encoding = match charset.0 {
+                    Some(label) => encoding_rs::Encoding::for_label(label.as_bytes())
+                        .ok_or_else(|| throw(&ctx, &format!("Unknown charset {label}")))?,
+                    None => encoding_rs::UTF_8,
+                };
+                rquickjs::Result::Ok(form_encode(&text, encoding))
+            },
+        )?,
+    )?;
+
+    java.set(
+        "md5Encode",
+        Function::new(ctx.clone(), |text: String| hex(&Md5::digest(text)))?,
+    )?;
+    java.set(
+        "md5Encode16",
+        Function::new(ctx.clone(), |text: String| {
+            hex(&Md5::digest(text))[8..24].to_string()
+        })?,
+    )?;
+    java.set(
+        "digestHex",
+        Function::new(
+            ctx.clone(),
+            |ctx: Ctx<'js>, text: String, algorithm: String| {
+                let digest = match algorithm.to_ascii_uppercase().replace('-', "").as_str() {
+                    "MD5" => Md5::digest(&text).to_vec(),
+                    "SHA1" => sha1_smol::Sha1::from(&text).digest().bytes().to_vec(),
+                    "SHA256" => Sha256::digest(&text).to_vec(),
+                    _ => return Err(throw(&ctx, &format!("Unsupported digest {algorithm}"))),
+                };
+                Ok(hex(&digest))
+            },
+        )?,
+    )?;
+    java.set(
+        "aesBase64DecodeToString",
+        Function::new(
+            ctx.clone(),
+            |ctx: Ctx<'js>, data: String, key: String, transformation: String, iv: Opt<String>| {
+                let data = decode_base64(&data).map_err(|e| throw(&ctx, &e))?;
+                let iv = iv.0.unwrap_or_default();
+                let plain = aes(&data, key.as_bytes(), &transformation, iv.as_bytes(), false)
+                    .map_err(|e| throw(&ctx, &e))?;
+                rquickjs::Result::Ok(String::from_utf8_lossy(&plain).into_owned())
+            },
+        )?,
+    )?;
+    java.set(
+        "aesEncodeToBase64String",
+        Function::new(
+            ctx.clone(),
+            |ctx: Ctx<'js>, data: String, key: String, transformation: String, iv: Opt<String>| {
+                let iv = iv.0.unwrap_or_default();
+                let encrypted = aes(
+                    data.as_bytes(),
+                    key.as_bytes(),
+                    &transformation,
+                    iv.as_bytes(),
+                    true,
+                )
+                .map_err(|e| throw(&ctx, &e))?;
+                rquickjs::Result::Ok(base64::engine::general_purpose::STANDARD.encode(encrypted))
+            },
+        )?,
+    )?;
+
+    java.set(
+        "t2s",
+        Function::new(ctx.clone(), |ctx: Ctx<'js>, text: String| {
+            convert_chinese(text, ChineseConversion::T2s).map_err(|e| throw(&ctx, &e.to_string()))
+        })?,
+    )?;
+    java.set(
+        "s2t",
+        Function::new(ctx.clone(), |ctx: Ctx<'js>, text: String| {
+            convert_chinese(text, ChineseConversion::S2t).map_err(|e| throw(&ctx, &e.to_string()))
+        })?,
+    )?;
+    java.set(
+        "log",
+        Function::new(ctx.clone(), |message: String| message)?,
+    )?;
+    globals.set("java", java)
+}
+
+fn throw(ctx: &Ctx, message: &str) -> rquickjs::Error {
+    Exception::throw_message(ctx, message)
+}
+
+fn request(ctx: &Ctx, fetch: Option<&Fetch>, url: &str) -> rquickjs::Result<String> {
+    let fetch = fetch.ok_or_else(|| throw(ctx, "HTTP requests are not available here"))?;
+    fetch(url).map_err(|e| throw(ctx, &e.to_string()))
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn decode_hex(text: &str) -> Result<Vec<u8>, String> {
+    let text = text.trim();
+    if !text.len().is_multiple_of(2) || !text.is_ascii() {
+        return Err(format!("Invalid hex string {text}"));
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&text[i..i + 2], 16).map_err(|e| e.to_string()))
+        .collect()
+}
+
+fn decode_base64(text: &str) -> Result<Vec<u8>, String> {
+    let text: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+    let text = text.trim_end_matches('=');
+    base64::engine::general_purpose::STANDARD_NO_PAD
+        .decode(text)
+        .or_else(|_| base64::engine::general_purpose::URL_SAFE_NO_PAD.decode(text))
+        .map_err(|e| format!("Invalid base64: {e}"))
+}
+
+/// `application/x-www-form-urlencoded` encoding, as Java's `URLEncoder`.
+fn form_encode(text: &str, encoding: &'static encoding_rs::Encoding) -> String {
+    let (bytes, _, _) = encoding.encode(text);
+    let mut output = String::with_capacity(bytes.len());
+    for &byte in bytes.iter() {
+        match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'*' => {
+                output.push(byte as char)
+            }
+            b' ' => output.push('+'),
+            byte => output.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    output
+}
+
+/// AES with a Java transformation such as `AES/CBC/PKCS5Padding`.
+fn aes(
+    data: &[u8],
+    key: &[u8],
+    transformation: &str,
+    iv: &[u8],
+    encrypt: bool,
+) -> Result<Vec<u8>, String> {
+    let parts: Vec<String> = transformation
+        .split('/')
+        .map(|part| part.trim().to_ascii_uppercase())
+        .collect();
+    let mode = parts.get(1).map_or("ECB", String::as_str);
+    let padding = parts.get(2).is_none_or(|padding| padding != "NOPADDING");
+    let ecb = match mode {
+        "ECB" => true,
+        "CBC" => false,
+        _ => return Err(format!("Unsupported AES transformation {transformation}")),
+    };
+    let options = Aes {
+        data,
+        key,
+        iv,
+        ecb,
+        padding,
+        encrypt,
+    };
+    match key.len() {
+        16 => options.run::<aes::Aes128>(),
+        24 => options.run::<aes::Aes192>(),
+        32 => options.run::<aes::Aes256>(),
+        n => Err(format!("Invalid AES key length {n}")),
+    }
+}
+
+struct Aes<'a> {
+    data: &'a [u8],
+    key: &'a [u8],
+    iv: &'a [u8],
+    ecb: bool,
+    padding: bool,
+    encrypt: bool,
+}
+
+impl Aes<'_> {
+    fn run<C>(&self) -> Result<Vec<u8>, String>
+    where
+        C: BlockCipherEncrypt + BlockCipherDecrypt + KeyInit,
+    {
+        let invalid = |e: &dyn std::fmt::Display| format!("AES failed: {e}");
+        let data = self.data;
+        Ok(match (self.ecb, self.encrypt, self.padding) {
+            (true, true, true) => ecb::Encryptor::<C>::new_from_slice(self.key)
+                .map_err(|e| invalid(&e))?
+                .encrypt_padded_vec::<Pkcs7>(data),
+            (true, true, false) => ecb::Encryptor::<C>::new_from_slice(self.key)
+                .map_err(|e| invalid(&e))?
+                .encrypt_padded_vec::<NoPadding>(data),
+            (true, false, true) => ecb::Decryptor::<C>::new_from_slice(self.key)
+                .map_err(|e| invalid(&e))?
+                .decrypt_padded_vec::<Pkcs7>(data)
+                .map_err(|e| invalid(&e))?,
+            (true, false, false) => ecb::Decryptor::<C>::new_from_slice(self.key)
+                .map_err(|e| invalid(&e))?
+                .decrypt_padded_vec::<NoPadding>(data)
+                .map_err(|e| invalid(&e))?,
+            (false, true, true) => cbc::Encryptor::<C>::new_from_slices(self.key, self.iv)
+                .map_err(|e| invalid(&e))?
+                .encrypt_padded_vec::<Pkcs7>(data),
+            (false, true, false) => cbc::Encryptor::<C>::new_from_slices(self.key, self.iv)
+                .map_err(|e| invalid(&e))?
+                .encrypt_padded_vec::<NoPadding>(data),
+            (false, false, true) => cbc::Decryptor::<C>::new_from_slices(self.key, self.iv)
+                .map_err(|e| invalid(&e))?
+                .decrypt_padded_vec::<Pkcs7>(data)
+                .map_err(|e| invalid(&e))?,
+            (false, false, false) => cbc::Decryptor::<C>::new_from_slices(self.key, self.iv)
+                .map_err(|e| invalid(&e))?
+                .decrypt_padded_vec::<NoPadding>(data)
+                .map_err(|e| invalid(&e))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scope(result: Value) -> Scope {
+        let variables = HashMap::from([
+            ("key".to_string(), "三体".to_string()),
+            ("page".to_string(), "2".to_string()),
+        ]);
+        Scope {
+            result,
+            base_url: "https://www.example.com/".to_string(),
+            variables: Rc::new(RefCell::new(variables)),
+            fetch: None,
+        }
+    }
+
+    #[test]
+    fn test_evaluates_with_legado_globals() {
+        let scope = scope(serde_json::json!({"data": [{"name": "a"}, {"name": "b"}]}));
+        assert_eq!(
+            eval("result.data.map(b => b.name)", &scope).unwrap(),
+            serde_json::json!(["a", "b"])
+        );
+        assert_eq!(
+            eval(
+                "baseUrl + 'search?p=' + (page - 1) + '&k=' + java.encodeURI(key, 'gbk')",
+                &scope
+            )
+            .unwrap(),
+            Value::String("https://www.example.com/search?p=1&k=%C8%FD%CC%E5".to_string())
+        );
+        eval("java.put('token', java.md5Encode('abc'))", &scope).unwrap();
+        assert_eq!(
+            scope.variables.borrow()["token"],
+            "900150983cd24fb0d6963f7d28e17f72"
+        );
+        assert_eq!(eval("undefined", &scope).unwrap(), Value::Null);
+        assert!(eval("java.ajax('https://www.example.com/')", &scope)
+            .unwrap_err()
+            .to_string()
+            .contains("not available"));
+    }
+
+    #[test]
+    fn test_crypto_helpers() {
+        let scope = scope(Value::Null);
+        let code = "var key = '0123456789abcdef', iv = 'fedcba9876543210';
+            var sealed = java.aesEncodeToBase64String('第一章', key, 'AES/CBC/PKCS5Padding', iv);
+            [sealed, java.aesBase64DecodeToString(sealed, key, 'AES/CBC/PKCS5Padding', iv),
+             java.digestHex('abc', 'SHA-256').slice(0, 8), java.base64Decode(java.base64Encode('阅读')),
+             java.hexDecodeToString(java.hexEncodeToString('hi'))]";
+        let value = eval(code, &scope).unwrap();
+        assert_eq!(value[1], "第一章");
+        assert_eq!(value[2], "ba7816bf");
+        assert_eq!(value[3], "阅读");
+        assert_eq!(value[4], "hi");
+        assert!(eval(
+            "java.aesBase64DecodeToString('AAAA', 'short', 'AES', '')",
+            &scope
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_enforces_limits() {
+        let scope = scope(Value::Null);
+        let error = eval("while (true) {}", &scope).unwrap_err().to_string();
+        assert!(error.contains("timed out"), "{error}");
+        assert!(eval("var a = []; while (true) a.push('x'.repeat(1024));", &scope).is_err());
+        assert!(
+            eval("typeof require + typeof std + typeof os", &scope).unwrap()
+                == "undefinedundefinedundefined"
+        );
+        assert!(eval("throw new Error('boom')", &scope)
+            .unwrap_err()
+            .to_string()
+            .contains("boom"));
+    }
+}
@@ -1,5 +1,23 @@
 mod api;
-mod frb_generated; /* AUTO INJECTED BY flutter_rust_bridge. This line may not be accurate, and you can change it according to your needs. */
+mod crypto;
+mod css;
+mod dns;
+mod frb_generated;
+mod js;
+mod mdict;
+mod mmap;
+mod remote;
+mod sevenz;
+mod sfnt;
+mod source_rule;
+mod stardict;
+#[cfg(test)]
+mod test_dirs;
+#[cfg(test)]
+mod test_fonts;
+mod user_regex;
+mod xhtml;
+mod xpath; /* AUTO INJECTED BY flutter_rust_bridge. This line may not be accurate, and you can change it according to your needs. */
 
 pub use api::*;
 
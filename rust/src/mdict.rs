@@ -0,0 +1,694 @@
+//! Reader for MDict `.mdx` dictionaries and `.mdd` resource files.
+//!
+//! Both share one layout: a UTF-16 XML header, a keyword section listing
+//! every headword with the offset of its record, and a record section of
+//! compressed blocks. Versions 1.x and 2.x are supported, including the
+//! common "encrypted" keyword index (`Encrypted="2"`), which is only
+//! obfuscated with a key derived from the data itself. Dictionaries that
+//! need a registration key (`Encrypted="1"`), version 3 files and LZO
+//! blocks are rejected.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use encoding_rs::{Encoding, UTF_16LE, UTF_8};
+use flate2::read::ZlibDecoder;
+use regex::Regex;
+
+use crate::api::dict::{Fold, KeyIndex};
+
+/// Largest decompressed block accepted. Writers use blocks of tens of
+/// kilobytes, so anything near this is a corrupt or hostile size field.
+const MAX_BLOCK_SIZE: u64 = 64 << 20;
+
+/// An open `.mdx` or `.mdd` file.
+pub(crate) struct Mdict {
+    file: File,
+    pub(crate) title: String,
+    pub(crate) description: String,
+    /// Encoding of headwords and, in `.mdx` files, of records.
+    encoding: &'static Encoding,
+    pub(crate) index: KeyIndex,
+    blocks: Vec<RecordBlock>,
+    /// The most recently decompressed record block.
+    cached: Option<(usize, Vec<u8>)>,
+}
+
+/// Where a record block is, in the file and in the decompressed records.
+struct RecordBlock {
+    file_offset: u64,
+    compressed_size: u64,
+    start: u64,
+    size: u64,
+}
+
+/// Integer width and key layout, which differ between format versions.
+#[derive(Clone, Copy)]
+struct Layout {
+    v2: bool,
+    /// Bytes per key code unit: 2 for UTF-16, otherwise 1.
+    unit: usize,
+}
+
+impl Mdict {
+    /// Open a dictionary; `resources` selects the `.mdd` key encoding.
+    pub(crate) fn open(path: &Path, resources: bool) -> Result<Self> {
+        let mut file =
+            File::open(path).map_err(|e| anyhow!("Failed to open {}: {e}", path.display()))?;
+        let mut reader = Reader::new(&mut file)?;
+
+        let header_len = reader.u32()? as usize;
+        let header = UTF_16LE
+            .decode_without_bom_handling(&reader.bytes(header_len)?)
+            .0
+            .into_owned();
+        reader.skip(4)?;
+        let attributes = attributes(&header);
+        let attribute = |name: &str| attributes.get(name).map(String::as_str).unwrap_or("");
+
+        let version: f32 = attribute("GeneratedByEngineVersion").parse().unwrap_or(2.0);
+        if version >= 3.0 {
+            return Err(anyhow!("MDict version {version} is not supported"));
+        }
+        let encrypted = match attribute("Encrypted") {
+            "Yes" => 1,
+            value => value.parse::<u32>().unwrap_or(0),
+        };
+        if encrypted & 1 != 0 {
+            return Err(anyhow!("Dictionary needs a registration key"));
+        }
+        let encoding = if resources {
+            UTF_16LE
+        } else {
+            match attribute("Encoding").to_ascii_lowercase().as_str() {
+                "" => UTF_8,
+                "utf-16" | "utf16" => UTF_16LE,
+                "gb2312" | "gbk" | "gb18030" => encoding_rs::GB18030,
+                label => Encoding::for_label(label.as_bytes()).unwrap_or(UTF_8),
+            }
+        };
+        let layout = Layout {
+            v2: version >= 2.0,
+            unit: if encoding == UTF_16LE { 2 } else { 1 },
+        };
+        let fold = Fold {
+            case_sensitive: attribute("KeyCaseSensitive").eq_ignore_ascii_case("yes"),
+            // Resource paths keep their punctuation.
+            strip: !resources && !attribute("StripKey").eq_ignore_ascii_case("no"),
+        };
+
+        let entries = read_keys(&mut reader, layout, encoding, encrypted & 2 != 0)?;
+        let (blocks, total) = read_record_blocks(&mut reader, layout)?;
+
+        let mut keyed = Vec::with_capacity(entries.len());
+        for (i, (start, key)) in entries.iter().enumerate() {
+            let end = entries.get(i + 1).map_or(total, |next| next.0);
+            keyed.push((key.clone(), *start, end.max(*start)));
+        }
+        let title = attribute("Title").trim();
+        let title = if title.is_empty() || title.starts_with("Title (No HTML") {
+            path.file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        } else {
+            title.to_string()
+        };
+        let description = attribute("Description").trim();
+        Ok(Self {
+            file,
+            title,
+            description: if description.starts_with("Description (No HTML") {
+                String::new()
+            } else {
+                description.to_string()
+            },
+            encoding,
+            index: KeyIndex::new(keyed, fold),
+            blocks,
+            cached: None,
+        })
+    }
+
+    /// Decode the text of a `.mdx` record.
+    pub(crate) fn text(&mut self, start: u64, end: u64) -> Result<String> {
+        let data = self.record(start, end)?;
+        let text = self.encoding.decode_without_bom_handling(&data).0;
+        Ok(text.trim_end_matches(['\0', '\r', '\n']).to_string())
+    }
+
+    /// The raw bytes of a record.
+    pub(crate) fn record(&mut self, start: u64, end: u64) -> Result<Vec<u8>> {
+        let len = end
+            .checked_sub(start)
+            .ok_or_else(|| anyhow!("Record {start}..{end} is invalid"))?;
+        let mut out = Vec::with_capacity(len.min(MAX_BLOCK_SIZE) as usize);
+        let mut position = start;
+        while position < end {
+            let index = self
+                .blocks
+                .partition_point(|block| block.start + block.size <= position);
+            let block = self
+                .blocks
+                .get(index)
+                .ok_or_else(|| anyhow!("Record offset {position} is out of range"))?;
+            let (block_start, block_end) = (block.start, block.start + block.size);
+            let data = self.block(index)?;
+            let from = (position - block_start) as usize;
+            let to = (end.min(block_end) - block_start) as usize;
+            let bytes = data
+                .get(from..to)
+                .ok_or_else(|| anyhow!("Record offset {position} is out of range"))?;
+            out.extend_from_slice(bytes);
+            position = block_end;
+        }
+        Ok(out)
+    }
+
+    fn block(&mut self, index: usize) -> Result<&[u8]> {
+        if self
+            .cached
+            .as_ref()
+            .is_none_or(|(cached, _)| *cached != index)
+        {
+            let block = &self.blocks[index];
+            let mut data = vec![0; block.compressed_size as usize];
+            self.file
+                .seek(SeekFrom::Start(block.file_offset))
+                .and_then(|_| self.file.read_exact(&mut data))
+                .map_err(|e| anyhow!("Failed to read dictionary: {e}"))?;
+            let decoded = decode_block(&data, false, block.size)?;
+            if decoded.len() as u64 != block.size {
+                return Err(anyhow!(
+                    "Record block decompressed to {} bytes, expected {}",
+                    decoded.len(),
+                    block.size
+                ));
+            }
+            self.cached = Some((index, decoded));
+        }
+        Ok(&self.cached.as_ref().unwrap().1)
+    }
+}
+
+/// Big-endian reads from the current file position.
+struct Reader<'a> {
+    file: &'a mut File,
+    len: u64,
+}
+
+impl<'a> Reader<'a> {
+    fn new(file: &'a mut File) -> Result<Self> {
+        let len = file
+            .metadata()
+            .map_err(|e| anyhow!("Failed to read dictionary: {e}"))?
+            .len();
+        Ok(Self { file, len })
+    }
+
+    /// Read `len` bytes, which must fit in the rest of the file, so a
+    /// corrupt size never turns into a huge allocation.
+    fn bytes(&mut self, len: usize) -> Result<Vec<u8>> {
+        let remaining = self.len.saturating_sub(self.position()?);
+        if len as u64 > remaining {
+            return Err(anyhow!(
+                "Dictionary is truncated: {len} bytes needed, {remaining} left"
+            ));
+        }
+        let mut data = vec![0; len];
+        self.file
+            .read_exact(&mut data)
+            .map_err(|e| anyhow!("Dictionary is truncated: {e}"))?;
+        Ok(data)
+    }
+
+    fn skip(&mut self, len: i64) -> Result<()> {
+        self.file
+            .seek(SeekFrom::Current(len))
+            .map(|_| ())
+            .map_err(|e| anyhow!("Failed to read dictionary: {e}"))
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_be_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    /// A `u64` in version 2 files, a `u32` before.
+    fn number(&mut self, layout: Layout) -> Result<u64> {
+        if layout.v2 {
+            Ok(u64::from_be_bytes(self.bytes(8)?.try_into().unwrap()))
+        } else {
+            self.u32().map(u64::from)
+        }
+    }
+
+    fn position(&mut self) -> Result<u64> {
+        self.file
+            .stream_position()
+            .map_err(|e| anyhow!("Failed to read dictionary: {e}"))
+    }
+}
+
+/// Read every headword with the offset of its record, in file order.
+fn read_keys(
+    reader: &mut Reader,
+    layout: Layout,
+    encoding: &'static Encoding,
+    encrypted_info: bool,
+) -> Result<Vec<(u64, String)>> {
+    let block_count = reader.number(layout)?;
+    let entry_count = reader.number(layout)?;
+    let info_len = if layout.v2 {
+        reader.number(layout)?
+    } else {
+        MAX_BLOCK_SIZE
+    };
+    let info_size = usize::try_from(reader.number(layout)?)?;
+    reader.number(layout)?; // total size of the key blocks
+    if layout.v2 {
+        reader.skip(4)?;
+    }
+
+    let info = reader.bytes(info_size)?;
+    let info = if layout.v2 {
+        decode_block(&info, encrypted_info, info_len)?
+    } else {
+        info
+    };
+    let mut sizes = Vec::new();
+    let mut pos: usize = 0;
+    let mut take = |len: usize| -> Result<&[u8]> {
+        let bytes = pos
+            .checked_add(len)
+            .and_then(|end| info.get(pos..end))
+            .ok_or_else(|| anyhow!("Keyword index is truncated"))?;
+        pos += len;
+        Ok(bytes)
+    };
+    let number = |bytes: &[u8]| bytes.iter().fold(0u64, |n, &b| n << 8 | b as u64);
+    let width = if layout.v2 { 8 } else { 4 };
+    for _ in 0..block_count {
+        take(width)?;
+        for _ in 0..2 {
+            // First and last headword of the block.
+            let len = if layout.v2 {
+                number(take(2)?) as usize + 1
+            } else {
+                number(take(1)?) as usize
+            };
+            take(len * layout.unit)?;
+        }
+        let compressed = usize::try_from(number(take(width)?))?;
+        let size = number(take(width)?);
+        sizes.push((compressed, size));
+    }
+
+    let mut entries = Vec::with_capacity(entry_count.min(1 << 16) as usize);
+    for (compressed, size) in sizes {
+        let block = decode_block(&reader.bytes(compressed)?, false, size)?;
+        let mut pos = 0;
+        while pos + width <= block.len() {
+            let offset = number(&block[pos..pos + width]);
+            pos += width;
+            let end = if layout.unit == 2 {
+                (pos..block.len().saturating_sub(1))
+                    .step_by(2)
+                    .find(|&i| block[i] == 0 && block[i + 1] == 0)
+                    .unwrap_or(block.len())
+            } else {
+                block[pos..]
+                    .iter()
+                    .position(|&b| b == 0)
+                    .map_or(block.len(), |i| pos + i)
+            };
+            let key = encoding.decode_without_bom_handling(&block[pos..end]).0;
+            entries.push((offset, key.into_owned()));
+            pos = (end + layout.unit).min(block.len());
+        }
+    }
+    Ok(entries)
+}
+
+/// Read the record block table; also returns the decompressed size of
+/// all records.
+fn read_record_blocks(reader: &mut Reader, layout: Layout) -> Result<(Vec<RecordBlock>, u64)> {
+    let block_count = reader.number(layout)?;
+    reader.number(layout)?; // entries
+    reader.number(layout)?; // size of the block table
+    reader.number(layout)?; // size of the blocks
+    let mut sizes = Vec::new();
+    for _ in 0..block_count {
+        sizes.push((reader.number(layout)?, reader.number(layout)?));
+    }
+
+    let mut file_offset = reader.position()?;
+    let mut start: u64 = 0;
+    let mut blocks = Vec::with_capacity(sizes.len());
+    for (compressed_size, size) in sizes {
+        let next_offset = file_offset
+            .checked_add(compressed_size)
+            .filter(|&end| end <= reader.len)
+            .ok_or_else(|| anyhow!("Record block at byte {file_offset} is out of range"))?;
+        if size > MAX_BLOCK_SIZE {
+            return Err(anyhow!("Record block of {size} bytes is too large"));
+        }
+        blocks.push(RecordBlock {
+            file_offset,
+            compressed_size,
+            start,
+            size,
+        });
+        file_offset = next_offset;
+        start = start
+            .checked_add(size)
+            .ok_or_else(|| anyhow!("Record blocks are too large"))?;
+    }
+    Ok((blocks, start))
+}
+
+/// Decompress a block: a 4-byte type, a 4-byte checksum, then the data,
+/// which may decompress to at most `max_len` bytes.
+fn decode_block(block: &[u8], encrypted: bool, max_len: u64) -> Result<Vec<u8>> {
+    if block.len() < 8 {
+        return Err(anyhow!("Dictionary block is truncated"));
+    }
+    let mut data = block[8..].to_vec();
+    if encrypted {
+        let mut seed = block[4..8].to_vec();
+        seed.extend_from_slice(&[0x95, 0x36, 0, 0]);
+        fast_decrypt(&mut data, &ripemd128(&seed));
+    }
+    let max_len = max_len.min(MAX_BLOCK_SIZE);
+    let out = match block[0] {
+        0 => data,
+        1 => return Err(anyhow!("LZO-compressed dictionaries are not supported")),
+        2 => {
+            let mut out = Vec::new();
+            ZlibDecoder::new(data.as_slice())
+                .take(max_len + 1)
+                .read_to_end(&mut out)
+                .map_err(|e| anyhow!("Corrupt dictionary block: {e}"))?;
+            out
+        }
+        kind => return Err(anyhow!("Unknown dictionary block type {kind}")),
+    };
+    if out.len() as u64 > max_len {
+        return Err(anyhow!(
+            "Dictionary block is larger than its declared {max_len} bytes"
+        ));
+    }
+    Ok(out)
+}
+
+/// Undo the byte-wise obfuscation of the keyword index.
+fn fast_decrypt(data: &mut [u8], key: &[u8]) {
+    let mut previous = 0x36;
+    for (i, byte) in data.iter_mut().enumerate() {
+        let cipher = *byte;
+        *byte = cipher.rotate_left(4) ^ previous ^ (i as u8) ^ key[i % key.len()];
+        previous = cipher;
+    }
+}
+
+fn attributes(header: &str) -> HashMap<String, String> {
+    let pattern = Regex::new(r#"(\w+)\s*=\s*"([^"]*)""#).expect("valid pattern");
+    pattern
+        .captures_iter(header)
+        .map(|caps| {
+            let value = quick_xml::escape::unescape(&caps[2])
+                .map(|value| value.into_owned())
+                .unwrap_or_else(|_| caps[2].to_string());
+            (caps[1].to_string(), value)
+        })
+        .collect()
+}
+
+/// RIPEMD-128 digest, which MDict uses to derive the index key.
+fn ripemd128(message: &[u8]) -> [u8; 16] {
+    const R: [usize; 64] = [
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 7, 4, 13, 1, 10, 6, 15, 3, 12, 0, 9,
+        5, 2, 14, 11, 8, 3, 10, 14, 4, 9, 15, 8, 1, 2, 7, 0, 6, 13, 11, 5, 12, 1, 9, 11, 10, 0, 8,
+        12, 4, 13, 3, 7, 15, 14, 5, 6, 2,
+    ];
+    const RP: [usize; 64] = [
+        5, 14, 7, 0, 9, 2, 11, 4, 13, 6, 15, 8, 1, 10, 3, 12, 6, 11, 3, 7, 0, 13, 5, 10, 14, 15, 8,
+        12, 4, 9, 1, 2, 15, 5, 1, 3, 7, 14, 6, 9, 11, 8, 12, 2, 10, 0, 4, 13, 8, 6, 4, 1, 3, 11,
+        15, 0, 5, 12, 2, 13, 9, 7, 10, 14,
+    ];
+    const S: [u32; 64] = [
+        11, 14, 15, 12, 5, 8, 7, 9, 11, 13, 14, 15, 6, 7, 9, 8, 7, 6, 8, 13, 11, 9, 7, 15, 7, 12,
+        15, 9, 11, 7, 13, 12, 11, 13, 6, 7, 14, 9, 13, 15, 14, 8, 13, 6, 5, 12, 7, 5, 11, 12, 14,
+        15, 14, 15, 9, 8, 9, 14, 5, 6, 8, 6, 5, 12,
+    ];
+    const SP: [u32; 64] = [
+        8, 9, 9, 11, 13, 15, 15, 5, 7, 7, 8, 11, 14, 14, 12, 6, 9, 13, 15, 7, 12, 8, 9, 11, 7, 7,
+        12, 7, 6, 15, 13, 11, 9, 7, 15, 11, 8, 6, 6, 14, 12, 13, 5, 14, 13, 13, 7, 5, 15, 5, 8, 11,
+        14, 14, 6, 14, 6, 9, 12, 9, 12, 5, 15, 8,
+    ];
+    const K: [u32; 4] = [0, 0x5A82_7999, 0x6ED9_EBA1, 0x8F1B_BCDC];
+    const KP: [u32; 4] = [0x50A2_8BE6, 0x5C4D_D124, 0x6D70_3EF3, 0];
+    fn f(round: usize, x: u32, y: u32, z: u32) -> u32 {
+        match round {
+            0 => x ^ y ^ z,
+            1 => (x & y) | (!x & z),
+            2 => (x | !y) ^ z,
+            _ => (x & z) | (y & !z),
+        }
+    }
+
+    let mut padded = message.to_vec();
+    padded.push(0x80);
+    while padded.len() % 64 != 56 {
+        padded.push(0);
+    }
+    padded.extend_from_slice(&((message.len() as u64) * 8).to_le_bytes());
+
+    let mut h: [u32; 4] = [0x6745_2301, 0xEFCD_AB89, 0x98BA_DCFE, 0x1032_5476];
+    for chunk in padded.chunks(64) {
+        let x: Vec<u32> = chunk
+            .chunks(4)
+            .map(|w| u32::from_le_bytes([w[0], w[1], w[2], w[3]]))
+            .collect();
+        let [mut a, mut b, mut c, mut d] = h;
+        let [mut ap, mut bp, mut cp, mut dp] = h;
+        for j in 0..64 {
+            let round = j / 16;
+            let t = a
+                .wrapping_add(f(round, b, c, d))
+                .wrapping_add(x[R[j]])
+                .wrapping_add(K[round])
+                .rotate_left(S[j]);
+            (a, b, c, d) = (d, t, b, c);
+            let t = ap
+                .wrapping_add(f(3 - round, bp, cp, dp))
+                .wrapping_add(x[RP[j]])
+                .wrapping_add(KP[round])
+                .rotate_left(SP[j]);
+            (ap, bp, cp, dp) = (dp, t, bp, cp);
+        }
+        let t = h[1].wrapping_add(c).wrapping_add(dp);
+        h[1] = h[2].wrapping_add(d).wrapping_add(ap);
+        h[2] = h[3].wrapping_add(a).wrapping_add(bp);
+        h[3] = h[0].wrapping_add(b).wrapping_add(cp);
+        h[0] = t;
+    }
+    let mut digest = [0; 16];
+    for (out, word) in digest.chunks_mut(4).zip(h) {
+        out.copy_from_slice(&word.to_le_bytes());
+    }
+    digest
+}
+
+/// Build small dictionaries for tests.
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::test_dirs::temp_dir;
+    use flate2::write::ZlibEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    fn zlib_block(data: &[u8]) -> Vec<u8> {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).unwrap();
+        let mut block = vec![2, 0, 0, 0, 0x12, 0x34, 0x56, 0x78];
+        block.extend_from_slice(&encoder.finish().unwrap());
+        block
+    }
+
+    fn encrypt_block(block: &mut [u8]) {
+        let mut seed = block[4..8].to_vec();
+        seed.extend_from_slice(&[0x95, 0x36, 0, 0]);
+        let key = ripemd128(&seed);
+        let mut previous = 0x36;
+        for (i, byte) in block[8..].iter_mut().enumerate() {
+            *byte = (*byte ^ previous ^ (i as u8) ^ key[i % key.len()]).rotate_left(4);
+            previous = *byte;
+        }
+    }
+
+    /// A version 2 file with an encrypted keyword index, two key blocks
+    /// and two record blocks. Keys must be given in sorted order.
+    pub(crate) fn build(entries: &[(&str, &[u8])], resources: bool, title: &str) -> Vec<u8> {
+        let encode = |text: &str| -> (Vec<u8>, u16) {
+            if resources {
+                let units: Vec<u16> = text.encode_utf16().collect();
+                (
+                    units.iter().flat_map(|u| u.to_le_bytes()).collect(),
+                    units.len() as u16,
+                )
+            } else {
+                (text.as_bytes().to_vec(), text.len() as u16)
+            }
+        };
+        let terminator: &[u8] = if resources { &[0, 0] } else { &[0] };
+        let encoding = if resources { "UTF-16" } else { "UTF-8" };
+        let header = format!(
+            "<Dictionary GeneratedByEngineVersion=\"2.0\" RequiredEngineVersion=\"2.0\" \
+             Encrypted=\"2\" Encoding=\"{encoding}\" Format=\"Html\" KeyCaseSensitive=\"No\" \
+             Title=\"{title}\" Description=\"Test &amp; sample\"/>\r\n\0"
+        );
+        let header: Vec<u8> = header
+            .encode_utf16()
+            .flat_map(|u| u.to_le_bytes())
+            .collect();
+
+        let mut offsets = Vec::new();
+        let mut records = Vec::new();
+        for (_, record) in entries {
+            offsets.push(records.len() as u64);
+            records.extend_from_slice(record);
+        }
+        let halves = entries.len().div_ceil(2);
+        let mut info = Vec::new();
+        let mut key_blocks = Vec::new();
+        for (chunk, chunk_offsets) in entries.chunks(halves).zip(offsets.chunks(halves)) {
+            let mut plain = Vec::new();
+            for ((key, _), offset) in chunk.iter().zip(chunk_offsets) {
+                plain.extend_from_slice(&offset.to_be_bytes());
+                plain.extend_from_slice(&encode(key).0);
+                plain.extend_from_slice(terminator);
+            }
+            let block = zlib_block(&plain);
+            info.extend_from_slice(&(chunk.len() as u64).to_be_bytes());
+            for key in [chunk[0].0, chunk[chunk.len() - 1].0] {
+                let (bytes, len) = encode(key);
+                info.extend_from_slice(&len.to_be_bytes());
+                info.extend_from_slice(&bytes);
+                info.extend_from_slice(terminator);
+            }
+            info.extend_from_slice(&(block.len() as u64).to_be_bytes());
+            info.extend_from_slice(&(plain.len() as u64).to_be_bytes());
+            key_blocks.extend_from_slice(&block);
+        }
+        let mut info_block = zlib_block(&info);
+        encrypt_block(&mut info_block);
+
+        let mut out = Vec::new();
+        out.extend_from_slice(&(header.len() as u32).to_be_bytes());
+        out.extend_from_slice(&header);
+        out.extend_from_slice(&[0; 4]);
+        let block_count = entries.chunks(halves).count() as u64;
+        for number in [
+            block_count,
+            entries.len() as u64,
+            info.len() as u64,
+            info_block.len() as u64,
+            key_blocks.len() as u64,
+        ] {
+            out.extend_from_slice(&number.to_be_bytes());
+        }
+        out.extend_from_slice(&[0; 4]);
+        out.extend_from_slice(&info_block);
+        out.extend_from_slice(&key_blocks);
+
+        let split = records.len() / 2;
+        let record_blocks = [zlib_block(&records[..split]), zlib_block(&records[split..])];
+        let sizes = [split, records.len() - split];
+        for number in [
+            2,
+            entries.len() as u64,
+            32,
+            record_blocks.concat().len() as u64,
+        ] {
+            out.extend_from_slice(&number.to_be_bytes());
+        }
+        for (block, size) in record_blocks.iter().zip(sizes) {
+            out.extend_from_slice(&(block.len() as u64).to_be_bytes());
+            out.extend_from_slice(&(size as u64).to_be_bytes());
+        }
+        out.extend_from_slice(&record_blocks.concat());
+        out
+    }
+
+    fn hex(digest: [u8; 16]) -> String {
+        digest.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn test_ripemd128() {
+        assert_eq!(hex(ripemd128(b"")), "cdf26213a150dc3ecb610f18f6b38b46");
+        assert_eq!(hex(ripemd128(b"abc")), "c14a12199c66e4ba84636b0f69144c77");
+        let digits = "1234567890".repeat(8);
+        assert_eq!(
+            hex(ripemd128(digits.as_bytes())),
+            "3f45ef194732c2dbb2c4a2c769795fa3"
+        );
+    }
+
+    #[test]
+    fn test_reads_records_across_blocks() {
+        let entries: [(&str, &[u8]); 3] = [
+            ("apple", b"<b>apple</b> a fruit\r\n\0"),
+            ("banana", b"<b>banana</b> a long fruit\r\n\0"),
+            ("cherry", b"<b>cherry</b>\r\n\0"),
+        ];
+        let dir = temp_dir("mdict-blocks");
+        let path = dir.join("blocks.mdx");
+        std::fs::write(&path, build(&entries, false, "Fruit")).unwrap();
+        let mut mdict = Mdict::open(&path, false).unwrap();
+        assert_eq!(mdict.title, "Fruit");
+        assert_eq!(mdict.description, "Test & sample");
+        assert_eq!(mdict.index.len(), 3);
+        for (key, record) in entries {
+            let (_, start, end) = mdict.index.find(key)[0];
+            let expected = String::from_utf8_lossy(record);
+            assert_eq!(
+                mdict.text(start, end).unwrap(),
+                expected.trim_end_matches(['\0', '\r', '\n'])
+            );
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_rejects_bad_block_sizes() {
+        let entries: [(&str, &[u8]); 2] = [("apple", b"a fruit\0"), ("banana", b"a long fruit\0")];
+        let file = build(&entries, false, "Fruit");
+        let records = entries.map(|(_, record)| record).concat();
+        let split = records.len() / 2;
+        let blocks_len = zlib_block(&records[..split]).len() + zlib_block(&records[split..]).len();
+        // The record block table sits right before the blocks.
+        let table = file.len() - blocks_len - 32;
+        let dir = temp_dir("mdict-bad-sizes");
+        let path = dir.join("bad_sizes.mdx");
+        let tampered = |at: usize, value: u64| {
+            let mut data = file.clone();
+            data[at..at + 8].copy_from_slice(&value.to_be_bytes());
+            std::fs::write(&path, data).unwrap();
+            Mdict::open(&path, false)
+        };
+
+        // A block that decompresses to less than it declares.
+        let mut mdict = tampered(table + 8, split as u64 + 100).unwrap();
+        let (_, start, end) = mdict.index.find("apple")[0];
+        assert!(mdict.text(start, end).is_err());
+        // Sizes that overflow or run past the file.
+        assert!(tampered(table, u64::MAX - 4).is_err());
+        assert!(tampered(table + 16, 1 << 40).is_err());
+        assert!(tampered(table + 24, 1 << 40).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
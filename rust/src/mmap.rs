@@ -0,0 +1,130 @@
+//! Read-only memory maps of whole files.
+//!
+//! Pages of a mapped file are read from disk when first touched and can
+//! be dropped again by the OS under memory pressure, so only the parts of
+//! a file in use take up memory, however large it is. Maps are private and
+//! read-only. Targets without `mmap` read the whole file instead.
+
+use std::fs::File;
+use std::ops::Deref;
+
+use anyhow::{anyhow, Result};
+
+/// The bytes of a file, mapped into memory.
+pub(crate) struct Mmap {
+    #[cfg(unix)]
+    ptr: *const u8,
+    #[cfg(unix)]
+    len: usize,
+    #[cfg(not(unix))]
+    data: Vec<u8>,
+}
+
+// SAFETY: the mapping is read-only and owned by this value, so sharing it
+// between threads is no different to sharing a `&[u8]`.
+unsafe impl Send for Mmap {}
+unsafe impl Sync for Mmap {}
+
+impl Mmap {
+    /// Map all of `file`. The file must not be truncated while the map is
+    /// alive: reading pages past its new end faults.
+    #[cfg(unix)]
+    pub(crate) fn map(file: &File) -> Result<Self> {
+        use std::os::unix::io::AsRawFd;
+
+        let len = file
+            .metadata()
+            .map_err(|e| anyhow!("Failed to read file size: {e}"))?
+            .len();
+        let len = usize::try_from(len).map_err(|_| anyhow!("File is too large to map"))?;
+        if len == 0 {
+            // Empty maps are an error; an empty slice needs no map.
+            return Ok(Self {
+                ptr: std::ptr::NonNull::dangling().as_ptr(),
+                len: 0,
+            });
+        }
+        // SAFETY: a fresh private read-only mapping of an open file; the
+        // pointer is only read through `deref` while `self` is alive.
+        let ptr = unsafe {
+            libc::mmap(
+                std::ptr::null_mut(),
+                len,
+                libc::PROT_READ,
+                libc::MAP_PRIVATE,
+                file.as_raw_fd(),
+                0,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(anyhow!(
+                "Failed to map file: {}",
+                std::io::Error::last_os_error()
+            ));
+        }
+        Ok(Self {
+            ptr: ptr as *const u8,
+            len,
+        })
+    }
+
+    #[cfg(not(unix))]
+    pub(crate) fn map(mut file: &File) -> Result<Self> {
+        use std::io::Read;
+
+        let mut data = Vec::new();
+        file.read_to_end(&mut data)
+            .map_err(|e| anyhow!("Failed to read file: {e}"))?;
+        Ok(Self { data })
+    }
+}
+
+impl Deref for Mmap {
+    type Target = [u8];
+
+    #[cfg(unix)]
+    fn deref(&self) -> &[u8] {
+        // SAFETY: `ptr` points to `len` mapped bytes, or is dangling with
+        // `len` 0, for as long as `self` lives.
+        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    #[cfg(not(unix))]
+    fn deref(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+#[cfg(unix)]
+impl Drop for Mmap {
+    fn drop(&mut self) {
+        if self.len > 0 {
+            // SAFETY: unmaps exactly the mapping made in `map`, which no
+            // borrow can outlive.
+            unsafe {
+                libc::munmap(self.ptr as *mut libc::c_void, self.len);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_dirs::temp_dir;
+
+    #[test]
+    fn test_maps_file_contents() {
+        let dir = temp_dir("mmap");
+        let path = dir.join("test.txt");
+        std::fs::write(&path, "第一章\n天色渐晚。\n").unwrap();
+        let map = Mmap::map(&File::open(&path).unwrap()).unwrap();
+        assert_eq!(&map[..], "第一章\n天色渐晚。\n".as_bytes());
+        drop(map);
+
+        std::fs::write(&path, "").unwrap();
+        let empty = Mmap::map(&File::open(&path).unwrap()).unwrap();
+        assert!(empty.is_empty());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
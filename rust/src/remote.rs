@@ -0,0 +1,157 @@
+//! Remote directories of blobs for sync: a WebDAV directory, or an HTTP
+//! endpoint that answers `GET`, `PUT` and `DELETE` on `<base>/<name>`.
+//! Writes can be made conditional on the current ETag, so two devices that
+//! update the same blob at once cannot both succeed.
+
+use std::io::Read;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+
+use crate::api::network;
+use crate::api::webdav::{self, WebDavConfig};
+
+/// Largest blob read.
+const MAX_FILE_BYTES: u64 = 64 * 1024 * 1024;
+
+/// A downloaded blob, with its ETag for conditional writes.
+pub(crate) struct RemoteFile {
+    pub(crate) data: Vec<u8>,
+    pub(crate) etag: Option<String>,
+}
+
+/// A remote directory of blobs.
+pub(crate) trait RemoteStore {
+    /// A blob; `None` if it does not exist.
+    fn get(&self, name: &str) -> Result<Option<RemoteFile>>;
+
+    /// Write a blob if `condition` holds. Returns false if it did not.
+    fn put(&self, name: &str, data: &[u8], condition: Condition) -> Result<bool>;
+
+    fn delete(&self, name: &str) -> Result<()>;
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum Condition<'a> {
+    Any,
+    Absent,
+    Matches(&'a str),
+}
+
+impl<'a> Condition<'a> {
+    fn header(self) -> Option<(&'static str, &'a str)> {
+        match self {
+            Condition::Any => None,
+            Condition::Absent => Some(("If-None-Match", "*")),
+            Condition::Matches(etag) => Some(("If-Match", etag)),
+        }
+    }
+}
+
+pub(crate) struct WebDavStore {
+    client: webdav::Client,
+    dir: String,
+}
+
+impl WebDavStore {
+    /// A store in `dir`, relative to the base URL; created if missing.
+    pub(crate) fn new(config: &WebDavConfig, dir: &str) -> Result<Self> {
+        let client = webdav::Client::new(config)?;
+        let dir = dir.trim_matches('/').to_string();
+        client.make_dirs(&dir)?;
+        Ok(Self { client, dir })
+    }
+
+    fn path(&self, name: &str) -> String {
+        format!("{}/{name}", self.dir)
+    }
+}
+
+impl RemoteStore for WebDavStore {
+    fn get(&self, name: &str) -> Result<Option<RemoteFile>> {
+        self.client.read_bytes(&self.path(name), MAX_FILE_BYTES)
+    }
+
+    fn put(&self, name: &str, data: &[u8], condition: Condition) -> Result<bool> {
+        self.client
+            .write_bytes(&self.path(name), data, condition.header())
+    }
+
+    fn delete(&self, name: &str) -> Result<()> {
+        self.client.delete(&self.path(name))
+    }
+}
+
+pub(crate) struct HttpStore {
+    agent: ureq::Agent,
+    base_url: String,
+    authorization: Option<String>,
+}
+
+impl HttpStore {
+    pub(crate) fn new(base_url: &str, bearer_token: Option<String>) -> Result<Self> {
+        let base_url = base_url.trim_end_matches('/').to_string();
+        Ok(Self {
+            agent: network::agent(
+                &base_url,
+                Duration::from_secs(30),
+                Duration::from_secs(60),
+                None,
+            )?,
+            base_url,
+            authorization: bearer_token.map(|token| format!("Bearer {token}")),
+        })
+    }
+
+    fn request(&self, method: &str, name: &str) -> ureq::Request {
+        let request = self
+            .agent
+            .request(method, &format!("{}/{name}", self.base_url));
+        match &self.authorization {
+            Some(authorization) => request.set("Authorization", authorization),
+            None => request,
+        }
+    }
+}
+
+impl RemoteStore for HttpStore {
+    fn get(&self, name: &str) -> Result<Option<RemoteFile>> {
+        let response = match self.request("GET", name).call() {
+            Ok(response) => response,
+            Err(ureq::Error::Status(404, _)) => return Ok(None),
+            Err(e) => return Err(anyhow!("Failed to download {name}: {e}")),
+        };
+        let etag = response.header("ETag").map(str::to_string);
+        let mut data = Vec::new();
+        response
+            .into_reader()
+            .take(MAX_FILE_BYTES + 1)
+            .read_to_end(&mut data)
+            .map_err(|e| anyhow!("Failed to download {name}: {e}"))?;
+        if data.len() as u64 > MAX_FILE_BYTES {
+            return Err(anyhow!("{name} is larger than {MAX_FILE_BYTES} bytes"));
+        }
+        Ok(Some(RemoteFile { data, etag }))
+    }
+
+    fn put(&self, name: &str, data: &[u8], condition: Condition) -> Result<bool> {
+        let mut request = self
+            .request("PUT", name)
+            .set("Content-Type", "application/octet-stream");
+        if let Some((header, value)) = condition.header() {
+            request = request.set(header, value);
+        }
+        match request.send_bytes(data) {
+            Ok(_) => Ok(true),
+            Err(ureq::Error::Status(412, _)) => Ok(false),
+            Err(e) => Err(anyhow!("Failed to upload {name}: {e}")),
+        }
+    }
+
+    fn delete(&self, name: &str) -> Result<()> {
+        match self.request("DELETE", name).call() {
+            Ok(_) | Err(ureq::Error::Status(404, _)) => Ok(()),
+            Err(e) => Err(anyhow!("Failed to delete {name}: {e}")),
+        }
+    }
+}
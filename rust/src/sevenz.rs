@@ -0,0 +1,698 @@
+//! Reader for 7z archives.
+//!
+//! Covers what 7-Zip writes for documents: LZMA, LZMA2, Deflate and stored
+//! folders, solid or not, with plain or compressed headers. Encrypted
+//! entries are listed but not extracted, as are folders built from several
+//! coders, such as the BCJ filters used for executables.
+
+use std::fs::File;
+use std::io::{self, BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use flate2::read::DeflateDecoder;
+use lzma_rust2::{Lzma2Reader, LzmaReader};
+
+const SIGNATURE: &[u8] = b"7z\xBC\xAF\x27\x1C";
+
+/// Largest header accepted, so a corrupt size cannot exhaust memory.
+const MAX_HEADER_BYTES: u64 = 64 << 20;
+
+const END: u64 = 0x00;
+const HEADER: u64 = 0x01;
+const ARCHIVE_PROPERTIES: u64 = 0x02;
+const ADDITIONAL_STREAMS_INFO: u64 = 0x03;
+const MAIN_STREAMS_INFO: u64 = 0x04;
+const FILES_INFO: u64 = 0x05;
+const PACK_INFO: u64 = 0x06;
+const UNPACK_INFO: u64 = 0x07;
+const SUBSTREAMS_INFO: u64 = 0x08;
+const SIZE: u64 = 0x09;
+const CRC: u64 = 0x0A;
+const FOLDER: u64 = 0x0B;
+const CODERS_UNPACK_SIZE: u64 = 0x0C;
+const NUM_UNPACK_STREAM: u64 = 0x0D;
+const EMPTY_STREAM: u64 = 0x0E;
+const EMPTY_FILE: u64 = 0x0F;
+const NAME: u64 = 0x11;
+const ENCODED_HEADER: u64 = 0x17;
+
+const COPY: &[u8] = &[0x00];
+const LZMA: &[u8] = &[0x03, 0x01, 0x01];
+const LZMA2: &[u8] = &[0x21];
+const DEFLATE: &[u8] = &[0x04, 0x01, 0x08];
+const AES: &[u8] = &[0x06, 0xF1, 0x07, 0x01];
+
+/// An open 7z archive.
+pub(crate) struct SevenZip {
+    file: File,
+    pub(crate) entries: Vec<Entry>,
+    folders: Vec<Folder>,
+}
+
+/// A file or directory in the archive.
+pub(crate) struct Entry {
+    pub(crate) name: String,
+    pub(crate) size: u64,
+    pub(crate) is_dir: bool,
+    /// Folder holding the data, and position among the folder's files;
+    /// `None` for empty files and directories.
+    stream: Option<(usize, usize)>,
+}
+
+/// A run of compressed data that decodes to one or more files.
+struct Folder {
+    coders: Vec<Coder>,
+    pack_offset: u64,
+    pack_size: u64,
+    unpack_size: u64,
+    /// Sizes of the files stored in the folder, in order.
+    file_sizes: Vec<u64>,
+}
+
+struct Coder {
+    id: Vec<u8>,
+    properties: Vec<u8>,
+}
+
+impl Folder {
+    fn is_encrypted(&self) -> bool {
+        self.coders.iter().any(|coder| coder.id == AES)
+    }
+}
+
+impl Entry {
+    pub(crate) fn is_encrypted(&self, archive: &SevenZip) -> bool {
+        self.stream
+            .is_some_and(|(folder, _)| archive.folders[folder].is_encrypted())
+    }
+}
+
+impl SevenZip {
+    pub(crate) fn open(path: &Path) -> Result<Self> {
+        let mut file =
+            File::open(path).map_err(|e| anyhow!("Failed to open {}: {e}", path.display()))?;
+        let mut start = [0; 32];
+        file.read_exact(&mut start)
+            .map_err(|_| anyhow!("Invalid 7z archive: truncated"))?;
+        if &start[..6] != SIGNATURE {
+            return Err(anyhow!("Not a 7z archive"));
+        }
+        let next_offset = u64::from_le_bytes(start[12..20].try_into().unwrap());
+        let next_size = u64::from_le_bytes(start[20..28].try_into().unwrap());
+        if next_size > MAX_HEADER_BYTES {
+            return Err(anyhow!("Invalid 7z archive: header too large"));
+        }
+        let mut header = vec![0; next_size as usize];
+        file.seek(SeekFrom::Start(32 + next_offset))
+            .and_then(|_| file.read_exact(&mut header))
+            .map_err(|_| anyhow!("Invalid 7z archive: truncated header"))?;
+
+        // Headers are usually compressed themselves, possibly more than once.
+        loop {
+            let mut reader = ByteReader::new(&header);
+            match reader.number()? {
+                HEADER => break,
+                ENCODED_HEADER => {
+                    let folders = read_streams_info(&mut reader)?;
+                    let folder = folders
+                        .first()
+                        .ok_or_else(|| anyhow!("Invalid 7z archive: empty encoded header"))?;
+                    if folder.unpack_size > MAX_HEADER_BYTES {
+                        return Err(anyhow!("Invalid 7z archive: header too large"));
+                    }
+                    let mut decoded = Vec::new();
+                    decoder(&file, folder)?
+                        .read_to_end(&mut decoded)
+                        .map_err(|e| anyhow!("Invalid 7z archive: {e}"))?;
+                    header = decoded;
+                }
+                id => return Err(anyhow!("Invalid 7z archive: unexpected header {id:#x}")),
+            }
+        }
+
+        let mut reader = ByteReader::new(&header[1..]);
+        let mut folders = Vec::new();
+        let mut entries = Vec::new();
+        loop {
+            match reader.number()? {
+                END => break,
+                ARCHIVE_PROPERTIES => loop {
+                    if reader.number()? == END {
+                        break;
+                    }
+                    let size = reader.number()?;
+                    reader.take(size)?;
+                },
+                ADDITIONAL_STREAMS_INFO => {
+                    read_streams_info(&mut reader)?;
+                }
+                MAIN_STREAMS_INFO => folders = read_streams_info(&mut reader)?,
+                FILES_INFO => entries = read_files_info(&mut reader, &folders)?,
+                id => return Err(anyhow!("Invalid 7z archive: unexpected property {id:#x}")),
+            }
+        }
+        Ok(Self {
+            file,
+            entries,
+            folders,
+        })
+    }
+
+    /// Stream the entries in `wanted` (indices into `entries`) to `f`, in
+    /// archive order. Solid folders are decoded once, skipping the files
+    /// that are not wanted.
+    pub(crate) fn extract(
+        &self,
+        wanted: &[usize],
+        mut f: impl FnMut(usize, &mut dyn Read) -> Result<()>,
+    ) -> Result<()> {
+        let mut order: Vec<usize> = wanted.to_vec();
+        order.sort_by_key(|&i| self.entries[i].stream.unwrap_or((usize::MAX, i)));
+        order.dedup();
+        let mut current: Option<(usize, usize, Box<dyn Read + '_>)> = None;
+        for index in order {
+            let Some((folder, position)) = self.entries[index].stream else {
+                f(index, &mut io::empty())?;
+                continue;
+            };
+            if self.folders[folder].is_encrypted() {
+                return Err(anyhow!("Encrypted 7z entries are not supported"));
+            }
+            if current.as_ref().is_none_or(|(open, _, _)| *open != folder) {
+                let reader = decoder(&self.file, &self.folders[folder])?;
+                current = Some((folder, 0, reader));
+            }
+            let (_, next, reader) = current.as_mut().unwrap();
+            let sizes = &self.folders[folder].file_sizes;
+            for &skipped in &sizes[*next..position] {
+                io::copy(&mut reader.take(skipped), &mut io::sink())
+                    .map_err(|e| anyhow!("Corrupt 7z data: {e}"))?;
+            }
+            let mut entry = reader.take(sizes[position]);
+            f(index, &mut entry)?;
+            // Drain whatever `f` did not read so the next file lines up.
+            io::copy(&mut entry, &mut io::sink()).map_err(|e| anyhow!("Corrupt 7z data: {e}"))?;
+            *next = position + 1;
+        }
+        Ok(())
+    }
+}
+
+/// A reader for the decoded contents of `folder`.
+fn decoder<'a>(mut file: &'a File, folder: &Folder) -> Result<Box<dyn Read + 'a>> {
+    file.seek(SeekFrom::Start(folder.pack_offset))
+        .map_err(|e| anyhow!("Failed to read 7z archive: {e}"))?;
+    if folder.is_encrypted() {
+        return Err(anyhow!("Encrypted 7z archives are not supported"));
+    }
+    let [coder] = folder.coders.as_slice() else {
+        return Err(anyhow!(
+            "Unsupported 7z archive: folders with {} coders (e.g. BCJ filters)",
+            folder.coders.len()
+        ));
+    };
+    let packed = BufReader::new(file.take(folder.pack_size));
+    let props = &coder.properties;
+    let reader: Box<dyn Read> = match coder.id.as_slice() {
+        COPY => Box::new(packed),
+        LZMA => {
+            if props.len() < 5 {
+                return Err(anyhow!("Invalid 7z archive: bad LZMA properties"));
+            }
+            let dict_size = u32::from_le_bytes(props[1..5].try_into().unwrap());
+            Box::new(
+                LzmaReader::new_with_props(packed, folder.unpack_size, props[0], dict_size, None)
+                    .map_err(|e| anyhow!("Invalid 7z archive: {e}"))?,
+            )
+        }
+        LZMA2 => {
+            let bits = *props
+                .first()
+                .ok_or_else(|| anyhow!("Invalid 7z archive: bad LZMA2 properties"))?;
+            let dict_size = if bits >= 40 {
+                u32::MAX
+            } else {
+                (2 | (bits as u32 & 1)) << (bits / 2 + 11)
+            };
+            Box::new(Lzma2Reader::new(packed, dict_size, None))
+        }
+        DEFLATE => Box::new(DeflateDecoder::new(packed)),
+        id => return Err(anyhow!("Unsupported 7z compression method {id:02x?}")),
+    };
+    Ok(Box::new(reader.take(folder.unpack_size)))
+}
+
+/// Pack, unpack and substream info, combined into one `Folder` per folder.
+fn read_streams_info(reader: &mut ByteReader) -> Result<Vec<Folder>> {
+    let mut pack_offset = 0;
+    let mut pack_sizes = Vec::new();
+    let mut folders: Vec<Folder> = Vec::new();
+    let mut files_per_folder: Option<Vec<u64>> = None;
+    let mut folder_digests = Vec::new();
+    loop {
+        match reader.number()? {
+            END => break,
+            PACK_INFO => {
+                pack_offset = 32 + reader.number()?;
+                let count = reader.count()?;
+                loop {
+                    match reader.number()? {
+                        END => break,
+                        SIZE => {
+                            pack_sizes =
+                                (0..count).map(|_| reader.number()).collect::<Result<_>>()?
+                        }
+                        CRC => {
+                            reader.digests(count)?;
+                        }
+                        id => return Err(anyhow!("Invalid 7z archive: unexpected {id:#x}")),
+                    }
+                }
+            }
+            UNPACK_INFO => {
+                if reader.number()? != FOLDER {
+                    return Err(anyhow!("Invalid 7z archive: missing folders"));
+                }
+                let count = reader.count()?;
+                if reader.byte()? != 0 {
+                    return Err(anyhow!("Invalid 7z archive: external folders"));
+                }
+                let mut out_streams = Vec::with_capacity(count);
+                for _ in 0..count {
+                    let (coders, outs, main) = read_folder(reader)?;
+                    out_streams.push((outs, main));
+                    folders.push(Folder {
+                        coders,
+                        pack_offset: 0,
+                        pack_size: 0,
+                        unpack_size: 0,
+                        file_sizes: Vec::new(),
+                    });
+                }
+                if reader.number()? != CODERS_UNPACK_SIZE {
+                    return Err(anyhow!("Invalid 7z archive: missing unpack sizes"));
+                }
+                for (folder, (outs, main)) in folders.iter_mut().zip(out_streams) {
+                    for i in 0..outs {
+                        let size = reader.number()?;
+                        if i == main {
+                            folder.unpack_size = size;
+                        }
+                    }
+                }
+                loop {
+                    match reader.number()? {
+                        END => break,
+                        CRC => folder_digests = reader.digests(count)?,
+                        id => return Err(anyhow!("Invalid 7z archive: unexpected {id:#x}")),
+                    }
+                }
+            }
+            SUBSTREAMS_INFO => {
+                let mut counts = vec![1; folders.len()];
+                let mut id = reader.number()?;
+                if id == NUM_UNPACK_STREAM {
+                    for count in &mut counts {
+                        *count = reader.number()?;
+                    }
+                    id = reader.number()?;
+                }
+                let explicit = id == SIZE;
+                for (folder, &count) in folders.iter_mut().zip(&counts) {
+                    folder.file_sizes.clear();
+                    if count == 0 {
+                        continue;
+                    }
+                    let mut sum = 0u64;
+                    if explicit {
+                        for _ in 1..count {
+                            let size = reader.number()?;
+                            sum = sum.saturating_add(size);
+                            folder.file_sizes.push(size);
+                        }
+                    }
+                    let last = folder
+                        .unpack_size
+                        .checked_sub(sum)
+                        .ok_or_else(|| anyhow!("Invalid 7z archive: file sizes exceed folder"))?;
+                    folder.file_sizes.push(last);
+                }
+                if explicit {
+                    id = reader.number()?;
+                }
+                while id != END {
+                    if id != CRC {
+                        return Err(anyhow!("Invalid 7z archive: unexpected {id:#x}"));
+                    }
+                    // Lone files in folders with a digest are not repeated.
+                    let unknown: u64 = counts
+                        .iter()
+                        .enumerate()
+                        .map(|(i, &count)| {
+                            let known = count == 1 && folder_digests.get(i) == Some(&true);
+                            if known {
+                                0
+                            } else {
+                                count
+                            }
+                        })
+                        .sum();
+                    reader.digests(unknown as usize)?;
+                    id = reader.number()?;
+                }
+                files_per_folder = Some(counts);
+            }
+            id => return Err(anyhow!("Invalid 7z archive: unexpected {id:#x}")),
+        }
+    }
+
+    let mut offset = pack_offset;
+    for (i, folder) in folders.iter_mut().enumerate() {
+        let size = *pack_sizes
+            .get(i)
+            .ok_or_else(|| anyhow!("Invalid 7z archive: missing pack sizes"))?;
+        folder.pack_offset = offset;
+        folder.pack_size = size;
+        offset += size;
+        if files_per_folder.is_none() {
+            folder.file_sizes = vec![folder.unpack_size];
+        }
+    }
+    Ok(folders)
+}
+
+/// A folder's coders, as method ID and properties, with its output count
+/// and which output holds the decoded data.
+fn read_folder(reader: &mut ByteReader) -> Result<(Vec<Coder>, u64, u64)> {
+    let count = reader.number()?;
+    let mut coders = Vec::new();
+    let mut total_in = 0;
+    let mut total_out = 0;
+    for _ in 0..count {
+        let flags = reader.byte()?;
+        let id = reader.take((flags & 0x0F) as u64)?.to_vec();
+        let (ins, outs) = if flags & 0x10 != 0 {
+            (reader.number()?, reader.number()?)
+        } else {
+            (1, 1)
+        };
+        total_in += ins;
+        total_out += outs;
+        let properties = if flags & 0x20 != 0 {
+            let size = reader.number()?;
+            reader.take(size)?.to_vec()
+        } else {
+            Vec::new()
+        };
+        coders.push(Coder { id, properties });
+    }
+    // Outputs fed into another coder; the one left over is the result.
+    let mut bound = Vec::new();
+    for _ in 1..total_out {
+        reader.number()?;
+        bound.push(reader.number()?);
+    }
+    let packed = total_in.saturating_sub(total_out.saturating_sub(1));
+    if packed > 1 {
+        for _ in 0..packed {
+            reader.number()?;
+        }
+    }
+    let main = (0..total_out).find(|i| !bound.contains(i)).unwrap_or(0);
+    Ok((coders, total_out, main))
+}
+
+fn read_files_info(reader: &mut ByteReader, folders: &[Folder]) -> Result<Vec<Entry>> {
+    let count = reader.count()?;
+    let mut empty_stream = vec![false; count];
+    let mut empty_file = Vec::new();
+    let mut names = Vec::new();
+    loop {
+        let id = reader.number()?;
+        if id == END {
+            break;
+        }
+        let size = reader.number()?;
+        let data = reader.take(size)?;
+        let mut property = ByteReader::new(data);
+        match id {
+            EMPTY_STREAM => empty_stream = property.bits(count)?,
+            EMPTY_FILE => {
+                let empties = empty_stream.iter().filter(|&&empty| empty).count();
+                empty_file = property.bits(empties)?;
+            }
+            NAME => {
+                if property.byte()? != 0 {
+                    return Err(anyhow!("Invalid 7z archive: external names"));
+                }
+                let units: Vec<u16> = data[1..]
+                    .chunks_exact(2)
+                    .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+                    .collect();
+                names = units
+                    .split(|&unit| unit == 0)
+                    .take(count)
+                    .map(String::from_utf16_lossy)
+                    .collect();
+            }
+            _ => {}
+        }
+    }
+
+    let mut streams = folders
+        .iter()
+        .enumerate()
+        .flat_map(|(folder, f)| (0..f.file_sizes.len()).map(move |position| (folder, position)));
+    let mut empty_index = 0;
+    let mut entries = Vec::with_capacity(count);
+    for (i, empty) in empty_stream.into_iter().enumerate() {
+        let name = names.get(i).cloned().unwrap_or_default().replace('\\', "/");
+        if empty {
+            let is_file = empty_file.get(empty_index).copied().unwrap_or(false);
+            empty_index += 1;
+            entries.push(Entry {
+                name,
+                size: 0,
+                is_dir: !is_file,
+                stream: None,
+            });
+        } else {
+            let (folder, position) = streams
+                .next()
+                .ok_or_else(|| anyhow!("Invalid 7z archive: more files than streams"))?;
+            entries.push(Entry {
+                name,
+                size: folders[folder].file_sizes[position],
+                is_dir: false,
+                stream: Some((folder, position)),
+            });
+        }
+    }
+    Ok(entries)
+}
+
+/// Cursor over header bytes.
+struct ByteReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn byte(&mut self) -> Result<u8> {
+        let byte = *self
+            .data
+            .get(self.pos)
+            .ok_or_else(|| anyhow!("Invalid 7z archive: truncated header"))?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn take(&mut self, len: u64) -> Result<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(len as usize)
+            .filter(|&end| end <= self.data.len())
+            .ok_or_else(|| anyhow!("Invalid 7z archive: truncated header"))?;
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    /// A 7z variable-length number: leading one bits in the first byte
+    /// count the extra little-endian bytes.
+    fn number(&mut self) -> Result<u64> {
+        let first = self.byte()?;
+        let mut value = 0u64;
+        for i in 0..8 {
+            let mask = 0x80u8 >> i;
+            if first & mask == 0 {
+                let high = (first & mask.wrapping_sub(1)) as u64;
+                return Ok(value | high << (8 * i));
+            }
+            value |= (self.byte()? as u64) << (8 * i);
+        }
+        Ok(value)
+    }
+
+    /// A number used as an item count, bounded by the header size.
+    fn count(&mut self) -> Result<usize> {
+        let count = self.number()?;
+        if count > self.data.len() as u64 * 8 {
+            return Err(anyhow!("Invalid 7z archive: bad item count"));
+        }
+        Ok(count as usize)
+    }
+
+    /// A bit field of `count` flags, most significant bit first.
+    fn bits(&mut self, count: usize) -> Result<Vec<bool>> {
+        let bytes = self.take(count.div_ceil(8) as u64)?;
+        Ok((0..count)
+            .map(|i| bytes[i / 8] & (0x80 >> (i % 8)) != 0)
+            .collect())
+    }
+
+    /// CRC digests of `count` items, which are skipped; returns which
+    /// items had one.
+    fn digests(&mut self, count: usize) -> Result<Vec<bool>> {
+        let defined = if self.byte()? != 0 {
+            vec![true; count]
+        } else {
+            self.bits(count)?
+        };
+        let present = defined.iter().filter(|&&bit| bit).count();
+        self.take(present as u64 * 4)?;
+        Ok(defined)
+    }
+}
+
+/// Build small archives for tests.
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::test_dirs::temp_dir;
+    use lzma_rust2::{Lzma2Options, Lzma2Writer};
+    use std::io::Write;
+
+    fn number(out: &mut Vec<u8>, value: u64) {
+        // Values below 0x80 fit in one byte; larger ones use the two-byte
+        // form, which is enough for test archives.
+        if value < 0x80 {
+            out.push(value as u8);
+        } else {
+            assert!(value < 0x4000);
+            out.push(0x80 | (value >> 8) as u8);
+            out.push(value as u8);
+        }
+    }
+
+    /// A solid LZMA2 archive holding the files in one folder, plus empty
+    /// entries for names ending in `/`, with an encoded header.
+    pub(crate) fn build(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let stored: Vec<&(&str, &[u8])> = files.iter().filter(|f| !f.0.ends_with('/')).collect();
+        let contents: Vec<u8> = stored.iter().flat_map(|f| f.1.to_vec()).collect();
+        let mut writer = Lzma2Writer::new(Vec::new(), Lzma2Options::with_preset(1));
+        writer.write_all(&contents).unwrap();
+        let packed = writer.finish().unwrap();
+
+        let mut header = vec![HEADER as u8, MAIN_STREAMS_INFO as u8, PACK_INFO as u8, 0, 1];
+        header.push(SIZE as u8);
+        number(&mut header, packed.len() as u64);
+        header.extend_from_slice(&[END as u8, UNPACK_INFO as u8, FOLDER as u8, 1, 0]);
+        // One simple coder with a one-byte property: a 16 MiB dictionary.
+        header.extend_from_slice(&[1, 0x21, 0x21, 1, 24, CODERS_UNPACK_SIZE as u8]);
+        number(&mut header, contents.len() as u64);
+        header.extend_from_slice(&[END as u8, SUBSTREAMS_INFO as u8, NUM_UNPACK_STREAM as u8]);
+        number(&mut header, stored.len() as u64);
+        header.push(SIZE as u8);
+        for file in &stored[..stored.len() - 1] {
+            number(&mut header, file.1.len() as u64);
+        }
+        header.extend_from_slice(&[END as u8, END as u8, FILES_INFO as u8]);
+        number(&mut header, files.len() as u64);
+        let empties: Vec<bool> = files.iter().map(|f| f.0.ends_with('/')).collect();
+        let mut bits = vec![0u8; files.len().div_ceil(8)];
+        for (i, &empty) in empties.iter().enumerate() {
+            if empty {
+                bits[i / 8] |= 0x80 >> (i % 8);
+            }
+        }
+        header.push(EMPTY_STREAM as u8);
+        number(&mut header, bits.len() as u64);
+        header.extend_from_slice(&bits);
+        let mut names = vec![0];
+        for (name, _) in files {
+            let name = name.trim_end_matches('/');
+            names.extend(name.encode_utf16().chain([0]).flat_map(u16::to_le_bytes));
+        }
+        header.push(NAME as u8);
+        number(&mut header, names.len() as u64);
+        header.extend_from_slice(&names);
+        header.extend_from_slice(&[END as u8, END as u8]);
+
+        // Store the header itself in a second, uncompressed folder.
+        let mut encoded = vec![ENCODED_HEADER as u8, PACK_INFO as u8];
+        number(&mut encoded, packed.len() as u64);
+        encoded.extend_from_slice(&[1, SIZE as u8]);
+        number(&mut encoded, header.len() as u64);
+        encoded.extend_from_slice(&[END as u8, UNPACK_INFO as u8, FOLDER as u8, 1, 0]);
+        encoded.extend_from_slice(&[1, 0x01, 0x00, CODERS_UNPACK_SIZE as u8]);
+        number(&mut encoded, header.len() as u64);
+        encoded.extend_from_slice(&[END as u8, END as u8]);
+
+        let mut out = SIGNATURE.to_vec();
+        out.extend_from_slice(&[0, 4, 0, 0, 0, 0]);
+        out.extend_from_slice(&((packed.len() + header.len()) as u64).to_le_bytes());
+        out.extend_from_slice(&(encoded.len() as u64).to_le_bytes());
+        out.extend_from_slice(&[0; 4]);
+        out.extend_from_slice(&packed);
+        out.extend_from_slice(&header);
+        out.extend_from_slice(&encoded);
+        out
+    }
+
+    #[test]
+    fn test_reads_solid_archive() {
+        let dir = temp_dir("sevenz");
+        let path = dir.join("solid.7z");
+        let long = "第一章 开始\n".repeat(500);
+        let files: [(&str, &[u8]); 4] = [
+            ("books/", b""),
+            ("books/a.txt", long.as_bytes()),
+            ("books\\b.epub", b"PK epub"),
+            ("c.txt", b"third"),
+        ];
+        std::fs::write(&path, build(&files)).unwrap();
+        let archive = SevenZip::open(&path).unwrap();
+        let names: Vec<_> = archive.entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["books", "books/a.txt", "books/b.epub", "c.txt"]);
+        assert!(archive.entries[0].is_dir);
+        assert_eq!(archive.entries[1].size, long.len() as u64);
+
+        // Skipping the first file in the solid folder still lines up.
+        let mut read = Vec::new();
+        archive
+            .extract(&[3, 2], |index, reader| {
+                let mut data = Vec::new();
+                reader.read_to_end(&mut data)?;
+                read.push((index, data));
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(read, [(2, b"PK epub".to_vec()), (3, b"third".to_vec())]);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_number_encoding() {
+        let mut reader = ByteReader::new(&[0x7F, 0x81, 0x02, 0xC0, 0x34, 0x12]);
+        assert_eq!(reader.number().unwrap(), 0x7F);
+        assert_eq!(reader.number().unwrap(), 0x102);
+        assert_eq!(reader.number().unwrap(), 0x1234);
+        assert!(reader.number().is_err());
+    }
+}
@@ -0,0 +1,353 @@
+//! Minimal SFNT (TrueType/OpenType) container helpers.
+//!
+//! Only the table directory is handled here; table contents are treated as
+//! opaque byte blobs so callers can rewrite individual tables without caring
+//! about the rest of the font.
+
+use std::ops::Range;
+
+use anyhow::{anyhow, Result};
+
+/// `0x00010000`, the sfnt version used by TrueType-flavored fonts.
+pub(crate) const TRUETYPE_FLAVOR: u32 = 0x0001_0000;
+
+/// Read a big-endian `u16` at `offset`.
+pub(crate) fn read_u16(data: &[u8], offset: usize) -> Result<u16> {
+    data.get(offset..offset + 2)
+        .map(|b| u16::from_be_bytes([b[0], b[1]]))
+        .ok_or_else(|| anyhow!("Unexpected end of data at offset {offset}"))
+}
+
+/// Read a big-endian `u32` at `offset`.
+pub(crate) fn read_u32(data: &[u8], offset: usize) -> Result<u32> {
+    data.get(offset..offset + 4)
+        .map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+        .ok_or_else(|| anyhow!("Unexpected end of data at offset {offset}"))
+}
+
+/// Append a big-endian `u16`.
+pub(crate) fn put_u16(out: &mut Vec<u8>, value: u16) {
+    out.extend_from_slice(&value.to_be_bytes());
+}
+
+/// Append a big-endian `i16`.
+pub(crate) fn put_i16(out: &mut Vec<u8>, value: i16) {
+    out.extend_from_slice(&value.to_be_bytes());
+}
+
+/// Append a big-endian `u32`.
+pub(crate) fn put_u32(out: &mut Vec<u8>, value: u32) {
+    out.extend_from_slice(&value.to_be_bytes());
+}
+
+/// Standard OpenType table checksum: the wrapping sum of big-endian `u32`s,
+/// with the final partial word zero-padded.
+pub(crate) fn checksum(data: &[u8]) -> u32 {
+    let mut sum = 0u32;
+    let mut chunks = data.chunks_exact(4);
+    for chunk in &mut chunks {
+        sum = sum.wrapping_add(u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]));
+    }
+    let rest = chunks.remainder();
+    if !rest.is_empty() {
+        let mut word = [0u8; 4];
+        word[..rest.len()].copy_from_slice(rest);
+        sum = sum.wrapping_add(u32::from_be_bytes(word));
+    }
+    sum
+}
+
+/// A single table to be written into an SFNT container.
+pub(crate) struct Table {
+    pub tag: [u8; 4],
+    pub data: Vec<u8>,
+}
+
+/// An SFNT font split into its individual tables.
+pub(crate) struct Font {
+    pub flavor: u32,
+    pub tables: Vec<Table>,
+}
+
+impl Font {
+    /// Return the contents of the table with the given tag.
+    pub fn table(&self, tag: &[u8; 4]) -> Option<&[u8]> {
+        self.tables
+            .iter()
+            .find(|table| &table.tag == tag)
+            .map(|table| table.data.as_slice())
+    }
+
+    /// Replace (or insert) the table with the given tag.
+    pub fn set_table(&mut self, tag: [u8; 4], data: Vec<u8>) {
+        match self.tables.iter_mut().find(|table| table.tag == tag) {
+            Some(table) => table.data = data,
+            None => self.tables.push(Table { tag, data }),
+        }
+    }
+
+    /// Remove the table with the given tag, if present.
+    pub fn remove_table(&mut self, tag: &[u8; 4]) {
+        self.tables.retain(|table| &table.tag != tag);
+    }
+
+    /// Serialize back into a standalone SFNT font.
+    pub fn build(self) -> Vec<u8> {
+        build(self.flavor, self.tables)
+    }
+}
+
+/// Split a standalone SFNT font into its tables.
+pub(crate) fn parse(data: &[u8]) -> Result<Font> {
+    parse_at(data, 0)
+}
+
+/// Split the SFNT font whose offset table starts at `offset`.
+///
+/// Table offsets are always relative to the start of `data`, which is what
+/// makes this usable for faces inside a TrueType Collection.
+pub(crate) fn parse_at(data: &[u8], offset: usize) -> Result<Font> {
+    let flavor = read_u32(data, offset)?;
+    let num_tables = read_u16(data, offset + 4)? as usize;
+    let mut tables = Vec::with_capacity(num_tables);
+
+    for index in 0..num_tables {
+        let record = offset + 12 + index * 16;
+        let tag = data
+            .get(record..record + 4)
+            .ok_or_else(|| anyhow!("Truncated table directory"))?;
+        let table_offset = read_u32(data, record + 8)? as usize;
+        let length = read_u32(data, record + 12)? as usize;
+        let table = table_offset
+            .checked_add(length)
+            .and_then(|end| data.get(table_offset..end))
+            .ok_or_else(|| anyhow!("Table out of bounds"))?;
+
+        tables.push(Table {
+            tag: [tag[0], tag[1], tag[2], tag[3]],
+            data: table.to_vec(),
+        });
+    }
+
+    Ok(Font { flavor, tables })
+}
+
+/// Serialize `tables` into a standalone SFNT font.
+///
+/// Tables are sorted by tag and padded to 4-byte boundaries as the spec
+/// requires. Table checksums are always recomputed; `head.checkSumAdjustment`
+/// is fixed up when a `head` table is present.
+pub(crate) fn build(flavor: u32, mut tables: Vec<Table>) -> Vec<u8> {
+    tables.sort_by_key(|table| table.tag);
+
+    let num_tables = tables.len() as u16;
+    let mut entry_selector = 0u16;
+    while num_tables > 0 && (1u16 << (entry_selector + 1)) <= num_tables {
+        entry_selector += 1;
+    }
+    let search_range = if num_tables == 0 {
+        0
+    } else {
+        (1u16 << entry_selector) * 16
+    };
+    let range_shift = num_tables * 16 - search_range;
+
+    let header_len = 12 + tables.len() * 16;
+    let body_len: usize = tables.iter().map(|t| padded_len(t.data.len())).sum();
+    let mut out = Vec::with_capacity(header_len + body_len);
+
+    out.extend_from_slice(&flavor.to_be_bytes());
+    out.extend_from_slice(&num_tables.to_be_bytes());
+    out.extend_from_slice(&search_range.to_be_bytes());
+    out.extend_from_slice(&entry_selector.to_be_bytes());
+    out.extend_from_slice(&range_shift.to_be_bytes());
+
+    let mut head_offset = None;
+    let mut offset = header_len;
+    for table in &mut tables {
+        if &table.tag == b"head" && table.data.len() >= 12 {
+            // checkSumAdjustment must be zero while checksums are computed.
+            table.data[8..12].fill(0);
+            head_offset = Some(offset);
+        }
+        out.extend_from_slice(&table.tag);
+        out.extend_from_slice(&checksum(&table.data).to_be_bytes());
+        out.extend_from_slice(&(offset as u32).to_be_bytes());
+        out.extend_from_slice(&(table.data.len() as u32).to_be_bytes());
+        offset += padded_len(table.data.len());
+    }
+
+    for table in &tables {
+        out.extend_from_slice(&table.data);
+        out.resize(padded_len(out.len()), 0);
+    }
+
+    if let Some(head_offset) = head_offset {
+        let adjustment = 0xB1B0_AFBAu32.wrapping_sub(checksum(&out));
+        out[head_offset + 8..head_offset + 12].copy_from_slice(&adjustment.to_be_bytes());
+    }
+
+    out
+}
+
+/// Look up a Unicode `name` table entry, preferring US English records.
+pub(crate) fn name_string(face: &ttf_parser::Face, name_id: u16) -> Option<String> {
+    let mut fallback = None;
+    for name in face.names() {
+        if name.name_id != name_id || !name.is_unicode() {
+            continue;
+        }
+        let Some(value) = name.to_string() else {
+            continue;
+        };
+        if name.language_id == 0x0409 {
+            return Some(value);
+        }
+        fallback.get_or_insert(value);
+    }
+    fallback
+}
+
+/// `maxp.numGlyphs`.
+pub(crate) fn num_glyphs(font: &Font) -> Result<u16> {
+    let maxp = font
+        .table(b"maxp")
+        .ok_or_else(|| anyhow!("Missing maxp table"))?;
+    read_u16(maxp, 4)
+}
+
+/// Byte ranges of every glyph inside `glyf`, decoded from `loca`.
+pub(crate) fn glyph_locations(font: &Font) -> Result<Vec<Range<usize>>> {
+    let head = font
+        .table(b"head")
+        .ok_or_else(|| anyhow!("Missing head table"))?;
+    let loca = font
+        .table(b"loca")
+        .ok_or_else(|| anyhow!("Missing loca table"))?;
+    let glyf_len = font.table(b"glyf").map_or(0, <[u8]>::len);
+    let long_format = read_u16(head, 50)? != 0;
+    let num_glyphs = num_glyphs(font)? as usize;
+
+    let offset_at = |index: usize| -> Result<usize> {
+        if long_format {
+            Ok(read_u32(loca, index * 4)? as usize)
+        } else {
+            Ok(read_u16(loca, index * 2)? as usize * 2)
+        }
+    };
+
+    let mut locations = Vec::with_capacity(num_glyphs);
+    let mut start = offset_at(0)?;
+    for index in 0..num_glyphs {
+        let end = offset_at(index + 1)?;
+        if start > end || end > glyf_len {
+            return Err(anyhow!("Invalid loca entry for glyph {index}"));
+        }
+        locations.push(start..end);
+        start = end;
+    }
+    Ok(locations)
+}
+
+/// Glyph IDs referenced by a composite glyph; empty for simple glyphs.
+pub(crate) fn composite_components(glyph: &[u8]) -> Result<Vec<u16>> {
+    const ARG_1_AND_2_ARE_WORDS: u16 = 0x0001;
+    const WE_HAVE_A_SCALE: u16 = 0x0008;
+    const MORE_COMPONENTS: u16 = 0x0020;
+    const WE_HAVE_AN_X_AND_Y_SCALE: u16 = 0x0040;
+    const WE_HAVE_A_TWO_BY_TWO: u16 = 0x0080;
+
+    if glyph.len() < 10 || read_u16(glyph, 0)? as i16 >= 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut components = Vec::new();
+    let mut offset = 10;
+    loop {
+        let flags = read_u16(glyph, offset)?;
+        components.push(read_u16(glyph, offset + 2)?);
+        offset += 4;
+        offset += if flags & ARG_1_AND_2_ARE_WORDS != 0 {
+            4
+        } else {
+            2
+        };
+        if flags & WE_HAVE_A_SCALE != 0 {
+            offset += 2;
+        } else if flags & WE_HAVE_AN_X_AND_Y_SCALE != 0 {
+            offset += 4;
+        } else if flags & WE_HAVE_A_TWO_BY_TWO != 0 {
+            offset += 8;
+        }
+        if flags & MORE_COMPONENTS == 0 {
+            break;
+        }
+    }
+    Ok(components)
+}
+
+fn padded_len(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksum_pads_trailing_bytes() {
+        assert_eq!(checksum(&[0, 0, 0, 1, 0x80]), 1 + 0x8000_0000);
+    }
+
+    #[test]
+    fn test_parse_roundtrips_build() {
+        let font = build(
+            TRUETYPE_FLAVOR,
+            vec![Table {
+                tag: *b"test",
+                data: vec![1, 2, 3],
+            }],
+        );
+        let parsed = parse(&font).unwrap();
+        assert_eq!(parsed.flavor, TRUETYPE_FLAVOR);
+        assert_eq!(parsed.table(b"test"), Some(&[1u8, 2, 3][..]));
+        assert!(parsed.table(b"none").is_none());
+    }
+
+    #[test]
+    fn test_parse_rejects_truncated_table() {
+        let mut font = build(
+            TRUETYPE_FLAVOR,
+            vec![Table {
+                tag: *b"test",
+                data: vec![1, 2, 3],
+            }],
+        );
+        font.truncate(font.len() - 4);
+        assert!(parse(&font).is_err());
+    }
+
+    #[test]
+    fn test_build_sorts_and_aligns_tables() {
+        let font = build(
+            TRUETYPE_FLAVOR,
+            vec![
+                Table {
+                    tag: *b"zzzz",
+                    data: vec![1, 2, 3],
+                },
+                Table {
+                    tag: *b"aaaa",
+                    data: vec![4; 5],
+                },
+            ],
+        );
+
+        assert_eq!(read_u16(&font, 4).unwrap(), 2);
+        assert_eq!(&font[12..16], b"aaaa");
+        assert_eq!(read_u32(&font, 12 + 8).unwrap(), 44);
+        assert_eq!(&font[28..32], b"zzzz");
+        assert_eq!(read_u32(&font, 28 + 8).unwrap(), 52);
+        assert_eq!(font.len(), 56);
+    }
+}
@@ -0,0 +1,1191 @@
+//! Rule evaluation for Legado (阅读) book sources.
+//!
+//! A rule picks values out of a page, and its prefix selects the syntax:
+//! `@css:` for CSS selectors, `@XPath:` or a leading `/` for XPath, `@json:`
+//! or a leading `$.` for JSONPath, `:` for an all-in-one regex whose groups
+//! later rules refer to as `$1`, and otherwise Legado's default
+//! `class.item.0@tag.a@href` chains. Rules on a JSON page default to
+//! JSONPath. Rules can be joined with `&&` (concatenate), `||` (first
+//! non-empty) or `%%` (interleave), end with `##regex##replacement`, and
+//! contain `{{...}}` templates and `@put:{...}`/`@get:{...}` variables.
+//! `<js>...</js>` blocks and a trailing `@js:` script run in the sandbox
+//! from `js`, each transforming the previous step's `result`; `{{...}}`
+//! templates that are not rules or variables are JavaScript too.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use anyhow::{anyhow, Result};
+use ego_tree::NodeId;
+use regex::Regex;
+use scraper::{ElementRef, Html, Selector};
+use serde_json::Value;
+use serde_json_path::JsonPath;
+
+use crate::js::{self, Fetch, Scope};
+use crate::xpath::{self, Item};
+
+/// Something a rule can be evaluated on.
+#[derive(Clone)]
+pub(crate) enum Node {
+    /// An element of a parsed page, or the page itself.
+    Element(Rc<Html>, NodeId),
+    Json(Value),
+    Text(String),
+    /// The groups of an all-in-one regex match.
+    Captures(Vec<String>),
+}
+
+impl Node {
+    /// Parse a response body as JSON if it looks like JSON, else as HTML.
+    pub(crate) fn parse(body: &str) -> Node {
+        let trimmed = body.trim_start();
+        if trimmed.starts_with('{') || trimmed.starts_with('[') {
+            if let Ok(value) = serde_json::from_str(trimmed) {
+                return Node::Json(value);
+            }
+        }
+        let (html, root) = parse_html(body);
+        Node::Element(html, root)
+    }
+
+    /// The node's source text, as regex rules and re-parsing see it.
+    fn source(&self) -> String {
+        match self {
+            Node::Element(html, id) => {
+                if *id == html.tree.root().id() {
+                    html.root_element().html()
+                } else {
+                    element(html, *id).html()
+                }
+            }
+            Node::Json(Value::String(text)) | Node::Text(text) => text.clone(),
+            Node::Json(value) => value.to_string(),
+            Node::Captures(groups) => groups.first().cloned().unwrap_or_default(),
+        }
+    }
+
+    fn html(&self) -> (Rc<Html>, NodeId) {
+        match self {
+            Node::Element(html, id) => (Rc::clone(html), *id),
+            _ => parse_html(&self.source()),
+        }
+    }
+
+    fn json(&self) -> Option<Value> {
+        match self {
+            Node::Json(value) => Some(value.clone()),
+            Node::Element(html, id) => {
+                let text: String = html
+                    .tree
+                    .get(*id)?
+                    .descendants()
+                    .filter_map(text_of)
+                    .collect();
+                serde_json::from_str(text.trim()).ok()
+            }
+            _ => serde_json::from_str(self.source().trim()).ok(),
+        }
+    }
+}
+
+fn parse_html(source: &str) -> (Rc<Html>, NodeId) {
+    let html = Html::parse_document(source);
+    let root = html.tree.root().id();
+    (Rc::new(html), root)
+}
+
+fn text_of(node: ego_tree::NodeRef<scraper::Node>) -> Option<String> {
+    node.value().as_text().map(|text| text.to_string())
+}
+
+/// The element for `id`, with the document itself standing for its root
+/// element.
+fn element(html: &Html, id: NodeId) -> ElementRef<'_> {
+    html.tree
+        .get(id)
+        .and_then(ElementRef::wrap)
+        .unwrap_or_else(|| html.root_element())
+}
+
+enum Mode<'r> {
+    Css(&'r str),
+    Default(&'r str),
+    XPath(&'r str),
+    Json(String),
+    Regex(&'r str),
+}
+
+/// One step of a rule with scripts in it.
+#[derive(Debug, PartialEq)]
+enum Step<'r> {
+    Rule(&'r str),
+    Script(&'r str),
+}
+
+/// Evaluates rules, keeping the variables set by `@put` and by the caller
+/// (such as `key` and `page`) for the rules that follow.
+pub(crate) struct Analyzer {
+    variables: Rc<RefCell<HashMap<String, String>>>,
+    base_url: RefCell<String>,
+    fetch: Option<Fetch>,
+}
+
+impl Analyzer {
+    pub(crate) fn new() -> Self {
+        Self {
+            variables: Rc::new(RefCell::new(HashMap::new())),
+            base_url: RefCell::new(String::new()),
+            fetch: None,
+        }
+    }
+
+    /// Let scripts make HTTP requests with `java.ajax` and `java.post`.
+    pub(crate) fn set_fetch(&mut self, fetch: Fetch) {
+        self.fetch = Some(fetch);
+    }
+
+    /// Set the page URL scripts see as `baseUrl`.
+    pub(crate) fn set_base_url(&self, url: &str) {
+        *self.base_url.borrow_mut() = url.to_string();
+    }
+
+    pub(crate) fn put(&self, name: &str, value: String) {
+        self.variables.borrow_mut().insert(name.to_string(), value);
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<String> {
+        self.variables.borrow().get(name).cloned()
+    }
+
+    /// Select the nodes of a list rule such as `bookList`. A leading `-`
+    /// reverses the list.
+    pub(crate) fn elements(&self, node: &Node, rule: &str) -> Result<Vec<Node>> {
+        let rule = rule.trim();
+        let (rule, reverse) = match rule.strip_prefix('-') {
+            Some(rule) => (rule, true),
+            None => (rule.strip_prefix('+').unwrap_or(rule), false),
+        };
+        if rule.is_empty() {
+            return Ok(Vec::new());
+        }
+        let rule = self.prepare(node, rule)?;
+        let mut nodes = match steps(&rule) {
+            Some(steps) => {
+                let (last, steps) = steps.split_last().unwrap();
+                let result = self.run(node, steps)?;
+                match last {
+                    Step::Rule(rule) => {
+                        let node = value_node(result);
+                        combine(rule, |part| self.part_elements(&node, part))?
+                    }
+                    Step::Script(code) => match self.script(code, result)? {
+                        Value::Null => Vec::new(),
+                        Value::Array(items) => items.into_iter().map(value_node).collect(),
+                        value => vec![value_node(value)],
+                    },
+                }
+            }
+            None => combine(&rule, |part| self.part_elements(node, part))?,
+        };
+        if reverse {
+            nodes.reverse();
+        }
+        Ok(nodes)
+    }
+
+    /// Evaluate a rule to one string, joining multiple results with
+    /// newlines.
+    pub(crate) fn string(&self, node: &Node, rule: &str) -> Result<String> {
+        Ok(self.strings(node, rule)?.join("\n"))
+    }
+
+    /// Evaluate a rule to its non-empty results.
+    pub(crate) fn strings(&self, node: &Node, rule: &str) -> Result<Vec<String>> {
+        let rule = rule.trim();
+        if rule.is_empty() {
+            return Ok(Vec::new());
+        }
+        let rule = self.prepare(node, rule)?;
+        if let Some(steps) = steps(&rule) {
+            let result = self.run(node, &steps)?;
+            return Ok(json_strings(&result)
+                .into_iter()
+                .filter(|value| !value.is_empty())
+                .collect());
+        }
+        if rule.contains("{{") || (matches!(node, Node::Json(_)) && rule.contains("{$.")) {
+            let value = self.template(node, &rule)?;
+            return Ok(if value.is_empty() {
+                Vec::new()
+            } else {
+                vec![value]
+            });
+        }
+        if let Node::Captures(groups) = node {
+            if group_reference().is_match(&rule) {
+                let value = group_reference()
+                    .replace_all(&rule, |c: &regex::Captures| {
+                        let index: usize = c[1].parse().unwrap_or(0);
+                        groups.get(index).cloned().unwrap_or_default()
+                    })
+                    .into_owned();
+                return Ok(vec![value]);
+            }
+        }
+        combine(&rule, |part| self.part_strings(node, part))
+    }
+
+    /// Apply a `##regex##replacement` rule to `text`.
+    pub(crate) fn replace(&self, text: &str, rule: &str) -> Result<String> {
+        if rule.trim().is_empty() {
+            return Ok(text.to_string());
+        }
+        // Only the start is trimmed; a replacement may end in spaces.
+        let rule = rule.trim_start();
+        let rule = rule.strip_prefix("##").unwrap_or(rule);
+        replace(text, rule)
+    }
+
+    /// Run `@put:{...}` assignments and substitute `@get:{...}` values.
+    fn prepare(&self, node: &Node, rule: &str) -> Result<String> {
+        let mut rule = rule.to_string();
+        while let Some(start) = rule.find("@put:{") {
+            let body_start = start + "@put:{".len();
+            let Some(length) = closing_brace(&rule[body_start..]) else {
+                return Err(anyhow!("Unterminated @put in rule {rule}"));
+            };
+            let body = rule[body_start..body_start + length].to_string();
+            for (name, value_rule) in assignments(&body) {
+                let value = self.string(node, &value_rule)?;
+                self.put(&name, value);
+            }
+            rule.replace_range(start..body_start + length + 1, "");
+        }
+        if rule.contains("@get:{") {
+            let pattern = Regex::new(r"@get:\{([^}]*)\}").unwrap();
+            rule = pattern
+                .replace_all(&rule, |c: &regex::Captures| {
+                    self.get(c[1].trim()).unwrap_or_default()
+                })
+                .into_owned();
+        }
+        Ok(rule.trim().to_string())
+    }
+
+    /// Expand a URL template's `{{...}}` parts, or run it if it is a script.
+    pub(crate) fn template(&self, node: &Node, rule: &str) -> Result<String> {
+        if let Some(steps) = steps(rule) {
+            return Ok(json_strings(&self.run(node, &steps)?).join("\n"));
+        }
+        let mut output = String::new();
+        let mut rest = rule;
+        while let Some(start) = rest.find("{{") {
+            output.push_str(&rest[..start]);
+            let inner = &rest[start + 2..];
+            let Some(end) = inner.find("}}") else {
+                output.push_str(&rest[start..]);
+                rest = "";
+                break;
+            };
+            let expression = inner[..end].trim();
+            match self.get(expression) {
+                Some(value) => output.push_str(&value),
+                None if is_rule(expression) => output.push_str(&self.string(node, expression)?),
+                None => output.push_str(
+                    &json_strings(&self.script(expression, node_value(node))?).join("\n"),
+                ),
+            }
+            rest = &inner[end + 2..];
+        }
+        output.push_str(rest);
+
+        if matches!(node, Node::Json(_)) && output.contains("{$.") {
+            let pattern = Regex::new(r"\{(\$\.[^{}]+)\}").unwrap();
+            let mut error = None;
+            output = pattern
+                .replace_all(&output, |c: &regex::Captures| {
+                    self.string(node, &c[1]).unwrap_or_else(|e| {
+                        error = Some(e);
+                        String::new()
+                    })
+                })
+                .into_owned();
+            if let Some(error) = error {
+                return Err(error);
+            }
+        }
+        Ok(output)
+    }
+
+    /// Run the steps of a scripted rule, each on the previous one's result.
+    fn run(&self, node: &Node, steps: &[Step]) -> Result<Value> {
+        let mut result = node_value(node);
+        for (i, step) in steps.iter().enumerate() {
+            result = match step {
+                Step::Rule(rule) => {
+                    let values = if i == 0 {
+                        self.strings(node, rule)?
+                    } else {
+                        self.strings(&value_node(result), rule)?
+                    };
+                    match <[String; 1]>::try_from(values) {
+                        Ok([value]) => Value::String(value),
+                        Err(values) => {
+                            Value::Array(values.into_iter().map(Value::String).collect())
+                        }
+                    }
+                }
+                Step::Script(code) => self.script(code, result)?,
+            };
+        }
+        Ok(result)
+    }
+
+    fn script(&self, code: &str, result: Value) -> Result<Value> {
+        js::eval(
+            code,
+            &Scope {
+                result,
+                base_url: self.base_url.borrow().clone(),
+                variables: Rc::clone(&self.variables),
+                fetch: self.fetch.clone(),
+            },
+        )
+    }
+
+    fn part_strings(&self, node: &Node, part: &str) -> Result<Vec<String>> {
+        let (rule, replacement) = match part.split_once("##") {
+            Some((rule, replacement)) => (rule.trim(), Some(replacement)),
+            None => (part.trim(), None),
+        };
+        let values = if rule.is_empty() {
+            vec![node.source()]
+        } else {
+            match mode(node, rule)? {
+                Mode::Css(rule) => css_strings(node, rule)?,
+                Mode::Default(rule) => default_strings(node, rule)?,
+                Mode::XPath(rule) => {
+                    let (html, id) = node.html();
+                    let root = html.tree.get(id).unwrap();
+                    xpath::select(root, rule)?
+                        .iter()
+                        .map(|item| match item {
+                            Item::Node(node) => match ElementRef::wrap(*node) {
+                                Some(element) => element.html(),
+                                None => item.string_value().trim().to_string(),
+                            },
+                            _ => item.string_value(),
+                        })
+                        .collect()
+                }
+                Mode::Json(path) => match node.json() {
+                    Some(value) => json_path(&path)?
+                        .query(&value)
+                        .all()
+                        .into_iter()
+                        .flat_map(json_strings)
+                        .collect(),
+                    None => Vec::new(),
+                },
+                Mode::Regex(rule) => regex(rule)?
+                    .find_iter(&node.source())
+                    .map(|m| m.as_str().to_string())
+                    .collect(),
+            }
+        };
+        let values = match replacement {
+            Some(replacement) => values
+                .iter()
+                .map(|value| replace(value, replacement))
+                .collect::<Result<Vec<_>>>()?,
+            None => values,
+        };
+        Ok(values.into_iter().filter(|v| !v.is_empty()).collect())
+    }
+
+    fn part_elements(&self, node: &Node, part: &str) -> Result<Vec<Node>> {
+        let part = part.trim();
+        Ok(match mode(node, part)? {
+            Mode::Css(rule) => {
+                let (html, id) = node.html();
+                let selector = selector(rule)?;
+                let ids: Vec<NodeId> = element(&html, id)
+                    .select(&selector)
+                    .map(|e| e.id())
+                    .collect();
+                ids.into_iter()
+                    .map(|id| Node::Element(Rc::clone(&html), id))
+                    .collect()
+            }
+            Mode::Default(rule) => {
+                let (html, id) = node.html();
+                default_chain(&html, id, rule.split('@').filter(|s| !s.is_empty()))?
+                    .into_iter()
+                    .map(|id| Node::Element(Rc::clone(&html), id))
+                    .collect()
+            }
+            Mode::XPath(rule) => {
+                let (html, id) = node.html();
+                let root = html.tree.get(id).unwrap();
+                xpath::select(root, rule)?
+                    .into_iter()
+                    .map(|item| match item {
+                        Item::Node(node) if node.value().is_element() => {
+                            Node::Element(Rc::clone(&html), node.id())
+                        }
+                        item => Node::Text(item.string_value()),
+                    })
+                    .collect()
+            }
+            Mode::Json(path) => {
+                let Some(value) = node.json() else {
+                    return Ok(Vec::new());
+                };
+                let mut values: Vec<Value> = json_path(&path)?
+                    .query(&value)
+                    .all()
+                    .into_iter()
+                    .cloned()
+                    .collect();
+                // `$.list` selects the array itself; its items are the
+                // elements.
+                if let [Value::Array(items)] = values.as_slice() {
+                    values = items.clone();
+                }
+                values.into_iter().map(Node::Json).collect()
+            }
+            Mode::Regex(rule) => regex(rule)?
+                .captures_iter(&node.source())
+                .map(|c| {
+                    Node::Captures(
+                        c.iter()
+                            .map(|m| m.map(|m| m.as_str().to_string()).unwrap_or_default())
+                            .collect(),
+                    )
+                })
+                .collect(),
+        })
+    }
+}
+
+/// Split a rule around its scripts, or `None` if it has none.
+fn steps(rule: &str) -> Option<Vec<Step<'_>>> {
+    let lower = rule.to_ascii_lowercase();
+    if !lower.contains("<js>") && !lower.contains("@js:") {
+        return None;
+    }
+    let mut steps = Vec::new();
+    let mut start = 0;
+    fn push_rule<'r>(steps: &mut Vec<Step<'r>>, text: &'r str) {
+        if !text.trim().is_empty() {
+            steps.push(Step::Rule(text.trim()));
+        }
+    }
+    loop {
+        let tag = lower[start..].find("<js>").map(|i| start + i);
+        let inline = lower[start..].find("@js:").map(|i| start + i);
+        match (tag, inline) {
+            (Some(tag), inline) if inline.is_none_or(|inline| tag < inline) => {
+                push_rule(&mut steps, &rule[start..tag]);
+                let body = tag + "<js>".len();
+                match lower[body..].find("</js>") {
+                    Some(end) => {
+                        steps.push(Step::Script(&rule[body..body + end]));
+                        start = body + end + "</js>".len();
+                    }
+                    None => {
+                        steps.push(Step::Script(&rule[body..]));
+                        break;
+                    }
+                }
+            }
+            (_, Some(inline)) => {
+                push_rule(&mut steps, &rule[start..inline]);
+                steps.push(Step::Script(&rule[inline + "@js:".len()..]));
+                break;
+            }
+            _ => {
+                push_rule(&mut steps, &rule[start..]);
+                break;
+            }
+        }
+    }
+    Some(steps)
+}
+
+/// Whether a `{{...}}` expression is a rule rather than JavaScript.
+fn is_rule(expression: &str) -> bool {
+    expression.starts_with('@')
+        || expression.starts_with("$.")
+        || expression.starts_with("$[")
+        || expression.starts_with("//")
+}
+
+/// What scripts see as `result` when run on a node.
+fn node_value(node: &Node) -> Value {
+    match node {
+        Node::Json(value) => value.clone(),
+        Node::Captures(groups) => Value::Array(groups.iter().cloned().map(Value::String).collect()),
+        node => Value::String(node.source()),
+    }
+}
+
+/// The node a rule after a script runs on.
+fn value_node(value: Value) -> Node {
+    match value {
+        Value::String(text) => Node::parse(&text),
+        value => Node::Json(value),
+    }
+}
+
+fn mode<'r>(node: &Node, rule: &'r str) -> Result<Mode<'r>> {
+    Ok(if let Some(rest) = strip_prefix_ci(rule, "@css:") {
+        Mode::Css(rest.trim())
+    } else if let Some(rest) = strip_prefix_ci(rule, "@xpath:") {
+        Mode::XPath(rest.trim())
+    } else if let Some(rest) = strip_prefix_ci(rule, "@json:") {
+        Mode::Json(json_rule(rest.trim()))
+    } else if let Some(rest) = rule.strip_prefix("@@") {
+        Mode::Default(rest)
+    } else if rule.starts_with('/') || rule.starts_with("./") {
+        Mode::XPath(rule)
+    } else if rule.starts_with("$.") || rule.starts_with("$[") {
+        Mode::Json(rule.to_string())
+    } else if let Some(rest) = rule.strip_prefix(':') {
+        Mode::Regex(rest)
+    } else if matches!(node, Node::Json(_)) {
+        Mode::Json(json_rule(rule))
+    } else {
+        Mode::Default(rule)
+    })
+}
+
+fn strip_prefix_ci<'r>(rule: &'r str, prefix: &str) -> Option<&'r str> {
+    let head = rule.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix)
+        .then(|| &rule[prefix.len()..])
+}
+
+fn json_rule(rule: &str) -> String {
+    if rule.starts_with('$') {
+        rule.to_string()
+    } else {
+        format!("$.{rule}")
+    }
+}
+
+fn json_path(path: &str) -> Result<JsonPath> {
+    JsonPath::parse(path).map_err(|e| anyhow!("Invalid JSONPath {path}: {e}"))
+}
+
+pub(crate) fn json_strings(value: &Value) -> Vec<String> {
+    match value {
+        Value::Null => Vec::new(),
+        Value::String(text) => vec![text.clone()],
+        Value::Array(items) => items.iter().flat_map(json_strings).collect(),
+        Value::Object(_) => vec![value.to_string()],
+        other => vec![other.to_string()],
+    }
+}
+
+fn selector(rule: &str) -> Result<Selector> {
+    Selector::parse(rule).map_err(|e| anyhow!("Invalid CSS selector {rule}: {e}"))
+}
+
+fn regex(rule: &str) -> Result<Regex> {
+    Regex::new(rule).map_err(|e| anyhow!("Invalid regex {rule}: {e}"))
+}
+
+fn group_reference() -> &'static Regex {
+    static PATTERN: std::sync::OnceLock<Regex> = std::sync::OnceLock::new();
+    PATTERN.get_or_init(|| Regex::new(r"\$(\d)").unwrap())
+}
+
+/// Split a rule on its top-level `&&`, `||` or `%%` operator, evaluate each
+/// part and combine the results.
+fn combine<T>(rule: &str, eval: impl Fn(&str) -> Result<Vec<T>>) -> Result<Vec<T>> {
+    let Some((parts, operator)) = split_operator(rule) else {
+        return eval(rule);
+    };
+    match operator {
+        "||" => {
+            for part in parts {
+                let values = eval(part)?;
+                if !values.is_empty() {
+                    return Ok(values);
+                }
+            }
+            Ok(Vec::new())
+        }
+        "&&" => {
+            let mut values = Vec::new();
+            for part in parts {
+                values.extend(eval(part)?);
+            }
+            Ok(values)
+        }
+        _ => {
+            let lists = parts
+                .into_iter()
+                .map(|part| eval(part).map(Vec::into_iter))
+                .collect::<Result<Vec<_>>>()?;
+            let mut lists = lists;
+            let mut values = Vec::new();
+            loop {
+                let before = values.len();
+                for list in &mut lists {
+                    values.extend(list.next());
+                }
+                if values.len() == before {
+                    return Ok(values);
+                }
+            }
+        }
+    }
+}
+
+/// The parts of `rule` around the first top-level operator, skipping
+/// operators inside brackets or quotes such as JSONPath filters.
+fn split_operator(rule: &str) -> Option<(Vec<&str>, &'static str)> {
+    let mut operator = None;
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut quote = None;
+    let mut start = 0;
+    let bytes = rule.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, b'\'' | b'"') => quote = Some(c),
+            (None, b'[' | b'(' | b'{') => depth += 1,
+            (None, b']' | b')' | b'}') => depth -= 1,
+            (None, b'&' | b'|' | b'%') if depth == 0 && bytes.get(i + 1) == Some(&c) => {
+                let found = match c {
+                    b'&' => "&&",
+                    b'|' => "||",
+                    _ => "%%",
+                };
+                if operator.is_none_or(|op| op == found) {
+                    operator = Some(found);
+                    parts.push(rule[start..i].trim());
+                    i += 2;
+                    start = i;
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    let operator = operator?;
+    parts.push(rule[start..].trim());
+    Some((parts, operator))
+}
+
+/// Apply `regex##replacement`, or `regex##replacement###` to keep only the
+/// first match with the replacement applied.
+fn replace(text: &str, rule: &str) -> Result<String> {
+    let (rule, first_only) = match rule.strip_suffix("###") {
+        Some(rule) => (rule, true),
+        None => (rule, false),
+    };
+    let (pattern, replacement) = rule.split_once("##").unwrap_or((rule, ""));
+    if pattern.is_empty() {
+        return Ok(text.to_string());
+    }
+    let pattern = regex(pattern)?;
+    // Java writes `$1abc` where Rust needs `${1}abc`.
+    let replacement = group_reference().replace_all(replacement, "$${$1}");
+    Ok(if first_only {
+        pattern
+            .find(text)
+            .map(|m| {
+                pattern
+                    .replace(m.as_str(), replacement.as_ref())
+                    .into_owned()
+            })
+            .unwrap_or_default()
+    } else {
+        pattern.replace_all(text, replacement.as_ref()).into_owned()
+    })
+}
+
+/// Length of the text before the `}` closing a brace whose content starts
+/// at the beginning of `text`.
+fn closing_brace(text: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth == 0 => return Some(i),
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Parse `name:"rule", other:rule` pairs, with or without JSON quoting.
+fn assignments(body: &str) -> Vec<(String, String)> {
+    if let Ok(Value::Object(map)) = serde_json::from_str::<Value>(&format!("{{{body}}}")) {
+        return map
+            .into_iter()
+            .map(|(name, value)| {
+                let rule = match value {
+                    Value::String(rule) => rule,
+                    other => other.to_string(),
+                };
+                (name, rule)
+            })
+            .collect();
+    }
+    let unquote = |s: &str| {
+        let s = s.trim();
+        s.strip_prefix('"')
+            .and_then(|s| s.strip_suffix('"'))
+            .or_else(|| s.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')))
+            .unwrap_or(s)
+            .to_string()
+    };
+    let mut pairs = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    let mut push = |pair: &str| {
+        if let Some((name, rule)) = pair.split_once(':') {
+            pairs.push((unquote(name), unquote(rule)));
+        }
+    };
+    for (i, c) in body.char_indices() {
+        match c {
+            '[' | '(' | '{' => depth += 1,
+            ']' | ')' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                push(&body[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    push(&body[start..]);
+    pairs
+}
+
+fn css_strings(node: &Node, rule: &str) -> Result<Vec<String>> {
+    let (html, id) = node.html();
+    let (selector_rule, getter) = match rule.rsplit_once('@') {
+        Some((selector, getter))
+            if !getter.is_empty()
+                && getter
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') =>
+        {
+            (selector.trim(), getter)
+        }
+        _ => (rule, "text"),
+    };
+    let context = element(&html, id);
+    if selector_rule.is_empty() {
+        return Ok(get(context, getter));
+    }
+    let selector = selector(selector_rule)?;
+    Ok(context
+        .select(&selector)
+        .flat_map(|element| get(element, getter))
+        .collect())
+}
+
+fn default_strings(node: &Node, rule: &str) -> Result<Vec<String>> {
+    let (html, id) = node.html();
+    let mut segments: Vec<&str> = rule.split('@').filter(|s| !s.is_empty()).collect();
+    let Some(getter) = segments.pop() else {
+        return Ok(Vec::new());
+    };
+    Ok(default_chain(&html, id, segments.into_iter())?
+        .into_iter()
+        .flat_map(|id| get(element(&html, id), getter))
+        .collect())
+}
+
+fn default_chain<'r>(
+    html: &Html,
+    id: NodeId,
+    segments: impl Iterator<Item = &'r str>,
+) -> Result<Vec<NodeId>> {
+    let mut current = vec![id];
+    for segment in segments {
+        let (body, index) = Index::parse(segment.trim());
+        let mut next = Vec::new();
+        for id in &current {
+            let context = element(html, *id);
+            let found: Vec<ElementRef> = match body.split_once('.') {
+                Some(("class", names)) => {
+                    let classes: Vec<&str> = names.split_whitespace().collect();
+                    context
+                        .select(&selector(&format!(".{}", classes.join(".")))?)
+                        .collect()
+                }
+                Some(("id", name)) => context
+                    .select(&selector(&format!(
+                        "[id=\"{}\"]",
+                        name.replace('"', "\\\"")
+                    ))?)
+                    .collect(),
+                Some(("tag", name)) => context.select(&selector(name)?).collect(),
+                Some(("text", text)) => context
+                    .descendent_elements()
+                    .filter(|element| {
+                        element
+                            .children()
+                            .filter_map(text_of)
+                            .any(|own| own.contains(text))
+                    })
+                    .collect(),
+                _ if body == "children" => context.child_elements().collect(),
+                _ => context.select(&selector(body)?).collect(),
+            };
+            next.extend(index.apply(found).into_iter().map(|e| e.id()));
+        }
+        current = next;
+    }
+    Ok(current)
+}
+
+/// Extract a value from an element with a Legado getter.
+pub(crate) fn get(element: ElementRef, getter: &str) -> Vec<String> {
+    let value = match getter {
+        "text" => element.text().collect::<Vec<_>>().join(" "),
+        "textNodes" => element
+            .children()
+            .filter_map(text_of)
+            .map(|text| text.trim().to_string())
+            .filter(|text| !text.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n"),
+        "ownText" => element.children().filter_map(text_of).collect(),
+        "html" => {
+            let pattern = Regex::new(r"(?is)<(script|style)\b.*?</(script|style)\s*>").unwrap();
+            pattern.replace_all(&element.html(), "").into_owned()
+        }
+        "all" => element.html(),
+        attribute => match element.attr(attribute) {
+            Some(value) => value.to_string(),
+            None => return Vec::new(),
+        },
+    };
+    let value = if matches!(getter, "text" | "ownText") {
+        value.split_whitespace().collect::<Vec<_>>().join(" ")
+    } else {
+        value
+    };
+    vec![value]
+}
+
+/// Index selection for default rules: `.0`, `.-1`, `.0:2` (a list),
+/// `!0` (exclusions) or `[0, 2:5, -1]` with inclusive ranges.
+#[derive(Debug, PartialEq)]
+enum Index {
+    All,
+    Include(Vec<Span>),
+    Exclude(Vec<Span>),
+}
+
+#[derive(Debug, PartialEq)]
+enum Span {
+    One(i64),
+    Range(Option<i64>, Option<i64>, i64),
+}
+
+impl Index {
+    fn parse(segment: &str) -> (&str, Index) {
+        if let Some(open) = segment.strip_suffix(']').and_then(|s| s.rfind('[')) {
+            let content = &segment[open + 1..segment.len() - 1];
+            let (exclude, list) = match content.trim().strip_prefix('!') {
+                Some(list) => (true, list),
+                None => (false, content),
+            };
+            let valid = !list.trim().is_empty()
+                && list
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || matches!(c, '-' | ':' | ',' | ' '));
+            if valid {
+                let spans = list
+                    .split(',')
+                    .filter_map(|item| Span::parse(item.trim()))
+                    .collect();
+                let index = if exclude {
+                    Index::Exclude(spans)
+                } else {
+                    Index::Include(spans)
+                };
+                return (&segment[..open], index);
+            }
+        }
+        let Some(split) = segment.rfind(['.', '!']) else {
+            return (segment, Index::All);
+        };
+        let list = &segment[split + 1..];
+        let valid =
+            !list.is_empty() && list.split(':').all(|n| n.parse::<i64>().is_ok()) && split > 0;
+        if !valid {
+            return (segment, Index::All);
+        }
+        let spans = list
+            .split(':')
+            .map(|n| Span::One(n.parse().unwrap()))
+            .collect();
+        let index = if segment[split..].starts_with('!') {
+            Index::Exclude(spans)
+        } else {
+            Index::Include(spans)
+        };
+        (&segment[..split], index)
+    }
+
+    fn apply<T: Copy>(&self, items: Vec<T>) -> Vec<T> {
+        let len = items.len() as i64;
+        let resolve = |i: i64| if i < 0 { len + i } else { i };
+        let positions = |spans: &[Span]| -> Vec<i64> {
+            let mut positions = Vec::new();
+            for span in spans {
+                match *span {
+                    Span::One(i) => positions.push(resolve(i)),
+                    Span::Range(start, end, step) => {
+                        let start = resolve(start.unwrap_or(0));
+                        let end = resolve(end.unwrap_or(len - 1));
+                        let step = step.max(1);
+                        if start <= end {
+                            positions.extend((start..=end).step_by(step as usize));
+                        } else {
+                            positions.extend((end..=start).rev().step_by(step as usize));
+                        }
+                    }
+                }
+            }
+            positions
+        };
+        match self {
+            Index::All => items,
+            Index::Include(spans) => positions(spans)
+                .into_iter()
+                .filter(|&i| (0..len).contains(&i))
+                .map(|i| items[i as usize])
+                .collect(),
+            Index::Exclude(spans) => {
+                let excluded = positions(spans);
+                items
+                    .into_iter()
+                    .enumerate()
+                    .filter(|(i, _)| !excluded.contains(&(*i as i64)))
+                    .map(|(_, item)| item)
+                    .collect()
+            }
+        }
+    }
+}
+
+impl Span {
+    fn parse(item: &str) -> Option<Span> {
+        if !item.contains(':') {
+            return item.parse().ok().map(Span::One);
+        }
+        let mut parts = item.split(':').map(|p| p.trim());
+        let bound = |p: Option<&str>| -> Option<Option<i64>> {
+            match p {
+                None | Some("") => Some(None),
+                Some(p) => p.parse().ok().map(Some),
+            }
+        };
+        let start = bound(parts.next())?;
+        let end = bound(parts.next())?;
+        let step = bound(parts.next())?.unwrap_or(1);
+        Some(Span::Range(start, end, step))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body>
+        <div class="result-list">
+          <div class="item"><h3><a href="/book/1">Book One</a></h3><p class="author">作者：Alice</p></div>
+          <div class="item"><h3><a href="/book/2">Book Two</a></h3><p class="author">作者：Bob</p></div>
+          <div class="item ad"><h3><a href="/ad">Buy now</a></h3></div>
+        </div>
+        <div id="next"><a href="/search?page=2">下一页</a></div>
+    </body></html>"#;
+
+    #[test]
+    fn test_default_and_css_rules() {
+        let analyzer = Analyzer::new();
+        let page = Node::parse(PAGE);
+        let items = analyzer.elements(&page, "class.item!-1").unwrap();
+        assert_eq!(items.len(), 2);
+        let names: Vec<String> = items
+            .iter()
+            .map(|item| analyzer.string(item, "tag.h3@tag.a@text").unwrap())
+            .collect();
+        assert_eq!(names, ["Book One", "Book Two"]);
+        assert_eq!(
+            analyzer
+                .string(&items[1], "@css:p.author@text##作者：")
+                .unwrap(),
+            "Bob"
+        );
+        assert_eq!(
+            analyzer.string(&items[0], "tag.a.0@href").unwrap(),
+            "/book/1"
+        );
+        assert_eq!(
+            analyzer.elements(&page, "-class.item[0:1]").unwrap().len(),
+            2
+        );
+        assert_eq!(
+            analyzer
+                .string(&page, "id.next@tag.a@href||class.missing@href")
+                .unwrap(),
+            "/search?page=2"
+        );
+        assert_eq!(
+            analyzer
+                .strings(&page, "class.item.0@tag.a@text&&class.item.1@tag.a@text")
+                .unwrap(),
+            ["Book One", "Book Two"]
+        );
+    }
+
+    #[test]
+    fn test_xpath_json_and_regex_rules() {
+        let analyzer = Analyzer::new();
+        let page = Node::parse(PAGE);
+        let items = analyzer.elements(&page, "//div[@class='item']").unwrap();
+        assert_eq!(analyzer.string(&items[0], "//a/@href").unwrap(), "/book/1");
+        assert_eq!(
+            analyzer
+                .string(&items[1], "//p/text()##作者：(.*)##$1###")
+                .unwrap(),
+            "Bob"
+        );
+
+        let json =
+            Node::parse(r#"{"data":{"list":[{"id":7,"title":"One"},{"id":8,"title":"Two"}]}}"#);
+        let books = analyzer.elements(&json, "$.data.list").unwrap();
+        assert_eq!(books.len(), 2);
+        assert_eq!(analyzer.string(&books[1], "title").unwrap(), "Two");
+        assert_eq!(
+            analyzer
+                .string(&books[0], "https://example.com/book/{$.id}.html")
+                .unwrap(),
+            "https://example.com/book/7.html"
+        );
+
+        let captures = analyzer
+            .elements(&page, r#":<a href="(/book/\d+)">([^<]+)</a>"#)
+            .unwrap();
+        assert_eq!(captures.len(), 2);
+        assert_eq!(
+            analyzer.string(&captures[1], "$2 at $1").unwrap(),
+            "Book Two at /book/2"
+        );
+    }
+
+    #[test]
+    fn test_scripted_rules() {
+        let analyzer = Analyzer::new();
+        analyzer.put("key", "三体".to_string());
+        analyzer.put("page", "2".to_string());
+        let page = Node::parse(PAGE);
+        assert_eq!(
+            analyzer
+                .string(&page, "class.item.0@tag.a@text@js:result.toUpperCase()")
+                .unwrap(),
+            "BOOK ONE"
+        );
+        assert_eq!(
+            analyzer
+                .string(
+                    &page,
+                    "<js>result.replace('Book One', 'Novel')</js>class.item.0@tag.a@text"
+                )
+                .unwrap(),
+            "Novel"
+        );
+        let json = Node::Json(serde_json::json!({"data": [{"vip": 0}, {"vip": 1}, {"vip": 0}]}));
+        assert_eq!(
+            analyzer
+                .elements(&json, "@js:result.data.filter(b => b.vip == 0)")
+                .unwrap()
+                .len(),
+            2
+        );
+        assert_eq!(
+            analyzer
+                .template(&Node::Text(String::new()), "/s?p={{page - 1}}&k={{key}}")
+                .unwrap(),
+            "/s?p=1&k=三体"
+        );
+        assert_eq!(
+            steps("a<js>b</js>c@JS:d").unwrap(),
+            [
+                Step::Rule("a"),
+                Step::Script("b"),
+                Step::Rule("c"),
+                Step::Script("d")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_variables_and_templates() {
+        let analyzer = Analyzer::new();
+        analyzer.put("key", "三体".to_string());
+        let page = Node::parse(PAGE);
+        assert_eq!(
+            analyzer
+                .string(
+                    &page,
+                    "@put:{next:\"id.next@tag.a@href\"}class.item.0@tag.a@text"
+                )
+                .unwrap(),
+            "Book One"
+        );
+        assert_eq!(analyzer.get("next").as_deref(), Some("/search?page=2"));
+        assert_eq!(
+            analyzer.string(&page, "{{key}}: @get:{next}").unwrap(),
+            "三体: /search?page=2"
+        );
+        assert_eq!(
+            analyzer
+                .replace("第一章 广告 正文", "##\\s*广告\\s*## ")
+                .unwrap(),
+            "第一章 正文"
+        );
+    }
+
+    #[test]
+    fn test_index_parsing() {
+        assert_eq!(
+            Index::parse("class.item.0"),
+            ("class.item", Index::Include(vec![Span::One(0)]))
+        );
+        assert_eq!(
+            Index::parse("tag.li!0:-1"),
+            ("tag.li", Index::Exclude(vec![Span::One(0), Span::One(-1)]))
+        );
+        assert_eq!(
+            Index::parse("li[1:3]"),
+            ("li", Index::Include(vec![Span::Range(Some(1), Some(3), 1)]))
+        );
+        assert_eq!(Index::parse("a[href]"), ("a[href]", Index::All));
+        assert_eq!(Index::parse("class.h1"), ("class.h1", Index::All));
+        assert_eq!(
+            Index::Include(vec![Span::Range(Some(-1), Some(0), 1)]).apply(vec![1, 2, 3]),
+            [3, 2, 1]
+        );
+    }
+}
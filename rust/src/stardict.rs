@@ -0,0 +1,504 @@
+//! Reader for StarDict dictionaries: an `.ifo` description, an `.idx`
+//! (or `.idx.gz`) headword index and a `.dict` (or dictzip `.dict.dz`)
+//! article file. Dictzip files are read chunk by chunk, so a lookup only
+//! inflates the piece of the file holding the article.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use flate2::read::GzDecoder;
+use flate2::{Decompress, FlushDecompress};
+use quick_xml::escape::escape;
+
+use crate::api::dict::{Fold, KeyIndex};
+
+/// An open StarDict dictionary.
+pub(crate) struct StarDict {
+    pub(crate) title: String,
+    pub(crate) description: String,
+    pub(crate) index: KeyIndex,
+    /// Field types shared by every article, if the dictionary declares them.
+    same_type_sequence: Option<String>,
+    articles: Articles,
+    /// Directory of bundled images and sounds, if there is one.
+    pub(crate) resource_dir: Option<PathBuf>,
+}
+
+/// Where article bytes come from.
+enum Articles {
+    Plain(File),
+    Dictzip(Dictzip),
+    /// A gzip file without a chunk table, inflated whole.
+    Memory(Vec<u8>),
+}
+
+/// The random-access table of a dictzip file.
+struct Dictzip {
+    file: File,
+    chunk_len: u64,
+    /// File offset of each chunk, followed by the end of the last one.
+    offsets: Vec<u64>,
+    cached: Option<(usize, Vec<u8>)>,
+}
+
+impl StarDict {
+    /// Open the dictionary described by an `.ifo` file.
+    pub(crate) fn open(ifo: &Path) -> Result<Self> {
+        let text = fs::read_to_string(ifo)
+            .map_err(|e| anyhow!("Failed to read {}: {e}", ifo.display()))?;
+        let mut lines = text.lines();
+        if lines.next().map(str::trim) != Some("StarDict's dict ifo file") {
+            return Err(anyhow!("Not a StarDict .ifo file"));
+        }
+        let info: HashMap<&str, &str> = lines
+            .filter_map(|line| line.split_once('='))
+            .map(|(key, value)| (key.trim(), value.trim()))
+            .collect();
+        let wide_offsets = info.get("idxoffsetbits") == Some(&"64");
+
+        let base = ifo.with_extension("");
+        let sibling = |extension: &str| {
+            let path = PathBuf::from(format!("{}.{extension}", base.display()));
+            path.exists().then_some(path)
+        };
+        let index = if let Some(path) = sibling("idx") {
+            fs::read(&path).map_err(|e| anyhow!("Failed to read {}: {e}", path.display()))?
+        } else if let Some(path) = sibling("idx.gz") {
+            gunzip(&path)?
+        } else {
+            return Err(anyhow!("Dictionary index (.idx) is missing"));
+        };
+        let articles = if let Some(path) = sibling("dict") {
+            Articles::Plain(
+                File::open(&path).map_err(|e| anyhow!("Failed to open {}: {e}", path.display()))?,
+            )
+        } else if let Some(path) = sibling("dict.dz") {
+            match Dictzip::open(&path)? {
+                Some(dictzip) => Articles::Dictzip(dictzip),
+                None => Articles::Memory(gunzip(&path)?),
+            }
+        } else {
+            return Err(anyhow!("Dictionary articles (.dict) are missing"));
+        };
+        let resource_dir = ifo
+            .parent()
+            .map(|dir| dir.join("res"))
+            .filter(|dir| dir.is_dir());
+
+        let title = info.get("bookname").copied().unwrap_or_default();
+        Ok(Self {
+            title: if title.is_empty() {
+                base.file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_default()
+            } else {
+                title.to_string()
+            },
+            description: info
+                .get("description")
+                .map(|text| text.replace("<br>", "\n"))
+                .unwrap_or_default(),
+            index: KeyIndex::new(
+                parse_index(&index, wide_offsets)?,
+                Fold {
+                    case_sensitive: false,
+                    strip: false,
+                },
+            ),
+            same_type_sequence: info
+                .get("sametypesequence")
+                .filter(|types| !types.is_empty())
+                .map(|types| types.to_string()),
+            articles,
+            resource_dir,
+        })
+    }
+
+    /// Render the article stored at `start..end` as HTML.
+    pub(crate) fn html(&mut self, start: u64, end: u64) -> Result<String> {
+        let data = self.articles.read(start, end)?;
+        Ok(render(&data, self.same_type_sequence.as_deref()))
+    }
+}
+
+impl Articles {
+    fn read(&mut self, start: u64, end: u64) -> Result<Vec<u8>> {
+        match self {
+            Articles::Plain(file) => {
+                let file_len = file
+                    .metadata()
+                    .map_err(|e| anyhow!("Failed to read dictionary: {e}"))?
+                    .len();
+                let len = end
+                    .checked_sub(start)
+                    .filter(|_| end <= file_len)
+                    .ok_or_else(|| anyhow!("Article offset {start} is out of range"))?;
+                let mut data = vec![0; len as usize];
+                file.seek(SeekFrom::Start(start))
+                    .and_then(|_| file.read_exact(&mut data))
+                    .map_err(|e| anyhow!("Failed to read dictionary: {e}"))?;
+                Ok(data)
+            }
+            Articles::Dictzip(dictzip) => dictzip.read(start, end),
+            Articles::Memory(data) => data
+                .get(start as usize..end as usize)
+                .map(<[u8]>::to_vec)
+                .ok_or_else(|| anyhow!("Article offset {start} is out of range")),
+        }
+    }
+}
+
+impl Dictzip {
+    /// Read the chunk table from the gzip header; `None` if the file is
+    /// ordinary gzip.
+    fn open(path: &Path) -> Result<Option<Self>> {
+        let mut file =
+            File::open(path).map_err(|e| anyhow!("Failed to open {}: {e}", path.display()))?;
+        let mut header = vec![0; 10];
+        file.read_exact(&mut header)
+            .map_err(|e| anyhow!("Dictionary is truncated: {e}"))?;
+        if header[..3] != [0x1f, 0x8b, 8] {
+            return Err(anyhow!("{} is not gzip-compressed", path.display()));
+        }
+        let flags = header[3];
+        if flags & 4 == 0 {
+            return Ok(None);
+        }
+        let mut read = |len: usize| -> Result<Vec<u8>> {
+            let mut data = vec![0; len];
+            file.read_exact(&mut data)
+                .map_err(|e| anyhow!("Dictionary is truncated: {e}"))?;
+            Ok(data)
+        };
+        let extra_len = u16::from_le_bytes(read(2)?.try_into().unwrap()) as usize;
+        let extra = read(extra_len)?;
+
+        let mut table = None;
+        let mut pos = 0;
+        while pos + 4 <= extra.len() {
+            let len = u16::from_le_bytes([extra[pos + 2], extra[pos + 3]]) as usize;
+            let field = extra.get(pos + 4..pos + 4 + len).unwrap_or_default();
+            if extra[pos..pos + 2] == *b"RA" && field.len() >= 6 {
+                table = Some(field.to_vec());
+            }
+            pos += 4 + len;
+        }
+        let Some(table) = table else {
+            return Ok(None);
+        };
+        let chunk_len = u16::from_le_bytes([table[2], table[3]]) as u64;
+        let chunk_count = u16::from_le_bytes([table[4], table[5]]) as usize;
+        if table.len() < 6 + chunk_count * 2 || chunk_len == 0 {
+            return Err(anyhow!("Dictzip chunk table is corrupt"));
+        }
+
+        // Skip the file name, comment and header checksum.
+        for flag in [8, 16] {
+            if flags & flag != 0 {
+                while read(1)?[0] != 0 {}
+            }
+        }
+        if flags & 2 != 0 {
+            read(2)?;
+        }
+        let mut offset = file
+            .stream_position()
+            .map_err(|e| anyhow!("Failed to read dictionary: {e}"))?;
+        let mut offsets = vec![offset];
+        for size in table[6..6 + chunk_count * 2].chunks(2) {
+            offset += u16::from_le_bytes([size[0], size[1]]) as u64;
+            offsets.push(offset);
+        }
+        Ok(Some(Self {
+            file,
+            chunk_len,
+            offsets,
+            cached: None,
+        }))
+    }
+
+    fn read(&mut self, start: u64, end: u64) -> Result<Vec<u8>> {
+        let total = self.chunk_len * (self.offsets.len() as u64 - 1);
+        let len = end
+            .checked_sub(start)
+            .filter(|_| end <= total)
+            .ok_or_else(|| anyhow!("Article offset {start} is out of range"))?;
+        let mut out = Vec::with_capacity(len as usize);
+        let mut position = start;
+        while position < end {
+            let index = (position / self.chunk_len) as usize;
+            let chunk_start = index as u64 * self.chunk_len;
+            let chunk = self.chunk(index)?;
+            let from = (position - chunk_start) as usize;
+            let to = ((end - chunk_start) as usize).min(chunk.len());
+            if from >= to {
+                return Err(anyhow!("Article offset {position} is out of range"));
+            }
+            out.extend_from_slice(&chunk[from..to]);
+            position = chunk_start + to as u64;
+        }
+        Ok(out)
+    }
+
+    fn chunk(&mut self, index: usize) -> Result<&[u8]> {
+        if self
+            .cached
+            .as_ref()
+            .is_none_or(|(cached, _)| *cached != index)
+        {
+            let (Some(&from), Some(&to)) = (self.offsets.get(index), self.offsets.get(index + 1))
+            else {
+                return Err(anyhow!("Dictzip chunk {index} is out of range"));
+            };
+            let mut compressed = vec![0; (to - from) as usize];
+            self.file
+                .seek(SeekFrom::Start(from))
+                .and_then(|_| self.file.read_exact(&mut compressed))
+                .map_err(|e| anyhow!("Failed to read dictionary: {e}"))?;
+            // Chunks end on a full flush, so each inflates on its own.
+            let mut chunk = Vec::with_capacity(self.chunk_len as usize);
+            Decompress::new(false)
+                .decompress_vec(&compressed, &mut chunk, FlushDecompress::Sync)
+                .map_err(|e| anyhow!("Corrupt dictzip chunk: {e}"))?;
+            self.cached = Some((index, chunk));
+        }
+        Ok(&self.cached.as_ref().unwrap().1)
+    }
+}
+
+fn gunzip(path: &Path) -> Result<Vec<u8>> {
+    let file = File::open(path).map_err(|e| anyhow!("Failed to open {}: {e}", path.display()))?;
+    let mut data = Vec::new();
+    GzDecoder::new(file)
+        .read_to_end(&mut data)
+        .map_err(|e| anyhow!("Failed to decompress {}: {e}", path.display()))?;
+    Ok(data)
+}
+
+/// Read `(headword, start, end)` entries from an `.idx` file.
+fn parse_index(data: &[u8], wide_offsets: bool) -> Result<Vec<(String, u64, u64)>> {
+    let offset_len = if wide_offsets { 8 } else { 4 };
+    let mut entries = Vec::new();
+    let mut pos = 0;
+    while pos < data.len() {
+        let nul = data[pos..]
+            .iter()
+            .position(|&b| b == 0)
+            .ok_or_else(|| anyhow!("Dictionary index is truncated"))?;
+        let word = String::from_utf8_lossy(&data[pos..pos + nul]).into_owned();
+        pos += nul + 1;
+        let numbers = data
+            .get(pos..pos + offset_len + 4)
+            .ok_or_else(|| anyhow!("Dictionary index is truncated"))?;
+        let start = numbers[..offset_len]
+            .iter()
+            .fold(0u64, |n, &b| n << 8 | b as u64);
+        let size = u32::from_be_bytes(numbers[offset_len..].try_into().unwrap()) as u64;
+        pos += offset_len + 4;
+        let end = start
+            .checked_add(size)
+            .ok_or_else(|| anyhow!("Dictionary index entry for {word} is out of range"))?;
+        entries.push((word, start, end));
+    }
+    Ok(entries)
+}
+
+/// Turn an article's fields into HTML. Text fields are escaped; markup
+/// fields are kept as they are; sounds and images are dropped.
+fn render(data: &[u8], same_type_sequence: Option<&str>) -> String {
+    let mut fields: Vec<(u8, &[u8])> = Vec::new();
+    let mut pos = 0;
+    let types: Vec<u8> = same_type_sequence
+        .map(|s| s.bytes().collect())
+        .unwrap_or_default();
+    let mut next_type = types.iter().copied();
+    while pos < data.len() {
+        let kind = if types.is_empty() {
+            pos += 1;
+            data[pos - 1]
+        } else {
+            match next_type.next() {
+                Some(kind) => kind,
+                None => break,
+            }
+        };
+        // With a shared type sequence the last field runs to the end.
+        let last = !types.is_empty() && next_type.len() == 0;
+        let rest = &data[pos..];
+        let (field, used) = if last {
+            (rest, rest.len())
+        } else if kind.is_ascii_lowercase() {
+            let len = rest.iter().position(|&b| b == 0).unwrap_or(rest.len());
+            (&rest[..len], (len + 1).min(rest.len()))
+        } else {
+            let len = rest
+                .get(..4)
+                .map_or(0, |b| u32::from_be_bytes(b.try_into().unwrap()) as usize);
+            let end = (4 + len).min(rest.len());
+            (rest.get(4..end).unwrap_or_default(), end)
+        };
+        fields.push((kind, field));
+        pos += used;
+    }
+
+    let mut html = String::new();
+    for (kind, field) in fields {
+        let text = String::from_utf8_lossy(field);
+        let text = text.trim_end_matches('\0');
+        match kind {
+            b'h' | b'g' | b'x' => html.push_str(text),
+            b't' | b'y' => {
+                html.push_str(&format!("<div class=\"phonetic\">[{}]</div>", escape(text)))
+            }
+            kind if kind.is_ascii_lowercase() => {
+                let lines: Vec<_> = text.lines().map(|line| escape(line).into_owned()).collect();
+                html.push_str(&format!("<div>{}</div>", lines.join("<br>")));
+            }
+            _ => {}
+        }
+    }
+    html
+}
+
+/// Build small dictionaries for tests.
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::test_dirs::temp_dir;
+    use flate2::{Compress, Compression, FlushCompress};
+
+    /// Write `name.ifo`, `name.idx` and a dictzip `name.dict.dz` with tiny
+    /// chunks, from entries given in sorted order.
+    pub(crate) fn build(dir: &Path, name: &str, entries: &[(&str, &str)]) -> PathBuf {
+        fs::create_dir_all(dir).unwrap();
+        let mut index = Vec::new();
+        let mut articles = Vec::new();
+        for (word, article) in entries {
+            index.extend_from_slice(word.as_bytes());
+            index.push(0);
+            index.extend_from_slice(&(articles.len() as u32).to_be_bytes());
+            index.extend_from_slice(&(article.len() as u32).to_be_bytes());
+            articles.extend_from_slice(article.as_bytes());
+        }
+
+        let chunk_len = 16;
+        let mut compress = Compress::new(Compression::default(), false);
+        let mut body = Vec::new();
+        let mut sizes = Vec::new();
+        for chunk in articles.chunks(chunk_len) {
+            let mut out = Vec::with_capacity(chunk.len() + 64);
+            compress
+                .compress_vec(chunk, &mut out, FlushCompress::Full)
+                .unwrap();
+            sizes.push(out.len() as u16);
+            body.extend_from_slice(&out);
+        }
+        let mut end = Vec::with_capacity(64);
+        compress
+            .compress_vec(&[], &mut end, FlushCompress::Finish)
+            .unwrap();
+        body.extend_from_slice(&end);
+
+        let mut field = vec![1, 0];
+        field.extend_from_slice(&(chunk_len as u16).to_le_bytes());
+        field.extend_from_slice(&(sizes.len() as u16).to_le_bytes());
+        for size in sizes {
+            field.extend_from_slice(&size.to_le_bytes());
+        }
+        let mut extra = b"RA".to_vec();
+        extra.extend_from_slice(&(field.len() as u16).to_le_bytes());
+        extra.extend_from_slice(&field);
+        let mut dz = vec![0x1f, 0x8b, 8, 4, 0, 0, 0, 0, 2, 3];
+        dz.extend_from_slice(&(extra.len() as u16).to_le_bytes());
+        dz.extend_from_slice(&extra);
+        dz.extend_from_slice(&body);
+        dz.extend_from_slice(&[0; 8]);
+
+        let ifo = dir.join(format!("{name}.ifo"));
+        fs::write(
+            &ifo,
+            format!(
+                "StarDict's dict ifo file\nversion=2.4.2\nbookname={name}\nwordcount={}\n\
+                 idxfilesize={}\nsametypesequence=m\ndescription=Made for tests\n",
+                entries.len(),
+                index.len()
+            ),
+        )
+        .unwrap();
+        fs::write(dir.join(format!("{name}.idx")), index).unwrap();
+        fs::write(dir.join(format!("{name}.dict.dz")), dz).unwrap();
+        ifo
+    }
+
+    #[test]
+    fn test_reads_dictzip_articles() {
+        let dir = temp_dir("stardict-dictzip");
+        let entries = [
+            ("alpha", "first letter\nof the Greek alphabet"),
+            ("beta", "second <letter>"),
+            ("gamma", "third letter, spanning several dictzip chunks"),
+        ];
+        let mut dict = StarDict::open(&build(&dir, "greek", &entries)).unwrap();
+        assert_eq!(dict.title, "greek");
+        assert!(matches!(dict.articles, Articles::Dictzip(_)));
+
+        let (_, start, end) = dict.index.find("Gamma")[0];
+        assert_eq!(
+            dict.html(start, end).unwrap(),
+            "<div>third letter, spanning several dictzip chunks</div>"
+        );
+        let (_, start, end) = dict.index.find("alpha")[0];
+        assert_eq!(
+            dict.html(start, end).unwrap(),
+            "<div>first letter<br>of the Greek alphabet</div>"
+        );
+        let (_, start, end) = dict.index.find("beta")[0];
+        assert_eq!(
+            dict.html(start, end).unwrap(),
+            "<div>second &lt;letter&gt;</div>"
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_rejects_bad_article_offsets() {
+        let dir = temp_dir("stardict-offsets");
+        let ifo = build(&dir, "bad", &[("alpha", "first letter")]);
+        let mut dict = StarDict::open(&ifo).unwrap();
+        assert!(dict.html(4, 2).is_err());
+        assert!(dict.html(0, u64::MAX).is_err());
+
+        // Uncompressed articles are checked against the file's length.
+        let dz = dir.join("bad.dict.dz");
+        fs::remove_file(dz).unwrap();
+        fs::write(dir.join("bad.dict"), "first letter").unwrap();
+        let mut dict = StarDict::open(&ifo).unwrap();
+        assert!(matches!(dict.articles, Articles::Plain(_)));
+        assert!(dict.html(0, u64::MAX).is_err());
+        assert!(dict.html(4, 2).is_err());
+
+        let mut index = b"alpha\0".to_vec();
+        index.extend_from_slice(&u32::MAX.to_be_bytes());
+        index.extend_from_slice(&u32::MAX.to_be_bytes());
+        assert!(parse_index(&index, false).is_ok());
+        let mut index = b"alpha\0".to_vec();
+        index.extend_from_slice(&u64::MAX.to_be_bytes());
+        index.extend_from_slice(&1u32.to_be_bytes());
+        assert!(parse_index(&index, true).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_render_typed_fields() {
+        // Without a shared sequence every field carries its type.
+        let data = b"tfo\xc3\xb0\0hone <b>one</b>\0";
+        assert_eq!(
+            render(data, None),
+            "<div class=\"phonetic\">[fo\u{f0}]</div>one <b>one</b>"
+        );
+        assert_eq!(render(b"a\0<i>b</i>", Some("mh")), "<div>a</div><i>b</i>");
+    }
+}
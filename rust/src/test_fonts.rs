@@ -0,0 +1,440 @@
+//! Tiny TrueType fonts generated on the fly for unit tests.
+//!
+//! Real fonts are far too large to check in, and most font APIs only need a
+//! handful of glyphs to be exercised meaningfully.
+
+use crate::sfnt::{self, put_i16, put_u16, put_u32, Table};
+
+pub(crate) const UNITS_PER_EM: u16 = 1000;
+
+/// Outline of a single test glyph.
+#[derive(Clone)]
+pub(crate) enum Glyph {
+    /// No outline at all; `advance` may still be non-zero (e.g. a space).
+    Empty { advance: u16 },
+    /// A filled box covering `0..advance` horizontally.
+    Square { advance: u16 },
+    /// A composite made of other glyphs' outlines.
+    Composite { advance: u16, components: Vec<u16> },
+}
+
+impl Glyph {
+    fn advance(&self) -> u16 {
+        match self {
+            Glyph::Empty { advance }
+            | Glyph::Square { advance }
+            | Glyph::Composite { advance, .. } => *advance,
+        }
+    }
+}
+
+/// Builder for a minimal but well-formed TrueType font.
+pub(crate) struct FontBuilder {
+    glyphs: Vec<Glyph>,
+    cmap: Vec<(u32, u16)>,
+    family: String,
+    subfamily: String,
+    weight: u16,
+    italic: bool,
+    extra_tables: Vec<Table>,
+}
+
+impl FontBuilder {
+    /// Start a font containing only `.notdef` (glyph 0).
+    pub fn new() -> Self {
+        Self {
+            glyphs: vec![Glyph::Empty { advance: 500 }],
+            cmap: Vec::new(),
+            family: "Test Sans".to_string(),
+            subfamily: "Regular".to_string(),
+            weight: 400,
+            italic: false,
+            extra_tables: Vec::new(),
+        }
+    }
+
+    /// A font mapping every character of `chars` to its own square glyph.
+    pub fn with_chars(chars: &str) -> Self {
+        let mut builder = Self::new();
+        for ch in chars.chars() {
+            builder = builder.glyph(ch, Glyph::Square { advance: 600 });
+        }
+        builder
+    }
+
+    /// Add a glyph mapped from `ch`. Glyph IDs are assigned sequentially.
+    pub fn glyph(mut self, ch: char, glyph: Glyph) -> Self {
+        let id = self.unmapped_glyph(glyph);
+        self.cmap.push((ch as u32, id));
+        self
+    }
+
+    /// Add a glyph without a cmap entry and return its ID.
+    pub fn unmapped_glyph(&mut self, glyph: Glyph) -> u16 {
+        self.glyphs.push(glyph);
+        (self.glyphs.len() - 1) as u16
+    }
+
+    pub fn family(mut self, family: &str, subfamily: &str) -> Self {
+        self.family = family.to_string();
+        self.subfamily = subfamily.to_string();
+        self
+    }
+
+    pub fn weight(mut self, weight: u16) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    pub fn italic(mut self, italic: bool) -> Self {
+        self.italic = italic;
+        self
+    }
+
+    /// Include an arbitrary extra table verbatim.
+    pub fn table(mut self, tag: [u8; 4], data: Vec<u8>) -> Self {
+        self.extra_tables.push(Table { tag, data });
+        self
+    }
+
+    pub fn build(self) -> Vec<u8> {
+        let (glyf, loca) = self.glyf_and_loca();
+        let mut tables = vec![
+            Table {
+                tag: *b"head",
+                data: self.head(),
+            },
+            Table {
+                tag: *b"hhea",
+                data: self.hhea(),
+            },
+            Table {
+                tag: *b"maxp",
+                data: self.maxp(),
+            },
+            Table {
+                tag: *b"hmtx",
+                data: self.hmtx(),
+            },
+            Table {
+                tag: *b"cmap",
+                data: self.cmap(),
+            },
+            Table {
+                tag: *b"loca",
+                data: loca,
+            },
+            Table {
+                tag: *b"glyf",
+                data: glyf,
+            },
+            Table {
+                tag: *b"name",
+                data: self.name(),
+            },
+            Table {
+                tag: *b"OS/2",
+                data: self.os2(),
+            },
+            Table {
+                tag: *b"post",
+                data: post(),
+            },
+        ];
+        tables.extend(self.extra_tables);
+        sfnt::build(sfnt::TRUETYPE_FLAVOR, tables)
+    }
+
+    fn head(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        put_u32(&mut out, 0x0001_0000); // version
+        put_u32(&mut out, 0x0001_0000); // fontRevision
+        put_u32(&mut out, 0); // checkSumAdjustment
+        put_u32(&mut out, 0x5F0F_3CF5); // magicNumber
+        put_u16(&mut out, 0x000B); // flags
+        put_u16(&mut out, UNITS_PER_EM);
+        out.extend_from_slice(&[0; 16]); // created, modified
+        put_i16(&mut out, 0); // xMin
+        put_i16(&mut out, -200); // yMin
+        put_i16(&mut out, self.max_advance() as i16); // xMax
+        put_i16(&mut out, 800); // yMax
+        put_u16(&mut out, if self.italic { 0x0002 } else { 0 }); // macStyle
+        put_u16(&mut out, 8); // lowestRecPPEM
+        put_i16(&mut out, 2); // fontDirectionHint
+        put_i16(&mut out, 1); // indexToLocFormat: long
+        put_i16(&mut out, 0); // glyphDataFormat
+        out
+    }
+
+    fn hhea(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        put_u32(&mut out, 0x0001_0000);
+        put_i16(&mut out, 800); // ascender
+        put_i16(&mut out, -200); // descender
+        put_i16(&mut out, 0); // lineGap
+        put_u16(&mut out, self.max_advance());
+        put_i16(&mut out, 0); // minLeftSideBearing
+        put_i16(&mut out, 0); // minRightSideBearing
+        put_i16(&mut out, self.max_advance() as i16); // xMaxExtent
+        put_i16(&mut out, 1); // caretSlopeRise
+        put_i16(&mut out, 0); // caretSlopeRun
+        out.extend_from_slice(&[0; 10]); // caretOffset + reserved
+        put_i16(&mut out, 0); // metricDataFormat
+        put_u16(&mut out, self.glyphs.len() as u16); // numberOfHMetrics
+        out
+    }
+
+    fn maxp(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        put_u32(&mut out, 0x0001_0000);
+        put_u16(&mut out, self.glyphs.len() as u16);
+        put_u16(&mut out, 4); // maxPoints
+        put_u16(&mut out, 1); // maxContours
+        put_u16(&mut out, 8); // maxCompositePoints
+        put_u16(&mut out, 2); // maxCompositeContours
+        put_u16(&mut out, 2); // maxZones
+        out.extend_from_slice(&[0; 12]);
+        put_u16(&mut out, 2); // maxComponentElements
+        put_u16(&mut out, 1); // maxComponentDepth
+        out
+    }
+
+    fn hmtx(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        for glyph in &self.glyphs {
+            put_u16(&mut out, glyph.advance());
+            put_i16(&mut out, 0);
+        }
+        out
+    }
+
+    fn cmap(&self) -> Vec<u8> {
+        let mut mappings = self.cmap.clone();
+        mappings.sort_unstable();
+
+        let mut out = Vec::new();
+        put_u16(&mut out, 0); // version
+        put_u16(&mut out, 1); // numTables
+        put_u16(&mut out, 3); // platformID: Windows
+        put_u16(&mut out, 10); // encodingID: UCS-4
+        put_u32(&mut out, 12); // offset
+        put_u16(&mut out, 12); // format
+        put_u16(&mut out, 0); // reserved
+        put_u32(&mut out, 16 + 12 * mappings.len() as u32);
+        put_u32(&mut out, 0); // language
+        put_u32(&mut out, mappings.len() as u32);
+        for (codepoint, glyph) in mappings {
+            put_u32(&mut out, codepoint);
+            put_u32(&mut out, codepoint);
+            put_u32(&mut out, glyph as u32);
+        }
+        out
+    }
+
+    fn glyf_and_loca(&self) -> (Vec<u8>, Vec<u8>) {
+        let mut glyf = Vec::new();
+        let mut loca = Vec::new();
+        for glyph in &self.glyphs {
+            put_u32(&mut loca, glyf.len() as u32);
+            match glyph {
+                Glyph::Empty { .. } => {}
+                Glyph::Square { advance } => {
+                    let width = *advance as i16;
+                    put_i16(&mut glyf, 1); // numberOfContours
+                    put_i16(&mut glyf, 0);
+                    put_i16(&mut glyf, 0);
+                    put_i16(&mut glyf, width);
+                    put_i16(&mut glyf, 700);
+                    put_u16(&mut glyf, 3); // endPtsOfContours
+                    put_u16(&mut glyf, 0); // instructionLength
+                    glyf.extend_from_slice(&[0x01; 4]); // on-curve, long coords
+                    for dx in [0, width, 0, -width] {
+                        put_i16(&mut glyf, dx);
+                    }
+                    for dy in [0, 0, 700, 0] {
+                        put_i16(&mut glyf, dy);
+                    }
+                }
+                Glyph::Composite {
+                    advance,
+                    components,
+                } => {
+                    put_i16(&mut glyf, -1);
+                    put_i16(&mut glyf, 0);
+                    put_i16(&mut glyf, 0);
+                    put_i16(&mut glyf, *advance as i16);
+                    put_i16(&mut glyf, 700);
+                    for (index, component) in components.iter().enumerate() {
+                        let more = if index + 1 < components.len() {
+                            0x0020
+                        } else {
+                            0
+                        };
+                        // ARG_1_AND_2_ARE_WORDS | ARGS_ARE_XY_VALUES
+                        put_u16(&mut glyf, 0x0003 | more);
+                        put_u16(&mut glyf, *component);
+                        put_i16(&mut glyf, 0);
+                        put_i16(&mut glyf, 0);
+                    }
+                }
+            }
+            glyf.resize((glyf.len() + 3) & !3, 0);
+        }
+        put_u32(&mut loca, glyf.len() as u32);
+        (glyf, loca)
+    }
+
+    fn name(&self) -> Vec<u8> {
+        let full = format!("{} {}", self.family, self.subfamily);
+        let postscript = full.replace(' ', "-");
+        let records = [
+            (1u16, self.family.as_str()),
+            (2, self.subfamily.as_str()),
+            (4, full.as_str()),
+            (6, postscript.as_str()),
+        ];
+
+        let mut strings = Vec::new();
+        let mut out = Vec::new();
+        put_u16(&mut out, 0); // format
+        put_u16(&mut out, records.len() as u16);
+        put_u16(&mut out, 6 + 12 * records.len() as u16); // stringOffset
+        for (name_id, value) in records {
+            let encoded: Vec<u8> = value.encode_utf16().flat_map(u16::to_be_bytes).collect();
+            put_u16(&mut out, 3); // platformID
+            put_u16(&mut out, 1); // encodingID
+            put_u16(&mut out, 0x0409); // languageID
+            put_u16(&mut out, name_id);
+            put_u16(&mut out, encoded.len() as u16);
+            put_u16(&mut out, strings.len() as u16);
+            strings.extend_from_slice(&encoded);
+        }
+        out.extend_from_slice(&strings);
+        out
+    }
+
+    fn os2(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        put_u16(&mut out, 4); // version
+        put_i16(&mut out, 600); // xAvgCharWidth
+        put_u16(&mut out, self.weight);
+        put_u16(&mut out, 5); // usWidthClass
+        put_u16(&mut out, 0); // fsType: installable
+        out.extend_from_slice(&[0; 20]); // sub/superscript metrics
+        put_i16(&mut out, 50); // yStrikeoutSize
+        put_i16(&mut out, 250); // yStrikeoutPosition
+        put_i16(&mut out, 0); // sFamilyClass
+        out.extend_from_slice(&[0; 10]); // panose
+        out.extend_from_slice(&[0; 16]); // ulUnicodeRange1-4
+        out.extend_from_slice(b"NOVL"); // achVendID
+        let selection = if self.italic {
+            0x0001
+        } else if self.weight == 400 {
+            0x0040
+        } else {
+            0
+        };
+        put_u16(&mut out, selection); // fsSelection
+        put_u16(&mut out, 0x0020); // usFirstCharIndex
+        put_u16(&mut out, 0xFFFF); // usLastCharIndex
+        put_i16(&mut out, 800); // sTypoAscender
+        put_i16(&mut out, -200); // sTypoDescender
+        put_i16(&mut out, 0); // sTypoLineGap
+        put_u16(&mut out, 800); // usWinAscent
+        put_u16(&mut out, 200); // usWinDescent
+        out.extend_from_slice(&[0; 8]); // ulCodePageRange1-2
+        put_i16(&mut out, 500); // sxHeight
+        put_i16(&mut out, 700); // sCapHeight
+        put_u16(&mut out, 0); // usDefaultChar
+        put_u16(&mut out, 0x20); // usBreakChar
+        put_u16(&mut out, 1); // usMaxContext
+        out
+    }
+
+    fn max_advance(&self) -> u16 {
+        self.glyphs.iter().map(Glyph::advance).max().unwrap_or(0)
+    }
+}
+
+/// An `fvar` table with the given `(tag, min, default, max)` axes and no
+/// named instances.
+pub(crate) fn fvar(axes: &[([u8; 4], f32, f32, f32)]) -> Vec<u8> {
+    let fixed = |value: f32| (value * 65536.0) as i32 as u32;
+    let mut out = Vec::new();
+    put_u16(&mut out, 1); // majorVersion
+    put_u16(&mut out, 0); // minorVersion
+    put_u16(&mut out, 16); // axesArrayOffset
+    put_u16(&mut out, 2); // reserved
+    put_u16(&mut out, axes.len() as u16);
+    put_u16(&mut out, 20); // axisSize
+    put_u16(&mut out, 0); // instanceCount
+    put_u16(&mut out, 4 + 4 * axes.len() as u16); // instanceSize
+    for (tag, min, default, max) in axes {
+        out.extend_from_slice(tag);
+        put_u32(&mut out, fixed(*min));
+        put_u32(&mut out, fixed(*default));
+        put_u32(&mut out, fixed(*max));
+        put_u16(&mut out, 0); // flags
+        put_u16(&mut out, 256); // axisNameID
+    }
+    out
+}
+
+/// Pack standalone fonts into a TrueType Collection.
+pub(crate) fn collection(fonts: &[Vec<u8>]) -> Vec<u8> {
+    let parsed: Vec<sfnt::Font> = fonts
+        .iter()
+        .map(|font| sfnt::parse(font).unwrap())
+        .collect();
+
+    let mut directory_offsets = Vec::new();
+    let mut offset = 12 + 4 * fonts.len();
+    for font in &parsed {
+        directory_offsets.push(offset);
+        offset += 12 + 16 * font.tables.len();
+    }
+
+    let mut out = Vec::new();
+    out.extend_from_slice(b"ttcf");
+    put_u32(&mut out, 0x0001_0000);
+    put_u32(&mut out, fonts.len() as u32);
+    for directory in &directory_offsets {
+        put_u32(&mut out, *directory as u32);
+    }
+
+    let mut body = Vec::new();
+    for font in &parsed {
+        put_u32(&mut out, font.flavor);
+        put_u16(&mut out, font.tables.len() as u16);
+        out.extend_from_slice(&[0; 6]);
+        for table in &font.tables {
+            out.extend_from_slice(&table.tag);
+            put_u32(&mut out, sfnt::checksum(&table.data));
+            put_u32(&mut out, (offset + body.len()) as u32);
+            put_u32(&mut out, table.data.len() as u32);
+            body.extend_from_slice(&table.data);
+            body.resize((body.len() + 3) & !3, 0);
+        }
+    }
+    out.extend_from_slice(&body);
+    out
+}
+
+fn post() -> Vec<u8> {
+    let mut out = Vec::new();
+    put_u32(&mut out, 0x0003_0000);
+    put_u32(&mut out, 0); // italicAngle
+    put_i16(&mut out, -100); // underlinePosition
+    put_i16(&mut out, 50); // underlineThickness
+    out.extend_from_slice(&[0; 20]);
+    out
+}
+
+#[test]
+fn test_builder_produces_parseable_font() {
+    let font = FontBuilder::with_chars("ab").family("Demo", "Bold").build();
+    let face = ttf_parser::Face::parse(&font, 0).unwrap();
+    assert_eq!(face.number_of_glyphs(), 3);
+    assert_eq!(face.glyph_index('b').map(|id| id.0), Some(2));
+    assert!(face.glyph_bounding_box(ttf_parser::GlyphId(1)).is_some());
+}
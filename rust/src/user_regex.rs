@@ -0,0 +1,90 @@
+//! Regular expressions written by users, for purify and chapter rules.
+//!
+//! `regex` matches in time linear in the input, so no pattern can
+//! backtrack catastrophically, but a pattern like `(\w{100}){100}` still
+//! compiles into a huge program that is slow to build and to run. Patterns
+//! are limited in length, nesting and compiled size, and loops applying a
+//! rule to a whole chapter check a `Deadline` between matches.
+
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Result};
+use regex::{Regex, RegexBuilder};
+
+/// Longest pattern accepted, in bytes.
+pub(crate) const MAX_PATTERN_LEN: usize = 4096;
+/// Memory for the compiled program, and for the lazy DFA's cache.
+const SIZE_LIMIT: usize = 2 * 1024 * 1024;
+const DFA_SIZE_LIMIT: usize = 4 * 1024 * 1024;
+const NEST_LIMIT: u32 = 64;
+
+/// Compile a user's pattern, with `^` and `$` matching at line boundaries
+/// when `multi_line` is set.
+pub(crate) fn compile(pattern: &str, multi_line: bool) -> Result<Regex> {
+    if pattern.len() > MAX_PATTERN_LEN {
+        return Err(anyhow!("pattern is longer than {MAX_PATTERN_LEN} bytes"));
+    }
+    RegexBuilder::new(pattern)
+        .multi_line(multi_line)
+        .size_limit(SIZE_LIMIT)
+        .dfa_size_limit(DFA_SIZE_LIMIT)
+        .nest_limit(NEST_LIMIT)
+        .build()
+        .map_err(|e| anyhow!("{e}"))
+}
+
+/// A time limit for applying a rule.
+pub(crate) struct Deadline {
+    start: Instant,
+    limit: Duration,
+}
+
+impl Deadline {
+    pub(crate) fn after(limit: Duration) -> Self {
+        Self {
+            start: Instant::now(),
+            limit,
+        }
+    }
+
+    /// Fail once the limit has passed; `rule` names the rule in the error.
+    pub(crate) fn check(&self, rule: &str) -> Result<()> {
+        if self.start.elapsed() > self.limit {
+            return Err(anyhow!(
+                "Rule {rule} took longer than {} ms",
+                self.limit.as_millis()
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compile_limits() {
+        let regex = compile(r"^第\d+章$", true).unwrap();
+        assert!(regex.is_match("序\n第12章\n"));
+        assert!(!compile(r"^第\d+章$", false)
+            .unwrap()
+            .is_match("序\n第12章\n"));
+        // `regex` has no backreferences, the usual cause of backtracking.
+        assert!(compile(r"(a+)\1", false).is_err());
+        assert!(compile(r"(\w{100}){100}", false).is_err());
+        assert!(compile(&"a".repeat(MAX_PATTERN_LEN + 1), false).is_err());
+        assert!(compile(&format!("{}a{}", "(".repeat(100), ")".repeat(100)), false).is_err());
+    }
+
+    #[test]
+    fn test_deadline() {
+        assert!(Deadline::after(Duration::from_secs(60))
+            .check("fast")
+            .is_ok());
+        let deadline = Deadline::after(Duration::ZERO);
+        std::thread::sleep(Duration::from_millis(1));
+        let error = deadline.check("slow").unwrap_err().to_string();
+        assert!(error.contains("slow"), "{error}");
+    }
+}
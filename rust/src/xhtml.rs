@@ -0,0 +1,533 @@
+//! Internal XHTML helpers shared by the ebook importers.
+//!
+//! Publisher markup is reduced to plain structural HTML: scripts, styles,
+//! forms and media are dropped, presentational attributes are stripped and
+//! links are rewritten to archive-absolute paths so the reader can resolve
+//! them without knowing where a chapter came from.
+
+use quick_xml::escape::{escape, resolve_xml_entity};
+use quick_xml::events::{BytesStart, BytesText, Event};
+use quick_xml::Reader;
+use regex::Regex;
+
+/// Elements removed together with their content.
+const DROPPED: &[&str] = &[
+    "head", "script", "style", "noscript", "template", "iframe", "object", "embed", "form",
+    "button", "input", "select", "textarea", "audio", "video", "link", "meta", "base",
+];
+
+/// Elements whose tags are removed but whose content is kept. Prefixed
+/// elements such as `mbp:section` are unwrapped as well.
+const UNWRAPPED: &[&str] = &["html", "body"];
+
+/// HTML elements that never have content, whether or not they are written
+/// self-closing.
+const VOID: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+const KEPT_ATTRIBUTES: &[&str] = &["id", "alt", "title", "colspan", "rowspan", "lang", "dir"];
+
+/// A chapter body after cleaning.
+pub(crate) struct CleanHtml {
+    pub html: String,
+    /// Text of the first `h1`–`h6`, used when the TOC does not name the chapter.
+    pub heading: Option<String>,
+}
+
+/// Create a lenient reader: ebook markup is frequently not well-formed.
+pub(crate) fn reader(xml: &str) -> Reader<&[u8]> {
+    let mut reader = Reader::from_str(xml);
+    let config = reader.config_mut();
+    config.check_end_names = false;
+    config.allow_unmatched_ends = true;
+    reader
+}
+
+/// Lowercased element or attribute name without its namespace prefix.
+pub(crate) fn local_name(name: &[u8]) -> String {
+    let local = name.rsplit(|&b| b == b':').next().unwrap_or(name);
+    String::from_utf8_lossy(local).to_ascii_lowercase()
+}
+
+/// Value of the attribute whose local name is `name`, unescaped.
+pub(crate) fn attribute(element: &BytesStart, name: &str) -> Option<String> {
+    element
+        .html_attributes()
+        .with_checks(false)
+        .flatten()
+        .find(|attr| local_name(attr.key.as_ref()) == name)
+        .map(|attr| {
+            attr.unescape_value()
+                .map(|value| value.into_owned())
+                .unwrap_or_else(|_| String::from_utf8_lossy(&attr.value).into_owned())
+        })
+}
+
+/// Unescaped text content, resolving the HTML entities common in ebooks.
+pub(crate) fn text(text: &BytesText) -> String {
+    text.unescape_with(html_entity)
+        .map(|value| value.into_owned())
+        .unwrap_or_else(|_| String::from_utf8_lossy(text).into_owned())
+}
+
+/// Collapse runs of whitespace into single spaces and trim the ends.
+pub(crate) fn normalize_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reduce escaped HTML, such as a feed summary, to plain text.
+pub(crate) fn strip_tags(html: &str) -> String {
+    let blocks = Regex::new(r"(?i)</?(p|br|div|li|tr|h[1-6])\b[^>]*>").unwrap();
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+    let text = blocks.replace_all(html, " ");
+    normalize_whitespace(&tags.replace_all(&text, ""))
+}
+
+/// Decode an XML document using its byte-order mark or the encoding named
+/// in its XML declaration, defaulting to UTF-8. The BOM is dropped.
+pub(crate) fn decode(bytes: &[u8]) -> String {
+    let encoding = declared_encoding(bytes).unwrap_or(encoding_rs::UTF_8);
+    let (text, _, _) = encoding.decode(bytes);
+    text.into_owned()
+}
+
+/// The encoding named in an XML declaration.
+pub(crate) fn declared_encoding(bytes: &[u8]) -> Option<&'static encoding_rs::Encoding> {
+    let head = &bytes[..bytes.len().min(256)];
+    let declaration = &head[..head.windows(2).position(|w| w == b"?>")?];
+    if !declaration.starts_with(b"<?xml") {
+        return None;
+    }
+    let start = declaration.windows(9).position(|w| w == b"encoding=")? + 9;
+    let quote = *declaration.get(start)?;
+    let label = declaration
+        .get(start + 1..)?
+        .split(|&b| b == quote)
+        .next()?;
+    encoding_rs::Encoding::for_label(label)
+}
+
+/// Directory part of an archive path (`""` for top-level entries).
+pub(crate) fn parent(path: &str) -> &str {
+    path.rfind('/').map_or("", |i| &path[..i])
+}
+
+/// Resolve `href` against `base_dir`, normalizing `.` and `..` segments.
+/// Percent-encoding in `href` is decoded.
+pub(crate) fn resolve(base_dir: &str, href: &str) -> String {
+    let href = percent_decode(href);
+    let mut segments: Vec<&str> = if href.starts_with('/') {
+        Vec::new()
+    } else {
+        base_dir.split('/').filter(|s| !s.is_empty()).collect()
+    };
+    for segment in href.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            _ => segments.push(segment),
+        }
+    }
+    segments.join("/")
+}
+
+/// Split `href` into its path and `#fragment` parts.
+pub(crate) fn split_fragment(href: &str) -> (&str, Option<&str>) {
+    match href.split_once('#') {
+        Some((path, fragment)) => (path, Some(fragment)),
+        None => (href, None),
+    }
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = |b: u8| (b as char).to_digit(16);
+            if let (Some(hi), Some(lo)) = (hex(bytes[i + 1]), hex(bytes[i + 2])) {
+                out.push((hi * 16 + lo) as u8);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn html_entity(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "nbsp" => "\u{a0}",
+        "ensp" => "\u{2002}",
+        "emsp" => "\u{2003}",
+        "thinsp" => "\u{2009}",
+        "shy" => "\u{ad}",
+        "ndash" => "–",
+        "mdash" => "—",
+        "hellip" => "…",
+        "lsquo" => "‘",
+        "rsquo" => "’",
+        "ldquo" => "“",
+        "rdquo" => "”",
+        "laquo" => "«",
+        "raquo" => "»",
+        "middot" => "·",
+        "bull" => "•",
+        "copy" => "©",
+        "reg" => "®",
+        "trade" => "™",
+        "times" => "×",
+        "deg" => "°",
+        _ => return resolve_xml_entity(name),
+    })
+}
+
+/// A stylesheet a chapter document uses, in document order.
+#[derive(Debug, PartialEq)]
+pub(crate) enum StyleSource {
+    /// Archive path of a `link`ed stylesheet.
+    Linked(String),
+    /// Content of a `style` element.
+    Inline(String),
+}
+
+/// Reduce a chapter document to clean body HTML.
+///
+/// `path` is the chapter's archive path; relative `href`/`src` values are
+/// resolved against it.
+pub(crate) fn clean(xhtml: &str, path: &str) -> CleanHtml {
+    clean_document(xhtml, path, None)
+}
+
+/// Like `clean`, but keeps `class` attributes and `style` attributes as
+/// rewritten by `style`, for use with the document's stylesheets.
+pub(crate) fn clean_styled(xhtml: &str, path: &str, style: &dyn Fn(&str) -> String) -> CleanHtml {
+    clean_document(xhtml, path, Some(style))
+}
+
+/// The stylesheets `link`ed or embedded anywhere in a document.
+pub(crate) fn stylesheets(xhtml: &str, path: &str) -> Vec<StyleSource> {
+    let base_dir = parent(path);
+    let mut reader = reader(xhtml);
+    let mut sources = Vec::new();
+    let mut inline: Option<String> = None;
+    loop {
+        match reader.read_event() {
+            Ok(Event::Eof) | Err(_) => break,
+            Ok(Event::Start(element)) | Ok(Event::Empty(element)) => {
+                match local_name(element.name().as_ref()).as_str() {
+                    "link" => {
+                        let rel = attribute(&element, "rel").unwrap_or_default();
+                        if rel
+                            .to_ascii_lowercase()
+                            .split_whitespace()
+                            .any(|r| r == "stylesheet")
+                        {
+                            if let Some(href) = attribute(&element, "href")
+                                .and_then(|href| resolve_link(base_dir, &href, false))
+                            {
+                                sources.push(StyleSource::Linked(href));
+                            }
+                        }
+                    }
+                    "style" => inline = Some(String::new()),
+                    _ => {}
+                }
+            }
+            Ok(Event::Text(content)) => {
+                if let Some(css) = inline.as_mut() {
+                    css.push_str(&text(&content));
+                }
+            }
+            Ok(Event::CData(content)) => {
+                if let Some(css) = inline.as_mut() {
+                    css.push_str(&String::from_utf8_lossy(&content));
+                }
+            }
+            Ok(Event::End(element)) if local_name(element.name().as_ref()) == "style" => {
+                if let Some(css) = inline.take() {
+                    sources.push(StyleSource::Inline(css));
+                }
+            }
+            _ => {}
+        }
+    }
+    sources
+}
+
+fn clean_document(xhtml: &str, path: &str, style: Option<&dyn Fn(&str) -> String>) -> CleanHtml {
+    let base_dir = parent(path);
+    let mut reader = reader(xhtml);
+    let mut html = String::with_capacity(xhtml.len());
+    let mut heading: Option<String> = None;
+    let mut collecting_heading = false;
+    // Emitted elements that are still open, innermost last. Unmatched end
+    // tags are ignored and anything left open is closed at the end, so
+    // fragments of a larger document still come out balanced.
+    let mut open: Vec<String> = Vec::new();
+    let mut skip_depth = 0usize;
+    let mut svg_depth = 0usize;
+
+    loop {
+        let event = match reader.read_event() {
+            Ok(Event::Eof) | Err(_) => break,
+            Ok(event) => event,
+        };
+        let is_start = matches!(event, Event::Start(_));
+        match event {
+            Event::Start(element) | Event::Empty(element) if skip_depth > 0 || svg_depth > 0 => {
+                let name = local_name(element.name().as_ref());
+                if svg_depth > 0 && skip_depth == 0 && name == "image" {
+                    push_svg_image(&mut html, &element, base_dir);
+                }
+                if is_start && !VOID.contains(&name.as_str()) {
+                    if skip_depth > 0 {
+                        skip_depth += 1;
+                    } else {
+                        svg_depth += 1;
+                    }
+                }
+            }
+            Event::End(_) if skip_depth > 0 => skip_depth -= 1,
+            Event::End(_) if svg_depth > 0 => svg_depth -= 1,
+            Event::Start(element) | Event::Empty(element) => {
+                let name = local_name(element.name().as_ref());
+                let prefixed = element.name().prefix().is_some();
+                let is_void = VOID.contains(&name.as_str());
+                if DROPPED.contains(&name.as_str()) {
+                    if is_start && !is_void {
+                        skip_depth = 1;
+                    }
+                } else if name == "svg" {
+                    if is_start {
+                        svg_depth = 1;
+                    }
+                } else if !prefixed && !UNWRAPPED.contains(&name.as_str()) {
+                    push_start_tag(&mut html, &name, &element, base_dir, style);
+                    if is_void {
+                    } else if is_start {
+                        if heading.is_none() && is_heading(&name) {
+                            collecting_heading = true;
+                            heading = Some(String::new());
+                        }
+                        open.push(name);
+                    } else {
+                        html.push_str(&format!("</{name}>"));
+                    }
+                }
+            }
+            Event::End(element) => {
+                let name = local_name(element.name().as_ref());
+                if let Some(pos) = open.iter().rposition(|open| *open == name) {
+                    for name in open.drain(pos..).rev() {
+                        html.push_str(&format!("</{name}>"));
+                        if is_heading(&name) {
+                            collecting_heading = false;
+                        }
+                    }
+                }
+            }
+            Event::Text(content) if skip_depth == 0 && svg_depth == 0 => {
+                // Entities are left escaped; the renderer understands them.
+                html.push_str(&String::from_utf8_lossy(&content));
+                if collecting_heading {
+                    if let Some(heading) = heading.as_mut() {
+                        heading.push_str(&text(&content));
+                    }
+                }
+            }
+            Event::CData(content) if skip_depth == 0 && svg_depth == 0 => {
+                let content = String::from_utf8_lossy(&content);
+                html.push_str(&escape(content.as_ref()));
+                if collecting_heading {
+                    if let Some(heading) = heading.as_mut() {
+                        heading.push_str(&content);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    for name in open.drain(..).rev() {
+        html.push_str(&format!("</{name}>"));
+    }
+
+    CleanHtml {
+        html: html.trim().to_string(),
+        heading: heading
+            .map(|heading| normalize_whitespace(&heading))
+            .filter(|heading| !heading.is_empty()),
+    }
+}
+
+fn is_heading(name: &str) -> bool {
+    matches!(name, "h1" | "h2" | "h3" | "h4" | "h5" | "h6")
+}
+
+fn push_start_tag(
+    html: &mut String,
+    name: &str,
+    element: &BytesStart,
+    base_dir: &str,
+    style: Option<&dyn Fn(&str) -> String>,
+) {
+    html.push('<');
+    html.push_str(name);
+    for attr in element.html_attributes().with_checks(false).flatten() {
+        let key = local_name(attr.key.as_ref());
+        let value = attr
+            .unescape_value()
+            .map(|value| value.into_owned())
+            .unwrap_or_else(|_| String::from_utf8_lossy(&attr.value).into_owned());
+        let value = match (name, key.as_str()) {
+            ("a", "href") => resolve_link(base_dir, &value, true),
+            ("img", "src") => resolve_link(base_dir, &value, false),
+            (_, key) if KEPT_ATTRIBUTES.contains(&key) => Some(value),
+            (_, "class") if style.is_some() => Some(value),
+            (_, "style") => style
+                .map(|style| style(&value))
+                .filter(|style| !style.is_empty()),
+            _ => None,
+        };
+        if let Some(value) = value {
+            html.push_str(&format!(" {key}=\"{}\"", escape(value.as_str())));
+        }
+    }
+    html.push('>');
+}
+
+/// Cover pages often wrap the image in SVG; keep it as a plain `img`.
+fn push_svg_image(html: &mut String, element: &BytesStart, base_dir: &str) {
+    if let Some(src) =
+        attribute(element, "href").and_then(|href| resolve_link(base_dir, &href, false))
+    {
+        html.push_str(&format!("<img src=\"{}\">", escape(src.as_str())));
+    }
+}
+
+/// Rewrite a link to an archive-absolute path. Web links are kept for
+/// anchors only; other schemes (`javascript:`, `kindle:` …) are dropped.
+fn resolve_link(base_dir: &str, value: &str, allow_external: bool) -> Option<String> {
+    let value = value.trim();
+    let lower = value.to_ascii_lowercase();
+    if value.starts_with('#') || lower.starts_with("data:") {
+        return Some(value.to_string());
+    }
+    if ["http://", "https://", "mailto:"]
+        .iter()
+        .any(|scheme| lower.starts_with(scheme))
+    {
+        return allow_external.then(|| value.to_string());
+    }
+    if has_scheme(&lower) {
+        return None;
+    }
+    let (path, fragment) = split_fragment(value);
+    let mut resolved = resolve(base_dir, path);
+    if let Some(fragment) = fragment {
+        resolved.push('#');
+        resolved.push_str(fragment);
+    }
+    Some(resolved)
+}
+
+fn has_scheme(link: &str) -> bool {
+    link.split_once(':').is_some_and(|(scheme, _)| {
+        scheme.len() > 1
+            && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_normalizes_relative_paths() {
+        assert_eq!(
+            resolve("OEBPS/Text", "../Images/a%20b.jpg"),
+            "OEBPS/Images/a b.jpg"
+        );
+        assert_eq!(resolve("OEBPS", "./ch1.xhtml"), "OEBPS/ch1.xhtml");
+        assert_eq!(resolve("", "ch1.xhtml"), "ch1.xhtml");
+        assert_eq!(resolve("OEBPS", "/cover.xhtml"), "cover.xhtml");
+    }
+
+    #[test]
+    fn test_clean_strips_scripts_styles_and_attributes() {
+        let xhtml = r#"<?xml version="1.0" encoding="utf-8"?>
+<html xmlns="http://www.w3.org/1999/xhtml">
+<head><title>Ignored</title><link rel="stylesheet" href="s.css"/></head>
+<body class="x">
+<h1 class="title" id="c1">Chapter&nbsp;One</h1>
+<script>alert(1)</script>
+<p style="color:red" onclick="x()">Hello <a href="ch2.xhtml#n1">note</a><br/></p>
+<img src="../Images/pic.png" alt="pic"/>
+<svg><image xlink:href="../Images/cover.jpg"/></svg>
+<div/>
+</body></html>"#;
+        let clean = clean(xhtml, "OEBPS/Text/ch1.xhtml");
+
+        assert_eq!(clean.heading.as_deref(), Some("Chapter One"));
+        assert_eq!(
+            clean.html,
+            "<h1 id=\"c1\">Chapter&nbsp;One</h1>\n\n\
+             <p>Hello <a href=\"OEBPS/Text/ch2.xhtml#n1\">note</a><br></p>\n\
+             <img src=\"OEBPS/Images/pic.png\" alt=\"pic\">\n\
+             <img src=\"OEBPS/Images/cover.jpg\">\n\
+             <div></div>"
+        );
+    }
+
+    #[test]
+    fn test_clean_styled_keeps_classes_and_styles() {
+        let xhtml = r#"<html><head><link rel="stylesheet" href="../Styles/main.css"/>
+<style>p { text-indent: 2em }</style></head>
+<body><p class="first" style="color:red">x</p></body></html>"#;
+        let clean = clean_styled(xhtml, "OEBPS/Text/ch1.xhtml", &|style| style.to_uppercase());
+        assert_eq!(clean.html, "<p class=\"first\" style=\"COLOR:RED\">x</p>");
+        assert_eq!(
+            stylesheets(xhtml, "OEBPS/Text/ch1.xhtml"),
+            [
+                StyleSource::Linked("OEBPS/Styles/main.css".to_string()),
+                StyleSource::Inline("p { text-indent: 2em }".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_honors_declared_encoding() {
+        let (bytes, _, _) = encoding_rs::WINDOWS_1251
+            .encode("<?xml version=\"1.0\" encoding=\"windows-1251\"?><p>Привет</p>");
+        assert!(decode(&bytes).ends_with("<p>Привет</p>"));
+        assert_eq!(decode("\u{feff}<p>x</p>".as_bytes()), "<p>x</p>");
+    }
+
+    #[test]
+    fn test_clean_balances_loose_html() {
+        let clean = clean(
+            "</div><p>one<br><mbp:nu>two</mbp:nu><meta name=x><b>three</p>",
+            "part0001.html",
+        );
+        assert_eq!(clean.html, "<p>one<br>two<b>three</b></p>");
+    }
+
+    #[test]
+    fn test_clean_drops_javascript_links() {
+        let clean = clean(
+            r#"<body><a href="javascript:go()">x</a><a href="https://example.com">y</a></body>"#,
+            "ch.xhtml",
+        );
+        assert_eq!(clean.html, "<a>x</a><a href=\"https://example.com\">y</a>");
+    }
+}
@@ -0,0 +1,806 @@
+//! A small XPath 1.0 evaluator over `scraper` documents.
+//!
+//! Book sources written for Legado use XPath for lists and fields, almost
+//! always as location paths with a few predicates. This covers the child,
+//! descendant, parent, self, attribute, ancestor and sibling axes, the
+//! `*`, `text()` and `node()` tests, unions, positional predicates,
+//! comparisons, `and`/`or`, `+`/`-` and the common string functions.
+//!
+//! Like the Java engine Legado uses, absolute paths start at the node the
+//! expression is evaluated on, so `//a` on a list item only finds the links
+//! inside that item.
+
+use anyhow::{anyhow, Result};
+use ego_tree::NodeRef;
+use scraper::Node;
+
+/// A node or attribute value selected by an expression, or the value of an
+/// expression that does not select nodes, such as `count(//li)`.
+#[derive(Clone)]
+pub(crate) enum Item<'a> {
+    Node(NodeRef<'a, Node>),
+    Attribute(&'a str),
+    Scalar(String),
+}
+
+impl Item<'_> {
+    /// The XPath string value: the text of a node and its descendants, or
+    /// the attribute value.
+    pub(crate) fn string_value(&self) -> String {
+        match self {
+            Item::Node(node) => string_value(*node),
+            Item::Attribute(value) => value.to_string(),
+            Item::Scalar(value) => value.clone(),
+        }
+    }
+}
+
+/// Evaluate `expression` with `context` as both the context node and the
+/// root of absolute paths.
+pub(crate) fn select<'a>(context: NodeRef<'a, Node>, expression: &str) -> Result<Vec<Item<'a>>> {
+    let tokens = tokenize(expression).map_err(|e| anyhow!("Invalid XPath {expression}: {e}"))?;
+    let mut parser = Parser { tokens, pos: 0 };
+    let expr = parser
+        .expr()
+        .map_err(|e| anyhow!("Invalid XPath {expression}: {e}"))?;
+    if parser.pos < parser.tokens.len() {
+        return Err(anyhow!(
+            "Invalid XPath {expression}: unexpected {:?}",
+            parser.tokens[parser.pos]
+        ));
+    }
+    let scope = Scope {
+        node: context,
+        position: 1,
+        size: 1,
+        root: context,
+    };
+    Ok(match eval(&expr, &scope)? {
+        Value::Nodes(items) => items,
+        value => vec![Item::Scalar(value.string())],
+    })
+}
+
+fn string_value(node: NodeRef<Node>) -> String {
+    match node.value() {
+        Node::Text(text) => text.to_string(),
+        _ => node
+            .descendants()
+            .filter_map(|n| n.value().as_text().map(|t| t.to_string()))
+            .collect(),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Slash,
+    DoubleSlash,
+    LBracket,
+    RBracket,
+    LParen,
+    RParen,
+    At,
+    Comma,
+    Pipe,
+    Dot,
+    DotDot,
+    Star,
+    Axis(String),
+    Op(&'static str),
+    Number(f64),
+    Literal(String),
+    Name(String),
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        i += 1;
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '/' if next == Some('/') => {
+                i += 1;
+                Token::DoubleSlash
+            }
+            '/' => Token::Slash,
+            '[' => Token::LBracket,
+            ']' => Token::RBracket,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            '@' => Token::At,
+            ',' => Token::Comma,
+            '|' => Token::Pipe,
+            '*' => Token::Star,
+            '+' => Token::Op("+"),
+            '-' => Token::Op("-"),
+            '=' => Token::Op("="),
+            '!' if next == Some('=') => {
+                i += 1;
+                Token::Op("!=")
+            }
+            '<' | '>' if next == Some('=') => {
+                i += 1;
+                Token::Op(if c == '<' { "<=" } else { ">=" })
+            }
+            '<' => Token::Op("<"),
+            '>' => Token::Op(">"),
+            '.' if next == Some('.') => {
+                i += 1;
+                Token::DotDot
+            }
+            '.' if !next.is_some_and(|n| n.is_ascii_digit()) => Token::Dot,
+            '\'' | '"' => {
+                let start = i;
+                while i < chars.len() && chars[i] != c {
+                    i += 1;
+                }
+                if i == chars.len() {
+                    return Err("unterminated string".to_string());
+                }
+                i += 1;
+                Token::Literal(chars[start..i - 1].iter().collect())
+            }
+            c if c.is_ascii_digit() || c == '.' => {
+                let start = i - 1;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let number: String = chars[start..i].iter().collect();
+                Token::Number(number.parse().map_err(|_| format!("bad number {number}"))?)
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i - 1;
+                while i < chars.len()
+                    && (chars[i].is_alphanumeric() || matches!(chars[i], '_' | '-' | '.'))
+                {
+                    i += 1;
+                }
+                let name: String = chars[start..i].iter().collect();
+                if chars.get(i) == Some(&':') && chars.get(i + 1) == Some(&':') {
+                    i += 2;
+                    Token::Axis(name)
+                } else {
+                    Token::Name(name)
+                }
+            }
+            c => return Err(format!("unexpected character {c:?}")),
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Axis {
+    Child,
+    Descendant,
+    DescendantOrSelf,
+    Parent,
+    Ancestor,
+    AncestorOrSelf,
+    /// `self::`
+    Current,
+    Attribute,
+    FollowingSibling,
+    PrecedingSibling,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Test {
+    Name(String),
+    Any,
+    Text,
+    Node,
+}
+
+#[derive(Debug)]
+struct Step {
+    axis: Axis,
+    test: Test,
+    predicates: Vec<Expr>,
+}
+
+#[derive(Debug)]
+enum Expr {
+    Path { absolute: bool, steps: Vec<Step> },
+    Union(Vec<Expr>),
+    Literal(String),
+    Number(f64),
+    Negate(Box<Expr>),
+    Call(String, Vec<Expr>),
+    Binary(Box<Expr>, &'static str, Box<Expr>),
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: Token) -> Result<(), String> {
+        if self.eat(&token) {
+            Ok(())
+        } else {
+            Err(format!("expected {token:?}, found {:?}", self.peek()))
+        }
+    }
+
+    fn eat_word(&mut self, word: &str) -> bool {
+        if matches!(self.peek(), Some(Token::Name(name)) if name == word) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_op(&mut self, ops: &[&'static str]) -> Option<&'static str> {
+        match self.peek() {
+            Some(Token::Op(op)) if ops.contains(op) => {
+                let op = *op;
+                self.pos += 1;
+                Some(op)
+            }
+            _ => None,
+        }
+    }
+
+    fn expr(&mut self) -> Result<Expr, String> {
+        let mut left = self.and()?;
+        while self.eat_word("or") {
+            left = Expr::Binary(Box::new(left), "or", Box::new(self.and()?));
+        }
+        Ok(left)
+    }
+
+    fn and(&mut self) -> Result<Expr, String> {
+        let mut left = self.comparison()?;
+        while self.eat_word("and") {
+            left = Expr::Binary(Box::new(left), "and", Box::new(self.comparison()?));
+        }
+        Ok(left)
+    }
+
+    fn comparison(&mut self) -> Result<Expr, String> {
+        let mut left = self.additive()?;
+        while let Some(op) = self.eat_op(&["=", "!=", "<", "<=", ">", ">="]) {
+            left = Expr::Binary(Box::new(left), op, Box::new(self.additive()?));
+        }
+        Ok(left)
+    }
+
+    fn additive(&mut self) -> Result<Expr, String> {
+        let mut left = self.unary()?;
+        while let Some(op) = self.eat_op(&["+", "-"]) {
+            left = Expr::Binary(Box::new(left), op, Box::new(self.unary()?));
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Result<Expr, String> {
+        if self.eat_op(&["-"]).is_some() {
+            return Ok(Expr::Negate(Box::new(self.unary()?)));
+        }
+        let first = self.primary()?;
+        if self.peek() != Some(&Token::Pipe) {
+            return Ok(first);
+        }
+        let mut paths = vec![first];
+        while self.eat(&Token::Pipe) {
+            paths.push(self.primary()?);
+        }
+        Ok(Expr::Union(paths))
+    }
+
+    fn primary(&mut self) -> Result<Expr, String> {
+        match self.peek().cloned() {
+            Some(Token::Number(n)) => {
+                self.pos += 1;
+                Ok(Expr::Number(n))
+            }
+            Some(Token::Literal(s)) => {
+                self.pos += 1;
+                Ok(Expr::Literal(s))
+            }
+            Some(Token::LParen) => {
+                self.pos += 1;
+                let expr = self.expr()?;
+                self.expect(Token::RParen)?;
+                Ok(expr)
+            }
+            Some(Token::Name(name))
+                if !matches!(name.as_str(), "text" | "node")
+                    && self.tokens.get(self.pos + 1) == Some(&Token::LParen) =>
+            {
+                self.pos += 2;
+                let mut args = Vec::new();
+                if !self.eat(&Token::RParen) {
+                    loop {
+                        args.push(self.expr()?);
+                        if self.eat(&Token::RParen) {
+                            break;
+                        }
+                        self.expect(Token::Comma)?;
+                    }
+                }
+                Ok(Expr::Call(name, args))
+            }
+            _ => self.path(),
+        }
+    }
+
+    fn path(&mut self) -> Result<Expr, String> {
+        let mut steps = Vec::new();
+        let mut absolute = false;
+        if self.eat(&Token::Slash) {
+            absolute = true;
+            if !self.at_step() {
+                return Ok(Expr::Path { absolute, steps });
+            }
+        } else if self.eat(&Token::DoubleSlash) {
+            absolute = true;
+            steps.push(descendant_or_self());
+        }
+        loop {
+            steps.push(self.step()?);
+            if self.eat(&Token::DoubleSlash) {
+                steps.push(descendant_or_self());
+            } else if !self.eat(&Token::Slash) {
+                break;
+            }
+        }
+        Ok(Expr::Path { absolute, steps })
+    }
+
+    fn at_step(&self) -> bool {
+        matches!(
+            self.peek(),
+            Some(
+                Token::Dot
+                    | Token::DotDot
+                    | Token::At
+                    | Token::Star
+                    | Token::Axis(_)
+                    | Token::Name(_)
+            )
+        )
+    }
+
+    fn step(&mut self) -> Result<Step, String> {
+        let (axis, test) = match self.peek().cloned() {
+            Some(Token::Dot) => {
+                self.pos += 1;
+                (Axis::Current, Test::Node)
+            }
+            Some(Token::DotDot) => {
+                self.pos += 1;
+                (Axis::Parent, Test::Node)
+            }
+            Some(Token::At) => {
+                self.pos += 1;
+                (Axis::Attribute, self.test()?)
+            }
+            Some(Token::Axis(name)) => {
+                self.pos += 1;
+                let axis = match name.as_str() {
+                    "child" => Axis::Child,
+                    "descendant" => Axis::Descendant,
+                    "descendant-or-self" => Axis::DescendantOrSelf,
+                    "parent" => Axis::Parent,
+                    "ancestor" => Axis::Ancestor,
+                    "ancestor-or-self" => Axis::AncestorOrSelf,
+                    "self" => Axis::Current,
+                    "attribute" => Axis::Attribute,
+                    "following-sibling" => Axis::FollowingSibling,
+                    "preceding-sibling" => Axis::PrecedingSibling,
+                    _ => return Err(format!("unsupported axis {name}")),
+                };
+                (axis, self.test()?)
+            }
+            _ => (Axis::Child, self.test()?),
+        };
+        let mut predicates = Vec::new();
+        while self.eat(&Token::LBracket) {
+            predicates.push(self.expr()?);
+            self.expect(Token::RBracket)?;
+        }
+        Ok(Step {
+            axis,
+            test,
+            predicates,
+        })
+    }
+
+    fn test(&mut self) -> Result<Test, String> {
+        match self.peek().cloned() {
+            Some(Token::Star) => {
+                self.pos += 1;
+                Ok(Test::Any)
+            }
+            Some(Token::Name(name)) => {
+                self.pos += 1;
+                if matches!(name.as_str(), "text" | "node") && self.eat(&Token::LParen) {
+                    self.expect(Token::RParen)?;
+                    return Ok(if name == "text" {
+                        Test::Text
+                    } else {
+                        Test::Node
+                    });
+                }
+                Ok(Test::Name(name.to_ascii_lowercase()))
+            }
+            token => Err(format!("expected a node test, found {token:?}")),
+        }
+    }
+}
+
+fn descendant_or_self() -> Step {
+    Step {
+        axis: Axis::DescendantOrSelf,
+        test: Test::Node,
+        predicates: Vec::new(),
+    }
+}
+
+enum Value<'a> {
+    Nodes(Vec<Item<'a>>),
+    Str(String),
+    Num(f64),
+    Bool(bool),
+}
+
+impl Value<'_> {
+    fn string(&self) -> String {
+        match self {
+            Value::Nodes(items) => items.first().map(Item::string_value).unwrap_or_default(),
+            Value::Str(s) => s.clone(),
+            Value::Num(n) if n.fract() == 0.0 && n.is_finite() => format!("{}", *n as i64),
+            Value::Num(n) => n.to_string(),
+            Value::Bool(b) => b.to_string(),
+        }
+    }
+
+    fn number(&self) -> f64 {
+        match self {
+            Value::Num(n) => *n,
+            Value::Bool(b) => f64::from(u8::from(*b)),
+            _ => self.string().trim().parse().unwrap_or(f64::NAN),
+        }
+    }
+
+    fn boolean(&self) -> bool {
+        match self {
+            Value::Nodes(items) => !items.is_empty(),
+            Value::Str(s) => !s.is_empty(),
+            Value::Num(n) => *n != 0.0 && !n.is_nan(),
+            Value::Bool(b) => *b,
+        }
+    }
+}
+
+struct Scope<'a> {
+    node: NodeRef<'a, Node>,
+    position: usize,
+    size: usize,
+    root: NodeRef<'a, Node>,
+}
+
+fn eval<'a>(expr: &Expr, scope: &Scope<'a>) -> Result<Value<'a>> {
+    Ok(match expr {
+        Expr::Path { absolute, steps } => {
+            let start = if *absolute { scope.root } else { scope.node };
+            Value::Nodes(eval_steps(steps, start, scope.root)?)
+        }
+        Expr::Union(parts) => {
+            let mut items = Vec::new();
+            for part in parts {
+                match eval(part, scope)? {
+                    Value::Nodes(nodes) => items.extend(nodes),
+                    _ => return Err(anyhow!("XPath union of non-node values")),
+                }
+            }
+            Value::Nodes(dedup(items))
+        }
+        Expr::Literal(s) => Value::Str(s.clone()),
+        Expr::Number(n) => Value::Num(*n),
+        Expr::Negate(inner) => Value::Num(-eval(inner, scope)?.number()),
+        Expr::Binary(left, "and", right) => {
+            Value::Bool(eval(left, scope)?.boolean() && eval(right, scope)?.boolean())
+        }
+        Expr::Binary(left, "or", right) => {
+            Value::Bool(eval(left, scope)?.boolean() || eval(right, scope)?.boolean())
+        }
+        Expr::Binary(left, op @ ("+" | "-"), right) => {
+            let (a, b) = (eval(left, scope)?.number(), eval(right, scope)?.number());
+            Value::Num(if *op == "+" { a + b } else { a - b })
+        }
+        Expr::Binary(left, op, right) => {
+            Value::Bool(compare(&eval(left, scope)?, op, &eval(right, scope)?))
+        }
+        Expr::Call(name, args) => call(name, args, scope)?,
+    })
+}
+
+fn call<'a>(name: &str, args: &[Expr], scope: &Scope<'a>) -> Result<Value<'a>> {
+    let arg = |i: usize| -> Result<Value<'a>> {
+        match args.get(i) {
+            Some(expr) => eval(expr, scope),
+            None => Ok(Value::Nodes(vec![Item::Node(scope.node)])),
+        }
+    };
+    let string = |i: usize| -> Result<String> { Ok(arg(i)?.string()) };
+    Ok(match name {
+        "position" => Value::Num(scope.position as f64),
+        "last" => Value::Num(scope.size as f64),
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        "not" => Value::Bool(!arg(0)?.boolean()),
+        "boolean" => Value::Bool(arg(0)?.boolean()),
+        "number" => Value::Num(arg(0)?.number()),
+        "string" => Value::Str(string(0)?),
+        "contains" => Value::Bool(string(0)?.contains(&string(1)?)),
+        "starts-with" => Value::Bool(string(0)?.starts_with(&string(1)?)),
+        "ends-with" => Value::Bool(string(0)?.ends_with(&string(1)?)),
+        "string-length" => Value::Num(string(0)?.chars().count() as f64),
+        "normalize-space" => {
+            Value::Str(string(0)?.split_whitespace().collect::<Vec<_>>().join(" "))
+        }
+        "concat" => Value::Str(
+            (0..args.len())
+                .map(string)
+                .collect::<Result<Vec<_>>>()?
+                .concat(),
+        ),
+        "substring-before" => {
+            let (s, sep) = (string(0)?, string(1)?);
+            Value::Str(
+                s.split_once(&sep)
+                    .map(|(a, _)| a.to_string())
+                    .unwrap_or_default(),
+            )
+        }
+        "substring-after" => {
+            let (s, sep) = (string(0)?, string(1)?);
+            Value::Str(
+                s.split_once(&sep)
+                    .map(|(_, b)| b.to_string())
+                    .unwrap_or_default(),
+            )
+        }
+        "count" => match arg(0)? {
+            Value::Nodes(items) => Value::Num(items.len() as f64),
+            _ => return Err(anyhow!("XPath count() needs a node set")),
+        },
+        "name" | "local-name" => {
+            let node = match arg(0)? {
+                Value::Nodes(items) => items.into_iter().next(),
+                _ => None,
+            };
+            Value::Str(match node {
+                Some(Item::Node(node)) => node
+                    .value()
+                    .as_element()
+                    .map(|e| e.name().to_string())
+                    .unwrap_or_default(),
+                _ => String::new(),
+            })
+        }
+        _ => return Err(anyhow!("Unsupported XPath function {name}()")),
+    })
+}
+
+fn compare(a: &Value, op: &str, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Nodes(items), other) => items
+            .iter()
+            .any(|item| compare_atoms(&Value::Str(item.string_value()), op, other)),
+        (other, Value::Nodes(items)) => items
+            .iter()
+            .any(|item| compare_atoms(other, op, &Value::Str(item.string_value()))),
+        _ => compare_atoms(a, op, b),
+    }
+}
+
+fn compare_atoms(a: &Value, op: &str, b: &Value) -> bool {
+    let equal = match (a, b) {
+        (Value::Bool(_), _) | (_, Value::Bool(_)) => a.boolean() == b.boolean(),
+        (Value::Num(_), _) | (_, Value::Num(_)) => a.number() == b.number(),
+        _ => a.string() == b.string(),
+    };
+    let (x, y) = (a.number(), b.number());
+    match op {
+        "=" => equal,
+        "!=" => !equal,
+        "<" => x < y,
+        "<=" => x <= y,
+        ">" => x > y,
+        _ => x >= y,
+    }
+}
+
+fn eval_steps<'a>(
+    steps: &[Step],
+    start: NodeRef<'a, Node>,
+    root: NodeRef<'a, Node>,
+) -> Result<Vec<Item<'a>>> {
+    let mut items = vec![Item::Node(start)];
+    for step in steps {
+        let mut next = Vec::new();
+        for item in &items {
+            let Item::Node(node) = item else {
+                continue;
+            };
+            let mut candidates = axis(step, *node);
+            for predicate in &step.predicates {
+                let size = candidates.len();
+                let mut kept = Vec::new();
+                for (index, candidate) in candidates.into_iter().enumerate() {
+                    let Item::Node(candidate_node) = candidate else {
+                        kept.push(candidate);
+                        continue;
+                    };
+                    let scope = Scope {
+                        node: candidate_node,
+                        position: index + 1,
+                        size,
+                        root,
+                    };
+                    let keep = match eval(predicate, &scope)? {
+                        Value::Num(n) => n == (index + 1) as f64,
+                        value => value.boolean(),
+                    };
+                    if keep {
+                        kept.push(candidate);
+                    }
+                }
+                candidates = kept;
+            }
+            next.extend(candidates);
+        }
+        items = dedup(next);
+    }
+    Ok(items)
+}
+
+fn axis<'a>(step: &Step, node: NodeRef<'a, Node>) -> Vec<Item<'a>> {
+    if step.axis == Axis::Attribute {
+        let Some(element) = node.value().as_element() else {
+            return Vec::new();
+        };
+        return element
+            .attrs()
+            .filter(|(name, _)| match &step.test {
+                Test::Name(test) => name.eq_ignore_ascii_case(test),
+                _ => true,
+            })
+            .map(|(_, value)| Item::Attribute(value))
+            .collect();
+    }
+    let nodes: Vec<NodeRef<'a, Node>> = match step.axis {
+        Axis::Child => node.children().collect(),
+        Axis::Descendant => node.descendants().skip(1).collect(),
+        Axis::DescendantOrSelf => node.descendants().collect(),
+        Axis::Parent => node.parent().into_iter().collect(),
+        Axis::Ancestor => node.ancestors().collect(),
+        Axis::AncestorOrSelf => std::iter::once(node).chain(node.ancestors()).collect(),
+        Axis::Current => vec![node],
+        Axis::FollowingSibling => node.next_siblings().collect(),
+        Axis::PrecedingSibling => node.prev_siblings().collect(),
+        Axis::Attribute => unreachable!(),
+    };
+    nodes
+        .into_iter()
+        .filter(|n| matches_test(&step.test, *n))
+        .map(Item::Node)
+        .collect()
+}
+
+fn matches_test(test: &Test, node: NodeRef<Node>) -> bool {
+    match test {
+        Test::Node => true,
+        Test::Text => node.value().is_text(),
+        Test::Any => node.value().is_element(),
+        Test::Name(name) => node
+            .value()
+            .as_element()
+            .is_some_and(|e| e.name().eq_ignore_ascii_case(name)),
+    }
+}
+
+fn dedup(items: Vec<Item>) -> Vec<Item> {
+    let mut seen = std::collections::HashSet::new();
+    let mut nodes: Vec<Item> = items
+        .into_iter()
+        .filter(|item| match item {
+            Item::Node(node) => seen.insert(node.id()),
+            Item::Attribute(_) | Item::Scalar(_) => true,
+        })
+        .collect();
+    // Keep node sets in document order.
+    if nodes.iter().all(|item| matches!(item, Item::Node(_))) {
+        nodes.sort_by_key(|item| match item {
+            Item::Node(node) => Some(node.id()),
+            _ => None,
+        });
+    }
+    nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scraper::Html;
+
+    fn strings(html: &Html, expression: &str) -> Vec<String> {
+        select(html.tree.root(), expression)
+            .unwrap()
+            .iter()
+            .map(|item| item.string_value().trim().to_string())
+            .collect()
+    }
+
+    const PAGE: &str = r#"<html><body>
+        <div class="list main"><ul>
+          <li><a href="/b/1">One</a><span>Author A</span></li>
+          <li><a href="/b/2">Two</a><span>Author B</span></li>
+          <li class="ad"><a href="/ad">Ad</a></li>
+        </ul></div>
+        <p id="intro">Hello <b>world</b></p>
+    </body></html>"#;
+
+    #[test]
+    fn test_paths_and_predicates() {
+        let html = Html::parse_document(PAGE);
+        assert_eq!(strings(&html, "//li/a/@href"), ["/b/1", "/b/2", "/ad"]);
+        assert_eq!(
+            strings(&html, "//li[not(@class='ad')]/a/text()"),
+            ["One", "Two"]
+        );
+        assert_eq!(
+            strings(&html, "//div[contains(@class,'main')]//li[last()]/a"),
+            ["Ad"]
+        );
+        assert_eq!(strings(&html, "//li[position()>1]/span"), ["Author B"]);
+        assert_eq!(strings(&html, "//a[.='Two']/../span"), ["Author B"]);
+        assert_eq!(strings(&html, "//p[@id='intro']/text()"), ["Hello"]);
+        assert_eq!(
+            strings(&html, "//*[@id='intro']|//li[1]/span"),
+            ["Author A", "Hello world"]
+        );
+        assert_eq!(strings(&html, "count(//li)"), ["3"]);
+    }
+
+    #[test]
+    fn test_absolute_paths_start_at_context() {
+        let html = Html::parse_document(PAGE);
+        let items = select(html.tree.root(), "//li").unwrap();
+        let Item::Node(second) = &items[1] else {
+            panic!("expected a node");
+        };
+        let links: Vec<String> = select(*second, "//a/@href")
+            .unwrap()
+            .iter()
+            .map(Item::string_value)
+            .collect();
+        assert_eq!(links, ["/b/2"]);
+        assert!(select(*second, "//a[").is_err());
+        assert!(select(*second, "//a[frobnicate()]").is_err());
+    }
+}